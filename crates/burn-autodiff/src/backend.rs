@@ -56,7 +56,20 @@ impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
     fn backward<const D: usize>(tensor: AutodiffTensor<B, D>) -> Gradients {
         let client = tensor.node.client.clone();
 
-        AutodiffClient::backward(&client, tensor)
+        AutodiffClient::backward(&client, tensor, None)
+    }
+
+    fn backward_with_hook<const D: usize>(
+        tensor: AutodiffTensor<B, D>,
+        hook: &mut dyn FnMut(&Gradients),
+    ) -> Gradients {
+        let client = tensor.node.client.clone();
+
+        AutodiffClient::backward(&client, tensor, Some(hook))
+    }
+
+    fn grad_complete<const D: usize>(tensor: &AutodiffTensor<B, D>, grads: &Gradients) -> bool {
+        grads.is_complete(tensor)
     }
 
     fn grad<const D: usize>(
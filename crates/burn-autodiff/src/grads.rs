@@ -1,4 +1,5 @@
 use burn_tensor::{backend::Backend, container::TensorContainer, Tensor};
+use std::collections::HashSet;
 
 use crate::{
     graph::{NodeRef, Requirement},
@@ -12,6 +13,9 @@ pub type GradID = u64;
 /// Gradients container used during the backward pass.
 pub struct Gradients {
     container: TensorContainer<GradID>,
+    // The gradients that won't change anymore during a hooked backward pass, all of them being
+    // complete when `None`.
+    pub(crate) completed: Option<HashSet<GradID>>,
 }
 
 type TensorPrimitive<B, const D: usize> = <B as Backend>::FloatTensorPrimitive<D>;
@@ -24,6 +28,7 @@ impl Gradients {
     ) -> Self {
         let mut gradients = Self {
             container: TensorContainer::new(),
+            completed: None,
         };
         gradients.register::<B, D>(
             root_node.id,
@@ -72,6 +77,15 @@ impl Gradients {
             .map(|tensor| tensor.into_primitive())
     }
 
+    /// Whether the gradient of a tensor won't change anymore during the backward pass, being
+    /// always true after it.
+    pub fn is_complete<B: Backend, const D: usize>(&self, tensor: &AutodiffTensor<B, D>) -> bool {
+        match &self.completed {
+            Some(completed) => completed.contains(&tensor.node.id.value),
+            None => true,
+        }
+    }
+
     /// Register a grad tensor in the container.
     ///
     /// If the tensor already exists, add both tensors together before saving the result.
@@ -23,6 +23,10 @@ impl<B: Backend, C: CheckpointStrategy> BoolTensorOps<Self> for Autodiff<B, C> {
         B::bool_into_data(tensor)
     }
 
+    fn bool_checksum<const D: usize>(tensor: BoolTensor<B, D>) -> Reader<u64> {
+        B::bool_checksum(tensor)
+    }
+
     fn bool_into_int<const D: usize>(tensor: BoolTensor<B, D>) -> IntTensor<B, D> {
         B::bool_into_int(tensor)
     }
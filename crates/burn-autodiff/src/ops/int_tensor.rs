@@ -2,9 +2,11 @@ use crate::{checkpoint::strategy::CheckpointStrategy, tensor::AutodiffTensor, Au
 
 use burn_tensor::{
     backend::Backend,
-    ops::{BoolTensor, IntTensor, IntTensorOps},
-    Device, Distribution, Reader, Shape, TensorData,
+    ops::{bincount, BoolTensor, FloatTensor, IntTensor, IntTensorOps},
+    Device, Distribution, IndexReduction, Reader, Shape, TensorData,
 };
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use burn_tensor::{ops::unique::UniqueOutput, Int};
 
 impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
     fn int_from_data<const D: usize>(data: TensorData, device: &Device<Self>) -> IntTensor<B, D> {
@@ -23,6 +25,10 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_into_data(tensor)
     }
 
+    fn int_checksum<const D: usize>(tensor: IntTensor<B, D>) -> Reader<u64> {
+        B::int_checksum(tensor)
+    }
+
     fn int_to_device<const D: usize>(
         tensor: IntTensor<B, D>,
         device: &Device<Self>,
@@ -350,6 +356,51 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_arange(range, device)
     }
 
+    fn int_bincount(tensor: IntTensor<Self, 1>, num_bins: usize) -> IntTensor<Self, 1> {
+        B::int_bincount(tensor, num_bins)
+    }
+
+    fn int_bincount_weighted(
+        tensor: IntTensor<Self, 1>,
+        weights: FloatTensor<Self, 1>,
+        num_bins: usize,
+    ) -> FloatTensor<Self, 1> {
+        // The gradient flows to the weights through the assignment of the reference implementation.
+        if weights.is_tracked() {
+            return bincount::select_bincount_weighted::<Self>(tensor, weights, num_bins);
+        }
+
+        AutodiffTensor::new(B::int_bincount_weighted(
+            tensor,
+            weights.primitive,
+            num_bins,
+        ))
+    }
+
+    fn int_segment_sum<const D: usize>(
+        tensor: IntTensor<B, D>,
+        segment_ids: IntTensor<B, 1>,
+        num_segments: usize,
+    ) -> IntTensor<B, D> {
+        B::int_segment_sum(tensor, segment_ids, num_segments)
+    }
+
+    fn int_segment_reduce_sorted<const D: usize>(
+        tensor: IntTensor<B, D>,
+        segment_ids: IntTensor<B, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> IntTensor<B, D> {
+        B::int_segment_reduce_sorted(tensor, segment_ids, num_segments, reduction)
+    }
+
+    fn int_mode<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        B::int_mode(tensor, dim)
+    }
+
     fn int_permute<const D: usize>(
         tensor: IntTensor<Self, D>,
         axes: [usize; D],
@@ -406,4 +457,12 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
     ) -> IntTensor<Self, D> {
         B::int_argsort(tensor, dim, descending)
     }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_unique<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        consecutive: bool,
+    ) -> UniqueOutput<Self, Int, D> {
+        B::int_unique(tensor, consecutive)
+    }
 }
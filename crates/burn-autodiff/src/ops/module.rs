@@ -2,12 +2,13 @@ use crate::checkpoint::base::Checkpointer;
 use crate::checkpoint::strategy::CheckpointStrategy;
 use crate::grads::Gradients;
 use crate::graph::NodeID;
-use crate::ops::{unary, Backward, Ops};
+use crate::ops::{binary, unary, Backward, Ops};
 use crate::tensor::AutodiffTensor;
 use crate::Autodiff;
 
 use burn_tensor::backend::Backend;
 use burn_tensor::ops::*;
+use burn_tensor::Shape;
 
 use super::OpsKind;
 
@@ -54,6 +55,73 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate embedding backward.");
     }
 
+    fn dequantize_matmul(
+        x: AutodiffTensor<B, 3>,
+        weight: IntTensor<B, 2>,
+        scale: AutodiffTensor<B, 1>,
+    ) -> AutodiffTensor<B, 3> {
+        #[derive(Debug)]
+        struct DequantizeMatmul;
+
+        impl<B: Backend> Backward<B, 3, 2> for DequantizeMatmul {
+            type State = (
+                B::FloatTensorPrimitive<3>,
+                IntTensor<B, 2>,
+                B::FloatTensorPrimitive<1>,
+            );
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (x, weight, scale) = ops.state;
+                let [batch_size, seq_length, d_input] = B::float_shape(&x).dims;
+                let [d_output] = B::float_shape(&scale).dims;
+                let device = B::float_device(&x);
+                let weight_transposed = B::int_transpose(weight.clone());
+
+                binary::<B, 3, 3, 1, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        // `grad @ (weight * scale)^T`, scaling the gradient instead of the weights.
+                        let scale = B::float_reshape(scale, Shape::new([1, 1, d_output]));
+                        let ones = B::float_ones(Shape::new([d_input]), &device);
+
+                        B::dequantize_matmul(B::float_mul(grad, scale), weight_transposed, ones)
+                    },
+                    |grad| {
+                        let ones = B::float_ones(Shape::new([d_output]), &device);
+                        let output = B::dequantize_matmul(x, weight, ones);
+                        let grad = B::float_reshape(
+                            B::float_mul(grad, output),
+                            Shape::new([batch_size * seq_length, d_output]),
+                        );
+
+                        B::float_reshape(B::float_sum_dim(grad, 0), Shape::new([d_output]))
+                    },
+                );
+            }
+        }
+
+        match DequantizeMatmul
+            .prepare::<C>([x.node, scale.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (x.primitive.clone(), weight.clone(), scale.primitive.clone()),
+                B::dequantize_matmul(x.primitive, weight, scale.primitive),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::dequantize_matmul(x.primitive, weight, scale.primitive))
+            }
+        }
+    }
+
     fn conv2d(
         x: AutodiffTensor<B, 4>,
         weight: AutodiffTensor<B, 4>,
@@ -1006,6 +1074,95 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive<4> {
         panic!("Can't differentiate interpolate backward.");
     }
+
+    fn pixel_shuffle(x: AutodiffTensor<B, 4>, upscale_factor: usize) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct PixelShuffle;
+
+        impl<B: Backend> Backward<B, 4, 1> for PixelShuffle {
+            type State = usize;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let upscale_factor = ops.state;
+
+                unary::<B, 4, 4, _>(ops.parents, ops.node, grads, |grad| {
+                    B::pixel_unshuffle(grad, upscale_factor)
+                });
+            }
+        }
+
+        match PixelShuffle
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                upscale_factor,
+                B::pixel_shuffle(x.primitive, upscale_factor),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::pixel_shuffle(x.primitive, upscale_factor)),
+        }
+    }
+
+    fn pixel_unshuffle(x: AutodiffTensor<B, 4>, downscale_factor: usize) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct PixelUnshuffle;
+
+        impl<B: Backend> Backward<B, 4, 1> for PixelUnshuffle {
+            type State = usize;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let downscale_factor = ops.state;
+
+                unary::<B, 4, 4, _>(ops.parents, ops.node, grads, |grad| {
+                    B::pixel_shuffle(grad, downscale_factor)
+                });
+            }
+        }
+
+        match PixelUnshuffle
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                downscale_factor,
+                B::pixel_unshuffle(x.primitive, downscale_factor),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::pixel_unshuffle(x.primitive, downscale_factor))
+            }
+        }
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn nms(
+        boxes: FloatTensor<Self, 2>,
+        scores: FloatTensor<Self, 1>,
+        iou_threshold: f32,
+    ) -> IntTensor<Self, 1> {
+        B::nms(boxes.primitive, scores.primitive, iou_threshold)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn batched_nms(
+        boxes: FloatTensor<Self, 2>,
+        scores: FloatTensor<Self, 1>,
+        idxs: IntTensor<Self, 1>,
+        iou_threshold: f32,
+    ) -> IntTensor<Self, 1> {
+        B::batched_nms(boxes.primitive, scores.primitive, idxs, iou_threshold)
+    }
 }
 
 #[derive(Debug)]
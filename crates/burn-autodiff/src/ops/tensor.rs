@@ -14,10 +14,18 @@ use crate::{
     Autodiff,
 };
 
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use burn_tensor::ops::unique::{self, UniqueOutput};
 use burn_tensor::{
     backend::Backend,
-    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
-    Device, ElementConversion, Reader, Shape, Tensor, TensorData,
+    linalg::{self, QrMode},
+    ops::{
+        einsum::{self, Contraction},
+        segment, BoolTensor, FloatElem, FloatGradHook, FloatGradMap, FloatTensor, FloatTensorOps,
+        IntTensor,
+    },
+    ComplexPrimitive, Device, ElementConversion, Float, IndexReduction, Reader, Shape, Tensor,
+    TensorData,
 };
 
 use super::maxmin::MaxMinDim;
@@ -38,6 +46,14 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         AutodiffTensor::new(B::float_random(shape, distribution, device))
     }
 
+    fn float_multinomial<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<B, D> {
+        B::float_multinomial(tensor.primitive, num_samples, replacement)
+    }
+
     fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
         AutodiffTensor::new(B::float_zeros(shape, device))
     }
@@ -58,6 +74,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_into_data(tensor.primitive)
     }
 
+    fn float_checksum<const D: usize>(tensor: FloatTensor<Self, D>) -> Reader<u64> {
+        B::float_checksum(tensor.primitive)
+    }
+
     fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
         B::float_device(&tensor.primitive)
     }
@@ -1431,6 +1451,91 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         matches!(tensor.node.requirement, Requirement::Grad)
     }
 
+    fn float_register_grad_hook<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        hook: FloatGradHook<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        struct GradHook<B: Backend, C: CheckpointStrategy, const D: usize> {
+            hook: FloatGradHook<Autodiff<B, C>, D>,
+        }
+
+        impl<B: Backend, C: CheckpointStrategy, const D: usize> std::fmt::Debug for GradHook<B, C, D> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("GradHook").finish()
+            }
+        }
+
+        impl<B: Backend, C: CheckpointStrategy, const D: usize> Backward<B, D, 1> for GradHook<B, C, D> {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The hook is called with an untracked copy of the gradient.
+                    (self.hook)(AutodiffTensor::new(grad.clone()));
+                    grad
+                });
+            }
+        }
+
+        let grad_hook = GradHook::<B, C, D> { hook };
+
+        match grad_hook
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((), tensor.primitive),
+            OpsKind::UnTracked(prep) => prep.finish(tensor.primitive),
+        }
+    }
+
+    fn float_map_grad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        map: FloatGradMap<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        struct MapGrad<B: Backend, C: CheckpointStrategy, const D: usize> {
+            map: FloatGradMap<Autodiff<B, C>, D>,
+        }
+
+        impl<B: Backend, C: CheckpointStrategy, const D: usize> std::fmt::Debug for MapGrad<B, C, D> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("MapGrad").finish()
+            }
+        }
+
+        impl<B: Backend, C: CheckpointStrategy, const D: usize> Backward<B, D, 1> for MapGrad<B, C, D> {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The function is called with an untracked gradient.
+                    (self.map)(AutodiffTensor::new(grad)).primitive
+                });
+            }
+        }
+
+        let map_grad = MapGrad::<B, C, D> { map };
+
+        match map_grad
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((), tensor.primitive),
+            OpsKind::UnTracked(prep) => prep.finish(tensor.primitive),
+        }
+    }
+
     fn float_mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
         #[derive(Debug)]
         struct Mean<const D: usize>;
@@ -1582,6 +1687,48 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_logsumexp_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct LogSumExpDim;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for LogSumExpDim {
+            type State = (NodeID, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (input, dim) = ops.state;
+                let input: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(input);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The gradient of the input is its softmax along the dimension.
+                    let output = B::float_logsumexp_dim(input.clone(), dim);
+                    let softmax = B::float_exp(B::float_sub(input, output));
+
+                    B::float_mul(softmax, grad)
+                });
+            }
+        }
+
+        match LogSumExpDim
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = (prep.checkpoint(&tensor), dim);
+                prep.finish(state, B::float_logsumexp_dim(tensor.primitive, dim))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_logsumexp_dim(tensor.primitive, dim)),
+        }
+    }
+
     fn float_argmax<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<B, D> {
         B::float_argmax(tensor.primitive, dim)
     }
@@ -2004,6 +2151,564 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Erfinv;
+
+        retro_unary!(RetroErfinv, B::float_erfinv);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Erfinv {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx erfinv(x) = sqrt(pi) / 2 * exp(erfinv(x)^2)
+                    let output = B::float_erfinv(input);
+                    let value = B::float_exp(B::float_powf_scalar(output, 2.0));
+                    let value =
+                        B::float_mul_scalar(value, (std::f64::consts::PI.sqrt() / 2.0).elem());
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Erfinv
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroErfinv::<B, D>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_erfinv(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_erfinv(tensor.primitive)),
+        }
+    }
+
+    fn float_lgamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Lgamma;
+
+        retro_unary!(RetroLgamma, B::float_lgamma);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Lgamma {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_digamma(input))
+                });
+            }
+        }
+
+        match Lgamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroLgamma::<B, D>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_lgamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_lgamma(tensor.primitive)),
+        }
+    }
+
+    fn float_digamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Digamma;
+
+        retro_unary!(RetroDigamma, B::float_digamma);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Digamma {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_polygamma(input, 1))
+                });
+            }
+        }
+
+        match Digamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroDigamma::<B, D>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_digamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_digamma(tensor.primitive)),
+        }
+    }
+
+    fn float_polygamma<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        order: u32,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Polygamma;
+
+        #[derive(new, Debug)]
+        struct RetroPolygamma<B: Backend, const D: usize> {
+            input_id: NodeID,
+            order: u32,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend, const D: usize> RetroForward for RetroPolygamma<B, D> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let input = states.get_state::<B::FloatTensorPrimitive<D>>(&self.input_id);
+                let out = B::float_polygamma(input, self.order);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Polygamma {
+            type State = (NodeID, u32);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (input_id, order) = ops.state;
+                let input = checkpointer.retrieve_node_output(input_id);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_polygamma(input, order + 1))
+                });
+            }
+        }
+
+        match Polygamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroPolygamma::<B, D>::new(tensor.node.id, order))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = (prep.checkpoint(&tensor), order);
+                prep.finish(state, B::float_polygamma(tensor.primitive, order))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_polygamma(tensor.primitive, order)),
+        }
+    }
+
+    fn float_atan2<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Atan2;
+
+        retro_binary!(RetroAtan2, B::float_atan2);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Atan2 {
+            type State = (NodeID, NodeID, BinaryOpsBroadcast<D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs_id, rhs_id, broadcast) = ops.state;
+                let lhs: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(lhs_id);
+                let rhs: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(rhs_id);
+
+                // The gradients are (x, -y) / (x^2 + y^2) for atan2(y, x).
+                let norm = B::float_add(
+                    B::float_powf_scalar(lhs.clone(), 2.0),
+                    B::float_powf_scalar(rhs.clone(), 2.0),
+                );
+                let [norm_4lhs, norm_4rhs] = duplicate(&ops.parents, Some(norm));
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        let value = B::float_div(rhs, norm_4lhs.unwrap());
+                        let grad = B::float_mul(grad, value);
+
+                        broadcast.backward_lhs::<B>(grad)
+                    },
+                    |grad| {
+                        let value = B::float_div(B::float_neg(lhs), norm_4rhs.unwrap());
+                        let grad = B::float_mul(grad, value);
+
+                        broadcast.backward_rhs::<B>(grad)
+                    },
+                );
+            }
+        }
+
+        let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+
+        match Atan2
+            .prepare::<C>([lhs.node.clone(), rhs.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAtan2::<B, D>::new(lhs.node.id, rhs.node.id))
+            .parents([&lhs, &rhs])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let lhs_state = prep.checkpoint(&lhs);
+                let rhs_state = prep.checkpoint(&rhs);
+                prep.finish(
+                    (lhs_state, rhs_state, broadcast),
+                    B::float_atan2(lhs.primitive, rhs.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_atan2(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn float_fft<const D: usize>(
+        tensor: ComplexPrimitive<Self, D>,
+        inverse: bool,
+    ) -> ComplexPrimitive<Self, D> {
+        /// Backward of the real or imaginary part of the transform, whose gradient is the
+        /// adjoint transform of the gradient of that part, the other one being zero.
+        #[derive(Debug)]
+        struct Fft {
+            inverse: bool,
+            imag: bool,
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Fft {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let [node_real, node_imag] = ops.parents;
+                let grad = grads.consume::<B, D>(&ops.node);
+                let shape = B::float_shape(&grad);
+                let zeros = B::float_zeros(shape.clone(), &B::float_device(&grad));
+                let (real, imag) = match self.imag {
+                    true => (zeros, grad),
+                    false => (grad, zeros),
+                };
+
+                // The adjoint of the transform is the unnormalized transform in the other
+                // direction, and the adjoint of the normalized inverse is the transform over n.
+                let n = shape.dims[D - 1] as f64;
+                let scale = match self.inverse {
+                    true => 1.0 / n,
+                    false => n,
+                };
+                let output = B::float_fft(ComplexPrimitive { real, imag }, !self.inverse);
+
+                if let Some(node) = node_real {
+                    grads.register::<B, D>(node.id, B::float_mul_scalar(output.real, scale.elem()));
+                }
+                if let Some(node) = node_imag {
+                    grads.register::<B, D>(node.id, B::float_mul_scalar(output.imag, scale.elem()));
+                }
+            }
+        }
+
+        let ComplexPrimitive { real, imag } = tensor;
+        let nodes = [real.node.clone(), imag.node.clone()];
+        let output = B::float_fft(
+            ComplexPrimitive {
+                real: real.primitive,
+                imag: imag.primitive,
+            },
+            inverse,
+        );
+
+        ComplexPrimitive {
+            real: Fft {
+                inverse,
+                imag: false,
+            }
+            .prepare::<C>(nodes.clone())
+            .compute_bound()
+            .stateless(output.real),
+            imag: Fft {
+                inverse,
+                imag: true,
+            }
+            .prepare::<C>(nodes)
+            .compute_bound()
+            .stateless(output.imag),
+        }
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        // The gradient flows through the Jacobi rotations of the reference decomposition, while
+        // the native decomposition of the inner backend is used when it isn't required.
+        if tensor.is_tracked() {
+            let (u, s, vt) = linalg::jacobi_svd(Tensor::<Self, D>::from_primitive(tensor));
+
+            return (u.into_primitive(), s.into_primitive(), vt.into_primitive());
+        }
+
+        let (u, s, vt) = B::float_svd(tensor.primitive);
+
+        (
+            AutodiffTensor::new(u),
+            AutodiffTensor::new(s),
+            AutodiffTensor::new(vt),
+        )
+    }
+
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mode: QrMode,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        // The gradient flows through the Householder reflections of the reference decomposition.
+        if tensor.is_tracked() {
+            let (q, r) = linalg::householder_qr(Tensor::<Self, D>::from_primitive(tensor), mode);
+
+            return (q.into_primitive(), r.into_primitive());
+        }
+
+        let (q, r) = B::float_qr(tensor.primitive, mode);
+
+        (AutodiffTensor::new(q), AutodiffTensor::new(r))
+    }
+
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // The gradient flows through the column by column reference decomposition.
+        if tensor.is_tracked() {
+            return linalg::column_cholesky(Tensor::<Self, D>::from_primitive(tensor))
+                .into_primitive();
+        }
+
+        AutodiffTensor::new(B::float_cholesky(tensor.primitive))
+    }
+
+    fn float_solve<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        // The gradient flows through the Gaussian elimination of the reference solver.
+        if tensor.is_tracked() || rhs.is_tracked() {
+            return linalg::elimination_solve(
+                Tensor::<Self, D>::from_primitive(tensor),
+                Tensor::<Self, D>::from_primitive(rhs),
+            )
+            .into_primitive();
+        }
+
+        AutodiffTensor::new(B::float_solve(tensor.primitive, rhs.primitive))
+    }
+
+    fn float_triangular_solve<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+        upper: bool,
+    ) -> FloatTensor<Self, D> {
+        // The gradient flows through the substitution of the reference solver.
+        if tensor.is_tracked() || rhs.is_tracked() {
+            return linalg::substitution_solve(
+                Tensor::<Self, D>::from_primitive(tensor),
+                Tensor::<Self, D>::from_primitive(rhs),
+                upper,
+            )
+            .into_primitive();
+        }
+
+        AutodiffTensor::new(B::float_triangular_solve(
+            tensor.primitive,
+            rhs.primitive,
+            upper,
+        ))
+    }
+
+    fn float_det<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // The gradient flows through the Gaussian elimination of the reference determinant.
+        if tensor.is_tracked() {
+            return linalg::elimination_det(Tensor::<Self, D>::from_primitive(tensor))
+                .into_primitive();
+        }
+
+        AutodiffTensor::new(B::float_det(tensor.primitive))
+    }
+
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        // The gradient flows through the Gaussian elimination of the reference determinant.
+        if tensor.is_tracked() {
+            let (sign, logabsdet) =
+                linalg::elimination_slogdet(Tensor::<Self, D>::from_primitive(tensor));
+
+            return (sign.into_primitive(), logabsdet.into_primitive());
+        }
+
+        let (sign, logabsdet) = B::float_slogdet(tensor.primitive);
+
+        (AutodiffTensor::new(sign), AutodiffTensor::new(logabsdet))
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_pinv<const D: usize>(tensor: FloatTensor<Self, D>, rtol: f64) -> FloatTensor<Self, D> {
+        // The gradient flows through the singular value decomposition of the reference
+        // pseudo-inverse.
+        if tensor.is_tracked() {
+            return linalg::svd_pinv(Tensor::<Self, D>::from_primitive(tensor), rtol)
+                .into_primitive();
+        }
+
+        AutodiffTensor::new(B::float_pinv(tensor.primitive, rtol))
+    }
+
+    fn float_lstsq<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        // The gradient flows through the QR decomposition of the reference solver.
+        if tensor.is_tracked() || rhs.is_tracked() {
+            return linalg::qr_lstsq(
+                Tensor::<Self, D>::from_primitive(tensor),
+                Tensor::<Self, D>::from_primitive(rhs),
+            )
+            .into_primitive();
+        }
+
+        AutodiffTensor::new(B::float_lstsq(tensor.primitive, rhs.primitive))
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        // The gradient flows through the Jacobi rotations of the reference decomposition.
+        if tensor.is_tracked() {
+            let (eigenvalues, vectors) =
+                linalg::jacobi_eigh(Tensor::<Self, D>::from_primitive(tensor));
+
+            return (eigenvalues.into_primitive(), vectors.into_primitive());
+        }
+
+        let (eigenvalues, vectors) = B::float_eigh(tensor.primitive);
+
+        (
+            AutodiffTensor::new(eigenvalues),
+            AutodiffTensor::new(vectors),
+        )
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_unique<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        consecutive: bool,
+    ) -> UniqueOutput<Self, Float, D> {
+        // The gradient flows to the first element of each run selected by the reference.
+        if tensor.is_tracked() {
+            return unique::sort_unique::<Self, D, Float>(tensor, consecutive);
+        }
+
+        let (values, inverse, counts) = B::float_unique(tensor.primitive, consecutive);
+
+        (AutodiffTensor::new(values), inverse, counts)
+    }
+
+    fn float_segment_sum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        segment_ids: IntTensor<B, 1>,
+        num_segments: usize,
+    ) -> FloatTensor<Self, D> {
+        // The gradient flows to the slices through the assignment of the reference implementation.
+        if tensor.is_tracked() {
+            return segment::select_segment_sum::<Self, D, Float>(
+                tensor,
+                segment_ids,
+                num_segments,
+            );
+        }
+
+        AutodiffTensor::new(B::float_segment_sum(
+            tensor.primitive,
+            segment_ids,
+            num_segments,
+        ))
+    }
+
+    fn float_segment_reduce_sorted<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        segment_ids: IntTensor<B, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> FloatTensor<Self, D> {
+        // The gradient flows to the selected slices through the scan of the reference
+        // implementation.
+        if tensor.is_tracked() {
+            return segment::scan_segment_reduce_sorted::<Self, D, Float>(
+                tensor,
+                segment_ids,
+                num_segments,
+                reduction,
+            );
+        }
+
+        AutodiffTensor::new(B::float_segment_reduce_sorted(
+            tensor.primitive,
+            segment_ids,
+            num_segments,
+            reduction,
+        ))
+    }
+
+    fn float_contract<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+        contraction: Contraction,
+    ) -> FloatTensor<Self, D> {
+        // The gradient flows through the matrix multiplication of the reference contraction.
+        if lhs.is_tracked() || rhs.is_tracked() {
+            return einsum::matmul_contraction::<Self, D>(lhs, rhs, &contraction);
+        }
+
+        AutodiffTensor::new(B::float_contract(lhs.primitive, rhs.primitive, contraction))
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<FloatTensor<Self, D>>,
         dim: usize,
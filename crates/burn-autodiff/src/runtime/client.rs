@@ -11,7 +11,14 @@ pub trait AutodiffClient: Send + Clone {
     /// Register a new step.
     fn register(&self, node_id: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder);
     /// Call backpropagation from the given tensor.
-    fn backward<B: Backend, const D: usize>(&self, tensor: AutodiffTensor<B, D>) -> Gradients;
+    ///
+    /// The hook is called during the backward pass each time the gradients of some nodes are
+    /// [complete](Gradients::is_complete), and a last time when it is done.
+    fn backward<B: Backend, const D: usize>(
+        &self,
+        tensor: AutodiffTensor<B, D>,
+        hook: Option<&mut dyn FnMut(&Gradients)>,
+    ) -> Gradients;
 }
 
 /// Client implementation in used.
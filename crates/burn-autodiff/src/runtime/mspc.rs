@@ -1,4 +1,7 @@
-use super::{server::AutodiffServer, AutodiffClient};
+use super::{
+    server::{AutodiffServer, BackwardTape},
+    AutodiffClient,
+};
 use crate::{
     checkpoint::builder::CheckpointerBuilder,
     grads::Gradients,
@@ -24,9 +27,9 @@ enum Message {
     },
     Backward {
         node_id: NodeID,
-        grads: Gradients,
-        callback: Sender<Gradients>,
+        callback: Sender<BackwardTape>,
     },
+    FreeUnavailableNodes,
 }
 impl ChannelClient {
     pub(crate) fn new() -> Self {
@@ -46,14 +49,10 @@ impl ChannelClient {
                         step,
                         actions,
                     } => server.register(node_id, step, actions),
-                    Message::Backward {
-                        node_id,
-                        grads,
-                        callback,
-                    } => {
-                        let grads = server.backward(grads, node_id);
-                        callback.send(grads).unwrap();
+                    Message::Backward { node_id, callback } => {
+                        callback.send(server.prepare_backward(node_id)).unwrap();
                     }
+                    Message::FreeUnavailableNodes => server.free_unavailable_nodes(),
                 }
             }
         });
@@ -73,22 +72,26 @@ impl AutodiffClient for ChannelClient {
             .unwrap()
     }
 
-    fn backward<B: Backend, const D: usize>(&self, root: AutodiffTensor<B, D>) -> Gradients {
+    fn backward<B: Backend, const D: usize>(
+        &self,
+        root: AutodiffTensor<B, D>,
+        hook: Option<&mut dyn FnMut(&Gradients)>,
+    ) -> Gradients {
         let node_id = root.node.id;
         let grads = Gradients::new::<B, D>(root.node, root.primitive);
         let (callback, receiver) = std::sync::mpsc::channel();
 
         self.sender
-            .send(Message::Backward {
-                node_id,
-                grads,
-                callback,
-            })
+            .send(Message::Backward { node_id, callback })
             .unwrap();
 
-        match receiver.recv() {
-            Ok(grads) => grads,
+        // The steps are executed on the current thread, so that they can execute new operations.
+        let grads = match receiver.recv() {
+            Ok(tape) => tape.execute(grads, hook),
             Err(err) => panic!("Error during backward {err:?}"),
-        }
+        };
+        self.sender.send(Message::FreeUnavailableNodes).unwrap();
+
+        grads
     }
 }
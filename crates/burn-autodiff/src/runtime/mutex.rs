@@ -31,18 +31,25 @@ impl AutodiffClient for MutexClient {
         server_new.register(node_id, step, actions);
         *server = Some(server_new);
     }
-    fn backward<B: Backend, const D: usize>(&self, root: AutodiffTensor<B, D>) -> Gradients {
-        let mut server = SERVER.lock();
+    fn backward<B: Backend, const D: usize>(
+        &self,
+        root: AutodiffTensor<B, D>,
+        hook: Option<&mut dyn FnMut(&Gradients)>,
+    ) -> Gradients {
         let node_id = root.node.id;
         let grads = Gradients::new::<B, D>(root.node, root.primitive);
 
-        if let Some(server) = server.as_mut() {
-            return server.backward(grads, node_id);
-        }
+        // The server isn't locked while the steps are executed, so that they can execute new
+        // operations and that the backward passes of other threads aren't blocked.
+        let tape = SERVER
+            .lock()
+            .get_or_insert_with(AutodiffServer::default)
+            .prepare_backward(node_id);
+        let gradients = tape.execute(grads, hook);
 
-        let mut server_new = AutodiffServer::default();
-        let gradients = server_new.backward(grads, node_id);
-        *server = Some(server_new);
+        if let Some(server) = SERVER.lock().as_mut() {
+            server.free_unavailable_nodes();
+        }
 
         gradients
     }
@@ -6,7 +6,7 @@ use crate::{
     tensor::NodeRefCount,
     NodeID,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Default)]
 pub struct AutodiffServer {
@@ -26,7 +26,10 @@ impl AutodiffServer {
         self.actions_builder.insert(node_id, actions);
     }
 
-    pub fn backward(&mut self, grads: Gradients, node_id: NodeID) -> Gradients {
+    /// Remove the steps required to compute the gradients of the node from the graph, so that
+    /// they can be [executed](BackwardTape::execute) without access to the server, e.g. while
+    /// other threads register new steps.
+    pub fn prepare_backward(&mut self, node_id: NodeID) -> BackwardTape {
         let step = self.steps.remove(&node_id).expect(
             "Node should have a step registered, did you forget to call \
              `Tensor::register_grad` on the tensor where you need gradients?",
@@ -36,16 +39,33 @@ impl AutodiffServer {
         let (tape, builder) = self.build_tape(node_id, step, builder);
         let checkpointer = builder.build(&self.steps);
 
-        let gradients = Self::execute_steps(tape, grads, checkpointer);
+        // The gradient of a node is complete once the shallowest step consuming it is executed,
+        // since the steps are executed from the deepest one.
+        let mut consumed_at = HashMap::new();
+        for (level, steps) in tape.iter().enumerate() {
+            for parent in steps.iter().flat_map(|step| step.parents()) {
+                consumed_at.entry(parent).or_insert(level);
+            }
+        }
+        let mut completions = tape.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+        for (node, level) in consumed_at {
+            completions[level].push(node);
+        }
+
+        BackwardTape {
+            tape,
+            completions,
+            checkpointer,
+        }
+    }
 
-        // Cleanup
+    /// Free the steps of the nodes that can't be used anymore, after a backward pass.
+    pub fn free_unavailable_nodes(&mut self) {
         self.memory_management
             .free_unavailable_nodes(|node_id: &NodeID| {
                 self.steps.remove(node_id);
                 self.actions_builder.remove(node_id);
             });
-
-        gradients
     }
 
     fn build_tape(
@@ -77,21 +97,55 @@ impl AutodiffServer {
 
         (tape, builder)
     }
+}
 
-    fn execute_steps(
-        tape: Vec<Vec<StepBoxed>>,
+/// The steps of a backward pass, ordered by depth.
+pub struct BackwardTape {
+    tape: Vec<Vec<StepBoxed>>,
+    // The nodes whose gradients are complete after the steps of each depth are executed.
+    completions: Vec<Vec<NodeID>>,
+    checkpointer: Checkpointer,
+}
+
+impl BackwardTape {
+    /// Execute the steps, from the deepest one, accumulating the gradients.
+    ///
+    /// The hook is called after the steps of each depth are executed, with the
+    /// [completed](Gradients::is_complete) gradients marked, and a last time when the backward
+    /// pass is done.
+    pub fn execute(
+        self,
         mut grads: Gradients,
-        mut checkpointer: Checkpointer,
+        mut hook: Option<&mut dyn FnMut(&Gradients)>,
     ) -> Gradients {
-        tape.into_iter().rev().for_each(|steps| {
+        let mut checkpointer = self.checkpointer;
+
+        if hook.is_some() {
+            grads.completed = Some(HashSet::new());
+        }
+
+        for (steps, completions) in self.tape.into_iter().zip(self.completions).rev() {
             steps
                 .into_iter()
-                .for_each(|step| step.step(&mut grads, &mut checkpointer))
-        });
+                .for_each(|step| step.step(&mut grads, &mut checkpointer));
+
+            if let Some(hook) = hook.as_mut() {
+                if let Some(completed) = grads.completed.as_mut() {
+                    completed.extend(completions.iter().map(|node| node.value));
+                }
+                hook(&grads);
+            }
+        }
 
         #[cfg(feature = "export_tests")]
         // For checkpointing tests
         assert!(checkpointer.is_empty());
+
+        grads.completed = None;
+        if let Some(hook) = hook {
+            hook(&grads);
+        }
+
         grads
     }
 }
@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(ad_atan2)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_atan2() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<1>::from_floats([1.0, 2.0], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::<1>::from_floats([3.0, -1.0], &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().atan2(tensor_2.clone());
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([0.3, -0.2]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&TensorData::from([-0.1, -0.4]), 5);
+    }
+}
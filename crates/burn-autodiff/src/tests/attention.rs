@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(ad_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::attention;
+    use burn_tensor::ops::AttentionOptions;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_attention() {
+        let device = Default::default();
+        let query = TestAutodiffTensor::<4>::from_floats([[[[1.0, 0.0], [0.5, -1.0]]]], &device)
+            .require_grad();
+        let key =
+            TestAutodiffTensor::<4>::from_floats([[[[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]]], &device)
+                .require_grad();
+        let value = TestAutodiffTensor::<4>::from_floats(
+            [[[[1.0, 2.0], [3.0, -1.0], [0.0, 0.5]]]],
+            &device,
+        )
+        .require_grad();
+
+        let output = attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            AttentionOptions::new(0.5, -1.0e4, false),
+        );
+        let grads = output.sum().backward();
+
+        query.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[[-0.0223, -0.2286], [0.0049, -0.2301]]]]),
+            3,
+        );
+        key.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[[0.3437, -0.2301], [0.0199, 0.0049], [-0.3635, 0.2251]]]]),
+            3,
+        );
+        value.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[[0.8647, 0.8647], [0.4599, 0.4599], [0.6754, 0.6754]]]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(ad_bincount)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_diff_bincount_weighted() {
+        let device = Default::default();
+        let tensor = Tensor::<TestAutodiffBackend, 1, Int>::from_ints([0, 2, 2, 1, 0, 1], &device);
+        let weights = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &device)
+            .require_grad();
+        let scale = TestAutodiffTensor::<1>::from_floats([1.0, 10.0, 100.0], &device);
+
+        let sums = tensor.bincount_weighted(weights.clone(), 3);
+        let grads = (sums.clone() * scale).sum().backward();
+
+        sums.into_data()
+            .assert_eq(&TensorData::from([6.0, 10.0, 5.0]), false);
+        weights.grad(&grads).unwrap().into_data().assert_eq(
+            &TensorData::from([1.0, 100.0, 100.0, 10.0, 1.0, 10.0]),
+            false,
+        );
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(ad_box_iou)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::paired_box_iou;
+    use burn_tensor::ops::BoxIouMode;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_generalized_box_iou() {
+        let (predictions, targets) = boxes();
+
+        let loss = paired_box_iou(predictions.clone(), targets, BoxIouMode::Generalized).neg();
+        let grads = loss.sum().backward();
+
+        predictions
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(
+                &TensorData::from([
+                    [-0.0932, -0.151, -0.2797, -0.2517],
+                    [0.1821, 0.2531, 0.0455, 0.037],
+                ]),
+                3,
+            );
+    }
+
+    #[test]
+    fn should_diff_complete_box_iou() {
+        let (predictions, targets) = boxes();
+
+        let loss = paired_box_iou(predictions.clone(), targets, BoxIouMode::Complete).neg();
+        let grads = loss.sum().backward();
+
+        predictions
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(
+                &TensorData::from([
+                    [-0.1043, -0.0769, -0.2786, -0.1511],
+                    [0.1583, 0.1271, 0.0435, 0.0452],
+                ]),
+                3,
+            );
+    }
+
+    fn boxes() -> (TestAutodiffTensor<2>, TestAutodiffTensor<2>) {
+        let device = Default::default();
+        let predictions =
+            TestAutodiffTensor::from_floats([[0.0, 0.0, 2.0, 2.0], [1.0, 1.0, 4.0, 3.0]], &device)
+                .require_grad();
+        let targets =
+            TestAutodiffTensor::from_floats([[1.0, 0.5, 3.0, 2.5], [0.0, 0.0, 2.0, 2.0]], &device);
+
+        (predictions, targets)
+    }
+}
@@ -0,0 +1,36 @@
+#[burn_tensor_testgen::testgen(ad_cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_cholesky() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[4.0, 2.0], [2.0, 5.0]], &device).require_grad();
+
+        let output = tensor.clone().cholesky();
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([[0.1875, 0.0], [0.25, 0.25]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cholesky_solve() {
+        let device = Default::default();
+        let lower =
+            TestAutodiffTensor::<2>::from_data([[2.0, 0.0], [1.0, 2.0]], &device).require_grad();
+        let rhs = TestAutodiffTensor::<2>::from_data([[4.0], [7.0]], &device).require_grad();
+
+        let output = lower.clone().cholesky_solve(rhs.clone());
+        let grads = output.sum().backward();
+
+        // A = [[4, 2], [2, 5]], the gradient of the right-hand side is A^-1 [1, 1].
+        let grad = rhs.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([[0.1875], [0.125]]), 3);
+        assert!(lower.grad(&grads).is_some());
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(ad_complex_tensor)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, Tensor, TensorData};
+
+    type TestAutodiffComplex<const D: usize> = Tensor<TestAutodiffBackend, D, Complex>;
+
+    fn complex(values: [[f32; 2]; 2]) -> TestAutodiffComplex<1> {
+        let device = Default::default();
+        let real = TestAutodiffTensor::<1>::from_floats([values[0][0], values[1][0]], &device);
+        let imag = TestAutodiffTensor::<1>::from_floats([values[0][1], values[1][1]], &device);
+
+        TestAutodiffComplex::from_parts(real, imag).complex_require_grad()
+    }
+
+    #[test]
+    fn should_diff_squared_magnitude_with_conj() {
+        let tensor = complex([[1.0, 2.0], [3.0, -1.0]]);
+
+        let loss = tensor.clone().complex_mul(tensor.clone().conj()).real();
+        let grads = loss.sum().backward();
+
+        let (real, imag) = tensor.grad(&grads).unwrap().into_parts();
+
+        real.to_data()
+            .assert_approx_eq(&TensorData::from([2.0, 6.0]), 5);
+        imag.to_data()
+            .assert_approx_eq(&TensorData::from([4.0, -2.0]), 5);
+    }
+
+    #[test]
+    fn should_diff_angle() {
+        let tensor = complex([[1.0, 2.0], [3.0, -1.0]]);
+
+        let grads = tensor.clone().angle().sum().backward();
+
+        let (real, imag) = tensor.grad(&grads).unwrap().into_parts();
+
+        real.to_data()
+            .assert_approx_eq(&TensorData::from([-0.4, 0.1]), 5);
+        imag.to_data()
+            .assert_approx_eq(&TensorData::from([0.2, 0.3]), 5);
+    }
+
+    #[test]
+    fn should_have_zero_grad_for_unused_part() {
+        let tensor = complex([[1.0, 2.0], [3.0, -1.0]]);
+
+        let grads = tensor.clone().real().mul_scalar(2.0).sum().backward();
+
+        let (real, imag) = tensor.grad(&grads).unwrap().into_parts();
+
+        real.to_data()
+            .assert_approx_eq(&TensorData::from([2.0, 2.0]), 5);
+        imag.to_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.0]), 5);
+    }
+}
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+        let weights = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+
+        let grads = (tensor.clone().cumsum(0) * weights).sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&TensorData::from([10.0, 9.0, 7.0, 4.0]), 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_zero() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_floats([2.0, 0.0, 3.0], &device).require_grad();
+
+        let grads = tensor.clone().cumprod(0).sum().backward();
+
+        // d/dx0 (x0 + x0 x1 + x0 x1 x2) = 1 + x1 + x1 x2
+        // d/dx1 = x0 + x0 x2
+        // d/dx2 = x0 x1
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&TensorData::from([1.0, 8.0, 0.0]), 5);
+    }
+
+    #[test]
+    fn should_diff_cummax() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<1>::from_floats([1.0, 3.0, 2.0, 5.0, 4.0], &device).require_grad();
+
+        let grads = tensor.clone().cummax(0).sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&TensorData::from([1.0, 2.0, 0.0, 2.0, 0.0]), 5);
+    }
+}
@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(ad_dequantize_matmul)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::dequantize_matmul;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_diff_dequantize_matmul() {
+        let device = Default::default();
+        let x =
+            TestAutodiffTensor::<3>::from_floats([[[1.0, 2.0, -1.0], [0.5, -0.5, 1.0]]], &device)
+                .require_grad();
+        let weight = Tensor::<TestAutodiffBackend, 2, Int>::from_ints(
+            [[127, -64], [0, 32], [-8, 7]],
+            &device,
+        );
+        let scale = TestAutodiffTensor::<1>::from_floats([0.01, 0.1], &device).require_grad();
+
+        let output = dequantize_matmul(x.clone(), weight, scale.clone());
+        let grads = output.sum().backward();
+
+        x.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[-5.13, 3.2, 0.62], [-5.13, 3.2, 0.62]]]),
+            3,
+        );
+        scale
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([190.5, -48.0]), 3);
+    }
+}
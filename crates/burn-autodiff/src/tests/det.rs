@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(ad_det)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_det() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[2.0, 1.0], [1.0, 3.0]], &device).require_grad();
+
+        let output = tensor.clone().det();
+        let grads = output.sum().backward();
+
+        // The gradient is det(A) A^-T.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([[3.0, -1.0], [-1.0, 2.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_slogdet() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[2.0, 1.0], [1.0, 3.0]], &device).require_grad();
+
+        let (_, logabsdet) = tensor.clone().slogdet();
+        let grads = logabsdet.sum().backward();
+
+        // The gradient is A^-T.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([[0.6, -0.2], [-0.2, 0.4]]), 3);
+    }
+
+    #[test]
+    fn should_diff_inverse() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[2.0, 1.0], [1.0, 3.0]], &device).require_grad();
+
+        let output = tensor.clone().inverse();
+        let grads = output.sum().backward();
+
+        // The gradient is -A^-T 1 1^T A^-T.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([[-0.16, -0.08], [-0.08, -0.04]]), 3);
+    }
+}
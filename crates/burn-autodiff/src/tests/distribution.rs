@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(ad_distribution)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ElementConversion, Tensor};
+
+    #[test]
+    fn should_diff_gamma_scale() {
+        let device = Default::default();
+        let concentration = TestAutodiffTensor::<1>::from_floats([0.5, 2.0, 8.0], &device);
+        let scale = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 0.5], &device).require_grad();
+
+        let samples = Tensor::random_gamma(concentration, scale.clone());
+        let grads = samples.clone().sum().backward();
+
+        // The samples are proportional to the scale.
+        let grad = scale.grad(&grads).unwrap();
+        let expected = samples.inner().div(scale.inner());
+        grad.to_data().assert_approx_eq(&expected.to_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_gamma_concentration() {
+        let device = Default::default();
+        let concentration = TestAutodiffTensor::<1>::full([4000], 3.0, &device).require_grad();
+        let scale = concentration.ones_like();
+
+        let samples = Tensor::random_gamma(concentration.clone(), scale);
+        let grads = samples.sum().backward();
+
+        // d/da E[X] = 1 for a unit scale.
+        let grad = concentration.grad(&grads).unwrap();
+        let mean = grad.mean().into_scalar().elem::<f32>();
+        assert!((mean - 1.0).abs() < 0.1, "Unexpected mean gradient {mean}");
+    }
+
+    #[test]
+    fn should_diff_dirichlet() {
+        let device = Default::default();
+        let concentration =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0], [0.5, 0.5, 4.0]], &device)
+                .require_grad();
+
+        let samples = Tensor::random_dirichlet(concentration.clone());
+        let grads = samples.sum().backward();
+
+        // The samples sum to one whatever the concentrations.
+        let grad = concentration.grad(&grads).unwrap();
+        let max = grad.abs().max().into_scalar().elem::<f32>();
+        assert!(max < 1e-4, "Unexpected gradient {max}");
+    }
+}
@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(ad_eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_eigenvalues_sum() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[3.0, 1.0], [1.0, 2.0]], &device).require_grad();
+
+        let (eigenvalues, _) = tensor.clone().eigh();
+        let grads = eigenvalues.sum().backward();
+
+        // The sum of the eigenvalues is the trace.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_largest_eigenvalue() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[2.0, 1.0], [1.0, 2.0]], &device).require_grad();
+
+        let (eigenvalues, _) = tensor.clone().eigh();
+        let grads = eigenvalues.narrow(1, 1, 1).sum().backward();
+
+        // The gradient is v v^T, accumulated in the lower triangular part that is read.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([[0.5, 0.0], [1.0, 0.5]]), 3);
+    }
+}
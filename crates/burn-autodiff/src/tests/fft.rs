@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(ad_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, Tensor, TensorData};
+
+    #[test]
+    fn should_diff_fft() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0], &device).require_grad();
+        let imag = TestAutodiffTensor::<1>::zeros([3], &device).require_grad();
+        let weights_real = TestAutodiffTensor::<1>::from_floats([1.0, -1.0, 2.0], &device);
+        let weights_imag = TestAutodiffTensor::<1>::from_floats([0.5, 1.0, -1.0], &device);
+
+        let (output_real, output_imag) =
+            Tensor::<TestAutodiffBackend, 1, Complex>::from_parts(real.clone(), imag.clone())
+                .fft()
+                .into_parts();
+        let loss = (output_real * weights_real).sum() + (output_imag * weights_imag).sum();
+        let grads = loss.backward();
+
+        real.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.0, -1.2321, 2.2321]), 3);
+        imag.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.5, -2.0981, 3.0981]), 3);
+    }
+
+    #[test]
+    fn should_diff_ifft() {
+        let device = Default::default();
+        let real =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+        let imag =
+            TestAutodiffTensor::<1>::from_floats([0.0, 1.0, 0.0, -1.0], &device).require_grad();
+        let weights_real = TestAutodiffTensor::<1>::from_floats([1.0, -1.0, 2.0, 0.0], &device);
+        let weights_imag = TestAutodiffTensor::<1>::from_floats([0.5, 1.0, -1.0, 2.0], &device);
+
+        let (output_real, output_imag) =
+            Tensor::<TestAutodiffBackend, 1, Complex>::from_parts(real.clone(), imag.clone())
+                .ifft()
+                .into_parts();
+        let loss = (output_real * weights_real).sum() + (output_imag * weights_imag).sum();
+        let grads = loss.backward();
+
+        real.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.5, -0.5, 1.0, 0.0]), 3);
+        imag.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.625, 0.625, -0.875, 0.125]), 3);
+    }
+}
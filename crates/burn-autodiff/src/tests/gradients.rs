@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(gradients)]
 mod tests {
     use super::*;
-    use burn_tensor::{activation, Distribution};
+    use burn_tensor::{activation, Distribution, TensorData};
 
     #[test]
     fn should_update_tensor_when_grad_replace() {
@@ -24,4 +24,93 @@ mod tests {
         assert_ne!(grad_1_new.to_data(), grad_1.into_data());
         assert_eq!(grad_1_new.into_data(), grad_1_updated.into_data());
     }
+
+    #[test]
+    fn should_call_grad_hook_with_the_gradient_of_the_tensor() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let hooked = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let hooked_clone = hooked.clone();
+
+        let tensor_2 = tensor_1
+            .clone()
+            .mul_scalar(2.0)
+            .register_grad_hook(move |grad| *hooked_clone.lock().unwrap() = Some(grad.into_data()));
+        let grads = tensor_2.powf_scalar(2.0).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = hooked.lock().unwrap().take().unwrap();
+
+        grad_2.assert_eq(&TensorData::from([[4.0, 8.0], [12.0, 16.0]]), false);
+        grad_1
+            .into_data()
+            .assert_eq(&TensorData::from([[8.0, 16.0], [24.0, 32.0]]), false);
+    }
+
+    #[test]
+    fn should_not_call_grad_hook_when_the_tensor_is_not_tracked() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_floats([[1.0, 2.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::<2>::from_floats([[3.0, 4.0]], &device)
+            .register_grad_hook(|_| panic!("The hook should not be called."));
+
+        let grads = tensor_1.clone().mul(tensor_2).sum().backward();
+
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 4.0]]), false);
+    }
+
+    #[test]
+    fn should_propagate_the_mapped_gradient() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1
+            .clone()
+            .mul_scalar(2.0)
+            .map_grad(|grad| grad.add_scalar(1.0));
+        let grads = tensor_2.powf_scalar(2.0).sum().backward();
+
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[10.0, 18.0], [26.0, 34.0]]), false);
+    }
+
+    #[test]
+    fn should_call_backward_hook_when_the_gradients_are_complete() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<1>::from_floats([1.0, 2.0], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::<1>::from_floats([3.0], &device).require_grad();
+
+        // The gradient of the second tensor is complete after the second depth of the backward
+        // pass, the one of the first tensor after the last depth.
+        let loss = (tensor_1.clone().mul_scalar(2.0).exp().sum() + tensor_2.clone()).sum();
+        let mut calls = Vec::new();
+        let grads = loss.backward_with_hook(|grads| {
+            let grad_2 = tensor_2
+                .grad_complete(grads)
+                .then(|| tensor_2.grad(grads).unwrap().into_data());
+            calls.push((tensor_1.grad_complete(grads), grad_2));
+        });
+
+        let (complete_1, grad_2) = calls.iter().find(|(_, grad_2)| grad_2.is_some()).unwrap();
+        assert!(!complete_1);
+        grad_2
+            .as_ref()
+            .unwrap()
+            .assert_eq(&TensorData::from([1.0]), false);
+        assert!(calls.last().unwrap().0);
+        assert!(tensor_1.grad_complete(&grads));
+        tensor_1.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([2.0 * 2.0f32.exp(), 2.0 * 4.0f32.exp()]),
+            3,
+        );
+    }
 }
@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample;
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_grid_sample_bilinear_zeros() {
+        assert_grid_sample_grads(
+            GridSamplePaddingMode::Zeros,
+            TensorData::from([[[
+                [0.225, 0.075, 0.6175],
+                [0.525, 0.3975, 0.4275],
+                [0.0, 0.8775, 0.4725],
+            ]]]),
+            TensorData::from([[[[1.5, 4.5], [1.5, 4.5]], [[-4.275, 2.925], [1.5, 4.5]]]]),
+        );
+    }
+
+    #[test]
+    fn should_diff_grid_sample_bilinear_border() {
+        assert_grid_sample_grads(
+            GridSamplePaddingMode::Border,
+            TensorData::from([[[
+                [0.225, 0.075, 1.0],
+                [0.525, 0.3975, 0.4275],
+                [0.0, 0.8775, 0.4725],
+            ]]]),
+            TensorData::from([[[[1.5, 4.5], [1.5, 4.5]], [[0.0, 0.0], [1.5, 4.5]]]]),
+        );
+    }
+
+    fn assert_grid_sample_grads(
+        padding_mode: GridSamplePaddingMode,
+        expected_x_grad: TensorData,
+        expected_grid_grad: TensorData,
+    ) {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::from_data(
+            [[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]],
+            &device,
+        )
+        .require_grad();
+        let grid = TestAutodiffTensor::<4>::from_data(
+            [[[[-0.5, -0.2], [0.5, 0.3]], [[0.9, -0.7], [0.1, 0.6]]]],
+            &device,
+        )
+        .require_grad();
+        let options = GridSampleOptions::new(GridSampleMode::Bilinear, padding_mode, false);
+
+        let output = grid_sample(x.clone(), grid.clone(), options);
+        let grads = output.sum().backward();
+
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected_x_grad, 3);
+        grid.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected_grid_grad, 3);
+    }
+}
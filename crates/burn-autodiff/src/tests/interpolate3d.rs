@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(ad_interpolate3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate3d;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_trilinear_interpolation() {
+        assert_grads(
+            InterpolateMode::Bilinear,
+            [3, 3, 4],
+            TensorData::from([
+                [[16.75, 20.5, 24.25], [32.75, 36.5, 40.25]],
+                [[64.75, 68.5, 72.25], [80.75, 84.5, 88.25]],
+            ]),
+        );
+    }
+
+    #[test]
+    fn should_diff_nearest_interpolation() {
+        assert_grads(
+            InterpolateMode::Nearest,
+            [3, 1, 4],
+            TensorData::from([
+                [[10.0, 8.0, 10.0], [0.0, 0.0, 0.0]],
+                [[17.0, 10.0, 11.0], [0.0, 0.0, 0.0]],
+            ]),
+        );
+    }
+
+    /// Checks the gradients of the output weighted by its flat indices, for an input of shape
+    /// `[1, 1, 2, 2, 3]`.
+    fn assert_grads(mode: InterpolateMode, output_size: [usize; 3], expected: TensorData) {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data(
+            TestTensorInt::<1>::arange(0..12, &device)
+                .float()
+                .into_data(),
+            &device,
+        )
+        .reshape([1, 1, 2, 2, 3])
+        .require_grad();
+        let [depth, height, width] = output_size;
+        let weights = TestAutodiffTensor::<1>::from_data(
+            TestTensorInt::<1>::arange(0..(depth * height * width) as i64, &device)
+                .float()
+                .into_data(),
+            &device,
+        )
+        .reshape([1, 1, depth, height, width]);
+
+        let output = interpolate3d(x.clone(), output_size, InterpolateOptions::new(mode));
+        let grads = output.mul(weights).sum().backward();
+
+        x.grad(&grads)
+            .unwrap()
+            .reshape([2, 2, 3])
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+    }
+}
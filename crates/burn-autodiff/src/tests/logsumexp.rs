@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(ad_logsumexp)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_logsumexp() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_floats(
+            [[0.0, 1.0, 2.0], [1000.0, 1000.0, -1000.0]],
+            &device,
+        )
+        .require_grad();
+
+        let grads = tensor.clone().logsumexp(1).sum().backward();
+
+        let expected = TensorData::from([[0.090031, 0.244728, 0.665241], [0.5, 0.5, 0.0]]);
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected, 4);
+    }
+}
@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(ad_lstsq)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_lstsq() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[1.0, 1.0], [1.0, 2.0], [1.0, 3.0]], &device)
+                .require_grad();
+        let rhs = TestAutodiffTensor::<2>::from_data([[1.0], [2.0], [2.0]], &device).require_grad();
+
+        let output = tensor.clone().lstsq(rhs.clone());
+        let grads = output.sum().backward();
+
+        // The gradient of the right-hand side is A (A^T A)^-1 1.
+        let grad_rhs = rhs.grad(&grads).unwrap();
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.8333], [0.3333], [-0.1667]]), 3);
+        assert!(tensor.grad(&grads).is_some());
+    }
+
+    #[test]
+    fn should_diff_pinv() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[2.0, 1.0], [1.0, 3.0]], &device).require_grad();
+
+        let output = tensor.clone().pinv();
+        let grads = output.sum().backward();
+
+        // For invertible matrices, the gradient matches the one of the inverse.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([[-0.16, -0.08], [-0.08, -0.04]]), 3);
+    }
+}
@@ -5,55 +5,80 @@ mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod add;
 mod aggregation;
+mod atan2;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
+mod bincount;
+mod box_iou;
 mod bridge;
 mod broadcast;
 mod cat;
 mod checkpoint;
+mod cholesky;
 mod complex;
+mod complex_tensor;
 mod conv1d;
 mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
+mod dequantize_matmul;
+mod det;
+mod distribution;
 mod div;
+mod eigh;
 mod erf;
 mod exp;
 mod expand;
+mod fft;
 mod flip;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
+mod interpolate3d;
 mod log;
 mod log1p;
 mod log_sigmoid;
+mod logsumexp;
+mod lstsq;
 mod mask;
 mod matmul;
 mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
 mod memory_management;
+mod morphology;
 mod mul;
 mod multithread;
 mod nearest_interpolate;
 mod neg;
 mod nonzero;
 mod permute;
+mod pixel_shuffle;
 mod pow;
 mod recip;
 mod relu;
 mod repeat;
+mod resample;
 mod reshape;
+mod roi_align;
+mod segment;
 mod select;
+mod selective_scan;
 mod sigmoid;
 mod sign;
 mod sin;
 mod slice;
 mod softmax;
+mod solve;
 mod sort;
+mod sparse_coo;
+mod special;
 mod sqrt;
 mod sub;
 mod tanh;
@@ -89,6 +114,16 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
         burn_autodiff::testgen_module_backward!();
         burn_autodiff::testgen_ad_nearest_interpolate!();
+        burn_autodiff::testgen_ad_interpolate3d!();
+        burn_autodiff::testgen_ad_grid_sample!();
+        burn_autodiff::testgen_ad_roi_align!();
+        burn_autodiff::testgen_ad_pixel_shuffle!();
+        burn_autodiff::testgen_ad_morphology!();
+        burn_autodiff::testgen_ad_resample!();
+        burn_autodiff::testgen_ad_box_iou!();
+        burn_autodiff::testgen_ad_attention!();
+        burn_autodiff::testgen_ad_selective_scan!();
+        burn_autodiff::testgen_ad_dequantize_matmul!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
@@ -130,5 +165,20 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_expand!();
         burn_autodiff::testgen_ad_sort!();
         burn_autodiff::testgen_ad_repeat!();
+        burn_autodiff::testgen_ad_cholesky!();
+        burn_autodiff::testgen_ad_eigh!();
+        burn_autodiff::testgen_ad_solve!();
+        burn_autodiff::testgen_ad_det!();
+        burn_autodiff::testgen_ad_lstsq!();
+        burn_autodiff::testgen_ad_sparse_coo!();
+        burn_autodiff::testgen_ad_cumulative!();
+        burn_autodiff::testgen_ad_logsumexp!();
+        burn_autodiff::testgen_ad_segment!();
+        burn_autodiff::testgen_ad_atan2!();
+        burn_autodiff::testgen_ad_complex_tensor!();
+        burn_autodiff::testgen_ad_fft!();
+        burn_autodiff::testgen_ad_special!();
+        burn_autodiff::testgen_ad_distribution!();
+        burn_autodiff::testgen_ad_bincount!();
     };
 }
@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(ad_morphology)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{dilate, erode};
+    use burn_tensor::{Bool, Tensor, TensorData};
+
+    #[test]
+    fn should_diff_dilate() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::from_floats([[[[1.0, 3.0, 2.0]]]], &device).require_grad();
+
+        let output = dilate(x.clone(), kernel());
+        let grads = output.sum().backward();
+
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[[[0.0, 3.0, 0.0]]]]), false);
+    }
+
+    #[test]
+    fn should_diff_erode() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::from_floats([[[[1.0, 3.0, 2.0]]]], &device).require_grad();
+
+        let output = erode(x.clone(), kernel());
+        let grads = output.sum().backward();
+
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[[[2.0, 0.0, 1.0]]]]), false);
+    }
+
+    fn kernel() -> Tensor<TestAutodiffBackend, 2, Bool> {
+        Tensor::from_bool(TensorData::from([[true, true, true]]), &Default::default())
+    }
+}
@@ -86,4 +86,67 @@ mod tests {
         assert_eq!(grad_1.to_data(), grad_1_moved.to_data());
         assert_eq!(grad_2.to_data(), grad_2_moved.to_data());
     }
+
+    #[test]
+    fn should_execute_operations_in_grad_hooks() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let sum = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let sum_hook = sum.clone();
+
+        // The operations on the gradient register steps in the graph during the backward pass.
+        let grads = tensor
+            .clone()
+            .register_grad_hook(move |grad| {
+                *sum_hook.lock().unwrap() = Some(grad.mul_scalar(2.0).sum().into_scalar());
+            })
+            .mul_scalar(3.0)
+            .sum()
+            .backward();
+
+        assert_eq!(*sum.lock().unwrap(), Some(24.0));
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 3.0], [3.0, 3.0]]), false);
+    }
+
+    #[test]
+    fn should_execute_backward_passes_concurrently() {
+        // Each backward pass waits for the one of the other thread, as a collective reducing the
+        // gradients of the ranks would.
+        let barrier = std::sync::Arc::new(std::sync::Barrier::new(2));
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        for value in [1.0, 2.0] {
+            let barrier = barrier.clone();
+            let sender = sender.clone();
+
+            std::thread::spawn(move || {
+                let device = Default::default();
+                let tensor =
+                    TestAutodiffTensor::<1>::from_floats([value, value], &device).require_grad();
+                let grads = tensor
+                    .clone()
+                    .register_grad_hook(move |_| {
+                        barrier.wait();
+                    })
+                    .powf_scalar(2.0)
+                    .sum()
+                    .backward();
+
+                sender.send((value, tensor.grad(&grads).unwrap())).unwrap();
+            });
+        }
+
+        for _ in 0..2 {
+            let (value, grad) = receiver
+                .recv_timeout(std::time::Duration::from_secs(30))
+                .expect("The backward passes should not block each other.");
+            grad.into_data()
+                .assert_eq(&TensorData::from([2.0 * value, 2.0 * value]), false);
+        }
+    }
 }
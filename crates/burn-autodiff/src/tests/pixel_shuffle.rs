@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(ad_pixel_shuffle)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{pixel_shuffle, pixel_unshuffle};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_pixel_shuffle() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::zeros([1, 4, 2, 2], &device).require_grad();
+        let weights = TestAutodiffTensor::<4>::from_data(
+            TestTensorInt::<1>::arange(0..16, &device)
+                .float()
+                .reshape([1, 1, 4, 4])
+                .into_data(),
+            &device,
+        );
+
+        let output = pixel_shuffle(x.clone(), 2);
+        let grads = output.mul(weights).sum().backward();
+
+        x.grad(&grads).unwrap().into_data().assert_eq(
+            &TensorData::from([[
+                [[0.0, 2.0], [8.0, 10.0]],
+                [[1.0, 3.0], [9.0, 11.0]],
+                [[4.0, 6.0], [12.0, 14.0]],
+                [[5.0, 7.0], [13.0, 15.0]],
+            ]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_diff_pixel_unshuffle() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::zeros([1, 1, 4, 4], &device).require_grad();
+        let weights = TestAutodiffTensor::<4>::from_data(
+            TestTensorInt::<1>::arange(0..16, &device)
+                .float()
+                .reshape([1, 4, 2, 2])
+                .into_data(),
+            &device,
+        );
+
+        let output = pixel_unshuffle(x.clone(), 2);
+        let grads = output.mul(weights).sum().backward();
+
+        x.grad(&grads).unwrap().into_data().assert_eq(
+            &TensorData::from([[[
+                [0.0, 4.0, 1.0, 5.0],
+                [8.0, 12.0, 9.0, 13.0],
+                [2.0, 6.0, 3.0, 7.0],
+                [10.0, 14.0, 11.0, 15.0],
+            ]]]),
+            false,
+        );
+    }
+}
@@ -0,0 +1,22 @@
+#[burn_tensor_testgen::testgen(ad_resample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::resample;
+    use burn_tensor::ops::ResampleOptions;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_resample() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<2>::from_floats([[1.0, -2.0, 3.0, 0.5, 2.0, 1.0]], &device)
+            .require_grad();
+
+        let output = resample(x.clone(), 2, 1, ResampleOptions::default());
+        let grads = output.sum().backward();
+
+        x.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[0.495901, 0.535154, 0.504342, 0.535154, 0.495901, 0.262545]]),
+            3,
+        );
+    }
+}
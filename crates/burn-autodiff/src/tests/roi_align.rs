@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(ad_roi_align)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{roi_align, roi_pool};
+    use burn_tensor::ops::{RoiAlignOptions, RoiPoolOptions};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_roi_align() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::from_data(
+            [[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]],
+            &device,
+        )
+        .require_grad();
+        let boxes = TestAutodiffTensor::<2>::from_data([[0.0, 0.2, 0.4, 2.1, 1.7]], &device);
+
+        let output = roi_align(
+            x.clone(),
+            boxes,
+            RoiAlignOptions::new([2, 2], 1.0, Some(2), true),
+        );
+        let grads = output.sum().backward();
+
+        x.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[
+                [0.7809, 0.89, 0.1665],
+                [0.9031, 1.0293, 0.1926],
+                [0.0159, 0.0182, 0.0034],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_roi_pool() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::from_data(
+            [[[[1.0, 5.0, 3.0], [4.0, 2.0, 6.0], [9.0, 8.0, 7.0]]]],
+            &device,
+        )
+        .require_grad();
+        let boxes = TestAutodiffTensor::<2>::from_data([[0.0, 0.0, 0.0, 2.0, 2.0]], &device);
+
+        let output = roi_pool(x.clone(), boxes, RoiPoolOptions::new([2, 2], 1.0));
+        let grads = output.sum().backward();
+
+        x.grad(&grads).unwrap().into_data().assert_eq(
+            &TensorData::from([[[[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 1.0, 0.0]]]]),
+            false,
+        );
+    }
+}
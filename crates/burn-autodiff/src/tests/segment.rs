@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(ad_segment)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_diff_segment_sum_and_max() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<1>::from_floats([1.0, 5.0, 2.0, 4.0, 3.0], &device).require_grad();
+        let segment_ids =
+            Tensor::<TestAutodiffBackend, 1, Int>::from_ints([0, 0, 1, 1, 1], &device);
+        let weights = TestAutodiffTensor::<1>::from_floats([1.0, 10.0], &device);
+
+        let sum = tensor.clone().segment_sum(segment_ids.clone(), 2) * weights.clone();
+        let max = tensor.clone().segment_max(segment_ids, 2) * weights;
+        let grads = (sum + max).sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&TensorData::from([1.0, 2.0, 10.0, 20.0, 10.0]), 5);
+    }
+}
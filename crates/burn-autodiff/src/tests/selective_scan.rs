@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(ad_selective_scan)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::selective_scan;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_selective_scan() {
+        let device = Default::default();
+        let u =
+            TestAutodiffTensor::<3>::from_floats([[[1.0, 2.0, -1.0], [0.5, -0.5, 1.0]]], &device)
+                .require_grad();
+        let delta =
+            TestAutodiffTensor::<3>::from_floats([[[0.1, 0.5, 1.0], [0.2, 0.3, 0.4]]], &device)
+                .require_grad();
+        let a = TestAutodiffTensor::<2>::from_floats([[-1.0, -2.0], [-0.5, -1.5]], &device)
+            .require_grad();
+        let b =
+            TestAutodiffTensor::<3>::from_floats([[[1.0, 0.5, -1.0], [0.0, 1.0, 2.0]]], &device)
+                .require_grad();
+        let c =
+            TestAutodiffTensor::<3>::from_floats([[[1.0, -1.0, 0.5], [0.5, 1.0, 1.0]]], &device)
+                .require_grad();
+
+        let output = selective_scan(u.clone(), delta.clone(), a.clone(), b.clone(), c.clone());
+        let grads = output.sum().backward();
+
+        u.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[0.0505, 0.3637, 1.5], [0.0983, 0.376, 0.6]]]),
+            3,
+        );
+        delta.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[0.505, 1.5041, -1.8738], [0.2458, -0.6013, 1.6212]]]),
+            3,
+        );
+        a.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0784, 0.1353], [-0.0134, -0.0329]]), 3);
+        b.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[0.0997, -0.7275, -0.3], [0.2405, 0.903, -0.6]]]),
+            3,
+        );
+        c.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[0.2, 0.5717, 0.8153], [0.0, 0.85, -1.147]]]),
+            3,
+        );
+    }
+}
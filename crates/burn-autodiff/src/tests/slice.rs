@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(ad_slice)]
 mod tests {
     use super::*;
-    use burn_tensor::TensorData;
+    use burn_tensor::{Slice, TensorData};
 
     #[test]
     fn should_diff_matmul_with_slice() {
@@ -28,6 +28,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_diff_slice_with_steps() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(
+            [[1.0, 2.0, 3.0, 4.0, 5.0], [6.0, 7.0, 8.0, 9.0, 10.0]],
+            &device,
+        )
+        .require_grad();
+
+        let tensor_2 = tensor_1
+            .clone()
+            .slice([Slice::from(..).with_step(-1), Slice::from(..).with_step(2)]);
+        let weights =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let grads = (tensor_2 * weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_eq(
+            &TensorData::from([[4.0, 0.0, 5.0, 0.0, 6.0], [1.0, 0.0, 2.0, 0.0, 3.0]]),
+            false,
+        );
+    }
+
     #[test]
     fn should_diff_matmul_with_slice_assign() {
         let data_1 = TensorData::from([[1.0, 7.0], [2.0, 3.0]]);
@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(ad_solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_solve() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[2.0, 1.0], [1.0, 3.0]], &device).require_grad();
+        let rhs = TestAutodiffTensor::<2>::from_data([[3.0], [5.0]], &device).require_grad();
+
+        let output = tensor.clone().solve(rhs.clone());
+        let grads = output.sum().backward();
+
+        // With X = A^-1 B, the gradients are G = A^-T 1 for B and -G X^T for A.
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.4], [0.2]]), 3);
+        grad_tensor
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[-0.32, -0.56], [-0.16, -0.28]]), 3);
+    }
+
+    #[test]
+    fn should_diff_triangular_solve() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[2.0, 1.0], [0.0, 4.0]], &device).require_grad();
+        let rhs = TestAutodiffTensor::<2>::from_data([[4.0], [8.0]], &device).require_grad();
+
+        let output = tensor.clone().triangular_solve(rhs.clone(), true);
+        let grads = output.sum().backward();
+
+        // X = [1, 2] and G = A^-T 1 = [0.5, 0.125].
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.5], [0.125]]), 3);
+        grad_tensor
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[-0.5, -1.0], [0.0, -0.25]]), 3);
+    }
+}
@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(ad_sparse_coo)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        sparse::{SparseCsrTensor, SparseTensor},
+        Int, Tensor, TensorData,
+    };
+
+    #[test]
+    fn should_diff_spmm() {
+        let device = Default::default();
+        let indices =
+            Tensor::<TestAutodiffBackend, 2, Int>::from_ints([[0, 1, 1], [1, 0, 2]], &device);
+        let values = TestAutodiffTensor::<1>::from_floats([2.0, -1.0, 3.0], &device);
+        let sparse = SparseTensor::new(indices, values, [2, 3]).require_grad();
+        let rhs =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device)
+                .require_grad();
+
+        let output = sparse.clone().spmm(rhs.clone());
+        let grads = output.sum().backward();
+
+        // The gradient of each stored value is the sum of the row of the dense matrix it selects.
+        let grad = sparse.grad(&grads).unwrap();
+        grad.indices()
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 1], [1, 0, 2]]), false);
+        grad.values()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([7.0, 3.0, 11.0]), 3);
+
+        // The gradient of the dense matrix is the sum of the columns of the sparse matrix.
+        rhs.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-1.0, -1.0], [2.0, 2.0], [3.0, 3.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_csr_spmm() {
+        let device = Default::default();
+        let row_offsets = Tensor::<TestAutodiffBackend, 1, Int>::from_ints([0, 1, 3], &device);
+        let col_indices = Tensor::<TestAutodiffBackend, 1, Int>::from_ints([1, 0, 2], &device);
+        let values = TestAutodiffTensor::<1>::from_floats([2.0, -1.0, 3.0], &device);
+        let sparse = SparseCsrTensor::new(row_offsets, col_indices, values, [2, 3]).require_grad();
+        let rhs =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device)
+                .require_grad();
+
+        let output = sparse.clone().spmm(rhs.clone());
+        let grads = output.sum().backward();
+
+        sparse
+            .grad(&grads)
+            .unwrap()
+            .values()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([7.0, 3.0, 11.0]), 3);
+        rhs.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-1.0, -1.0], [2.0, 2.0], [3.0, 3.0]]), 3);
+    }
+}
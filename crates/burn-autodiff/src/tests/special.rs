@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(ad_special)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_erfinv() {
+        let tensor = TestAutodiffTensor::<1>::from_floats([0.0, 0.5, -0.8], &Default::default())
+            .require_grad();
+
+        let grads = tensor.clone().erfinv().sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([0.88622693, 1.11258482, 2.01456838]), 4);
+    }
+
+    #[test]
+    fn should_diff_lgamma() {
+        let tensor =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.5], &Default::default()).require_grad();
+
+        let grads = tensor.clone().lgamma().sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([-0.57721566, 0.70315664]), 4);
+    }
+
+    #[test]
+    fn should_diff_digamma() {
+        let tensor =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.5], &Default::default()).require_grad();
+
+        let grads = tensor.clone().digamma().sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([1.64493407, 0.49035776]), 4);
+    }
+
+    #[test]
+    fn should_diff_polygamma() {
+        let tensor =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.5], &Default::default()).require_grad();
+
+        let grads = tensor.clone().polygamma(1).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([-2.40411381, -0.23620405]), 3);
+    }
+}
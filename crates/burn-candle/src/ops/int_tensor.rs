@@ -1,11 +1,14 @@
 use burn_tensor::{
-    ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
+    ops::{
+        random::random_from_standard, BoolTensor, FloatTensor, FloatTensorOps, IntElem, IntTensor,
+        IntTensorOps,
+    },
     Bool, Device, Distribution, ElementConversion, Reader, Shape, TensorData,
 };
 
 use crate::{
     element::{CandleElement, FloatCandleElement, IntCandleElement},
-    Candle, CandleTensor,
+    Candle, CandleDevice, CandleTensor,
 };
 
 use super::base::{expand, permute, sign};
@@ -426,6 +429,11 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
                 candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
                     .unwrap(),
             ),
+            distribution => Self::float_into_int(random_from_standard::<Self, D>(
+                Shape::new(*shape),
+                distribution,
+                &CandleDevice::from(device.clone()),
+            )),
         }
     }
 
@@ -1,14 +1,17 @@
 use std::borrow::Borrow;
 
 use burn_tensor::{
-    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, FullPrecisionBackend, IntTensor},
+    ops::{
+        random::random_from_standard, BoolTensor, FloatElem, FloatTensor, FloatTensorOps,
+        FullPrecisionBackend, IntTensor,
+    },
     Device, Distribution, ElementConversion, Reader, Shape, TensorData,
 };
 use candle_core::{backend::BackendStorage, shape, Tensor};
 
 use crate::{
     element::{CandleElement, FloatCandleElement, IntCandleElement},
-    Candle, CandleTensor,
+    Candle, CandleDevice, CandleTensor,
 };
 
 use super::base::{expand, permute, sign};
@@ -52,6 +55,11 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
                 candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
                     .unwrap(),
             ),
+            distribution => random_from_standard::<Self, D>(
+                Shape::new(*shape),
+                distribution,
+                &CandleDevice::from(device.clone()),
+            ),
         }
     }
 
@@ -10,6 +10,18 @@ pub use burn_autodiff as autodiff;
 #[cfg(feature = "autodiff")]
 pub use burn_autodiff::Autodiff;
 
+#[cfg(feature = "vmap")]
+pub use burn_vmap as vmap;
+
+#[cfg(feature = "vmap")]
+pub use burn_vmap::Vmap;
+
+#[cfg(feature = "trace")]
+pub use burn_trace as trace;
+
+#[cfg(feature = "trace")]
+pub use burn_trace::Trace;
+
 #[cfg(feature = "wgpu")]
 pub use burn_wgpu as wgpu;
 
@@ -0,0 +1,734 @@
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::tensor::{backend::Backend, Tensor, TensorData};
+
+thread_local! {
+    static CURRENT: RefCell<Option<Collective>> = const { RefCell::new(None) };
+}
+
+/// How long the ranks of a group of processes try to reach the first rank.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The reduction applied by an [all-reduce](Collective::all_reduce).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReduceOp {
+    /// The sum of the tensors of all the ranks.
+    Sum,
+    /// The mean of the tensors of all the ranks.
+    Mean,
+}
+
+/// The handle of one participant, called a rank, of a group exchanging tensors with collective
+/// operations, e.g. the thread or the process training a replica of a model on one device.
+///
+/// Each collective operation blocks until it has been called by every rank of the group, so all
+/// the ranks must call the same operations in the same order on each
+/// [channel](Collective::channel). The tensors are exchanged through their [data](TensorData),
+/// so the ranks can use different devices. The ranks are either threads of the same process,
+/// created with [group](Collective::group), or different processes, possibly on different
+/// machines, connected with [connect](Collective::connect).
+///
+/// The collective operations on float tensors are differentiable: the gradients of their outputs
+/// are reduced across the group during the backward pass, which must then be executed by every
+/// rank.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let handles = Collective::group(devices.len())
+///     .into_iter()
+///     .zip(devices)
+///     .map(|(collective, device)| {
+///         std::thread::spawn(move || {
+///             let tensor = Tensor::<B, 1>::ones([2], &device);
+///             // Each rank receives the sum of the tensors of all the ranks.
+///             collective.all_reduce(tensor, ReduceOp::Sum)
+///         })
+///     });
+/// ```
+#[derive(Clone, Debug)]
+pub struct Collective {
+    rank: usize,
+    channel: usize,
+    group: Arc<Group>,
+}
+
+#[derive(Debug)]
+enum Group {
+    Threads(ThreadGroup),
+    Processes(ProcessGroup),
+}
+
+/// The ranks of a group of threads, exchanging their data in memory.
+#[derive(Debug)]
+struct ThreadGroup {
+    world_size: usize,
+    channels: Mutex<HashMap<usize, Exchange>>,
+    condvar: Condvar,
+}
+
+/// The state of the exchange in progress on a channel.
+#[derive(Debug)]
+struct Exchange {
+    generation: u64,
+    arrived: usize,
+    slots: Vec<Option<TensorData>>,
+    result: Arc<Vec<TensorData>>,
+}
+
+/// The ranks of a group of processes, exchanging their data through the first rank over TCP,
+/// with a connection for each channel of each other rank.
+#[derive(Debug)]
+struct ProcessGroup {
+    world_size: usize,
+    // The connections of each channel: the connection with the first rank on the other ranks,
+    // and the ones with the other ranks, ordered by rank, on the first rank.
+    channels: Vec<Mutex<Vec<TcpStream>>>,
+}
+
+impl Collective {
+    /// Create the handles of a group of `world_size` ranks, ordered by rank, each one to be moved
+    /// to the thread of its rank.
+    ///
+    /// # Panics
+    ///
+    /// If the world size is zero.
+    pub fn group(world_size: usize) -> Vec<Self> {
+        assert!(world_size > 0, "A group must have at least one rank.");
+
+        let group = Arc::new(Group::Threads(ThreadGroup {
+            world_size,
+            channels: Mutex::new(HashMap::new()),
+            condvar: Condvar::new(),
+        }));
+
+        (0..world_size)
+            .map(|rank| Self {
+                rank,
+                channel: 0,
+                group: group.clone(),
+            })
+            .collect()
+    }
+
+    /// Create the handle of the rank of the current process in a group of `world_size`
+    /// processes, e.g. one process for each device, possibly on different machines.
+    ///
+    /// Every process calls it with its own rank and the same address, where the first rank
+    /// listens for the connections of the other ones, which retry to connect for up to a minute
+    /// while the first rank starts. The group has the channels `0` and `1`, the latter being
+    /// used by the [gradients reducer](crate::optim::GradientsReducer), see
+    /// [connect_with_channels](Collective::connect_with_channels) for more.
+    ///
+    /// The processes of the group are trusted: the connections aren't authenticated nor
+    /// encrypted, and the data received from the other ranks is deserialized as is, so the
+    /// address should only be reachable from the machines of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// // Launched with `RANK=<rank> WORLD_SIZE=<world size> ./train`.
+    /// let rank = std::env::var("RANK").unwrap().parse().unwrap();
+    /// let world_size = std::env::var("WORLD_SIZE").unwrap().parse().unwrap();
+    /// let collective = Collective::connect(rank, world_size, "10.0.0.1:29500")?;
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the world size is zero or the rank isn't smaller than the world size.
+    pub fn connect(
+        rank: usize,
+        world_size: usize,
+        address: impl ToSocketAddrs,
+    ) -> std::io::Result<Self> {
+        Self::connect_with_channels(rank, world_size, 2, address)
+    }
+
+    /// Create the handle of the rank of the current process in a group of `world_size`
+    /// processes, like [connect](Collective::connect), with the channels `0..num_channels`.
+    ///
+    /// The connections of every channel are established before returning: the first rank stops
+    /// listening once it has accepted the one of each channel of each other rank.
+    ///
+    /// # Panics
+    ///
+    /// If the world size or the number of channels is zero, or the rank isn't smaller than the
+    /// world size.
+    pub fn connect_with_channels(
+        rank: usize,
+        world_size: usize,
+        num_channels: usize,
+        address: impl ToSocketAddrs,
+    ) -> std::io::Result<Self> {
+        assert!(world_size > 0, "A group must have at least one rank.");
+        assert!(
+            rank < world_size,
+            "The rank {rank} isn't part of a group of {world_size} ranks."
+        );
+        assert!(num_channels > 0, "A group must have at least one channel.");
+
+        let address = address.to_socket_addrs()?.next().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "No address to connect to.",
+            )
+        })?;
+
+        let channels = match rank {
+            0 => accept(TcpListener::bind(address)?, world_size, num_channels)?,
+            _ => (0..num_channels)
+                .map(|channel| connect(address, rank, channel).map(|stream| vec![stream]))
+                .collect::<std::io::Result<_>>()?,
+        };
+
+        Ok(Self {
+            rank,
+            channel: 0,
+            group: Arc::new(Group::Processes(ProcessGroup {
+                world_size,
+                channels: channels.into_iter().map(Mutex::new).collect(),
+            })),
+        })
+    }
+
+    /// The rank of the handle in its group.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// The number of ranks of the group.
+    pub fn world_size(&self) -> usize {
+        match self.group.as_ref() {
+            Group::Threads(group) => group.world_size,
+            Group::Processes(group) => group.world_size,
+        }
+    }
+
+    /// A handle of the same rank whose collective operations are independent of the ones of
+    /// the other channels, so that each rank can call collective operations concurrently from
+    /// different threads, each one using its own channel, e.g. to reduce gradients on a
+    /// communication thread during the backward pass.
+    ///
+    /// The handles returned by [group](Collective::group) and [connect](Collective::connect) use
+    /// the channel `0`. A group of processes only has the channels it was
+    /// [connected with](Collective::connect_with_channels), the others panicking on their first
+    /// collective operation.
+    pub fn channel(&self, channel: usize) -> Self {
+        Self {
+            rank: self.rank,
+            channel,
+            group: self.group.clone(),
+        }
+    }
+
+    /// Use the collective on the current thread until the returned guard is dropped, so that the
+    /// modules synchronizing across devices, e.g. [SyncBatchNorm](crate::nn::SyncBatchNorm),
+    /// can retrieve it with [current](Collective::current).
+    pub fn install(&self) -> CollectiveGuard {
+        let previous = CURRENT.with(|current| current.borrow_mut().replace(self.clone()));
+
+        CollectiveGuard { previous }
+    }
+
+    /// The collective [installed](Collective::install) on the current thread, if any.
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Reduce the tensors of all the ranks, each rank receiving the result.
+    ///
+    /// The tensors are reduced in the order of the ranks, so every rank receives the same values.
+    ///
+    /// # Shapes
+    ///
+    /// - tensor: `[...]`, the same on every rank
+    /// - output: `[...]`
+    pub fn all_reduce<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        op: ReduceOp,
+    ) -> Tensor<B, D> {
+        let device = tensor.device();
+        let reduced = self.reduce(tensor.clone().into_data(), op);
+        let reduced = Tensor::from_data(reduced.convert::<B::FloatElem>(), &device);
+        let local = match op {
+            ReduceOp::Sum => tensor,
+            ReduceOp::Mean => tensor.div_scalar(self.world_size() as f64),
+        };
+
+        // The output depends on the tensor of each rank, so its gradient is the sum of the
+        // gradients of the outputs of all the ranks.
+        let collective = self.clone();
+        (local.clone() + (reduced - local).detach())
+            .map_grad(move |grad| collective.reduce_grad(grad))
+    }
+
+    /// Concatenate the tensors of all the ranks along a dimension, in the order of the ranks,
+    /// each rank receiving the result.
+    ///
+    /// # Shapes
+    ///
+    /// - tensor: `[...]`, the same on every rank except along the dimension
+    /// - output: `[...]`, with the sum of the sizes of the dimension on every rank
+    pub fn all_gather<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        dim: usize,
+    ) -> Tensor<B, D> {
+        let device = tensor.device();
+        let tensors: Vec<Tensor<B, D>> = self
+            .exchange(tensor.clone().detach().into_data())
+            .iter()
+            .map(|data| Tensor::from_data(data.clone(), &device))
+            .collect();
+
+        // Replace the copy of the tensor of the current rank with the tracked one.
+        let start: usize = tensors[..self.rank]
+            .iter()
+            .map(|tensor| tensor.dims()[dim])
+            .sum();
+        let mut ranges = tensor.dims().map(|size| 0..size);
+        ranges[dim] = start..start + tensor.dims()[dim];
+        let gathered = Tensor::cat(tensors, dim).slice_assign(ranges, tensor);
+
+        let collective = self.clone();
+        gathered.map_grad(move |grad| collective.reduce_grad(grad))
+    }
+
+    /// Send the tensor of the root rank to all the ranks, e.g. to start the training of the
+    /// replicas of a model from the same parameters.
+    ///
+    /// The output isn't tracked by autodiff.
+    pub fn broadcast<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        root: usize,
+    ) -> Tensor<B, D> {
+        let device = tensor.device();
+        let tensors = self.exchange(tensor.into_data());
+
+        Tensor::from_data(tensors[root].clone(), &device)
+    }
+
+    /// Gather the data of all the ranks on the host, in the order of the ranks, each rank
+    /// receiving the result, e.g. to save the state of every rank with the checkpoint of one.
+    pub fn all_gather_data(&self, data: TensorData) -> Vec<TensorData> {
+        self.exchange(data).as_ref().clone()
+    }
+
+    /// Block until every rank of the group has called the barrier.
+    pub fn barrier(&self) {
+        self.exchange(TensorData::new(Vec::<f32>::new(), [0]));
+    }
+
+    /// Sum the gradients of all the ranks, during the backward pass.
+    fn reduce_grad<B: Backend, const D: usize>(&self, grad: Tensor<B, D>) -> Tensor<B, D> {
+        let device = grad.device();
+        let reduced = self.reduce(grad.into_data(), ReduceOp::Sum);
+
+        Tensor::from_data(reduced.convert::<B::FloatElem>(), &device)
+    }
+
+    /// Reduce the data of all the ranks on the host.
+    fn reduce(&self, data: TensorData, op: ReduceOp) -> TensorData {
+        let shape = data.shape.clone();
+        let mut values = vec![0.0; data.num_elements()];
+
+        for data in self.exchange(data).iter() {
+            for (value, other) in values.iter_mut().zip(data.iter::<f64>()) {
+                *value += other;
+            }
+        }
+
+        if op == ReduceOp::Mean {
+            let world_size = self.world_size() as f64;
+            values.iter_mut().for_each(|value| *value /= world_size);
+        }
+
+        TensorData::new(values, shape)
+    }
+
+    /// Exchange the data of every rank, ordered by rank.
+    fn exchange(&self, data: TensorData) -> Arc<Vec<TensorData>> {
+        match self.group.as_ref() {
+            Group::Threads(group) => group.exchange(self.rank, self.channel, data),
+            Group::Processes(group) => group.exchange(self.rank, self.channel, data),
+        }
+    }
+}
+
+impl ThreadGroup {
+    fn exchange(&self, rank: usize, channel: usize, data: TensorData) -> Arc<Vec<TensorData>> {
+        let mut channels = self.channels.lock().unwrap();
+        let state = channels.entry(channel).or_insert_with(|| Exchange {
+            generation: 0,
+            arrived: 0,
+            slots: (0..self.world_size).map(|_| None).collect(),
+            result: Arc::new(Vec::new()),
+        });
+        let generation = state.generation;
+
+        state.slots[rank] = Some(data);
+        state.arrived += 1;
+
+        if state.arrived == self.world_size {
+            let result = state.slots.iter_mut().map(|slot| slot.take().unwrap());
+            state.result = Arc::new(result.collect());
+            state.arrived = 0;
+            state.generation += 1;
+            self.condvar.notify_all();
+
+            return state.result.clone();
+        }
+
+        // The result can't be replaced before every rank has read it, since the next exchange
+        // on the channel can't complete without the current rank.
+        let channels = self
+            .condvar
+            .wait_while(channels, |channels| {
+                channels[&channel].generation == generation
+            })
+            .unwrap();
+
+        channels[&channel].result.clone()
+    }
+}
+
+impl ProcessGroup {
+    /// The first rank receives the data of the other ranks, in the order of the ranks, and sends
+    /// them back the data of every rank.
+    fn exchange(&self, rank: usize, channel: usize, data: TensorData) -> Arc<Vec<TensorData>> {
+        let mut connections = self
+            .channels
+            .get(channel)
+            .unwrap_or_else(|| {
+                panic!(
+                    "The channel {channel} isn't one of the {} channels of the group.",
+                    self.channels.len()
+                )
+            })
+            .lock()
+            .unwrap();
+
+        if rank > 0 {
+            let stream = &mut connections[0];
+            write_message(stream, &data);
+            return Arc::new(read_message(stream));
+        }
+
+        let mut result = vec![data];
+        for stream in connections.iter_mut() {
+            result.push(read_message(stream));
+        }
+        for stream in connections.iter_mut() {
+            write_message(stream, &result);
+        }
+
+        Arc::new(result)
+    }
+}
+
+/// Accept the connection of each channel of each other rank, ordered by rank for each channel,
+/// then stop listening.
+fn accept(
+    listener: TcpListener,
+    world_size: usize,
+    num_channels: usize,
+) -> std::io::Result<Vec<Vec<TcpStream>>> {
+    let mut connections = HashMap::new();
+
+    while connections.len() < (world_size - 1) * num_channels {
+        let (mut stream, _) = listener.accept()?;
+        let mut header = [0u8; 16];
+        if stream.read_exact(&mut header).is_err() {
+            continue;
+        }
+
+        // The connections of unknown ranks or channels are closed, the peers being trusted not
+        // to connect twice.
+        let (rank, channel) = decode_header(header);
+        if (1..world_size).contains(&rank) && channel < num_channels {
+            stream.set_nodelay(true)?;
+            connections.insert((channel, rank), stream);
+        }
+    }
+
+    Ok((0..num_channels)
+        .map(|channel| {
+            (1..world_size)
+                .map(|rank| connections.remove(&(channel, rank)).unwrap())
+                .collect()
+        })
+        .collect())
+}
+
+/// Connect the channel of the rank to the first rank, retrying while it starts.
+fn connect(address: SocketAddr, rank: usize, channel: usize) -> std::io::Result<TcpStream> {
+    let start = Instant::now();
+    let mut stream = loop {
+        match TcpStream::connect(address) {
+            Ok(stream) => break stream,
+            Err(err) if start.elapsed() > CONNECT_TIMEOUT => return Err(err),
+            Err(_) => std::thread::sleep(Duration::from_millis(100)),
+        }
+    };
+
+    stream.set_nodelay(true)?;
+    stream.write_all(&encode_header(rank, channel))?;
+
+    Ok(stream)
+}
+
+fn encode_header(rank: usize, channel: usize) -> [u8; 16] {
+    let mut header = [0u8; 16];
+    header[..8].copy_from_slice(&(rank as u64).to_le_bytes());
+    header[8..].copy_from_slice(&(channel as u64).to_le_bytes());
+    header
+}
+
+fn decode_header(header: [u8; 16]) -> (usize, usize) {
+    let rank = u64::from_le_bytes(header[..8].try_into().unwrap());
+    let channel = u64::from_le_bytes(header[8..].try_into().unwrap());
+    (rank as usize, channel as usize)
+}
+
+/// Write a message prefixed by its number of bytes.
+fn write_message<T: serde::Serialize>(stream: &mut TcpStream, message: &T) {
+    let bytes = rmp_serde::to_vec(message).expect("The message should be serializable.");
+    stream
+        .write_all(&(bytes.len() as u64).to_le_bytes())
+        .and_then(|_| stream.write_all(&bytes))
+        .expect("The connection with the rank should be open.");
+}
+
+fn read_message<T: serde::de::DeserializeOwned>(stream: &mut TcpStream) -> T {
+    let mut num_bytes = [0u8; 8];
+    let mut bytes = Vec::new();
+    stream
+        .read_exact(&mut num_bytes)
+        .and_then(|_| {
+            bytes.resize(u64::from_le_bytes(num_bytes) as usize, 0);
+            stream.read_exact(&mut bytes)
+        })
+        .expect("The connection with the rank should be open.");
+
+    rmp_serde::from_slice(&bytes).expect("The message should be deserializable.")
+}
+
+/// Restore the collective previously [installed](Collective::install) on the current thread when
+/// dropped.
+#[must_use = "The collective is uninstalled when the guard is dropped."]
+pub struct CollectiveGuard {
+    previous: Option<Collective>,
+}
+
+impl Drop for CollectiveGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    fn run<O: Send + 'static>(
+        world_size: usize,
+        func: impl Fn(Collective) -> O + Send + Sync + 'static,
+    ) -> Vec<O> {
+        let func = Arc::new(func);
+        let handles: Vec<_> = Collective::group(world_size)
+            .into_iter()
+            .map(|collective| {
+                let func = func.clone();
+                std::thread::spawn(move || func(collective))
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn should_reduce_the_tensors_of_all_ranks() {
+        let outputs = run(3, |collective| {
+            let device = Default::default();
+            let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device)
+                .mul_scalar(collective.rank() as f64 + 1.0);
+
+            let sum = collective.all_reduce(tensor.clone(), ReduceOp::Sum);
+            let mean = collective.all_reduce(tensor, ReduceOp::Mean);
+
+            (sum.into_data(), mean.into_data())
+        });
+
+        for (sum, mean) in outputs {
+            sum.assert_eq(&TensorData::from([6.0f32, 12.0]), false);
+            mean.assert_eq(&TensorData::from([2.0f32, 4.0]), false);
+        }
+    }
+
+    #[test]
+    fn should_gather_and_broadcast_the_tensors_of_all_ranks() {
+        let outputs = run(2, |collective| {
+            let device = Default::default();
+            let rank = collective.rank() as f32;
+            let tensor = Tensor::<TestBackend, 2>::from_floats([[rank, rank]], &device);
+
+            let gathered = collective.all_gather(tensor.clone(), 0);
+            collective.barrier();
+            let broadcast = collective.broadcast(tensor.add_scalar(1.0), 1);
+
+            (gathered.into_data(), broadcast.into_data())
+        });
+
+        for (gathered, broadcast) in outputs {
+            gathered.assert_eq(&TensorData::from([[0.0f32, 0.0], [1.0, 1.0]]), false);
+            broadcast.assert_eq(&TensorData::from([[2.0f32, 2.0]]), false);
+        }
+    }
+
+    #[test]
+    fn should_reduce_the_gradients_across_ranks() {
+        let outputs = run(2, |collective| {
+            let device = Default::default();
+            let rank = collective.rank() as f32;
+            let tensor =
+                Tensor::<TestAutodiffBackend, 1>::from_floats([1.0, 2.0], &device).require_grad();
+
+            // The loss of each rank is the sum of the reduced tensor weighted by `rank + 1`.
+            let sum = collective.all_reduce(tensor.clone(), ReduceOp::Sum);
+            let gathered = collective.all_gather(tensor.clone(), 0);
+            let loss = sum.mul_scalar(rank + 1.0).sum() + gathered.sum();
+            let grads = loss.backward();
+
+            tensor.grad(&grads).unwrap().into_data()
+        });
+
+        for grad in outputs {
+            grad.assert_eq(&TensorData::from([5.0f32, 5.0]), false);
+        }
+    }
+
+    #[test]
+    fn should_exchange_on_independent_channels() {
+        let outputs = run(2, |collective| {
+            let device = Default::default();
+            let rank = collective.rank() as f32;
+            let channel = collective.channel(1);
+            let other = std::thread::spawn(move || {
+                let tensor = Tensor::<TestBackend, 1>::from_floats([rank + 10.0], &device);
+                channel.all_reduce(tensor, ReduceOp::Sum).into_data()
+            });
+
+            let tensor = Tensor::<TestBackend, 1>::from_floats([rank], &device);
+            let sum = collective.all_reduce(tensor, ReduceOp::Sum).into_data();
+
+            (sum, other.join().unwrap())
+        });
+
+        for (sum, other) in outputs {
+            sum.assert_eq(&TensorData::from([1.0f32]), false);
+            other.assert_eq(&TensorData::from([21.0f32]), false);
+        }
+    }
+
+    #[test]
+    fn should_reduce_the_tensors_of_connected_processes() {
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let handles: Vec<_> = (0..3)
+            .map(|rank| {
+                std::thread::spawn(move || {
+                    let collective = Collective::connect(rank, 3, address).unwrap();
+                    let device = Default::default();
+                    let tensor = Tensor::<TestBackend, 2>::from_floats([[rank as f32]], &device);
+
+                    let gathered = collective.channel(1).all_gather(tensor.clone(), 0);
+                    let sum = collective.all_reduce(tensor, ReduceOp::Sum);
+
+                    (gathered.into_data(), sum.into_data())
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let (gathered, sum) = handle.join().unwrap();
+            gathered.assert_eq(&TensorData::from([[0.0f32], [1.0], [2.0]]), false);
+            sum.assert_eq(&TensorData::from([[3.0f32]]), false);
+        }
+    }
+
+    #[test]
+    fn should_stop_listening_once_every_rank_is_connected() {
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let other = std::thread::spawn(move || {
+            // A connection for a rank outside of the group is ignored.
+            let mut stray = connect(address, 5, 0).unwrap();
+            let collective = Collective::connect_with_channels(1, 2, 3, address).unwrap();
+            let mut byte = [0u8; 1];
+            assert_eq!(stray.read(&mut byte).unwrap_or(0), 0);
+            collective
+        });
+
+        let collective = Collective::connect_with_channels(0, 2, 3, address).unwrap();
+        let other = other.join().unwrap();
+
+        assert!(TcpStream::connect(address).is_err());
+        let outputs = [collective, other].map(|collective| {
+            std::thread::spawn(move || {
+                let tensor = Tensor::<TestBackend, 1>::from_floats([1.0], &Default::default());
+                collective.channel(2).all_reduce(tensor, ReduceOp::Sum)
+            })
+        });
+        for output in outputs {
+            output
+                .join()
+                .unwrap()
+                .into_data()
+                .assert_eq(&TensorData::from([2.0f32]), false);
+        }
+    }
+
+    #[test]
+    #[should_panic = "isn't one of the 1 channels"]
+    fn should_panic_on_a_channel_the_processes_are_not_connected_with() {
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let collective = Collective::connect_with_channels(0, 1, 1, address).unwrap();
+
+        collective.channel(1).barrier();
+    }
+
+    #[test]
+    fn should_install_the_collective_on_the_current_thread() {
+        let collective = Collective::group(1).remove(0);
+        assert!(Collective::current().is_none());
+
+        {
+            let _guard = collective.install();
+            assert_eq!(Collective::current().unwrap().rank(), 0);
+        }
+
+        assert!(Collective::current().is_none());
+    }
+}
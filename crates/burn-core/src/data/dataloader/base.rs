@@ -15,6 +15,12 @@ pub struct Progress {
 pub trait DataLoaderIterator<O>: Iterator<Item = O> {
     /// Returns the progress of the data loader.
     fn progress(&self) -> Progress;
+    /// Returns the number of items processed by each of the iterators read by the data loader
+    /// iterator, e.g. one per worker thread, to [resume](DataLoader::set_positions) a later
+    /// iteration at the same position.
+    fn positions(&self) -> Vec<usize> {
+        vec![self.progress().items_processed]
+    }
 }
 
 /// A data loader that can be used to iterate over a dataset.
@@ -24,6 +30,18 @@ pub trait DataLoader<O>: Send {
     /// The number of items (not the number of batches nor the number of iterations),
     /// corresponding to the items_total of the progress returned by the iterator.
     fn num_items(&self) -> usize;
+    /// Sets the number of iterations already started over the data loader, so that the next
+    /// iteration shuffles the dataset the same way as the next one of a data loader iterated that
+    /// many times, e.g. to resume a training at the same epoch.
+    ///
+    /// Data loaders which don't shuffle the dataset have nothing to do.
+    fn set_iterations(&self, _iterations: usize) {}
+    /// Sets the [positions](DataLoaderIterator::positions) where the next iteration starts,
+    /// skipping the items processed before them, e.g. to resume a training interrupted during an
+    /// epoch. The iterations after the next one start at the beginning of the dataset again.
+    ///
+    /// Data loaders which don't support it start the next iteration at the beginning.
+    fn set_positions(&self, _positions: &[usize]) {}
 }
 
 /// A super trait for [dataloader](DataLoader) that allows it to be cloned dynamically.
@@ -15,6 +15,11 @@ pub struct BatchDataLoader<I, O> {
     dataset: Arc<dyn Dataset<I>>,
     batcher: Box<dyn DynBatcher<I, O>>,
     rng: Option<Arc<spin::Mutex<rand::rngs::StdRng>>>,
+    // The rng before the first iteration, to restore the shuffling of the next iterations.
+    rng_initial: Option<rand::rngs::StdRng>,
+    // The index of the item where the next iteration starts, shared with the clones iterated by
+    // the worker threads.
+    position: Arc<spin::Mutex<usize>>,
 }
 
 impl<I, O> Clone for BatchDataLoader<I, O> {
@@ -24,6 +29,8 @@ impl<I, O> Clone for BatchDataLoader<I, O> {
             dataset: self.dataset.clone(),
             batcher: self.batcher.clone_dyn(),
             rng: self.rng.clone(),
+            rng_initial: self.rng_initial.clone(),
+            position: self.position.clone(),
         }
     }
 }
@@ -52,7 +59,9 @@ impl<I, O> BatchDataLoader<I, O> {
             strategy,
             dataset,
             batcher,
+            rng_initial: rng.clone(),
             rng: rng.map(|rng| Arc::new(spin::Mutex::new(rng))),
+            position: Arc::new(spin::Mutex::new(0)),
         }
     }
 }
@@ -130,16 +139,35 @@ where
             }
             None => self.dataset.clone(),
         };
-        Box::new(BatchDataloaderIterator::new(
+        let mut iterator = BatchDataloaderIterator::new(
             self.strategy.clone_dyn(),
             dataset,
             self.batcher.clone_dyn(),
-        ))
+        );
+        iterator.current_index = core::mem::take(&mut *self.position.lock());
+
+        Box::new(iterator)
     }
 
     fn num_items(&self) -> usize {
         self.dataset.len()
     }
+
+    fn set_iterations(&self, iterations: usize) {
+        if let (Some(rng), Some(rng_initial)) = (&self.rng, &self.rng_initial) {
+            let mut rng_restored = rng_initial.clone();
+
+            // Each iteration samples the seed of its shuffling.
+            for _ in 0..iterations {
+                let _: u64 = rng_restored.sample(Standard);
+            }
+            *rng.lock() = rng_restored;
+        }
+    }
+
+    fn set_positions(&self, positions: &[usize]) {
+        *self.position.lock() = positions.first().copied().unwrap_or_default();
+    }
 }
 
 impl<I, O> BatchDataloaderIterator<I, O> {
@@ -201,8 +229,8 @@ mod tests {
 
     use super::*;
     use crate::data::dataloader::batcher::TestBatcher;
-    use crate::data::dataloader::FixBatchStrategy;
-    use crate::data::dataset::FakeDataset;
+    use crate::data::dataloader::{DataLoaderBuilder, FixBatchStrategy};
+    use crate::data::dataset::{FakeDataset, InMemDataset};
 
     #[test]
     fn test_batch_dataloader() {
@@ -266,4 +294,68 @@ mod tests {
 
         assert_eq!(items_single_thread, items_multi_thread);
     }
+
+    #[test]
+    fn test_batch_dataloader_set_iterations() {
+        let dataloader = |num_threads| {
+            let builder = DataLoaderBuilder::new(TestBatcher::new())
+                .batch_size(5)
+                .shuffle(42);
+            match num_threads {
+                Some(num_threads) => builder.num_workers(num_threads),
+                None => builder,
+            }
+            .build(InMemDataset::new((0..27).map(|i| i.to_string()).collect()))
+        };
+        let items = |dataloader: &Arc<dyn DataLoader<Vec<String>>>| {
+            let mut items: Vec<Vec<String>> = dataloader.iter().collect();
+            items.sort();
+            items
+        };
+
+        for num_threads in [None, Some(2)] {
+            let dataloader_iterated = dataloader(num_threads);
+            items(&dataloader_iterated);
+            items(&dataloader_iterated);
+            let expected = items(&dataloader_iterated);
+            assert_ne!(items(&dataloader(num_threads)), expected);
+
+            let dataloader_restored = dataloader(num_threads);
+            dataloader_restored.set_iterations(2);
+            assert_eq!(items(&dataloader_restored), expected);
+        }
+    }
+
+    #[test]
+    fn test_batch_dataloader_set_positions() {
+        let dataloader = |num_threads| {
+            let builder = DataLoaderBuilder::new(TestBatcher::new())
+                .batch_size(5)
+                .shuffle(42);
+            match num_threads {
+                Some(num_threads) => builder.num_workers(num_threads),
+                None => builder,
+            }
+            .build(InMemDataset::new((0..27).map(|i| i.to_string()).collect()))
+        };
+
+        for num_threads in [None, Some(2)] {
+            let dataloader_interrupted = dataloader(num_threads);
+            let mut iterator = dataloader_interrupted.iter();
+            iterator.next();
+            iterator.next();
+            let positions = iterator.positions();
+            let mut expected: Vec<Vec<String>> = iterator.collect();
+            expected.sort();
+
+            let dataloader_restored = dataloader(num_threads);
+            dataloader_restored.set_positions(&positions);
+            let mut items: Vec<Vec<String>> = dataloader_restored.iter().collect();
+            items.sort();
+            assert_eq!(items, expected);
+
+            // Only the next iteration is resumed.
+            assert_eq!(dataloader_restored.iter().flatten().count(), 27);
+        }
+    }
 }
@@ -1,5 +1,9 @@
 use super::{batcher::DynBatcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy};
-use burn_dataset::Dataset;
+use burn_dataset::{
+    transform::{ComposedDataset, PartialDataset},
+    Dataset,
+};
+use burn_tensor::Generator;
 use rand::{rngs::StdRng, SeedableRng};
 use std::sync::Arc;
 
@@ -9,6 +13,8 @@ pub struct DataLoaderBuilder<I, O> {
     batcher: Box<dyn DynBatcher<I, O>>,
     num_threads: Option<usize>,
     shuffle: Option<u64>,
+    shard: Option<(usize, usize)>,
+    drop_last: bool,
 }
 
 impl<I, O> DataLoaderBuilder<I, O>
@@ -34,6 +40,8 @@ where
             strategy: None,
             num_threads: None,
             shuffle: None,
+            shard: None,
+            drop_last: false,
         }
     }
 
@@ -68,6 +76,22 @@ where
         self
     }
 
+    /// Sets the seed for shuffling to the next seed of the given generator.
+    ///
+    /// The workers of the data loader are seeded from this seed, so restoring the state of the
+    /// generator shuffles the dataset in the same order.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - The random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn shuffle_with(self, generator: &mut Generator) -> Self {
+        self.shuffle(generator.next_seed())
+    }
+
     /// Sets the number of workers.
     ///
     /// # Arguments
@@ -82,6 +106,53 @@ where
         self
     }
 
+    /// Only load the shard of the dataset of a rank of a distributed training, so that each rank
+    /// iterates over different items, e.g. with the rank and the world size of a
+    /// [collective](crate::collective::Collective).
+    ///
+    /// The dataset is split in `world_size` contiguous shards of the same size, so that every
+    /// rank loads the same number of batches. When the length of the dataset isn't a multiple
+    /// of the world size, the last shards are padded with the first items of the dataset, which
+    /// are then loaded twice per epoch, unless the last `len % world_size` items are
+    /// [dropped](Self::drop_last) instead. When shuffling, each rank shuffles the items of its
+    /// shard.
+    ///
+    /// # Arguments
+    ///
+    /// * `rank` - The rank loading the data loader.
+    /// * `world_size` - The number of ranks.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    ///
+    /// # Panics
+    ///
+    /// If the rank isn't smaller than the world size.
+    pub fn shard(mut self, rank: usize, world_size: usize) -> Self {
+        assert!(
+            rank < world_size,
+            "The rank ({rank}) must be smaller than the world size ({world_size})."
+        );
+        self.shard = Some((rank, world_size));
+        self
+    }
+
+    /// Drop the last `len % world_size` items of a [sharded](Self::shard) dataset instead of
+    /// padding the last shards, so that every item is loaded at most once per epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `drop_last` - If the last items are dropped.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn drop_last(mut self, drop_last: bool) -> Self {
+        self.drop_last = drop_last;
+        self
+    }
+
     /// Builds the data loader.
     ///
     /// # Arguments
@@ -95,7 +166,30 @@ where
     where
         D: Dataset<I> + 'static,
     {
-        let dataset = Arc::new(dataset);
+        let dataset: Arc<dyn Dataset<I>> = match self.shard {
+            Some((rank, world_size)) => {
+                let dataset = Arc::new(dataset);
+                let len = dataset.len();
+                let size = match self.drop_last {
+                    true => len / world_size,
+                    false => len.div_ceil(world_size),
+                };
+
+                // The padding wraps around the dataset, as many times as needed when the
+                // dataset is smaller than the world size.
+                let mut parts = Vec::new();
+                let (mut start, end) = (rank * size, (rank + 1) * size);
+                while start < end {
+                    let index = start % len;
+                    let index_end = (index + end - start).min(len);
+                    parts.push(PartialDataset::new(dataset.clone(), index, index_end));
+                    start += index_end - index;
+                }
+
+                Arc::new(ComposedDataset::new(parts))
+            }
+            None => Arc::new(dataset),
+        };
 
         let rng = self.shuffle.map(StdRng::seed_from_u64);
         let strategy = match self.strategy {
@@ -115,3 +209,55 @@ where
         Arc::new(BatchDataLoader::new(strategy, dataset, self.batcher, rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataset::InMemDataset;
+
+    #[test]
+    fn test_sharded_dataloaders_should_load_disjoint_shards_of_the_same_size() {
+        let dataset = Arc::new(InMemDataset::new((0..27).collect::<Vec<i32>>()));
+        let mut items = HashSet::new();
+
+        for rank in 0..4 {
+            let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+                .batch_size(2)
+                .shuffle(42)
+                .shard(rank, 4)
+                .drop_last(true)
+                .build(dataset.clone());
+            let shard: Vec<i32> = dataloader.iter().flatten().collect();
+
+            assert_eq!(shard.len(), 6);
+            items.extend(shard);
+        }
+
+        assert_eq!(items.len(), 24);
+    }
+
+    #[test]
+    fn test_sharded_dataloaders_should_pad_the_last_shards() {
+        let shards = |len: i32, world_size: usize| {
+            let dataset = Arc::new(InMemDataset::new((0..len).collect::<Vec<i32>>()));
+            (0..world_size)
+                .map(|rank| {
+                    let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+                        .batch_size(2)
+                        .shard(rank, world_size)
+                        .build(dataset.clone());
+                    dataloader.iter().flatten().collect::<Vec<i32>>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            shards(10, 4),
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8], vec![9, 0, 1]]
+        );
+        assert_eq!(shards(2, 3), vec![vec![0], vec![1], vec![0]]);
+    }
+}
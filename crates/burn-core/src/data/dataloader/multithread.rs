@@ -7,6 +7,8 @@ const MAX_QUEUED_ITEMS: usize = 100;
 /// A multi-threaded data loader that can be used to iterate over a dataset.
 pub struct MultiThreadDataLoader<O> {
     dataloaders: Vec<Box<dyn DynDataLoader<O>>>,
+    // The positions where the next iteration of each data loader starts.
+    positions: spin::Mutex<Vec<usize>>,
 }
 
 /// A message that can be sent between threads.
@@ -37,7 +39,10 @@ impl<O> MultiThreadDataLoader<O> {
     ///
     /// The multi-threaded data loader.
     pub fn new(dataloaders: Vec<Box<dyn DynDataLoader<O>>>) -> Self {
-        Self { dataloaders }
+        Self {
+            dataloaders,
+            positions: spin::Mutex::new(Vec::new()),
+        }
     }
 }
 
@@ -49,6 +54,7 @@ where
         let (sender, receiver) = mpsc::sync_channel::<Message<O>>(MAX_QUEUED_ITEMS);
 
         let mut progresses = Vec::with_capacity(self.dataloaders.len());
+        let positions = core::mem::take(&mut *self.positions.lock());
 
         let handlers: Vec<_> = self
             .dataloaders
@@ -57,7 +63,8 @@ where
             .map(|(index, dataloader)| {
                 let dataloader_cloned = dataloader.clone_dyn();
                 let sender_cloned = sender.clone();
-                progresses.push(Progress::new(0, dataloader_cloned.num_items()));
+                let position = positions.get(index).copied().unwrap_or_default();
+                progresses.push(Progress::new(position, dataloader_cloned.num_items()));
 
                 thread::spawn(move || {
                     let mut iterator = dataloader_cloned.iter();
@@ -85,6 +92,19 @@ where
     fn num_items(&self) -> usize {
         self.dataloaders.iter().map(|dl| dl.num_items()).sum()
     }
+
+    fn set_iterations(&self, iterations: usize) {
+        for dataloader in self.dataloaders.iter() {
+            dataloader.set_iterations(iterations);
+        }
+    }
+
+    fn set_positions(&self, positions: &[usize]) {
+        for (dataloader, position) in self.dataloaders.iter().zip(positions) {
+            dataloader.set_positions(&[*position]);
+        }
+        *self.positions.lock() = positions.to_vec();
+    }
 }
 
 impl<O> MultiThreadsDataloaderIterator<O> {
@@ -113,6 +133,13 @@ impl<O: std::fmt::Debug> DataLoaderIterator<O> for MultiThreadsDataloaderIterato
 
         Progress::new(items_processed, items_total)
     }
+
+    fn positions(&self) -> Vec<usize> {
+        self.progresses
+            .iter()
+            .map(|progress| progress.items_processed)
+            .collect()
+    }
 }
 
 impl<O: std::fmt::Debug> Iterator for MultiThreadsDataloaderIterator<O> {
@@ -24,6 +24,10 @@ pub mod optim;
 #[cfg(feature = "std")]
 pub mod lr_scheduler;
 
+/// Collective operations between the ranks of a distributed training.
+#[cfg(feature = "std")]
+pub mod collective;
+
 /// Gradient clipping module.
 pub mod grad_clipping;
 
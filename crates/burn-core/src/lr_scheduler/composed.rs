@@ -0,0 +1,172 @@
+use burn_tensor::backend::Backend;
+
+use super::LrScheduler;
+use crate::LearningRate;
+
+/// Learning rate scheduler using a first scheduler for a number of steps, then a second one, e.g.
+/// to warm up the learning rate before annealing it.
+///
+/// The second scheduler starts from its initial state once the milestone is reached, and both can
+/// themselves be composed to chain more than two schedulers.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// // Warm up linearly for 1000 iterations, then anneal over the remaining 9000 iterations.
+/// let scheduler = SequentialLrScheduler::new(
+///     LinearLrSchedulerConfig::new(1e-5, 1e-3, 1000).init(),
+///     CosineAnnealingLrSchedulerConfig::new(1e-3, 9000).init(),
+///     1000,
+/// );
+/// ```
+#[derive(new, Clone, Debug)]
+pub struct SequentialLrScheduler<S1, S2> {
+    first: S1,
+    second: S2,
+    /// The number of steps performed by the first scheduler.
+    milestone: usize,
+    #[new(default)]
+    current_iter: usize,
+}
+
+impl<B, S1, S2> LrScheduler<B> for SequentialLrScheduler<S1, S2>
+where
+    B: Backend,
+    S1: LrScheduler<B>,
+    S2: LrScheduler<B>,
+{
+    type Record = (S1::Record, S2::Record, usize);
+
+    fn step(&mut self) -> LearningRate {
+        let lr = match self.current_iter < self.milestone {
+            true => self.first.step(),
+            false => self.second.step(),
+        };
+        self.current_iter = self.current_iter.saturating_add(1);
+
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (
+            self.first.to_record(),
+            self.second.to_record(),
+            self.current_iter,
+        )
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        let (first, second, current_iter) = record;
+
+        Self {
+            first: self.first.load_record(first),
+            second: self.second.load_record(second),
+            milestone: self.milestone,
+            current_iter,
+        }
+    }
+}
+
+/// Learning rate scheduler multiplying the learning rates of two schedulers, which are both
+/// stepped at each step, e.g. to decay a cyclic schedule.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// // A cosine annealing with cold restarts, whose restarts decay exponentially.
+/// let scheduler = ProductLrScheduler::new(
+///     CosineAnnealingLrSchedulerConfig::new(1e-3, 1000).init(),
+///     ExponentialLrSchedulerConfig::new(1.0, 0.9999).init(),
+/// );
+/// ```
+#[derive(new, Clone, Debug)]
+pub struct ProductLrScheduler<S1, S2> {
+    first: S1,
+    second: S2,
+}
+
+impl<B, S1, S2> LrScheduler<B> for ProductLrScheduler<S1, S2>
+where
+    B: Backend,
+    S1: LrScheduler<B>,
+    S2: LrScheduler<B>,
+{
+    type Record = (S1::Record, S2::Record);
+
+    fn step(&mut self) -> LearningRate {
+        self.first.step() * self.second.step()
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (self.first.to_record(), self.second.to_record())
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        let (first, second) = record;
+
+        Self {
+            first: self.first.load_record(first),
+            second: self.second.load_record(second),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lr_scheduler::{
+        exponential::ExponentialLrSchedulerConfig, linear::LinearLrSchedulerConfig,
+    };
+    use crate::TestBackend;
+
+    #[test]
+    fn test_sequential_lr_change() {
+        let mut scheduler = SequentialLrScheduler::new(
+            LinearLrSchedulerConfig::new(0.1, 0.5, 2).init(),
+            LinearLrSchedulerConfig::new(0.5, 0.1, 4).init(),
+            2,
+        );
+
+        let lrs: Vec<LearningRate> = (0..7)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect();
+
+        let expected = [0.3, 0.5, 0.4, 0.3, 0.2, 0.1, 0.1];
+        for (lr, expected) in lrs.iter().zip(expected) {
+            assert!((lr - expected).abs() < 1e-10, "{lrs:?}");
+        }
+    }
+
+    #[test]
+    fn test_product_lr_change() {
+        let mut scheduler =
+            ProductLrScheduler::new(0.5, ExponentialLrSchedulerConfig::new(1.0, 0.5).init());
+
+        let lrs: Vec<LearningRate> = (0..3)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect();
+
+        assert_eq!(lrs, [0.25, 0.125, 0.0625]);
+    }
+
+    #[test]
+    fn test_sequential_load_record() {
+        let init = || {
+            SequentialLrScheduler::new(
+                LinearLrSchedulerConfig::new(0.1, 0.5, 2).init(),
+                LinearLrSchedulerConfig::new(0.5, 0.1, 4).init(),
+                2,
+            )
+        };
+        let mut scheduler = init();
+        for _ in 0..3 {
+            LrScheduler::<TestBackend>::step(&mut scheduler);
+        }
+        let record = LrScheduler::<TestBackend>::to_record(&scheduler);
+        let expected = LrScheduler::<TestBackend>::step(&mut scheduler);
+
+        let mut scheduler = LrScheduler::<TestBackend>::load_record(init(), record);
+
+        assert_eq!(LrScheduler::<TestBackend>::step(&mut scheduler), expected);
+    }
+}
@@ -13,6 +13,11 @@ pub mod exponential;
 /// Cosine learning rate scheduler
 pub mod cosine;
 
+/// One-cycle learning rate scheduler
+pub mod one_cycle;
+
 mod base;
+mod composed;
 
 pub use base::*;
+pub use composed::*;
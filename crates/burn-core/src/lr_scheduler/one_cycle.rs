@@ -0,0 +1,313 @@
+use super::LrScheduler;
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a one-cycle learning rate scheduler.
+///
+/// This scheduler warms up the learning rate from `max_lr / div_factor` to `max_lr` during the first
+/// `pct_start` of the `num_iters` iterations, then anneals it down to
+/// `max_lr / (div_factor * final_div_factor)` at the last iteration, after which it stays constant.
+/// When `cycle_momentum` is true, the [momentum](OneCycleLrScheduler::momentum) is cycled inversely
+/// to the learning rate, between `max_momentum` and `base_momentum`.
+///
+/// Reference: [Super-Convergence](https://arxiv.org/abs/1708.07120)
+#[derive(Config)]
+pub struct OneCycleLrSchedulerConfig {
+    /// The maximum learning rate, reached at the end of the warmup.
+    max_lr: LearningRate,
+    /// The total number of iterations of the cycle.
+    num_iters: usize,
+    /// The fraction of the iterations used to warm up the learning rate.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    /// The initial learning rate is `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    /// The final learning rate is the initial learning rate divided by `final_div_factor`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+    /// The function used to anneal the learning rate and the momentum.
+    #[config(default = "AnnealStrategy::Cosine")]
+    anneal_strategy: AnnealStrategy,
+    /// If the momentum is cycled inversely to the learning rate.
+    #[config(default = false)]
+    cycle_momentum: bool,
+    /// The momentum at the end of the warmup.
+    #[config(default = 0.85)]
+    base_momentum: f64,
+    /// The momentum at the start and at the end of the cycle.
+    #[config(default = 0.95)]
+    max_momentum: f64,
+}
+
+/// The function used by the [one-cycle](OneCycleLrScheduler) learning rate scheduler to go from a
+/// value to another.
+#[derive(Config, Debug, Copy, PartialEq, Eq)]
+pub enum AnnealStrategy {
+    /// Follow half a period of a cosine function.
+    Cosine,
+    /// Change the value by a constant amount on each iteration.
+    Linear,
+}
+
+impl AnnealStrategy {
+    fn anneal(&self, start: f64, end: f64, pct: f64) -> f64 {
+        match self {
+            AnnealStrategy::Cosine => {
+                end + 0.5 * (start - end) * (1.0 + (pct * std::f64::consts::PI).cos())
+            }
+            AnnealStrategy::Linear => start + (end - start) * pct,
+        }
+    }
+}
+
+impl OneCycleLrSchedulerConfig {
+    /// Initializes a [one-cycle learning rate scheduler](OneCycleLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `max_lr` is not between 0 and 1, if `num_iters` is 0, if `pct_start`
+    /// is not between 0 and 1, if the division factors aren't positive, or if the momentums are not
+    /// between 0 and 1 with `base_momentum` at most `max_momentum`.
+    pub fn init(&self) -> OneCycleLrScheduler {
+        assert!(
+            self.max_lr > 0. && self.max_lr <= 1.,
+            "Maximum learning rate must be greater than 0 and at most 1"
+        );
+        assert!(
+            self.num_iters > 0,
+            "Number of iterations must be at least 1"
+        );
+        assert!(
+            (0.0..=1.0).contains(&self.pct_start),
+            "Warmup fraction must be at least 0 and at most 1"
+        );
+        assert!(
+            self.div_factor > 0. && self.final_div_factor > 0.,
+            "Division factors must be greater than 0"
+        );
+        if self.cycle_momentum {
+            assert!(
+                self.base_momentum >= 0.
+                    && self.base_momentum <= self.max_momentum
+                    && self.max_momentum <= 1.,
+                "Base momentum must be at least 0 and at most equal to the maximum momentum, which must be at most 1"
+            );
+        }
+
+        let initial_lr = self.max_lr / self.div_factor;
+
+        OneCycleLrScheduler {
+            initial_lr,
+            max_lr: self.max_lr,
+            min_lr: initial_lr / self.final_div_factor,
+            warmup_end: (self.pct_start * self.num_iters as f64 - 1.0).max(0.0),
+            num_iters: self.num_iters,
+            anneal_strategy: self.anneal_strategy,
+            momentum: self
+                .cycle_momentum
+                .then_some((self.base_momentum, self.max_momentum)),
+            current_iter: 0,
+        }
+    }
+}
+
+/// A one-cycle learning rate scheduler.
+///
+/// See [OneCycleLrSchedulerConfig] for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct OneCycleLrScheduler {
+    initial_lr: LearningRate,
+    max_lr: LearningRate,
+    min_lr: LearningRate,
+    // The iteration at which the maximum learning rate is reached.
+    warmup_end: f64,
+    num_iters: usize,
+    anneal_strategy: AnnealStrategy,
+    // The base and maximum momentums, if the momentum is cycled.
+    momentum: Option<(f64, f64)>,
+    // The number of steps already performed.
+    current_iter: usize,
+}
+
+impl OneCycleLrScheduler {
+    /// The momentum paired with the learning rate of the last step, if the momentum is cycled.
+    ///
+    /// It is meant for optimizers with a momentum, such as the `beta_1` of Adam. Before the first
+    /// step, it is the maximum momentum.
+    pub fn momentum(&self) -> Option<f64> {
+        let (base_momentum, max_momentum) = self.momentum?;
+        let (warmup, pct) = self.progress(self.current_iter.saturating_sub(1));
+
+        Some(match warmup {
+            true => self
+                .anneal_strategy
+                .anneal(max_momentum, base_momentum, pct),
+            false => self
+                .anneal_strategy
+                .anneal(base_momentum, max_momentum, pct),
+        })
+    }
+
+    /// Returns if the iteration is part of the warmup, and the progress of its phase.
+    fn progress(&self, iter: usize) -> (bool, f64) {
+        let iter = iter.min(self.num_iters - 1) as f64;
+        let last = (self.num_iters - 1) as f64;
+
+        if iter <= self.warmup_end {
+            let pct = match self.warmup_end > 0.0 {
+                true => iter / self.warmup_end,
+                false => 1.0,
+            };
+            (true, pct)
+        } else {
+            (false, (iter - self.warmup_end) / (last - self.warmup_end))
+        }
+    }
+}
+
+impl<B: Backend> LrScheduler<B> for OneCycleLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let (warmup, pct) = self.progress(self.current_iter);
+        self.current_iter = usize::min(self.current_iter + 1, self.num_iters);
+
+        match warmup {
+            true => self
+                .anneal_strategy
+                .anneal(self.initial_lr, self.max_lr, pct),
+            false => self.anneal_strategy.anneal(self.max_lr, self.min_lr, pct),
+        }
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.current_iter
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.current_iter = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Maximum learning rate must be greater than 0 and at most 1"]
+    fn config_max_lr_too_high() {
+        OneCycleLrSchedulerConfig::new(1.5, 10).init();
+    }
+
+    #[test]
+    #[should_panic = "Number of iterations must be at least 1"]
+    fn config_num_iters_too_low() {
+        OneCycleLrSchedulerConfig::new(0.5, 0).init();
+    }
+
+    #[test]
+    #[should_panic = "Warmup fraction must be at least 0 and at most 1"]
+    fn config_pct_start_too_high() {
+        OneCycleLrSchedulerConfig::new(0.5, 10)
+            .with_pct_start(1.5)
+            .init();
+    }
+
+    #[test]
+    #[should_panic = "Base momentum must be at least 0 and at most equal to the maximum momentum"]
+    fn config_base_momentum_too_high() {
+        OneCycleLrSchedulerConfig::new(0.5, 10)
+            .with_cycle_momentum(true)
+            .with_base_momentum(0.99)
+            .init();
+    }
+
+    #[test]
+    fn test_lr_change() {
+        const MAX_LR: LearningRate = 0.5;
+        const NUM_ITERS: usize = 20;
+
+        let mut scheduler = OneCycleLrSchedulerConfig::new(MAX_LR, NUM_ITERS)
+            .with_pct_start(0.25)
+            .init();
+
+        let lrs: Vec<LearningRate> = (0..NUM_ITERS + 2)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect();
+
+        assert!(
+            (lrs[0] - MAX_LR / 25.0).abs() < 1e-12,
+            "Should start at the initial learning rate"
+        );
+        assert!(
+            lrs[..5].windows(2).all(|lrs| lrs[0] < lrs[1]),
+            "Learning rate should increase during the warmup"
+        );
+        assert!(
+            (lrs[4] - MAX_LR).abs() < 1e-12,
+            "Should reach the maximum learning rate"
+        );
+        assert!(
+            lrs[4..NUM_ITERS].windows(2).all(|lrs| lrs[0] > lrs[1]),
+            "Learning rate should decrease after the warmup"
+        );
+        assert!(
+            (lrs[NUM_ITERS - 1] - MAX_LR / 25.0 / 1e4).abs() < 1e-12,
+            "Should reach the final learning rate"
+        );
+        assert_eq!(
+            lrs[NUM_ITERS - 1..],
+            [lrs[NUM_ITERS - 1]; 3],
+            "Learning rate should remain constant after the cycle"
+        );
+    }
+
+    #[test]
+    fn test_linear_lr_and_momentum_change() {
+        let mut scheduler = OneCycleLrSchedulerConfig::new(0.5, 5)
+            .with_pct_start(0.4)
+            .with_div_factor(5.0)
+            .with_final_div_factor(1.0)
+            .with_anneal_strategy(AnnealStrategy::Linear)
+            .with_cycle_momentum(true)
+            .with_base_momentum(0.8)
+            .with_max_momentum(0.9)
+            .init();
+
+        let mut lrs = Vec::new();
+        let mut momentums = Vec::new();
+        for _ in 0..5 {
+            lrs.push(LrScheduler::<TestBackend>::step(&mut scheduler));
+            momentums.push(scheduler.momentum().unwrap());
+        }
+
+        let expected_lrs = [0.1, 0.5, 0.366_666, 0.233_333, 0.1];
+        let expected_momentums = [0.9, 0.8, 0.833_333, 0.866_666, 0.9];
+        for (actual, expected) in lrs.iter().zip(expected_lrs) {
+            assert!((actual - expected).abs() < 1e-5, "{lrs:?}");
+        }
+        for (actual, expected) in momentums.iter().zip(expected_momentums) {
+            assert!((actual - expected).abs() < 1e-5, "{momentums:?}");
+        }
+    }
+
+    #[test]
+    fn test_load_record() {
+        let mut scheduler = OneCycleLrSchedulerConfig::new(0.5, 10).init();
+        for _ in 0..4 {
+            LrScheduler::<TestBackend>::step(&mut scheduler);
+        }
+        let record = LrScheduler::<TestBackend>::to_record(&scheduler);
+        let expected = LrScheduler::<TestBackend>::step(&mut scheduler);
+
+        let mut scheduler = LrScheduler::<TestBackend>::load_record(
+            OneCycleLrSchedulerConfig::new(0.5, 10).init(),
+            record,
+        );
+
+        assert_eq!(LrScheduler::<TestBackend>::step(&mut scheduler), expected);
+    }
+}
@@ -125,6 +125,16 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
         )
     }
 
+    /// Quantize each float tensor of the module with the [quantizer](super::Quantizer), replacing
+    /// it with the dequantized values.
+    ///
+    /// The parameters of each tensor are computed from the range of its values, and recorded in
+    /// the quantizer.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn quantize_weights(self, quantizer: &mut super::Quantizer) -> Self {
+        self.map(quantizer)
+    }
+
     /// Get the number of parameters the module has, including all of its sub-modules.
     fn num_params(&self) -> usize {
         module!(
@@ -136,6 +146,33 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
             init = || 0
         )
     }
+
+    /// Compute a deterministic checksum of all the tensors in the module, including all of its
+    /// sub-modules.
+    ///
+    /// The checksum doesn't depend on the parameter ids, so it can be compared between processes
+    /// or machines, e.g. to check that the replicas of a model haven't diverged during distributed
+    /// training without transferring the weights. See [Checksum](super::Checksum) for details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn checksum(&self) -> u64 {
+        let mut checksum = super::Checksum::new();
+        self.visit(&mut checksum);
+
+        checksum.digest()
+    }
+
+    /// Summarize the module for an input of the given shape, listing its submodules with their
+    /// output shape, number of parameters and estimated number of floating point operations.
+    ///
+    /// The shapes are propagated through the module tree without running the forward pass, see
+    /// [ModuleSummary](super::ModuleSummary) for details.
+    fn summary(&self, input_shape: &[usize]) -> super::ModuleSummary
+    where
+        Self: super::ShapePropagation + super::ModuleDisplay,
+    {
+        super::ModuleSummary::new(self, input_shape)
+    }
+
     /// Visit each tensor parameter in the module with a [visitor](ModuleVisitor).
     fn visit<Visitor: ModuleVisitor<B>>(&self, visitor: &mut Visitor);
 
@@ -160,6 +197,7 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
     /// * [json compressed with gzip](crate::record::JsonGzFileRecorder)
     /// * [named mpk](crate::record::NamedMpkFileRecorder)
     /// * [named mpk compressed with gzip](crate::record::NamedMpkGzFileRecorder)
+    /// * [named mpk split into shards](crate::record::ShardedFileRecorder)
     ///
     /// ## Notes
     ///
@@ -202,10 +240,44 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
 
         Ok(self.load_record(record))
     }
+
+    #[cfg(feature = "std")]
+    /// Load the module partially from a file using the provided
+    /// [file recorder](crate::record::FileRecorder).
+    ///
+    /// Parameters found in the file with a matching shape are loaded, the other ones keep their
+    /// current values. The returned [report](crate::record::LoadReport) lists the missing,
+    /// unexpected and mismatched keys, which is useful when fine-tuning a modified architecture.
+    ///
+    /// ## Notes
+    ///
+    /// Only self-describing formats support partial loading, such as
+    /// [named mpk](crate::record::NamedMpkFileRecorder) or
+    /// [pretty json](crate::record::PrettyJsonFileRecorder).
+    fn load_file_partial<FR, PB>(
+        self,
+        file_path: PB,
+        recorder: &FR,
+        device: &B::Device,
+    ) -> Result<(Self, crate::record::LoadReport), crate::record::RecorderError>
+    where
+        FR: crate::record::FileRecorder<B>,
+        PB: Into<std::path::PathBuf>,
+    {
+        let (record, report) =
+            recorder.load_partial(file_path.into(), self.clone().into_record(), device)?;
+
+        Ok((self.load_record(record), report))
+    }
 }
 
 /// Module visitor trait.
 pub trait ModuleVisitor<B: Backend> {
+    /// Called before visiting a submodule or a parameter, given its field name, or its index in
+    /// a collection, so that the visitor can track its path in the module tree.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after visiting the submodule or the parameter entered with the same name.
+    fn exit_module(&mut self, _name: &str) {}
     /// Visit a float tensor in the module.
     fn visit_float<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D>) {}
     /// Visit an int tensor in the module.
@@ -216,6 +288,11 @@ pub trait ModuleVisitor<B: Backend> {
 
 /// Module mapper trait.
 pub trait ModuleMapper<B: Backend> {
+    /// Called before mapping a submodule or a parameter, given its field name, or its index in
+    /// a collection, so that the mapper can track its path in the module tree.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after mapping the submodule or the parameter entered with the same name.
+    fn exit_module(&mut self, _name: &str) {}
     /// Map a float tensor in the module.
     fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
         tensor
@@ -0,0 +1,89 @@
+use xxhash_rust::xxh64::Xxh64;
+
+use super::{ModuleVisitor, ParamId};
+use crate::tensor::{backend::Backend, Bool, Int, Tensor};
+
+/// Module visitor combining the [checksums](Tensor::checksum) of all the tensors of a module.
+///
+/// The tensors are combined in the order they are visited, but their parameter ids are ignored,
+/// so the same module has the same checksum in different processes.
+#[derive(Clone)]
+pub struct Checksum {
+    hasher: Xxh64,
+}
+
+impl Checksum {
+    /// Create a new checksum visitor.
+    pub fn new() -> Self {
+        Self {
+            hasher: Xxh64::new(0),
+        }
+    }
+
+    /// Returns the checksum of all the visited tensors.
+    pub fn digest(&self) -> u64 {
+        self.hasher.digest()
+    }
+
+    fn update(&mut self, checksum: u64) {
+        self.hasher.update(&checksum.to_le_bytes());
+    }
+}
+
+impl Default for Checksum {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Checksum {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Checksum")
+            .field("digest", &self.digest())
+            .finish()
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for Checksum {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        self.update(tensor.checksum());
+    }
+
+    fn visit_int<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Int>) {
+        self.update(tensor.checksum());
+    }
+
+    fn visit_bool<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Bool>) {
+        self.update(tensor.checksum());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module::Module;
+    use crate::nn::LinearConfig;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_have_the_same_checksum_for_the_same_weights() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 2).init::<TestBackend>(&device);
+        let copy = LinearConfig::new(4, 2)
+            .init::<TestBackend>(&device)
+            .load_record(linear.clone().into_record());
+
+        assert_eq!(linear.checksum(), copy.checksum());
+    }
+
+    #[test]
+    fn should_change_checksum_when_a_weight_changes() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 2).init::<TestBackend>(&device);
+        let checksum = linear.checksum();
+
+        let mut diverged = linear.clone();
+        diverged.weight = diverged.weight.map(|weight| weight.add_scalar(1e-6));
+
+        assert_ne!(checksum, diverged.checksum());
+    }
+}
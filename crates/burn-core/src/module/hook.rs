@@ -0,0 +1,408 @@
+use alloc::{string::String, sync::Arc, vec::Vec};
+use core::marker::PhantomData;
+
+use super::{
+    AutodiffModule, Content, Devices, DisplaySettings, Module, ModuleDisplay, ModuleDisplayDefault,
+    ModuleMapper, ModuleVisitor,
+};
+use crate::nn::{
+    conv::{Conv1d, Conv2d},
+    BatchNorm, Dropout, Embedding, Gelu, LayerNorm, Linear, Relu, RmsNorm,
+};
+use crate::tensor::backend::{AutodiffBackend, Backend};
+use crate::tensor::{Int, Tensor, TensorKind};
+
+/// The forward pass of a module for a given input type, used by [Hooked] to call the forward pass
+/// of the wrapped module with its hooks.
+pub trait Forward<I> {
+    /// The output of the forward pass.
+    type Output;
+
+    /// Applies the forward pass on the input.
+    fn forward(&self, input: I) -> Self::Output;
+}
+
+type ForwardHook<I, O> = Arc<dyn Fn(&I, &O) + Send + Sync>;
+type BackwardHook<O> = Arc<dyn Fn(O) -> O + Send + Sync>;
+
+/// A module calling hooks with the input and the output of its forward pass, and with the
+/// gradient of its output during the backward pass on autodiff backends, e.g. to log activations
+/// or extract features without changing the wrapped module.
+///
+/// The wrapper is transparent: it has the same parameters, record and display as the wrapped
+/// module, and the same `forward` method for the input type `I`, so it can replace it in a model
+/// without changing the forward pass of the model, while still loading the same records.
+///
+/// The hooks have the types of the input and the output of the forward pass, so a hook with other
+/// types is rejected when it is registered:
+///
+/// ```rust, compile_fail
+/// use burn_core::module::Hooked;
+/// use burn_core::nn::Linear;
+/// use burn_core::tensor::{backend::Backend, Tensor};
+///
+/// fn hook<B: Backend>(linear: Linear<B>) -> Hooked<Linear<B>, Tensor<B, 2>> {
+///     // The output of the forward pass of the linear module has 2 dimensions, not 3.
+///     Hooked::new(linear).register_forward_hook(|_input: &Tensor<B, 2>, _output: &Tensor<B, 3>| {})
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```rust, ignore
+/// #[derive(Module, Debug)]
+/// struct Model<B: Backend> {
+///     linear: Hooked<Linear<B>, Tensor<B, 2>>,
+/// }
+///
+/// let model = Model {
+///     linear: Hooked::new(linear).register_forward_hook(|input, output| {
+///         log::info!("{:?} -> {:?}", input.dims(), output.dims());
+///     }),
+/// };
+///
+/// // In the forward pass of the model, unchanged.
+/// let output = self.linear.forward(input);
+/// ```
+pub struct Hooked<M: Forward<I>, I> {
+    /// The wrapped module.
+    pub inner: M,
+    forward_hooks: Vec<ForwardHook<I, M::Output>>,
+    backward_hooks: Vec<BackwardHook<M::Output>>,
+    _input: PhantomData<fn(I)>,
+}
+
+impl<M: Forward<I>, I> Hooked<M, I> {
+    /// Wrap a module, without any hook.
+    pub fn new(inner: M) -> Self {
+        Self {
+            inner,
+            forward_hooks: Vec::new(),
+            backward_hooks: Vec::new(),
+            _input: PhantomData,
+        }
+    }
+
+    /// Register a hook called with the input and the output of the forward pass, after the
+    /// forward pass.
+    pub fn register_forward_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&I, &M::Output) + Send + Sync + 'static,
+    {
+        self.forward_hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Register a hook called with the gradient of the output of the forward pass, during the
+    /// backward pass.
+    ///
+    /// The hook is only called on autodiff backends, when the output is tracked. See
+    /// [register_grad_hook](Tensor::register_grad_hook).
+    pub fn register_backward_hook<B, const D: usize, F>(mut self, hook: F) -> Self
+    where
+        B: Backend,
+        M: Forward<I, Output = Tensor<B, D>>,
+        F: Fn(Tensor<B, D>) + Send + Sync + 'static,
+    {
+        let hook = Arc::new(hook);
+
+        self.backward_hooks
+            .push(Arc::new(move |output: Tensor<B, D>| {
+                let hook = hook.clone();
+                output.register_grad_hook(move |grad| hook(grad))
+            }));
+        self
+    }
+
+    /// Remove all the hooks.
+    pub fn clear_hooks(mut self) -> Self {
+        self.forward_hooks.clear();
+        self.backward_hooks.clear();
+        self
+    }
+
+    /// Unwrap the module.
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+
+    /// Applies the forward pass of the wrapped module, calling the hooks.
+    ///
+    /// The input is only cloned when there are forward hooks, to pass it to them after the
+    /// forward pass.
+    pub fn forward(&self, input: I) -> M::Output
+    where
+        I: Clone,
+    {
+        let hooked_input = (!self.forward_hooks.is_empty()).then(|| input.clone());
+        let mut output = self.inner.forward(input);
+
+        if let Some(input) = hooked_input {
+            for hook in self.forward_hooks.iter() {
+                hook(&input, &output);
+            }
+        }
+
+        for hook in self.backward_hooks.iter() {
+            output = hook(output);
+        }
+
+        output
+    }
+
+    fn map_inner(self, func: impl FnOnce(M) -> M) -> Self {
+        Self {
+            inner: func(self.inner),
+            forward_hooks: self.forward_hooks,
+            backward_hooks: self.backward_hooks,
+            _input: PhantomData,
+        }
+    }
+}
+
+impl<M: Forward<I> + Clone, I> Clone for Hooked<M, I> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            forward_hooks: self.forward_hooks.clone(),
+            backward_hooks: self.backward_hooks.clone(),
+            _input: PhantomData,
+        }
+    }
+}
+
+impl<M: Forward<I> + core::fmt::Debug, I> core::fmt::Debug for Hooked<M, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Hooked")
+            .field("inner", &self.inner)
+            .field("forward_hooks", &self.forward_hooks.len())
+            .field("backward_hooks", &self.backward_hooks.len())
+            .finish()
+    }
+}
+
+impl<B, M, I> Module<B> for Hooked<M, I>
+where
+    B: Backend,
+    M: Module<B> + Forward<I>,
+    I: Send + 'static,
+    M::Output: 'static,
+{
+    type Record = <M as Module<B>>::Record;
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        self.inner.collect_devices(devices)
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        self.map_inner(|inner| inner.fork(device))
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        self.map_inner(|inner| inner.to_device(device))
+    }
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.inner.visit(visitor)
+    }
+
+    fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self {
+        self.map_inner(|inner| inner.map(mapper))
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        self.map_inner(|inner| inner.load_record(record))
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.inner.into_record()
+    }
+}
+
+impl<B, M, K, const D: usize> AutodiffModule<B> for Hooked<M, Tensor<B, D, K>>
+where
+    B: AutodiffBackend,
+    K: TensorKind<B> + TensorKind<B::InnerBackend> + 'static,
+    M: AutodiffModule<B> + Forward<Tensor<B, D, K>>,
+    M::InnerModule: Forward<Tensor<B::InnerBackend, D, K>>,
+    M::Output: 'static,
+    <M::InnerModule as Forward<Tensor<B::InnerBackend, D, K>>>::Output: 'static,
+{
+    type InnerModule = Hooked<M::InnerModule, Tensor<B::InnerBackend, D, K>>;
+
+    fn valid(&self) -> Self::InnerModule {
+        // The hooks are specific to the backend of the module, so they are not kept.
+        Hooked::new(self.inner.valid())
+    }
+}
+
+impl<M: Forward<I> + ModuleDisplay, I> ModuleDisplayDefault for Hooked<M, I> {
+    fn content(&self, content: Content) -> Option<Content> {
+        self.inner.content(content)
+    }
+
+    fn num_params(&self) -> usize {
+        ModuleDisplayDefault::num_params(&self.inner)
+    }
+}
+
+impl<M: Forward<I> + ModuleDisplay, I> ModuleDisplay for Hooked<M, I> {
+    fn format(&self, passed_settings: DisplaySettings) -> String {
+        self.inner.format(passed_settings)
+    }
+}
+
+macro_rules! forward_same_dims {
+    ($($module:ty),*) => {
+        $(
+            impl<B: Backend, const D: usize> Forward<Tensor<B, D>> for $module {
+                type Output = Tensor<B, D>;
+
+                fn forward(&self, input: Tensor<B, D>) -> Self::Output {
+                    self.forward(input)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! forward_fixed_dims {
+    ($($module:ty: $input:ty => $output:ty),*) => {
+        $(
+            impl<B: Backend> Forward<$input> for $module {
+                type Output = $output;
+
+                fn forward(&self, input: $input) -> Self::Output {
+                    self.forward(input)
+                }
+            }
+        )*
+    };
+}
+
+forward_same_dims!(Linear<B>, LayerNorm<B>, RmsNorm<B>, Dropout, Relu, Gelu);
+forward_fixed_dims!(
+    Embedding<B>: Tensor<B, 2, Int> => Tensor<B, 3>,
+    Conv1d<B>: Tensor<B, 3> => Tensor<B, 3>,
+    Conv2d<B>: Tensor<B, 4> => Tensor<B, 4>
+);
+
+impl<B: Backend, const D: usize, const DI: usize> Forward<Tensor<B, DI>> for BatchNorm<B, D> {
+    type Output = Tensor<B, DI>;
+
+    fn forward(&self, input: Tensor<B, DI>) -> Self::Output {
+        self.forward(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::nn::LinearConfig;
+    use crate::tensor::TensorData;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use std::sync::Mutex;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear: Hooked<Linear<B>, Tensor<B, 2>>,
+        relu: Relu,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self.linear.forward(input);
+            self.relu.forward(x)
+        }
+    }
+
+    #[test]
+    fn test_forward_hooks_are_called_with_the_input_and_output() {
+        let device = Default::default();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let calls_clone = calls.clone();
+        let linear = LinearConfig::new(3, 2).init::<TestBackend>(&device);
+        let hooked = Hooked::new(linear.clone()).register_forward_hook(
+            move |input: &Tensor<TestBackend, 2>, output: &Tensor<TestBackend, 2>| {
+                calls_clone
+                    .lock()
+                    .unwrap()
+                    .push((input.dims(), output.clone().into_data()));
+            },
+        );
+        let input = Tensor::<TestBackend, 2>::ones([4, 3], &device);
+
+        let output = hooked.forward(input);
+
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, [4, 3]);
+        calls[0].1.assert_eq(&output.into_data(), true);
+        assert_eq!(Module::num_params(&hooked), Module::num_params(&linear));
+    }
+
+    #[test]
+    fn test_forward_hooks_are_called_by_the_unchanged_forward_pass_of_a_model() {
+        let device = Default::default();
+        let outputs = Arc::new(Mutex::new(Vec::new()));
+        let outputs_clone = outputs.clone();
+        let linear = LinearConfig::new(3, 2).init::<TestBackend>(&device);
+        let model = Model {
+            linear: Hooked::new(linear.clone()).register_forward_hook(move |_input, output| {
+                outputs_clone
+                    .lock()
+                    .unwrap()
+                    .push(output.clone().into_data());
+            }),
+            relu: Relu::new(),
+        };
+        let input = Tensor::<TestBackend, 2>::ones([4, 3], &device);
+
+        model.forward(input.clone());
+
+        let outputs = outputs.lock().unwrap();
+        assert_eq!(outputs.len(), 1);
+        outputs[0].assert_eq(&linear.forward(input).into_data(), true);
+    }
+
+    #[test]
+    fn test_backward_hooks_are_called_with_the_output_gradient() {
+        let device = Default::default();
+        let grad = Arc::new(Mutex::new(None));
+        let grad_clone = grad.clone();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(2, 2).init(&device);
+        let hooked = Hooked::new(linear).register_backward_hook(
+            move |output_grad: Tensor<TestAutodiffBackend, 2>| {
+                *grad_clone.lock().unwrap() = Some(output_grad.into_data());
+            },
+        );
+        let input = Tensor::<TestAutodiffBackend, 2>::ones([1, 2], &device);
+
+        let output = hooked.forward(input);
+        output.mul_scalar(3.0).sum().backward();
+
+        grad.lock()
+            .unwrap()
+            .take()
+            .expect("The backward hook should be called.")
+            .assert_eq(&TensorData::from([[3.0f32, 3.0]]), false);
+    }
+
+    #[test]
+    fn test_hooked_module_has_the_record_of_the_wrapped_module() {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 2).init::<TestBackend>(&device);
+        let other = LinearConfig::new(2, 2).init::<TestBackend>(&device);
+
+        let hooked: Hooked<_, Tensor<TestBackend, 2>> =
+            Hooked::new(other).load_record(linear.clone().into_record());
+
+        hooked
+            .inner
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&linear.weight.val().into_data(), true);
+    }
+}
@@ -1,7 +1,23 @@
 mod base;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod checksum;
 mod display;
+mod hook;
 mod param;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod quantize;
+#[cfg(feature = "std")]
+mod sharded;
+mod summary;
 
 pub use base::*;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub use checksum::*;
 pub use display::*;
+pub use hook::*;
 pub use param::*;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub use quantize::*;
+#[cfg(feature = "std")]
+pub use sharded::*;
+pub use summary::*;
@@ -1,6 +1,7 @@
 mod base;
 mod constant;
 mod id;
+mod parametrization;
 mod primitive;
 mod running;
 mod tensor;
@@ -9,5 +10,6 @@ mod visitor;
 pub use base::*;
 pub use constant::*;
 pub use id::*;
+pub use parametrization::*;
 pub use running::*;
 pub use visitor::*;
@@ -0,0 +1,377 @@
+use super::{Param, ParamId};
+use crate::module::{
+    AutodiffModule, Content, Devices, Ignored, Module, ModuleDisplay, ModuleDisplayDefault,
+    ModuleMapper, ModuleVisitor,
+};
+use crate::tensor::{
+    activation::softplus,
+    backend::{AutodiffBackend, Backend},
+    linalg::QrMode,
+    Tensor,
+};
+
+/// A transformation constraining a parameter, e.g. to be orthogonal or positive, while the
+/// optimizer updates an unconstrained tensor.
+///
+/// The parameter is stored as the unconstrained tensor, and its constrained value is computed by
+/// the [forward](Parametrization::forward) transformation each time it is used, so the gradients
+/// flow through the transformation. See [Parametrized].
+pub trait Parametrization: Clone + core::fmt::Debug + Send + Sync {
+    /// Compute the constrained value from the unconstrained tensor.
+    fn forward<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D>;
+
+    /// Compute an unconstrained tensor whose [forward](Parametrization::forward) transformation
+    /// is the given constrained value, used to parametrize an existing parameter.
+    fn right_inverse<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D>;
+}
+
+/// A float parameter constrained by a [parametrization](Parametrization).
+///
+/// The unconstrained tensor is the parameter visited by the optimizers and saved in the record,
+/// with the id of the parametrized parameter, while [val](Parametrized::val) returns the
+/// constrained value. Since the record holds the unconstrained tensor, constrained values, e.g.
+/// the record of a parameter before it was parametrized, should be loaded with
+/// [load_constrained](Parametrized::load_constrained).
+///
+/// Should be created with [parametrize](Param::parametrize).
+///
+/// # Example
+///
+/// ```rust, ignore
+/// #[derive(Module, Debug)]
+/// struct Rotation<B: Backend> {
+///     weight: Parametrized<B, Orthogonal, 2>,
+/// }
+///
+/// let weight = Initializer::Orthogonal { gain: 1.0 }.init([4, 4], &device);
+/// let rotation = Rotation { weight: weight.parametrize(Orthogonal) };
+///
+/// let output = input.matmul(rotation.weight.val());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Parametrized<B: Backend, P, const D: usize> {
+    /// The unconstrained tensor, updated by the optimizers.
+    pub unconstrained: Param<Tensor<B, D>>,
+    parametrization: Ignored<P>,
+}
+
+impl<B: Backend, const D: usize> Param<Tensor<B, D>> {
+    /// Constrain the parameter with the given parametrization, keeping its id and its value,
+    /// which should satisfy the constraint.
+    pub fn parametrize<P: Parametrization>(self, parametrization: P) -> Parametrized<B, P, D> {
+        let unconstrained = self.map(|tensor| {
+            let require_grad = tensor.is_require_grad();
+
+            parametrization
+                .right_inverse(tensor)
+                .detach()
+                .set_require_grad(require_grad)
+        });
+
+        Parametrized::from_unconstrained(unconstrained, parametrization)
+    }
+}
+
+impl<B: Backend, P: Parametrization, const D: usize> Parametrized<B, P, D> {
+    /// Constrain the parameter with the given parametrization, the parameter being the
+    /// unconstrained tensor.
+    pub fn from_unconstrained(unconstrained: Param<Tensor<B, D>>, parametrization: P) -> Self {
+        Self {
+            unconstrained,
+            parametrization: Ignored(parametrization),
+        }
+    }
+
+    /// The constrained value of the parameter.
+    pub fn val(&self) -> Tensor<B, D> {
+        self.parametrization.forward(self.unconstrained.val())
+    }
+
+    /// The parametrization constraining the parameter.
+    pub fn parametrization(&self) -> &P {
+        &self.parametrization
+    }
+
+    /// The id of the parameter, the one of the unconstrained tensor.
+    pub fn id(&self) -> &ParamId {
+        &self.unconstrained.id
+    }
+
+    /// Load a constrained value, e.g. from the record of the parameter before it was
+    /// parametrized.
+    pub fn load_constrained(self, record: Param<Tensor<B, D>>) -> Self {
+        let parametrization = self.parametrization.0;
+        let record = record.map(|tensor| parametrization.right_inverse(tensor).detach());
+
+        Self {
+            unconstrained: self.unconstrained.load_record(record),
+            parametrization: Ignored(parametrization),
+        }
+    }
+
+    /// Remove the parametrization, returning a parameter with the constrained value and the same
+    /// id.
+    pub fn into_param(self) -> Param<Tensor<B, D>> {
+        let value = self.val();
+        let require_grad = value.is_require_grad();
+
+        Param::initialized(
+            self.unconstrained.id,
+            value.detach().set_require_grad(require_grad),
+        )
+    }
+
+    fn map_unconstrained(
+        self,
+        func: impl FnOnce(Param<Tensor<B, D>>) -> Param<Tensor<B, D>>,
+    ) -> Self {
+        Self {
+            unconstrained: func(self.unconstrained),
+            parametrization: self.parametrization,
+        }
+    }
+}
+
+impl<B: Backend, P: Parametrization, const D: usize> Module<B> for Parametrized<B, P, D> {
+    type Record = Param<Tensor<B, D>>;
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        self.unconstrained.collect_devices(devices)
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        self.map_unconstrained(|param| param.fork(device))
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        self.map_unconstrained(|param| param.to_device(device))
+    }
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.unconstrained.visit(visitor)
+    }
+
+    fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        self.map_unconstrained(|param| Module::map(param, mapper))
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        self.map_unconstrained(|param| param.load_record(record))
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.unconstrained
+    }
+}
+
+impl<B: AutodiffBackend, P: Parametrization, const D: usize> AutodiffModule<B>
+    for Parametrized<B, P, D>
+{
+    type InnerModule = Parametrized<B::InnerBackend, P, D>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Parametrized {
+            unconstrained: self.unconstrained.valid(),
+            parametrization: self.parametrization.clone(),
+        }
+    }
+}
+
+impl<B: Backend, P: Parametrization, const D: usize> ModuleDisplayDefault
+    for Parametrized<B, P, D>
+{
+    fn content(&self, content: Content) -> Option<Content> {
+        content
+            .add("unconstrained", &self.unconstrained)
+            .add("parametrization", &self.parametrization)
+            .optional()
+    }
+
+    fn num_params(&self) -> usize {
+        Module::num_params(&self.unconstrained)
+    }
+}
+
+impl<B: Backend, P: Parametrization, const D: usize> ModuleDisplay for Parametrized<B, P, D> {}
+
+/// Constrain matrices to be orthogonal, or to have orthonormal columns or rows when they are not
+/// square, with the `Q` factor of their [QR decomposition](Tensor::qr).
+///
+/// The last two dimensions are the matrices, the other ones are batch dimensions. The parameter
+/// should be orthogonal when it is [parametrized](Param::parametrize), e.g. initialized with
+/// [Initializer::Orthogonal](crate::nn::Initializer::Orthogonal), since it is its own
+/// unconstrained tensor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Orthogonal;
+
+impl Parametrization for Orthogonal {
+    fn forward<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        assert!(D >= 2, "Only matrices can be orthogonal, got {D}D tensors.");
+        let dims = tensor.dims();
+
+        // Orthonormal rows are the orthonormal columns of the transposed matrices.
+        if dims[D - 2] < dims[D - 1] {
+            return self.forward(tensor.transpose()).transpose();
+        }
+
+        tensor.qr(QrMode::Reduced).0
+    }
+
+    fn right_inverse<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        tensor
+    }
+}
+
+/// Constrain the elements to be positive with the [softplus](softplus) function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Positive;
+
+impl Parametrization for Positive {
+    fn forward<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        softplus(tensor, 1.0)
+    }
+
+    fn right_inverse<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        // log(exp(y) - 1), written to avoid overflowing for large values.
+        tensor.clone() + tensor.neg().exp().neg().log1p()
+    }
+}
+
+/// Constrain the slices along a dimension to have a unit L2 norm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitNorm {
+    /// The dimension along which the norm is computed.
+    pub dim: usize,
+    /// A value required for numerical stability.
+    pub epsilon: f64,
+}
+
+impl UnitNorm {
+    /// Constrain the slices along the given dimension, with an epsilon of `1e-12`.
+    pub fn new(dim: usize) -> Self {
+        Self {
+            dim,
+            epsilon: 1e-12,
+        }
+    }
+}
+
+impl Parametrization for UnitNorm {
+    fn forward<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let norm = tensor
+            .clone()
+            .powf_scalar(2.0)
+            .sum_dim(self.dim)
+            .sqrt()
+            .add_scalar(self.epsilon);
+
+        tensor.div(norm)
+    }
+
+    fn right_inverse<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{activation::sigmoid, TensorData};
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[test]
+    fn test_orthogonal_parametrization() {
+        let device = Default::default();
+        let param = Param::<Tensor<TestBackend, 2>>::from_data(
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]],
+            &device,
+        );
+
+        let parametrized = Parametrized::from_unconstrained(param, Orthogonal);
+        let weight = parametrized.val();
+
+        weight
+            .clone()
+            .transpose()
+            .matmul(weight.clone())
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+        // The parametrization of an orthogonal parameter keeps its value.
+        Param::from_tensor(weight.clone())
+            .parametrize(Orthogonal)
+            .val()
+            .into_data()
+            .assert_approx_eq(&weight.into_data(), 3);
+    }
+
+    #[test]
+    fn test_positive_and_unit_norm_parametrizations() {
+        let device = Default::default();
+        let param = Param::<Tensor<TestBackend, 2>>::from_data([[0.5, 2.0], [3.0, 4.0]], &device);
+
+        let positive = param.clone().parametrize(Positive);
+        let unit_norm = param.clone().parametrize(UnitNorm::new(1));
+
+        positive
+            .val()
+            .into_data()
+            .assert_approx_eq(&param.val().into_data(), 3);
+        assert_eq!(positive.id(), &param.id);
+        positive
+            .unconstrained
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-0.4328, 1.8546], [2.9489, 3.9815]]), 3);
+        unit_norm
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.2425, 0.9701], [0.6, 0.8]]), 3);
+    }
+
+    #[test]
+    fn test_parametrized_gradients_flow_to_the_unconstrained_tensor() {
+        let device = Default::default();
+        let parametrized = Param::<Tensor<TestAutodiffBackend, 1>>::from_data([1.0, 2.0], &device)
+            .parametrize(Positive);
+
+        let grads = parametrized.val().sum().backward();
+        let grad = parametrized.unconstrained.grad(&grads).unwrap();
+
+        // The gradient of softplus is the sigmoid of the unconstrained tensor.
+        let expected = sigmoid(parametrized.unconstrained.val().inner());
+        grad.into_data().assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_parametrized_record_holds_the_unconstrained_tensor() {
+        let device = Default::default();
+        let param = Param::<Tensor<TestBackend, 1>>::from_data([1.0, 2.0], &device);
+        let parametrized = param.clone().parametrize(Positive);
+        let other =
+            Param::<Tensor<TestBackend, 1>>::from_data([5.0, 5.0], &device).parametrize(Positive);
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder
+            .record(parametrized.clone().into_record(), ())
+            .unwrap();
+        let record = recorder.load(bytes, &device).unwrap();
+        let loaded = other.clone().load_record(record);
+        let constrained = other.load_constrained(param.clone());
+
+        loaded
+            .val()
+            .into_data()
+            .assert_approx_eq(&param.val().into_data(), 3);
+        constrained
+            .val()
+            .into_data()
+            .assert_approx_eq(&param.val().into_data(), 3);
+        assert_eq!(loaded.id(), &param.id);
+        loaded
+            .into_param()
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 2.0]), 3);
+    }
+}
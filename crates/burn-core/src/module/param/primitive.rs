@@ -3,7 +3,7 @@ use crate::module::{
     ModuleVisitor,
 };
 
-use alloc::{format, vec::Vec};
+use alloc::{format, string::ToString, vec::Vec};
 
 use burn_tensor::backend::{AutodiffBackend, Backend};
 use core::fmt::Debug;
@@ -64,6 +64,10 @@ impl<T: ModuleDisplay> ModuleDisplayDefault for Option<T> {
             None => content.add_single("None").optional(),
         }
     }
+
+    fn num_params(&self) -> usize {
+        self.as_ref().map(|module| module.num_params()).unwrap_or(0)
+    }
 }
 
 impl<T: ModuleDisplay> ModuleDisplay for Option<T> {}
@@ -97,13 +101,25 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = i.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        self.into_iter().map(|module| module.map(mapper)).collect()
+        self.into_iter()
+            .enumerate()
+            .map(|(i, module)| {
+                let name = i.to_string();
+                mapper.enter_module(&name);
+                let module = module.map(mapper);
+                mapper.exit_module(&name);
+                module
+            })
+            .collect()
     }
 
     fn into_record(self) -> Self::Record {
@@ -155,6 +171,10 @@ impl<T: ModuleDisplay> ModuleDisplayDefault for Vec<T> {
             .set_top_level_type(format!("Vec<0..{}>", self.len()).as_str())
             .optional()
     }
+
+    fn num_params(&self) -> usize {
+        self.iter().map(|module| module.num_params()).sum()
+    }
 }
 
 impl<T: ModuleDisplay> ModuleDisplay for Vec<T> {}
@@ -197,13 +217,25 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = i.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        self.map(|module| module.map(mapper))
+        let mut i = 0;
+
+        self.map(|module| {
+            let name = i.to_string();
+            mapper.enter_module(&name);
+            let module = module.map(mapper);
+            mapper.exit_module(&name);
+            i += 1;
+            module
+        })
     }
 
     fn load_record(self, record: Self::Record) -> Self {
@@ -239,6 +271,10 @@ impl<const N: usize, T: ModuleDisplay> ModuleDisplayDefault for [T; N] {
             .set_top_level_type(format!("[0..{}]", self.len()).as_str())
             .optional()
     }
+
+    fn num_params(&self) -> usize {
+        self.iter().map(|module| module.num_params()).sum()
+    }
 }
 
 impl<const N: usize, T: ModuleDisplay> ModuleDisplay for [T; N] {}
@@ -288,11 +324,22 @@ macro_rules! impl_module_tuple {
             }
 
             fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-                $(self.$i.visit(visitor);)*
+                $(
+                    visitor.enter_module(stringify!($i));
+                    self.$i.visit(visitor);
+                    visitor.exit_module(stringify!($i));
+                )*
             }
 
             fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-                ($(self.$i.map(mapper),)*)
+                ($(
+                    {
+                        mapper.enter_module(stringify!($i));
+                        let module = self.$i.map(mapper);
+                        mapper.exit_module(stringify!($i));
+                        module
+                    },
+                )*)
             }
 
             fn load_record(self, record: Self::Record) -> Self {
@@ -326,6 +373,10 @@ macro_rules! impl_module_tuple {
                     .set_top_level_type(format!("({})", stringify!($($l),*)).as_str());
                 content.optional()
             }
+
+            fn num_params(&self) -> usize {
+                0 $(+ self.$i.num_params())*
+            }
         }
 
         impl<$($l,)*> ModuleDisplay for ($($l,)*) where $($l: ModuleDisplay,)* {}
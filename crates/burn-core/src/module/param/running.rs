@@ -162,6 +162,11 @@ impl<const D: usize, B: Backend> RunningState<Tensor<B, D>> {
         }
     }
 
+    /// The id of the running state.
+    pub fn id(&self) -> &ParamId {
+        &self.id
+    }
+
     /// Update the value on the current thread.
     pub fn update(&self, value: Tensor<B, D>) {
         let thread_id = get_thread_current_id();
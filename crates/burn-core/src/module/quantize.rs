@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+
+use super::{ModuleMapper, ParamId};
+use crate::tensor::{
+    backend::Backend,
+    quantization::{QuantizationParameters, QuantizationScheme},
+    Tensor,
+};
+
+/// Module mapper quantizing each float tensor of a module with the range of its own values, and
+/// replacing it with the dequantized values, for post-training quantization.
+///
+/// The quantization parameters computed for each tensor are recorded with their parameter id,
+/// e.g. to store the quantized values.
+#[derive(new, Debug, Clone)]
+pub struct Quantizer {
+    /// The quantization scheme.
+    pub scheme: QuantizationScheme,
+    /// The quantization parameters of each quantized tensor.
+    #[new(default)]
+    pub params: Vec<(ParamId, QuantizationParameters)>,
+}
+
+impl<B: Backend> ModuleMapper<B> for Quantizer {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let require_grad = tensor.is_require_grad();
+        let quantized = tensor.quantize_dynamic(&self.scheme);
+        self.params.push((id.clone(), quantized.params));
+
+        quantized.dequantize().set_require_grad(require_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::nn::LinearConfig;
+    use crate::tensor::quantization::QuantizationType;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_quantize_module_weights() {
+        let device = Default::default();
+        let linear = LinearConfig::new(16, 8).init::<TestBackend>(&device);
+        let weight = linear.weight.val();
+        let mut quantizer = Quantizer::new(QuantizationScheme::PerTensorSymmetric(
+            QuantizationType::QInt8,
+        ));
+
+        let linear = linear.quantize_weights(&mut quantizer);
+
+        // The weight and the bias.
+        assert_eq!(quantizer.params.len(), 2);
+        assert_eq!(quantizer.params[0].0, linear.weight.id);
+        let scale = quantizer.params[0].1.scale;
+        let error = linear.weight.val().sub(weight).abs().max().into_scalar();
+        assert!(error <= scale / 2.0 + 1e-6, "Unexpected error {error}");
+    }
+}
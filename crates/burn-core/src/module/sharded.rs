@@ -0,0 +1,381 @@
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::collective::Collective;
+use crate::module::{
+    AutodiffModule, Content, Devices, Module, ModuleDisplay, ModuleDisplayDefault, ModuleMapper,
+    ModuleVisitor, Param, ParamId,
+};
+use crate::tensor::{
+    backend::{AutodiffBackend, Backend},
+    Tensor,
+};
+
+/// A module whose float tensors are sharded across the ranks of a [collective](Collective), as
+/// in fully sharded data parallelism (FSDP), so that models too large for the memory of a single
+/// device can be trained.
+///
+/// Each rank only holds a shard of each tensor, flattened, and the full module is
+/// [gathered](Sharded::gather) from the shards of all the ranks before being used, e.g. around
+/// the forward pass of each layer. The shards are the parameters visited by the optimizers and
+/// saved in the record of each rank, so the optimizer states are sharded as well. During the
+/// backward pass, the gradients of the gathered tensors are reduced across the ranks, each rank
+/// receiving the mean of the gradients of its shards, so the backward pass must be executed by
+/// every rank.
+///
+/// The gathered tensors needed by the backward pass are kept until it is executed, so the
+/// memory used by a layer is only reduced between its forward and backward passes when it is
+/// gathered in its own `Sharded` module. The running states of the module, e.g. of a batch norm,
+/// are sharded like the parameters and aren't updated by the forward pass.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// #[derive(Module, Debug)]
+/// struct Model<B: Backend> {
+///     blocks: Vec<Sharded<B, TransformerEncoderLayer<B>>>,
+/// }
+///
+/// impl<B: Backend> Model<B> {
+///     fn forward(&self, mut x: Tensor<B, 3>) -> Tensor<B, 3> {
+///         for block in self.blocks.iter() {
+///             x = block.gather().forward(TransformerEncoderInput::new(x));
+///         }
+///         x
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sharded<B: Backend, M> {
+    /// The shard of each float tensor of the module held by the rank, in the order of the
+    /// tensors of the module, with the ids of the tensors.
+    pub shards: Vec<Param<Tensor<B, 1>>>,
+    module: M,
+    shapes: Vec<Vec<usize>>,
+    collective: Collective,
+}
+
+impl<B: Backend, M: Module<B>> Sharded<B, M> {
+    /// Shard the float tensors of the module across the ranks of the collective.
+    ///
+    /// Every rank must shard a module with the same structure, whose tensors are replaced by the
+    /// ones of the first rank before being sharded, so that all the ranks start from the same
+    /// module.
+    pub fn new(module: M, collective: &Collective) -> Self {
+        let mut sharder = Sharder {
+            collective,
+            shards: Vec::new(),
+            shapes: Vec::new(),
+        };
+        let module = module.map(&mut sharder);
+
+        Self {
+            shards: sharder.shards,
+            module,
+            shapes: sharder.shapes,
+            collective: collective.clone(),
+        }
+    }
+
+    /// Gather the full module from the shards of all the ranks, which must all call it.
+    pub fn gather(&self) -> M {
+        let world_size = self.collective.world_size() as f64;
+        let mut tensors = self
+            .shards
+            .iter()
+            .zip(self.shapes.iter())
+            .map(|(shard, shape)| {
+                let shard = shard.val();
+                // Average the gradients of the ranks instead of summing them, without changing the
+                // value of the shard.
+                let scaled = shard.clone().div_scalar(world_size);
+                let shard = scaled.clone() + (shard - scaled).detach();
+
+                let num_elements = shape.iter().product();
+                let tensor = self.collective.all_gather(shard, 0);
+
+                (tensor.narrow(0, 0, num_elements), shape)
+            });
+
+        self.module.clone().map(&mut Gatherer {
+            tensors: &mut tensors,
+        })
+    }
+
+    /// The collective the tensors are sharded across.
+    pub fn collective(&self) -> &Collective {
+        &self.collective
+    }
+
+    fn map_shards(
+        self,
+        func: impl FnOnce(Vec<Param<Tensor<B, 1>>>) -> Vec<Param<Tensor<B, 1>>>,
+    ) -> Self {
+        Self {
+            shards: func(self.shards),
+            module: self.module,
+            shapes: self.shapes,
+            collective: self.collective,
+        }
+    }
+}
+
+/// Replace each float tensor with a placeholder, keeping the shard of the rank.
+struct Sharder<'a, B: Backend> {
+    collective: &'a Collective,
+    shards: Vec<Param<Tensor<B, 1>>>,
+    shapes: Vec<Vec<usize>>,
+}
+
+impl<'a, B: Backend> ModuleMapper<B> for Sharder<'a, B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let device = tensor.device();
+        let is_require_grad = tensor.is_require_grad();
+        let rank = self.collective.rank();
+        let world_size = self.collective.world_size();
+
+        let num_elements = tensor.shape().num_elements();
+        let shard_size = num_elements.div_ceil(world_size);
+        self.shapes.push(tensor.dims().to_vec());
+
+        let mut tensor = self
+            .collective
+            .broadcast(tensor.detach().flatten::<1>(0, D - 1), 0);
+
+        // The tensor is padded, so that all the shards have the same size.
+        let padding = shard_size * world_size - num_elements;
+        if padding > 0 {
+            tensor = Tensor::cat(alloc::vec![tensor, Tensor::zeros([padding], &device)], 0);
+        }
+        let shard = tensor
+            .narrow(0, rank * shard_size, shard_size)
+            .detach()
+            .set_require_grad(is_require_grad);
+
+        self.shards.push(Param::initialized(id.clone(), shard));
+
+        Tensor::zeros([1; D], &device)
+    }
+}
+
+/// Replace each placeholder with the gathered tensor.
+struct Gatherer<'a, I> {
+    tensors: &'a mut I,
+}
+
+impl<'a, 'b, B, I> ModuleMapper<B> for Gatherer<'a, I>
+where
+    B: Backend,
+    I: Iterator<Item = (Tensor<B, 1>, &'b Vec<usize>)>,
+{
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, _tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let (tensor, shape) = self
+            .tensors
+            .next()
+            .expect("Should have a shard for each float tensor of the module.");
+        let mut dims = [0; D];
+        dims.copy_from_slice(shape);
+
+        tensor.reshape(dims)
+    }
+}
+
+impl<B: Backend, M: Module<B>> Module<B> for Sharded<B, M> {
+    type Record = <Vec<Param<Tensor<B, 1>>> as Module<B>>::Record;
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        self.shards.collect_devices(devices)
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        Self {
+            shards: self.shards.fork(device),
+            module: self.module.fork(device),
+            shapes: self.shapes,
+            collective: self.collective,
+        }
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            shards: self.shards.to_device(device),
+            module: self.module.to_device(device),
+            shapes: self.shapes,
+            collective: self.collective,
+        }
+    }
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.shards.visit(visitor)
+    }
+
+    fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self {
+        self.map_shards(|shards| shards.map(mapper))
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        self.map_shards(|shards| shards.load_record(record))
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.shards.into_record()
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> AutodiffModule<B> for Sharded<B, M> {
+    type InnerModule = Sharded<B::InnerBackend, M::InnerModule>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Sharded {
+            shards: self.shards.valid(),
+            module: self.module.valid(),
+            shapes: self.shapes.clone(),
+            collective: self.collective.clone(),
+        }
+    }
+}
+
+impl<B: Backend, M: Module<B>> ModuleDisplayDefault for Sharded<B, M> {
+    fn content(&self, content: Content) -> Option<Content> {
+        content
+            .add_formatted(&format!(
+                "rank: {}/{}",
+                self.collective.rank(),
+                self.collective.world_size()
+            ))
+            .optional()
+    }
+
+    fn num_params(&self) -> usize {
+        self.shapes
+            .iter()
+            .map(|shape| shape.iter().product::<usize>())
+            .sum()
+    }
+}
+
+impl<B: Backend, M: Module<B>> ModuleDisplay for Sharded<B, M> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::GradientsParams;
+    use crate::tensor::{Distribution, TensorData};
+    use crate::TestAutodiffBackend;
+    use alloc::vec;
+
+    #[test]
+    fn sharded_module_should_have_the_gradients_of_the_mean_of_the_losses_of_all_ranks() {
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 5).init::<TestAutodiffBackend>(&device);
+        let inputs = vec![
+            Tensor::<TestAutodiffBackend, 2>::random([2, 3], Distribution::Default, &device),
+            Tensor::<TestAutodiffBackend, 2>::random([4, 3], Distribution::Default, &device),
+        ];
+
+        // The gradients of the mean of the losses on a single device.
+        let loss = inputs
+            .iter()
+            .map(|input| linear.forward(input.clone()).sum())
+            .reduce(|a, b| a + b)
+            .unwrap()
+            .div_scalar(2.0);
+        let grads = loss.backward();
+        let expected = [
+            linear.weight.grad(&grads).unwrap().flatten::<1>(0, 1),
+            linear.bias.as_ref().unwrap().grad(&grads).unwrap(),
+        ];
+
+        let handles: Vec<_> = Collective::group(2)
+            .into_iter()
+            .zip(inputs)
+            .map(|(collective, input)| {
+                // Only the module of the first rank is kept.
+                let linear = match collective.rank() {
+                    0 => linear.clone(),
+                    _ => LinearConfig::new(3, 5).init(&device),
+                };
+
+                std::thread::spawn(move || {
+                    let sharded = Sharded::new(linear, &collective);
+                    let gathered = sharded.gather().forward(input);
+                    let grads = gathered.sum().backward();
+                    let mut grads = GradientsParams::from_grads(grads, &sharded);
+
+                    let grads: Vec<TensorData> = sharded
+                        .shards
+                        .iter()
+                        .map(|shard| {
+                            grads
+                                .remove::<<TestAutodiffBackend as AutodiffBackend>::InnerBackend, 1>(
+                                    &shard.id,
+                                )
+                                .unwrap()
+                                .into_data()
+                        })
+                        .collect();
+
+                    grads
+                })
+            })
+            .collect();
+        let results: Vec<_> = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        // The shards of the tensors of 15 and 5 elements, padded to 16 and 6 elements.
+        for (tensor, expected) in expected.into_iter().enumerate() {
+            let num_elements = expected.dims()[0];
+            let grad = Tensor::<TestAutodiffBackend, 1>::cat(
+                results
+                    .iter()
+                    .map(|grads| Tensor::from_data(grads[tensor].clone(), &device))
+                    .collect(),
+                0,
+            )
+            .narrow(0, 0, num_elements);
+
+            grad.into_data().assert_approx_eq(&expected.into_data(), 4);
+        }
+    }
+
+    #[test]
+    fn gathered_module_should_be_the_module_of_the_first_rank() {
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 5).init::<TestAutodiffBackend>(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([2, 3], Distribution::Default, &device);
+        let expected = linear.forward(input.clone()).into_data();
+
+        let handles: Vec<_> = Collective::group(3)
+            .into_iter()
+            .map(|collective| {
+                let linear: Linear<TestAutodiffBackend> = match collective.rank() {
+                    0 => linear.clone(),
+                    _ => LinearConfig::new(3, 5).init(&device),
+                };
+                let input = input.clone();
+
+                std::thread::spawn(move || {
+                    let sharded = Sharded::new(linear, &collective);
+                    assert_eq!(sharded.shards[0].dims(), [5]);
+                    // The shards of the tensors of 15 and 5 elements.
+                    assert_eq!(Module::num_params(&sharded), 7);
+
+                    let output = sharded.gather().forward(input.clone());
+                    let output_valid = sharded.valid().gather().forward(input.inner());
+
+                    (output.into_data(), output_valid.into_data())
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let (output, output_valid) = handle.join().unwrap();
+            output.assert_approx_eq(&expected, 5);
+            output_valid.assert_approx_eq(&expected, 5);
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter};
+
+use super::{extract_type_name, ModuleDisplay};
+
+/// Propagates the shape of the input of a module to the shape of its output, to build the
+/// [summary](ModuleSummary) of a module without running its forward pass.
+///
+/// Modules made of other modules propagate the shape through their submodules in the order of
+/// their forward pass with [layer](SummaryBuilder::layer), while the layers report the number of
+/// floating point operations of their forward pass with [add_flops](SummaryBuilder::add_flops).
+///
+/// # Example
+///
+/// ```rust, ignore
+/// impl<B: Backend> ShapePropagation for Model<B> {
+///     fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+///         let x = summary.layer("conv", &self.conv, input);
+///         let x = summary.layer("pool", &self.pool, &x);
+///         summary.layer("linear", &self.linear, &[x[0], x[1] * x[2] * x[3]])
+///     }
+/// }
+/// ```
+pub trait ShapePropagation {
+    /// Returns the shape of the output of the forward pass for an input of the given shape.
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize>;
+}
+
+/// A submodule in the [summary](ModuleSummary) of a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryRow {
+    /// The names of the fields leading to the submodule, separated by dots, empty for the
+    /// summarized module.
+    pub path: String,
+    /// The depth of the submodule in the module tree, zero for the summarized module.
+    pub depth: usize,
+    /// The type of the submodule.
+    pub ty: String,
+    /// The shape of the output of the submodule.
+    pub output_shape: Vec<usize>,
+    /// The number of parameters of the submodule, including the ones of its own submodules.
+    pub num_params: usize,
+    /// The estimated number of floating point operations of the forward pass of the submodule,
+    /// including the ones of its own submodules.
+    pub flops: u64,
+}
+
+/// Builds a [summary](ModuleSummary) by propagating the shapes through the module tree.
+#[derive(Debug, Default)]
+pub struct SummaryBuilder {
+    rows: Vec<SummaryRow>,
+    path: Vec<String>,
+    flops: Vec<u64>,
+}
+
+impl SummaryBuilder {
+    /// Propagates the shape through a submodule with the given name, adding it to the summary.
+    pub fn layer<M>(&mut self, name: &str, module: &M, input: &[usize]) -> Vec<usize>
+    where
+        M: ShapePropagation + ModuleDisplay,
+    {
+        self.path.push(name.to_string());
+        let output = self.record(module, input);
+        self.path.pop();
+
+        output
+    }
+
+    /// Adds floating point operations to the forward pass of the current submodule.
+    ///
+    /// The operations are usually counted as in the forward pass of a layer, a multiply-add
+    /// counting as two operations and element-wise operations as one per element.
+    pub fn add_flops(&mut self, flops: u64) {
+        if let Some(current) = self.flops.last_mut() {
+            *current += flops;
+        }
+    }
+
+    fn record<M>(&mut self, module: &M, input: &[usize]) -> Vec<usize>
+    where
+        M: ShapePropagation + ModuleDisplay,
+    {
+        let index = self.rows.len();
+        self.rows.push(SummaryRow {
+            path: self.path.join("."),
+            depth: self.path.len(),
+            ty: extract_type_name::<M>().to_string(),
+            output_shape: Vec::new(),
+            num_params: module.num_params(),
+            flops: 0,
+        });
+
+        self.flops.push(0);
+        let output = module.propagate_shape(input, self);
+        let flops = self.flops.pop().unwrap_or(0);
+        self.add_flops(flops);
+
+        let row = &mut self.rows[index];
+        row.output_shape = output.clone();
+        row.flops = flops;
+
+        output
+    }
+}
+
+/// Summary of a module, listing its submodules with their output shape, number of parameters
+/// and estimated number of floating point operations (FLOPs) for an input of a given shape,
+/// similar to [torchinfo](https://github.com/TylerYep/torchinfo).
+///
+/// The summary is built by a shape propagation pass over the module tree, see
+/// [ShapePropagation], and displayed as a table.
+#[derive(Debug, Clone)]
+pub struct ModuleSummary {
+    input_shape: Vec<usize>,
+    rows: Vec<SummaryRow>,
+}
+
+impl ModuleSummary {
+    /// Summarize a module for an input of the given shape.
+    pub fn new<M>(module: &M, input_shape: &[usize]) -> Self
+    where
+        M: ShapePropagation + ModuleDisplay,
+    {
+        let mut builder = SummaryBuilder::default();
+        builder.record(module, input_shape);
+
+        Self {
+            input_shape: input_shape.to_vec(),
+            rows: builder.rows,
+        }
+    }
+
+    /// The summarized module followed by its submodules, in the order of the forward pass.
+    pub fn rows(&self) -> &[SummaryRow] {
+        &self.rows
+    }
+
+    /// The shape of the output of the module.
+    pub fn output_shape(&self) -> &[usize] {
+        &self.rows[0].output_shape
+    }
+
+    /// The number of parameters of the module.
+    pub fn num_params(&self) -> usize {
+        self.rows[0].num_params
+    }
+
+    /// The estimated number of floating point operations of the forward pass of the module.
+    pub fn flops(&self) -> u64 {
+        self.rows[0].flops
+    }
+}
+
+impl Display for ModuleSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let names: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| match row.path.rsplit('.').next() {
+                Some(name) if row.depth > 0 => {
+                    format!("{}{name} ({})", "  ".repeat(row.depth - 1), row.ty)
+                }
+                _ => row.ty.clone(),
+            })
+            .collect();
+        let shapes: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| format!("{:?}", row.output_shape))
+            .collect();
+
+        let name_width = names.iter().map(String::len).max().unwrap_or(0).max(12) + 2;
+        let shape_width = shapes.iter().map(String::len).max().unwrap_or(0).max(12) + 2;
+        let line = "=".repeat(name_width + shape_width + 32);
+
+        writeln!(
+            f,
+            "{:<name_width$}{:<shape_width$}{:>16}{:>16}",
+            "Layer (type)", "Output Shape", "Param #", "FLOPs"
+        )?;
+        writeln!(f, "{line}")?;
+        for ((name, shape), row) in names.iter().zip(shapes.iter()).zip(self.rows.iter()) {
+            writeln!(
+                f,
+                "{name:<name_width$}{shape:<shape_width$}{:>16}{:>16}",
+                row.num_params, row.flops
+            )?;
+        }
+        writeln!(f, "{line}")?;
+        writeln!(f, "Input shape: {:?}", self.input_shape)?;
+        writeln!(f, "Total params: {}", self.num_params())?;
+        write!(f, "Total FLOPs: {}", self.flops())
+    }
+}
+
+impl<M> ShapePropagation for Vec<M>
+where
+    M: ShapePropagation + ModuleDisplay,
+{
+    /// Propagates the shape through the modules one after the other.
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        self.iter()
+            .enumerate()
+            .fold(input.to_vec(), |shape, (index, module)| {
+                summary.layer(&index.to_string(), module, &shape)
+            })
+    }
+}
+
+/// The number of elements of a tensor of the given shape, e.g. to count element-wise operations.
+pub(crate) fn num_elements(shape: &[usize]) -> u64 {
+    shape.iter().product::<usize>() as u64
+}
+
+/// The dimensions of a shape of the given rank, panicking with a clear message otherwise.
+pub(crate) fn shape_dims<const D: usize>(shape: &[usize]) -> [usize; D] {
+    shape.try_into().unwrap_or_else(|_| {
+        panic!(
+            "The input shape ({shape:?}) should have {D} dimensions to propagate it through the \
+             module."
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::Module;
+    use crate::nn::{
+        conv::{Conv2d, Conv2dConfig},
+        pool::{AdaptiveAvgPool2d, AdaptiveAvgPool2dConfig},
+        Linear, LinearConfig, Relu,
+    };
+    use crate::tensor::backend::Backend;
+    use crate::TestBackend;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        conv: Conv2d<B>,
+        activation: Relu,
+        pool: AdaptiveAvgPool2d,
+        layers: Vec<Linear<B>>,
+    }
+
+    impl<B: Backend> ShapePropagation for Model<B> {
+        fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+            let x = summary.layer("conv", &self.conv, input);
+            let x = summary.layer("activation", &self.activation, &x);
+            let x = summary.layer("pool", &self.pool, &x);
+            summary.layer("layers", &self.layers, &[x[0], x[1] * x[2] * x[3]])
+        }
+    }
+
+    fn model() -> Model<TestBackend> {
+        let device = Default::default();
+
+        Model {
+            conv: Conv2dConfig::new([3, 4], [3, 3]).init(&device),
+            activation: Relu::new(),
+            pool: AdaptiveAvgPool2dConfig::new([1, 1]).init(),
+            layers: vec![
+                LinearConfig::new(4, 5).init(&device),
+                LinearConfig::new(5, 2).init(&device),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_summary_propagates_shapes_through_the_module_tree() {
+        let summary = model().summary(&[2, 3, 8, 8]);
+
+        let rows: Vec<_> = summary
+            .rows()
+            .iter()
+            .map(|row| {
+                (
+                    row.path.as_str(),
+                    row.depth,
+                    row.output_shape.clone(),
+                    row.num_params,
+                    row.flops,
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("", 0, vec![2, 2], 149, 16262),
+                ("conv", 1, vec![2, 4, 6, 6], 112, 15552),
+                ("activation", 1, vec![2, 4, 6, 6], 0, 288),
+                ("pool", 1, vec![2, 4, 1, 1], 0, 288),
+                ("layers", 1, vec![2, 2], 37, 134),
+                ("layers.0", 2, vec![2, 5], 25, 90),
+                ("layers.1", 2, vec![2, 2], 12, 44),
+            ]
+        );
+        assert_eq!(summary.output_shape(), [2, 2]);
+        assert_eq!(summary.num_params(), model().num_params());
+    }
+
+    #[test]
+    fn test_summary_display() {
+        let summary = model().summary(&[2, 3, 8, 8]);
+
+        assert_eq!(
+            summary.to_string(),
+            "Layer (type)              Output Shape           Param #           FLOPs
+========================================================================
+Model                     [2, 2]                     149           16262
+conv (Conv2d)             [2, 4, 6, 6]               112           15552
+activation (Relu)         [2, 4, 6, 6]                 0             288
+pool (AdaptiveAvgPool2d)  [2, 4, 1, 1]                 0             288
+layers (Vec)              [2, 2]                      37             134
+  0 (Linear)              [2, 5]                      25              90
+  1 (Linear)              [2, 2]                      12              44
+========================================================================
+Input shape: [2, 3, 8, 8]
+Total params: 149
+Total FLOPs: 16262"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use alloc::vec::Vec;
+
+use crate::tensor::{backend::Backend, Int, Tensor, TensorData};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Returns the slope of the [ALiBi bias](generate_alibi_bias) of each attention head.
+///
+/// The slopes form a geometric sequence starting at `2^(-8 / n_heads)`. When the number of
+/// heads isn't a power of two, the slopes of the closest lower power of two are followed by
+/// every other slope of the next one.
+pub fn alibi_slopes(n_heads: usize) -> Vec<f64> {
+    fn geometric(n_heads: usize) -> Vec<f64> {
+        let start = 2.0f64.powf(-8.0 / n_heads as f64);
+
+        (1..=n_heads).map(|i| start.powi(i as i32)).collect()
+    }
+
+    if n_heads.is_power_of_two() {
+        return geometric(n_heads);
+    }
+
+    let closest = 1 << n_heads.ilog2();
+    let mut slopes = geometric(closest);
+    slopes.extend(
+        geometric(2 * closest)
+            .into_iter()
+            .step_by(2)
+            .take(n_heads - closest),
+    );
+
+    slopes
+}
+
+/// Generate the ALiBi attention bias, penalizing the attention scores of each head linearly
+/// with the distance between the query and the key positions, instead of adding positional
+/// embeddings to the inputs.
+///
+/// The last query and the last key share the same position, so that the bias also applies when
+/// the keys of the previous positions are [cached](super::KvCache). The distance is absolute,
+/// which is the original causal bias for the keys a query can attend to with an
+/// [autoregressive mask](super::generate_autoregressive_mask), and a symmetric bias without.
+///
+/// Reference: <https://arxiv.org/abs/2108.12409>
+///
+/// # Shapes
+///
+/// - output: `[n_heads, seq_length_1, seq_length_2]`
+pub fn generate_alibi_bias<B: Backend>(
+    n_heads: usize,
+    seq_length_1: usize,
+    seq_length_2: usize,
+    device: &B::Device,
+) -> Tensor<B, 3> {
+    let offset = seq_length_2 as i64 - seq_length_1 as i64;
+    let query = Tensor::<B, 1, Int>::arange(offset..offset + seq_length_1 as i64, device)
+        .reshape([1, seq_length_1, 1]);
+    let key =
+        Tensor::<B, 1, Int>::arange(0..seq_length_2 as i64, device).reshape([1, 1, seq_length_2]);
+    let distance = query
+        .expand([1, seq_length_1, seq_length_2])
+        .sub(key.expand([1, seq_length_1, seq_length_2]))
+        .abs()
+        .float();
+
+    let slopes = Tensor::<B, 1>::from_data(
+        TensorData::new(alibi_slopes(n_heads), [n_heads]).convert::<B::FloatElem>(),
+        device,
+    )
+    .reshape([n_heads, 1, 1]);
+
+    distance.mul(slopes).neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_alibi_slopes() {
+        assert_eq!(
+            alibi_slopes(4),
+            [0.25, 0.0625, 0.015625, 0.00390625].to_vec()
+        );
+        assert_eq!(
+            alibi_slopes(6),
+            [0.25, 0.0625, 0.015625, 0.00390625, 0.5, 0.125].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_generate_alibi_bias() {
+        let device = Default::default();
+
+        let bias = generate_alibi_bias::<TestBackend>(2, 2, 3, &device);
+
+        bias.into_data().assert_approx_eq(
+            &TensorData::from([
+                [[-0.0625, 0.0, -0.0625], [-0.125, -0.0625, 0.0]],
+                [
+                    [-0.00390625, 0.0, -0.00390625],
+                    [-0.0078125, -0.00390625, 0.0],
+                ],
+            ]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use alloc::vec::Vec;
+
+use crate::tensor::{backend::Backend, Bool, Int, Tensor};
+
+/// How a [key-value cache](KvCache) stores the keys and values of the previous positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KvCacheStrategy {
+    /// The keys and values are written in buffers allocated once for the given maximum number of
+    /// positions, the cache panicking when it is full.
+    Static(usize),
+    /// Only the keys and values of the given number of last positions are kept, each position
+    /// attending to this window of positions.
+    Rolling(usize),
+    /// The keys and values are written in pages holding the given number of positions, a new
+    /// page being allocated when the last one is full.
+    Paged(usize),
+}
+
+/// Cache of the keys and values of the previous positions of a
+/// [multi-head attention](super::MultiHeadAttention), so that autoregressive decoding only
+/// computes the projections of the new positions.
+///
+/// To be used during inference with [forward_cached](super::MultiHeadAttention::forward_cached).
+pub struct KvCache<B: Backend> {
+    strategy: KvCacheStrategy,
+    pages: Vec<KvPage<B>>,
+    length: usize,
+    position: usize,
+}
+
+struct KvPage<B: Backend> {
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+}
+
+impl<B: Backend> KvPage<B> {
+    fn zeros(key: &Tensor<B, 4>, value: &Tensor<B, 4>, size: usize) -> Self {
+        let [batch_size, n_heads, _, d_k] = key.dims();
+        let [_, _, _, d_v] = value.dims();
+        let device = key.device();
+
+        Self {
+            key: Tensor::zeros([batch_size, n_heads, size, d_k], &device),
+            value: Tensor::zeros([batch_size, n_heads, size, d_v], &device),
+        }
+    }
+
+    fn write(&mut self, key: Tensor<B, 4>, value: Tensor<B, 4>, offset: usize) {
+        let [batch_size, n_heads, seq_length, d_k] = key.dims();
+        let [_, _, _, d_v] = value.dims();
+        let positions = offset..offset + seq_length;
+
+        self.key = self
+            .key
+            .clone()
+            .slice_assign([0..batch_size, 0..n_heads, positions.clone(), 0..d_k], key);
+        self.value = self
+            .value
+            .clone()
+            .slice_assign([0..batch_size, 0..n_heads, positions, 0..d_v], value);
+    }
+}
+
+impl<B: Backend> KvCache<B> {
+    /// Create an empty cache storing the keys and values with the given strategy.
+    pub fn new(strategy: KvCacheStrategy) -> Self {
+        let size = match strategy {
+            KvCacheStrategy::Static(size)
+            | KvCacheStrategy::Rolling(size)
+            | KvCacheStrategy::Paged(size) => size,
+        };
+        assert!(
+            size > 0,
+            "The size of the key-value cache must be positive."
+        );
+
+        Self {
+            strategy,
+            pages: Vec::new(),
+            length: 0,
+            position: 0,
+        }
+    }
+
+    /// The number of positions whose keys and values are cached.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// If no keys and values are cached.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The number of positions given to the cache since it was created or reset, which is the
+    /// position of the next one.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Remove the cached keys and values, so that the cache can be used for a new sequence.
+    pub fn reset(&mut self) {
+        self.pages.clear();
+        self.length = 0;
+        self.position = 0;
+    }
+
+    /// Append the keys and values of new positions, returning the keys and values they attend
+    /// to, ending with theirs.
+    ///
+    /// # Shapes
+    ///
+    /// - key: `[batch_size, n_heads, seq_length, d_k]`
+    /// - value: `[batch_size, n_heads, seq_length, d_v]`
+    /// - output: `[batch_size, n_heads, seq_length_2, d_k]` and
+    ///   `[batch_size, n_heads, seq_length_2, d_v]`
+    pub fn update(
+        &mut self,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let [_, _, seq_length, _] = key.dims();
+        self.position += seq_length;
+
+        match self.strategy {
+            KvCacheStrategy::Static(max_seq_length) => {
+                assert!(
+                    self.length + seq_length <= max_seq_length,
+                    "The key-value cache is full, it can't hold more than {max_seq_length} positions."
+                );
+
+                if self.pages.is_empty() {
+                    self.pages.push(KvPage::zeros(&key, &value, max_seq_length));
+                }
+                self.pages[0].write(key, value, self.length);
+                self.length += seq_length;
+
+                self.cached()
+            }
+            KvCacheStrategy::Rolling(window) => {
+                let (key, value) = match self.pages.pop() {
+                    Some(page) => (
+                        Tensor::cat(alloc::vec![page.key, key], 2),
+                        Tensor::cat(alloc::vec![page.value, value], 2),
+                    ),
+                    None => (key, value),
+                };
+
+                // The new positions attend to the window of each, the oldest ones being dropped.
+                let [_, _, length, _] = key.dims();
+                let start = length.saturating_sub(window);
+                self.pages.push(KvPage {
+                    key: key.clone().narrow(2, start, length - start),
+                    value: value.clone().narrow(2, start, length - start),
+                });
+                self.length = length - start;
+
+                (key, value)
+            }
+            KvCacheStrategy::Paged(page_size) => {
+                let mut written = 0;
+
+                while written < seq_length {
+                    let offset = self.length % page_size;
+                    if offset == 0 {
+                        self.pages.push(KvPage::zeros(&key, &value, page_size));
+                    }
+
+                    let count = usize::min(page_size - offset, seq_length - written);
+                    self.pages.last_mut().unwrap().write(
+                        key.clone().narrow(2, written, count),
+                        value.clone().narrow(2, written, count),
+                        offset,
+                    );
+                    self.length += count;
+                    written += count;
+                }
+
+                self.cached()
+            }
+        }
+    }
+
+    /// Returns the mask of the keys returned by the last [update](KvCache::update) which each new
+    /// position can't attend to, the following positions and, with a rolling cache, the ones
+    /// outside of its window.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[batch_size, seq_length_1, seq_length_2]`
+    pub fn mask(
+        &self,
+        batch_size: usize,
+        seq_length_1: usize,
+        seq_length_2: usize,
+        device: &B::Device,
+    ) -> Tensor<B, 3, Bool> {
+        let end = self.position as i64;
+        let query = Tensor::<B, 1, Int>::arange(end - seq_length_1 as i64..end, device)
+            .reshape([seq_length_1, 1])
+            .expand([seq_length_1, seq_length_2]);
+        let key = Tensor::<B, 1, Int>::arange(end - seq_length_2 as i64..end, device)
+            .reshape([1, seq_length_2])
+            .expand([seq_length_1, seq_length_2]);
+
+        let mut mask = key.clone().greater(query.clone()).int();
+        if let KvCacheStrategy::Rolling(window) = self.strategy {
+            let outside = key.lower_equal(query.sub_scalar(window as i64));
+            mask = mask.add(outside.int());
+        }
+
+        mask.greater_elem(0)
+            .reshape([1, seq_length_1, seq_length_2])
+            .expand([batch_size, seq_length_1, seq_length_2])
+    }
+
+    fn cached(&self) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let keys = self.pages.iter().map(|page| page.key.clone()).collect();
+        let values = self.pages.iter().map(|page| page.value.clone()).collect();
+
+        (
+            Tensor::cat(keys, 2).narrow(2, 0, self.length),
+            Tensor::cat(values, 2).narrow(2, 0, self.length),
+        )
+    }
+}
@@ -1,5 +1,5 @@
 use alloc::vec::Vec;
-use burn_tensor::ops::IntElem;
+use burn_tensor::ops::{attention::window_mask, IntElem};
 
 use crate::tensor::{backend::Backend, Bool, ElementConversion, Int, Shape, Tensor, TensorData};
 
@@ -24,6 +24,31 @@ pub fn generate_autoregressive_mask<B: Backend>(
     mask.equal_elem(1_i64.elem::<i64>())
 }
 
+/// Generate a sliding window attention mask, where each query only attends to the keys in a
+/// window around its position, and to the global tokens if any.
+///
+/// The queries are the last positions of the keys, and the global tokens also attend to every
+/// key, see [window_attention](crate::tensor::module::window_attention).
+pub fn generate_sliding_window_mask<B: Backend>(
+    batch_size: usize,
+    seq_length_1: usize,
+    seq_length_2: usize,
+    window_before: usize,
+    window_after: usize,
+    global: Option<Tensor<B, 2, Bool>>,
+    device: &B::Device,
+) -> Tensor<B, 3, Bool> {
+    window_mask(
+        batch_size,
+        seq_length_1,
+        seq_length_2,
+        window_before,
+        window_after,
+        global,
+        device,
+    )
+}
+
 /// Generate a padding attention mask.
 pub struct GeneratePaddingMask<B: Backend> {
     /// The generated tensor.
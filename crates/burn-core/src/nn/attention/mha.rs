@@ -1,12 +1,19 @@
 use crate as burn;
 
+use crate::nn::attention::{generate_alibi_bias, generate_sliding_window_mask, KvCache};
 use crate::nn::cache::TensorCache;
 use crate::nn::Initializer;
 use crate::{
     config::Config,
     module::Module,
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{
+        activation,
+        backend::Backend,
+        module,
+        ops::{AttentionOptions, WindowAttentionOptions},
+        Bool, Tensor,
+    },
 };
 
 #[cfg(not(feature = "std"))]
@@ -19,6 +26,15 @@ pub struct MultiHeadAttentionConfig {
     pub d_model: usize,
     /// The number of heads.
     pub n_heads: usize,
+    /// The number of key and value heads, each shared by `n_heads / num_kv_heads` query heads.
+    /// Default: `n_heads`
+    ///
+    /// - Grouped-query attention uses fewer key and value heads than query heads, and multi-query
+    ///   attention a single one, reducing the size of the keys and values to cache.
+    ///
+    /// Reference: <https://arxiv.org/abs/2305.13245>
+    #[config(default = "None")]
+    pub num_kv_heads: Option<usize>,
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     pub dropout: f64,
@@ -40,6 +56,32 @@ pub struct MultiHeadAttentionConfig {
         default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// Add the [ALiBi bias](generate_alibi_bias) to the attention scores, encoding the relative
+    /// positions of the queries and keys instead of absolute or rotary embeddings.
+    ///
+    /// - Models trained on short sequences extrapolate to longer ones.
+    ///
+    /// Reference: <https://arxiv.org/abs/2108.12409>
+    #[config(default = false)]
+    pub alibi: bool,
+    /// The number of keys before and after the position of each query that it attends to, as
+    /// in Longformer, or Mistral with no keys after.
+    ///
+    /// - The [global tokens](MhaInput::global) attend to every key, and every query attends to
+    ///   them.
+    /// - The [fused attention](MultiHeadAttention::forward_flash) only scores the keys
+    ///   of the window, without materializing a mask, when there are no padding nor attention
+    ///   masks.
+    ///
+    /// Reference: <https://arxiv.org/abs/2004.05150>
+    #[config(default = "None")]
+    pub sliding_window: Option<[usize; 2]>,
+    /// The scale of the attention scores, the products of the queries and the keys.
+    /// Default: `1 / sqrt(d_k)`
+    ///
+    /// - T5 doesn't scale the attention scores, using a scale of `1.0`.
+    #[config(default = "None")]
+    pub scale: Option<f64>,
 }
 
 /// The multihead attention module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -61,9 +103,13 @@ pub struct MultiHeadAttention<B: Backend> {
     dropout: nn::Dropout,
     activation: nn::Gelu,
     n_heads: usize,
+    n_kv_heads: usize,
     d_k: usize,
     min_float: f64,
     quiet_softmax: bool,
+    alibi: bool,
+    sliding_window: Option<[usize; 2]>,
+    scale: f64,
 }
 
 /// [Multihead attention](MultiHeadAttention) forward pass input argument.
@@ -77,28 +123,43 @@ pub struct MhaInput<B: Backend> {
     value: Tensor<B, 3>,
     mask_pad: Option<Tensor<B, 2, Bool>>,
     mask_attn: Option<Tensor<B, 3, Bool>>,
+    global: Option<Tensor<B, 2, Bool>>,
+    bias: Option<Tensor<B, 3>>,
 }
 
 impl MultiHeadAttentionConfig {
     /// Initialize a new [multihead attention](MultiHeadAttention) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> MultiHeadAttention<B> {
-        let linear = |config: &Self| {
-            nn::LinearConfig::new(config.d_model, config.d_model)
+        let n_kv_heads = self.num_kv_heads.unwrap_or(self.n_heads);
+        assert!(
+            n_kv_heads > 0 && self.n_heads % n_kv_heads == 0,
+            "The number of heads ({}) must be a multiple of the number of key and value heads ({}).",
+            self.n_heads,
+            n_kv_heads
+        );
+
+        let d_k = self.d_model / self.n_heads;
+        let linear = |d_output: usize| {
+            nn::LinearConfig::new(self.d_model, d_output)
                 .with_initializer(self.initializer.clone())
                 .init(device)
         };
 
         MultiHeadAttention {
-            query: linear(self),
-            key: linear(self),
-            value: linear(self),
-            output: linear(self),
+            query: linear(self.d_model),
+            key: linear(n_kv_heads * d_k),
+            value: linear(n_kv_heads * d_k),
+            output: linear(self.d_model),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
             activation: nn::Gelu::new(),
             n_heads: self.n_heads,
-            d_k: self.d_model / self.n_heads,
+            n_kv_heads,
+            d_k,
             min_float: self.min_float,
             quiet_softmax: self.quiet_softmax,
+            alibi: self.alibi,
+            sliding_window: self.sliding_window,
+            scale: self.scale.unwrap_or(1.0 / (d_k as f64).sqrt()),
         }
     }
 }
@@ -116,6 +177,8 @@ impl<B: Backend> MhaInput<B> {
             value: tensor,
             mask_pad: None,
             mask_attn: None,
+            global: None,
+            bias: None,
         }
     }
 
@@ -127,6 +190,8 @@ impl<B: Backend> MhaInput<B> {
             value,
             mask_pad: None,
             mask_attn: None,
+            global: None,
+            bias: None,
         }
     }
 
@@ -141,6 +206,26 @@ impl<B: Backend> MhaInput<B> {
         self.mask_attn = Some(mask_attn);
         self
     }
+
+    /// Register the global tokens of the [sliding window](MultiHeadAttentionConfig::sliding_window)
+    /// among the keys, ignored without one.
+    ///
+    /// # Shape
+    /// - global: `[batch_size, seq_length_2]`
+    pub fn global(mut self, global: Tensor<B, 2, Bool>) -> Self {
+        self.global = Some(global);
+        self
+    }
+
+    /// Register a bias added to the attention scores of each head, e.g. a
+    /// [relative position bias](super::RelativePositionBias).
+    ///
+    /// # Shape
+    /// - bias: `[n_heads, seq_length_1, seq_length_2]`
+    pub fn bias(mut self, bias: Tensor<B, 3>) -> Self {
+        self.bias = Some(bias);
+        self
+    }
 }
 
 /// [Multihead attention](MultiHeadAttention) outputs.
@@ -166,14 +251,19 @@ impl<B: Backend> MultiHeadAttention<B> {
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-        let query = self.attention_linear(input.query, &self.query);
-        let key = self.attention_linear(input.key, &self.key);
-        let value = self.attention_linear(input.value, &self.value);
-
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let query = self.attention_linear(input.query, &self.query, self.n_heads);
+        let key = self.attention_linear(input.key, &self.key, self.n_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.n_kv_heads);
 
-        let context = weights.clone().matmul(value);
+        let (context, weights) = self.attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            input.global,
+            input.bias,
+        );
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -182,6 +272,43 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
+    /// Applies the forward pass with a fused attention, computing the context without
+    /// materializing the attention weights.
+    ///
+    /// - Backends with a fused kernel are faster and use much less memory on long sequences.
+    /// - The regular attention is used instead when the dropout applies to the attention scores,
+    ///   during training, with [ALiBi](MultiHeadAttentionConfig::alibi), or with a
+    ///   [bias](MhaInput::bias).
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_flash(&self, input: MhaInput<B>) -> Tensor<B, 3> {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+
+        let query = self.attention_linear(input.query, &self.query, self.n_heads);
+        let key = self.attention_linear(input.key, &self.key, self.n_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.n_kv_heads);
+
+        let context = self.flash_attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            input.global,
+            input.bias,
+        );
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+
+        self.output.forward(context)
+    }
+
     /// Applies the forward pass using a cache.
     ///
     /// # Shapes
@@ -193,20 +320,25 @@ impl<B: Backend> MultiHeadAttention<B> {
     pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> MhaOutput<B> {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-        let query = cache
-            .query
-            .forward(input.query, |t| self.attention_linear(t, &self.query));
-        let key = cache
-            .key
-            .forward(input.key, |t| self.attention_linear(t, &self.key));
-        let value = cache
-            .value
-            .forward(input.value, |t| self.attention_linear(t, &self.value));
-
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let query = cache.query.forward(input.query, |t| {
+            self.attention_linear(t, &self.query, self.n_heads)
+        });
+        let key = cache.key.forward(input.key, |t| {
+            self.attention_linear(t, &self.key, self.n_kv_heads)
+        });
+        let value = cache.value.forward(input.value, |t| {
+            self.attention_linear(t, &self.value, self.n_kv_heads)
+        });
 
-        let context = weights.clone().matmul(value);
+        let (context, weights) = self.attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            input.global,
+            input.bias,
+        );
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -216,10 +348,182 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
-    fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
-        let attn_scores = query
-            .matmul(key.transpose())
-            .div_scalar((self.d_k as f32).sqrt());
+    /// Applies the forward pass on the new positions of a sequence, using the keys and values of
+    /// the previous positions stored in the [key-value cache](KvCache).
+    ///
+    /// The new positions attend to the previous ones and to themselves causally. The padding and
+    /// attention masks, if any, apply to the keys returned by the [cache](KvCache::update) and
+    /// are combined with its [mask](KvCache::mask).
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_1, d_model]`
+    /// - value: `[batch_size, seq_length_1, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_cached(&self, input: MhaInput<B>, cache: &mut KvCache<B>) -> MhaOutput<B> {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        let device = input.query.device();
+
+        let query = self.attention_linear(input.query, &self.query, self.n_heads);
+        let key = self.attention_linear(input.key, &self.key, self.n_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.n_kv_heads);
+        let (key, value) = cache.update(key, value);
+
+        let [_, _, seq_length_2, _] = key.dims();
+        let mask_cache = cache.mask(batch_size, seq_length_1, seq_length_2, &device);
+        let mask_attn = match input.mask_attn {
+            Some(mask_attn) => mask_attn.int().add(mask_cache.int()).greater_elem(0),
+            None => mask_cache,
+        };
+
+        let (context, weights) = self.attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            Some(mask_attn),
+            input.global,
+            input.bias,
+        );
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+        let context = self.output.forward(context);
+
+        MhaOutput { weights, context }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn attention(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        global: Option<Tensor<B, 2, Bool>>,
+        bias: Option<Tensor<B, 3>>,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let key = self.repeat_kv(key);
+        let value = self.repeat_kv(value);
+        let mask_attn = self.mask_window(&query, &key, mask_attn, global);
+
+        let attn_scores = self.attn_scores(query, key, bias);
+        let weights = self.attn_weights(attn_scores, mask_pad, mask_attn);
+
+        (weights.clone().matmul(value), weights)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn flash_attention(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        global: Option<Tensor<B, 2, Bool>>,
+        bias: Option<Tensor<B, 3>>,
+    ) -> Tensor<B, 4> {
+        // The dropout applies to the attention scores, which the fused attention doesn't expose.
+        let dropout = B::ad_enabled() && self.dropout.prob > 0.0;
+        if dropout || self.alibi || bias.is_some() {
+            let (context, _weights) =
+                self.attention(query, key, value, mask_pad, mask_attn, global, bias);
+            return context;
+        }
+
+        let key = self.repeat_kv(key);
+        let value = self.repeat_kv(value);
+
+        if let Some([window_before, window_after]) = self.sliding_window {
+            if mask_pad.is_none() && mask_attn.is_none() {
+                let options = WindowAttentionOptions::new(
+                    self.scale,
+                    window_before,
+                    window_after,
+                    self.quiet_softmax,
+                );
+
+                return module::window_attention(query, key, value, global, options);
+            }
+        }
+        let mask_attn = self.mask_window(&query, &key, mask_attn, global);
+
+        let [batch_size, n_heads, seq_length_1, _] = query.dims();
+        let [_, _, seq_length_2, _] = key.dims();
+        let shape = [batch_size, n_heads, seq_length_1, seq_length_2];
+
+        let mask_pad =
+            mask_pad.map(|mask| mask.reshape([batch_size, 1, 1, seq_length_2]).expand(shape));
+        let mask_attn = mask_attn.map(|mask| {
+            mask.reshape([batch_size, 1, seq_length_1, seq_length_2])
+                .expand(shape)
+        });
+        let mask = match (mask_pad, mask_attn) {
+            (Some(mask_pad), Some(mask_attn)) => {
+                Some(mask_pad.int().add(mask_attn.int()).greater_elem(0))
+            }
+            (mask_pad, mask_attn) => mask_pad.or(mask_attn),
+        };
+
+        let options = AttentionOptions::new(self.scale, self.min_float, self.quiet_softmax);
+
+        module::attention(query, key, value, mask, options)
+    }
+
+    /// Combines the attention mask with the mask of the sliding window, if any.
+    fn mask_window(
+        &self,
+        query: &Tensor<B, 4>,
+        key: &Tensor<B, 4>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        global: Option<Tensor<B, 2, Bool>>,
+    ) -> Option<Tensor<B, 3, Bool>> {
+        let [window_before, window_after] = match self.sliding_window {
+            Some(window) => window,
+            None => return mask_attn,
+        };
+
+        let [batch_size, _, seq_length_1, _] = query.dims();
+        let [_, _, seq_length_2, _] = key.dims();
+        let mask_window = generate_sliding_window_mask(
+            batch_size,
+            seq_length_1,
+            seq_length_2,
+            window_before,
+            window_after,
+            global,
+            &query.device(),
+        );
+
+        match mask_attn {
+            Some(mask_attn) => Some(mask_attn.int().add(mask_window.int()).greater_elem(0)),
+            None => Some(mask_window),
+        }
+    }
+
+    fn attn_scores(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        bias: Option<Tensor<B, 3>>,
+    ) -> Tensor<B, 4> {
+        let [_, n_heads, seq_length_1, _] = query.dims();
+        let [_, _, seq_length_2, _] = key.dims();
+        let device = query.device();
+
+        let mut attn_scores = query.matmul(key.transpose()).mul_scalar(self.scale);
+
+        if self.alibi {
+            let bias = generate_alibi_bias(n_heads, seq_length_1, seq_length_2, &device);
+            attn_scores = attn_scores.add(bias.unsqueeze());
+        }
+
+        if let Some(bias) = bias {
+            attn_scores = attn_scores.add(bias.unsqueeze());
+        }
 
         self.dropout.forward(attn_scores)
     }
@@ -255,13 +559,31 @@ impl<B: Backend> MultiHeadAttention<B> {
         }
     }
 
-    fn attention_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
+    fn attention_linear(
+        &self,
+        x: Tensor<B, 3>,
+        linear: &nn::Linear<B>,
+        n_heads: usize,
+    ) -> Tensor<B, 4> {
         let [batch_size, seq_length, _d_model] = x.dims();
         linear
             .forward(x)
-            .reshape([batch_size, seq_length, self.n_heads, self.d_k])
+            .reshape([batch_size, seq_length, n_heads, self.d_k])
             .swap_dims(1, 2)
     }
+
+    /// Repeats each key or value head for the query heads sharing it.
+    fn repeat_kv(&self, x: Tensor<B, 4>) -> Tensor<B, 4> {
+        let n_groups = self.n_heads / self.n_kv_heads;
+        if n_groups == 1 {
+            return x;
+        }
+
+        let [batch_size, n_kv_heads, seq_length, d_k] = x.dims();
+        x.reshape([batch_size, n_kv_heads, 1, seq_length, d_k])
+            .expand([batch_size, n_kv_heads, n_groups, seq_length, d_k])
+            .reshape([batch_size, self.n_heads, seq_length, d_k])
+    }
 }
 
 /// Cache for the [Multi Head Attention](MultiHeadAttention) layer.
@@ -320,9 +642,15 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::module::Param;
     use crate::tensor::Int;
-    use crate::tensor::{Distribution, Shape};
-    use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
+    use crate::tensor::{Distribution, Shape, TensorData};
+    use crate::{
+        nn::attention::{
+            generate_autoregressive_mask, KvCacheStrategy, RelativePositionBiasConfig,
+        },
+        TestBackend,
+    };
     use alloc::vec::Vec;
 
     #[test]
@@ -478,4 +806,243 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_2.into_data(), 3);
     }
+
+    #[test]
+    fn test_flash_attention_should_have_same_output_as_attention() {
+        let [batch_size, seq_length, d_model, n_heads, num_padded] = [3, 6, 32, 4, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_quiet_softmax(true)
+            .init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_pad = Tensor::<TestBackend, 2, Int>::zeros([batch_size, seq_length], &device)
+            .slice_assign(
+                [0..batch_size, seq_length - num_padded..seq_length],
+                Tensor::ones([batch_size, num_padded], &device),
+            )
+            .equal_elem(1);
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let input = MhaInput::self_attn(tensor)
+            .mask_pad(mask_pad)
+            .mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input.clone());
+        let output_2 = mha.forward_flash(input);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_sliding_window_flash_attention_should_have_same_output_as_attention() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 7, 32, 4];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_sliding_window(Some([2, 1]))
+            .init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let global = Tensor::<TestBackend, 2, Int>::zeros([batch_size, seq_length], &device)
+            .slice_assign([0..1, 0..1], Tensor::ones([1, 1], &device))
+            .equal_elem(1);
+        let input = MhaInput::self_attn(tensor).global(global);
+
+        let output_1 = mha.forward(input.clone());
+        let output_2 = mha.forward_flash(input);
+
+        // The first query of the first sample is global, so its weights are dense.
+        let weights = output_1.weights.greater_elem(0.0).int().sum_dim(3);
+        let expected = TensorData::from([[7i64, 3, 4, 5, 5, 5, 4], [2, 3, 4, 4, 4, 4, 3]]);
+        weights
+            .slice([0..batch_size, 0..1])
+            .reshape([batch_size, seq_length])
+            .into_data()
+            .assert_eq(
+                &expected.convert::<<TestBackend as Backend>::IntElem>(),
+                true,
+            );
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_kv_cache_should_have_same_output_as_autoregressive_mask() {
+        for strategy in [
+            KvCacheStrategy::Static(5),
+            KvCacheStrategy::Rolling(5),
+            KvCacheStrategy::Paged(2),
+        ] {
+            test_kv_cache(strategy, None, false);
+        }
+    }
+
+    #[test]
+    fn test_rolling_kv_cache_should_have_same_output_as_sliding_window_mask() {
+        test_kv_cache(KvCacheStrategy::Rolling(2), Some(2), false);
+    }
+
+    #[test]
+    fn test_alibi_kv_cache_should_have_same_output_as_autoregressive_mask() {
+        test_kv_cache(KvCacheStrategy::Paged(2), None, true);
+        test_kv_cache(KvCacheStrategy::Rolling(2), Some(2), true);
+    }
+
+    #[test]
+    #[should_panic = "The key-value cache is full"]
+    fn test_static_kv_cache_should_panic_when_full() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend>::new(KvCacheStrategy::Static(2));
+
+        for _ in 0..3 {
+            let tensor = Tensor::zeros([1, 1, 1, 4], &device);
+            cache.update(tensor.clone(), tensor);
+        }
+    }
+
+    /// Decodes a prefix of two positions, then one position at a time.
+    fn test_kv_cache(strategy: KvCacheStrategy, window: Option<usize>, alibi: bool) {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 12, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_alibi(alibi)
+            .init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let query = Tensor::<TestBackend, 1, Int>::arange(0..seq_length as i64, &device)
+            .reshape([seq_length, 1])
+            .expand([seq_length, seq_length]);
+        let key = query.clone().transpose();
+        let mut mask_attn = key.clone().greater(query.clone()).int();
+        if let Some(window) = window {
+            mask_attn = mask_attn.add(key.lower_equal(query.sub_scalar(window as i64)).int());
+        }
+        let mask_attn = mask_attn
+            .greater_elem(0)
+            .reshape([1, seq_length, seq_length])
+            .expand([batch_size, seq_length, seq_length]);
+        let input = MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input);
+        let mut output_2 = Vec::new();
+        let mut cache = KvCache::new(strategy);
+
+        for range in [0..2, 2..3, 3..4, 4..5] {
+            let tensor = tensor.clone().slice([0..batch_size, range, 0..d_model]);
+            let input = MhaInput::self_attn(tensor);
+            output_2.push(mha.forward_cached(input, &mut cache).context);
+        }
+
+        assert_eq!(cache.position(), seq_length);
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_relative_position_bias_kv_cache_should_have_same_output_as_autoregressive_mask() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 12, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_scale(Some(1.0))
+            .init::<TestBackend>(&device);
+        let relative_position = RelativePositionBiasConfig::new(n_heads)
+            .with_num_buckets(4)
+            .with_bidirectional(false)
+            .init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let input = MhaInput::self_attn(tensor.clone())
+            .mask_attn(mask_attn)
+            .bias(relative_position.forward(seq_length, seq_length, &device));
+
+        let output_1 = mha.forward(input);
+        let mut output_2 = Vec::new();
+        let mut cache = KvCache::new(KvCacheStrategy::Paged(2));
+
+        for range in [0..2, 2..3, 3..4, 4..5] {
+            let bias = relative_position.forward(range.len(), range.end, &device);
+            let tensor = tensor.clone().slice([0..batch_size, range, 0..d_model]);
+            let input = MhaInput::self_attn(tensor).bias(bias);
+            output_2.push(mha.forward_cached(input, &mut cache).context);
+        }
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_grouped_query_attention_should_have_same_output_as_repeated_heads() {
+        let [batch_size, seq_length, d_model, n_heads, n_kv_heads] = [2, 5, 12, 4, 2];
+        let d_k = d_model / n_heads;
+        let device = Default::default();
+        let gqa = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_num_kv_heads(Some(n_kv_heads))
+            .init::<TestBackend>(&device);
+        assert_eq!(gqa.key.weight.dims(), [d_model, n_kv_heads * d_k]);
+
+        // Each key and value head is shared by two consecutive query heads.
+        let repeat = |linear: &nn::Linear<TestBackend>| nn::Linear {
+            weight: Param::from_tensor(
+                linear
+                    .weight
+                    .val()
+                    .reshape([d_model, n_kv_heads, 1, d_k])
+                    .expand([d_model, n_kv_heads, 2, d_k])
+                    .reshape([d_model, d_model]),
+            ),
+            bias: linear.bias.as_ref().map(|bias| {
+                Param::from_tensor(
+                    bias.val()
+                        .reshape([n_kv_heads, 1, d_k])
+                        .expand([n_kv_heads, 2, d_k])
+                        .reshape([d_model]),
+                )
+            }),
+        };
+        let mha = MultiHeadAttention {
+            key: repeat(&gqa.key),
+            value: repeat(&gqa.value),
+            n_kv_heads: n_heads,
+            ..gqa.clone()
+        };
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let output_1 = gqa.forward(MhaInput::self_attn(tensor.clone()));
+        let output_2 = mha.forward(MhaInput::self_attn(tensor));
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.context.into_data(), 3);
+    }
 }
@@ -0,0 +1,205 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::{Embedding, EmbeddingConfig, Initializer};
+use crate::tensor::{backend::Backend, Int, Tensor, TensorData};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create a [RelativePositionBias](RelativePositionBias) module using the
+/// [init function](RelativePositionBiasConfig::init).
+#[derive(Config, Debug)]
+pub struct RelativePositionBiasConfig {
+    /// The number of attention heads.
+    pub n_heads: usize,
+    /// The number of buckets of relative positions. Default: 32
+    #[config(default = 32)]
+    pub num_buckets: usize,
+    /// The distance from which all the relative positions share the same bucket. Default: 128
+    #[config(default = 128)]
+    pub max_distance: usize,
+    /// If true, the keys before and after a query use different buckets, as in an encoder,
+    /// otherwise the keys after a query share the bucket of the query, as in a causal decoder.
+    /// Default: true
+    #[config(default = true)]
+    pub bidirectional: bool,
+    /// The type of function used to initialize the bias of each bucket.
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// The relative position bias of T5, learning a bias of the attention scores of each head for
+/// buckets of the distances between the queries and the keys, instead of adding positional
+/// embeddings to the inputs.
+///
+/// The buckets are exact for small distances, and logarithmically larger up to the
+/// [maximum distance](RelativePositionBiasConfig::max_distance). The bias is added to the
+/// attention scores with [MhaInput::bias](super::MhaInput::bias), usually computed once and shared
+/// by all the layers of a transformer.
+///
+/// Reference: <https://arxiv.org/abs/1910.10683>
+///
+/// Should be created with [RelativePositionBiasConfig].
+#[derive(Module, Debug)]
+pub struct RelativePositionBias<B: Backend> {
+    /// The bias of each bucket and head, of shape `[num_buckets, n_heads]`.
+    pub relative_attention_bias: Embedding<B>,
+    num_buckets: usize,
+    max_distance: usize,
+    bidirectional: bool,
+}
+
+impl RelativePositionBiasConfig {
+    /// Initialize a new [relative position bias](RelativePositionBias) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> RelativePositionBias<B> {
+        RelativePositionBias {
+            relative_attention_bias: EmbeddingConfig::new(self.num_buckets, self.n_heads)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+            num_buckets: self.num_buckets,
+            max_distance: self.max_distance,
+            bidirectional: self.bidirectional,
+        }
+    }
+}
+
+impl<B: Backend> RelativePositionBias<B> {
+    /// Compute the bias of the attention scores of each head.
+    ///
+    /// As with the [ALiBi bias](super::generate_alibi_bias), the last query and the last key
+    /// share the same position, so that the bias also applies when the keys of the previous
+    /// positions are [cached](super::KvCache).
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[n_heads, seq_length_1, seq_length_2]`
+    pub fn forward(
+        &self,
+        seq_length_1: usize,
+        seq_length_2: usize,
+        device: &B::Device,
+    ) -> Tensor<B, 3> {
+        let offset = seq_length_2 as i64 - seq_length_1 as i64;
+        let buckets: Vec<i64> = (0..seq_length_1 as i64)
+            .flat_map(|query| {
+                (0..seq_length_2 as i64).map(move |key| {
+                    relative_position_bucket(
+                        key - (query + offset),
+                        self.bidirectional,
+                        self.num_buckets,
+                        self.max_distance,
+                    ) as i64
+                })
+            })
+            .collect();
+        let buckets = Tensor::<B, 2, Int>::from_data(
+            TensorData::new(buckets, [seq_length_1, seq_length_2]).convert::<B::IntElem>(),
+            device,
+        );
+
+        // [seq_length_1, seq_length_2, n_heads] -> [n_heads, seq_length_1, seq_length_2]
+        self.relative_attention_bias
+            .forward(buckets)
+            .swap_dims(0, 2)
+            .swap_dims(1, 2)
+    }
+}
+
+/// Returns the bucket of the relative position of a key from a query, `key - query`, as in T5.
+///
+/// When bidirectional, the first half of the buckets is used by the keys up to the query, and
+/// the second half by the keys after it. The first half of the buckets of each direction maps
+/// the distances exactly, and the other half covers logarithmically larger ranges up to the
+/// maximum distance.
+pub fn relative_position_bucket(
+    relative_position: i64,
+    bidirectional: bool,
+    num_buckets: usize,
+    max_distance: usize,
+) -> usize {
+    let (num_buckets, offset, distance) = match bidirectional {
+        true => {
+            let num_buckets = num_buckets / 2;
+            let offset = if relative_position > 0 {
+                num_buckets
+            } else {
+                0
+            };
+
+            (
+                num_buckets,
+                offset,
+                relative_position.unsigned_abs() as usize,
+            )
+        }
+        false => (num_buckets, 0, (-relative_position).max(0) as usize),
+    };
+
+    let max_exact = num_buckets / 2;
+    if distance < max_exact {
+        return offset + distance;
+    }
+
+    // Computed in single precision, as in the reference implementation.
+    let ratio =
+        (distance as f32 / max_exact as f32).ln() / (max_distance as f32 / max_exact as f32).ln();
+    let bucket = max_exact + (ratio * (num_buckets - max_exact) as f32) as usize;
+
+    offset + bucket.min(num_buckets - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_relative_position_bucket_bidirectional() {
+        let buckets: Vec<usize> = [0, -1, 1, -7, -8, 8, -20, -200, 200]
+            .into_iter()
+            .map(|position| relative_position_bucket(position, true, 32, 128))
+            .collect();
+
+        assert_eq!(buckets, [0, 1, 17, 7, 8, 24, 10, 15, 31]);
+    }
+
+    #[test]
+    fn test_relative_position_bucket_causal() {
+        let buckets: Vec<usize> = [0, 5, -5, -15, -16, -20, -1000]
+            .into_iter()
+            .map(|position| relative_position_bucket(position, false, 32, 128))
+            .collect();
+
+        assert_eq!(buckets, [0, 0, 5, 15, 16, 17, 31]);
+    }
+
+    #[test]
+    fn test_relative_position_bias() {
+        let device = Default::default();
+        let module = RelativePositionBiasConfig::new(2)
+            .with_num_buckets(4)
+            .with_max_distance(4)
+            .init::<TestBackend>(&device);
+        let weight = module.relative_attention_bias.weight.val();
+
+        let bias = module.forward(2, 3, &device);
+
+        // The buckets of the relative positions of the keys from the queries 1 and 2.
+        let buckets = [[1, 0, 3], [1, 1, 0]];
+        let expected = Tensor::stack::<3>(
+            buckets
+                .iter()
+                .map(|row| {
+                    let indices = Tensor::<TestBackend, 1, Int>::from_ints(*row, &device);
+                    weight.clone().select(0, indices).transpose()
+                })
+                .collect(),
+            1,
+        );
+        bias.into_data().assert_approx_eq(&expected.into_data(), 5);
+    }
+}
@@ -1,10 +1,13 @@
-use alloc::format;
+use alloc::{format, vec::Vec};
 
 use crate as burn;
 
 use crate::{
     config::Config,
-    module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param},
+    module::{
+        num_elements, shape_dims, Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param,
+        ShapePropagation, SummaryBuilder,
+    },
     nn::{conv::checks, Initializer, PaddingConfig1d},
     tensor::{backend::Backend, module::conv1d, ops::ConvOptions, Tensor},
 };
@@ -50,11 +53,11 @@ pub struct Conv1d<B: Backend> {
     pub weight: Param<Tensor<B, 3>>,
     /// Tensor of shape `[channels_out]`
     pub bias: Option<Param<Tensor<B, 1>>>,
-    stride: usize,
-    kernel_size: usize,
-    dilation: usize,
-    groups: usize,
-    padding: Ignored<PaddingConfig1d>,
+    pub(crate) stride: usize,
+    pub(crate) kernel_size: usize,
+    pub(crate) dilation: usize,
+    pub(crate) groups: usize,
+    pub(crate) padding: Ignored<PaddingConfig1d>,
 }
 
 impl<B: Backend> ModuleDisplay for Conv1d<B> {
@@ -125,6 +128,7 @@ impl<B: Backend> Conv1d<B> {
     /// - input: `[batch_size, channels_in, length_in]`
     /// - output: `[batch_size, channels_out, length_out]`
     pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let input = self.padding.pad_input(input);
         let [_batch_size, _channels, length] = input.dims();
         let padding = self
             .padding
@@ -139,6 +143,22 @@ impl<B: Backend> Conv1d<B> {
     }
 }
 
+impl<B: Backend> ShapePropagation for Conv1d<B> {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        let [batch_size, _channels, length] = shape_dims(input);
+        let [channels_out, channels_per_group, _] = self.weight.dims();
+        let length_out =
+            self.padding
+                .output_size_1d(length, self.kernel_size, self.stride, self.dilation);
+
+        let output = [batch_size, channels_out, length_out];
+        let macs = channels_per_group * self.kernel_size;
+        summary.add_flops(num_elements(&output) * 2 * macs as u64);
+
+        output.to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +189,38 @@ mod tests {
             .to_data()
             .assert_approx_eq(&TensorData::zeros::<f32, _>(conv.weight.shape()), 3);
     }
+
+    #[test]
+    fn circular_padding() {
+        let device = Default::default();
+        let conv = Conv1dConfig::new(1, 1, 3)
+            .with_padding(PaddingConfig1d::Circular(1))
+            .with_initializer(Initializer::Ones)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0, 3.0, 4.0]]], &device);
+
+        let output = conv.forward(input);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[7.0, 6.0, 9.0, 8.0]]]), false);
+    }
+
+    #[test]
+    fn reflect_padding() {
+        let device = Default::default();
+        let conv = Conv1dConfig::new(1, 1, 3)
+            .with_padding(PaddingConfig1d::Reflect(1))
+            .with_initializer(Initializer::Ones)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0, 3.0, 4.0]]], &device);
+
+        let output = conv.forward(input);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[5.0, 6.0, 9.0, 10.0]]]), false);
+    }
 }
@@ -1,9 +1,12 @@
-use alloc::format;
+use alloc::{format, vec::Vec};
 
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
+use crate::module::{
+    num_elements, shape_dims, Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param,
+    ShapePropagation, SummaryBuilder,
+};
 use crate::nn::Initializer;
 use crate::nn::PaddingConfig2d;
 use crate::tensor::backend::Backend;
@@ -52,11 +55,11 @@ pub struct Conv2d<B: Backend> {
     pub weight: Param<Tensor<B, 4>>,
     /// Tensor of shape `[channels_out]`
     pub bias: Option<Param<Tensor<B, 1>>>,
-    stride: [usize; 2],
-    kernel_size: [usize; 2],
-    dilation: [usize; 2],
-    groups: usize,
-    padding: Ignored<PaddingConfig2d>,
+    pub(crate) stride: [usize; 2],
+    pub(crate) kernel_size: [usize; 2],
+    pub(crate) dilation: [usize; 2],
+    pub(crate) groups: usize,
+    pub(crate) padding: Ignored<PaddingConfig2d>,
 }
 
 impl Conv2dConfig {
@@ -137,6 +140,7 @@ impl<B: Backend> Conv2d<B> {
     /// - input: `[batch_size, channels_in, height_in, width_in]`
     /// - output: `[batch_size, channels_out, height_out, width_out]`
     pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let input = self.padding.pad_input(input);
         let [_batch_size, _channels_in, height_in, width_in] = input.dims();
         let padding =
             self.padding
@@ -150,6 +154,26 @@ impl<B: Backend> Conv2d<B> {
     }
 }
 
+impl<B: Backend> ShapePropagation for Conv2d<B> {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        let [batch_size, _channels, height, width] = shape_dims(input);
+        let [channels_out, channels_per_group, _, _] = self.weight.dims();
+        let [height_out, width_out] = self.padding.output_size_2d(
+            height,
+            width,
+            &self.kernel_size,
+            &self.stride,
+            &self.dilation,
+        );
+
+        let output = [batch_size, channels_out, height_out, width_out];
+        let macs = channels_per_group * self.kernel_size[0] * self.kernel_size[1];
+        summary.add_flops(num_elements(&output) * 2 * macs as u64);
+
+        output.to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +238,33 @@ mod tests {
 
         assert_eq!(config.initializer, init);
     }
+
+    #[test]
+    fn reflect_padding() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([1, 1], [3, 3])
+            .with_padding(PaddingConfig2d::Reflect(1, 1))
+            .with_initializer(Initializer::Ones)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+
+        let output = conv.forward(input);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[[27.0, 24.0], [21.0, 18.0]]]]), false);
+    }
+
+    #[test]
+    #[should_panic = "Reflect padding (2) must be smaller than the padded dimension (2)."]
+    fn reflect_padding_larger_than_input() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([1, 1], [3, 3])
+            .with_padding(PaddingConfig2d::Reflect(2, 2))
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::zeros([1, 1, 2, 2], &device);
+
+        let _ = conv.forward(input);
+    }
 }
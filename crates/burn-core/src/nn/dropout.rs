@@ -1,7 +1,9 @@
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::{DisplaySettings, Module, ModuleDisplay};
+use crate::module::{DisplaySettings, Module, ModuleDisplay, ShapePropagation, SummaryBuilder};
 use crate::tensor::backend::Backend;
 use crate::tensor::{Distribution, Tensor};
 
@@ -23,7 +25,7 @@ pub struct DropoutConfig {
 #[derive(Module, Clone, Debug)]
 #[module(custom_display)]
 pub struct Dropout {
-    prob: f64,
+    pub(crate) prob: f64,
 }
 
 impl DropoutConfig {
@@ -55,6 +57,12 @@ impl Dropout {
     }
 }
 
+impl ShapePropagation for Dropout {
+    fn propagate_shape(&self, input: &[usize], _summary: &mut SummaryBuilder) -> Vec<usize> {
+        input.to_vec()
+    }
+}
+
 impl ModuleDisplay for Dropout {
     fn custom_settings(&self) -> Option<DisplaySettings> {
         DisplaySettings::new()
@@ -1,9 +1,12 @@
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use super::Initializer;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
+use crate::module::{shape_dims, ShapePropagation, SummaryBuilder};
 use crate::tensor::backend::Backend;
 use crate::tensor::Int;
 use crate::tensor::Tensor;
@@ -24,6 +27,31 @@ pub struct EmbeddingConfig {
 
 /// Lookup table to store a fix number of vectors.
 ///
+/// # Weight tying
+///
+/// The output projection of a language model can share the weight of its input embedding, as in
+/// GPT-2 or T5, with a [TiedLinear] layer, which only has a bias and projects onto the weight of
+/// the embedding it is given, or directly with [attend](Embedding::attend). Since the weight is
+/// a single parameter of the embedding, it's counted once, saved once in the record, updated
+/// once by the optimizers, and receives the gradients of both the embedding and the projection.
+///
+/// ```rust, ignore
+/// #[derive(Module, Debug)]
+/// struct LanguageModel<B: Backend> {
+///     embedding: Embedding<B>,
+///     decoder: TransformerDecoder<B>,
+///     output: TiedLinear<B>,
+/// }
+///
+/// impl<B: Backend> LanguageModel<B> {
+///     fn forward(&self, tokens: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+///         let hidden = self.decoder.forward(self.embedding.forward(tokens));
+///         // [batch_size, seq_length, d_model] -> [batch_size, seq_length, n_embedding]
+///         self.output.forward(hidden, &self.embedding)
+///     }
+/// }
+/// ```
+///
 /// Should be created with [EmbeddingConfig].
 #[derive(Module, Debug)]
 pub struct Embedding<B: Backend> {
@@ -55,13 +83,106 @@ impl<B: Backend> Embedding<B> {
     pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
         embedding(self.weight.val(), input)
     }
+
+    /// Project the input vectors onto the embedding vectors, computing their dot products with
+    /// the transposed weight, e.g. the logits of a language model whose output projection is
+    /// tied to its input embedding.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_model]`
+    /// - output: `[..., n_embedding]`
+    pub fn attend<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if D == 1 {
+            // Insert and remove an extra batch dimension for the batch matmul to work.
+            return Self::attend::<2>(self, input.unsqueeze()).flatten(0, 1);
+        }
+
+        input.matmul(self.weight.val().transpose().unsqueeze())
+    }
+}
+
+/// Configuration to create a [TiedLinear](TiedLinear) layer using the
+/// [init function](TiedLinearConfig::init).
+#[derive(Config)]
+pub struct TiedLinearConfig {
+    /// The number of embedding vectors of the tied embedding, the size of the output features.
+    pub n_embedding: usize,
+    /// If a bias should be applied during the projection.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize the bias.
+    #[config(default = "Initializer::Zeros")]
+    pub initializer: Initializer,
+}
+
+/// A linear output layer whose weight is tied to the weight of an [embedding](Embedding),
+/// projecting its input onto the embedding vectors with [attend](Embedding::attend).
+///
+/// The layer only has its bias: the weight stays a parameter of the embedding, so a model
+/// containing both has a single weight in its record and its gradients.
+///
+/// Only the output projection can be tied to an embedding this way, there is no general
+/// mechanism to share a parameter between modules. Cloning a [Param] into two modules doesn't
+/// tie them: each module has its own copy in the record, and the copies aren't kept identical by
+/// the optimizers nor when loading a record. Other modules sharing weights should hold them in a single module and pass it to the
+/// others in their forward pass, like this layer does with the embedding.
+///
+/// Should be created with [TiedLinearConfig].
+#[derive(Module, Debug)]
+pub struct TiedLinear<B: Backend> {
+    /// The learnable bias of the module of shape `[n_embedding]`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl TiedLinearConfig {
+    /// Initialize a new [tied linear](TiedLinear) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> TiedLinear<B> {
+        let bias = self
+            .bias
+            .then(|| self.initializer.init([self.n_embedding], device));
+
+        TiedLinear { bias }
+    }
+}
+
+impl<B: Backend> TiedLinear<B> {
+    /// Applies the forward pass on the input tensor, with the weight of the given embedding.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_model]`
+    /// - output: `[..., n_embedding]`
+    pub fn forward<const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        embedding: &Embedding<B>,
+    ) -> Tensor<B, D> {
+        let output = embedding.attend(input);
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+impl<B: Backend> ShapePropagation for Embedding<B> {
+    fn propagate_shape(&self, input: &[usize], _summary: &mut SummaryBuilder) -> Vec<usize> {
+        let [batch_size, seq_length] = shape_dims(input);
+        let [_, d_model] = self.weight.dims();
+
+        alloc::vec![batch_size, seq_length, d_model]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::optim::GradientsParams;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
     use crate::tensor::TensorData;
-    use crate::TestBackend;
+    use crate::{TestAutodiffBackend, TestBackend};
 
     #[test]
     fn initializer_default() {
@@ -100,4 +221,131 @@ mod tests {
             .to_data()
             .assert_approx_eq(&TensorData::zeros::<f32, _>(embed.weight.shape()), 3);
     }
+
+    #[test]
+    fn attend_should_project_onto_the_embedding_vectors() {
+        let device = Default::default();
+        let embed = Embedding::<TestBackend> {
+            weight: Param::from_data([[1.0, 0.0], [0.0, 1.0], [1.0, -1.0]], &device),
+        };
+        let input = Tensor::<TestBackend, 3>::from_floats([[[2.0, 3.0]]], &device);
+
+        embed
+            .attend(input.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([[[2.0f32, 3.0, -1.0]]]), false);
+        embed
+            .attend(input.reshape([2]))
+            .into_data()
+            .assert_eq(&TensorData::from([2.0f32, 3.0, -1.0]), false);
+    }
+
+    #[test]
+    fn tied_weight_should_receive_the_gradients_of_both_uses() {
+        let device = Default::default();
+        let embed = EmbeddingConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let tokens = Tensor::<TestAutodiffBackend, 2, Int>::from_ints([[0, 2, 2]], &device);
+
+        let logits = embed.attend(embed.forward(tokens.clone()));
+        let grads = logits.sum().backward();
+        let grad = embed.weight.grad(&grads).unwrap();
+
+        // The same computation with an untied copy of the weight.
+        let weight_1 = embed.weight.val().detach().require_grad();
+        let weight_2 = embed.weight.val().detach().require_grad();
+        let logits =
+            embedding(weight_1.clone(), tokens).matmul(weight_2.clone().transpose().unsqueeze());
+        let grads = logits.sum().backward();
+        let expected = weight_1.grad(&grads).unwrap() + weight_2.grad(&grads).unwrap();
+
+        grad.into_data().assert_approx_eq(&expected.into_data(), 4);
+        assert_eq!(embed.num_params(), 12);
+    }
+
+    #[derive(Module, Debug)]
+    struct TiedModel<B: Backend> {
+        embedding: Embedding<B>,
+        output: TiedLinear<B>,
+    }
+
+    impl<B: Backend> TiedModel<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                embedding: EmbeddingConfig::new(4, 3).init(device),
+                output: TiedLinearConfig::new(4)
+                    .with_initializer(Initializer::Normal {
+                        mean: 0.0,
+                        std: 1.0,
+                    })
+                    .init(device),
+            }
+        }
+
+        fn forward(&self, tokens: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+            self.output
+                .forward(self.embedding.forward(tokens), &self.embedding)
+        }
+    }
+
+    #[test]
+    fn tied_linear_should_share_a_single_weight_gradient() {
+        let device = Default::default();
+        let model = TiedModel::<TestAutodiffBackend>::new(&device);
+        let tokens = Tensor::<TestAutodiffBackend, 2, Int>::from_ints([[0, 2, 2]], &device);
+
+        let grads = model.forward(tokens.clone()).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &model);
+
+        // The weight of the embedding and the bias.
+        assert_eq!(grads.len(), 2);
+        assert_eq!(model.num_params(), 4 * 3 + 4);
+
+        // The same computation with an untied copy of the weight.
+        let weight_1 = model.embedding.weight.val().detach().require_grad();
+        let weight_2 = model.embedding.weight.val().detach().require_grad();
+        let logits = embedding(weight_1.clone(), tokens)
+            .matmul(weight_2.clone().transpose().unsqueeze())
+            + model
+                .output
+                .bias
+                .as_ref()
+                .unwrap()
+                .val()
+                .detach()
+                .unsqueeze();
+        let untied_grads = logits.sum().backward();
+        let expected =
+            weight_1.grad(&untied_grads).unwrap() + weight_2.grad(&untied_grads).unwrap();
+
+        grads
+            .get::<TestBackend, 2>(&model.embedding.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn tied_linear_should_keep_the_tied_weight_through_the_record() {
+        let device = Default::default();
+        let model = TiedModel::<TestBackend>::new(&device);
+        let tokens = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1, 3]], &device);
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+        let loaded = TiedModel::<TestBackend>::new(&device)
+            .load_record(recorder.load(bytes, &device).unwrap());
+
+        // The output layer only records its bias.
+        assert_eq!(loaded.output.num_params(), 4);
+        loaded
+            .embedding
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&model.embedding.weight.val().into_data(), true);
+        loaded
+            .forward(tokens.clone())
+            .into_data()
+            .assert_approx_eq(&model.forward(tokens).into_data(), 4);
+    }
 }
@@ -0,0 +1,250 @@
+use crate as burn;
+
+use super::Initializer;
+use crate::config::Config;
+use crate::module::{Ignored, Module, Param};
+use crate::tensor::backend::Backend;
+use crate::tensor::module::embedding;
+use crate::tensor::{Int, Tensor};
+
+/// How the embeddings of a bag are reduced by an [EmbeddingBag](EmbeddingBag).
+#[derive(Config, Debug, Copy, PartialEq, Eq)]
+pub enum EmbeddingBagMode {
+    /// Sum of the embeddings, optionally weighted.
+    Sum,
+    /// Mean of the embeddings.
+    Mean,
+    /// Maximum of each feature of the embeddings.
+    Max,
+}
+
+/// Configuration to create an [EmbeddingBag](EmbeddingBag) layer using the
+/// [init function](EmbeddingBagConfig::init).
+#[derive(Config, Debug)]
+pub struct EmbeddingBagConfig {
+    /// The number of embedding vectors.
+    pub n_embedding: usize,
+    /// The size of each vector.
+    pub d_model: usize,
+    /// How the embeddings of a bag are reduced. Default: Mean
+    #[config(default = "EmbeddingBagMode::Mean")]
+    pub mode: EmbeddingBagMode,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// Lookup table reducing the vectors of bags of indices, without materializing the vectors of
+/// each index, e.g. for the sparse features of recommendation models.
+///
+/// The bags either have the same size, or are given as a flat list of indices with the offset
+/// of each bag.
+///
+/// Should be created with [EmbeddingBagConfig].
+#[derive(Module, Debug)]
+pub struct EmbeddingBag<B: Backend> {
+    /// The learnable weights of the module of shape `[n_embedding, d_model]` initialized
+    /// from a normal distribution `N(0, 1)`.
+    pub weight: Param<Tensor<B, 2>>,
+    mode: Ignored<EmbeddingBagMode>,
+}
+
+impl EmbeddingBagConfig {
+    /// Initialize a new [embedding bag](EmbeddingBag) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> EmbeddingBag<B> {
+        let weight = self
+            .initializer
+            .init([self.n_embedding, self.d_model], device);
+
+        EmbeddingBag {
+            weight,
+            mode: Ignored(self.mode),
+        }
+    }
+}
+
+impl<B: Backend> EmbeddingBag<B> {
+    /// Applies the forward pass on bags of the same size, the embeddings of each being weighted
+    /// by the given weights, only supported by the sum mode.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, bag_size]`
+    /// - per_sample_weights: `[batch_size, bag_size]`
+    /// - output: `[batch_size, d_model]`
+    pub fn forward(
+        &self,
+        input: Tensor<B, 2, Int>,
+        per_sample_weights: Option<Tensor<B, 2>>,
+    ) -> Tensor<B, 2> {
+        let [batch_size, bag_size] = input.dims();
+        let [_, d_model] = self.weight.dims();
+        let embeddings = embedding(self.weight.val(), input);
+
+        let output = match *self.mode {
+            EmbeddingBagMode::Sum => {
+                let embeddings = match per_sample_weights {
+                    Some(weights) => embeddings.mul(weights.reshape([batch_size, bag_size, 1])),
+                    None => embeddings,
+                };
+                embeddings.sum_dim(1)
+            }
+            EmbeddingBagMode::Mean => {
+                Self::assert_no_weights(per_sample_weights.is_some());
+                embeddings.mean_dim(1)
+            }
+            EmbeddingBagMode::Max => {
+                Self::assert_no_weights(per_sample_weights.is_some());
+                embeddings.max_dim(1)
+            }
+        };
+
+        output.reshape([batch_size, d_model])
+    }
+
+    /// Applies the forward pass on bags of any size, given as a flat list of indices with the
+    /// offset of the first index of each bag, in increasing order.
+    ///
+    /// The bags are reduced with [segment reductions](Tensor::segment_sum) on the device, the
+    /// output of empty bags being zeros. The embeddings of each index can be weighted by the
+    /// given weights, only supported by the sum mode.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[num_indices]`
+    /// - offsets: `[num_bags]`
+    /// - per_sample_weights: `[num_indices]`
+    /// - output: `[num_bags, d_model]`
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn forward_offsets(
+        &self,
+        input: Tensor<B, 1, Int>,
+        offsets: Tensor<B, 1, Int>,
+        per_sample_weights: Option<Tensor<B, 1>>,
+    ) -> Tensor<B, 2> {
+        let [num_indices] = input.dims();
+        let [num_bags] = offsets.dims();
+        let device = input.device();
+
+        // The bag of each index is the number of offsets it reaches, minus one.
+        let positions = Tensor::<B, 1, Int>::arange(0..num_indices as i64, &device)
+            .reshape([num_indices, 1])
+            .expand([num_indices, num_bags]);
+        let bags = positions
+            .greater_equal(
+                offsets
+                    .reshape([1, num_bags])
+                    .expand([num_indices, num_bags]),
+            )
+            .int()
+            .sum_dim(1)
+            .sub_scalar(1)
+            .reshape([num_indices]);
+
+        let embeddings = self.weight.val().select(0, input);
+
+        match *self.mode {
+            EmbeddingBagMode::Sum => {
+                let embeddings = match per_sample_weights {
+                    Some(weights) => embeddings.mul(weights.reshape([num_indices, 1])),
+                    None => embeddings,
+                };
+                embeddings.segment_sum(bags, num_bags)
+            }
+            EmbeddingBagMode::Mean => {
+                Self::assert_no_weights(per_sample_weights.is_some());
+                let counts = Tensor::<B, 2>::ones([num_indices, 1], &device)
+                    .segment_sum(bags.clone(), num_bags)
+                    .clamp_min(1.0);
+                embeddings.segment_sum(bags, num_bags).div(counts)
+            }
+            EmbeddingBagMode::Max => {
+                Self::assert_no_weights(per_sample_weights.is_some());
+                embeddings.segment_max(bags, num_bags)
+            }
+        }
+    }
+
+    fn assert_no_weights(has_weights: bool) {
+        assert!(
+            !has_weights,
+            "Per sample weights are only supported by the sum mode."
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    fn embedding_bag(mode: EmbeddingBagMode) -> EmbeddingBag<TestBackend> {
+        let device = Default::default();
+        let mut embedding_bag = EmbeddingBagConfig::new(4, 2)
+            .with_mode(mode)
+            .init::<TestBackend>(&device);
+        embedding_bag.weight =
+            Param::from_data([[1.0, -1.0], [2.0, 0.0], [-3.0, 4.0], [0.5, 0.5]], &device);
+
+        embedding_bag
+    }
+
+    #[test]
+    fn test_embedding_bag_modes() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1, 2], [3, 3, 1]], &device);
+
+        let sum = embedding_bag(EmbeddingBagMode::Sum).forward(input.clone(), None);
+        let mean = embedding_bag(EmbeddingBagMode::Mean).forward(input.clone(), None);
+        let max = embedding_bag(EmbeddingBagMode::Max).forward(input, None);
+
+        sum.into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 3.0], [3.0, 1.0]]), 3);
+        mean.into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 1.0], [1.0, 0.333333]]), 3);
+        max.into_data()
+            .assert_approx_eq(&TensorData::from([[2.0, 4.0], [2.0, 0.5]]), 3);
+    }
+
+    #[test]
+    fn test_embedding_bag_per_sample_weights() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2, Int>::from_ints([[0, 2]], &device);
+        let weights = Tensor::<TestBackend, 2>::from_floats([[2.0, 0.5]], &device);
+
+        let output = embedding_bag(EmbeddingBagMode::Sum).forward(input, Some(weights));
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.5, 0.0]]), 3);
+    }
+
+    #[test]
+    fn test_embedding_bag_offsets() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 1, Int>::from_ints([0, 1, 2, 3, 1], &device);
+        // The second bag is empty.
+        let offsets = Tensor::<TestBackend, 1, Int>::from_ints([0, 3, 3], &device);
+        let weights = Tensor::<TestBackend, 1>::from_floats([1.0, 1.0, 1.0, 2.0, -1.0], &device);
+
+        let sum = embedding_bag(EmbeddingBagMode::Sum).forward_offsets(
+            input.clone(),
+            offsets.clone(),
+            Some(weights),
+        );
+        let mean = embedding_bag(EmbeddingBagMode::Mean).forward_offsets(
+            input.clone(),
+            offsets.clone(),
+            None,
+        );
+        let max = embedding_bag(EmbeddingBagMode::Max).forward_offsets(input, offsets, None);
+
+        sum.into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 3.0], [0.0, 0.0], [-1.0, 1.0]]), 3);
+        mean.into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 1.0], [0.0, 0.0], [1.25, 0.25]]), 3);
+        max.into_data()
+            .assert_approx_eq(&TensorData::from([[2.0, 4.0], [0.0, 0.0], [2.0, 0.5]]), 3);
+    }
+}
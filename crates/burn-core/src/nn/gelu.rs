@@ -1,6 +1,9 @@
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::module::Module;
+use crate::module::{num_elements, ShapePropagation, SummaryBuilder};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
@@ -25,3 +28,11 @@ impl Gelu {
         crate::tensor::activation::gelu(input)
     }
 }
+
+impl ShapePropagation for Gelu {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        summary.add_flops(num_elements(input));
+
+        input.to_vec()
+    }
+}
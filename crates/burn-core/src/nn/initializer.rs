@@ -3,7 +3,7 @@ use crate::tensor::Shape;
 use crate::config::Config;
 use crate::module::{Param, ParamId};
 use crate::tensor::backend::Backend;
-use crate::tensor::{Distribution, Tensor};
+use crate::tensor::{linalg::QrMode, Distribution, Tensor};
 
 use crate as burn;
 
@@ -68,6 +68,66 @@ pub enum Initializer {
         /// The gain to use in initialization formula
         gain: f64,
     },
+    /// Fills tensor with values drawn from a normal distribution with specified mean and std,
+    /// truncated to the specified bounds
+    TruncatedNormal {
+        /// The mean of the normal distribution
+        mean: f64,
+
+        /// The standard deviation of the normal distribution
+        std: f64,
+
+        /// The minimum value to draw
+        min: f64,
+
+        /// The maximum value to draw
+        max: f64,
+    },
+    /// Fills tensor with a (semi) orthogonal matrix, as described in [Exact solutions to the
+    /// nonlinear dynamics of learning in deep linear neural networks](https://arxiv.org/abs/1312.6120)
+    ///
+    /// The first dimension holds the rows of the matrix, and the other ones are flattened into
+    /// its columns.
+    Orthogonal {
+        /// The gain to use in initialization formula
+        gain: f64,
+    },
+    /// Fills tensor with values whose variance is scaled by the number of input and/or output
+    /// units, generalizing the Kaiming and Xavier initializations
+    VarianceScaling {
+        /// The scale of the variance, e.g. 2.0 for Kaiming and 1.0 for Xavier initialization
+        scale: f64,
+
+        /// The number of units dividing the variance
+        mode: FanMode,
+
+        /// The distribution the values are drawn from
+        distribution: VarianceScalingDistribution,
+    },
+}
+
+/// The number of units dividing the variance of the
+/// [variance scaling initialization](Initializer::VarianceScaling).
+#[derive(Config, Debug, Copy, PartialEq, Eq)]
+pub enum FanMode {
+    /// The number of input units.
+    In,
+    /// The number of output units.
+    Out,
+    /// The average of the number of input and output units.
+    Avg,
+}
+
+/// The distribution of the [variance scaling initialization](Initializer::VarianceScaling).
+#[derive(Config, Debug, Copy, PartialEq, Eq)]
+pub enum VarianceScalingDistribution {
+    /// A normal distribution truncated to two standard deviations, the standard deviation being
+    /// adjusted so that the variance is the scaled one after the truncation.
+    TruncatedNormal,
+    /// A normal distribution.
+    Normal,
+    /// A uniform distribution.
+    Uniform,
 }
 
 impl Initializer {
@@ -146,6 +206,34 @@ impl Initializer {
                 let std = *gain * self.xavier_std(fan_in, fan_out);
                 normal_draw(shape, 0.0, std, device)
             }
+            Initializer::TruncatedNormal {
+                mean,
+                std,
+                min,
+                max,
+            } => truncated_normal_draw(shape, *mean, *std, *min, *max, device),
+            Initializer::Orthogonal { gain } => orthogonal_draw(shape, *gain, device),
+            Initializer::VarianceScaling {
+                scale,
+                mode,
+                distribution,
+            } => {
+                let std = (*scale / self.variance_scaling_fan(*mode, fan_in, fan_out)).sqrt();
+
+                match distribution {
+                    VarianceScalingDistribution::TruncatedNormal => {
+                        // The standard deviation of a standard normal distribution truncated to
+                        // [-2, 2].
+                        let std = std / 0.879_625_661_034_239_8;
+                        truncated_normal_draw(shape, 0.0, std, -2.0 * std, 2.0 * std, device)
+                    }
+                    VarianceScalingDistribution::Normal => normal_draw(shape, 0.0, std, device),
+                    VarianceScalingDistribution::Uniform => {
+                        let a = 3.0f64.sqrt() * std;
+                        uniform_draw(shape, -a, a, device)
+                    }
+                }
+            }
         }
     }
 
@@ -174,6 +262,28 @@ impl Initializer {
         );
         (2.0 / (fan_in + fan_out) as f64).sqrt()
     }
+
+    fn variance_scaling_fan(
+        &self,
+        mode: FanMode,
+        fan_in: Option<usize>,
+        fan_out: Option<usize>,
+    ) -> f64 {
+        let expect_fan = |fan: Option<usize>, name: &str| {
+            fan.unwrap_or_else(|| {
+                panic!(
+                    "Can't use variance scaling initialization without specifying {name}. Use \
+                     init_with method and provide {name}."
+                )
+            }) as f64
+        };
+
+        match mode {
+            FanMode::In => expect_fan(fan_in, "fan in"),
+            FanMode::Out => expect_fan(fan_out, "fan out"),
+            FanMode::Avg => (expect_fan(fan_in, "fan in") + expect_fan(fan_out, "fan out")) / 2.0,
+        }
+    }
 }
 
 fn uniform_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
@@ -196,6 +306,70 @@ fn normal_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
     Tensor::<B, D>::random(shape, distribution, device)
 }
 
+/// Draws from a truncated normal distribution by inverse transform sampling, mapping uniform
+/// values between the cumulative distribution function of the bounds through the inverse of the
+/// cumulative distribution function.
+fn truncated_normal_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
+    shape: S,
+    mean: f64,
+    std: f64,
+    min: f64,
+    max: f64,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    assert!(
+        min < max,
+        "The minimum ({min}) should be lower than the maximum ({max}) of the truncated normal \
+         distribution."
+    );
+    let shape = shape.into();
+    let sqrt_2 = core::f64::consts::SQRT_2;
+
+    // The cumulative distribution function is `(1 + erf(x / sqrt(2))) / 2`, the uniform values
+    // are drawn between the error functions of the bounds instead.
+    let low = Tensor::<B, D>::full(shape.clone(), (min - mean) / (std * sqrt_2), device).erf();
+    let high = Tensor::<B, D>::full(shape.clone(), (max - mean) / (std * sqrt_2), device).erf();
+    let uniform = uniform_draw(shape, 0.0, 1.0, device);
+
+    (low.clone() + (high - low) * uniform)
+        .erfinv()
+        .mul_scalar(std * sqrt_2)
+        .add_scalar(mean)
+        // Keep the values in the bounds despite rounding errors.
+        .clamp(min, max)
+}
+
+/// Draws a random (semi) orthogonal matrix from the QR decomposition of a matrix drawn from a
+/// standard normal distribution, flattening all the dimensions but the first into the columns.
+fn orthogonal_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
+    shape: S,
+    gain: f64,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let shape = shape.into();
+    assert!(
+        D >= 2,
+        "Can't use orthogonal initialization on {D}D tensors, only on tensors with at least 2 \
+         dimensions."
+    );
+    let rows = shape.dims[0];
+    let columns = shape.num_elements() / rows;
+
+    // The QR decomposition gives orthonormal columns, so the matrix is transposed when it has
+    // more columns than rows. Since the diagonal of R is non-negative, Q is uniformly
+    // distributed.
+    let matrix = if rows < columns {
+        let (q, _) = normal_draw::<B, 2, _>([columns, rows], 0.0, 1.0, device).qr(QrMode::Reduced);
+        q.transpose()
+    } else {
+        normal_draw::<B, 2, _>([rows, columns], 0.0, 1.0, device)
+            .qr(QrMode::Reduced)
+            .0
+    };
+
+    matrix.mul_scalar(gain).reshape(shape)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,4 +608,126 @@ mod tests {
             .init([fan_out, fan_in], &Default::default())
             .into_value();
     }
+
+    #[test]
+    fn initializer_truncated_normal_init() {
+        TB::seed(0);
+
+        let (mean, std, min, max) = (1.0, 2.0, -1.0, 3.0);
+        let tensor: Tensor<TB, 2> = Initializer::TruncatedNormal {
+            mean,
+            std,
+            min,
+            max,
+        }
+        .init([100, 100], &Default::default())
+        .into_value();
+
+        tensor
+            .clone()
+            .into_data()
+            .assert_within_range(min..max + 1e-6);
+        // The bounds are symmetric around the mean.
+        let mean_act: f32 = tensor.mean().into_scalar().elem();
+        assert!(
+            (mean_act - 1.0).abs() < 0.05,
+            "Expected mean to be between 1.0 += 0.05, but got {mean_act}"
+        );
+    }
+
+    #[test]
+    fn initializer_orthogonal_init() {
+        TB::seed(0);
+
+        let gain = 2.0;
+        let wide: Tensor<TB, 2> = Initializer::Orthogonal { gain }
+            .init([3, 5], &Default::default())
+            .into_value();
+        let tall: Tensor<TB, 3> = Initializer::Orthogonal { gain }
+            .init([6, 2, 2], &Default::default())
+            .into_value();
+
+        // The rows of wide matrices and the columns of tall ones are orthogonal.
+        wide.clone()
+            .matmul(wide.transpose())
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TB, 2>::eye(3, &Default::default())
+                    .mul_scalar(4.0)
+                    .into_data(),
+                3,
+            );
+        let tall = tall.reshape([6, 4]);
+        tall.clone()
+            .transpose()
+            .matmul(tall)
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TB, 2>::eye(4, &Default::default())
+                    .mul_scalar(4.0)
+                    .into_data(),
+                3,
+            );
+    }
+
+    #[test]
+    fn initializer_variance_scaling_init() {
+        TB::seed(0);
+
+        let scale = 2.0;
+        let (fan_in, fan_out) = (1000, 10);
+        let expected_var = scale / ((fan_in + fan_out) as f64 / 2.0);
+
+        for distribution in [
+            VarianceScalingDistribution::TruncatedNormal,
+            VarianceScalingDistribution::Normal,
+            VarianceScalingDistribution::Uniform,
+        ] {
+            let tensor: Tensor<TB, 2> = Initializer::VarianceScaling {
+                scale,
+                mode: FanMode::Avg,
+                distribution,
+            }
+            .init_with(
+                [fan_out, fan_in],
+                Some(fan_in),
+                Some(fan_out),
+                &Default::default(),
+            )
+            .into_value();
+
+            assert_normal_init(0.0, expected_var, &tensor);
+        }
+    }
+
+    #[test]
+    fn initializer_variance_scaling_truncated_normal_bounds() {
+        TB::seed(0);
+
+        let fan_in = 4;
+        let std = (1.0 / fan_in as f64).sqrt() / 0.879_625_661_034_239_8;
+        let tensor: Tensor<TB, 2> = Initializer::VarianceScaling {
+            scale: 1.0,
+            mode: FanMode::In,
+            distribution: VarianceScalingDistribution::TruncatedNormal,
+        }
+        .init_with([100, fan_in], Some(fan_in), None, &Default::default())
+        .into_value();
+
+        tensor
+            .into_data()
+            .assert_within_range(-2.0 * std..2.0 * std + 1e-6);
+    }
+
+    #[test]
+    #[should_panic = "Can't use variance scaling initialization without specifying fan out."]
+    fn initializer_variance_scaling_no_fan() {
+        let _: Tensor<TB, 2> = Initializer::VarianceScaling {
+            scale: 1.0,
+            mode: FanMode::Out,
+            distribution: VarianceScalingDistribution::Normal,
+        }
+        .init_with([5, 6], Some(6), None, &Default::default())
+        .into_value();
+    }
 }
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate as burn;
 use crate::module::DisplaySettings;
 use crate::module::ModuleDisplay;
@@ -5,6 +7,7 @@ use crate::module::ModuleDisplay;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
+use crate::module::{num_elements, ShapePropagation, SummaryBuilder};
 use crate::tensor::{backend::Backend, Tensor};
 
 use super::Initializer;
@@ -86,6 +89,21 @@ impl<B: Backend> Linear<B> {
     }
 }
 
+impl<B: Backend> ShapePropagation for Linear<B> {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        let [d_input, d_output] = self.weight.dims();
+        let num_rows = num_elements(input) / d_input.max(1) as u64;
+        let bias = self.bias.as_ref().map(|_| d_output as u64).unwrap_or(0);
+        summary.add_flops(num_rows * (2 * d_input as u64 * d_output as u64 + bias));
+
+        let mut output = input.to_vec();
+        if let Some(last) = output.last_mut() {
+            *last = d_output;
+        }
+        output
+    }
+}
+
 impl<B: Backend> ModuleDisplay for Linear<B> {
     fn custom_settings(&self) -> Option<DisplaySettings> {
         DisplaySettings::new()
@@ -0,0 +1,132 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Ignored, Module};
+use crate::nn::conv::{Conv2d, Conv2dConfig};
+use crate::nn::{DropoutConfig, Initializer, Linear, LinearConfig};
+use crate::tensor::{backend::Backend, quantization::QuantizationType};
+
+use super::{quantized::quantize_per_column, LoraConv2d, LoraLinear, QLoraLinear};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to wrap a layer with low-rank adapters, creating a [LoraLinear](LoraLinear)
+/// with [init_linear](LoraConfig::init_linear), a [QLoraLinear](QLoraLinear) with
+/// [init_quantized_linear](LoraConfig::init_quantized_linear) or a [LoraConv2d](LoraConv2d) with
+/// [init_conv2d](LoraConfig::init_conv2d).
+///
+/// The weights of the wrapped layer are frozen, and the update learned by the adapters is the
+/// product of a down projection to the rank and of an up projection, scaled by `alpha / rank`.
+///
+/// Reference: <https://arxiv.org/abs/2106.09685>
+#[derive(Config, Debug)]
+pub struct LoraConfig {
+    /// The rank of the adapters.
+    pub rank: usize,
+    /// The numerator of the scaling of the update, `alpha / rank`. Default: 1.0
+    #[config(default = 1.0)]
+    pub alpha: f64,
+    /// The dropout rate applied to the input of the adapters. Default: 0.0
+    #[config(default = 0.0)]
+    pub dropout: f64,
+}
+
+impl LoraConfig {
+    /// Wrap the [linear](Linear) layer with adapters, its parameters being frozen.
+    ///
+    /// The down projection is initialized from a uniform distribution, and the up projection
+    /// with zeros, so that the wrapped layer initially has the same output.
+    pub fn init_linear<B: Backend>(&self, linear: Linear<B>) -> LoraLinear<B> {
+        assert!(self.rank > 0, "The rank of the adapters must be positive.");
+
+        let [d_input, d_output] = linear.weight.dims();
+        let device = linear.weight.device();
+
+        LoraLinear {
+            base: linear.no_grad(),
+            lora_a: LinearConfig::new(d_input, self.rank)
+                .with_bias(false)
+                .with_initializer(self.initializer())
+                .init(&device),
+            lora_b: LinearConfig::new(self.rank, d_output)
+                .with_bias(false)
+                .with_initializer(Initializer::Zeros)
+                .init(&device),
+            dropout: DropoutConfig::new(self.dropout).init(),
+            scaling: self.alpha / self.rank as f64,
+            merged: false,
+        }
+    }
+
+    /// Quantize the weights of the [linear](Linear) layer to integers of the given type, and wrap
+    /// it with adapters, its weights and bias being frozen.
+    ///
+    /// The adapters are initialized as with [init_linear](LoraConfig::init_linear).
+    pub fn init_quantized_linear<B: Backend>(
+        &self,
+        linear: Linear<B>,
+        q_type: QuantizationType,
+    ) -> QLoraLinear<B> {
+        let lora = self.init_linear(linear);
+        let (weight, scale) = quantize_per_column(lora.base.weight.val(), q_type);
+
+        QLoraLinear {
+            weight,
+            scale,
+            bias: lora.base.bias,
+            lora_a: lora.lora_a,
+            lora_b: lora.lora_b,
+            dropout: lora.dropout,
+            scaling: lora.scaling,
+        }
+    }
+
+    /// Wrap the [2D convolution](Conv2d) with adapters, its parameters being frozen.
+    ///
+    /// The down projection is a convolution with the same kernel, stride, dilation and padding
+    /// to the rank channels, initialized from a uniform distribution, and the up projection a
+    /// `1x1` convolution initialized with zeros, so that the wrapped layer initially has the same
+    /// output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the convolution is grouped.
+    pub fn init_conv2d<B: Backend>(&self, conv: Conv2d<B>) -> LoraConv2d<B> {
+        assert!(self.rank > 0, "The rank of the adapters must be positive.");
+        assert_eq!(
+            conv.groups, 1,
+            "Grouped convolutions can't be wrapped with adapters."
+        );
+
+        let [channels_out, channels_in, _, _] = conv.weight.dims();
+        let device = conv.weight.device();
+
+        let mut lora_a = Conv2dConfig::new([channels_in, self.rank], conv.kernel_size)
+            .with_bias(false)
+            .with_initializer(self.initializer())
+            .init(&device);
+        lora_a.stride = conv.stride;
+        lora_a.dilation = conv.dilation;
+        lora_a.padding = Ignored(conv.padding.0.clone());
+
+        LoraConv2d {
+            base: conv.no_grad(),
+            lora_a,
+            lora_b: Conv2dConfig::new([self.rank, channels_out], [1, 1])
+                .with_bias(false)
+                .with_initializer(Initializer::Zeros)
+                .init(&device),
+            dropout: DropoutConfig::new(self.dropout).init(),
+            scaling: self.alpha / self.rank as f64,
+            merged: false,
+        }
+    }
+
+    fn initializer(&self) -> Initializer {
+        Initializer::KaimingUniform {
+            gain: 1.0 / 3.0f64.sqrt(),
+            fan_out_only: false,
+        }
+    }
+}
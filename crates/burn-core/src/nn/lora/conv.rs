@@ -0,0 +1,137 @@
+use crate as burn;
+
+use crate::module::Module;
+use crate::nn::{conv::Conv2d, Dropout};
+use crate::tensor::{backend::Backend, Tensor};
+
+/// A [2D convolution](Conv2d) whose frozen weights are adapted by a trainable low-rank update.
+///
+/// Should be created with [LoraConfig](super::LoraConfig::init_conv2d).
+#[derive(Module, Debug)]
+pub struct LoraConv2d<B: Backend> {
+    /// The frozen layer being adapted.
+    pub base: Conv2d<B>,
+    /// Down projection to the rank channels, with the kernel of the base layer, without bias.
+    pub lora_a: Conv2d<B>,
+    /// Up projection from the rank channels to `channels_out`, a `1x1` convolution without bias.
+    pub lora_b: Conv2d<B>,
+    pub(super) dropout: Dropout,
+    pub(super) scaling: f64,
+    pub(super) merged: bool,
+}
+
+impl<B: Backend> LoraConv2d<B> {
+    /// Applies the forward pass on the input tensor, adding the scaled update of the adapters to
+    /// the output of the base layer, unless they are [merged](LoraConv2d::merge).
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let output = self.base.forward(input.clone());
+
+        if self.merged {
+            return output;
+        }
+
+        let update = self
+            .lora_b
+            .forward(self.lora_a.forward(self.dropout.forward(input)));
+
+        output.add(update.mul_scalar(self.scaling))
+    }
+
+    /// The update of the weights of the base layer learned by the adapters.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[channels_out, channels_in, kernel_size_1, kernel_size_2]`
+    pub fn delta_weight(&self) -> Tensor<B, 4> {
+        let [rank, channels_in, kernel_size_1, kernel_size_2] = self.lora_a.weight.dims();
+        let [channels_out, _, _, _] = self.lora_b.weight.dims();
+
+        self.lora_b
+            .weight
+            .val()
+            .reshape([channels_out, rank])
+            .matmul(
+                self.lora_a
+                    .weight
+                    .val()
+                    .reshape([rank, channels_in * kernel_size_1 * kernel_size_2]),
+            )
+            .reshape([channels_out, channels_in, kernel_size_1, kernel_size_2])
+            .mul_scalar(self.scaling)
+    }
+
+    /// If the update of the adapters is merged into the weights of the base layer.
+    pub fn is_merged(&self) -> bool {
+        self.merged
+    }
+
+    /// Add the update of the adapters to the weights of the base layer, so that the forward pass
+    /// doesn't compute it anymore, usually for inference.
+    pub fn merge(mut self) -> Self {
+        if !self.merged {
+            let delta = self.delta_weight().detach();
+            self.base.weight = self.base.weight.map(|weight| {
+                let require_grad = weight.is_require_grad();
+                weight.add(delta.clone()).set_require_grad(require_grad)
+            });
+            self.merged = true;
+        }
+
+        self
+    }
+
+    /// Subtract the update of the adapters from the weights of the base layer, restoring them
+    /// after a [merge](LoraConv2d::merge).
+    pub fn unmerge(mut self) -> Self {
+        if self.merged {
+            let delta = self.delta_weight().detach();
+            self.base.weight = self.base.weight.map(|weight| {
+                let require_grad = weight.is_require_grad();
+                weight.sub(delta.clone()).set_require_grad(require_grad)
+            });
+            self.merged = false;
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::{conv::Conv2dConfig, lora::LoraConfig, PaddingConfig2d};
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_lora_conv2d_merge_should_have_same_output() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([3, 4], [3, 3])
+            .with_stride([2, 1])
+            .with_padding(PaddingConfig2d::Explicit(1, 1))
+            .init::<TestBackend>(&device);
+        let mut lora = LoraConfig::new(2).init_conv2d(conv.clone());
+        assert_eq!(lora.lora_a.weight.dims(), [2, 3, 3, 3]);
+        assert_eq!(lora.lora_b.weight.dims(), [4, 2, 1, 1]);
+
+        let input = Tensor::<TestBackend, 4>::random([2, 3, 5, 6], Distribution::Default, &device);
+        lora.forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&conv.forward(input.clone()).into_data(), 3);
+
+        lora.lora_b.weight =
+            Param::from_tensor(Tensor::random([4, 2, 1, 1], Distribution::Default, &device));
+        let output = lora.forward(input.clone());
+
+        lora.merge()
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&output.into_data(), 3);
+    }
+}
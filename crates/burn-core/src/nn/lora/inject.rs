@@ -0,0 +1,159 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::module::{Module, ModuleMapper, ParamId};
+use crate::tensor::{backend::Backend, Tensor};
+
+/// Enables the adapters of the [LoRA layers](super::LoraLinear) whose path in the module tree
+/// matches one of the patterns, making them the only trainable parameters of the module.
+///
+/// The path of a layer is made of the names of the fields leading to it, and of the indices of
+/// the vectors and arrays, separated by dots, like `layers.0.mha.query`. A `*` in a pattern
+/// matches any sequence of characters, so that `layers.*.mha.query` matches the queries of every
+/// layer.
+///
+/// Every other parameter is frozen, and the adapters of the layers which don't match are
+/// disabled by zeroing their up projection, their update being zero.
+pub fn inject_lora<B: Backend, M: Module<B>>(module: M, patterns: &[&str]) -> M {
+    let mut injector = LoraInjector {
+        patterns,
+        path: Vec::new(),
+    };
+
+    module.map(&mut injector)
+}
+
+struct LoraInjector<'a> {
+    patterns: &'a [&'a str],
+    path: Vec<String>,
+}
+
+impl<'a, B: Backend> ModuleMapper<B> for LoraInjector<'a> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.into());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        // The parameters of the adapters are the weights of their projections.
+        let adapter = match self.path.len().checked_sub(2) {
+            Some(index) => Some((index, self.path[index].as_str())),
+            None => None,
+        };
+
+        match adapter {
+            Some((index, projection @ ("lora_a" | "lora_b"))) => {
+                let layer = self.path[..index].join(".");
+
+                if self
+                    .patterns
+                    .iter()
+                    .any(|pattern| matches_pattern(pattern, &layer))
+                {
+                    tensor.set_require_grad(true)
+                } else if projection == "lora_b" {
+                    tensor.zeros_like().set_require_grad(false)
+                } else {
+                    tensor.set_require_grad(false)
+                }
+            }
+            _ => tensor.set_require_grad(false),
+        }
+    }
+}
+
+/// Returns if the path matches the pattern, a `*` matching any sequence of characters.
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let path = path.as_bytes();
+    // The position after the last `*`, and the position in the path it was matched up to.
+    let mut backtrack = None;
+    let (mut i, mut j) = (0, 0);
+
+    while j < path.len() {
+        if i < pattern.len() && pattern[i] == b'*' {
+            backtrack = Some((i + 1, j));
+            i += 1;
+        } else if i < pattern.len() && pattern[i] == path[j] {
+            i += 1;
+            j += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // The `*` matches one more character.
+            backtrack = Some((star, matched + 1));
+            i = star;
+            j = matched + 1;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[i..].iter().all(|c| *c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::Param;
+    use crate::nn::{
+        lora::{LoraConfig, LoraLinear},
+        LinearConfig,
+    };
+    use crate::tensor::Distribution;
+    use crate::TestAutodiffBackend;
+
+    #[derive(Module, Debug)]
+    struct Block<B: Backend> {
+        query: LoraLinear<B>,
+        value: LoraLinear<B>,
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("layers.*.query", "layers.0.query"));
+        assert!(matches_pattern("*query", "layers.10.query"));
+        assert!(matches_pattern("*", "layers.0.value"));
+        assert!(!matches_pattern("layers.*.query", "layers.0.value"));
+        assert!(!matches_pattern("layers.0", "layers.0.query"));
+    }
+
+    #[test]
+    fn test_inject_lora_should_only_train_matching_adapters() {
+        let device = Default::default();
+        let config = LoraConfig::new(2);
+        let layer = || {
+            let mut lora =
+                config.init_linear(LinearConfig::new(4, 4).init::<TestAutodiffBackend>(&device));
+            lora.lora_b.weight =
+                Param::from_tensor(Tensor::random([2, 4], Distribution::Default, &device));
+            lora
+        };
+        let blocks = alloc::vec![
+            Block {
+                query: layer(),
+                value: layer(),
+            },
+            Block {
+                query: layer(),
+                value: layer(),
+            },
+        ];
+
+        let blocks = inject_lora(blocks, &["*.query"]);
+
+        for block in blocks.iter() {
+            assert!(!block.query.base.weight.is_require_grad());
+            assert!(block.query.lora_a.weight.is_require_grad());
+            assert!(block.query.lora_b.weight.is_require_grad());
+            assert!(!block.value.base.weight.is_require_grad());
+            assert!(!block.value.lora_a.weight.is_require_grad());
+            assert_eq!(
+                block.value.lora_b.weight.val().abs().sum().into_scalar(),
+                0.0
+            );
+        }
+    }
+}
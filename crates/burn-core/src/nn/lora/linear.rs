@@ -0,0 +1,141 @@
+use crate as burn;
+
+use crate::module::Module;
+use crate::nn::{Dropout, Linear};
+use crate::tensor::{backend::Backend, Tensor};
+
+/// A [linear](Linear) layer whose frozen weights are adapted by a trainable low-rank update.
+///
+/// Should be created with [LoraConfig](super::LoraConfig::init_linear).
+#[derive(Module, Debug)]
+pub struct LoraLinear<B: Backend> {
+    /// The frozen layer being adapted.
+    pub base: Linear<B>,
+    /// Down projection from `d_input` to the rank, without bias.
+    pub lora_a: Linear<B>,
+    /// Up projection from the rank to `d_output`, without bias.
+    pub lora_b: Linear<B>,
+    pub(super) dropout: Dropout,
+    pub(super) scaling: f64,
+    pub(super) merged: bool,
+}
+
+impl<B: Backend> LoraLinear<B> {
+    /// Applies the forward pass on the input tensor, adding the scaled update of the adapters to
+    /// the output of the base layer, unless they are [merged](LoraLinear::merge).
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let output = self.base.forward(input.clone());
+
+        if self.merged {
+            return output;
+        }
+
+        let update = self
+            .lora_b
+            .forward(self.lora_a.forward(self.dropout.forward(input)));
+
+        output.add(update.mul_scalar(self.scaling))
+    }
+
+    /// The update of the weights of the base layer learned by the adapters.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[d_input, d_output]`
+    pub fn delta_weight(&self) -> Tensor<B, 2> {
+        self.lora_a
+            .weight
+            .val()
+            .matmul(self.lora_b.weight.val())
+            .mul_scalar(self.scaling)
+    }
+
+    /// If the update of the adapters is merged into the weights of the base layer.
+    pub fn is_merged(&self) -> bool {
+        self.merged
+    }
+
+    /// Add the update of the adapters to the weights of the base layer, so that the forward pass
+    /// doesn't compute it anymore, usually for inference.
+    pub fn merge(mut self) -> Self {
+        if !self.merged {
+            let delta = self.delta_weight().detach();
+            self.base.weight = self.base.weight.map(|weight| {
+                let require_grad = weight.is_require_grad();
+                weight.add(delta.clone()).set_require_grad(require_grad)
+            });
+            self.merged = true;
+        }
+
+        self
+    }
+
+    /// Subtract the update of the adapters from the weights of the base layer, restoring them
+    /// after a [merge](LoraLinear::merge).
+    pub fn unmerge(mut self) -> Self {
+        if self.merged {
+            let delta = self.delta_weight().detach();
+            self.base.weight = self.base.weight.map(|weight| {
+                let require_grad = weight.is_require_grad();
+                weight.sub(delta.clone()).set_require_grad(require_grad)
+            });
+            self.merged = false;
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::{lora::LoraConfig, LinearConfig};
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_lora_linear_should_initially_have_base_output() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestBackend>(&device);
+        let lora = LoraConfig::new(2).init_linear(linear.clone());
+        assert_eq!(lora.lora_a.weight.dims(), [6, 2]);
+        assert_eq!(lora.lora_b.weight.dims(), [2, 4]);
+
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &device);
+
+        lora.forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&linear.forward(input).into_data(), 3);
+    }
+
+    #[test]
+    fn test_lora_linear_merge_should_have_same_output() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestBackend>(&device);
+        let mut lora = LoraConfig::new(2)
+            .with_alpha(4.0)
+            .init_linear(linear.clone());
+        lora.lora_b.weight =
+            Param::from_tensor(Tensor::random([2, 4], Distribution::Default, &device));
+
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &device);
+        let output = lora.forward(input.clone());
+        let lora = lora.merge();
+        assert!(lora.is_merged());
+
+        lora.forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&output.into_data(), 3);
+        lora.unmerge()
+            .base
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&linear.forward(input).into_data(), 3);
+    }
+}
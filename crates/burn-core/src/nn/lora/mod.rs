@@ -0,0 +1,11 @@
+mod config;
+mod conv;
+mod inject;
+mod linear;
+mod quantized;
+
+pub use config::*;
+pub use conv::*;
+pub use inject::*;
+pub use linear::*;
+pub use quantized::*;
@@ -0,0 +1,203 @@
+use crate as burn;
+
+use crate::module::{Module, Param, ParamId};
+use crate::nn::{Dropout, Linear};
+use crate::tensor::{
+    backend::Backend, module::dequantize_matmul, quantization::QuantizationType, Int, Tensor,
+};
+
+use super::LoraLinear;
+
+/// A [linear](Linear) layer whose frozen weights are quantized to integers, adapted by a
+/// trainable low-rank update, as described in
+/// [QLoRA: Efficient Finetuning of Quantized LLMs](https://arxiv.org/abs/2305.14314).
+///
+/// The weights are quantized symmetrically with a scale for each output feature, and are
+/// dequantized on the fly by the forward pass with
+/// [dequantize_matmul](crate::tensor::module::dequantize_matmul). The adapters are trained with
+/// the floating point element of the backend, e.g. `bf16`.
+///
+/// Should be created with [LoraConfig](super::LoraConfig::init_quantized_linear).
+#[derive(Module, Debug)]
+pub struct QLoraLinear<B: Backend> {
+    /// The quantized values of the frozen weights, of shape `[d_input, d_output]`.
+    pub weight: Param<Tensor<B, 2, Int>>,
+    /// The frozen scale of the weights of each output feature, of shape `[d_output]`.
+    pub scale: Param<Tensor<B, 1>>,
+    /// The frozen bias of the layer, of shape `[d_output]`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    /// Down projection from `d_input` to the rank, without bias.
+    pub lora_a: Linear<B>,
+    /// Up projection from the rank to `d_output`, without bias.
+    pub lora_b: Linear<B>,
+    pub(super) dropout: Dropout,
+    pub(super) scaling: f64,
+}
+
+impl<B: Backend> QLoraLinear<B> {
+    /// Applies the forward pass on the input tensor, adding the scaled update of the adapters to
+    /// the output of the quantized layer.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let mut dims = input.dims();
+        let [d_input, d_output] = self.weight.dims();
+        let num_rows = dims[..D - 1].iter().product();
+
+        let output = dequantize_matmul(
+            input.clone().reshape([1, num_rows, d_input]),
+            self.weight.val(),
+            self.scale.val(),
+        );
+        dims[D - 1] = d_output;
+        let output = output.reshape(dims);
+        let output = match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        };
+
+        let update = self
+            .lora_b
+            .forward(self.lora_a.forward(self.dropout.forward(input)));
+
+        output.add(update.mul_scalar(self.scaling))
+    }
+
+    /// The update of the weights of the quantized layer learned by the adapters.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[d_input, d_output]`
+    pub fn delta_weight(&self) -> Tensor<B, 2> {
+        self.lora_a
+            .weight
+            .val()
+            .matmul(self.lora_b.weight.val())
+            .mul_scalar(self.scaling)
+    }
+
+    /// Dequantize the weights of the frozen layer, returning a [LoraLinear] with the same
+    /// adapters, e.g. to [merge](LoraLinear::merge) them for inference.
+    pub fn dequantize(self) -> LoraLinear<B> {
+        let [_, d_output] = self.weight.dims();
+        let weight = self
+            .weight
+            .val()
+            .float()
+            .mul(self.scale.val().reshape([1, d_output]));
+
+        LoraLinear {
+            base: Linear {
+                weight: Param::from_tensor(weight).set_require_grad(false),
+                bias: self.bias,
+            },
+            lora_a: self.lora_a,
+            lora_b: self.lora_b,
+            dropout: self.dropout,
+            scaling: self.scaling,
+            merged: false,
+        }
+    }
+}
+
+/// Quantize the weights symmetrically with the scale of each column, so that its largest
+/// magnitude is mapped to the largest value of the quantization type, returning the quantized
+/// values and the scales.
+pub(super) fn quantize_per_column<B: Backend>(
+    weight: Tensor<B, 2>,
+    q_type: QuantizationType,
+) -> (Param<Tensor<B, 2, Int>>, Param<Tensor<B, 1>>) {
+    let [_, d_output] = weight.dims();
+    let (q_min, q_max) = q_type.range();
+
+    let scale = weight.clone().abs().max_dim(0).div_scalar(q_max);
+    // Avoid dividing by zero when all the weights of a column are zero.
+    let scale = scale.clone().mask_fill(scale.equal_elem(0.0), 1.0);
+
+    // Truncating the magnitude increased by one half rounds half away from zero.
+    let scaled = weight.div(scale.clone());
+    let values = scaled
+        .clone()
+        .abs()
+        .add_scalar(0.5)
+        .int()
+        .mul(scaled.sign().int())
+        .clamp(q_min, q_max);
+
+    (
+        Param::initialized(ParamId::new(), values),
+        Param::from_tensor(scale.reshape([d_output])).set_require_grad(false),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{lora::LoraConfig, LinearConfig};
+    use crate::tensor::Distribution;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[test]
+    fn test_qlora_linear_should_initially_approximate_base_output() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestBackend>(&device);
+        let qlora =
+            LoraConfig::new(2).init_quantized_linear(linear.clone(), QuantizationType::QInt8);
+        let values = qlora.weight.val().abs().max().into_scalar();
+        assert_eq!(values, 127);
+
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &device);
+
+        qlora
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&linear.forward(input).into_data(), 1);
+    }
+
+    #[test]
+    fn test_qlora_linear_should_quantize_to_int4() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestBackend>(&device);
+        let weight = linear.weight.val();
+        let qlora = LoraConfig::new(2).init_quantized_linear(linear, QuantizationType::QInt4);
+
+        let values = qlora.weight.val();
+        assert!(values.clone().max().into_scalar() <= 7);
+        assert!(values.min().into_scalar() >= -8);
+
+        // The error of each weight is at most half the scale of its column.
+        let lora = qlora.dequantize();
+        let scale = weight.clone().abs().max_dim(0).div_scalar(7.0);
+        let error = lora
+            .base
+            .weight
+            .val()
+            .sub(weight)
+            .abs()
+            .sub(scale.div_scalar(2.0));
+        assert!(error.max().into_scalar() <= 1e-6);
+    }
+
+    #[test]
+    fn test_qlora_linear_should_only_train_adapters() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestAutodiffBackend>(&device);
+        let mut qlora = LoraConfig::new(2).init_quantized_linear(linear, QuantizationType::QInt8);
+        qlora.lora_b.weight =
+            Param::from_tensor(Tensor::random([2, 4], Distribution::Default, &device));
+
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([2, 3, 6], Distribution::Default, &device)
+                .require_grad();
+        let grads = qlora.forward(input.clone()).sum().backward();
+
+        assert!(input.grad(&grads).is_some());
+        assert!(qlora.lora_a.weight.grad(&grads).is_some());
+        assert!(qlora.lora_b.weight.grad(&grads).is_some());
+        assert!(qlora.scale.grad(&grads).is_none());
+        assert!(qlora.bias.unwrap().grad(&grads).is_none());
+    }
+}
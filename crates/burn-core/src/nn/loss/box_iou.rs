@@ -0,0 +1,134 @@
+use crate as burn;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::module::paired_box_iou;
+use crate::tensor::ops::BoxIouMode;
+use crate::tensor::Tensor;
+use crate::{
+    config::Config,
+    module::{Ignored, Module},
+};
+use core::marker::PhantomData;
+
+use super::Reduction;
+
+/// Configuration to create a [box IoU loss](BoxIouLoss).
+#[derive(Config, Debug)]
+pub struct BoxIouLossConfig {
+    /// The overlap measure of the predicted and target boxes.
+    pub mode: BoxIouMode,
+}
+
+impl BoxIouLossConfig {
+    /// Initialize [box IoU loss](BoxIouLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> BoxIouLoss<B> {
+        // device is not needed as of now, but we might want to prepare some data on it
+        // and its consistent with other loss functions
+        let _ = device;
+        BoxIouLoss {
+            mode: Ignored(self.mode),
+            _backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the intersection over union loss between predicted and target boxes given as
+/// `(x1, y1, x2, y2)`.
+///
+/// The loss of each pair of boxes is `1 - IoU`, the overlap being measured by the intersection
+/// over union or one of its [variants](BoxIouMode). Unlike the plain IoU, the generalized,
+/// distance and complete variants still provide a gradient for boxes which don't overlap.
+///
+/// See also: <https://arxiv.org/abs/1902.09630> and <https://arxiv.org/abs/1911.08287>
+#[derive(Module, Debug)]
+pub struct BoxIouLoss<B: Backend> {
+    mode: Ignored<BoxIouMode>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> BoxIouLoss<B> {
+    /// Compute the loss for each pair of predicted and target boxes, then reduce to a single
+    /// loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[num_boxes, 4\]
+    /// - targets: \[num_boxes, 4\]
+    /// - output: \[1\]
+    pub fn forward(
+        &self,
+        predictions: Tensor<B, 2>,
+        targets: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss for each pair of predicted and target boxes.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[num_boxes, 4\]
+    /// - targets: \[num_boxes, 4\]
+    /// - output: \[num_boxes\]
+    pub fn forward_no_reduction(
+        &self,
+        predictions: Tensor<B, 2>,
+        targets: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        paired_box_iou(predictions, targets, *self.mode)
+            .neg()
+            .add_scalar(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    fn boxes() -> (TestTensor<2>, TestTensor<2>) {
+        let device = Default::default();
+        let predictions =
+            TestTensor::from_floats([[0.0, 0.0, 2.0, 2.0], [1.0, 1.0, 4.0, 3.0]], &device);
+        let targets =
+            TestTensor::from_floats([[1.0, 0.5, 3.0, 2.5], [0.0, 0.0, 2.0, 2.0]], &device);
+
+        (predictions, targets)
+    }
+
+    #[test]
+    fn test_iou_loss() {
+        let (predictions, targets) = boxes();
+        let loss = BoxIouLossConfig::new(BoxIouMode::Iou).init(&Default::default());
+
+        let loss_sum = loss.forward(predictions.clone(), targets.clone(), Reduction::Sum);
+        let loss_no_reduction = loss.forward_no_reduction(predictions, targets);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.769231, 0.888889]), 5);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.65812]), 4);
+    }
+
+    #[test]
+    fn test_complete_iou_loss() {
+        let (predictions, targets) = boxes();
+        let loss = BoxIouLossConfig::new(BoxIouMode::Complete).init(&Default::default());
+
+        let loss = loss.forward(predictions, targets, Reduction::Auto);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.935182]), 4);
+    }
+}
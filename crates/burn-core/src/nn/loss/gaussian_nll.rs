@@ -0,0 +1,189 @@
+use crate as burn;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::{config::Config, module::Module};
+use core::f32::consts::PI;
+use core::marker::PhantomData;
+
+use super::Reduction;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create a [Gaussian negative log likelihood loss](GaussianNllLoss).
+#[derive(Config, Debug)]
+pub struct GaussianNllLossConfig {
+    /// Add the constant `0.5 * log(2 * pi)`, so that the loss is the full negative log
+    /// likelihood. Default: false
+    #[config(default = false)]
+    pub full: bool,
+    /// The minimum of the variances, for stability. Default: 1e-6
+    #[config(default = 1e-6)]
+    pub eps: f32,
+}
+
+impl GaussianNllLossConfig {
+    /// Initialize [Gaussian negative log likelihood loss](GaussianNllLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> GaussianNllLoss<B> {
+        // device is not needed as of now, but we might want to prepare some data on it
+        // and its consistent with other loss functions
+        let _ = device;
+        self.assertions();
+        GaussianNllLoss {
+            full: self.full,
+            eps: self.eps,
+            _backend: PhantomData,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.eps > 0.,
+            "The epsilon of the Gaussian negative log likelihood loss must be positive."
+        );
+    }
+}
+
+/// Calculate the negative log likelihood of the targets, following Gaussian distributions whose
+/// means and variances are predicted, e.g. for heteroscedastic regression.
+///
+/// The loss of each element is given by
+///
+/// ```text
+/// L(x, t, v) = 0.5 * (log(max(v, eps)) + (x - t)^2 / max(v, eps))
+/// ```
+///
+/// omitting the constant `0.5 * log(2 * pi)`, unless `full` is set. The variances are clamped
+/// without affecting their gradient.
+///
+/// See also: <https://en.wikipedia.org/wiki/Normal_distribution>
+#[derive(Module, Debug)]
+pub struct GaussianNllLoss<B: Backend> {
+    full: bool,
+    eps: f32,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> GaussianNllLoss<B> {
+    /// Compute the loss element-wise for the predictions, targets and variances, then reduce to
+    /// a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[batch_size, ...dims\]
+    /// - targets: \[batch_size, ...dims\]
+    /// - variances: \[batch_size, ...dims\], or broadcastable to it
+    /// - output: \[1\]
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        variances: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets, variances);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the predictions, targets and variances.
+    ///
+    /// A single variance can be predicted for several elements, e.g. with a size of one for the
+    /// last dimension, the variances being broadcast.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - variances: \[...dims\], or broadcastable to it
+    /// - output: \[...dims\]
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        variances: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        assert!(
+            predictions.dims() == targets.dims(),
+            "Shape of targets ({:?}) should correspond to the shape of predictions ({:?}).",
+            targets.dims(),
+            predictions.dims()
+        );
+        let variances = variances.expand(predictions.shape());
+
+        // Only the value of the variances is clamped, their gradient being unchanged.
+        let clamped = variances.clone().clamp_min(self.eps);
+        let variances = variances.clone().add(clamped.sub(variances).detach());
+
+        let loss = variances
+            .clone()
+            .log()
+            .add(predictions.sub(targets).powf_scalar(2.0).div(variances))
+            .mul_scalar(0.5);
+
+        match self.full {
+            true => loss.add_scalar(0.5 * (2.0 * PI).ln()),
+            false => loss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_gaussian_nll_loss() {
+        let device = Default::default();
+        let predictions = TestTensor::<2>::from_floats([[1.0, 2.0]], &device);
+        let targets = TestTensor::<2>::from_floats([[0.0, 2.0]], &device);
+        let variances = TestTensor::<2>::from_floats([[0.5, 2.0]], &device);
+        let loss = GaussianNllLossConfig::new().init(&device);
+
+        let loss_no_reduction =
+            loss.forward_no_reduction(predictions.clone(), targets.clone(), variances.clone());
+        let loss = loss.forward(predictions, targets, variances, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.653426, 0.346574]]), 5);
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.5]), 5);
+    }
+
+    #[test]
+    fn test_gaussian_nll_loss_full_with_shared_variance() {
+        let device = Default::default();
+        let predictions = TestTensor::<2>::from_floats([[1.0, 2.0]], &device);
+        let targets = TestTensor::<2>::from_floats([[0.0, 2.0]], &device);
+        let variances = TestTensor::<2>::from_floats([[0.5]], &device);
+        let loss = GaussianNllLossConfig::new().with_full(true).init(&device);
+
+        let loss = loss.forward(predictions, targets, variances, Reduction::Sum);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([2.144732]), 5);
+    }
+
+    #[test]
+    fn test_gaussian_nll_loss_clamps_variances() {
+        let device = Default::default();
+        let predictions = TestTensor::<1>::from_floats([1.0], &device);
+        let targets = TestTensor::<1>::from_floats([1.0], &device);
+        let variances = TestTensor::<1>::from_floats([0.0], &device);
+        let loss = GaussianNllLossConfig::new().init(&device);
+
+        let loss = loss.forward_no_reduction(predictions, targets, variances);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([-6.907755]), 4);
+    }
+}
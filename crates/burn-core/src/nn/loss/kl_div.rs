@@ -0,0 +1,182 @@
+use crate as burn;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::{config::Config, module::Module};
+use core::marker::PhantomData;
+
+use super::Reduction;
+
+/// Configuration to create a [KL divergence loss](KlDivLoss).
+#[derive(Config, Debug)]
+pub struct KlDivLossConfig {
+    /// If the targets are given as log-probabilities, like the predictions, instead of
+    /// probabilities. Default: false
+    #[config(default = false)]
+    pub log_target: bool,
+    /// If the mean reduction divides the sum of the losses by the batch size, the size of the
+    /// first dimension, instead of the number of elements. Default: false
+    #[config(default = false)]
+    pub batch_mean: bool,
+}
+
+impl KlDivLossConfig {
+    /// Initialize [KL divergence loss](KlDivLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> KlDivLoss<B> {
+        // device is not needed as of now, but we might want to prepare some data on it
+        // and its consistent with other loss functions
+        let _ = device;
+        KlDivLoss {
+            log_target: self.log_target,
+            batch_mean: self.batch_mean,
+            _backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the Kullback-Leibler divergence of the predictions from the targets, e.g. of the
+/// student from the teacher for knowledge distillation.
+///
+/// The predictions are log-probabilities, usually computed with
+/// [log_softmax](crate::tensor::activation::log_softmax), and the loss of each element is given
+/// by
+///
+/// ```text
+/// L(x, t) = t * (log(t) - x)
+/// ```
+///
+/// which is zero when the target probability is zero. The mean reduction only computes the
+/// divergence of the distributions of each sample, averaged over the batch, when
+/// [batch_mean](KlDivLossConfig::batch_mean) is set; otherwise it's also divided by the size of
+/// the distributions.
+///
+/// See also: <https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence>
+#[derive(Module, Debug)]
+pub struct KlDivLoss<B: Backend> {
+    log_target: bool,
+    batch_mean: bool,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> KlDivLoss<B> {
+    /// Compute the loss element-wise for the predictions and targets, then reduce to a single
+    /// loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`, which divides the sum by the batch size
+    /// when [batch_mean](KlDivLossConfig::batch_mean) is set.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[batch_size, ...dims\]
+    /// - targets: \[batch_size, ...dims\]
+    /// - output: \[1\]
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let batch_size = predictions.dims()[0];
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto if self.batch_mean => {
+                loss.sum().div_scalar(batch_size as f32)
+            }
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the predictions and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[...dims\]
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        assert!(
+            predictions.dims() == targets.dims(),
+            "Shape of targets ({:?}) should correspond to the shape of predictions ({:?}).",
+            targets.dims(),
+            predictions.dims()
+        );
+
+        if self.log_target {
+            return targets.clone().exp().mul(targets.sub(predictions));
+        }
+
+        // The log of the zero targets is replaced by zero, so that neither their loss nor its
+        // gradient is undefined.
+        let log_targets = targets
+            .clone()
+            .mask_fill(targets.clone().lower_equal_elem(0.0), 1.0)
+            .log();
+
+        targets.mul(log_targets.sub(predictions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_kl_div_loss() {
+        let device = Default::default();
+        let predictions = TestTensor::<2>::from_floats([[0.25, 0.75], [0.25, 0.75]], &device).log();
+        let targets = TestTensor::<2>::from_floats([[0.5, 0.5], [1.0, 0.0]], &device);
+        let loss = KlDivLossConfig::new().init(&device);
+        let loss_batch = KlDivLossConfig::new().with_batch_mean(true).init(&device);
+
+        let loss_no_reduction = loss.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss_mean = loss.forward(predictions.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = loss.forward(predictions.clone(), targets.clone(), Reduction::Sum);
+        let loss_batch_mean =
+            loss_batch.forward(predictions.clone(), targets.clone(), Reduction::Mean);
+        let loss_batch_sum = loss_batch.forward(predictions, targets, Reduction::Sum);
+
+        loss_no_reduction.into_data().assert_approx_eq(
+            &TensorData::from([[0.346574, -0.202733], [1.386294, 0.0]]),
+            5,
+        );
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.382534]), 5);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.530135]), 5);
+        loss_batch_mean
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.765068]), 5);
+        loss_batch_sum
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.530135]), 5);
+    }
+
+    #[test]
+    fn test_kl_div_loss_log_target() {
+        let device = Default::default();
+        let predictions = TestTensor::<2>::from_floats([[0.25, 0.75], [0.6, 0.4]], &device).log();
+        let targets = TestTensor::<2>::from_floats([[0.5, 0.5], [0.9, 0.1]], &device);
+        let loss = KlDivLossConfig::new().with_batch_mean(true).init(&device);
+        let loss_log_target = KlDivLossConfig::new()
+            .with_log_target(true)
+            .with_batch_mean(true)
+            .init(&device);
+
+        let expected = loss.forward(predictions.clone(), targets.clone(), Reduction::Mean);
+        let output = loss_log_target.forward(predictions, targets.log(), Reduction::Mean);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+}
@@ -1,11 +1,23 @@
 mod binary_cross_entropy;
+mod box_iou;
 mod cross_entropy;
+mod gaussian_nll;
 mod huber;
+mod kl_div;
 mod mse;
+mod poisson_nll;
 mod reduction;
+mod triplet;
+mod tversky;
 
 pub use binary_cross_entropy::*;
+pub use box_iou::*;
 pub use cross_entropy::*;
+pub use gaussian_nll::*;
 pub use huber::*;
+pub use kl_div::*;
 pub use mse::*;
+pub use poisson_nll::*;
 pub use reduction::*;
+pub use triplet::*;
+pub use tversky::*;
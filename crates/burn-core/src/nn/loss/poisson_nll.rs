@@ -0,0 +1,199 @@
+use crate as burn;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::{config::Config, module::Module};
+use core::f32::consts::PI;
+use core::marker::PhantomData;
+
+use super::Reduction;
+
+/// Configuration to create a [Poisson negative log likelihood loss](PoissonNllLoss).
+#[derive(Config, Debug)]
+pub struct PoissonNllLossConfig {
+    /// If the predictions are the logarithms of the rates, instead of the rates. Default: true
+    #[config(default = true)]
+    pub log_input: bool,
+    /// Add the Stirling approximation of `log(t!)`, so that the loss is the full negative log
+    /// likelihood. Default: false
+    #[config(default = false)]
+    pub full: bool,
+    /// Added to the rates before taking their logarithm, when they aren't given as logarithms.
+    /// Default: 1e-8
+    #[config(default = 1e-8)]
+    pub eps: f32,
+}
+
+impl PoissonNllLossConfig {
+    /// Initialize [Poisson negative log likelihood loss](PoissonNllLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> PoissonNllLoss<B> {
+        // device is not needed as of now, but we might want to prepare some data on it
+        // and its consistent with other loss functions
+        let _ = device;
+        self.assertions();
+        PoissonNllLoss {
+            log_input: self.log_input,
+            full: self.full,
+            eps: self.eps,
+            _backend: PhantomData,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.eps >= 0.,
+            "The epsilon of the Poisson negative log likelihood loss must be non-negative."
+        );
+    }
+}
+
+/// Calculate the negative log likelihood of the targets, counts following Poisson distributions
+/// whose rates are predicted.
+///
+/// The loss of each element is given by
+///
+/// ```text
+/// L(x, t) = exp(x) - t * x            with log_input
+/// L(x, t) = x - t * log(x + eps)      without
+/// ```
+///
+/// omitting the constant `log(t!)`, unless `full` is set, in which case its Stirling
+/// approximation `t * log(t) - t + 0.5 * log(2 * pi * t)` is added for the targets above one.
+///
+/// See also: <https://en.wikipedia.org/wiki/Poisson_regression>
+#[derive(Module, Debug)]
+pub struct PoissonNllLoss<B: Backend> {
+    log_input: bool,
+    full: bool,
+    eps: f32,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> PoissonNllLoss<B> {
+    /// Compute the loss element-wise for the predictions and targets, then reduce to a single
+    /// loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[batch_size, ...dims\]
+    /// - targets: \[batch_size, ...dims\]
+    /// - output: \[1\]
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the predictions and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[...dims\]
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        assert!(
+            predictions.dims() == targets.dims(),
+            "Shape of targets ({:?}) should correspond to the shape of predictions ({:?}).",
+            targets.dims(),
+            predictions.dims()
+        );
+
+        let loss = if self.log_input {
+            predictions
+                .clone()
+                .exp()
+                .sub(targets.clone().mul(predictions))
+        } else {
+            let log_rates = predictions.clone().add_scalar(self.eps).log();
+            predictions.sub(targets.clone().mul(log_rates))
+        };
+
+        if !self.full {
+            return loss;
+        }
+
+        // The approximation is only added for the targets above one, the log of the factorial of
+        // zero and one being zero.
+        let small = targets.clone().lower_equal_elem(1.0);
+        let safe_targets = targets.mask_fill(small.clone(), 1.0);
+        let stirling = safe_targets
+            .clone()
+            .mul(safe_targets.clone().log())
+            .sub(safe_targets.clone())
+            .add(safe_targets.mul_scalar(2.0 * PI).log().mul_scalar(0.5))
+            .mask_fill(small, 0.0);
+
+        loss.add(stirling)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_poisson_nll_loss() {
+        let device = Default::default();
+        let predictions = TestTensor::<1>::from_floats([0.0, 1.0, -1.0], &device);
+        let targets = TestTensor::<1>::from_floats([1.0, 2.0, 0.0], &device);
+        let loss = PoissonNllLossConfig::new().init(&device);
+
+        let loss_no_reduction = loss.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss = loss.forward(predictions, targets, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 0.718282, 0.367879]), 5);
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.695387]), 5);
+    }
+
+    #[test]
+    fn test_poisson_nll_loss_full() {
+        let device = Default::default();
+        let predictions = TestTensor::<1>::from_floats([0.0, 1.0, -1.0], &device);
+        let targets = TestTensor::<1>::from_floats([1.0, 2.0, 0.0], &device);
+        let loss = PoissonNllLossConfig::new().with_full(true).init(&device);
+
+        let loss = loss.forward_no_reduction(predictions, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 1.370088, 0.367879]), 5);
+    }
+
+    #[test]
+    fn test_poisson_nll_loss_rates() {
+        let device = Default::default();
+        let predictions = TestTensor::<2>::from_floats([[0.5, 2.0]], &device);
+        let targets = TestTensor::<2>::from_floats([[1.0, 3.0]], &device);
+        let loss = PoissonNllLossConfig::new()
+            .with_log_input(false)
+            .init(&device);
+
+        let loss_no_reduction = loss.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss = loss.forward(predictions, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.193147, -0.079442]]), 5);
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([1.113705]), 5);
+    }
+}
@@ -0,0 +1,398 @@
+use crate as burn;
+
+use crate::tensor::activation::relu;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+use crate::{
+    config::Config,
+    module::{Ignored, Module},
+};
+use core::marker::PhantomData;
+
+use super::Reduction;
+
+/// How the triplets are mined from a batch of labeled embeddings by a
+/// [triplet margin loss](TripletMarginLoss::forward_mined).
+#[derive(Config, Debug, Copy, PartialEq, Eq)]
+pub enum TripletMining {
+    /// Each embedding is the anchor of a single triplet, with its farthest positive and its
+    /// closest negative.
+    BatchHard,
+    /// Each pair of embeddings with the same label is the anchor and the positive of a triplet,
+    /// with the closest negative farther than the positive, or the farthest negative when they
+    /// are all closer.
+    BatchSemiHard,
+}
+
+/// Configuration to create a [triplet margin loss](TripletMarginLoss).
+#[derive(Config, Debug)]
+pub struct TripletMarginLossConfig {
+    /// The margin by which the negatives should be farther from the anchors than the positives.
+    /// Default: 1.0
+    #[config(default = 1.0)]
+    pub margin: f32,
+    /// The norm degree of the distances. Default: 2.0
+    #[config(default = 2.0)]
+    pub p: f32,
+    /// Added to the differences of the embeddings, to avoid the singular gradient of the
+    /// distance of equal embeddings. Default: 1e-6
+    #[config(default = 1e-6)]
+    pub eps: f32,
+    /// Use the distance between the positive and the negative when it is smaller than the
+    /// distance between the anchor and the negative, for explicit triplets. Default: false
+    #[config(default = false)]
+    pub swap: bool,
+    /// How the triplets are mined from a batch of labeled embeddings. Default: BatchHard
+    #[config(default = "TripletMining::BatchHard")]
+    pub mining: TripletMining,
+}
+
+impl TripletMarginLossConfig {
+    /// Initialize [triplet margin loss](TripletMarginLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> TripletMarginLoss<B> {
+        // device is not needed as of now, but we might want to prepare some data on it
+        // and its consistent with other loss functions
+        let _ = device;
+        self.assertions();
+        TripletMarginLoss {
+            margin: self.margin,
+            p: self.p,
+            eps: self.eps,
+            swap: self.swap,
+            mining: Ignored(self.mining),
+            _backend: PhantomData,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.margin >= 0.,
+            "The margin of the triplet margin loss must be non-negative."
+        );
+        assert!(
+            self.p > 0.,
+            "The norm degree of the triplet margin loss must be positive."
+        );
+    }
+}
+
+/// Calculate the triplet margin loss, pulling the anchors towards their positives and pushing
+/// them away from their negatives, for metric learning.
+///
+/// The loss of each triplet is given by
+///
+/// ```text
+/// L(a, p, n) = max(d(a, p) - d(a, n) + margin, 0)
+/// ```
+///
+/// where `d(x, y) = ||x - y + eps||_p`. The triplets are either given explicitly with
+/// [forward](TripletMarginLoss::forward), or [mined](TripletMining) on the device from a batch of
+/// labeled embeddings with [forward_mined](TripletMarginLoss::forward_mined).
+///
+/// See also: <https://arxiv.org/abs/1503.03832> and <https://arxiv.org/abs/1703.07737>
+#[derive(Module, Debug)]
+pub struct TripletMarginLoss<B: Backend> {
+    margin: f32,
+    p: f32,
+    eps: f32,
+    swap: bool,
+    mining: Ignored<TripletMining>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> TripletMarginLoss<B> {
+    /// Compute the loss of each triplet, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: \[batch_size, d_model\]
+    /// - positives: \[batch_size, d_model\]
+    /// - negatives: \[batch_size, d_model\]
+    /// - output: \[1\]
+    pub fn forward(
+        &self,
+        anchors: Tensor<B, 2>,
+        positives: Tensor<B, 2>,
+        negatives: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(anchors, positives, negatives);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss of each triplet.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: \[batch_size, d_model\]
+    /// - positives: \[batch_size, d_model\]
+    /// - negatives: \[batch_size, d_model\]
+    /// - output: \[batch_size\]
+    pub fn forward_no_reduction(
+        &self,
+        anchors: Tensor<B, 2>,
+        positives: Tensor<B, 2>,
+        negatives: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        Self::assertions(&anchors, &positives, &negatives);
+        let [batch_size, _] = anchors.dims();
+
+        let positive_distances = self.distance(anchors.clone(), positives.clone());
+        let mut negative_distances = self.distance(anchors, negatives.clone());
+        if self.swap {
+            negative_distances = negative_distances.min_pair(self.distance(positives, negatives));
+        }
+
+        relu(
+            positive_distances
+                .sub(negative_distances)
+                .add_scalar(self.margin),
+        )
+        .reshape([batch_size])
+    }
+
+    /// Compute the loss of the triplets mined from a batch of labeled embeddings with the
+    /// configured [strategy](TripletMining), then reduce to a single loss value.
+    ///
+    /// The triplets are mined with the distances between every pair of embeddings, without
+    /// synchronizing with the device. With `Reduction::Mean` or `Reduction::Auto`, the loss is
+    /// averaged over the mined triplets, the anchors without any positive or negative being
+    /// ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - embeddings: \[batch_size, d_model\]
+    /// - labels: \[batch_size\]
+    /// - output: \[1\]
+    pub fn forward_mined(
+        &self,
+        embeddings: Tensor<B, 2>,
+        labels: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = embeddings.dims();
+        let [num_labels] = labels.dims();
+        assert!(
+            batch_size == num_labels,
+            "Shape of labels ({}) should correspond to outer shape of embeddings ({}).",
+            num_labels,
+            batch_size
+        );
+
+        let device = embeddings.device();
+        let distances = self
+            .distance::<3>(embeddings.clone().unsqueeze_dim(1), embeddings.unsqueeze())
+            .reshape([batch_size, batch_size]);
+
+        // Masks of the pairs of embeddings with the same label, and with different labels.
+        let same = labels
+            .clone()
+            .reshape([batch_size, 1])
+            .expand([batch_size, batch_size])
+            .equal(
+                labels
+                    .reshape([1, batch_size])
+                    .expand([batch_size, batch_size]),
+            )
+            .float();
+        let positives = same.clone().sub(Tensor::eye(batch_size, &device));
+        let negatives = same.neg().add_scalar(1.0);
+        let has_negative = negatives.clone().max_dim(1);
+
+        // Added to the distances of the pairs which aren't candidates, so that they aren't the
+        // minimums.
+        let max_distance = distances.clone().max_dim(1).detach();
+
+        let (loss, mask) = match *self.mining {
+            TripletMining::BatchHard => {
+                let hardest_positive = distances.clone().mul(positives.clone()).max_dim(1);
+                let hardest_negative = distances
+                    .add(negatives.clone().neg().add_scalar(1.0).mul(max_distance))
+                    .min_dim(1);
+
+                let loss = relu(
+                    hardest_positive
+                        .sub(hardest_negative)
+                        .add_scalar(self.margin),
+                );
+
+                (loss, positives.max_dim(1).mul(has_negative))
+            }
+            TripletMining::BatchSemiHard => {
+                let shape = [batch_size, batch_size, batch_size];
+                // `[anchor, positive, negative]`
+                let positive_distances: Tensor<B, 3> = distances.clone().unsqueeze_dim(2);
+                let negative_distances: Tensor<B, 3> = distances.clone().unsqueeze_dim(1);
+                let semi_hard = negative_distances
+                    .clone()
+                    .expand(shape)
+                    .greater(positive_distances.clone().expand(shape))
+                    .float()
+                    .mul(negatives.clone().unsqueeze_dim(1));
+
+                let closest_semi_hard = negative_distances
+                    .expand(shape)
+                    .add(
+                        semi_hard
+                            .clone()
+                            .neg()
+                            .add_scalar(1.0)
+                            .mul(max_distance.unsqueeze_dim(2)),
+                    )
+                    .min_dim(2)
+                    .squeeze(2);
+                let has_semi_hard = semi_hard.max_dim(2).squeeze(2);
+                let farthest = distances.mul(negatives).max_dim(1);
+
+                let negative = closest_semi_hard
+                    .mul(has_semi_hard.clone())
+                    .add(farthest.mul(has_semi_hard.neg().add_scalar(1.0)));
+                let loss = relu(
+                    positive_distances
+                        .squeeze(2)
+                        .sub(negative)
+                        .add_scalar(self.margin),
+                );
+
+                (loss, positives.mul(has_negative))
+            }
+        };
+
+        let loss = loss.mul(mask.clone()).sum();
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.div(mask.sum().clamp_min(1.0)),
+            Reduction::Sum => loss,
+        }
+    }
+
+    /// The distance between the embeddings of the last dimension.
+    fn distance<const D: usize>(&self, x1: Tensor<B, D>, x2: Tensor<B, D>) -> Tensor<B, D> {
+        x1.sub(x2)
+            .add_scalar(self.eps)
+            .abs()
+            .powf_scalar(self.p)
+            .sum_dim(D - 1)
+            .powf_scalar(1.0 / self.p)
+    }
+
+    fn assertions(anchors: &Tensor<B, 2>, positives: &Tensor<B, 2>, negatives: &Tensor<B, 2>) {
+        assert!(
+            anchors.dims() == positives.dims() && anchors.dims() == negatives.dims(),
+            "Shapes of anchors ({:?}), positives ({:?}) and negatives ({:?}) should be the same.",
+            anchors.dims(),
+            positives.dims(),
+            negatives.dims()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let device = Default::default();
+        let anchors = TestTensor::<2>::from_floats([[0.0, 0.0], [0.0, 0.0]], &device);
+        let positives = TestTensor::<2>::from_floats([[3.0, 4.0], [1.0, 0.0]], &device);
+        let negatives = TestTensor::<2>::from_floats([[1.0, 0.0], [0.0, 3.0]], &device);
+        let loss = TripletMarginLossConfig::new().init(&device);
+
+        let loss_no_reduction =
+            loss.forward_no_reduction(anchors.clone(), positives.clone(), negatives.clone());
+        let loss = loss.forward(anchors, positives, negatives, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([5.0, 0.0]), 3);
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([2.5]), 3);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_swap() {
+        let device = Default::default();
+        let anchors = TestTensor::<2>::from_floats([[0.0, 0.0]], &device);
+        let positives = TestTensor::<2>::from_floats([[0.0, 1.0]], &device);
+        let negatives = TestTensor::<2>::from_floats([[0.0, 3.0]], &device);
+        let config = TripletMarginLossConfig::new().with_margin(2.0);
+
+        let loss = config.init(&device).forward(
+            anchors.clone(),
+            positives.clone(),
+            negatives.clone(),
+            Reduction::Sum,
+        );
+        let loss_swap = config.with_swap(true).init(&device).forward(
+            anchors,
+            positives,
+            negatives,
+            Reduction::Sum,
+        );
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.0]), 3);
+        loss_swap
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0]), 3);
+    }
+
+    fn embeddings() -> (TestTensor<2>, Tensor<TestBackend, 1, Int>) {
+        let device = Default::default();
+        let embeddings =
+            TestTensor::from_floats([[0.0, 0.0], [1.0, 0.0], [3.0, 0.0], [4.0, 0.0]], &device);
+        let labels = Tensor::from_ints([0, 1, 0, 1], &device);
+
+        (embeddings, labels)
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_batch_hard() {
+        let (embeddings, labels) = embeddings();
+        let loss = TripletMarginLossConfig::new().init(&Default::default());
+
+        let loss = loss.forward_mined(embeddings, labels, Reduction::Auto);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([3.0]), 3);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_batch_semi_hard() {
+        let (embeddings, labels) = embeddings();
+        let loss = TripletMarginLossConfig::new()
+            .with_mining(TripletMining::BatchSemiHard)
+            .init(&Default::default());
+
+        let loss_mean = loss.forward_mined(embeddings.clone(), labels.clone(), Reduction::Auto);
+        let loss_sum = loss.forward_mined(embeddings, labels, Reduction::Sum);
+
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&TensorData::from([4.0]), 3);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_ignores_anchors_without_negative() {
+        let device = Default::default();
+        let embeddings = TestTensor::<2>::from_floats([[0.0, 0.0], [1.0, 0.0]], &device);
+        let labels = Tensor::<TestBackend, 1, Int>::from_ints([0, 0], &device);
+        let loss = TripletMarginLossConfig::new().init(&device);
+
+        let loss = loss.forward_mined(embeddings, labels, Reduction::Auto);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.0]), 3);
+    }
+}
@@ -0,0 +1,319 @@
+use crate as burn;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::{
+    config::Config,
+    module::{Ignored, Module},
+};
+use core::marker::PhantomData;
+
+use super::Reduction;
+
+/// How the overlaps of the classes are combined into the loss of each sample by a
+/// [Tversky loss](TverskyLoss).
+#[derive(Config, Debug, Copy, PartialEq, Eq)]
+pub enum ClassAveraging {
+    /// The true positives, false positives and false negatives of every class are summed before
+    /// computing a single overlap, so that classes weigh as much as their number of elements.
+    Micro,
+    /// The loss of each class is computed independently and averaged, so that rare classes weigh
+    /// as much as frequent ones.
+    Macro,
+}
+
+/// Configuration to create a [Tversky loss](TverskyLoss).
+#[derive(Config, Debug)]
+pub struct TverskyLossConfig {
+    /// The weight of the false positives. Default: 0.5
+    #[config(default = 0.5)]
+    pub alpha: f32,
+    /// The weight of the false negatives. Default: 0.5
+    #[config(default = 0.5)]
+    pub beta: f32,
+    /// Added to the numerator and the denominator of the overlaps, to avoid dividing by zero
+    /// when a class is absent from both the predictions and the targets. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub smooth: f32,
+    /// How the overlaps of the classes are combined. Default: Macro
+    #[config(default = "ClassAveraging::Macro")]
+    pub average: ClassAveraging,
+}
+
+impl TverskyLossConfig {
+    /// Initialize [Tversky loss](TverskyLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> TverskyLoss<B> {
+        // device is not needed as of now, but we might want to prepare some data on it
+        // and its consistent with other loss functions
+        let _ = device;
+        self.assertions();
+        TverskyLoss {
+            alpha: self.alpha,
+            beta: self.beta,
+            smooth: self.smooth,
+            average: Ignored(self.average),
+            _backend: PhantomData,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.alpha >= 0. && self.beta >= 0.,
+            "The weights of the false positives and false negatives must be non-negative."
+        );
+        assert!(
+            self.smooth >= 0.,
+            "The smoothing term of the Tversky loss must be non-negative."
+        );
+    }
+}
+
+/// Configuration to create a [Dice loss](TverskyLoss), the Tversky loss weighting the false
+/// positives and the false negatives equally.
+#[derive(Config, Debug)]
+pub struct DiceLossConfig {
+    /// Added to the numerator and the denominator of the overlaps, to avoid dividing by zero
+    /// when a class is absent from both the predictions and the targets. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub smooth: f32,
+    /// How the overlaps of the classes are combined. Default: Macro
+    #[config(default = "ClassAveraging::Macro")]
+    pub average: ClassAveraging,
+}
+
+impl DiceLossConfig {
+    /// Initialize [Dice loss](TverskyLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> TverskyLoss<B> {
+        TverskyLossConfig::new()
+            .with_smooth(self.smooth)
+            .with_average(self.average)
+            .init(device)
+    }
+}
+
+/// Calculate the soft Tversky loss between the predicted probabilities of each class and the
+/// targets, usually for segmentation.
+///
+/// The overlap of each class is the Tversky index
+///
+/// ```text
+/// TI = (TP + s) / (TP + alpha * FP + beta * FN + s)
+/// ```
+///
+/// where the true positives `TP = sum(p * t)`, the false positives `FP = sum(p * (1 - t))` and
+/// the false negatives `FN = sum((1 - p) * t)` are summed over the elements of the class, and
+/// the loss is `1 - TI`. With `alpha = beta = 0.5`, this is the Dice loss, and with
+/// `alpha = beta = 1` the Jaccard loss. Increasing `beta` penalizes the false negatives more,
+/// which improves the recall of small structures.
+///
+/// See also: <https://arxiv.org/abs/1706.05721>
+#[derive(Module, Debug)]
+pub struct TverskyLoss<B: Backend> {
+    alpha: f32,
+    beta: f32,
+    smooth: f32,
+    average: Ignored<ClassAveraging>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> TverskyLoss<B> {
+    /// Compute the loss of each sample, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[batch_size, num_classes, ...dims\]
+    /// - targets: \[batch_size, num_classes, ...dims\]
+    /// - output: \[1\]
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss of each sample, combining its classes with the configured
+    /// [averaging](ClassAveraging).
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[batch_size, num_classes, ...dims\]
+    /// - targets: \[batch_size, num_classes, ...dims\]
+    /// - output: \[batch_size\]
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, 1> {
+        let [true_positives, false_positives, false_negatives] =
+            self.confusion(predictions, targets);
+        let [batch_size, _] = true_positives.dims();
+
+        match *self.average {
+            ClassAveraging::Micro => self
+                .loss(
+                    true_positives.sum_dim(1),
+                    false_positives.sum_dim(1),
+                    false_negatives.sum_dim(1),
+                )
+                .reshape([batch_size]),
+            ClassAveraging::Macro => self
+                .loss(true_positives, false_positives, false_negatives)
+                .mean_dim(1)
+                .reshape([batch_size]),
+        }
+    }
+
+    /// Compute the loss of each class of each sample.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[batch_size, num_classes, ...dims\]
+    /// - targets: \[batch_size, num_classes, ...dims\]
+    /// - output: \[batch_size, num_classes\]
+    pub fn forward_per_class<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, 2> {
+        let [true_positives, false_positives, false_negatives] =
+            self.confusion(predictions, targets);
+
+        self.loss(true_positives, false_positives, false_negatives)
+    }
+
+    /// The soft true positives, false positives and false negatives of each class of each
+    /// sample, of shape `[batch_size, num_classes]`.
+    fn confusion<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> [Tensor<B, 2>; 3] {
+        Self::assertions(&predictions, &targets);
+
+        let dims = predictions.dims();
+        let [batch_size, num_classes] = [dims[0], dims[1]];
+        let num_elements = dims[2..].iter().product::<usize>();
+        let sum = |tensor: Tensor<B, D>| {
+            tensor
+                .reshape([batch_size, num_classes, num_elements])
+                .sum_dim(2)
+                .reshape([batch_size, num_classes])
+        };
+
+        let true_positives = sum(predictions.clone().mul(targets.clone()));
+        let false_positives = sum(predictions.clone()).sub(true_positives.clone());
+        let false_negatives = sum(targets).sub(true_positives.clone());
+
+        [true_positives, false_positives, false_negatives]
+    }
+
+    fn loss(
+        &self,
+        true_positives: Tensor<B, 2>,
+        false_positives: Tensor<B, 2>,
+        false_negatives: Tensor<B, 2>,
+    ) -> Tensor<B, 2> {
+        let numerator = true_positives.clone().add_scalar(self.smooth);
+        let denominator = true_positives
+            .add(false_positives.mul_scalar(self.alpha))
+            .add(false_negatives.mul_scalar(self.beta))
+            .add_scalar(self.smooth);
+
+        numerator.div(denominator).neg().add_scalar(1.0)
+    }
+
+    fn assertions<const D: usize>(predictions: &Tensor<B, D>, targets: &Tensor<B, D>) {
+        assert!(
+            D >= 2,
+            "The predictions must have a batch and a class dimension."
+        );
+        assert!(
+            predictions.dims() == targets.dims(),
+            "Shape of targets ({:?}) should correspond to the shape of predictions ({:?}).",
+            targets.dims(),
+            predictions.dims()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    fn inputs() -> (TestTensor<3>, TestTensor<3>) {
+        let device = Default::default();
+        let predictions = TestTensor::from_floats([[[0.8, 0.4], [0.2, 0.6]]], &device);
+        let targets = TestTensor::from_floats([[[1.0, 0.0], [0.0, 1.0]]], &device);
+
+        (predictions, targets)
+    }
+
+    #[test]
+    fn test_dice_loss() {
+        let (predictions, targets) = inputs();
+        let device = Default::default();
+        let macro_loss = DiceLossConfig::new().with_smooth(0.0).init(&device);
+        let micro_loss = DiceLossConfig::new()
+            .with_smooth(0.0)
+            .with_average(ClassAveraging::Micro)
+            .init(&device);
+
+        let loss_per_class = macro_loss.forward_per_class(predictions.clone(), targets.clone());
+        let loss_macro = macro_loss.forward(predictions.clone(), targets.clone(), Reduction::Auto);
+        let loss_micro = micro_loss.forward(predictions, targets, Reduction::Auto);
+
+        loss_per_class
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.272727, 0.333333]]), 5);
+        loss_macro
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.30303]), 5);
+        loss_micro
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.3]), 5);
+    }
+
+    #[test]
+    fn test_tversky_loss() {
+        let (predictions, targets) = inputs();
+        let loss = TverskyLossConfig::new()
+            .with_alpha(0.3)
+            .with_beta(0.7)
+            .with_smooth(0.0)
+            .init(&Default::default());
+
+        let loss_per_class = loss.forward_per_class(predictions.clone(), targets.clone());
+        let loss_sum = loss.forward(predictions, targets, Reduction::Sum);
+
+        loss_per_class
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.245283, 0.361702]]), 5);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.303493]), 5);
+    }
+
+    #[test]
+    fn test_tversky_loss_of_absent_class_is_zero() {
+        let device = Default::default();
+        let predictions = TestTensor::<4>::zeros([2, 1, 2, 2], &device);
+        let targets = TestTensor::<4>::zeros([2, 1, 2, 2], &device);
+        let loss = TverskyLossConfig::new().init(&device);
+
+        let loss = loss.forward_no_reduction(predictions, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.0]), 5);
+    }
+}
@@ -0,0 +1,222 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, Param};
+use crate::nn::conv::{Conv1d, Conv1dConfig};
+use crate::tensor::activation::{silu, softplus};
+use crate::tensor::{backend::Backend, module::selective_scan, Distribution, Int, Tensor};
+
+use super::{Initializer, Linear, LinearConfig, PaddingConfig1d};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create a [Mamba](Mamba) block using the [init function](MambaConfig::init).
+#[derive(Config, Debug)]
+pub struct MambaConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the state of each channel. Default: 16
+    #[config(default = 16)]
+    pub d_state: usize,
+    /// The size of the kernel of the causal convolution. Default: 4
+    #[config(default = 4)]
+    pub d_conv: usize,
+    /// The factor by which the input features are expanded into channels. Default: 2
+    #[config(default = 2)]
+    pub expand: usize,
+    /// The rank of the projection of the time steps. Default: `ceil(d_model / 16)`
+    #[config(default = "None")]
+    pub dt_rank: Option<usize>,
+    /// The minimum of the initial time steps. Default: 0.001
+    #[config(default = 0.001)]
+    pub dt_min: f64,
+    /// The maximum of the initial time steps. Default: 0.1
+    #[config(default = 0.1)]
+    pub dt_max: f64,
+    /// The type of function used to initialize the linear and convolution layers parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The Mamba block, a sequence model whose channels are selective state spaces, as described in
+/// the paper [Mamba: Linear-Time Sequence Modeling with Selective State Spaces](https://arxiv.org/abs/2312.00752).
+///
+/// The input is projected into channels, mixed by a causal depthwise convolution, and scanned
+/// with state space parameters computed from the input itself, the output being gated.
+///
+/// Should be created with [MambaConfig].
+#[derive(Module, Debug)]
+pub struct Mamba<B: Backend> {
+    /// Linear layer projecting the input into the channels and their gate.
+    pub in_proj: Linear<B>,
+    /// Causal depthwise convolution of the channels.
+    pub conv1d: Conv1d<B>,
+    /// Linear layer computing the low rank time steps, and the input and output matrices of
+    /// the state space.
+    pub x_proj: Linear<B>,
+    /// Linear layer projecting the low rank time steps to each channel.
+    pub dt_proj: Linear<B>,
+    /// Logarithm of the opposite of the diagonal state matrix of each channel, of shape
+    /// `[d_inner, d_state]`.
+    pub a_log: Param<Tensor<B, 2>>,
+    /// Skip connection of each channel, of shape `[d_inner]`.
+    pub d: Param<Tensor<B, 1>>,
+    /// Linear layer projecting the channels to the output.
+    pub out_proj: Linear<B>,
+    d_state: usize,
+    dt_rank: usize,
+}
+
+impl MambaConfig {
+    /// Initialize a new [Mamba](Mamba) block.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Mamba<B> {
+        let d_inner = self.expand * self.d_model;
+        let dt_rank = self.dt_rank.unwrap_or(self.d_model.div_ceil(16));
+        let linear = |d_input: usize, d_output: usize, bias: bool| {
+            LinearConfig::new(d_input, d_output)
+                .with_bias(bias)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        // The time steps are initially between the minimum and the maximum, log-uniformly, the
+        // bias being the inverse of the softplus applied to it.
+        let dt = Tensor::<B, 1>::random(
+            [d_inner],
+            Distribution::Uniform(self.dt_min.ln(), self.dt_max.ln()),
+            device,
+        )
+        .exp();
+        let dt_bias = dt.clone().add(dt.neg().exp().neg().add_scalar(1.0).log());
+        let mut dt_proj = linear(dt_rank, d_inner, true);
+        dt_proj.bias = Some(Param::from_tensor(dt_bias));
+
+        // Each channel has the state matrix `-diag(1, 2, ..., d_state)`.
+        let a_log = Tensor::<B, 1, Int>::arange(1..self.d_state as i64 + 1, device)
+            .float()
+            .log()
+            .reshape([1, self.d_state])
+            .repeat(0, d_inner);
+
+        Mamba {
+            in_proj: linear(self.d_model, 2 * d_inner, false),
+            conv1d: Conv1dConfig::new(d_inner, d_inner, self.d_conv)
+                .with_groups(d_inner)
+                .with_padding(PaddingConfig1d::Explicit(self.d_conv - 1))
+                .with_initializer(self.initializer.clone())
+                .init(device),
+            x_proj: linear(d_inner, dt_rank + 2 * self.d_state, false),
+            dt_proj,
+            a_log: Param::from_tensor(a_log),
+            d: Initializer::Ones.init([d_inner], device),
+            out_proj: linear(d_inner, self.d_model, false),
+            d_state: self.d_state,
+            dt_rank,
+        }
+    }
+}
+
+impl<B: Backend> Mamba<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_, seq_length, _] = input.dims();
+        let [d_inner, _] = self.a_log.dims();
+
+        let xz = self.in_proj.forward(input);
+        let x = xz.clone().narrow(2, 0, d_inner);
+        let z = xz.narrow(2, d_inner, d_inner);
+
+        // `[batch_size, d_inner, seq_length]`, the convolution being padded on both sides.
+        let x = self
+            .conv1d
+            .forward(x.swap_dims(1, 2))
+            .narrow(2, 0, seq_length);
+        let x = silu(x);
+
+        let x_dbl = self.x_proj.forward(x.clone().swap_dims(1, 2));
+        let dt = x_dbl.clone().narrow(2, 0, self.dt_rank);
+        let b = x_dbl.clone().narrow(2, self.dt_rank, self.d_state);
+        let c = x_dbl.narrow(2, self.dt_rank + self.d_state, self.d_state);
+
+        let delta = softplus(self.dt_proj.forward(dt), 1.0).swap_dims(1, 2);
+        let a = self.a_log.val().exp().neg();
+
+        let y = selective_scan(x.clone(), delta, a, b.swap_dims(1, 2), c.swap_dims(1, 2));
+        let y = y.add(x.mul(self.d.val().reshape([1, d_inner, 1])));
+        let y = y.swap_dims(1, 2).mul(silu(z));
+
+        self.out_proj.forward(y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_mamba_shapes() {
+        let [batch_size, seq_length, d_model] = [2, 7, 12];
+        let device = Default::default();
+        let mamba = MambaConfig::new(d_model)
+            .with_d_state(4)
+            .init::<TestBackend>(&device);
+        assert_eq!(mamba.a_log.dims(), [2 * d_model, 4]);
+        assert_eq!(mamba.x_proj.weight.dims(), [2 * d_model, 1 + 2 * 4]);
+
+        let input = Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output = mamba.forward(input);
+
+        assert_eq!(
+            output.shape(),
+            Shape::new([batch_size, seq_length, d_model])
+        );
+    }
+
+    #[test]
+    fn test_mamba_should_be_causal() {
+        let [batch_size, seq_length, d_model] = [2, 6, 8];
+        let device = Default::default();
+        let mamba = MambaConfig::new(d_model)
+            .with_d_state(4)
+            .init::<TestBackend>(&device);
+
+        let input_1 = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        // Change the last positions of the input.
+        let input_2 = input_1.clone().slice_assign(
+            [0..batch_size, 4..seq_length, 0..d_model],
+            Tensor::random([batch_size, 2, d_model], Distribution::Default, &device),
+        );
+
+        let output_1 = mamba.forward(input_1);
+        let output_2 = mamba.forward(input_2);
+
+        output_1
+            .slice([0..batch_size, 0..4, 0..d_model])
+            .into_data()
+            .assert_approx_eq(
+                &output_2
+                    .slice([0..batch_size, 0..4, 0..d_model])
+                    .into_data(),
+                3,
+            );
+    }
+}
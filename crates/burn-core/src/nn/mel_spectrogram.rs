@@ -0,0 +1,275 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Ignored, Module};
+use crate::tensor::backend::Backend;
+use crate::tensor::{StftOptions, Tensor, TensorData, WindowFunction};
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Scale converting the frequencies to mels.
+#[derive(Config, Debug, PartialEq)]
+pub enum MelScale {
+    /// The scale of the HTK toolkit, `2595 log10(1 + f / 700)`.
+    Htk,
+    /// The scale of the Auditory Toolbox of Slaney, linear below 1 kHz and logarithmic above.
+    Slaney,
+}
+
+const SLANEY_LINEAR_STEP: f64 = 200.0 / 3.0;
+const SLANEY_MIN_LOG_HZ: f64 = 1000.0;
+const SLANEY_MIN_LOG_MEL: f64 = SLANEY_MIN_LOG_HZ / SLANEY_LINEAR_STEP;
+
+fn slaney_log_step() -> f64 {
+    6.4f64.ln() / 27.0
+}
+
+impl MelScale {
+    fn to_mel(&self, frequency: f64) -> f64 {
+        match self {
+            Self::Htk => 2595.0 * (1.0 + frequency / 700.0).log10(),
+            Self::Slaney => match frequency < SLANEY_MIN_LOG_HZ {
+                true => frequency / SLANEY_LINEAR_STEP,
+                false => {
+                    SLANEY_MIN_LOG_MEL + (frequency / SLANEY_MIN_LOG_HZ).ln() / slaney_log_step()
+                }
+            },
+        }
+    }
+
+    fn to_frequency(&self, mel: f64) -> f64 {
+        match self {
+            Self::Htk => 700.0 * (10f64.powf(mel / 2595.0) - 1.0),
+            Self::Slaney => match mel < SLANEY_MIN_LOG_MEL {
+                true => mel * SLANEY_LINEAR_STEP,
+                false => SLANEY_MIN_LOG_HZ * (slaney_log_step() * (mel - SLANEY_MIN_LOG_MEL)).exp(),
+            },
+        }
+    }
+}
+
+/// Configuration to create a [MelSpectrogram](MelSpectrogram) layer using the
+/// [init function](MelSpectrogramConfig::init).
+///
+/// The default values are the ones of the preprocessing of Whisper, whose filters are the
+/// normalized ones of the Slaney scale.
+#[derive(Config, Debug)]
+pub struct MelSpectrogramConfig {
+    /// The sample rate of the signals, in hertz.
+    #[config(default = 16000)]
+    pub sample_rate: usize,
+    /// The size of the frames of the short-time Fourier transform.
+    #[config(default = 400)]
+    pub n_fft: usize,
+    /// The distance between the starts of consecutive frames.
+    #[config(default = 160)]
+    pub hop_length: usize,
+    /// The number of mel bands.
+    #[config(default = 80)]
+    pub n_mels: usize,
+    /// The lowest frequency of the mel bands, in hertz.
+    #[config(default = 0.0)]
+    pub f_min: f64,
+    /// The highest frequency of the mel bands, in hertz, the Nyquist frequency if none.
+    #[config(default = "None")]
+    pub f_max: Option<f64>,
+    /// The window applied to each frame.
+    #[config(default = "WindowFunction::Hann")]
+    pub window: WindowFunction,
+    /// If true, the frames are centered on multiples of the hop length, the signals being padded
+    /// with their reflection.
+    #[config(default = true)]
+    pub center: bool,
+    /// The exponent of the magnitude of the spectrogram, 2 for the power spectrogram.
+    #[config(default = 2.0)]
+    pub power: f64,
+    /// The scale converting the frequencies to mels.
+    #[config(default = "MelScale::Slaney")]
+    pub mel_scale: MelScale,
+    /// If true, each triangular filter is divided by the width of its band, so that it has a
+    /// constant energy, as done by Slaney.
+    #[config(default = true)]
+    pub normalize: bool,
+}
+
+/// Computes the mel spectrogram of audio signals, the energy of their short-time Fourier
+/// transform in overlapping triangular bands equally spaced on the mel scale.
+///
+/// Should be created with [MelSpectrogramConfig].
+#[derive(Module, Debug)]
+pub struct MelSpectrogram<B: Backend> {
+    /// The triangular filters of shape `[n_fft / 2 + 1, n_mels]`.
+    filterbank: Tensor<B, 2>,
+    stft: Ignored<StftOptions>,
+    power: f64,
+}
+
+impl MelSpectrogramConfig {
+    /// Initialize a new [mel spectrogram](MelSpectrogram) module.
+    ///
+    /// # Panics
+    ///
+    /// If the frequency range isn't within the Nyquist frequency.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> MelSpectrogram<B> {
+        let nyquist = self.sample_rate as f64 / 2.0;
+        let f_max = self.f_max.unwrap_or(nyquist);
+        assert!(
+            0.0 <= self.f_min && self.f_min < f_max && f_max <= nyquist,
+            "The frequency range [{}, {f_max}] must be increasing and within [0, {nyquist}].",
+            self.f_min,
+        );
+
+        let num_freqs = self.n_fft / 2 + 1;
+        let filterbank = Tensor::from_data(
+            TensorData::new(self.filterbank(f_max), [num_freqs, self.n_mels])
+                .convert::<B::FloatElem>(),
+            device,
+        );
+
+        MelSpectrogram {
+            filterbank,
+            stft: Ignored(StftOptions::new(
+                self.n_fft,
+                self.hop_length,
+                self.window,
+                self.center,
+            )),
+            power: self.power,
+        }
+    }
+
+    /// Returns the weights of the frequencies of the transform in each mel band.
+    fn filterbank(&self, f_max: f64) -> Vec<f32> {
+        let num_freqs = self.n_fft / 2 + 1;
+        let nyquist = self.sample_rate as f64 / 2.0;
+        let mel_min = self.mel_scale.to_mel(self.f_min);
+        let mel_max = self.mel_scale.to_mel(f_max);
+
+        // The band m spans the frequencies between the edges m and m + 2, peaking at m + 1.
+        let edges: Vec<f64> = (0..self.n_mels + 2)
+            .map(|i| {
+                let mel = mel_min + (mel_max - mel_min) * i as f64 / (self.n_mels + 1) as f64;
+                self.mel_scale.to_frequency(mel)
+            })
+            .collect();
+
+        let mut filterbank = Vec::with_capacity(num_freqs * self.n_mels);
+        for i in 0..num_freqs {
+            let frequency = match num_freqs {
+                1 => 0.0,
+                _ => nyquist * i as f64 / (num_freqs - 1) as f64,
+            };
+
+            for m in 0..self.n_mels {
+                let rising = (frequency - edges[m]) / (edges[m + 1] - edges[m]);
+                let falling = (edges[m + 2] - frequency) / (edges[m + 2] - edges[m + 1]);
+                let mut weight = f64::max(0.0, f64::min(rising, falling));
+
+                if self.normalize {
+                    weight *= 2.0 / (edges[m + 2] - edges[m]);
+                }
+
+                filterbank.push(weight as f32);
+            }
+        }
+
+        filterbank
+    }
+}
+
+impl<B: Backend> MelSpectrogram<B> {
+    /// Applies the forward pass on the input signals.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, length]`
+    /// - output: `[batch_size, n_mels, num_frames]`
+    pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 3> {
+        let (real, imag) = input.stft(&self.stft);
+        let [batch_size, num_freqs, num_frames] = real.dims();
+        let [_, n_mels] = self.filterbank.dims();
+
+        let spectrogram = real.powf_scalar(2.0) + imag.powf_scalar(2.0);
+        let spectrogram = match self.power == 2.0 {
+            true => spectrogram,
+            false => spectrogram.powf_scalar(self.power / 2.0),
+        };
+
+        spectrogram
+            .swap_dims(1, 2)
+            .reshape([batch_size * num_frames, num_freqs])
+            .matmul(self.filterbank.clone())
+            .reshape([batch_size, num_frames, n_mels])
+            .swap_dims(1, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn mel_spectrogram_htk() {
+        let config = small_config()
+            .with_mel_scale(MelScale::Htk)
+            .with_normalize(false);
+
+        assert_mel_spectrogram(
+            config,
+            TensorData::from([[
+                [16.984888, 14.978759],
+                [3.304284, 4.290021],
+                [35.310293, 16.411322],
+            ]]),
+        );
+    }
+
+    #[test]
+    fn mel_spectrogram_slaney_normalized() {
+        let config = small_config();
+
+        assert_mel_spectrogram(
+            config,
+            TensorData::from([[[8.528806, 7.521447], [1.625, 2.125], [17.721194, 8.228553]]]),
+        );
+    }
+
+    #[test]
+    fn mel_spectrogram_default_shape() {
+        let device = Default::default();
+        let mel = MelSpectrogramConfig::new().init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::zeros([2, 1600], &device);
+
+        let output = mel.forward(input);
+
+        assert_eq!(output.dims(), [2, 80, 11]);
+    }
+
+    fn small_config() -> MelSpectrogramConfig {
+        MelSpectrogramConfig::new()
+            .with_sample_rate(16)
+            .with_n_fft(8)
+            .with_hop_length(4)
+            .with_n_mels(3)
+            .with_window(WindowFunction::Rectangular)
+            .with_center(false)
+    }
+
+    fn assert_mel_spectrogram(config: MelSpectrogramConfig, expected: TensorData) {
+        let device = Default::default();
+        let mel = config.init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[
+                1.0, -2.0, 3.0, 0.5, 2.0, 1.0, -1.0, 0.0, 1.5, -0.5, 2.5, 1.0,
+            ]],
+            &device,
+        );
+
+        let output = mel.forward(input);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
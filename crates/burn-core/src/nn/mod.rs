@@ -7,6 +7,12 @@ pub mod cache;
 /// Convolution module
 pub mod conv;
 
+/// LoRA module
+pub mod lora;
+
+/// Quantization-aware training module
+pub mod qat;
+
 /// Loss module
 pub mod loss;
 
@@ -18,12 +24,16 @@ pub mod transformer;
 
 mod dropout;
 mod embedding;
+mod embedding_bag;
 mod gelu;
 mod initializer;
 mod leaky_relu;
 mod linear;
+mod mamba;
+mod mel_spectrogram;
 mod norm;
 mod padding;
+mod pixel_shuffle;
 mod pos_encoding;
 mod prelu;
 mod relu;
@@ -35,12 +45,16 @@ mod unfold;
 
 pub use dropout::*;
 pub use embedding::*;
+pub use embedding_bag::*;
 pub use gelu::*;
 pub use initializer::*;
 pub use leaky_relu::*;
 pub use linear::*;
+pub use mamba::*;
+pub use mel_spectrogram::*;
 pub use norm::*;
 pub use padding::*;
+pub use pixel_shuffle::*;
 pub use pos_encoding::*;
 pub use prelu::*;
 pub use relu::*;
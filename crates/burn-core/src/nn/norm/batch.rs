@@ -1,6 +1,11 @@
+use alloc::vec::Vec;
+
 use crate as burn;
-use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::module::{
+    num_elements, Content, DisplaySettings, ModuleDisplay, ShapePropagation, SummaryBuilder,
+};
 
+use super::statistics::running_momentum;
 use crate::nn::Initializer;
 use crate::{
     config::Config,
@@ -44,8 +49,8 @@ pub struct BatchNorm<B: Backend, const D: usize> {
     pub running_mean: RunningState<Tensor<B, 1>>,
     /// The running variance.
     pub running_var: RunningState<Tensor<B, 1>>,
-    momentum: f64,
-    epsilon: f64,
+    pub(crate) momentum: f64,
+    pub(crate) epsilon: f64,
 }
 
 impl BatchNormConfig {
@@ -144,17 +149,18 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
 
         let running_mean = self.running_mean.value_sync().to_device(&device);
         let running_var = self.running_var.value_sync().to_device(&device);
+        let momentum = running_momentum(self.running_mean.id(), self.momentum);
 
-        let running_mean = running_mean.mul_scalar(1.0 - self.momentum).add(
+        let running_mean = running_mean.mul_scalar(1.0 - momentum).add(
             mean.clone()
                 .detach()
-                .mul_scalar(self.momentum)
+                .mul_scalar(momentum)
                 .reshape([channels]),
         );
-        let running_var = running_var.mul_scalar(1.0 - self.momentum).add(
+        let running_var = running_var.mul_scalar(1.0 - momentum).add(
             var.clone()
                 .detach()
-                .mul_scalar(self.momentum)
+                .mul_scalar(momentum)
                 .reshape([channels]),
         );
 
@@ -185,6 +191,15 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
     }
 }
 
+impl<const D: usize, B: Backend> ShapePropagation for BatchNorm<B, D> {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        // The statistics, the normalization and the affine transformation.
+        summary.add_flops(7 * num_elements(input));
+
+        input.to_vec()
+    }
+}
+
 impl<const D: usize, B: Backend> ModuleDisplay for BatchNorm<B, D> {
     fn custom_settings(&self) -> Option<DisplaySettings> {
         DisplaySettings::new()
@@ -1,9 +1,12 @@
+use alloc::vec::Vec;
+
 use crate as burn;
 use crate::config::Config;
 use crate::module::DisplaySettings;
 use crate::module::Module;
 use crate::module::ModuleDisplay;
 use crate::module::Param;
+use crate::module::{num_elements, ShapePropagation, SummaryBuilder};
 use crate::nn::Initializer;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
@@ -73,6 +76,15 @@ impl<B: Backend> LayerNorm<B> {
     }
 }
 
+impl<B: Backend> ShapePropagation for LayerNorm<B> {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        // The statistics, the normalization and the affine transformation.
+        summary.add_flops(7 * num_elements(input));
+
+        input.to_vec()
+    }
+}
+
 impl<B: Backend> ModuleDisplay for LayerNorm<B> {
     fn custom_settings(&self) -> Option<DisplaySettings> {
         DisplaySettings::new()
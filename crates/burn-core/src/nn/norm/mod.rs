@@ -3,9 +3,18 @@ mod group;
 mod instance;
 mod layer;
 mod rms;
+mod statistics;
+#[cfg(feature = "std")]
+mod sync_batch;
+mod weight;
 
 pub use batch::*;
 pub use group::*;
 pub use instance::*;
 pub use layer::*;
 pub use rms::*;
+#[cfg(feature = "std")]
+pub use statistics::cumulative_statistics;
+#[cfg(feature = "std")]
+pub use sync_batch::*;
+pub use weight::*;
@@ -0,0 +1,54 @@
+use crate::module::ParamId;
+
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static CUMULATIVE: RefCell<Option<HashMap<ParamId, usize>>> = const { RefCell::new(None) };
+}
+
+/// Runs the function with the running statistics of the [batch norm](crate::nn::BatchNorm)
+/// layers updated as the cumulative average of the statistics of the batches of its training
+/// forward passes on the current thread, instead of their exponential moving average.
+///
+/// The running statistics of each layer are replaced by the ones of its first batch, so they only
+/// depend on the forward passes of the function, e.g. to recompute them for the averaged
+/// parameters of a stochastic weight average.
+#[cfg(feature = "std")]
+pub fn cumulative_statistics<R>(func: impl FnOnce() -> R) -> R {
+    struct Guard(Option<HashMap<ParamId, usize>>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CUMULATIVE.with(|counts| *counts.borrow_mut() = previous);
+        }
+    }
+
+    let _guard = Guard(CUMULATIVE.with(|counts| counts.borrow_mut().replace(HashMap::new())));
+
+    func()
+}
+
+/// The momentum of the update of the running statistics identified by the id, counting the
+/// update when they are [cumulative](cumulative_statistics).
+#[cfg(feature = "std")]
+pub(crate) fn running_momentum(id: &ParamId, momentum: f64) -> f64 {
+    CUMULATIVE.with(|counts| match counts.borrow_mut().as_mut() {
+        Some(counts) => {
+            let count = counts.entry(id.clone()).or_insert(0);
+            *count += 1;
+
+            1.0 / *count as f64
+        }
+        None => momentum,
+    })
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn running_momentum(_id: &ParamId, momentum: f64) -> f64 {
+    momentum
+}
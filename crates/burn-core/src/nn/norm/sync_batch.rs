@@ -0,0 +1,404 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::collective::{Collective, ReduceOp};
+use crate::module::{
+    num_elements, Content, DisplaySettings, ModuleDisplay, ShapePropagation, SummaryBuilder,
+};
+
+use super::statistics::running_momentum;
+use crate::nn::{BatchNorm, BatchNormConfig};
+use crate::{
+    config::Config,
+    module::{Module, Param, RunningState},
+    tensor::{backend::Backend, Tensor},
+};
+
+/// Configuration to create a [SyncBatchNorm](SyncBatchNorm) layer using the
+/// [init function](SyncBatchNormConfig::init).
+#[derive(Config, Debug)]
+pub struct SyncBatchNormConfig {
+    /// The number of features.
+    pub num_features: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// Momentum used to update the metrics. Default: 0.1
+    #[config(default = 0.1)]
+    pub momentum: f64,
+}
+
+/// Applies Batch Normalization over a tensor with the statistics of the batches of all the ranks
+/// of a distributed training, so that the normalization doesn't depend on how the batch is split
+/// across devices, e.g. when training a CNN with small batches on each device.
+///
+/// The statistics are reduced with the [collective](Collective)
+/// [installed](Collective::install) on the current thread when training, and are the ones of the
+/// local batch when none is installed, as with [BatchNorm]. The running statistics are the same
+/// on every rank.
+///
+/// The gradients of the statistics are reduced during the backward pass, which must then be
+/// executed by every rank.
+///
+/// The ranks are either threads of the same process, [grouped](Collective::group) to exchange
+/// the statistics through the host memory, or processes, possibly on different machines,
+/// [connected](Collective::connect) to exchange them over TCP.
+///
+/// Should be created using [SyncBatchNormConfig], or from a [BatchNorm] with
+/// [from_batch_norm](SyncBatchNorm::from_batch_norm).
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct SyncBatchNorm<B: Backend, const D: usize> {
+    /// The learnable weight gamma.
+    pub gamma: Param<Tensor<B, 1>>,
+    /// The learnable weight beta.
+    pub beta: Param<Tensor<B, 1>>,
+    /// The running mean.
+    pub running_mean: RunningState<Tensor<B, 1>>,
+    /// The running variance.
+    pub running_var: RunningState<Tensor<B, 1>>,
+    momentum: f64,
+    epsilon: f64,
+}
+
+impl SyncBatchNormConfig {
+    /// Initializes a new [sync batch norm](SyncBatchNorm) module.
+    pub fn init<B: Backend, const D: usize>(&self, device: &B::Device) -> SyncBatchNorm<B, D> {
+        let batch_norm = BatchNormConfig::new(self.num_features)
+            .with_epsilon(self.epsilon)
+            .with_momentum(self.momentum)
+            .init(device);
+
+        SyncBatchNorm::from_batch_norm(batch_norm)
+    }
+}
+
+impl<const D: usize, B: Backend> SyncBatchNorm<B, D> {
+    /// Synchronize the statistics of the [batch norm](BatchNorm) across ranks, keeping its
+    /// parameters and running statistics.
+    pub fn from_batch_norm(batch_norm: BatchNorm<B, D>) -> Self {
+        Self {
+            gamma: batch_norm.gamma,
+            beta: batch_norm.beta,
+            running_mean: batch_norm.running_mean,
+            running_var: batch_norm.running_var,
+            momentum: batch_norm.momentum,
+            epsilon: batch_norm.epsilon,
+        }
+    }
+
+    /// Convert into a [batch norm](BatchNorm) with the same parameters and running statistics,
+    /// e.g. for inference on a single device.
+    pub fn into_batch_norm(self) -> BatchNorm<B, D> {
+        BatchNorm {
+            gamma: self.gamma,
+            beta: self.beta,
+            running_mean: self.running_mean,
+            running_var: self.running_var,
+            momentum: self.momentum,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [SyncBatchNorm](SyncBatchNorm) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, ...]`
+    /// - output: `[batch_size, channels, ...]`
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the input tensor has a dimension different from `D + 2`.
+    pub fn forward<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+        if D + 2 != DI {
+            panic!(
+                "SyncBatchNorm{}D can only be applied on tensors of size {} with the following \
+                 shape [batch_size, channels, ...], received {}D tensor",
+                D,
+                D + 2,
+                DI
+            );
+        }
+
+        match B::ad_enabled() {
+            true => self.forward_train(input),
+            false => self.forward_inference(input),
+        }
+    }
+
+    fn forward_inference<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+        let device = input.device();
+        let channels = input.dims()[1];
+        let mean = self.running_mean.value().to_device(&device);
+        let var = self.running_var.value().to_device(&device);
+
+        let mut shape = [1; DI];
+        shape[1] = channels;
+
+        self.forward_shared(input, mean.reshape(shape), var.reshape(shape))
+    }
+
+    fn forward_train<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+        let device = input.device();
+        let dims = input.dims();
+        let channels = dims[1];
+        let flatten_size = dims[0] * dims[2..].iter().product::<usize>();
+
+        let mut shape_unsqueeze = [1; DI];
+        shape_unsqueeze[1] = channels;
+
+        let collective = Collective::current();
+        let all_reduce = |tensor: Tensor<B, 1>| match &collective {
+            Some(collective) => collective.all_reduce(tensor, ReduceOp::Sum),
+            None => tensor,
+        };
+
+        let values = input
+            .clone()
+            .swap_dims(0, 1)
+            .reshape([channels, flatten_size]);
+
+        // The ranks can have batches of different sizes.
+        let count = Tensor::from_floats([flatten_size as f32], &device);
+        let sum = values.clone().sum_dim(1).reshape([channels]);
+        let stats = all_reduce(Tensor::cat(vec![sum, count], 0));
+        let count = stats.clone().narrow(0, channels, 1);
+        let mean = stats.narrow(0, 0, channels).div(count.clone());
+
+        let var = values
+            .sub(mean.clone().reshape([channels, 1]))
+            .powf_scalar(2.0)
+            .sum_dim(1)
+            .reshape([channels]);
+        let var = all_reduce(var).div(count);
+
+        let running_mean = self.running_mean.value_sync().to_device(&device);
+        let running_var = self.running_var.value_sync().to_device(&device);
+        let momentum = running_momentum(self.running_mean.id(), self.momentum);
+
+        let running_mean = running_mean
+            .mul_scalar(1.0 - momentum)
+            .add(mean.clone().detach().mul_scalar(momentum));
+        let running_var = running_var
+            .mul_scalar(1.0 - momentum)
+            .add(var.clone().detach().mul_scalar(momentum));
+
+        self.running_mean.update(running_mean.detach());
+        self.running_var.update(running_var.detach());
+
+        self.forward_shared(
+            input,
+            mean.reshape(shape_unsqueeze),
+            var.reshape(shape_unsqueeze),
+        )
+    }
+
+    fn forward_shared<const DI: usize>(
+        &self,
+        x: Tensor<B, DI>,
+        mean: Tensor<B, DI>,
+        var: Tensor<B, DI>,
+    ) -> Tensor<B, DI> {
+        let channels = x.dims()[1];
+        let mut shape = [1; DI];
+        shape[1] = channels;
+
+        let std = var.add_scalar(self.epsilon).sqrt();
+
+        let x = x.sub(mean);
+        let x = x.div(std);
+
+        let x = x.mul(self.gamma.val().reshape(shape));
+
+        x.add(self.beta.val().reshape(shape))
+    }
+}
+
+impl<const D: usize, B: Backend> ShapePropagation for SyncBatchNorm<B, D> {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        // The statistics, the normalization and the affine transformation.
+        summary.add_flops(7 * num_elements(input));
+
+        input.to_vec()
+    }
+}
+
+impl<const D: usize, B: Backend> ModuleDisplay for SyncBatchNorm<B, D> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [num_features] = self.beta.shape().dims;
+
+        content
+            .add("num_features", &num_features)
+            .add("momentum", &self.momentum)
+            .add("epsilon", &self.epsilon)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{module::AutodiffModule, TestAutodiffBackend, TestBackend};
+    use std::thread;
+
+    #[test]
+    fn sync_batch_norm_should_match_batch_norm_without_collective() {
+        let device = Default::default();
+        let batch_norm = BatchNormConfig::new(3).init::<TestAutodiffBackend, 2>(&device);
+        let sync_batch_norm = SyncBatchNorm::from_batch_norm(batch_norm.clone());
+        let input = Tensor::random([2, 3, 2, 2], Distribution::Default, &device);
+
+        sync_batch_norm
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&batch_norm.forward(input.clone()).into_data(), 4);
+        sync_batch_norm
+            .valid()
+            .forward(input.clone().inner())
+            .into_data()
+            .assert_approx_eq(&batch_norm.valid().forward(input.inner()).into_data(), 4);
+    }
+
+    #[test]
+    fn sync_batch_norm_should_use_the_statistics_of_all_ranks() {
+        let device = Default::default();
+        let batch_norm = BatchNormConfig::new(3).init::<TestAutodiffBackend, 1>(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([5, 3, 4], Distribution::Default, &device);
+        let weights =
+            Tensor::<TestAutodiffBackend, 3>::random([5, 3, 4], Distribution::Default, &device);
+
+        // The gradients of the whole batch on a single device.
+        let input_full = input.clone().require_grad();
+        let output = batch_norm.clone().forward(input_full.clone());
+        let grads = output.clone().mul(weights.clone()).sum().backward();
+        let expected_grad = input_full.grad(&grads).unwrap();
+
+        // The batch is split in batches of different sizes across two ranks.
+        let handles: Vec<_> = Collective::group(2)
+            .into_iter()
+            .zip([0..2, 2..5])
+            .map(|(collective, range)| {
+                let module = SyncBatchNorm::from_batch_norm(batch_norm.clone().fork(&device));
+                let input = input.clone().slice([range.clone()]).require_grad();
+                let weights = weights.clone().slice([range]);
+
+                thread::spawn(move || {
+                    let _guard = collective.install();
+                    let output = module.forward(input.clone());
+                    let grads = output.clone().mul(weights).sum().backward();
+
+                    (output.into_data(), input.grad(&grads).unwrap().into_data())
+                })
+            })
+            .collect();
+        let (outputs, grads): (Vec<_>, Vec<_>) = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .unzip();
+
+        let output = Tensor::<TestAutodiffBackend, 3>::cat(
+            outputs
+                .into_iter()
+                .map(|data| Tensor::from_data(data, &device))
+                .collect(),
+            0,
+        );
+        output
+            .into_data()
+            .assert_approx_eq(&batch_norm.forward(input.clone()).into_data(), 4);
+        let grad = Tensor::<TestBackend, 3>::cat(
+            grads
+                .into_iter()
+                .map(|data| Tensor::from_data(data, &device))
+                .collect(),
+            0,
+        );
+        grad.into_data()
+            .assert_approx_eq(&expected_grad.into_data(), 4);
+    }
+
+    #[test]
+    fn sync_batch_norm_should_use_the_statistics_of_connected_processes() {
+        let device = Default::default();
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([5, 3, 4], Distribution::Default, &device);
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        // Each process of the group normalizes its part of the batch with its own module.
+        let handles: Vec<_> = [0..2, 2..5]
+            .into_iter()
+            .enumerate()
+            .map(|(rank, range)| {
+                let module = SyncBatchNormConfig::new(3).init::<TestAutodiffBackend, 1>(&device);
+                let input = input.clone().slice([range]);
+
+                thread::spawn(move || {
+                    let collective = Collective::connect(rank, 2, address).unwrap();
+                    let _guard = collective.install();
+                    let output = module.forward(input);
+
+                    (
+                        output.into_data(),
+                        module.running_mean.value_sync().into_data(),
+                    )
+                })
+            })
+            .collect();
+        let (outputs, running_means): (Vec<_>, Vec<_>) = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .unzip();
+
+        let output = Tensor::<TestAutodiffBackend, 3>::cat(
+            outputs
+                .into_iter()
+                .map(|data| Tensor::from_data(data, &device))
+                .collect(),
+            0,
+        );
+        let batch_norm = BatchNormConfig::new(3).init::<TestAutodiffBackend, 1>(&device);
+        output
+            .into_data()
+            .assert_approx_eq(&batch_norm.forward(input).into_data(), 4);
+        for running_mean in running_means {
+            running_mean.assert_approx_eq(&batch_norm.running_mean.value_sync().into_data(), 5);
+        }
+    }
+
+    #[test]
+    fn sync_batch_norm_should_update_the_running_statistics() {
+        let device = Default::default();
+        let module = SyncBatchNormConfig::new(2).init::<TestAutodiffBackend, 0>(&device);
+
+        module.forward(Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[1.0, 2.0], [3.0, 6.0]],
+            &device,
+        ));
+
+        module
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.2, 0.4]), 5);
+        module
+            .running_var
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 1.3]), 5);
+    }
+}
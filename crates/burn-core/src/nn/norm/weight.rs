@@ -0,0 +1,384 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Ignored, Module, Param};
+use crate::nn::conv::{Conv1d, Conv2d};
+use crate::nn::{Linear, PaddingConfig1d, PaddingConfig2d};
+use crate::tensor::backend::Backend;
+use crate::tensor::module::{conv1d, conv2d};
+use crate::tensor::ops::ConvOptions;
+use crate::tensor::Tensor;
+
+/// Configuration to reparameterize the weights of a layer with [weight normalization](WeightNorm),
+/// creating a [WeightNormLinear](WeightNormLinear) with [init_linear](WeightNormConfig::init_linear),
+/// a [WeightNormConv1d](WeightNormConv1d) with [init_conv1d](WeightNormConfig::init_conv1d) or a
+/// [WeightNormConv2d](WeightNormConv2d) with [init_conv2d](WeightNormConfig::init_conv2d).
+#[derive(Config, Debug)]
+pub struct WeightNormConfig {
+    /// A value required for numerical stability. Default: 1e-12
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+}
+
+impl WeightNormConfig {
+    /// Reparameterize the weights of the [linear](Linear) layer, with a magnitude for each output
+    /// feature.
+    pub fn init_linear<B: Backend>(&self, linear: Linear<B>) -> WeightNormLinear<B> {
+        WeightNormLinear {
+            weight: self.init(linear.weight, 1),
+            bias: linear.bias,
+        }
+    }
+
+    /// Reparameterize the weights of the [1D convolution](Conv1d), with a magnitude for each
+    /// output channel.
+    pub fn init_conv1d<B: Backend>(&self, conv: Conv1d<B>) -> WeightNormConv1d<B> {
+        WeightNormConv1d {
+            weight: self.init(conv.weight, 0),
+            bias: conv.bias,
+            stride: conv.stride,
+            kernel_size: conv.kernel_size,
+            dilation: conv.dilation,
+            groups: conv.groups,
+            padding: conv.padding,
+        }
+    }
+
+    /// Reparameterize the weights of the [2D convolution](Conv2d), with a magnitude for each
+    /// output channel.
+    pub fn init_conv2d<B: Backend>(&self, conv: Conv2d<B>) -> WeightNormConv2d<B> {
+        WeightNormConv2d {
+            weight: self.init(conv.weight, 0),
+            bias: conv.bias,
+            stride: conv.stride,
+            kernel_size: conv.kernel_size,
+            dilation: conv.dilation,
+            groups: conv.groups,
+            padding: conv.padding,
+        }
+    }
+
+    /// Reparameterize the weights with a magnitude for each index of the given dimension, the
+    /// initial magnitudes being the norms of the weights so that they are unchanged.
+    pub fn init<B: Backend, const D: usize>(
+        &self,
+        weight: Param<Tensor<B, D>>,
+        dim: usize,
+    ) -> WeightNorm<B, D> {
+        assert!(
+            dim < D,
+            "The dimension {dim} is out of bounds for {D}D weights."
+        );
+
+        let direction = weight;
+        let magnitude = norm_except(direction.val(), dim, 0.0).detach();
+
+        WeightNorm {
+            magnitude: Param::from_tensor(magnitude),
+            direction,
+            dim,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+/// Weight normalization, reparameterizing weights as the product of a magnitude and of a
+/// direction, `w = g * v / ||v||`, to decouple their norm from their direction during the
+/// optimization, as described in the paper
+/// [Weight Normalization: A Simple Reparameterization to Accelerate Training of Deep Neural Networks](https://arxiv.org/abs/1602.07868).
+///
+/// The norm is computed over every dimension except one, each index of which has its own
+/// magnitude. The magnitudes and the directions are the parameters, and are the ones saved in
+/// the record, the weights being recomputed by each forward pass.
+///
+/// Should be created with [WeightNormConfig].
+#[derive(Module, Debug)]
+pub struct WeightNorm<B: Backend, const D: usize> {
+    /// The magnitude `g` of the weights, with the size of the weights for the normalized
+    /// dimension and a size of one for the others.
+    pub magnitude: Param<Tensor<B, D>>,
+    /// The direction `v` of the weights, with the shape of the weights.
+    pub direction: Param<Tensor<B, D>>,
+    dim: usize,
+    epsilon: f64,
+}
+
+impl<B: Backend, const D: usize> WeightNorm<B, D> {
+    /// Compute the weights from the magnitude and the direction.
+    pub fn weight(&self) -> Tensor<B, D> {
+        let direction = self.direction.val();
+        let norm = norm_except(direction.clone(), self.dim, self.epsilon);
+
+        direction.mul(self.magnitude.val().div(norm))
+    }
+}
+
+/// The norm of the tensor over every dimension except one, keeping the dimensions.
+fn norm_except<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    dim: usize,
+    epsilon: f64,
+) -> Tensor<B, D> {
+    (0..D)
+        .filter(|&d| d != dim)
+        .fold(tensor.powf_scalar(2.0), |sum, d| sum.sum_dim(d))
+        .sqrt()
+        .add_scalar(epsilon)
+}
+
+/// A [linear](Linear) layer whose weights are reparameterized with
+/// [weight normalization](WeightNorm).
+///
+/// Should be created with [WeightNormConfig](WeightNormConfig::init_linear).
+#[derive(Module, Debug)]
+pub struct WeightNormLinear<B: Backend> {
+    /// The reparameterized weights, of shape `[d_input, d_output]`.
+    pub weight: WeightNorm<B, 2>,
+    /// The bias, of shape `[d_output]`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl<B: Backend> WeightNormLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if D == 1 {
+            // Insert and remove an extra batch dimension for the batch matmul to work.
+            return Self::forward::<2>(self, input.unsqueeze()).flatten(0, 1);
+        }
+
+        let output = input.matmul(self.weight.weight().unsqueeze());
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+
+    /// Remove the reparameterization, returning the [linear](Linear) layer with the computed
+    /// weights.
+    pub fn into_linear(self) -> Linear<B> {
+        Linear {
+            weight: Param::from_tensor(self.weight.weight()),
+            bias: self.bias,
+        }
+    }
+}
+
+/// A [1D convolution](Conv1d) whose weights are reparameterized with
+/// [weight normalization](WeightNorm).
+///
+/// Should be created with [WeightNormConfig](WeightNormConfig::init_conv1d).
+#[derive(Module, Debug)]
+pub struct WeightNormConv1d<B: Backend> {
+    /// The reparameterized weights, of shape `[channels_out, channels_in / groups, kernel_size]`.
+    pub weight: WeightNorm<B, 3>,
+    /// The bias, of shape `[channels_out]`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    stride: usize,
+    kernel_size: usize,
+    dilation: usize,
+    groups: usize,
+    padding: Ignored<PaddingConfig1d>,
+}
+
+impl<B: Backend> WeightNormConv1d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, length_in]`
+    /// - output: `[batch_size, channels_out, length_out]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let input = self.padding.pad_input(input);
+        let [_batch_size, _channels, length] = input.dims();
+        let padding = self
+            .padding
+            .calculate_padding_1d(length, self.kernel_size, self.stride);
+
+        conv1d(
+            input,
+            self.weight.weight(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new([self.stride], [padding], [self.dilation], self.groups),
+        )
+    }
+
+    /// Remove the reparameterization, returning the [1D convolution](Conv1d) with the computed
+    /// weights.
+    pub fn into_conv1d(self) -> Conv1d<B> {
+        Conv1d {
+            weight: Param::from_tensor(self.weight.weight()),
+            bias: self.bias,
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            groups: self.groups,
+            padding: self.padding,
+        }
+    }
+}
+
+/// A [2D convolution](Conv2d) whose weights are reparameterized with
+/// [weight normalization](WeightNorm).
+///
+/// Should be created with [WeightNormConfig](WeightNormConfig::init_conv2d).
+#[derive(Module, Debug)]
+pub struct WeightNormConv2d<B: Backend> {
+    /// The reparameterized weights, of shape
+    /// `[channels_out, channels_in / groups, kernel_size_1, kernel_size_2]`.
+    pub weight: WeightNorm<B, 4>,
+    /// The bias, of shape `[channels_out]`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    stride: [usize; 2],
+    kernel_size: [usize; 2],
+    dilation: [usize; 2],
+    groups: usize,
+    padding: Ignored<PaddingConfig2d>,
+}
+
+impl<B: Backend> WeightNormConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let input = self.padding.pad_input(input);
+        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let padding =
+            self.padding
+                .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+
+        conv2d(
+            input,
+            self.weight.weight(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(self.stride, padding, self.dilation, self.groups),
+        )
+    }
+
+    /// Remove the reparameterization, returning the [2D convolution](Conv2d) with the computed
+    /// weights.
+    pub fn into_conv2d(self) -> Conv2d<B> {
+        Conv2d {
+            weight: Param::from_tensor(self.weight.weight()),
+            bias: self.bias,
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            groups: self.groups,
+            padding: self.padding,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::LinearConfig;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[test]
+    fn test_weight_norm_linear_should_have_same_output() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestBackend>(&device);
+        let weight_norm = WeightNormConfig::new().init_linear(linear.clone());
+        assert_eq!(weight_norm.weight.magnitude.dims(), [1, 4]);
+
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &device);
+
+        weight_norm
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&linear.forward(input).into_data(), 3);
+    }
+
+    #[test]
+    fn test_weight_norm_conv2d_should_have_same_output() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 3], [3, 3])
+            .with_padding(PaddingConfig2d::Same)
+            .init::<TestBackend>(&device);
+        let weight_norm = WeightNormConfig::new().init_conv2d(conv.clone());
+        assert_eq!(weight_norm.weight.magnitude.dims(), [3, 1, 1, 1]);
+
+        let input = Tensor::<TestBackend, 4>::random([2, 2, 5, 5], Distribution::Default, &device);
+
+        weight_norm
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&conv.forward(input).into_data(), 3);
+    }
+
+    #[test]
+    fn test_weight_norm_magnitude() {
+        let device = Default::default();
+        let mut weight_norm = WeightNormConfig::new().init_linear(Linear::<TestBackend> {
+            weight: Param::from_data([[3.0, 0.0], [4.0, 1.0]], &device),
+            bias: None,
+        });
+        weight_norm.weight.magnitude = Param::from_data([[10.0, 2.0]], &device);
+
+        let linear = weight_norm.into_linear();
+
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[6.0f32, 0.0], [8.0, 2.0]]), 3);
+    }
+
+    #[test]
+    fn test_weight_norm_record_should_have_magnitude_and_direction() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestAutodiffBackend>(&device);
+        let mut weight_norm = WeightNormConfig::new().init_linear(linear);
+        weight_norm.weight.magnitude = weight_norm
+            .weight
+            .magnitude
+            .map(|magnitude| magnitude.mul_scalar(2.0));
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder
+            .record(weight_norm.clone().into_record(), ())
+            .unwrap();
+        let loaded = WeightNormConfig::new()
+            .init_linear(LinearConfig::new(6, 4).init::<TestAutodiffBackend>(&device))
+            .load_record(recorder.load(bytes, &device).unwrap());
+
+        loaded
+            .weight
+            .magnitude
+            .val()
+            .into_data()
+            .assert_approx_eq(&weight_norm.weight.magnitude.val().into_data(), 5);
+        loaded
+            .weight
+            .weight()
+            .into_data()
+            .assert_approx_eq(&weight_norm.weight.weight().into_data(), 5);
+    }
+
+    #[test]
+    fn test_weight_norm_should_train_magnitude_and_direction() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestAutodiffBackend>(&device);
+        let weight_norm = WeightNormConfig::new().init_linear(linear);
+
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([3, 6], Distribution::Default, &device);
+        let grads = weight_norm.forward(input).sum().backward();
+
+        assert!(weight_norm.weight.magnitude.grad(&grads).is_some());
+        assert!(weight_norm.weight.direction.grad(&grads).is_some());
+    }
+}
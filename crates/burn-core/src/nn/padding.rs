@@ -1,8 +1,10 @@
 use crate as burn;
 
-use crate::tensor::ops::conv::calculate_conv_padding;
+use crate::tensor::ops::conv::{calculate_conv_output_size, calculate_conv_padding};
+use crate::tensor::{backend::Backend, Int, Tensor, TensorData};
 
 use crate::config::Config;
+use alloc::vec::Vec;
 
 /// Padding configuration for 1D operators.
 #[derive(Config, Debug, PartialEq)]
@@ -14,6 +16,11 @@ pub enum PaddingConfig1d {
     Valid,
     /// Applies the specified amount of padding to all inputs.
     Explicit(usize),
+    /// Pads the input with its values wrapped around, by the specified amount.
+    Circular(usize),
+    /// Pads the input with its values reflected around its edges, which aren't repeated, by the
+    /// specified amount.
+    Reflect(usize),
 }
 
 impl PaddingConfig1d {
@@ -28,8 +35,42 @@ impl PaddingConfig1d {
             Self::Valid => 0,
             Self::Same => same_padding(),
             Self::Explicit(value) => *value,
+            Self::Circular(_) | Self::Reflect(_) => 0,
         }
     }
+
+    /// Pads the input for the [circular](Self::Circular) and [reflect](Self::Reflect) modes, the
+    /// other modes being applied by the operator itself.
+    pub(crate) fn pad_input<B: Backend>(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        match self {
+            Self::Circular(padding) => pad_dim(input, 2, *padding, false),
+            Self::Reflect(padding) => pad_dim(input, 2, *padding, true),
+            Self::Same | Self::Valid | Self::Explicit(_) => input,
+        }
+    }
+
+    /// The padding added to the input by [pad_input](Self::pad_input) on each side.
+    pub(crate) fn input_padding(&self) -> usize {
+        match self {
+            Self::Circular(padding) | Self::Reflect(padding) => *padding,
+            Self::Same | Self::Valid | Self::Explicit(_) => 0,
+        }
+    }
+
+    /// The length of the output of a 1D operator, e.g. a convolution or a pooling, for an input
+    /// of the given length.
+    pub(crate) fn output_size_1d(
+        &self,
+        length: usize,
+        kernel_size: usize,
+        stride: usize,
+        dilation: usize,
+    ) -> usize {
+        let length = length + 2 * self.input_padding();
+        let padding = self.calculate_padding_1d(length, kernel_size, stride);
+
+        calculate_conv_output_size(kernel_size, stride, padding, dilation, length)
+    }
 }
 
 /// Padding configuration for 2D operators.
@@ -42,6 +83,12 @@ pub enum PaddingConfig2d {
     Valid,
     /// Applies the specified amount of padding to all inputs.
     Explicit(usize, usize),
+    /// Pads the input with its values wrapped around, by the specified amount for the height and
+    /// the width.
+    Circular(usize, usize),
+    /// Pads the input with its values reflected around its edges, which aren't repeated, by the
+    /// specified amount for the height and the width.
+    Reflect(usize, usize),
 }
 
 impl PaddingConfig2d {
@@ -63,6 +110,85 @@ impl PaddingConfig2d {
             Self::Same => same_padding(),
             Self::Valid => [0, 0],
             Self::Explicit(v1, v2) => [*v1, *v2],
+            Self::Circular(_, _) | Self::Reflect(_, _) => [0, 0],
         }
     }
+
+    /// Pads the input for the [circular](Self::Circular) and [reflect](Self::Reflect) modes, the
+    /// other modes being applied by the operator itself.
+    pub(crate) fn pad_input<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        match self {
+            Self::Circular(v1, v2) => pad_dim(pad_dim(input, 2, *v1, false), 3, *v2, false),
+            Self::Reflect(v1, v2) => pad_dim(pad_dim(input, 2, *v1, true), 3, *v2, true),
+            Self::Same | Self::Valid | Self::Explicit(_, _) => input,
+        }
+    }
+
+    /// The padding added to the input by [pad_input](Self::pad_input) on each side of the
+    /// height and the width.
+    pub(crate) fn input_padding(&self) -> [usize; 2] {
+        match self {
+            Self::Circular(v1, v2) | Self::Reflect(v1, v2) => [*v1, *v2],
+            Self::Same | Self::Valid | Self::Explicit(_, _) => [0, 0],
+        }
+    }
+
+    /// The shape of the output of a 2D operator, e.g. a convolution or a pooling, for an input
+    /// of the given height and width.
+    pub(crate) fn output_size_2d(
+        &self,
+        height: usize,
+        width: usize,
+        kernel_size: &[usize; 2],
+        stride: &[usize; 2],
+        dilation: &[usize; 2],
+    ) -> [usize; 2] {
+        let [input_padding_1, input_padding_2] = self.input_padding();
+        let height = height + 2 * input_padding_1;
+        let width = width + 2 * input_padding_2;
+        let padding = self.calculate_padding_2d(height, width, kernel_size, stride);
+
+        [
+            calculate_conv_output_size(kernel_size[0], stride[0], padding[0], dilation[0], height),
+            calculate_conv_output_size(kernel_size[1], stride[1], padding[1], dilation[1], width),
+        ]
+    }
+}
+
+/// Pads both sides of a dimension by selecting the values wrapped around or reflected at each
+/// padded position, so that the gradients flow back to the selected values.
+fn pad_dim<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    dim: usize,
+    padding: usize,
+    reflect: bool,
+) -> Tensor<B, D> {
+    if padding == 0 {
+        return input;
+    }
+
+    let size = input.dims()[dim] as i64;
+    let padding = padding as i64;
+
+    if reflect {
+        assert!(
+            padding < size,
+            "Reflect padding ({padding}) must be smaller than the padded dimension ({size})."
+        );
+    }
+
+    let indices: Vec<i64> = (-padding..size + padding)
+        .map(|index| match reflect {
+            true if index < 0 => -index,
+            true if index >= size => 2 * (size - 1) - index,
+            _ => index.rem_euclid(size),
+        })
+        .collect();
+    let num_indices = indices.len();
+    let indices = Tensor::<B, 1, Int>::from_data(
+        TensorData::new(indices, [num_indices]).convert::<B::IntElem>(),
+        &input.device(),
+    );
+
+    input.select(dim, indices)
 }
@@ -0,0 +1,140 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{DisplaySettings, Module, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::module::{pixel_shuffle, pixel_unshuffle};
+use crate::tensor::Tensor;
+
+/// Configuration to create a [PixelShuffle](PixelShuffle) layer using the
+/// [init function](PixelShuffleConfig::init).
+#[derive(Config, Debug)]
+pub struct PixelShuffleConfig {
+    /// The factor by which the height and width of the input are increased.
+    pub upscale_factor: usize,
+}
+
+/// Rearranges the channels of the input into spatial blocks, as described in the paper
+/// [Real-Time Single Image and Video Super-Resolution Using an Efficient Sub-Pixel Convolutional Neural Network](https://arxiv.org/abs/1609.05158).
+///
+/// Should be created with [PixelShuffleConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct PixelShuffle {
+    upscale_factor: usize,
+}
+
+impl PixelShuffleConfig {
+    /// Initialize a new [pixel shuffle](PixelShuffle) module.
+    pub fn init(&self) -> PixelShuffle {
+        PixelShuffle {
+            upscale_factor: self.upscale_factor,
+        }
+    }
+}
+
+impl PixelShuffle {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [pixel_shuffle](crate::tensor::module::pixel_shuffle) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels * upscale_factor^2, height, width]`
+    /// - output: `[batch_size, channels, height * upscale_factor, width * upscale_factor]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        pixel_shuffle(input, self.upscale_factor)
+    }
+}
+
+impl ModuleDisplay for PixelShuffle {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: crate::module::Content) -> Option<crate::module::Content> {
+        content
+            .add("upscale_factor", &self.upscale_factor)
+            .optional()
+    }
+}
+
+/// Configuration to create a [PixelUnshuffle](PixelUnshuffle) layer using the
+/// [init function](PixelUnshuffleConfig::init).
+#[derive(Config, Debug)]
+pub struct PixelUnshuffleConfig {
+    /// The factor by which the height and width of the input are decreased.
+    pub downscale_factor: usize,
+}
+
+/// Rearranges spatial blocks of the input into channels, the inverse of [PixelShuffle].
+///
+/// Should be created with [PixelUnshuffleConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct PixelUnshuffle {
+    downscale_factor: usize,
+}
+
+impl PixelUnshuffleConfig {
+    /// Initialize a new [pixel unshuffle](PixelUnshuffle) module.
+    pub fn init(&self) -> PixelUnshuffle {
+        PixelUnshuffle {
+            downscale_factor: self.downscale_factor,
+        }
+    }
+}
+
+impl PixelUnshuffle {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [pixel_unshuffle](crate::tensor::module::pixel_unshuffle) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height * downscale_factor, width * downscale_factor]`
+    /// - output: `[batch_size, channels * downscale_factor^2, height, width]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        pixel_unshuffle(input, self.downscale_factor)
+    }
+}
+
+impl ModuleDisplay for PixelUnshuffle {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: crate::module::Content) -> Option<crate::module::Content> {
+        content
+            .add("downscale_factor", &self.downscale_factor)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn pixel_unshuffle_should_invert_pixel_shuffle() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 4>::random(
+            [2, 8, 3, 5],
+            crate::tensor::Distribution::Default,
+            &device,
+        );
+        let shuffle = PixelShuffleConfig::new(2).init();
+        let unshuffle = PixelUnshuffleConfig::new(2).init();
+
+        let upscaled = shuffle.forward(input.clone());
+        let output = unshuffle.forward(upscaled.clone());
+
+        assert_eq!(upscaled.dims(), [2, 2, 6, 10]);
+        output.into_data().assert_eq(&input.into_data(), true);
+    }
+}
@@ -1,7 +1,10 @@
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::config::Config;
 use crate::module::Module;
+use crate::module::{num_elements, shape_dims, ShapePropagation, SummaryBuilder};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
@@ -44,3 +47,17 @@ impl AdaptiveAvgPool2d {
         adaptive_avg_pool2d(input, self.output_size)
     }
 }
+
+impl ShapePropagation for AdaptiveAvgPool2d {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        let [batch_size, channels, _, _] = shape_dims(input);
+        summary.add_flops(num_elements(input));
+
+        alloc::vec![
+            batch_size,
+            channels,
+            self.output_size[0],
+            self.output_size[1]
+        ]
+    }
+}
@@ -57,6 +57,7 @@ impl MaxPool1d {
     /// - input: `[batch_size, channels, length_in]`
     /// - output: `[batch_size, channels, length_out]`
     pub fn forward<B: Backend>(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let input = self.padding.pad_input(input);
         let [_batch_size, _channels, length] = input.dims();
         let padding = self
             .padding
@@ -1,7 +1,9 @@
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::{Ignored, Module};
+use crate::module::{num_elements, shape_dims, Ignored, Module, ShapePropagation, SummaryBuilder};
 use crate::nn::PaddingConfig2d;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
@@ -57,6 +59,7 @@ impl MaxPool2d {
     /// - input: `[batch_size, channels, height_in, width_in]`
     /// - output: `[batch_size, channels, height_out, width_out]`
     pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let input = self.padding.pad_input(input);
         let [_batch_size, _channels_in, height_in, width_in] = input.dims();
         let padding =
             self.padding
@@ -65,3 +68,22 @@ impl MaxPool2d {
         max_pool2d(input, self.kernel_size, self.stride, padding, self.dilation)
     }
 }
+
+impl ShapePropagation for MaxPool2d {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        let [batch_size, channels, height, width] = shape_dims(input);
+        let [height_out, width_out] = self.padding.output_size_2d(
+            height,
+            width,
+            &self.kernel_size,
+            &self.stride,
+            &self.dilation,
+        );
+
+        let output = [batch_size, channels, height_out, width_out];
+        let kernel_size = self.kernel_size[0] * self.kernel_size[1];
+        summary.add_flops(num_elements(&output) * kernel_size as u64);
+
+        output.to_vec()
+    }
+}
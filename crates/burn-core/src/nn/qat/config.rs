@@ -0,0 +1,96 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Ignored, Param, RunningState};
+use crate::nn::conv::Conv2d;
+use crate::nn::Linear;
+use crate::tensor::{backend::Backend, quantization::QuantizationType, Tensor};
+
+use super::{fake_quant::channel_max, FakeQuantize, QatConv2d, QatLinear};
+
+/// How the scales of a [fake quantization](FakeQuantize) are computed.
+#[derive(Config, Debug, PartialEq)]
+pub enum FakeQuantizeScale {
+    /// The scales are parameters trained with the weights.
+    Learned,
+    /// The scales are computed from a moving average of the largest magnitude of the values
+    /// observed in training, `max = momentum * max + (1 - momentum) * observed`.
+    Observed {
+        /// The momentum of the moving average.
+        momentum: f64,
+    },
+}
+
+/// Configuration to simulate the quantization of the weights of a layer during training,
+/// creating a [QatLinear](QatLinear) with [init_linear](QatConfig::init_linear) or a
+/// [QatConv2d](QatConv2d) with [init_conv2d](QatConfig::init_conv2d).
+///
+/// The weights are quantized symmetrically, and are initially scaled so that their largest
+/// magnitude is mapped to the largest value of the quantization type.
+#[derive(Config, Debug)]
+pub struct QatConfig {
+    /// The integer type the weights are quantized to. Default: QInt8
+    #[config(default = "QuantizationType::QInt8")]
+    pub q_type: QuantizationType,
+    /// If true, each output channel has its own scale, otherwise the whole weight shares one.
+    /// Default: true
+    #[config(default = true)]
+    pub per_channel: bool,
+    /// How the scales are computed. Default: Learned
+    #[config(default = "FakeQuantizeScale::Learned")]
+    pub scale: FakeQuantizeScale,
+}
+
+impl QatConfig {
+    /// Wrap the [linear](Linear) layer, simulating the quantization of its weights with a scale
+    /// for each output feature.
+    pub fn init_linear<B: Backend>(&self, linear: Linear<B>) -> QatLinear<B> {
+        let weight_quant = self.init_fake_quantize(&linear.weight.val(), Some(1));
+
+        QatLinear {
+            linear,
+            weight_quant,
+        }
+    }
+
+    /// Wrap the [2D convolution](Conv2d), simulating the quantization of its weights with a scale
+    /// for each output channel.
+    pub fn init_conv2d<B: Backend>(&self, conv: Conv2d<B>) -> QatConv2d<B> {
+        let weight_quant = self.init_fake_quantize(&conv.weight.val(), Some(0));
+
+        QatConv2d { conv, weight_quant }
+    }
+
+    /// Initialize a [fake quantization](FakeQuantize) of the tensor, with a scale for each channel
+    /// along the axis when the quantization is per channel.
+    pub fn init_fake_quantize<B: Backend, const D: usize>(
+        &self,
+        tensor: &Tensor<B, D>,
+        axis: Option<usize>,
+    ) -> FakeQuantize<B> {
+        let axis = axis.filter(|_| self.per_channel);
+        let max = channel_max(tensor.clone(), axis);
+        let (_, q_max) = self.q_type.range();
+
+        let (scale, observed_max, momentum) = match self.scale {
+            FakeQuantizeScale::Learned => {
+                let scale = max.div_scalar(q_max);
+                // Avoid dividing by zero when all the values of a channel are zero.
+                let scale = scale.clone().mask_fill(scale.equal_elem(0.0), 1.0);
+
+                (Some(Param::from_tensor(scale.detach())), None, 0.0)
+            }
+            FakeQuantizeScale::Observed { momentum } => {
+                (None, Some(RunningState::new(max.detach())), momentum)
+            }
+        };
+
+        FakeQuantize {
+            scale,
+            observed_max,
+            axis,
+            momentum,
+            q_type: Ignored(self.q_type),
+        }
+    }
+}
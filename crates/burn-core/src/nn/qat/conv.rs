@@ -0,0 +1,102 @@
+use crate as burn;
+
+use crate::module::{Ignored, Module, Param};
+use crate::nn::conv::Conv2d;
+use crate::tensor::{backend::Backend, module::conv2d, ops::ConvOptions, Int, Tensor};
+
+use super::FakeQuantize;
+
+/// A [2D convolution](Conv2d) trained with the simulated quantization of its weights, whose
+/// fake quantization is folded into the weights once trained.
+///
+/// Should be created with [QatConfig](super::QatConfig::init_conv2d).
+#[derive(Module, Debug)]
+pub struct QatConv2d<B: Backend> {
+    /// The wrapped convolution, with the weights in floating point.
+    pub conv: Conv2d<B>,
+    /// The fake quantization of the weights, with a scale for each output channel.
+    pub weight_quant: FakeQuantize<B>,
+}
+
+impl<B: Backend> QatConv2d<B> {
+    /// Applies the forward pass on the input tensor, with the fake quantized weights.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let conv = &self.conv;
+        let input = conv.padding.pad_input(input);
+        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let padding =
+            conv.padding
+                .calculate_padding_2d(height_in, width_in, &conv.kernel_size, &conv.stride);
+
+        conv2d(
+            input,
+            self.weight_quant.forward(conv.weight.val()),
+            conv.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(conv.stride, padding, conv.dilation, conv.groups),
+        )
+    }
+
+    /// Fold the fake quantization into the weights, returning a [2D convolution](Conv2d) whose
+    /// weights are the dequantized ones.
+    pub fn into_conv2d(self) -> Conv2d<B> {
+        let weight = self.weight_quant.forward(self.conv.weight.val());
+
+        Conv2d {
+            weight: Param::initialized(self.conv.weight.id, weight.detach()),
+            bias: self.conv.bias,
+            stride: self.conv.stride,
+            kernel_size: self.conv.kernel_size,
+            dilation: self.conv.dilation,
+            groups: self.conv.groups,
+            padding: Ignored(self.conv.padding.0),
+        }
+    }
+
+    /// Quantize the weights with the trained scales, returning the quantized values of shape
+    /// `[channels_out, channels_in / groups, kernel_size_1, kernel_size_2]`, and the scale of each
+    /// output channel, of shape `[channels_out]`, or of the whole weight, of shape `[1]`.
+    pub fn quantized_weight(&self) -> (Tensor<B, 4, Int>, Tensor<B, 1>) {
+        (
+            self.weight_quant.quantize(self.conv.weight.val()),
+            self.weight_quant.scale().detach(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{conv::Conv2dConfig, qat::QatConfig, PaddingConfig2d};
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_qat_conv2d_should_fold_into_conv2d() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 3], [3, 3])
+            .with_padding(PaddingConfig2d::Same)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([1, 2, 5, 5], Distribution::Default, &device);
+        let qat = QatConfig::new().init_conv2d(conv);
+
+        let (values, scale) = qat.quantized_weight();
+        assert_eq!(values.dims(), [3, 2, 3, 3]);
+        assert_eq!(scale.dims(), [3]);
+
+        let expected = qat.forward(input.clone()).into_data();
+        let folded = qat.into_conv2d();
+        folded.weight.val().into_data().assert_approx_eq(
+            &values.float().mul(scale.reshape([3, 1, 1, 1])).into_data(),
+            5,
+        );
+        folded
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,236 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use crate::module::{Ignored, Module, Param, RunningState};
+use crate::tensor::{backend::Backend, quantization::QuantizationType, Int, Tensor};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Simulates the symmetric quantization of a tensor during training, rounding its values to the
+/// ones representable by integers of a quantization type, `x = clamp(round(x / scale)) * scale`,
+/// so that a model learns to be robust to the quantization of its weights.
+///
+/// The rounding is skipped by the backward pass, the gradient going straight through to the
+/// values in the representable range. The scales are either learned with the gradient of the
+/// quantization error, as described in [Learned Step Size Quantization](https://arxiv.org/abs/1902.08153),
+/// or computed from a moving average of the largest magnitude observed by the forward passes in
+/// training.
+///
+/// Each channel along an axis can have its own scale, or the whole tensor can share one.
+///
+/// Should be created with [QatConfig](super::QatConfig).
+#[derive(Module, Debug)]
+pub struct FakeQuantize<B: Backend> {
+    /// The learned scale of each channel, of shape `[num_channels]`, or `None` when the scales
+    /// are observed.
+    pub scale: Option<Param<Tensor<B, 1>>>,
+    /// The moving average of the largest magnitude of each channel, of shape `[num_channels]`,
+    /// from which the scales are computed when they are observed.
+    pub observed_max: Option<RunningState<Tensor<B, 1>>>,
+    pub(super) axis: Option<usize>,
+    pub(super) momentum: f64,
+    pub(super) q_type: Ignored<QuantizationType>,
+}
+
+impl<B: Backend> FakeQuantize<B> {
+    /// Applies the forward pass on the input tensor, updating the observed scales when training.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[...]`, with `num_channels` elements along the axis of the channels
+    /// - output: `[...]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let (q_min, q_max) = self.q_type.range();
+
+        if let (Some(observed_max), true) = (&self.observed_max, B::ad_enabled()) {
+            let max = channel_max(input.clone().detach(), self.axis);
+            let max = observed_max
+                .value_sync()
+                .to_device(&max.device())
+                .mul_scalar(self.momentum)
+                .add(max.mul_scalar(1.0 - self.momentum));
+
+            observed_max.update(max);
+        }
+
+        let scale = match &self.scale {
+            Some(scale) => {
+                // Balance the gradient of the scales with the one of the weights.
+                let num_elements = input.shape().num_elements() / scale.dims()[0];
+                let grad_scale = 1.0 / ((num_elements * q_max as usize) as f64).sqrt();
+                let scale = scale.val().clamp_min(f32::EPSILON);
+                let scaled = scale.clone().mul_scalar(grad_scale);
+
+                scaled.clone() + (scale - scaled).detach()
+            }
+            None => self.scale(),
+        };
+        let scale = self.broadcast(scale);
+
+        let scaled = input.div(scale.clone());
+        let rounded = round(scaled.clone()).float();
+        // Straight-through estimator: the value is rounded, the gradient is the identity.
+        let rounded = scaled.clone() + (rounded - scaled).detach();
+
+        rounded.clamp(q_min, q_max).mul(scale)
+    }
+
+    /// The scale of each channel, of shape `[num_channels]`.
+    pub fn scale(&self) -> Tensor<B, 1> {
+        match (&self.scale, &self.observed_max) {
+            (Some(scale), _) => scale.val().clamp_min(f32::EPSILON),
+            (None, Some(observed_max)) => {
+                let (_, q_max) = self.q_type.range();
+                let scale = observed_max.value_sync().div_scalar(q_max);
+
+                // Avoid dividing by zero when no value of a channel was observed.
+                scale.clone().mask_fill(scale.equal_elem(0.0), 1.0)
+            }
+            (None, None) => unreachable!("The scales should either be learned or observed."),
+        }
+    }
+
+    /// Quantize the tensor with the current scales, returning the integer values, e.g. to export
+    /// the weights of a trained model.
+    pub fn quantize<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D, Int> {
+        let (q_min, q_max) = self.q_type.range();
+        let scale = self.broadcast(self.scale());
+
+        round(tensor.div(scale)).clamp(q_min, q_max)
+    }
+
+    /// The integer type the values are quantized to.
+    pub fn q_type(&self) -> QuantizationType {
+        *self.q_type
+    }
+
+    /// The axis of the channels, or `None` when the whole tensor shares one scale.
+    pub fn axis(&self) -> Option<usize> {
+        self.axis
+    }
+
+    /// Reshape the scales to broadcast them along the axis of the channels.
+    fn broadcast<const D: usize>(&self, scale: Tensor<B, 1>) -> Tensor<B, D> {
+        let mut dims = [1; D];
+        if let Some(axis) = self.axis {
+            dims[axis] = scale.dims()[0];
+        }
+
+        scale.reshape(dims)
+    }
+}
+
+/// The largest magnitude of each channel along the axis, or of the whole tensor.
+pub(super) fn channel_max<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    axis: Option<usize>,
+) -> Tensor<B, 1> {
+    let tensor = tensor.abs();
+
+    match axis {
+        Some(axis) => {
+            let num_channels = tensor.dims()[axis];
+            let dims: Vec<usize> = (0..D).filter(|&dim| dim != axis).collect();
+
+            dims.into_iter()
+                .fold(tensor, |tensor, dim| tensor.max_dim(dim))
+                .reshape([num_channels])
+        }
+        None => tensor.max(),
+    }
+}
+
+/// Round the values half away from zero.
+fn round<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D, Int> {
+    // Truncating the magnitude increased by one half rounds half away from zero.
+    tensor
+        .clone()
+        .abs()
+        .add_scalar(0.5)
+        .int()
+        .mul(tensor.sign().int())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::qat::{FakeQuantizeScale, QatConfig};
+    use crate::tensor::TensorData;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[test]
+    fn test_fake_quantize_per_channel() {
+        let device = Default::default();
+        let weight = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 0.11], [-0.55, 0.03], [0.3, -0.2]],
+            &device,
+        );
+
+        let fake_quant = QatConfig::new()
+            .with_q_type(QuantizationType::QInt4)
+            .init_fake_quantize(&weight, Some(1));
+
+        // The scales are 1/7 and 0.2/7, the largest magnitude of each column being mapped to 7.
+        fake_quant
+            .scale()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0 / 7.0, 0.2 / 7.0]), 5);
+        fake_quant
+            .quantize(weight.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([[7, 4], [-4, 1], [2, -7]]), false);
+        fake_quant.forward(weight).into_data().assert_approx_eq(
+            &TensorData::from([[1.0, 0.8 / 7.0], [-4.0 / 7.0, 0.2 / 7.0], [2.0 / 7.0, -0.2]]),
+            5,
+        );
+    }
+
+    #[test]
+    fn test_fake_quantize_gradients_go_straight_through() {
+        let device = Default::default();
+        let weight = Tensor::<TestAutodiffBackend, 1>::from_floats([1.0, -0.26, 3.0], &device)
+            .require_grad();
+        let mut fake_quant = QatConfig::new()
+            .with_per_channel(false)
+            .init_fake_quantize(&weight, None);
+        // Values above 1.27 are clamped.
+        fake_quant.scale = Some(Param::from_data([0.01], &device));
+
+        let grads = fake_quant.forward(weight.clone()).sum().backward();
+
+        weight
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0f32, 1.0, 0.0]), false);
+        let scale = fake_quant.scale.as_ref().unwrap();
+        assert!(scale.grad(&grads).is_some());
+    }
+
+    #[test]
+    fn test_fake_quantize_observes_the_range_in_training() {
+        let device = Default::default();
+        let weight = Tensor::<TestAutodiffBackend, 1>::from_floats([1.27, -0.5], &device);
+        let fake_quant = QatConfig::new()
+            .with_per_channel(false)
+            .with_scale(FakeQuantizeScale::Observed { momentum: 0.5 })
+            .init_fake_quantize(&weight, None);
+
+        fake_quant.forward(weight.mul_scalar(3.0));
+
+        // The observed maximum is updated from 1.27 to (1.27 + 3.81) / 2.
+        fake_quant
+            .scale()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.02]), 5);
+        // Not when the forward pass doesn't track gradients.
+        let valid = crate::module::AutodiffModule::valid(&fake_quant);
+        valid.forward(Tensor::<TestBackend, 1>::from_floats([100.0], &device));
+        valid
+            .scale()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.02]), 5);
+    }
+}
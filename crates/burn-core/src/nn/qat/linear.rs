@@ -0,0 +1,169 @@
+use crate as burn;
+
+use crate::module::{Module, Param, ParamId};
+use crate::nn::Linear;
+use crate::tensor::{backend::Backend, module::dequantize_matmul, Int, Tensor};
+
+use super::FakeQuantize;
+
+/// A [linear](Linear) layer trained with the simulated quantization of its weights, to be
+/// exported as a [QuantizedLinear] once trained.
+///
+/// Should be created with [QatConfig](super::QatConfig::init_linear).
+#[derive(Module, Debug)]
+pub struct QatLinear<B: Backend> {
+    /// The wrapped layer, with the weights in floating point.
+    pub linear: Linear<B>,
+    /// The fake quantization of the weights, with a scale for each output feature.
+    pub weight_quant: FakeQuantize<B>,
+}
+
+impl<B: Backend> QatLinear<B> {
+    /// Applies the forward pass on the input tensor, with the fake quantized weights.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if D == 1 {
+            // Insert and remove an extra batch dimension for the batch matmul to work.
+            return Self::forward::<2>(self, input.unsqueeze()).flatten(0, 1);
+        }
+
+        let weight = self.weight_quant.forward(self.linear.weight.val());
+        let output = input.matmul(weight.unsqueeze());
+
+        match &self.linear.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+
+    /// Fold the fake quantization into the weights, returning a [linear](Linear) layer whose
+    /// weights are the dequantized ones.
+    pub fn into_linear(self) -> Linear<B> {
+        let weight = self.weight_quant.forward(self.linear.weight.val());
+
+        Linear {
+            weight: Param::initialized(self.linear.weight.id, weight.detach()),
+            bias: self.linear.bias,
+        }
+    }
+
+    /// Quantize the weights with the trained scales, returning a [QuantizedLinear] layer.
+    pub fn into_quantized(self) -> QuantizedLinear<B> {
+        let [_, d_output] = self.linear.weight.dims();
+        let weight = self.weight_quant.quantize(self.linear.weight.val());
+        // The scale shared by the whole weight is repeated for each output feature.
+        let scale = self
+            .weight_quant
+            .scale()
+            .detach()
+            .expand([d_output])
+            .reshape([d_output]);
+
+        QuantizedLinear {
+            weight: Param::initialized(ParamId::new(), weight),
+            scale: Param::from_tensor(scale).set_require_grad(false),
+            bias: self.linear.bias,
+        }
+    }
+}
+
+/// A [linear](Linear) layer whose weights are quantized to integers with a scale for each output
+/// feature, dequantized on the fly by the forward pass with
+/// [dequantize_matmul](crate::tensor::module::dequantize_matmul).
+///
+/// Should be created with [QatLinear::into_quantized].
+#[derive(Module, Debug)]
+pub struct QuantizedLinear<B: Backend> {
+    /// The quantized values of the weights, of shape `[d_input, d_output]`.
+    pub weight: Param<Tensor<B, 2, Int>>,
+    /// The scale of the weights of each output feature, of shape `[d_output]`.
+    pub scale: Param<Tensor<B, 1>>,
+    /// The bias of the layer, of shape `[d_output]`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl<B: Backend> QuantizedLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let mut dims = input.dims();
+        let [d_input, d_output] = self.weight.dims();
+        let num_rows = dims[..D - 1].iter().product();
+
+        let output = dequantize_matmul(
+            input.reshape([1, num_rows, d_input]),
+            self.weight.val(),
+            self.scale.val(),
+        );
+        dims[D - 1] = d_output;
+        let output = output.reshape(dims);
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+
+    /// Dequantize the weights, returning a [linear](Linear) layer.
+    pub fn dequantize(self) -> Linear<B> {
+        let [_, d_output] = self.weight.dims();
+        let weight = self
+            .weight
+            .val()
+            .float()
+            .mul(self.scale.val().reshape([1, d_output]));
+
+        Linear {
+            weight: Param::from_tensor(weight),
+            bias: self.bias,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{qat::QatConfig, LinearConfig};
+    use crate::tensor::{quantization::QuantizationType, Distribution, TensorData};
+    use crate::TestBackend;
+
+    #[test]
+    fn test_quantized_linear_should_match_qat_linear() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &device);
+
+        for per_channel in [true, false] {
+            let qat = QatConfig::new()
+                .with_q_type(QuantizationType::QInt4)
+                .with_per_channel(per_channel)
+                .init_linear(linear.clone());
+            let expected = qat.forward(input.clone()).into_data();
+
+            let quantized = qat.clone().into_quantized();
+            quantized
+                .forward(input.clone())
+                .into_data()
+                .assert_approx_eq(&expected, 3);
+            quantized
+                .weight
+                .val()
+                .abs()
+                .max()
+                .into_data()
+                .assert_eq(&TensorData::from([7]), false);
+            qat.into_linear()
+                .forward(input.clone())
+                .into_data()
+                .assert_approx_eq(&expected, 3);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod config;
+mod conv;
+mod fake_quant;
+mod linear;
+
+pub use config::*;
+pub use conv::*;
+pub use fake_quant::*;
+pub use linear::*;
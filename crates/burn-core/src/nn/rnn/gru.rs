@@ -3,10 +3,13 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::nn::rnn::gate_controller;
+use crate::nn::rnn::PackedSequence;
 use crate::nn::Initializer;
 use crate::tensor::activation;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
+use alloc::vec;
+use alloc::vec::Vec;
 
 use super::gate_controller::GateController;
 
@@ -22,9 +25,15 @@ pub struct GruConfig {
     /// Gru initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
+    /// If the sequences should also be processed in reverse, with
+    /// [forward_bidirectional](Gru::forward_bidirectional), concatenating the hidden states of
+    /// both directions and stacking their final states.
+    #[config(default = false)]
+    pub bidirectional: bool,
 }
 
-/// The Gru (Gated recurrent unit) module. This implementation is for a unidirectional, stateless, Gru.
+/// The Gru (Gated recurrent unit) module. This implementation is for a stateless Gru,
+/// unidirectional unless created with [bidirectional](GruConfig::bidirectional).
 ///
 /// Introduced in the paper: [Learning Phrase Representations using RNN Encoder-Decoder for Statistical Machine Translation](https://arxiv.org/abs/1406.1078).
 ///
@@ -34,40 +43,42 @@ pub struct Gru<B: Backend> {
     update_gate: GateController<B>,
     reset_gate: GateController<B>,
     new_gate: GateController<B>,
+    reverse: Option<GruGates<B>>,
     d_hidden: usize,
 }
 
+/// The gates of the reverse direction of a bidirectional [Gru](Gru).
+#[derive(Module, Debug)]
+pub struct GruGates<B: Backend> {
+    update_gate: GateController<B>,
+    reset_gate: GateController<B>,
+    new_gate: GateController<B>,
+}
+
 impl GruConfig {
     /// Initialize a new [gru](Gru) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> Gru<B> {
         let d_output = self.d_hidden;
 
-        let update_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-            device,
-        );
-        let reset_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-            device,
-        );
-        let new_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-            device,
-        );
+        let new_gate = || {
+            gate_controller::GateController::new(
+                self.d_input,
+                d_output,
+                self.bias,
+                self.initializer.clone(),
+                device,
+            )
+        };
 
         Gru {
-            update_gate,
-            reset_gate,
-            new_gate,
+            update_gate: new_gate(),
+            reset_gate: new_gate(),
+            new_gate: new_gate(),
+            reverse: self.bidirectional.then(|| GruGates {
+                update_gate: new_gate(),
+                reset_gate: new_gate(),
+                new_gate: new_gate(),
+            }),
             d_hidden: self.d_hidden,
         }
     }
@@ -80,14 +91,22 @@ impl<B: Backend> Gru<B> {
     /// # Shapes
     /// - batched_input: `[batch_size, sequence_length, input_size]`.
     /// - state: An optional tensor representing an initial cell state with the same dimensions
-    ///          as batched_input. If none is provided, one will be generated.
+    ///   as batched_input. If none is provided, one will be generated.
     /// - output: `[batch_size, sequence_length, hidden_size]`.
+    ///
+    /// # Panics
+    ///
+    /// When the GRU is [bidirectional](GruConfig::bidirectional), whose states have a
+    /// direction dimension, see [forward_bidirectional](Gru::forward_bidirectional).
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
         state: Option<Tensor<B, 3>>,
     ) -> Tensor<B, 3> {
+        self.assert_unidirectional();
+
         let [batch_size, seq_length, _] = batched_input.shape().dims;
+        let gates = self.gates();
 
         let mut hidden_state = match state {
             Some(state) => state,
@@ -104,25 +123,7 @@ impl<B: Backend> Gru<B> {
         {
             let input_t = input_t.squeeze(1);
             let hidden_t = hidden_t.squeeze(1);
-            // u(pdate)g(ate) tensors
-            let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, &self.update_gate);
-            let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
-
-            // r(eset)g(ate) tensors
-            let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, &self.reset_gate);
-            let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
-            let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
-
-            // n(ew)g(ate) tensor
-            let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, &self.new_gate);
-            let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
-
-            // calculate linear interpolation between previous hidden state and candidate state:
-            // g(t) * (1 - z(t)) + z(t) * hidden_t
-            let state_vector = candidate_state
-                .clone()
-                .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
-                + update_values.clone().mul(hidden_t);
+            let state_vector = gates.step(input_t, hidden_t);
 
             let current_shape = state_vector.shape().dims;
             let unsqueezed_shape = [current_shape[0], 1, current_shape[1]];
@@ -136,6 +137,254 @@ impl<B: Backend> Gru<B> {
         hidden_state
     }
 
+    /// Applies the forward pass on the packed sequences, only computing the steps of the
+    /// sequences that are not over, so that the padding doesn't affect their states.
+    ///
+    /// # Shapes
+    /// - input: [packed sequences](PackedSequence) with `input_size` features.
+    /// - state: An optional initial hidden state of shape `[batch_size, hidden_size]`, in the
+    ///   order of the batch the sequences were packed from. If none is provided, zeros
+    ///   are used.
+    /// - output: packed sequences with `hidden_size` features, and the hidden state at the last
+    ///   step of each sequence, of shape `[batch_size, hidden_size]`.
+    ///
+    /// # Panics
+    ///
+    /// When the GRU is [bidirectional](GruConfig::bidirectional), see
+    /// [forward_packed_bidirectional](Gru::forward_packed_bidirectional).
+    pub fn forward_packed(
+        &self,
+        input: PackedSequence<B>,
+        state: Option<Tensor<B, 2>>,
+    ) -> (PackedSequence<B>, Tensor<B, 2>) {
+        self.assert_unidirectional();
+
+        let state = state.map(|state| input.sort(state));
+        let (output, state) =
+            self.gates()
+                .forward_steps(input.data(), input.batch_sizes(), state, false);
+
+        (input.with_data(output), input.unsort(state))
+    }
+
+    /// Applies the forward pass of a [bidirectional](GruConfig::bidirectional) GRU on the input
+    /// tensor, like [BiGru::forward] with the weights of a single module.
+    ///
+    /// # Shapes
+    /// - batched_input: `[batch_size, sequence_length, input_size]`.
+    /// - state: An optional initial hidden state of the forward and reverse directions, of shape
+    ///   `[2, batch_size, hidden_size]`. If none is provided, zeros are used.
+    /// - output: `[batch_size, sequence_length, hidden_size * 2]`, and the final hidden state of
+    ///   the forward and reverse directions, of shape `[2, batch_size, hidden_size]`.
+    ///
+    /// # Panics
+    ///
+    /// When the GRU isn't bidirectional.
+    pub fn forward_bidirectional(
+        &self,
+        batched_input: Tensor<B, 3>,
+        state: Option<Tensor<B, 3>>,
+    ) -> (Tensor<B, 3>, Tensor<B, 3>) {
+        forward_bidirectional(self.gates(), self.reverse_gates(), batched_input, state)
+    }
+
+    /// Applies the forward pass of a [bidirectional](GruConfig::bidirectional) GRU on the packed
+    /// sequences, like [BiGru::forward_packed] with the weights of a single module.
+    ///
+    /// # Shapes
+    /// - input: [packed sequences](PackedSequence) with `input_size` features.
+    /// - state: An optional initial hidden state of the forward and reverse directions, of shape
+    ///   `[2, batch_size, hidden_size]`, in the order of the batch the sequences were packed
+    ///   from. If none is provided, zeros are used.
+    /// - output: packed sequences with `hidden_size * 2` features, and the final hidden state of
+    ///   the forward and reverse directions of each sequence, of shape
+    ///   `[2, batch_size, hidden_size]`.
+    ///
+    /// # Panics
+    ///
+    /// When the GRU isn't bidirectional.
+    pub fn forward_packed_bidirectional(
+        &self,
+        input: PackedSequence<B>,
+        state: Option<Tensor<B, 3>>,
+    ) -> (PackedSequence<B>, Tensor<B, 3>) {
+        forward_packed_bidirectional(self.gates(), self.reverse_gates(), input, state)
+    }
+
+    fn assert_unidirectional(&self) {
+        assert!(
+            self.reverse.is_none(),
+            "The states of a bidirectional GRU have a direction dimension, use \
+             forward_bidirectional or forward_packed_bidirectional."
+        );
+    }
+
+    fn reverse_gates(&self) -> Gates<'_, B> {
+        let reverse = self
+            .reverse
+            .as_ref()
+            .expect("The GRU should be bidirectional, use forward or forward_packed otherwise.");
+
+        Gates {
+            update_gate: &reverse.update_gate,
+            reset_gate: &reverse.reset_gate,
+            new_gate: &reverse.new_gate,
+            d_hidden: self.d_hidden,
+        }
+    }
+
+    fn gates(&self) -> Gates<'_, B> {
+        Gates {
+            update_gate: &self.update_gate,
+            reset_gate: &self.reset_gate,
+            new_gate: &self.new_gate,
+            d_hidden: self.d_hidden,
+        }
+    }
+}
+
+/// Applies the forward and reverse directions on the input tensor, concatenating their hidden
+/// states and stacking their final states.
+fn forward_bidirectional<B: Backend>(
+    forward: Gates<'_, B>,
+    reverse: Gates<'_, B>,
+    batched_input: Tensor<B, 3>,
+    state: Option<Tensor<B, 3>>,
+) -> (Tensor<B, 3>, Tensor<B, 3>) {
+    let [batch_size, seq_length, _] = batched_input.dims();
+    let [state_forward, state_reverse] = split_state(state);
+
+    let (output_forward, final_state_forward) = forward.forward_steps(
+        batched_input.clone(),
+        vec![batch_size; seq_length],
+        state_forward,
+        false,
+    );
+    let (output_reverse, final_state_reverse) = reverse.forward_steps(
+        batched_input,
+        vec![batch_size; seq_length],
+        state_reverse,
+        true,
+    );
+
+    (
+        Tensor::cat([output_forward, output_reverse].to_vec(), 2),
+        Tensor::stack([final_state_forward, final_state_reverse].to_vec(), 0),
+    )
+}
+
+/// Applies the forward and reverse directions on the packed sequences, the reverse direction
+/// starting at the last step of each sequence.
+fn forward_packed_bidirectional<B: Backend>(
+    forward: Gates<'_, B>,
+    reverse: Gates<'_, B>,
+    input: PackedSequence<B>,
+    state: Option<Tensor<B, 3>>,
+) -> (PackedSequence<B>, Tensor<B, 3>) {
+    let [state_forward, state_reverse] =
+        split_state(state).map(|state| state.map(|state| input.sort(state)));
+
+    let (output_forward, final_state_forward) =
+        forward.forward_steps(input.data(), input.batch_sizes(), state_forward, false);
+    let (output_reverse, final_state_reverse) =
+        reverse.forward_steps(input.data(), input.batch_sizes(), state_reverse, true);
+
+    (
+        input.with_data(Tensor::cat([output_forward, output_reverse].to_vec(), 2)),
+        Tensor::stack(
+            [final_state_forward, final_state_reverse]
+                .map(|state| input.unsort(state))
+                .to_vec(),
+            0,
+        ),
+    )
+}
+
+/// Split the initial states of the forward and reverse directions.
+fn split_state<B: Backend>(state: Option<Tensor<B, 3>>) -> [Option<Tensor<B, 2>>; 2] {
+    match state {
+        Some(state) => {
+            [0, 1].map(|direction| Some(state.clone().narrow(0, direction, 1).squeeze(0)))
+        }
+        None => [None, None],
+    }
+}
+
+/// The gates of one direction of a GRU.
+struct Gates<'a, B: Backend> {
+    update_gate: &'a GateController<B>,
+    reset_gate: &'a GateController<B>,
+    new_gate: &'a GateController<B>,
+    d_hidden: usize,
+}
+
+impl<'a, B: Backend> Gates<'a, B> {
+    /// Computes the padded output and the final hidden state of the sequences, given the number
+    /// of sequences still running at each step, which are the first ones of the batch. In
+    /// reverse, each sequence starts from its initial state at its last step.
+    fn forward_steps(
+        &self,
+        data: Tensor<B, 3>,
+        batch_sizes: Vec<usize>,
+        state: Option<Tensor<B, 2>>,
+        reverse: bool,
+    ) -> (Tensor<B, 3>, Tensor<B, 2>) {
+        let device = data.device();
+        let [batch_size, seq_length, _] = data.dims();
+
+        let mut hidden_state = match state {
+            Some(state) => state,
+            None => Tensor::zeros([batch_size, self.d_hidden], &device),
+        };
+        let mut batched_hidden_state =
+            Tensor::zeros([batch_size, seq_length, self.d_hidden], &device);
+
+        let mut steps: Vec<_> = batch_sizes.into_iter().enumerate().collect();
+        if reverse {
+            steps.reverse();
+        }
+
+        for (t, running) in steps {
+            let input_t = data.clone().slice([0..running, t..(t + 1)]).squeeze(1);
+            let hidden_t = self.step(
+                input_t,
+                hidden_state.clone().slice([0..running, 0..self.d_hidden]),
+            );
+
+            hidden_state =
+                hidden_state.slice_assign([0..running, 0..self.d_hidden], hidden_t.clone());
+            batched_hidden_state = batched_hidden_state.slice_assign(
+                [0..running, t..(t + 1), 0..self.d_hidden],
+                hidden_t.unsqueeze_dim(1),
+            );
+        }
+
+        (batched_hidden_state, hidden_state)
+    }
+
+    /// Computes the hidden state of a single step.
+    fn step(&self, input_t: Tensor<B, 2>, hidden_t: Tensor<B, 2>) -> Tensor<B, 2> {
+        // u(pdate)g(ate) tensors
+        let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, self.update_gate);
+        let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
+
+        // r(eset)g(ate) tensors
+        let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, self.reset_gate);
+        let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
+        let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
+
+        // n(ew)g(ate) tensor
+        let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, self.new_gate);
+        let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
+
+        // calculate linear interpolation between previous hidden state and candidate state:
+        // g(t) * (1 - z(t)) + z(t) * hidden_t
+        candidate_state
+            .clone()
+            .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
+            + update_values.clone().mul(hidden_t)
+    }
+
     /// Helper function for performing weighted matrix product for a gate and adds
     /// bias, if any.
     ///
@@ -176,6 +425,90 @@ impl<B: Backend> Gru<B> {
     }
 }
 
+/// Configuration to create a [BiGru](BiGru) module using the [init function](BiGruConfig::init).
+#[derive(Config)]
+pub struct BiGruConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the hidden state.
+    pub d_hidden: usize,
+    /// If a bias should be applied during the BiGru transformation.
+    pub bias: bool,
+    /// BiGru initializer
+    #[config(default = "Initializer::XavierNormal{gain:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// The BiGru module. This implementation is for Bidirectional GRU.
+///
+/// Should be created with [BiGruConfig].
+#[derive(Module, Debug)]
+pub struct BiGru<B: Backend> {
+    /// GRU for the forward direction.
+    pub forward: Gru<B>,
+    /// GRU for the reverse direction.
+    pub reverse: Gru<B>,
+}
+
+impl BiGruConfig {
+    /// Initialize a new [Bidirectional GRU](BiGru) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> BiGru<B> {
+        BiGru {
+            forward: GruConfig::new(self.d_input, self.d_hidden, self.bias)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+            reverse: GruConfig::new(self.d_input, self.d_hidden, self.bias)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+        }
+    }
+}
+
+impl<B: Backend> BiGru<B> {
+    /// Applies the forward pass on the input tensor. This Bidirectional GRU implementation
+    /// returns the state for each element in a sequence (i.e., across seq_length) and a final
+    /// state.
+    ///
+    /// # Shapes
+    /// - batched_input: `[batch_size, sequence_length, input_size]`.
+    /// - state: An optional initial hidden state of the forward and reverse directions, of shape
+    ///   `[2, batch_size, hidden_size]`. If none is provided, zeros are used.
+    /// - output: `[batch_size, sequence_length, hidden_size * 2]`, and the final hidden state of
+    ///   the forward and reverse directions, of shape `[2, batch_size, hidden_size]`.
+    pub fn forward(
+        &self,
+        batched_input: Tensor<B, 3>,
+        state: Option<Tensor<B, 3>>,
+    ) -> (Tensor<B, 3>, Tensor<B, 3>) {
+        forward_bidirectional(
+            self.forward.gates(),
+            self.reverse.gates(),
+            batched_input,
+            state,
+        )
+    }
+
+    /// Applies the forward pass on the packed sequences, only computing the steps of the
+    /// sequences that are not over, so that the padding doesn't affect their states. The reverse
+    /// direction starts at the last step of each sequence.
+    ///
+    /// # Shapes
+    /// - input: [packed sequences](PackedSequence) with `input_size` features.
+    /// - state: An optional initial hidden state of the forward and reverse directions, of shape
+    ///   `[2, batch_size, hidden_size]`, in the order of the batch the sequences were packed
+    ///   from. If none is provided, zeros are used.
+    /// - output: packed sequences with `hidden_size * 2` features, and the final hidden state of
+    ///   the forward and reverse directions of each sequence, of shape
+    ///   `[2, batch_size, hidden_size]`.
+    pub fn forward_packed(
+        &self,
+        input: PackedSequence<B>,
+        state: Option<Tensor<B, 3>>,
+    ) -> (PackedSequence<B>, Tensor<B, 3>) {
+        forward_packed_bidirectional(self.forward.gates(), self.reverse.gates(), input, state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,4 +607,206 @@ mod tests {
 
         assert_eq!(hidden_state.shape().dims, [8, 10, 1024]);
     }
+
+    #[test]
+    fn test_forward_packed_should_ignore_padding() {
+        let device = Default::default();
+        let gru = GruConfig::new(4, 3, true).init::<TestBackend>(&device);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([2, 4, 4], Distribution::Default, &device);
+
+        let packed = PackedSequence::new(batched_input.clone(), &[4, 2]);
+        let (output, state) = gru.forward_packed(packed, None);
+
+        // The second sequence alone, without padding.
+        let sequence = batched_input.slice([1..2, 0..2]);
+        let (expected_output, expected_state) =
+            gru.forward_packed(PackedSequence::new(sequence, &[2]), None);
+
+        output
+            .into_padded()
+            .slice([1..2, 0..2])
+            .into_data()
+            .assert_approx_eq(&expected_output.into_padded().into_data(), 3);
+        state
+            .slice([1..2, 0..3])
+            .into_data()
+            .assert_approx_eq(&expected_state.into_data(), 3);
+    }
+
+    /// Test the forward pass in both directions with an initial state of shape
+    /// `[2, batch_size, hidden_size]`, like the `h_0` and `h_n` of a bidirectional PyTorch GRU.
+    ///
+    /// The expected values follow the equations of `tests_forward_single_input_single_feature`,
+    /// the reverse direction starting at the last step.
+    #[test]
+    fn test_bidirectional_forward_with_stacked_states() {
+        let device = Default::default();
+        let mut gru = BiGruConfig::new(1, 1, false).init::<TestBackend>(&device);
+
+        let create_gate_controller = |weights: f32| {
+            let record = || LinearRecord {
+                weight: Param::from_data(TensorData::from([[weights]]), &device),
+                bias: None,
+            };
+            GateController::create_with_weights(
+                1,
+                1,
+                false,
+                Initializer::XavierNormal { gain: 1.0 },
+                record(),
+                record(),
+            )
+        };
+
+        gru.forward.update_gate = create_gate_controller(0.5);
+        gru.forward.reset_gate = create_gate_controller(0.6);
+        gru.forward.new_gate = create_gate_controller(0.7);
+        gru.reverse.update_gate = create_gate_controller(0.4);
+        gru.reverse.reset_gate = create_gate_controller(0.3);
+        gru.reverse.new_gate = create_gate_controller(0.2);
+
+        let input =
+            Tensor::<TestBackend, 3>::from_data(TensorData::from([[[0.1], [0.2]]]), &device);
+        let h0 =
+            Tensor::<TestBackend, 3>::from_data(TensorData::from([[[0.3]], [[-0.2]]]), &device);
+
+        let (output, hn) = gru.forward(input.clone(), Some(h0.clone()));
+        let (output_packed, hn_packed) =
+            gru.forward_packed(PackedSequence::new(input, &[2]), Some(h0));
+
+        let expected_output = TensorData::from([[[0.2484, -0.0396], [0.2421, -0.0900]]]);
+        let expected_hn = TensorData::from([[[0.2421]], [[-0.0396]]]);
+        output.into_data().assert_approx_eq(&expected_output, 3);
+        hn.into_data().assert_approx_eq(&expected_hn, 3);
+        output_packed
+            .into_padded()
+            .into_data()
+            .assert_approx_eq(&expected_output, 3);
+        hn_packed.into_data().assert_approx_eq(&expected_hn, 3);
+
+        // The same weights in a single GRU created with the bidirectional flag.
+        let flagged = Gru {
+            reverse: Some(GruGates {
+                update_gate: gru.reverse.update_gate.clone(),
+                reset_gate: gru.reverse.reset_gate.clone(),
+                new_gate: gru.reverse.new_gate.clone(),
+            }),
+            ..gru.forward.clone()
+        };
+        let (output, hn) = flagged.forward_bidirectional(
+            Tensor::from_data(TensorData::from([[[0.1], [0.2]]]), &device),
+            Some(Tensor::from_data(
+                TensorData::from([[[0.3]], [[-0.2]]]),
+                &device,
+            )),
+        );
+
+        output.into_data().assert_approx_eq(&expected_output, 3);
+        hn.into_data().assert_approx_eq(&expected_hn, 3);
+    }
+
+    #[test]
+    fn test_bidirectional_flag_should_stack_the_states_of_both_directions() {
+        let device = Default::default();
+        let gru = GruConfig::new(4, 3, true)
+            .with_bidirectional(true)
+            .init::<TestBackend>(&device);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Default, &device);
+        let lengths = [5, 3];
+
+        let (output, state) = gru.forward_bidirectional(batched_input.clone(), None);
+        let (output_packed, state_packed) = gru.forward_packed_bidirectional(
+            PackedSequence::new(batched_input.clone(), &lengths),
+            None,
+        );
+        let output_packed = output_packed.into_padded();
+
+        assert_eq!(output.dims(), [2, 5, 6]);
+        assert_eq!(state.dims(), [2, 2, 3]);
+        assert_eq!(state_packed.dims(), [2, 2, 3]);
+
+        // The forward direction is the unidirectional GRU with the same gates.
+        let unidirectional = Gru {
+            reverse: None,
+            ..gru.clone()
+        };
+        let (expected_output, expected_state) = unidirectional
+            .forward_packed(PackedSequence::new(batched_input.clone(), &[5, 5]), None);
+        output
+            .clone()
+            .slice([0..2, 0..5, 0..3])
+            .into_data()
+            .assert_approx_eq(&expected_output.into_padded().into_data(), 3);
+        state
+            .clone()
+            .narrow(0, 0, 1)
+            .squeeze::<2>(0)
+            .into_data()
+            .assert_approx_eq(&expected_state.into_data(), 3);
+
+        for (index, length) in lengths.into_iter().enumerate() {
+            let sequence = batched_input.clone().slice([index..index + 1, 0..length]);
+            let (expected_output, expected_state) = gru.forward_bidirectional(sequence, None);
+
+            output_packed
+                .clone()
+                .slice([index..index + 1, 0..length])
+                .into_data()
+                .assert_approx_eq(&expected_output.into_data(), 3);
+            state_packed
+                .clone()
+                .slice([0..2, index..index + 1])
+                .into_data()
+                .assert_approx_eq(&expected_state.into_data(), 3);
+        }
+    }
+
+    #[test]
+    #[should_panic = "bidirectional"]
+    fn test_bidirectional_flag_should_reject_unidirectional_states() {
+        let device = Default::default();
+        let gru = GruConfig::new(4, 3, true)
+            .with_bidirectional(true)
+            .init::<TestBackend>(&device);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Default, &device);
+
+        gru.forward(batched_input, None);
+    }
+
+    #[test]
+    fn test_bidirectional_forward_packed_should_ignore_padding() {
+        let device = Default::default();
+        let gru = BiGruConfig::new(4, 3, true).init::<TestBackend>(&device);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([3, 5, 4], Distribution::Default, &device);
+        let state = Tensor::<TestBackend, 3>::random([2, 3, 3], Distribution::Default, &device);
+        let lengths = [2, 5, 3];
+
+        let packed = PackedSequence::new(batched_input.clone(), &lengths);
+        let (output, final_state) = gru.forward_packed(packed, Some(state.clone()));
+        let output = output.into_padded();
+
+        assert_eq!(output.dims(), [3, 5, 6]);
+        assert_eq!(final_state.dims(), [2, 3, 3]);
+
+        for (index, length) in lengths.into_iter().enumerate() {
+            let sequence = batched_input.clone().slice([index..index + 1, 0..length]);
+            let initial_state = state.clone().slice([0..2, index..index + 1]);
+            let (expected_output, expected_state) = gru.forward(sequence, Some(initial_state));
+
+            output
+                .clone()
+                .slice([index..index + 1, 0..length])
+                .into_data()
+                .assert_approx_eq(&expected_output.into_data(), 3);
+            final_state
+                .clone()
+                .slice([0..2, index..index + 1])
+                .into_data()
+                .assert_approx_eq(&expected_state.into_data(), 3);
+        }
+    }
 }
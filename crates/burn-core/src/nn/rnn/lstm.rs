@@ -3,10 +3,12 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::nn::rnn::gate_controller::GateController;
+use crate::nn::rnn::PackedSequence;
 use crate::nn::Initializer;
 use crate::tensor::activation;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
+use alloc::vec::Vec;
 
 /// A LstmState is used to store cell state and hidden state in LSTM.
 pub struct LstmState<B: Backend, const D: usize> {
@@ -35,9 +37,15 @@ pub struct LstmConfig {
     /// Lstm initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
+    /// If the sequences should also be processed in reverse, with
+    /// [forward_bidirectional](Lstm::forward_bidirectional), concatenating the hidden states of
+    /// both directions and stacking their final states.
+    #[config(default = false)]
+    pub bidirectional: bool,
 }
 
-/// The Lstm module. This implementation is for a unidirectional, stateless, Lstm.
+/// The Lstm module. This implementation is for a stateless Lstm, unidirectional unless created
+/// with [bidirectional](LstmConfig::bidirectional).
 ///
 /// Introduced in the paper: [Long Short-Term Memory](https://www.researchgate.net/publication/13853244).
 ///
@@ -52,9 +60,25 @@ pub struct Lstm<B: Backend> {
     pub output_gate: GateController<B>,
     /// The cell gate is used to compute the cell state that stores and carries information through time.
     pub cell_gate: GateController<B>,
+    /// The gates of the reverse direction, if bidirectional, like the `_reverse` weights of a
+    /// PyTorch LSTM.
+    pub reverse: Option<LstmGates<B>>,
     d_hidden: usize,
 }
 
+/// The gates of the reverse direction of a bidirectional [Lstm](Lstm).
+#[derive(Module, Debug)]
+pub struct LstmGates<B: Backend> {
+    /// The input gate of the reverse direction.
+    pub input_gate: GateController<B>,
+    /// The forget gate of the reverse direction.
+    pub forget_gate: GateController<B>,
+    /// The output gate of the reverse direction.
+    pub output_gate: GateController<B>,
+    /// The cell gate of the reverse direction.
+    pub cell_gate: GateController<B>,
+}
+
 impl LstmConfig {
     /// Initialize a new [lstm](Lstm) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> Lstm<B> {
@@ -75,6 +99,12 @@ impl LstmConfig {
             forget_gate: new_gate(),
             output_gate: new_gate(),
             cell_gate: new_gate(),
+            reverse: self.bidirectional.then(|| LstmGates {
+                input_gate: new_gate(),
+                forget_gate: new_gate(),
+                output_gate: new_gate(),
+                cell_gate: new_gate(),
+            }),
             d_hidden: self.d_hidden,
         }
     }
@@ -87,22 +117,28 @@ impl<B: Backend> Lstm<B> {
     /// ## Parameters:
     /// - batched_input: The input tensor of shape `[batch_size, sequence_length, input_size]`.
     /// - state: An optional `LstmState` representing the initial cell state and hidden state.
-    ///          Each state tensor has shape `[batch_size, hidden_size]`.
-    ///          If no initial state is provided, these tensors are initialized to zeros.
+    ///   Each state tensor has shape `[batch_size, hidden_size]`.
+    ///   If no initial state is provided, these tensors are initialized to zeros.
     ///
     /// ## Returns:
     /// - output: A tensor represents the output features of LSTM. Shape: `[batch_size, sequence_length, hidden_size]`
     /// - state: A `LstmState` represents the final states. Both `state.cell` and `state.hidden` have the shape
-    ///          `[batch_size, hidden_size]`.
+    ///   `[batch_size, hidden_size]`.
+    ///
+    /// # Panics
+    ///
+    /// When the LSTM is [bidirectional](LstmConfig::bidirectional), whose states have a
+    /// direction dimension, see [forward_bidirectional](Lstm::forward_bidirectional).
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
         state: Option<LstmState<B, 2>>,
     ) -> (Tensor<B, 3>, LstmState<B, 2>) {
+        self.assert_unidirectional();
         let device = batched_input.device();
         let [batch_size, seq_length, _] = batched_input.dims();
 
-        self.forward_iter(
+        self.gates().forward_iter(
             batched_input.iter_dim(1).zip(0..seq_length),
             state,
             batch_size,
@@ -111,6 +147,229 @@ impl<B: Backend> Lstm<B> {
         )
     }
 
+    /// Applies the forward pass on the packed sequences, only computing the steps of the
+    /// sequences that are not over, so that the padding doesn't affect their states.
+    ///
+    /// ## Parameters:
+    /// - input: The [packed sequences](PackedSequence), with `input_size` features.
+    /// - state: An optional `LstmState` representing the initial cell state and hidden state,
+    ///   in the order of the batch the sequences were packed from.
+    ///   Each state tensor has shape `[batch_size, hidden_size]`.
+    ///   If no initial state is provided, these tensors are initialized to zeros.
+    ///
+    /// ## Returns:
+    /// - output: The packed output features of LSTM, with `hidden_size` features.
+    /// - state: A `LstmState` represents the states at the last step of each sequence. Both
+    ///   `state.cell` and `state.hidden` have the shape `[batch_size, hidden_size]`.
+    ///
+    /// # Panics
+    ///
+    /// When the LSTM is [bidirectional](LstmConfig::bidirectional), see
+    /// [forward_packed_bidirectional](Lstm::forward_packed_bidirectional).
+    pub fn forward_packed(
+        &self,
+        input: PackedSequence<B>,
+        state: Option<LstmState<B, 2>>,
+    ) -> (PackedSequence<B>, LstmState<B, 2>) {
+        self.assert_unidirectional();
+        let state =
+            state.map(|state| LstmState::new(input.sort(state.cell), input.sort(state.hidden)));
+        let (output, state) = self.gates().forward_packed(&input, state, false);
+
+        (
+            input.with_data(output),
+            LstmState::new(input.unsort(state.cell), input.unsort(state.hidden)),
+        )
+    }
+
+    /// Applies the forward pass of a [bidirectional](LstmConfig::bidirectional) LSTM on the
+    /// input tensor, like [BiLstm::forward] with the layout of the states of a bidirectional
+    /// PyTorch LSTM.
+    ///
+    /// ## Parameters:
+    /// - batched_input: The input tensor of shape `[batch_size, sequence_length, input_size]`.
+    /// - state: An optional `LstmState` representing the initial cell state and hidden state of
+    ///   the forward and reverse directions. Each state tensor has shape
+    ///   `[2, batch_size, hidden_size]`.
+    ///   If no initial state is provided, these tensors are initialized to zeros.
+    ///
+    /// ## Returns:
+    /// - output: A tensor represents the output features of LSTM. Shape: `[batch_size, sequence_length, hidden_size * 2]`
+    /// - state: A `LstmState` represents the final forward and reverse states. Both `state.cell` and
+    ///   `state.hidden` have the shape `[2, batch_size, hidden_size]`.
+    ///
+    /// # Panics
+    ///
+    /// When the LSTM isn't bidirectional.
+    pub fn forward_bidirectional(
+        &self,
+        batched_input: Tensor<B, 3>,
+        state: Option<LstmState<B, 3>>,
+    ) -> (Tensor<B, 3>, LstmState<B, 3>) {
+        forward_bidirectional(self.gates(), self.reverse_gates(), batched_input, state)
+    }
+
+    /// Applies the forward pass of a [bidirectional](LstmConfig::bidirectional) LSTM on the
+    /// packed sequences, like [BiLstm::forward_packed].
+    ///
+    /// ## Parameters:
+    /// - input: The [packed sequences](PackedSequence), with `input_size` features.
+    /// - state: An optional `LstmState` representing the initial cell state and hidden state of
+    ///   the forward and reverse directions, in the order of the batch the sequences were packed
+    ///   from. Each state tensor has shape `[2, batch_size, hidden_size]`.
+    ///   If no initial state is provided, these tensors are initialized to zeros.
+    ///
+    /// ## Returns:
+    /// - output: The packed output features of LSTM, with `hidden_size * 2` features.
+    /// - state: A `LstmState` represents the final forward and reverse states of each sequence.
+    ///   Both `state.cell` and `state.hidden` have the shape `[2, batch_size, hidden_size]`.
+    ///
+    /// # Panics
+    ///
+    /// When the LSTM isn't bidirectional.
+    pub fn forward_packed_bidirectional(
+        &self,
+        input: PackedSequence<B>,
+        state: Option<LstmState<B, 3>>,
+    ) -> (PackedSequence<B>, LstmState<B, 3>) {
+        forward_packed_bidirectional(self.gates(), self.reverse_gates(), input, state)
+    }
+
+    fn assert_unidirectional(&self) {
+        assert!(
+            self.reverse.is_none(),
+            "The states of a bidirectional LSTM have a direction dimension, use \
+             forward_bidirectional or forward_packed_bidirectional."
+        );
+    }
+
+    fn reverse_gates(&self) -> Gates<'_, B> {
+        let reverse = self
+            .reverse
+            .as_ref()
+            .expect("The LSTM should be bidirectional, use forward or forward_packed otherwise.");
+
+        Gates {
+            input_gate: &reverse.input_gate,
+            forget_gate: &reverse.forget_gate,
+            output_gate: &reverse.output_gate,
+            cell_gate: &reverse.cell_gate,
+            d_hidden: self.d_hidden,
+        }
+    }
+
+    fn gates(&self) -> Gates<'_, B> {
+        Gates {
+            input_gate: &self.input_gate,
+            forget_gate: &self.forget_gate,
+            output_gate: &self.output_gate,
+            cell_gate: &self.cell_gate,
+            d_hidden: self.d_hidden,
+        }
+    }
+}
+
+/// Applies the forward and reverse directions on the input tensor, concatenating their hidden
+/// states and stacking their final states.
+fn forward_bidirectional<B: Backend>(
+    forward: Gates<'_, B>,
+    reverse: Gates<'_, B>,
+    batched_input: Tensor<B, 3>,
+    state: Option<LstmState<B, 3>>,
+) -> (Tensor<B, 3>, LstmState<B, 3>) {
+    let device = batched_input.device();
+    let [batch_size, seq_length, _] = batched_input.dims();
+    let [init_state_forward, init_state_reverse] = split_state(state, |tensor| tensor);
+
+    let (batched_hidden_state_forward, final_state_forward) = forward.forward_iter(
+        batched_input.clone().iter_dim(1).zip(0..seq_length),
+        init_state_forward,
+        batch_size,
+        seq_length,
+        &device,
+    );
+    let (batched_hidden_state_reverse, final_state_reverse) = reverse.forward_iter(
+        batched_input.iter_dim(1).rev().zip((0..seq_length).rev()),
+        init_state_reverse,
+        batch_size,
+        seq_length,
+        &device,
+    );
+
+    (
+        Tensor::cat(
+            [batched_hidden_state_forward, batched_hidden_state_reverse].to_vec(),
+            2,
+        ),
+        stack_states([final_state_forward, final_state_reverse], |tensor| tensor),
+    )
+}
+
+/// Applies the forward and reverse directions on the packed sequences, the reverse direction
+/// starting at the last step of each sequence.
+fn forward_packed_bidirectional<B: Backend>(
+    forward: Gates<'_, B>,
+    reverse: Gates<'_, B>,
+    input: PackedSequence<B>,
+    state: Option<LstmState<B, 3>>,
+) -> (PackedSequence<B>, LstmState<B, 3>) {
+    let [init_state_forward, init_state_reverse] = split_state(state, |tensor| input.sort(tensor));
+
+    let (output_forward, final_state_forward) =
+        forward.forward_packed(&input, init_state_forward, false);
+    let (output_reverse, final_state_reverse) =
+        reverse.forward_packed(&input, init_state_reverse, true);
+
+    let output = Tensor::cat([output_forward, output_reverse].to_vec(), 2);
+    let state = stack_states([final_state_forward, final_state_reverse], |tensor| {
+        input.unsort(tensor)
+    });
+
+    (input.with_data(output), state)
+}
+
+/// Split the states of shape `[2, batch_size, hidden_size]` into the ones of each direction.
+fn split_state<B: Backend>(
+    state: Option<LstmState<B, 3>>,
+    map: impl Fn(Tensor<B, 2>) -> Tensor<B, 2>,
+) -> [Option<LstmState<B, 2>>; 2] {
+    match state {
+        Some(state) => [0, 1].map(|direction| {
+            let split = |tensor: Tensor<B, 3>| map(tensor.narrow(0, direction, 1).squeeze(0));
+
+            Some(LstmState::new(
+                split(state.cell.clone()),
+                split(state.hidden.clone()),
+            ))
+        }),
+        None => [None, None],
+    }
+}
+
+/// Stack the states of each direction into states of shape `[2, batch_size, hidden_size]`.
+fn stack_states<B: Backend>(
+    states: [LstmState<B, 2>; 2],
+    map: impl Fn(Tensor<B, 2>) -> Tensor<B, 2>,
+) -> LstmState<B, 3> {
+    let [forward, reverse] = states;
+
+    LstmState::new(
+        Tensor::stack([map(forward.cell), map(reverse.cell)].to_vec(), 0),
+        Tensor::stack([map(forward.hidden), map(reverse.hidden)].to_vec(), 0),
+    )
+}
+
+/// The gates of one direction of a [Lstm](Lstm).
+struct Gates<'a, B: Backend> {
+    input_gate: &'a GateController<B>,
+    forget_gate: &'a GateController<B>,
+    output_gate: &'a GateController<B>,
+    cell_gate: &'a GateController<B>,
+    d_hidden: usize,
+}
+
+impl<B: Backend> Gates<'_, B> {
+    /// Computes the hidden states of the time steps given by the iterator, with their index.
     fn forward_iter<I: Iterator<Item = (Tensor<B, 3>, usize)>>(
         &self,
         input_timestep_iter: I,
@@ -132,32 +391,7 @@ impl<B: Backend> Lstm<B> {
 
         for (input_t, t) in input_timestep_iter {
             let input_t = input_t.squeeze(1);
-            // f(orget)g(ate) tensors
-            let biased_fg_input_sum = self
-                .forget_gate
-                .gate_product(input_t.clone(), hidden_state.clone());
-            let forget_values = activation::sigmoid(biased_fg_input_sum); // to multiply with cell state
-
-            // i(nput)g(ate) tensors
-            let biased_ig_input_sum = self
-                .input_gate
-                .gate_product(input_t.clone(), hidden_state.clone());
-            let add_values = activation::sigmoid(biased_ig_input_sum);
-
-            // o(output)g(ate) tensors
-            let biased_og_input_sum = self
-                .output_gate
-                .gate_product(input_t.clone(), hidden_state.clone());
-            let output_values = activation::sigmoid(biased_og_input_sum);
-
-            // c(ell)g(ate) tensors
-            let biased_cg_input_sum = self
-                .cell_gate
-                .gate_product(input_t.clone(), hidden_state.clone());
-            let candidate_cell_values = biased_cg_input_sum.tanh();
-
-            cell_state = forget_values * cell_state.clone() + add_values * candidate_cell_values;
-            hidden_state = output_values * cell_state.clone().tanh();
+            (cell_state, hidden_state) = self.step(input_t, cell_state, hidden_state);
 
             let unsqueezed_hidden_state = hidden_state.clone().unsqueeze_dim(1);
 
@@ -173,6 +407,95 @@ impl<B: Backend> Lstm<B> {
             LstmState::new(cell_state, hidden_state),
         )
     }
+
+    /// Computes the padded output and the final states of the packed sequences, with the
+    /// states sorted like the sequences. In reverse, each sequence starts from its initial
+    /// state at its last step.
+    fn forward_packed(
+        &self,
+        input: &PackedSequence<B>,
+        state: Option<LstmState<B, 2>>,
+        reverse: bool,
+    ) -> (Tensor<B, 3>, LstmState<B, 2>) {
+        let data = input.data();
+        let device = data.device();
+        let [batch_size, seq_length, _] = data.dims();
+
+        let (mut cell_state, mut hidden_state) = match state {
+            Some(state) => (state.cell, state.hidden),
+            None => (
+                Tensor::zeros([batch_size, self.d_hidden], &device),
+                Tensor::zeros([batch_size, self.d_hidden], &device),
+            ),
+        };
+        let mut batched_hidden_state =
+            Tensor::zeros([batch_size, seq_length, self.d_hidden], &device);
+
+        // The running sequences are the first ones of the batch.
+        let mut steps: Vec<_> = input.batch_sizes().into_iter().enumerate().collect();
+        if reverse {
+            steps.reverse();
+        }
+
+        for (t, running) in steps {
+            let input_t = data.clone().slice([0..running, t..(t + 1)]).squeeze(1);
+            let (cell_t, hidden_t) = self.step(
+                input_t,
+                cell_state.clone().slice([0..running, 0..self.d_hidden]),
+                hidden_state.clone().slice([0..running, 0..self.d_hidden]),
+            );
+
+            cell_state = cell_state.slice_assign([0..running, 0..self.d_hidden], cell_t);
+            hidden_state =
+                hidden_state.slice_assign([0..running, 0..self.d_hidden], hidden_t.clone());
+            batched_hidden_state = batched_hidden_state.slice_assign(
+                [0..running, t..(t + 1), 0..self.d_hidden],
+                hidden_t.unsqueeze_dim(1),
+            );
+        }
+
+        (
+            batched_hidden_state,
+            LstmState::new(cell_state, hidden_state),
+        )
+    }
+
+    /// Computes the cell state and the hidden state of a single step.
+    fn step(
+        &self,
+        input_t: Tensor<B, 2>,
+        cell_state: Tensor<B, 2>,
+        hidden_state: Tensor<B, 2>,
+    ) -> (Tensor<B, 2>, Tensor<B, 2>) {
+        // f(orget)g(ate) tensors
+        let biased_fg_input_sum = self
+            .forget_gate
+            .gate_product(input_t.clone(), hidden_state.clone());
+        let forget_values = activation::sigmoid(biased_fg_input_sum); // to multiply with cell state
+
+        // i(nput)g(ate) tensors
+        let biased_ig_input_sum = self
+            .input_gate
+            .gate_product(input_t.clone(), hidden_state.clone());
+        let add_values = activation::sigmoid(biased_ig_input_sum);
+
+        // o(output)g(ate) tensors
+        let biased_og_input_sum = self
+            .output_gate
+            .gate_product(input_t.clone(), hidden_state.clone());
+        let output_values = activation::sigmoid(biased_og_input_sum);
+
+        // c(ell)g(ate) tensors
+        let biased_cg_input_sum = self
+            .cell_gate
+            .gate_product(input_t.clone(), hidden_state.clone());
+        let candidate_cell_values = biased_cg_input_sum.tanh();
+
+        let cell_state = forget_values * cell_state.clone() + add_values * candidate_cell_values;
+        let hidden_state = output_values * cell_state.clone().tanh();
+
+        (cell_state, hidden_state)
+    }
 }
 
 /// Configuration to create a [BiLstm](BiLstm) module using the [init function](BiLstmConfig::init).
@@ -225,81 +548,47 @@ impl<B: Backend> BiLstm<B> {
     /// ## Parameters:
     /// - batched_input: The input tensor of shape `[batch_size, sequence_length, input_size]`.
     /// - state: An optional `LstmState` representing the initial cell state and hidden state.
-    ///          Each state tensor has shape `[2, batch_size, hidden_size]`.
-    ///          If no initial state is provided, these tensors are initialized to zeros.
+    ///   Each state tensor has shape `[2, batch_size, hidden_size]`.
+    ///   If no initial state is provided, these tensors are initialized to zeros.
     ///
     /// ## Returns:
     /// - output: A tensor represents the output features of LSTM. Shape: `[batch_size, sequence_length, hidden_size * 2]`
     /// - state: A `LstmState` represents the final forward and reverse states. Both `state.cell` and
-    ///          `state.hidden` have the shape `[2, batch_size, hidden_size]`.
+    ///   `state.hidden` have the shape `[2, batch_size, hidden_size]`.
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
         state: Option<LstmState<B, 3>>,
     ) -> (Tensor<B, 3>, LstmState<B, 3>) {
-        let device = batched_input.clone().device();
-        let [batch_size, seq_length, _] = batched_input.shape().dims;
-
-        let [init_state_forward, init_state_reverse] = match state {
-            Some(state) => {
-                let cell_state_forward = state
-                    .cell
-                    .clone()
-                    .slice([0..1, 0..batch_size, 0..self.d_hidden])
-                    .squeeze(0);
-                let hidden_state_forward = state
-                    .hidden
-                    .clone()
-                    .slice([0..1, 0..batch_size, 0..self.d_hidden])
-                    .squeeze(0);
-                let cell_state_reverse = state
-                    .cell
-                    .slice([1..2, 0..batch_size, 0..self.d_hidden])
-                    .squeeze(0);
-                let hidden_state_reverse = state
-                    .hidden
-                    .slice([1..2, 0..batch_size, 0..self.d_hidden])
-                    .squeeze(0);
-
-                [
-                    Some(LstmState::new(cell_state_forward, hidden_state_forward)),
-                    Some(LstmState::new(cell_state_reverse, hidden_state_reverse)),
-                ]
-            }
-            None => [None, None],
-        };
-
-        // forward direction
-        let (batched_hidden_state_forward, final_state_forward) = self
-            .forward
-            .forward(batched_input.clone(), init_state_forward);
-
-        // reverse direction
-        let (batched_hidden_state_reverse, final_state_reverse) = self.reverse.forward_iter(
-            batched_input.iter_dim(1).rev().zip((0..seq_length).rev()),
-            init_state_reverse,
-            batch_size,
-            seq_length,
-            &device,
-        );
-
-        let output = Tensor::cat(
-            [batched_hidden_state_forward, batched_hidden_state_reverse].to_vec(),
-            2,
-        );
-
-        let state = LstmState::new(
-            Tensor::stack(
-                [final_state_forward.cell, final_state_reverse.cell].to_vec(),
-                0,
-            ),
-            Tensor::stack(
-                [final_state_forward.hidden, final_state_reverse.hidden].to_vec(),
-                0,
-            ),
-        );
+        forward_bidirectional(
+            self.forward.gates(),
+            self.reverse.gates(),
+            batched_input,
+            state,
+        )
+    }
 
-        (output, state)
+    /// Applies the forward pass on the packed sequences, only computing the steps of the
+    /// sequences that are not over, so that the padding doesn't affect their states. The reverse
+    /// direction starts at the last step of each sequence.
+    ///
+    /// ## Parameters:
+    /// - input: The [packed sequences](PackedSequence), with `input_size` features.
+    /// - state: An optional `LstmState` representing the initial cell state and hidden state,
+    ///   in the order of the batch the sequences were packed from.
+    ///   Each state tensor has shape `[2, batch_size, hidden_size]`.
+    ///   If no initial state is provided, these tensors are initialized to zeros.
+    ///
+    /// ## Returns:
+    /// - output: The packed output features of LSTM, with `hidden_size * 2` features.
+    /// - state: A `LstmState` represents the final forward and reverse states of each sequence.
+    ///   Both `state.cell` and `state.hidden` have the shape `[2, batch_size, hidden_size]`.
+    pub fn forward_packed(
+        &self,
+        input: PackedSequence<B>,
+        state: Option<LstmState<B, 3>>,
+    ) -> (PackedSequence<B>, LstmState<B, 3>) {
+        forward_packed_bidirectional(self.forward.gates(), self.reverse.gates(), input, state)
     }
 }
 
@@ -455,6 +744,42 @@ mod tests {
         assert_eq!(state.hidden.dims(), [1, 1024]);
     }
 
+    #[test]
+    fn test_forward_packed_should_ignore_padding() {
+        let device = Default::default();
+        let lstm = LstmConfig::new(4, 3, true).init::<TestBackend>(&device);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([3, 5, 4], Distribution::Default, &device);
+        let lengths = [2, 5, 3];
+
+        let packed = PackedSequence::new(batched_input.clone(), &lengths);
+        let (output, state) = lstm.forward_packed(packed, None);
+        let output = output.into_padded();
+
+        for (index, length) in lengths.into_iter().enumerate() {
+            let sequence = batched_input.clone().slice([index..index + 1, 0..length]);
+            let (expected_output, expected_state) = lstm.forward(sequence, None);
+
+            output
+                .clone()
+                .slice([index..index + 1, 0..length])
+                .into_data()
+                .assert_approx_eq(&expected_output.into_data(), 3);
+            state
+                .hidden
+                .clone()
+                .slice([index..index + 1, 0..3])
+                .into_data()
+                .assert_approx_eq(&expected_state.hidden.into_data(), 3);
+            state
+                .cell
+                .clone()
+                .slice([index..index + 1, 0..3])
+                .into_data()
+                .assert_approx_eq(&expected_state.cell.into_data(), 3);
+        }
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_batched_backward_pass() {
@@ -667,8 +992,25 @@ mod tests {
         ]);
 
         let (output_with_init_state, state_with_init_state) =
-            lstm.forward(input.clone(), Some(LstmState::new(c0, h0)));
-        let (output_without_init_state, state_without_init_state) = lstm.forward(input, None);
+            lstm.forward(input.clone(), Some(LstmState::new(c0.clone(), h0.clone())));
+        let (output_without_init_state, state_without_init_state) =
+            lstm.forward(input.clone(), None);
+        let (output_packed, state_packed) = lstm.forward_packed(
+            PackedSequence::new(input.clone(), &[4]),
+            Some(LstmState::new(c0.clone(), h0.clone())),
+        );
+        // The same weights in a single LSTM created with the bidirectional flag.
+        let flagged = Lstm {
+            reverse: Some(LstmGates {
+                input_gate: lstm.reverse.input_gate.clone(),
+                forget_gate: lstm.reverse.forget_gate.clone(),
+                output_gate: lstm.reverse.output_gate.clone(),
+                cell_gate: lstm.reverse.cell_gate.clone(),
+            }),
+            ..lstm.forward.clone()
+        };
+        let (output_flagged, state_flagged) =
+            flagged.forward_bidirectional(input, Some(LstmState::new(c0, h0)));
 
         output_with_init_state
             .to_data()
@@ -692,5 +1034,147 @@ mod tests {
             .cell
             .to_data()
             .assert_approx_eq(&expected_cn_without_init_state, 3);
+        output_packed
+            .into_padded()
+            .to_data()
+            .assert_approx_eq(&expected_output_with_init_state, 3);
+        state_packed
+            .hidden
+            .to_data()
+            .assert_approx_eq(&expected_hn_with_init_state, 3);
+        state_packed
+            .cell
+            .to_data()
+            .assert_approx_eq(&expected_cn_with_init_state, 3);
+        output_flagged
+            .to_data()
+            .assert_approx_eq(&expected_output_with_init_state, 3);
+        state_flagged
+            .hidden
+            .to_data()
+            .assert_approx_eq(&expected_hn_with_init_state, 3);
+        state_flagged
+            .cell
+            .to_data()
+            .assert_approx_eq(&expected_cn_with_init_state, 3);
+    }
+
+    #[test]
+    fn test_bidirectional_flag_should_stack_the_states_of_both_directions() {
+        let device = Default::default();
+        let lstm = LstmConfig::new(4, 3, true)
+            .with_bidirectional(true)
+            .init::<TestBackend>(&device);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Default, &device);
+        let lengths = [5, 3];
+
+        let (output, state) = lstm.forward_bidirectional(batched_input.clone(), None);
+        let (output_packed, state_packed) = lstm.forward_packed_bidirectional(
+            PackedSequence::new(batched_input.clone(), &lengths),
+            None,
+        );
+        let output_packed = output_packed.into_padded();
+
+        assert_eq!(output.dims(), [2, 5, 6]);
+        assert_eq!(state.hidden.dims(), [2, 2, 3]);
+        assert_eq!(state.cell.dims(), [2, 2, 3]);
+        assert_eq!(state_packed.hidden.dims(), [2, 2, 3]);
+
+        // The forward direction is the unidirectional LSTM with the same gates.
+        let unidirectional = Lstm {
+            reverse: None,
+            ..lstm.clone()
+        };
+        let (expected_output, expected_state) = unidirectional.forward(batched_input.clone(), None);
+        output
+            .clone()
+            .slice([0..2, 0..5, 0..3])
+            .into_data()
+            .assert_approx_eq(&expected_output.into_data(), 3);
+        state
+            .hidden
+            .clone()
+            .narrow(0, 0, 1)
+            .squeeze::<2>(0)
+            .into_data()
+            .assert_approx_eq(&expected_state.hidden.into_data(), 3);
+
+        for (index, length) in lengths.into_iter().enumerate() {
+            let sequence = batched_input.clone().slice([index..index + 1, 0..length]);
+            let (expected_output, expected_state) = lstm.forward_bidirectional(sequence, None);
+
+            output_packed
+                .clone()
+                .slice([index..index + 1, 0..length])
+                .into_data()
+                .assert_approx_eq(&expected_output.into_data(), 3);
+            state_packed
+                .hidden
+                .clone()
+                .slice([0..2, index..index + 1])
+                .into_data()
+                .assert_approx_eq(&expected_state.hidden.into_data(), 3);
+        }
+    }
+
+    #[test]
+    #[should_panic = "bidirectional"]
+    fn test_bidirectional_flag_should_reject_unidirectional_states() {
+        let device = Default::default();
+        let lstm = LstmConfig::new(4, 3, true)
+            .with_bidirectional(true)
+            .init::<TestBackend>(&device);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Default, &device);
+
+        lstm.forward(batched_input, None);
+    }
+
+    #[test]
+    fn test_bidirectional_forward_packed_should_ignore_padding() {
+        let device = Default::default();
+        let lstm = BiLstmConfig::new(4, 3, true).init::<TestBackend>(&device);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([3, 5, 4], Distribution::Default, &device);
+        let cell = Tensor::<TestBackend, 3>::random([2, 3, 3], Distribution::Default, &device);
+        let hidden = Tensor::<TestBackend, 3>::random([2, 3, 3], Distribution::Default, &device);
+        let lengths = [2, 5, 3];
+
+        let packed = PackedSequence::new(batched_input.clone(), &lengths);
+        let (output, state) =
+            lstm.forward_packed(packed, Some(LstmState::new(cell.clone(), hidden.clone())));
+        let output = output.into_padded();
+
+        assert_eq!(output.dims(), [3, 5, 6]);
+        assert_eq!(state.hidden.dims(), [2, 3, 3]);
+        assert_eq!(state.cell.dims(), [2, 3, 3]);
+
+        for (index, length) in lengths.into_iter().enumerate() {
+            let sequence = batched_input.clone().slice([index..index + 1, 0..length]);
+            let initial_state = LstmState::new(
+                cell.clone().slice([0..2, index..index + 1]),
+                hidden.clone().slice([0..2, index..index + 1]),
+            );
+            let (expected_output, expected_state) = lstm.forward(sequence, Some(initial_state));
+
+            output
+                .clone()
+                .slice([index..index + 1, 0..length])
+                .into_data()
+                .assert_approx_eq(&expected_output.into_data(), 3);
+            state
+                .hidden
+                .clone()
+                .slice([0..2, index..index + 1])
+                .into_data()
+                .assert_approx_eq(&expected_state.hidden.into_data(), 3);
+            state
+                .cell
+                .clone()
+                .slice([0..2, index..index + 1])
+                .into_data()
+                .assert_approx_eq(&expected_state.cell.into_data(), 3);
+        }
     }
 }
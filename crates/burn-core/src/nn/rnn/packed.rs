@@ -0,0 +1,202 @@
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor, TensorData};
+
+/// A batch of sequences of different lengths, padded to the length of the longest one and
+/// sorted by decreasing length, so that the recurrent modules only compute the steps of the
+/// sequences that are not over, e.g. with [forward_packed](super::Lstm::forward_packed).
+///
+/// At each step, the sequences still running are the first ones of the batch, whose number is
+/// given by the [batch sizes](PackedSequence::batch_sizes). The padding is never read, so it
+/// doesn't leak into the states, and the padded steps are not computed.
+#[derive(Clone, Debug)]
+pub struct PackedSequence<B: Backend> {
+    data: Tensor<B, 3>,
+    lengths: Vec<usize>,
+    sorted_indices: Tensor<B, 1, Int>,
+    unsorted_indices: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> PackedSequence<B> {
+    /// Pack a padded batch of sequences with the given lengths, in any order.
+    ///
+    /// # Shapes
+    ///
+    /// - padded: `[batch_size, sequence_length, d_input]`
+    /// - lengths: `[batch_size]`, each between one and `sequence_length`
+    pub fn new(padded: Tensor<B, 3>, lengths: &[usize]) -> Self {
+        let [batch_size, seq_length, d_input] = padded.dims();
+        assert_eq!(
+            lengths.len(),
+            batch_size,
+            "The number of lengths should match the batch size."
+        );
+        assert!(
+            lengths
+                .iter()
+                .all(|&length| length > 0 && length <= seq_length),
+            "The lengths ({lengths:?}) should be between 1 and the sequence length ({seq_length})."
+        );
+
+        let device = padded.device();
+        let max_length = lengths.iter().copied().max().unwrap_or(0);
+
+        // Stable sort, so that sequences of the same length keep their order.
+        let mut order: Vec<usize> = (0..batch_size).collect();
+        order.sort_by_key(|&index| Reverse(lengths[index]));
+        let mut inverse = alloc::vec![0; batch_size];
+        for (position, &index) in order.iter().enumerate() {
+            inverse[index] = position;
+        }
+
+        let sorted_indices = indices_tensor::<B>(&order, &device);
+        let unsorted_indices = indices_tensor::<B>(&inverse, &device);
+
+        let data = padded
+            .slice([0..batch_size, 0..max_length, 0..d_input])
+            .select(0, sorted_indices.clone());
+
+        let mut packed = Self {
+            data,
+            lengths: lengths.to_vec(),
+            sorted_indices,
+            unsorted_indices,
+        };
+        packed.data = packed.mask_padding(packed.data.clone());
+
+        packed
+    }
+
+    /// The padded data of the sequences, sorted by decreasing length, with zeros after the end
+    /// of each sequence.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[batch_size, max_length, d_model]`
+    pub fn data(&self) -> Tensor<B, 3> {
+        self.data.clone()
+    }
+
+    /// The lengths of the sequences, in the order of the batch they were packed from.
+    pub fn lengths(&self) -> &[usize] {
+        &self.lengths
+    }
+
+    /// The number of sequences still running at each step, the length of the longest sequence.
+    pub fn batch_sizes(&self) -> Vec<usize> {
+        let max_length = self.lengths.iter().copied().max().unwrap_or(0);
+
+        (0..max_length)
+            .map(|step| self.lengths.iter().filter(|&&length| length > step).count())
+            .collect()
+    }
+
+    /// Unpack the sequences into a padded batch, in the order of the batch they were packed
+    /// from, with zeros after the end of each sequence.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[batch_size, max_length, d_model]`
+    pub fn into_padded(self) -> Tensor<B, 3> {
+        self.unsort(self.data.clone())
+    }
+
+    /// Sort a tensor in the order of the batch the sequences were packed from, e.g. an initial
+    /// state, by decreasing length.
+    pub(crate) fn sort<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        tensor.select(0, self.sorted_indices.clone())
+    }
+
+    /// Restore the order of the batch the sequences were packed from, e.g. of a final state.
+    pub(crate) fn unsort<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        tensor.select(0, self.unsorted_indices.clone())
+    }
+
+    /// Pack new data for the same sequences, e.g. the output of a recurrent module.
+    pub(crate) fn with_data(&self, data: Tensor<B, 3>) -> Self {
+        Self {
+            data: self.mask_padding(data),
+            lengths: self.lengths.clone(),
+            sorted_indices: self.sorted_indices.clone(),
+            unsorted_indices: self.unsorted_indices.clone(),
+        }
+    }
+
+    fn mask_padding(&self, data: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [batch_size, max_length, d_model] = data.dims();
+        let device = data.device();
+        let lengths = self.sorted_lengths();
+
+        let steps = Tensor::<B, 1, Int>::arange(0..max_length as i64, &device)
+            .reshape([1, max_length])
+            .expand([batch_size, max_length]);
+        let lengths = indices_tensor::<B>(&lengths, &device)
+            .reshape([batch_size, 1])
+            .expand([batch_size, max_length]);
+        let padding = steps
+            .greater_equal(lengths)
+            .reshape([batch_size, max_length, 1])
+            .expand([batch_size, max_length, d_model]);
+
+        data.mask_fill(padding, 0.0)
+    }
+
+    /// The lengths of the sequences, sorted by decreasing length.
+    fn sorted_lengths(&self) -> Vec<usize> {
+        let mut lengths = self.lengths.clone();
+        lengths.sort_by_key(|&length| Reverse(length));
+        lengths
+    }
+}
+
+fn indices_tensor<B: Backend>(indices: &[usize], device: &B::Device) -> Tensor<B, 1, Int> {
+    let indices: Vec<i64> = indices.iter().map(|&index| index as i64).collect();
+    let num_indices = indices.len();
+
+    Tensor::from_data(
+        TensorData::new(indices, [num_indices]).convert::<B::IntElem>(),
+        device,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_packed_sequence_round_trip() {
+        let device = Default::default();
+        let padded = Tensor::<TestBackend, 3>::from_floats(
+            [
+                [[1.0], [2.0], [9.0], [9.0]],
+                [[3.0], [4.0], [5.0], [9.0]],
+                [[6.0], [9.0], [9.0], [9.0]],
+            ],
+            &device,
+        );
+
+        let packed = PackedSequence::new(padded, &[2, 3, 1]);
+
+        assert_eq!(packed.batch_sizes(), [3, 2, 1]);
+        assert_eq!(packed.lengths(), [2, 3, 1]);
+        packed.data().into_data().assert_eq(
+            &TensorData::from([
+                [[3.0f32], [4.0], [5.0]],
+                [[1.0], [2.0], [0.0]],
+                [[6.0], [0.0], [0.0]],
+            ]),
+            false,
+        );
+        packed.into_padded().into_data().assert_eq(
+            &TensorData::from([
+                [[1.0f32], [2.0], [0.0]],
+                [[3.0], [4.0], [5.0]],
+                [[6.0], [0.0], [0.0]],
+            ]),
+            false,
+        );
+    }
+}
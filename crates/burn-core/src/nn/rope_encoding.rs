@@ -4,7 +4,9 @@ use crate::module::Module;
 use crate::tensor::backend::Backend;
 use crate::tensor::Int;
 use crate::tensor::Tensor;
+use crate::tensor::TensorData;
 use alloc::vec;
+use alloc::vec::Vec;
 
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
@@ -21,6 +23,48 @@ pub struct RotaryEncodingConfig {
     /// Scaling factor for frequency computation. Defaults to 10000.0
     #[config(default = "10000.0")]
     pub theta: f32,
+
+    /// Scaling of the frequencies, extending the context of a model to longer sequences than the
+    /// ones it was trained on. Defaults to no scaling.
+    #[config(default = "RopeScaling::None")]
+    pub scaling: RopeScaling,
+}
+
+/// Scaling of the frequencies of a [RotaryEncoding](RotaryEncoding), used to extend the context
+/// of a model by the given factor.
+#[derive(Config, Debug, PartialEq)]
+pub enum RopeScaling {
+    /// The frequencies aren't scaled.
+    None,
+    /// The positions are divided by the factor, interpolating them in the trained context.
+    ///
+    /// Reference: <https://arxiv.org/abs/2306.15595>
+    Linear {
+        /// The context extension factor.
+        factor: f32,
+    },
+    /// The base frequency is increased so that the lowest frequency is interpolated by the factor,
+    /// while the highest ones are almost unchanged.
+    NtkAware {
+        /// The context extension factor.
+        factor: f32,
+    },
+    /// The frequencies rotating less than `beta_slow` times in the original context are
+    /// interpolated, the ones rotating more than `beta_fast` times are unchanged, and the ones in
+    /// between are blended. The encoding is also scaled to compensate for the entropy of the
+    /// attention on longer sequences.
+    ///
+    /// Reference: <https://arxiv.org/abs/2309.00071>
+    Yarn {
+        /// The context extension factor.
+        factor: f32,
+        /// The maximum sequence length the model was trained on.
+        original_max_sequence_length: usize,
+        /// The number of rotations above which the frequencies are unchanged, usually 32.
+        beta_fast: f32,
+        /// The number of rotations below which the frequencies are interpolated, usually 1.
+        beta_slow: f32,
+    },
 }
 
 impl RotaryEncodingConfig {
@@ -40,6 +84,21 @@ impl RotaryEncodingConfig {
             self.theta > 0.0,
             "Theta parameter must be positive (default: 10000)."
         );
+        if let RopeScaling::Linear { factor }
+        | RopeScaling::NtkAware { factor }
+        | RopeScaling::Yarn { factor, .. } = self.scaling
+        {
+            assert!(factor >= 1.0, "The scaling factor must be at least 1.");
+        }
+
+        // The NTK-aware scaling increases the base so that the last frequency is divided by the
+        // factor, `theta' = theta * factor ^ (d_model / (d_model - 2))`
+        let theta = match self.scaling {
+            RopeScaling::NtkAware { factor } if self.d_model > 2 => {
+                self.theta * factor.powf(self.d_model as f32 / (self.d_model - 2) as f32)
+            }
+            _ => self.theta,
+        };
 
         // Calculate the rotation frequencies for positional embeddings based on the formula
         // `theta_i = 1 / (10000 ^ (2i / d_model)) for i in [0..d_model/2]`
@@ -49,9 +108,41 @@ impl RotaryEncodingConfig {
 
         // Calculate (10000 ^ (2i / d_model)) by using the log base property `exp(log(10000) * (2i / d_model))`
         // This is done since burn doesn't support exponentiation of scalar to tensor
-        let theta_i = exponent.mul_scalar(self.theta.ln()).exp();
+        let theta_i = exponent.mul_scalar(theta.ln()).exp();
         let theta_i = theta_i.powf_scalar(-1.0);
 
+        let (theta_i, magnitude) = match self.scaling {
+            RopeScaling::None | RopeScaling::NtkAware { .. } => (theta_i, 1.0),
+            RopeScaling::Linear { factor } => (theta_i.div_scalar(factor), 1.0),
+            RopeScaling::Yarn {
+                factor,
+                original_max_sequence_length,
+                beta_fast,
+                beta_slow,
+            } => {
+                let ramp = Tensor::<B, 1>::from_data(
+                    TensorData::new(
+                        yarn_ramp(
+                            self.d_model,
+                            self.theta,
+                            original_max_sequence_length,
+                            beta_fast,
+                            beta_slow,
+                        ),
+                        [self.d_model / 2],
+                    )
+                    .convert::<B::FloatElem>(),
+                    device,
+                );
+                let interpolated = theta_i.clone().div_scalar(factor).mul(ramp.clone());
+
+                (
+                    theta_i.mul(ramp.neg().add_scalar(1.0)).add(interpolated),
+                    0.1 * factor.ln() + 1.0,
+                )
+            }
+        };
+
         // Generate frequency values for positional embeddings
         let frequencies: Tensor<B, 2> =
             Tensor::<B, 1, Int>::arange(0..self.max_sequence_length as i64, device)
@@ -62,8 +153,8 @@ impl RotaryEncodingConfig {
                 * theta_i.unsqueeze();
 
         // Convert frequency values to complex numbers (polar form)
-        let p_cos = frequencies.clone().cos();
-        let p_sin = frequencies.sin();
+        let p_cos = frequencies.clone().cos().mul_scalar(magnitude);
+        let p_sin = frequencies.sin().mul_scalar(magnitude);
 
         // Create the frequency tensor of shape (max_sequence_length, d_model, 2) with the real(cos)
         // and imaginary(sin) components along last dimension
@@ -78,6 +169,31 @@ impl RotaryEncodingConfig {
     }
 }
 
+/// Returns the interpolation weight of each frequency of the YaRN scaling, zero for the ones
+/// rotating more than `beta_fast` times in the original context, one for the ones rotating less
+/// than `beta_slow` times, and linear in between.
+fn yarn_ramp(
+    d_model: usize,
+    theta: f32,
+    original_max_sequence_length: usize,
+    beta_fast: f32,
+    beta_slow: f32,
+) -> Vec<f32> {
+    // The index of the frequency rotating the given number of times in the original context.
+    let index = |rotations: f32| {
+        d_model as f32
+            * (original_max_sequence_length as f32 / (rotations * 2.0 * core::f32::consts::PI)).ln()
+            / (2.0 * theta.ln())
+    };
+    let low = index(beta_fast).floor().max(0.0);
+    let high = index(beta_slow).ceil().min(d_model as f32 - 1.0);
+    let high = if high <= low { low + 0.001 } else { high };
+
+    (0..d_model / 2)
+        .map(|i| ((i as f32 - low) / (high - low)).clamp(0.0, 1.0))
+        .collect()
+}
+
 /// A module that applies rotary positional encoding to a tensor.
 /// Rotary Position Encoding or Embedding (RoPE), is a type of position embedding which encodes
 /// absolute positional information with rotation matrix and naturally incorporates
@@ -227,6 +343,70 @@ mod tests {
             .assert_approx_eq(&expected_output.to_data(), 4);
     }
 
+    #[test]
+    fn test_linear_scaling_should_interpolate_positions() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(10, 4).init::<TestBackend>(&device);
+        let scaled_encoding = RotaryEncodingConfig::new(10, 4)
+            .with_scaling(RopeScaling::Linear { factor: 2.0 })
+            .init::<TestBackend>(&device);
+
+        // Input = [Batch size, Num of heads, Seq_len, d_model]
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[5.0, 6.0, 7.0, 8.0]]]], &device);
+
+        let output = scaled_encoding.apply(input.clone(), 6);
+        let expected_output = rotary_encoding.apply(input, 3);
+
+        output
+            .to_data()
+            .assert_approx_eq(&expected_output.to_data(), 4);
+    }
+
+    #[test]
+    fn test_ntk_aware_scaling_forward() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(10, 4)
+            .with_scaling(RopeScaling::NtkAware { factor: 4.0 })
+            .init::<TestBackend>(&device);
+
+        // Input = [Batch size, Num of heads, Seq_len, d_model]
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[5.0, 6.0, 7.0, 8.0]]]], &device);
+
+        // The highest frequency is unchanged and the lowest one is divided by the factor.
+        let output = rotary_encoding.apply(input, 1);
+        let expected_output =
+            Tensor::<TestBackend, 4>::from_floats([[[[-2.3473, 7.4492, 6.9800, 8.0175]]]], &device);
+
+        output
+            .to_data()
+            .assert_approx_eq(&expected_output.to_data(), 4);
+    }
+
+    #[test]
+    fn test_yarn_scaling_forward() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(10, 4)
+            .with_scaling(RopeScaling::Yarn {
+                factor: 4.0,
+                original_max_sequence_length: 16,
+                beta_fast: 32.0,
+                beta_slow: 1.0,
+            })
+            .init::<TestBackend>(&device);
+
+        // Input = [Batch size, Num of heads, Seq_len, d_model]
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[5.0, 6.0, 7.0, 8.0]]]], &device);
+
+        // The lowest frequency is interpolated, and the encoding scaled by `0.1 * ln(4) + 1`.
+        let output = rotary_encoding.apply(input, 1);
+        let expected_output =
+            Tensor::<TestBackend, 4>::from_floats([[[[-2.6727, 8.4818, 7.9476, 9.1289]]]], &device);
+
+        output
+            .to_data()
+            .assert_approx_eq(&expected_output.to_data(), 3);
+    }
+
     #[test]
     #[should_panic]
     fn test_valid_input_hidden_dim() {
@@ -1,6 +1,9 @@
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::module::Module;
+use crate::module::{num_elements, ShapePropagation, SummaryBuilder};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
@@ -24,3 +27,11 @@ impl Tanh {
         crate::tensor::activation::tanh(input)
     }
 }
+
+impl ShapePropagation for Tanh {
+    fn propagate_shape(&self, input: &[usize], summary: &mut SummaryBuilder) -> Vec<usize> {
+        summary.add_flops(num_elements(input));
+
+        input.to_vec()
+    }
+}
@@ -42,6 +42,10 @@ pub struct TransformerDecoderConfig {
     /// Reference: <https://www.evanmiller.org/attention-is-off-by-one.html>
     #[config(default = false)]
     pub quiet_softmax: bool,
+    /// Add the [ALiBi bias](crate::nn::attention::generate_alibi_bias) to the self-attention
+    /// scores, instead of adding positional embeddings to the inputs.
+    #[config(default = false)]
+    pub alibi: bool,
     /// The type of function used to initialize neural network parameters
     #[config(
         default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
@@ -179,6 +183,7 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
+            .with_alibi(config.alibi)
             .init(device);
 
         let cross_attn = MultiHeadAttentionConfig::new(config.d_model, config.n_heads)
@@ -3,7 +3,11 @@ use alloc::vec::Vec;
 
 use crate::{
     self as burn,
-    nn::{attention::MhaCache, cache::TensorCache, Initializer},
+    nn::{
+        attention::{KvCache, KvCacheStrategy, MhaCache},
+        cache::TensorCache,
+        Initializer,
+    },
 };
 
 use super::{PositionWiseFeedForward, PositionWiseFeedForwardConfig};
@@ -42,6 +46,10 @@ pub struct TransformerEncoderConfig {
     /// Reference: <https://www.evanmiller.org/attention-is-off-by-one.html>
     #[config(default = false)]
     pub quiet_softmax: bool,
+    /// Add the [ALiBi bias](crate::nn::attention::generate_alibi_bias) to the self-attention
+    /// scores, instead of adding positional embeddings to the inputs.
+    #[config(default = false)]
+    pub alibi: bool,
     /// The type of function used to initialize neural network parameters
     #[config(
         default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
@@ -150,6 +158,39 @@ impl<B: Backend> TransformerEncoder<B> {
     pub fn new_autoregressive_cache(&self) -> TransformerEncoderAutoregressiveCache<B> {
         TransformerEncoderAutoregressiveCache::empty(self.layers.len())
     }
+
+    /// Applies the forward pass on the new positions of a sequence, using the keys and values of
+    /// the previous positions stored in the key-value cache.
+    ///
+    /// The new positions attend to the previous ones and to themselves causally, see
+    /// [forward_cached](MultiHeadAttention::forward_cached).
+    ///
+    /// # Shapes
+    ///
+    /// - tensor: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward_cached(
+        &self,
+        input: TransformerEncoderInput<B>,
+        cache: &mut TransformerEncoderKvCache<B>,
+    ) -> Tensor<B, 3> {
+        let mut x = input.tensor;
+
+        for (layer, cache) in self.layers.iter().zip(cache.layers.iter_mut()) {
+            x = layer.forward_cached(x, input.mask_pad.clone(), input.mask_attn.clone(), cache);
+        }
+
+        x
+    }
+
+    /// Create an empty key-value cache storing the keys and values with the given strategy.
+    pub fn new_kv_cache(&self, strategy: KvCacheStrategy) -> TransformerEncoderKvCache<B> {
+        TransformerEncoderKvCache {
+            layers: (0..self.layers.len())
+                .map(|_| KvCache::new(strategy))
+                .collect(),
+        }
+    }
 }
 
 /// Transformer encoder layer module.
@@ -169,6 +210,7 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
+            .with_alibi(config.alibi)
             .init(device);
         let norm_1 = LayerNormConfig::new(config.d_model).init(device);
         let norm_2 = LayerNormConfig::new(config.d_model).init(device);
@@ -194,6 +236,47 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         mask_pad: Option<Tensor<B, 2, Bool>>,
         mask_attn: Option<Tensor<B, 3, Bool>>,
     ) -> Tensor<B, 3> {
+        self.forward_with_attention(input, |x| {
+            self.mha
+                .forward(Self::mha_input(x, mask_pad, mask_attn))
+                .context
+        })
+    }
+
+    fn forward_cached(
+        &self,
+        input: Tensor<B, 3>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        cache: &mut KvCache<B>,
+    ) -> Tensor<B, 3> {
+        self.forward_with_attention(input, |x| {
+            self.mha
+                .forward_cached(Self::mha_input(x, mask_pad, mask_attn), cache)
+                .context
+        })
+    }
+
+    fn mha_input(
+        x: Tensor<B, 3>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+    ) -> MhaInput<B> {
+        let mut input_mhs = MhaInput::self_attn(x);
+        if let Some(mask_pad) = mask_pad {
+            input_mhs = input_mhs.mask_pad(mask_pad);
+        }
+        if let Some(mask_attn) = mask_attn {
+            input_mhs = input_mhs.mask_attn(mask_attn);
+        }
+
+        input_mhs
+    }
+
+    fn forward_with_attention<F>(&self, input: Tensor<B, 3>, attention: F) -> Tensor<B, 3>
+    where
+        F: FnOnce(Tensor<B, 3>) -> Tensor<B, 3>,
+    {
         // Multi-head attention residual path.
         let x = input;
         let mut residual_path = x.clone();
@@ -204,14 +287,7 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         }
 
         // Multi-head attention.
-        let mut input_mhs = MhaInput::self_attn(residual_path);
-        if let Some(mask_pad) = mask_pad {
-            input_mhs = input_mhs.mask_pad(mask_pad);
-        }
-        if let Some(mask_attn) = mask_attn {
-            input_mhs = input_mhs.mask_attn(mask_attn);
-        }
-        let residual_path = self.mha.forward(input_mhs).context;
+        let residual_path = attention(residual_path);
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
@@ -337,6 +413,22 @@ impl<B: Backend> TransformerEncoderAutoregressiveCache<B> {
     }
 }
 
+/// Key-value cache for the [Transformer Encoder](TransformerEncoder) layers.
+///
+/// To be used during inference when decoding tokens, only the new ones being given to
+/// [forward_cached](TransformerEncoder::forward_cached).
+pub struct TransformerEncoderKvCache<B: Backend> {
+    layers: Vec<KvCache<B>>,
+}
+
+impl<B: Backend> TransformerEncoderKvCache<B> {
+    /// Remove the cached keys and values of every layer, so that the cache can be used for a new
+    /// sequence.
+    pub fn reset(&mut self) {
+        self.layers.iter_mut().for_each(KvCache::reset);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,4 +484,35 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_2.into_data(), 3);
     }
+
+    #[test]
+    fn test_kv_cache() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        let [batch_size, seq_length] = [3, 4];
+        let device = Default::default();
+        let transformer = TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+            .with_norm_first(true)
+            .init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = TransformerEncoderInput::new(tensor.clone()).mask_attn(mask_attn);
+
+        let output_1 = transformer.forward(input);
+        let mut cache = transformer.new_kv_cache(KvCacheStrategy::Paged(3));
+        let output_2 = (0..seq_length)
+            .map(|i| {
+                let tensor = tensor.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+                transformer.forward_cached(TransformerEncoderInput::new(tensor), &mut cache)
+            })
+            .collect();
+
+        output_1
+            .into_data()
+            .assert_approx_eq(&Tensor::cat(output_2, 1).into_data(), 3);
+    }
 }
@@ -8,6 +8,19 @@ use crate::module::{AutodiffModule, ParamId};
 
 use super::visitor::{GradientsParamsChangeDevice, GradientsParamsConverter};
 
+#[cfg(feature = "std")]
+use super::visitor::{
+    FlatGradient, GradientsParamsFlatten, GradientsParamsUnflatten, ParamGrad, ParamGradsCollector,
+};
+#[cfg(feature = "std")]
+use crate::collective::{Collective, ReduceOp};
+#[cfg(feature = "std")]
+use alloc::{rc::Rc, vec::Vec};
+#[cfg(feature = "std")]
+use burn_tensor::backend::{install_backward_hook, BackwardHookGuard};
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+
 /// Data type that contains gradients for parameters.
 #[derive(Default)]
 pub struct GradientsParams {
@@ -85,16 +98,319 @@ impl GradientsParams {
         module.visit(&mut visitor);
         grads_params
     }
+
+    /// Average the gradients of the [module](AutodiffModule) across the ranks of a data parallel
+    /// training, each rank receiving the mean of the gradients of all the ranks.
+    ///
+    /// The gradients are flattened in the order of the parameters of the module, and grouped in
+    /// buckets of at least `bucket_size` elements, each one reduced with a single collective
+    /// operation. The buckets are reduced on a communication thread while the next ones are
+    /// prepared. A parameter whose gradients weren't computed by a rank, e.g. an unused branch of
+    /// the model, is reduced with zeros for that rank, and keeps no gradients when no rank
+    /// computed them.
+    ///
+    /// Every rank of the collective must call it with a replica of the same module.
+    #[cfg(feature = "std")]
+    pub fn all_reduce<B: AutodiffBackend, M: AutodiffModule<B>>(
+        mut self,
+        module: &M,
+        collective: &Collective,
+        bucket_size: usize,
+    ) -> Self {
+        let mut flattened = Vec::new();
+        module.visit(&mut GradientsParamsFlatten::<M, B>::new(
+            &mut self,
+            &mut flattened,
+        ));
+
+        let device = match flattened.first() {
+            Some(param) => param.device.clone(),
+            None => return self,
+        };
+
+        // Only reduce the parameters with gradients on at least one rank.
+        let computed: Vec<f32> = flattened
+            .iter()
+            .map(|param| param.grad.is_some() as u8 as f32)
+            .collect();
+        let computed = collective
+            .all_reduce(
+                Tensor::<B::InnerBackend, 1>::from_floats(computed.as_slice(), &device),
+                ReduceOp::Sum,
+            )
+            .into_data();
+        let flattened: Vec<FlatGradient<B::InnerBackend>> = flattened
+            .into_iter()
+            .zip(computed.iter::<f32>())
+            .filter(|(_, computed)| *computed > 0.0)
+            .map(|(param, _)| param)
+            .collect();
+
+        let buckets = std::thread::scope(|scope| {
+            let (sender, receiver) = std::sync::mpsc::channel();
+            let communication = scope.spawn(move || {
+                receiver
+                    .iter()
+                    .map(|bucket| collective.all_reduce(bucket, ReduceOp::Mean))
+                    .collect::<Vec<Tensor<B::InnerBackend, 1>>>()
+            });
+
+            let mut bucket = Vec::new();
+            let mut num_elements = 0;
+
+            for param in flattened.iter() {
+                let grad = match &param.grad {
+                    Some(grad) => grad.clone(),
+                    None => Tensor::zeros([param.num_elements], &param.device),
+                };
+
+                bucket.push(grad.to_device(&device));
+                num_elements += param.num_elements;
+
+                if num_elements >= bucket_size {
+                    sender
+                        .send(Tensor::cat(core::mem::take(&mut bucket), 0))
+                        .unwrap();
+                    num_elements = 0;
+                }
+            }
+
+            if !bucket.is_empty() {
+                sender.send(Tensor::cat(bucket, 0)).unwrap();
+            }
+
+            core::mem::drop(sender);
+            communication.join().unwrap()
+        });
+
+        // Split the buckets in the same order.
+        let mut reduced = hashbrown::HashMap::new();
+        let mut buckets = buckets.into_iter();
+        let mut current = buckets.next();
+        let mut offset = 0;
+
+        for param in flattened {
+            let bucket = current.as_ref().unwrap();
+            let grad = bucket.clone().narrow(0, offset, param.num_elements);
+            reduced.insert(param.id, grad.to_device(&param.device));
+            offset += param.num_elements;
+
+            if offset == bucket.dims()[0] {
+                current = buckets.next();
+                offset = 0;
+            }
+        }
+
+        module.visit(&mut GradientsParamsUnflatten::<M, B>::new(
+            &mut self, reduced,
+        ));
+        self
+    }
+}
+
+/// Average the gradients of a [module](AutodiffModule) across the ranks of a data parallel
+/// training during the backward pass, as [all_reduce](GradientsParams::all_reduce) does after
+/// it.
+///
+/// The buckets of gradients are reduced on a communication thread as soon as the gradients of
+/// all their parameters are complete, overlapping the communication with the rest of the
+/// backward pass. The parameters are grouped in buckets of at least `bucket_size` elements in
+/// the reverse order of the parameters of the module, since the gradients of the last layers
+/// are usually complete first.
+///
+/// The reducer is called by the [backward](Tensor::backward) passes of the current thread until
+/// it is [finished](GradientsReducer::finish), and every rank must create it with a replica of
+/// the same module and run a single backward pass before finishing it. The buckets are reduced
+/// on the channel `1` of the [collective](Collective::channel), so the backward pass can use its
+/// channel `0`, e.g. to synchronize the statistics of
+/// [batch normalization](crate::nn::SyncBatchNorm).
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let reducer = GradientsReducer::new(&model, &collective, bucket_size);
+/// let grads = model.forward(item).loss.backward();
+/// let grads = reducer.finish(GradientsParams::from_grads(grads, &model), &model);
+/// ```
+#[cfg(feature = "std")]
+pub struct GradientsReducer<B: AutodiffBackend> {
+    state: Rc<RefCell<ReducerState<B>>>,
+    communication: std::thread::JoinHandle<Vec<Tensor<B::InnerBackend, 1>>>,
+    collective: Collective,
+    bucket_size: usize,
+    _hook: BackwardHookGuard,
+}
+
+#[cfg(feature = "std")]
+struct ReducerState<B: AutodiffBackend> {
+    buckets: Vec<Vec<ParamGrad<B>>>,
+    device: B::Device,
+    // The index of the next bucket to reduce.
+    next: usize,
+    sender: Option<std::sync::mpsc::Sender<Tensor<B::InnerBackend, 1>>>,
+}
+
+#[cfg(feature = "std")]
+impl<B: AutodiffBackend> GradientsReducer<B> {
+    /// Start reducing the gradients of the module computed by the next backward pass of the
+    /// current thread.
+    pub fn new<M: AutodiffModule<B>>(
+        module: &M,
+        collective: &Collective,
+        bucket_size: usize,
+    ) -> Self {
+        let mut params = Vec::new();
+        module.visit(&mut ParamGradsCollector::<M, B>::new(&mut params));
+
+        let device = params
+            .first()
+            .map(|param| param.device.clone())
+            .unwrap_or_default();
+        let mut buckets = Vec::new();
+        let mut bucket = Vec::new();
+        let mut num_elements = 0;
+
+        for param in params.into_iter().rev() {
+            num_elements += param.num_elements;
+            bucket.push(param);
+
+            if num_elements >= bucket_size {
+                buckets.push(core::mem::take(&mut bucket));
+                num_elements = 0;
+            }
+        }
+        if !bucket.is_empty() {
+            buckets.push(bucket);
+        }
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let channel = collective.channel(1);
+        let communication = std::thread::spawn(move || {
+            receiver
+                .iter()
+                .map(|bucket| channel.all_reduce(bucket, ReduceOp::Mean))
+                .collect()
+        });
+
+        let state = Rc::new(RefCell::new(ReducerState {
+            buckets,
+            device,
+            next: 0,
+            sender: Some(sender),
+        }));
+        let state_hook = state.clone();
+        let hook = install_backward_hook::<B>(move |grads| state_hook.borrow_mut().send(grads));
+
+        Self {
+            state,
+            communication,
+            collective: collective.clone(),
+            bucket_size,
+            _hook: hook,
+        }
+    }
+
+    /// Wait for the reduction of the gradients, replacing the ones of the parameters of the
+    /// module.
+    ///
+    /// The gradients are reduced after the backward pass, with
+    /// [all_reduce](GradientsParams::all_reduce), if the reducer wasn't called by any backward
+    /// pass.
+    pub fn finish<M: AutodiffModule<B>>(
+        self,
+        mut grads: GradientsParams,
+        module: &M,
+    ) -> GradientsParams {
+        let Self {
+            state,
+            communication,
+            collective,
+            bucket_size,
+            _hook,
+        } = self;
+        core::mem::drop(_hook);
+
+        let mut state = state.borrow_mut();
+        core::mem::drop(state.sender.take());
+        let buckets = communication.join().unwrap();
+
+        if state.next < state.buckets.len() {
+            return grads.all_reduce(module, &collective, bucket_size);
+        }
+
+        // Split the buckets, keeping the gradients of the parameters computed by at least one
+        // rank.
+        let mut reduced = hashbrown::HashMap::new();
+        for (params, bucket) in state.buckets.iter().zip(buckets) {
+            let num_elements: usize = params.iter().map(|param| param.num_elements).sum();
+            let computed = bucket
+                .clone()
+                .narrow(0, num_elements, params.len())
+                .into_data();
+            let mut offset = 0;
+
+            for (param, computed) in params.iter().zip(computed.iter::<f32>()) {
+                if computed > 0.0 {
+                    let grad = bucket.clone().narrow(0, offset, param.num_elements);
+                    reduced.insert(param.id.clone(), grad.to_device(&param.device));
+                }
+                offset += param.num_elements;
+            }
+        }
+
+        module.visit(&mut GradientsParamsUnflatten::<M, B>::new(
+            &mut grads, reduced,
+        ));
+        grads
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: AutodiffBackend> ReducerState<B> {
+    /// Send the next buckets whose gradients are complete to the communication thread, each one
+    /// with a flag for each of its parameters computed by the rank.
+    fn send(&mut self, grads: &B::Gradients) {
+        while let Some(params) = self.buckets.get(self.next) {
+            let Some(param_grads) = params
+                .iter()
+                .map(|param| (param.grad)(grads))
+                .collect::<Option<Vec<_>>>()
+            else {
+                return;
+            };
+
+            let computed: Vec<f32> = param_grads
+                .iter()
+                .map(|grad| grad.is_some() as u8 as f32)
+                .collect();
+            let mut bucket: Vec<Tensor<B::InnerBackend, 1>> = params
+                .iter()
+                .zip(param_grads)
+                .map(|(param, grad)| {
+                    grad.unwrap_or_else(|| Tensor::zeros([param.num_elements], &param.device))
+                        .to_device(&self.device)
+                })
+                .collect();
+            bucket.push(Tensor::from_floats(computed.as_slice(), &self.device));
+
+            if let Some(sender) = &self.sender {
+                sender.send(Tensor::cat(bucket, 0)).unwrap();
+            }
+            self.next += 1;
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
+        collective::Collective,
         module::{list_param_ids, Module},
         nn::{Linear, LinearConfig},
-        TestAutodiffBackend,
+        TestAutodiffBackend, TestBackend,
     };
+    use alloc::vec;
     use burn_tensor::{backend::Backend, Distribution};
 
     #[test]
@@ -116,6 +432,111 @@ mod tests {
         assert_eq!(grads_2.len(), param_ids_2.len());
     }
 
+    #[test]
+    fn test_all_reduce_grads() {
+        let device = Default::default();
+        let layers = vec![
+            layer::<TestAutodiffBackend>(&device),
+            layer::<TestAutodiffBackend>(&device),
+        ];
+        let inputs = [random_tensor(&device), random_tensor(&device)];
+
+        // The second layer is only used by the first rank.
+        let grads = |layers: &Vec<Linear<TestAutodiffBackend>>, rank: usize| {
+            let mut output = layers[0].forward(inputs[rank].clone());
+            if rank == 0 {
+                output = layers[1].forward(output);
+            }
+            GradientsParams::from_grads(output.sum().backward(), layers)
+        };
+        let mut grads_0 = grads(&layers, 0);
+        let mut grads_1 = grads(&layers, 1);
+
+        let handles: Vec<_> = Collective::group(2)
+            .into_iter()
+            .map(|collective| {
+                let layers = layers.clone();
+                let grads = grads(&layers, collective.rank());
+                std::thread::spawn(move || grads.all_reduce(&layers, &collective, 300))
+            })
+            .collect();
+
+        for handle in handles {
+            let mut reduced = handle.join().unwrap();
+
+            for layer in layers.iter() {
+                let id = &layer.weight.id;
+                let expected = match grads_1.get::<TestBackend, 2>(id) {
+                    Some(grad_1) => (grads_0.get::<TestBackend, 2>(id).unwrap() + grad_1) / 2,
+                    None => grads_0.get::<TestBackend, 2>(id).unwrap() / 2,
+                };
+                reduced
+                    .remove::<TestBackend, 2>(id)
+                    .unwrap()
+                    .into_data()
+                    .assert_approx_eq(&expected.into_data(), 4);
+            }
+        }
+        assert!(grads_0
+            .remove::<TestBackend, 2>(&layers[1].weight.id)
+            .is_some());
+        assert!(grads_1
+            .remove::<TestBackend, 2>(&layers[1].weight.id)
+            .is_none());
+    }
+
+    #[test]
+    fn test_reduce_grads_during_backward() {
+        let device = Default::default();
+        let layers = vec![
+            layer::<TestAutodiffBackend>(&device),
+            layer::<TestAutodiffBackend>(&device),
+        ];
+        let inputs = [random_tensor(&device), random_tensor(&device)];
+
+        // The second layer is only used by the first rank.
+        let loss = |layers: &Vec<Linear<TestAutodiffBackend>>, rank: usize| {
+            let mut output = layers[0].forward(inputs[rank].clone());
+            if rank == 0 {
+                output = layers[1].forward(output);
+            }
+            output.sum()
+        };
+        let grads_0 = GradientsParams::from_grads(loss(&layers, 0).backward(), &layers);
+        let grads_1 = GradientsParams::from_grads(loss(&layers, 1).backward(), &layers);
+
+        let handles: Vec<_> = Collective::group(2)
+            .into_iter()
+            .map(|collective| {
+                let layers = layers.clone();
+                let loss = loss(&layers, collective.rank());
+                std::thread::spawn(move || {
+                    // The buckets hold one or two parameters.
+                    let reducer = GradientsReducer::new(&layers, &collective, 300);
+                    let grads = GradientsParams::from_grads(loss.backward(), &layers);
+                    reducer.finish(grads, &layers)
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let mut reduced = handle.join().unwrap();
+
+            for layer in layers.iter() {
+                let id = &layer.weight.id;
+                let expected = match grads_1.get::<TestBackend, 2>(id) {
+                    Some(grad_1) => (grads_0.get::<TestBackend, 2>(id).unwrap() + grad_1) / 2,
+                    None => grads_0.get::<TestBackend, 2>(id).unwrap() / 2,
+                };
+                reduced
+                    .remove::<TestBackend, 2>(id)
+                    .unwrap()
+                    .into_data()
+                    .assert_approx_eq(&expected.into_data(), 4);
+            }
+        }
+    }
+
     fn layer<B: Backend>(device: &B::Device) -> Linear<B> {
         LinearConfig::new(20, 20).with_bias(true).init(device)
     }
@@ -3,6 +3,13 @@ use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
 use burn_tensor::{backend::AutodiffBackend, Tensor};
 use core::marker::PhantomData;
 
+#[cfg(feature = "std")]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "std")]
+use burn_tensor::backend::Backend;
+#[cfg(feature = "std")]
+use hashbrown::HashMap;
+
 #[derive(new)]
 pub struct GradientsParamsConverter<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
     grads: B::Gradients,
@@ -42,3 +49,101 @@ where
         }
     }
 }
+
+/// The flattened gradient of a parameter, in the order of the parameters of a module.
+#[cfg(feature = "std")]
+pub struct FlatGradient<B: Backend> {
+    pub id: ParamId,
+    pub num_elements: usize,
+    pub device: B::Device,
+    pub grad: Option<Tensor<B, 1>>,
+}
+
+/// A parameter whose gradient is read during a backward pass, in the order of the parameters of
+/// a module.
+#[cfg(feature = "std")]
+pub struct ParamGrad<B: AutodiffBackend> {
+    pub id: ParamId,
+    pub num_elements: usize,
+    pub device: B::Device,
+    /// The flattened gradient of the parameter once it is complete, if it was computed.
+    #[allow(clippy::type_complexity)]
+    pub grad: Box<dyn Fn(&B::Gradients) -> Option<Option<Tensor<B::InnerBackend, 1>>>>,
+}
+
+#[cfg(feature = "std")]
+#[derive(new)]
+pub struct ParamGradsCollector<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    params: &'a mut Vec<ParamGrad<B>>,
+    phatom: PhantomData<M>,
+}
+
+#[cfg(feature = "std")]
+#[derive(new)]
+pub struct GradientsParamsFlatten<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    grads: &'a mut GradientsParams,
+    flattened: &'a mut Vec<FlatGradient<B::InnerBackend>>,
+    phatom: PhantomData<M>,
+}
+
+#[cfg(feature = "std")]
+#[derive(new)]
+pub struct GradientsParamsUnflatten<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    grads: &'a mut GradientsParams,
+    flattened: HashMap<ParamId, Tensor<B::InnerBackend, 1>>,
+    phatom: PhantomData<M>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, B, M> ModuleVisitor<B> for ParamGradsCollector<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let tensor = tensor.clone();
+
+        self.params.push(ParamGrad {
+            id: id.clone(),
+            num_elements: tensor.shape().num_elements(),
+            device: tensor.device(),
+            grad: Box::new(move |grads| {
+                tensor
+                    .grad_complete(grads)
+                    .then(|| tensor.grad(grads).map(|grad| grad.flatten(0, D - 1)))
+            }),
+        });
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, B, M> ModuleVisitor<B> for GradientsParamsFlatten<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let grad = self.grads.remove::<B::InnerBackend, D>(id);
+
+        self.flattened.push(FlatGradient {
+            id: id.clone(),
+            num_elements: tensor.shape().num_elements(),
+            device: tensor.device(),
+            grad: grad.map(|grad| grad.flatten(0, D - 1)),
+        });
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, B, M> ModuleVisitor<B> for GradientsParamsUnflatten<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.flattened.remove(id) {
+            self.grads
+                .register::<B::InnerBackend, D>(id.clone(), grad.reshape(tensor.shape()));
+        }
+    }
+}
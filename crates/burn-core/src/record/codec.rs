@@ -0,0 +1,584 @@
+use super::RecorderError;
+use alloc::sync::Arc;
+use std::io::{BufRead, BufReader, Read, Write};
+
+/// Magic bytes identifying the start of the codec header.
+const CODEC_MAGIC: [u8; 4] = *b"BCDC";
+
+/// Writer returned by a [codec](RecordCodec), which must be finished once everything is written.
+pub trait CodecWriter: Write {
+    /// Finish encoding, flushing every remaining byte to the inner writer and finishing it.
+    fn finish(self: Box<Self>) -> Result<(), RecorderError>;
+}
+
+/// Codec transforming the byte stream written and read by the file recorders, such as
+/// compression or encryption.
+///
+/// The identifiers of the codecs used to save a record are written in a header at the start of
+/// the file, so that loading it with different codecs fails with a clear error. The header isn't
+/// encoded, codecs authenticating the record should authenticate it as well.
+pub trait RecordCodec: core::fmt::Debug + Send + Sync {
+    /// Identifier of the codec written in the header.
+    fn id(&self) -> &str;
+
+    /// Wrap the writer to encode the bytes written to it, the header being already written.
+    fn encoder<'a>(
+        &self,
+        writer: Box<dyn CodecWriter + 'a>,
+        header: &[u8],
+    ) -> Result<Box<dyn CodecWriter + 'a>, RecorderError>;
+
+    /// Wrap the reader to decode the bytes read from it, the header being already read.
+    fn decoder<'a>(
+        &self,
+        reader: Box<dyn BufRead + 'a>,
+        header: &[u8],
+    ) -> Result<Box<dyn BufRead + 'a>, RecorderError>;
+}
+
+/// Writer without any encoding.
+struct PlainWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Write for PlainWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> CodecWriter for PlainWriter<W> {
+    fn finish(mut self: Box<Self>) -> Result<(), RecorderError> {
+        self.writer.flush().map_err(io_error)
+    }
+}
+
+/// The codecs applied by a file recorder, in order.
+///
+/// With `zstd` followed by `aes`, records are compressed then encrypted when saved, and
+/// decrypted then decompressed when loaded.
+#[derive(Clone, Debug, Default)]
+pub struct RecordCodecs {
+    codecs: alloc::vec::Vec<Arc<dyn RecordCodec>>,
+}
+
+impl RecordCodecs {
+    /// Append a codec applied after the current ones when saving.
+    pub fn with<C: RecordCodec + 'static>(mut self, codec: C) -> Self {
+        self.codecs.push(Arc::new(codec));
+        self
+    }
+
+    /// Whether no codec is used.
+    pub fn is_empty(&self) -> bool {
+        self.codecs.is_empty()
+    }
+
+    /// Write the codec header and wrap the writer with every codec.
+    pub(crate) fn encoder<'a, W: Write + 'a>(
+        &self,
+        mut writer: W,
+    ) -> Result<Box<dyn CodecWriter + 'a>, RecorderError> {
+        let header = self.header();
+        if !self.is_empty() {
+            writer.write_all(&header).map_err(io_error)?;
+        }
+
+        let mut writer: Box<dyn CodecWriter + 'a> = Box::new(PlainWriter { writer });
+        for codec in self.codecs.iter().rev() {
+            writer = codec.encoder(writer, &header)?;
+        }
+
+        Ok(writer)
+    }
+
+    /// Read the codec header and wrap the reader with every codec.
+    pub(crate) fn decoder<'a, R: Read + 'a>(
+        &self,
+        mut reader: R,
+    ) -> Result<Box<dyn BufRead + 'a>, RecorderError> {
+        let (ids, start) = read_header(&mut reader)?;
+        let expected = self
+            .codecs
+            .iter()
+            .map(|codec| codec.id())
+            .collect::<Vec<_>>();
+
+        if ids != expected {
+            return Err(RecorderError::DeserializeError(format!(
+                "The record was saved with the codecs {ids:?}, but the recorder uses {expected:?}."
+            )));
+        }
+
+        // The bytes read past the header are the start of the record when it doesn't have any.
+        let header = self.header();
+        let mut reader: Box<dyn BufRead + 'a> =
+            Box::new(BufReader::new(std::io::Cursor::new(start).chain(reader)));
+        for codec in self.codecs.iter().rev() {
+            reader = codec.decoder(reader, &header)?;
+        }
+
+        Ok(reader)
+    }
+
+    /// The header written before the encoded record, empty without any codec.
+    fn header(&self) -> Vec<u8> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        let mut header = CODEC_MAGIC.to_vec();
+        header.push(self.codecs.len() as u8);
+
+        for codec in self.codecs.iter() {
+            let id = codec.id().as_bytes();
+            header.push(id.len() as u8);
+            header.extend_from_slice(id);
+        }
+
+        header
+    }
+}
+
+/// Read the codec identifiers from the header, if any.
+///
+/// Returns the identifiers along with the bytes read past the header, which are the start of the
+/// record when it doesn't have any header.
+fn read_header<R: Read>(reader: &mut R) -> Result<(Vec<String>, Vec<u8>), RecorderError> {
+    let mut magic = Vec::with_capacity(CODEC_MAGIC.len());
+    // Reads until the whole magic is read, whatever the number of bytes returned by each read.
+    reader
+        .take(CODEC_MAGIC.len() as u64)
+        .read_to_end(&mut magic)
+        .map_err(io_error)?;
+    if magic != CODEC_MAGIC {
+        return Ok((Vec::new(), magic));
+    }
+
+    let count = read_u8(reader)?;
+    let mut ids = Vec::with_capacity(count as usize);
+
+    for _ in 0..count {
+        let mut id = vec![0; read_u8(reader)? as usize];
+        reader.read_exact(&mut id).map_err(io_error)?;
+        ids.push(String::from_utf8_lossy(&id).into_owned());
+    }
+
+    Ok((ids, Vec::new()))
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> Result<u8, RecorderError> {
+    let mut value = [0];
+    reader.read_exact(&mut value).map_err(io_error)?;
+    Ok(value[0])
+}
+
+fn io_error(err: std::io::Error) -> RecorderError {
+    RecorderError::Unknown(err.to_string())
+}
+
+#[cfg(feature = "record-codec-zstd")]
+mod zstd_codec {
+    use super::*;
+
+    /// [Codec](RecordCodec) compressing records with [zstd](zstd).
+    #[derive(new, Debug, Clone)]
+    pub struct ZstdCodec {
+        /// The compression level, from 1 to 22.
+        level: i32,
+    }
+
+    impl Default for ZstdCodec {
+        fn default() -> Self {
+            Self::new(zstd::DEFAULT_COMPRESSION_LEVEL)
+        }
+    }
+
+    impl<'a> CodecWriter for zstd::stream::write::Encoder<'static, Box<dyn CodecWriter + 'a>> {
+        fn finish(self: Box<Self>) -> Result<(), RecorderError> {
+            let writer = (*self).finish().map_err(io_error)?;
+            writer.finish()
+        }
+    }
+
+    impl RecordCodec for ZstdCodec {
+        fn id(&self) -> &str {
+            "zstd"
+        }
+
+        fn encoder<'a>(
+            &self,
+            writer: Box<dyn CodecWriter + 'a>,
+            _header: &[u8],
+        ) -> Result<Box<dyn CodecWriter + 'a>, RecorderError> {
+            let encoder =
+                zstd::stream::write::Encoder::new(writer, self.level).map_err(io_error)?;
+            Ok(Box::new(encoder))
+        }
+
+        fn decoder<'a>(
+            &self,
+            reader: Box<dyn BufRead + 'a>,
+            _header: &[u8],
+        ) -> Result<Box<dyn BufRead + 'a>, RecorderError> {
+            let decoder = zstd::stream::read::Decoder::with_buffer(reader).map_err(io_error)?;
+            Ok(Box::new(BufReader::new(decoder)))
+        }
+    }
+}
+
+#[cfg(feature = "record-codec-zstd")]
+pub use zstd_codec::*;
+
+#[cfg(feature = "record-codec-aes")]
+mod aes_codec {
+    use super::*;
+    use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+    use aes_gcm::{Aes256Gcm, Key, Nonce};
+
+    const NONCE_SIZE: usize = 12;
+
+    /// Version of the layout of the encrypted records, written before the nonce.
+    const AES_FORMAT_VERSION: u8 = 1;
+
+    /// [Codec](RecordCodec) encrypting records with AES-256-GCM.
+    ///
+    /// The whole record is buffered in memory to be encrypted, and a nonce is generated by the
+    /// operating system every time a record is saved. The codec header and the version of the
+    /// layout are authenticated along with the record.
+    #[derive(Clone)]
+    pub struct AesGcmCodec {
+        cipher: Aes256Gcm,
+    }
+
+    impl AesGcmCodec {
+        /// Create the codec with the given 256-bit key.
+        pub fn new(key: [u8; 32]) -> Self {
+            Self {
+                cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)),
+            }
+        }
+    }
+
+    impl core::fmt::Debug for AesGcmCodec {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // Never print the key.
+            f.debug_struct("AesGcmCodec").finish_non_exhaustive()
+        }
+    }
+
+    /// The data authenticated without being encrypted.
+    fn associated_data(header: &[u8]) -> Vec<u8> {
+        let mut data = header.to_vec();
+        data.push(AES_FORMAT_VERSION);
+        data
+    }
+
+    struct AesGcmWriter<'a> {
+        cipher: Aes256Gcm,
+        associated_data: Vec<u8>,
+        buffer: Vec<u8>,
+        writer: Box<dyn CodecWriter + 'a>,
+    }
+
+    impl Write for AesGcmWriter<'_> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.buffer.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl CodecWriter for AesGcmWriter<'_> {
+        fn finish(self: Box<Self>) -> Result<(), RecorderError> {
+            let mut this = *self;
+            let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+            let payload = Payload {
+                msg: &this.buffer,
+                aad: &this.associated_data,
+            };
+            let encrypted = this
+                .cipher
+                .encrypt(&nonce, payload)
+                .map_err(|err| RecorderError::Unknown(format!("Unable to encrypt: {err}")))?;
+
+            this.writer
+                .write_all(&[AES_FORMAT_VERSION])
+                .map_err(io_error)?;
+            this.writer.write_all(&nonce).map_err(io_error)?;
+            this.writer.write_all(&encrypted).map_err(io_error)?;
+            this.writer.finish()
+        }
+    }
+
+    impl RecordCodec for AesGcmCodec {
+        fn id(&self) -> &str {
+            "aes-256-gcm"
+        }
+
+        fn encoder<'a>(
+            &self,
+            writer: Box<dyn CodecWriter + 'a>,
+            header: &[u8],
+        ) -> Result<Box<dyn CodecWriter + 'a>, RecorderError> {
+            Ok(Box::new(AesGcmWriter {
+                cipher: self.cipher.clone(),
+                associated_data: associated_data(header),
+                buffer: Vec::new(),
+                writer,
+            }))
+        }
+
+        fn decoder<'a>(
+            &self,
+            mut reader: Box<dyn BufRead + 'a>,
+            header: &[u8],
+        ) -> Result<Box<dyn BufRead + 'a>, RecorderError> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).map_err(io_error)?;
+
+            let Some((version, bytes)) = bytes.split_first() else {
+                return Err(RecorderError::DeserializeError(
+                    "The encrypted record is truncated.".to_string(),
+                ));
+            };
+            if *version != AES_FORMAT_VERSION {
+                return Err(RecorderError::DeserializeError(format!(
+                    "The encrypted record version {version} isn't supported, the supported \
+                     version is {AES_FORMAT_VERSION}."
+                )));
+            }
+            if bytes.len() < NONCE_SIZE {
+                return Err(RecorderError::DeserializeError(
+                    "The encrypted record is truncated.".to_string(),
+                ));
+            }
+
+            let (nonce, encrypted) = bytes.split_at(NONCE_SIZE);
+            let payload = Payload {
+                msg: encrypted,
+                aad: &associated_data(header),
+            };
+            let decrypted = self
+                .cipher
+                .decrypt(Nonce::from_slice(nonce), payload)
+                .map_err(|_| {
+                    RecorderError::DeserializeError(
+                        "Unable to decrypt the record, the key is invalid or the file is corrupted."
+                            .to_string(),
+                    )
+                })?;
+
+            Ok(Box::new(std::io::Cursor::new(decrypted)))
+        }
+    }
+}
+
+#[cfg(feature = "record-codec-aes")]
+pub use aes_codec::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{
+        BinFileRecorder, BinGzFileRecorder, FileRecorder, FullPrecisionSettings,
+        NamedMpkFileRecorder, NamedMpkGzFileRecorder, Recorder,
+    };
+    use crate::TestBackend;
+    use tempfile::TempDir;
+
+    /// Codec flipping every bit, only used to test the codec chain.
+    #[derive(Debug)]
+    struct InvertCodec;
+
+    struct InvertWriter<'a> {
+        writer: Box<dyn CodecWriter + 'a>,
+    }
+
+    impl Write for InvertWriter<'_> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let inverted = buf.iter().map(|b| !b).collect::<Vec<_>>();
+            self.writer.write_all(&inverted)?;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.writer.flush()
+        }
+    }
+
+    impl CodecWriter for InvertWriter<'_> {
+        fn finish(self: Box<Self>) -> Result<(), RecorderError> {
+            self.writer.finish()
+        }
+    }
+
+    impl RecordCodec for InvertCodec {
+        fn id(&self) -> &str {
+            "invert"
+        }
+
+        fn encoder<'a>(
+            &self,
+            writer: Box<dyn CodecWriter + 'a>,
+            _header: &[u8],
+        ) -> Result<Box<dyn CodecWriter + 'a>, RecorderError> {
+            Ok(Box::new(InvertWriter { writer }))
+        }
+
+        fn decoder<'a>(
+            &self,
+            mut reader: Box<dyn BufRead + 'a>,
+            _header: &[u8],
+        ) -> Result<Box<dyn BufRead + 'a>, RecorderError> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).map_err(io_error)?;
+            let inverted = bytes.iter().map(|b| !b).collect::<Vec<_>>();
+
+            Ok(Box::new(std::io::Cursor::new(inverted)))
+        }
+    }
+
+    fn item() -> Vec<f32> {
+        (0..256).map(|i| (i % 7) as f32).collect()
+    }
+
+    fn assert_roundtrip<R>(recorder: R)
+    where
+        R: FileRecorder<TestBackend>,
+    {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("item");
+
+        Recorder::<TestBackend>::record(&recorder, item(), path.clone()).unwrap();
+        let loaded: Vec<f32> =
+            Recorder::<TestBackend>::load(&recorder, path, &Default::default()).unwrap();
+
+        assert_eq!(loaded, item());
+    }
+
+    #[test]
+    fn roundtrip_with_codec() {
+        let codecs = || RecordCodecs::default().with(InvertCodec);
+
+        assert_roundtrip(BinFileRecorder::<FullPrecisionSettings>::new().with_codecs(codecs()));
+        assert_roundtrip(BinGzFileRecorder::<FullPrecisionSettings>::new().with_codecs(codecs()));
+        assert_roundtrip(
+            NamedMpkFileRecorder::<FullPrecisionSettings>::new().with_codecs(codecs()),
+        );
+        assert_roundtrip(
+            NamedMpkGzFileRecorder::<FullPrecisionSettings>::new().with_codecs(codecs()),
+        );
+    }
+
+    #[test]
+    fn codecs_mismatch_is_an_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("item");
+        let with_codec = NamedMpkFileRecorder::<FullPrecisionSettings>::new()
+            .with_codecs(RecordCodecs::default().with(InvertCodec));
+        let without_codec = NamedMpkFileRecorder::<FullPrecisionSettings>::new();
+
+        Recorder::<TestBackend>::record(&with_codec, item(), path.clone()).unwrap();
+        let result: Result<Vec<f32>, _> =
+            Recorder::<TestBackend>::load(&without_codec, path.clone(), &Default::default());
+        assert!(matches!(result, Err(RecorderError::DeserializeError(_))));
+
+        Recorder::<TestBackend>::record(&without_codec, item(), path.clone()).unwrap();
+        let result: Result<Vec<f32>, _> =
+            Recorder::<TestBackend>::load(&with_codec, path, &Default::default());
+        assert!(matches!(result, Err(RecorderError::DeserializeError(_))));
+    }
+
+    #[test]
+    fn memory_map_with_codecs_is_an_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("item");
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::new()
+            .with_codecs(RecordCodecs::default().with(InvertCodec))
+            .with_memory_map(true);
+
+        Recorder::<TestBackend>::record(&recorder, item(), path.clone()).unwrap();
+        let result: Result<Vec<f32>, _> =
+            Recorder::<TestBackend>::load(&recorder, path, &Default::default());
+
+        assert!(matches!(result, Err(RecorderError::Unknown(_))));
+    }
+
+    #[cfg(feature = "record-codec-zstd")]
+    #[test]
+    fn roundtrip_zstd() {
+        let codecs = || RecordCodecs::default().with(ZstdCodec::default());
+
+        assert_roundtrip(BinFileRecorder::<FullPrecisionSettings>::new().with_codecs(codecs()));
+        assert_roundtrip(
+            NamedMpkFileRecorder::<FullPrecisionSettings>::new().with_codecs(codecs()),
+        );
+    }
+
+    #[cfg(feature = "record-codec-aes")]
+    #[test]
+    fn roundtrip_aes() {
+        let codecs = || RecordCodecs::default().with(AesGcmCodec::new([7; 32]));
+
+        assert_roundtrip(BinFileRecorder::<FullPrecisionSettings>::new().with_codecs(codecs()));
+        assert_roundtrip(
+            NamedMpkFileRecorder::<FullPrecisionSettings>::new().with_codecs(codecs()),
+        );
+    }
+
+    #[cfg(feature = "record-codec-aes")]
+    #[test]
+    fn aes_nonce_is_unique() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("item");
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::new()
+            .with_codecs(RecordCodecs::default().with(AesGcmCodec::new([1; 32])));
+        let file = path.with_extension(
+            <NamedMpkFileRecorder<FullPrecisionSettings> as FileRecorder<TestBackend>>::file_extension(),
+        );
+
+        Recorder::<TestBackend>::record(&recorder, item(), path.clone()).unwrap();
+        let first = std::fs::read(&file).unwrap();
+        Recorder::<TestBackend>::record(&recorder, item(), path.clone()).unwrap();
+        let second = std::fs::read(&file).unwrap();
+
+        assert_ne!(first, second);
+    }
+
+    #[cfg(feature = "record-codec-aes")]
+    #[test]
+    fn aes_wrong_key_is_an_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("item");
+        let recorder = |key| {
+            NamedMpkFileRecorder::<FullPrecisionSettings>::new()
+                .with_codecs(RecordCodecs::default().with(AesGcmCodec::new(key)))
+        };
+
+        Recorder::<TestBackend>::record(&recorder([1; 32]), item(), path.clone()).unwrap();
+        let result: Result<Vec<f32>, _> =
+            Recorder::<TestBackend>::load(&recorder([2; 32]), path, &Default::default());
+
+        assert!(matches!(result, Err(RecorderError::DeserializeError(_))));
+    }
+
+    #[cfg(all(feature = "record-codec-zstd", feature = "record-codec-aes"))]
+    #[test]
+    fn roundtrip_zstd_then_aes() {
+        assert_roundtrip(
+            BinGzFileRecorder::<FullPrecisionSettings>::new().with_codecs(
+                RecordCodecs::default()
+                    .with(ZstdCodec::new(5))
+                    .with(AesGcmCodec::new([3; 32])),
+            ),
+        );
+    }
+}
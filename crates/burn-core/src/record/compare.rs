@@ -0,0 +1,317 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use burn_tensor::{backend::Backend, DType, TensorData};
+use serde::Deserialize;
+
+use super::{join, DoublePrecisionSettings, Record, RecordValue, RecorderError};
+
+/// Difference between the same parameter of two records.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamDiff {
+    /// Both parameters have the same shape and data type.
+    Values {
+        /// The maximum absolute difference between the elements.
+        max_abs_diff: f64,
+        /// The mean absolute difference between the elements.
+        mean_abs_diff: f64,
+    },
+    /// The parameters don't have the same shape.
+    ShapeMismatch {
+        /// The shape of the parameter in the first record.
+        lhs: Vec<usize>,
+        /// The shape of the parameter in the second record.
+        rhs: Vec<usize>,
+    },
+    /// The parameters don't have the same data type.
+    DTypeMismatch {
+        /// The data type of the parameter in the first record.
+        lhs: DType,
+        /// The data type of the parameter in the second record.
+        rhs: DType,
+    },
+    /// Values that aren't tensors are different.
+    ValueMismatch,
+}
+
+/// Comparison of a single parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamComparison {
+    /// The path of the parameter in the record, e.g. `layers.0.linear.weight`.
+    pub key: String,
+    /// The difference between both records.
+    pub diff: ParamDiff,
+}
+
+/// Result of [comparing two records](compare_records).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecordComparison {
+    /// The comparison of every parameter found in both records.
+    pub params: Vec<ParamComparison>,
+    /// Keys only present in the first record.
+    pub lhs_only_keys: Vec<String>,
+    /// Keys only present in the second record.
+    pub rhs_only_keys: Vec<String>,
+}
+
+impl RecordComparison {
+    /// The maximum absolute difference over every parameter with matching shapes.
+    pub fn max_abs_diff(&self) -> f64 {
+        self.params
+            .iter()
+            .filter_map(|param| match param.diff {
+                ParamDiff::Values { max_abs_diff, .. } => Some(max_abs_diff),
+                _ => None,
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Whether both records have the same structure and every element is within the tolerance.
+    pub fn is_close(&self, tolerance: f64) -> bool {
+        self.lhs_only_keys.is_empty()
+            && self.rhs_only_keys.is_empty()
+            && self.params.iter().all(|param| match param.diff {
+                ParamDiff::Values { max_abs_diff, .. } => max_abs_diff <= tolerance,
+                _ => false,
+            })
+    }
+
+    fn compare(&mut self, lhs: RecordValue, rhs: RecordValue, path: &str) {
+        if lhs.is_param() && rhs.is_param() {
+            let (lhs, rhs) = (lhs.map_get("param"), rhs.map_get("param"));
+            if let (Some(lhs), Some(rhs)) = (lhs, rhs) {
+                self.compare(lhs.clone(), rhs.clone(), path);
+            }
+            return;
+        }
+
+        if lhs.is_tensor() && rhs.is_tensor() {
+            let diff = match (TensorData::deserialize(lhs), TensorData::deserialize(rhs)) {
+                (Ok(lhs), Ok(rhs)) => compare_data(&lhs, &rhs),
+                _ => ParamDiff::ValueMismatch,
+            };
+            self.params.push(ParamComparison {
+                key: path.to_string(),
+                diff,
+            });
+            return;
+        }
+
+        match (lhs, rhs) {
+            (RecordValue::Map(lhs), RecordValue::Map(mut rhs)) => {
+                for (key, value) in lhs {
+                    let key_path = join(path, &key.key_name());
+                    match rhs.iter().position(|(k, _)| k == &key) {
+                        Some(index) => self.compare(value, rhs.remove(index).1, &key_path),
+                        None => self.lhs_only_keys.push(key_path),
+                    }
+                }
+
+                for (key, _) in rhs {
+                    self.rhs_only_keys.push(join(path, &key.key_name()));
+                }
+            }
+            (RecordValue::Seq(lhs), RecordValue::Seq(rhs)) => {
+                let (num_lhs, num_rhs) = (lhs.len(), rhs.len());
+
+                for (index, (lhs, rhs)) in lhs.into_iter().zip(rhs).enumerate() {
+                    self.compare(lhs, rhs, &join(path, &index.to_string()));
+                }
+                for index in num_rhs..num_lhs {
+                    self.lhs_only_keys.push(join(path, &index.to_string()));
+                }
+                for index in num_lhs..num_rhs {
+                    self.rhs_only_keys.push(join(path, &index.to_string()));
+                }
+            }
+            (RecordValue::Unit, RecordValue::Unit) => {}
+            (RecordValue::Unit, _) => self.rhs_only_keys.push(path.to_string()),
+            (_, RecordValue::Unit) => self.lhs_only_keys.push(path.to_string()),
+            (lhs, rhs) => {
+                if lhs != rhs {
+                    self.params.push(ParamComparison {
+                        key: path.to_string(),
+                        diff: ParamDiff::ValueMismatch,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn compare_data(lhs: &TensorData, rhs: &TensorData) -> ParamDiff {
+    if lhs.dtype != rhs.dtype {
+        return ParamDiff::DTypeMismatch {
+            lhs: lhs.dtype,
+            rhs: rhs.dtype,
+        };
+    }
+
+    if lhs.shape != rhs.shape {
+        return ParamDiff::ShapeMismatch {
+            lhs: lhs.shape.clone(),
+            rhs: rhs.shape.clone(),
+        };
+    }
+
+    let (max_abs_diff, sum_abs_diff) = lhs
+        .iter::<f64>()
+        .zip(rhs.iter::<f64>())
+        .map(|(a, b)| (a - b).abs())
+        .fold((0.0, 0.0), |(max, sum), diff| {
+            (f64::max(max, diff), sum + diff)
+        });
+    let num_elements = lhs.num_elements().max(1);
+
+    ParamDiff::Values {
+        max_abs_diff,
+        mean_abs_diff: sum_abs_diff / num_elements as f64,
+    }
+}
+
+impl fmt::Display for RecordComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for param in self.params.iter() {
+            match &param.diff {
+                ParamDiff::Values {
+                    max_abs_diff,
+                    mean_abs_diff,
+                } => writeln!(
+                    f,
+                    "{}: max abs diff {max_abs_diff:e}, mean abs diff {mean_abs_diff:e}",
+                    param.key
+                )?,
+                ParamDiff::ShapeMismatch { lhs, rhs } => {
+                    writeln!(f, "{}: shape mismatch {lhs:?} != {rhs:?}", param.key)?
+                }
+                ParamDiff::DTypeMismatch { lhs, rhs } => {
+                    writeln!(f, "{}: dtype mismatch {lhs:?} != {rhs:?}", param.key)?
+                }
+                ParamDiff::ValueMismatch => writeln!(f, "{}: value mismatch", param.key)?,
+            }
+        }
+
+        for key in self.lhs_only_keys.iter() {
+            writeln!(f, "{key}: only in the first record")?;
+        }
+        for key in self.rhs_only_keys.iter() {
+            writeln!(f, "{key}: only in the second record")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Compare two records of the same type, reporting the difference of every parameter.
+///
+/// Tensors are compared with double precision. This is useful to verify that a model imported
+/// from another framework matches the original one, or to debug non-determinism between runs.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let comparison = compare_records(imported.into_record(), reference.into_record())?;
+/// println!("{comparison}");
+/// assert!(comparison.is_close(1e-5));
+/// ```
+pub fn compare_records<B, R>(lhs: R, rhs: R) -> Result<RecordComparison, RecorderError>
+where
+    B: Backend,
+    R: Record<B>,
+{
+    let lhs = RecordValue::from_item(&lhs.into_item::<DoublePrecisionSettings>())?;
+    let rhs = RecordValue::from_item(&rhs.into_item::<DoublePrecisionSettings>())?;
+
+    let mut comparison = RecordComparison::default();
+    comparison.compare(lhs, rhs, "");
+
+    Ok(comparison)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::{Module, Param};
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::Tensor;
+    use crate::TestBackend;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        layers: Vec<Linear<B>>,
+    }
+
+    fn model(sizes: &[usize], bias: bool) -> Model<TestBackend> {
+        let device = Default::default();
+        let layers = sizes
+            .windows(2)
+            .map(|w| LinearConfig::new(w[0], w[1]).with_bias(bias).init(&device))
+            .collect();
+
+        Model { layers }
+    }
+
+    #[test]
+    fn identical_records_have_no_difference() {
+        let model = model(&[4, 8, 2], true);
+        let comparison =
+            compare_records::<TestBackend, _>(model.clone().into_record(), model.into_record())
+                .unwrap();
+
+        assert_eq!(comparison.params.len(), 4);
+        assert_eq!(comparison.max_abs_diff(), 0.0);
+        assert!(comparison.is_close(0.0));
+    }
+
+    #[test]
+    fn should_report_max_and_mean_abs_diff() {
+        let lhs = model(&[2, 2], false);
+        let mut rhs = lhs.clone();
+        let weight = rhs.layers[0].weight.val();
+        rhs.layers[0].weight = Param::from_tensor(
+            weight + Tensor::from_floats([[0.5, 0.0], [0.0, -0.25]], &Default::default()),
+        );
+
+        let comparison =
+            compare_records::<TestBackend, _>(lhs.into_record(), rhs.into_record()).unwrap();
+
+        assert_eq!(comparison.params[0].key, "layers.0.weight");
+        match comparison.params[0].diff {
+            ParamDiff::Values {
+                max_abs_diff,
+                mean_abs_diff,
+            } => {
+                assert!((max_abs_diff - 0.5).abs() < 1e-6);
+                assert!((mean_abs_diff - 0.1875).abs() < 1e-6);
+            }
+            ref diff => panic!("Unexpected diff {diff:?}"),
+        }
+        assert!(comparison.is_close(0.6));
+        assert!(!comparison.is_close(0.4));
+    }
+
+    #[test]
+    fn should_report_structure_differences() {
+        let lhs = model(&[4, 8, 2], true);
+        let rhs = model(&[4, 6], false);
+
+        let comparison =
+            compare_records::<TestBackend, _>(lhs.into_record(), rhs.into_record()).unwrap();
+
+        assert_eq!(
+            comparison.params[0].diff,
+            ParamDiff::ShapeMismatch {
+                lhs: vec![4, 8],
+                rhs: vec![4, 6]
+            }
+        );
+        assert_eq!(
+            comparison.lhs_only_keys,
+            vec!["layers.0.bias".to_string(), "layers.1".to_string()]
+        );
+        assert!(comparison.rhs_only_keys.is_empty());
+        assert!(!comparison.is_close(f64::MAX));
+    }
+}
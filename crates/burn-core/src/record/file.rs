@@ -1,10 +1,15 @@
-use super::{bin_config, PrecisionSettings, Recorder, RecorderError};
+use super::mapped::MappedFile;
+use super::{
+    bin_config, parse_record_header, read_versioned, record_header, CodecWriter, PrecisionSettings,
+    RecordCodecs, RecordMigrations, Recorder, RecorderError, RECORD_FORMAT_VERSION,
+};
 use burn_tensor::backend::Backend;
 use core::marker::PhantomData;
 use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use serde::{de::DeserializeOwned, Serialize};
-use std::io::{BufReader, BufWriter};
-use std::{fs::File, path::PathBuf};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
 
 /// Recorder trait specialized to save and load data to and from files.
 pub trait FileRecorder<B: Backend>:
@@ -21,12 +26,20 @@ pub type DefaultFileRecorder<S> = NamedMpkFileRecorder<S>;
 #[derive(new, Debug, Default, Clone)]
 pub struct BinFileRecorder<S: PrecisionSettings> {
     _settings: PhantomData<S>,
+    #[new(default)]
+    migrations: RecordMigrations,
+    #[new(default)]
+    codecs: RecordCodecs,
 }
 
 /// File recorder using the [bincode format](bincode) compressed with gzip.
 #[derive(new, Debug, Default, Clone)]
 pub struct BinGzFileRecorder<S: PrecisionSettings> {
     _settings: PhantomData<S>,
+    #[new(default)]
+    migrations: RecordMigrations,
+    #[new(default)]
+    codecs: RecordCodecs,
 }
 
 /// File recorder using the [json format](serde_json) compressed with gzip.
@@ -45,14 +58,96 @@ pub struct PrettyJsonFileRecorder<S: PrecisionSettings> {
 #[derive(new, Debug, Default, Clone)]
 pub struct NamedMpkGzFileRecorder<S: PrecisionSettings> {
     _settings: PhantomData<S>,
+    #[new(default)]
+    migrations: RecordMigrations,
+    #[new(default)]
+    codecs: RecordCodecs,
 }
 
 /// File recorder using the [named msgpack](rmp_serde) format.
 #[derive(new, Debug, Default, Clone)]
 pub struct NamedMpkFileRecorder<S: PrecisionSettings> {
     _settings: PhantomData<S>,
+    #[new(default)]
+    migrations: RecordMigrations,
+    #[new(default)]
+    codecs: RecordCodecs,
+    #[new(default)]
+    memory_map: bool,
 }
 
+impl<S: PrecisionSettings> NamedMpkFileRecorder<S> {
+    /// Memory map the files when loading instead of reading them.
+    ///
+    /// The record is deserialized directly from the mapped pages, which are loaded on access and
+    /// shared through the page cache between the processes loading the same file. The values of
+    /// the tensors are viewed in the mapped pages instead of being copied when deserializing, and
+    /// each tensor is only copied when it's created on the device.
+    ///
+    /// Files saved with [codecs](RecordCodecs) can't be mapped, since their payload is encoded,
+    /// so loading them returns an error. Records saved with an older format version are migrated
+    /// in memory, and their tensors are copied when deserializing.
+    ///
+    /// # Notes
+    ///
+    /// The file must not be modified by another process while it is loaded.
+    pub fn with_memory_map(mut self, memory_map: bool) -> Self {
+        self.memory_map = memory_map;
+        self
+    }
+
+    fn load_mapped<I: DeserializeOwned>(&self, path: &Path) -> Result<I, RecorderError> {
+        if !self.codecs.is_empty() {
+            return Err(RecorderError::Unknown(
+                "Files encoded with codecs can't be memory mapped, disable the memory map to load \
+                 them."
+                    .to_string(),
+            ));
+        }
+
+        // The file stays registered while the item is deserialized, so that its tensors view the
+        // mapped pages.
+        let file = MappedFile::open(path)?;
+        let bytes = file.bytes();
+        let (version, offset) = parse_record_header(bytes);
+
+        let decode = |bytes: &[u8]| {
+            rmp_serde::from_slice(bytes).map_err(|err| RecorderError::Unknown(err.to_string()))
+        };
+
+        if version == RECORD_FORMAT_VERSION {
+            decode(&bytes[offset..])
+        } else {
+            decode(&self.migrations.migrate(version, bytes[offset..].to_vec())?)
+        }
+    }
+}
+
+macro_rules! impl_recorder_options {
+    ($recorder:ident) => {
+        impl<S: PrecisionSettings> $recorder<S> {
+            /// Use the given [migrations](RecordMigrations) to upgrade records saved with an older
+            /// format version when loading.
+            pub fn with_migrations(mut self, migrations: RecordMigrations) -> Self {
+                self.migrations = migrations;
+                self
+            }
+
+            /// Use the given [codecs](RecordCodecs) to encode the files, e.g. to compress or
+            /// encrypt them. Records must be loaded with the same codecs they were saved with.
+            pub fn with_codecs(mut self, codecs: RecordCodecs) -> Self {
+                self.codecs = codecs;
+                self
+            }
+        }
+    };
+}
+
+impl_recorder_options!(BinFileRecorder);
+impl_recorder_options!(BinGzFileRecorder);
+impl_recorder_options!(NamedMpkGzFileRecorder);
+impl_recorder_options!(NamedMpkFileRecorder);
+
 impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for BinGzFileRecorder<S> {
     fn file_extension() -> &'static str {
         "bin.gz"
@@ -128,6 +223,14 @@ macro_rules! str2writer {
     }};
 }
 
+/// Finish the gzip stream, then the codecs.
+fn finish_gz(writer: GzEncoder<Box<dyn CodecWriter + '_>>) -> Result<(), RecorderError> {
+    writer
+        .finish()
+        .map_err(|err| RecorderError::Unknown(err.to_string()))?
+        .finish()
+}
+
 impl<S: PrecisionSettings, B: Backend> Recorder<B> for BinGzFileRecorder<S> {
     type Settings = S;
     type RecordArgs = PathBuf;
@@ -140,22 +243,26 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for BinGzFileRecorder<S> {
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
         let config = bin_config();
-        let writer = str2writer!(file)?;
+        let writer = self.codecs.encoder(str2writer!(file)?)?;
         let mut writer = GzEncoder::new(writer, Compression::default());
+        writer
+            .write_all(&record_header())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
         bincode::serde::encode_into_std_write(&item, &mut writer, config)
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
-        Ok(())
+        finish_gz(writer)
     }
 
     fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
-        let reader = str2reader!(file)?;
-        let mut reader = GzDecoder::new(reader);
-        let state = bincode::serde::decode_from_std_read(&mut reader, bin_config())
-            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let reader = self.codecs.decoder(str2reader!(file)?)?;
+        let reader = BufReader::new(GzDecoder::new(reader));
 
-        Ok(state)
+        read_versioned(reader, &self.migrations, |mut reader| {
+            bincode::serde::decode_from_std_read(&mut reader, bin_config())
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
     }
 }
 
@@ -171,17 +278,23 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for BinFileRecorder<S> {
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
         let config = bin_config();
-        let mut writer = str2writer!(file)?;
+        let mut writer = self.codecs.encoder(str2writer!(file)?)?;
+        writer
+            .write_all(&record_header())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
         bincode::serde::encode_into_std_write(&item, &mut writer, config)
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
-        Ok(())
+
+        writer.finish()
     }
 
     fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
-        let mut reader = str2reader!(file)?;
-        let state = bincode::serde::decode_from_std_read(&mut reader, bin_config())
-            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
-        Ok(state)
+        let reader = self.codecs.decoder(str2reader!(file)?)?;
+
+        read_versioned(reader, &self.migrations, |mut reader| {
+            bincode::serde::decode_from_std_read(&mut reader, bin_config())
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
     }
 }
 
@@ -251,21 +364,25 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkGzFileRecorder<S>
         item: I,
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
-        let writer = str2writer!(file)?;
+        let writer = self.codecs.encoder(str2writer!(file)?)?;
         let mut writer = GzEncoder::new(writer, Compression::default());
+        writer
+            .write_all(&record_header())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
         rmp_serde::encode::write_named(&mut writer, &item)
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
-        Ok(())
+        finish_gz(writer)
     }
 
     fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
-        let reader = str2reader!(file)?;
-        let reader = GzDecoder::new(reader);
-        let state = rmp_serde::decode::from_read(reader)
-            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let reader = self.codecs.decoder(str2reader!(file)?)?;
+        let reader = BufReader::new(GzDecoder::new(reader));
 
-        Ok(state)
+        read_versioned(reader, &self.migrations, |reader| {
+            rmp_serde::decode::from_read(reader)
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
     }
 }
 
@@ -280,20 +397,29 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkFileRecorder<S> {
         item: I,
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
-        let mut writer = str2writer!(file)?;
+        let mut writer = self.codecs.encoder(str2writer!(file)?)?;
+        writer
+            .write_all(&record_header())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
         rmp_serde::encode::write_named(&mut writer, &item)
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
-        Ok(())
+        writer.finish()
     }
 
     fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
-        let reader = str2reader!(file)?;
-        let state = rmp_serde::decode::from_read(reader)
-            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        if self.memory_map {
+            file.set_extension(<Self as FileRecorder<B>>::file_extension());
+            return self.load_mapped(&file);
+        }
 
-        Ok(state)
+        let reader = self.codecs.decoder(str2reader!(file)?)?;
+
+        read_versioned(reader, &self.migrations, |reader| {
+            rmp_serde::decode::from_read(reader)
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
     }
 }
 
@@ -301,6 +427,8 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkFileRecorder<S> {
 mod tests {
 
     use burn_tensor::backend::Backend;
+    use burn_tensor::quantization::{QuantizationScheme, QuantizationType, QuantizedTensorData};
+    use burn_tensor::Tensor;
 
     use super::*;
     use crate::{
@@ -309,7 +437,7 @@ mod tests {
             conv::{Conv2d, Conv2dConfig},
             Linear, LinearConfig,
         },
-        record::{BinBytesRecorder, FullPrecisionSettings},
+        record::{BinBytesRecorder, FullPrecisionSettings, HalfPrecisionSettings, Record},
         TestBackend,
     };
 
@@ -352,6 +480,38 @@ mod tests {
         test_can_save_and_load(NamedMpkFileRecorder::<FullPrecisionSettings>::default())
     }
 
+    #[test]
+    fn test_can_load_mpk_format_memory_mapped() {
+        let device = Default::default();
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("model");
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::default();
+        let model_before = create_model(&device);
+        recorder
+            .record(model_before.clone().into_record(), path.clone())
+            .unwrap();
+
+        let recorder = recorder.with_memory_map(true);
+        let model_after =
+            create_model(&device).load_record(recorder.load(path.clone(), &device).unwrap());
+
+        let byte_recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let model_bytes_before = byte_recorder
+            .record(model_before.into_record(), ())
+            .unwrap();
+        let model_bytes_after = byte_recorder.record(model_after.into_record(), ()).unwrap();
+
+        assert_eq!(model_bytes_after, model_bytes_before);
+        assert!(matches!(
+            Recorder::<TestBackend>::load::<Vec<f32>>(
+                &recorder,
+                temp_dir.path().join("missing"),
+                &device
+            ),
+            Err(RecorderError::FileNotFound(_))
+        ));
+    }
+
     fn test_can_save_and_load<Recorder>(recorder: Recorder)
     where
         Recorder: FileRecorder<TestBackend>,
@@ -374,6 +534,63 @@ mod tests {
         assert_eq!(model_bytes_after, model_bytes_before);
     }
 
+    #[test]
+    fn test_can_save_and_load_quantized_record() {
+        test_can_save_and_load_quantized(BinFileRecorder::<FullPrecisionSettings>::default());
+        test_can_save_and_load_quantized(BinGzFileRecorder::<FullPrecisionSettings>::default());
+        test_can_save_and_load_quantized(JsonGzFileRecorder::<FullPrecisionSettings>::default());
+        test_can_save_and_load_quantized(PrettyJsonFileRecorder::<FullPrecisionSettings>::default());
+        test_can_save_and_load_quantized(NamedMpkGzFileRecorder::<FullPrecisionSettings>::default());
+        test_can_save_and_load_quantized(NamedMpkFileRecorder::<HalfPrecisionSettings>::default());
+    }
+
+    fn test_can_save_and_load_quantized<Recorder>(recorder: Recorder)
+    where
+        Recorder: FileRecorder<TestBackend>,
+    {
+        let device = Default::default();
+        let record = create_quantized_record(&device);
+
+        recorder.record(record.clone(), file_path()).unwrap();
+        let loaded: QuantizedRecord<TestBackend> = recorder.load(file_path(), &device).unwrap();
+
+        assert_eq!(loaded.weight, record.weight);
+        assert_eq!(loaded.bias, record.bias);
+        loaded
+            .norm
+            .into_data()
+            .assert_eq(&record.norm.into_data(), true);
+    }
+
+    #[derive(Record, Debug, Clone)]
+    pub struct QuantizedRecord<B: Backend> {
+        weight: QuantizedTensorData,
+        bias: QuantizedTensorData,
+        norm: Tensor<B, 1>,
+    }
+
+    pub fn create_quantized_record(
+        device: &<TestBackend as Backend>::Device,
+    ) -> QuantizedRecord<TestBackend> {
+        let linear = LinearConfig::new(8, 5)
+            .with_bias(true)
+            .init::<TestBackend>(device);
+        let weight = QuantizedTensorData::quantize(
+            &linear.weight.val().into_data(),
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        );
+        let bias = QuantizedTensorData::quantize(
+            &linear.bias.unwrap().val().into_data(),
+            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4),
+        );
+
+        QuantizedRecord {
+            weight,
+            bias,
+            norm: Tensor::from_floats([1.0, 2.0, 3.0], device),
+        }
+    }
+
     #[derive(Module, Debug)]
     pub struct Model<B: Backend> {
         conv2d1: Conv2d<B>,
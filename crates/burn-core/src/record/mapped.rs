@@ -0,0 +1,78 @@
+use super::RecorderError;
+use core::ops::Range;
+use memmap2::Mmap;
+use std::fs::File;
+use std::path::Path;
+use std::sync::{Arc, Mutex, Weak};
+
+/// The files being deserialized from their mapped pages.
+///
+/// The registry is shared by all threads, since a record can be deserialized on another thread
+/// than the one that mapped its file.
+static MAPPED_FILES: Mutex<Vec<Weak<Mmap>>> = Mutex::new(Vec::new());
+
+/// A memory-mapped record file, registered while it's deserialized so that the tensor values read
+/// from its pages are [viewed](MappedBytes) instead of copied.
+pub(crate) struct MappedFile {
+    map: Arc<Mmap>,
+}
+
+impl MappedFile {
+    /// Map the file at the given path.
+    pub(crate) fn open(path: &Path) -> Result<Self, RecorderError> {
+        let file = File::open(path).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+            _ => RecorderError::Unknown(err.to_string()),
+        })?;
+        // SAFETY: The file is only read, and must not be modified while the record is loaded.
+        let map =
+            unsafe { Mmap::map(&file) }.map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let map = Arc::new(map);
+
+        MAPPED_FILES.lock().unwrap().push(Arc::downgrade(&map));
+
+        Ok(Self { map })
+    }
+
+    /// The mapped bytes of the file.
+    pub(crate) fn bytes(&self) -> &[u8] {
+        &self.map
+    }
+}
+
+impl Drop for MappedFile {
+    fn drop(&mut self) {
+        // The views created while deserializing keep the pages mapped after the file is dropped.
+        MAPPED_FILES
+            .lock()
+            .unwrap()
+            .retain(|map| map.strong_count() > 0 && !core::ptr::eq(map.as_ptr(), &*self.map));
+    }
+}
+
+/// Bytes viewed in the pages of a memory-mapped record file.
+#[derive(Clone)]
+pub(crate) struct MappedBytes {
+    map: Arc<Mmap>,
+    range: Range<usize>,
+}
+
+impl MappedBytes {
+    /// View the given bytes when they're borrowed from a file being deserialized.
+    pub(crate) fn find(bytes: &[u8]) -> Option<Self> {
+        let start = bytes.as_ptr() as usize;
+        let files = MAPPED_FILES.lock().unwrap();
+
+        files.iter().filter_map(Weak::upgrade).find_map(|map| {
+            let offset = start.checked_sub(map.as_ptr() as usize)?;
+            let range = offset..offset + bytes.len();
+
+            (range.end <= map.len()).then_some(Self { map, range })
+        })
+    }
+
+    /// The viewed bytes.
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.map[self.range.clone()]
+    }
+}
@@ -1,4 +1,7 @@
-use super::{bin_config, PrecisionSettings, Recorder, RecorderError};
+use super::{
+    bin_config, parse_record_header, record_header, PrecisionSettings, RecordMigrations, Recorder,
+    RecorderError,
+};
 use alloc::vec::Vec;
 use burn_tensor::backend::Backend;
 use serde::{de::DeserializeOwned, Serialize};
@@ -18,6 +21,17 @@ pub trait BytesRecorder<B: Backend>:
 #[derive(new, Debug, Default, Clone)]
 pub struct BinBytesRecorder<S: PrecisionSettings> {
     _settings: core::marker::PhantomData<S>,
+    #[new(default)]
+    migrations: RecordMigrations,
+}
+
+impl<S: PrecisionSettings> BinBytesRecorder<S> {
+    /// Use the given [migrations](RecordMigrations) to upgrade records saved with an older
+    /// format version when loading.
+    pub fn with_migrations(mut self, migrations: RecordMigrations) -> Self {
+        self.migrations = migrations;
+        self
+    }
 }
 
 impl<S: PrecisionSettings, B: Backend> BytesRecorder<B> for BinBytesRecorder<S> {}
@@ -33,10 +47,13 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for BinBytesRecorder<S> {
         item: I,
         _args: Self::RecordArgs,
     ) -> Result<Self::RecordOutput, RecorderError> {
-        Ok(bincode::serde::encode_to_vec(item, bin_config()).unwrap())
+        let mut bytes = record_header().to_vec();
+        bytes.extend(bincode::serde::encode_to_vec(item, bin_config()).unwrap());
+        Ok(bytes)
     }
     fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
-        let state = bincode::serde::decode_borrowed_from_slice(&args, bin_config()).unwrap();
+        let bytes = load_versioned_bytes(args, &self.migrations)?;
+        let state = bincode::serde::decode_borrowed_from_slice(&bytes, bin_config()).unwrap();
         Ok(state)
     }
 }
@@ -46,6 +63,18 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for BinBytesRecorder<S> {
 #[derive(new, Debug, Default, Clone)]
 pub struct NamedMpkBytesRecorder<S: PrecisionSettings> {
     _settings: core::marker::PhantomData<S>,
+    #[new(default)]
+    migrations: RecordMigrations,
+}
+
+#[cfg(feature = "std")]
+impl<S: PrecisionSettings> NamedMpkBytesRecorder<S> {
+    /// Use the given [migrations](RecordMigrations) to upgrade records saved with an older
+    /// format version when loading.
+    pub fn with_migrations(mut self, migrations: RecordMigrations) -> Self {
+        self.migrations = migrations;
+        self
+    }
 }
 
 #[cfg(feature = "std")]
@@ -63,18 +92,45 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkBytesRecorder<S>
         item: I,
         _args: Self::RecordArgs,
     ) -> Result<Self::RecordOutput, RecorderError> {
-        rmp_serde::encode::to_vec_named(&item).map_err(|e| RecorderError::Unknown(e.to_string()))
+        let mut bytes = record_header().to_vec();
+        rmp_serde::encode::write_named(&mut bytes, &item)
+            .map_err(|e| RecorderError::Unknown(e.to_string()))?;
+        Ok(bytes)
     }
     fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
-        rmp_serde::decode::from_slice(&args).map_err(|e| RecorderError::Unknown(e.to_string()))
+        let bytes = load_versioned_bytes(args, &self.migrations)?;
+        rmp_serde::decode::from_slice(&bytes).map_err(|e| RecorderError::Unknown(e.to_string()))
+    }
+}
+
+/// Strip the header of the record, migrating it if it was saved with an older format version.
+fn load_versioned_bytes(
+    mut bytes: Vec<u8>,
+    migrations: &RecordMigrations,
+) -> Result<Vec<u8>, RecorderError> {
+    let (version, header_size) = parse_record_header(&bytes);
+    bytes.drain(..header_size);
+
+    if version == super::RECORD_FORMAT_VERSION {
+        return Ok(bytes);
     }
+
+    migrations.migrate(version, bytes)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        module::Module, nn, record::FullPrecisionSettings, tensor::backend::Backend, TestBackend,
+        module::Module,
+        nn,
+        record::FullPrecisionSettings,
+        tensor::{
+            backend::Backend,
+            quantization::{QuantizationScheme, QuantizationType, QuantizedTensorData},
+            TensorData,
+        },
+        TestBackend,
     };
 
     #[test]
@@ -105,6 +161,29 @@ mod tests {
         assert_eq!(bytes1, bytes2_after);
     }
 
+    #[test]
+    fn test_can_save_and_load_quantized_data() {
+        test_can_save_and_load_quantized(BinBytesRecorder::<FullPrecisionSettings>::default());
+        #[cfg(feature = "std")]
+        test_can_save_and_load_quantized(NamedMpkBytesRecorder::<FullPrecisionSettings>::default());
+    }
+
+    fn test_can_save_and_load_quantized<Recorder>(recorder: Recorder)
+    where
+        Recorder: BytesRecorder<TestBackend>,
+    {
+        let data = TensorData::from([[-1.0f32, 0.25, 0.5], [0.75, 1.0, 2.0]]);
+        let quantized = QuantizedTensorData::quantize(
+            &data,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt4),
+        );
+
+        let bytes = recorder.record(quantized.clone(), ()).unwrap();
+        let loaded: QuantizedTensorData = recorder.load(bytes, &Default::default()).unwrap();
+
+        assert_eq!(loaded, quantized);
+    }
+
     pub fn create_model<B: Backend>(device: &B::Device) -> nn::Linear<B> {
         nn::LinearConfig::new(32, 32).with_bias(true).init(device)
     }
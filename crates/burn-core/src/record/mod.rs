@@ -5,17 +5,42 @@ mod base;
 mod memory;
 mod recorder;
 mod settings;
+mod version;
 
 pub use base::*;
 pub use memory::*;
 pub use recorder::*;
 pub use settings::*;
+pub use version::*;
+
+#[cfg(feature = "std")]
+mod mapped;
 
 #[cfg(feature = "std")]
 mod file;
 #[cfg(feature = "std")]
 pub use file::*;
 
+#[cfg(feature = "std")]
+mod codec;
+#[cfg(feature = "std")]
+pub use codec::*;
+
+#[cfg(feature = "std")]
+mod compare;
+#[cfg(feature = "std")]
+pub use compare::*;
+
+#[cfg(feature = "std")]
+mod partial;
+#[cfg(feature = "std")]
+pub use partial::*;
+
+#[cfg(feature = "std")]
+mod sharded;
+#[cfg(feature = "std")]
+pub use sharded::*;
+
 pub use primitive::ParamSerde;
 
 #[cfg(feature = "record-item-custom-serde")]
@@ -0,0 +1,679 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{
+    self, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
+};
+use serde::{forward_to_deserialize_any, Deserialize, Serialize};
+
+use super::RecorderError;
+
+/// Report produced when loading a record partially.
+///
+/// Keys are the paths of the parameters in the record, with fields separated by dots and
+/// sequence elements identified by their index (e.g. `layers.0.linear.weight`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoadReport {
+    /// Keys expected by the record that were not found in the loaded data. Their initial values
+    /// are kept.
+    pub missing_keys: Vec<String>,
+
+    /// Keys found in the loaded data that are not part of the record. They are ignored.
+    pub unexpected_keys: Vec<String>,
+
+    /// Keys found in both, but with an incompatible shape or type. Their initial values are kept.
+    pub mismatched_keys: Vec<String>,
+}
+
+impl LoadReport {
+    /// Whether every key of the record was loaded and no key was ignored.
+    pub fn is_complete(&self) -> bool {
+        self.missing_keys.is_empty()
+            && self.unexpected_keys.is_empty()
+            && self.mismatched_keys.is_empty()
+    }
+}
+
+impl fmt::Display for LoadReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut write_keys = |name: &str, keys: &[String]| -> fmt::Result {
+            if !keys.is_empty() {
+                writeln!(f, "{name} keys ({}):", keys.len())?;
+                for key in keys {
+                    writeln!(f, "  - {key}")?;
+                }
+            }
+            Ok(())
+        };
+
+        write_keys("Missing", &self.missing_keys)?;
+        write_keys("Unexpected", &self.unexpected_keys)?;
+        write_keys("Mismatched", &self.mismatched_keys)
+    }
+}
+
+/// Self-describing representation of a serialized record item.
+///
+/// Sequences of bytes, which is how tensor data is serialized, are stored compactly.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RecordValue {
+    Unit,
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Seq(Vec<RecordValue>),
+    Map(Vec<(RecordValue, RecordValue)>),
+}
+
+impl RecordValue {
+    /// Serialize the item into its self-describing representation.
+    pub(crate) fn from_item<I: Serialize>(item: &I) -> Result<Self, RecorderError> {
+        let bytes =
+            rmp_serde::to_vec_named(item).map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        rmp_serde::from_slice(&bytes).map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    pub(crate) fn map_get(&self, key: &str) -> Option<&RecordValue> {
+        match self {
+            RecordValue::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| matches!(k, RecordValue::String(k) if k == key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn has_keys(&self, keys: &[&str]) -> bool {
+        match self {
+            RecordValue::Map(entries) => {
+                entries.len() == keys.len() && keys.iter().all(|key| self.map_get(key).is_some())
+            }
+            _ => false,
+        }
+    }
+
+    /// Serialized [tensor data](burn_tensor::TensorData).
+    pub(crate) fn is_tensor(&self) -> bool {
+        self.has_keys(&["value", "shape", "dtype"])
+    }
+
+    /// Serialized [parameter](crate::record::ParamSerde).
+    pub(crate) fn is_param(&self) -> bool {
+        self.has_keys(&["id", "param"])
+    }
+
+    pub(crate) fn key_name(&self) -> String {
+        match self {
+            RecordValue::String(key) => key.clone(),
+            RecordValue::U64(key) => key.to_string(),
+            RecordValue::I64(key) => key.to_string(),
+            other => format!("{other:?}"),
+        }
+    }
+
+    /// Whether both values can be deserialized into the same type.
+    fn is_compatible(&self, other: &RecordValue) -> bool {
+        use RecordValue::*;
+
+        if self.is_tensor() || other.is_tensor() {
+            return self.is_tensor()
+                && other.is_tensor()
+                && self.map_get("shape") == other.map_get("shape");
+        }
+
+        matches!(
+            (self, other),
+            (Unit, Unit)
+                | (Bool(_), Bool(_))
+                | (String(_), String(_))
+                | (I64(_) | U64(_), I64(_) | U64(_))
+                | (F32(_) | F64(_), F32(_) | F64(_))
+                | (Bytes(_) | Seq(_), Bytes(_) | Seq(_))
+                | (Map(_), Map(_))
+        )
+    }
+}
+
+pub(crate) fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// Merge the loaded value into the expected one, recording the differences in the report.
+///
+/// Every value of `expected` found with a compatible structure in `loaded` is replaced, the rest
+/// is kept as is.
+pub(crate) fn merge_partial(
+    expected: RecordValue,
+    loaded: RecordValue,
+    path: &str,
+    report: &mut LoadReport,
+) -> RecordValue {
+    if expected.is_param() || expected.is_tensor() {
+        if loaded == RecordValue::Unit {
+            report.missing_keys.push(path.to_string());
+            return expected;
+        }
+
+        let compatible = match expected.is_param() {
+            true => {
+                loaded.is_param()
+                    && expected
+                        .map_get("param")
+                        .zip(loaded.map_get("param"))
+                        .is_some_and(|(expected, loaded)| expected.is_compatible(loaded))
+            }
+            false => expected.is_compatible(&loaded),
+        };
+
+        if compatible {
+            return loaded;
+        }
+
+        report.mismatched_keys.push(path.to_string());
+        return expected;
+    }
+
+    match (expected, loaded) {
+        (RecordValue::Map(expected), RecordValue::Map(mut loaded)) => {
+            let mut merged = Vec::with_capacity(expected.len());
+
+            for (key, value) in expected {
+                let key_path = join(path, &key.key_name());
+                match loaded.iter().position(|(k, _)| k == &key) {
+                    Some(index) => {
+                        let (_, loaded_value) = loaded.remove(index);
+                        merged.push((key, merge_partial(value, loaded_value, &key_path, report)));
+                    }
+                    None => {
+                        report.missing_keys.push(key_path);
+                        merged.push((key, value));
+                    }
+                }
+            }
+
+            for (key, _) in loaded {
+                report.unexpected_keys.push(join(path, &key.key_name()));
+            }
+
+            RecordValue::Map(merged)
+        }
+        (RecordValue::Seq(expected), RecordValue::Seq(loaded))
+            if expected.iter().chain(loaded.iter()).any(|v| {
+                matches!(
+                    v,
+                    RecordValue::Map(_) | RecordValue::Seq(_) | RecordValue::Unit
+                )
+            }) =>
+        {
+            let num_loaded = loaded.len();
+            let num_expected = expected.len();
+            let mut loaded = loaded.into_iter();
+            let mut merged = Vec::with_capacity(num_expected);
+
+            for (index, value) in expected.into_iter().enumerate() {
+                let key_path = join(path, &index.to_string());
+                match loaded.next() {
+                    Some(loaded_value) => {
+                        merged.push(merge_partial(value, loaded_value, &key_path, report))
+                    }
+                    None => {
+                        report.missing_keys.push(key_path);
+                        merged.push(value);
+                    }
+                }
+            }
+
+            for index in num_expected..num_loaded {
+                report.unexpected_keys.push(join(path, &index.to_string()));
+            }
+
+            RecordValue::Seq(merged)
+        }
+        // Optional values that are absent on one side.
+        (expected @ RecordValue::Unit, loaded) if !matches!(loaded, RecordValue::Unit) => {
+            report.unexpected_keys.push(path.to_string());
+            expected
+        }
+        (expected, RecordValue::Unit) if !matches!(expected, RecordValue::Unit) => {
+            report.missing_keys.push(path.to_string());
+            expected
+        }
+        (expected, loaded) => {
+            if expected.is_compatible(&loaded) {
+                loaded
+            } else {
+                report.mismatched_keys.push(path.to_string());
+                expected
+            }
+        }
+    }
+}
+
+// --- DESERIALIZE IMPLEMENTATION --- //
+
+impl<'de> Deserialize<'de> for RecordValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(RecordValueVisitor)
+    }
+}
+
+struct RecordValueVisitor;
+
+impl<'de> Visitor<'de> for RecordValueVisitor {
+    type Value = RecordValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a self-describing record value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(RecordValue::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(RecordValue::I64(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(RecordValue::U64(v))
+    }
+
+    fn visit_f32<E: de::Error>(self, v: f32) -> Result<Self::Value, E> {
+        Ok(RecordValue::F32(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(RecordValue::F64(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(RecordValue::String(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(RecordValue::String(v))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(RecordValue::Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(RecordValue::Bytes(v))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(RecordValue::Unit)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(RecordValue::Unit)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        RecordValue::deserialize(deserializer)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        RecordValue::deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Tensor data is serialized as a sequence of bytes, which is kept compact until an
+        // element that isn't a byte is found.
+        let mut bytes = Vec::new();
+        let mut values = Vec::new();
+
+        while let Some(value) = seq.next_element::<RecordValue>()? {
+            match value {
+                RecordValue::U64(byte) if values.is_empty() && byte <= u8::MAX as u64 => {
+                    bytes.push(byte as u8)
+                }
+                value => {
+                    if values.is_empty() {
+                        values.extend(bytes.drain(..).map(|b| RecordValue::U64(b as u64)));
+                    }
+                    values.push(value);
+                }
+            }
+        }
+
+        if values.is_empty() && !bytes.is_empty() {
+            return Ok(RecordValue::Bytes(bytes));
+        }
+
+        Ok(RecordValue::Seq(values))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::new();
+
+        while let Some(entry) = map.next_entry::<RecordValue, RecordValue>()? {
+            entries.push(entry);
+        }
+
+        Ok(RecordValue::Map(entries))
+    }
+}
+
+// --- DESERIALIZER IMPLEMENTATION --- //
+
+/// Error when deserializing a [record value](RecordValue) into a record item.
+#[derive(Debug)]
+pub(crate) struct RecordValueError(String);
+
+impl fmt::Display for RecordValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl de::Error for RecordValueError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        RecordValueError(msg.to_string())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecordValueError {}
+
+#[cfg(not(feature = "std"))]
+impl de::StdError for RecordValueError {}
+
+impl<'de> IntoDeserializer<'de, RecordValueError> for RecordValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for RecordValue {
+    type Error = RecordValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            RecordValue::Unit => visitor.visit_unit(),
+            RecordValue::Bool(v) => visitor.visit_bool(v),
+            RecordValue::I64(v) => visitor.visit_i64(v),
+            RecordValue::U64(v) => visitor.visit_u64(v),
+            RecordValue::F32(v) => visitor.visit_f32(v),
+            RecordValue::F64(v) => visitor.visit_f64(v),
+            RecordValue::String(v) => visitor.visit_string(v),
+            RecordValue::Bytes(v) => {
+                visitor.visit_seq(de::value::SeqDeserializer::new(v.into_iter()))
+            }
+            RecordValue::Seq(v) => {
+                visitor.visit_seq(de::value::SeqDeserializer::new(v.into_iter()))
+            }
+            RecordValue::Map(v) => {
+                visitor.visit_map(de::value::MapDeserializer::new(v.into_iter()))
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            RecordValue::Unit => visitor.visit_none(),
+            value => visitor.visit_some(value),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self {
+            RecordValue::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            RecordValue::Map(mut entries) if entries.len() == 1 => {
+                let (variant, value) = entries.remove(0);
+                visitor.visit_enum(RecordEnumAccess { variant, value })
+            }
+            other => Err(de::Error::custom(format!(
+                "Expected an enum variant, got {other:?}"
+            ))),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct RecordEnumAccess {
+    variant: RecordValue,
+    value: RecordValue,
+}
+
+impl<'de> EnumAccess<'de> for RecordEnumAccess {
+    type Error = RecordValueError;
+    type Variant = RecordVariantAccess;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(self.variant)?;
+        Ok((variant, RecordVariantAccess(Box::new(self.value))))
+    }
+}
+
+struct RecordVariantAccess(Box<RecordValue>);
+
+impl<'de> VariantAccess<'de> for RecordVariantAccess {
+    type Error = RecordValueError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(*self.0)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        serde::Deserializer::deserialize_any(*self.0, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        serde::Deserializer::deserialize_any(*self.0, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::Module;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::record::{
+        BinFileRecorder, FullPrecisionSettings, NamedMpkFileRecorder, PrettyJsonFileRecorder,
+        RecorderError,
+    };
+    use crate::tensor::backend::Backend;
+    use crate::TestBackend;
+    use tempfile::TempDir;
+
+    #[derive(Module, Debug)]
+    struct Source<B: Backend> {
+        linear1: Linear<B>,
+        linear2: Linear<B>,
+        extra: Linear<B>,
+    }
+
+    #[derive(Module, Debug)]
+    struct Target<B: Backend> {
+        linear1: Linear<B>,
+        linear2: Linear<B>,
+        head: Linear<B>,
+    }
+
+    fn source(device: &<TestBackend as Backend>::Device) -> Source<TestBackend> {
+        Source {
+            linear1: LinearConfig::new(4, 8).init(device),
+            linear2: LinearConfig::new(8, 8).init(device),
+            extra: LinearConfig::new(8, 2).init(device),
+        }
+    }
+
+    fn target(device: &<TestBackend as Backend>::Device) -> Target<TestBackend> {
+        Target {
+            linear1: LinearConfig::new(4, 8).init(device),
+            linear2: LinearConfig::new(8, 16).init(device),
+            head: LinearConfig::new(16, 2).init(device),
+        }
+    }
+
+    fn assert_partial_load<FR>(recorder: FR)
+    where
+        FR: crate::record::FileRecorder<TestBackend>,
+    {
+        let device = Default::default();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("model");
+        let source = source(&device);
+        let target = target(&device);
+        let linear2_weight = target.linear2.weight.val().into_data();
+        let linear1_weight = source.linear1.weight.val().into_data();
+
+        source.save_file(path.clone(), &recorder).unwrap();
+        let (model, report) = target.load_file_partial(path, &recorder, &device).unwrap();
+
+        assert_eq!(report.missing_keys, vec!["head".to_string()]);
+        assert_eq!(report.unexpected_keys, vec!["extra".to_string()]);
+        assert_eq!(
+            report.mismatched_keys,
+            vec!["linear2.weight".to_string(), "linear2.bias".to_string()]
+        );
+        assert!(!report.is_complete());
+        model
+            .linear1
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&linear1_weight, true);
+        model
+            .linear2
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&linear2_weight, true);
+    }
+
+    #[test]
+    fn load_partial_named_mpk() {
+        assert_partial_load(NamedMpkFileRecorder::<FullPrecisionSettings>::new());
+    }
+
+    #[test]
+    fn load_partial_json() {
+        assert_partial_load(PrettyJsonFileRecorder::<FullPrecisionSettings>::new());
+    }
+
+    #[test]
+    fn load_partial_complete() {
+        let device = Default::default();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("model");
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::new();
+        let source = source(&device);
+        let bias = source.linear2.bias.as_ref().unwrap().val().into_data();
+
+        source.save_file(path.clone(), &recorder).unwrap();
+        let (model, report) = self::source(&device)
+            .load_file_partial(path, &recorder, &device)
+            .unwrap();
+
+        assert!(report.is_complete());
+        model
+            .linear2
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&bias, true);
+    }
+
+    #[test]
+    fn load_partial_missing_bias() {
+        let device = Default::default();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("model");
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::new();
+
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 8).with_bias(false).init(&device);
+        linear.save_file(path.clone(), &recorder).unwrap();
+        let (_, report) = LinearConfig::new(4, 8)
+            .init::<TestBackend>(&device)
+            .load_file_partial(path, &recorder, &device)
+            .unwrap();
+
+        assert_eq!(report.missing_keys, vec!["bias".to_string()]);
+        assert!(report.unexpected_keys.is_empty());
+        assert!(report.mismatched_keys.is_empty());
+    }
+
+    #[test]
+    fn load_partial_bin_is_an_error() {
+        let device = Default::default();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("model");
+        let recorder = BinFileRecorder::<FullPrecisionSettings>::new();
+
+        source(&device).save_file(path.clone(), &recorder).unwrap();
+        let result = target(&device).load_file_partial(path, &recorder, &device);
+
+        assert!(matches!(result, Err(RecorderError::DeserializeError(_))));
+    }
+}
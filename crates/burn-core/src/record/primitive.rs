@@ -270,6 +270,9 @@ primitive!(i32);
 primitive!(i16);
 primitive!(i8);
 
+// Quantized data is already packed, it is saved as is regardless of the precision settings.
+primitive!(burn_tensor::quantization::QuantizedTensorData);
+
 /// A wrapper around an array of size N, so that it can be serialized and deserialized
 /// using serde.
 ///
@@ -8,6 +8,9 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use super::{BinBytesRecorder, FullPrecisionSettings, PrecisionSettings, Record};
 
+#[cfg(feature = "std")]
+use super::{merge_partial, LoadReport, RecordValue};
+
 #[cfg(feature = "std")]
 use super::{
     BinFileRecorder, BinGzFileRecorder, DefaultFileRecorder, HalfPrecisionSettings,
@@ -32,6 +35,10 @@ pub trait Recorder<B: Backend>:
 
     /// Records an item.
     ///
+    /// Tensors are only read from their device when the item is serialized, one at a time, so
+    /// recorders writing to a file stream each tensor to disk without holding a host copy of
+    /// the whole record in memory.
+    ///
     /// # Arguments
     ///
     /// * `record` - The item to record.
@@ -55,6 +62,10 @@ pub trait Recorder<B: Backend>:
     }
 
     /// Load an item from the given arguments.
+    ///
+    /// The tensors are created on the device by [Record::from_item], which consumes the
+    /// deserialized data of each tensor, so its host copy is released as soon as the tensor is
+    /// created.
     fn load<R>(&self, args: Self::LoadArgs, device: &B::Device) -> Result<R, RecorderError>
     where
         R: Record<B>,
@@ -104,6 +115,43 @@ pub trait Recorder<B: Backend>:
         Ok(R::from_item(item.item, device))
     }
 
+    /// Load a record partially from the given arguments.
+    ///
+    /// Every parameter of the given `record` found in the loaded data with the same shape is
+    /// replaced, while the other ones keep their current values. The returned
+    /// [report](LoadReport) lists the missing, unexpected and mismatched keys.
+    ///
+    /// # Notes
+    ///
+    /// Only recorders using a self-describing format, such as named msgpack or json, support
+    /// partial loading.
+    #[cfg(feature = "std")]
+    fn load_partial<R>(
+        &self,
+        args: Self::LoadArgs,
+        record: R,
+        device: &B::Device,
+    ) -> Result<(R, LoadReport), RecorderError>
+    where
+        R: Record<B>,
+    {
+        let loaded: BurnRecord<RecordValue, B> = self.load_item(args).map_err(|err| {
+            RecorderError::DeserializeError(format!(
+                "Unable to load the record partially, the recorder must use a self-describing \
+                 format such as named msgpack or json.\nError: {err:?}"
+            ))
+        })?;
+
+        let expected = RecordValue::from_item(&record.into_item::<Self::Settings>())?;
+
+        let mut report = LoadReport::default();
+        let merged = merge_partial(expected, loaded.item, "", &mut report);
+        let item = R::Item::<Self::Settings>::deserialize(merged)
+            .map_err(|err| RecorderError::DeserializeError(err.to_string()))?;
+
+        Ok((R::from_item(item, device), report))
+    }
+
     /// Saves an item.
     ///
     /// This method is used by [record](Recorder::record) to save the item.
@@ -222,18 +222,26 @@ impl<'de, A: BurnModuleAdapter> serde::Deserializer<'de> for Deserializer<A> {
         visitor.visit_str(self.value.unwrap().as_string().unwrap().as_ref())
     }
 
-    fn deserialize_bytes<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_bytes is not implemented")
+        self.deserialize_byte_buf(visitor)
     }
 
-    fn deserialize_byte_buf<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_byte_buf is not implemented")
+        match self.value {
+            Some(NestedValue::U8s(bytes)) => visitor.visit_byte_buf(bytes),
+            value => Self {
+                value,
+                default_for_missing_fields: self.default_for_missing_fields,
+                phantom: std::marker::PhantomData,
+            }
+            .deserialize_seq(visitor),
+        }
     }
 
     fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
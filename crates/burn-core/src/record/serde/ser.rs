@@ -99,6 +99,10 @@ impl SerializerTrait for Serializer {
         Ok(NestedValue::F64(v))
     }
 
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Ok(NestedValue::U8s(v.to_vec()))
+    }
+
     // The following methods are not implemented because they are not needed for the
     // serialization of Param structs.
 
@@ -106,10 +110,6 @@ impl SerializerTrait for Serializer {
         unimplemented!()
     }
 
-    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
-    }
-
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
         Ok(NestedValue::Default(None))
     }
@@ -0,0 +1,520 @@
+use super::{
+    read_versioned, record_header, FileRecorder, PrecisionSettings, RecordMigrations, Recorder,
+    RecorderError,
+};
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Default maximum size of a single shard file, 1 GiB.
+pub const DEFAULT_MAX_SHARD_SIZE: usize = 1024 * 1024 * 1024;
+
+/// File recorder using the [named msgpack](rmp_serde) format, split into multiple shard files.
+///
+/// The serialized record is streamed into shard files of at most `max_shard_size` bytes each,
+/// and an index file listing the shards in order is written alongside them. Loading reads the
+/// index and streams the shards back one after the other, so the record is never assembled
+/// into a single buffer or file.
+///
+/// For a file path `model`, the recorder writes `model.mpk.index.json` along with
+/// `model.shard-00000.mpk`, `model.shard-00001.mpk`, etc.
+#[derive(Debug, Clone)]
+pub struct ShardedFileRecorder<S: PrecisionSettings> {
+    max_shard_size: usize,
+    migrations: RecordMigrations,
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings> ShardedFileRecorder<S> {
+    /// Create a new sharded recorder with the given maximum shard size in bytes.
+    pub fn new(max_shard_size: usize) -> Self {
+        assert!(
+            max_shard_size > 0,
+            "The maximum shard size must be non-zero"
+        );
+
+        Self {
+            max_shard_size,
+            migrations: RecordMigrations::default(),
+            _settings: PhantomData,
+        }
+    }
+
+    /// Use the given [migrations](RecordMigrations) to upgrade records saved with an older
+    /// format version when loading.
+    pub fn with_migrations(mut self, migrations: RecordMigrations) -> Self {
+        self.migrations = migrations;
+        self
+    }
+
+    /// The maximum size of a single shard file in bytes.
+    pub fn max_shard_size(&self) -> usize {
+        self.max_shard_size
+    }
+}
+
+impl<S: PrecisionSettings> Default for ShardedFileRecorder<S> {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_SHARD_SIZE)
+    }
+}
+
+/// Index file describing the shards of a record saved with a [sharded recorder](ShardedFileRecorder).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ShardIndex {
+    /// Maximum size of a single shard when the record was saved.
+    pub max_shard_size: usize,
+
+    /// Total size of the record in bytes.
+    pub total_size: usize,
+
+    /// Shards in the order they must be read.
+    pub shards: Vec<ShardEntry>,
+}
+
+/// A single shard listed in a [shard index](ShardIndex).
+#[derive(new, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ShardEntry {
+    /// File name of the shard, relative to the index file.
+    pub file: String,
+
+    /// Size of the shard in bytes.
+    pub size: usize,
+}
+
+impl ShardIndex {
+    /// Read the shard index of a record saved with a [sharded recorder](ShardedFileRecorder).
+    ///
+    /// The file extension is automatically added, you don't have to specify it.
+    pub fn read<P: Into<PathBuf>>(file: P) -> Result<Self, RecorderError> {
+        read_index(&index_path(file.into()))
+    }
+}
+
+fn index_path(mut file: PathBuf) -> PathBuf {
+    file.set_extension(SHARD_INDEX_EXTENSION);
+    file
+}
+
+const SHARD_INDEX_EXTENSION: &str = "mpk.index.json";
+
+/// The start of the file names of the shards, before their number.
+fn shard_prefix(index_path: &Path) -> String {
+    let name = index_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(SHARD_INDEX_EXTENSION))
+        .unwrap_or_default();
+
+    format!("{name}shard-")
+}
+
+fn shard_file_name(index_path: &Path, shard: usize) -> String {
+    format!("{}{shard:05}.mpk", shard_prefix(index_path))
+}
+
+/// Removes the shard files of the record that the index doesn't list, like the last shards of a
+/// previous record saved at the same path, even when its index is gone.
+fn remove_stale_shards(index_path: &Path, index: &ShardIndex) -> std::io::Result<()> {
+    let directory = match index_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let prefix = shard_prefix(index_path);
+
+    for entry in std::fs::read_dir(&directory)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        let Some(file) = file_name.to_str() else {
+            continue;
+        };
+
+        let is_shard = file
+            .strip_prefix(&prefix)
+            .and_then(|file| file.strip_suffix(".mpk"))
+            .is_some_and(|number| {
+                !number.is_empty() && number.bytes().all(|byte| byte.is_ascii_digit())
+            });
+
+        if is_shard && !index.shards.iter().any(|shard| shard.file == file) {
+            log::info!("Removing stale shard {file}");
+            std::fs::remove_file(entry.path())?;
+        }
+    }
+
+    Ok(())
+}
+
+fn io_error(err: std::io::Error) -> RecorderError {
+    match err.kind() {
+        std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+        _ => RecorderError::Unknown(err.to_string()),
+    }
+}
+
+fn read_index(path: &Path) -> Result<ShardIndex, RecorderError> {
+    let reader = File::open(path).map(BufReader::new).map_err(io_error)?;
+
+    serde_json::from_reader(reader).map_err(|err| RecorderError::DeserializeError(err.to_string()))
+}
+
+/// Writer streaming bytes into consecutive shard files.
+struct ShardWriter {
+    directory: PathBuf,
+    index_path: PathBuf,
+    max_shard_size: usize,
+    current: Option<BufWriter<File>>,
+    current_size: usize,
+    shards: Vec<ShardEntry>,
+}
+
+impl ShardWriter {
+    fn new(index_path: PathBuf, max_shard_size: usize) -> Self {
+        let directory = index_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        Self {
+            directory,
+            index_path,
+            max_shard_size,
+            current: None,
+            current_size: 0,
+            shards: Vec::new(),
+        }
+    }
+
+    fn next_shard(&mut self) -> std::io::Result<()> {
+        self.close_shard()?;
+
+        let file = shard_file_name(&self.index_path, self.shards.len());
+        let writer = BufWriter::new(File::create(self.directory.join(&file))?);
+
+        self.shards.push(ShardEntry::new(file, 0));
+        self.current = Some(writer);
+        self.current_size = 0;
+
+        Ok(())
+    }
+
+    fn close_shard(&mut self) -> std::io::Result<()> {
+        if let Some(mut writer) = self.current.take() {
+            writer.flush()?;
+            if let Some(entry) = self.shards.last_mut() {
+                entry.size = self.current_size;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> std::io::Result<ShardIndex> {
+        // An empty record still produces a single (empty) shard.
+        if self.shards.is_empty() {
+            self.next_shard()?;
+        }
+        self.close_shard()?;
+
+        Ok(ShardIndex {
+            max_shard_size: self.max_shard_size,
+            total_size: self.shards.iter().map(|shard| shard.size).sum(),
+            shards: self.shards,
+        })
+    }
+}
+
+impl Write for ShardWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.current.is_none() || self.current_size == self.max_shard_size {
+            self.next_shard()?;
+        }
+
+        let available = self.max_shard_size - self.current_size;
+        let size = usize::min(available, buf.len());
+        let writer = self.current.as_mut().unwrap();
+        let written = writer.write(&buf[..size])?;
+        self.current_size += written;
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self.current.as_mut() {
+            Some(writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Reader chaining the shard files listed in an index.
+struct ShardReader {
+    directory: PathBuf,
+    shards: std::vec::IntoIter<ShardEntry>,
+    current: Option<(BufReader<File>, ShardEntry, usize)>,
+}
+
+impl ShardReader {
+    fn new(index_path: &Path, index: ShardIndex) -> Self {
+        let directory = index_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        Self {
+            directory,
+            shards: index.shards.into_iter(),
+            current: None,
+        }
+    }
+}
+
+impl Read for ShardReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if self.current.is_none() {
+                let shard = match self.shards.next() {
+                    Some(shard) => shard,
+                    None => return Ok(0),
+                };
+                let file = File::open(self.directory.join(&shard.file))?;
+                self.current = Some((BufReader::new(file), shard, 0));
+            }
+
+            let (reader, shard, read) = self.current.as_mut().unwrap();
+            let count = reader.read(buf)?;
+            *read += count;
+
+            if count > 0 || buf.is_empty() {
+                return Ok(count);
+            }
+
+            if *read != shard.size {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    format!(
+                        "Shard {} has {} bytes, expected {}",
+                        shard.file, read, shard.size
+                    ),
+                ));
+            }
+
+            self.current = None;
+        }
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for ShardedFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        SHARD_INDEX_EXTENSION
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for ShardedFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let path = index_path(file);
+
+        // Add parent directories if they don't exist
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        let mut writer = ShardWriter::new(path.clone(), self.max_shard_size);
+        writer
+            .write_all(&record_header())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        rmp_serde::encode::write_named(&mut writer, &item)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let index = writer
+            .finish()
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        let writer = File::create(&path).map(BufWriter::new).map_err(io_error)?;
+        serde_json::to_writer_pretty(writer, &index)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        // Shards of a previous record at the same path would otherwise be left behind.
+        remove_stale_shards(&path, &index).map_err(io_error)?;
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, file: Self::LoadArgs) -> Result<I, RecorderError> {
+        let path = index_path(file);
+        let index = read_index(&path)?;
+        let reader = BufReader::new(ShardReader::new(&path, index));
+
+        read_versioned(reader, &self.migrations, |reader| {
+            rmp_serde::decode::from_read(reader)
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        record::{BinBytesRecorder, FullPrecisionSettings},
+        TestBackend,
+    };
+    use tempfile::TempDir;
+
+    fn create_model(device: &<TestBackend as Backend>::Device) -> Linear<TestBackend> {
+        LinearConfig::new(32, 32).with_bias(true).init(device)
+    }
+
+    #[test]
+    fn test_can_save_and_load_sharded() {
+        let device = Default::default();
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("model");
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new(1024);
+
+        let model_before = create_model(&device);
+        Recorder::<TestBackend>::record(
+            &recorder,
+            model_before.clone().into_record(),
+            file_path.clone(),
+        )
+        .unwrap();
+        let model_after =
+            create_model(&device).load_record(recorder.load(file_path.clone(), &device).unwrap());
+
+        let index = ShardIndex::read(file_path).unwrap();
+        assert!(index.shards.len() > 1);
+        assert!(index.shards.iter().all(|shard| shard.size <= 1024));
+        for shard in index.shards.iter() {
+            assert!(temp_dir.path().join(&shard.file).exists());
+        }
+
+        let byte_recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes_before =
+            Recorder::<TestBackend>::record(&byte_recorder, model_before.into_record(), ())
+                .unwrap();
+        let bytes_after =
+            Recorder::<TestBackend>::record(&byte_recorder, model_after.into_record(), ()).unwrap();
+
+        assert_eq!(bytes_before, bytes_after);
+    }
+
+    #[test]
+    fn test_small_record_is_single_shard() {
+        let device = Default::default();
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("model");
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::default();
+
+        Recorder::<TestBackend>::record(
+            &recorder,
+            create_model(&device).into_record(),
+            file_path.clone(),
+        )
+        .unwrap();
+
+        let index = ShardIndex::read(file_path).unwrap();
+        assert_eq!(index.shards.len(), 1);
+        assert_eq!(index.shards[0].size, index.total_size);
+    }
+
+    #[test]
+    fn test_stale_shards_are_removed() {
+        let device = Default::default();
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("model");
+
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new(512);
+        Recorder::<TestBackend>::record(
+            &recorder,
+            create_model(&device).into_record(),
+            file_path.clone(),
+        )
+        .unwrap();
+        let index_before = ShardIndex::read(file_path.clone()).unwrap();
+
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new(4096);
+        Recorder::<TestBackend>::record(
+            &recorder,
+            create_model(&device).into_record(),
+            file_path.clone(),
+        )
+        .unwrap();
+        let index_after = ShardIndex::read(file_path).unwrap();
+
+        assert!(index_after.shards.len() < index_before.shards.len());
+        for shard in index_before.shards.iter().skip(index_after.shards.len()) {
+            assert!(!temp_dir.path().join(&shard.file).exists());
+        }
+        assert_eq!(shard_files(&temp_dir), shard_names(&index_after));
+    }
+
+    #[test]
+    fn test_stale_shards_are_removed_without_previous_index() {
+        let device = Default::default();
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("model");
+
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new(512);
+        Recorder::<TestBackend>::record(
+            &recorder,
+            create_model(&device).into_record(),
+            file_path.clone(),
+        )
+        .unwrap();
+        std::fs::remove_file(index_path(file_path.clone())).unwrap();
+        // Files of other records, or not numbered like shards, are kept.
+        std::fs::write(temp_dir.path().join("other.shard-00007.mpk"), []).unwrap();
+        std::fs::write(temp_dir.path().join("model.shard-notes.mpk"), []).unwrap();
+
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new(4096);
+        Recorder::<TestBackend>::record(
+            &recorder,
+            create_model(&device).into_record(),
+            file_path.clone(),
+        )
+        .unwrap();
+        let index = ShardIndex::read(file_path).unwrap();
+
+        let mut expected = shard_names(&index);
+        expected.extend(["model.shard-notes.mpk", "other.shard-00007.mpk"].map(String::from));
+        expected.sort();
+        assert_eq!(shard_files(&temp_dir), expected);
+    }
+
+    /// The sorted names of the files of the directory that look like shards.
+    fn shard_files(temp_dir: &TempDir) -> Vec<String> {
+        let mut files: Vec<_> = std::fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|file| file.contains(".shard-"))
+            .collect();
+        files.sort();
+        files
+    }
+
+    fn shard_names(index: &ShardIndex) -> Vec<String> {
+        index
+            .shards
+            .iter()
+            .map(|shard| shard.file.clone())
+            .collect()
+    }
+}
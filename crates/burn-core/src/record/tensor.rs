@@ -1,10 +1,17 @@
 use core::marker::PhantomData;
 
 use super::{PrecisionSettings, Record};
+use alloc::boxed::Box;
 use alloc::format;
-use burn_tensor::{backend::Backend, Bool, Element, Int, Tensor, TensorData};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use burn_common::stub::Mutex;
+use burn_tensor::{backend::Backend, Bool, DType, Element, Int, Tensor, TensorData};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "std")]
+use super::mapped::MappedBytes;
+
 #[cfg(feature = "record-backward-compat")]
 use burn_tensor::DataSerialize;
 
@@ -17,8 +24,8 @@ enum TensorDataSerde<E> {
     V2(TensorData),
 }
 
-/// Deserialize the value into [`TensorData`].
-fn deserialize_data<'de, E, De>(deserializer: De) -> Result<TensorData, De::Error>
+/// Deserialize the value into the [data source](TensorDataSource) of a tensor.
+fn deserialize_data<'de, E, De>(deserializer: De) -> Result<TensorDataSource, De::Error>
 where
     E: Element + Deserialize<'de>,
     De: serde::Deserializer<'de>,
@@ -30,41 +37,286 @@ where
             // NOTE: loading f32 weights with f16 precision will deserialize the f32 weights (bytes) first and then convert to f16
             TensorDataSerde::V2(data) => data.convert::<E>(),
         };
-        Ok(data)
+        Ok(TensorDataSource::Data(data))
     }
 
     #[cfg(not(feature = "record-backward-compat"))]
     {
-        let data = TensorData::deserialize(deserializer).map_err(|e| {
+        let data = TensorDataView::deserialize(deserializer).map_err(|e| {
             serde::de::Error::custom(format!(
                 "{:?}\nThe internal data format has changed since version 0.14.0. If you are trying to load a record saved in a previous version, use the `record-backward-compat` feature flag. Once you have saved the record in the new format, you can disable the feature flag.\n",
                 e
             ))
         })?;
-        Ok(data.convert::<E>())
+        let num_bytes = data.shape.iter().product::<usize>() * data.dtype.size();
+        if data.value.as_slice().len() != num_bytes {
+            return Err(serde::de::Error::custom(format!(
+                "Expected {num_bytes} bytes for the values of a tensor of shape {:?} and type {:?}, got {}",
+                data.shape,
+                data.dtype,
+                data.value.as_slice().len()
+            )));
+        }
+
+        Ok(data.into_source::<E>())
+    }
+}
+
+/// The [tensor data](TensorData) as it's deserialized, borrowing its values from the deserializer
+/// when possible.
+#[cfg(not(feature = "record-backward-compat"))]
+#[derive(Deserialize)]
+#[serde(rename = "TensorData")]
+struct TensorDataView<'a> {
+    #[serde(borrow)]
+    value: TensorValue<'a>,
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+#[cfg(not(feature = "record-backward-compat"))]
+impl TensorDataView<'_> {
+    fn into_source<E: Element>(self) -> TensorDataSource {
+        let bytes = match self.value {
+            // Values borrowed from a memory-mapped file are viewed instead of copied, unless they
+            // have to be converted to the element type of the record.
+            #[cfg(feature = "std")]
+            TensorValue::Borrowed(bytes) if self.dtype == E::dtype() => {
+                match MappedBytes::find(bytes) {
+                    Some(bytes) => {
+                        return TensorDataSource::Mapped(MappedData {
+                            bytes,
+                            shape: self.shape,
+                            dtype: self.dtype,
+                        })
+                    }
+                    None => bytes.to_vec(),
+                }
+            }
+            TensorValue::Borrowed(bytes) => bytes.to_vec(),
+            TensorValue::Owned(bytes) => bytes,
+        };
+
+        TensorDataSource::Data(TensorData::from_bytes(bytes, self.shape, self.dtype).convert::<E>())
+    }
+}
+
+/// The values of a tensor, borrowed from the deserializer input when possible.
+#[cfg(not(feature = "record-backward-compat"))]
+enum TensorValue<'a> {
+    Borrowed(&'a [u8]),
+    Owned(Vec<u8>),
+}
+
+#[cfg(not(feature = "record-backward-compat"))]
+impl TensorValue<'_> {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Self::Borrowed(bytes) => bytes,
+            Self::Owned(bytes) => bytes,
+        }
+    }
+}
+
+#[cfg(not(feature = "record-backward-compat"))]
+impl<'de: 'a, 'a> Deserialize<'de> for TensorValue<'a> {
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        struct ValueVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+            type Value = TensorValue<'de>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("the bytes of the tensor values")
+            }
+
+            fn visit_borrowed_bytes<E: serde::de::Error>(
+                self,
+                v: &'de [u8],
+            ) -> Result<Self::Value, E> {
+                Ok(TensorValue::Borrowed(v))
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(TensorValue::Owned(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(TensorValue::Owned(v))
+            }
+
+            // Records saved before the format version 2 have sequences of bytes.
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut bytes = Vec::new();
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+
+                Ok(TensorValue::Owned(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(ValueVisitor)
+    }
+}
+
+/// Tensor data viewed in the pages of a memory-mapped record file.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+struct MappedData {
+    bytes: MappedBytes,
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+type ReadData = Box<dyn Fn() -> TensorData + Send>;
+
+/// The data of a recorded tensor.
+///
+/// When a record is created from a module, the tensor data is only read when the item is
+/// serialized. Recorders write each tensor to the underlying writer as soon as it is read, so
+/// the peak memory usage when saving a record is bounded by the largest tensor instead of the
+/// whole record.
+///
+/// Symmetrically, when a record is [loaded](super::Recorder::load), the data of each tensor is
+/// consumed by [Record::from_item] with the device it's loaded on, so the host copy of a tensor is
+/// released as soon as the tensor is created.
+#[derive(Clone)]
+enum TensorDataSource {
+    /// Data that is already available, e.g. after deserialization.
+    Data(TensorData),
+    /// Data read from the tensor on demand.
+    Lazy(Arc<Mutex<ReadData>>),
+    /// Data viewed in a memory-mapped file, only copied when it's read.
+    #[cfg(feature = "std")]
+    Mapped(MappedData),
+}
+
+impl TensorDataSource {
+    fn lazy<F: Fn() -> TensorData + Send + 'static>(func: F) -> Self {
+        Self::Lazy(Arc::new(Mutex::new(Box::new(func))))
+    }
+
+    fn read(&self) -> TensorData {
+        match self {
+            Self::Data(data) => data.clone(),
+            Self::Lazy(func) => {
+                let func = func.lock().unwrap();
+                func()
+            }
+            #[cfg(feature = "std")]
+            Self::Mapped(data) => TensorData::from_bytes(
+                data.bytes.as_slice().to_vec(),
+                data.shape.clone(),
+                data.dtype,
+            ),
+        }
+    }
+
+    fn into_data(self) -> TensorData {
+        match self {
+            Self::Data(data) => data,
+            _ => self.read(),
+        }
+    }
+
+    fn serialize<Se: serde::Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        match self {
+            Self::Data(data) => data.serialize(serializer),
+            _ => self.read().serialize(serializer),
+        }
+    }
+}
+
+impl core::fmt::Debug for TensorDataSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Data(data) => f.debug_tuple("Data").field(data).finish(),
+            Self::Lazy(_) => f.write_str("Lazy"),
+            #[cfg(feature = "std")]
+            Self::Mapped(_) => f.write_str("Mapped"),
+        }
     }
 }
 
 /// This struct implements serde to lazily serialize and deserialize a float tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct FloatTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    data: TensorDataSource,
     _e: PhantomData<S::FloatElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an int tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct IntTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    data: TensorDataSource,
     _e: PhantomData<S::IntElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an bool tensor.
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct BoolTensorSerde {
-    data: TensorData,
+    data: TensorDataSource,
+}
+
+impl<S: PrecisionSettings> FloatTensorSerde<S> {
+    /// Create a new float tensor serde from the given data.
+    pub fn new(data: TensorData) -> Self {
+        Self {
+            data: TensorDataSource::Data(data),
+            _e: PhantomData,
+        }
+    }
+
+    /// Read the data of the tensor only when the item is serialized.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn lazy<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Self {
+        Self {
+            data: TensorDataSource::lazy(move || tensor.to_data().convert::<S::FloatElem>()),
+            _e: PhantomData,
+        }
+    }
+}
+
+impl<S: PrecisionSettings> IntTensorSerde<S> {
+    /// Create a new int tensor serde from the given data.
+    pub fn new(data: TensorData) -> Self {
+        Self {
+            data: TensorDataSource::Data(data),
+            _e: PhantomData,
+        }
+    }
+
+    /// Read the data of the tensor only when the item is serialized.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn lazy<B: Backend, const D: usize>(tensor: Tensor<B, D, Int>) -> Self {
+        Self {
+            data: TensorDataSource::lazy(move || tensor.to_data().convert::<S::IntElem>()),
+            _e: PhantomData,
+        }
+    }
+}
+
+impl BoolTensorSerde {
+    /// Create a new bool tensor serde from the given data.
+    pub fn new(data: TensorData) -> Self {
+        Self {
+            data: TensorDataSource::Data(data),
+        }
+    }
+
+    /// Read the data of the tensor only when the item is serialized.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn lazy<B: Backend, const D: usize>(tensor: Tensor<B, D, Bool>) -> Self {
+        Self {
+            data: TensorDataSource::lazy(move || tensor.to_data()),
+        }
+    }
 }
 
 // --- SERDE IMPLEMENTATIONS --- //
@@ -83,9 +335,10 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for FloatTensorSerde<S> {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = deserialize_data::<S::FloatElem, De>(deserializer)?;
-
-        Ok(Self::new(data))
+        Ok(Self {
+            data: deserialize_data::<S::FloatElem, De>(deserializer)?,
+            _e: PhantomData,
+        })
     }
 }
 
@@ -103,9 +356,10 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for IntTensorSerde<S> {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = deserialize_data::<S::IntElem, De>(deserializer)?;
-
-        Ok(Self::new(data))
+        Ok(Self {
+            data: deserialize_data::<S::IntElem, De>(deserializer)?,
+            _e: PhantomData,
+        })
     }
 }
 
@@ -123,9 +377,9 @@ impl<'de> Deserialize<'de> for BoolTensorSerde {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = deserialize_data::<bool, De>(deserializer)?;
-
-        Ok(Self::new(data))
+        Ok(Self {
+            data: deserialize_data::<bool, De>(deserializer)?,
+        })
     }
 }
 
@@ -139,11 +393,11 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
         todo!("Recording float tensors isn't yet supported on wasm.");
 
         #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-        FloatTensorSerde::new(self.into_data().convert::<S::FloatElem>())
+        FloatTensorSerde::lazy(self)
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data.convert::<B::FloatElem>(), device)
+        Tensor::from_data(item.data.into_data().convert::<B::FloatElem>(), device)
     }
 }
 
@@ -156,11 +410,11 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Int> {
         todo!("Recording int tensors isn't yet supported on wasm.");
 
         #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-        IntTensorSerde::new(self.into_data().convert::<S::IntElem>())
+        IntTensorSerde::lazy(self)
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data.convert::<B::IntElem>(), device)
+        Tensor::from_data(item.data.into_data().convert::<B::IntElem>(), device)
     }
 }
 
@@ -173,10 +427,130 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Bool> {
         todo!("Recording bool tensors isn't yet supported on wasm.");
 
         #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-        BoolTensorSerde::new(self.into_data())
+        BoolTensorSerde::lazy(self)
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data, device)
+        Tensor::from_data(item.data.into_data(), device)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{
+        BinBytesRecorder, FullPrecisionSettings, HalfPrecisionSettings, NamedMpkFileRecorder,
+        Recorder,
+    };
+    use crate::TestBackend;
+
+    #[test]
+    fn lazy_item_serializes_like_eager_item() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let recorder = BinBytesRecorder::<HalfPrecisionSettings>::default();
+
+        let lazy = Record::<TestBackend>::into_item::<HalfPrecisionSettings>(tensor.clone());
+        let eager = FloatTensorSerde::<HalfPrecisionSettings>::new(
+            tensor.into_data().convert::<half::f16>(),
+        );
+
+        let bytes_lazy = Recorder::<TestBackend>::save_item(&recorder, lazy.clone(), ()).unwrap();
+        let bytes_eager = Recorder::<TestBackend>::save_item(&recorder, eager, ()).unwrap();
+        // The lazy item can be serialized more than once.
+        let bytes_lazy_again = Recorder::<TestBackend>::save_item(&recorder, lazy, ()).unwrap();
+
+        assert_eq!(bytes_lazy, bytes_eager);
+        assert_eq!(bytes_lazy, bytes_lazy_again);
+    }
+
+    #[test]
+    fn lazy_item_can_be_converted_back_without_serialization() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device);
+
+        let item = Record::<TestBackend>::into_item::<FullPrecisionSettings>(tensor.clone());
+        let tensor_after: Tensor<TestBackend, 1, Int> = Record::from_item(item, &device);
+
+        tensor_after
+            .into_data()
+            .assert_eq(&tensor.into_data(), true);
+    }
+
+    #[test]
+    fn loaded_record_is_created_on_the_given_device() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let recorder = BinBytesRecorder::<HalfPrecisionSettings>::default();
+        let bytes = Recorder::<TestBackend>::record(&recorder, tensor.clone(), ()).unwrap();
+
+        let loaded: Tensor<TestBackend, 2> =
+            Recorder::<TestBackend>::load(&recorder, bytes, &device).unwrap();
+
+        assert_eq!(loaded.device(), device);
+        loaded.into_data().assert_eq(&tensor.into_data(), true);
+    }
+
+    #[test]
+    fn item_deserialized_on_another_thread_is_created_on_the_given_device() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device);
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let item = Record::<TestBackend>::into_item::<FullPrecisionSettings>(tensor.clone());
+        let bytes = Recorder::<TestBackend>::save_item(&recorder, item, ()).unwrap();
+
+        let item: IntTensorSerde<FullPrecisionSettings> = std::thread::spawn(move || {
+            Recorder::<TestBackend>::load_item(&recorder, bytes).unwrap()
+        })
+        .join()
+        .unwrap();
+        let loaded: Tensor<TestBackend, 1, Int> = Record::from_item(item, &device);
+
+        assert_eq!(loaded.device(), device);
+        loaded.into_data().assert_eq(&tensor.into_data(), true);
+    }
+
+    #[test]
+    fn item_loaded_from_memory_map_views_the_file() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("tensor");
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::default();
+        let item = Record::<TestBackend>::into_item::<FullPrecisionSettings>(tensor.clone());
+        Recorder::<TestBackend>::save_item(&recorder, item, path.clone()).unwrap();
+
+        let recorder = recorder.with_memory_map(true);
+        let item: FloatTensorSerde<FullPrecisionSettings> =
+            Recorder::<TestBackend>::load_item(&recorder, path).unwrap();
+        assert!(matches!(item.data, TensorDataSource::Mapped(_)));
+        let loaded: Tensor<TestBackend, 2> = Record::from_item(item, &device);
+
+        loaded.into_data().assert_eq(&tensor.into_data(), true);
+    }
+
+    #[test]
+    fn item_with_sequence_of_bytes_is_deserialized() {
+        // Tensor values were serialized as sequences of bytes before the format version 2.
+        #[derive(Serialize)]
+        struct TensorDataV1 {
+            value: Vec<u8>,
+            shape: Vec<usize>,
+            dtype: DType,
+        }
+
+        let device = Default::default();
+        let data = TensorData::from([1i64, 2, 3]);
+        let bytes = rmp_serde::to_vec_named(&TensorDataV1 {
+            value: data.as_bytes().to_vec(),
+            shape: data.shape.clone(),
+            dtype: data.dtype,
+        })
+        .unwrap();
+
+        let item: IntTensorSerde<FullPrecisionSettings> = rmp_serde::from_slice(&bytes).unwrap();
+        let loaded: Tensor<TestBackend, 1, Int> = Record::from_item(item, &device);
+
+        loaded.into_data().assert_eq(&data, false);
     }
 }
@@ -0,0 +1,360 @@
+use super::RecorderError;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+/// Magic bytes identifying the start of a record header.
+const RECORD_MAGIC: [u8; 4] = *b"BURN";
+
+/// Size of the record header in bytes.
+pub(crate) const RECORD_HEADER_SIZE: usize = RECORD_MAGIC.len() + 2;
+
+/// Version of the format written by the recorders.
+///
+/// Records saved before the format was versioned don't have a header and are considered to be
+/// at version `0`. Since version `2`, the values of the tensors are serialized as bytes instead of
+/// sequences of bytes, so that binary formats store them contiguously.
+pub const RECORD_FORMAT_VERSION: u16 = 2;
+
+/// Encode the header written before the serialized record.
+pub(crate) fn record_header() -> [u8; RECORD_HEADER_SIZE] {
+    let mut header = [0; RECORD_HEADER_SIZE];
+    let version = RECORD_FORMAT_VERSION.to_le_bytes();
+
+    header[..RECORD_MAGIC.len()].copy_from_slice(&RECORD_MAGIC);
+    header[RECORD_MAGIC.len()..].copy_from_slice(&version);
+    header
+}
+
+/// Parse the header from the start of the given bytes, if any.
+///
+/// Returns the format version along with the number of bytes used by the header.
+pub(crate) fn parse_record_header(bytes: &[u8]) -> (u16, usize) {
+    if bytes.len() >= RECORD_HEADER_SIZE && bytes[..RECORD_MAGIC.len()] == RECORD_MAGIC {
+        let version = [bytes[RECORD_MAGIC.len()], bytes[RECORD_MAGIC.len() + 1]];
+        (u16::from_le_bytes(version), RECORD_HEADER_SIZE)
+    } else {
+        // Neither msgpack maps nor bincode records can start with the magic bytes.
+        (0, 0)
+    }
+}
+
+/// A migration upgrading the serialized payload of a record by one format version.
+pub type RecordMigration = dyn Fn(Vec<u8>) -> Result<Vec<u8>, RecorderError> + Send + Sync;
+
+/// Registry of the migrations used to upgrade records saved with an older format version.
+///
+/// Each migration receives the uncompressed serialized record (without its header) at a given
+/// version and returns it serialized at the next version. When loading a record, the recorder
+/// applies the migrations in sequence until the record reaches [RECORD_FORMAT_VERSION].
+///
+/// The default registry only contains the migrations from unversioned records (version `0`) and
+/// from version `1`, whose payloads are still read as is: both have the same payload as version
+/// `1`, and the sequences of bytes of their tensor values are accepted when deserializing.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let migrations = RecordMigrations::default().register(0, |bytes| {
+///     let record: BurnRecord<OldItem, B> = rmp_serde::from_slice(&bytes)?;
+///     rmp_serde::to_vec_named(&upgrade(record))
+/// });
+/// let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::new().with_migrations(migrations);
+/// ```
+#[derive(Clone)]
+pub struct RecordMigrations {
+    migrations: BTreeMap<u16, Arc<RecordMigration>>,
+}
+
+impl Default for RecordMigrations {
+    fn default() -> Self {
+        Self::empty().register(0, Ok).register(1, Ok)
+    }
+}
+
+impl core::fmt::Debug for RecordMigrations {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RecordMigrations")
+            .field("from_versions", &self.migrations.keys())
+            .finish()
+    }
+}
+
+impl RecordMigrations {
+    /// Create a registry without any migration, not even for unversioned records.
+    pub fn empty() -> Self {
+        Self {
+            migrations: BTreeMap::new(),
+        }
+    }
+
+    /// Register the migration upgrading records from the given version to the next one.
+    ///
+    /// Registering a migration for a version that already has one replaces it.
+    pub fn register<F>(mut self, from_version: u16, migration: F) -> Self
+    where
+        F: Fn(Vec<u8>) -> Result<Vec<u8>, RecorderError> + Send + Sync + 'static,
+    {
+        assert!(
+            from_version < RECORD_FORMAT_VERSION,
+            "Can't register a migration from version {from_version}, the current format version is {RECORD_FORMAT_VERSION}"
+        );
+
+        self.migrations.insert(from_version, Arc::new(migration));
+        self
+    }
+
+    /// Upgrade the serialized record from the given version to [RECORD_FORMAT_VERSION].
+    pub fn migrate(&self, version: u16, mut bytes: Vec<u8>) -> Result<Vec<u8>, RecorderError> {
+        if version > RECORD_FORMAT_VERSION {
+            return Err(RecorderError::DeserializeError(format!(
+                "The record format version {version} is newer than the supported version \
+                 {RECORD_FORMAT_VERSION}, it was probably saved with a newer version of Burn."
+            )));
+        }
+
+        for from_version in version..RECORD_FORMAT_VERSION {
+            let migration = self.migrations.get(&from_version).ok_or_else(|| {
+                RecorderError::DeserializeError(format!(
+                    "The record format version {version} is older than the current version \
+                     {RECORD_FORMAT_VERSION} and no migration from version {from_version} is \
+                     registered. Register one using `RecordMigrations::register`."
+                ))
+            })?;
+
+            bytes = migration(bytes)?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) use io::*;
+
+#[cfg(feature = "std")]
+mod io {
+    use super::*;
+    use std::io::Read;
+
+    /// Read the header of a record from the reader.
+    ///
+    /// Returns the format version along with the bytes read past the header, which are the start
+    /// of the record when it doesn't have any header.
+    pub(crate) fn read_record_header<R: Read>(
+        reader: &mut R,
+    ) -> Result<(u16, Vec<u8>), RecorderError> {
+        let mut bytes = Vec::with_capacity(RECORD_HEADER_SIZE);
+        // Reads until the whole header is read or the end of the record is reached, whatever
+        // the number of bytes returned by each read.
+        reader
+            .take(RECORD_HEADER_SIZE as u64)
+            .read_to_end(&mut bytes)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let (version, size) = parse_record_header(&bytes);
+        bytes.drain(..size);
+
+        Ok((version, bytes))
+    }
+
+    /// Read a versioned record, applying the migrations when it was saved with an older format.
+    ///
+    /// Records at the current version are decoded directly from the reader, older records are
+    /// read into memory to be migrated first.
+    pub(crate) fn read_versioned<I, R, F>(
+        mut reader: R,
+        migrations: &RecordMigrations,
+        decode: F,
+    ) -> Result<I, RecorderError>
+    where
+        R: Read,
+        F: FnOnce(&mut dyn Read) -> Result<I, RecorderError>,
+    {
+        let (version, start) = read_record_header(&mut reader)?;
+        let mut reader = start.as_slice().chain(reader);
+
+        if version == RECORD_FORMAT_VERSION {
+            return decode(&mut reader);
+        }
+
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let bytes = migrations.migrate(version, bytes)?;
+
+        decode(&mut bytes.as_slice())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::record::{
+        BinFileRecorder, BurnRecord, FileRecorder, FullPrecisionSettings, NamedMpkFileRecorder,
+        PrecisionSettings, Record, Recorder,
+    };
+    use crate::TestBackend;
+    use serde::{Deserialize, Serialize};
+    use std::io::Read;
+    use tempfile::TempDir;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct OldItem {
+        weight: f32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct NewItem {
+        weights: Vec<f32>,
+    }
+
+    impl<B: burn_tensor::backend::Backend> Record<B> for NewItem {
+        type Item<S: PrecisionSettings> = NewItem;
+
+        fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+            self
+        }
+
+        fn from_item<S: PrecisionSettings>(item: Self::Item<S>, _device: &B::Device) -> Self {
+            item
+        }
+    }
+
+    #[test]
+    fn header_roundtrip() {
+        let header = record_header();
+
+        assert_eq!(parse_record_header(&header), (RECORD_FORMAT_VERSION, 6));
+        assert_eq!(parse_record_header(&[0x82, 1, 2, 3, 4, 5, 6]), (0, 0));
+    }
+
+    /// A reader returning a single byte on each read.
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl Read for ByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((byte, rest)), Some(value)) => {
+                    *value = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn read_header_one_byte_at_a_time() {
+        let read_payload = |bytes: &[u8]| {
+            read_versioned(ByteReader(bytes), &RecordMigrations::default(), |reader| {
+                let mut payload = Vec::new();
+                reader.read_to_end(&mut payload).unwrap();
+                Ok(payload)
+            })
+            .unwrap()
+        };
+        let mut versioned = record_header().to_vec();
+        versioned.extend([1, 2, 3]);
+
+        assert_eq!(read_payload(&versioned), vec![1, 2, 3]);
+        // Records without a header are read from their first byte.
+        assert_eq!(read_payload(&[0x82, 1, 2]), vec![0x82, 1, 2]);
+        assert_eq!(read_payload(&versioned[..4]), b"BURN".to_vec());
+    }
+
+    #[test]
+    fn saved_record_starts_with_header() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("item");
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::new();
+        let item = NewItem {
+            weights: vec![1.0, 2.0],
+        };
+
+        Recorder::<TestBackend>::record(&recorder, item.clone(), path.clone()).unwrap();
+        let bytes = std::fs::read(path.with_extension(
+            <NamedMpkFileRecorder<FullPrecisionSettings> as FileRecorder<TestBackend>>::file_extension(),
+        ))
+        .unwrap();
+
+        assert_eq!(&bytes[..RECORD_HEADER_SIZE], &record_header());
+        let loaded: NewItem =
+            Recorder::<TestBackend>::load(&recorder, path, &Default::default()).unwrap();
+        assert_eq!(loaded, item);
+    }
+
+    #[test]
+    fn load_unversioned_record() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("item");
+        let item = NewItem {
+            weights: vec![1.0, 2.0],
+        };
+
+        // Records saved before versioning don't have any header.
+        let record = BurnRecord::<_, TestBackend>::new::<BinFileRecorder<FullPrecisionSettings>>(
+            item.clone(),
+        );
+        let bytes = bincode::serde::encode_to_vec(&record, crate::record::bin_config()).unwrap();
+        std::fs::write(path.with_extension("bin"), bytes).unwrap();
+
+        let recorder = BinFileRecorder::<FullPrecisionSettings>::new();
+        let loaded: NewItem =
+            Recorder::<TestBackend>::load(&recorder, path, &Default::default()).unwrap();
+
+        assert_eq!(loaded, item);
+    }
+
+    #[test]
+    fn load_unversioned_record_with_migration() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("item");
+
+        let record = BurnRecord::<_, TestBackend>::new::<NamedMpkFileRecorder<FullPrecisionSettings>>(
+            OldItem { weight: 3.0 },
+        );
+        let bytes = rmp_serde::to_vec_named(&record).unwrap();
+        std::fs::write(path.with_extension("mpk"), bytes).unwrap();
+
+        let migrations = RecordMigrations::default().register(0, |bytes| {
+            let record: BurnRecord<OldItem, TestBackend> = rmp_serde::from_slice(&bytes)
+                .map_err(|err| RecorderError::DeserializeError(err.to_string()))?;
+            let record = BurnRecord::<_, TestBackend>::new::<
+                NamedMpkFileRecorder<FullPrecisionSettings>,
+            >(NewItem {
+                weights: vec![record.item.weight],
+            });
+
+            rmp_serde::to_vec_named(&record).map_err(|err| RecorderError::Unknown(err.to_string()))
+        });
+        let recorder =
+            NamedMpkFileRecorder::<FullPrecisionSettings>::new().with_migrations(migrations);
+        let loaded: NewItem =
+            Recorder::<TestBackend>::load(&recorder, path, &Default::default()).unwrap();
+
+        assert_eq!(loaded.weights, vec![3.0]);
+    }
+
+    #[test]
+    fn missing_migration_is_an_error() {
+        let migrations = RecordMigrations::empty();
+
+        assert!(matches!(
+            migrations.migrate(0, Vec::new()),
+            Err(RecorderError::DeserializeError(_))
+        ));
+    }
+
+    #[test]
+    fn newer_version_is_an_error() {
+        let migrations = RecordMigrations::default();
+
+        assert!(matches!(
+            migrations.migrate(RECORD_FORMAT_VERSION + 1, Vec::new()),
+            Err(RecorderError::DeserializeError(_))
+        ));
+    }
+}
@@ -320,6 +320,9 @@ fn create_scalar_handles<R: Runtime, E1: CubeElement, E2: CubeElement, E3: CubeE
         Elem::Int(_) => 1,
         Elem::UInt => 2,
         Elem::Bool => panic!("Bool scalars are not supported"),
+        Elem::AtomicFloat(_) | Elem::AtomicInt(_) | Elem::AtomicUInt => {
+            panic!("Atomic scalars are not supported")
+        }
     };
     let scalar_priorities: [usize; 3] = [
         element_priority(E1::cube_elem()),
@@ -115,6 +115,9 @@ impl<R: Runtime> KernelLauncher<R> {
                 },
                 Elem::UInt => self.scalar_u32.register::<R>(client, &mut bindings),
                 Elem::Bool => panic!("Bool can't be passed as bindings."),
+                Elem::AtomicFloat(_) | Elem::AtomicInt(_) | Elem::AtomicUInt => {
+                    panic!("Atomics can't be passed as scalar bindings.")
+                }
             }
         }
 
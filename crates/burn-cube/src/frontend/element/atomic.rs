@@ -0,0 +1,89 @@
+use crate::frontend::{CubeContext, CubePrimitive, CubeType, ExpandElement, UInt, F32, I32};
+use crate::ir::{AtomicOperator, Elem, FloatKind, IntKind, Item, Operator};
+use crate::unexpanded;
+
+use super::{Tensor, Vectorized};
+
+macro_rules! impl_atomic {
+    ($type:ident, $value:ident, $primitive:ty, $elem:expr) => {
+        /// An atomic number, the element of arrays whose values are updated with atomic
+        /// operations by multiple units.
+        ///
+        /// The arrays of atomics are bound to the same buffers as the arrays of their values.
+        #[derive(Clone, Copy)]
+        pub struct $type {
+            pub val: $primitive,
+            pub vectorization: u8,
+        }
+
+        impl CubeType for $type {
+            type ExpandType = ExpandElement;
+        }
+
+        impl CubeType for &$type {
+            type ExpandType = ExpandElement;
+        }
+
+        impl CubeType for &mut $type {
+            type ExpandType = ExpandElement;
+        }
+
+        impl core::cmp::PartialEq for $type {
+            fn eq(&self, other: &Self) -> bool {
+                self.val == other.val && self.vectorization == other.vectorization
+            }
+        }
+
+        impl core::cmp::Eq for $type {}
+
+        impl CubePrimitive for $type {
+            fn as_elem() -> Elem {
+                $elem
+            }
+        }
+
+        impl Vectorized for $type {
+            fn vectorization_factor(&self) -> UInt {
+                UInt {
+                    val: self.vectorization as u32,
+                    vectorization: 1,
+                }
+            }
+
+            fn vectorize(mut self, factor: UInt) -> Self {
+                self.vectorization = factor.vectorization;
+                self
+            }
+        }
+
+        impl $type {
+            /// Atomically add the value to the element of the array at the given index, returning
+            /// the previous value of the element.
+            pub fn add(_array: &Tensor<Self>, _index: UInt, _value: $value) -> $value {
+                unexpanded!()
+            }
+
+            pub fn add_expand(
+                context: &mut CubeContext,
+                array: ExpandElement,
+                index: ExpandElement,
+                value: ExpandElement,
+            ) -> ExpandElement {
+                let out = context.create_local(Item::new($value::as_elem()));
+
+                context.register(Operator::AtomicAdd(AtomicOperator {
+                    array: *array,
+                    index: *index,
+                    value: *value,
+                    out: *out,
+                }));
+
+                out
+            }
+        }
+    };
+}
+
+impl_atomic!(AtomicF32, F32, f32, Elem::AtomicFloat(FloatKind::F32));
+impl_atomic!(AtomicI32, I32, i32, Elem::AtomicInt(IntKind::I32));
+impl_atomic!(AtomicU32, UInt, u32, Elem::AtomicUInt);
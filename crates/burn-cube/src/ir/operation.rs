@@ -64,6 +64,7 @@ pub enum Operator {
     ShiftLeft(BinaryOperator),
     ShiftRight(BinaryOperator),
     Remainder(BinaryOperator),
+    AtomicAdd(AtomicOperator),
 }
 
 /// All metadata that can be access in a shader.
@@ -118,6 +119,17 @@ pub struct ClampOperator {
     pub out: Variable,
 }
 
+/// An atomic operation on an element of an array of atomics, whose previous value is written to
+/// the output.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct AtomicOperator {
+    pub array: Variable,
+    pub index: Variable,
+    pub value: Variable,
+    pub out: Variable,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[allow(missing_docs)]
 pub struct ReadGlobalOperator {
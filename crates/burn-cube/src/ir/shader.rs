@@ -37,8 +37,11 @@ pub enum IntKind {
 #[allow(missing_docs)]
 pub enum Elem {
     Float(FloatKind),
+    AtomicFloat(FloatKind),
     Int(IntKind),
+    AtomicInt(IntKind),
     UInt,
+    AtomicUInt,
     Bool,
 }
 
@@ -73,8 +76,11 @@ impl Display for Elem {
         match self {
             // NOTE: we'll eventually want to differentiate between int/float types
             Self::Float(_) => f.write_str("float"),
+            Self::AtomicFloat(_) => f.write_str("atomic<float>"),
             Self::Int(_) => f.write_str("int"),
+            Self::AtomicInt(_) => f.write_str("atomic<int>"),
             Self::UInt => f.write_str("uint"),
+            Self::AtomicUInt => f.write_str("atomic<uint>"),
             Self::Bool => f.write_str("bool"),
         }
     }
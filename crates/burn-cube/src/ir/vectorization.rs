@@ -77,6 +77,8 @@ impl Operator {
             Operator::ShiftLeft(op) => Operator::ShiftLeft(op.vectorize(vectorization)),
             Operator::ShiftRight(op) => Operator::ShiftRight(op.vectorize(vectorization)),
             Operator::Remainder(op) => Operator::Remainder(op.vectorize(vectorization)),
+            // Atomic operations update a single element.
+            Operator::AtomicAdd(op) => Operator::AtomicAdd(op.clone()),
         }
     }
 }
@@ -0,0 +1,44 @@
+use burn_cube::prelude::*;
+
+#[cube]
+fn atomic_add(output: &mut Tensor<AtomicU32>, index: UInt, value: UInt) -> UInt {
+    AtomicU32::add(output, index, value)
+}
+
+mod tests {
+    use super::*;
+    use burn_cube::ir::{AtomicOperator, Item, Operator, Variable};
+
+    #[test]
+    fn cube_support_atomic_add() {
+        let mut context = CubeContext::root();
+        let output = context.output(0, Item::new(AtomicU32::as_elem()));
+        let index = context.create_local(Item::new(UInt::as_elem()));
+        let value = context.create_local(Item::new(UInt::as_elem()));
+
+        atomic_add_expand(&mut context, output, index, value);
+        assert_eq!(
+            format!("{:?}", context.into_scope().operations),
+            inline_macro_ref()
+        );
+    }
+
+    fn inline_macro_ref() -> String {
+        let mut context = CubeContext::root();
+        let output = context.output(0, Item::new(AtomicU32::as_elem()));
+        let index = context.create_local(Item::new(UInt::as_elem()));
+        let value = context.create_local(Item::new(UInt::as_elem()));
+
+        let mut scope = context.into_scope();
+        let out = scope.create_local(Item::new(UInt::as_elem()));
+        let output: Variable = output.into();
+        scope.register(Operator::AtomicAdd(AtomicOperator {
+            array: output,
+            index: index.into(),
+            value: value.into(),
+            out,
+        }));
+
+        format!("{:?}", scope.operations)
+    }
+}
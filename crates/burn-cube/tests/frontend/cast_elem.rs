@@ -265,6 +265,7 @@ mod tests {
             Elem::Int(_) => cpa!(scope, x = x + 2i32),
             Elem::UInt => cpa!(scope, x = x + 2u32),
             Elem::Bool => cpa!(scope, x = x && false),
+            _ => unreachable!("Atomics can't be cast"),
         }
 
         cpa!(scope, y = cast(x));
@@ -274,6 +275,7 @@ mod tests {
             Elem::Int(_) => cpa!(scope, y = y + 34i32),
             Elem::UInt => cpa!(scope, y = y + 34u32),
             Elem::Bool => cpa!(scope, y = y || true),
+            _ => unreachable!("Atomics can't be cast"),
         }
 
         format!("{:?}", scope.operations)
@@ -1,5 +1,6 @@
 mod array;
 mod assign;
+mod atomic;
 mod cast_elem;
 mod cast_kind;
 mod comptime;
@@ -302,6 +302,12 @@ impl CudaCompiler {
             gpu::Operator::Floor(op) => Instruction::Floor(self.compile_unary(op)),
             gpu::Operator::Ceil(op) => Instruction::Ceil(self.compile_unary(op)),
             gpu::Operator::Remainder(_op) => todo!(),
+            gpu::Operator::AtomicAdd(op) => Instruction::AtomicAdd {
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                value: self.compile_variable(op.value),
+                out: self.compile_variable(op.out),
+            },
         }
     }
 
@@ -426,17 +432,18 @@ impl CudaCompiler {
 
     fn compile_elem(value: gpu::Elem) -> super::Elem {
         match value {
-            gpu::Elem::Float(kind) => match kind {
+            gpu::Elem::Float(kind) | gpu::Elem::AtomicFloat(kind) => match kind {
                 gpu::FloatKind::F16 => super::Elem::F16,
                 gpu::FloatKind::BF16 => super::Elem::BF16,
                 gpu::FloatKind::F32 => super::Elem::F32,
                 gpu::FloatKind::F64 => panic!("f64 isn't supported yet"),
             },
-            gpu::Elem::Int(kind) => match kind {
+            gpu::Elem::Int(kind) | gpu::Elem::AtomicInt(kind) => match kind {
                 gpu::IntKind::I32 => super::Elem::I32,
                 gpu::IntKind::I64 => panic!("i64 isn't supported yet"),
             },
-            gpu::Elem::UInt => super::Elem::U32,
+            // Atomic operations are applied on the arrays of their values.
+            gpu::Elem::UInt | gpu::Elem::AtomicUInt => super::Elem::U32,
             gpu::Elem::Bool => super::Elem::Bool,
         }
     }
@@ -95,6 +95,12 @@ pub enum Instruction {
         max_value: Variable,
         out: Variable,
     },
+    AtomicAdd {
+        array: Variable,
+        index: Variable,
+        value: Variable,
+        out: Variable,
+    },
     SyncThreads,
     Ceil(UnaryInstruction),
     Floor(UnaryInstruction),
@@ -210,6 +216,14 @@ for (uint {i} = {start}; {i} < {end}; {i}++) {{
 {out} = max({out}, {min_value});
                 "
             )),
+            Instruction::AtomicAdd {
+                array,
+                index,
+                value,
+                out,
+            } => f.write_fmt(format_args!(
+                "{out} = atomicAdd(&{array}[{index}], {value});\n"
+            )),
             Instruction::SyncThreads => f.write_str("__syncthreads();\n"),
             Instruction::Ceil(it) => Ceil::format(f, &it.input, &it.out),
             Instruction::Floor(it) => Floor::format(f, &it.input, &it.out),
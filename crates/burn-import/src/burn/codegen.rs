@@ -73,6 +73,14 @@ impl ToTokens for PaddingConfig1d {
                 let padding = padding.to_tokens();
                 quote! { PaddingConfig1d::Explicit(#padding) }
             }
+            Self::Circular(padding) => {
+                let padding = padding.to_tokens();
+                quote! { PaddingConfig1d::Circular(#padding) }
+            }
+            Self::Reflect(padding) => {
+                let padding = padding.to_tokens();
+                quote! { PaddingConfig1d::Reflect(#padding) }
+            }
         }
     }
 }
@@ -88,6 +96,16 @@ impl ToTokens for PaddingConfig2d {
                 let padding2 = padding2.to_tokens();
                 quote! { PaddingConfig2d::Explicit(#padding1, #padding2) }
             }
+            Self::Circular(padding1, padding2) => {
+                let padding1 = padding1.to_tokens();
+                let padding2 = padding2.to_tokens();
+                quote! { PaddingConfig2d::Circular(#padding1, #padding2) }
+            }
+            Self::Reflect(padding1, padding2) => {
+                let padding1 = padding1.to_tokens();
+                let padding2 = padding2.to_tokens();
+                quote! { PaddingConfig2d::Reflect(#padding1, #padding2) }
+            }
         }
     }
 }
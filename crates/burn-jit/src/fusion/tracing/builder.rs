@@ -125,6 +125,9 @@ impl TraceBuilder {
                 self.scalars.num_bool += 1;
                 var
             }
+            Elem::AtomicFloat(_) | Elem::AtomicInt(_) | Elem::AtomicUInt => {
+                panic!("Atomic scalars can't be fused")
+            }
         }
     }
 
@@ -385,6 +388,10 @@ impl TraceBuilder {
                         &mut local_tensor_ids_input,
                         &mut local_tensor_ids_output,
                     ),
+                    Operator::AtomicAdd(op) => {
+                        mark(&op.value, &mut local_tensor_ids_input);
+                        mark(&op.out, &mut local_tensor_ids_output);
+                    }
                 },
                 Operation::Procedure(proc) => {
                     match proc {
@@ -0,0 +1,392 @@
+use burn_cube::prelude::*;
+use burn_tensor::{
+    ops::{AttentionOptions, WindowAttentionOptions},
+    ElementConversion, Shape, TensorData,
+};
+
+use crate::{
+    ops::{
+        expand, from_data,
+        numeric::{empty_device, full_device},
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Number of keys scored at once by the units of a cube, one key each.
+const ATTENTION_TILE_SIZE: u32 = 32;
+
+#[cube(launch)]
+fn attention_kernel<F: Float>(
+    query: &Tensor<F>,
+    key: &Tensor<F>,
+    value: &Tensor<F>,
+    mask: &Tensor<UInt>,
+    scalars: &Tensor<F>,
+    output: &mut Tensor<F>,
+    quiet_softmax: UInt,
+) {
+    let n_heads = output.shape(1);
+    let seq_length_1 = output.shape(2);
+    let d_v = output.shape(3);
+    let seq_length_2 = key.shape(2);
+    let d_k = query.shape(3);
+
+    // Each cube computes a block of columns of the output of a single query.
+    let row = CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X;
+    if row >= output.shape(0) * n_heads * seq_length_1 {
+        return;
+    }
+
+    let b = row / (n_heads * seq_length_1);
+    let h = row / seq_length_1 % n_heads;
+    let i = row % seq_length_1;
+    let column = CUBE_POS_Z * CUBE_DIM_X + UNIT_POS_X;
+
+    let scale = scalars[0];
+    let mask_value = scalars[1];
+    let query_offset = b * query.stride(0) + h * query.stride(1) + i * query.stride(2);
+    let key_offset = b * key.stride(0) + h * key.stride(1);
+    let value_offset = b * value.stride(0) + h * value.stride(1);
+    let mask_offset = b * mask.stride(0) + h * mask.stride(1) + i * mask.stride(2);
+
+    // Sized as the tile, the number of units of the cube.
+    let mut scores = SharedMemory::<F>::new(32);
+    // Lower than any score, negative literals not being supported.
+    let mut max = F::new(0.0) - F::new(1.0e30);
+    let mut sum = F::new(0.0);
+    let mut accumulated = F::new(0.0);
+
+    let mut start = UInt::new(0);
+    loop {
+        if start >= seq_length_2 {
+            break;
+        }
+
+        let position = start + UNIT_POS_X;
+
+        if position < seq_length_2 {
+            let mut score = F::new(0.0);
+            for d in range(0u32, d_k, Comptime::new(false)) {
+                score += query[query_offset + d * query.stride(3)]
+                    * key[key_offset + position * key.stride(2) + d * key.stride(3)];
+            }
+            score *= scale;
+
+            if mask[mask_offset + position * mask.stride(3)] == UInt::new(1) {
+                score = mask_value;
+            }
+
+            scores[UNIT_POS_X] = score;
+        }
+        sync_units();
+
+        let mut tile_length = seq_length_2 - start;
+        if tile_length > CUBE_DIM_X {
+            tile_length = CUBE_DIM_X;
+        }
+
+        // The online softmax rescales the previous sums to the maximum score seen so far.
+        let mut tile_max = max;
+        for t in range(0u32, tile_length, Comptime::new(false)) {
+            tile_max = F::max(tile_max, scores[t]);
+        }
+        let correction = F::exp(max - tile_max);
+        sum *= correction;
+        accumulated *= correction;
+
+        for t in range(0u32, tile_length, Comptime::new(false)) {
+            let weight = F::exp(scores[t] - tile_max);
+            sum += weight;
+
+            if column < d_v {
+                accumulated += weight
+                    * value
+                        [value_offset + (start + t) * value.stride(2) + column * value.stride(3)];
+            }
+        }
+        max = tile_max;
+        sync_units();
+
+        start += CUBE_DIM_X;
+    }
+
+    if column < d_v {
+        if quiet_softmax == UInt::new(1) {
+            sum += F::new(1.0);
+        }
+
+        output[row * d_v + column] = accumulated / sum;
+    }
+}
+
+/// Computes the scaled dot-product attention without materializing the attention weights.
+///
+/// Each cube handles a query, its units scoring a tile of keys at a time in shared memory, and
+/// accumulating a column of the output each with an online softmax.
+pub(crate) fn attention<R: JitRuntime, E: FloatElement>(
+    query: JitTensor<R, E, 4>,
+    key: JitTensor<R, E, 4>,
+    value: JitTensor<R, E, 4>,
+    mask: Option<JitTensor<R, u32, 4>>,
+    options: AttentionOptions,
+) -> JitTensor<R, E, 4> {
+    let [batch_size, n_heads, seq_length_1, _] = query.shape.dims;
+    let [_, _, seq_length_2, d_v] = value.shape.dims;
+    let shape_out = Shape::new([batch_size, n_heads, seq_length_1, d_v]);
+    let num_rows = batch_size * n_heads * seq_length_1;
+
+    if seq_length_2 == 0 || num_rows * d_v == 0 {
+        return full_device::<R, E, 4>(
+            query.client.clone(),
+            shape_out,
+            query.device.clone(),
+            0.elem(),
+        );
+    }
+
+    let output = empty_device(query.client.clone(), query.device.clone(), shape_out);
+    let scalars = from_data::<R, E, 1>(
+        TensorData::new(vec![options.scale as f32, options.mask_value as f32], [2]),
+        &query.device,
+    );
+    // Without a mask, a false value is broadcasted to every score.
+    let mask = mask.unwrap_or_else(|| {
+        expand(
+            full_device::<R, u32, 4>(
+                query.client.clone(),
+                Shape::new([1, 1, 1, 1]),
+                query.device.clone(),
+                0,
+            ),
+            Shape::new([batch_size, n_heads, seq_length_1, seq_length_2]),
+        )
+    });
+
+    let cube_count_x = f32::ceil(f32::sqrt(num_rows as f32)) as u32;
+    let cube_count_y = num_rows.div_ceil(cube_count_x as usize) as u32;
+    let cube_count_z = d_v.div_ceil(ATTENTION_TILE_SIZE as usize) as u32;
+
+    attention_kernel_launch::<E::FloatPrimitive, R>(
+        query.client.clone(),
+        CubeCount::new(cube_count_x, cube_count_y, cube_count_z),
+        KernelSettings::default().cube_dim(CubeDim::new(ATTENTION_TILE_SIZE, 1, 1)),
+        TensorHandle::new(&query.handle, &query.strides, &query.shape.dims),
+        TensorHandle::new(&key.handle, &key.strides, &key.shape.dims),
+        TensorHandle::new(&value.handle, &value.strides, &value.shape.dims),
+        TensorHandle::new(&mask.handle, &mask.strides, &mask.shape.dims),
+        TensorHandle::new(&scalars.handle, &scalars.strides, &scalars.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        options.quiet_softmax as u32,
+    );
+
+    output
+}
+
+#[cube(launch)]
+fn window_attention_kernel<F: Float>(
+    query: &Tensor<F>,
+    key: &Tensor<F>,
+    value: &Tensor<F>,
+    global: &Tensor<UInt>,
+    scalars: &Tensor<F>,
+    output: &mut Tensor<F>,
+    window_before: UInt,
+    window_after: UInt,
+    has_global: UInt,
+    quiet_softmax: UInt,
+) {
+    let n_heads = output.shape(1);
+    let seq_length_1 = output.shape(2);
+    let d_v = output.shape(3);
+    let seq_length_2 = key.shape(2);
+    let d_k = query.shape(3);
+
+    // Each cube computes a block of columns of the output of a single query.
+    let row = CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X;
+    if row >= output.shape(0) * n_heads * seq_length_1 {
+        return;
+    }
+
+    let b = row / (n_heads * seq_length_1);
+    let h = row / seq_length_1 % n_heads;
+    let i = row % seq_length_1;
+    let column = CUBE_POS_Z * CUBE_DIM_X + UNIT_POS_X;
+
+    let scale = scalars[0];
+    let query_position = i + seq_length_2 - seq_length_1;
+    let query_offset = b * query.stride(0) + h * query.stride(1) + i * query.stride(2);
+    let key_offset = b * key.stride(0) + h * key.stride(1);
+    let value_offset = b * value.stride(0) + h * value.stride(1);
+    let global_offset = b * global.stride(0);
+
+    // Without global tokens, only the keys of the window are visited.
+    let mut first = UInt::new(0);
+    let mut last = seq_length_2;
+    let mut query_global = UInt::new(0);
+    if has_global == UInt::new(0) {
+        if query_position > window_before {
+            first = query_position - window_before;
+        }
+        if query_position + window_after + UInt::new(1) < seq_length_2 {
+            last = query_position + window_after + UInt::new(1);
+        }
+    } else {
+        query_global = global[global_offset + query_position * global.stride(1)];
+    }
+
+    // Sized as the tile, the number of units of the cube.
+    let mut scores = SharedMemory::<F>::new(32);
+    let mut attended = SharedMemory::<UInt>::new(32);
+    // Lower than any score, negative literals not being supported.
+    let mut max = F::new(0.0) - F::new(1.0e30);
+    let mut sum = F::new(0.0);
+    let mut accumulated = F::new(0.0);
+
+    let mut start = first;
+    loop {
+        if start >= last {
+            break;
+        }
+
+        let position = start + UNIT_POS_X;
+        let mut in_window = UInt::new(0);
+
+        if position < last {
+            let within_window = position + window_before >= query_position
+                && position <= query_position + window_after;
+            if within_window {
+                in_window = UInt::new(1);
+            }
+            if query_global == UInt::new(1) {
+                in_window = UInt::new(1);
+            }
+            // Without global tokens, the broadcast false value is read.
+            if global[global_offset + position * global.stride(1)] == UInt::new(1) {
+                in_window = UInt::new(1);
+            }
+
+            if in_window == UInt::new(1) {
+                let mut score = F::new(0.0);
+                for d in range(0u32, d_k, Comptime::new(false)) {
+                    score += query[query_offset + d * query.stride(3)]
+                        * key[key_offset + position * key.stride(2) + d * key.stride(3)];
+                }
+                scores[UNIT_POS_X] = score * scale;
+            }
+        }
+        attended[UNIT_POS_X] = in_window;
+        sync_units();
+
+        let mut tile_length = last - start;
+        if tile_length > CUBE_DIM_X {
+            tile_length = CUBE_DIM_X;
+        }
+
+        // The online softmax rescales the previous sums to the maximum score seen so far, the
+        // keys outside of the window being skipped.
+        let mut tile_max = max;
+        for t in range(0u32, tile_length, Comptime::new(false)) {
+            if attended[t] == UInt::new(1) {
+                tile_max = F::max(tile_max, scores[t]);
+            }
+        }
+        let correction = F::exp(max - tile_max);
+        sum *= correction;
+        accumulated *= correction;
+
+        for t in range(0u32, tile_length, Comptime::new(false)) {
+            if attended[t] == UInt::new(1) {
+                let weight = F::exp(scores[t] - tile_max);
+                sum += weight;
+
+                if column < d_v {
+                    accumulated += weight
+                        * value[value_offset
+                            + (start + t) * value.stride(2)
+                            + column * value.stride(3)];
+                }
+            }
+        }
+        max = tile_max;
+        sync_units();
+
+        start += CUBE_DIM_X;
+    }
+
+    if column < d_v {
+        if quiet_softmax == UInt::new(1) {
+            sum += F::new(1.0);
+        }
+
+        output[row * d_v + column] = accumulated / sum;
+    }
+}
+
+/// Computes the sliding window attention without materializing the attention weights nor the
+/// mask.
+///
+/// Each cube handles a query like the [attention](attention), but only visits the keys of its
+/// window when there are no global tokens.
+pub(crate) fn window_attention<R: JitRuntime, E: FloatElement>(
+    query: JitTensor<R, E, 4>,
+    key: JitTensor<R, E, 4>,
+    value: JitTensor<R, E, 4>,
+    global: Option<JitTensor<R, u32, 2>>,
+    options: WindowAttentionOptions,
+) -> JitTensor<R, E, 4> {
+    let [batch_size, n_heads, seq_length_1, _] = query.shape.dims;
+    let [_, _, seq_length_2, d_v] = value.shape.dims;
+    let shape_out = Shape::new([batch_size, n_heads, seq_length_1, d_v]);
+    let num_rows = batch_size * n_heads * seq_length_1;
+
+    if num_rows * d_v == 0 {
+        return full_device::<R, E, 4>(
+            query.client.clone(),
+            shape_out,
+            query.device.clone(),
+            0.elem(),
+        );
+    }
+
+    let output = empty_device(query.client.clone(), query.device.clone(), shape_out);
+    let scalars = from_data::<R, E, 1>(
+        TensorData::new(vec![options.scale as f32], [1]),
+        &query.device,
+    );
+    let has_global = global.is_some();
+    // Without global tokens, a false value is broadcasted to every key.
+    let global = global.unwrap_or_else(|| {
+        expand(
+            full_device::<R, u32, 2>(
+                query.client.clone(),
+                Shape::new([1, 1]),
+                query.device.clone(),
+                0,
+            ),
+            Shape::new([batch_size, seq_length_2]),
+        )
+    });
+
+    let cube_count_x = f32::ceil(f32::sqrt(num_rows as f32)) as u32;
+    let cube_count_y = num_rows.div_ceil(cube_count_x as usize) as u32;
+    let cube_count_z = d_v.div_ceil(ATTENTION_TILE_SIZE as usize) as u32;
+
+    window_attention_kernel_launch::<E::FloatPrimitive, R>(
+        query.client.clone(),
+        CubeCount::new(cube_count_x, cube_count_y, cube_count_z),
+        KernelSettings::default().cube_dim(CubeDim::new(ATTENTION_TILE_SIZE, 1, 1)),
+        TensorHandle::new(&query.handle, &query.strides, &query.shape.dims),
+        TensorHandle::new(&key.handle, &key.strides, &key.shape.dims),
+        TensorHandle::new(&value.handle, &value.strides, &value.shape.dims),
+        TensorHandle::new(&global.handle, &global.strides, &global.shape.dims),
+        TensorHandle::new(&scalars.handle, &scalars.strides, &scalars.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        options.window_before as u32,
+        options.window_after as u32,
+        has_global as u32,
+        options.quiet_softmax as u32,
+    );
+
+    output
+}
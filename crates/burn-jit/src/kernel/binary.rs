@@ -1,6 +1,5 @@
 use crate::{element::JitElement, tensor::JitTensor, JitRuntime};
 use burn_cube::{frontend::TensorHandle, CubeCountSettings, Execution};
-use burn_tensor::Shape;
 
 /// Creates a binary kernel.
 #[macro_export]
@@ -172,17 +171,10 @@ where
 
         rhs
     } else {
-        let mut shape_out = [0; D];
-        lhs.shape
-            .dims
-            .iter()
-            .zip(rhs.shape.dims.iter())
-            .enumerate()
-            .for_each(|(index, (dim_lhs, dim_rhs))| {
-                shape_out[index] = usize::max(*dim_lhs, *dim_rhs);
-            });
-
-        let shape_out = Shape::new(shape_out);
+        let shape_out = lhs
+            .shape
+            .broadcast(&rhs.shape)
+            .unwrap_or_else(|error| panic!("{error}"));
         let num_elems = shape_out.num_elements();
         let buffer = lhs.client.empty(num_elems * core::mem::size_of::<E>());
         let out = JitTensor::new(lhs.client.clone(), lhs.device, shape_out, buffer);
@@ -0,0 +1,96 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::Shape;
+
+use crate::{
+    kernel::cast, ops::numeric::zeros_device, tensor::JitTensor, FloatElement, IntElement,
+    JitRuntime,
+};
+
+#[cube(launch)]
+fn bincount_kernel<I: Int>(input: &Tensor<I>, counts: &mut Tensor<AtomicI32>) {
+    if ABSOLUTE_POS >= input.shape(0) {
+        return;
+    }
+
+    // The negative values wrap around to bins out of range.
+    let bin = UInt::cast_from(input[ABSOLUTE_POS * input.stride(0)]);
+    if bin < counts.len() {
+        AtomicI32::add(counts, bin, I32::new(1));
+    }
+}
+
+#[cube(launch)]
+fn bincount_weighted_kernel<F: Float, I: Int>(
+    input: &Tensor<I>,
+    weights: &Tensor<F>,
+    sums: &mut Tensor<AtomicF32>,
+) {
+    if ABSOLUTE_POS >= input.shape(0) {
+        return;
+    }
+
+    let bin = UInt::cast_from(input[ABSOLUTE_POS * input.stride(0)]);
+    if bin < sums.len() {
+        let weight = F32::cast_from(weights[ABSOLUTE_POS * weights.stride(0)]);
+        AtomicF32::add(sums, bin, weight);
+    }
+}
+
+/// Counts the occurrences of each value in `[0, num_bins)`, each unit atomically incrementing the
+/// bin of its value.
+pub(crate) fn bincount<R: JitRuntime, I: IntElement>(
+    tensor: JitTensor<R, I, 1>,
+    num_bins: usize,
+) -> JitTensor<R, I, 1> {
+    let num_elems = tensor.shape.dims[0];
+    let counts = zeros_device::<R, I, 1>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        Shape::new([num_bins]),
+    );
+
+    if num_elems * num_bins == 0 {
+        return counts;
+    }
+
+    bincount_kernel_launch::<I::IntPrimitive, R>(
+        tensor.client.clone(),
+        calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorHandle::new(&counts.handle, &counts.strides, &counts.shape.dims),
+    );
+
+    counts
+}
+
+/// Sums the weights of the occurrences of each value in `[0, num_bins)`, each unit atomically
+/// adding its weight to the bin of its value.
+///
+/// The weights are accumulated in single precision, since it's the only floating point atomic
+/// addition supported by every runtime, and the order of the additions isn't deterministic.
+pub(crate) fn bincount_weighted<R: JitRuntime, E: FloatElement, I: IntElement>(
+    tensor: JitTensor<R, I, 1>,
+    weights: JitTensor<R, E, 1>,
+    num_bins: usize,
+) -> JitTensor<R, E, 1> {
+    let num_elems = tensor.shape.dims[0];
+    let sums = zeros_device::<R, f32, 1>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        Shape::new([num_bins]),
+    );
+
+    if num_elems * num_bins > 0 {
+        bincount_weighted_kernel_launch::<E::FloatPrimitive, I::IntPrimitive, R>(
+            tensor.client.clone(),
+            calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+            TensorHandle::new(&weights.handle, &weights.strides, &weights.shape.dims),
+            TensorHandle::new(&sums.handle, &sums.strides, &sums.shape.dims),
+        );
+    }
+
+    cast::<R, f32, E, 1>(sums)
+}
@@ -0,0 +1,169 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::Shape;
+
+use crate::{
+    kernel::into_contiguous,
+    ops::numeric::{empty_device, full_device},
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+
+/// Returns the bit of the value at the index of a row of `size` values packed in `num_bytes`
+/// bytes.
+#[cube]
+fn packed_bit<I: Int>(packed: &Tensor<I>, position: UInt, size: UInt, num_bytes: UInt) -> UInt {
+    let row = position / size;
+    let index = position % size;
+    let byte = UInt::cast_from(packed[row * num_bytes + index / UInt::new(8)]);
+
+    (byte >> (index % UInt::new(8))) & UInt::new(1)
+}
+
+#[cube(launch)]
+fn pack_bits_kernel<I: Int>(mask: &Tensor<UInt>, output: &mut Tensor<I>, size: UInt) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let num_bytes = output.shape(output.rank() - UInt::new(1));
+    let row = ABSOLUTE_POS / num_bytes;
+    let byte = ABSOLUTE_POS % num_bytes;
+
+    // The bits are distinct, so adding them sets them. The last byte of a row only holds the
+    // remaining bits.
+    let start = byte * UInt::new(8);
+    let num_bits = UInt::min(size - start, UInt::new(8));
+    let mut value = UInt::new(0);
+    for bit in range(0u32, num_bits, Comptime::new(false)) {
+        if mask[row * size + start + bit] != UInt::new(0) {
+            value += UInt::new(1) << bit;
+        }
+    }
+
+    output[ABSOLUTE_POS] = I::cast_from(value);
+}
+
+#[cube(launch)]
+fn unpack_bits_kernel<I: Int>(packed: &Tensor<I>, output: &mut Tensor<UInt>, size: UInt) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let num_bytes = packed.shape(packed.rank() - UInt::new(1));
+    output[ABSOLUTE_POS] = packed_bit::<I>(packed, ABSOLUTE_POS, size, num_bytes);
+}
+
+#[cube(launch)]
+fn mask_fill_packed_kernel<F: Float, I: Int>(
+    input: &Tensor<F>,
+    mask: &Tensor<I>,
+    value: &Tensor<F>,
+    output: &mut Tensor<F>,
+    size: UInt,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let num_bytes = mask.shape(mask.rank() - UInt::new(1));
+    if packed_bit::<I>(mask, ABSOLUTE_POS, size, num_bytes) == UInt::new(1) {
+        output[ABSOLUTE_POS] = value[0];
+    } else {
+        output[ABSOLUTE_POS] = input[ABSOLUTE_POS];
+    }
+}
+
+/// Packs the last dimension of a bool tensor into bytes, each invocation setting the bits of one
+/// byte.
+pub(crate) fn pack_bits<R: JitRuntime, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, u32, D>,
+) -> JitTensor<R, I, D> {
+    let tensor = into_contiguous(tensor);
+    let size = tensor.shape.dims[D - 1];
+
+    let mut shape = tensor.shape.clone();
+    shape.dims[D - 1] = size.div_ceil(8);
+    let output = empty_device(tensor.client.clone(), tensor.device.clone(), shape);
+
+    pack_bits_kernel_launch::<I::IntPrimitive, R>(
+        tensor.client.clone(),
+        cube_count(&output.shape),
+        settings(),
+        TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        size as u32,
+    );
+
+    output
+}
+
+/// Unpacks the last dimension of a bitmap into the given number of bool values, each invocation
+/// reading one bit.
+pub(crate) fn unpack_bits<R: JitRuntime, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, I, D>,
+    size: usize,
+) -> JitTensor<R, u32, D> {
+    let tensor = into_contiguous(tensor);
+
+    let mut shape = tensor.shape.clone();
+    shape.dims[D - 1] = size;
+    let output = empty_device(tensor.client.clone(), tensor.device.clone(), shape);
+
+    unpack_bits_kernel_launch::<I::IntPrimitive, R>(
+        tensor.client.clone(),
+        cube_count(&output.shape),
+        settings(),
+        TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        size as u32,
+    );
+
+    output
+}
+
+/// Fills the values of the tensor where the bits of the packed mask are set, reading the bits
+/// without unpacking the mask.
+pub(crate) fn mask_fill_packed<R: JitRuntime, E: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    mask: JitTensor<R, I, D>,
+    value: E,
+) -> JitTensor<R, E, D> {
+    let tensor = into_contiguous(tensor);
+    let mask = into_contiguous(mask);
+    let size = tensor.shape.dims[D - 1];
+
+    let value = full_device::<R, E, 1>(
+        tensor.client.clone(),
+        Shape::new([1]),
+        tensor.device.clone(),
+        value,
+    );
+    let output = empty_device(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    mask_fill_packed_kernel_launch::<E::FloatPrimitive, I::IntPrimitive, R>(
+        tensor.client.clone(),
+        cube_count(&output.shape),
+        settings(),
+        TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorHandle::new(&mask.handle, &mask.strides, &mask.shape.dims),
+        TensorHandle::new(&value.handle, &value.strides, &value.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        size as u32,
+    );
+
+    output
+}
+
+fn cube_count<const D: usize>(shape: &Shape<D>) -> CubeCount {
+    calculate_cube_count_elemwise(shape.num_elements(), SUBCUBE_DIM_APPROX)
+}
+
+fn settings() -> KernelSettings {
+    KernelSettings::default()
+        .vectorize_input(0, 1)
+        .vectorize_output(0, 1)
+}
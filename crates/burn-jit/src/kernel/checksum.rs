@@ -0,0 +1,160 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{Checksum, DType, Reader, Shape};
+
+use crate::{
+    element::JitElement,
+    kernel::into_contiguous,
+    ops::{into_data, numeric::empty_device},
+    tensor::JitTensor,
+    JitRuntime,
+};
+
+/// Number of values, or of partial lanes, summed by each unit.
+const CHECKSUM_BLOCK_SIZE: usize = 256;
+
+/// The 32-bit finalizer of MurmurHash3, matching [Checksum::mix].
+#[cube]
+fn mix(value: UInt) -> UInt {
+    let mut hash = value ^ (value >> UInt::new(16));
+    hash *= UInt::new(0x85EB_CA6B);
+    hash = hash ^ (hash >> UInt::new(13));
+    hash *= UInt::new(0xC2B2_AE35);
+    hash ^ (hash >> UInt::new(16))
+}
+
+/// The hash of a value in a lane, matching [Checksum::hash] for values of at most 32 bits.
+#[cube]
+fn hash(seed: UInt, index: UInt, bits: UInt) -> UInt {
+    mix(mix(index ^ seed) ^ bits ^ mix(seed))
+}
+
+#[cube(launch)]
+fn checksum_kernel(
+    values: &Tensor<UInt>,
+    lanes: &mut Tensor<UInt>,
+    num_values: UInt,
+    half_words: UInt,
+    block_size: UInt,
+) {
+    let start = ABSOLUTE_POS * block_size;
+    if start >= num_values {
+        return;
+    }
+
+    // The values are read as words, two 16-bit values being packed in each of them when
+    // `half_words` is 1, in which case the word is shifted and masked to the selected half.
+    let mask = UInt::new(0xFFFF_FFFF) >> (half_words * UInt::new(16));
+    let mut sum_a = UInt::new(0);
+    let mut sum_b = UInt::new(0);
+    for offset in range(0u32, block_size, Comptime::new(false)) {
+        let index = start + offset;
+        if index < num_values {
+            let shift = (index & half_words) * UInt::new(16);
+            let bits = (values[index >> half_words] >> shift) & mask;
+            sum_a += hash(UInt::new(0x9E37_79B9), index, bits);
+            sum_b += hash(UInt::new(0x85EB_CA6B), index, bits);
+        }
+    }
+
+    lanes[ABSOLUTE_POS * UInt::new(2)] = sum_a;
+    lanes[ABSOLUTE_POS * UInt::new(2) + UInt::new(1)] = sum_b;
+}
+
+#[cube(launch)]
+fn sum_lanes_kernel(
+    input: &Tensor<UInt>,
+    output: &mut Tensor<UInt>,
+    num_blocks: UInt,
+    block_size: UInt,
+) {
+    let start = ABSOLUTE_POS * block_size;
+    if start >= num_blocks {
+        return;
+    }
+
+    let mut sum_a = UInt::new(0);
+    let mut sum_b = UInt::new(0);
+    for offset in range(0u32, block_size, Comptime::new(false)) {
+        let block = start + offset;
+        if block < num_blocks {
+            sum_a += input[block * UInt::new(2)];
+            sum_b += input[block * UInt::new(2) + UInt::new(1)];
+        }
+    }
+
+    output[ABSOLUTE_POS * UInt::new(2)] = sum_a;
+    output[ABSOLUTE_POS * UInt::new(2) + UInt::new(1)] = sum_b;
+}
+
+/// Computes the [checksum](burn_tensor::TensorData::checksum) of a tensor on the device, reading
+/// back only the lanes of the checksum.
+///
+/// The values are hashed by blocks, and the partial lanes of the blocks are summed by a reduction
+/// until a single block remains. Elements of other sizes than 16 or 32 bits are hashed from the
+/// data of the tensor instead.
+pub(crate) fn checksum<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dtype: DType,
+) -> Reader<u64> {
+    let half_words = match core::mem::size_of::<E>() {
+        2 => true,
+        4 => false,
+        _ => return into_data(tensor).map(|data| data.checksum()),
+    };
+
+    let shape = tensor.shape.dims;
+    let num_values = tensor.shape.num_elements();
+    if num_values == 0 {
+        return Reader::Concrete(Checksum::default().finalize(dtype, &shape));
+    }
+
+    let tensor = into_contiguous(tensor);
+    let mut num_blocks = num_values.div_ceil(CHECKSUM_BLOCK_SIZE);
+    let mut lanes = empty_device::<R, u32, 2>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        Shape::new([num_blocks, 2]),
+    );
+
+    // The tensor is bound as words, whatever its element type.
+    checksum_kernel_launch::<R>(
+        tensor.client.clone(),
+        calculate_cube_count_elemwise(num_blocks, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorHandle::new(&lanes.handle, &lanes.strides, &lanes.shape.dims),
+        num_values as u32,
+        half_words as u32,
+        CHECKSUM_BLOCK_SIZE as u32,
+    );
+
+    while num_blocks > 1 {
+        let num_sums = num_blocks.div_ceil(CHECKSUM_BLOCK_SIZE);
+        let sums = empty_device::<R, u32, 2>(
+            lanes.client.clone(),
+            lanes.device.clone(),
+            Shape::new([num_sums, 2]),
+        );
+
+        sum_lanes_kernel_launch::<R>(
+            lanes.client.clone(),
+            calculate_cube_count_elemwise(num_sums, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(&lanes.handle, &lanes.strides, &lanes.shape.dims),
+            TensorHandle::new(&sums.handle, &sums.strides, &sums.shape.dims),
+            num_blocks as u32,
+            CHECKSUM_BLOCK_SIZE as u32,
+        );
+
+        lanes = sums;
+        num_blocks = num_sums;
+    }
+
+    lanes.client.read(lanes.handle.binding()).map(move |bytes| {
+        let lanes = u32::from_bytes(&bytes);
+        Checksum {
+            lanes: [lanes[0], lanes[1]],
+        }
+        .finalize(dtype, &shape)
+    })
+}
@@ -0,0 +1,81 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::Shape;
+
+use crate::{
+    ops::{
+        numeric::{empty_device, zeros_device},
+        reshape,
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn cholesky_kernel<F: Float>(input: &Tensor<F>, lower: &mut Tensor<F>, col: UInt) {
+    let n = lower.shape(2);
+    if ABSOLUTE_POS >= lower.shape(0) * n {
+        return;
+    }
+
+    // Each unit computes an element of the column, the previous columns being complete.
+    let matrix = ABSOLUTE_POS / n;
+    let row = ABSOLUTE_POS % n;
+    if row < col {
+        return;
+    }
+
+    let offset = matrix * lower.stride(0);
+    let input_offset = matrix * input.stride(0);
+
+    let mut pivot = input[input_offset + col * input.stride(1) + col * input.stride(2)];
+    let mut value = input[input_offset + row * input.stride(1) + col * input.stride(2)];
+    for p in range(0u32, col, Comptime::new(false)) {
+        let l_col = lower[offset + col * lower.stride(1) + p * lower.stride(2)];
+        pivot -= l_col * l_col;
+        value -= lower[offset + row * lower.stride(1) + p * lower.stride(2)] * l_col;
+    }
+    pivot = F::sqrt(pivot);
+
+    let mut output = pivot;
+    if row > col {
+        output = value / pivot;
+    }
+    let index = offset + row * lower.stride(1) + col * lower.stride(2);
+    lower[index] = output;
+}
+
+/// Computes the Cholesky decomposition of a batch of symmetric positive-definite matrices, reading
+/// only their lower triangular part.
+///
+/// Each column is computed by a kernel where every unit computes an element of the column.
+pub(crate) fn cholesky<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let shape = tensor.shape.clone();
+    let n = shape.dims[D - 1];
+    let num_matrices = shape.num_elements() / usize::max(n * n, 1);
+
+    if num_matrices * n == 0 {
+        return empty_device(tensor.client.clone(), tensor.device.clone(), shape);
+    }
+
+    let input = reshape(tensor, Shape::new([num_matrices, n, n]));
+    let lower = zeros_device::<R, E, 3>(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([num_matrices, n, n]),
+    );
+
+    for col in 0..n {
+        cholesky_kernel_launch::<E::FloatPrimitive, R>(
+            input.client.clone(),
+            calculate_cube_count_elemwise(num_matrices * n, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+            TensorHandle::new(&lower.handle, &lower.strides, &lower.shape.dims),
+            col as u32,
+        );
+    }
+
+    reshape(lower, shape)
+}
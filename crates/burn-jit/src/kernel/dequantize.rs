@@ -0,0 +1,68 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::Shape;
+
+use crate::{ops::numeric::empty_device, tensor::JitTensor, FloatElement, IntElement, JitRuntime};
+
+#[cube(launch)]
+fn dequantize_matmul_kernel<F: Float, I: Int>(
+    x: &Tensor<F>,
+    weight: &Tensor<I>,
+    scale: &Tensor<F>,
+    output: &mut Tensor<F>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0);
+    let row = (ABSOLUTE_POS / output.stride(1)) % output.shape(1);
+    let col = ABSOLUTE_POS % output.shape(2);
+    let d_input = weight.shape(0);
+
+    let x_offset = batch * x.stride(0) + row * x.stride(1);
+    let mut sum = F::new(0.);
+
+    for k in range(0u32, d_input, Comptime::new(false)) {
+        let value = F::cast_from(weight[k * weight.stride(0) + col * weight.stride(1)]);
+        sum += x[x_offset + k * x.stride(2)] * value;
+    }
+
+    // The scale is shared by the whole column, so it's applied once to the sum.
+    output[ABSOLUTE_POS] = sum * scale[col * scale.stride(0)];
+}
+
+/// Multiplies the input by quantized weights, each invocation converting the integer weights of
+/// a column to floating point values while reducing them with a row of the input, so that the
+/// dequantized weights are never written to memory.
+pub(crate) fn dequantize_matmul<R: JitRuntime, E: FloatElement, I: IntElement>(
+    x: JitTensor<R, E, 3>,
+    weight: JitTensor<R, I, 2>,
+    scale: JitTensor<R, E, 1>,
+) -> JitTensor<R, E, 3> {
+    let [batch_size, seq_length, _] = x.shape.dims;
+    let [_, d_output] = weight.shape.dims;
+
+    let output = empty_device(
+        x.client.clone(),
+        x.device.clone(),
+        Shape::new([batch_size, seq_length, d_output]),
+    );
+
+    let num_elems_output = output.shape.num_elements();
+    let cube_count = calculate_cube_count_elemwise(num_elems_output, SUBCUBE_DIM_APPROX);
+    let settings = KernelSettings::default()
+        .vectorize_input(0, 1)
+        .vectorize_output(0, 1);
+
+    dequantize_matmul_kernel_launch::<E::FloatPrimitive, I::IntPrimitive, R>(
+        x.client,
+        cube_count,
+        settings,
+        TensorHandle::new(&x.handle, &x.strides, &x.shape.dims),
+        TensorHandle::new(&weight.handle, &weight.strides, &weight.shape.dims),
+        TensorHandle::new(&scale.handle, &scale.strides, &scale.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+    );
+
+    output
+}
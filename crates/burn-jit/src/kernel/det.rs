@@ -0,0 +1,261 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::Shape;
+
+use crate::{
+    ops::{
+        numeric::{empty_device, ones_device, zeros_device},
+        reshape,
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn pivot_kernel<F: Float>(
+    a: &mut Tensor<F>,
+    factors: &mut Tensor<F>,
+    signs: &mut Tensor<F>,
+    step: UInt,
+) {
+    let n = a.shape(1);
+    if ABSOLUTE_POS >= a.shape(0) {
+        return;
+    }
+
+    let offset = ABSOLUTE_POS * a.stride(0);
+
+    // The row with the largest pivot is swapped with the current one, flipping the sign.
+    let mut pivot = step;
+    let mut largest = F::abs(a[offset + step * a.stride(1) + step * a.stride(2)]);
+    for i in range(step + UInt::new(1), n, Comptime::new(false)) {
+        let candidate = F::abs(a[offset + i * a.stride(1) + step * a.stride(2)]);
+        if candidate > largest {
+            pivot = i;
+            largest = candidate;
+        }
+    }
+
+    if pivot != step {
+        for c in range(0u32, n, Comptime::new(false)) {
+            let index = offset + step * a.stride(1) + c * a.stride(2);
+            let pivot_index = offset + pivot * a.stride(1) + c * a.stride(2);
+            let value = a[index];
+            a[index] = a[pivot_index];
+            a[pivot_index] = value;
+        }
+        signs[ABSOLUTE_POS] = F::new(0.0) - signs[ABSOLUTE_POS];
+    }
+
+    // Columns without a non-zero pivot are skipped, leaving a zero on the diagonal.
+    let diagonal = a[offset + step * a.stride(1) + step * a.stride(2)];
+    for row in range(step + UInt::new(1), n, Comptime::new(false)) {
+        let mut factor = F::new(0.0);
+        if diagonal != F::new(0.0) {
+            factor = a[offset + row * a.stride(1) + step * a.stride(2)] / diagonal;
+        }
+        factors[ABSOLUTE_POS * n + row] = factor;
+    }
+}
+
+#[cube(launch)]
+fn eliminate_kernel<F: Float>(a: &mut Tensor<F>, factors: &Tensor<F>, step: UInt) {
+    let n = a.shape(1);
+    if ABSOLUTE_POS >= a.len() {
+        return;
+    }
+
+    // Each unit eliminates an element below the pivot row.
+    let matrix = ABSOLUTE_POS / (n * n);
+    let row = (ABSOLUTE_POS / n) % n;
+    let col = ABSOLUTE_POS % n;
+    if row <= step || col < step {
+        return;
+    }
+
+    let offset = matrix * a.stride(0) + col * a.stride(2);
+    let pivot = a[offset + step * a.stride(1)];
+    let index = offset + row * a.stride(1);
+    a[index] -= factors[matrix * n + row] * pivot;
+}
+
+#[cube(launch)]
+fn det_kernel<F: Float>(a: &Tensor<F>, signs: &Tensor<F>, det: &mut Tensor<F>) {
+    if ABSOLUTE_POS >= det.len() {
+        return;
+    }
+
+    let offset = ABSOLUTE_POS * a.stride(0);
+    let mut product = signs[ABSOLUTE_POS];
+    for i in range(0u32, a.shape(1), Comptime::new(false)) {
+        product *= a[offset + i * a.stride(1) + i * a.stride(2)];
+    }
+    det[ABSOLUTE_POS] = product;
+}
+
+#[cube(launch)]
+fn slogdet_kernel<F: Float>(
+    a: &Tensor<F>,
+    signs: &Tensor<F>,
+    sign: &mut Tensor<F>,
+    logabsdet: &mut Tensor<F>,
+) {
+    if ABSOLUTE_POS >= sign.len() {
+        return;
+    }
+
+    let offset = ABSOLUTE_POS * a.stride(0);
+    let mut sign_product = signs[ABSOLUTE_POS];
+    let mut sum = F::new(0.0);
+    for i in range(0u32, a.shape(1), Comptime::new(false)) {
+        let value = a[offset + i * a.stride(1) + i * a.stride(2)];
+        // The sign of zero is zero, for singular matrices.
+        if value < F::new(0.0) {
+            sign_product = F::new(0.0) - sign_product;
+        }
+        if value == F::new(0.0) {
+            sign_product = F::new(0.0);
+        }
+        sum += F::log(F::abs(value));
+    }
+    sign[ABSOLUTE_POS] = sign_product;
+    logabsdet[ABSOLUTE_POS] = sum;
+}
+
+/// Gaussian elimination with partial pivoting of a batch of `[n, n]` matrices, returning the
+/// upper triangular matrices and the signs of the permutations.
+///
+/// Each column is eliminated by a kernel selecting the pivot of every matrix, followed by a
+/// kernel updating the elements below it in parallel.
+fn eliminate<R: JitRuntime, E: FloatElement>(
+    tensor: JitTensor<R, E, 3>,
+) -> (JitTensor<R, E, 3>, JitTensor<R, E, 1>) {
+    let [num_matrices, n, _] = tensor.shape.dims;
+
+    // The elimination is applied in place on a copy of the matrices.
+    let a = match tensor.can_mut() {
+        true => tensor,
+        false => tensor.copy(),
+    };
+    let factors = empty_device::<R, E, 2>(
+        a.client.clone(),
+        a.device.clone(),
+        Shape::new([num_matrices, n]),
+    );
+    let signs = ones_device::<R, E, 1>(
+        a.client.clone(),
+        a.device.clone(),
+        Shape::new([num_matrices]),
+    );
+
+    for step in 0..n {
+        pivot_kernel_launch::<E::FloatPrimitive, R>(
+            a.client.clone(),
+            calculate_cube_count_elemwise(num_matrices, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+            TensorHandle::new(&factors.handle, &factors.strides, &factors.shape.dims),
+            TensorHandle::new(&signs.handle, &signs.strides, &signs.shape.dims),
+            step as u32,
+        );
+        eliminate_kernel_launch::<E::FloatPrimitive, R>(
+            a.client.clone(),
+            calculate_cube_count_elemwise(num_matrices * n * n, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+            TensorHandle::new(&factors.handle, &factors.strides, &factors.shape.dims),
+            step as u32,
+        );
+    }
+
+    (a, signs)
+}
+
+/// The shape of the determinants, keeping the matrix dimensions.
+fn output_shape<const D: usize>(shape: &Shape<D>) -> Shape<D> {
+    let mut dims = shape.dims;
+    dims[D - 2] = 1;
+    dims[D - 1] = 1;
+    Shape::new(dims)
+}
+
+/// Computes the determinant of a batch of square matrices from the Gaussian elimination with
+/// partial pivoting.
+pub(crate) fn det<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let shape = output_shape(&tensor.shape);
+    let n = tensor.shape.dims[D - 1];
+    let num_matrices = shape.num_elements();
+
+    if num_matrices == 0 {
+        return empty_device(tensor.client.clone(), tensor.device.clone(), shape);
+    }
+    // The determinant of an empty matrix is one.
+    if n == 0 {
+        return ones_device(tensor.client.clone(), tensor.device.clone(), shape);
+    }
+
+    let (a, signs) = eliminate(reshape(tensor, Shape::new([num_matrices, n, n])));
+    let det = empty_device::<R, E, 1>(
+        a.client.clone(),
+        a.device.clone(),
+        Shape::new([num_matrices]),
+    );
+    det_kernel_launch::<E::FloatPrimitive, R>(
+        a.client.clone(),
+        calculate_cube_count_elemwise(num_matrices, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&signs.handle, &signs.strides, &signs.shape.dims),
+        TensorHandle::new(&det.handle, &det.strides, &det.shape.dims),
+    );
+
+    reshape(det, shape)
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of a batch
+/// of square matrices from the Gaussian elimination with partial pivoting.
+pub(crate) fn slogdet<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let shape = output_shape(&tensor.shape);
+    let n = tensor.shape.dims[D - 1];
+    let num_matrices = shape.num_elements();
+
+    if num_matrices == 0 {
+        return (
+            empty_device(tensor.client.clone(), tensor.device.clone(), shape.clone()),
+            empty_device(tensor.client.clone(), tensor.device.clone(), shape),
+        );
+    }
+
+    if n == 0 {
+        return (
+            ones_device(tensor.client.clone(), tensor.device.clone(), shape.clone()),
+            zeros_device(tensor.client.clone(), tensor.device.clone(), shape),
+        );
+    }
+
+    let (a, signs) = eliminate(reshape(tensor, Shape::new([num_matrices, n, n])));
+    let sign = empty_device::<R, E, 1>(
+        a.client.clone(),
+        a.device.clone(),
+        Shape::new([num_matrices]),
+    );
+    let logabsdet = empty_device::<R, E, 1>(
+        a.client.clone(),
+        a.device.clone(),
+        Shape::new([num_matrices]),
+    );
+    slogdet_kernel_launch::<E::FloatPrimitive, R>(
+        a.client.clone(),
+        calculate_cube_count_elemwise(num_matrices, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&signs.handle, &signs.strides, &signs.shape.dims),
+        TensorHandle::new(&sign.handle, &sign.strides, &sign.shape.dims),
+        TensorHandle::new(&logabsdet.handle, &logabsdet.strides, &logabsdet.shape.dims),
+    );
+
+    (reshape(sign, shape.clone()), reshape(logabsdet, shape))
+}
@@ -0,0 +1,356 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{DType, Shape};
+
+use crate::{
+    ops::{
+        into_data,
+        numeric::{empty_device, zeros_device},
+        reshape,
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Maximum number of sweeps over every pair of rows and columns.
+const MAX_SWEEPS: usize = 30;
+
+#[cube(launch)]
+fn init_kernel<F: Float>(input: &Tensor<F>, a: &mut Tensor<F>, v: &mut Tensor<F>) {
+    if ABSOLUTE_POS >= a.len() {
+        return;
+    }
+
+    let width = a.shape(2);
+    let n = input.shape(2);
+    let matrix = ABSOLUTE_POS / (width * width);
+    let row = (ABSOLUTE_POS / width) % width;
+    let col = ABSOLUTE_POS % width;
+
+    // The matrices are symmetrized from their lower triangular part, and padded with a row and a
+    // column of zeros to an even size.
+    let mut value = F::new(0.0);
+    if row < n && col < n {
+        let offset = matrix * input.stride(0);
+        value = input[offset + row * input.stride(1) + col * input.stride(2)];
+        if col > row {
+            value = input[offset + col * input.stride(1) + row * input.stride(2)];
+        }
+    }
+    a[ABSOLUTE_POS] = value;
+
+    // The rotations are accumulated starting from the identity.
+    let mut diagonal = F::new(0.0);
+    if row == col {
+        diagonal = F::new(1.0);
+    }
+    v[ABSOLUTE_POS] = diagonal;
+}
+
+#[cube(launch)]
+fn norm_kernel<F: Float>(a: &Tensor<F>, norm: &mut Tensor<F>) {
+    if ABSOLUTE_POS >= norm.len() {
+        return;
+    }
+
+    let mut sum = F::new(0.0);
+    for i in range(0u32, a.shape(1), Comptime::new(false)) {
+        for j in range(0u32, a.shape(2), Comptime::new(false)) {
+            let x = a[ABSOLUTE_POS * a.stride(0) + i * a.stride(1) + j * a.stride(2)];
+            sum += x * x;
+        }
+    }
+    norm[ABSOLUTE_POS] = F::sqrt(sum);
+}
+
+/// The index at the position in the round-robin ordering of the round, the first index being
+/// fixed while the others rotate.
+#[cube]
+fn player(position: UInt, round: UInt, width: UInt) -> UInt {
+    let mut player = UInt::new(0);
+    if position > UInt::new(0) {
+        let cycle = width - UInt::new(1);
+        player = UInt::new(1) + (position - UInt::new(1) + cycle - round) % cycle;
+    }
+    player
+}
+
+#[cube(launch)]
+fn angle_kernel<F: Float>(
+    a: &Tensor<F>,
+    norm: &Tensor<F>,
+    rotations: &mut Tensor<F>,
+    off: &mut Tensor<F>,
+    round: UInt,
+) {
+    let width = a.shape(2);
+    let half = width / UInt::new(2);
+    if ABSOLUTE_POS >= a.shape(0) * half {
+        return;
+    }
+
+    // Each unit computes the rotation of a pair of indices, the pairs of a round being disjoint.
+    let matrix = ABSOLUTE_POS / half;
+    let pair = ABSOLUTE_POS % half;
+    let p = player(pair, round, width);
+    let q = player(width - UInt::new(1) - pair, round, width);
+    let offset = matrix * a.stride(0);
+
+    let alpha = a[offset + p * a.stride(1) + p * a.stride(2)];
+    let beta = a[offset + q * a.stride(1) + q * a.stride(2)];
+    let gamma = a[offset + p * a.stride(1) + q * a.stride(2)];
+
+    let mut cos = F::new(1.0);
+    let mut sin = F::new(0.0);
+    if gamma != F::new(0.0) {
+        // The off-diagonal element relative to the norm, compared to the tolerance by the host.
+        let ratio = F::abs(gamma) / norm[matrix];
+        off[ABSOLUTE_POS] = F::max(off[ABSOLUTE_POS], ratio);
+
+        // The rotation of the smallest angle zeroing gamma.
+        let zeta = (beta - alpha) / (F::new(2.0) * gamma);
+        let mut t = F::new(1.0) / (F::abs(zeta) + F::sqrt(F::new(1.0) + zeta * zeta));
+        if zeta < F::new(0.0) {
+            t = F::new(0.0) - t;
+        }
+        cos = F::new(1.0) / F::sqrt(F::new(1.0) + t * t);
+        sin = cos * t;
+    }
+
+    rotations[ABSOLUTE_POS * UInt::new(2)] = cos;
+    rotations[ABSOLUTE_POS * UInt::new(2) + UInt::new(1)] = sin;
+}
+
+#[cube(launch)]
+fn column_kernel<F: Float>(
+    a: &mut Tensor<F>,
+    v: &mut Tensor<F>,
+    rotations: &Tensor<F>,
+    round: UInt,
+) {
+    let width = a.shape(2);
+    let half = width / UInt::new(2);
+    if ABSOLUTE_POS >= a.shape(0) * half * width {
+        return;
+    }
+
+    // Each unit rotates the pair of columns of a row of A and V.
+    let rotation = ABSOLUTE_POS / width;
+    let row = ABSOLUTE_POS % width;
+    let matrix = rotation / half;
+    let pair = rotation % half;
+    let p = player(pair, round, width);
+    let q = player(width - UInt::new(1) - pair, round, width);
+    let cos = rotations[rotation * UInt::new(2)];
+    let sin = rotations[rotation * UInt::new(2) + UInt::new(1)];
+
+    let a_index_p = matrix * a.stride(0) + row * a.stride(1) + p * a.stride(2);
+    let a_index_q = matrix * a.stride(0) + row * a.stride(1) + q * a.stride(2);
+    let x = a[a_index_p];
+    let y = a[a_index_q];
+    a[a_index_p] = x * cos - y * sin;
+    a[a_index_q] = x * sin + y * cos;
+
+    let v_index_p = matrix * v.stride(0) + row * v.stride(1) + p * v.stride(2);
+    let v_index_q = matrix * v.stride(0) + row * v.stride(1) + q * v.stride(2);
+    let v_x = v[v_index_p];
+    let v_y = v[v_index_q];
+    v[v_index_p] = v_x * cos - v_y * sin;
+    v[v_index_q] = v_x * sin + v_y * cos;
+}
+
+#[cube(launch)]
+fn row_kernel<F: Float>(a: &mut Tensor<F>, rotations: &Tensor<F>, round: UInt) {
+    let width = a.shape(2);
+    let half = width / UInt::new(2);
+    if ABSOLUTE_POS >= a.shape(0) * half * width {
+        return;
+    }
+
+    // Each unit rotates the pair of rows of a column of A.
+    let rotation = ABSOLUTE_POS / width;
+    let col = ABSOLUTE_POS % width;
+    let matrix = rotation / half;
+    let pair = rotation % half;
+    let p = player(pair, round, width);
+    let q = player(width - UInt::new(1) - pair, round, width);
+    let cos = rotations[rotation * UInt::new(2)];
+    let sin = rotations[rotation * UInt::new(2) + UInt::new(1)];
+
+    let index_p = matrix * a.stride(0) + p * a.stride(1) + col * a.stride(2);
+    let index_q = matrix * a.stride(0) + q * a.stride(1) + col * a.stride(2);
+    let x = a[index_p];
+    let y = a[index_q];
+    a[index_p] = x * cos - y * sin;
+    a[index_q] = x * sin + y * cos;
+}
+
+#[cube(launch)]
+fn output_kernel<F: Float>(
+    a: &Tensor<F>,
+    v: &Tensor<F>,
+    eigenvalues: &mut Tensor<F>,
+    vectors: &mut Tensor<F>,
+) {
+    let n = vectors.shape(2);
+    if ABSOLUTE_POS >= eigenvalues.len() {
+        return;
+    }
+
+    let matrix = ABSOLUTE_POS / n;
+    let col = ABSOLUTE_POS % n;
+    let offset = matrix * a.stride(0);
+    let value = a[offset + col * a.stride(1) + col * a.stride(2)];
+
+    // The eigenvalues are sorted in ascending order by counting the smaller ones, ties being
+    // ordered by index.
+    let mut rank = UInt::new(0);
+    for i in range(0u32, n, Comptime::new(false)) {
+        let other = a[offset + i * a.stride(1) + i * a.stride(2)];
+        if other < value || (other == value && i < col) {
+            rank += UInt::new(1);
+        }
+    }
+
+    eigenvalues[matrix * n + rank] = value;
+    for i in range(0u32, n, Comptime::new(false)) {
+        vectors[matrix * n * n + i * n + rank] =
+            v[matrix * v.stride(0) + i * v.stride(1) + col * v.stride(2)];
+    }
+}
+
+/// Computes the eigendecomposition of a batch of symmetric matrices with two-sided Jacobi
+/// rotations, reading only their lower triangular part.
+///
+/// Each round of the round-robin ordering of the indices launches a kernel computing the disjoint
+/// rotations of every matrix, followed by kernels applying them to the columns and then to the
+/// rows. The convergence is checked after each sweep when the data can be read synchronously,
+/// otherwise the maximum number of sweeps is always executed.
+pub(crate) fn eigh<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let dims = tensor.shape.dims;
+    let n = dims[D - 1];
+
+    let client = tensor.client.clone();
+    let device = tensor.device.clone();
+    let num_matrices = dims[..D - 2].iter().product::<usize>();
+    let shape = |rows: usize, cols: usize| {
+        let mut dims = dims;
+        dims[D - 2] = rows;
+        dims[D - 1] = cols;
+        Shape::new(dims)
+    };
+
+    if num_matrices * n == 0 {
+        return (
+            empty_device(client.clone(), device.clone(), shape(1, n)),
+            empty_device(client, device, shape(n, n)),
+        );
+    }
+
+    let input = reshape(tensor, Shape::new([num_matrices, n, n]));
+    let width = n + n % 2;
+    let a = empty_device::<R, E, 3>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, width, width]),
+    );
+    let v = empty_device::<R, E, 3>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, width, width]),
+    );
+
+    init_kernel_launch::<E::FloatPrimitive, R>(
+        client.clone(),
+        calculate_cube_count_elemwise(num_matrices * width * width, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&v.handle, &v.strides, &v.shape.dims),
+    );
+
+    // The rotations are invariant to the Frobenius norm, used as the scale of the tolerance.
+    let norm = empty_device::<R, E, 1>(client.clone(), device.clone(), Shape::new([num_matrices]));
+    norm_kernel_launch::<E::FloatPrimitive, R>(
+        client.clone(),
+        calculate_cube_count_elemwise(num_matrices, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&norm.handle, &norm.strides, &norm.shape.dims),
+    );
+
+    let tolerance = match E::dtype() {
+        DType::F64 => 1e-14,
+        _ => 1e-6,
+    };
+    let num_pairs = num_matrices * width / 2;
+    let rotations =
+        empty_device::<R, E, 1>(client.clone(), device.clone(), Shape::new([num_pairs * 2]));
+
+    for _ in 0..MAX_SWEEPS {
+        let off = zeros_device::<R, E, 1>(client.clone(), device.clone(), Shape::new([num_pairs]));
+
+        for round in 0..width - 1 {
+            angle_kernel_launch::<E::FloatPrimitive, R>(
+                client.clone(),
+                calculate_cube_count_elemwise(num_pairs, SUBCUBE_DIM_APPROX),
+                KernelSettings::default(),
+                TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+                TensorHandle::new(&norm.handle, &norm.strides, &norm.shape.dims),
+                TensorHandle::new(&rotations.handle, &rotations.strides, &rotations.shape.dims),
+                TensorHandle::new(&off.handle, &off.strides, &off.shape.dims),
+                round as u32,
+            );
+            column_kernel_launch::<E::FloatPrimitive, R>(
+                client.clone(),
+                calculate_cube_count_elemwise(num_pairs * width, SUBCUBE_DIM_APPROX),
+                KernelSettings::default(),
+                TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+                TensorHandle::new(&v.handle, &v.strides, &v.shape.dims),
+                TensorHandle::new(&rotations.handle, &rotations.strides, &rotations.shape.dims),
+                round as u32,
+            );
+            row_kernel_launch::<E::FloatPrimitive, R>(
+                client.clone(),
+                calculate_cube_count_elemwise(num_pairs * width, SUBCUBE_DIM_APPROX),
+                KernelSettings::default(),
+                TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+                TensorHandle::new(&rotations.handle, &rotations.strides, &rotations.shape.dims),
+                round as u32,
+            );
+        }
+
+        let converged = into_data(off)
+            .read_sync()
+            .map(|data| data.iter::<f64>().all(|off| off <= tolerance));
+        if converged == Some(true) {
+            break;
+        }
+    }
+
+    let eigenvalues = empty_device::<R, E, 3>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, 1, n]),
+    );
+    let vectors = empty_device::<R, E, 3>(client.clone(), device, Shape::new([num_matrices, n, n]));
+    output_kernel_launch::<E::FloatPrimitive, R>(
+        client,
+        calculate_cube_count_elemwise(num_matrices * n, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&v.handle, &v.strides, &v.shape.dims),
+        TensorHandle::new(
+            &eigenvalues.handle,
+            &eigenvalues.strides,
+            &eigenvalues.shape.dims,
+        ),
+        TensorHandle::new(&vectors.handle, &vectors.strides, &vectors.shape.dims),
+    );
+
+    (
+        reshape(eigenvalues, shape(1, n)),
+        reshape(vectors, shape(n, n)),
+    )
+}
@@ -0,0 +1,117 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{ops::einsum::Contraction, Shape, TensorData};
+
+use crate::{
+    ops::{from_data, numeric::empty_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn contract_kernel<F: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    output: &mut Tensor<F>,
+    info: &Tensor<UInt>,
+    num_summed: UInt,
+    summed_elements: UInt,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    // The info holds the size and the strides of both operands for the output labels, followed by
+    // the summed labels, with a stride of zero for broadcast and missing dimensions.
+    let rank = output.rank();
+    let mut lhs_offset = UInt::new(0);
+    let mut rhs_offset = UInt::new(0);
+    let mut remaining = ABSOLUTE_POS;
+    for i in range(0u32, rank, Comptime::new(false)) {
+        let base = (rank - UInt::new(1) - i) * UInt::new(3);
+        let size = info[base];
+        let coordinate = remaining % size;
+        remaining /= size;
+        lhs_offset += coordinate * info[base + UInt::new(1)];
+        rhs_offset += coordinate * info[base + UInt::new(2)];
+    }
+
+    // Each unit sums the products over every combination of the summed labels.
+    let summed_base = rank * UInt::new(3);
+    let mut sum = F::new(0.0);
+    for element in range(0u32, summed_elements, Comptime::new(false)) {
+        let mut lhs_index = lhs_offset;
+        let mut rhs_index = rhs_offset;
+        let mut summed_remaining = element;
+        for j in range(0u32, num_summed, Comptime::new(false)) {
+            let summed_info = summed_base + (num_summed - UInt::new(1) - j) * UInt::new(3);
+            let summed_size = info[summed_info];
+            let summed_coordinate = summed_remaining % summed_size;
+            summed_remaining /= summed_size;
+            lhs_index += summed_coordinate * info[summed_info + UInt::new(1)];
+            rhs_index += summed_coordinate * info[summed_info + UInt::new(2)];
+        }
+        sum += lhs[lhs_index] * rhs[rhs_index];
+    }
+    output[ABSOLUTE_POS] = sum;
+}
+
+/// The stride of an operand for the given label, zero when it's missing or broadcast.
+fn label_stride<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: &JitTensor<R, E, D>,
+    labels: &[usize],
+    label: usize,
+    size: usize,
+) -> u32 {
+    match labels.iter().position(|l| *l == label) {
+        Some(dim) if tensor.shape.dims[dim] == size => tensor.strides[dim] as u32,
+        _ => 0,
+    }
+}
+
+/// Computes a contraction of two tensors with a single fused kernel, with one unit per output
+/// element.
+///
+/// The operands are read through their strides, so permuted and broadcast dimensions don't
+/// require any copy, and the intermediate products are never materialized.
+pub(crate) fn contract<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    contraction: Contraction,
+) -> JitTensor<R, E, D> {
+    let shape = Shape::new(contraction.output_shape::<D>());
+    let output = empty_device::<R, E, D>(lhs.client.clone(), lhs.device.clone(), shape);
+    let num_elements = output.shape.num_elements();
+
+    if num_elements == 0 {
+        return output;
+    }
+
+    let summed = contraction.summed();
+    let mut info = Vec::with_capacity(3 * (D + summed.len()));
+    for label in contraction.output.iter().chain(summed.iter()) {
+        let size = contraction.sizes[*label];
+        info.push(size as u32);
+        info.push(label_stride(&lhs, &contraction.lhs, *label, size));
+        info.push(label_stride(&rhs, &contraction.rhs, *label, size));
+    }
+    let summed_elements = summed
+        .iter()
+        .map(|label| contraction.sizes[*label])
+        .product::<usize>();
+    let info_length = info.len();
+    let info = from_data::<R, u32, 1>(TensorData::new(info, [info_length]), &lhs.device);
+
+    contract_kernel_launch::<E::FloatPrimitive, R>(
+        lhs.client.clone(),
+        calculate_cube_count_elemwise(num_elements, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&lhs.handle, &lhs.strides, &lhs.shape.dims),
+        TensorHandle::new(&rhs.handle, &rhs.strides, &rhs.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        TensorHandle::new(&info.handle, &info.strides, &info.shape.dims),
+        summed.len() as u32,
+        summed_elements as u32,
+    );
+
+    output
+}
@@ -0,0 +1,311 @@
+// The cube macro only supports literals, so the butterfly angles can't use the `PI` constant.
+#![allow(clippy::approx_constant)]
+
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{
+    ops::fft::{bluestein_chirp, bluestein_kernel, bluestein_size},
+    Complex64, ElementConversion, Shape, TensorData,
+};
+
+use crate::{
+    ops::{
+        from_data,
+        numeric::{empty_device, mul_scalar},
+        reshape,
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn bit_reverse_kernel<F: Float>(
+    input_real: &Tensor<F>,
+    input_imag: &Tensor<F>,
+    output_real: &mut Tensor<F>,
+    output_imag: &mut Tensor<F>,
+    bits: UInt,
+) {
+    if ABSOLUTE_POS >= output_real.len() {
+        return;
+    }
+
+    let size = output_real.shape(1);
+    let row = ABSOLUTE_POS / size;
+    let index = ABSOLUTE_POS % size;
+
+    let mut remaining = index;
+    let mut reversed = UInt::new(0);
+    for _bit in range(0u32, bits, Comptime::new(false)) {
+        reversed = reversed * UInt::new(2) + remaining % UInt::new(2);
+        remaining /= UInt::new(2);
+    }
+
+    output_real[row * size + reversed] =
+        input_real[row * input_real.stride(0) + index * input_real.stride(1)];
+    output_imag[row * size + reversed] =
+        input_imag[row * input_imag.stride(0) + index * input_imag.stride(1)];
+}
+
+#[cube(launch)]
+fn butterfly_kernel<F: Float>(
+    real: &mut Tensor<F>,
+    imag: &mut Tensor<F>,
+    half: UInt,
+    forward: Comptime<bool>,
+) {
+    let size = real.shape(1);
+    let butterflies_per_row = size / UInt::new(2);
+    if ABSOLUTE_POS >= real.shape(0) * butterflies_per_row {
+        return;
+    }
+
+    let row = ABSOLUTE_POS / butterflies_per_row;
+    let butterfly = ABSOLUTE_POS % butterflies_per_row;
+    let k = butterfly % half;
+    let even = row * size + (butterfly / half) * UInt::new(2) * half + k;
+    let odd = even + half;
+
+    let mut angle = F::new(3.1415927) * F::cast_from(k) / F::cast_from(half);
+    if Comptime::get(forward) {
+        angle = F::new(0.0) - angle;
+    }
+    let cos = F::cos(angle);
+    let sin = F::sin(angle);
+
+    let odd_real = real[odd] * cos - imag[odd] * sin;
+    let odd_imag = real[odd] * sin + imag[odd] * cos;
+    let even_real = real[even];
+    let even_imag = imag[even];
+
+    real[even] = even_real + odd_real;
+    imag[even] = even_imag + odd_imag;
+    real[odd] = even_real - odd_real;
+    imag[odd] = even_imag - odd_imag;
+}
+
+#[cube(launch)]
+fn chirp_kernel<F: Float>(
+    input_real: &Tensor<F>,
+    input_imag: &Tensor<F>,
+    chirp_real: &Tensor<F>,
+    chirp_imag: &Tensor<F>,
+    output_real: &mut Tensor<F>,
+    output_imag: &mut Tensor<F>,
+) {
+    if ABSOLUTE_POS >= output_real.len() {
+        return;
+    }
+
+    let size = output_real.shape(1);
+    let row = ABSOLUTE_POS / size;
+    let index = ABSOLUTE_POS % size;
+
+    // The signal is truncated or padded with zeros to the size of the output.
+    let mut real = F::new(0.0);
+    let mut imag = F::new(0.0);
+    if index < input_real.shape(1) {
+        let a = input_real[row * input_real.stride(0) + index * input_real.stride(1)];
+        let b = input_imag[row * input_imag.stride(0) + index * input_imag.stride(1)];
+        let c = chirp_real[index];
+        let d = chirp_imag[index];
+
+        real = a * c - b * d;
+        imag = a * d + b * c;
+    }
+
+    output_real[ABSOLUTE_POS] = real;
+    output_imag[ABSOLUTE_POS] = imag;
+}
+
+/// Computes the discrete Fourier transform along the last dimension.
+///
+/// Sizes that are powers of two are transformed with the iterative radix-2 algorithm, a kernel
+/// permuting the values in bit reversed order followed by one kernel per stage of butterflies.
+/// The other sizes use Bluestein's algorithm, a convolution with a chirp computed with radix-2
+/// transforms of a larger size.
+pub(crate) fn fft<R: JitRuntime, E: FloatElement, const D: usize>(
+    real: JitTensor<R, E, D>,
+    imag: JitTensor<R, E, D>,
+    inverse: bool,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let shape = real.shape.clone();
+    let n = shape.dims[D - 1];
+    if n <= 1 || shape.num_elements() == 0 {
+        return (real, imag);
+    }
+
+    let num_rows = shape.num_elements() / n;
+    let real = reshape(real, Shape::new([num_rows, n]));
+    let imag = reshape(imag, Shape::new([num_rows, n]));
+
+    let (real, imag) = match n.is_power_of_two() {
+        true => radix2(real, imag, inverse),
+        false => bluestein(real, imag, inverse),
+    };
+    let (real, imag) = match inverse {
+        true => {
+            let scale: E = (1.0 / n as f64).elem();
+            (mul_scalar(real, scale), mul_scalar(imag, scale))
+        }
+        false => (real, imag),
+    };
+
+    (reshape(real, shape.clone()), reshape(imag, shape))
+}
+
+/// Unnormalized radix-2 transform of rows whose size is a power of two.
+fn radix2<R: JitRuntime, E: FloatElement>(
+    real: JitTensor<R, E, 2>,
+    imag: JitTensor<R, E, 2>,
+    inverse: bool,
+) -> (JitTensor<R, E, 2>, JitTensor<R, E, 2>) {
+    let [num_rows, size] = real.shape.dims;
+    let client = real.client.clone();
+    let output_real = empty_device::<R, E, 2>(
+        client.clone(),
+        real.device.clone(),
+        Shape::new([num_rows, size]),
+    );
+    let output_imag = empty_device::<R, E, 2>(
+        client.clone(),
+        real.device.clone(),
+        Shape::new([num_rows, size]),
+    );
+
+    bit_reverse_kernel_launch::<E::FloatPrimitive, R>(
+        client.clone(),
+        calculate_cube_count_elemwise(num_rows * size, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&real.handle, &real.strides, &real.shape.dims),
+        TensorHandle::new(&imag.handle, &imag.strides, &imag.shape.dims),
+        TensorHandle::new(
+            &output_real.handle,
+            &output_real.strides,
+            &output_real.shape.dims,
+        ),
+        TensorHandle::new(
+            &output_imag.handle,
+            &output_imag.strides,
+            &output_imag.shape.dims,
+        ),
+        size.trailing_zeros(),
+    );
+
+    // The stages are launched in order, each one combining pairs of transforms of size `half`.
+    let mut half = 1;
+    while half < size {
+        butterfly_kernel_launch::<E::FloatPrimitive, R>(
+            client.clone(),
+            calculate_cube_count_elemwise(num_rows * size / 2, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(
+                &output_real.handle,
+                &output_real.strides,
+                &output_real.shape.dims,
+            ),
+            TensorHandle::new(
+                &output_imag.handle,
+                &output_imag.strides,
+                &output_imag.shape.dims,
+            ),
+            half as u32,
+            !inverse,
+        );
+        half *= 2;
+    }
+
+    (output_real, output_imag)
+}
+
+/// Unnormalized transform of rows of any size with Bluestein's algorithm.
+fn bluestein<R: JitRuntime, E: FloatElement>(
+    real: JitTensor<R, E, 2>,
+    imag: JitTensor<R, E, 2>,
+    inverse: bool,
+) -> (JitTensor<R, E, 2>, JitTensor<R, E, 2>) {
+    let [num_rows, n] = real.shape.dims;
+    let size = bluestein_size(n);
+    let chirp = bluestein_chirp(n, inverse);
+
+    // The normalization of the inverse transform of the convolution is applied to its kernel.
+    let scale = 1.0 / size as f64;
+    let kernel: Vec<Complex64> = bluestein_kernel(&chirp, size)
+        .into_iter()
+        .map(|value| value * scale)
+        .collect();
+
+    let (chirp_real, chirp_imag) = upload::<R, E>(&chirp, &real);
+    let (kernel_real, kernel_imag) = upload::<R, E>(&kernel, &real);
+
+    let (real, imag) = mul_chirp(real, imag, &chirp_real, &chirp_imag, [num_rows, size]);
+    let (real, imag) = radix2(real, imag, false);
+    let (real, imag) = mul_chirp(real, imag, &kernel_real, &kernel_imag, [num_rows, size]);
+    let (real, imag) = radix2(real, imag, true);
+
+    mul_chirp(real, imag, &chirp_real, &chirp_imag, [num_rows, n])
+}
+
+/// Multiplies the rows by the chirp, truncating or padding them with zeros to the given shape.
+fn mul_chirp<R: JitRuntime, E: FloatElement>(
+    real: JitTensor<R, E, 2>,
+    imag: JitTensor<R, E, 2>,
+    chirp_real: &JitTensor<R, E, 1>,
+    chirp_imag: &JitTensor<R, E, 1>,
+    shape: [usize; 2],
+) -> (JitTensor<R, E, 2>, JitTensor<R, E, 2>) {
+    let client = real.client.clone();
+    let output_real =
+        empty_device::<R, E, 2>(client.clone(), real.device.clone(), Shape::new(shape));
+    let output_imag =
+        empty_device::<R, E, 2>(client.clone(), real.device.clone(), Shape::new(shape));
+
+    chirp_kernel_launch::<E::FloatPrimitive, R>(
+        client,
+        calculate_cube_count_elemwise(shape[0] * shape[1], SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&real.handle, &real.strides, &real.shape.dims),
+        TensorHandle::new(&imag.handle, &imag.strides, &imag.shape.dims),
+        TensorHandle::new(
+            &chirp_real.handle,
+            &chirp_real.strides,
+            &chirp_real.shape.dims,
+        ),
+        TensorHandle::new(
+            &chirp_imag.handle,
+            &chirp_imag.strides,
+            &chirp_imag.shape.dims,
+        ),
+        TensorHandle::new(
+            &output_real.handle,
+            &output_real.strides,
+            &output_real.shape.dims,
+        ),
+        TensorHandle::new(
+            &output_imag.handle,
+            &output_imag.strides,
+            &output_imag.shape.dims,
+        ),
+    );
+
+    (output_real, output_imag)
+}
+
+/// Creates the tensors of the real and imaginary parts of the values on the device of the tensor.
+fn upload<R: JitRuntime, E: FloatElement>(
+    values: &[Complex64],
+    tensor: &JitTensor<R, E, 2>,
+) -> (JitTensor<R, E, 1>, JitTensor<R, E, 1>) {
+    let real = values
+        .iter()
+        .map(|value| value.re as f32)
+        .collect::<Vec<_>>();
+    let imag = values
+        .iter()
+        .map(|value| value.im as f32)
+        .collect::<Vec<_>>();
+
+    (
+        from_data::<R, E, 1>(TensorData::new(real, [values.len()]), &tensor.device),
+        from_data::<R, E, 1>(TensorData::new(imag, [values.len()]), &tensor.device),
+    )
+}
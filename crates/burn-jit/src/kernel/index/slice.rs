@@ -61,6 +61,119 @@ impl SliceComputeShader {
     }
 }
 
+#[derive(new)]
+struct SliceWithStepsEagerKernel<R: JitRuntime, E: JitElement> {
+    rank: usize,
+    _runtime: PhantomData<R>,
+    _elem: PhantomData<E>,
+}
+
+pub struct SliceWithStepsComputeShader {
+    input: Variable,
+    output: Variable,
+    rank: usize,
+}
+
+impl SliceWithStepsComputeShader {
+    pub fn expand(self, scope: &mut Scope) {
+        let input = self.input;
+        let output = self.output;
+        let id = Variable::AbsolutePos;
+
+        let offset_input = scope.zero(Elem::UInt);
+        let offset_local = scope.create_local(Elem::UInt);
+
+        let stride_input = scope.create_local(Elem::UInt);
+        let stride_output = scope.create_local(Elem::UInt);
+        let shape_output = scope.create_local(Elem::UInt);
+        let first = scope.create_local(Elem::UInt);
+        let step = scope.create_local(Elem::UInt);
+        let reversed = scope.create_local(Elem::UInt);
+        let reversed_bool = scope.create_local(Elem::Bool);
+
+        for i in 0..self.rank {
+            cpa!(scope, stride_input = stride(input, i));
+            cpa!(scope, stride_output = stride(output, i));
+            cpa!(scope, shape_output = shape(output, i));
+            cpa!(
+                scope,
+                first = cast(Variable::GlobalScalar(i as u16, Elem::UInt))
+            );
+            cpa!(
+                scope,
+                step = cast(Variable::GlobalScalar((self.rank + i) as u16, Elem::UInt))
+            );
+            cpa!(
+                scope,
+                reversed = cast(Variable::GlobalScalar(
+                    (2 * self.rank + i) as u16,
+                    Elem::UInt
+                ))
+            );
+            cpa!(scope, reversed_bool = reversed == 1u32);
+
+            cpa!(scope, offset_local = id / stride_output);
+            cpa!(scope, offset_local = offset_local % shape_output);
+            cpa!(scope, offset_local = offset_local * step);
+
+            cpa!(scope, if(reversed_bool).then(|scope| {
+                cpa!(scope, offset_local = first - offset_local);
+            }).else(|scope| {
+                cpa!(scope, offset_local = first + offset_local);
+            }));
+            cpa!(scope, offset_local = offset_local * stride_input);
+
+            cpa!(scope, offset_input += offset_local);
+        }
+
+        let result = scope.create_local(input.item());
+        cpa!(scope, result = input[offset_input]);
+        cpa!(scope, output[id] = result);
+    }
+}
+
+impl<R: JitRuntime, E: JitElement> Kernel for SliceWithStepsEagerKernel<R, E> {
+    fn define(&self) -> KernelDefinition {
+        let mut scope = Scope::root();
+        let item = E::cube_elem().into();
+
+        let input = Variable::GlobalInputArray(0, item);
+        let output = Variable::GlobalOutputArray(0, item);
+
+        scope.write_global_custom(output);
+
+        SliceWithStepsComputeShader {
+            input,
+            output,
+            rank: self.rank,
+        }
+        .expand(&mut scope);
+
+        let input = InputInfo::Array {
+            item,
+            visibility: Visibility::Read,
+        };
+        let slices = InputInfo::Scalar {
+            elem: Elem::UInt,
+            size: 3 * self.rank,
+        };
+        let output = OutputInfo::Array { item };
+
+        let info = KernelExpansion {
+            inputs: vec![input, slices],
+            outputs: vec![output],
+            scope,
+        };
+
+        let settings = KernelSettings::default();
+        KernelIntegrator::new(info).integrate(settings)
+    }
+
+    fn id(&self) -> String {
+        format!("{:?}-rank={:?}", core::any::TypeId::of::<Self>(), self.rank)
+    }
+}
+
 impl<R: JitRuntime, E: JitElement> Kernel for SliceEagerKernel<R, E> {
     fn define(&self) -> KernelDefinition {
         let mut scope = Scope::root();
@@ -146,3 +259,53 @@ pub(crate) fn slice_on_output<R: JitRuntime, E: JitElement, const D1: usize, con
 
     output
 }
+
+pub(crate) fn slice_with_steps<R: JitRuntime, E: JitElement, const D1: usize, const D2: usize>(
+    tensor: JitTensor<R, E, D1>,
+    indices: [Range<usize>; D2],
+    steps: [isize; D2],
+) -> JitTensor<R, E, D1> {
+    let mut dims = tensor.shape.dims;
+    let mut firsts = [0; D1];
+    let mut step_sizes = [1; D1];
+    let mut reversed = [0; D1];
+    for i in 0..D2 {
+        let step = steps[i].unsigned_abs();
+        dims[i] = (indices[i].end - indices[i].start).div_ceil(step);
+        step_sizes[i] = step;
+        match steps[i] < 0 {
+            true => {
+                firsts[i] = indices[i].end - 1;
+                reversed[i] = 1;
+            }
+            false => firsts[i] = indices[i].start,
+        }
+    }
+    let shape_output = Shape::new(dims);
+    let output = empty_device(tensor.client.clone(), tensor.device.clone(), shape_output);
+
+    let scalars: Vec<i32> = firsts
+        .into_iter()
+        .chain(step_sizes)
+        .chain(reversed)
+        .map(|value| (value as i32).elem())
+        .collect();
+
+    let kernel = SliceWithStepsEagerKernel::<R, E>::new(D1);
+
+    Execution::start(kernel, tensor.client)
+        .inputs(&[TensorHandle::<R>::new(
+            &tensor.handle,
+            &tensor.strides,
+            &tensor.shape.dims,
+        )])
+        .outputs(&[TensorHandle::new(
+            &output.handle,
+            &output.strides,
+            &output.shape.dims,
+        )])
+        .with_scalars(&scalars)
+        .execute(CubeCountSettings::Output { pos: 0 });
+
+    output
+}
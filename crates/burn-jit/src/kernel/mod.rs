@@ -15,18 +15,69 @@ pub use unary::*;
 
 pub use burn_cube::{Kernel, SUBCUBE_DIM_APPROX};
 
+/// Attention kernels
+pub mod attention;
+/// Bin counting kernels
+pub mod bincount;
+/// Bit packing kernels
+pub mod bits;
+/// Checksum kernels
+pub mod checksum;
+/// Cholesky decomposition kernels
+pub mod cholesky;
 /// Convolution kernels
 pub mod conv;
+/// Dequantizing matmul kernels
+pub mod dequantize;
+/// Determinant kernels
+pub mod det;
+/// Symmetric eigendecomposition kernels
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub mod eigh;
+/// Einsum contraction kernels
+pub mod einsum;
+/// Fourier transform kernels
+pub mod fft;
 /// Interpolation kernels
 pub mod interpolate;
 /// Matmul kernels
 pub mod matmul;
+/// Mode reduction kernels
+pub mod mode;
+/// Morphology kernels
+pub mod morphology;
+/// Multinomial sampling kernels
+pub mod multinomial;
+/// Non-maximum suppression kernels
+pub mod nms;
+/// Pixel shuffle kernels
+pub mod pixel_shuffle;
 /// Pooling kernels
 pub mod pool;
 /// Pseudo-random number generator kernels
 pub mod prng;
+/// QR decomposition kernels
+pub mod qr;
 /// Reduction algorithms
 pub mod reduce;
+/// Resampling kernels
+pub mod resample;
+/// Selective scan kernels
+pub mod scan;
+/// Segment reduction kernels
+pub mod segment;
+/// Linear system solver kernels
+pub mod solve;
+/// Sparse matrix kernels
+pub mod sparse;
+/// Special function kernels
+pub mod special;
+/// Singular value decomposition kernels
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub mod svd;
+/// Unique elements kernels
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub mod unique;
 
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
@@ -0,0 +1,96 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+
+use crate::{
+    element::JitElement, ops::numeric::empty_device, tensor::JitTensor, IntElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn mode_kernel<I: Int>(
+    input: &Tensor<I>,
+    values: &mut Tensor<I>,
+    counts: &mut Tensor<I>,
+    dim: UInt,
+) {
+    if ABSOLUTE_POS >= values.len() {
+        return;
+    }
+
+    // The first value of the lane reduced by the unit, the outputs having a size of 1 along the
+    // dimension.
+    let mut offset = UInt::new(0);
+    for axis in range(0u32, input.rank(), Comptime::new(false)) {
+        let coordinate = ABSOLUTE_POS / values.stride(axis) % values.shape(axis);
+        offset += coordinate * input.stride(axis);
+    }
+
+    let size = input.shape(dim);
+    let stride = input.stride(dim);
+    let mut mode = I::new(0);
+    let mut max_count = UInt::new(0);
+
+    // Each value is compared with all the values of the lane, keeping the smallest value among the
+    // most frequent ones.
+    for index in range(0u32, size, Comptime::new(false)) {
+        let value = input[offset + index * stride];
+        let mut count = UInt::new(0);
+        for other in range(0u32, size, Comptime::new(false)) {
+            if input[offset + other * stride] == value {
+                count += UInt::new(1);
+            }
+        }
+
+        let mut replaced = UInt::new(0);
+        if count > max_count {
+            replaced = UInt::new(1);
+        }
+        if count == max_count && value < mode {
+            replaced = UInt::new(1);
+        }
+        if replaced == UInt::new(1) {
+            mode = value;
+            max_count = count;
+        }
+    }
+
+    values[ABSOLUTE_POS] = mode;
+    counts[ABSOLUTE_POS] = I::cast_from(max_count);
+}
+
+fn handle<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: &JitTensor<R, E, D>,
+) -> TensorHandle<'_, R> {
+    TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims)
+}
+
+/// Returns the most frequent value along the given dimension with its number of occurrences.
+///
+/// Each unit reduces a lane along the dimension, counting the occurrences of each value by
+/// comparing it with all the values of the lane, so the work is quadratic in the size of the
+/// dimension but no memory is used besides the outputs.
+pub(crate) fn mode<R: JitRuntime, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, I, D>,
+    dim: usize,
+) -> (JitTensor<R, I, D>, JitTensor<R, I, D>) {
+    let mut shape = tensor.shape.clone();
+    shape.dims[dim] = 1;
+
+    let client = tensor.client.clone();
+    let device = tensor.device.clone();
+    let values = empty_device::<R, I, D>(client.clone(), device.clone(), shape.clone());
+    let counts = empty_device::<R, I, D>(client.clone(), device, shape.clone());
+
+    let num_elems = shape.num_elements();
+    if num_elems > 0 {
+        mode_kernel_launch::<I::IntPrimitive, R>(
+            client,
+            calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            handle(&tensor),
+            handle(&values),
+            handle(&counts),
+            dim as u32,
+        );
+    }
+
+    (values, counts)
+}
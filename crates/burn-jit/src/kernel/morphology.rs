@@ -0,0 +1,123 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{ElementConversion, Shape};
+
+use crate::{
+    kernel::into_contiguous,
+    ops::numeric::{empty_device, full_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn morphology_kernel<F: Float>(
+    input: &Tensor<F>,
+    element: &Tensor<UInt>,
+    border: &Tensor<F>,
+    output: &mut Tensor<F>,
+    erode: UInt,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let height = output.shape(2);
+    let width = output.shape(3);
+    let element_height = element.shape(0);
+    let element_width = element.shape(1);
+    let origin_y = (element_height - UInt::new(1)) / UInt::new(2);
+    let origin_x = (element_width - UInt::new(1)) / UInt::new(2);
+
+    let b = ABSOLUTE_POS / output.stride(0);
+    let c = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % height;
+    let x = ABSOLUTE_POS % width;
+    let index_base = b * input.stride(0) + c * input.stride(1);
+
+    let mut value = border[0];
+
+    for i in range(0u32, element_height, Comptime::new(false)) {
+        for j in range(0u32, element_width, Comptime::new(false)) {
+            // The unsigned positions are shifted by the origin to stay positive.
+            let y_shifted = y + i;
+            let x_shifted = x + j;
+            let selected = element[i * element_width + j] != UInt::new(0);
+
+            if selected && y_shifted >= origin_y && x_shifted >= origin_x {
+                let y_in = y_shifted - origin_y;
+                let x_in = x_shifted - origin_x;
+
+                if y_in < height && x_in < width {
+                    let current =
+                        input[index_base + y_in * input.stride(2) + x_in * input.stride(3)];
+
+                    if erode == UInt::new(1) {
+                        value = F::min(value, current);
+                    } else {
+                        value = F::max(value, current);
+                    }
+                }
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = value;
+}
+
+/// Replaces each value by the maximum of its neighborhood given by the structuring element.
+pub(crate) fn dilate<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    kernel: JitTensor<R, u32, 2>,
+) -> JitTensor<R, E, 4> {
+    morphology(input, kernel, false)
+}
+
+/// Replaces each value by the minimum of its neighborhood given by the structuring element.
+pub(crate) fn erode<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    kernel: JitTensor<R, u32, 2>,
+) -> JitTensor<R, E, 4> {
+    morphology(input, kernel, true)
+}
+
+/// Reduces the neighborhood of each value, each invocation visiting the positions of the
+/// structuring element for one output value.
+fn morphology<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    kernel: JitTensor<R, u32, 2>,
+    erode: bool,
+) -> JitTensor<R, E, 4> {
+    let kernel = into_contiguous(kernel);
+    let output = empty_device(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+    let border = match erode {
+        true => f32::INFINITY,
+        false => f32::NEG_INFINITY,
+    };
+    let border = full_device::<R, E, 1>(
+        input.client.clone(),
+        Shape::new([1]),
+        input.device.clone(),
+        border.elem(),
+    );
+
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), SUBCUBE_DIM_APPROX);
+    let settings = KernelSettings::default()
+        .vectorize_input(0, 1)
+        .vectorize_output(0, 1);
+
+    morphology_kernel_launch::<E::FloatPrimitive, R>(
+        input.client.clone(),
+        cube_count,
+        settings,
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&kernel.handle, &kernel.strides, &kernel.shape.dims),
+        TensorHandle::new(&border.handle, &border.strides, &border.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        erode as u32,
+    );
+
+    output
+}
@@ -0,0 +1,249 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{ElementConversion, Shape};
+
+use crate::{
+    element::JitElement,
+    kernel::{into_contiguous, prng::random_uniform},
+    ops::{
+        into_data,
+        numeric::{empty_device, full_device},
+        reshape,
+    },
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+
+#[cube]
+fn total_weight<F: Float>(weights: &Tensor<F>, row: UInt) -> F {
+    let num_categories = weights.shape(1);
+    let mut total = F::new(0.0);
+    for category in range(0u32, num_categories, Comptime::new(false)) {
+        total += weights[row * num_categories + category];
+    }
+
+    total
+}
+
+/// The first category with a weight whose cumulative weight is greater than the value, or the last
+/// category with a weight when the rounding of the sums leaves the value past them.
+#[cube]
+fn sample_category<F: Float>(weights: &Tensor<F>, row: UInt, value: F) -> UInt {
+    let num_categories = weights.shape(1);
+    let mut cumulative = F::new(0.0);
+    let mut sample = num_categories - UInt::new(1);
+    let mut found = UInt::new(0);
+
+    for category in range(0u32, num_categories, Comptime::new(false)) {
+        let weight = weights[row * num_categories + category];
+        if found == UInt::new(0) && weight > F::new(0.0) {
+            cumulative += weight;
+            sample = category;
+            if cumulative > value {
+                found = UInt::new(1);
+            }
+        }
+    }
+
+    sample
+}
+
+/// Flags each row of weights with 3 when a weight isn't finite and non-negative, 2 when no weight
+/// is positive, or 1 when fewer weights than the minimum are positive.
+#[cube(launch)]
+fn check_weights_kernel<F: Float, I: Int>(
+    weights: &Tensor<F>,
+    infinity: &Tensor<F>,
+    errors: &mut Tensor<I>,
+    min_positive: UInt,
+) {
+    if ABSOLUTE_POS >= errors.shape(0) {
+        return;
+    }
+
+    let num_categories = weights.shape(1);
+    let infinity = infinity[0];
+    let mut num_valid = UInt::new(0);
+    let mut num_positive = UInt::new(0);
+    for category in range(0u32, num_categories, Comptime::new(false)) {
+        let weight = weights[ABSOLUTE_POS * num_categories + category];
+        // NaN fails every comparison, so only the finite non-negative weights are counted.
+        if weight >= F::new(0.0) && weight < infinity {
+            num_valid += UInt::new(1);
+        }
+        if weight > F::new(0.0) {
+            num_positive += UInt::new(1);
+        }
+    }
+
+    // The flags grow with the severity of the errors, so the most severe one is reported.
+    let mut error = UInt::new(0);
+    if num_positive < min_positive {
+        error = UInt::new(1);
+    }
+    if num_positive == UInt::new(0) {
+        error = UInt::new(2);
+    }
+    if num_valid < num_categories {
+        error = UInt::new(3);
+    }
+    errors[ABSOLUTE_POS] = I::cast_from(error);
+}
+
+#[cube(launch)]
+fn multinomial_kernel<F: Float, I: Int>(
+    weights: &Tensor<F>,
+    uniform: &Tensor<F>,
+    samples: &mut Tensor<I>,
+) {
+    if ABSOLUTE_POS >= samples.len() {
+        return;
+    }
+
+    let row = ABSOLUTE_POS / samples.shape(1);
+    let value = uniform[ABSOLUTE_POS] * total_weight::<F>(weights, row);
+    samples[ABSOLUTE_POS] = I::cast_from(sample_category::<F>(weights, row, value));
+}
+
+#[cube(launch)]
+fn multinomial_without_replacement_kernel<F: Float, I: Int>(
+    weights: &mut Tensor<F>,
+    uniform: &Tensor<F>,
+    samples: &mut Tensor<I>,
+) {
+    if ABSOLUTE_POS >= samples.shape(0) {
+        return;
+    }
+
+    let num_samples = samples.shape(1);
+    let num_categories = weights.shape(1);
+    for index in range(0u32, num_samples, Comptime::new(false)) {
+        let position = ABSOLUTE_POS * num_samples + index;
+        let value = uniform[position] * total_weight::<F>(weights, ABSOLUTE_POS);
+        let sample = sample_category::<F>(weights, ABSOLUTE_POS, value);
+
+        weights[ABSOLUTE_POS * num_categories + sample] = F::new(0.0);
+        samples[position] = I::cast_from(sample);
+    }
+}
+
+/// Rejects the weights that aren't a distribution, as well as the ones with fewer categories
+/// with a positive weight than the number of samples to draw without replacement, reading back
+/// the flags of the rows only.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn check_weights<R: JitRuntime, F: FloatElement, I: IntElement>(
+    weights: &JitTensor<R, F, 2>,
+    num_samples: usize,
+    replacement: bool,
+) {
+    let num_rows = weights.shape.dims[0];
+    let errors = empty_device::<R, I, 1>(
+        weights.client.clone(),
+        weights.device.clone(),
+        Shape::new([num_rows]),
+    );
+    let infinity = full_device::<R, F, 1>(
+        weights.client.clone(),
+        Shape::new([1]),
+        weights.device.clone(),
+        f32::INFINITY.elem(),
+    );
+    let min_positive = if replacement { 1 } else { num_samples };
+
+    check_weights_kernel_launch::<F::FloatPrimitive, I::IntPrimitive, R>(
+        weights.client.clone(),
+        calculate_cube_count_elemwise(num_rows, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        handle(weights),
+        handle(&infinity),
+        TensorHandle::new(&errors.handle, &errors.strides, &errors.shape.dims),
+        min_positive as u32,
+    );
+
+    let error = into_data(errors)
+        .read_sync()
+        .expect("Can't read the multinomial weights checks on this runtime")
+        .iter::<i64>()
+        .max()
+        .unwrap_or(0);
+    match error {
+        3 => panic!("The multinomial weights must be finite and non-negative."),
+        2 => panic!("The multinomial weights of a row must have a positive sum."),
+        1 => panic!(
+            "Can't sample {num_samples} indices without replacement from fewer categories with \
+             a positive weight."
+        ),
+        _ => {}
+    }
+}
+
+fn handle<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: &JitTensor<R, E, D>,
+) -> TensorHandle<'_, R> {
+    TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims)
+}
+
+/// Samples category indices from the unnormalized weights along the last dimension, with the
+/// uniform values of the random number generator of the runtime.
+///
+/// With replacement, each unit draws a sample, finding its value between zero and the sum of the
+/// weights of its row in their cumulative sum. Without replacement, the samples of a row depend on
+/// each other, so each unit draws the samples of a row one after the other, setting the weight of
+/// each sampled category to zero in a copy of the weights.
+pub(crate) fn multinomial<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, F, D>,
+    num_samples: usize,
+    replacement: bool,
+) -> JitTensor<R, I, D> {
+    let mut shape = tensor.shape.clone();
+    let num_categories = shape.dims[D - 1];
+    let num_rows = shape.num_elements() / num_categories.max(1);
+    shape.dims[D - 1] = num_samples;
+
+    let client = tensor.client.clone();
+    let device = tensor.device.clone();
+    let samples = empty_device::<R, I, 2>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_rows, num_samples]),
+    );
+    if num_rows * num_samples == 0 {
+        return reshape(samples, shape);
+    }
+
+    let weights = reshape(
+        into_contiguous(tensor),
+        Shape::new([num_rows, num_categories]),
+    );
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    check_weights::<R, F, I>(&weights, num_samples, replacement);
+
+    let uniform = random_uniform::<R, F, 2>(
+        Shape::new([num_rows, num_samples]),
+        &device,
+        0.elem(),
+        1.elem(),
+    );
+
+    if replacement {
+        multinomial_kernel_launch::<F::FloatPrimitive, I::IntPrimitive, R>(
+            client,
+            calculate_cube_count_elemwise(num_rows * num_samples, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            handle(&weights),
+            handle(&uniform),
+            handle(&samples),
+        );
+    } else {
+        let weights = weights.copy();
+        multinomial_without_replacement_kernel_launch::<F::FloatPrimitive, I::IntPrimitive, R>(
+            client,
+            calculate_cube_count_elemwise(num_rows, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            handle(&weights),
+            handle(&uniform),
+            handle(&samples),
+        );
+    }
+
+    reshape(samples, shape)
+}
@@ -0,0 +1,111 @@
+use burn_cube::prelude::*;
+use burn_tensor::{ElementConversion, Shape};
+
+use crate::{
+    kernel::into_contiguous,
+    ops::numeric::{empty_device, full_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Number of units of the single cube running the suppression.
+const NMS_CUBE_DIM: u32 = 256;
+
+/// Returns the intersection over union of two boxes `(x1, y1, x2, y2)`.
+#[cube]
+fn iou<F: Float>(boxes: &Tensor<F>, lhs: UInt, rhs: UInt) -> F {
+    let lhs = lhs * UInt::new(4);
+    let rhs = rhs * UInt::new(4);
+
+    let width = F::min(boxes[lhs + UInt::new(2)], boxes[rhs + UInt::new(2)])
+        - F::max(boxes[lhs], boxes[rhs]);
+    let height = F::min(boxes[lhs + UInt::new(3)], boxes[rhs + UInt::new(3)])
+        - F::max(boxes[lhs + UInt::new(1)], boxes[rhs + UInt::new(1)]);
+    let intersection = F::max(width, F::new(0.0)) * F::max(height, F::new(0.0));
+
+    let area_lhs = (boxes[lhs + UInt::new(2)] - boxes[lhs])
+        * (boxes[lhs + UInt::new(3)] - boxes[lhs + UInt::new(1)]);
+    let area_rhs = (boxes[rhs + UInt::new(2)] - boxes[rhs])
+        * (boxes[rhs + UInt::new(3)] - boxes[rhs + UInt::new(1)]);
+
+    intersection / (area_lhs + area_rhs - intersection)
+}
+
+#[cube(launch)]
+fn nms_kernel<F: Float>(boxes: &Tensor<F>, iou_threshold: &Tensor<F>, keep: &mut Tensor<UInt>) {
+    let num_boxes = keep.len();
+    let threshold = iou_threshold[0];
+
+    let mut index = UNIT_POS;
+    loop {
+        if index >= num_boxes {
+            break;
+        }
+
+        keep[index] = UInt::new(1);
+        index += CUBE_DIM;
+    }
+    sync_units();
+
+    // The boxes are visited by decreasing score, each kept box suppressing the following ones,
+    // which are split between the units.
+    for current in range(0u32, num_boxes, Comptime::new(false)) {
+        if keep[current] == UInt::new(1) {
+            let mut other = current + UInt::new(1) + UNIT_POS;
+            loop {
+                if other >= num_boxes {
+                    break;
+                }
+
+                if iou::<F>(boxes, current, other) > threshold {
+                    keep[other] = UInt::new(0);
+                }
+                other += CUBE_DIM;
+            }
+        }
+        sync_units();
+    }
+}
+
+/// Returns whether each box, sorted by decreasing score, is kept by the non-maximum suppression.
+///
+/// The suppression is sequential, so it runs in a single cube, its units sharing the boxes
+/// suppressed by each kept box.
+pub(crate) fn nms_keep<R: JitRuntime, E: FloatElement>(
+    boxes: JitTensor<R, E, 2>,
+    iou_threshold: f32,
+) -> JitTensor<R, u32, 1> {
+    let boxes = into_contiguous(boxes);
+    let num_boxes = boxes.shape.dims[0];
+    let keep = empty_device(
+        boxes.client.clone(),
+        boxes.device.clone(),
+        Shape::new([num_boxes]),
+    );
+
+    if num_boxes == 0 {
+        return keep;
+    }
+
+    let iou_threshold = full_device::<R, E, 1>(
+        boxes.client.clone(),
+        Shape::new([1]),
+        boxes.device.clone(),
+        iou_threshold.elem(),
+    );
+
+    nms_kernel_launch::<E::FloatPrimitive, R>(
+        boxes.client.clone(),
+        CubeCount::new(1, 1, 1),
+        KernelSettings::default().cube_dim(CubeDim::new(NMS_CUBE_DIM, 1, 1)),
+        TensorHandle::new(&boxes.handle, &boxes.strides, &boxes.shape.dims),
+        TensorHandle::new(
+            &iou_threshold.handle,
+            &iou_threshold.strides,
+            &iou_threshold.shape.dims,
+        ),
+        TensorHandle::new(&keep.handle, &keep.strides, &keep.shape.dims),
+    );
+
+    keep
+}
@@ -0,0 +1,129 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::Shape;
+
+use crate::{ops::numeric::empty_device, tensor::JitTensor, FloatElement, JitRuntime};
+
+#[cube(launch)]
+fn pixel_shuffle_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>, factor: UInt) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let b = ABSOLUTE_POS / output.stride(0);
+    let c = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let x = ABSOLUTE_POS % output.shape(3);
+
+    let channel = (c * factor + y % factor) * factor + x % factor;
+    let index = b * input.stride(0)
+        + channel * input.stride(1)
+        + y / factor * input.stride(2)
+        + x / factor * input.stride(3);
+
+    output[ABSOLUTE_POS] = input[index];
+}
+
+#[cube(launch)]
+fn pixel_unshuffle_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>, factor: UInt) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let b = ABSOLUTE_POS / output.stride(0);
+    let c = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let x = ABSOLUTE_POS % output.shape(3);
+
+    let block_size = factor * factor;
+    let channel = c / block_size;
+    let offset_y = c % block_size / factor;
+    let offset_x = c % factor;
+    let index = b * input.stride(0)
+        + channel * input.stride(1)
+        + (y * factor + offset_y) * input.stride(2)
+        + (x * factor + offset_x) * input.stride(3);
+
+    output[ABSOLUTE_POS] = input[index];
+}
+
+/// Rearranges blocks of channels into spatial blocks, each invocation reading the input value of
+/// one output value through the strides of the input, without permuting it.
+pub(crate) fn pixel_shuffle<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    upscale_factor: usize,
+) -> JitTensor<R, E, 4> {
+    let [batch_size, channels, height, width] = input.shape.dims;
+    let factor = upscale_factor;
+    let shape_out = Shape::new([
+        batch_size,
+        channels / (factor * factor),
+        height * factor,
+        width * factor,
+    ]);
+
+    launch(
+        input,
+        shape_out,
+        factor,
+        pixel_shuffle_kernel_launch::<E::FloatPrimitive, R>,
+    )
+}
+
+/// Rearranges spatial blocks into blocks of channels, each invocation reading the input value of
+/// one output value through the strides of the input, without permuting it.
+pub(crate) fn pixel_unshuffle<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    downscale_factor: usize,
+) -> JitTensor<R, E, 4> {
+    let [batch_size, channels, height, width] = input.shape.dims;
+    let factor = downscale_factor;
+    let shape_out = Shape::new([
+        batch_size,
+        channels * factor * factor,
+        height / factor,
+        width / factor,
+    ]);
+
+    launch(
+        input,
+        shape_out,
+        factor,
+        pixel_unshuffle_kernel_launch::<E::FloatPrimitive, R>,
+    )
+}
+
+/// Launch function generated for the pixel shuffle and unshuffle kernels.
+type KernelLaunch<R> = fn(
+    ComputeClient<<R as Runtime>::Server, <R as Runtime>::Channel>,
+    CubeCount,
+    KernelSettings,
+    TensorHandle<'_, R>,
+    TensorHandle<'_, R>,
+    u32,
+);
+
+/// Launches the kernel with one invocation per output value.
+fn launch<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    shape_out: Shape<4>,
+    factor: usize,
+    kernel: KernelLaunch<R>,
+) -> JitTensor<R, E, 4> {
+    let output = empty_device(input.client.clone(), input.device.clone(), shape_out);
+
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), SUBCUBE_DIM_APPROX);
+    let settings = KernelSettings::default()
+        .vectorize_input(0, 1)
+        .vectorize_output(0, 1);
+
+    kernel(
+        input.client.clone(),
+        cube_count,
+        settings,
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        factor as u32,
+    );
+
+    output
+}
@@ -0,0 +1,254 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{linalg::QrMode, Shape};
+
+use crate::{
+    ops::{numeric::empty_device, reshape},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn eye_kernel<F: Float>(q: &mut Tensor<F>) {
+    if ABSOLUTE_POS >= q.len() {
+        return;
+    }
+
+    let size = q.shape(2);
+    let mut value = F::new(0.0);
+    if (ABSOLUTE_POS / size) % size == ABSOLUTE_POS % size {
+        value = F::new(1.0);
+    }
+    q[ABSOLUTE_POS] = value;
+}
+
+#[cube(launch)]
+fn reflector_kernel<F: Float>(r: &Tensor<F>, w: &mut Tensor<F>, step: UInt) {
+    let rows = r.shape(1);
+    if ABSOLUTE_POS >= r.shape(0) {
+        return;
+    }
+
+    let offset = ABSOLUTE_POS * r.stride(0) + step * r.stride(2);
+    let mut norm = F::new(0.0);
+    for i in range(step, rows, Comptime::new(false)) {
+        let x = r[offset + i * r.stride(1)];
+        norm += x * x;
+    }
+
+    // Reflect the column onto the first axis, away from its first element to avoid cancellation.
+    let head = r[offset + step * r.stride(1)];
+    let mut alpha = F::sqrt(norm);
+    if head >= F::new(0.0) {
+        alpha = F::new(0.0) - alpha;
+    }
+
+    // The norm of the column with its first element replaced by `head - alpha`.
+    let reflected = norm - head * head + (head - alpha) * (head - alpha);
+    let mut scale = F::new(0.0);
+    if reflected > F::new(0.0) {
+        scale = F::new(1.0) / F::sqrt(reflected);
+    }
+
+    for i in range(0u32, rows, Comptime::new(false)) {
+        let mut value = F::new(0.0);
+        if i >= step {
+            value = r[offset + i * r.stride(1)];
+            if i == step {
+                value = head - alpha;
+            }
+        }
+        w[ABSOLUTE_POS * rows + i] = value * scale;
+    }
+}
+
+#[cube(launch)]
+fn reflect_kernel<F: Float>(r: &mut Tensor<F>, q: &mut Tensor<F>, w: &Tensor<F>) {
+    let rows = r.shape(1);
+    let cols = r.shape(2);
+    let width = cols + rows;
+    if ABSOLUTE_POS >= r.shape(0) * width {
+        return;
+    }
+
+    // Each unit applies H = I - 2 w w^T to a column of R or a row of Q.
+    let matrix = ABSOLUTE_POS / width;
+    let index = ABSOLUTE_POS % width;
+    let w_offset = matrix * rows;
+
+    if index < cols {
+        let r_offset = matrix * r.stride(0) + index * r.stride(2);
+        let mut dot = F::new(0.0);
+        for i in range(0u32, rows, Comptime::new(false)) {
+            dot += w[w_offset + i] * r[r_offset + i * r.stride(1)];
+        }
+        for i in range(0u32, rows, Comptime::new(false)) {
+            let r_index = r_offset + i * r.stride(1);
+            r[r_index] -= F::new(2.0) * dot * w[w_offset + i];
+        }
+    } else {
+        let q_offset = matrix * q.stride(0) + (index - cols) * q.stride(1);
+        let mut q_dot = F::new(0.0);
+        for i in range(0u32, rows, Comptime::new(false)) {
+            q_dot += q[q_offset + i * q.stride(2)] * w[w_offset + i];
+        }
+        for i in range(0u32, rows, Comptime::new(false)) {
+            let q_index = q_offset + i * q.stride(2);
+            q[q_index] -= F::new(2.0) * q_dot * w[w_offset + i];
+        }
+    }
+}
+
+#[cube(launch)]
+fn output_kernel<F: Float>(
+    r: &Tensor<F>,
+    q: &Tensor<F>,
+    q_output: &mut Tensor<F>,
+    r_output: &mut Tensor<F>,
+    k: UInt,
+) {
+    // The signs are flipped so that the diagonal of R is non-negative.
+    if ABSOLUTE_POS < q_output.len() {
+        let q_cols = q_output.shape(2);
+        let matrix = ABSOLUTE_POS / (q_output.shape(1) * q_cols);
+        let row = (ABSOLUTE_POS / q_cols) % q_output.shape(1);
+        let col = ABSOLUTE_POS % q_cols;
+
+        // The diagonal only has `k` elements, the index being clamped to read it in bounds.
+        let diagonal = UInt::min(col, k - UInt::new(1));
+        let negative =
+            r[matrix * r.stride(0) + diagonal * r.stride(1) + diagonal * r.stride(2)] < F::new(0.0);
+        let mut value = q[matrix * q.stride(0) + row * q.stride(1) + col * q.stride(2)];
+        if col < k && negative {
+            value = F::new(0.0) - value;
+        }
+        q_output[ABSOLUTE_POS] = value;
+    }
+
+    if ABSOLUTE_POS < r_output.len() {
+        let r_cols = r_output.shape(2);
+        let r_matrix = ABSOLUTE_POS / (r_output.shape(1) * r_cols);
+        let r_row = (ABSOLUTE_POS / r_cols) % r_output.shape(1);
+        let r_col = ABSOLUTE_POS % r_cols;
+        let r_offset = r_matrix * r.stride(0);
+
+        // Only the upper triangular part is kept.
+        let mut r_value = F::new(0.0);
+        if r_col >= r_row {
+            let r_diagonal = UInt::min(r_row, k - UInt::new(1));
+            let r_negative =
+                r[r_offset + r_diagonal * r.stride(1) + r_diagonal * r.stride(2)] < F::new(0.0);
+            r_value = r[r_offset + r_row * r.stride(1) + r_col * r.stride(2)];
+            if r_row < k && r_negative {
+                r_value = F::new(0.0) - r_value;
+            }
+        }
+        r_output[ABSOLUTE_POS] = r_value;
+    }
+}
+
+/// Computes the QR decomposition of a batch of matrices with Householder reflections, with the
+/// diagonal of `R` made non-negative.
+///
+/// Each column is reduced by a kernel computing the reflector of every matrix, followed by a
+/// kernel applying it to the columns of `R` and the rows of `Q` in parallel.
+pub(crate) fn qr<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    mode: QrMode,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let dims = tensor.shape.dims;
+    let [m, n] = [dims[D - 2], dims[D - 1]];
+    let k = usize::min(m, n);
+    let cols = match mode {
+        QrMode::Reduced => k,
+        QrMode::Complete => m,
+    };
+
+    let client = tensor.client.clone();
+    let device = tensor.device.clone();
+    let num_matrices = dims[..D - 2].iter().product::<usize>();
+    let shape = |rows: usize, cols: usize| {
+        let mut dims = dims;
+        dims[D - 2] = rows;
+        dims[D - 1] = cols;
+        Shape::new(dims)
+    };
+
+    if num_matrices * cols == 0 {
+        return (
+            empty_device(client.clone(), device.clone(), shape(m, cols)),
+            empty_device(client, device, shape(cols, n)),
+        );
+    }
+
+    let q = empty_device::<R, E, 3>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, m, m]),
+    );
+    eye_kernel_launch::<E::FloatPrimitive, R>(
+        client.clone(),
+        calculate_cube_count_elemwise(num_matrices * m * m, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&q.handle, &q.strides, &q.shape.dims),
+    );
+    if n == 0 {
+        return (
+            reshape(q, shape(m, m)),
+            empty_device(client, device, shape(cols, n)),
+        );
+    }
+
+    // The reflections are applied in place on a copy of the matrices.
+    let r = reshape(tensor, Shape::new([num_matrices, m, n]));
+    let r = match r.can_mut() {
+        true => r,
+        false => r.copy(),
+    };
+    let w = empty_device::<R, E, 2>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, m]),
+    );
+
+    for step in 0..k {
+        reflector_kernel_launch::<E::FloatPrimitive, R>(
+            client.clone(),
+            calculate_cube_count_elemwise(num_matrices, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(&r.handle, &r.strides, &r.shape.dims),
+            TensorHandle::new(&w.handle, &w.strides, &w.shape.dims),
+            step as u32,
+        );
+        reflect_kernel_launch::<E::FloatPrimitive, R>(
+            client.clone(),
+            calculate_cube_count_elemwise(num_matrices * (n + m), SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(&r.handle, &r.strides, &r.shape.dims),
+            TensorHandle::new(&q.handle, &q.strides, &q.shape.dims),
+            TensorHandle::new(&w.handle, &w.strides, &w.shape.dims),
+        );
+    }
+
+    let q_output = empty_device::<R, E, 3>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, m, cols]),
+    );
+    let r_output =
+        empty_device::<R, E, 3>(client.clone(), device, Shape::new([num_matrices, cols, n]));
+    output_kernel_launch::<E::FloatPrimitive, R>(
+        client,
+        calculate_cube_count_elemwise(num_matrices * cols * usize::max(m, n), SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&r.handle, &r.strides, &r.shape.dims),
+        TensorHandle::new(&q.handle, &q.strides, &q.shape.dims),
+        TensorHandle::new(&q_output.handle, &q_output.strides, &q_output.shape.dims),
+        TensorHandle::new(&r_output.handle, &r_output.strides, &r_output.shape.dims),
+        k as u32,
+    );
+
+    (
+        reshape(q_output, shape(m, cols)),
+        reshape(r_output, shape(cols, n)),
+    )
+}
@@ -92,5 +92,6 @@ macro_rules! reduce_operation {
 reduce_operation!(sum_dim, SumDim);
 reduce_operation!(mean_dim, MeanDim);
 reduce_operation!(prod_dim, ProdDim);
+reduce_operation!(logsumexp_dim, LogSumExpDim);
 reduce_operation!(argmin, Argmin);
 reduce_operation!(argmax, Argmax);
@@ -0,0 +1,67 @@
+use crate::{kernel::reduce::LogSumExpDim, JitElement};
+use burn_cube::{
+    cpa,
+    ir::{Elem, Item, Scope, Variable},
+};
+
+use super::base::ReduceDimNaive;
+
+impl<E: JitElement> ReduceDimNaive<E> for LogSumExpDim {
+    type Accumulator = (Variable, Variable);
+
+    fn initialize_naive(
+        scope: &mut Scope,
+        input_item: Item,
+        output_item: Item,
+    ) -> Self::Accumulator {
+        let max = scope.create_local(input_item);
+        let max_initial = Variable::ConstantScalar(E::minimum_value().to_f64(), input_item.elem());
+        cpa!(scope, max = max_initial);
+        let sum = scope.zero(output_item);
+
+        (max, sum)
+    }
+
+    fn inner_loop_naive(
+        scope: &mut Scope,
+        (max, sum): Self::Accumulator,
+        value: Variable,
+        _i: Variable,
+    ) {
+        // Online reduction: the sum is rescaled every time the running maximum changes.
+        let condition = scope.create_local(Elem::Bool);
+        let scale = scope.create_local(value.item());
+        let one = scope.create_with_value(1, value.item());
+        cpa!(scope, condition = value > max);
+        cpa!(scope, if(condition).then(|scope| {
+            cpa!(scope, scale = max - value);
+            cpa!(scope, scale = exp(scale));
+            cpa!(scope, sum = sum * scale);
+            cpa!(scope, sum += one);
+            cpa!(scope, max = value);
+        }).else(|scope| {
+            // An infinite value equal to the maximum isn't subtracted from itself.
+            cpa!(scope, condition = value == max);
+            cpa!(scope, if(condition).then(|scope| {
+                cpa!(scope, sum += one);
+            }).else(|scope| {
+                cpa!(scope, scale = value - max);
+                cpa!(scope, scale = exp(scale));
+                cpa!(scope, sum += scale);
+            }));
+        }));
+    }
+
+    fn assign_naive(
+        scope: &mut Scope,
+        output: Variable,
+        (max, sum): Self::Accumulator,
+        _shape_reduce_dim: Variable,
+    ) {
+        let id = Variable::AbsolutePos;
+        let result = scope.create_local(output.item());
+        cpa!(scope, result = log(sum));
+        cpa!(scope, result += max);
+        cpa!(scope, output[id] = result);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{kernel::reduce::LogSumExpDim, JitElement};
+use burn_cube::{
+    cpa,
+    ir::{Elem, Item, Scope, Variable},
+};
+
+use super::base::ReduceDimShared;
+
+impl<E: JitElement> ReduceDimShared<E> for LogSumExpDim {
+    type Accumulator = (Variable, Variable);
+
+    fn initialize_shared(
+        scope: &mut Scope,
+        shared_memory_size: u32,
+        write_position: Variable,
+        input_item: Item,
+    ) -> Self::Accumulator {
+        let max_shared_memory = scope.create_shared(input_item, shared_memory_size);
+        let sum_shared_memory = scope.create_shared(input_item, shared_memory_size);
+
+        let max = Variable::ConstantScalar(E::minimum_value().to_f64(), input_item.elem());
+        let sum = scope.zero(input_item);
+        cpa!(scope, max_shared_memory[write_position] = max);
+        cpa!(scope, sum_shared_memory[write_position] = sum);
+        (max_shared_memory, sum_shared_memory)
+    }
+
+    fn write_to_shared(
+        scope: &mut Scope,
+        shared_memory: Self::Accumulator,
+        write_position: Variable,
+        (max, sum): Self::Accumulator,
+    ) {
+        // Merge two partial sums by rescaling them to their common maximum.
+        let (max_shared_memory, sum_shared_memory) = shared_memory;
+        let current_max = scope.create_local(max.item());
+        let current_sum = scope.create_local(sum.item());
+        cpa!(scope, current_max = max_shared_memory[write_position]);
+        cpa!(scope, current_sum = sum_shared_memory[write_position]);
+
+        let new_max = scope.create_local(max.item());
+        cpa!(scope, new_max = max(current_max, max));
+
+        // A partial maximum equal to the common one, possibly infinite, keeps its sum unscaled
+        // instead of subtracting the maximum from itself.
+        let condition = scope.create_local(Elem::Bool);
+        let scale = scope.create_local(max.item());
+        let new_sum = scope.create_local(sum.item());
+        cpa!(scope, condition = current_max == new_max);
+        cpa!(scope, if(condition).then(|scope| {
+            cpa!(scope, new_sum = current_sum);
+        }).else(|scope| {
+            cpa!(scope, scale = current_max - new_max);
+            cpa!(scope, scale = exp(scale));
+            cpa!(scope, new_sum = current_sum * scale);
+        }));
+        cpa!(scope, condition = max == new_max);
+        cpa!(scope, if(condition).then(|scope| {
+            cpa!(scope, new_sum += sum);
+        }).else(|scope| {
+            cpa!(scope, scale = max - new_max);
+            cpa!(scope, scale = exp(scale));
+            cpa!(scope, scale = sum * scale);
+            cpa!(scope, new_sum += scale);
+        }));
+
+        cpa!(scope, max_shared_memory[write_position] = new_max);
+        cpa!(scope, sum_shared_memory[write_position] = new_sum);
+    }
+
+    fn read_from_input(
+        scope: &mut Scope,
+        input: Variable,
+        read_position: Variable,
+        _i: Variable,
+    ) -> Self::Accumulator {
+        let value = scope.create_local(input.item());
+        cpa!(scope, value = input[read_position]);
+        let one = scope.create_with_value(1, input.item());
+        (value, one)
+    }
+
+    fn read_from_shared(
+        scope: &mut Scope,
+        shared_memory: Self::Accumulator,
+        read_position: Variable,
+    ) -> Self::Accumulator {
+        let (max_shared_memory, sum_shared_memory) = shared_memory;
+        let max = scope.create_local(max_shared_memory.item());
+        cpa!(scope, max = max_shared_memory[read_position]);
+        let sum = scope.create_local(sum_shared_memory.item());
+        cpa!(scope, sum = sum_shared_memory[read_position]);
+        (max, sum)
+    }
+
+    fn assign_shared(
+        scope: &mut Scope,
+        shared_memory: Self::Accumulator,
+        output: Variable,
+        write_position: Variable,
+        _shape_reduce_dim: Variable,
+    ) {
+        let (max_shared_memory, sum_shared_memory) = shared_memory;
+        let max = scope.create_local(output.item());
+        let final_value = scope.create_local(output.item());
+        cpa!(scope, max = max_shared_memory[0]);
+        cpa!(scope, final_value = sum_shared_memory[0]);
+        cpa!(scope, final_value = log(final_value));
+        cpa!(scope, final_value += max);
+        cpa!(scope, output[write_position] = final_value);
+    }
+}
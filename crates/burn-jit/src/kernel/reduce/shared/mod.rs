@@ -1,6 +1,7 @@
 pub(crate) mod argmax;
 pub(crate) mod argmin;
 pub(crate) mod base;
+pub(crate) mod logsumexp_dim;
 pub(crate) mod mean_dim;
 pub(crate) mod prod_dim;
 pub(crate) mod shader;
@@ -0,0 +1,97 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{
+    ops::{resample::ResampleFilter, ResampleOptions},
+    Shape, TensorData,
+};
+
+use crate::{
+    ops::{from_data, numeric::empty_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn resample_kernel<F: Float>(
+    input: &Tensor<F>,
+    weights: &Tensor<F>,
+    output: &mut Tensor<F>,
+    orig_freq: UInt,
+    new_freq: UInt,
+    width: UInt,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let length = input.shape(1);
+    let num_taps = weights.shape(1);
+
+    let b = ABSOLUTE_POS / output.stride(0);
+    let position = ABSOLUTE_POS % output.shape(1);
+    let group = position / new_freq;
+    let phase = position % new_freq;
+
+    // The unsigned input positions are shifted by the width of the filter to stay positive.
+    let start = group * orig_freq;
+    let mut sum = F::new(0.0);
+
+    for tap in range(0u32, num_taps, Comptime::new(false)) {
+        let shifted = start + tap;
+
+        if shifted >= width {
+            let index = shifted - width;
+
+            if index < length {
+                sum += input[b * input.stride(0) + index * input.stride(1)]
+                    * weights[phase * num_taps + tap];
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = sum;
+}
+
+/// Resamples the signals, each invocation computing one output value with the phase of the
+/// filter matching its position.
+pub(crate) fn resample<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 2>,
+    orig_freq: usize,
+    new_freq: usize,
+    options: ResampleOptions,
+) -> JitTensor<R, E, 2> {
+    let filter = ResampleFilter::new(orig_freq, new_freq, &options);
+    if filter.orig_freq == filter.new_freq {
+        return input;
+    }
+
+    let [batch_size, length] = input.shape.dims;
+    let num_taps = filter.num_taps();
+    let weights = from_data::<R, E, 2>(
+        TensorData::new(filter.weights.clone(), [filter.new_freq, num_taps]),
+        &input.device,
+    );
+    let output = empty_device(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([batch_size, filter.output_length(length)]),
+    );
+
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), SUBCUBE_DIM_APPROX);
+    let settings = KernelSettings::default()
+        .vectorize_input(0, 1)
+        .vectorize_output(0, 1);
+
+    resample_kernel_launch::<E::FloatPrimitive, R>(
+        input.client.clone(),
+        cube_count,
+        settings,
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&weights.handle, &weights.strides, &weights.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        filter.orig_freq as u32,
+        filter.new_freq as u32,
+        filter.width as u32,
+    );
+
+    output
+}
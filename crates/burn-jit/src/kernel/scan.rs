@@ -0,0 +1,133 @@
+use burn_cube::prelude::*;
+use burn_tensor::{ElementConversion, Shape};
+
+use crate::{
+    ops::numeric::{empty_device, full_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Maximum size of the state held in shared memory by the [selective scan](selective_scan).
+pub(crate) const SCAN_MAX_STATE_SIZE: usize = 256;
+
+/// Number of state dimensions updated at once by the units of a cube, one each.
+const SCAN_CUBE_DIM: u32 = 32;
+
+#[cube(launch)]
+fn selective_scan_kernel<F: Float>(
+    u: &Tensor<F>,
+    delta: &Tensor<F>,
+    a: &Tensor<F>,
+    b: &Tensor<F>,
+    c: &Tensor<F>,
+    output: &mut Tensor<F>,
+) {
+    let channels = output.shape(1);
+    let length = output.shape(2);
+    let d_state = a.shape(1);
+
+    // Each cube scans the whole sequence of a single channel.
+    let row = CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X;
+    if row >= output.shape(0) * channels {
+        return;
+    }
+
+    let batch = row / channels;
+    let channel = row % channels;
+
+    // Sized as the maximum state size, and the number of units of the cube.
+    let mut states = SharedMemory::<F>::new(256);
+    let mut partials = SharedMemory::<F>::new(32);
+
+    let mut index = UNIT_POS_X;
+    loop {
+        if index >= d_state {
+            break;
+        }
+
+        states[index] = F::new(0.0);
+        index += CUBE_DIM_X;
+    }
+
+    for t in range(0u32, length, Comptime::new(false)) {
+        let step = delta[batch * delta.stride(0) + channel * delta.stride(1) + t * delta.stride(2)];
+        let input = step * u[batch * u.stride(0) + channel * u.stride(1) + t * u.stride(2)];
+
+        // Each unit updates its own dimensions of the state, and sums their contributions.
+        let mut partial = F::new(0.0);
+        let mut n = UNIT_POS_X;
+        loop {
+            if n >= d_state {
+                break;
+            }
+
+            let decay = F::exp(step * a[channel * a.stride(0) + n * a.stride(1)]);
+            let state = decay * states[n]
+                + input * b[batch * b.stride(0) + n * b.stride(1) + t * b.stride(2)];
+
+            states[n] = state;
+            partial += state * c[batch * c.stride(0) + n * c.stride(1) + t * c.stride(2)];
+            n += CUBE_DIM_X;
+        }
+        partials[UNIT_POS_X] = partial;
+        sync_units();
+
+        if UNIT_POS_X == UInt::new(0) {
+            let mut sum = F::new(0.0);
+            for i in range(0u32, CUBE_DIM_X, Comptime::new(false)) {
+                sum += partials[i];
+            }
+
+            output[row * length + t] = sum;
+        }
+        sync_units();
+    }
+}
+
+/// Computes the selective scan of a state space model in a single kernel, without
+/// materializing the states of every step.
+///
+/// Each cube scans a channel, keeping its state in shared memory, its units updating the
+/// dimensions of the state in parallel at each step.
+pub(crate) fn selective_scan<R: JitRuntime, E: FloatElement>(
+    u: JitTensor<R, E, 3>,
+    delta: JitTensor<R, E, 3>,
+    a: JitTensor<R, E, 2>,
+    b: JitTensor<R, E, 3>,
+    c: JitTensor<R, E, 3>,
+) -> JitTensor<R, E, 3> {
+    let [batch_size, channels, length] = u.shape.dims;
+    let [_, d_state] = a.shape.dims;
+    let num_rows = batch_size * channels;
+    assert!(
+        d_state <= SCAN_MAX_STATE_SIZE,
+        "The state size must be at most {SCAN_MAX_STATE_SIZE}."
+    );
+
+    if d_state == 0 || num_rows * length == 0 {
+        return full_device::<R, E, 3>(u.client.clone(), u.shape, u.device.clone(), 0.elem());
+    }
+
+    let output = empty_device(
+        u.client.clone(),
+        u.device.clone(),
+        Shape::new([batch_size, channels, length]),
+    );
+
+    let cube_count_x = f32::ceil(f32::sqrt(num_rows as f32)) as u32;
+    let cube_count_y = num_rows.div_ceil(cube_count_x as usize) as u32;
+
+    selective_scan_kernel_launch::<E::FloatPrimitive, R>(
+        u.client.clone(),
+        CubeCount::new(cube_count_x, cube_count_y, 1),
+        KernelSettings::default().cube_dim(CubeDim::new(SCAN_CUBE_DIM, 1, 1)),
+        TensorHandle::new(&u.handle, &u.strides, &u.shape.dims),
+        TensorHandle::new(&delta.handle, &delta.strides, &delta.shape.dims),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&b.handle, &b.strides, &b.shape.dims),
+        TensorHandle::new(&c.handle, &c.strides, &c.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+    );
+
+    output
+}
@@ -0,0 +1,259 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{IndexReduction, Shape};
+
+use crate::{
+    element::JitElement,
+    kernel::{cast, into_contiguous},
+    ops::{
+        numeric::{empty_device, zeros_device},
+        reshape,
+    },
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn segment_sum_float_kernel<F: Float, I: Int>(
+    input: &Tensor<F>,
+    segment_ids: &Tensor<I>,
+    sums: &mut Tensor<AtomicF32>,
+) {
+    if ABSOLUTE_POS >= input.len() {
+        return;
+    }
+
+    // The negative ids wrap around to segments out of range.
+    let size = input.shape(1);
+    let segment = UInt::cast_from(segment_ids[ABSOLUTE_POS / size * segment_ids.stride(0)]);
+    if segment < sums.len() / size {
+        let value = F32::cast_from(input[ABSOLUTE_POS]);
+        AtomicF32::add(sums, segment * size + ABSOLUTE_POS % size, value);
+    }
+}
+
+#[cube(launch)]
+fn segment_sum_int_kernel<I: Int>(
+    input: &Tensor<I>,
+    segment_ids: &Tensor<I>,
+    sums: &mut Tensor<AtomicI32>,
+) {
+    if ABSOLUTE_POS >= input.len() {
+        return;
+    }
+
+    let size = input.shape(1);
+    let segment = UInt::cast_from(segment_ids[ABSOLUTE_POS / size * segment_ids.stride(0)]);
+    if segment < sums.len() / size {
+        let value = I32::cast_from(input[ABSOLUTE_POS]);
+        AtomicI32::add(sums, segment * size + ABSOLUTE_POS % size, value);
+    }
+}
+
+/// The first slice whose segment isn't before the given one, the segment ids being sorted.
+#[cube]
+fn lower_bound<I: Int>(segment_ids: &Tensor<I>, segment: UInt) -> UInt {
+    let target = I::cast_from(segment);
+    let mut low = UInt::new(0);
+    let mut high = segment_ids.shape(0);
+
+    // The loop breaks explicitly, since a cube `while` exits as soon as its condition holds.
+    loop {
+        if low >= high {
+            break;
+        }
+
+        let middle = (low + high) / UInt::new(2);
+        if segment_ids[middle * segment_ids.stride(0)] < target {
+            low = middle + UInt::new(1);
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+#[cube(launch)]
+fn segment_reduce_sorted_kernel<N: Numeric, I: Int>(
+    input: &Tensor<N>,
+    segment_ids: &Tensor<I>,
+    output: &mut Tensor<N>,
+    reduction: UInt,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let size = output.shape(1);
+    let segment = ABSOLUTE_POS / size;
+    let column = ABSOLUTE_POS % size;
+    let start = lower_bound::<I>(segment_ids, segment);
+    let end = lower_bound::<I>(segment_ids, segment + UInt::new(1));
+
+    // The reductions are numbered by `reduction_code`, the extrema starting from the first slice.
+    let mut accumulated = N::from_int(0);
+    if reduction == UInt::new(2) {
+        accumulated = N::from_int(1);
+    }
+    if start < end && reduction >= UInt::new(3) {
+        accumulated = input[start * size + column];
+    }
+
+    for slice in range(start, end, Comptime::new(false)) {
+        let value = input[slice * size + column];
+
+        if reduction <= UInt::new(1) {
+            accumulated += value;
+        }
+        if reduction == UInt::new(2) {
+            accumulated *= value;
+        }
+        if reduction == UInt::new(3) && value > accumulated {
+            accumulated = value;
+        }
+        if reduction == UInt::new(4) && value < accumulated {
+            accumulated = value;
+        }
+    }
+
+    if reduction == UInt::new(1) && end > start {
+        accumulated /= N::cast_from(end - start);
+    }
+    output[ABSOLUTE_POS] = accumulated;
+}
+
+fn reduction_code(reduction: IndexReduction) -> u32 {
+    match reduction {
+        IndexReduction::Sum => 0,
+        IndexReduction::Mean => 1,
+        IndexReduction::Prod => 2,
+        IndexReduction::Amax => 3,
+        IndexReduction::Amin => 4,
+    }
+}
+
+/// The contiguous slices of the first dimension, with the number of elements of each slice.
+fn slices<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> (JitTensor<R, E, 2>, usize) {
+    let num_slices = tensor.shape.dims[0];
+    let size = tensor.shape.dims[1..].iter().product();
+
+    (
+        reshape(into_contiguous(tensor), Shape::new([num_slices, size])),
+        size,
+    )
+}
+
+fn handle<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: &JitTensor<R, E, D>,
+) -> TensorHandle<'_, R> {
+    TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims)
+}
+
+/// Sums the slices of the first dimension belonging to the same segment, each unit atomically
+/// adding its element to the slice of its segment.
+///
+/// The floats are accumulated in single precision, the only floating point atomic addition
+/// supported by every runtime, and the order of the additions isn't deterministic.
+pub(crate) fn segment_sum_float<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, F, D>,
+    segment_ids: JitTensor<R, I, 1>,
+    num_segments: usize,
+) -> JitTensor<R, F, D> {
+    let mut shape = tensor.shape.clone();
+    shape.dims[0] = num_segments;
+    let (input, size) = slices(tensor);
+    let sums = zeros_device::<R, f32, 2>(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([num_segments, size]),
+    );
+
+    let num_elems = input.shape.num_elements();
+    if num_elems * num_segments > 0 {
+        segment_sum_float_kernel_launch::<F::FloatPrimitive, I::IntPrimitive, R>(
+            input.client.clone(),
+            calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            handle(&input),
+            handle(&segment_ids),
+            handle(&sums),
+        );
+    }
+
+    reshape(cast::<R, f32, F, 2>(sums), shape)
+}
+
+/// Sums the slices of the first dimension belonging to the same segment, each unit atomically
+/// adding its element to the slice of its segment in 32 bits.
+pub(crate) fn segment_sum_int<R: JitRuntime, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, I, D>,
+    segment_ids: JitTensor<R, I, 1>,
+    num_segments: usize,
+) -> JitTensor<R, I, D> {
+    let mut shape = tensor.shape.clone();
+    shape.dims[0] = num_segments;
+    let (input, size) = slices(tensor);
+    let sums = zeros_device::<R, i32, 2>(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([num_segments, size]),
+    );
+
+    let num_elems = input.shape.num_elements();
+    if num_elems * num_segments > 0 {
+        segment_sum_int_kernel_launch::<I::IntPrimitive, R>(
+            input.client.clone(),
+            calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            handle(&input),
+            handle(&segment_ids),
+            handle(&sums),
+        );
+    }
+
+    reshape(cast::<R, i32, I, 2>(sums), shape)
+}
+
+/// Reduces the slices of the first dimension belonging to the same segment, the segment ids being
+/// sorted.
+///
+/// Each unit computes an element of the output, finding the slices of its segment with binary
+/// searches and reducing them in order, so the result is deterministic.
+pub(crate) fn segment_reduce_sorted<
+    R: JitRuntime,
+    E: JitElement,
+    N: Numeric,
+    I: IntElement,
+    const D: usize,
+>(
+    tensor: JitTensor<R, E, D>,
+    segment_ids: JitTensor<R, I, 1>,
+    num_segments: usize,
+    reduction: IndexReduction,
+) -> JitTensor<R, E, D> {
+    let mut shape = tensor.shape.clone();
+    shape.dims[0] = num_segments;
+    let (input, size) = slices(tensor);
+    let output = empty_device::<R, E, 2>(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([num_segments, size]),
+    );
+
+    let num_elems = num_segments * size;
+    if num_elems > 0 {
+        segment_reduce_sorted_kernel_launch::<N, I::IntPrimitive, R>(
+            input.client.clone(),
+            calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            handle(&input),
+            handle(&segment_ids),
+            handle(&output),
+            reduction_code(reduction),
+        );
+    }
+
+    reshape(output, shape)
+}
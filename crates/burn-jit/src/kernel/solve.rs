@@ -0,0 +1,234 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::Shape;
+
+use crate::{
+    ops::{numeric::empty_device, reshape},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn pivot_kernel<F: Float>(
+    a: &mut Tensor<F>,
+    b: &mut Tensor<F>,
+    factors: &mut Tensor<F>,
+    step: UInt,
+) {
+    let n = a.shape(1);
+    let k = b.shape(2);
+    if ABSOLUTE_POS >= a.shape(0) {
+        return;
+    }
+
+    let a_offset = ABSOLUTE_POS * a.stride(0);
+    let b_offset = ABSOLUTE_POS * b.stride(0);
+
+    // The row with the largest pivot is swapped with the current one.
+    let mut pivot = step;
+    let mut largest = F::abs(a[a_offset + step * a.stride(1) + step * a.stride(2)]);
+    for i in range(step + UInt::new(1), n, Comptime::new(false)) {
+        let candidate = F::abs(a[a_offset + i * a.stride(1) + step * a.stride(2)]);
+        if candidate > largest {
+            pivot = i;
+            largest = candidate;
+        }
+    }
+
+    if pivot != step {
+        for c in range(0u32, n, Comptime::new(false)) {
+            let index = a_offset + step * a.stride(1) + c * a.stride(2);
+            let pivot_index = a_offset + pivot * a.stride(1) + c * a.stride(2);
+            let value = a[index];
+            a[index] = a[pivot_index];
+            a[pivot_index] = value;
+        }
+        for b_col in range(0u32, k, Comptime::new(false)) {
+            let b_index = b_offset + step * b.stride(1) + b_col * b.stride(2);
+            let b_pivot_index = b_offset + pivot * b.stride(1) + b_col * b.stride(2);
+            let b_value = b[b_index];
+            b[b_index] = b[b_pivot_index];
+            b[b_pivot_index] = b_value;
+        }
+    }
+
+    // The factors are computed before the elimination, which overwrites the column.
+    let diagonal = a[a_offset + step * a.stride(1) + step * a.stride(2)];
+    for row in range(step + UInt::new(1), n, Comptime::new(false)) {
+        factors[ABSOLUTE_POS * n + row] =
+            a[a_offset + row * a.stride(1) + step * a.stride(2)] / diagonal;
+    }
+}
+
+#[cube(launch)]
+fn eliminate_kernel<F: Float>(
+    a: &mut Tensor<F>,
+    b: &mut Tensor<F>,
+    factors: &Tensor<F>,
+    step: UInt,
+) {
+    let n = a.shape(1);
+    let width = n + b.shape(2);
+    if ABSOLUTE_POS >= a.shape(0) * n * width {
+        return;
+    }
+
+    // Each unit eliminates an element of A or B below the pivot row.
+    let matrix = ABSOLUTE_POS / (n * width);
+    let row = (ABSOLUTE_POS / width) % n;
+    let col = ABSOLUTE_POS % width;
+    if row <= step {
+        return;
+    }
+
+    let factor = factors[matrix * n + row];
+    if col < n {
+        if col >= step {
+            let a_offset = matrix * a.stride(0) + col * a.stride(2);
+            let pivot = a[a_offset + step * a.stride(1)];
+            let index = a_offset + row * a.stride(1);
+            a[index] -= factor * pivot;
+        }
+    } else {
+        let b_offset = matrix * b.stride(0) + (col - n) * b.stride(2);
+        let b_pivot = b[b_offset + step * b.stride(1)];
+        let b_index = b_offset + row * b.stride(1);
+        b[b_index] -= factor * b_pivot;
+    }
+}
+
+#[cube(launch)]
+fn substitution_kernel<F: Float>(a: &Tensor<F>, b: &Tensor<F>, x: &mut Tensor<F>, upper: UInt) {
+    let n = a.shape(1);
+    let k = b.shape(2);
+    if ABSOLUTE_POS >= a.shape(0) * k {
+        return;
+    }
+
+    // Each unit solves a column of the right-hand side, row by row.
+    let matrix = ABSOLUTE_POS / k;
+    let col = ABSOLUTE_POS % k;
+    let a_offset = matrix * a.stride(0);
+    let b_offset = matrix * b.stride(0) + col * b.stride(2);
+    let x_offset = matrix * x.stride(0) + col * x.stride(2);
+
+    for step in range(0u32, n, Comptime::new(false)) {
+        let mut i = step;
+        let mut start = UInt::new(0);
+        let mut end = step;
+        if upper == UInt::new(1) {
+            i = n - UInt::new(1) - step;
+            start = i + UInt::new(1);
+            end = n;
+        }
+
+        let mut value = b[b_offset + i * b.stride(1)];
+        for j in range(start, end, Comptime::new(false)) {
+            value -=
+                a[a_offset + i * a.stride(1) + j * a.stride(2)] * x[x_offset + j * x.stride(1)];
+        }
+        let index = x_offset + i * x.stride(1);
+        x[index] = value / a[a_offset + i * a.stride(1) + i * a.stride(2)];
+    }
+}
+
+/// Solves the linear systems `A X = B` of a batch of square matrices with Gaussian elimination
+/// and partial pivoting.
+///
+/// Each column is eliminated by a kernel selecting the pivot of every matrix, followed by a
+/// kernel updating the elements of `A` and `B` below it in parallel. The triangular systems are
+/// then solved with one unit per column of the right-hand side.
+pub(crate) fn solve<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let dims = rhs.shape.dims;
+    let [n, k] = [dims[D - 2], dims[D - 1]];
+    let num_matrices = dims[..D - 2].iter().product::<usize>();
+
+    if num_matrices * n * k == 0 {
+        return empty_device(rhs.client.clone(), rhs.device.clone(), rhs.shape);
+    }
+
+    // The elimination is applied in place on copies of the matrices.
+    let a = reshape(tensor, Shape::new([num_matrices, n, n]));
+    let a = match a.can_mut() {
+        true => a,
+        false => a.copy(),
+    };
+    let b = reshape(rhs, Shape::new([num_matrices, n, k]));
+    let b = match b.can_mut() {
+        true => b,
+        false => b.copy(),
+    };
+    let factors = empty_device::<R, E, 2>(
+        a.client.clone(),
+        a.device.clone(),
+        Shape::new([num_matrices, n]),
+    );
+
+    for step in 0..n {
+        pivot_kernel_launch::<E::FloatPrimitive, R>(
+            a.client.clone(),
+            calculate_cube_count_elemwise(num_matrices, SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+            TensorHandle::new(&b.handle, &b.strides, &b.shape.dims),
+            TensorHandle::new(&factors.handle, &factors.strides, &factors.shape.dims),
+            step as u32,
+        );
+        eliminate_kernel_launch::<E::FloatPrimitive, R>(
+            a.client.clone(),
+            calculate_cube_count_elemwise(num_matrices * n * (n + k), SUBCUBE_DIM_APPROX),
+            KernelSettings::default(),
+            TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+            TensorHandle::new(&b.handle, &b.strides, &b.shape.dims),
+            TensorHandle::new(&factors.handle, &factors.strides, &factors.shape.dims),
+            step as u32,
+        );
+    }
+
+    reshape(substitute(a, b, true), Shape::new(dims))
+}
+
+/// Solves the linear systems `A X = B` of a batch of triangular matrices with substitution, with
+/// one unit per column of the right-hand side.
+pub(crate) fn triangular_solve<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    upper: bool,
+) -> JitTensor<R, E, D> {
+    let dims = rhs.shape.dims;
+    let [n, k] = [dims[D - 2], dims[D - 1]];
+    let num_matrices = dims[..D - 2].iter().product::<usize>();
+
+    if num_matrices * n * k == 0 {
+        return empty_device(rhs.client.clone(), rhs.device.clone(), rhs.shape);
+    }
+
+    let a = reshape(tensor, Shape::new([num_matrices, n, n]));
+    let b = reshape(rhs, Shape::new([num_matrices, n, k]));
+
+    reshape(substitute(a, b, upper), Shape::new(dims))
+}
+
+/// Solve the triangular systems with one unit per column of the right-hand side.
+fn substitute<R: JitRuntime, E: FloatElement>(
+    a: JitTensor<R, E, 3>,
+    b: JitTensor<R, E, 3>,
+    upper: bool,
+) -> JitTensor<R, E, 3> {
+    let [num_matrices, _, k] = b.shape.dims;
+    let x = empty_device::<R, E, 3>(b.client.clone(), b.device.clone(), b.shape.clone());
+
+    substitution_kernel_launch::<E::FloatPrimitive, R>(
+        a.client.clone(),
+        calculate_cube_count_elemwise(num_matrices * k, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&b.handle, &b.strides, &b.shape.dims),
+        TensorHandle::new(&x.handle, &x.strides, &x.shape.dims),
+        upper as u32,
+    );
+
+    x
+}
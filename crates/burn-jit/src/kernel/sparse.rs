@@ -0,0 +1,79 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::Shape;
+
+use crate::{
+    kernel::into_contiguous, ops::numeric::empty_device, tensor::JitTensor, FloatElement,
+    IntElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn spmm_csr_kernel<F: Float, I: Int>(
+    row_offsets: &Tensor<I>,
+    col_indices: &Tensor<I>,
+    values: &Tensor<F>,
+    rhs: &Tensor<F>,
+    output: &mut Tensor<F>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let row = ABSOLUTE_POS / output.stride(0);
+    let col = ABSOLUTE_POS % output.stride(0);
+
+    // Reduce the segment of the stored elements of the row.
+    let start = UInt::cast_from(row_offsets[row]);
+    let end = UInt::cast_from(row_offsets[row + UInt::new(1)]);
+    let mut sum = F::new(0.);
+
+    for i in range(start, end, Comptime::new(false)) {
+        let index = UInt::cast_from(col_indices[i]) * rhs.stride(0) + col * rhs.stride(1);
+        sum += values[i] * rhs[index];
+    }
+
+    output[ABSOLUTE_POS] = sum;
+}
+
+/// Multiplies a sparse matrix in the compressed sparse row format by a dense matrix, each
+/// invocation reducing the segment of a row for one column of the output.
+pub(crate) fn spmm_csr<R: JitRuntime, E: FloatElement, I: IntElement>(
+    row_offsets: JitTensor<R, I, 1>,
+    col_indices: JitTensor<R, I, 1>,
+    values: JitTensor<R, E, 1>,
+    rhs: JitTensor<R, E, 2>,
+) -> JitTensor<R, E, 2> {
+    let row_offsets = into_contiguous(row_offsets);
+    let col_indices = into_contiguous(col_indices);
+    let values = into_contiguous(values);
+
+    let m = row_offsets.shape.dims[0] - 1;
+    let n = rhs.shape.dims[1];
+    let output = empty_device(rhs.client.clone(), rhs.device.clone(), Shape::new([m, n]));
+
+    let num_elems_output = output.shape.num_elements();
+    let cube_count = calculate_cube_count_elemwise(num_elems_output, SUBCUBE_DIM_APPROX);
+    let settings = KernelSettings::default()
+        .vectorize_input(0, 1)
+        .vectorize_output(0, 1);
+
+    spmm_csr_kernel_launch::<E::FloatPrimitive, I::IntPrimitive, R>(
+        rhs.client,
+        cube_count,
+        settings,
+        TensorHandle::new(
+            &row_offsets.handle,
+            &row_offsets.strides,
+            &row_offsets.shape.dims,
+        ),
+        TensorHandle::new(
+            &col_indices.handle,
+            &col_indices.strides,
+            &col_indices.shape.dims,
+        ),
+        TensorHandle::new(&values.handle, &values.strides, &values.shape.dims),
+        TensorHandle::new(&rhs.handle, &rhs.strides, &rhs.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+    );
+
+    output
+}
@@ -0,0 +1,307 @@
+// The cube functions only expand plain literals, so the coefficients are written as is.
+#![allow(clippy::approx_constant, clippy::excessive_precision)]
+
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+
+use crate::{
+    kernel::into_contiguous, ops::numeric::empty_device, tensor::JitTensor, FloatElement,
+    JitRuntime,
+};
+
+#[cube]
+fn erfinv_value<F: Float>(x: F) -> F {
+    // Approximation from Giles, "Approximating the erfinv function" (2010).
+    let w = F::new(0.0) - F::log((F::new(1.0) - x) * (F::new(1.0) + x));
+    let mut p = F::new(2.8102264e-8);
+
+    if w < F::new(5.0) {
+        let central = w - F::new(2.5);
+        p = p * central + F::new(3.4327394e-7);
+        p = p * central - F::new(3.5233877e-6);
+        p = p * central - F::new(4.3915065e-6);
+        p = p * central + F::new(0.00021858087);
+        p = p * central - F::new(0.001253725);
+        p = p * central - F::new(0.0041776816);
+        p = p * central + F::new(0.24664073);
+        p = p * central + F::new(1.5014094);
+    } else {
+        let tail = F::sqrt(w) - F::new(3.0);
+        p = F::new(0.0) - F::new(0.00020021426);
+        p = p * tail + F::new(0.00010095056);
+        p = p * tail + F::new(0.0013493432);
+        p = p * tail - F::new(0.0036734284);
+        p = p * tail + F::new(0.0057395077);
+        p = p * tail - F::new(0.0076224613);
+        p = p * tail + F::new(0.0094388705);
+        p = p * tail + F::new(1.0016741);
+        p = p * tail + F::new(2.8329768);
+    }
+
+    // A step of Newton's method, with d/dy erf(y) = 2 / sqrt(pi) * exp(-y^2).
+    let mut y = p * x;
+    let derivative = F::new(1.1283792) * F::exp(F::new(0.0) - y * y);
+    y -= (F::erf(y) - x) / derivative;
+
+    // The output is infinite at the bounds, where w is.
+    if F::abs(x) == F::new(1.0) {
+        y = x * w;
+    }
+
+    y
+}
+
+#[cube]
+fn lgamma_value<F: Float>(x: F) -> F {
+    // Lanczos approximation with g = 7, using the reflection formula below 0.5.
+    let reflected = x < F::new(0.5);
+    let mut z = x;
+    if reflected {
+        z = F::new(1.0) - x;
+    }
+    z -= F::new(1.0);
+
+    let mut series = F::new(1.0);
+    series += F::new(676.5204) / (z + F::new(1.0));
+    series -= F::new(1259.1392) / (z + F::new(2.0));
+    series += F::new(771.3234) / (z + F::new(3.0));
+    series -= F::new(176.61503) / (z + F::new(4.0));
+    series += F::new(12.507343) / (z + F::new(5.0));
+    series -= F::new(0.1385711) / (z + F::new(6.0));
+    series += F::new(9.9843696e-6) / (z + F::new(7.0));
+    series += F::new(1.5056327e-7) / (z + F::new(8.0));
+
+    let t = z + F::new(7.5);
+    let mut output = F::new(0.9189385) + (z + F::new(0.5)) * F::log(t) - t + F::log(series);
+
+    if reflected {
+        let pi = F::new(3.1415927);
+        output = F::log(pi / F::abs(F::sin(pi * x))) - output;
+    }
+
+    output
+}
+
+#[cube]
+fn digamma_value<F: Float>(x: F) -> F {
+    let reflected = x < F::new(0.5);
+    let mut z = x;
+    if reflected {
+        z = F::new(1.0) - x;
+    }
+
+    // psi(z) = psi(z + 1) - 1 / z, where z >= 0.5 reaches 10 in as many steps.
+    let mut shift = F::new(0.0);
+    for _ in range(0u32, 10u32, Comptime::new(false)) {
+        if z < F::new(10.0) {
+            shift += F::new(1.0) / z;
+            z += F::new(1.0);
+        }
+    }
+
+    // psi(z) ~ ln(z) - 1 / 2z - sum(B_2k / (2k z^2k))
+    let t = F::new(1.0) / (z * z);
+    let mut series = F::new(0.021092796) - F::new(0.083333336) * t;
+    series = series * t - F::new(0.0075757576);
+    series = series * t + F::new(0.004166667);
+    series = series * t - F::new(0.003968254);
+    series = series * t + F::new(0.008333334);
+    series = series * t - F::new(0.083333336);
+    series *= t;
+
+    let mut output = F::log(z) - F::new(0.5) / z + series - shift;
+
+    // psi(x) = psi(1 - x) - pi / tan(pi x)
+    if reflected {
+        let pi = F::new(3.1415927);
+        output -= pi * F::cos(pi * x) / F::sin(pi * x);
+    }
+
+    // The poles at the non-positive integers are undefined, like in PyTorch, the square root of
+    // the fraction minus one giving NaN.
+    let fraction = x - F::floor(x);
+    if x <= F::new(0.0) && fraction == F::new(0.0) {
+        output = F::sqrt(fraction - F::new(1.0));
+    }
+
+    output
+}
+
+#[cube]
+fn polygamma_value<F: Float>(x: F, order: UInt) -> F {
+    let n = F::cast_from(order);
+    let mut factorial = F::new(1.0);
+    for i in range(1u32, order + UInt::new(1), Comptime::new(false)) {
+        factorial *= F::cast_from(i);
+    }
+
+    // psi_n(z) = psi_n(z + 1) + (-1)^(n + 1) n! / z^(n + 1)
+    let mut z = x;
+    let mut shift = F::new(0.0);
+    for _ in range(0u32, 10u32, Comptime::new(false)) {
+        if z < F::new(10.0) {
+            shift += F::powf(z, F::new(0.0) - n - F::new(1.0));
+            z += F::new(1.0);
+        }
+    }
+
+    // psi_n(z) ~ (-1)^(n + 1) [(n - 1)! / z^n + n! / 2z^(n + 1)
+    //                          + sum(B_2k (2k + n - 1)! / ((2k)! z^(2k + n)))]
+    let t = F::new(1.0) / (z * z);
+    let mut term = factorial * (n + F::new(1.0)) / F::new(2.0) * t;
+    let mut series = factorial / n + factorial / (F::new(2.0) * z);
+    series += F::new(0.16666667) * term;
+    term = polygamma_next_term::<F>(term, t, n, F::new(2.0));
+    series -= F::new(0.033333335) * term;
+    term = polygamma_next_term::<F>(term, t, n, F::new(4.0));
+    series += F::new(0.023809524) * term;
+    term = polygamma_next_term::<F>(term, t, n, F::new(6.0));
+    series -= F::new(0.033333335) * term;
+    term = polygamma_next_term::<F>(term, t, n, F::new(8.0));
+    series += F::new(0.07575758) * term;
+    term = polygamma_next_term::<F>(term, t, n, F::new(10.0));
+    series -= F::new(0.25311355) * term;
+    term = polygamma_next_term::<F>(term, t, n, F::new(12.0));
+    series += F::new(1.1666667) * term;
+
+    let mut output = series * F::powf(z, F::new(0.0) - n) + factorial * shift;
+    if order % UInt::new(2) == UInt::new(0) {
+        output = F::new(0.0) - output;
+    }
+
+    output
+}
+
+/// Returns the next term `(2k + n + 1)! / ((2k + 2)! z^(2k + 2))` of the asymptotic series of
+/// the polygamma functions from the current one, where `k2 = 2k`.
+#[cube]
+fn polygamma_next_term<F: Float>(term: F, t: F, n: F, k2: F) -> F {
+    term * t * (k2 + n) * (k2 + n + F::new(1.0)) / ((k2 + F::new(1.0)) * (k2 + F::new(2.0)))
+}
+
+#[cube(launch)]
+fn erfinv_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    output[ABSOLUTE_POS] = erfinv_value::<F>(input[ABSOLUTE_POS]);
+}
+
+#[cube(launch)]
+fn lgamma_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    output[ABSOLUTE_POS] = lgamma_value::<F>(input[ABSOLUTE_POS]);
+}
+
+#[cube(launch)]
+fn digamma_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    output[ABSOLUTE_POS] = digamma_value::<F>(input[ABSOLUTE_POS]);
+}
+
+#[cube(launch)]
+fn polygamma_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>, order: UInt) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    output[ABSOLUTE_POS] = polygamma_value::<F>(input[ABSOLUTE_POS], order);
+}
+
+/// Applies the inverse error function element wise.
+pub(crate) fn erfinv<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let (input, output, cube_count) = prepare(tensor);
+
+    erfinv_kernel_launch::<E::FloatPrimitive, R>(
+        input.client.clone(),
+        cube_count,
+        settings(),
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+    );
+
+    output
+}
+
+/// Applies the natural logarithm of the absolute value of the gamma function element wise.
+pub(crate) fn lgamma<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let (input, output, cube_count) = prepare(tensor);
+
+    lgamma_kernel_launch::<E::FloatPrimitive, R>(
+        input.client.clone(),
+        cube_count,
+        settings(),
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+    );
+
+    output
+}
+
+/// Applies the digamma function element wise.
+pub(crate) fn digamma<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let (input, output, cube_count) = prepare(tensor);
+
+    digamma_kernel_launch::<E::FloatPrimitive, R>(
+        input.client.clone(),
+        cube_count,
+        settings(),
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+    );
+
+    output
+}
+
+/// Applies the polygamma function of the given order, which is at least one, element wise.
+pub(crate) fn polygamma<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    order: u32,
+) -> JitTensor<R, E, D> {
+    let (input, output, cube_count) = prepare(tensor);
+
+    polygamma_kernel_launch::<E::FloatPrimitive, R>(
+        input.client.clone(),
+        cube_count,
+        settings(),
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        order,
+    );
+
+    output
+}
+
+/// Returns the contiguous input, the output with the same layout and the cube count of an
+/// element wise kernel.
+fn prepare<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>, CubeCount) {
+    let input = into_contiguous(tensor);
+    let output = empty_device(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), SUBCUBE_DIM_APPROX);
+
+    (input, output, cube_count)
+}
+
+fn settings() -> KernelSettings {
+    KernelSettings::default()
+        .vectorize_input(0, 1)
+        .vectorize_output(0, 1)
+}
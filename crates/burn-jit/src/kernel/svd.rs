@@ -0,0 +1,317 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{DType, Shape};
+
+use crate::{
+    ops::{
+        into_data,
+        numeric::{empty_device, zeros_device},
+        reshape, swap_dims,
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Maximum number of sweeps over every pair of columns.
+const MAX_SWEEPS: usize = 30;
+
+#[cube(launch)]
+fn init_kernel<F: Float>(input: &Tensor<F>, a: &mut Tensor<F>, v: &mut Tensor<F>) {
+    let width = a.shape(2);
+
+    // The matrices are padded with a column of zeros to an even number of columns.
+    if ABSOLUTE_POS < a.len() {
+        let matrix = ABSOLUTE_POS / (a.shape(1) * width);
+        let row = (ABSOLUTE_POS / width) % a.shape(1);
+        let col = ABSOLUTE_POS % width;
+
+        let mut value = F::new(0.0);
+        if col < input.shape(2) {
+            value = input[matrix * input.stride(0) + row * input.stride(1) + col * input.stride(2)];
+        }
+        a[ABSOLUTE_POS] = value;
+    }
+
+    // The rotations are accumulated starting from the identity.
+    if ABSOLUTE_POS < v.len() {
+        let v_row = (ABSOLUTE_POS / width) % width;
+        let v_col = ABSOLUTE_POS % width;
+
+        let mut diagonal = F::new(0.0);
+        if v_row == v_col {
+            diagonal = F::new(1.0);
+        }
+        v[ABSOLUTE_POS] = diagonal;
+    }
+}
+
+/// The column at the position in the round-robin ordering of the round, the first column being
+/// fixed while the others rotate.
+#[cube]
+fn player(position: UInt, round: UInt, width: UInt) -> UInt {
+    let mut player = UInt::new(0);
+    if position > UInt::new(0) {
+        let cycle = width - UInt::new(1);
+        player = UInt::new(1) + (position - UInt::new(1) + cycle - round) % cycle;
+    }
+    player
+}
+
+#[cube(launch)]
+fn rotate_kernel<F: Float>(a: &mut Tensor<F>, v: &mut Tensor<F>, off: &mut Tensor<F>, round: UInt) {
+    let rows = a.shape(1);
+    let width = a.shape(2);
+    let half = width / UInt::new(2);
+    if ABSOLUTE_POS >= a.shape(0) * half {
+        return;
+    }
+
+    // Each unit rotates a pair of columns, the pairs of a round being disjoint.
+    let matrix = ABSOLUTE_POS / half;
+    let pair = ABSOLUTE_POS % half;
+    let p = player(pair, round, width);
+    let q = player(width - UInt::new(1) - pair, round, width);
+    let a_offset = matrix * a.stride(0);
+    let v_offset = matrix * v.stride(0);
+
+    let mut alpha = F::new(0.0);
+    let mut beta = F::new(0.0);
+    let mut gamma = F::new(0.0);
+    for i in range(0u32, rows, Comptime::new(false)) {
+        let x = a[a_offset + i * a.stride(1) + p * a.stride(2)];
+        let y = a[a_offset + i * a.stride(1) + q * a.stride(2)];
+        alpha += x * x;
+        beta += y * y;
+        gamma += x * y;
+    }
+
+    if gamma != F::new(0.0) {
+        // The cosine of the angle between the columns, compared to the tolerance by the host.
+        let ratio = F::abs(gamma) / F::sqrt(alpha * beta);
+        off[ABSOLUTE_POS] = F::max(off[ABSOLUTE_POS], ratio);
+
+        // The rotation of the smallest angle zeroing gamma.
+        let zeta = (beta - alpha) / (F::new(2.0) * gamma);
+        let mut t = F::new(1.0) / (F::abs(zeta) + F::sqrt(F::new(1.0) + zeta * zeta));
+        if zeta < F::new(0.0) {
+            t = F::new(0.0) - t;
+        }
+        let cos = F::new(1.0) / F::sqrt(F::new(1.0) + t * t);
+        let sin = cos * t;
+
+        for i in range(0u32, rows, Comptime::new(false)) {
+            let index_p = a_offset + i * a.stride(1) + p * a.stride(2);
+            let index_q = a_offset + i * a.stride(1) + q * a.stride(2);
+            let x = a[index_p];
+            let y = a[index_q];
+            a[index_p] = x * cos - y * sin;
+            a[index_q] = x * sin + y * cos;
+        }
+
+        for i in range(0u32, width, Comptime::new(false)) {
+            let index_p = v_offset + i * v.stride(1) + p * v.stride(2);
+            let index_q = v_offset + i * v.stride(1) + q * v.stride(2);
+            let x = v[index_p];
+            let y = v[index_q];
+            v[index_p] = x * cos - y * sin;
+            v[index_q] = x * sin + y * cos;
+        }
+    }
+}
+
+#[cube(launch)]
+fn norm_kernel<F: Float>(a: &Tensor<F>, sigma: &mut Tensor<F>) {
+    let cols = sigma.shape(1);
+    if ABSOLUTE_POS >= sigma.len() {
+        return;
+    }
+
+    let matrix = ABSOLUTE_POS / cols;
+    let col = ABSOLUTE_POS % cols;
+
+    let mut sum = F::new(0.0);
+    for i in range(0u32, a.shape(1), Comptime::new(false)) {
+        let x = a[matrix * a.stride(0) + i * a.stride(1) + col * a.stride(2)];
+        sum += x * x;
+    }
+    sigma[ABSOLUTE_POS] = F::sqrt(sum);
+}
+
+#[cube(launch)]
+fn output_kernel<F: Float>(
+    a: &Tensor<F>,
+    v: &Tensor<F>,
+    sigma: &Tensor<F>,
+    u: &mut Tensor<F>,
+    s: &mut Tensor<F>,
+    vt: &mut Tensor<F>,
+) {
+    let rows = u.shape(1);
+    let cols = u.shape(2);
+    if ABSOLUTE_POS >= sigma.len() {
+        return;
+    }
+
+    let matrix = ABSOLUTE_POS / cols;
+    let col = ABSOLUTE_POS % cols;
+    let offset = matrix * cols;
+    let value = sigma[ABSOLUTE_POS];
+
+    // The singular values are sorted in descending order by counting the larger ones, ties being
+    // ordered by column.
+    let mut rank = UInt::new(0);
+    for i in range(0u32, cols, Comptime::new(false)) {
+        let other = sigma[offset + i];
+        if other > value || (other == value && i < col) {
+            rank += UInt::new(1);
+        }
+    }
+
+    s[offset + rank] = value;
+
+    let mut scale = F::new(0.0);
+    if value > F::new(0.0) {
+        scale = F::new(1.0) / value;
+    }
+    for i in range(0u32, rows, Comptime::new(false)) {
+        u[matrix * rows * cols + i * cols + rank] =
+            a[matrix * a.stride(0) + i * a.stride(1) + col * a.stride(2)] * scale;
+    }
+    for i in range(0u32, cols, Comptime::new(false)) {
+        vt[matrix * cols * cols + rank * cols + i] =
+            v[matrix * v.stride(0) + i * v.stride(1) + col * v.stride(2)];
+    }
+}
+
+/// Computes the reduced singular value decomposition of a batch of matrices with one-sided Jacobi
+/// rotations.
+///
+/// Each sweep launches one kernel per round of the round-robin ordering of the columns, where
+/// every unit rotates a disjoint pair of columns of a matrix. The convergence is checked after
+/// each sweep when the data can be read synchronously, otherwise the maximum number of sweeps is
+/// always executed.
+pub(crate) fn svd<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let dims = tensor.shape.dims;
+    let [m, n] = [dims[D - 2], dims[D - 1]];
+
+    // The columns of the transpose are rotated instead, so that there are fewer pairs.
+    if m < n {
+        let (u, s, vt) = svd(swap_dims(tensor, D - 2, D - 1));
+        return (swap_dims(vt, D - 2, D - 1), s, swap_dims(u, D - 2, D - 1));
+    }
+
+    let client = tensor.client.clone();
+    let device = tensor.device.clone();
+    let num_matrices = dims[..D - 2].iter().product::<usize>();
+    let shape = |rows: usize, cols: usize| {
+        let mut dims = dims;
+        dims[D - 2] = rows;
+        dims[D - 1] = cols;
+        Shape::new(dims)
+    };
+
+    if num_matrices * n == 0 {
+        return (
+            empty_device(client.clone(), device.clone(), shape(m, n)),
+            empty_device(client.clone(), device.clone(), shape(1, n)),
+            empty_device(client, device, shape(n, n)),
+        );
+    }
+
+    let input = reshape(tensor, Shape::new([num_matrices, m, n]));
+    let width = n + n % 2;
+    let a = empty_device::<R, E, 3>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, m, width]),
+    );
+    let v = empty_device::<R, E, 3>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, width, width]),
+    );
+
+    init_kernel_launch::<E::FloatPrimitive, R>(
+        client.clone(),
+        calculate_cube_count_elemwise(
+            num_matrices * width * usize::max(m, width),
+            SUBCUBE_DIM_APPROX,
+        ),
+        KernelSettings::default(),
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&v.handle, &v.strides, &v.shape.dims),
+    );
+
+    let tolerance = match E::dtype() {
+        DType::F64 => 1e-14,
+        _ => 1e-6,
+    };
+    let num_pairs = num_matrices * width / 2;
+
+    for _ in 0..MAX_SWEEPS {
+        let off = zeros_device::<R, E, 1>(client.clone(), device.clone(), Shape::new([num_pairs]));
+
+        for round in 0..width - 1 {
+            rotate_kernel_launch::<E::FloatPrimitive, R>(
+                client.clone(),
+                calculate_cube_count_elemwise(num_pairs, SUBCUBE_DIM_APPROX),
+                KernelSettings::default(),
+                TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+                TensorHandle::new(&v.handle, &v.strides, &v.shape.dims),
+                TensorHandle::new(&off.handle, &off.strides, &off.shape.dims),
+                round as u32,
+            );
+        }
+
+        let converged = into_data(off)
+            .read_sync()
+            .map(|data| data.iter::<f64>().all(|off| off <= tolerance));
+        if converged == Some(true) {
+            break;
+        }
+    }
+
+    let sigma = empty_device::<R, E, 2>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, n]),
+    );
+    norm_kernel_launch::<E::FloatPrimitive, R>(
+        client.clone(),
+        calculate_cube_count_elemwise(num_matrices * n, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&sigma.handle, &sigma.strides, &sigma.shape.dims),
+    );
+
+    let u = empty_device::<R, E, 3>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, m, n]),
+    );
+    let s = empty_device::<R, E, 3>(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_matrices, 1, n]),
+    );
+    let vt = empty_device::<R, E, 3>(client.clone(), device, Shape::new([num_matrices, n, n]));
+    output_kernel_launch::<E::FloatPrimitive, R>(
+        client,
+        calculate_cube_count_elemwise(num_matrices * n, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&a.handle, &a.strides, &a.shape.dims),
+        TensorHandle::new(&v.handle, &v.strides, &v.shape.dims),
+        TensorHandle::new(&sigma.handle, &sigma.strides, &sigma.shape.dims),
+        TensorHandle::new(&u.handle, &u.strides, &u.shape.dims),
+        TensorHandle::new(&s.handle, &s.strides, &s.shape.dims),
+        TensorHandle::new(&vt.handle, &vt.strides, &vt.shape.dims),
+    );
+
+    (
+        reshape(u, shape(m, n)),
+        reshape(s, shape(1, n)),
+        reshape(vt, shape(n, n)),
+    )
+}
@@ -0,0 +1,274 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::Shape;
+
+use crate::{
+    element::JitElement,
+    kernel::{into_contiguous, slice},
+    ops::{into_data, numeric::empty_device, reshape},
+    tensor::JitTensor,
+    IntElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn init_kernel<N: Numeric, I: Int>(input: &Tensor<N>, keys: &mut Tensor<N>, order: &mut Tensor<I>) {
+    if ABSOLUTE_POS >= keys.shape(0) {
+        return;
+    }
+
+    // The keys past the input are padding, which is identified by its position.
+    if ABSOLUTE_POS < input.shape(0) {
+        keys[ABSOLUTE_POS] = input[ABSOLUTE_POS];
+    }
+    order[ABSOLUTE_POS] = I::cast_from(ABSOLUTE_POS);
+}
+
+#[cube(launch)]
+fn bitonic_step_kernel<N: Numeric, I: Int>(
+    keys: &mut Tensor<N>,
+    order: &mut Tensor<I>,
+    num_elems: UInt,
+    block: UInt,
+    stride: UInt,
+) {
+    let partner = ABSOLUTE_POS ^ stride;
+    if ABSOLUTE_POS >= keys.shape(0) || partner <= ABSOLUTE_POS {
+        return;
+    }
+
+    let key = keys[ABSOLUTE_POS];
+    let partner_key = keys[partner];
+    let position = UInt::cast_from(order[ABSOLUTE_POS]);
+    let partner_position = UInt::cast_from(order[partner]);
+
+    // Whether the element is ordered after its partner, by key and then by position to keep the
+    // sort stable, the padding being ordered last.
+    let mut after = UInt::new(0);
+    if position > partner_position {
+        after = UInt::new(1);
+    }
+    if key < partner_key {
+        after = UInt::new(0);
+    }
+    if key > partner_key {
+        after = UInt::new(1);
+    }
+    if position >= num_elems {
+        after = UInt::new(1);
+    }
+    if partner_position >= num_elems {
+        after = UInt::new(0);
+    }
+
+    // The elements are sorted in ascending order in the even blocks, and descending in the odd
+    // ones, so that the merged blocks are bitonic.
+    let descending = (ABSOLUTE_POS & block) / block;
+    if after != descending {
+        keys[ABSOLUTE_POS] = partner_key;
+        keys[partner] = key;
+        order[ABSOLUTE_POS] = I::cast_from(partner_position);
+        order[partner] = I::cast_from(position);
+    }
+}
+
+#[cube(launch)]
+fn flags_kernel<N: Numeric, I: Int>(keys: &Tensor<N>, flags: &mut Tensor<I>) {
+    if ABSOLUTE_POS >= flags.shape(0) {
+        return;
+    }
+
+    let mut previous = ABSOLUTE_POS;
+    if ABSOLUTE_POS > UInt::new(0) {
+        previous = ABSOLUTE_POS - UInt::new(1);
+    }
+
+    let mut flag = I::new(1);
+    if previous < ABSOLUTE_POS && keys[ABSOLUTE_POS] == keys[previous] {
+        flag = I::new(0);
+    }
+    flags[ABSOLUTE_POS] = flag;
+}
+
+#[cube(launch)]
+fn scan_step_kernel<I: Int>(input: &Tensor<I>, output: &mut Tensor<I>, offset: UInt) {
+    if ABSOLUTE_POS >= output.shape(0) {
+        return;
+    }
+
+    let mut sum = input[ABSOLUTE_POS];
+    if ABSOLUTE_POS >= offset {
+        sum += input[ABSOLUTE_POS - offset];
+    }
+    output[ABSOLUTE_POS] = sum;
+}
+
+#[cube(launch)]
+fn scatter_kernel<N: Numeric, I: Int>(
+    keys: &Tensor<N>,
+    order: &Tensor<I>,
+    runs: &Tensor<I>,
+    values: &mut Tensor<N>,
+    inverse: &mut Tensor<I>,
+    starts: &mut Tensor<I>,
+) {
+    if ABSOLUTE_POS >= runs.shape(0) {
+        return;
+    }
+
+    // The runs are numbered from one by the inclusive scan of the flags.
+    let run = runs[ABSOLUTE_POS];
+    let mut previous = I::new(0);
+    if ABSOLUTE_POS > UInt::new(0) {
+        previous = runs[ABSOLUTE_POS - UInt::new(1)];
+    }
+
+    let index = UInt::cast_from(run) - UInt::new(1);
+    inverse[UInt::cast_from(order[ABSOLUTE_POS])] = I::cast_from(index);
+    if run != previous {
+        values[index] = keys[ABSOLUTE_POS];
+        starts[index] = I::cast_from(ABSOLUTE_POS);
+    }
+}
+
+#[cube(launch)]
+fn counts_kernel<I: Int>(starts: &Tensor<I>, counts: &mut Tensor<I>, num_elems: UInt) {
+    let num_runs = counts.shape(0);
+    if ABSOLUTE_POS >= num_runs {
+        return;
+    }
+
+    let mut end = I::cast_from(num_elems);
+    if ABSOLUTE_POS + UInt::new(1) < num_runs {
+        end = starts[ABSOLUTE_POS + UInt::new(1)];
+    }
+    counts[ABSOLUTE_POS] = end - starts[ABSOLUTE_POS];
+}
+
+fn handle<R: JitRuntime, E: JitElement>(tensor: &JitTensor<R, E, 1>) -> TensorHandle<'_, R> {
+    TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims)
+}
+
+/// Collapses the runs of equal elements of the flattened tensor, sorting the elements first unless
+/// only the consecutive runs are collapsed.
+///
+/// The elements are sorted with their positions by a bitonic sort, padded to a power of two,
+/// where every unit compares and swaps a pair of elements at each step. The first element of
+/// each run is then flagged, and the flags are summed by an inclusive scan giving the run of each
+/// element. Only the number of runs is read on the host, since it defines the size of the output.
+#[allow(clippy::single_range_in_vec_init)]
+pub(crate) fn unique<R: JitRuntime, E: JitElement, N: Numeric, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    consecutive: bool,
+) -> (JitTensor<R, E, 1>, JitTensor<R, I, D>, JitTensor<R, I, 1>) {
+    let shape = tensor.shape.clone();
+    let num_elems = shape.num_elements();
+    let client = tensor.client.clone();
+    let device = tensor.device.clone();
+
+    if num_elems == 0 {
+        return (
+            empty_device(client.clone(), device.clone(), Shape::new([0])),
+            empty_device(client.clone(), device.clone(), shape),
+            empty_device(client, device, Shape::new([0])),
+        );
+    }
+
+    let input = reshape(into_contiguous(tensor), Shape::new([num_elems]));
+    let size = match consecutive {
+        true => num_elems,
+        false => num_elems.next_power_of_two(),
+    };
+    let cube_count =
+        |num_units: usize| calculate_cube_count_elemwise(num_units, SUBCUBE_DIM_APPROX);
+
+    let keys = empty_device::<R, E, 1>(client.clone(), device.clone(), Shape::new([size]));
+    let order = empty_device::<R, I, 1>(client.clone(), device.clone(), Shape::new([size]));
+    init_kernel_launch::<N, I::IntPrimitive, R>(
+        client.clone(),
+        cube_count(size),
+        KernelSettings::default(),
+        handle(&input),
+        handle(&keys),
+        handle(&order),
+    );
+
+    if !consecutive {
+        let mut block = 2;
+        while block <= size {
+            let mut stride = block / 2;
+            while stride > 0 {
+                bitonic_step_kernel_launch::<N, I::IntPrimitive, R>(
+                    client.clone(),
+                    cube_count(size),
+                    KernelSettings::default(),
+                    handle(&keys),
+                    handle(&order),
+                    num_elems as u32,
+                    block as u32,
+                    stride as u32,
+                );
+                stride /= 2;
+            }
+            block *= 2;
+        }
+    }
+
+    let mut runs = empty_device::<R, I, 1>(client.clone(), device.clone(), Shape::new([num_elems]));
+    flags_kernel_launch::<N, I::IntPrimitive, R>(
+        client.clone(),
+        cube_count(num_elems),
+        KernelSettings::default(),
+        handle(&keys),
+        handle(&runs),
+    );
+
+    // Inclusive scan of the flags, each step adding the partial sum `offset` elements before.
+    let mut offset = 1;
+    while offset < num_elems {
+        let output =
+            empty_device::<R, I, 1>(client.clone(), device.clone(), Shape::new([num_elems]));
+        scan_step_kernel_launch::<I::IntPrimitive, R>(
+            client.clone(),
+            cube_count(num_elems),
+            KernelSettings::default(),
+            handle(&runs),
+            handle(&output),
+            offset as u32,
+        );
+        runs = output;
+        offset *= 2;
+    }
+
+    let num_runs = into_data(slice(runs.clone(), [num_elems - 1..num_elems]))
+        .read_sync()
+        .expect("Can't read the number of unique elements on this runtime")
+        .iter::<i64>()
+        .next()
+        .unwrap() as usize;
+
+    let values = empty_device::<R, E, 1>(client.clone(), device.clone(), Shape::new([num_runs]));
+    let inverse = empty_device::<R, I, 1>(client.clone(), device.clone(), Shape::new([num_elems]));
+    let starts = empty_device::<R, I, 1>(client.clone(), device.clone(), Shape::new([num_runs]));
+    scatter_kernel_launch::<N, I::IntPrimitive, R>(
+        client.clone(),
+        cube_count(num_elems),
+        KernelSettings::default(),
+        handle(&keys),
+        handle(&order),
+        handle(&runs),
+        handle(&values),
+        handle(&inverse),
+        handle(&starts),
+    );
+
+    let counts = empty_device::<R, I, 1>(client.clone(), device, Shape::new([num_runs]));
+    counts_kernel_launch::<I::IntPrimitive, R>(
+        client,
+        cube_count(num_runs),
+        KernelSettings::default(),
+        handle(&starts),
+        handle(&counts),
+        num_elems as u32,
+    );
+
+    (values, reshape(inverse, shape), counts)
+}
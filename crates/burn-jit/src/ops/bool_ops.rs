@@ -1,7 +1,7 @@
 use crate::{kernel, FloatElement, IntElement, JitBackend, JitRuntime};
 use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntTensor};
 use burn_tensor::Reader;
-use burn_tensor::{ops::BoolTensorOps, Shape, TensorData};
+use burn_tensor::{ops::BoolTensorOps, DType, Shape, TensorData};
 use std::ops::Range;
 
 use super::{expand, permute};
@@ -24,6 +24,10 @@ where
         super::bool_into_data(tensor)
     }
 
+    fn bool_checksum<const D: usize>(tensor: BoolTensor<Self, D>) -> Reader<u64> {
+        kernel::checksum::checksum(tensor, DType::Bool)
+    }
+
     fn bool_from_data<const D: usize>(
         data: TensorData,
         device: &Device<Self>,
@@ -61,6 +65,14 @@ where
         kernel::slice(tensor, ranges)
     }
 
+    fn bool_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> BoolTensor<Self, D1> {
+        kernel::slice_with_steps(tensor, ranges, steps)
+    }
+
     fn bool_slice_assign<const D1: usize, const D2: usize>(
         tensor: BoolTensor<Self, D1>,
         ranges: [Range<usize>; D2],
@@ -117,6 +129,10 @@ where
         expand(tensor, shape)
     }
 
+    fn bool_pack_bits<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        kernel::bits::pack_bits(tensor)
+    }
+
     fn bool_flip<const D: usize>(
         tensor: BoolTensor<Self, D>,
         axes: &[usize],
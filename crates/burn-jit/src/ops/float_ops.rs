@@ -6,9 +6,16 @@ use crate::{unary, JitBackend};
 use crate::{FloatElement, IntElement, JitRuntime};
 use burn_cube::ir::{BinaryOperator, Elem, Operator, Scope, UnaryOperator, Variable};
 use burn_cube::Runtime;
-use burn_tensor::ops::{BoolTensor, Device, FloatElem, FloatTensor, IntTensor};
-use burn_tensor::{ops::FloatTensorOps, Distribution, Shape, TensorData};
-use burn_tensor::{ElementConversion, Reader};
+use burn_tensor::ops::{
+    einsum::Contraction, random::random_from_standard, BoolTensor, Device, FloatElem, FloatTensor,
+    IntTensor,
+};
+use burn_tensor::{
+    linalg::QrMode, ops::FloatTensorOps, ComplexPrimitive, Distribution, Shape, TensorData,
+};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use burn_tensor::{ops::unique::UniqueOutput, Float};
+use burn_tensor::{ElementConversion, IndexReduction, Reader};
 use std::ops::Range;
 
 impl<R, F, I> FloatTensorOps<Self> for JitBackend<R, F, I>
@@ -38,9 +45,18 @@ where
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem(), std.elem())
             }
+            distribution => random_from_standard::<Self, D>(shape, distribution, device),
         }
     }
 
+    fn float_multinomial<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::multinomial::multinomial(tensor, num_samples, replacement)
+    }
+
     fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
         tensor.shape.clone()
     }
@@ -49,6 +65,10 @@ where
         super::into_data(tensor)
     }
 
+    fn float_checksum<const D: usize>(tensor: FloatTensor<Self, D>) -> Reader<u64> {
+        kernel::checksum::checksum(tensor, F::dtype())
+    }
+
     fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
         tensor.device.clone()
     }
@@ -150,6 +170,15 @@ where
         matmul(lhs, rhs, MatmulStrategy::default())
     }
 
+    fn float_spmm_csr(
+        row_offsets: IntTensor<Self, 1>,
+        col_indices: IntTensor<Self, 1>,
+        values: FloatTensor<Self, 1>,
+        rhs: FloatTensor<Self, 2>,
+    ) -> FloatTensor<Self, 2> {
+        kernel::sparse::spmm_csr(row_offsets, col_indices, values, rhs)
+    }
+
     fn float_swap_dims<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim1: usize,
@@ -206,6 +235,14 @@ where
         kernel::slice(tensor, ranges)
     }
 
+    fn float_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> FloatTensor<Self, D1> {
+        kernel::slice_with_steps(tensor, ranges, steps)
+    }
+
     fn float_slice_assign<const D1: usize, const D2: usize>(
         tensor: FloatTensor<Self, D1>,
         ranges: [Range<usize>; D2],
@@ -230,6 +267,14 @@ where
         kernel::mask_fill_auto(tensor, mask, value)
     }
 
+    fn float_mask_fill_packed<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: IntTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        kernel::bits::mask_fill_packed(tensor, mask, value)
+    }
+
     fn float_equal<const D: usize>(
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
@@ -311,6 +356,13 @@ where
         reduce::sum_dim(tensor, dim, Default::default())
     }
 
+    fn float_logsumexp_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        reduce::logsumexp_dim(tensor, dim, Default::default())
+    }
+
     fn float_mean_dim<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -453,6 +505,129 @@ where
         )
     }
 
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        kernel::special::erfinv(tensor)
+    }
+
+    fn float_fft<const D: usize>(
+        tensor: ComplexPrimitive<Self, D>,
+        inverse: bool,
+    ) -> ComplexPrimitive<Self, D> {
+        let (real, imag) = kernel::fft::fft(tensor.real, tensor.imag, inverse);
+
+        ComplexPrimitive { real, imag }
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        kernel::svd::svd(tensor)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_unique<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        consecutive: bool,
+    ) -> UniqueOutput<Self, Float, D> {
+        kernel::unique::unique::<R, F, F::FloatPrimitive, I, D>(tensor, consecutive)
+    }
+
+    fn float_segment_sum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        segment_ids: IntTensor<Self, 1>,
+        num_segments: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::segment::segment_sum_float(tensor, segment_ids, num_segments)
+    }
+
+    fn float_segment_reduce_sorted<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        segment_ids: IntTensor<Self, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> FloatTensor<Self, D> {
+        kernel::segment::segment_reduce_sorted::<R, F, F::FloatPrimitive, I, D>(
+            tensor,
+            segment_ids,
+            num_segments,
+            reduction,
+        )
+    }
+
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mode: QrMode,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        kernel::qr::qr(tensor, mode)
+    }
+
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        kernel::cholesky::cholesky(tensor)
+    }
+
+    fn float_solve<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        kernel::solve::solve(tensor, rhs)
+    }
+
+    fn float_triangular_solve<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+        upper: bool,
+    ) -> FloatTensor<Self, D> {
+        kernel::solve::triangular_solve(tensor, rhs, upper)
+    }
+
+    fn float_det<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        kernel::det::det(tensor)
+    }
+
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        kernel::det::slogdet(tensor)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        kernel::eigh::eigh(tensor)
+    }
+
+    fn float_contract<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+        contraction: Contraction,
+    ) -> FloatTensor<Self, D> {
+        kernel::einsum::contract(lhs, rhs, contraction)
+    }
+
+    fn float_lgamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        kernel::special::lgamma(tensor)
+    }
+
+    fn float_digamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        kernel::special::digamma(tensor)
+    }
+
+    fn float_polygamma<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        order: u32,
+    ) -> FloatTensor<Self, D> {
+        match order {
+            0 => kernel::special::digamma(tensor),
+            _ => kernel::special::polygamma(tensor, order),
+        }
+    }
+
     fn float_argmax<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -3,8 +3,14 @@ use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
 use crate::{kernel, unary, FloatElement, IntElement, JitBackend, JitRuntime};
 use burn_cube::ir::{Elem, Item, Operator, Scope, UnaryOperator, Variable};
 use burn_cube::Runtime;
-use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
-use burn_tensor::{ops::IntTensorOps, Distribution, ElementConversion, Reader, Shape, TensorData};
+use burn_tensor::ops::{
+    random::random_from_standard, BoolTensor, Device, FloatTensor, IntElem, IntTensor,
+};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use burn_tensor::{ops::unique::UniqueOutput, Int};
+use burn_tensor::{
+    ops::IntTensorOps, Distribution, ElementConversion, IndexReduction, Reader, Shape, TensorData,
+};
 use std::ops::Range;
 
 impl<R, F, I> IntTensorOps<Self> for JitBackend<R, F, I>
@@ -25,6 +31,10 @@ where
         super::into_data(tensor)
     }
 
+    fn int_checksum<const D: usize>(tensor: IntTensor<Self, D>) -> Reader<u64> {
+        kernel::checksum::checksum(tensor, I::dtype())
+    }
+
     fn int_from_data<const D: usize>(
         data: TensorData,
         device: &Device<Self>,
@@ -57,6 +67,14 @@ where
         kernel::slice(tensor, ranges)
     }
 
+    fn int_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> IntTensor<Self, D1> {
+        kernel::slice_with_steps(tensor, ranges, steps)
+    }
+
     fn int_slice_assign<const D1: usize, const D2: usize>(
         tensor: IntTensor<Self, D1>,
         ranges: [Range<usize>; D2],
@@ -308,6 +326,55 @@ where
         kernel::cast(tensor)
     }
 
+    fn int_bincount(tensor: IntTensor<Self, 1>, num_bins: usize) -> IntTensor<Self, 1> {
+        kernel::bincount::bincount(tensor, num_bins)
+    }
+
+    fn int_bincount_weighted(
+        tensor: IntTensor<Self, 1>,
+        weights: FloatTensor<Self, 1>,
+        num_bins: usize,
+    ) -> FloatTensor<Self, 1> {
+        kernel::bincount::bincount_weighted(tensor, weights, num_bins)
+    }
+
+    fn int_segment_sum<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        segment_ids: IntTensor<Self, 1>,
+        num_segments: usize,
+    ) -> IntTensor<Self, D> {
+        kernel::segment::segment_sum_int(tensor, segment_ids, num_segments)
+    }
+
+    fn int_segment_reduce_sorted<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        segment_ids: IntTensor<Self, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> IntTensor<Self, D> {
+        kernel::segment::segment_reduce_sorted::<R, I, I::IntPrimitive, I, D>(
+            tensor,
+            segment_ids,
+            num_segments,
+            reduction,
+        )
+    }
+
+    fn int_mode<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::mode::mode(tensor, dim)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_unique<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        consecutive: bool,
+    ) -> UniqueOutput<Self, Int, D> {
+        kernel::unique::unique::<R, I, I::IntPrimitive, I, D>(tensor, consecutive)
+    }
+
     fn int_swap_dims<const D: usize>(
         mut tensor: IntTensor<Self, D>,
         dim1: usize,
@@ -341,6 +408,9 @@ where
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem(), std.elem())
             }
+            distribution => {
+                return kernel::cast(random_from_standard::<Self, D>(shape, distribution, device))
+            }
         };
 
         kernel::cast(float_tensor)
@@ -363,4 +433,11 @@ where
     fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
         kernel::flip(tensor, axes)
     }
+
+    fn int_unpack_bits<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        size: usize,
+    ) -> BoolTensor<Self, D> {
+        kernel::bits::unpack_bits(tensor, size)
+    }
 }
@@ -1,9 +1,13 @@
 use crate::{kernel, FloatElement, IntElement, JitBackend, JitRuntime};
+use burn_tensor::ops::{scan, BoolTensor, FloatTensor, IntTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices,
-    ModuleOps,
+    AttentionOptions, ConvOptions, ConvTransposeOptions, InterpolateOptions, MaxPool2dBackward,
+    MaxPool2dWithIndices, ModuleOps, ResampleOptions, WindowAttentionOptions,
 };
-use burn_tensor::ops::{FloatTensor, IntTensor};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use burn_tensor::ops::{BoolTensorOps, FloatTensorOps, IntTensorOps};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use burn_tensor::Shape;
 
 impl<R, F, I> ModuleOps<Self> for JitBackend<R, F, I>
 where
@@ -123,4 +127,88 @@ where
     ) -> FloatTensor<Self, 4> {
         kernel::interpolate::interpolate_backward(x, grad, output_size, options)
     }
+
+    fn dilate(x: FloatTensor<Self, 4>, kernel: BoolTensor<Self, 2>) -> FloatTensor<Self, 4> {
+        kernel::morphology::dilate(x, kernel)
+    }
+
+    fn erode(x: FloatTensor<Self, 4>, kernel: BoolTensor<Self, 2>) -> FloatTensor<Self, 4> {
+        kernel::morphology::erode(x, kernel)
+    }
+
+    fn resample(
+        x: FloatTensor<Self, 2>,
+        orig_freq: usize,
+        new_freq: usize,
+        options: ResampleOptions,
+    ) -> FloatTensor<Self, 2> {
+        kernel::resample::resample(x, orig_freq, new_freq, options)
+    }
+
+    fn attention(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        mask: Option<BoolTensor<Self, 4>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<Self, 4> {
+        kernel::attention::attention(query, key, value, mask, options)
+    }
+
+    fn window_attention(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        global: Option<BoolTensor<Self, 2>>,
+        options: WindowAttentionOptions,
+    ) -> FloatTensor<Self, 4> {
+        kernel::attention::window_attention(query, key, value, global, options)
+    }
+
+    fn dequantize_matmul(
+        x: FloatTensor<Self, 3>,
+        weight: IntTensor<Self, 2>,
+        scale: FloatTensor<Self, 1>,
+    ) -> FloatTensor<Self, 3> {
+        kernel::dequantize::dequantize_matmul(x, weight, scale)
+    }
+
+    fn selective_scan(
+        u: FloatTensor<Self, 3>,
+        delta: FloatTensor<Self, 3>,
+        a: FloatTensor<Self, 2>,
+        b: FloatTensor<Self, 3>,
+        c: FloatTensor<Self, 3>,
+    ) -> FloatTensor<Self, 3> {
+        if a.shape.dims[1] > kernel::scan::SCAN_MAX_STATE_SIZE {
+            return scan::selective_scan::<Self>(u, delta, a, b, c);
+        }
+
+        kernel::scan::selective_scan(u, delta, a, b, c)
+    }
+
+    fn pixel_shuffle(x: FloatTensor<Self, 4>, upscale_factor: usize) -> FloatTensor<Self, 4> {
+        kernel::pixel_shuffle::pixel_shuffle(x, upscale_factor)
+    }
+
+    fn pixel_unshuffle(x: FloatTensor<Self, 4>, downscale_factor: usize) -> FloatTensor<Self, 4> {
+        kernel::pixel_shuffle::pixel_unshuffle(x, downscale_factor)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn nms(
+        boxes: FloatTensor<Self, 2>,
+        scores: FloatTensor<Self, 1>,
+        iou_threshold: f32,
+    ) -> IntTensor<Self, 1> {
+        let order = Self::float_argsort(scores, 0, true);
+        let boxes = kernel::select(boxes, 0, order.clone());
+        let keep = kernel::nms::nms_keep(boxes, iou_threshold);
+
+        let kept = Self::bool_argwhere(keep);
+        let num_kept = kept.shape.dims[0];
+        let kept = Self::int_reshape(kept, Shape::new([num_kept]));
+
+        kernel::select(order, 0, kept)
+    }
 }
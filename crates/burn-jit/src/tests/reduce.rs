@@ -2,7 +2,7 @@
 mod reduction {
     use super::*;
     use burn_jit::kernel::reduce::{
-        argmax, argmin, mean_dim, prod, prod_dim, sum, sum_dim, ReduceStrategy,
+        argmax, argmin, logsumexp_dim, mean_dim, prod, prod_dim, sum, sum_dim, ReduceStrategy,
     };
     use burn_tensor::{
         backend::Backend, ops::IntTensorOps, Distribution, Int, Shape, Tensor, TensorData,
@@ -44,6 +44,28 @@ mod reduction {
         val_ref.into_data().assert_approx_eq(&val.into_data(), 2);
     }
 
+    #[test]
+    fn reduction_logsumexp_dim_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 2>::random(
+            [6, 1024],
+            Distribution::Uniform(-50.0, 50.0),
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+        let reduce_dim = 1;
+
+        let val =
+            Tensor::<TestBackend, 2>::from_primitive(logsumexp_dim::<TestRuntime, f32, f32, 2>(
+                tensor.into_primitive(),
+                reduce_dim,
+                ReduceStrategy::Naive,
+            ));
+        let val_ref = tensor_ref.logsumexp(1);
+
+        val_ref.into_data().assert_approx_eq(&val.into_data(), 2);
+    }
+
     #[test]
     fn reduction_argmin_dim_should_work_with_multiple_invocations() {
         let tensor =
@@ -129,6 +151,28 @@ mod reduction {
         val_ref.into_data().assert_approx_eq(&val.into_data(), 2);
     }
 
+    #[test]
+    fn reduction_logsumexp_dim_shared_memory_not_divisible() {
+        let tensor = Tensor::<TestBackend, 2>::random(
+            [12, 1107],
+            Distribution::Uniform(-50.0, 50.0),
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+        let reduce_dim = 1;
+
+        let val =
+            Tensor::<TestBackend, 2>::from_primitive(logsumexp_dim::<TestRuntime, f32, f32, 2>(
+                tensor.into_primitive(),
+                reduce_dim,
+                ReduceStrategy::SharedMemory,
+            ));
+        let val_ref = tensor_ref.logsumexp(reduce_dim);
+
+        val_ref.into_data().assert_approx_eq(&val.into_data(), 2);
+    }
+
     #[test]
     fn reduction_sum_dim_shared_memory_medium_divisible() {
         let tensor =
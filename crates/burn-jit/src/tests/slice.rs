@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(slice)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::{Distribution, Slice, Tensor};
 
     #[test]
     fn slice_should_work_with_multiple_workgroups() {
@@ -18,4 +18,23 @@ mod tests {
             .into_data()
             .assert_approx_eq(&actual.into_data(), 3);
     }
+
+    #[test]
+    fn slice_with_steps_should_work_with_multiple_workgroups() {
+        let tensor =
+            Tensor::<TestBackend, 2>::random([6, 256], Distribution::Default, &Default::default());
+        let slices = [
+            Slice::from(1..6).with_step(2),
+            Slice::from(3..250).with_step(-3),
+        ];
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+
+        let actual = tensor.slice(slices.clone());
+        let expected = tensor_ref.slice(slices);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
 }
@@ -42,6 +42,17 @@ where
         NdArrayTensor { array }
     }
 
+    pub fn slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> NdArrayTensor<E, D1> {
+        let slices = Self::to_slice_args_with_steps::<D1, D2>(ranges, steps);
+        let array = tensor.array.slice_move(slices.as_slice()).into_shared();
+
+        NdArrayTensor { array }
+    }
+
     pub fn slice_assign<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D1>,
         ranges: [Range<usize>; D2],
@@ -84,6 +95,13 @@ where
 
     fn to_slice_args<const D1: usize, const D2: usize>(
         ranges: [Range<usize>; D2],
+    ) -> [SliceInfoElem; D1] {
+        Self::to_slice_args_with_steps(ranges, [1; D2])
+    }
+
+    fn to_slice_args_with_steps<const D1: usize, const D2: usize>(
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
     ) -> [SliceInfoElem; D1] {
         let mut slices = [SliceInfoElem::NewAxis; D1];
         for i in 0..D1 {
@@ -97,7 +115,7 @@ where
                 slices[i] = SliceInfoElem::Slice {
                     start: ranges[i].start as isize,
                     end: Some(ranges[i].end as isize),
-                    step: 1,
+                    step: steps[i],
                 }
             }
         }
@@ -400,17 +418,26 @@ where
         mask: NdArrayTensor<bool, D>,
         source: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul_4tensor = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_mul_4source = mask.array.mapv(|x| match x {
-            true => 1.elem(),
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul_4tensor) + (source.array * mask_mul_4source);
+        // The values are selected rather than blended with the mask, so non-finite values on
+        // the side that isn't selected don't propagate.
+        let shape = IxDyn(
+            &(0..D)
+                .map(|i| {
+                    tensor.array.shape()[i]
+                        .max(mask.array.shape()[i])
+                        .max(source.array.shape()[i])
+                })
+                .collect::<Vec<_>>(),
+        );
+        let array = Zip::from(tensor.array.broadcast(shape.clone()).unwrap())
+            .and(mask.array.broadcast(shape.clone()).unwrap())
+            .and(source.array.broadcast(shape).unwrap())
+            .map_collect(|tensor, mask, source| match mask {
+                true => *source,
+                false => *tensor,
+            });
 
-        NdArrayTensor::new(array)
+        NdArrayTensor::new(array.into_shared())
     }
 
     pub fn mask_fill<const D: usize>(
@@ -418,17 +445,19 @@ where
         mask: NdArrayTensor<bool, D>,
         value: E,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_add = mask.array.mapv(|x| match x {
-            true => value,
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul) + mask_add;
+        let shape = IxDyn(
+            &(0..D)
+                .map(|i| tensor.array.shape()[i].max(mask.array.shape()[i]))
+                .collect::<Vec<_>>(),
+        );
+        let array = Zip::from(tensor.array.broadcast(shape.clone()).unwrap())
+            .and(mask.array.broadcast(shape).unwrap())
+            .map_collect(|tensor, mask| match mask {
+                true => value,
+                false => *tensor,
+            });
 
-        NdArrayTensor::new(array)
+        NdArrayTensor::new(array.into_shared())
     }
 
     fn gather_batch_size<const D: usize>(
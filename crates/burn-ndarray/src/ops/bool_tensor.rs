@@ -39,6 +39,12 @@ impl<E: FloatNdArrayElement> BoolTensorOps<Self> for NdArray<E> {
         Reader::Concrete(TensorData::new(values, shape))
     }
 
+    fn bool_checksum<const D: usize>(
+        tensor: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> Reader<u64> {
+        Reader::Concrete(tensor.checksum())
+    }
+
     fn bool_to_device<const D: usize>(
         tensor: NdArrayTensor<bool, D>,
         _device: &NdArrayDevice,
@@ -60,6 +66,14 @@ impl<E: FloatNdArrayElement> BoolTensorOps<Self> for NdArray<E> {
         NdArrayOps::slice(tensor, ranges)
     }
 
+    fn bool_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<bool, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> NdArrayTensor<bool, D1> {
+        NdArrayOps::slice_with_steps(tensor, ranges, steps)
+    }
+
     fn bool_into_int<const D: usize>(
         tensor: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> NdArrayTensor<i64, D> {
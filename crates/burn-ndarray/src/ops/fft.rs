@@ -0,0 +1,53 @@
+use alloc::vec::Vec;
+use burn_tensor::{ops::fft::fft_in_place, Complex64, ComplexPrimitive, ElementConversion};
+use ndarray::{Array, IxDyn};
+
+use crate::{
+    iter_range_par, run_par, FloatNdArrayElement, NdArray, NdArrayTensor, UnsafeSharedRef,
+};
+
+/// Computes the discrete Fourier transform along the last dimension, each row being transformed
+/// in parallel with double precision values.
+pub(crate) fn fft<E: FloatNdArrayElement, const D: usize>(
+    tensor: ComplexPrimitive<NdArray<E>, D>,
+    inverse: bool,
+) -> ComplexPrimitive<NdArray<E>, D> {
+    let shape = tensor.real.array.shape().to_vec();
+    let n = shape[D - 1];
+    let num_rows = match n {
+        0 => 0,
+        _ => shape.iter().product::<usize>() / n,
+    };
+
+    let mut values: Vec<Complex64> = tensor
+        .real
+        .array
+        .iter()
+        .zip(tensor.imag.array.iter())
+        .map(|(real, imag)| Complex64::new(real.elem(), imag.elem()))
+        .collect();
+    let unsafe_shared_values = UnsafeSharedRef::new(&mut values);
+
+    run_par!(|| {
+        iter_range_par!(0, num_rows).for_each(|row| unsafe {
+            let values = unsafe_shared_values.get();
+            fft_in_place(&mut values[row * n..(row + 1) * n], inverse);
+        })
+    });
+
+    let real = values.iter().map(|value| value.re.elem()).collect();
+    let imag = values.iter().map(|value| value.im.elem()).collect();
+
+    ComplexPrimitive {
+        real: NdArrayTensor::new(
+            Array::from_shape_vec(IxDyn(&shape), real)
+                .unwrap()
+                .into_shared(),
+        ),
+        imag: NdArrayTensor::new(
+            Array::from_shape_vec(IxDyn(&shape), imag)
+                .unwrap()
+                .into_shared(),
+        ),
+    }
+}
@@ -7,7 +7,7 @@ use burn_tensor::{Distribution, Reader};
 
 use burn_tensor::ElementConversion;
 use core::ops::Range;
-use ndarray::IntoDimension;
+use ndarray::{Axis, IntoDimension};
 
 // Current crate
 use crate::element::ExpElement;
@@ -16,9 +16,13 @@ use crate::{tensor::NdArrayTensor, NdArray};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
-use burn_tensor::{backend::Backend, Shape, TensorData};
+use burn_tensor::{backend::Backend, IndexReduction, Shape, TensorData};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use burn_tensor::{ops::unique::UniqueOutput, Int};
 
-use super::{NdArrayMathOps, NdArrayOps};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use super::unique;
+use super::{segment, NdArrayMathOps, NdArrayOps};
 
 impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
     fn int_from_data<const D: usize>(
@@ -39,6 +43,10 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         Reader::Concrete(TensorData::new(values, shape))
     }
 
+    fn int_checksum<const D: usize>(tensor: NdArrayTensor<i64, D>) -> Reader<u64> {
+        Reader::Concrete(tensor.checksum())
+    }
+
     fn int_to_device<const D: usize>(
         tensor: NdArrayTensor<i64, D>,
         _device: &NdArrayDevice,
@@ -60,6 +68,14 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayOps::slice(tensor, ranges)
     }
 
+    fn int_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<i64, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> NdArrayTensor<i64, D1> {
+        NdArrayOps::slice_with_steps(tensor, ranges, steps)
+    }
+
     fn int_device<const D: usize>(
         _tensor: &NdArrayTensor<i64, D>,
     ) -> <NdArray<E> as Backend>::Device {
@@ -390,6 +406,94 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayTensor { array }
     }
 
+    fn int_bincount(tensor: NdArrayTensor<i64, 1>, num_bins: usize) -> NdArrayTensor<i64, 1> {
+        let mut counts = vec![0; num_bins];
+        for bin in bins(&tensor, num_bins).flatten() {
+            counts[bin] += 1;
+        }
+
+        NdArrayTensor::from_data(TensorData::new(counts, Shape::new([num_bins])))
+    }
+
+    fn int_bincount_weighted(
+        tensor: NdArrayTensor<i64, 1>,
+        weights: NdArrayTensor<E, 1>,
+        num_bins: usize,
+    ) -> NdArrayTensor<E, 1> {
+        let mut sums = vec![E::zero(); num_bins];
+        for (bin, weight) in bins(&tensor, num_bins).zip(weights.array.iter()) {
+            if let Some(bin) = bin {
+                sums[bin] += *weight;
+            }
+        }
+
+        NdArrayTensor::from_data(TensorData::new(sums, Shape::new([num_bins])))
+    }
+
+    fn int_segment_sum<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        segment_ids: NdArrayTensor<i64, 1>,
+        num_segments: usize,
+    ) -> NdArrayTensor<i64, D> {
+        segment::segment_sum(tensor, segment_ids, num_segments)
+    }
+
+    fn int_segment_reduce_sorted<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        segment_ids: NdArrayTensor<i64, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> NdArrayTensor<i64, D> {
+        segment::segment_reduce_sorted(tensor, segment_ids, num_segments, reduction)
+    }
+
+    fn int_mode<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        let mut shape = tensor.shape();
+        shape.dims[dim] = 1;
+
+        // The longest run of the sorted values of each lane, the first one among the runs of the
+        // same length holding the smallest value.
+        let (values, counts): (Vec<i64>, Vec<i64>) = tensor
+            .array
+            .lanes(Axis(dim))
+            .into_iter()
+            .map(|lane| {
+                let mut lane = lane.to_vec();
+                lane.sort_unstable();
+
+                let mut mode = (0, 0);
+                let mut start = 0;
+                for end in 1..=lane.len() {
+                    if end < lane.len() && lane[end] == lane[start] {
+                        continue;
+                    }
+                    let count = (end - start) as i64;
+                    if count > mode.1 {
+                        mode = (lane[start], count);
+                    }
+                    start = end;
+                }
+                mode
+            })
+            .unzip();
+
+        (
+            NdArrayTensor::from_data(TensorData::new(values, shape.clone())),
+            NdArrayTensor::from_data(TensorData::new(counts, shape)),
+        )
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_unique<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        consecutive: bool,
+    ) -> UniqueOutput<Self, Int, D> {
+        unique::unique(tensor, consecutive)
+    }
+
     fn int_swap_dims<const D: usize>(
         tensor: <NdArray<E> as Backend>::IntTensorPrimitive<D>,
         dim1: usize,
@@ -471,3 +575,14 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayOps::expand(tensor, shape)
     }
 }
+
+/// The bins of the values, which are ignored outside of `[0, num_bins)`.
+fn bins(
+    tensor: &NdArrayTensor<i64, 1>,
+    num_bins: usize,
+) -> impl Iterator<Item = Option<usize>> + '_ {
+    tensor
+        .array
+        .iter()
+        .map(move |value| usize::try_from(*value).ok().filter(|bin| *bin < num_bins))
+}
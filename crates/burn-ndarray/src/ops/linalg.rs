@@ -0,0 +1,662 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{linalg::QrMode, ElementConversion};
+use ndarray::{Array, IxDyn};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{iter_range_par, run_par, FloatNdArrayElement, NdArrayTensor};
+
+/// Maximum number of sweeps over every pair of columns of the Jacobi methods.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+const MAX_SWEEPS: usize = 30;
+
+/// Convergence tolerance of the Jacobi sweeps, the decompositions being computed with double
+/// precision values.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+const TOLERANCE: f64 = 1e-14;
+
+/// The matrices of a tensor as double precision values, in row-major order.
+struct Matrices {
+    dims: Vec<usize>,
+    num_matrices: usize,
+    rows: usize,
+    cols: usize,
+    values: Vec<f64>,
+}
+
+impl Matrices {
+    fn new<E: FloatNdArrayElement, const D: usize>(tensor: &NdArrayTensor<E, D>) -> Self {
+        let dims = tensor.array.shape().to_vec();
+        let (rows, cols) = (dims[D - 2], dims[D - 1]);
+
+        Self {
+            num_matrices: dims[..D - 2].iter().product(),
+            rows,
+            cols,
+            values: tensor.array.iter().map(|value| value.elem()).collect(),
+            dims,
+        }
+    }
+
+    fn matrix(&self, index: usize) -> &[f64] {
+        let size = self.rows * self.cols;
+        &self.values[index * size..(index + 1) * size]
+    }
+
+    /// Decompose every matrix in parallel, returning the outputs of each matrix.
+    fn map<T, F>(&self, func: F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(&[f64]) -> T + Send + Sync,
+    {
+        run_par!(|| {
+            iter_range_par!(0, self.num_matrices)
+                .map(|index| func(self.matrix(index)))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// Solve the systems of every matrix with its right-hand side in parallel, returning the
+    /// solutions of each matrix.
+    fn solve<F>(&self, rhs: &Matrices, func: F) -> Vec<Vec<f64>>
+    where
+        F: Fn(&[f64], &[f64]) -> Vec<f64> + Send + Sync,
+    {
+        run_par!(|| {
+            iter_range_par!(0, self.num_matrices)
+                .map(|index| func(self.matrix(index), rhs.matrix(index)))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// The dimensions of the tensor with matrices of the given size.
+    fn dims(&self, rows: usize, cols: usize) -> Vec<usize> {
+        let mut dims = self.dims.clone();
+        let num_dims = dims.len();
+        dims[num_dims - 2] = rows;
+        dims[num_dims - 1] = cols;
+        dims
+    }
+}
+
+/// Create a tensor from the matrices of every batch, each one in row-major order.
+fn from_matrices<E: FloatNdArrayElement, const D: usize>(
+    dims: Vec<usize>,
+    matrices: impl Iterator<Item = Vec<f64>>,
+) -> NdArrayTensor<E, D> {
+    let values = matrices.flatten().map(|value| value.elem()).collect();
+
+    NdArrayTensor::new(
+        Array::from_shape_vec(IxDyn(&dims), values)
+            .unwrap()
+            .into_shared(),
+    )
+}
+
+/// Transpose the `[rows, cols]` matrix.
+fn transpose(matrix: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+    let mut output = vec![0.0; rows * cols];
+    for i in 0..rows {
+        for j in 0..cols {
+            output[j * rows + i] = matrix[i * cols + j];
+        }
+    }
+    output
+}
+
+/// Computes the reduced singular value decomposition of a batch of matrices, each one in parallel
+/// with one-sided Jacobi rotations on double precision values.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) fn svd<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (
+    NdArrayTensor<E, D>,
+    NdArrayTensor<E, D>,
+    NdArrayTensor<E, D>,
+) {
+    let matrices = Matrices::new(&tensor);
+    let (m, n) = (matrices.rows, matrices.cols);
+    let k = usize::min(m, n);
+
+    let outputs = matrices.map(|matrix| svd_matrix(matrix, m, n));
+
+    let (mut u, mut s, mut vt) = (Vec::new(), Vec::new(), Vec::new());
+    for (u_matrix, s_matrix, vt_matrix) in outputs {
+        u.push(u_matrix);
+        s.push(s_matrix);
+        vt.push(vt_matrix);
+    }
+
+    (
+        from_matrices(matrices.dims(m, k), u.into_iter()),
+        from_matrices(matrices.dims(1, k), s.into_iter()),
+        from_matrices(matrices.dims(k, n), vt.into_iter()),
+    )
+}
+
+/// Reduced singular value decomposition of a `[m, n]` matrix, returning `U` of shape `[m, k]`, the
+/// `k` singular values in descending order and `Vt` of shape `[k, n]`, with `k = min(m, n)`.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn svd_matrix(matrix: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    match m < n {
+        true => {
+            let (u, s, vt) = svd_tall(&transpose(matrix, m, n), n, m);
+            (transpose(&vt, m, m), s, transpose(&u, n, m))
+        }
+        false => svd_tall(matrix, m, n),
+    }
+}
+
+/// Singular value decomposition of a `[m, n]` matrix with `m >= n`, returning `U` of shape
+/// `[m, n]`, the `n` singular values in descending order and `Vt` of shape `[n, n]`.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn svd_tall(matrix: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    // The columns are stored contiguously, since the rotations are applied to pairs of columns.
+    let mut a = transpose(matrix, m, n);
+    let mut v = vec![0.0; n * n];
+    for i in 0..n {
+        v[i * n + i] = 1.0;
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let mut converged = true;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let (col_p, col_q) = (&a[p * m..(p + 1) * m], &a[q * m..(q + 1) * m]);
+                let alpha: f64 = col_p.iter().map(|x| x * x).sum();
+                let beta: f64 = col_q.iter().map(|x| x * x).sum();
+                let gamma: f64 = col_p.iter().zip(col_q).map(|(x, y)| x * y).sum();
+
+                if gamma == 0.0 {
+                    continue;
+                }
+                if gamma.abs() > TOLERANCE * (alpha * beta).sqrt() {
+                    converged = false;
+                }
+
+                let (cos, sin) = rotation(alpha, beta, gamma);
+                rotate(&mut a, m, p, q, cos, sin);
+                rotate(&mut v, n, p, q, cos, sin);
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    let sigma: Vec<f64> = (0..n)
+        .map(|j| {
+            a[j * m..(j + 1) * m]
+                .iter()
+                .map(|x| x * x)
+                .sum::<f64>()
+                .sqrt()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|i, j| sigma[*j].total_cmp(&sigma[*i]));
+
+    let mut u = vec![0.0; m * n];
+    let mut vt = vec![0.0; n * n];
+    for (rank, j) in order.iter().enumerate() {
+        let scale = match sigma[*j] > 0.0 {
+            true => 1.0 / sigma[*j],
+            false => 0.0,
+        };
+        for i in 0..m {
+            u[i * n + rank] = a[j * m + i] * scale;
+        }
+        vt[rank * n..(rank + 1) * n].copy_from_slice(&v[j * n..(j + 1) * n]);
+    }
+    let s = order.iter().map(|j| sigma[*j]).collect();
+
+    (u, s, vt)
+}
+
+/// Computes the QR decomposition of a batch of matrices, each one in parallel with Householder
+/// reflections on double precision values.
+pub(crate) fn qr<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    mode: QrMode,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let matrices = Matrices::new(&tensor);
+    let (m, n) = (matrices.rows, matrices.cols);
+    let cols = match mode {
+        QrMode::Reduced => usize::min(m, n),
+        QrMode::Complete => m,
+    };
+
+    let (q, r): (Vec<_>, Vec<_>) = matrices
+        .map(|matrix| qr_matrix(matrix, m, n, cols))
+        .into_iter()
+        .unzip();
+
+    (
+        from_matrices(matrices.dims(m, cols), q.into_iter()),
+        from_matrices(matrices.dims(cols, n), r.into_iter()),
+    )
+}
+
+/// QR decomposition of a `[m, n]` matrix, returning the first `cols` columns of `Q` and rows of
+/// `R`.
+fn qr_matrix(matrix: &[f64], m: usize, n: usize, cols: usize) -> (Vec<f64>, Vec<f64>) {
+    let k = usize::min(m, n);
+    let mut r = matrix.to_vec();
+    let mut q = vec![0.0; m * m];
+    for i in 0..m {
+        q[i * m + i] = 1.0;
+    }
+
+    for j in 0..k {
+        // Reflect the column onto the first axis, away from its first element to avoid
+        // cancellation.
+        let mut w: Vec<f64> = (j..m).map(|i| r[i * n + j]).collect();
+        let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let alpha = match w[0] >= 0.0 {
+            true => -norm,
+            false => norm,
+        };
+        w[0] -= alpha;
+        let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        w.iter_mut().for_each(|x| *x /= norm);
+
+        // H = I - 2 w w^T, applied to the rows of R and the columns of Q.
+        for c in 0..n {
+            let dot: f64 = (j..m).map(|i| w[i - j] * r[i * n + c]).sum();
+            (j..m).for_each(|i| r[i * n + c] -= 2.0 * dot * w[i - j]);
+        }
+        for row in 0..m {
+            let dot: f64 = (j..m).map(|i| q[row * m + i] * w[i - j]).sum();
+            (j..m).for_each(|i| q[row * m + i] -= 2.0 * dot * w[i - j]);
+        }
+    }
+
+    // Flip the signs so that the diagonal of R is non-negative.
+    for i in 0..k {
+        if r[i * n + i] < 0.0 {
+            (0..m).for_each(|row| q[row * m + i] = -q[row * m + i]);
+            (0..n).for_each(|c| r[i * n + c] = -r[i * n + c]);
+        }
+    }
+
+    let q = (0..m * cols).map(|index| q[(index / cols) * m + index % cols]);
+    let r = (0..cols * n).map(|index| match index % n >= index / n {
+        true => r[index],
+        false => 0.0,
+    });
+
+    (q.collect(), r.collect())
+}
+
+/// Computes the Cholesky decomposition of a batch of symmetric positive-definite matrices, each one
+/// in parallel on double precision values.
+pub(crate) fn cholesky<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> NdArrayTensor<E, D> {
+    let matrices = Matrices::new(&tensor);
+    let n = matrices.cols;
+
+    let lower = matrices.map(|matrix| {
+        let mut lower = vec![0.0; n * n];
+        for j in 0..n {
+            let dot = |i: usize| {
+                (0..j)
+                    .map(|p| lower[i * n + p] * lower[j * n + p])
+                    .sum::<f64>()
+            };
+            let pivot = (matrix[j * n + j] - dot(j)).sqrt();
+            let column = (j + 1..n)
+                .map(|i| (matrix[i * n + j] - dot(i)) / pivot)
+                .collect::<Vec<_>>();
+
+            lower[j * n + j] = pivot;
+            for (i, value) in (j + 1..n).zip(column) {
+                lower[i * n + j] = value;
+            }
+        }
+        lower
+    });
+
+    from_matrices(matrices.dims(n, n), lower.into_iter())
+}
+
+/// Computes the eigendecomposition of a batch of symmetric matrices, each one in parallel with
+/// two-sided Jacobi rotations on double precision values, reading only their lower triangular
+/// part.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) fn eigh<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let matrices = Matrices::new(&tensor);
+    let n = matrices.cols;
+
+    let (eigenvalues, vectors): (Vec<_>, Vec<_>) = matrices
+        .map(|matrix| eigh_matrix(matrix, n))
+        .into_iter()
+        .unzip();
+
+    (
+        from_matrices(matrices.dims(1, n), eigenvalues.into_iter()),
+        from_matrices(matrices.dims(n, n), vectors.into_iter()),
+    )
+}
+
+/// Eigendecomposition of a `[n, n]` symmetric matrix, returning the eigenvalues in ascending
+/// order and the eigenvectors as columns.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn eigh_matrix(matrix: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut a = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..=i {
+            a[i * n + j] = matrix[i * n + j];
+            a[j * n + i] = matrix[i * n + j];
+        }
+    }
+    // The rotations are invariant to the Frobenius norm, used as the scale of the tolerance.
+    let norm = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    // The columns of V are stored contiguously.
+    let mut v = vec![0.0; n * n];
+    for i in 0..n {
+        v[i * n + i] = 1.0;
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let mut converged = true;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let gamma = a[p * n + q];
+                if gamma == 0.0 {
+                    continue;
+                }
+                if gamma.abs() > TOLERANCE * norm {
+                    converged = false;
+                }
+
+                // A is rotated on both sides, its rows being its contiguous columns since it is
+                // symmetric.
+                let (cos, sin) = rotation(a[p * n + p], a[q * n + q], gamma);
+                rotate(&mut a, n, p, q, cos, sin);
+                for i in 0..n {
+                    let x = a[i * n + p];
+                    let y = a[i * n + q];
+                    a[i * n + p] = x * cos - y * sin;
+                    a[i * n + q] = x * sin + y * cos;
+                }
+                rotate(&mut v, n, p, q, cos, sin);
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|i, j| a[*i * n + *i].total_cmp(&a[*j * n + *j]));
+
+    let mut vectors = vec![0.0; n * n];
+    for (rank, j) in order.iter().enumerate() {
+        for i in 0..n {
+            vectors[i * n + rank] = v[j * n + i];
+        }
+    }
+    let eigenvalues = order.iter().map(|j| a[j * n + j]).collect();
+
+    (eigenvalues, vectors)
+}
+
+/// Solves the linear systems of a batch of square matrices, each one in parallel with Gaussian
+/// elimination and partial pivoting on double precision values.
+pub(crate) fn solve<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    rhs: NdArrayTensor<E, D>,
+) -> NdArrayTensor<E, D> {
+    let matrices = Matrices::new(&tensor);
+    let rhs = Matrices::new(&rhs);
+    let (n, k) = (matrices.cols, rhs.cols);
+
+    let solutions = matrices.solve(&rhs, |matrix, rhs| {
+        let mut a = matrix.to_vec();
+        let mut b = rhs.to_vec();
+        eliminate(&mut a, &mut b, n, k);
+
+        substitute(&a, b, n, k, true)
+    });
+
+    from_matrices(matrices.dims(n, k), solutions.into_iter())
+}
+
+/// Computes the determinant of a batch of square matrices, each one in parallel from the Gaussian
+/// elimination with partial pivoting on double precision values.
+pub(crate) fn det<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> NdArrayTensor<E, D> {
+    let matrices = Matrices::new(&tensor);
+    let n = matrices.cols;
+
+    let det = matrices.map(|matrix| {
+        let mut a = matrix.to_vec();
+        let sign = eliminate(&mut a, &mut [], n, 0);
+
+        vec![(0..n).fold(sign, |det, i| det * a[i * n + i])]
+    });
+
+    from_matrices(matrices.dims(1, 1), det.into_iter())
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of a batch
+/// of square matrices, each one in parallel from the Gaussian elimination with partial pivoting
+/// on double precision values.
+pub(crate) fn slogdet<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let matrices = Matrices::new(&tensor);
+    let n = matrices.cols;
+
+    let (sign, logabsdet): (Vec<_>, Vec<_>) = matrices
+        .map(|matrix| {
+            let mut a = matrix.to_vec();
+            let sign = eliminate(&mut a, &mut [], n, 0);
+            let diagonal = (0..n).map(|i| a[i * n + i]);
+
+            // The sign of zero is zero, for singular matrices.
+            let sign = diagonal
+                .clone()
+                .fold(sign, |sign, value| match value == 0.0 {
+                    true => 0.0,
+                    false => sign * value.signum(),
+                });
+            let logabsdet = diagonal.map(|value| value.abs().ln()).sum::<f64>();
+
+            (vec![sign], vec![logabsdet])
+        })
+        .into_iter()
+        .unzip();
+
+    (
+        from_matrices(matrices.dims(1, 1), sign.into_iter()),
+        from_matrices(matrices.dims(1, 1), logabsdet.into_iter()),
+    )
+}
+
+/// Computes the Moore-Penrose pseudo-inverse of a batch of matrices, each one in parallel from its
+/// singular value decomposition on double precision values.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) fn pinv<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    rtol: f64,
+) -> NdArrayTensor<E, D> {
+    let matrices = Matrices::new(&tensor);
+    let (m, n) = (matrices.rows, matrices.cols);
+    let k = usize::min(m, n);
+
+    let pinv = matrices.map(|matrix| {
+        let (u, s, vt) = svd_matrix(matrix, m, n);
+        let cutoff = s.first().copied().unwrap_or(0.0) * rtol;
+        let s_inv: Vec<f64> = s
+            .iter()
+            .map(|value| match *value > cutoff {
+                true => 1.0 / value,
+                false => 0.0,
+            })
+            .collect();
+
+        // V S^-1 U^T
+        let mut pinv = vec![0.0; n * m];
+        for i in 0..n {
+            for j in 0..m {
+                pinv[i * m + j] = (0..k)
+                    .map(|p| vt[p * n + i] * s_inv[p] * u[j * k + p])
+                    .sum();
+            }
+        }
+        pinv
+    });
+
+    from_matrices(matrices.dims(n, m), pinv.into_iter())
+}
+
+/// Computes the least squares solutions of the linear systems of a batch of matrices, each one in
+/// parallel from the QR decomposition of the matrix, or of its transpose for underdetermined
+/// systems, on double precision values.
+pub(crate) fn lstsq<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    rhs: NdArrayTensor<E, D>,
+) -> NdArrayTensor<E, D> {
+    let matrices = Matrices::new(&tensor);
+    let rhs = Matrices::new(&rhs);
+    let (m, n, k) = (matrices.rows, matrices.cols, rhs.cols);
+
+    let solutions = matrices.solve(&rhs, |matrix, rhs| match m >= n {
+        // A = Q R, X = R^-1 Q^T B
+        true => {
+            let (q, r) = qr_matrix(matrix, m, n, n);
+            let mut y = vec![0.0; n * k];
+            for i in 0..n {
+                for c in 0..k {
+                    y[i * k + c] = (0..m).map(|p| q[p * n + i] * rhs[p * k + c]).sum();
+                }
+            }
+            substitute(&r, y, n, k, true)
+        }
+        // A^T = Q R, X = Q R^-T B
+        false => {
+            let (q, r) = qr_matrix(&transpose(matrix, m, n), n, m, m);
+            let y = substitute(&transpose(&r, m, m), rhs.to_vec(), m, k, false);
+            let mut x = vec![0.0; n * k];
+            for i in 0..n {
+                for c in 0..k {
+                    x[i * k + c] = (0..m).map(|p| q[i * m + p] * y[p * k + c]).sum();
+                }
+            }
+            x
+        }
+    });
+
+    from_matrices(matrices.dims(n, k), solutions.into_iter())
+}
+
+/// Gaussian elimination with partial pivoting of the `[n, n]` matrix in place, applying the row
+/// operations to the `[n, k]` right-hand side, and returning the sign of the permutation.
+///
+/// Columns without a non-zero pivot are skipped, leaving a zero on the diagonal.
+fn eliminate(a: &mut [f64], b: &mut [f64], n: usize, k: usize) -> f64 {
+    let mut sign = 1.0;
+
+    for j in 0..n {
+        // The row with the largest pivot is swapped with the current one.
+        let pivot = (j..n)
+            .max_by(|x, y| a[x * n + j].abs().total_cmp(&a[y * n + j].abs()))
+            .unwrap();
+        if a[pivot * n + j] == 0.0 {
+            continue;
+        }
+        if pivot != j {
+            (0..n).for_each(|c| a.swap(j * n + c, pivot * n + c));
+            (0..k).for_each(|c| b.swap(j * k + c, pivot * k + c));
+            sign = -sign;
+        }
+
+        for i in j + 1..n {
+            let factor = a[i * n + j] / a[j * n + j];
+            (j..n).for_each(|c| a[i * n + c] -= factor * a[j * n + c]);
+            (0..k).for_each(|c| b[i * k + c] -= factor * b[j * k + c]);
+        }
+    }
+
+    sign
+}
+
+/// Solves the linear systems of a batch of triangular matrices, each one in parallel with
+/// substitution on double precision values.
+pub(crate) fn triangular_solve<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    rhs: NdArrayTensor<E, D>,
+    upper: bool,
+) -> NdArrayTensor<E, D> {
+    let matrices = Matrices::new(&tensor);
+    let rhs = Matrices::new(&rhs);
+    let (n, k) = (matrices.cols, rhs.cols);
+
+    let solutions = matrices.solve(&rhs, |matrix, rhs| {
+        substitute(matrix, rhs.to_vec(), n, k, upper)
+    });
+
+    from_matrices(matrices.dims(n, k), solutions.into_iter())
+}
+
+/// Solve `A X = B` in place of the `[n, k]` right-hand side, where `A` is a `[n, n]` upper or
+/// lower triangular matrix of which only the triangular part is read.
+fn substitute(matrix: &[f64], mut rhs: Vec<f64>, n: usize, k: usize, upper: bool) -> Vec<f64> {
+    for step in 0..n {
+        let i = match upper {
+            true => n - 1 - step,
+            false => step,
+        };
+        let solved = match upper {
+            true => i + 1..n,
+            false => 0..i,
+        };
+
+        for c in 0..k {
+            let dot: f64 = solved
+                .clone()
+                .map(|j| matrix[i * n + j] * rhs[j * k + c])
+                .sum();
+            rhs[i * k + c] = (rhs[i * k + c] - dot) / matrix[i * n + i];
+        }
+    }
+
+    rhs
+}
+
+/// Computes the cosine and sine of the rotation zeroing `gamma` in the symmetric `2x2` matrix
+/// `[[alpha, gamma], [gamma, beta]]`, using the smallest angle.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn rotation(alpha: f64, beta: f64, gamma: f64) -> (f64, f64) {
+    let zeta = (beta - alpha) / (2.0 * gamma);
+    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+    let cos = 1.0 / (1.0 + t * t).sqrt();
+
+    (cos, cos * t)
+}
+
+/// Rotate the columns `p` and `q` of size `size`, stored contiguously.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn rotate(columns: &mut [f64], size: usize, p: usize, q: usize, cos: f64, sin: f64) {
+    for i in 0..size {
+        let x = columns[p * size + i];
+        let y = columns[q * size + i];
+        columns[p * size + i] = x * cos - y * sin;
+        columns[q * size + i] = x * sin + y * cos;
+    }
+}
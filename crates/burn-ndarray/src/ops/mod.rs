@@ -8,10 +8,16 @@ mod tensor;
 pub(crate) mod adaptive_avgpool;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+pub(crate) mod fft;
 pub(crate) mod interpolate;
+pub(crate) mod linalg;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
+pub(crate) mod multinomial;
 pub(crate) mod padding;
+pub(crate) mod segment;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) mod unique;
 
 pub(crate) use base::*;
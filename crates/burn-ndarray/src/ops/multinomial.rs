@@ -0,0 +1,105 @@
+use alloc::vec::Vec;
+use burn_tensor::TensorData;
+use ndarray::Axis;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{element::NdArrayElement, NdArrayTensor};
+
+/// Samples category indices from the unnormalized weights along the last dimension, each sample
+/// being the first category whose cumulative weight is greater than a uniform value between zero
+/// and the sum of the weights.
+///
+/// Without replacement, the weight of each sampled category is set to zero before drawing the
+/// next sample.
+pub(crate) fn multinomial<E: NdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    num_samples: usize,
+    replacement: bool,
+    rng: &mut StdRng,
+) -> NdArrayTensor<i64, D> {
+    let mut shape = tensor.shape();
+    let num_categories = shape.dims[D - 1];
+    let weights: Vec<f64> = tensor.array.iter().map(|weight| weight.elem()).collect();
+    let mut samples = Vec::with_capacity(weights.len() / num_categories.max(1) * num_samples);
+
+    for row in weights.chunks(num_categories.max(1)) {
+        let mut row = row.to_vec();
+        for _ in 0..num_samples {
+            let category = sample_category(&row, rng);
+            if !replacement {
+                row[category] = 0.0;
+            }
+            samples.push(category as i64);
+        }
+    }
+
+    shape.dims[D - 1] = num_samples;
+    NdArrayTensor::from_data(TensorData::new(samples, shape))
+}
+
+/// Rejects the weights that aren't a distribution, as well as the ones with fewer categories
+/// with a positive weight than the number of samples to draw without replacement, which would
+/// otherwise sample categories without weight.
+pub(crate) fn check_weights<E: NdArrayElement, const D: usize>(
+    tensor: &NdArrayTensor<E, D>,
+    num_samples: usize,
+    replacement: bool,
+) {
+    if num_samples == 0 {
+        return;
+    }
+
+    for row in tensor.array.lanes(Axis(D - 1)) {
+        check_row(
+            row.iter().map(|weight| weight.elem()),
+            num_samples,
+            replacement,
+        );
+    }
+}
+
+fn check_row(weights: impl Iterator<Item = f64>, num_samples: usize, replacement: bool) {
+    let mut num_positive = 0;
+    for weight in weights {
+        assert!(
+            weight.is_finite() && weight >= 0.0,
+            "The multinomial weights must be finite and non-negative."
+        );
+        if weight > 0.0 {
+            num_positive += 1;
+        }
+    }
+
+    assert!(
+        num_positive > 0,
+        "The multinomial weights of a row must have a positive sum."
+    );
+    assert!(
+        replacement || num_samples <= num_positive,
+        "Can't sample {num_samples} indices without replacement from {num_positive} categories \
+         with a positive weight."
+    );
+}
+
+fn sample_category(weights: &[f64], rng: &mut StdRng) -> usize {
+    let total: f64 = weights.iter().sum();
+    let value = rng.gen::<f64>() * total;
+
+    // The last category with a weight is sampled when the rounding of the sums leaves the value
+    // past the cumulative weights.
+    let mut cumulative = 0.0;
+    let mut last = weights.len() - 1;
+    for (category, weight) in weights.iter().enumerate() {
+        if *weight <= 0.0 {
+            continue;
+        }
+
+        cumulative += weight;
+        last = category;
+        if cumulative > value {
+            return category;
+        }
+    }
+
+    last
+}
@@ -0,0 +1,104 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{IndexReduction, Shape, TensorData};
+
+use crate::{element::NdArrayElement, NdArrayTensor};
+
+/// Sums the slices of the first dimension belonging to the same segment, the segment ids out of
+/// range being ignored.
+pub(crate) fn segment_sum<E: NdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    segment_ids: NdArrayTensor<i64, 1>,
+    num_segments: usize,
+) -> NdArrayTensor<E, D> {
+    let (slices, size) = slices(&tensor);
+    let mut output = vec![E::zero(); num_segments * size];
+
+    for (slice, segment) in slices
+        .chunks(size.max(1))
+        .zip(segments(&segment_ids, num_segments))
+    {
+        if let Some(segment) = segment {
+            let sums = &mut output[segment * size..(segment + 1) * size];
+            for (sum, value) in sums.iter_mut().zip(slice) {
+                *sum += *value;
+            }
+        }
+    }
+
+    from_slices(output, tensor.shape(), num_segments)
+}
+
+/// Reduces the slices of the first dimension belonging to the same segment, in the order of the
+/// slices, the segment ids being sorted.
+pub(crate) fn segment_reduce_sorted<E: NdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    segment_ids: NdArrayTensor<i64, 1>,
+    num_segments: usize,
+    reduction: IndexReduction,
+) -> NdArrayTensor<E, D> {
+    let (slices, size) = slices(&tensor);
+    let mut output = vec![E::zero(); num_segments * size];
+    let mut counts = vec![0; num_segments];
+
+    for (slice, segment) in slices
+        .chunks(size.max(1))
+        .zip(segments(&segment_ids, num_segments))
+    {
+        let Some(segment) = segment else {
+            continue;
+        };
+
+        let accumulated = &mut output[segment * size..(segment + 1) * size];
+        for (accumulated, value) in accumulated.iter_mut().zip(slice) {
+            *accumulated = match reduction {
+                _ if counts[segment] == 0 => *value,
+                IndexReduction::Sum | IndexReduction::Mean => *accumulated + *value,
+                IndexReduction::Prod => *accumulated * *value,
+                IndexReduction::Amax if *value > *accumulated => *value,
+                IndexReduction::Amin if *value < *accumulated => *value,
+                IndexReduction::Amax | IndexReduction::Amin => *accumulated,
+            };
+        }
+        counts[segment] += 1;
+    }
+
+    if reduction == IndexReduction::Mean {
+        for (means, count) in output.chunks_mut(size.max(1)).zip(counts) {
+            let count = E::from_usize(count.max(1)).unwrap();
+            means.iter_mut().for_each(|mean| *mean = *mean / count);
+        }
+    }
+
+    from_slices(output, tensor.shape(), num_segments)
+}
+
+/// The elements of the tensor in logical order, with the number of elements of each slice of the
+/// first dimension.
+fn slices<E: NdArrayElement, const D: usize>(tensor: &NdArrayTensor<E, D>) -> (Vec<E>, usize) {
+    let shape = tensor.shape();
+    let size = shape.dims[1..].iter().product();
+
+    (tensor.array.iter().copied().collect(), size)
+}
+
+fn segments(
+    segment_ids: &NdArrayTensor<i64, 1>,
+    num_segments: usize,
+) -> impl Iterator<Item = Option<usize>> + '_ {
+    segment_ids.array.iter().map(move |segment| {
+        usize::try_from(*segment)
+            .ok()
+            .filter(|segment| *segment < num_segments)
+    })
+}
+
+fn from_slices<E: NdArrayElement, const D: usize>(
+    output: Vec<E>,
+    mut shape: Shape<D>,
+    num_segments: usize,
+) -> NdArrayTensor<E, D> {
+    shape.dims[0] = num_segments;
+
+    NdArrayTensor::from_data(TensorData::new(output, shape))
+}
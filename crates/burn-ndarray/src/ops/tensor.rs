@@ -4,21 +4,29 @@ use core::ops::Range;
 use ndarray::IntoDimension;
 
 // Current crate
-use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use super::unique;
+use super::{fft::fft, linalg, matmul::matmul, multinomial, segment, NdArrayMathOps, NdArrayOps};
 use crate::element::FloatNdArrayElement;
 use crate::{tensor::NdArrayTensor, NdArray};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
 use burn_common::rand::get_seeded_rng;
-use burn_tensor::{backend::Backend, ops::FloatTensorOps, ElementConversion, Shape, TensorData};
-use burn_tensor::{Distribution, Reader};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use burn_tensor::ops::unique::UniqueOutput;
+use burn_tensor::{
+    backend::Backend,
+    ops::{FloatTensorOps, IntTensor},
+    ElementConversion, Shape, TensorData,
+};
+use burn_tensor::{linalg::QrMode, ComplexPrimitive, Distribution, IndexReduction, Reader};
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::Float;
 
-use libm::erf;
+use libm::{erf, lgamma};
 
 impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
     fn float_from_data<const D: usize>(
@@ -47,6 +55,24 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         tensor
     }
 
+    fn float_multinomial<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<Self, D> {
+        multinomial::check_weights(&tensor, num_samples, replacement);
+
+        let mut seed = SEED.lock().unwrap();
+        let mut rng = if let Some(rng_seeded) = seed.as_ref() {
+            rng_seeded.clone()
+        } else {
+            get_seeded_rng()
+        };
+        let samples = multinomial::multinomial(tensor, num_samples, replacement, &mut rng);
+        *seed = Some(rng);
+        samples
+    }
+
     fn float_shape<const D: usize>(tensor: &NdArrayTensor<E, D>) -> Shape<D> {
         tensor.shape()
     }
@@ -58,6 +84,10 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         Reader::Concrete(TensorData::new(values, shape))
     }
 
+    fn float_checksum<const D: usize>(tensor: NdArrayTensor<E, D>) -> Reader<u64> {
+        Reader::Concrete(tensor.checksum())
+    }
+
     fn float_device<const D: usize>(_tensor: &NdArrayTensor<E, D>) -> NdArrayDevice {
         NdArrayDevice::Cpu
     }
@@ -198,6 +228,14 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayOps::slice(tensor, ranges)
     }
 
+    fn float_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> NdArrayTensor<E, D1> {
+        NdArrayOps::slice_with_steps(tensor, ranges, steps)
+    }
+
     fn float_slice_assign<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D1>,
         ranges: [Range<usize>; D2],
@@ -440,6 +478,113 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayTensor::new(array)
     }
 
+    fn float_lgamma<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| lgamma(a.to_f64()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_fft<const D: usize>(
+        tensor: ComplexPrimitive<Self, D>,
+        inverse: bool,
+    ) -> ComplexPrimitive<Self, D> {
+        fft(tensor, inverse)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_svd<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+    ) {
+        linalg::svd(tensor)
+    }
+
+    fn float_qr<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        mode: QrMode,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        linalg::qr(tensor, mode)
+    }
+
+    fn float_cholesky<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        linalg::cholesky(tensor)
+    }
+
+    fn float_solve<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        linalg::solve(tensor, rhs)
+    }
+
+    fn float_triangular_solve<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+        upper: bool,
+    ) -> NdArrayTensor<E, D> {
+        linalg::triangular_solve(tensor, rhs, upper)
+    }
+
+    fn float_det<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        linalg::det(tensor)
+    }
+
+    fn float_slogdet<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        linalg::slogdet(tensor)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_pinv<const D: usize>(tensor: NdArrayTensor<E, D>, rtol: f64) -> NdArrayTensor<E, D> {
+        linalg::pinv(tensor, rtol)
+    }
+
+    fn float_lstsq<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        linalg::lstsq(tensor, rhs)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_eigh<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        linalg::eigh(tensor)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_unique<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        consecutive: bool,
+    ) -> UniqueOutput<Self, burn_tensor::Float, D> {
+        unique::unique(tensor, consecutive)
+    }
+
+    fn float_segment_sum<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        segment_ids: NdArrayTensor<i64, 1>,
+        num_segments: usize,
+    ) -> NdArrayTensor<E, D> {
+        segment::segment_sum(tensor, segment_ids, num_segments)
+    }
+
+    fn float_segment_reduce_sorted<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        segment_ids: NdArrayTensor<i64, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> NdArrayTensor<E, D> {
+        segment::segment_reduce_sorted(tensor, segment_ids, num_segments, reduction)
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<NdArrayTensor<E, D>>,
         dim: usize,
@@ -0,0 +1,50 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{Shape, TensorData};
+use core::cmp::Ordering;
+
+use crate::{element::NdArrayElement, NdArrayTensor};
+
+/// Collapses the runs of equal elements of the flattened tensor, stably sorting the elements first
+/// unless only the consecutive runs are collapsed.
+pub(crate) fn unique<E: NdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    consecutive: bool,
+) -> (
+    NdArrayTensor<E, 1>,
+    NdArrayTensor<i64, D>,
+    NdArrayTensor<i64, 1>,
+) {
+    let shape = tensor.shape();
+    let values = tensor.array.iter().copied().collect::<Vec<_>>();
+
+    let mut order = (0..values.len()).collect::<Vec<_>>();
+    if !consecutive {
+        order.sort_by(|a, b| {
+            values[*a]
+                .partial_cmp(&values[*b])
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+
+    let mut unique = Vec::new();
+    let mut counts = Vec::new();
+    let mut inverse = vec![0; values.len()];
+    for index in order {
+        let value = values[index];
+        if unique.last() != Some(&value) {
+            unique.push(value);
+            counts.push(0);
+        }
+
+        counts[unique.len() - 1] += 1;
+        inverse[index] = unique.len() as i64 - 1;
+    }
+
+    let num_runs = unique.len();
+    (
+        NdArrayTensor::from_data(TensorData::new(unique, Shape::new([num_runs]))),
+        NdArrayTensor::from_data(TensorData::new(inverse, shape)),
+        NdArrayTensor::from_data(TensorData::new(counts, Shape::new([num_runs]))),
+    )
+}
@@ -1,4 +1,4 @@
-use burn_tensor::{Element, Shape, TensorData};
+use burn_tensor::{Checksum, Element, Shape, TensorData};
 
 use ndarray::{ArcArray, Array, Dim, IxDyn};
 
@@ -109,6 +109,16 @@ where
             d D
         )
     }
+
+    /// Compute the [checksum](TensorData::checksum) of the tensor without copying its data.
+    pub(crate) fn checksum(&self) -> u64 {
+        let mut checksum = Checksum::default();
+        for (index, elem) in self.array.iter().enumerate() {
+            checksum.update_elem(index, elem);
+        }
+
+        checksum.finalize(E::dtype(), self.array.shape())
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,5 @@
-use burn_tensor::Shape;
-use tch::Scalar;
+use burn_tensor::{Checksum, DType, IndexReduction, Shape};
+use tch::{Kind, Scalar};
 
 use crate::{LibTorchDevice, TchShape, TchTensor};
 use std::{marker::PhantomData, ops::Range};
@@ -24,6 +24,62 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         TchTensor::new(tensor.tensor.to(device))
     }
 
+    /// Computes the [checksum](burn_tensor::TensorData::checksum) of the tensor on its device,
+    /// reading back only the lanes of the checksum.
+    ///
+    /// The bits of the values are extended with zeros to 64-bit integers, whose low 32 bits
+    /// wrap around like unsigned 32-bit integers.
+    pub fn checksum<const D: usize>(tensor: TchTensor<E, D>, dtype: DType) -> u64 {
+        let shape = tensor.shape();
+        let size = E::KIND.elt_size_in_bytes();
+        let kind = match size {
+            1 => Kind::Uint8,
+            2 => Kind::Int16,
+            4 => Kind::Int,
+            _ => Kind::Int64,
+        };
+        let mut bits = tensor
+            .tensor
+            .view_dtype(kind)
+            .reshape([-1])
+            .to_kind(Kind::Int64);
+        if size < 8 {
+            bits = bits.bitwise_and((1i64 << (8 * size)) - 1);
+        }
+        let low = bits.bitwise_and(0xFFFF_FFFFi64);
+        let high = bits
+            .bitwise_right_shift_tensor_scalar(32)
+            .bitwise_and(0xFFFF_FFFFi64);
+        let index = tch::Tensor::arange(low.numel() as i64, (Kind::Int64, low.device()))
+            .bitwise_and(0xFFFF_FFFFi64);
+
+        let mut lanes = [0; 2];
+        for (lane, seed) in lanes.iter_mut().zip(Checksum::SEEDS) {
+            let seed = seed as i64;
+            let hash = Self::mix(
+                &Self::mix(&index.bitwise_xor(seed))
+                    .bitwise_xor_tensor(&low)
+                    .bitwise_xor_tensor(&Self::mix(&high.bitwise_xor(seed))),
+            );
+            *lane = hash.sum(Kind::Int64).int64_value(&[]) as u32;
+        }
+
+        Checksum { lanes }.finalize(dtype, &shape.dims)
+    }
+
+    /// The 32-bit finalizer of MurmurHash3 on 64-bit integers, matching [Checksum::mix].
+    fn mix(hash: &tch::Tensor) -> tch::Tensor {
+        let hash = hash.bitwise_xor_tensor(&hash.bitwise_right_shift_tensor_scalar(16));
+        let hash = hash
+            .g_mul_scalar(0x85EB_CA6Bi64)
+            .bitwise_and(0xFFFF_FFFFi64);
+        let hash = hash.bitwise_xor_tensor(&hash.bitwise_right_shift_tensor_scalar(13));
+        let hash = hash
+            .g_mul_scalar(0xC2B2_AE35i64)
+            .bitwise_and(0xFFFF_FFFFi64);
+        hash.bitwise_xor_tensor(&hash.bitwise_right_shift_tensor_scalar(16))
+    }
+
     pub fn reshape<const D1: usize, const D2: usize>(
         tensor: TchTensor<E, D1>,
         shape: Shape<D2>,
@@ -355,6 +411,24 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         TchTensor::from_existing(tensor, storage)
     }
 
+    pub fn multinomial<const D: usize>(
+        tensor: TchTensor<E, D>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> TchTensor<i64, D> {
+        let mut shape = tensor.tensor.size();
+        let num_categories = shape[D - 1];
+
+        // Torch only samples the rows of vectors and matrices of weights.
+        let samples = tensor
+            .tensor
+            .reshape([-1, num_categories])
+            .multinomial(num_samples as i64, replacement);
+        shape[D - 1] = num_samples as i64;
+
+        TchTensor::new(samples.reshape(shape))
+    }
+
     pub fn max_dim<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
         let storage = tensor.storage.clone();
         let (tensor, _indices) = tensor.tensor.max_dim(dim as i64, true);
@@ -512,4 +586,55 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
     ) -> TchTensor<i64, D> {
         TchTensor::new(tensor.tensor.argsort(dim as i64, descending))
     }
+
+    pub fn unique<const D: usize>(
+        tensor: TchTensor<E, D>,
+        consecutive: bool,
+    ) -> (TchTensor<E, 1>, TchTensor<i64, D>, TchTensor<i64, 1>) {
+        let (values, inverse, counts) = match consecutive {
+            true => tensor.tensor.unique_consecutive(true, true, None::<i64>),
+            false => tensor.tensor.internal_unique2(true, true, true),
+        };
+
+        (
+            TchTensor::new(values),
+            TchTensor::new(inverse),
+            TchTensor::new(counts),
+        )
+    }
+    pub fn segment_sum<const D: usize>(
+        tensor: TchTensor<E, D>,
+        segment_ids: TchTensor<i64, 1>,
+        num_segments: usize,
+    ) -> TchTensor<E, D> {
+        let output = Self::segments(&tensor.tensor, num_segments);
+
+        TchTensor::new(output.index_add(0, &segment_ids.tensor, &tensor.tensor))
+    }
+
+    pub fn segment_reduce_sorted<const D: usize>(
+        tensor: TchTensor<E, D>,
+        segment_ids: TchTensor<i64, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> TchTensor<E, D> {
+        let reduce = match reduction {
+            IndexReduction::Sum => return Self::segment_sum(tensor, segment_ids, num_segments),
+            IndexReduction::Prod => "prod",
+            IndexReduction::Mean => "mean",
+            IndexReduction::Amax => "amax",
+            IndexReduction::Amin => "amin",
+        };
+        let output = Self::segments(&tensor.tensor, num_segments);
+
+        // Without the zeros of the output, only the empty segments keep them.
+        TchTensor::new(output.index_reduce(0, &segment_ids.tensor, &tensor.tensor, reduce, false))
+    }
+
+    fn segments(tensor: &tch::Tensor, num_segments: usize) -> tch::Tensor {
+        let mut shape = tensor.size();
+        shape[0] = num_segments as i64;
+
+        tch::Tensor::zeros(shape.as_slice(), (tensor.kind(), tensor.device()))
+    }
 }
@@ -1,6 +1,6 @@
 use super::TchOps;
 use crate::{element::TchElement, LibTorch, LibTorchDevice, TchTensor};
-use burn_tensor::{backend::Backend, ops::BoolTensorOps, Reader, Shape, TensorData};
+use burn_tensor::{backend::Backend, ops::BoolTensorOps, DType, Reader, Shape, TensorData};
 use std::ops::Range;
 
 impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
@@ -31,6 +31,10 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
         Reader::Concrete(TensorData::new(values.unwrap(), shape))
     }
 
+    fn bool_checksum<const D: usize>(tensor: TchTensor<bool, D>) -> Reader<u64> {
+        Reader::Concrete(TchOps::checksum(tensor, DType::Bool))
+    }
+
     fn bool_to_device<const D: usize>(
         tensor: TchTensor<bool, D>,
         device: &LibTorchDevice,
@@ -1,6 +1,10 @@
 use std::ops::Range;
 
-use burn_tensor::{backend::Backend, ops::IntTensorOps, Distribution, Reader, Shape, TensorData};
+use burn_tensor::{
+    backend::Backend,
+    ops::{random::random_from_standard, unique::UniqueOutput, FloatTensorOps, IntTensorOps},
+    DType, Distribution, IndexReduction, Int, Reader, Shape, TensorData,
+};
 
 use crate::{element::TchElement, LibTorch, LibTorchDevice, TchShape, TchTensor};
 
@@ -34,6 +38,10 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         Reader::Concrete(TensorData::new(values.unwrap(), shape))
     }
 
+    fn int_checksum<const D: usize>(tensor: TchTensor<i64, D>) -> Reader<u64> {
+        Reader::Concrete(TchOps::checksum(tensor, DType::I64))
+    }
+
     fn int_to_device<const D: usize>(
         tensor: TchTensor<i64, D>,
         device: &LibTorchDevice,
@@ -409,6 +417,54 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchTensor::new(tensor)
     }
 
+    fn int_bincount(tensor: TchTensor<i64, 1>, num_bins: usize) -> TchTensor<i64, 1> {
+        let (tensor, _) = in_bins(tensor.tensor, None, num_bins);
+
+        TchTensor::new(tensor.bincount::<tch::Tensor>(None, num_bins as i64))
+    }
+
+    fn int_bincount_weighted(
+        tensor: TchTensor<i64, 1>,
+        weights: TchTensor<E, 1>,
+        num_bins: usize,
+    ) -> TchTensor<E, 1> {
+        let (tensor, weights) = in_bins(tensor.tensor, Some(weights.tensor), num_bins);
+        let sums = tensor.bincount(weights, num_bins as i64);
+
+        TchTensor::new(sums.to_kind(E::KIND))
+    }
+
+    fn int_segment_sum<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        segment_ids: TchTensor<i64, 1>,
+        num_segments: usize,
+    ) -> TchTensor<i64, D> {
+        TchOps::segment_sum(tensor, segment_ids, num_segments)
+    }
+
+    fn int_segment_reduce_sorted<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        segment_ids: TchTensor<i64, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> TchTensor<i64, D> {
+        TchOps::segment_reduce_sorted(tensor, segment_ids, num_segments, reduction)
+    }
+
+    fn int_mode<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+    ) -> (TchTensor<i64, D>, TchTensor<i64, D>) {
+        let (values, _) = tensor.tensor.mode(dim as i64, true);
+        let counts = tensor.tensor.eq_tensor(&values).sum_dim_intlist(
+            Some([dim as i64].as_slice()),
+            true,
+            tch::Kind::Int64,
+        );
+
+        (TchTensor::new(values), TchTensor::new(counts))
+    }
+
     fn int_swap_dims<const D: usize>(
         tensor: <LibTorch<E> as Backend>::IntTensorPrimitive<D>,
         dim1: usize,
@@ -460,6 +516,9 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
                 let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            distribution => {
+                Self::float_into_int(random_from_standard::<Self, D>(shape, distribution, device))
+            }
         }
     }
 
@@ -516,4 +575,24 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
     ) -> <LibTorch<E> as Backend>::IntTensorPrimitive<D> {
         TchOps::argsort(tensor, dim, descending)
     }
+
+    fn int_unique<const D: usize>(
+        tensor: <LibTorch<E> as Backend>::IntTensorPrimitive<D>,
+        consecutive: bool,
+    ) -> UniqueOutput<Self, Int, D> {
+        TchOps::unique(tensor, consecutive)
+    }
+}
+
+/// Selects the values in `[0, num_bins)`, with their weights, since LibTorch counts the values up to
+/// the largest one and rejects the negative values.
+fn in_bins(
+    tensor: tch::Tensor,
+    weights: Option<tch::Tensor>,
+    num_bins: usize,
+) -> (tch::Tensor, Option<tch::Tensor>) {
+    let mask = tensor.ge(0).logical_and(&tensor.lt(num_bins as i64));
+    let weights = weights.map(|weights| weights.masked_select(&mask));
+
+    (tensor.masked_select(&mask), weights)
 }
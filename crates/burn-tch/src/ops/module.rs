@@ -1,7 +1,7 @@
 use crate::{element::TchElement, LibTorch, TchTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions, MaxPool1dWithIndices,
-    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    attention, AttentionOptions, ConvOptions, ConvTransposeOptions, InterpolateMode,
+    InterpolateOptions, MaxPool1dWithIndices, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
 
 impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
@@ -284,6 +284,33 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         TchTensor::new(tensor)
     }
 
+    fn attention(
+        query: TchTensor<E, 4>,
+        key: TchTensor<E, 4>,
+        value: TchTensor<E, 4>,
+        mask: Option<TchTensor<bool, 4>>,
+        options: AttentionOptions,
+    ) -> TchTensor<E, 4> {
+        if options.quiet_softmax {
+            return attention::attention::<Self>(query, key, value, mask, options);
+        }
+
+        // The masked scores are offset by the mask value instead of being replaced, which only
+        // changes the attention weights of the queries whose keys are all masked.
+        let mask = mask.map(|mask| mask.tensor.to_kind(query.tensor.kind()) * options.mask_value);
+        let tensor = tch::Tensor::scaled_dot_product_attention(
+            &query.tensor,
+            &key.tensor,
+            &value.tensor,
+            mask,
+            0.0,
+            false,
+            options.scale,
+        );
+
+        TchTensor::new(tensor)
+    }
+
     fn interpolate(
         x: TchTensor<E, 4>,
         output_size: [usize; 2],
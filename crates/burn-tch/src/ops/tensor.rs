@@ -1,7 +1,10 @@
 use super::TchOps;
 use crate::{element::TchElement, LibTorch, LibTorchDevice, TchShape, TchTensor};
 use burn_tensor::{
-    backend::Backend, ops::FloatTensorOps, Distribution, ElementConversion, Reader, Shape,
+    backend::Backend,
+    linalg::QrMode,
+    ops::{random::random_from_standard, unique::UniqueOutput, FloatTensorOps},
+    ComplexPrimitive, Distribution, ElementConversion, Float, IndexReduction, Reader, Shape,
     TensorData,
 };
 use std::ops::Range;
@@ -40,9 +43,18 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
                 let mut tensor = TchTensor::<E, D>::empty(shape, *device);
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            distribution => random_from_standard::<Self, D>(shape, distribution, device),
         }
     }
 
+    fn float_multinomial<const D: usize>(
+        tensor: TchTensor<E, D>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::multinomial(tensor, num_samples, replacement)
+    }
+
     fn float_repeat<const D: usize>(
         tensor: TchTensor<E, D>,
         dim: usize,
@@ -81,6 +93,10 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
         Reader::Concrete(TensorData::new(values.unwrap(), shape))
     }
 
+    fn float_checksum<const D: usize>(tensor: TchTensor<E, D>) -> Reader<u64> {
+        Reader::Concrete(TchOps::checksum(tensor, E::dtype()))
+    }
+
     fn float_device<const D: usize>(tensor: &TchTensor<E, D>) -> LibTorchDevice {
         tensor.tensor.device().into()
     }
@@ -417,6 +433,201 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
         tensor.unary_ops(|mut tensor| tensor.erf_(), |tensor| tensor.erf())
     }
 
+    fn float_fft<const D: usize>(
+        tensor: ComplexPrimitive<Self, D>,
+        inverse: bool,
+    ) -> ComplexPrimitive<Self, D> {
+        let kind = tensor.real.tensor.kind();
+        // Complex tensors are only supported in single and double precision.
+        let complex_kind = match kind {
+            tch::Kind::Double => tch::Kind::Double,
+            _ => tch::Kind::Float,
+        };
+        let complex = tch::Tensor::complex(
+            &tensor.real.tensor.to_kind(complex_kind),
+            &tensor.imag.tensor.to_kind(complex_kind),
+        );
+
+        let output = match inverse {
+            true => complex.fft_ifft(None, D as i64 - 1, "backward"),
+            false => complex.fft_fft(None, D as i64 - 1, "backward"),
+        };
+
+        ComplexPrimitive {
+            real: TchTensor::new(output.real().to_kind(kind).contiguous()),
+            imag: TchTensor::new(output.imag().to_kind(kind).contiguous()),
+        }
+    }
+
+    fn float_svd<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>, TchTensor<E, D>) {
+        let kind = tensor.tensor.kind();
+        let (u, s, v) = tensor.tensor.to_kind(linalg_kind(kind)).svd(true, true);
+
+        (
+            TchTensor::new(u.to_kind(kind).contiguous()),
+            TchTensor::new(s.unsqueeze(-2).to_kind(kind).contiguous()),
+            TchTensor::new(v.transpose(-2, -1).to_kind(kind).contiguous()),
+        )
+    }
+
+    fn float_qr<const D: usize>(
+        tensor: TchTensor<E, D>,
+        mode: QrMode,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let kind = tensor.tensor.kind();
+        let mode = match mode {
+            QrMode::Reduced => "reduced",
+            QrMode::Complete => "complete",
+        };
+        let (q, r) = tch::Tensor::linalg_qr(&tensor.tensor.to_kind(linalg_kind(kind)), mode);
+
+        // Flip the signs so that the diagonal of R is non-negative, like the other backends. The
+        // diagonal has `min(m, n)` elements, the remaining columns of Q and rows of R are kept.
+        let [q_cols, r_rows] = [q.size()[D - 1], r.size()[D - 2]];
+        let signs = r.diagonal(0, -2, -1).ge(0.0).to_kind(q.kind()) * 2.0 - 1.0;
+        let k = signs.size()[D - 2];
+        let q = tch::Tensor::cat(
+            &[
+                q.narrow(-1, 0, k) * signs.unsqueeze(-2),
+                q.narrow(-1, k, q_cols - k),
+            ],
+            -1,
+        );
+        let r = tch::Tensor::cat(
+            &[
+                r.narrow(-2, 0, k) * signs.unsqueeze(-1),
+                r.narrow(-2, k, r_rows - k),
+            ],
+            -2,
+        );
+
+        (
+            TchTensor::new(q.to_kind(kind).contiguous()),
+            TchTensor::new(r.to_kind(kind).contiguous()),
+        )
+    }
+
+    fn float_cholesky<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        let kind = tensor.tensor.kind();
+        // Matrices that aren't positive-definite don't raise an error, like the other backends.
+        let (lower, _info) = tensor
+            .tensor
+            .to_kind(linalg_kind(kind))
+            .linalg_cholesky_ex(false, false);
+
+        TchTensor::new(lower.to_kind(kind).contiguous())
+    }
+
+    fn float_solve<const D: usize>(
+        tensor: TchTensor<E, D>,
+        rhs: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        let kind = tensor.tensor.kind();
+        // Singular matrices don't raise an error, like the other backends.
+        let (solution, _info) = tch::Tensor::linalg_solve_ex(
+            &tensor.tensor.to_kind(linalg_kind(kind)),
+            &rhs.tensor.to_kind(linalg_kind(kind)),
+            true,
+            false,
+        );
+
+        TchTensor::new(solution.to_kind(kind).contiguous())
+    }
+
+    fn float_triangular_solve<const D: usize>(
+        tensor: TchTensor<E, D>,
+        rhs: TchTensor<E, D>,
+        upper: bool,
+    ) -> TchTensor<E, D> {
+        let kind = tensor.tensor.kind();
+        let solution = tensor
+            .tensor
+            .to_kind(linalg_kind(kind))
+            .linalg_solve_triangular(&rhs.tensor.to_kind(linalg_kind(kind)), upper, true, false);
+
+        TchTensor::new(solution.to_kind(kind).contiguous())
+    }
+
+    fn float_det<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        let kind = tensor.tensor.kind();
+        let det = tch::Tensor::linalg_det(&tensor.tensor.to_kind(linalg_kind(kind)));
+
+        // The determinants keep the matrix dimensions, like the other backends.
+        TchTensor::new(det.unsqueeze(-1).unsqueeze(-1).to_kind(kind).contiguous())
+    }
+
+    fn float_slogdet<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let kind = tensor.tensor.kind();
+        let (sign, logabsdet) =
+            tch::Tensor::linalg_slogdet(&tensor.tensor.to_kind(linalg_kind(kind)));
+
+        (
+            TchTensor::new(sign.unsqueeze(-1).unsqueeze(-1).to_kind(kind).contiguous()),
+            TchTensor::new(
+                logabsdet
+                    .unsqueeze(-1)
+                    .unsqueeze(-1)
+                    .to_kind(kind)
+                    .contiguous(),
+            ),
+        )
+    }
+
+    fn float_pinv<const D: usize>(tensor: TchTensor<E, D>, rtol: f64) -> TchTensor<E, D> {
+        let kind = tensor.tensor.kind();
+        let pinv = tensor
+            .tensor
+            .to_kind(linalg_kind(kind))
+            .linalg_pinv(rtol, false);
+
+        TchTensor::new(pinv.to_kind(kind).contiguous())
+    }
+
+    fn float_lstsq<const D: usize>(
+        tensor: TchTensor<E, D>,
+        rhs: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        let kind = tensor.tensor.kind();
+        let a = tensor.tensor.to_kind(linalg_kind(kind));
+        let b = rhs.tensor.to_kind(linalg_kind(kind));
+        let [m, n] = [a.size()[D - 2], a.size()[D - 1]];
+
+        // The solutions are computed from the QR decomposition like the other backends, since the
+        // drivers of `linalg_lstsq` for underdetermined systems depend on the device.
+        let solution = match m >= n {
+            // A = Q R, X = R^-1 Q^T B
+            true => {
+                let (q, r) = tch::Tensor::linalg_qr(&a, "reduced");
+                r.linalg_solve_triangular(&q.transpose(-2, -1).matmul(&b), true, true, false)
+            }
+            // A^T = Q R, X = Q R^-T B
+            false => {
+                let (q, r) = tch::Tensor::linalg_qr(&a.transpose(-2, -1), "reduced");
+                q.matmul(
+                    &r.transpose(-2, -1)
+                        .linalg_solve_triangular(&b, false, true, false),
+                )
+            }
+        };
+
+        TchTensor::new(solution.to_kind(kind).contiguous())
+    }
+
+    fn float_eigh<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let kind = tensor.tensor.kind();
+        // The eigenvalues are returned in ascending order, reading only the lower triangular part.
+        let (eigenvalues, vectors) = tensor.tensor.to_kind(linalg_kind(kind)).linalg_eigh("L");
+
+        (
+            TchTensor::new(eigenvalues.unsqueeze(-2).to_kind(kind).contiguous()),
+            TchTensor::new(vectors.to_kind(kind).contiguous()),
+        )
+    }
+
     fn float_cat<const D: usize>(tensors: Vec<TchTensor<E, D>>, dim: usize) -> TchTensor<E, D> {
         TchOps::cat(tensors, dim)
     }
@@ -514,4 +725,37 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
     ) -> <LibTorch<E> as Backend>::IntTensorPrimitive<D> {
         TchOps::argsort(tensor, dim, descending)
     }
+
+    fn float_unique<const D: usize>(
+        tensor: <LibTorch<E> as Backend>::FloatTensorPrimitive<D>,
+        consecutive: bool,
+    ) -> UniqueOutput<Self, Float, D> {
+        TchOps::unique(tensor, consecutive)
+    }
+
+    fn float_segment_sum<const D: usize>(
+        tensor: TchTensor<E, D>,
+        segment_ids: TchTensor<i64, 1>,
+        num_segments: usize,
+    ) -> TchTensor<E, D> {
+        TchOps::segment_sum(tensor, segment_ids, num_segments)
+    }
+
+    fn float_segment_reduce_sorted<const D: usize>(
+        tensor: TchTensor<E, D>,
+        segment_ids: TchTensor<i64, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> TchTensor<E, D> {
+        TchOps::segment_reduce_sorted(tensor, segment_ids, num_segments, reduction)
+    }
+}
+
+/// The kind used by the linear algebra routines of LibTorch, which only support single and double
+/// precision.
+fn linalg_kind(kind: tch::Kind) -> tch::Kind {
+    match kind {
+        tch::Kind::Double => tch::Kind::Double,
+        _ => tch::Kind::Float,
+    }
 }
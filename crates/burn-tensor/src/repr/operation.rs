@@ -1383,6 +1383,9 @@ impl core::hash::Hash for RandomOperationDescription {
             Distribution::Bernoulli(_) => 2u8.hash(state),
             Distribution::Uniform(_, _) => 3u8.hash(state),
             Distribution::Normal(_, _) => 4u8.hash(state),
+            Distribution::Gamma(_, _) => 5u8.hash(state),
+            Distribution::Beta(_, _) => 6u8.hash(state),
+            Distribution::Poisson(_) => 7u8.hash(state),
         }
     }
 }
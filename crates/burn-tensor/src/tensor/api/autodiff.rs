@@ -1,11 +1,38 @@
-use crate::{backend::AutodiffBackend, BasicOps, Bool, Float, Int, Tensor, TensorKind};
+use crate::{
+    backend::AutodiffBackend, BasicOps, Bool, Complex, ComplexPrimitive, Float, Int, Tensor,
+    TensorKind,
+};
 
 impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
     /// Backward pass of the tensor.
+    ///
+    /// The hook [installed](crate::backend::install_backward_hook) on the current thread, if any,
+    /// is called during the backward pass.
     pub fn backward(&self) -> B::Gradients {
+        #[cfg(feature = "std")]
+        if let Some(mut hook) = crate::backend::take_backward_hook::<B>() {
+            let grads = self.backward_with_hook(&mut hook);
+            crate::backend::restore_backward_hook::<B>(hook);
+
+            return grads;
+        }
+
         B::backward::<D>(self.primitive.clone())
     }
 
+    /// Backward pass of the tensor, calling the hook with the gradients computed so far each time
+    /// the gradients of some tensors are [complete](Tensor::grad_complete), and a last time when
+    /// the backward pass is done.
+    pub fn backward_with_hook(&self, mut hook: impl FnMut(&B::Gradients)) -> B::Gradients {
+        B::backward_with_hook::<D>(self.primitive.clone(), &mut hook)
+    }
+
+    /// Whether the gradients of the tensor won't change anymore, during a backward pass with a
+    /// [hook](Tensor::backward_with_hook).
+    pub fn grad_complete(&self, grads: &B::Gradients) -> bool {
+        B::grad_complete(&self.primitive, grads)
+    }
+
     /// Get the gradients of a tensor if it exist.
     ///
     /// Returns a new reference to the same tensor. Therefore the same grad tensor can
@@ -27,6 +54,59 @@ impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
     }
 }
 
+impl<const D: usize, B: AutodiffBackend> Tensor<B, D, Complex> {
+    /// Get the gradients of a complex tensor if they exist.
+    ///
+    /// For a real loss `L`, the gradient with respect to `z = x + iy` is `dL/dx + i dL/dy`, which
+    /// is twice the conjugate Wirtinger derivative `dL/dz*`. This is the direction of steepest
+    /// ascent, so gradient descent updates complex parameters like real ones, and the gradients
+    /// of holomorphic operations are propagated by multiplying with the conjugate of their
+    /// derivative.
+    ///
+    /// The gradient of a part that doesn't have one, because it isn't used by the loss, is zero.
+    pub fn grad(&self, grads: &B::Gradients) -> Option<Tensor<B::InnerBackend, D, Complex>> {
+        let real = B::grad(&self.primitive.real, grads);
+        let imag = B::grad(&self.primitive.imag, grads);
+
+        complex_grad::<B, D>(&self.primitive, real, imag)
+    }
+
+    /// Remove the gradients of the complex tensor from the [grads](AutodiffBackend::Gradients)
+    /// struct returning the result.
+    ///
+    /// See [grad](Tensor::grad) for the convention of the complex gradients.
+    pub fn grad_remove(
+        &self,
+        grads: &mut B::Gradients,
+    ) -> Option<Tensor<B::InnerBackend, D, Complex>> {
+        let real = B::grad_remove(&self.primitive.real, grads);
+        let imag = B::grad_remove(&self.primitive.imag, grads);
+
+        complex_grad::<B, D>(&self.primitive, real, imag)
+    }
+}
+
+fn complex_grad<B: AutodiffBackend, const D: usize>(
+    tensor: &ComplexPrimitive<B, D>,
+    real: Option<<B::InnerBackend as crate::backend::Backend>::FloatTensorPrimitive<D>>,
+    imag: Option<<B::InnerBackend as crate::backend::Backend>::FloatTensorPrimitive<D>>,
+) -> Option<Tensor<B::InnerBackend, D, Complex>> {
+    let zeros = |part: &B::FloatTensorPrimitive<D>| {
+        Tensor::<B::InnerBackend, D>::from_primitive(B::inner(part.clone())).zeros_like()
+    };
+    let (real, imag) = match (real, imag) {
+        (None, None) => return None,
+        (real, imag) => (
+            real.map(Tensor::from_primitive)
+                .unwrap_or_else(|| zeros(&tensor.real)),
+            imag.map(Tensor::from_primitive)
+                .unwrap_or_else(|| zeros(&tensor.imag)),
+        ),
+    };
+
+    Some(Tensor::from_parts(real, imag))
+}
+
 impl<const D: usize, B: AutodiffBackend, K: BasicAutodiffOps<B>> Tensor<B, D, K> {
     /// Returns the inner tensor without the autodiff information.
     pub fn inner(self) -> Tensor<B::InnerBackend, D, K::InnerKind> {
@@ -95,6 +175,28 @@ impl<B: AutodiffBackend> BasicAutodiffOps<B> for Bool {
     }
 }
 
+impl<B: AutodiffBackend> BasicAutodiffOps<B> for Complex {
+    type InnerKind = Complex;
+
+    fn inner<const D: usize>(
+        tensor: <Self as TensorKind<B>>::Primitive<D>,
+    ) -> <Self::InnerKind as TensorKind<<B as AutodiffBackend>::InnerBackend>>::Primitive<D> {
+        ComplexPrimitive {
+            real: B::inner(tensor.real),
+            imag: B::inner(tensor.imag),
+        }
+    }
+
+    fn from_inner<const D: usize>(
+        inner: <Self::InnerKind as TensorKind<<B as AutodiffBackend>::InnerBackend>>::Primitive<D>,
+    ) -> <Self as TensorKind<B>>::Primitive<D> {
+        ComplexPrimitive {
+            real: B::from_inner(inner.real),
+            imag: B::from_inner(inner.imag),
+        }
+    }
+}
+
 /// Trait that list all operations that can be applied on all tensors on an autodiff backend.
 ///
 /// # Warnings
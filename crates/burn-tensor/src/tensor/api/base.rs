@@ -20,8 +20,8 @@ use serde::{Serialize, Serializer};
 use crate::check::TensorCheck;
 use crate::tensor::api::chunk::chunk;
 use crate::tensor::api::narrow::narrow;
-use crate::Element;
-use crate::{backend::Backend, check, Bool, Float, Int, Shape, TensorData, TensorKind};
+use crate::{backend::Backend, check, Bool, Float, Int, Shape, Slice, TensorData, TensorKind};
+use crate::{DType, Element, ElementConversion};
 
 /// A tensor with a given backend, shape and data type.
 #[derive(new, Clone, Debug)]
@@ -582,15 +582,19 @@ where
 
     /// Returns a tensor containing the elements selected from the given ranges.
     ///
+    /// Each dimension is sliced with a range of indices or a [Slice], which can have negative
+    /// bounds counted from the end of the dimension and a step between the selected indices. A
+    /// negative step selects the indices of the range in reverse order.
+    ///
     /// # Panics
     ///
-    /// If a range exceeds the number of elements on a dimension.
+    /// If a range exceeds the number of elements on a dimension, is empty, or has a zero step.
     ///
     /// # Example
     ///
     /// ```rust
     /// use burn_tensor::backend::Backend;
-    /// use burn_tensor::{Tensor, Shape};
+    /// use burn_tensor::{Slice, Tensor, Shape};
     ///
     /// fn example<B: Backend>() {
     ///     let device = B::Device::default();
@@ -620,14 +624,28 @@ where
     ///     // let mut v = vec![[[]]];
     ///     // v[0][0][0] = tensor[0][1][1];
     ///     // v[0][1][0] = tensor[0][2][1];
-    ///     let tensor_slices = tensor.slice([0..1, 1..3, 1..2]);
+    ///     let tensor_slices = tensor.clone().slice([0..1, 1..3, 1..2]);
     ///     println!("\nexpecting [1, 2, 1] : {:?}", tensor_slices.dims());
     ///     println!("expecting [[[5],[9]]] : {:?}", tensor_slices);
+    ///
+    ///     // Select every other element of the second dimension, in reverse order
+    ///     let tensor_slices = tensor.slice([Slice::from(..), Slice::from(..).with_step(-2)]);
+    ///     println!("\nexpecting [[[8,9,10,11],[0,1,2,3]]] : {:?}", tensor_slices);
     /// }
     /// ```
-    pub fn slice<const D2: usize>(self, ranges: [core::ops::Range<usize>; D2]) -> Self {
+    pub fn slice<const D2: usize, S: Into<Slice>>(self, slices: [S; D2]) -> Self {
+        let dims = self.dims();
+        let slices = slices.map(Into::into);
+        let ranges =
+            core::array::from_fn(|i| slices[i].to_range(dims.get(i).copied().unwrap_or(0)));
+        let steps = slices.map(|slice| slice.step);
         check!(TensorCheck::slice(&self.shape(), &ranges));
-        Self::new(K::slice(self.primitive, ranges))
+        check!(TensorCheck::slice_steps(&steps));
+
+        match steps.iter().all(|step| *step == 1) {
+            true => Self::new(K::slice(self.primitive, ranges)),
+            false => Self::new(K::slice_with_steps(self.primitive, ranges, steps)),
+        }
     }
 
     /// Returns a copy of the current tensor with the selected elements changed to the new ones at
@@ -699,6 +717,25 @@ where
         Self::into_data(self.clone())
     }
 
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Returns a deterministic checksum of the current tensor.
+    ///
+    /// See [TensorData::checksum] for how it is computed.
+    pub async fn checksum(&self) -> u64 {
+        K::checksum(self.primitive.clone()).read().await
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns a deterministic checksum of the current tensor.
+    ///
+    /// Two tensors have the same checksum when they have the same data type and shape, and their
+    /// values are bitwise identical. This is a cheap way to check that copies of a tensor, on
+    /// different devices or machines, haven't diverged. See [TensorData::checksum] for how it is
+    /// computed.
+    pub fn checksum(&self) -> u64 {
+        K::checksum(self.primitive.clone()).read()
+    }
+
     /// Create a tensor from the given data on the given device.
     pub fn from_data<T>(data: T, device: &B::Device) -> Self
     where
@@ -810,6 +847,59 @@ where
             .collect()
     }
 
+    /// Roll the elements of the tensor along the given dimensions, with wrap-around: the elements
+    /// shifted beyond the last position are re-introduced at the first position.
+    ///
+    /// # Arguments
+    ///
+    /// * `shifts` - The number of places by which the elements are shifted along each dimension.
+    ///   Negative shifts roll the elements towards the first position.
+    /// * `dims` - The dimensions to roll.
+    ///
+    /// # Panics
+    ///
+    /// - If the number of shifts and dimensions differ.
+    /// - If a dimension is greater than the number of dimensions of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the elements rolled.
+    pub fn roll(self, shifts: &[i64], dims: &[usize]) -> Self {
+        assert_eq!(
+            shifts.len(),
+            dims.len(),
+            "Roll expects as many shifts as dimensions, got {} shifts and {} dimensions.",
+            shifts.len(),
+            dims.len()
+        );
+
+        shifts
+            .iter()
+            .zip(dims)
+            .fold(self, |tensor, (shift, dim)| tensor.roll_dim(*shift, *dim))
+    }
+
+    /// Roll the elements of the tensor along the given dimension, with wrap-around.
+    ///
+    /// See [roll](Tensor::roll) for more details.
+    pub fn roll_dim(self, shift: i64, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("roll", dim));
+
+        let size = self.dims()[dim];
+        let shift = match size {
+            0 => 0,
+            _ => shift.rem_euclid(size as i64) as usize,
+        };
+        if shift == 0 {
+            return self;
+        }
+
+        let head = self.clone().narrow(dim, size - shift, shift);
+        let tail = self.narrow(dim, 0, size - shift);
+
+        Self::cat(alloc::vec![head, tail], dim)
+    }
+
     /// Tests if any element in the `tensor` evaluates to True.
     ///
     /// # Arguments
@@ -916,6 +1006,44 @@ where
 
         Tensor::<B, D2, K>::new(K::expand(self.primitive, shape))
     }
+
+    /// Broadcast the tensor to the given shape, following the same rules as the element-wise
+    /// binary operations.
+    ///
+    /// The dimensions of the tensor are aligned with the trailing dimensions of the shape, and
+    /// each of them must either have the size of the target dimension or a size of one. Backends
+    /// repeat the values with strides of zero when they can, without copying them.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor cannot be broadcasted to the given shape, the error naming the mismatching
+    /// dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{broadcast_shapes, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 2>::from_floats([[1.0], [2.0]], &device);
+    ///     let rhs = Tensor::<B, 2>::from_floats([[3.0, 4.0, 5.0]], &device);
+    ///
+    ///     let shape = broadcast_shapes(&[&lhs.dims(), &rhs.dims()]).unwrap();
+    ///     let lhs = lhs.broadcast_to::<2, _>(shape.clone());
+    ///     let rhs = rhs.broadcast_to::<2, _>(shape);
+    ///     println!("{lhs}\n{rhs}");
+    ///     // [[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]]
+    ///     // [[3.0, 4.0, 5.0], [3.0, 4.0, 5.0]]
+    /// }
+    /// ```
+    pub fn broadcast_to<const D2: usize, S: Into<Shape<D2>>>(self, shape: S) -> Tensor<B, D2, K> {
+        let shape = shape.into();
+        check!(TensorCheck::expand("broadcast_to", &self.shape(), &shape));
+
+        Tensor::<B, D2, K>::new(K::expand(self.primitive, shape))
+    }
 }
 
 /// Iterator given by (Tensor::iter_dim).
@@ -1004,6 +1132,7 @@ where
         acc: &mut String,
         depth: usize,
         multi_index: &mut [usize],
+        print_options: &PrintOptions,
         range: (usize, usize),
     ) {
         let (start, end) = range;
@@ -1022,7 +1151,29 @@ where
                 .iter::<<K as BasicOps<B>>::Elem>()
                 .next()
                 .unwrap();
-            acc.push_str(&format!("{elem:?}"));
+            acc.push_str(&Self::fmt_elem(elem, print_options));
+        }
+    }
+
+    /// Formats an element, with the precision and notation of the print options for floats.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn fmt_elem(elem: &<K as BasicOps<B>>::Elem, print_options: &PrintOptions) -> String {
+        let float = |value: f64| match (print_options.precision, print_options.sci_mode) {
+            (Some(precision), true) => format!("{value:.precision$e}"),
+            (Some(precision), false) => format!("{value:.precision$}"),
+            (None, true) => format!("{value:e}"),
+            (None, false) => format!("{elem:?}"),
+        };
+
+        match <K as BasicOps<B>>::Elem::dtype() {
+            // Single and half precision floats are formatted as f32 to keep their shortest
+            // representation.
+            DType::F32 | DType::F16 | DType::BF16 => match print_options.sci_mode {
+                true if print_options.precision.is_none() => format!("{:e}", elem.elem::<f32>()),
+                _ => float(elem.elem::<f64>()),
+            },
+            DType::F64 => float(elem.elem::<f64>()),
+            _ => format!("{elem:?}"),
         }
     }
 
@@ -1080,18 +1231,25 @@ where
             // if we are at the innermost dimension, just push its elements into the accumulator
             if summarize && self.dims()[depth] > 2 * edge_items {
                 // print the starting `edge_items` elements
-                self.fmt_inner_tensor(acc, depth, multi_index, (0, edge_items));
+                self.fmt_inner_tensor(acc, depth, multi_index, print_options, (0, edge_items));
                 acc.push_str(", ...");
                 // print the last `edge_items` elements
                 self.fmt_inner_tensor(
                     acc,
                     depth,
                     multi_index,
+                    print_options,
                     (self.dims()[depth] - edge_items, self.dims()[depth]),
                 );
             } else {
                 // print all the elements
-                self.fmt_inner_tensor(acc, depth, multi_index, (0, self.dims()[depth]));
+                self.fmt_inner_tensor(
+                    acc,
+                    depth,
+                    multi_index,
+                    print_options,
+                    (0, self.dims()[depth]),
+                );
             }
         } else {
             // otherwise, iterate through the current dimension and recursively display the inner tensors
@@ -1137,11 +1295,20 @@ where
 }
 
 /// Options for Tensor pretty printing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PrintOptions {
     /// number of elements to start summarizing tensor
     pub threshold: usize,
     /// number of starting elements and ending elements to display
     pub edge_items: usize,
+    /// number of digits after the decimal point of float elements, the shortest representation
+    /// being used when `None`
+    pub precision: Option<usize>,
+    /// whether float elements are displayed in scientific notation
+    pub sci_mode: bool,
+    /// whether the tensor is displayed on a single block followed by its shape, device and data
+    /// type, like `tensor([1.0, 2.0], shape=[2], device=Cpu, dtype=f32)`
+    pub compact: bool,
 }
 
 static PRINT_OPTS: Mutex<PrintOptions> = Mutex::new(PrintOptions::const_default());
@@ -1152,37 +1319,105 @@ impl PrintOptions {
         Self {
             threshold: 1000,
             edge_items: 3,
+            precision: None,
+            sci_mode: false,
+            compact: false,
         }
     }
 }
 
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
 /// Set print options
 pub fn set_print_options(options: PrintOptions) {
     *PRINT_OPTS.lock().unwrap() = options
 }
 
-/// Pretty print tensors
-impl<B, const D: usize, K> core::fmt::Display for Tensor<B, D, K>
+/// Get the current print options
+pub fn print_options() -> PrintOptions {
+    *PRINT_OPTS.lock().unwrap()
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
 where
     B: Backend,
     B::IntElem: core::fmt::Display,
     K: BasicOps<B>,
     <K as BasicOps<B>>::Elem: Debug,
 {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        writeln!(f, "Tensor {{")?;
+    /// Returns a value displaying the tensor with the given print options instead of the global
+    /// ones set with [set_print_options].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{PrintOptions, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, 2.5, 1000.0], &device);
+    ///
+    ///     let options = PrintOptions {
+    ///         precision: Some(2),
+    ///         sci_mode: true,
+    ///         compact: true,
+    ///         ..Default::default()
+    ///     };
+    ///     println!("{}", tensor.display_with(options));
+    ///     // tensor([1.00e0, 2.50e0, 1.00e3], shape=[3], device=Cpu, dtype=f32)
+    /// }
+    /// ```
+    pub fn display_with(&self, options: PrintOptions) -> TensorDisplay<'_, B, D, K> {
+        TensorDisplay {
+            tensor: self,
+            options,
+        }
+    }
+
+    fn fmt_with(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        mut options: PrintOptions,
+    ) -> core::fmt::Result {
+        // The precision of the formatter, e.g. `{:.2}`, takes precedence over the options.
+        if let Some(precision) = f.precision() {
+            options.precision = Some(precision);
+        }
 
         #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-        {
-            let po = PRINT_OPTS.lock().unwrap();
+        let data = {
             let mut acc = String::new();
             let mut multi_index = vec![0; D];
-            let summarize = self.shape().num_elements() > po.threshold;
+            let summarize = self.shape().num_elements() > options.threshold;
 
-            self.display_recursive(&mut acc, 0, &mut multi_index, &po, summarize);
+            self.display_recursive(&mut acc, 0, &mut multi_index, &options, summarize);
+            acc
+        };
+
+        if options.compact {
+            write!(f, "tensor(")?;
+            #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+            write!(f, "{}, ", data.replace('\n', "\n       "))?;
+            return write!(
+                f,
+                "shape={:?}, device={:?}, dtype={})",
+                self.dims(),
+                self.device(),
+                K::elem_type_name()
+            );
+        }
 
+        writeln!(f, "Tensor {{")?;
+
+        #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+        {
             writeln!(f, "  data:")?;
-            write!(f, "{acc}")?;
+            write!(f, "{data}")?;
             writeln!(f, ",")?;
         }
 
@@ -1195,6 +1430,42 @@ where
     }
 }
 
+/// Displays a tensor with the given print options, created with
+/// [display_with](Tensor::display_with).
+pub struct TensorDisplay<'a, B, const D: usize, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    tensor: &'a Tensor<B, D, K>,
+    options: PrintOptions,
+}
+
+impl<B, const D: usize, K> core::fmt::Display for TensorDisplay<'_, B, D, K>
+where
+    B: Backend,
+    B::IntElem: core::fmt::Display,
+    K: BasicOps<B>,
+    <K as BasicOps<B>>::Elem: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.tensor.fmt_with(f, self.options)
+    }
+}
+
+/// Pretty print tensors
+impl<B, const D: usize, K> core::fmt::Display for Tensor<B, D, K>
+where
+    B: Backend,
+    B::IntElem: core::fmt::Display,
+    K: BasicOps<B>,
+    <K as BasicOps<B>>::Elem: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_with(f, print_options())
+    }
+}
+
 /// Transpose marker (zero-size type). Used to sugar the transpose of a tensor, e.g.
 /// ```rust
 /// use burn_tensor::backend::Backend;
@@ -1365,6 +1636,33 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         range: [Range<usize>; D2],
     ) -> Self::Primitive<D1>;
 
+    /// Select tensor elements corresponding for the given ranges, with a step between the
+    /// selected indices of each range.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `ranges` - The ranges of the elements to select.
+    /// * `steps` - The non-zero steps of the ranges, negative ones selecting in reverse order.
+    ///
+    /// # Returns
+    ///
+    /// The selected elements.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For selecting elements of a tensor, users should prefer the [Tensor::slice](Tensor::slice) function,
+    /// which is more high-level and designed for public use.
+    fn slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> Self::Primitive<D1>;
+
     ///  Assigns the given value to the tensor elements corresponding for the given ranges.
     ///
     /// # Arguments
@@ -1455,6 +1753,26 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// which is more high-level and designed for public use.
     fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<TensorData>;
 
+    /// Computes a deterministic checksum of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The checksum of the tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the checksum of a tensor, users should prefer the [Tensor::checksum](Tensor::checksum)
+    /// function, which is more high-level and designed for public use.
+    fn checksum<const D: usize>(tensor: Self::Primitive<D>) -> Reader<u64>;
+
     /// Creates a tensor from the given data.
     ///
     /// # Arguments
@@ -1705,6 +2023,14 @@ impl<B: Backend> BasicOps<B> for Float {
         B::float_slice(tensor, ranges)
     }
 
+    fn slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> Self::Primitive<D1> {
+        B::float_slice_with_steps(tensor, ranges, steps)
+    }
+
     fn slice_assign<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         ranges: [Range<usize>; D2],
@@ -1728,6 +2054,10 @@ impl<B: Backend> BasicOps<B> for Float {
         B::float_into_data(tensor)
     }
 
+    fn checksum<const D: usize>(tensor: Self::Primitive<D>) -> Reader<u64> {
+        B::float_checksum(tensor)
+    }
+
     fn from_data<const D: usize>(data: TensorData, device: &B::Device) -> Self::Primitive<D> {
         B::float_from_data(data, device)
     }
@@ -1827,6 +2157,14 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_slice(tensor, ranges)
     }
 
+    fn slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> Self::Primitive<D1> {
+        B::int_slice_with_steps(tensor, ranges, steps)
+    }
+
     fn slice_assign<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         ranges: [Range<usize>; D2],
@@ -1850,6 +2188,10 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_into_data(tensor)
     }
 
+    fn checksum<const D: usize>(tensor: Self::Primitive<D>) -> Reader<u64> {
+        B::int_checksum(tensor)
+    }
+
     fn from_data<const D: usize>(data: TensorData, device: &B::Device) -> Self::Primitive<D> {
         B::int_from_data(data, device)
     }
@@ -1949,6 +2291,14 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_slice(tensor, ranges)
     }
 
+    fn slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> Self::Primitive<D1> {
+        B::bool_slice_with_steps(tensor, ranges, steps)
+    }
+
     fn slice_assign<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         ranges: [Range<usize>; D2],
@@ -1972,6 +2322,10 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_into_data(tensor)
     }
 
+    fn checksum<const D: usize>(tensor: Self::Primitive<D>) -> Reader<u64> {
+        B::bool_checksum(tensor)
+    }
+
     fn from_data<const D: usize>(data: TensorData, device: &B::Device) -> Self::Primitive<D> {
         B::bool_from_data(data, device)
     }
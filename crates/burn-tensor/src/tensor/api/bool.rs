@@ -1,3 +1,5 @@
+use crate::check;
+use crate::check::TensorCheck;
 use crate::{backend::Backend, Bool, Int, Shape, Tensor, TensorData};
 use alloc::vec::Vec;
 
@@ -40,6 +42,70 @@ where
         Tensor::new(B::bool_not(self.primitive))
     }
 
+    /// Returns the most frequent value along the given dimension, with its number of occurrences.
+    ///
+    /// When there are as many `true` as `false` values, `false` is returned. The given dimension
+    /// of both outputs has a size of 1, like other reductions along a dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor, TensorData};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let votes = Tensor::<B, 2, Bool>::from_bool(
+    ///         TensorData::from([[true, false, true], [false, false, true]]),
+    ///         &device,
+    ///     );
+    ///
+    ///     let (values, counts) = votes.mode(1);
+    ///     println!("{values}");
+    ///     // [[true], [false]]
+    ///     println!("{counts}");
+    ///     // [[2], [2]]
+    /// }
+    /// ```
+    pub fn mode(self, dim: usize) -> (Tensor<B, D, Bool>, Tensor<B, D, Int>) {
+        check!(TensorCheck::aggregate_dim::<D>("Mode", dim));
+
+        let size = self.dims()[dim] as i32;
+        let num_true = self.int().sum_dim(dim);
+        let num_false = num_true.clone().neg().add_scalar(size);
+        let mode = num_true.clone().greater(num_false.clone());
+        let counts = num_false.mask_where(mode.clone(), num_true);
+
+        (mode, counts)
+    }
+
+    /// Packs the last dimension of the tensor into a bitmap of bytes, e.g. to store large
+    /// attention masks with 8 times less elements.
+    ///
+    /// The last dimension of the bitmap has the size `ceil(n / 8)`, the `i`-th value of the
+    /// last dimension setting the bit `i % 8` of the byte `i / 8`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let mask = Tensor::<B, 2, Bool>::tril_mask([2, 10], 0, &device);
+    ///
+    ///     let packed = mask.clone().pack_bits();
+    ///     println!("{packed}");
+    ///     // [[254, 3], [252, 3]]
+    ///     let unpacked = packed.unpack_bits(10);
+    ///     unpacked.into_data().assert_eq(&mask.into_data(), true);
+    /// }
+    /// ```
+    pub fn pack_bits(self) -> Tensor<B, D, Int> {
+        Tensor::new(B::bool_pack_bits(self.primitive))
+    }
+
     /// Compute the indices of the elements that are non-zero.
     ///
     /// # Returns
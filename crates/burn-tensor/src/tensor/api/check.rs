@@ -1,4 +1,4 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use crate::{backend::Backend, ops::ResampleOptions, BasicOps, BroadcastError, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -706,6 +706,21 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn slice_steps<const D: usize>(steps: &[isize; D]) -> Self {
+        let mut check = Self::Ok;
+
+        if let Some(dim) = steps.iter().position(|step| *step == 0) {
+            check = check.register(
+                "Slice",
+                TensorError::new("The provided slices have a step of zero.").details(format!(
+                    "The slice at dimension '{dim}' has a step of zero, provided steps {steps:?}."
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn slice_assign<const D1: usize, const D2: usize>(
         shape: &Shape<D1>,
         shape_value: &Shape<D1>,
@@ -954,6 +969,411 @@ impl TensorCheck {
         check
     }
 
+    /// Checks if the packed bitmap has the shape of the unpacked tensor, its last dimension
+    /// holding the bits of the last dimension of the tensor.
+    pub(crate) fn packed_bits<const D: usize>(
+        ops: &str,
+        packed: &Shape<D>,
+        unpacked: &Shape<D>,
+    ) -> Self {
+        let mut expected = unpacked.dims;
+        expected[D - 1] = unpacked.dims[D - 1].div_ceil(8);
+
+        match packed.dims == expected {
+            true => Self::Ok,
+            false => Self::Ok.register(
+                ops,
+                TensorError::new("The packed bitmap doesn't have the expected shape.").details(
+                    format!(
+                        "Expected the shape {expected:?} for {} values in the last dimension of \
+                         the shape {:?}, got {:?}.",
+                        unpacked.dims[D - 1],
+                        unpacked.dims,
+                        packed.dims,
+                    ),
+                ),
+            ),
+        }
+    }
+
+    /// Checks if the grid has the batch size of the input and holds 2 coordinates per location.
+    pub(crate) fn grid_sample(x: &Shape<4>, grid: &Shape<4>) -> Self {
+        let [batch_size, _, _, _] = x.dims;
+        let [grid_batch_size, _, _, num_coords] = grid.dims;
+
+        match grid_batch_size == batch_size && num_coords == 2 {
+            true => Self::Ok,
+            false => Self::Ok.register(
+                "Grid Sample",
+                TensorError::new("The grid doesn't have the expected shape.").details(format!(
+                    "Expected the shape [{batch_size}, height_out, width_out, 2] for an input \
+                     of shape {:?}, got {:?}.",
+                    x.dims, grid.dims,
+                )),
+            ),
+        }
+    }
+
+    /// Checks if the affine matrices are `2x3` matrices, one for each sample of the output.
+    pub(crate) fn affine_grid(theta: &Shape<3>, size: &[usize; 4]) -> Self {
+        let [batch_size, _, _, _] = *size;
+
+        match theta.dims == [batch_size, 2, 3] {
+            true => Self::Ok,
+            false => Self::Ok.register(
+                "Affine Grid",
+                TensorError::new("The affine matrices don't have the expected shape.").details(
+                    format!(
+                        "Expected the shape [{batch_size}, 2, 3] for an output of size {size:?}, \
+                         got {:?}.",
+                        theta.dims,
+                    ),
+                ),
+            ),
+        }
+    }
+
+    /// Checks if the keys and the values match the queries, and if the mask has one element for
+    /// each pair of query and key.
+    pub(crate) fn attention(
+        query: &Shape<4>,
+        key: &Shape<4>,
+        value: &Shape<4>,
+        mask: Option<&Shape<4>>,
+    ) -> Self {
+        let [batch_size, n_heads, seq_length_1, d_k] = query.dims;
+        let [_, _, seq_length_2, d_v] = value.dims;
+        let mut check = Self::Ok;
+
+        let expected = [batch_size, n_heads, seq_length_2, d_k];
+        if key.dims != expected {
+            check = check.register(
+                "Attention",
+                TensorError::new("The keys don't have the expected shape.").details(format!(
+                    "Expected the shape {expected:?}, got {:?}.",
+                    key.dims,
+                )),
+            );
+        }
+
+        if value.dims != [batch_size, n_heads, seq_length_2, d_v] {
+            check = check.register(
+                "Attention",
+                TensorError::new("The values don't have the expected shape.").details(format!(
+                    "Expected the batch size {batch_size} and {n_heads} heads, got {:?}.",
+                    value.dims,
+                )),
+            );
+        }
+
+        if let Some(mask) = mask {
+            let expected = [batch_size, n_heads, seq_length_1, seq_length_2];
+
+            if mask.dims != expected {
+                check = check.register(
+                    "Attention",
+                    TensorError::new("The mask doesn't have the expected shape.").details(format!(
+                        "Expected the shape {expected:?}, got {:?}.",
+                        mask.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    /// Checks if the keys and the values match the queries, if the queries are at most as many
+    /// as the keys, and if the global tokens are given for each key.
+    pub(crate) fn window_attention(
+        query: &Shape<4>,
+        key: &Shape<4>,
+        value: &Shape<4>,
+        global: Option<&Shape<2>>,
+    ) -> Self {
+        let [batch_size, _, seq_length_1, _] = query.dims;
+        let [_, _, seq_length_2, _] = key.dims;
+        let mut check = Self::attention(query, key, value, None);
+
+        if seq_length_1 > seq_length_2 {
+            check = check.register(
+                "Window Attention",
+                TensorError::new("The queries should be the last positions of the keys.").details(
+                    format!("Got {seq_length_1} queries for {seq_length_2} keys."),
+                ),
+            );
+        }
+
+        if let Some(global) = global {
+            let expected = [batch_size, seq_length_2];
+
+            if global.dims != expected {
+                check = check.register(
+                    "Window Attention",
+                    TensorError::new("The global tokens don't have the expected shape.").details(
+                        format!("Expected the shape {expected:?}, got {:?}.", global.dims),
+                    ),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn dequantize_matmul(x: &Shape<3>, weight: &Shape<2>, scale: &Shape<1>) -> Self {
+        let [d_input, d_output] = weight.dims;
+        let mut check = Self::Ok;
+
+        if x.dims[2] != d_input {
+            check = check.register(
+                "Dequantize Matmul",
+                TensorError::new("The input doesn't have the number of features of the weights.")
+                    .details(format!(
+                        "Expected {d_input} features, got the shape {:?}.",
+                        x.dims
+                    )),
+            );
+        }
+
+        if scale.dims[0] != d_output {
+            check = check.register(
+                "Dequantize Matmul",
+                TensorError::new("The scale doesn't have the number of outputs of the weights.")
+                    .details(format!(
+                        "Expected the shape {:?}, got {:?}.",
+                        [d_output],
+                        scale.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn selective_scan(
+        u: &Shape<3>,
+        delta: &Shape<3>,
+        a: &Shape<2>,
+        b: &Shape<3>,
+        c: &Shape<3>,
+    ) -> Self {
+        let [batch_size, channels, length] = u.dims;
+        let [_, d_state] = a.dims;
+        let mut check = Self::Ok;
+
+        if delta.dims != u.dims {
+            check = check.register(
+                "Selective Scan",
+                TensorError::new("The time steps don't have the shape of the input.").details(
+                    format!("Expected the shape {:?}, got {:?}.", u.dims, delta.dims),
+                ),
+            );
+        }
+
+        if a.dims[0] != channels {
+            check = check.register(
+                "Selective Scan",
+                TensorError::new("The state matrix doesn't have the expected shape.").details(
+                    format!("Expected {channels} channels, got the shape {:?}.", a.dims),
+                ),
+            );
+        }
+
+        for (name, tensor) in [("input", b), ("output", c)] {
+            let expected = [batch_size, d_state, length];
+
+            if tensor.dims != expected {
+                check = check.register(
+                    "Selective Scan",
+                    TensorError::new(format!(
+                        "The {name} matrices don't have the expected shape."
+                    ))
+                    .details(format!(
+                        "Expected the shape {expected:?}, got {:?}.",
+                        tensor.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    /// Checks if the boxes are given as `(x1, y1, x2, y2)`, and if there are as many boxes on
+    /// both sides when they are paired.
+    pub(crate) fn box_iou(ops: &str, lhs: &Shape<2>, rhs: &Shape<2>, paired: bool) -> Self {
+        let mut check = Self::Ok;
+
+        for boxes in [lhs, rhs] {
+            if boxes.dims[1] != 4 {
+                check = check.register(
+                    ops,
+                    TensorError::new("The boxes don't have the expected shape.").details(format!(
+                        "Expected the shape [num_boxes, 4], got {:?}.",
+                        boxes.dims
+                    )),
+                );
+            }
+        }
+
+        if paired && lhs.dims[0] != rhs.dims[0] {
+            check = check.register(
+                ops,
+                TensorError::new("The paired boxes don't have the same number of boxes.")
+                    .details(format!("Got {} and {} boxes.", lhs.dims[0], rhs.dims[0])),
+            );
+        }
+
+        check
+    }
+
+    /// Checks if each box is given as `(batch_index, x1, y1, x2, y2)`.
+    pub(crate) fn roi(ops: &str, boxes: &Shape<2>) -> Self {
+        let [_, num_values] = boxes.dims;
+
+        match num_values == 5 {
+            true => Self::Ok,
+            false => Self::Ok.register(
+                ops,
+                TensorError::new("The boxes don't have the expected shape.").details(format!(
+                    "Expected the shape [num_boxes, 5], got {:?}.",
+                    boxes.dims,
+                )),
+            ),
+        }
+    }
+
+    /// Checks if each box is given as `(x1, y1, x2, y2)`, with a score and optionally a category.
+    pub(crate) fn nms(boxes: &Shape<2>, scores: &Shape<1>, idxs: Option<&Shape<1>>) -> Self {
+        let [num_boxes, num_coords] = boxes.dims;
+        let mut check = Self::Ok;
+
+        if num_coords != 4 {
+            check = check.register(
+                "Nms",
+                TensorError::new("The boxes don't have the expected shape.").details(format!(
+                    "Expected the shape [num_boxes, 4], got {:?}.",
+                    boxes.dims,
+                )),
+            );
+        }
+
+        if scores.dims != [num_boxes] {
+            check = check.register(
+                "Nms",
+                TensorError::new("The scores don't have the expected shape.").details(format!(
+                    "Expected one score per box, the shape [{num_boxes}], got {:?}.",
+                    scores.dims,
+                )),
+            );
+        }
+
+        if let Some(idxs) = idxs {
+            if idxs.dims != [num_boxes] {
+                check = check.register(
+                    "Nms",
+                    TensorError::new("The categories don't have the expected shape.").details(
+                        format!(
+                            "Expected one category per box, the shape [{num_boxes}], got {:?}.",
+                            idxs.dims,
+                        ),
+                    ),
+                );
+            }
+        }
+
+        check
+    }
+
+    /// Checks if the structuring element of a morphological operation isn't empty.
+    pub(crate) fn morphology(ops: &str, kernel: &Shape<2>) -> Self {
+        match kernel.num_elements() > 0 {
+            true => Self::Ok,
+            false => Self::Ok.register(
+                ops,
+                TensorError::new("The structuring element is empty.").details(format!(
+                    "Expected a non-empty structuring element, got the shape {:?}.",
+                    kernel.dims,
+                )),
+            ),
+        }
+    }
+
+    /// Checks if the rates and the filter of a resampling are valid.
+    pub(crate) fn resample(orig_freq: usize, new_freq: usize, options: &ResampleOptions) -> Self {
+        let mut check = Self::Ok;
+
+        if orig_freq == 0 || new_freq == 0 {
+            check = check.register(
+                "Resample",
+                TensorError::new("The rates must be positive.").details(format!(
+                    "Got the original rate {orig_freq} and the new rate {new_freq}.",
+                )),
+            );
+        }
+
+        if options.lowpass_filter_width == 0 || !(options.rolloff > 0.0 && options.rolloff <= 1.0) {
+            check = check.register(
+                "Resample",
+                TensorError::new("The filter is invalid.").details(format!(
+                    "Expected a positive filter width and a rolloff in (0, 1], got {options:?}.",
+                )),
+            );
+        }
+
+        check
+    }
+
+    /// Checks if the channels of the input can be rearranged into spatial blocks.
+    pub(crate) fn pixel_shuffle(shape: &Shape<4>, upscale_factor: usize) -> Self {
+        let [_, channels, _, _] = shape.dims;
+        let block_size = upscale_factor * upscale_factor;
+        let mut check = Self::Ok;
+
+        if upscale_factor == 0 {
+            check = check.register(
+                "Pixel Shuffle",
+                TensorError::new("The upscale factor must be positive."),
+            );
+        } else if channels % block_size != 0 {
+            check = check.register(
+                "Pixel Shuffle",
+                TensorError::new("The channels can't be split into spatial blocks.").details(
+                    format!(
+                        "Expected the channels to be divisible by the squared upscale factor \
+                         {block_size}, got {channels} channels.",
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    /// Checks if the height and width of the input can be split into spatial blocks.
+    pub(crate) fn pixel_unshuffle(shape: &Shape<4>, downscale_factor: usize) -> Self {
+        let [_, _, height, width] = shape.dims;
+        let mut check = Self::Ok;
+
+        if downscale_factor == 0 {
+            check = check.register(
+                "Pixel Unshuffle",
+                TensorError::new("The downscale factor must be positive."),
+            );
+        } else if height % downscale_factor != 0 || width % downscale_factor != 0 {
+            check = check.register(
+                "Pixel Unshuffle",
+                TensorError::new("The height and width can't be split into spatial blocks.")
+                    .details(format!(
+                        "Expected the height and width to be divisible by the downscale factor \
+                         {downscale_factor}, got {height}x{width}.",
+                    )),
+            );
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occurred, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
@@ -979,33 +1399,18 @@ impl TensorCheck {
         lhs: &Shape<D>,
         rhs: &Shape<D>,
     ) -> Self {
-        let mut check = self;
-
-        for i in 0..D {
-            let d_lhs = lhs.dims[i];
-            let d_rhs = rhs.dims[i];
-
-            if d_lhs != d_rhs {
-                let is_broadcast = d_lhs == 1 || d_rhs == 1;
-
-                if is_broadcast {
-                    continue;
-                }
-
-                check = check.register(
-                    ops,
-                    TensorError::new("The provided tensors have incompatible shapes.").details(
-                        format!(
-                            "Incompatible size at dimension '{}' => '{} != {}', which can't be \
-                             broadcasted. Lhs tensor shape {:?}, Rhs tensor shape {:?}.",
-                            i, d_lhs, d_rhs, lhs.dims, rhs.dims,
-                        ),
+        match lhs.broadcast(rhs) {
+            Ok(_) => self,
+            Err(error) => self.register(
+                ops,
+                TensorError::new("The provided tensors have incompatible shapes.").details(
+                    format!(
+                        "{error} Lhs tensor shape {:?}, Rhs tensor shape {:?}.",
+                        lhs.dims, rhs.dims,
                     ),
-                );
-            }
+                ),
+            ),
         }
-
-        check
     }
 
     /// Checks if tensor devices are equal.
@@ -1032,48 +1437,29 @@ impl TensorCheck {
         shape: &Shape<D1>,
         to: &Shape<D2>,
     ) -> Self {
-        let mut check = TensorCheck::Ok;
-        let max_dims = core::cmp::max(D1, D2);
-
-        // Calculate the starting indices for each shape array, ensuring alignment from the right.
-        let start_index_shape = max_dims.saturating_sub(D1);
-        let start_index_to = max_dims.saturating_sub(D2);
-
-        for i in 0..max_dims {
-            // Use 1 as the default dimension size for dimensions beyond the tensor's rank.
-            let d_shape = if i >= start_index_shape {
-                shape.dims[i - start_index_shape]
-            } else {
-                1
-            };
-            let d_to = if i >= start_index_to {
-                to.dims[i - start_index_to]
-            } else {
-                1
-            };
-
-            if d_shape != d_to && d_shape != 1 && d_to != 1 {
-                // Register an incompatibility error.
-                check = check.register(
-                    ops,
-                    TensorError::new(
-                        "The provided tensor can't be broadcasted to the target shape.",
-                    )
-                    .details(format!(
-                        "Incompatible size at dimension '{}' => '{} != {}', which can't be \
-                         broadcasted. Tensor shape {:?}, Target shape {:?}.",
-                        max_dims - i - 1,
-                        d_shape,
-                        d_to,
-                        shape.dims,
-                        to.dims,
-                    )),
-                );
-                break; // Incompatibility found, no need to check further.
-            }
-        }
+        let rank = usize::max(D1, D2);
+        // Use 1 as the size of the dimensions beyond the rank of a shape, aligning them from the
+        // right.
+        let size = |dims: &[usize], dim: usize| match dim + dims.len() >= rank {
+            true => dims[dim + dims.len() - rank],
+            false => 1,
+        };
 
-        check
+        let Some(error) = (0..rank).find_map(|dim| {
+            let sizes = (size(&shape.dims, dim), size(&to.dims, dim));
+            (sizes.0 != sizes.1 && sizes.0 != 1).then_some(BroadcastError { dim, sizes })
+        }) else {
+            return TensorCheck::Ok;
+        };
+
+        TensorCheck::Ok.register(
+            ops,
+            TensorError::new("The provided tensor can't be broadcasted to the target shape.")
+                .details(format!(
+                    "{error} Tensor shape {:?}, Target shape {:?}.",
+                    shape.dims, to.dims,
+                )),
+        )
     }
 }
 
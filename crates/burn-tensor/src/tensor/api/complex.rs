@@ -0,0 +1,509 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use burn_common::reader::Reader;
+use num_complex::Complex as ComplexElem;
+pub use num_complex::{Complex32, Complex64};
+
+use crate::check::TensorCheck;
+use crate::{
+    backend::Backend, check, BasicOps, Bool, Complex, ComplexPrimitive, Element, ElementConversion,
+    Shape, Tensor, TensorData,
+};
+
+/// Complex tensors are stored as a pair of float tensors, holding the real and imaginary parts.
+///
+/// This planar layout only requires float operations, so it is supported by every backend and is
+/// differentiable. Kernels working on interleaved values, where each element is stored as
+/// `[real, imag]` consecutive floats, such as the ones of the cubecl backends, can exchange data
+/// with [into_interleaved](Tensor::into_interleaved) and
+/// [from_interleaved](Tensor::from_interleaved).
+///
+/// Arithmetic operations are prefixed with `complex_`, since their names are already taken by
+/// the operations of numeric tensors.
+impl<B: Backend, const D: usize> Tensor<B, D, Complex> {
+    /// Create a complex tensor from its real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If the parts don't have the same shape.
+    pub fn from_parts(real: Tensor<B, D>, imag: Tensor<B, D>) -> Self {
+        assert_eq!(
+            real.shape(),
+            imag.shape(),
+            "The real and imaginary parts should have the same shape."
+        );
+
+        Self::new(ComplexPrimitive {
+            real: real.into_primitive(),
+            imag: imag.into_primitive(),
+        })
+    }
+
+    /// Create a complex tensor from its real part, with an imaginary part of zero.
+    pub fn from_real(real: Tensor<B, D>) -> Self {
+        let imag = real.zeros_like();
+        Self::from_parts(real, imag)
+    }
+
+    /// Create a complex tensor from the magnitude and the phase, in radians, of its elements.
+    pub fn from_polar(magnitude: Tensor<B, D>, phase: Tensor<B, D>) -> Self {
+        let real = magnitude.clone() * phase.clone().cos();
+        let imag = magnitude * phase.sin();
+
+        Self::from_parts(real, imag)
+    }
+
+    /// Create a complex tensor from the given values and shape.
+    pub fn from_complex<E: Element, S: Into<Shape<D>>>(
+        values: &[ComplexElem<E>],
+        shape: S,
+        device: &B::Device,
+    ) -> Self {
+        let shape = shape.into();
+        let real = values.iter().map(|value| value.re).collect::<Vec<_>>();
+        let imag = values.iter().map(|value| value.im).collect::<Vec<_>>();
+
+        Self::from_parts(
+            Tensor::from_data(
+                TensorData::new(real, shape.clone()).convert::<B::FloatElem>(),
+                device,
+            ),
+            Tensor::from_data(
+                TensorData::new(imag, shape).convert::<B::FloatElem>(),
+                device,
+            ),
+        )
+    }
+
+    /// Returns the real and imaginary parts.
+    pub fn into_parts(self) -> (Tensor<B, D>, Tensor<B, D>) {
+        (
+            Tensor::from_primitive(self.primitive.real),
+            Tensor::from_primitive(self.primitive.imag),
+        )
+    }
+
+    /// Returns the real part.
+    pub fn real(self) -> Tensor<B, D> {
+        Tensor::from_primitive(self.primitive.real)
+    }
+
+    /// Returns the imaginary part.
+    pub fn imag(self) -> Tensor<B, D> {
+        Tensor::from_primitive(self.primitive.imag)
+    }
+
+    /// Returns the values of the tensor.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn to_complex<E: Element>(&self) -> Vec<ComplexElem<E>> {
+        let (real, imag) = self.clone().into_parts();
+        let (real, imag) = (real.into_data(), imag.into_data());
+
+        real.iter::<E>()
+            .zip(imag.iter::<E>())
+            .map(|(re, im)| ComplexElem::new(re, im))
+            .collect()
+    }
+
+    /// Returns the complex conjugate.
+    pub fn conj(self) -> Self {
+        let (real, imag) = self.into_parts();
+        Self::from_parts(real, imag.neg())
+    }
+
+    /// Returns the phase of the elements, in radians within `[-pi, pi]`.
+    pub fn angle(self) -> Tensor<B, D> {
+        let (real, imag) = self.into_parts();
+        imag.atan2(real)
+    }
+
+    /// Returns the magnitude of the elements.
+    pub fn complex_abs(self) -> Tensor<B, D> {
+        let (real, imag) = self.into_parts();
+        (real.powf_scalar(2.0) + imag.powf_scalar(2.0)).sqrt()
+    }
+
+    /// Negates the elements.
+    pub fn complex_neg(self) -> Self {
+        self.map(Tensor::neg)
+    }
+
+    /// Adds two complex tensors, with broadcasting.
+    pub fn complex_add(self, other: Self) -> Self {
+        let (a, b) = self.into_parts();
+        let (c, d) = other.into_parts();
+        Self::from_parts(a + c, b + d)
+    }
+
+    /// Subtracts two complex tensors, with broadcasting.
+    pub fn complex_sub(self, other: Self) -> Self {
+        let (a, b) = self.into_parts();
+        let (c, d) = other.into_parts();
+        Self::from_parts(a - c, b - d)
+    }
+
+    /// Multiplies two complex tensors element-wise, with broadcasting.
+    pub fn complex_mul(self, other: Self) -> Self {
+        let (a, b) = self.into_parts();
+        let (c, d) = other.into_parts();
+
+        // (a + ib)(c + id) = (ac - bd) + i(ad + bc)
+        Self::from_parts(a.clone() * c.clone() - b.clone() * d.clone(), a * d + b * c)
+    }
+
+    /// Divides two complex tensors element-wise, with broadcasting.
+    pub fn complex_div(self, other: Self) -> Self {
+        let (a, b) = self.into_parts();
+        let (c, d) = other.into_parts();
+        let denominator = c.clone().powf_scalar(2.0) + d.clone().powf_scalar(2.0);
+
+        // (a + ib) / (c + id) = ((ac + bd) + i(bc - ad)) / (c^2 + d^2)
+        Self::from_parts(
+            (a.clone() * c.clone() + b.clone() * d.clone()) / denominator.clone(),
+            (b * c - a * d) / denominator,
+        )
+    }
+
+    /// Multiplies the elements by a real tensor, with broadcasting.
+    pub fn mul_real(self, other: Tensor<B, D>) -> Self {
+        self.map(|tensor| tensor * other.clone())
+    }
+
+    /// Multiplies the elements by a real scalar.
+    pub fn complex_mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = other.elem::<f64>();
+        self.map(|tensor| tensor.mul_scalar(other))
+    }
+
+    /// Applies the complex exponential element-wise.
+    pub fn exp(self) -> Self {
+        let (real, imag) = self.into_parts();
+        Self::from_polar(real.exp(), imag)
+    }
+
+    /// Applies the matrix multiplication of the last two dimensions.
+    pub fn matmul(self, other: Self) -> Self {
+        let (a, b) = self.into_parts();
+        let (c, d) = other.into_parts();
+
+        Self::from_parts(
+            a.clone().matmul(c.clone()) - b.clone().matmul(d.clone()),
+            a.matmul(d) + b.matmul(c),
+        )
+    }
+
+    /// Sums all the elements.
+    pub fn complex_sum(self) -> Tensor<B, 1, Complex> {
+        let (real, imag) = self.into_parts();
+        Tensor::from_parts(real.sum(), imag.sum())
+    }
+
+    /// Sums the elements along the given dimension, keeping it with a size of one.
+    pub fn complex_sum_dim(self, dim: usize) -> Self {
+        self.map(|tensor| tensor.sum_dim(dim))
+    }
+
+    /// Computes the mean of all the elements.
+    pub fn complex_mean(self) -> Tensor<B, 1, Complex> {
+        let (real, imag) = self.into_parts();
+        Tensor::from_parts(real.mean(), imag.mean())
+    }
+
+    /// Computes the mean of the elements along the given dimension, keeping it with a size of one.
+    pub fn complex_mean_dim(self, dim: usize) -> Self {
+        self.map(|tensor| tensor.mean_dim(dim))
+    }
+
+    /// Converts the tensor into interleaved floats, adding a last dimension of size 2 holding the
+    /// real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If `D2` isn't equal to `D + 1`.
+    pub fn into_interleaved<const D2: usize>(self) -> Tensor<B, D2> {
+        let (real, imag) = self.into_parts();
+        Tensor::stack(alloc::vec![real, imag], D)
+    }
+
+    /// Create a complex tensor from interleaved floats, where the last dimension of size 2 holds
+    /// the real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If `D2` isn't equal to `D + 1` or the last dimension isn't of size 2.
+    pub fn from_interleaved<const D2: usize>(tensor: Tensor<B, D2>) -> Self {
+        assert_eq!(
+            D2,
+            D + 1,
+            "Interleaved tensors should have one more dimension."
+        );
+        assert_eq!(
+            tensor.dims()[D],
+            2,
+            "The last dimension of interleaved tensors should be of size 2."
+        );
+
+        let mut shape = [0; D];
+        shape.copy_from_slice(&tensor.dims()[..D]);
+        let real = tensor.clone().narrow(D, 0, 1).reshape(shape);
+        let imag = tensor.narrow(D, 1, 1).reshape(shape);
+
+        Self::from_parts(real, imag)
+    }
+
+    /// Detach the real and imaginary parts from the autodiff graph.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn complex_detach(self) -> Self {
+        self.map(|part| part.detach())
+    }
+
+    /// Mark the tensor to keep gradients during the backward pass.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn complex_require_grad(self) -> Self {
+        self.complex_set_require_grad(true)
+    }
+
+    /// Returns true if the tensor requires gradients during the backward pass.
+    pub fn is_complex_require_grad(&self) -> bool {
+        B::float_is_require_grad(&self.primitive.real)
+    }
+
+    /// Mark the tensor as tracked or untracked depending on the require grad argument.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn complex_set_require_grad(self, require_grad: bool) -> Self {
+        self.map(|part| part.set_require_grad(require_grad))
+    }
+
+    fn map<F: Fn(Tensor<B, D>) -> Tensor<B, D>>(self, func: F) -> Self {
+        let (real, imag) = self.into_parts();
+        Self::from_parts(func(real), func(imag))
+    }
+}
+
+/// The data of complex tensors is interleaved: the real and imaginary parts of each element are
+/// consecutive values, so the last dimension of the data is twice the one of the tensor. This
+/// matches the memory layout of [Complex32] and [Complex64] values.
+impl<B: Backend> BasicOps<B> for Complex {
+    type Elem = B::FloatElem;
+
+    fn empty<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
+        ComplexPrimitive {
+            real: B::float_empty(shape.clone(), device),
+            imag: B::float_empty(shape, device),
+        }
+    }
+
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        B::float_shape(&tensor.real)
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2> {
+        ComplexPrimitive {
+            real: B::float_reshape(tensor.real, shape.clone()),
+            imag: B::float_reshape(tensor.imag, shape),
+        }
+    }
+
+    fn transpose<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        map_parts(tensor, B::float_transpose)
+    }
+
+    fn swap_dims<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> Self::Primitive<D> {
+        check!(TensorCheck::swap_dims::<D>(dim1, dim2));
+        map_parts(tensor, |part| B::float_swap_dims(part, dim1, dim2))
+    }
+
+    fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
+        map_parts(tensor, |part| B::float_permute(part, axes))
+    }
+
+    fn flip<const D: usize>(tensor: Self::Primitive<D>, axes: &[usize]) -> Self::Primitive<D> {
+        map_parts(tensor, |part| B::float_flip(part, axes))
+    }
+
+    fn slice<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+    ) -> Self::Primitive<D1> {
+        map_parts(tensor, |part| B::float_slice(part, ranges.clone()))
+    }
+
+    fn slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> Self::Primitive<D1> {
+        map_parts(tensor, |part| {
+            B::float_slice_with_steps(part, ranges.clone(), steps)
+        })
+    }
+
+    fn slice_assign<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        value: Self::Primitive<D1>,
+    ) -> Self::Primitive<D1> {
+        ComplexPrimitive {
+            real: B::float_slice_assign(tensor.real, ranges.clone(), value.real),
+            imag: B::float_slice_assign(tensor.imag, ranges, value.imag),
+        }
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> B::Device {
+        B::float_device(&tensor.real)
+    }
+
+    fn to_device<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &B::Device,
+    ) -> Self::Primitive<D> {
+        map_parts(tensor, |part| B::float_to_device(part, device))
+    }
+
+    fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<TensorData> {
+        let mut shape = B::float_shape(&tensor.real).dims.to_vec();
+        let num_elements = shape.iter().product();
+        shape[D - 1] *= 2;
+
+        // Interleave the parts on the device, so that a single read is needed.
+        let columns = [num_elements, 1];
+        let interleaved = B::float_cat(
+            vec![
+                B::float_reshape(tensor.real, Shape::new(columns)),
+                B::float_reshape(tensor.imag, Shape::new(columns)),
+            ],
+            1,
+        );
+
+        B::float_into_data(interleaved).map(move |mut data| {
+            data.shape = shape;
+            data
+        })
+    }
+
+    fn checksum<const D: usize>(tensor: Self::Primitive<D>) -> Reader<u64> {
+        <Self as BasicOps<B>>::into_data(tensor).map(|data| data.checksum())
+    }
+
+    fn from_data<const D: usize>(mut data: TensorData, device: &B::Device) -> Self::Primitive<D> {
+        assert_eq!(
+            data.shape[D - 1] % 2,
+            0,
+            "The last dimension of complex data should be even, holding the interleaved real and \
+             imaginary parts."
+        );
+
+        let mut dims = [0; D];
+        dims.copy_from_slice(&data.shape);
+        dims[D - 1] /= 2;
+        let num_elements = dims.iter().product();
+        data.shape = vec![num_elements, 2];
+
+        let interleaved = B::float_from_data::<2>(data.convert::<B::FloatElem>(), device);
+        let part = |index: usize| {
+            let column = B::float_slice(interleaved.clone(), [0..num_elements, index..index + 1]);
+            B::float_reshape(column, Shape::new(dims))
+        };
+
+        ComplexPrimitive {
+            real: part(0),
+            imag: part(1),
+        }
+    }
+
+    fn repeat<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        times: usize,
+    ) -> Self::Primitive<D> {
+        map_parts(tensor, |part| B::float_repeat(part, dim, times))
+    }
+
+    fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
+        let (real, imag) = vectors
+            .into_iter()
+            .map(|tensor| (tensor.real, tensor.imag))
+            .unzip();
+
+        ComplexPrimitive {
+            real: B::float_cat(real, dim),
+            imag: B::float_cat(imag, dim),
+        }
+    }
+
+    fn equal<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Tensor<B, D, Bool> {
+        let real = Tensor::<B, D, Bool>::new(B::float_equal(lhs.real, rhs.real));
+        let imag = Tensor::<B, D, Bool>::new(B::float_equal(lhs.imag, rhs.imag));
+
+        (real.int() + imag.int()).equal_elem(2)
+    }
+
+    fn not_equal<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Tensor<B, D, Bool> {
+        Self::equal(lhs, rhs).bool_not()
+    }
+
+    fn any<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, 1, Bool> {
+        nonzero(tensor).any()
+    }
+
+    fn any_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool> {
+        nonzero(tensor).any_dim(dim)
+    }
+
+    fn all<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, 1, Bool> {
+        nonzero(tensor).all()
+    }
+
+    fn all_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool> {
+        nonzero(tensor).all_dim(dim)
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2> {
+        ComplexPrimitive {
+            real: B::float_expand(tensor.real, shape.clone()),
+            imag: B::float_expand(tensor.imag, shape),
+        }
+    }
+}
+
+fn map_parts<B: Backend, const D: usize, F>(
+    tensor: ComplexPrimitive<B, D>,
+    func: F,
+) -> ComplexPrimitive<B, D>
+where
+    F: Fn(B::FloatTensorPrimitive<D>) -> B::FloatTensorPrimitive<D>,
+{
+    ComplexPrimitive {
+        real: func(tensor.real),
+        imag: func(tensor.imag),
+    }
+}
+
+/// Returns which elements have a real or imaginary part different from zero.
+fn nonzero<B: Backend, const D: usize>(tensor: ComplexPrimitive<B, D>) -> Tensor<B, D, Bool> {
+    let real = Tensor::<B, D>::from_primitive(tensor.real).not_equal_elem(0.0);
+    let imag = Tensor::<B, D>::from_primitive(tensor.imag).not_equal_elem(0.0);
+
+    (real.int() + imag.int()).greater_elem(0)
+}
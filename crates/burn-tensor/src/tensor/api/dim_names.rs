@@ -0,0 +1,317 @@
+use crate::{backend::Backend, BasicOps, Element, ElementConversion, Float, Numeric, Tensor};
+
+/// The optional name of each dimension of a tensor.
+pub type DimNames<const D: usize> = [Option<&'static str>; D];
+
+/// A tensor with optional names on its dimensions, such as `["batch", "seq", "dim"]`.
+///
+/// The names are carried through the operations, which can refer to the dimensions by name, and
+/// binary operations check that the dimensions they align have the same names. This turns the
+/// silent broadcasting of mismatched dimensions, e.g. a `[seq, seq]` tensor added to a
+/// `[batch, seq]` one with the same sizes, into a clear error.
+///
+/// Unnamed dimensions align with any dimension. The underlying tensor is available with
+/// [tensor](DimNamedTensor::tensor) for the operations that don't keep the names.
+#[derive(Clone, Debug)]
+pub struct DimNamedTensor<B: Backend, const D: usize, K: BasicOps<B> = Float> {
+    tensor: Tensor<B, D, K>,
+    names: DimNames<D>,
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Name the dimensions of the tensor.
+    ///
+    /// Each name is either a string or an option, `None` leaving the dimension unnamed.
+    ///
+    /// # Panics
+    ///
+    /// If a name is used for more than one dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 3>::ones([2, 8, 16], &device)
+    ///         .with_dim_names(["batch", "seq", "dim"]);
+    ///
+    ///     let output = tensor.sum_dim("seq").permute(["dim", "seq", "batch"]);
+    ///     assert_eq!(output.dims(), [16, 1, 2]);
+    /// }
+    /// ```
+    pub fn with_dim_names<N: Into<Option<&'static str>>>(
+        self,
+        names: [N; D],
+    ) -> DimNamedTensor<B, D, K> {
+        DimNamedTensor::new(self, names.map(Into::into))
+    }
+}
+
+impl<B, const D: usize, K> DimNamedTensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Create a tensor with the given dimension names.
+    ///
+    /// # Panics
+    ///
+    /// If a name is used for more than one dimension.
+    pub fn new(tensor: Tensor<B, D, K>, names: DimNames<D>) -> Self {
+        for (dim, name) in names.iter().enumerate() {
+            if let Some(name) = name {
+                assert!(
+                    !names[dim + 1..].contains(&Some(*name)),
+                    "The dimension name '{name}' is used more than once in {}.",
+                    display(&names)
+                );
+            }
+        }
+
+        Self { tensor, names }
+    }
+
+    /// Returns the dimension names.
+    pub fn names(&self) -> DimNames<D> {
+        self.names
+    }
+
+    /// Returns the dimensions of the tensor.
+    pub fn dims(&self) -> [usize; D] {
+        self.tensor.dims()
+    }
+
+    /// Returns the tensor without the dimension names.
+    pub fn tensor(self) -> Tensor<B, D, K> {
+        self.tensor
+    }
+
+    /// Returns the index of the dimension with the given name.
+    ///
+    /// # Panics
+    ///
+    /// If no dimension has the given name.
+    pub fn dim(&self, name: &str) -> usize {
+        self.names
+            .iter()
+            .position(|n| *n == Some(name))
+            .unwrap_or_else(|| {
+                panic!(
+                    "No dimension is named '{name}' in {}.",
+                    display(&self.names)
+                )
+            })
+    }
+
+    /// Rename the dimensions.
+    pub fn rename<N: Into<Option<&'static str>>>(self, names: [N; D]) -> Self {
+        Self::new(self.tensor, names.map(Into::into))
+    }
+
+    /// Permute the dimensions to follow the given order of names.
+    ///
+    /// # Panics
+    ///
+    /// If the names aren't a permutation of the names of the tensor.
+    pub fn permute(self, names: [&'static str; D]) -> Self {
+        let axes = names.map(|name| self.dim(name) as isize);
+
+        Self::new(self.tensor.permute(axes), names.map(Some))
+    }
+
+    /// Swap the two dimensions with the given names.
+    pub fn swap_dims(self, name1: &str, name2: &str) -> Self {
+        let (dim1, dim2) = (self.dim(name1), self.dim(name2));
+        let mut names = self.names;
+        names.swap(dim1, dim2);
+
+        Self::new(self.tensor.swap_dims(dim1, dim2), names)
+    }
+
+    /// Apply an operation keeping the shape, and so the dimension names, of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the operation changes the number of elements of a dimension, except for reductions
+    /// to a single element.
+    pub fn map<K2, F>(self, func: F) -> DimNamedTensor<B, D, K2>
+    where
+        K2: BasicOps<B>,
+        F: FnOnce(Tensor<B, D, K>) -> Tensor<B, D, K2>,
+    {
+        let dims = self.tensor.dims();
+        let tensor = func(self.tensor);
+
+        for (dim, (before, after)) in dims.iter().zip(tensor.dims()).enumerate() {
+            assert!(
+                *before == after || after == 1,
+                "Mapping a named tensor changed the size of dimension {} from {before} to {after}.",
+                display_name(self.names[dim], dim)
+            );
+        }
+
+        DimNamedTensor::new(tensor, self.names)
+    }
+
+    /// Returns the names of the dimensions aligned by a binary operation with another tensor.
+    ///
+    /// # Panics
+    ///
+    /// If two aligned dimensions have different names.
+    fn align<K2: BasicOps<B>>(&self, other: &DimNamedTensor<B, D, K2>) -> DimNames<D> {
+        let mut names = self.names;
+
+        for (dim, name) in names.iter_mut().enumerate() {
+            match (*name, other.names[dim]) {
+                (Some(lhs), Some(rhs)) if lhs != rhs => panic!(
+                    "Can't align the dimension {dim} named '{lhs}' with the dimension named \
+                     '{rhs}': {} and {} don't match.",
+                    display(&self.names),
+                    display(&other.names)
+                ),
+                (None, rhs) => *name = rhs,
+                _ => (),
+            }
+        }
+
+        names
+    }
+}
+
+impl<B, const D: usize, K> DimNamedTensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Sum the elements along the dimension with the given name, keeping it with a size of one.
+    pub fn sum_dim(self, name: &str) -> Self {
+        let dim = self.dim(name);
+        self.map(|tensor| tensor.sum_dim(dim))
+    }
+
+    /// Average the elements along the dimension with the given name, keeping it with a size of
+    /// one.
+    pub fn mean_dim(self, name: &str) -> Self {
+        let dim = self.dim(name);
+        self.map(|tensor| tensor.mean_dim(dim))
+    }
+
+    /// Take the maximum along the dimension with the given name, keeping it with a size of one.
+    pub fn max_dim(self, name: &str) -> Self {
+        let dim = self.dim(name);
+        self.map(|tensor| tensor.max_dim(dim))
+    }
+
+    /// Multiply the tensor by a scalar.
+    pub fn mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        self.map(|tensor| tensor.mul_scalar(other))
+    }
+
+    /// Add a scalar to the tensor.
+    pub fn add_scalar<E: ElementConversion>(self, other: E) -> Self {
+        self.map(|tensor| tensor.add_scalar(other))
+    }
+}
+
+impl<B: Backend, const D: usize> DimNamedTensor<B, D> {
+    /// Applies the matrix multiplication on the last two dimensions.
+    ///
+    /// The contracted dimensions, i.e. the last one of the left hand side and the second to last
+    /// one of the right hand side, and the batch dimensions must have the same names.
+    pub fn matmul(self, other: Self) -> Self {
+        let lhs_contracted = self.names[D - 1];
+        let rhs_contracted = other.names[D - 2];
+        if let (Some(lhs), Some(rhs)) = (lhs_contracted, rhs_contracted) {
+            assert_eq!(
+                lhs,
+                rhs,
+                "Can't contract the dimension named '{lhs}' with the dimension named '{rhs}': {} \
+                 and {} don't match.",
+                display(&self.names),
+                display(&other.names)
+            );
+        }
+
+        let mut names = self.names;
+        names[D - 1] = other.names[D - 1];
+        for (dim, name) in names.iter_mut().enumerate().take(D - 2) {
+            match (*name, other.names[dim]) {
+                (Some(lhs), Some(rhs)) => assert_eq!(
+                    lhs,
+                    rhs,
+                    "Can't align the batch dimension {dim}: {} and {} don't match.",
+                    display(&self.names),
+                    display(&other.names)
+                ),
+                (None, rhs) => *name = rhs,
+                _ => (),
+            }
+        }
+
+        Self::new(self.tensor.matmul(other.tensor), names)
+    }
+}
+
+macro_rules! binary_op {
+    ($trait:ident, $method:ident) => {
+        impl<B, const D: usize, K> core::ops::$trait<Self> for DimNamedTensor<B, D, K>
+        where
+            B: Backend,
+            K: Numeric<B>,
+            K::Elem: Element,
+        {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                let names = self.align(&other);
+                Self::new(self.tensor.$method(other.tensor), names)
+            }
+        }
+    };
+}
+
+binary_op!(Add, add);
+binary_op!(Sub, sub);
+binary_op!(Mul, mul);
+binary_op!(Div, div);
+
+impl<B, const D: usize, K> core::fmt::Display for DimNamedTensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DimNamedTensor[shape={:?}, names={}]",
+            self.tensor.dims(),
+            display(&self.names)
+        )
+    }
+}
+
+/// Format the names of the dimensions, unnamed dimensions being shown by their index.
+fn display<const D: usize>(names: &DimNames<D>) -> alloc::string::String {
+    let names = names
+        .iter()
+        .enumerate()
+        .map(|(dim, name)| display_name(*name, dim))
+        .collect::<alloc::vec::Vec<_>>();
+
+    alloc::format!("[{}]", names.join(", "))
+}
+
+fn display_name(name: Option<&'static str>, dim: usize) -> alloc::string::String {
+    match name {
+        Some(name) => alloc::format!("'{name}'"),
+        None => alloc::format!("<{dim}>"),
+    }
+}
@@ -0,0 +1,730 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, ops::einsum::Contraction, Tensor};
+
+/// The maximum number of dimensions of the operands and intermediate results of [einsum].
+const MAX_RANK: usize = 6;
+
+/// First label used for the dimensions covered by an ellipsis, in the unicode private use area so
+/// it can't collide with the labels of the equation.
+const ELLIPSIS_LABEL: u32 = 0xE000;
+
+/// First label used for the dimensions of a [Contraction], in a separate private use plane.
+const CONTRACTION_LABEL: u32 = 0xF0000;
+
+/// Operand of [einsum], created from a float tensor of any rank.
+pub struct EinsumOperand<B: Backend> {
+    term: Term<B>,
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D>> for EinsumOperand<B> {
+    fn from(tensor: Tensor<B, D>) -> Self {
+        let shape = tensor.dims().to_vec();
+
+        Self {
+            term: Term {
+                tensor: tensor.reshape(padded(&shape)),
+                labels: Vec::new(),
+                shape,
+            },
+        }
+    }
+}
+
+/// Evaluates the Einstein summation convention on the operands.
+///
+/// The equation lists the labels of the dimensions of each operand, separated by commas, followed
+/// by `->` and the labels of the output. Labels appearing in the inputs but not in the output are
+/// summed over. When the output is omitted, it contains the labels appearing only once, in
+/// alphabetical order. An ellipsis (`...`) stands for the remaining dimensions, which are
+/// broadcast between operands.
+///
+/// Operands are contracted two at a time, choosing at each step the pair producing the smallest
+/// intermediate result. Each contraction is computed by the
+/// [backend](crate::ops::FloatTensorOps::float_contract), with a fused kernel when it supports it,
+/// and otherwise with a matrix multiplication.
+///
+/// # Arguments
+///
+/// * `equation` - The einsum equation, e.g. `"bij,bjk->bik"`.
+/// * `operands` - The operands, one for each input of the equation.
+///
+/// # Panics
+///
+/// If the equation is invalid, doesn't match the operands or the output rank, or if a tensor has
+/// more than 6 dimensions.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{einsum, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let lhs = Tensor::<B, 3>::ones([4, 2, 3], &device);
+///     let rhs = Tensor::<B, 2>::ones([3, 5], &device);
+///
+///     // Batched matrix multiplication with a broadcast right hand side.
+///     let output: Tensor<B, 3> = einsum("bij,jk->bik", [lhs.into(), rhs.into()]);
+///     assert_eq!(output.dims(), [4, 2, 5]);
+/// }
+/// ```
+pub fn einsum<B: Backend, const D: usize, O: IntoIterator<Item = EinsumOperand<B>>>(
+    equation: &str,
+    operands: O,
+) -> Tensor<B, D> {
+    let mut terms: Vec<Term<B>> = operands.into_iter().map(|op| op.term).collect();
+    let ranks: Vec<usize> = terms.iter().map(|term| term.shape.len()).collect();
+    let (inputs, output) = parse_equation(equation, &ranks);
+
+    assert!(
+        D == output.len().max(1),
+        "Einsum output has {} dimensions, expected {D}.",
+        output.len()
+    );
+
+    let mut sizes = BTreeMap::new();
+    for (term, labels) in terms.iter_mut().zip(inputs) {
+        for (label, size) in labels.iter().zip(term.shape.iter()) {
+            let current = sizes.entry(*label).or_insert(*size);
+            assert!(
+                *current == *size || *current == 1 || *size == 1,
+                "Einsum label '{}' has incompatible sizes {current} and {size}.",
+                display_label(*label),
+            );
+            *current = usize::max(*current, *size);
+        }
+        term.labels = labels;
+    }
+
+    let mut terms: Vec<Term<B>> = terms.into_iter().map(Term::diagonal).collect();
+
+    while terms.len() > 1 {
+        let (lhs, rhs) = plan_contraction(&terms, &output, &sizes);
+        let rhs = terms.remove(rhs);
+        let lhs = terms.remove(lhs);
+        let keep = kept_labels(&terms, &output);
+
+        terms.push(lhs.contract(rhs, &keep));
+    }
+
+    terms
+        .pop()
+        .expect("Einsum requires at least one operand.")
+        .sum_labels(|label| !output.contains(&label))
+        .into_tensor(&output)
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Evaluates the Einstein summation convention on the operands.
+    ///
+    /// See [einsum](crate::einsum) for the details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 2>::ones([2, 3], &device);
+    ///     let rhs = Tensor::<B, 2>::ones([3, 4], &device);
+    ///
+    ///     let output = Tensor::<B, 2>::einsum("ij,jk->ik", [lhs.into(), rhs.into()]);
+    ///     assert_eq!(output.dims(), [2, 4]);
+    /// }
+    /// ```
+    pub fn einsum<O: IntoIterator<Item = EinsumOperand<B>>>(equation: &str, operands: O) -> Self {
+        einsum(equation, operands)
+    }
+}
+
+/// Computes the tensor contraction of the given dimensions of both tensors.
+///
+/// Each dimension `dims.0[i]` of the left hand side is summed with the dimension `dims.1[i]` of
+/// the right hand side, which must have the same size. The output has the remaining dimensions of
+/// the left hand side followed by the remaining ones of the right hand side, so contracting the
+/// last dimension of a matrix with the first one of another is a matrix multiplication.
+///
+/// The contraction is computed like a step of [einsum].
+///
+/// # Panics
+///
+/// If the contracted dimensions are out of bounds, repeated or have different sizes, if the
+/// output rank isn't `D1 + D2 - 2 * N`, or if a tensor has more than 6 dimensions.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{tensordot, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let lhs = Tensor::<B, 3>::ones([2, 3, 4], &device);
+///     let rhs = Tensor::<B, 3>::ones([4, 3, 5], &device);
+///
+///     let output: Tensor<B, 2> = tensordot(lhs, rhs, ([1, 2], [1, 0]));
+///     assert_eq!(output.dims(), [2, 5]);
+/// }
+/// ```
+pub fn tensordot<B: Backend, const D1: usize, const D2: usize, const D: usize, const N: usize>(
+    lhs: Tensor<B, D1>,
+    rhs: Tensor<B, D2>,
+    dims: ([usize; N], [usize; N]),
+) -> Tensor<B, D> {
+    let rank = D1 + D2 - 2 * N;
+    assert!(
+        D == rank.max(1),
+        "Tensordot output has {rank} dimensions, expected {D}."
+    );
+
+    let lhs_dims = lhs.dims();
+    let rhs_dims = rhs.dims();
+    let label = |index: usize| char::from_u32('a' as u32 + index as u32).unwrap();
+    let lhs_labels: Vec<char> = (0..D1).map(label).collect();
+    let mut rhs_labels: Vec<char> = (D1..D1 + D2).map(label).collect();
+
+    for (lhs_dim, rhs_dim) in dims.0.into_iter().zip(dims.1) {
+        assert!(
+            lhs_dim < D1 && rhs_dim < D2,
+            "Tensordot can't contract the dimensions {lhs_dim} and {rhs_dim} of tensors with {D1} and {D2} dimensions."
+        );
+        assert_eq!(
+            lhs_dims[lhs_dim], rhs_dims[rhs_dim],
+            "Tensordot contracted dimensions {lhs_dim} and {rhs_dim} have different sizes."
+        );
+        assert!(
+            rhs_labels[rhs_dim] == label(D1 + rhs_dim),
+            "Tensordot dimension {rhs_dim} is contracted more than once."
+        );
+        rhs_labels[rhs_dim] = lhs_labels[lhs_dim];
+    }
+
+    let contracted = &rhs_labels;
+    let output: Vec<char> = lhs_labels
+        .iter()
+        .filter(|label| !contracted.contains(label))
+        .chain(
+            rhs_labels
+                .iter()
+                .filter(|label| !lhs_labels.contains(label)),
+        )
+        .copied()
+        .collect();
+    assert_eq!(
+        output.len(),
+        rank,
+        "Tensordot dimensions of the left hand side are contracted more than once."
+    );
+
+    let term = |operand: EinsumOperand<B>, labels: Vec<char>| Term {
+        labels,
+        ..operand.term
+    };
+    let lhs = term(lhs.into(), lhs_labels);
+    let rhs = term(rhs.into(), rhs_labels);
+
+    lhs.contract(rhs, &output).into_tensor(&output)
+}
+
+impl<B: Backend, const D1: usize> Tensor<B, D1> {
+    /// Computes the tensor contraction of the given dimensions of both tensors.
+    ///
+    /// See [tensordot](crate::tensordot) for the details.
+    pub fn tensordot<const D2: usize, const D: usize, const N: usize>(
+        self,
+        other: Tensor<B, D2>,
+        dims: ([usize; N], [usize; N]),
+    ) -> Tensor<B, D> {
+        tensordot(self, other, dims)
+    }
+}
+
+/// A tensor along with the labels of its dimensions.
+///
+/// The tensor is stored with [MAX_RANK] dimensions, padded with leading dimensions of size 1.
+struct Term<B: Backend> {
+    tensor: Tensor<B, MAX_RANK>,
+    labels: Vec<char>,
+    shape: Vec<usize>,
+}
+
+impl<B: Backend> Term<B> {
+    fn offset(&self) -> usize {
+        MAX_RANK - self.shape.len()
+    }
+
+    fn with_shape(tensor: Tensor<B, MAX_RANK>, labels: Vec<char>, shape: Vec<usize>) -> Self {
+        Self {
+            tensor: tensor.reshape(padded(&shape)),
+            labels,
+            shape,
+        }
+    }
+
+    /// Permute the dimensions to follow the output labels and remove the padding.
+    fn into_tensor<const D: usize>(self, output: &[char]) -> Tensor<B, D> {
+        let term = self.permute(output);
+
+        if output.is_empty() {
+            term.tensor.reshape([1; D])
+        } else {
+            let mut shape = [0; D];
+            shape.copy_from_slice(&term.shape);
+            term.tensor.reshape(shape)
+        }
+    }
+
+    /// Take the diagonal of every label repeated in the term.
+    fn diagonal(mut self) -> Self {
+        while let Some((first, second)) = self.repeated_label() {
+            let size = self.shape[first];
+            assert_eq!(
+                size,
+                self.shape[second],
+                "Einsum label '{}' is repeated with different sizes.",
+                display_label(self.labels[first]),
+            );
+
+            let mut mask_shape = alloc::vec![1; self.shape.len()];
+            mask_shape[first] = size;
+            mask_shape[second] = size;
+            let mask =
+                Tensor::<B, 2>::eye(size, &self.tensor.device()).reshape(padded(&mask_shape));
+            let dim = self.offset() + second;
+            let tensor = (self.tensor * mask).sum_dim(dim);
+
+            self.labels.remove(second);
+            self.shape.remove(second);
+            self = Self::with_shape(tensor, self.labels, self.shape);
+        }
+
+        self
+    }
+
+    fn repeated_label(&self) -> Option<(usize, usize)> {
+        self.labels.iter().enumerate().find_map(|(first, label)| {
+            self.labels[first + 1..]
+                .iter()
+                .position(|other| other == label)
+                .map(|second| (first, first + 1 + second))
+        })
+    }
+
+    /// Sum over the dimensions whose label matches the predicate.
+    fn sum_labels<F: Fn(char) -> bool>(self, predicate: F) -> Self {
+        let offset = self.offset();
+        let mut tensor = self.tensor;
+        let mut labels = Vec::new();
+        let mut shape = Vec::new();
+
+        for (dim, (label, size)) in self.labels.into_iter().zip(self.shape).enumerate() {
+            if predicate(label) {
+                tensor = tensor.sum_dim(offset + dim);
+            } else {
+                labels.push(label);
+                shape.push(size);
+            }
+        }
+
+        Self::with_shape(tensor, labels, shape)
+    }
+
+    /// Permute the dimensions to follow the given label order.
+    fn permute(self, order: &[char]) -> Self {
+        let offset = self.offset();
+        let mut axes = [0; MAX_RANK];
+        for (dim, axis) in axes.iter_mut().enumerate() {
+            *axis = dim as isize;
+        }
+
+        let mut shape = Vec::with_capacity(order.len());
+        for (dim, label) in order.iter().enumerate() {
+            let source = self
+                .labels
+                .iter()
+                .position(|l| l == label)
+                .expect("Label should be in the term");
+            axes[offset + dim] = (offset + source) as isize;
+            shape.push(self.shape[source]);
+        }
+
+        Self {
+            tensor: self.tensor.permute(axes),
+            labels: order.to_vec(),
+            shape,
+        }
+    }
+
+    /// Broadcast the dimensions of the given labels to their full size.
+    fn broadcast(self, labels: &[char], sizes: &BTreeMap<char, usize>) -> Self {
+        let shape: Vec<usize> = self
+            .labels
+            .iter()
+            .zip(self.shape.iter())
+            .map(|(label, size)| match labels.contains(label) {
+                true => sizes[label],
+                false => *size,
+            })
+            .collect();
+
+        if shape == self.shape {
+            return self;
+        }
+
+        Self {
+            tensor: self.tensor.expand(padded(&shape)),
+            labels: self.labels,
+            shape,
+        }
+    }
+
+    /// Contract both terms with the backend, summing the labels that aren't kept.
+    fn contract(self, rhs: Self, keep: &[char]) -> Self {
+        let mut labels = self.labels.clone();
+        for label in rhs.labels.iter() {
+            if !labels.contains(label) {
+                labels.push(*label);
+            }
+        }
+        let size = |label: &char| {
+            let lhs_dim = self.labels.iter().position(|l| l == label);
+            let rhs_dim = rhs.labels.iter().position(|l| l == label);
+            usize::max(
+                lhs_dim.map_or(1, |dim| self.shape[dim]),
+                rhs_dim.map_or(1, |dim| rhs.shape[dim]),
+            )
+        };
+        let mut sizes: Vec<usize> = labels.iter().map(size).collect();
+        let output: Vec<char> = labels
+            .iter()
+            .filter(|label| keep.contains(label))
+            .copied()
+            .collect();
+        let shape: Vec<usize> = output.iter().map(size).collect();
+
+        // The padding dimensions are given distinct labels of size 1.
+        let mut dims = |term_labels: &[char]| {
+            let mut dims: Vec<usize> = (term_labels.len()..MAX_RANK)
+                .map(|_| {
+                    sizes.push(1);
+                    sizes.len() - 1
+                })
+                .collect();
+            dims.extend(
+                term_labels
+                    .iter()
+                    .map(|label| labels.iter().position(|l| l == label).unwrap()),
+            );
+            dims
+        };
+        let lhs_dims = dims(&self.labels);
+        let rhs_dims = dims(&rhs.labels);
+        let output_dims = dims(&output);
+        let contraction = Contraction::new(lhs_dims, rhs_dims, output_dims, sizes);
+
+        let tensor = B::float_contract(
+            self.tensor.into_primitive(),
+            rhs.tensor.into_primitive(),
+            contraction,
+        );
+
+        Self::with_shape(Tensor::from_primitive(tensor), output, shape)
+    }
+
+    /// Contract both terms with a batched matrix multiplication.
+    fn contract_with_matmul(self, rhs: Self, keep: &[char], sizes: &BTreeMap<char, usize>) -> Self {
+        let lhs = self.sum_labels(|label| !rhs.labels.contains(&label) && !keep.contains(&label));
+        let rhs = rhs.sum_labels(|label| !lhs.labels.contains(&label) && !keep.contains(&label));
+
+        let shared: Vec<char> = lhs
+            .labels
+            .iter()
+            .filter(|label| rhs.labels.contains(label))
+            .copied()
+            .collect();
+        let (batch, contracted): (Vec<char>, Vec<char>) =
+            shared.iter().partition(|label| keep.contains(label));
+        let lhs_only: Vec<char> = lhs
+            .labels
+            .iter()
+            .filter(|label| !shared.contains(label))
+            .copied()
+            .collect();
+        let rhs_only: Vec<char> = rhs
+            .labels
+            .iter()
+            .filter(|label| !shared.contains(label))
+            .copied()
+            .collect();
+
+        let lhs = lhs
+            .broadcast(&shared, sizes)
+            .permute(&[batch.as_slice(), &lhs_only, &contracted].concat());
+        let rhs = rhs
+            .broadcast(&shared, sizes)
+            .permute(&[batch.as_slice(), &contracted, &rhs_only].concat());
+
+        let (num_batch, num_lhs_only) = (batch.len(), lhs_only.len());
+        let size = |shape: &[usize]| shape.iter().product::<usize>();
+        let b = size(&lhs.shape[..num_batch]);
+        let m = size(&lhs.shape[num_batch..num_batch + num_lhs_only]);
+        let k = size(&lhs.shape[num_batch + num_lhs_only..]);
+        let n = size(&rhs.shape[num_batch + contracted.len()..]);
+        let shape = [
+            &lhs.shape[..num_batch + num_lhs_only],
+            &rhs.shape[num_batch + contracted.len()..],
+        ]
+        .concat();
+
+        let output = lhs
+            .tensor
+            .reshape([b, m, k])
+            .matmul(rhs.tensor.reshape([b, k, n]));
+
+        let labels = [batch.as_slice(), &lhs_only, &rhs_only].concat();
+
+        Self {
+            tensor: output.reshape(padded(&shape)),
+            labels,
+            shape,
+        }
+    }
+}
+
+/// Computes the contraction with a batched matrix multiplication, as the reference for
+/// [FloatTensorOps::float_contract](crate::ops::FloatTensorOps::float_contract).
+pub(crate) fn contract_with_matmul<B: Backend, const D: usize>(
+    lhs: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+    contraction: &Contraction,
+) -> Tensor<B, D> {
+    let label = |index: &usize| char::from_u32(CONTRACTION_LABEL + *index as u32).unwrap();
+    let sizes: BTreeMap<char, usize> = contraction
+        .sizes
+        .iter()
+        .enumerate()
+        .map(|(index, size)| (label(&index), *size))
+        .collect();
+    let term = |tensor: Tensor<B, D>, dims: &[usize]| {
+        let shape = tensor.dims().to_vec();
+        let labels: Vec<char> = dims.iter().map(label).collect();
+        Term::with_shape(tensor.reshape(padded(&shape)), labels.clone(), shape)
+            .broadcast(&labels, &sizes)
+    };
+    let lhs = term(lhs, &contraction.lhs);
+    let rhs = term(rhs, &contraction.rhs);
+
+    // The output labels missing from both operands have a size of 1, so they are only reshaped.
+    let output: Vec<char> = contraction
+        .output
+        .iter()
+        .map(label)
+        .filter(|label| lhs.labels.contains(label) || rhs.labels.contains(label))
+        .collect();
+
+    lhs.contract_with_matmul(rhs, &output, &sizes)
+        .permute(&output)
+        .tensor
+        .reshape(contraction.output_shape::<D>())
+}
+
+/// Labels that must be kept after a contraction: the ones of the output and the remaining terms.
+fn kept_labels<B: Backend>(terms: &[Term<B>], output: &[char]) -> Vec<char> {
+    terms
+        .iter()
+        .flat_map(|term| term.labels.iter())
+        .chain(output.iter())
+        .copied()
+        .collect()
+}
+
+/// Choose the pair of terms to contract next, greedily minimizing the size of the result.
+fn plan_contraction<B: Backend>(
+    terms: &[Term<B>],
+    output: &[char],
+    sizes: &BTreeMap<char, usize>,
+) -> (usize, usize) {
+    let mut best = (0, 1);
+    let mut best_size = usize::MAX;
+
+    for lhs in 0..terms.len() {
+        for rhs in lhs + 1..terms.len() {
+            let others: Vec<&Term<B>> = terms
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index != lhs && *index != rhs)
+                .map(|(_, term)| term)
+                .collect();
+            let is_kept = |label: &char| {
+                output.contains(label) || others.iter().any(|term| term.labels.contains(label))
+            };
+
+            let mut labels: Vec<char> = terms[lhs].labels.clone();
+            for label in terms[rhs].labels.iter() {
+                if !labels.contains(label) {
+                    labels.push(*label);
+                }
+            }
+            let size = labels
+                .iter()
+                .filter(|label| is_kept(label))
+                .map(|label| sizes[label])
+                .product::<usize>();
+
+            if size < best_size {
+                best = (lhs, rhs);
+                best_size = size;
+            }
+        }
+    }
+
+    best
+}
+
+/// Parse the equation, returning the labels of every input and of the output.
+///
+/// The dimensions covered by an ellipsis are given labels aligned from the right, so that they
+/// broadcast like element-wise operations.
+fn parse_equation(equation: &str, ranks: &[usize]) -> (Vec<Vec<char>>, Vec<char>) {
+    let equation: alloc::string::String = equation.chars().filter(|c| !c.is_whitespace()).collect();
+    let (inputs, output) = match equation.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output)),
+        None => (equation.as_str(), None),
+    };
+
+    let inputs: Vec<(Vec<char>, Option<usize>)> = inputs.split(',').map(parse_term).collect();
+    assert_eq!(
+        inputs.len(),
+        ranks.len(),
+        "Einsum equation has {} inputs, but {} operands were provided.",
+        inputs.len(),
+        ranks.len()
+    );
+
+    let ellipsis_rank = inputs
+        .iter()
+        .zip(ranks)
+        .map(|((labels, ellipsis), rank)| match ellipsis {
+            Some(_) => rank.checked_sub(labels.len()).unwrap_or_else(|| {
+                panic!("Einsum operand with {rank} dimensions has too many labels.")
+            }),
+            None => {
+                assert_eq!(
+                    labels.len(),
+                    *rank,
+                    "Einsum operand with {rank} dimensions has {} labels.",
+                    labels.len()
+                );
+                0
+            }
+        })
+        .max()
+        .unwrap_or(0);
+
+    let ellipsis_labels = |count: usize| -> Vec<char> {
+        (ellipsis_rank - count..ellipsis_rank)
+            .map(|index| char::from_u32(ELLIPSIS_LABEL + index as u32).unwrap())
+            .collect()
+    };
+    let expand = |labels: &[char], ellipsis: Option<usize>, count: usize| -> Vec<char> {
+        match ellipsis {
+            Some(position) => [
+                &labels[..position],
+                ellipsis_labels(count).as_slice(),
+                &labels[position..],
+            ]
+            .concat(),
+            None => labels.to_vec(),
+        }
+    };
+
+    let output = match output {
+        Some(output) => {
+            let (labels, ellipsis) = parse_term(output);
+            expand(&labels, ellipsis, ellipsis_rank)
+        }
+        None => {
+            let mut counts = BTreeMap::new();
+            for label in inputs.iter().flat_map(|(labels, _)| labels.iter()) {
+                *counts.entry(*label).or_insert(0) += 1;
+            }
+            let mut output = ellipsis_labels(ellipsis_rank);
+            output.extend(counts.into_iter().filter(|(_, c)| *c == 1).map(|(l, _)| l));
+            output
+        }
+    };
+
+    let inputs: Vec<Vec<char>> = inputs
+        .into_iter()
+        .zip(ranks)
+        .map(|((labels, ellipsis), rank)| expand(&labels, ellipsis, rank - labels.len()))
+        .collect();
+
+    for (index, label) in output.iter().enumerate() {
+        assert!(
+            !output[index + 1..].contains(label),
+            "Einsum output label '{}' is repeated.",
+            display_label(*label)
+        );
+        assert!(
+            inputs.iter().any(|labels| labels.contains(label)),
+            "Einsum output label '{}' isn't in any input.",
+            display_label(*label)
+        );
+    }
+
+    (inputs, output)
+}
+
+/// Parse the labels of a term, along with the position of the ellipsis if any.
+fn parse_term(term: &str) -> (Vec<char>, Option<usize>) {
+    let mut labels = Vec::new();
+    let mut ellipsis = None;
+    let mut rest = term;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(remaining) = rest.strip_prefix("...") {
+            assert!(
+                ellipsis.is_none(),
+                "Einsum term '{term}' has more than one ellipsis."
+            );
+            ellipsis = Some(labels.len());
+            rest = remaining;
+            continue;
+        }
+
+        assert!(
+            c.is_ascii_alphabetic(),
+            "Einsum term '{term}' has an invalid label '{c}'."
+        );
+        labels.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    (labels, ellipsis)
+}
+
+fn display_label(label: char) -> char {
+    if label as u32 >= ELLIPSIS_LABEL {
+        '.'
+    } else {
+        label
+    }
+}
+
+fn padded(shape: &[usize]) -> [usize; MAX_RANK] {
+    assert!(
+        shape.len() <= MAX_RANK,
+        "Einsum supports up to {MAX_RANK} dimensions, got {}.",
+        shape.len()
+    );
+
+    let mut padded = [1; MAX_RANK];
+    padded[MAX_RANK - shape.len()..].copy_from_slice(shape);
+    padded
+}
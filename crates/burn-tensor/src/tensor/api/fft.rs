@@ -0,0 +1,85 @@
+use crate::{backend::Backend, Complex, Tensor};
+
+/// Fourier transforms along the last dimensions.
+///
+/// The transforms are computed by the [backend](crate::ops::FloatTensorOps::float_fft), natively
+/// in `O(n log(n))` when it supports it, and otherwise as products with the transform matrix.
+impl<B: Backend, const D: usize> Tensor<B, D, Complex> {
+    /// Computes the discrete Fourier transform along the last dimension.
+    pub fn fft(self) -> Self {
+        Self::new(B::float_fft(self.primitive, false))
+    }
+
+    /// Computes the inverse discrete Fourier transform along the last dimension, normalized by
+    /// `1 / n`.
+    pub fn ifft(self) -> Self {
+        Self::new(B::float_fft(self.primitive, true))
+    }
+
+    /// Computes the inverse of [rfft](Tensor::rfft), returning a real signal of size `n`.
+    ///
+    /// The tensor holds the `n / 2 + 1` non-negative frequencies, the negative ones being their
+    /// complex conjugates.
+    ///
+    /// # Panics
+    ///
+    /// If the last dimension isn't of size `n / 2 + 1`.
+    pub fn irfft(self, n: usize) -> Tensor<B, D> {
+        let num_freqs = self.dims()[D - 1];
+        assert_eq!(
+            num_freqs,
+            n / 2 + 1,
+            "irfft expects {} frequencies for a signal of size {n}, got {num_freqs}.",
+            n / 2 + 1
+        );
+
+        // The frequencies above the Nyquist one are the conjugates of the positive ones, in
+        // reverse order.
+        let num_negative = n.saturating_sub(1) / 2;
+        let spectrum = match num_negative {
+            0 => self,
+            _ => {
+                let negative = self
+                    .clone()
+                    .narrow(D - 1, 1, num_negative)
+                    .flip([D as isize - 1])
+                    .conj();
+                Tensor::cat(alloc::vec![self, negative], D - 1)
+            }
+        };
+
+        spectrum.ifft().real()
+    }
+
+    /// Computes the two-dimensional discrete Fourier transform along the last two dimensions.
+    pub fn fft2(self) -> Self {
+        assert!(D >= 2, "fft2 requires at least 2 dimensions.");
+
+        self.fft()
+            .swap_dims(D - 1, D - 2)
+            .fft()
+            .swap_dims(D - 1, D - 2)
+    }
+
+    /// Computes the inverse of [fft2](Tensor::fft2) along the last two dimensions.
+    pub fn ifft2(self) -> Self {
+        assert!(D >= 2, "ifft2 requires at least 2 dimensions.");
+
+        self.ifft()
+            .swap_dims(D - 1, D - 2)
+            .ifft()
+            .swap_dims(D - 1, D - 2)
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the discrete Fourier transform of a real signal along the last dimension.
+    ///
+    /// Only the `n / 2 + 1` non-negative frequencies are returned, the other ones being their
+    /// complex conjugates.
+    pub fn rfft(self) -> Tensor<B, D, Complex> {
+        let n = self.dims()[D - 1];
+
+        Tensor::from_real(self).fft().narrow(D - 1, 0, n / 2 + 1)
+    }
+}
@@ -1,12 +1,14 @@
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::convert::TryInto;
 
 use crate::check;
 use crate::check::TensorCheck;
-use crate::ops::FullPrecisionBackend;
+use crate::ops::{random, FloatGradHook, FloatGradMap, FullPrecisionBackend};
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::{Distribution, Shape, TensorData};
+use crate::ElementConversion;
 use crate::Int;
 use crate::Tensor;
 
@@ -63,6 +65,38 @@ where
         Self::new(B::float_erf(self.primitive))
     }
 
+    /// Applies the inverse of the [error function](https://en.wikipedia.org/wiki/Error_function)
+    /// element wise.
+    ///
+    /// `y = erfinv(x)`, which is infinite at `-1` and `1` and undefined outside of `[-1, 1]`.
+    pub fn erfinv(self) -> Self {
+        Self::new(B::float_erfinv(self.primitive))
+    }
+
+    /// Applies the natural logarithm of the absolute value of the
+    /// [gamma function](https://en.wikipedia.org/wiki/Gamma_function) element wise.
+    ///
+    /// `y = ln|gamma(x)|`
+    pub fn lgamma(self) -> Self {
+        Self::new(B::float_lgamma(self.primitive))
+    }
+
+    /// Applies the [digamma function](https://en.wikipedia.org/wiki/Digamma_function), the
+    /// derivative of [lgamma](Tensor::lgamma), element wise.
+    ///
+    /// `y = psi(x)`
+    pub fn digamma(self) -> Self {
+        Self::new(B::float_digamma(self.primitive))
+    }
+
+    /// Applies the [polygamma function](https://en.wikipedia.org/wiki/Polygamma_function) of
+    /// the given order, the derivative of that order of [digamma](Tensor::digamma), element wise.
+    ///
+    /// `y = psi_n(x)`, where orders above `0` are only defined for positive inputs.
+    pub fn polygamma(self, order: u32) -> Self {
+        Self::new(B::float_polygamma(self.primitive, order))
+    }
+
     /// Applies element wise reciprocal operation.
     pub fn recip(self) -> Self {
         Self::new(B::float_recip(self.primitive))
@@ -88,6 +122,15 @@ where
         Self::new(B::float_tanh(self.primitive))
     }
 
+    /// Applies the four-quadrant arctangent of `self / other` element wise, with broadcasting.
+    ///
+    /// `y = atan2(self, other)`, the angle in radians within `[-pi, pi]` of the point
+    /// `(other, self)`.
+    pub fn atan2(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Atan2", &self, &other));
+        Self::new(B::float_atan2(self.primitive, other.primitive))
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -137,8 +180,140 @@ where
 
     /// Returns a new tensor with the same shape and device as the current tensor filled random
     /// values sampled from the given distribution.
+    ///
+    /// Like [random](Tensor::random), the tensor is seeded with the generator installed on the
+    /// current thread, if any.
     pub fn random_like(&self, distribution: Distribution) -> Self {
-        Tensor::new(B::float_random(self.shape(), distribution, &self.device()))
+        Tensor::random(self.shape(), distribution, &self.device())
+    }
+
+    /// Samples the Gamma distributions with the given concentrations (shapes) and scales.
+    ///
+    /// # Remarks
+    ///
+    /// The samples are reparameterized: they are a differentiable transform of the accepted
+    /// proposals of the rejection sampler of Marsaglia and Tsang, so gradients flow to the
+    /// concentrations and the scales, e.g. to optimize a variational distribution.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let concentration = Tensor::<B, 1>::from_floats([0.5, 2.0, 10.0], &device);
+    ///     let scale = concentration.ones_like();
+    ///
+    ///     let samples = Tensor::random_gamma(concentration, scale);
+    ///     assert_eq!(samples.dims(), [3]);
+    /// }
+    /// ```
+    pub fn random_gamma(concentration: Self, scale: Self) -> Self {
+        random::gamma(concentration, scale)
+    }
+
+    /// Samples the Beta distributions with the given `alpha` and `beta` shape parameters.
+    ///
+    /// # Remarks
+    ///
+    /// The samples are computed from reparameterized samples of the Gamma distributions, see
+    /// [random_gamma](Tensor::random_gamma), so gradients flow to the parameters.
+    pub fn random_beta(alpha: Self, beta: Self) -> Self {
+        random::beta(alpha, beta)
+    }
+
+    /// Samples the Dirichlet distributions with the given concentrations along the last
+    /// dimension.
+    ///
+    /// # Remarks
+    ///
+    /// The samples are reparameterized samples of the Gamma distributions with a unit scale
+    /// normalized along the last dimension, so gradients flow to the concentrations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let concentration =
+    ///         Tensor::<B, 2>::from_floats([[1.0, 1.0, 1.0], [0.1, 5.0, 2.0]], &device);
+    ///
+    ///     // Each row is a probability vector.
+    ///     let probabilities = Tensor::random_dirichlet(concentration);
+    ///     assert_eq!(probabilities.dims(), [2, 3]);
+    /// }
+    /// ```
+    pub fn random_dirichlet(concentration: Self) -> Self {
+        let samples = Self::random_gamma(concentration.clone(), concentration.ones_like());
+
+        samples.clone().div(samples.sum_dim(D - 1))
+    }
+
+    /// Samples the Poisson distributions with the given rates.
+    ///
+    /// # Remarks
+    ///
+    /// The samples are counts, which aren't differentiable with respect to the rates.
+    pub fn random_poisson(rate: Self) -> Self {
+        random::poisson(rate.detach())
+    }
+
+    /// Samples category indices from the unnormalized weights along the last dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - The number of indices to sample for each sequence of weights.
+    /// * `replacement` - If false, a category is sampled at most once, so the number of samples
+    ///   can't exceed the number of categories with a positive weight.
+    ///
+    /// # Returns
+    ///
+    /// The `[..., num_samples]` sampled indices, the category `i` being sampled with a
+    /// probability proportional to its non-negative weight.
+    ///
+    /// # Panics
+    ///
+    /// If a weight is negative or not finite, if the weights of a row sum to zero, or if more
+    /// indices are sampled without replacement than a row has categories with a positive weight.
+    ///
+    /// # Remarks
+    ///
+    /// The weights don't leave the device: backends sample them with a native kernel, or an
+    /// inverse transform of their cumulative sum otherwise, only reading back whether they are
+    /// valid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let logits = Tensor::<B, 2>::from_floats([[0.5, 2.0, -1.0], [1.0, 1.0, 3.0]], &device);
+    ///
+    ///     let tokens = logits.exp().multinomial(1, true);
+    ///     assert_eq!(tokens.dims(), [2, 1]);
+    /// }
+    /// ```
+    pub fn multinomial(self, num_samples: usize, replacement: bool) -> Tensor<B, D, Int> {
+        let num_categories = self.dims()[D - 1];
+        assert!(
+            replacement || num_samples <= num_categories,
+            "Can't sample {num_samples} indices without replacement from {num_categories} \
+             categories."
+        );
+
+        Tensor::new(B::float_multinomial(
+            self.primitive,
+            num_samples,
+            replacement,
+        ))
     }
 
     /// Create a one hot tensor.
@@ -182,6 +357,18 @@ where
         Self::new(B::float_matmul(self.primitive, other.primitive))
     }
 
+    /// Computes the logarithm of the sum of the exponentials of the elements along the given
+    /// dimension.
+    ///
+    /// `y = log(sum(exp(x)))`
+    ///
+    /// The maximum is subtracted before the exponentials and added back after the logarithm, so
+    /// the reduction doesn't overflow, even with half precision elements.
+    pub fn logsumexp(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("LogSumExp", dim));
+        Self::new(B::float_logsumexp_dim(self.primitive, dim))
+    }
+
     /// Calculate the variance along the given dimension.
     pub fn var(self, dim: usize) -> Self {
         stats::var(self, dim)
@@ -245,11 +432,74 @@ where
         Self::new(B::float_set_require_grad(self.primitive, require_grad))
     }
 
+    /// Register a hook called with the gradient of the tensor during the backward pass, e.g. to
+    /// log the gradients of intermediate activations.
+    ///
+    /// Only the gradient flowing through the returned tensor is passed to the hook, so it should
+    /// replace the current tensor in the rest of the computation.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn register_grad_hook<F>(self, hook: F) -> Self
+    where
+        F: Fn(Self) + Send + Sync + 'static,
+    {
+        let hook: FloatGradHook<B, D> = Arc::new(move |grad| hook(Self::new(grad)));
+
+        Self::new(B::float_register_grad_hook(self.primitive, hook))
+    }
+
+    /// Replace the gradient of the tensor during the backward pass with the one returned by the
+    /// given function, called with the gradient, e.g. to reduce the gradient across devices.
+    ///
+    /// Only the gradient flowing through the returned tensor is mapped, so it should replace the
+    /// current tensor in the rest of the computation. The returned gradient must have the same
+    /// shape.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn map_grad<F>(self, map: F) -> Self
+    where
+        F: Fn(Self) -> Self + Send + Sync + 'static,
+    {
+        let map: FloatGradMap<B, D> = Arc::new(move |grad| map(Self::new(grad)).primitive);
+
+        Self::new(B::float_map_grad(self.primitive, map))
+    }
+
     /// Applies the relu function to the tensor.
     pub(crate) fn relu(self) -> Self {
         Self::new(B::relu(self.primitive))
     }
 
+    /// Computes the histogram of the elements with bins of equal width.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins.
+    /// * `min` - The lower end of the range of the bins.
+    /// * `max` - The upper end of the range of the bins. If `min` and `max` are equal, the range
+    ///   spans the minimum and maximum elements of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements falling in each bin, ignoring the elements outside of the range.
+    pub fn histc(self, bins: usize, min: f64, max: f64) -> Tensor<B, 1> {
+        stats::histc(self, bins, min, max)
+    }
+
+    /// Computes the histogram of the elements with the given bin edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - The increasing edges of the bins, the bin `i` spanning `[edges[i], edges[i + 1])`
+    ///   except for the last one, which includes its right edge.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements falling in each bin, ignoring the elements outside of the edges.
+    pub fn histogram(self, edges: Tensor<B, 1>) -> Tensor<B, 1> {
+        stats::histogram(self, edges)
+    }
+
     /// Calculate covaraince matrix between different entries alongside a given dimension.
     ///
     /// # Arguments
@@ -349,4 +599,48 @@ where
             indices.select(dim, k_indices),
         )
     }
+
+    /// Update the given tensor with the value where the bits of the packed mask are set.
+    ///
+    /// This is similar to [mask_fill](Tensor::mask_fill), the mask being packed with
+    /// [pack_bits](Tensor::pack_bits) so it uses 8 times less elements, which backends can read
+    /// without unpacking it.
+    ///
+    /// # Panics
+    ///
+    /// If the mask doesn't have the shape of the tensor packed along its last dimension.
+    pub fn mask_fill_packed<E: ElementConversion>(self, mask: Tensor<B, D, Int>, value: E) -> Self {
+        check!(TensorCheck::packed_bits(
+            "Mask Fill Packed",
+            &mask.shape(),
+            &self.shape()
+        ));
+
+        Tensor::new(B::float_mask_fill_packed(
+            self.primitive,
+            mask.primitive,
+            value.elem(),
+        ))
+    }
+}
+
+impl<B: Backend> Tensor<B, 2> {
+    /// Computes the multidimensional histogram of the `[n, D]` points, with bins of equal width
+    /// along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins along each dimension.
+    /// * `ranges` - The lower and upper ends of the range of the bins along each dimension.
+    ///
+    /// # Returns
+    ///
+    /// The number of points falling in each bin, ignoring the points outside of the ranges.
+    pub fn histogramdd<const D: usize>(
+        self,
+        bins: [usize; D],
+        ranges: [(f64, f64); D],
+    ) -> Tensor<B, D> {
+        stats::histogramdd(self, bins, ranges)
+    }
 }
@@ -1,9 +1,11 @@
-use crate::{backend::Backend, Float, Int, Shape, Tensor, TensorData};
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Bool, Float, Int, Shape, Tensor, TensorData};
 
 use core::ops::Range;
 
 #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
-use crate::{argsort, check, check::TensorCheck, sort, sort_with_indices};
+use crate::{argsort, sort, sort_with_indices};
 
 impl<B> Tensor<B, 1, Int>
 where
@@ -28,6 +30,88 @@ where
     pub fn arange_step(range: Range<i64>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::int_arange_step(range, step, device))
     }
+
+    /// Counts the number of occurrences of each value.
+    ///
+    /// # Arguments
+    ///
+    /// * `minlength` - The minimum number of bins.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of size `max(minlength, max(values) + 1)`, where the element at index `i` is the
+    /// number of occurrences of the value `i`.
+    ///
+    /// # Panics
+    ///
+    /// If a value is negative.
+    ///
+    /// # Remarks
+    ///
+    /// The largest value is read to size the output, so this method is only available for
+    /// non-wasm targets or when the `wasm-sync` feature is enabled.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn bincount(self, minlength: usize) -> Self {
+        let num_bins = self.num_bins(minlength);
+
+        Tensor::new(B::int_bincount(self.primitive, num_bins))
+    }
+
+    /// Sums the weights of the occurrences of each value.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The weight of each element, of the same size as the tensor.
+    /// * `minlength` - The minimum number of bins.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of size `max(minlength, max(values) + 1)`, where the element at index `i` is the
+    /// sum of the weights of the elements equal to `i`.
+    ///
+    /// # Panics
+    ///
+    /// - If the weights don't have the same size as the tensor.
+    /// - If a value is negative.
+    ///
+    /// # Remarks
+    ///
+    /// The largest value is read to size the output, so this method is only available for
+    /// non-wasm targets or when the `wasm-sync` feature is enabled.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn bincount_weighted(self, weights: Tensor<B, 1>, minlength: usize) -> Tensor<B, 1> {
+        assert_eq!(
+            self.dims(),
+            weights.dims(),
+            "The weights should have the same size as the tensor."
+        );
+        let num_bins = self.num_bins(minlength);
+
+        Tensor::new(B::int_bincount_weighted(
+            self.primitive,
+            weights.primitive,
+            num_bins,
+        ))
+    }
+
+    /// Returns the number of bins counting every value, reading the smallest and largest values
+    /// at once.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn num_bins(&self, minlength: usize) -> usize {
+        if self.dims()[0] == 0 {
+            return minlength;
+        }
+
+        let bounds = Tensor::cat([self.clone().min(), self.clone().max()].to_vec(), 0).into_data();
+        let mut bounds = bounds.iter::<i64>();
+        let (min, max) = (bounds.next().unwrap(), bounds.next().unwrap());
+        assert!(
+            min >= 0,
+            "The values to count should be non-negative, got the value {min}."
+        );
+
+        minlength.max(max as usize + 1)
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -71,6 +155,58 @@ where
         Tensor::new(B::int_into_float(self.primitive))
     }
 
+    /// Returns the most frequent value along the given dimension, with its number of occurrences.
+    ///
+    /// When several values occur the same number of times, the smallest one is returned. The
+    /// given dimension of both outputs has a size of 1, like other reductions along a dimension.
+    ///
+    /// # Notes
+    ///
+    /// The occurrences are counted on the device, so the values aren't read on the host.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let votes = Tensor::<B, 2, Int>::from_ints([[2, 1, 2, 0], [3, 1, 1, 3]], &device);
+    ///
+    ///     let (values, counts) = votes.mode(1);
+    ///     println!("{values}");
+    ///     // [[2], [1]]
+    ///     println!("{counts}");
+    ///     // [[2], [2]]
+    /// }
+    /// ```
+    pub fn mode(self, dim: usize) -> (Tensor<B, D, Int>, Tensor<B, D, Int>) {
+        check!(TensorCheck::aggregate_dim::<D>("Mode", dim));
+
+        let (values, counts) = B::int_mode(self.primitive, dim);
+
+        (Tensor::new(values), Tensor::new(counts))
+    }
+
+    /// Unpacks the bitmap created with [pack_bits](Tensor::pack_bits) into a bool tensor with
+    /// the given size for its last dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the last dimension of the bitmap doesn't have the size `ceil(size / 8)`.
+    pub fn unpack_bits(self, size: usize) -> Tensor<B, D, Bool> {
+        let mut shape = self.shape();
+        shape.dims[D - 1] = size;
+        check!(TensorCheck::packed_bits(
+            "Unpack Bits",
+            &self.shape(),
+            &shape
+        ));
+
+        Tensor::new(B::int_unpack_bits(self.primitive, size))
+    }
+
     /// Generates a cartesian grid for the given tensor shape on the specified device.
     /// The generated tensor is of dimension `D2 = D + 1`, where each element at dimension D contains the cartesian grid coordinates for that element.
     ///
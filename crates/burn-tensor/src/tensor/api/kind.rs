@@ -12,6 +12,13 @@ pub struct Int;
 #[derive(Clone, Debug)]
 pub struct Bool;
 
+/// A type-level representation of the kind of a complex tensor.
+///
+/// Complex tensors are stored as two float tensors, holding the real and imaginary parts, so every
+/// backend supports them without complex elements.
+#[derive(Clone, Debug)]
+pub struct Complex;
+
 /// A type-level representation of the kind of a tensor.
 pub trait TensorKind<B: Backend>: Clone + core::fmt::Debug {
     /// The primitive type of the tensor.
@@ -41,3 +48,20 @@ impl<B: Backend> TensorKind<B> for Bool {
         "Bool"
     }
 }
+
+impl<B: Backend> TensorKind<B> for Complex {
+    type Primitive<const D: usize> = ComplexPrimitive<B, D>;
+    fn name() -> &'static str {
+        "Complex"
+    }
+}
+
+/// The primitive of a [complex](Complex) tensor, with the real and imaginary parts stored as float
+/// primitives of the same shape.
+#[derive(Clone, Debug)]
+pub struct ComplexPrimitive<B: Backend, const D: usize> {
+    /// The real part.
+    pub real: B::FloatTensorPrimitive<D>,
+    /// The imaginary part.
+    pub imag: B::FloatTensorPrimitive<D>,
+}
@@ -6,19 +6,33 @@ mod base;
 mod bool;
 mod cartesian_grid;
 mod chunk;
+mod complex;
+mod dim_names;
+mod einsum;
+mod fft;
 mod float;
 mod int;
 mod kind;
 mod narrow;
 mod numeric;
+mod segment;
+mod slice;
 mod sort;
+mod stft;
 
 pub use argwhere::argwhere;
 pub use autodiff::*;
 pub use base::*;
 pub use cartesian_grid::cartesian_grid;
 pub use chunk::chunk;
+pub use complex::*;
+pub use dim_names::{DimNamedTensor, DimNames};
+pub(crate) use einsum::contract_with_matmul;
+pub use einsum::{einsum, tensordot, EinsumOperand};
 pub use kind::*;
 pub use narrow::narrow;
 pub use numeric::*;
+pub use segment::IndexReduction;
+pub use slice::Slice;
 pub use sort::{argsort, sort, sort_with_indices};
+pub use stft::{StftOptions, WindowFunction};
@@ -4,9 +4,12 @@ use crate::alloc::borrow::ToOwned;
 
 use crate::{
     backend::Backend, check, check::TensorCheck, BasicOps, Bool, Distribution, Element,
-    ElementConversion, Float, Int, Shape, Tensor, TensorKind,
+    ElementConversion, Float, Generator, IndexReduction, Int, Shape, Tensor, TensorKind,
 };
 
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use crate::ops::unique::UniqueOutput;
+
 impl<B, const D: usize, K> Tensor<B, D, K>
 where
     B: Backend,
@@ -240,6 +243,63 @@ where
         Self::new(K::mask_fill(self.primitive, mask, value.elem()))
     }
 
+    /// Update the given tensor with the consecutive values of the source where the mask is true.
+    ///
+    /// The `i`-th true element of the mask, in row-major order, is replaced by the `i`-th element
+    /// of the flattened source, so the source must have at least as many elements as there are
+    /// true elements in the mask. The mask is broadcast to the shape of the tensor.
+    ///
+    /// This is similar to [mask_where](Tensor::mask_where), however the values are packed in the
+    /// source instead of being at the positions of the mask, e.g. to write the new entries of a
+    /// cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2>::zeros([2, 3], &device);
+    ///     let mask =
+    ///         Tensor::<B, 2, Bool>::from_bool([[true, false, true], [false, true, false]].into(), &device);
+    ///     let source = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+    ///
+    ///     let output = tensor.masked_scatter(mask, source);
+    ///     println!("{output}");
+    ///     // [[1.0, 0.0, 2.0], [0.0, 3.0, 0.0]]
+    /// }
+    /// ```
+    pub fn masked_scatter<const D2: usize>(
+        self,
+        mask: Tensor<B, D, Bool>,
+        source: Tensor<B, D2, K>,
+    ) -> Self {
+        let shape = self.shape();
+        let mask = mask.broadcast_to(shape.clone());
+        let num_elements = shape.num_elements();
+        let num_values = source.shape().num_elements();
+        if num_values == 0 {
+            return self;
+        }
+
+        // The position in the source of each element is the number of true elements before it.
+        let positions = mask
+            .clone()
+            .int()
+            .reshape([num_elements])
+            .cumsum(0)
+            .sub_scalar(1)
+            .clamp(0, num_values as i64 - 1);
+        let values = source
+            .reshape([num_values])
+            .select(0, positions)
+            .reshape(shape);
+
+        self.mask_where(mask, values)
+    }
+
     /// Gather tensor elements corresponding to the given indices from the specified dim.
     ///
     /// Example using a 3D tensor:
@@ -660,12 +720,34 @@ where
 
     /// Create a random tensor of the given shape on the given device where each element is
     /// sampled from the given distribution.
+    ///
+    /// The tensor is seeded with the next seed of the generator [installed](Generator::install)
+    /// on the current thread, if any, and with the global seed of the backend otherwise.
     pub fn random<S: Into<Shape<D>>>(
         shape: S,
         distribution: Distribution,
         device: &B::Device,
     ) -> Self {
-        Self::new(K::random(shape.into(), distribution, device))
+        match Generator::next_installed_seed() {
+            Some(seed) => Self::new(K::random_seeded(shape.into(), distribution, seed, device)),
+            None => Self::new(K::random(shape.into(), distribution, device)),
+        }
+    }
+
+    /// Create a random tensor of the given shape on the given device where each element is
+    /// sampled from the given distribution, with the next seed of the given generator instead of
+    /// the global seed of the backend.
+    ///
+    /// The same state of the generator always samples the same values, see [Generator].
+    pub fn random_with<S: Into<Shape<D>>>(
+        shape: S,
+        distribution: Distribution,
+        generator: &mut Generator,
+        device: &B::Device,
+    ) -> Self {
+        let seed = generator.next_seed();
+
+        Self::new(K::random_seeded(shape.into(), distribution, seed, device))
     }
 
     /// Sort the elements by value in ascending order along a given dimension.
@@ -746,6 +828,277 @@ where
         )
     }
 
+    /// Returns the cumulative sum of the elements along the given dimension.
+    pub fn cumsum(self, dim: usize) -> Self {
+        self.scan(dim, |accumulated, previous| accumulated + previous)
+    }
+
+    /// Returns the cumulative product of the elements along the given dimension.
+    ///
+    /// # Remarks
+    ///
+    /// The products are accumulated with multiplications, and not with a sum of logarithms, so
+    /// zeros and negative elements are supported, including in the backward pass.
+    pub fn cumprod(self, dim: usize) -> Self {
+        self.scan(dim, |accumulated, previous| accumulated * previous)
+    }
+
+    /// Returns the cumulative maximum of the elements along the given dimension.
+    pub fn cummax(self, dim: usize) -> Self {
+        self.cummax_with_indices(dim).0
+    }
+
+    /// Returns the cumulative maximum of the elements along the given dimension, with the index
+    /// of each maximum along the dimension.
+    ///
+    /// When the maximum is reached multiple times, the index of the last occurrence is returned.
+    pub fn cummax_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        self.scan_with_indices(dim, |accumulated, previous| previous.greater(accumulated))
+    }
+
+    /// Returns the cumulative minimum of the elements along the given dimension.
+    pub fn cummin(self, dim: usize) -> Self {
+        self.cummin_with_indices(dim).0
+    }
+
+    /// Returns the cumulative minimum of the elements along the given dimension, with the index
+    /// of each minimum along the dimension.
+    ///
+    /// When the minimum is reached multiple times, the index of the last occurrence is returned.
+    pub fn cummin_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        self.scan_with_indices(dim, |accumulated, previous| previous.lower(accumulated))
+    }
+
+    /// Computes the n-th order discrete difference along the given dimension.
+    ///
+    /// The first order difference is `output[i] = input[i + 1] - input[i]`, and higher orders are
+    /// computed by applying it recursively.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of times the difference is applied.
+    /// * `dim` - The dimension along which the difference is computed.
+    /// * `prepend` - Values concatenated before the tensor along the dimension.
+    /// * `append` - Values concatenated after the tensor along the dimension.
+    ///
+    /// # Returns
+    ///
+    /// A tensor where the size of the dimension is reduced by `n`, after the concatenation of the
+    /// prepended and appended values, or empty along the dimension if it doesn't have more than
+    /// `n` elements.
+    pub fn diff(self, n: usize, dim: usize, prepend: Option<Self>, append: Option<Self>) -> Self {
+        check!(TensorCheck::dim_ops::<D>("diff", dim));
+
+        let mut tensors = Vec::with_capacity(3);
+        tensors.extend(prepend);
+        tensors.push(self);
+        tensors.extend(append);
+        let mut output = match tensors.len() {
+            1 => tensors.remove(0),
+            _ => Tensor::cat(tensors, dim),
+        };
+
+        let size = output.dims()[dim];
+        if n >= size {
+            let mut dims = output.dims();
+            dims[dim] = 0;
+
+            return Self::empty(dims, &output.device());
+        }
+
+        for order in 0..n {
+            let length = size - order - 1;
+            output = output.clone().narrow(dim, 1, length) - output.narrow(dim, 0, length);
+        }
+
+        output
+    }
+
+    /// Computes an inclusive scan along the dimension with the Hillis-Steele algorithm.
+    ///
+    /// After the step with the offset `2^i`, each element combines the `2^(i + 1)` elements
+    /// ending at its position, so only `log2(n)` element-wise steps are needed and the scan is
+    /// differentiable through the combining operation.
+    fn scan<F: Fn(Self, Self) -> Self>(self, dim: usize, combine: F) -> Self {
+        let n = self.dims()[dim];
+        let mut output = self;
+
+        let mut offset = 1;
+        while offset < n {
+            let (previous, valid) = output.clone().shifted(dim, offset);
+            output = output.clone().mask_where(valid, combine(output, previous));
+            offset *= 2;
+        }
+
+        output
+    }
+
+    /// Computes an inclusive scan along the dimension, where each step replaces the accumulated
+    /// element by the previous one when the predicate holds, keeping track of its index.
+    fn scan_with_indices<F: Fn(Self, Self) -> Tensor<B, D, Bool>>(
+        self,
+        dim: usize,
+        replace: F,
+    ) -> (Self, Tensor<B, D, Int>) {
+        let shape = self.shape();
+        let n = shape.dims[dim];
+        let mut index_shape = [1; D];
+        index_shape[dim] = n;
+
+        let mut output = self;
+        let mut indices = Tensor::<B, 1, Int>::arange(0..n as i64, &output.device())
+            .reshape(index_shape)
+            .expand(shape);
+
+        let mut offset = 1;
+        while offset < n {
+            let (previous, valid) = output.clone().shifted(dim, offset);
+            let (previous_indices, _) = indices.clone().shifted(dim, offset);
+
+            let replaced = (replace(output.clone(), previous.clone()).int() * valid.int()).bool();
+            output = output.mask_where(replaced.clone(), previous);
+            indices = indices.mask_where(replaced, previous_indices);
+            offset *= 2;
+        }
+
+        (output, indices)
+    }
+
+    /// Shift the elements along the dimension by the given offset.
+    ///
+    /// Returns the shifted tensor and a mask of the positions that have a previous element, the
+    /// first positions being filled with the first element.
+    pub(crate) fn shifted(self, dim: usize, offset: usize) -> (Self, Tensor<B, D, Bool>) {
+        let dims = self.dims();
+        let n = dims[dim];
+        let positions = Tensor::<B, 1, Int>::arange(0..n as i64, &self.device());
+
+        let shifted = self.select(
+            dim,
+            positions.clone().sub_scalar(offset as i64).clamp_min(0),
+        );
+
+        let mut mask_shape = [1; D];
+        mask_shape[dim] = n;
+        let valid = positions
+            .greater_equal_elem(offset as i64)
+            .reshape(mask_shape)
+            .expand(dims);
+
+        (shifted, valid)
+    }
+
+    /// Finds the indices where the values should be inserted in the sorted sequences along the
+    /// last dimension to keep them sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The `[..., k]` values to search in the `[..., n]` sorted sequences of the
+    ///   tensor. The batch dimensions of the sequences are broadcast to the ones of the values.
+    /// * `right` - If false, returns the first suitable index `i`, such that
+    ///   `sequence[i - 1] < value <= sequence[i]`. Otherwise, returns the last one, such that
+    ///   `sequence[i - 1] <= value < sequence[i]`.
+    ///
+    /// # Returns
+    ///
+    /// The `[..., k]` insertion indices, between `0` and `n`.
+    ///
+    /// # Remarks
+    ///
+    /// The binary search is expressed with tensor operations, so the values don't leave the
+    /// device.
+    pub fn searchsorted(self, values: Self, right: bool) -> Tensor<B, D, Int> {
+        let n = self.dims()[D - 1];
+        let mut dims = values.dims();
+        dims[D - 1] = n;
+
+        let sequences = self.expand(dims);
+        let device = values.device();
+        let mut low = Tensor::<B, D, Int>::zeros(values.shape(), &device);
+        let mut high = Tensor::<B, D, Int>::full(values.shape(), n as i64, &device);
+
+        // Each step halves the candidate range, which initially has n + 1 indices.
+        let steps = usize::BITS - n.leading_zeros();
+        for _ in 0..steps {
+            let active = low.clone().lower(high.clone()).int();
+            let middle = (low.clone() + high.clone()).div_scalar(2);
+            let pivots = sequences
+                .clone()
+                .gather(D - 1, middle.clone().clamp_max(n as i64 - 1));
+
+            let after = match right {
+                false => pivots.lower(values.clone()),
+                true => pivots.lower_equal(values.clone()),
+            }
+            .int();
+
+            let move_low = after.clone() * active.clone();
+            let move_high = active - move_low.clone();
+            low = low.mask_where(move_low.bool(), middle.clone().add_scalar(1));
+            high = high.mask_where(move_high.bool(), middle);
+        }
+
+        low
+    }
+
+    /// Returns the sorted unique elements of the flattened tensor.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unique(self) -> Tensor<B, 1, K> {
+        self.unique_runs(false).0
+    }
+
+    /// Returns the sorted unique elements of the flattened tensor, with the inverse indices and
+    /// the counts.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - The unique elements.
+    /// - The inverse indices, of the same shape as the tensor, giving the index of each element
+    ///   in the unique elements.
+    /// - The number of occurrences of each unique element.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unique_with_inverse_and_counts(
+        self,
+    ) -> (Tensor<B, 1, K>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        self.unique_runs(false)
+    }
+
+    /// Returns the elements of the flattened tensor, keeping only the first element of each run
+    /// of consecutive equal elements.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unique_consecutive(self) -> Tensor<B, 1, K> {
+        self.unique_runs(true).0
+    }
+
+    /// Returns the elements of the flattened tensor, keeping only the first element of each run
+    /// of consecutive equal elements, with the inverse indices and the counts.
+    ///
+    /// See [unique_with_inverse_and_counts](Tensor::unique_with_inverse_and_counts) for the
+    /// returned values.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unique_consecutive_with_inverse_and_counts(
+        self,
+    ) -> (Tensor<B, 1, K>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        self.unique_runs(true)
+    }
+
+    /// Collapse the runs of equal elements of the flattened tensor, sorted first unless only the
+    /// consecutive runs are collapsed.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn unique_runs(
+        self,
+        consecutive: bool,
+    ) -> (Tensor<B, 1, K>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        let (values, inverse, counts) = K::unique(self.primitive, consecutive);
+
+        (
+            Tensor::new(values),
+            Tensor::new(inverse),
+            Tensor::new(counts),
+        )
+    }
+
     /// Pad the tensor with the given value on the last two dimensions.
     ///
     /// # Arguments
@@ -2008,6 +2361,34 @@ where
         device: &B::Device,
     ) -> Self::Primitive<D>;
 
+    /// Creates a new tensor with random values sampled with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the output tensor.
+    /// * `distribution` - The distribution used to sample.
+    /// * `seed` - The seed of the random number generator.
+    /// * `device` - The device to use.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::random_with](Tensor::random_with) function,
+    /// which is more high-level and designed for public use.
+    fn random_seeded<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        seed: u64,
+        device: &B::Device,
+    ) -> Self::Primitive<D>;
+
     /// Sort the elements of the input `tensor` by value along a given dimension.
     ///
     /// This sort is unstable (i.e., may reorder equal elements).
@@ -2093,6 +2474,66 @@ where
         dim: usize,
         descending: bool,
     ) -> <Int as TensorKind<B>>::Primitive<D>;
+
+    /// Collapses the runs of equal elements of the flattened tensor, sorting the elements first
+    /// unless only the consecutive runs are collapsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `consecutive` - Whether only the consecutive runs of the unsorted elements are collapsed.
+    ///
+    /// # Returns
+    ///
+    /// The first element of each run, the run of each element and the number of elements of each
+    /// run.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::unique_with_inverse_and_counts](Tensor::unique_with_inverse_and_counts)
+    /// function, which is more high-level and designed for public use.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn unique<const D: usize>(
+        tensor: Self::Primitive<D>,
+        consecutive: bool,
+    ) -> UniqueOutput<B, Self, D>;
+
+    /// Sums the slices of the first dimension belonging to the same segment.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::segment_sum](Tensor::segment_sum) function,
+    /// which is more high-level and designed for public use.
+    fn segment_sum<const D: usize>(
+        tensor: Self::Primitive<D>,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+    ) -> Self::Primitive<D>;
+
+    /// Reduces the slices of the first dimension belonging to the same segment, the segment ids
+    /// being sorted.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::segment_reduce_sorted](Tensor::segment_reduce_sorted)
+    /// function, which is more high-level and designed for public use.
+    fn segment_reduce_sorted<const D: usize>(
+        tensor: Self::Primitive<D>,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> Self::Primitive<D>;
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -2407,6 +2848,15 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_random(shape, distribution, device)
     }
 
+    fn random_seeded<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        seed: u64,
+        device: &<B as Backend>::Device,
+    ) -> Self::Primitive<D> {
+        B::int_random_seeded(shape, distribution, seed, device)
+    }
+
     fn sign<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
         B::int_sign(tensor)
     }
@@ -2437,6 +2887,31 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> <Int as TensorKind<B>>::Primitive<D> {
         B::int_argsort(tensor, dim, descending)
     }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn unique<const D: usize>(
+        tensor: Self::Primitive<D>,
+        consecutive: bool,
+    ) -> UniqueOutput<B, Self, D> {
+        B::int_unique(tensor, consecutive)
+    }
+
+    fn segment_sum<const D: usize>(
+        tensor: Self::Primitive<D>,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+    ) -> Self::Primitive<D> {
+        B::int_segment_sum(tensor, segment_ids.primitive, num_segments)
+    }
+
+    fn segment_reduce_sorted<const D: usize>(
+        tensor: Self::Primitive<D>,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> Self::Primitive<D> {
+        B::int_segment_reduce_sorted(tensor, segment_ids.primitive, num_segments, reduction)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -2754,6 +3229,15 @@ impl<B: Backend> Numeric<B> for Float {
         B::float_random(shape, distribution, device)
     }
 
+    fn random_seeded<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        seed: u64,
+        device: &<B as Backend>::Device,
+    ) -> Self::Primitive<D> {
+        B::float_random_seeded(shape, distribution, seed, device)
+    }
+
     fn sign<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
         B::float_sign(tensor)
     }
@@ -2784,6 +3268,31 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> <Int as TensorKind<B>>::Primitive<D> {
         B::float_argsort(tensor, dim, descending)
     }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn unique<const D: usize>(
+        tensor: Self::Primitive<D>,
+        consecutive: bool,
+    ) -> UniqueOutput<B, Self, D> {
+        B::float_unique(tensor, consecutive)
+    }
+
+    fn segment_sum<const D: usize>(
+        tensor: Self::Primitive<D>,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+    ) -> Self::Primitive<D> {
+        B::float_segment_sum(tensor, segment_ids.primitive, num_segments)
+    }
+
+    fn segment_reduce_sorted<const D: usize>(
+        tensor: Self::Primitive<D>,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> Self::Primitive<D> {
+        B::float_segment_reduce_sorted(tensor, segment_ids.primitive, num_segments, reduction)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
@@ -0,0 +1,255 @@
+use crate::{backend::Backend, Element, Int, Numeric, Tensor};
+
+/// The reduction of the values assigned to the same index by
+/// [index_reduce](Tensor::index_reduce), or to the same segment by
+/// [segment_reduce_sorted](Tensor::segment_reduce_sorted).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexReduction {
+    /// Sum of the values.
+    Sum,
+    /// Product of the values.
+    Prod,
+    /// Mean of the values, truncated for integers.
+    Mean,
+    /// Maximum of the values.
+    Amax,
+    /// Minimum of the values.
+    Amin,
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Sums the slices of the first dimension belonging to the same segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment_ids` - The segment of each of the `n` slices of the first dimension, between
+    ///   `0` and `num_segments - 1`. The ids don't need to be sorted.
+    /// * `num_segments` - The number of segments.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `num_segments` slices on the first dimension, where empty segments are
+    /// filled with zeros.
+    ///
+    /// # Remarks
+    ///
+    /// The slices are added atomically by the backends supporting it, so the order of the
+    /// additions, and thus the rounding of floating point sums, may change between runs. The
+    /// sums of [segment_reduce_sorted](Tensor::segment_reduce_sorted) are deterministic.
+    pub fn segment_sum(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        check_segments(&self.dims(), &segment_ids);
+
+        Self::new(K::segment_sum(self.primitive, segment_ids, num_segments))
+    }
+
+    /// Reduces the slices of the first dimension belonging to the same segment, the segment ids
+    /// being sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment_ids` - The segment of each of the `n` slices of the first dimension, between
+    ///   `0` and `num_segments - 1`, in increasing order.
+    /// * `num_segments` - The number of segments.
+    /// * `reduction` - The reduction of the slices of each segment.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `num_segments` slices on the first dimension, where empty segments are
+    /// filled with zeros.
+    ///
+    /// # Remarks
+    ///
+    /// Each segment is reduced in the order of its slices, so the result is deterministic on every
+    /// backend. The result is unspecified if the segment ids aren't sorted.
+    pub fn segment_reduce_sorted(
+        self,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> Self {
+        check_segments(&self.dims(), &segment_ids);
+
+        Self::new(K::segment_reduce_sorted(
+            self.primitive,
+            segment_ids,
+            num_segments,
+            reduction,
+        ))
+    }
+
+    /// Takes the maximum of the slices of the first dimension belonging to the same segment.
+    ///
+    /// See [segment_sum](Tensor::segment_sum) for the arguments, empty segments being filled
+    /// with zeros.
+    ///
+    /// # Remarks
+    ///
+    /// The slices are sorted by segment and reduced with
+    /// [segment_reduce_sorted](Tensor::segment_reduce_sorted), so the result is deterministic.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn segment_max(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        self.sort_segments(segment_ids, num_segments, IndexReduction::Amax)
+    }
+
+    /// Takes the minimum of the slices of the first dimension belonging to the same segment.
+    ///
+    /// See [segment_max](Tensor::segment_max) for more details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn segment_min(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        self.sort_segments(segment_ids, num_segments, IndexReduction::Amin)
+    }
+
+    /// Multiplies the slices of the first dimension belonging to the same segment.
+    ///
+    /// See [segment_sum](Tensor::segment_sum) for the arguments, empty segments being filled
+    /// with zeros.
+    ///
+    /// # Remarks
+    ///
+    /// The slices are sorted by segment and reduced like [segment_max](Tensor::segment_max).
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn segment_prod(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        self.sort_segments(segment_ids, num_segments, IndexReduction::Prod)
+    }
+
+    /// Reduces the values along the given dimension into the slices of the tensor selected by the
+    /// indices, leaving the other slices unchanged.
+    ///
+    /// Example using a 3D tensor with the sum reduction:
+    ///
+    /// `input[indices[i], j, k] += values[i, j, k]; // dim = 0`
+    /// `input[i, indices[j], k] += values[i, j, k]; // dim = 1`
+    /// `input[i, j, indices[k]] += values[i, j, k]; // dim = 2`
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension along which the values are reduced.
+    /// * `indices` - The index in the tensor of each slice of the values along the dimension.
+    ///   Indices can be repeated, their values being reduced together.
+    /// * `values` - The values, with the shape of the tensor except for the dimension.
+    /// * `reduction` - The reduction of the values with the same index.
+    /// * `include_self` - Whether the selected slices of the tensor are included in the
+    ///   reduction, or replaced by the reduction of the values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{IndexReduction, Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2>::zeros([3, 2], &device);
+    ///     let indices = Tensor::<B, 1, Int>::from_ints([0, 2, 0], &device);
+    ///     let values = Tensor::<B, 2>::from_floats([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]], &device);
+    ///
+    ///     let output = tensor.index_reduce(0, indices, values, IndexReduction::Amax, false);
+    ///     println!("{output}");
+    ///     // [[3.0, 6.0], [0.0, 0.0], [2.0, 5.0]]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn index_reduce(
+        self,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self,
+        reduction: IndexReduction,
+        include_self: bool,
+    ) -> Self {
+        let dims = self.dims();
+        let values_dims = values.dims();
+        let [n] = indices.dims();
+        assert!(
+            dim < D
+                && n == values_dims[dim]
+                && (0..D).all(|i| i == dim || dims[i] == values_dims[i]),
+            "Index reduce expects values with the shape {dims:?} except for the dimension {dim}, \
+             with an index for each of their slices, got the shape {values_dims:?} and {n} indices."
+        );
+
+        let size = dims[dim];
+        let device = self.device();
+        let values = values.swap_dims(0, dim);
+        let reduced = match reduction {
+            IndexReduction::Sum | IndexReduction::Mean => values.segment_sum(indices.clone(), size),
+            IndexReduction::Prod => values.segment_prod(indices.clone(), size),
+            IndexReduction::Amax => values.segment_max(indices.clone(), size),
+            IndexReduction::Amin => values.segment_min(indices.clone(), size),
+        }
+        .swap_dims(0, dim);
+
+        // The number of values reduced in each slice of the tensor.
+        let mut counts_shape = [1; D];
+        counts_shape[dim] = size;
+        let counts = Tensor::<B, 1, K>::zeros([size], &device)
+            .select_assign(0, indices, Tensor::ones([n], &device))
+            .reshape(counts_shape)
+            .expand(dims);
+        let selected = counts.clone().greater_elem(0);
+
+        let output = match (reduction, include_self) {
+            (IndexReduction::Sum, true) => self.clone().add(reduced),
+            (IndexReduction::Prod, true) => self.clone().mul(reduced),
+            (IndexReduction::Amax, true) => self.clone().max_pair(reduced),
+            (IndexReduction::Amin, true) => self.clone().min_pair(reduced),
+            (IndexReduction::Mean, true) => self.clone().add(reduced).div(counts.add_scalar(1)),
+            (IndexReduction::Mean, false) => reduced.div(counts.clamp_min(1)),
+            (_, false) => reduced,
+        };
+
+        self.mask_where(selected, output)
+    }
+
+    /// Reduce the segments after sorting the slices by segment.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn sort_segments(
+        self,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> Self {
+        check_segments(&self.dims(), &segment_ids);
+
+        let (segment_ids, order) = segment_ids.sort_with_indices(0);
+        self.select(0, order)
+            .segment_reduce_sorted(segment_ids, num_segments, reduction)
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Averages the slices of the first dimension belonging to the same segment.
+    ///
+    /// See [segment_sum](Tensor::segment_sum) for the arguments, empty segments being filled
+    /// with zeros.
+    pub fn segment_mean(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        let mut counts_shape = [1; D];
+        counts_shape[0] = num_segments;
+
+        // The number of segments is given, so the ids are counted without reading the largest one.
+        let counts = Tensor::<B, 1, Int>::new(B::int_bincount(
+            segment_ids.clone().into_primitive(),
+            num_segments,
+        ))
+        .clamp_min(1)
+        .float()
+        .reshape(counts_shape);
+
+        self.segment_sum(segment_ids, num_segments) / counts
+    }
+}
+
+/// Check that there is a segment id for each slice of the first dimension.
+fn check_segments<B: Backend, const D: usize>(dims: &[usize; D], segment_ids: &Tensor<B, 1, Int>) {
+    let [n] = segment_ids.dims();
+    assert_eq!(
+        n, dims[0],
+        "Segment reductions expect a segment id for each of the {} slices of the first dimension, got {n}.",
+        dims[0]
+    );
+}
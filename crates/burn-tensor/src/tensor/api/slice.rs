@@ -0,0 +1,135 @@
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo};
+
+/// A range of indices along a dimension, selected with a step, like `start:end:step` in NumPy.
+///
+/// Negative bounds are counted from the end of the dimension. The selection begins at `start`
+/// and moves by `step` until it reaches `end`, which is excluded. With a negative step, `end` is
+/// thus a lower bound, and `None` stands for the position before the first index, so
+/// `Slice::new(-1, None, -1)` reverses a dimension like `[::-1]` in NumPy.
+///
+/// Slices are created from their bounds with [Slice::new], or from the ranges of indices,
+/// e.g. `Slice::from(1..).with_step(2)`, where a negative step iterates the range in reverse
+/// starting from its last index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Slice {
+    /// The first selected index.
+    pub start: isize,
+    /// The bound where the selection stops, which is excluded, or `None` for the end of the
+    /// dimension in the direction of the step.
+    pub end: Option<isize>,
+    /// The step between the selected indices.
+    pub step: isize,
+}
+
+impl Slice {
+    /// Create a slice from its bounds and step.
+    ///
+    /// # Panics
+    ///
+    /// If the step is zero.
+    pub fn new(start: isize, end: Option<isize>, step: isize) -> Self {
+        assert!(step != 0, "The step of a slice can't be zero.");
+
+        Self { start, end, step }
+    }
+
+    /// Returns the slice of the same range of indices with the given step, iterating the range
+    /// in reverse starting from its last index when the step is negative.
+    ///
+    /// # Panics
+    ///
+    /// If the step is zero.
+    pub fn with_step(self, step: isize) -> Self {
+        // Going from one direction to the other, the first index becomes the bound just past the
+        // last one and conversely.
+        let (start, end) = match (self.step > 0, step > 0) {
+            (true, false) => (
+                match self.end {
+                    Some(0) => return Self::new(0, Some(0), step),
+                    Some(end) => end - 1,
+                    None => -1,
+                },
+                match self.start {
+                    0 => None,
+                    start => Some(start - 1),
+                },
+            ),
+            (false, true) => (
+                match self.end {
+                    Some(-1) => return Self::new(0, Some(0), step),
+                    Some(end) => end + 1,
+                    None => 0,
+                },
+                match self.start {
+                    -1 => None,
+                    start => Some(start + 1),
+                },
+            ),
+            _ => (self.start, self.end),
+        };
+
+        Self::new(start, end, step)
+    }
+
+    /// Returns the range of the indices selected by the slice along a dimension of the given
+    /// size, which are iterated from its last index when the step is negative.
+    pub fn to_range(&self, size: usize) -> Range<usize> {
+        let index = |value: isize| match value < 0 {
+            true => size as isize + value,
+            false => value,
+        };
+
+        match self.step > 0 {
+            true => {
+                let start = index(self.start).max(0) as usize;
+                let end = self.end.map(|end| index(end).max(0) as usize);
+
+                start..end.unwrap_or(size)
+            }
+            false => {
+                // The exclusive lower bound, where -1 is the position before the first index.
+                let end = self.end.map(|end| index(end).max(-1)).unwrap_or(-1);
+                let start = index(self.start).max(-1);
+
+                (end + 1) as usize..(start + 1) as usize
+            }
+        }
+    }
+
+    /// Returns the number of indices selected by the slice along a dimension of the given size.
+    pub fn output_size(&self, size: usize) -> usize {
+        let range = self.to_range(size);
+
+        range.len().div_ceil(self.step.unsigned_abs())
+    }
+}
+
+impl From<Range<usize>> for Slice {
+    fn from(range: Range<usize>) -> Self {
+        Self::new(range.start as isize, Some(range.end as isize), 1)
+    }
+}
+
+impl From<RangeInclusive<usize>> for Slice {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        Self::new(*range.start() as isize, Some(*range.end() as isize + 1), 1)
+    }
+}
+
+impl From<RangeFrom<usize>> for Slice {
+    fn from(range: RangeFrom<usize>) -> Self {
+        Self::new(range.start as isize, None, 1)
+    }
+}
+
+impl From<RangeTo<usize>> for Slice {
+    fn from(range: RangeTo<usize>) -> Self {
+        Self::new(0, Some(range.end as isize), 1)
+    }
+}
+
+impl From<RangeFull> for Slice {
+    fn from(_: RangeFull) -> Self {
+        Self::new(0, None, 1)
+    }
+}
@@ -0,0 +1,242 @@
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{backend::Backend, Int, Tensor, TensorData};
+
+/// Window function applied to each frame of a [short-time Fourier transform](Tensor::stft).
+///
+/// The windows are periodic, as expected by spectral analysis: a window of size `n` is the first
+/// `n` values of the symmetric window of size `n + 1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowFunction {
+    /// Every value is one.
+    Rectangular,
+    /// The Hann window, `0.5 - 0.5 cos(2πi/n)`.
+    Hann,
+    /// The Hamming window, `0.54 - 0.46 cos(2πi/n)`.
+    Hamming,
+    /// The Blackman window, `0.42 - 0.5 cos(2πi/n) + 0.08 cos(4πi/n)`.
+    Blackman,
+}
+
+impl WindowFunction {
+    /// Returns the values of the window of the given size.
+    pub fn values(&self, size: usize) -> Vec<f32> {
+        (0..size)
+            .map(|i| {
+                let angle = 2.0 * PI * i as f64 / size as f64;
+
+                let value = match self {
+                    Self::Rectangular => 1.0,
+                    Self::Hann => 0.5 - 0.5 * angle.cos(),
+                    Self::Hamming => 0.54 - 0.46 * angle.cos(),
+                    Self::Blackman => 0.42 - 0.5 * angle.cos() + 0.08 * (2.0 * angle).cos(),
+                };
+
+                value as f32
+            })
+            .collect()
+    }
+
+    /// Creates a tensor with the values of the window of the given size.
+    pub fn to_tensor<B: Backend>(&self, size: usize, device: &B::Device) -> Tensor<B, 1> {
+        Tensor::from_data(
+            TensorData::new(self.values(size), [size]).convert::<B::FloatElem>(),
+            device,
+        )
+    }
+}
+
+/// Options of the [short-time Fourier transform](Tensor::stft) and its
+/// [inverse](Tensor::istft).
+#[derive(new, Clone, Debug, PartialEq, Eq)]
+pub struct StftOptions {
+    /// The size of each frame, whose Fourier transform has `n_fft / 2 + 1` frequencies.
+    pub n_fft: usize,
+    /// The distance between the starts of consecutive frames.
+    pub hop_length: usize,
+    /// The window applied to each frame.
+    pub window: WindowFunction,
+    /// If true, the signal is padded with its reflection by `n_fft / 2` on both sides, so that
+    /// the frame `t` is centered on the sample `t * hop_length`.
+    pub center: bool,
+}
+
+impl<B: Backend> Tensor<B, 2> {
+    /// Computes the short-time Fourier transform of a batch of real signals, the discrete Fourier
+    /// transform of overlapping windowed frames.
+    ///
+    /// # Shapes
+    ///
+    /// self: `[batch_size, length]`,
+    /// returns: the real and imaginary parts, of shape `[batch_size, n_fft / 2 + 1, num_frames]`.
+    ///
+    /// # Panics
+    ///
+    /// If the signal, padded when centered, is shorter than a frame.
+    pub fn stft(self, options: &StftOptions) -> (Tensor<B, 3>, Tensor<B, 3>) {
+        let StftOptions {
+            n_fft,
+            hop_length,
+            window,
+            center,
+        } = options;
+        let device = self.device();
+
+        let signal = match center {
+            true => reflect_pad(self, n_fft / 2),
+            false => self,
+        };
+        let [batch_size, length] = signal.dims();
+        assert!(
+            length >= *n_fft,
+            "The signal of length {length} is shorter than a frame of size {n_fft}."
+        );
+
+        let num_frames = 1 + (length - n_fft) / hop_length;
+        let indices = frame_indices::<B>(num_frames, *n_fft, *hop_length, &device);
+        let window = window
+            .to_tensor::<B>(*n_fft, &device)
+            .reshape([1, 1, *n_fft]);
+
+        let frames = signal
+            .select(1, indices)
+            .reshape([batch_size, num_frames, *n_fft])
+            .mul(window);
+        let (real, imag) = frames.rfft().swap_dims(1, 2).into_parts();
+
+        (real, imag)
+    }
+}
+
+impl<B: Backend> Tensor<B, 3> {
+    /// Computes the inverse of the [short-time Fourier transform](Tensor::stft), summing the
+    /// overlapping frames weighted by the window.
+    ///
+    /// The tensor is the real part of the transform, and `imag` its imaginary part. The signal is
+    /// truncated or padded with zeros to the given length, which is otherwise deduced from the
+    /// number of frames.
+    ///
+    /// # Shapes
+    ///
+    /// self, imag: `[batch_size, n_fft / 2 + 1, num_frames]`,
+    /// returns: `[batch_size, length]`.
+    pub fn istft(self, imag: Self, options: &StftOptions, length: Option<usize>) -> Tensor<B, 2> {
+        let StftOptions {
+            n_fft,
+            hop_length,
+            window,
+            center,
+        } = options;
+        let [batch_size, _, num_frames] = self.dims();
+        let device = self.device();
+
+        let window_values = window.values(*n_fft);
+        let frames = Tensor::from_parts(self, imag)
+            .swap_dims(1, 2)
+            .irfft(*n_fft)
+            .mul(
+                window
+                    .to_tensor::<B>(*n_fft, &device)
+                    .reshape([1, 1, *n_fft]),
+            )
+            .reshape([batch_size, num_frames * n_fft]);
+
+        // Each sample is divided by the sum of the squared windows of the frames overlapping it.
+        let total_length = n_fft + hop_length * (num_frames - 1);
+        let mut envelope = alloc::vec![0.0; total_length];
+        for frame in 0..num_frames {
+            for (i, value) in window_values.iter().enumerate() {
+                envelope[frame * hop_length + i] += value * value;
+            }
+        }
+        let envelope: Vec<f32> = envelope
+            .into_iter()
+            .map(|value| match value > 1e-11 {
+                true => 1.0 / value,
+                false => 0.0,
+            })
+            .collect();
+        let envelope = Tensor::<B, 1>::from_data(
+            TensorData::new(envelope, [total_length]).convert::<B::FloatElem>(),
+            &device,
+        );
+
+        let indices = frame_indices::<B>(num_frames, *n_fft, *hop_length, &device)
+            .reshape([1, num_frames * n_fft])
+            .expand([batch_size, num_frames * n_fft]);
+        let signal = Tensor::<B, 2>::zeros([batch_size, total_length], &device)
+            .scatter(1, indices, frames)
+            .mul(envelope.reshape([1, total_length]));
+
+        let start = match center {
+            true => n_fft / 2,
+            false => 0,
+        };
+        let length = match length {
+            Some(length) => length,
+            None => total_length - 2 * start,
+        };
+        let end = usize::min(start + length, total_length);
+        let signal = signal.slice([0..batch_size, start..end]);
+
+        match end - start < length {
+            true => Tensor::cat(
+                alloc::vec![
+                    signal,
+                    Tensor::zeros([batch_size, length - (end - start)], &device),
+                ],
+                1,
+            ),
+            false => signal,
+        }
+    }
+}
+
+/// Returns the flattened indices of the samples of each frame.
+fn frame_indices<B: Backend>(
+    num_frames: usize,
+    n_fft: usize,
+    hop_length: usize,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    let indices: Vec<i64> = (0..num_frames)
+        .flat_map(|frame| (0..n_fft).map(move |i| (frame * hop_length + i) as i64))
+        .collect();
+
+    Tensor::from_data(
+        TensorData::new(indices, [num_frames * n_fft]).convert::<B::IntElem>(),
+        device,
+    )
+}
+
+/// Pads both sides of the signals with their reflection, the edge values not being repeated.
+fn reflect_pad<B: Backend>(signal: Tensor<B, 2>, padding: usize) -> Tensor<B, 2> {
+    let [_, length] = signal.dims();
+    assert!(
+        padding < length,
+        "The padding ({padding}) of the centered frames must be smaller than the signal ({length})."
+    );
+
+    let length = length as i64;
+    let padding = padding as i64;
+    let indices: Vec<i64> = (-padding..length + padding)
+        .map(|index| match index {
+            index if index < 0 => -index,
+            index if index >= length => 2 * (length - 1) - index,
+            index => index,
+        })
+        .collect();
+    let num_indices = indices.len();
+    let indices = Tensor::<B, 1, Int>::from_data(
+        TensorData::new(indices, [num_indices]).convert::<B::IntElem>(),
+        &signal.device(),
+    );
+
+    signal.select(1, indices)
+}
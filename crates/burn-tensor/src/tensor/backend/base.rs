@@ -123,6 +123,51 @@ pub trait AutodiffBackend: Backend {
     /// The gradients.
     fn backward<const D: usize>(tensor: FloatTensor<Self, D>) -> Self::Gradients;
 
+    /// Backward pass, calling the hook with the gradients computed so far each time the gradients
+    /// of some tensors are [complete](AutodiffBackend::grad_complete), e.g. to start exchanging
+    /// them while the backward pass continues.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor is the last node of computational graph where the gradients are computed.
+    /// * `hook` - The function called with the gradients.
+    ///
+    /// # Returns
+    ///
+    /// The gradients.
+    ///
+    /// The default implementation calls the hook once, after the backward pass, and should be
+    /// overridden by backends computing the gradients progressively.
+    fn backward_with_hook<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        hook: &mut dyn FnMut(&Self::Gradients),
+    ) -> Self::Gradients {
+        let grads = Self::backward::<D>(tensor);
+        hook(&grads);
+        grads
+    }
+
+    /// Returns whether the gradients of a tensor won't change anymore, during a backward pass
+    /// with a [hook](AutodiffBackend::backward_with_hook).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to check the gradients of.
+    /// * `grads` - The gradients.
+    ///
+    /// # Returns
+    ///
+    /// Whether the gradients are complete, which is always the case after the backward pass.
+    ///
+    /// The default implementation returns true, and should be overridden by backends
+    /// overriding [backward_with_hook](AutodiffBackend::backward_with_hook).
+    fn grad_complete<const D: usize>(
+        _tensor: &FloatTensor<Self, D>,
+        _grads: &Self::Gradients,
+    ) -> bool {
+        true
+    }
+
     /// Returns the gradients of a tensor.
     ///
     /// # Arguments
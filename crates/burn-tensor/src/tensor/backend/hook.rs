@@ -0,0 +1,66 @@
+use alloc::boxed::Box;
+use core::any::Any;
+use core::cell::RefCell;
+
+use super::AutodiffBackend;
+
+std::thread_local! {
+    static INSTALLED: RefCell<Option<Box<dyn Any>>> = const { RefCell::new(None) };
+}
+
+type Hook<B> = Box<dyn FnMut(&<B as AutodiffBackend>::Gradients)>;
+
+/// Call the hook during the backward passes started on the current thread with
+/// [backward](crate::Tensor::backward), until the returned guard is dropped.
+///
+/// The hook is called as with [backward_with_hook](crate::Tensor::backward_with_hook), so the
+/// gradients can be used as soon as they are complete without changing the code calling the
+/// backward pass, e.g. to exchange the gradients of the replicas of a model trained on different
+/// devices while the backward pass continues.
+pub fn install_backward_hook<B: AutodiffBackend>(
+    hook: impl FnMut(&B::Gradients) + 'static,
+) -> BackwardHookGuard {
+    let hook: Hook<B> = Box::new(hook);
+    let previous = INSTALLED.with(|installed| installed.borrow_mut().replace(Box::new(hook)));
+
+    BackwardHookGuard { previous }
+}
+
+/// Take the hook installed on the current thread for the backend, if any, so that the backward
+/// passes started by the hook itself don't call it.
+pub(crate) fn take_backward_hook<B: AutodiffBackend>() -> Option<Hook<B>> {
+    INSTALLED.with(|installed| {
+        let mut installed = installed.borrow_mut();
+        match installed.take().map(|hook| hook.downcast::<Hook<B>>()) {
+            Some(Ok(hook)) => Some(*hook),
+            Some(Err(other)) => {
+                // The hook was installed for another backend.
+                *installed = Some(other);
+                None
+            }
+            None => None,
+        }
+    })
+}
+
+/// Restore a hook [taken](take_backward_hook) from the current thread, unless another one was
+/// installed since.
+pub(crate) fn restore_backward_hook<B: AutodiffBackend>(hook: Hook<B>) {
+    INSTALLED.with(|installed| {
+        installed.borrow_mut().get_or_insert_with(|| Box::new(hook));
+    });
+}
+
+/// Restore the hook previously [installed](install_backward_hook) on the current thread when
+/// dropped.
+#[must_use = "The hook is uninstalled when the guard is dropped."]
+pub struct BackwardHookGuard {
+    previous: Option<Box<dyn Any>>,
+}
+
+impl Drop for BackwardHookGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        INSTALLED.with(|installed| *installed.borrow_mut() = previous);
+    }
+}
@@ -1,10 +1,14 @@
 mod base;
 mod bridge;
 mod device;
+#[cfg(feature = "std")]
+mod hook;
 
 pub use base::*;
 pub use bridge::*;
 pub use device::*;
+#[cfg(feature = "std")]
+pub use hook::*;
 
 // Not needed for now, useful for different tensor memory layout
 // pub mod conversion;
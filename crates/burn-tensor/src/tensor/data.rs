@@ -6,6 +6,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use half::{bf16, f16};
 
+use super::tolerance::{self, Tolerance};
 use crate::{tensor::Shape, DType, Distribution, Element, ElementConversion};
 
 use num_traits::pow::Pow;
@@ -15,6 +16,7 @@ use num_traits::pow::Pow;
 use num_traits::Float;
 
 use rand::RngCore;
+use xxhash_rust::xxh64::Xxh64;
 
 /// The things that can go wrong when manipulating tensor data.
 #[derive(Debug)]
@@ -29,6 +31,7 @@ pub enum DataError {
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct TensorData {
     /// The values of the tensor (as bytes).
+    #[serde(with = "value_bytes")]
     value: Vec<u8>,
 
     /// The shape of the tensor.
@@ -50,7 +53,10 @@ impl TensorData {
 
     /// Returns the immutable slice view of the tensor data.
     pub fn as_slice<E: Element>(&self) -> Result<&[E], DataError> {
-        if E::dtype() == self.dtype {
+        if E::dtype() == self.dtype && self.value.is_empty() {
+            // The empty bytes may not be aligned for the element type.
+            Ok(&[])
+        } else if E::dtype() == self.dtype {
             bytemuck::checked::try_cast_slice(&self.value).map_err(DataError::CastError)
         } else {
             Err(DataError::TypeMismatch(format!(
@@ -84,7 +90,10 @@ impl TensorData {
 
     /// Returns an iterator over the values of the tensor data.
     pub fn iter<E: Element>(&self) -> Box<dyn Iterator<Item = E> + '_> {
-        if E::dtype() == self.dtype {
+        if self.value.is_empty() {
+            // The empty bytes may not be aligned for the element type.
+            Box::new(core::iter::empty())
+        } else if E::dtype() == self.dtype {
             Box::new(bytemuck::checked::cast_slice(&self.value).iter().copied())
         } else {
             match self.dtype {
@@ -218,11 +227,54 @@ impl TensorData {
         }
     }
 
+    /// Creates the data from the bytes of its values.
+    ///
+    /// # Panics
+    ///
+    /// If the number of bytes doesn't match the shape and the data type.
+    pub fn from_bytes<S: Into<Vec<usize>>>(bytes: Vec<u8>, shape: S, dtype: DType) -> Self {
+        let shape = shape.into();
+        assert_eq!(
+            bytes.len(),
+            Self::numel(&shape) * dtype.size(),
+            "The number of bytes doesn't match the shape {shape:?} of {dtype:?} values"
+        );
+
+        Self {
+            value: bytes,
+            shape,
+            dtype,
+        }
+    }
+
     /// Returns the data as a slice of bytes.
     pub fn as_bytes(&self) -> &[u8] {
         self.value.as_slice()
     }
 
+    /// Returns a deterministic checksum of the data.
+    ///
+    /// Every value is hashed along with its position, and the hashes are summed in two 32-bit
+    /// lanes, so that devices can compute them with a parallel reduction. The checksum is the
+    /// [xxh64](https://github.com/Cyan4973/xxHash) hash of the data type, the shape and the lanes,
+    /// so it doesn't depend on the platform. Two tensor data have the same checksum when they have
+    /// the same data type and shape, and their values are bitwise identical.
+    ///
+    /// This isn't the xxh64 hash of the bytes of the data, which can't be computed in parallel.
+    /// See [Checksum] for its collision properties.
+    pub fn checksum(&self) -> u64 {
+        let mut checksum = Checksum::default();
+        let size = self.dtype.size();
+
+        // Indexing the values rather than chunking the bytes supports values without bytes.
+        for index in 0..self.num_elements() {
+            let elem = &self.value[index * size..(index + 1) * size];
+            checksum.update(index, Checksum::bits(elem));
+        }
+
+        checksum.finalize(self.dtype, &self.shape)
+    }
+
     /// Asserts the data is approximately equal to another data.
     ///
     /// # Arguments
@@ -369,6 +421,61 @@ impl TensorData {
         }
     }
 
+    /// Asserts the data is close to the expected data, with the
+    /// [default tolerance](Tolerance::for_dtype) of its data type.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The expected data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is not close, with a report of the number of mismatches, the maximum
+    /// errors and the worst mismatches.
+    #[track_caller]
+    pub fn assert_close(&self, expected: &Self) {
+        self.assert_close_with(expected, Tolerance::for_dtype(self.dtype))
+    }
+
+    /// Asserts the data is close to the expected data.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The expected data.
+    /// * `tolerance` - The tolerance of the comparison, the units in the last place being counted
+    ///   in the data type of this data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is not close, with a report of the number of mismatches, the maximum
+    /// errors and the worst mismatches.
+    #[track_caller]
+    pub fn assert_close_with(&self, expected: &Self, tolerance: Tolerance) {
+        let mut message = String::new();
+        if self.shape != expected.shape {
+            message += format!(
+                "\n  => Shape is different: {:?} != {:?}",
+                self.shape, expected.shape
+            )
+            .as_str();
+        }
+
+        if let Some(report) = tolerance::compare(
+            self.iter::<f64>(),
+            expected.iter::<f64>(),
+            &self.shape,
+            self.dtype,
+            &tolerance,
+        ) {
+            message += "\n  => ";
+            message += report.as_str();
+        }
+
+        if !message.is_empty() {
+            panic!("Tensors are not close:{}", message);
+        }
+    }
+
     /// Asserts each value is within a given range.
     ///
     /// # Arguments
@@ -391,6 +498,97 @@ impl TensorData {
     }
 }
 
+/// The lanes of a [tensor data checksum](TensorData::checksum), updated with the bits of the values.
+///
+/// Backends computing the checksum on the device hash the values with [Checksum::hash], sum the
+/// lanes with wrapping additions and only read the lanes back to [finalize](Checksum::finalize)
+/// the checksum.
+///
+/// # Collisions
+///
+/// The checksum detects accidental changes, like diverging weights, but isn't a cryptographic
+/// hash, nor the xxh64 hash of the bytes:
+///
+/// - The data of the same type and shape are summarized by the 64 bits of the two lanes, so
+///   different values collide with a probability of about `2^-64`, as long as they aren't
+///   chosen to collide.
+/// - The lanes are sums, so changes whose hashes cancel out, which can be found on purpose, give
+///   the same checksum.
+/// - The positions wrap around at `2^32`, so swapping values `2^32` positions apart isn't
+///   detected.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Checksum {
+    /// The wrapping sums of the value hashes.
+    pub lanes: [u32; 2],
+}
+
+impl Checksum {
+    /// The seeds of the lanes.
+    pub const SEEDS: [u32; 2] = [0x9E37_79B9, 0x85EB_CA6B];
+
+    /// The 32-bit finalizer of [MurmurHash3](https://github.com/aappleby/smhasher).
+    pub fn mix(mut hash: u32) -> u32 {
+        hash ^= hash >> 16;
+        hash = hash.wrapping_mul(0x85EB_CA6B);
+        hash ^= hash >> 13;
+        hash = hash.wrapping_mul(0xC2B2_AE35);
+        hash ^= hash >> 16;
+        hash
+    }
+
+    /// Hash of a value in a lane, from its position and the bits of its little-endian bytes
+    /// extended with zeros. Positions wrap around at 2^32.
+    pub fn hash(lane: usize, index: usize, bits: u64) -> u32 {
+        let seed = Self::SEEDS[lane];
+        let key = Self::mix(index as u32 ^ seed);
+        let high = Self::mix((bits >> 32) as u32 ^ seed);
+
+        Self::mix(key ^ bits as u32 ^ high)
+    }
+
+    /// Adds the value at the given position.
+    pub fn update(&mut self, index: usize, bits: u64) {
+        for (lane, sum) in self.lanes.iter_mut().enumerate() {
+            *sum = sum.wrapping_add(Self::hash(lane, index, bits));
+        }
+    }
+
+    /// Adds the element at the given position.
+    pub fn update_elem<E: Element>(&mut self, index: usize, elem: &E) {
+        self.update(
+            index,
+            Self::bits(bytemuck::cast_slice(core::slice::from_ref(elem))),
+        );
+    }
+
+    /// The bits of a value from its native bytes, extended with zeros.
+    fn bits(elem: &[u8]) -> u64 {
+        let mut bytes = [0; 8];
+        bytes[..elem.len()].copy_from_slice(elem);
+
+        #[cfg(target_endian = "big")]
+        bytes[..elem.len()].reverse();
+
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Returns the checksum of a tensor with the given data type and shape.
+    pub fn finalize(&self, dtype: DType, shape: &[usize]) -> u64 {
+        let mut hasher = Xxh64::new(0);
+
+        hasher.update(&[dtype as u8]);
+        hasher.update(&(shape.len() as u64).to_le_bytes());
+        for dim in shape.iter() {
+            hasher.update(&(*dim as u64).to_le_bytes());
+        }
+        for lane in self.lanes.iter() {
+            hasher.update(&lane.to_le_bytes());
+        }
+
+        hasher.digest()
+    }
+}
+
 impl<E: Element, const A: usize> From<[E; A]> for TensorData {
     fn from(elems: [E; A]) -> Self {
         TensorData::new(elems.to_vec(), [A])
@@ -874,6 +1072,48 @@ impl<E: core::fmt::Debug, const D: usize> core::fmt::Display for Data<E, D> {
     }
 }
 
+/// Serializes the values of a [tensor data](TensorData) as bytes, which binary formats store
+/// contiguously, while still accepting the sequences of bytes written by previous versions.
+mod value_bytes {
+    use alloc::vec::Vec;
+    use serde::{de, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> de::Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("the bytes of the tensor values")
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::new();
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+
+            Ok(bytes)
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(deprecated)]
 mod tests {
@@ -932,4 +1172,59 @@ mod tests {
 
         data1.assert_approx_eq(&data2, 2);
     }
+
+    #[test]
+    fn should_assert_close_within_default_tolerance() {
+        let data1 = TensorData::from([[3.0f32, 5.0, 6.0]]);
+        let data2 = TensorData::from([[3.00001f32, 5.0, 6.0]]);
+
+        data1.assert_close(&data2);
+    }
+
+    #[test]
+    #[should_panic(expected = "1 / 3 elements differ")]
+    fn should_assert_close_above_default_tolerance() {
+        let data1 = TensorData::from([[3.0f32, 5.0, 6.0]]);
+        let data2 = TensorData::from([[3.0001f32, 5.0, 6.0]]);
+
+        data1.assert_close(&data2);
+    }
+
+    #[test]
+    fn should_assert_close_within_ulps() {
+        let value = 1.0e6f32;
+        let data1 = TensorData::from([value, f32::NAN, f32::INFINITY]);
+        let data2 =
+            TensorData::from([f32::from_bits(value.to_bits() + 2), f32::NAN, f32::INFINITY]);
+
+        data1.assert_close_with(&data2, Tolerance::ulps(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Max absolute error 2 at index [1, 0]")]
+    fn should_assert_close_report_worst_index() {
+        let data1 = TensorData::from([[1i32, 2], [3, 4]]);
+        let data2 = TensorData::from([[1i32, 3], [5, 4]]);
+
+        data1.assert_close(&data2);
+    }
+
+    #[test]
+    fn should_have_a_stable_checksum() {
+        let data = TensorData::from([[1.0f32, 2.0], [3.0, 4.0]]);
+
+        assert_eq!(data.checksum(), 14849588435379430611);
+    }
+
+    #[test]
+    fn should_checksum_the_shape_and_dtype() {
+        let data = TensorData::from([[1.0f32, 2.0], [3.0, 4.0]]);
+
+        assert_ne!(
+            data.checksum(),
+            TensorData::from([1.0f32, 2.0, 3.0, 4.0]).checksum()
+        );
+        assert_ne!(data.checksum(), data.clone().convert::<f64>().checksum());
+        assert_eq!(data.checksum(), data.clone().checksum());
+    }
 }
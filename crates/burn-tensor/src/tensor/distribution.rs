@@ -16,6 +16,15 @@ pub enum Distribution {
 
     /// Normal distribution with the given mean and standard deviation.
     Normal(f64, f64),
+
+    /// Gamma distribution with the given shape (concentration) and scale.
+    Gamma(f64, f64),
+
+    /// Beta distribution with the given alpha and beta shape parameters.
+    Beta(f64, f64),
+
+    /// Poisson distribution with the given rate.
+    Poisson(f64),
 }
 
 /// Distribution sampler for random value of a tensor.
@@ -47,6 +56,15 @@ where
 
     /// Normal distribution.
     Normal(rand_distr::Normal<f64>),
+
+    /// Gamma distribution.
+    Gamma(rand_distr::Gamma<f64>),
+
+    /// Beta distribution.
+    Beta(rand_distr::Beta<f64>),
+
+    /// Poisson distribution.
+    Poisson(rand_distr::Poisson<f64>),
 }
 
 impl<'a, E, R> DistributionSampler<'a, E, R>
@@ -69,6 +87,9 @@ where
                 }
             }
             DistributionSamplerKind::Normal(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Gamma(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Beta(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Poisson(distribution) => self.rng.sample(distribution).elem(),
         }
     }
 }
@@ -102,6 +123,15 @@ impl Distribution {
             Distribution::Normal(mean, std) => {
                 DistributionSamplerKind::Normal(rand_distr::Normal::new(mean, std).unwrap())
             }
+            Distribution::Gamma(shape, scale) => {
+                DistributionSamplerKind::Gamma(rand_distr::Gamma::new(shape, scale).unwrap())
+            }
+            Distribution::Beta(alpha, beta) => {
+                DistributionSamplerKind::Beta(rand_distr::Beta::new(alpha, beta).unwrap())
+            }
+            Distribution::Poisson(rate) => {
+                DistributionSamplerKind::Poisson(rand_distr::Poisson::<f64>::new(rate).unwrap())
+            }
         };
 
         DistributionSampler::new(kind, rng)
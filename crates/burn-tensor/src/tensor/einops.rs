@@ -0,0 +1,585 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, BasicOps, Element, Numeric, Tensor};
+
+/// The maximum number of elementary axes in each side of a pattern.
+const MAX_RANK: usize = 6;
+
+/// The ellipsis, standing for the remaining dimensions of the input.
+const ELLIPSIS: &str = "...";
+
+/// The reduction applied by [reduce] to the axes missing from the output of the pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reduction {
+    /// Sum of the elements.
+    Sum,
+    /// Mean of the elements.
+    Mean,
+    /// Maximum of the elements.
+    Max,
+    /// Minimum of the elements.
+    Min,
+    /// Product of the elements.
+    Prod,
+}
+
+/// Rearranges the dimensions of the tensor following the given pattern.
+///
+/// The pattern lists the axes of the input, followed by `->` and the axes of the output, each
+/// dimension being either an axis name, a group of axes in parentheses which are merged into a
+/// single dimension, or `1` for a dimension of size one. An ellipsis (`...`) stands for the
+/// remaining dimensions of the input, and can be grouped in the output. All the axes of the input
+/// must appear in the output.
+///
+/// The rearrangement is lowered to a reshape splitting the groups of the input, a permutation of
+/// the axes, and a reshape merging the groups of the output.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to rearrange.
+/// * `pattern` - The pattern, e.g. `"b (h w) c -> b c h w"`.
+/// * `axes` - The sizes of the axes which can't be inferred from the shape of the input.
+///
+/// # Panics
+///
+/// If the pattern is invalid or doesn't match the shape of the tensor, if `D2` isn't the number of
+/// dimensions of the output, or if a side of the pattern has more than 6 axes.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{einops, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 3>::ones([2, 12, 3], &device);
+///
+///     let output: Tensor<B, 4> = einops::rearrange(tensor, "b (h w) c -> b c h w", &[("h", 4)]);
+///     assert_eq!(output.dims(), [2, 3, 4, 3]);
+/// }
+/// ```
+pub fn rearrange<B, const D: usize, const D2: usize, K>(
+    tensor: Tensor<B, D, K>,
+    pattern: &str,
+    axes: &[(&str, usize)],
+) -> Tensor<B, D2, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    let plan = Plan::new::<D2>("Rearrange", pattern, &tensor.dims(), axes);
+    plan.assert_no_reduction();
+    plan.assert_no_repetition();
+
+    plan.apply(tensor, |tensor, _| tensor)
+}
+
+/// Reduces the axes of the tensor missing from the output of the pattern.
+///
+/// The pattern follows the syntax of [rearrange], but the axes of the input can be omitted from
+/// the output to reduce them with the given [reduction](Reduction). An output without dimensions
+/// is a tensor with a single element.
+///
+/// # Panics
+///
+/// If the pattern is invalid or doesn't match the shape of the tensor, if `D2` isn't the number of
+/// dimensions of the output, or if a side of the pattern has more than 6 axes.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::einops::{self, Reduction};
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let images = Tensor::<B, 4>::ones([8, 3, 32, 32], &device);
+///
+///     // 2x2 max pooling.
+///     let output: Tensor<B, 4> = einops::reduce(
+///         images,
+///         "b c (h h2) (w w2) -> b c h w",
+///         Reduction::Max,
+///         &[("h2", 2), ("w2", 2)],
+///     );
+///     assert_eq!(output.dims(), [8, 3, 16, 16]);
+/// }
+/// ```
+pub fn reduce<B, const D: usize, const D2: usize, K>(
+    tensor: Tensor<B, D, K>,
+    pattern: &str,
+    reduction: Reduction,
+    axes: &[(&str, usize)],
+) -> Tensor<B, D2, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    let plan = Plan::new::<D2>("Reduce", pattern, &tensor.dims(), axes);
+    plan.assert_no_repetition();
+
+    plan.apply(tensor, |tensor, dim| match reduction {
+        Reduction::Sum => tensor.sum_dim(dim),
+        Reduction::Mean => tensor.mean_dim(dim),
+        Reduction::Max => tensor.max_dim(dim),
+        Reduction::Min => tensor.min_dim(dim),
+        Reduction::Prod => tensor.prod_dim(dim),
+    })
+}
+
+/// Repeats the tensor along the axes of the output missing from the input of the pattern.
+///
+/// The pattern follows the syntax of [rearrange], but the output can have new axes, whose sizes
+/// must be given, along which the tensor is repeated.
+///
+/// # Panics
+///
+/// If the pattern is invalid or doesn't match the shape of the tensor, if the size of a new axis
+/// isn't given, if `D2` isn't the number of dimensions of the output, or if a side of the pattern
+/// has more than 6 axes.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{einops, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let image = Tensor::<B, 2>::ones([32, 32], &device);
+///
+///     // Upsample the image by repeating each pixel along both spatial dimensions.
+///     let output: Tensor<B, 2> =
+///         einops::repeat(image, "h w -> (h h2) (w w2)", &[("h2", 2), ("w2", 2)]);
+///     assert_eq!(output.dims(), [64, 64]);
+/// }
+/// ```
+pub fn repeat<B, const D: usize, const D2: usize, K>(
+    tensor: Tensor<B, D, K>,
+    pattern: &str,
+    axes: &[(&str, usize)],
+) -> Tensor<B, D2, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    let plan = Plan::new::<D2>("Repeat", pattern, &tensor.dims(), axes);
+    plan.assert_no_reduction();
+
+    plan.apply(tensor, |tensor, _| tensor)
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Rearranges the dimensions of the tensor following the given pattern.
+    ///
+    /// See [rearrange](crate::einops::rearrange) for the details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 4>::ones([2, 8, 16, 64], &device);
+    ///
+    ///     // Merge the attention heads.
+    ///     let output = tensor.rearrange::<3>("b h s d -> b s (h d)", &[]);
+    ///     assert_eq!(output.dims(), [2, 16, 512]);
+    /// }
+    /// ```
+    pub fn rearrange<const D2: usize>(
+        self,
+        pattern: &str,
+        axes: &[(&str, usize)],
+    ) -> Tensor<B, D2, K> {
+        rearrange(self, pattern, axes)
+    }
+
+    /// Repeats the tensor along the axes of the output missing from the input of the pattern.
+    ///
+    /// See [repeat](crate::einops::repeat) for the details.
+    pub fn repeat_pattern<const D2: usize>(
+        self,
+        pattern: &str,
+        axes: &[(&str, usize)],
+    ) -> Tensor<B, D2, K> {
+        repeat(self, pattern, axes)
+    }
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Reduces the axes of the tensor missing from the output of the pattern.
+    ///
+    /// See [reduce](crate::einops::reduce) for the details.
+    pub fn reduce_pattern<const D2: usize>(
+        self,
+        pattern: &str,
+        reduction: Reduction,
+        axes: &[(&str, usize)],
+    ) -> Tensor<B, D2, K> {
+        reduce(self, pattern, reduction, axes)
+    }
+}
+
+/// The elementary axes of both sides of a pattern, along with their sizes.
+struct Plan {
+    name: &'static str,
+    input: Vec<String>,
+    output: Vec<Vec<String>>,
+    sizes: BTreeMap<String, usize>,
+}
+
+impl Plan {
+    fn new<const D2: usize>(
+        name: &'static str,
+        pattern: &str,
+        dims: &[usize],
+        axes: &[(&str, usize)],
+    ) -> Self {
+        let (input, output) = pattern
+            .split_once("->")
+            .unwrap_or_else(|| panic!("{name} pattern '{pattern}' should contain '->'."));
+        let input = parse_side(name, input);
+        let output = parse_side(name, output);
+        assert!(
+            input
+                .iter()
+                .all(|dim| !dim.has_ellipsis() || matches!(dim, Dim::Ellipsis)),
+            "{name} pattern '{pattern}' can't group the ellipsis of the input."
+        );
+        let has_ellipsis = |dims: &[Dim]| dims.iter().any(Dim::has_ellipsis);
+        assert!(
+            has_ellipsis(&input) || !has_ellipsis(&output),
+            "{name} pattern '{pattern}' has an ellipsis in its output only."
+        );
+        let count = match has_ellipsis(&input) {
+            true => (dims.len() + 1).saturating_sub(input.len()),
+            false => 0,
+        };
+        let input = expand_ellipsis(input, count);
+        let output = expand_ellipsis(output, count);
+
+        assert_eq!(
+            input.len(),
+            dims.len(),
+            "{name} pattern '{pattern}' has {} input dimensions, but the tensor has {}.",
+            input.len(),
+            dims.len()
+        );
+        assert!(
+            D2 == output.len().max(1),
+            "{name} pattern '{pattern}' has {} output dimensions, expected {D2}.",
+            output.len()
+        );
+
+        let mut sizes = BTreeMap::new();
+        for (axis, size) in axes {
+            assert!(
+                input.iter().chain(&output).flatten().any(|a| a == axis),
+                "{name} pattern '{pattern}' has no axis named '{axis}'."
+            );
+            sizes.insert(axis.to_string(), *size);
+        }
+
+        for (group, size) in input.iter().zip(dims) {
+            let unknown = group
+                .iter()
+                .filter(|axis| !sizes.contains_key(*axis))
+                .collect::<Vec<_>>();
+            let known = group
+                .iter()
+                .filter_map(|axis| sizes.get(axis))
+                .product::<usize>();
+
+            match unknown.as_slice() {
+                [] => assert_eq!(
+                    known,
+                    *size,
+                    "{name} pattern '{pattern}' expects a dimension of size {known} for {}, but \
+                     it has size {size}.",
+                    display_group(group)
+                ),
+                [axis] => {
+                    assert!(
+                        known > 0 && size % known == 0,
+                        "{name} pattern '{pattern}' can't split the dimension of size {size} \
+                         into {}.",
+                        display_group(group)
+                    );
+                    sizes.insert(axis.to_string(), size / known);
+                }
+                _ => panic!(
+                    "{name} pattern '{pattern}' can't infer the sizes of {}, which should be \
+                     given.",
+                    display_group(group)
+                ),
+            }
+        }
+
+        for axis in output.iter().flatten() {
+            assert!(
+                sizes.contains_key(axis),
+                "{name} pattern '{pattern}' has a new axis '{axis}' whose size should be given."
+            );
+        }
+
+        let input = input.into_iter().flatten().collect::<Vec<_>>();
+        for side in [&input, &output.concat()] {
+            assert!(
+                side.len() <= MAX_RANK,
+                "{name} pattern '{pattern}' has more than {MAX_RANK} axes in a side."
+            );
+        }
+
+        Self {
+            name,
+            input,
+            output,
+            sizes,
+        }
+    }
+
+    fn reduced(&self) -> Vec<&String> {
+        self.input
+            .iter()
+            .filter(|axis| !self.output.iter().flatten().any(|a| a == *axis))
+            .collect()
+    }
+
+    fn repeated(&self) -> Vec<&String> {
+        self.output
+            .iter()
+            .flatten()
+            .filter(|axis| !self.input.contains(axis))
+            .collect()
+    }
+
+    fn assert_no_reduction(&self) {
+        let reduced = self.reduced();
+        assert!(
+            reduced.is_empty(),
+            "{} pattern is missing the input axes {:?} in its output, which can only be reduced \
+             with reduce.",
+            self.name,
+            reduced
+        );
+    }
+
+    fn assert_no_repetition(&self) {
+        let repeated = self.repeated();
+        assert!(
+            repeated.is_empty(),
+            "{} pattern has the new axes {:?} in its output, which can only be added with repeat.",
+            self.name,
+            repeated
+        );
+    }
+
+    /// Split the input groups, reduce the axes missing from the output with the given function,
+    /// permute and repeat the axes, then merge the output groups.
+    fn apply<B, const D: usize, const D2: usize, K, F>(
+        &self,
+        tensor: Tensor<B, D, K>,
+        reduce: F,
+    ) -> Tensor<B, D2, K>
+    where
+        B: Backend,
+        K: BasicOps<B>,
+        F: Fn(Tensor<B, MAX_RANK, K>, usize) -> Tensor<B, MAX_RANK, K>,
+    {
+        let reduced = self.reduced();
+        let mut axes = Vec::new();
+        let mut tensor = tensor.reshape(self.padded(&self.input));
+        let offset = MAX_RANK - self.input.len();
+
+        for (dim, axis) in self.input.iter().enumerate() {
+            if reduced.contains(&axis) {
+                tensor = reduce(tensor, offset + dim);
+            } else {
+                axes.push(axis.clone());
+            }
+        }
+        tensor = tensor.reshape(self.padded(&axes));
+
+        let output = self.output.concat();
+        let kept = output
+            .iter()
+            .filter(|axis| axes.contains(axis))
+            .cloned()
+            .collect::<Vec<_>>();
+        let offset = MAX_RANK - axes.len();
+        let mut permutation = [0; MAX_RANK];
+        for (dim, axis) in permutation.iter_mut().enumerate() {
+            *axis = match dim < offset {
+                true => dim,
+                false => offset + axes.iter().position(|a| *a == kept[dim - offset]).unwrap(),
+            } as isize;
+        }
+        tensor = tensor.permute(permutation);
+
+        if kept.len() < output.len() {
+            let mut shape = [1; MAX_RANK];
+            let offset = MAX_RANK - output.len();
+            for (dim, axis) in output.iter().enumerate() {
+                if kept.contains(axis) {
+                    shape[offset + dim] = self.sizes[axis];
+                }
+            }
+            tensor = tensor.reshape(shape).expand(self.padded(&output));
+        }
+
+        let mut shape = [1; D2];
+        for (size, group) in shape.iter_mut().zip(&self.output) {
+            *size = group.iter().map(|axis| self.sizes[axis]).product();
+        }
+
+        tensor.reshape(shape)
+    }
+
+    /// The shape of the given axes, padded with leading dimensions of size 1.
+    fn padded(&self, axes: &[String]) -> [usize; MAX_RANK] {
+        let mut shape = [1; MAX_RANK];
+        let offset = MAX_RANK - axes.len();
+        for (dim, axis) in axes.iter().enumerate() {
+            shape[offset + dim] = self.sizes[axis];
+        }
+
+        shape
+    }
+}
+
+/// A dimension of a side of a pattern.
+enum Dim {
+    /// The axes merged into the dimension, which can include the ellipsis.
+    Axes(Vec<String>),
+    /// The ellipsis, standing for any number of dimensions.
+    Ellipsis,
+}
+
+impl Dim {
+    fn has_ellipsis(&self) -> bool {
+        match self {
+            Dim::Axes(axes) => axes.iter().any(|axis| axis == ELLIPSIS),
+            Dim::Ellipsis => true,
+        }
+    }
+}
+
+/// Parse a side of a pattern into its dimensions, where `1` is a dimension without axes.
+fn parse_side(name: &str, side: &str) -> Vec<Dim> {
+    let mut dims: Vec<Dim> = Vec::new();
+    let mut group: Option<Vec<String>> = None;
+    let mut chars = side.trim().chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let axis = match c {
+            c if c.is_whitespace() => continue,
+            '(' => {
+                assert!(group.is_none(), "{name} pattern can't nest parentheses.");
+                group = Some(Vec::new());
+                continue;
+            }
+            ')' => {
+                let axes = group
+                    .take()
+                    .unwrap_or_else(|| panic!("{name} pattern has an unmatched ')'."));
+                dims.push(Dim::Axes(axes));
+                continue;
+            }
+            '.' => {
+                for _ in 0..2 {
+                    assert_eq!(
+                        chars.next(),
+                        Some('.'),
+                        "{name} pattern has an invalid '.'."
+                    );
+                }
+                String::from(ELLIPSIS)
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut axis = String::from(c);
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+                    axis.push(c);
+                }
+                axis
+            }
+            c => panic!("{name} pattern has an invalid character '{c}'."),
+        };
+
+        let axes = match axis.as_str() {
+            ELLIPSIS if group.is_none() => {
+                dims.push(Dim::Ellipsis);
+                continue;
+            }
+            "1" => Vec::new(),
+            axis if axis.starts_with(|c: char| c.is_ascii_digit()) => {
+                panic!("{name} pattern can only use 1 as an anonymous axis, got '{axis}'.")
+            }
+            _ => alloc::vec![axis],
+        };
+        match group.as_mut() {
+            Some(group) => group.extend(axes),
+            None => dims.push(Dim::Axes(axes)),
+        }
+    }
+    assert!(group.is_none(), "{name} pattern has an unmatched '('.");
+
+    let axes = dims
+        .iter()
+        .flat_map(|dim| match dim {
+            Dim::Axes(axes) => axes.iter().map(String::as_str).collect(),
+            Dim::Ellipsis => alloc::vec![ELLIPSIS],
+        })
+        .collect::<Vec<_>>();
+    for (i, axis) in axes.iter().enumerate() {
+        assert!(
+            !axes[i + 1..].contains(axis),
+            "{name} pattern has the axis '{axis}' more than once in a side."
+        );
+    }
+
+    dims
+}
+
+/// Replace the ellipsis by the given number of axes, named `...0`, `...1`, etc., which are
+/// separate dimensions unless the ellipsis is in a group.
+fn expand_ellipsis(dims: Vec<Dim>, count: usize) -> Vec<Vec<String>> {
+    let axes = (0..count).map(|i| format!("{ELLIPSIS}{i}"));
+
+    dims.into_iter()
+        .flat_map(|dim| match dim {
+            Dim::Axes(group) => alloc::vec![group
+                .into_iter()
+                .flat_map(|axis| match axis == ELLIPSIS {
+                    true => axes.clone().collect(),
+                    false => alloc::vec![axis],
+                })
+                .collect()],
+            Dim::Ellipsis => axes.clone().map(|axis| alloc::vec![axis]).collect(),
+        })
+        .collect()
+}
+
+fn display_group(group: &[String]) -> String {
+    match group.len() {
+        1 => format!("'{}'", group[0]),
+        _ => format!("({})", group.join(" ")),
+    }
+}
@@ -0,0 +1,147 @@
+use burn_common::rand::{SeedableRng, StdRng};
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static INSTALLED: RefCell<Option<Generator>> = const { RefCell::new(None) };
+}
+
+/// Increment of the counter of the generator, from the SplitMix64 generator.
+const GOLDEN_GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// An explicit random number generator, seeding the random tensors created with it.
+///
+/// The state of a generator is its initial seed and the number of seeds drawn from it, so it can
+/// be cloned, saved with serde and restored to resume the exact same sequence of random
+/// tensors, independently of the global seed of the backends set with
+/// [seed](crate::backend::Backend::seed).
+///
+/// Independent generators, e.g. for each device or data loader worker, are created with
+/// [fork](Generator::fork).
+///
+/// A generator can also be [installed](Generator::install) on a thread, to seed the random tensors
+/// created there without an explicit generator, e.g. the masks of the dropout layers.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Distribution, Generator, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let mut generator = Generator::new(42);
+///     let saved = generator.clone();
+///
+///     let tensor =
+///         Tensor::<B, 2>::random_with([2, 3], Distribution::Default, &mut generator, &device);
+///
+///     // Restoring the state samples the same values again.
+///     let mut generator = saved;
+///     let other =
+///         Tensor::<B, 2>::random_with([2, 3], Distribution::Default, &mut generator, &device);
+///     tensor.into_data().assert_eq(&other.into_data(), true);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Generator {
+    seed: u64,
+    offset: u64,
+}
+
+impl Generator {
+    /// Creates a generator with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self { seed, offset: 0 }
+    }
+
+    /// Creates a generator with a random seed.
+    pub fn from_entropy() -> Self {
+        Self::new(burn_common::rand::gen_random())
+    }
+
+    /// Returns the seed the generator was created with.
+    pub fn initial_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the number of seeds drawn from the generator.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Draws the next seed of the generator.
+    pub fn next_seed(&mut self) -> u64 {
+        self.offset += 1;
+
+        // The output of SplitMix64 for the state at the current offset.
+        let mut z = self
+            .seed
+            .wrapping_add(self.offset.wrapping_mul(GOLDEN_GAMMA));
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Creates an independent generator, seeded with the next seed of this one.
+    pub fn fork(&mut self) -> Self {
+        Self::new(self.next_seed())
+    }
+
+    /// Creates a random number generator seeded with the next seed of the generator, to sample
+    /// values on the host.
+    pub fn rng(&mut self) -> StdRng {
+        StdRng::seed_from_u64(self.next_seed())
+    }
+
+    /// Use the generator on the current thread until the returned guard is dropped, so that the
+    /// random tensors created on the thread with [random](crate::Tensor::random) and
+    /// [random_like](crate::Tensor::random_like) are seeded with its next seeds instead of the
+    /// global seed of the backend.
+    ///
+    /// Each thread has its own generator, so the random tensors of threads training on different
+    /// devices don't depend on the order in which the threads draw their seeds.
+    #[cfg(feature = "std")]
+    pub fn install(self) -> GeneratorGuard {
+        let previous = INSTALLED.with(|installed| installed.borrow_mut().replace(self));
+
+        GeneratorGuard { previous }
+    }
+
+    /// The generator [installed](Generator::install) on the current thread, if any, in its
+    /// current state.
+    #[cfg(feature = "std")]
+    pub fn installed() -> Option<Self> {
+        INSTALLED.with(|installed| installed.borrow().clone())
+    }
+
+    /// Draws the next seed of the generator installed on the current thread, if any.
+    pub(crate) fn next_installed_seed() -> Option<u64> {
+        #[cfg(feature = "std")]
+        {
+            INSTALLED.with(|installed| installed.borrow_mut().as_mut().map(Self::next_seed))
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            None
+        }
+    }
+}
+
+/// Restore the generator previously [installed](Generator::install) on the current thread when
+/// dropped.
+#[cfg(feature = "std")]
+#[must_use = "The generator is uninstalled when the guard is dropped."]
+pub struct GeneratorGuard {
+    previous: Option<Generator>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for GeneratorGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        INSTALLED.with(|installed| *installed.borrow_mut() = previous);
+    }
+}
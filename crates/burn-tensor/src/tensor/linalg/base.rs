@@ -0,0 +1,159 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, broadcast_shapes, Int, Tensor, TensorData};
+
+/// Create a batch of identity matrices of size `n`, with the batch dimensions of `dims`.
+pub(crate) fn batched_eye<B: Backend, const D: usize>(
+    mut dims: [usize; D],
+    n: usize,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let mut shape = [1; D];
+    shape[D - 2] = n;
+    shape[D - 1] = n;
+    dims[D - 2] = n;
+    dims[D - 1] = n;
+
+    Tensor::<B, 2>::eye(n, device).reshape(shape).expand(dims)
+}
+
+/// The diagonal of the matrices, with the shape `[..., 1, n]`.
+pub(crate) fn diagonal<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let dims = tensor.dims();
+    let eye = batched_eye::<B, D>(dims, dims[D - 1], &tensor.device());
+
+    (tensor * eye).sum_dim(D - 2)
+}
+
+/// Create a one dimensional index tensor.
+pub(crate) fn indices<B: Backend>(indices: &[usize], device: &B::Device) -> Tensor<B, 1, Int> {
+    let values = indices.iter().map(|i| *i as i64).collect::<Vec<_>>();
+    let data = TensorData::new(values, [indices.len()]).convert::<B::IntElem>();
+
+    Tensor::from_data(data, device)
+}
+
+/// Replace the elements equal to zero by one, to use the tensor as a safe divisor.
+pub(crate) fn nonzero<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let mask = tensor.clone().equal_elem(0.0);
+    tensor.mask_fill(mask, 1.0)
+}
+
+/// Check that the tensor is a batch of matrices, panicking with the name of the operation
+/// otherwise.
+pub(crate) fn check_matrix<const D: usize>(op: &str) {
+    assert!(
+        D >= 2,
+        "{op} requires a tensor of at least 2 dimensions, got {D}."
+    );
+}
+
+/// Check that the tensor is a batch of square matrices, panicking with the name of the operation
+/// otherwise.
+pub(crate) fn check_square<const D: usize>(op: &str, dims: &[usize; D]) {
+    check_matrix::<D>(op);
+    assert_eq!(
+        dims[D - 2],
+        dims[D - 1],
+        "{op} requires square matrices, got {}x{}.",
+        dims[D - 2],
+        dims[D - 1]
+    );
+}
+
+/// Create a mask of shape `[1, ..., n, 1]` selecting the rows from `start`.
+pub(crate) fn rows_from<B: Backend, const D: usize>(
+    n: usize,
+    start: usize,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let mut shape = [1; D];
+    shape[D - 2] = n;
+
+    Tensor::<B, 1, Int>::arange(0..n as i64, device)
+        .greater_equal_elem(start as i64)
+        .float()
+        .reshape(shape)
+}
+
+/// Solve `L X = B` where `L` is a batch of lower triangular matrices, row by row.
+pub(crate) fn forward_substitution<B: Backend, const D: usize>(
+    lower: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let n = lower.dims()[D - 1];
+    let mut solution: Option<Tensor<B, D>> = None;
+
+    for i in 0..n {
+        let row = lower.clone().narrow(D - 2, i, 1);
+        let mut value = rhs.clone().narrow(D - 2, i, 1);
+        if let Some(solved) = &solution {
+            value = value - row.clone().narrow(D - 1, 0, i).matmul(solved.clone());
+        }
+        let value = value / row.narrow(D - 1, i, 1);
+
+        solution = Some(match solution {
+            Some(solved) => Tensor::cat(vec![solved, value], D - 2),
+            None => value,
+        });
+    }
+
+    solution.expect("Matrices should not be empty.")
+}
+
+/// Solve `U X = B` where `U` is a batch of upper triangular matrices, row by row.
+pub(crate) fn back_substitution<B: Backend, const D: usize>(
+    upper: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let n = upper.dims()[D - 1];
+    let mut solution: Option<Tensor<B, D>> = None;
+
+    for i in (0..n).rev() {
+        let row = upper.clone().narrow(D - 2, i, 1);
+        let mut value = rhs.clone().narrow(D - 2, i, 1);
+        if let Some(solved) = &solution {
+            value = value
+                - row
+                    .clone()
+                    .narrow(D - 1, i + 1, n - i - 1)
+                    .matmul(solved.clone());
+        }
+        let value = value / row.narrow(D - 1, i, 1);
+
+        solution = Some(match solution {
+            Some(solved) => Tensor::cat(vec![value, solved], D - 2),
+            None => value,
+        });
+    }
+
+    solution.expect("Matrices should not be empty.")
+}
+
+/// Broadcast the batch dimensions of the left-hand side matrices and the right-hand side matrices.
+pub(crate) fn broadcast_batch<B: Backend, const D: usize>(
+    op: &str,
+    lhs: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (mut lhs_dims, mut rhs_dims) = (lhs.dims(), rhs.dims());
+    assert_eq!(
+        lhs_dims[D - 2],
+        rhs_dims[D - 2],
+        "{op} requires the right-hand side to have {} rows, got {}.",
+        lhs_dims[D - 2],
+        rhs_dims[D - 2]
+    );
+
+    let batch =
+        broadcast_shapes(&[&lhs_dims[..D - 2], &rhs_dims[..D - 2]]).unwrap_or_else(|error| {
+            panic!(
+                "{op} can't broadcast the batch dimensions {lhs_dims:?} and {rhs_dims:?}: {error}"
+            )
+        });
+    lhs_dims[..D - 2].copy_from_slice(&batch);
+    rhs_dims[..D - 2].copy_from_slice(&batch);
+
+    (lhs.expand(lhs_dims), rhs.expand(rhs_dims))
+}
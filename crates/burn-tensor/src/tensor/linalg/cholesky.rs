@@ -0,0 +1,84 @@
+use alloc::vec;
+
+use super::{broadcast_batch, check_square, rows_from, triangular_solve};
+use crate::{backend::Backend, Tensor};
+
+/// Computes the Cholesky decomposition of a batch of symmetric positive-definite matrices.
+///
+/// The last two dimensions are the `[n, n]` matrices, the other ones are batch dimensions. The
+/// lower triangular matrix `L` such that `A = L L^T` is returned. Only the lower triangular part of
+/// the input is read.
+///
+/// The result is undefined, and may contain `NaN`, when a matrix isn't positive-definite.
+///
+/// The decomposition is computed by the [backend](crate::ops::FloatTensorOps::float_cholesky),
+/// natively when it supports it, and otherwise [column by column](column_cholesky) with tensor
+/// operations.
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square::<D>("cholesky", &tensor.dims());
+
+    Tensor::from_primitive(B::float_cholesky(tensor.into_primitive()))
+}
+
+/// Computes the Cholesky decomposition of a batch of symmetric positive-definite matrices column
+/// by column with tensor operations.
+///
+/// This is the reference implementation used by backends without a native decomposition: it is
+/// supported by every backend and is differentiable. See [cholesky] for more details.
+pub fn column_cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let dims = tensor.dims();
+    check_square::<D>("cholesky", &dims);
+
+    let device = tensor.device();
+    let n = dims[D - 1];
+    let mut lower: Option<Tensor<B, D>> = None;
+
+    for j in 0..n {
+        let mut column = tensor.clone().narrow(D - 1, j, 1);
+        if let Some(lower) = &lower {
+            let row = lower.clone().narrow(D - 2, j, 1);
+            column = column - lower.clone().matmul(row.transpose());
+        }
+
+        let pivot = column.clone().narrow(D - 2, j, 1).sqrt();
+        let column = column / pivot * rows_from::<B, D>(n, j, &device);
+
+        lower = Some(match lower {
+            Some(lower) => Tensor::cat(vec![lower, column], D - 1),
+            None => column,
+        });
+    }
+
+    lower.expect("Matrices should not be empty.")
+}
+
+/// Solves `A X = B` given the [Cholesky factor](cholesky) `L` of `A`.
+///
+/// The factor has the shape `[..., n, n]` and the right-hand side the shape `[..., n, k]`. Batch
+/// dimensions of size one are broadcast.
+pub fn cholesky_solve<B: Backend, const D: usize>(
+    lower: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> Tensor<B, D> {
+    check_square::<D>("cholesky_solve", &lower.dims());
+    let (lower, rhs) = broadcast_batch("cholesky_solve", lower, rhs);
+
+    let y = triangular_solve(lower.clone(), rhs, false);
+    triangular_solve(lower.transpose(), y, true)
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the Cholesky decomposition of a batch of symmetric positive-definite matrices.
+    ///
+    /// See [linalg::cholesky](crate::linalg::cholesky) for more details.
+    pub fn cholesky(self) -> Self {
+        cholesky(self)
+    }
+
+    /// Solves `A X = rhs` where the tensor is the [Cholesky factor](Tensor::cholesky) of `A`.
+    ///
+    /// See [linalg::cholesky_solve](crate::linalg::cholesky_solve) for more details.
+    pub fn cholesky_solve(self, rhs: Self) -> Self {
+        cholesky_solve(self, rhs)
+    }
+}
@@ -0,0 +1,97 @@
+use super::lu::eliminate;
+use super::{check_square, diagonal};
+use crate::{backend::Backend, Tensor};
+
+/// Computes the determinant of a batch of square matrices.
+///
+/// The last two dimensions are the `[n, n]` matrices, the other ones are batch dimensions. The
+/// determinants are returned with the shape `[..., 1, 1]`, similar to reductions with
+/// [sum_dim](Tensor::sum_dim).
+///
+/// The determinant is computed by the [backend](crate::ops::FloatTensorOps::float_det), natively
+/// when it supports it, and otherwise from the [Gaussian elimination](elimination_det).
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square::<D>("det", &tensor.dims());
+
+    Tensor::from_primitive(B::float_det(tensor.into_primitive()))
+}
+
+/// Computes the determinant of a batch of square matrices from the Gaussian elimination with
+/// partial pivoting, expressed with tensor operations.
+///
+/// This is the reference implementation used by backends without a native determinant: it is
+/// supported by every backend and is differentiable. See [det] for the shape of the output.
+pub fn elimination_det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square::<D>("det", &tensor.dims());
+
+    let n = tensor.dims()[D - 1];
+    let elimination = eliminate(tensor, None);
+    let diagonal = diagonal(elimination.upper);
+
+    // The product is unrolled, since the default product of the backends is computed in the
+    // logarithmic domain and doesn't support negative values.
+    (0..n).fold(elimination.sign, |det, i| {
+        det * diagonal.clone().narrow(D - 1, i, 1)
+    })
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of a batch
+/// of square matrices.
+///
+/// Both are returned with the shape `[..., 1, 1]`. For singular matrices, the sign is zero and the
+/// logarithm is negative infinity. This is more accurate than the [determinant](det) when it is
+/// very small or very large, e.g. for the log-likelihood of normalizing flows.
+///
+/// They are computed by the [backend](crate::ops::FloatTensorOps::float_slogdet), natively when it
+/// supports it, and otherwise from the [Gaussian elimination](elimination_slogdet).
+pub fn slogdet<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_square::<D>("slogdet", &tensor.dims());
+
+    let (sign, logabsdet) = B::float_slogdet(tensor.into_primitive());
+
+    (
+        Tensor::from_primitive(sign),
+        Tensor::from_primitive(logabsdet),
+    )
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of a batch
+/// of square matrices from the Gaussian elimination with partial pivoting, expressed with tensor
+/// operations.
+///
+/// This is the reference implementation used by backends without a native determinant: it is
+/// supported by every backend and is differentiable. See [slogdet] for the shapes of the outputs.
+pub fn elimination_slogdet<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_square::<D>("slogdet", &tensor.dims());
+
+    let elimination = eliminate(tensor, None);
+    let diagonal = diagonal(elimination.upper);
+
+    let signs = diagonal.clone().sign();
+    let n = signs.dims()[D - 1];
+    let sign = (0..n).fold(elimination.sign, |sign, i| {
+        sign * signs.clone().narrow(D - 1, i, 1)
+    });
+    let logabsdet = diagonal.abs().log().sum_dim(D - 1);
+
+    (sign, logabsdet)
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the determinant of a batch of square matrices.
+    ///
+    /// See [linalg::det](crate::linalg::det) for more details.
+    pub fn det(self) -> Self {
+        det(self)
+    }
+
+    /// Computes the sign and the natural logarithm of the absolute value of the determinant of a
+    /// batch of square matrices.
+    ///
+    /// See [linalg::slogdet](crate::linalg::slogdet) for more details.
+    pub fn slogdet(self) -> (Self, Self) {
+        slogdet(self)
+    }
+}
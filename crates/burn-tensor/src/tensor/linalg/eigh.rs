@@ -0,0 +1,137 @@
+use alloc::vec;
+
+use super::jacobi::{rotate_columns, rotation, rounds, tolerance, MAX_SWEEPS};
+use super::{batched_eye, check_square, diagonal, nonzero};
+use crate::{backend::Backend, ElementConversion, Int, Tensor};
+
+/// Computes the eigendecomposition of a batch of symmetric matrices.
+///
+/// The last two dimensions are the `[n, n]` matrices, the other ones are batch dimensions. Only
+/// the lower triangular part of the input is read. The decomposition `A = V diag(W) V^T` is
+/// returned as:
+///
+/// - `W` of shape `[..., 1, n]` with the eigenvalues in ascending order,
+/// - `V` of shape `[..., n, n]` with the orthonormal eigenvectors as columns.
+///
+/// The eigenvalues keep the dimension of the rows so that `V * W` broadcasts, similar to
+/// reductions with [sum_dim](Tensor::sum_dim).
+///
+/// The decomposition is computed by the [backend](crate::ops::FloatTensorOps::float_eigh),
+/// natively when it supports it, and otherwise with [two-sided Jacobi rotations](jacobi_eigh).
+/// The signs of the eigenvectors may differ between backends, and the gradient isn't defined for
+/// repeated eigenvalues.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_square::<D>("eigh", &tensor.dims());
+
+    let (eigenvalues, vectors) = B::float_eigh(tensor.into_primitive());
+
+    (
+        Tensor::from_primitive(eigenvalues),
+        Tensor::from_primitive(vectors),
+    )
+}
+
+/// Computes the eigendecomposition of a batch of symmetric matrices with two-sided Jacobi
+/// rotations expressed with tensor operations.
+///
+/// This is the reference implementation used by backends without a native decomposition: it is
+/// supported by every backend and is differentiable. See [eigh] for the shapes of the
+/// decomposition.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn jacobi_eigh<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let dims = tensor.dims();
+    check_square::<D>("eigh", &dims);
+
+    let device = tensor.device();
+    let n = dims[D - 1];
+
+    // Symmetrize from the lower triangular part.
+    let mut a = tensor.clone().tril(0) + tensor.tril(-1).transpose();
+
+    // Pairs are rotated in parallel, which requires an even number of columns. The padding row and
+    // column are zero, so they are never rotated with the others.
+    if n % 2 == 1 {
+        let mut pad_dims = dims;
+        pad_dims[D - 1] = 1;
+        a = Tensor::cat(vec![a, Tensor::zeros(pad_dims, &device)], D - 1);
+        pad_dims[D - 2] = 1;
+        pad_dims[D - 1] = n + 1;
+        a = Tensor::cat(vec![a, Tensor::zeros(pad_dims, &device)], D - 2);
+    }
+    let width = n + n % 2;
+    let mut v = batched_eye::<B, D>(a.dims(), width, &device);
+    let eye = batched_eye::<B, D>(a.dims(), width / 2, &device);
+
+    let tolerance = tolerance::<B>();
+    let rounds = rounds::<B>(width, &device);
+
+    for _ in 0..MAX_SWEEPS {
+        for (perm, inverse) in rounds.iter() {
+            (a, v) = rotate(a, v, &eye, perm.clone(), inverse.clone());
+        }
+
+        let norm = a.clone().powf_scalar(2.0).sum_dim(D - 1).sum_dim(D - 2);
+        let diagonal = diagonal(a.clone()).powf_scalar(2.0).sum_dim(D - 1);
+        let off = ((norm.clone() - diagonal).clamp_min(0.0) / nonzero(norm))
+            .sqrt()
+            .max();
+
+        let off: f64 = off.into_scalar().elem();
+        if off <= tolerance {
+            break;
+        }
+    }
+
+    // The padding column has an eigenvalue of zero, removed before sorting.
+    let eigenvalues = diagonal(a).narrow(D - 1, 0, n);
+    let vectors = v.narrow(D - 2, 0, n).narrow(D - 1, 0, n);
+    let (eigenvalues, order) = eigenvalues.sort_with_indices(D - 1);
+    let vectors = vectors.gather(D - 1, order.expand(dims));
+
+    (eigenvalues, vectors)
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the eigendecomposition of a batch of symmetric matrices.
+    ///
+    /// See [linalg::eigh](crate::linalg::eigh) for more details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn eigh(self) -> (Self, Self) {
+        eigh(self)
+    }
+}
+
+/// Rotate the disjoint pairs of rows and columns given by the permutation, where the first half
+/// of the permutation is paired with the second half.
+fn rotate<B: Backend, const D: usize>(
+    a: Tensor<B, D>,
+    v: Tensor<B, D>,
+    eye: &Tensor<B, D>,
+    perm: Tensor<B, 1, Int>,
+    inverse: Tensor<B, 1, Int>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let half = perm.dims()[0] / 2;
+
+    let a = a.select(D - 2, perm.clone()).select(D - 1, perm.clone());
+    let diagonal = diagonal(a.clone());
+    let alpha = diagonal.clone().narrow(D - 1, 0, half);
+    let beta = diagonal.narrow(D - 1, half, half);
+    let gamma =
+        (a.clone().narrow(D - 2, 0, half).narrow(D - 1, half, half) * eye.clone()).sum_dim(D - 2);
+
+    let (cos, sin) = rotation(alpha, beta, gamma);
+
+    // A' = J^T A J, rotating the columns then the rows.
+    let a = rotate_columns(a, cos.clone(), sin.clone()).transpose();
+    let a = rotate_columns(a, cos.clone(), sin.clone()).transpose();
+    let a = a
+        .select(D - 2, inverse.clone())
+        .select(D - 1, inverse.clone());
+
+    let v = rotate_columns(v.select(D - 1, perm), cos, sin).select(D - 1, inverse);
+
+    (a, v)
+}
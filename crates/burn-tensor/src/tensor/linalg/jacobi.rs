@@ -0,0 +1,122 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{indices, nonzero};
+use crate::{backend::Backend, DType, Element, Int, Tensor};
+
+/// Maximum number of sweeps over every pair of columns.
+pub(crate) const MAX_SWEEPS: usize = 30;
+
+/// Convergence tolerance of the Jacobi sweeps for the float element of the backend.
+pub(crate) fn tolerance<B: Backend>() -> f64 {
+    match B::FloatElem::dtype() {
+        DType::F64 => 1e-14,
+        _ => 1e-6,
+    }
+}
+
+/// Permutations of the columns for every round of a sweep, with their inverse.
+///
+/// In each permutation, the first half is paired with the second half, so disjoint pairs can be
+/// rotated in parallel.
+pub(crate) fn rounds<B: Backend>(
+    n: usize,
+    device: &B::Device,
+) -> Vec<(Tensor<B, 1, Int>, Tensor<B, 1, Int>)> {
+    round_robin(n)
+        .into_iter()
+        .map(|perm| {
+            let mut inverse = vec![0; n];
+            for (i, p) in perm.iter().enumerate() {
+                inverse[*p] = i;
+            }
+            (indices::<B>(&perm, device), indices::<B>(&inverse, device))
+        })
+        .collect()
+}
+
+/// Computes the cosine and sine of the rotations zeroing `gamma` in the symmetric `2x2` matrices
+/// `[[alpha, gamma], [gamma, beta]]`, using the smallest angle.
+pub(crate) fn rotation<B: Backend, const D: usize>(
+    alpha: Tensor<B, D>,
+    beta: Tensor<B, D>,
+    gamma: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let tau = beta - alpha;
+    let sign = tau.clone().greater_equal_elem(0.0).float() * 2.0 - 1.0;
+    // Avoid the absolute value and the square root of zero, where the gradient isn't defined.
+    let radius =
+        nonzero(tau.clone().powf_scalar(2.0) + gamma.clone().powf_scalar(2.0) * 4.0).sqrt();
+    let t = gamma * sign.clone() * 2.0 / (tau * sign + radius);
+    let cos = (t.clone().powf_scalar(2.0) + 1.0).sqrt().recip();
+    let sin = cos.clone() * t;
+
+    (cos, sin)
+}
+
+/// Rotate the pairs of columns `p` and `q`, returning the rotated columns concatenated.
+fn rotate_pairs<B: Backend, const D: usize>(
+    p: Tensor<B, D>,
+    q: Tensor<B, D>,
+    cos: Tensor<B, D>,
+    sin: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let p_next = p.clone() * cos.clone() - q.clone() * sin.clone();
+    let q_next = p * sin + q * cos;
+
+    Tensor::cat(vec![p_next, q_next], D - 1)
+}
+
+/// Rotate the columns of the permuted matrices, where the first half of the columns is paired with
+/// the second half.
+pub(crate) fn rotate_columns<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    cos: Tensor<B, D>,
+    sin: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let half = tensor.dims()[D - 1] / 2;
+    let p = tensor.clone().narrow(D - 1, 0, half);
+    let q = tensor.narrow(D - 1, half, half);
+
+    rotate_pairs(p, q, cos, sin)
+}
+
+/// Round-robin ordering of `n` columns, `n` being even, where every pair appears exactly once.
+///
+/// Each round is a permutation where the first half is paired with the second half.
+fn round_robin(n: usize) -> Vec<Vec<usize>> {
+    let half = n / 2;
+    let mut players = (0..n).collect::<Vec<_>>();
+    let mut rounds = Vec::with_capacity(n - 1);
+
+    for _ in 0..n - 1 {
+        let mut perm = Vec::with_capacity(n);
+        perm.extend((0..half).map(|i| players[i]));
+        perm.extend((0..half).map(|i| players[n - 1 - i]));
+        rounds.push(perm);
+
+        // The first player is fixed while the others rotate.
+        players[1..].rotate_right(1);
+    }
+
+    rounds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_robin_should_pair_every_column_once() {
+        let mut pairs = round_robin(6)
+            .into_iter()
+            .flat_map(|perm| {
+                (0..3).map(move |i| (perm[i].min(perm[i + 3]), perm[i].max(perm[i + 3])))
+            })
+            .collect::<Vec<_>>();
+        pairs.sort();
+        pairs.dedup();
+
+        assert_eq!(pairs.len(), 15);
+    }
+}
@@ -0,0 +1,55 @@
+use super::check_matrix;
+use crate::{backend::Backend, Tensor};
+
+/// Computes the Kronecker product of two batches of matrices.
+///
+/// The last two dimensions are the `[m, n]` and `[p, q]` matrices, the other ones are batch
+/// dimensions, which are broadcast. The product is the `[..., m * p, n * q]` block matrix where
+/// each block `(i, j)` is `lhs[i, j] * rhs`.
+///
+/// The blocks are computed with a single broadcast multiplication of the matrices reshaped with
+/// interleaved singleton dimensions, followed by a reshape, so the operation is supported by every
+/// backend and is differentiable.
+pub fn kron<B: Backend, const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check_matrix::<D>("kron");
+
+    let lhs_dims = lhs.dims();
+    let rhs_dims = rhs.dims();
+    let [m, n] = [lhs_dims[D - 2], lhs_dims[D - 1]];
+    let [p, q] = [rhs_dims[D - 2], rhs_dims[D - 1]];
+
+    let mut batch = 1;
+    let mut lhs_expanded = lhs_dims;
+    let mut rhs_expanded = rhs_dims;
+    let mut output_dims = lhs_dims;
+    for dim in 0..D - 2 {
+        let size = match (lhs_dims[dim], rhs_dims[dim]) {
+            (a, b) if a == b => a,
+            (1, b) => b,
+            (a, 1) => a,
+            (a, b) => {
+                panic!("kron can't broadcast the batch dimension {dim} of sizes {a} and {b}.")
+            }
+        };
+        lhs_expanded[dim] = size;
+        rhs_expanded[dim] = size;
+        output_dims[dim] = size;
+        batch *= size;
+    }
+    output_dims[D - 2] = m * p;
+    output_dims[D - 1] = n * q;
+
+    let lhs = lhs.expand(lhs_expanded).reshape([batch, m, 1, n, 1]);
+    let rhs = rhs.expand(rhs_expanded).reshape([batch, 1, p, 1, q]);
+
+    (lhs * rhs).reshape(output_dims)
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the Kronecker product of two batches of matrices.
+    ///
+    /// See [linalg::kron](crate::linalg::kron) for more details.
+    pub fn kron(self, other: Self) -> Self {
+        kron(self, other)
+    }
+}
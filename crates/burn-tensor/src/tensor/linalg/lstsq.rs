@@ -0,0 +1,122 @@
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use super::svd;
+use super::{broadcast_batch, check_matrix, qr, triangular_solve, QrMode};
+use crate::{backend::Backend, DType, Element, Tensor};
+
+/// Computes the Moore-Penrose pseudo-inverse of a batch of matrices.
+///
+/// The last two dimensions are the `[m, n]` matrices, the other ones are batch dimensions. The
+/// pseudo-inverse has the shape `[..., n, m]`.
+///
+/// It is computed from the singular value decomposition, where singular values smaller than
+/// `max(m, n) * eps` times the largest one are treated as zero, `eps` being the machine epsilon of
+/// the float element. The pseudo-inverse is computed by the
+/// [backend](crate::ops::FloatTensorOps::float_pinv), natively when it supports it, and otherwise
+/// [composed](svd_pinv) from its [singular value decomposition](svd()).
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn pinv<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_matrix::<D>("pinv");
+
+    let dims = tensor.dims();
+    let rtol = usize::max(dims[D - 2], dims[D - 1]) as f64 * epsilon::<B>();
+
+    Tensor::from_primitive(B::float_pinv(tensor.into_primitive(), rtol))
+}
+
+/// Computes the Moore-Penrose pseudo-inverse of a batch of matrices from their
+/// [singular value decomposition](svd()), where singular values smaller than `rtol` times the
+/// largest one are treated as zero.
+///
+/// This is the reference implementation used by backends without a native pseudo-inverse: it is
+/// supported by every backend and is differentiable. See [pinv] for the shape of the output.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn svd_pinv<B: Backend, const D: usize>(tensor: Tensor<B, D>, rtol: f64) -> Tensor<B, D> {
+    check_matrix::<D>("pinv");
+
+    let (u, s, vt) = svd(tensor);
+
+    let cutoff = s.clone().max_dim(D - 1).mul_scalar(rtol).expand(s.dims());
+    let discarded = s.clone().greater(cutoff).bool_not();
+    let s_inv = s
+        .mask_fill(discarded.clone(), 1.0)
+        .recip()
+        .mask_fill(discarded, 0.0);
+
+    (vt.transpose() * s_inv).matmul(u.transpose())
+}
+
+/// Computes the least squares solutions of the linear systems `A X = B`, minimizing the norm of
+/// `A X - B`.
+///
+/// The matrices `A` have the shape `[..., m, n]` and the right-hand side `B` the shape
+/// `[..., m, k]`. Batch dimensions of size one are broadcast. The solutions have the shape
+/// `[..., n, k]`.
+///
+/// For underdetermined systems (`m < n`), the solution with the minimum norm is returned. The
+/// solutions are computed from the QR decomposition, which requires the matrices to have full
+/// rank. Use the [pseudo-inverse](pinv) for rank-deficient matrices. They are computed by the
+/// [backend](crate::ops::FloatTensorOps::float_lstsq), natively when it supports it, and
+/// otherwise [composed](qr_lstsq) from its [QR decomposition](qr()) and
+/// [triangular solver](triangular_solve).
+pub fn lstsq<B: Backend, const D: usize>(tensor: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check_matrix::<D>("lstsq");
+    let (tensor, rhs) = broadcast_batch("lstsq", tensor, rhs);
+
+    Tensor::from_primitive(B::float_lstsq(
+        tensor.into_primitive(),
+        rhs.into_primitive(),
+    ))
+}
+
+/// Computes the least squares solutions of the linear systems `A X = B` from the
+/// [QR decomposition](qr()) of `A`, or of its transpose for underdetermined systems.
+///
+/// This is the reference implementation used by backends without a native solver: it is
+/// supported by every backend and is differentiable. The batch dimensions of `A` and `B` must be
+/// equal, see [lstsq] for the broadcasting version.
+pub fn qr_lstsq<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> Tensor<B, D> {
+    check_matrix::<D>("lstsq");
+
+    let dims = tensor.dims();
+
+    if dims[D - 2] >= dims[D - 1] {
+        // A = Q R, X = R^-1 Q^T B
+        let (q, r) = qr(tensor, QrMode::Reduced);
+        triangular_solve(r, q.transpose().matmul(rhs), true)
+    } else {
+        // A^T = Q R, X = Q R^-T B
+        let (q, r) = qr(tensor.transpose(), QrMode::Reduced);
+        q.matmul(triangular_solve(r.transpose(), rhs, false))
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the Moore-Penrose pseudo-inverse of a batch of matrices.
+    ///
+    /// See [linalg::pinv](crate::linalg::pinv) for more details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn pinv(self) -> Self {
+        pinv(self)
+    }
+
+    /// Computes the least squares solutions of the linear systems `A X = rhs` where the tensor is a
+    /// batch of matrices `A`.
+    ///
+    /// See [linalg::lstsq](crate::linalg::lstsq) for more details.
+    pub fn lstsq(self, rhs: Self) -> Self {
+        lstsq(self, rhs)
+    }
+}
+
+/// Machine epsilon of the float element of the backend.
+fn epsilon<B: Backend>() -> f64 {
+    match B::FloatElem::dtype() {
+        DType::F64 => f64::EPSILON,
+        DType::F16 => 9.77e-4,
+        DType::BF16 => 7.81e-3,
+        _ => f32::EPSILON as f64,
+    }
+}
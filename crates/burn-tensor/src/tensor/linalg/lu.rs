@@ -0,0 +1,69 @@
+use super::{nonzero, rows_from};
+use crate::{backend::Backend, Int, Tensor};
+
+/// Result of the [Gaussian elimination](eliminate) of a batch of square matrices.
+pub(crate) struct Elimination<B: Backend, const D: usize> {
+    /// The upper triangular matrices `U` such that `P A = L U`.
+    pub upper: Tensor<B, D>,
+    /// The right-hand side with the same row operations applied, `L^-1 P B`.
+    pub rhs: Option<Tensor<B, D>>,
+    /// The sign of the permutation `P`, with the shape `[..., 1, 1]`.
+    pub sign: Tensor<B, D>,
+}
+
+/// Gaussian elimination with partial pivoting, expressed with tensor operations.
+///
+/// The row operations are also applied to the right-hand side when provided. Columns without a
+/// non-zero pivot are skipped, leaving a zero on the diagonal of `U`.
+pub(crate) fn eliminate<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    rhs: Option<Tensor<B, D>>,
+) -> Elimination<B, D> {
+    let device = tensor.device();
+    let dims = tensor.dims();
+    let n = dims[D - 1];
+
+    let mut column_dims = dims;
+    column_dims[D - 1] = 1;
+    let mut column_shape = [1; D];
+    column_shape[D - 2] = n;
+    let rows = Tensor::<B, 1, Int>::arange(0..n as i64, &device)
+        .reshape(column_shape)
+        .expand(column_dims);
+
+    let mut sign_dims = column_dims;
+    sign_dims[D - 2] = 1;
+
+    let mut upper = tensor;
+    let mut rhs = rhs;
+    let mut sign = Tensor::<B, D>::ones(sign_dims, &device);
+
+    for j in 0..n {
+        // Swap the row j with the row of the largest candidate pivot, with P = I - d d^T.
+        let column = upper.clone().narrow(D - 1, j, 1);
+        let candidates = column.abs() * rows_from::<B, D>(n, j, &device);
+        let pivot_row = candidates.argmax(D - 2).expand(column_dims);
+        let pivot_row = rows.clone().equal(pivot_row).float();
+        let row = rows.clone().equal_elem(j as i64).float();
+
+        sign = sign * (pivot_row.clone().narrow(D - 2, j, 1) * 2.0 - 1.0);
+
+        let swap = row - pivot_row;
+        upper = upper.clone() - swap.clone().matmul(swap.clone().transpose().matmul(upper));
+        rhs = rhs.map(|rhs| rhs.clone() - swap.clone().matmul(swap.transpose().matmul(rhs)));
+
+        // Eliminate the column below the pivot.
+        let pivot = upper.clone().narrow(D - 2, j, 1).narrow(D - 1, j, 1);
+        let factors = upper.clone().narrow(D - 1, j, 1) / nonzero(pivot)
+            * rows_from::<B, D>(n, j + 1, &device);
+
+        upper = upper.clone() - factors.clone().matmul(upper.narrow(D - 2, j, 1));
+        rhs = rhs.map(|rhs| rhs.clone() - factors.matmul(rhs.narrow(D - 2, j, 1)));
+    }
+
+    Elimination {
+        upper: upper.triu(0),
+        rhs,
+        sign,
+    }
+}
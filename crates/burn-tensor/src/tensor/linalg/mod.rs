@@ -0,0 +1,21 @@
+mod base;
+mod cholesky;
+mod det;
+mod eigh;
+mod jacobi;
+mod kron;
+mod lstsq;
+mod lu;
+mod qr;
+mod solve;
+mod svd;
+
+pub(crate) use base::*;
+pub use cholesky::*;
+pub use det::*;
+pub use eigh::*;
+pub use kron::*;
+pub use lstsq::*;
+pub use qr::*;
+pub use solve::*;
+pub use svd::*;
@@ -0,0 +1,114 @@
+use alloc::vec;
+
+use super::{batched_eye, check_matrix, nonzero};
+use crate::{backend::Backend, Int, Tensor};
+
+/// The shape of the matrices returned by the [QR decomposition](qr).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QrMode {
+    /// With `k = min(m, n)`, `Q` has the shape `[..., m, k]` and `R` the shape `[..., k, n]`.
+    #[default]
+    Reduced,
+    /// `Q` is a square matrix of shape `[..., m, m]` and `R` has the shape `[..., m, n]`.
+    Complete,
+}
+
+/// Computes the QR decomposition of a batch of matrices.
+///
+/// The last two dimensions are the `[m, n]` matrices, the other ones are batch dimensions. The
+/// decomposition `A = Q R` is returned as `Q` with orthonormal columns and `R` upper triangular,
+/// with shapes depending on the [mode](QrMode).
+///
+/// The diagonal of `R` is made non-negative, which makes the decomposition unique for matrices of
+/// full rank.
+///
+/// The decomposition is computed by the [backend](crate::ops::FloatTensorOps::float_qr), natively
+/// when it supports it, and otherwise with [Householder reflections](householder_qr).
+pub fn qr<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    mode: QrMode,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_matrix::<D>("qr");
+
+    let (q, r) = B::float_qr(tensor.into_primitive(), mode);
+
+    (Tensor::from_primitive(q), Tensor::from_primitive(r))
+}
+
+/// Computes the QR decomposition of a batch of matrices with Householder reflections expressed
+/// with tensor operations.
+///
+/// This is the reference implementation used by backends without a native decomposition: it is
+/// supported by every backend and is differentiable. See [qr](qr()) for the shapes of the
+/// decomposition.
+pub fn householder_qr<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    mode: QrMode,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_matrix::<D>("qr");
+
+    let device = tensor.device();
+    let dims = tensor.dims();
+    let [m, n] = [dims[D - 2], dims[D - 1]];
+    let k = usize::min(m, n);
+
+    let mut q = batched_eye::<B, D>(dims, m, &device);
+    let mut r = tensor;
+
+    for j in 0..k {
+        let x = r.clone().narrow(D - 2, j, m - j).narrow(D - 1, j, 1);
+
+        // Reflect the column onto the first axis, away from its first element to avoid
+        // cancellation.
+        let head = x.clone().narrow(D - 2, 0, 1);
+        let sign = head.greater_equal_elem(0.0).float() * 2.0 - 1.0;
+        let alpha = x.clone().powf_scalar(2.0).sum_dim(D - 2).sqrt() * sign.neg();
+
+        let mut axis_shape = [1; D];
+        axis_shape[D - 2] = m - j;
+        let axis = Tensor::<B, 1, Int>::arange(0..(m - j) as i64, &device)
+            .equal_elem(0)
+            .float()
+            .reshape(axis_shape);
+        let v = x - axis * alpha;
+
+        let norm = v.clone().powf_scalar(2.0).sum_dim(D - 2).sqrt();
+        let mut w = v / nonzero(norm);
+        if j > 0 {
+            let mut zeros_dims = w.dims();
+            zeros_dims[D - 2] = j;
+            w = Tensor::cat(vec![Tensor::zeros(zeros_dims, &device), w], D - 2);
+        }
+
+        // H = I - 2 w w^T
+        r = r.clone() - w.clone().matmul(w.clone().transpose().matmul(r)) * 2.0;
+        q = q.clone() - q.matmul(w.clone()).matmul(w.transpose()) * 2.0;
+    }
+
+    // Flip the signs so that the diagonal of R is non-negative.
+    let diagonal = (r.clone().narrow(D - 2, 0, k).narrow(D - 1, 0, k)
+        * batched_eye::<B, D>(dims, k, &device))
+    .sum_dim(D - 1);
+    let signs = diagonal.greater_equal_elem(0.0).float() * 2.0 - 1.0;
+
+    let q_reduced = q.clone().narrow(D - 1, 0, k) * signs.clone().transpose();
+    let r_reduced = r.clone().narrow(D - 2, 0, k) * signs;
+
+    match mode {
+        QrMode::Reduced => (q_reduced, r_reduced.triu(0)),
+        QrMode::Complete if m > k => (
+            Tensor::cat(vec![q_reduced, q.narrow(D - 1, k, m - k)], D - 1),
+            Tensor::cat(vec![r_reduced, r.narrow(D - 2, k, m - k)], D - 2).triu(0),
+        ),
+        QrMode::Complete => (q_reduced, r_reduced.triu(0)),
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the QR decomposition of a batch of matrices.
+    ///
+    /// See [linalg::qr](crate::linalg::qr) for more details.
+    pub fn qr(self, mode: QrMode) -> (Self, Self) {
+        qr(self, mode)
+    }
+}
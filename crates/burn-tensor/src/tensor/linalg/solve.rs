@@ -0,0 +1,121 @@
+use super::lu::eliminate;
+use super::{back_substitution, batched_eye, broadcast_batch, check_square, forward_substitution};
+use crate::{backend::Backend, Tensor};
+
+/// Solves the linear systems `A X = B` for a batch of square matrices.
+///
+/// The matrices `A` have the shape `[..., n, n]` and the right-hand side `B` the shape
+/// `[..., n, k]`. Batch dimensions of size one are broadcast.
+///
+/// The systems are solved by the [backend](crate::ops::FloatTensorOps::float_solve), natively
+/// when it supports it, and otherwise with [Gaussian elimination](elimination_solve). The result
+/// is undefined when a matrix is singular.
+pub fn solve<B: Backend, const D: usize>(tensor: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check_square::<D>("solve", &tensor.dims());
+    let (tensor, rhs) = broadcast_batch("solve", tensor, rhs);
+
+    Tensor::from_primitive(B::float_solve(
+        tensor.into_primitive(),
+        rhs.into_primitive(),
+    ))
+}
+
+/// Solves the linear systems `A X = B` for a batch of square matrices with Gaussian elimination
+/// and partial pivoting expressed with tensor operations.
+///
+/// This is the reference implementation used by backends without a native solver: it is
+/// supported by every backend and is differentiable. The batch dimensions of `A` and `B` must be
+/// equal, see [solve] for the broadcasting version.
+pub fn elimination_solve<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> Tensor<B, D> {
+    check_square::<D>("solve", &tensor.dims());
+
+    let elimination = eliminate(tensor, Some(rhs));
+    let rhs = elimination
+        .rhs
+        .expect("Right-hand side should be eliminated.");
+
+    back_substitution(elimination.upper, rhs)
+}
+
+/// Solves the linear systems `A X = B` for a batch of triangular matrices.
+///
+/// The matrices `A` have the shape `[..., n, n]` and the right-hand side `B` the shape
+/// `[..., n, k]`. Batch dimensions of size one are broadcast. Only the upper or lower triangular
+/// part of `A` is read, depending on `upper`.
+///
+/// The systems are solved by the
+/// [backend](crate::ops::FloatTensorOps::float_triangular_solve), natively when it supports it,
+/// and otherwise [row by row](substitution_solve).
+pub fn triangular_solve<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+    upper: bool,
+) -> Tensor<B, D> {
+    check_square::<D>("triangular_solve", &tensor.dims());
+    let (tensor, rhs) = broadcast_batch("triangular_solve", tensor, rhs);
+
+    Tensor::from_primitive(B::float_triangular_solve(
+        tensor.into_primitive(),
+        rhs.into_primitive(),
+        upper,
+    ))
+}
+
+/// Solves the linear systems `A X = B` for a batch of triangular matrices with forward or back
+/// substitution expressed with tensor operations, row by row.
+///
+/// This is the reference implementation used by backends without a native solver: it is
+/// supported by every backend and is differentiable. The batch dimensions of `A` and `B` must be
+/// equal, see [triangular_solve] for the broadcasting version.
+pub fn substitution_solve<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+    upper: bool,
+) -> Tensor<B, D> {
+    check_square::<D>("triangular_solve", &tensor.dims());
+
+    match upper {
+        true => back_substitution(tensor, rhs),
+        false => forward_substitution(tensor, rhs),
+    }
+}
+
+/// Computes the inverse of a batch of square matrices.
+///
+/// The last two dimensions are the `[n, n]` matrices, the other ones are batch dimensions. The
+/// inverse is computed by [solving](solve) the systems with the identity as right-hand side, so
+/// prefer [solve] when the inverse is only used to multiply another matrix.
+pub fn inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let dims = tensor.dims();
+    check_square::<D>("inverse", &dims);
+
+    let eye = batched_eye::<B, D>(dims, dims[D - 1], &tensor.device());
+    solve(tensor, eye)
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Solves the linear systems `A X = rhs` where the tensor is a batch of square matrices `A`.
+    ///
+    /// See [linalg::solve](crate::linalg::solve) for more details.
+    pub fn solve(self, rhs: Self) -> Self {
+        solve(self, rhs)
+    }
+
+    /// Solves the linear systems `A X = rhs` where the tensor is a batch of triangular matrices
+    /// `A`.
+    ///
+    /// See [linalg::triangular_solve](crate::linalg::triangular_solve) for more details.
+    pub fn triangular_solve(self, rhs: Self, upper: bool) -> Self {
+        triangular_solve(self, rhs, upper)
+    }
+
+    /// Computes the inverse of a batch of square matrices.
+    ///
+    /// See [linalg::inverse](crate::linalg::inverse) for more details.
+    pub fn inverse(self) -> Self {
+        inverse(self)
+    }
+}
@@ -0,0 +1,158 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::jacobi::{rotate_columns, rotation, rounds, tolerance, MAX_SWEEPS};
+use super::{batched_eye, check_matrix, nonzero};
+use crate::{backend::Backend, ElementConversion, Int, Tensor};
+
+/// Computes the reduced singular value decomposition of a batch of matrices.
+///
+/// The last two dimensions are the `[m, n]` matrices, the other ones are batch dimensions. With
+/// `k = min(m, n)`, the decomposition `A = U diag(S) Vt` is returned as:
+///
+/// - `U` of shape `[..., m, k]` with orthonormal columns,
+/// - `S` of shape `[..., 1, k]` with the singular values in descending order,
+/// - `Vt` of shape `[..., k, n]` with orthonormal rows.
+///
+/// The singular values keep the dimension of the rows so that `U * S` broadcasts, similar to
+/// reductions with [sum_dim](Tensor::sum_dim).
+///
+/// The decomposition is computed by the [backend](crate::ops::FloatTensorOps::float_svd),
+/// natively when it supports it, and otherwise with [one-sided Jacobi rotations](jacobi_svd). The
+/// signs of the singular vectors may differ between backends. Columns of `U` associated with a
+/// singular value of zero are set to zero.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check_matrix::<D>("svd");
+
+    let (u, s, vt) = B::float_svd(tensor.into_primitive());
+
+    (
+        Tensor::from_primitive(u),
+        Tensor::from_primitive(s),
+        Tensor::from_primitive(vt),
+    )
+}
+
+/// Computes the reduced singular value decomposition of a batch of matrices with one-sided Jacobi
+/// rotations expressed with tensor operations.
+///
+/// This is the reference implementation used by backends without a native decomposition: it is
+/// supported by every backend and is differentiable, but every rotation is a sequence of tensor
+/// operations. See [svd] for the shapes of the decomposition.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn jacobi_svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check_matrix::<D>("svd");
+
+    let [m, n] = [tensor.dims()[D - 2], tensor.dims()[D - 1]];
+
+    if m < n {
+        let (u, s, vt) = jacobi_svd(tensor.transpose());
+        return (vt.transpose(), s, u.transpose());
+    }
+
+    let (a, v) = jacobi(tensor);
+
+    let sigma = a.clone().powf_scalar(2.0).sum_dim(D - 2).sqrt();
+    let (sigma, order) = sigma.sort_descending_with_indices(D - 1);
+
+    let mut u_dims = a.dims();
+    u_dims[D - 2] = m;
+    let u = a.gather(D - 1, order.clone().expand(u_dims)) / nonzero(sigma.clone());
+
+    let mut v_dims = u_dims;
+    v_dims[D - 2] = n;
+    let v = v.gather(D - 1, order.expand(v_dims));
+
+    (u, sigma, v.transpose())
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the reduced singular value decomposition of a batch of matrices.
+    ///
+    /// See [linalg::svd](crate::linalg::svd) for more details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn svd(self) -> (Self, Self, Self) {
+        svd(self)
+    }
+}
+
+/// Orthogonalize the columns of the `[..., m, n]` matrices, with `m >= n`.
+///
+/// Returns the orthogonalized columns `A V` and the accumulated rotations `V`.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn jacobi<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    let device = tensor.device();
+    let mut dims = tensor.dims();
+    let n = dims[D - 1];
+
+    // Pairs are rotated in parallel, which requires an even number of columns.
+    let mut a = match n % 2 {
+        0 => tensor,
+        _ => {
+            dims[D - 1] = 1;
+            Tensor::cat(vec![tensor, Tensor::zeros(dims, &device)], D - 1)
+        }
+    };
+    let width = n + n % 2;
+    let mut v = batched_eye::<B, D>(a.dims(), width, &device);
+
+    let tolerance = tolerance::<B>();
+    let rounds = rounds::<B>(width, &device);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut off = Vec::with_capacity(rounds.len());
+
+        for (perm, inverse) in rounds.iter() {
+            let (a_next, v_next, off_round) = rotate(a, v, perm.clone(), inverse.clone());
+            a = a_next;
+            v = v_next;
+            off.push(off_round.max());
+        }
+
+        let off: f64 = Tensor::cat(off, 0).max().into_scalar().elem();
+        if off <= tolerance {
+            break;
+        }
+    }
+
+    if width != n {
+        a = a.narrow(D - 1, 0, n);
+        v = v.narrow(D - 2, 0, n).narrow(D - 1, 0, n);
+    }
+
+    (a, v)
+}
+
+/// Rotate the disjoint pairs of columns given by the permutation, where the first half of the
+/// permutation is paired with the second half.
+///
+/// Returns the rotated matrices and the cosine of the angle between each pair before the rotation.
+fn rotate<B: Backend, const D: usize>(
+    a: Tensor<B, D>,
+    v: Tensor<B, D>,
+    perm: Tensor<B, 1, Int>,
+    inverse: Tensor<B, 1, Int>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    let half = perm.dims()[0] / 2;
+
+    let a = a.select(D - 1, perm.clone());
+    let p = a.clone().narrow(D - 1, 0, half);
+    let q = a.clone().narrow(D - 1, half, half);
+
+    let alpha = p.clone().powf_scalar(2.0).sum_dim(D - 2);
+    let beta = q.clone().powf_scalar(2.0).sum_dim(D - 2);
+    let gamma = (p * q).sum_dim(D - 2);
+
+    let off = gamma.clone().abs() / nonzero((alpha.clone() * beta.clone()).sqrt());
+    let (cos, sin) = rotation(alpha, beta, gamma);
+
+    let a = rotate_columns(a, cos.clone(), sin.clone()).select(D - 1, inverse.clone());
+    let v = rotate_columns(v.select(D - 1, perm), cos, sin).select(D - 1, inverse);
+
+    (a, v, off)
+}
@@ -4,13 +4,17 @@ mod api;
 mod data;
 mod distribution;
 mod element;
+mod generator;
 mod shape;
+mod tolerance;
 
 pub use api::*;
 pub use data::*;
 pub use distribution::*;
 pub use element::*;
+pub use generator::*;
 pub use shape::*;
+pub use tolerance::Tolerance;
 
 /// The activation module.
 pub mod activation;
@@ -21,6 +25,12 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// Rearrange, reduce and repeat tensors with einops patterns.
+pub mod einops;
+
+/// The linear algebra module.
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
@@ -30,6 +40,15 @@ pub mod module;
 /// Operations on tensors module.
 pub mod ops;
 
+/// The sparse tensor module.
+pub mod sparse;
+
+/// The ragged tensor module.
+pub mod ragged;
+
+/// The quantization module.
+pub mod quantization;
+
 #[cfg(feature = "experimental-named-tensor")]
 mod named;
 #[cfg(feature = "experimental-named-tensor")]
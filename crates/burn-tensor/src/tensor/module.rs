@@ -1,9 +1,18 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
-    Int, Tensor,
+    check,
+    check::TensorCheck,
+    ops::{
+        boxes, AttentionOptions, BoxIouMode, ConvOptions, ConvTransposeOptions, GridSampleOptions,
+        InterpolateOptions, ResampleOptions, RoiAlignOptions, RoiPoolOptions, UnfoldOptions,
+        WindowAttentionOptions,
+    },
+    Bool, Int, Tensor,
 };
 
+/// Added to the denominators of the box overlaps, which vanish for degenerate boxes.
+const BOX_IOU_EPS: f64 = 1e-7;
+
 /// Applies the [embedding module](crate::ops::ModuleOps::embedding).
 pub fn embedding<B>(weights: Tensor<B, 2>, indices: Tensor<B, 2, Int>) -> Tensor<B, 3>
 where
@@ -12,6 +21,195 @@ where
     Tensor::new(B::embedding(weights.primitive, indices.primitive))
 }
 
+/// Applies the [scaled dot-product attention](crate::ops::ModuleOps::attention).
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::attention;
+/// use burn_tensor::ops::AttentionOptions;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let query = Tensor::<B, 4>::from_floats([[[[1.0, 0.0]]]], &device);
+///     let key = Tensor::<B, 4>::from_floats([[[[1.0, 0.0], [0.0, 1.0]]]], &device);
+///     let value = Tensor::<B, 4>::from_floats([[[[1.0], [3.0]]]], &device);
+///
+///     let options = AttentionOptions::new(1.0, -1.0e4, false);
+///     let output = attention(query, key, value, None, options);
+///     println!("{output}");
+///     // [[[[1.5378828]]]]
+/// }
+/// ```
+pub fn attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    mask: Option<Tensor<B, 4, Bool>>,
+    options: AttentionOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::attention(
+        &query.shape(),
+        &key.shape(),
+        &value.shape(),
+        mask.as_ref().map(|mask| mask.shape()).as_ref(),
+    ));
+
+    Tensor::new(B::attention(
+        query.primitive,
+        key.primitive,
+        value.primitive,
+        mask.map(|mask| mask.primitive),
+        options,
+    ))
+}
+
+/// Applies the [sliding window attention](crate::ops::ModuleOps::window_attention), with
+/// optional global tokens.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::window_attention;
+/// use burn_tensor::ops::WindowAttentionOptions;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let query = Tensor::<B, 4>::from_floats([[[[1.0], [1.0], [1.0]]]], &device);
+///     let key = Tensor::<B, 4>::from_floats([[[[0.0], [0.0], [0.0]]]], &device);
+///     let value = Tensor::<B, 4>::from_floats([[[[1.0], [2.0], [3.0]]]], &device);
+///
+///     // Causal attention over the current and the previous position.
+///     let options = WindowAttentionOptions::new(1.0, 1, 0, false);
+///     let output = window_attention(query, key, value, None, options);
+///     println!("{output}");
+///     // [[[[1.0], [1.5], [2.5]]]]
+/// }
+/// ```
+pub fn window_attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    global: Option<Tensor<B, 2, Bool>>,
+    options: WindowAttentionOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::window_attention(
+        &query.shape(),
+        &key.shape(),
+        &value.shape(),
+        global.as_ref().map(|global| global.shape()).as_ref(),
+    ));
+
+    Tensor::new(B::window_attention(
+        query.primitive,
+        key.primitive,
+        value.primitive,
+        global.map(|global| global.primitive),
+        options,
+    ))
+}
+
+/// Applies the [selective scan](crate::ops::ModuleOps::selective_scan) of a state space model.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::selective_scan;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let u = Tensor::<B, 3>::from_floats([[[1.0, 1.0, 1.0]]], &device);
+///     let delta = Tensor::<B, 3>::from_floats([[[1.0, 1.0, 1.0]]], &device);
+///     let a = Tensor::<B, 2>::from_floats([[-1.0]], &device);
+///     let b = Tensor::<B, 3>::from_floats([[[1.0, 1.0, 1.0]]], &device);
+///     let c = Tensor::<B, 3>::from_floats([[[1.0, 1.0, 1.0]]], &device);
+///
+///     let output = selective_scan(u, delta, a, b, c);
+///     println!("{output}");
+///     // [[[1.0, 1.3678794, 1.5032147]]]
+/// }
+/// ```
+pub fn selective_scan<B>(
+    u: Tensor<B, 3>,
+    delta: Tensor<B, 3>,
+    a: Tensor<B, 2>,
+    b: Tensor<B, 3>,
+    c: Tensor<B, 3>,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    check!(TensorCheck::selective_scan(
+        &u.shape(),
+        &delta.shape(),
+        &a.shape(),
+        &b.shape(),
+        &c.shape(),
+    ));
+
+    Tensor::new(B::selective_scan(
+        u.primitive,
+        delta.primitive,
+        a.primitive,
+        b.primitive,
+        c.primitive,
+    ))
+}
+
+/// Multiplies the input by [quantized weights](crate::ops::ModuleOps::dequantize_matmul),
+/// dequantized with the scale of each output feature.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::dequantize_matmul;
+/// use burn_tensor::{Int, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let x = Tensor::<B, 3>::from_floats([[[1.0, 2.0]]], &device);
+///     let weight = Tensor::<B, 2, Int>::from_ints([[127, -64], [0, 32]], &device);
+///     let scale = Tensor::<B, 1>::from_floats([0.01, 0.1], &device);
+///
+///     let output = dequantize_matmul(x, weight, scale);
+///     println!("{output}");
+///     // [[[1.27, 0.0]]]
+/// }
+/// ```
+pub fn dequantize_matmul<B>(
+    x: Tensor<B, 3>,
+    weight: Tensor<B, 2, Int>,
+    scale: Tensor<B, 1>,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    check!(TensorCheck::dequantize_matmul(
+        &x.shape(),
+        &weight.shape(),
+        &scale.shape(),
+    ));
+
+    Tensor::new(B::dequantize_matmul(
+        x.primitive,
+        weight.primitive,
+        scale.primitive,
+    ))
+}
+
 /// Applies a [1D convolution](crate::ops::ModuleOps::conv2d).
 pub fn conv1d<B>(
     x: Tensor<B, 3>,
@@ -231,3 +429,455 @@ where
 {
     Tensor::new(B::interpolate(x.primitive, output_size, options))
 }
+
+/// Applies a [3D interpolation](crate::ops::ModuleOps::interpolate3d).
+pub fn interpolate3d<B>(
+    x: Tensor<B, 5>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::interpolate3d(x.primitive, output_size, options))
+}
+
+/// Applies a [morphological dilation](crate::ops::ModuleOps::dilate), replacing each value by
+/// the maximum of its neighborhood given by the structuring element.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::dilate;
+/// use burn_tensor::{Bool, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let x = Tensor::<B, 4>::from_floats([[[[0.0, 0.0, 1.0, 0.0, 0.0]]]], &device);
+///     let kernel = Tensor::<B, 2, Bool>::from_bool([[true, true, true]].into(), &device);
+///
+///     let output = dilate(x, kernel);
+///     println!("{output}");
+///     // [[[[0.0, 1.0, 1.0, 1.0, 0.0]]]]
+/// }
+/// ```
+pub fn dilate<B>(x: Tensor<B, 4>, kernel: Tensor<B, 2, Bool>) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::morphology("Dilate", &kernel.shape()));
+
+    Tensor::new(B::dilate(x.primitive, kernel.primitive))
+}
+
+/// Applies a [morphological erosion](crate::ops::ModuleOps::erode), replacing each value by the
+/// minimum of its neighborhood given by the structuring element.
+pub fn erode<B>(x: Tensor<B, 4>, kernel: Tensor<B, 2, Bool>) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::morphology("Erode", &kernel.shape()));
+
+    Tensor::new(B::erode(x.primitive, kernel.primitive))
+}
+
+/// Applies a morphological opening, an [erosion](erode) followed by a [dilation](dilate) with
+/// the same structuring element, removing the bright details smaller than it.
+pub fn opening<B>(x: Tensor<B, 4>, kernel: Tensor<B, 2, Bool>) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::morphology("Opening", &kernel.shape()));
+
+    let eroded = B::erode(x.primitive, kernel.primitive.clone());
+    Tensor::new(B::dilate(eroded, kernel.primitive))
+}
+
+/// Applies a morphological closing, a [dilation](dilate) followed by an [erosion](erode) with
+/// the same structuring element, filling the dark details smaller than it.
+pub fn closing<B>(x: Tensor<B, 4>, kernel: Tensor<B, 2, Bool>) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::morphology("Closing", &kernel.shape()));
+
+    let dilated = B::dilate(x.primitive, kernel.primitive.clone());
+    Tensor::new(B::erode(dilated, kernel.primitive))
+}
+
+/// Applies a [pixel shuffle](crate::ops::ModuleOps::pixel_shuffle), rearranging channels into
+/// spatial blocks.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::pixel_shuffle;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let x = Tensor::<B, 4>::from_floats([[[[1.0]], [[2.0]], [[3.0]], [[4.0]]]], &device);
+///
+///     let output = pixel_shuffle(x, 2);
+///     println!("{output}");
+///     // [[[[1.0, 2.0], [3.0, 4.0]]]]
+/// }
+/// ```
+pub fn pixel_shuffle<B>(x: Tensor<B, 4>, upscale_factor: usize) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::pixel_shuffle(&x.shape(), upscale_factor));
+
+    Tensor::new(B::pixel_shuffle(x.primitive, upscale_factor))
+}
+
+/// Applies a [pixel unshuffle](crate::ops::ModuleOps::pixel_unshuffle), rearranging spatial
+/// blocks into channels.
+pub fn pixel_unshuffle<B>(x: Tensor<B, 4>, downscale_factor: usize) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::pixel_unshuffle(&x.shape(), downscale_factor));
+
+    Tensor::new(B::pixel_unshuffle(x.primitive, downscale_factor))
+}
+
+/// Applies a [resampling](crate::ops::ModuleOps::resample) of signals from the original rate to
+/// the new one.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::resample;
+/// use burn_tensor::ops::ResampleOptions;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     // One second of audio sampled at 44.1 kHz.
+///     let signal = Tensor::<B, 2>::zeros([1, 44100], &device);
+///
+///     let output = resample(signal, 44100, 16000, ResampleOptions::default());
+///     assert_eq!(output.dims(), [1, 16000]);
+/// }
+/// ```
+pub fn resample<B>(
+    x: Tensor<B, 2>,
+    orig_freq: usize,
+    new_freq: usize,
+    options: ResampleOptions,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    check!(TensorCheck::resample(orig_freq, new_freq, &options));
+
+    Tensor::new(B::resample(x.primitive, orig_freq, new_freq, options))
+}
+
+/// Applies a [2D grid sampling](crate::ops::ModuleOps::grid_sample).
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::grid_sample;
+/// use burn_tensor::ops::GridSampleOptions;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let x = Tensor::<B, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+///     // Samples the center of the input.
+///     let grid = Tensor::<B, 4>::zeros([1, 1, 1, 2], &device);
+///
+///     let output = grid_sample(x, grid, GridSampleOptions::default());
+///     println!("{output}");
+///     // [[[[2.5]]]]
+/// }
+/// ```
+pub fn grid_sample<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::grid_sample(&x.shape(), &grid.shape()));
+
+    Tensor::new(B::grid_sample(x.primitive, grid.primitive, options))
+}
+
+/// Generates the sampling grid of [grid_sample] for a batch of 2D affine transformations.
+///
+/// Each `2x3` matrix maps the normalized coordinates `(x, y, 1)` of the output locations to the
+/// coordinates to sample in the input, both in `[-1, 1]`.
+///
+/// # Arguments
+///
+/// * `theta` - The affine matrices, of shape `[batch_size, 2, 3]`.
+/// * `size` - The size `[batch_size, channels, height, width]` of the output of [grid_sample].
+/// * `align_corners` - If true, the coordinates `-1` and `1` are the centers of the corner pixels,
+///   otherwise they are the outer edges of the corner pixels. It should be the same as the one
+///   used by [grid_sample].
+///
+/// # Returns
+///
+/// The grid of shape `[batch_size, height, width, 2]`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::{affine_grid, grid_sample};
+/// use burn_tensor::ops::GridSampleOptions;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let x = Tensor::<B, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+///     // Flips the input horizontally.
+///     let theta = Tensor::<B, 3>::from_floats([[[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]], &device);
+///
+///     let grid = affine_grid(theta, [1, 1, 2, 2], false);
+///     let output = grid_sample(x, grid, GridSampleOptions::default());
+///     println!("{output}");
+///     // [[[[2.0, 1.0], [4.0, 3.0]]]]
+/// }
+/// ```
+pub fn affine_grid<B>(theta: Tensor<B, 3>, size: [usize; 4], align_corners: bool) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::affine_grid(&theta.shape(), &size));
+
+    let [batch_size, _, height, width] = size;
+    let device = theta.device();
+
+    let xs = normalized_coordinates::<B>(width, align_corners, &device)
+        .reshape([1, width, 1])
+        .expand([height, width, 1]);
+    let ys = normalized_coordinates::<B>(height, align_corners, &device)
+        .reshape([height, 1, 1])
+        .expand([height, width, 1]);
+    let ones = Tensor::ones([height, width, 1], &device);
+
+    let base = Tensor::cat(alloc::vec![xs, ys, ones], 2)
+        .reshape([1, height * width, 3])
+        .expand([batch_size, height * width, 3]);
+
+    base.matmul(theta.swap_dims(1, 2))
+        .reshape([batch_size, height, width, 2])
+}
+
+/// Applies a [region of interest align](crate::ops::ModuleOps::roi_align).
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::roi_align;
+/// use burn_tensor::ops::RoiAlignOptions;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let x = Tensor::<B, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+///     // The whole input of the first sample.
+///     let boxes = Tensor::<B, 2>::from_floats([[0.0, 0.0, 0.0, 2.0, 2.0]], &device);
+///
+///     let output = roi_align(x, boxes, RoiAlignOptions::new([1, 1], 1.0, Some(1), true));
+///     println!("{output}");
+///     // [[[[2.5]]]]
+/// }
+/// ```
+pub fn roi_align<B>(x: Tensor<B, 4>, boxes: Tensor<B, 2>, options: RoiAlignOptions) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::roi("Roi Align", &boxes.shape()));
+
+    Tensor::new(B::roi_align(x.primitive, boxes.primitive, options))
+}
+
+/// Applies a [region of interest pooling](crate::ops::ModuleOps::roi_pool).
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::roi_pool;
+/// use burn_tensor::ops::RoiPoolOptions;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let x = Tensor::<B, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+///     // The pixels of the first column of the first sample.
+///     let boxes = Tensor::<B, 2>::from_floats([[0.0, 0.0, 0.0, 0.0, 1.0]], &device);
+///
+///     let output = roi_pool(x, boxes, RoiPoolOptions::new([1, 1], 1.0));
+///     println!("{output}");
+///     // [[[[3.0]]]]
+/// }
+/// ```
+pub fn roi_pool<B>(x: Tensor<B, 4>, boxes: Tensor<B, 2>, options: RoiPoolOptions) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::roi("Roi Pool", &boxes.shape()));
+
+    Tensor::new(B::roi_pool(x.primitive, boxes.primitive, options))
+}
+
+/// Applies [non-maximum suppression](crate::ops::ModuleOps::nms) to boxes given as
+/// `(x1, y1, x2, y2)`, returning the indices of the kept boxes by decreasing score.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::nms;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let boxes = Tensor::<B, 2>::from_floats(
+///         [[0.0, 0.0, 2.0, 2.0], [0.0, 0.0, 2.0, 1.8], [3.0, 3.0, 4.0, 4.0]],
+///         &device,
+///     );
+///     let scores = Tensor::<B, 1>::from_floats([0.8, 0.9, 0.7], &device);
+///
+///     let kept = nms(boxes, scores, 0.5);
+///     println!("{kept}");
+///     // [1, 2]
+/// }
+/// ```
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn nms<B>(boxes: Tensor<B, 2>, scores: Tensor<B, 1>, iou_threshold: f32) -> Tensor<B, 1, Int>
+where
+    B: Backend,
+{
+    check!(TensorCheck::nms(&boxes.shape(), &scores.shape(), None));
+
+    Tensor::new(B::nms(boxes.primitive, scores.primitive, iou_threshold))
+}
+
+/// Applies [non-maximum suppression](crate::ops::ModuleOps::batched_nms) independently to the
+/// boxes of each category, returning the indices of the kept boxes by decreasing score.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn batched_nms<B>(
+    boxes: Tensor<B, 2>,
+    scores: Tensor<B, 1>,
+    idxs: Tensor<B, 1, Int>,
+    iou_threshold: f32,
+) -> Tensor<B, 1, Int>
+where
+    B: Backend,
+{
+    check!(TensorCheck::nms(
+        &boxes.shape(),
+        &scores.shape(),
+        Some(&idxs.shape())
+    ));
+
+    Tensor::new(B::batched_nms(
+        boxes.primitive,
+        scores.primitive,
+        idxs.primitive,
+        iou_threshold,
+    ))
+}
+
+/// Returns the overlap of every pair of boxes given as `(x1, y1, x2, y2)`, measured by the
+/// intersection over union or one of its [variants](BoxIouMode).
+///
+/// # Shapes
+///
+/// lhs: `[n, 4]`,
+/// rhs: `[m, 4]`,
+/// returns: `[n, m]`,
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::box_iou;
+/// use burn_tensor::ops::BoxIouMode;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let lhs = Tensor::<B, 2>::from_floats([[0.0, 0.0, 2.0, 2.0]], &device);
+///     let rhs = Tensor::<B, 2>::from_floats([[1.0, 0.0, 3.0, 2.0], [0.0, 3.0, 1.0, 4.0]], &device);
+///
+///     let iou = box_iou(lhs, rhs, BoxIouMode::Iou);
+///     println!("{iou}");
+///     // [[0.33333334, 0.0]]
+/// }
+/// ```
+pub fn box_iou<B>(lhs: Tensor<B, 2>, rhs: Tensor<B, 2>, mode: BoxIouMode) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    check!(TensorCheck::box_iou(
+        "Box Iou",
+        &lhs.shape(),
+        &rhs.shape(),
+        false
+    ));
+
+    boxes::pairwise_box_iou(lhs, rhs, mode, BOX_IOU_EPS)
+}
+
+/// Returns the overlap of each box given as `(x1, y1, x2, y2)` with the box at the same index,
+/// measured by the intersection over union or one of its [variants](BoxIouMode).
+///
+/// The overlap is differentiable, so it can be used as a loss between the predicted and the
+/// target boxes.
+///
+/// # Shapes
+///
+/// lhs: `[n, 4]`,
+/// rhs: `[n, 4]`,
+/// returns: `[n]`,
+pub fn paired_box_iou<B>(lhs: Tensor<B, 2>, rhs: Tensor<B, 2>, mode: BoxIouMode) -> Tensor<B, 1>
+where
+    B: Backend,
+{
+    check!(TensorCheck::box_iou(
+        "Paired Box Iou",
+        &lhs.shape(),
+        &rhs.shape(),
+        true
+    ));
+
+    boxes::paired_box_iou(lhs, rhs, mode, BOX_IOU_EPS)
+}
+
+/// Returns the normalized coordinates of the pixels along a dimension of the given size.
+fn normalized_coordinates<B: Backend>(
+    size: usize,
+    align_corners: bool,
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    if size <= 1 {
+        return Tensor::zeros([size], device);
+    }
+
+    let indices = Tensor::<B, 1, Int>::arange(0..size as i64, device).float();
+
+    match align_corners {
+        true => indices.mul_scalar(2.0 / (size - 1) as f64).sub_scalar(1.0),
+        false => indices
+            .mul_scalar(2.0)
+            .add_scalar(1.0)
+            .div_scalar(size as f64)
+            .sub_scalar(1.0),
+    }
+}
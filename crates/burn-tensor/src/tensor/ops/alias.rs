@@ -20,3 +20,11 @@ pub type FloatTensor<B, const D: usize> = <B as Backend>::FloatTensorPrimitive<D
 pub type IntTensor<B, const D: usize> = <B as Backend>::IntTensorPrimitive<D>;
 /// Boolean tensor primitive type used by the backend.
 pub type BoolTensor<B, const D: usize> = <B as Backend>::BoolTensorPrimitive<D>;
+
+/// Hook called with the gradient of a float tensor during the backward pass.
+pub type FloatGradHook<B, const D: usize> =
+    alloc::sync::Arc<dyn Fn(FloatTensor<B, D>) + Send + Sync>;
+
+/// Function replacing the gradient of a float tensor during the backward pass.
+pub type FloatGradMap<B, const D: usize> =
+    alloc::sync::Arc<dyn Fn(FloatTensor<B, D>) -> FloatTensor<B, D> + Send + Sync>;
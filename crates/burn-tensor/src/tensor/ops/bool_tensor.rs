@@ -1,6 +1,6 @@
 use super::{
-    cat::cat_with_slice_assign, repeat::repeat_with_slice_assign, BoolTensor, Device, FloatTensor,
-    IntTensor,
+    bits, cat::cat_with_slice_assign, repeat::repeat_with_slice_assign,
+    slice::slice_with_steps_reshape, BoolTensor, Device, FloatTensor, IntTensor,
 };
 use crate::{
     backend::Backend, chunk, narrow, tensor::Shape, Bool, ElementConversion, Tensor, TensorData,
@@ -49,6 +49,24 @@ pub trait BoolTensorOps<B: Backend> {
     /// The data structure with the tensor's data.
     fn bool_into_data<const D: usize>(tensor: BoolTensor<B, D>) -> Reader<TensorData>;
 
+    /// Computes a deterministic checksum of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The [checksum](TensorData::checksum) of the tensor's data.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the data of the tensor, backends can compute the same
+    /// checksum on the device to avoid transferring the whole tensor.
+    fn bool_checksum<const D: usize>(tensor: BoolTensor<B, D>) -> Reader<u64> {
+        Self::bool_into_data(tensor).map(|data| data.checksum())
+    }
+
     /// Gets the data from the tensor.
     ///
     ///
@@ -144,6 +162,31 @@ pub trait BoolTensorOps<B: Backend> {
         ranges: [Range<usize>; D2],
     ) -> BoolTensor<B, D1>;
 
+    /// Gets the elements of the tensor for the given ranges, with a step between the selected
+    /// indices of each range.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `ranges` - The ranges to get the elements from.
+    /// * `steps` - The non-zero steps of the ranges, negative ones selecting in reverse order.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the selected elements.
+    fn bool_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<B, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> BoolTensor<B, D1> {
+        slice_with_steps_reshape::<B, D1, D2, Bool>(
+            Tensor::<B, D1, Bool>::from_primitive(tensor),
+            ranges,
+            steps,
+        )
+        .into_primitive()
+    }
+
     /// Sets the values in the tensor for the given ranges.
     ///
     /// # Arguments
@@ -450,4 +493,19 @@ pub trait BoolTensorOps<B: Backend> {
         tensor: BoolTensor<B, D1>,
         shape: Shape<D2>,
     ) -> BoolTensor<B, D2>;
+
+    /// Packs the last dimension of the bool `tensor` into a bitmap of bytes, using 8 times less
+    /// elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pack.
+    ///
+    /// # Returns
+    ///
+    /// The int tensor whose last dimension has the size `ceil(n / 8)`, the `i`-th value of the
+    /// last dimension setting the bit `i % 8` of the byte `i / 8`.
+    fn bool_pack_bits<const D: usize>(tensor: BoolTensor<B, D>) -> IntTensor<B, D> {
+        bits::pack_bits::<B, D>(tensor)
+    }
 }
@@ -1,14 +1,25 @@
+use super::bincount;
+use super::bits;
 use super::cat::cat_with_slice_assign;
+use super::mode;
 use super::repeat::repeat_with_slice_assign;
+use super::segment;
+use super::slice::slice_with_steps_reshape;
 use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
 use crate::cast::ToElement;
-use crate::{backend::Backend, tensor::Shape, Distribution, ElementConversion, Int, TensorData};
+use crate::{
+    backend::Backend, tensor::Shape, Distribution, ElementConversion, IndexReduction, Int,
+    TensorData,
+};
 use crate::{cartesian_grid, Tensor};
 use crate::{tensor::api::chunk, tensor::api::narrow};
 use alloc::vec::Vec;
+use burn_common::rand::{SeedableRng, StdRng};
 use burn_common::reader::Reader;
 use core::ops::Range;
 
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use super::unique;
 #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
 use crate::{argsort, sort, sort_with_indices};
 
@@ -49,6 +60,24 @@ pub trait IntTensorOps<B: Backend> {
     /// The data structure with the tensor's data.
     fn int_into_data<const D: usize>(tensor: IntTensor<B, D>) -> Reader<TensorData>;
 
+    /// Computes a deterministic checksum of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The [checksum](TensorData::checksum) of the tensor's data.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the data of the tensor, backends can compute the same
+    /// checksum on the device to avoid transferring the whole tensor.
+    fn int_checksum<const D: usize>(tensor: IntTensor<B, D>) -> Reader<u64> {
+        Self::int_into_data(tensor).map(|data| data.checksum())
+    }
+
     /// Gets the data from the tensor.
     ///
     /// # Arguments
@@ -121,6 +150,31 @@ pub trait IntTensorOps<B: Backend> {
         indices: [Range<usize>; D2],
     ) -> IntTensor<B, D1>;
 
+    /// Gets the elements of the tensor for the given ranges, with a step between the selected
+    /// indices of each range.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `ranges` - The ranges to get the elements from.
+    /// * `steps` - The non-zero steps of the ranges, negative ones selecting in reverse order.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the selected elements.
+    fn int_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> IntTensor<B, D1> {
+        slice_with_steps_reshape::<B, D1, D2, Int>(
+            Tensor::<B, D1, Int>::from_primitive(tensor),
+            ranges,
+            steps,
+        )
+        .into_primitive()
+    }
+
     /// Sets the element at the given indices.
     ///
     /// # Arguments
@@ -865,7 +919,7 @@ pub trait IntTensorOps<B: Backend> {
     fn int_max_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
         let index = B::int_argmax(tensor.clone(), dim);
 
-        B::int_gather(D - 1, tensor, index)
+        B::int_gather(dim, tensor, index)
     }
 
     /// Gets the maximum elements and corresponding indices along a dimension.
@@ -883,7 +937,7 @@ pub trait IntTensorOps<B: Backend> {
         dim: usize,
     ) -> (IntTensor<B, D>, IntTensor<B, D>) {
         let index = B::int_argmax(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, index.clone());
+        let values = B::int_gather(dim, tensor, index.clone());
 
         (values, index)
     }
@@ -917,7 +971,7 @@ pub trait IntTensorOps<B: Backend> {
     fn int_min_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
         let index = B::int_argmin(tensor.clone(), dim);
 
-        B::int_gather(D - 1, tensor, index)
+        B::int_gather(dim, tensor, index)
     }
 
     /// Gets the minimum elements and corresponding indices along a dimension.
@@ -935,7 +989,7 @@ pub trait IntTensorOps<B: Backend> {
         dim: usize,
     ) -> (IntTensor<B, D>, IntTensor<B, D>) {
         let indices = B::int_argmin(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, indices.clone());
+        let values = B::int_gather(dim, tensor, indices.clone());
 
         (values, indices)
     }
@@ -1091,6 +1145,41 @@ pub trait IntTensorOps<B: Backend> {
         device: &Device<B>,
     ) -> IntTensor<B, D>;
 
+    /// Creates a new int tensor with random values sampled with the given seed, instead of the
+    /// global random number generator of the backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the tensor.
+    /// * `distribution` - The distribution to sample from.
+    /// * `seed` - The seed of the random number generator.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the given shape and random values.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation samples the values on the host, so they are the same for all
+    /// the backends. Like [int_random](IntTensorOps::int_random), the default distribution
+    /// samples values between 0 and 255.
+    fn int_random_seeded<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        seed: u64,
+        device: &Device<B>,
+    ) -> IntTensor<B, D> {
+        let distribution = match distribution {
+            Distribution::Default => Distribution::Uniform(0.0, 255.0),
+            distribution => distribution,
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+        let data = TensorData::random::<IntElem<B>, _, _>(shape, distribution, &mut rng);
+
+        B::int_from_data(data, device)
+    }
+
     /// Creates a new tensor with values from the given range with the given step size.
     ///
     /// # Arguments
@@ -1130,6 +1219,131 @@ pub trait IntTensorOps<B: Backend> {
         Self::int_arange_step(range, 1, device)
     }
 
+    /// Counts the number of occurrences of each value.
+    ///
+    /// The default implementation [assigns ones](super::bincount::select_bincount) at the values
+    /// with tensor operations, and should be overridden by backends with an atomic counting
+    /// kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The values to count.
+    /// * `num_bins` - The number of bins.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of size `num_bins`, where the element at index `i` is the number of occurrences of
+    /// the value `i`. The values are in `[0, num_bins)`, the number of bins being sized by the
+    /// tensor API to count every value.
+    fn int_bincount(tensor: IntTensor<B, 1>, num_bins: usize) -> IntTensor<B, 1> {
+        bincount::select_bincount::<B>(tensor, num_bins)
+    }
+
+    /// Sums the weights of the occurrences of each value.
+    ///
+    /// The default implementation [assigns the weights](super::bincount::select_bincount_weighted)
+    /// at the values with tensor operations, and should be overridden by backends with an atomic
+    /// counting kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The values to count.
+    /// * `weights` - The weight of each value, of the same size as the tensor.
+    /// * `num_bins` - The number of bins.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of size `num_bins`, where the element at index `i` is the sum of the weights of the
+    /// values equal to `i`. The values are in `[0, num_bins)`, the number of bins being sized by
+    /// the tensor API to count every value.
+    fn int_bincount_weighted(
+        tensor: IntTensor<B, 1>,
+        weights: FloatTensor<B, 1>,
+        num_bins: usize,
+    ) -> FloatTensor<B, 1> {
+        bincount::select_bincount_weighted::<B>(tensor, weights, num_bins)
+    }
+
+    /// Sums the slices of the first dimension belonging to the same segment.
+    ///
+    /// The order in which the slices are added is unspecified, so backends can add them
+    /// atomically. The default implementation [assigns the slices](super::segment::select_segment_sum)
+    /// with tensor operations, and should be overridden by backends with an atomic kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `segment_ids` - The segment of each slice of the first dimension, which don't need to be
+    ///   sorted.
+    /// * `num_segments` - The number of segments.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `num_segments` slices on the first dimension, where empty segments are
+    /// filled with zeros.
+    fn int_segment_sum<const D: usize>(
+        tensor: IntTensor<B, D>,
+        segment_ids: IntTensor<B, 1>,
+        num_segments: usize,
+    ) -> IntTensor<B, D> {
+        segment::select_segment_sum::<B, D, Int>(tensor, segment_ids, num_segments)
+    }
+
+    /// Reduces the slices of the first dimension belonging to the same segment, the segment ids
+    /// being sorted.
+    ///
+    /// Each segment is reduced in the order of its slices, so the result is deterministic. The
+    /// default implementation [scans the slices](super::segment::scan_segment_reduce_sorted) with
+    /// tensor operations, and should be overridden by backends with a native kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `segment_ids` - The sorted segment of each slice of the first dimension.
+    /// * `num_segments` - The number of segments.
+    /// * `reduction` - The reduction of the slices of each segment.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `num_segments` slices on the first dimension, where empty segments are
+    /// filled with zeros.
+    fn int_segment_reduce_sorted<const D: usize>(
+        tensor: IntTensor<B, D>,
+        segment_ids: IntTensor<B, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> IntTensor<B, D> {
+        segment::scan_segment_reduce_sorted::<B, D, Int>(
+            tensor,
+            segment_ids,
+            num_segments,
+            reduction,
+        )
+    }
+
+    /// Returns the most frequent value along the given dimension, with its number of occurrences.
+    ///
+    /// The default implementation [compares each pair of values](super::mode::pairwise_mode)
+    /// with tensor operations, using a quadratic amount of memory, and should be overridden by
+    /// backends with a native kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which to find the mode.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the most frequent values, the smallest one among the values occurring the
+    /// same number of times, and their number of occurrences. The given dimension of both has a
+    /// size of 1.
+    fn int_mode<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        mode::pairwise_mode::<B, D>(tensor, dim)
+    }
+
     /// Tests if any element in the int `tensor` evaluates to True.
     ///
     /// # Arguments
@@ -1294,4 +1508,46 @@ pub trait IntTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Int>(tensor, dim, descending)
     }
+
+    /// Collapses the runs of equal elements of the flattened tensor, sorting the elements first
+    /// unless only the consecutive runs are collapsed.
+    ///
+    /// The default implementation [flags the runs](super::unique::sort_unique) with tensor
+    /// operations, and should be overridden by backends with a native kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `consecutive` - Whether only the consecutive runs of the unsorted elements are collapsed.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - The first element of each run.
+    /// - The inverse indices, of the same shape as the tensor, giving the run of each element.
+    /// - The number of elements of each run.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_unique<const D: usize>(
+        tensor: IntTensor<B, D>,
+        consecutive: bool,
+    ) -> unique::UniqueOutput<B, Int, D> {
+        unique::sort_unique::<B, D, Int>(tensor, consecutive)
+    }
+
+    /// Unpacks the bits of the last dimension of a bitmap into a bool tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The bitmap, whose values are bytes from
+    ///   [bool_pack_bits](super::BoolTensorOps::bool_pack_bits).
+    /// * `size` - The size of the last dimension of the unpacked tensor, at most 8 times the
+    ///   size of the last dimension of the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// The bool tensor whose `i`-th value of the last dimension is the bit `i % 8` of the byte
+    /// `i / 8`.
+    fn int_unpack_bits<const D: usize>(tensor: IntTensor<B, D>, size: usize) -> BoolTensor<B, D> {
+        bits::unpack_bits::<B, D>(tensor, size)
+    }
 }
@@ -0,0 +1,122 @@
+use crate::{
+    activation::{quiet_softmax, softmax},
+    backend::Backend,
+    ops::{AttentionOptions, BoolTensor, FloatTensor, WindowAttentionOptions},
+    Bool, Int, Tensor,
+};
+
+/// Computes the scaled dot-product attention, see [attention](super::ModuleOps::attention).
+///
+/// The attention weights of every pair of query and key are materialized, so autodiff backends
+/// compute the gradients of the query, the key and the value, and fused implementations can fall
+/// back to it for the options they don't support.
+pub fn attention<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    mask: Option<BoolTensor<B, 4>>,
+    options: AttentionOptions,
+) -> FloatTensor<B, 4> {
+    let query = Tensor::<B, 4>::from_primitive(query);
+    let key = Tensor::<B, 4>::from_primitive(key);
+    let value = Tensor::<B, 4>::from_primitive(value);
+
+    let mut scores = query.matmul(key.transpose()).mul_scalar(options.scale);
+    if let Some(mask) = mask {
+        scores = scores.mask_fill(Tensor::from_primitive(mask), options.mask_value);
+    }
+
+    let weights = match options.quiet_softmax {
+        true => quiet_softmax(scores, 3),
+        false => softmax(scores, 3),
+    };
+
+    weights.matmul(value).into_primitive()
+}
+
+/// Computes the sliding window attention, see
+/// [window_attention](super::ModuleOps::window_attention).
+///
+/// The keys outside of the window of each query are masked before the
+/// [attention](super::ModuleOps::attention), so the mask of every pair of query and key is
+/// materialized.
+pub fn window_attention<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    global: Option<BoolTensor<B, 2>>,
+    options: WindowAttentionOptions,
+) -> FloatTensor<B, 4> {
+    let [batch_size, n_heads, seq_length_1, _] = B::float_shape(&query).dims;
+    let [_, _, seq_length_2, _] = B::float_shape(&key).dims;
+
+    let mask = window_mask::<B>(
+        batch_size,
+        seq_length_1,
+        seq_length_2,
+        options.window_before,
+        options.window_after,
+        global.map(Tensor::from_primitive),
+        &B::float_device(&query),
+    )
+    .reshape([batch_size, 1, seq_length_1, seq_length_2])
+    .expand([batch_size, n_heads, seq_length_1, seq_length_2]);
+
+    // The position of each query is in its window, so every query attends to a key.
+    let options = AttentionOptions::new(options.scale, f64::NEG_INFINITY, options.quiet_softmax);
+
+    B::attention(query, key, value, Some(mask.into_primitive()), options)
+}
+
+/// The mask of the keys outside of the window of each query, and which aren't global tokens
+/// nor attended by a global token.
+///
+/// # Shapes
+///
+/// global: `[batch_size, seq_length_2]`,
+/// returns: `[batch_size, seq_length_1, seq_length_2]`,
+pub fn window_mask<B: Backend>(
+    batch_size: usize,
+    seq_length_1: usize,
+    seq_length_2: usize,
+    window_before: usize,
+    window_after: usize,
+    global: Option<Tensor<B, 2, Bool>>,
+    device: &B::Device,
+) -> Tensor<B, 3, Bool> {
+    let shape = [batch_size, seq_length_1, seq_length_2];
+    let offset = (seq_length_2 - seq_length_1) as i64;
+
+    let queries = Tensor::<B, 1, Int>::arange(offset..offset + seq_length_1 as i64, device)
+        .reshape([1, seq_length_1, 1])
+        .expand(shape);
+    let keys = Tensor::<B, 1, Int>::arange(0..seq_length_2 as i64, device)
+        .reshape([1, 1, seq_length_2])
+        .expand(shape);
+
+    let before = keys
+        .clone()
+        .add_scalar(window_before as i64)
+        .lower(queries.clone());
+    let after = keys.greater(queries.add_scalar(window_after as i64));
+    let outside = before.int().add(after.int());
+
+    let outside = match global {
+        Some(global) => {
+            let not_global = global.bool_not().int();
+            let keys = not_global
+                .clone()
+                .reshape([batch_size, 1, seq_length_2])
+                .expand(shape);
+            let queries = not_global
+                .slice([0..batch_size, offset as usize..seq_length_2])
+                .reshape([batch_size, seq_length_1, 1])
+                .expand(shape);
+
+            outside.mul(keys).mul(queries)
+        }
+        None => outside,
+    };
+
+    outside.greater_elem(0)
+}
@@ -1,9 +1,15 @@
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use super::nms;
+use super::{
+    attention, conv, dequantize, grid_sample, interpolate, morphology, pixel_shuffle, pool,
+    resample, roi, scan, unfold::unfold4d_using_conv2d,
+};
 use crate::{
     backend::Backend,
-    ops::{FloatTensor, IntTensor},
+    ops::{BoolTensor, FloatTensor, IntTensor},
     Shape,
 };
+use serde::{Deserialize, Serialize};
 
 /// Gradient computed during the backward pass for each tensor used by [conv2d](ModuleOps::conv2d).
 #[derive(new)]
@@ -137,6 +143,149 @@ pub struct InterpolateOptions {
     pub mode: InterpolateMode,
 }
 
+/// Algorithm used to compute the values at the sampled locations of [grid_sample](ModuleOps::grid_sample).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSampleMode {
+    /// Bilinear interpolation of the 4 nearest values.
+    Bilinear,
+
+    /// Value of the nearest location.
+    Nearest,
+}
+
+/// Values used for the locations outside of the input of [grid_sample](ModuleOps::grid_sample).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSamplePaddingMode {
+    /// Zeros.
+    Zeros,
+
+    /// Values at the border of the input.
+    Border,
+
+    /// Values of the input reflected by its borders.
+    Reflection,
+}
+
+/// Grid sampling options.
+#[derive(new, Debug, Clone)]
+pub struct GridSampleOptions {
+    /// Algorithm used to compute the sampled values.
+    pub mode: GridSampleMode,
+
+    /// Values used for the locations outside of the input.
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// If true, the coordinates `-1` and `1` are the centers of the corner pixels of the input,
+    /// otherwise they are the outer edges of the corner pixels.
+    pub align_corners: bool,
+}
+
+impl Default for GridSampleOptions {
+    fn default() -> Self {
+        Self::new(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+        )
+    }
+}
+
+/// Region of interest align options.
+#[derive(new, Debug, Clone)]
+pub struct RoiAlignOptions {
+    /// Height and width of the pooled regions.
+    pub output_size: [usize; 2],
+
+    /// Scale mapping the coordinates of the boxes to the coordinates of the input.
+    pub spatial_scale: f32,
+
+    /// Number of samples along each axis of a bin, adapted to the size of each region when
+    /// `None`.
+    pub sampling_ratio: Option<usize>,
+
+    /// If true, the box coordinates are shifted by half a pixel, so that the pixels are sampled
+    /// at their centers.
+    pub aligned: bool,
+}
+
+/// Region of interest pooling options.
+#[derive(new, Debug, Clone)]
+pub struct RoiPoolOptions {
+    /// Height and width of the pooled regions.
+    pub output_size: [usize; 2],
+
+    /// Scale mapping the coordinates of the boxes to the coordinates of the input.
+    pub spatial_scale: f32,
+}
+
+/// Scaled dot-product attention options.
+#[derive(new, Debug, Clone)]
+pub struct AttentionOptions {
+    /// Factor applied to the dot products of the queries and the keys, usually the inverse of
+    /// the square root of their size.
+    pub scale: f64,
+
+    /// Value replacing the masked scores before the softmax.
+    pub mask_value: f64,
+
+    /// If true, the [quiet softmax](crate::activation::quiet_softmax) is used to compute the
+    /// attention weights, so that a query can attend to none of the keys.
+    pub quiet_softmax: bool,
+}
+
+/// Sliding window attention options, see [window_attention](ModuleOps::window_attention).
+#[derive(new, Debug, Clone)]
+pub struct WindowAttentionOptions {
+    /// Factor applied to the dot products of the queries and the keys, usually the inverse of
+    /// the square root of their size.
+    pub scale: f64,
+
+    /// Number of keys before the position of each query that it attends to.
+    pub window_before: usize,
+
+    /// Number of keys after the position of each query that it attends to, zero for a causal
+    /// attention.
+    pub window_after: usize,
+
+    /// If true, the [quiet softmax](crate::activation::quiet_softmax) is used to compute the
+    /// attention weights.
+    pub quiet_softmax: bool,
+}
+
+/// Overlap measure of two boxes, see [box_iou](crate::module::box_iou).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BoxIouMode {
+    /// The intersection over union.
+    Iou,
+    /// The intersection over union, minus the fraction of the smallest enclosing box not covered
+    /// by the union.
+    Generalized,
+    /// The intersection over union, minus the squared distance between the centers relative to
+    /// the squared diagonal of the smallest enclosing box.
+    Distance,
+    /// The distance intersection over union, minus a penalty on the difference between the
+    /// aspect ratios.
+    Complete,
+}
+
+/// Resampling options.
+#[derive(new, Debug, Clone)]
+pub struct ResampleOptions {
+    /// Number of zero crossings of the windowed sinc filter on each side of its center, a wider
+    /// filter being sharper but more expensive.
+    pub lowpass_filter_width: usize,
+
+    /// Cutoff frequency of the filter, as a fraction of the lowest Nyquist frequency of the
+    /// original and new rates, below one to reduce the aliasing.
+    pub rolloff: f64,
+}
+
+impl Default for ResampleOptions {
+    fn default() -> Self {
+        Self::new(6, 0.99)
+    }
+}
+
 /// Gradient computed during the backward pass for each tensor used by [interpolate](ModuleOps::interpolate).
 #[derive(new)]
 pub struct InterpolateBackward<B: Backend> {
@@ -488,4 +637,284 @@ pub trait ModuleOps<B: Backend> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<B, 4>;
+
+    /// Down/up samples the input along its depth, height and width.
+    ///
+    /// The [bilinear](InterpolateMode::Bilinear) and [bicubic](InterpolateMode::Bicubic) modes
+    /// interpolate along the depth as well, becoming trilinear and tricubic.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, depth, height, width]`,
+    /// returns: `[batch_size, channels, depth_out, height_out, width_out]`,
+    fn interpolate3d(
+        x: FloatTensor<B, 5>,
+        output_size: [usize; 3],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B, 5> {
+        interpolate::interpolate3d::<B>(x, output_size, options)
+    }
+
+    /// Performs a morphological dilation, replacing each value by the maximum of its neighborhood.
+    ///
+    /// The neighborhood is given by the true values of the structuring element, its origin being
+    /// at its center, rounded toward its top left corner. The values outside of the input are
+    /// ignored.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// kernel: `[kernel_height, kernel_width]`,
+    /// returns: `[batch_size, channels, height, width]`,
+    fn dilate(x: FloatTensor<B, 4>, kernel: BoolTensor<B, 2>) -> FloatTensor<B, 4> {
+        morphology::dilate::<B>(x, kernel)
+    }
+
+    /// Performs a morphological erosion, replacing each value by the minimum of its neighborhood.
+    ///
+    /// The neighborhood is the same as for [dilate](ModuleOps::dilate).
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// kernel: `[kernel_height, kernel_width]`,
+    /// returns: `[batch_size, channels, height, width]`,
+    fn erode(x: FloatTensor<B, 4>, kernel: BoolTensor<B, 2>) -> FloatTensor<B, 4> {
+        morphology::erode::<B>(x, kernel)
+    }
+
+    /// Rearranges the channels of the input into spatial blocks, increasing its height and width
+    /// by the upscale factor.
+    ///
+    /// The channels are split into groups of `upscale_factor * upscale_factor`, each group forming
+    /// the blocks of an output channel, in row-major order.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels * upscale_factor^2, height, width]`,
+    /// returns: `[batch_size, channels, height * upscale_factor, width * upscale_factor]`,
+    fn pixel_shuffle(x: FloatTensor<B, 4>, upscale_factor: usize) -> FloatTensor<B, 4> {
+        pixel_shuffle::pixel_shuffle::<B>(x, upscale_factor)
+    }
+
+    /// Rearranges spatial blocks of the input into channels, the inverse of
+    /// [pixel_shuffle](ModuleOps::pixel_shuffle).
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height * downscale_factor, width * downscale_factor]`,
+    /// returns: `[batch_size, channels * downscale_factor^2, height, width]`,
+    fn pixel_unshuffle(x: FloatTensor<B, 4>, downscale_factor: usize) -> FloatTensor<B, 4> {
+        pixel_shuffle::pixel_unshuffle::<B>(x, downscale_factor)
+    }
+
+    /// Resamples signals from the original rate to the new one, with a windowed sinc filter.
+    ///
+    /// The rates are reduced by their greatest common divisor, each group of `new_freq` output
+    /// values being computed from the same input values with a different phase of the filter. The
+    /// signals are padded with zeros.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, length]`,
+    /// returns: `[batch_size, ceil(length * new_freq / orig_freq)]`,
+    fn resample(
+        x: FloatTensor<B, 2>,
+        orig_freq: usize,
+        new_freq: usize,
+        options: ResampleOptions,
+    ) -> FloatTensor<B, 2> {
+        resample::resample::<B>(x, orig_freq, new_freq, options)
+    }
+
+    /// Computes the scaled dot-product attention of the queries over the keys, the attention
+    /// weights being the softmax of the scaled dot products.
+    ///
+    /// The masked scores, where the mask is true, are replaced by the mask value before the
+    /// softmax. Backends can fuse the operation so that the attention weights are never
+    /// materialized.
+    ///
+    /// # Shapes
+    ///
+    /// query: `[batch_size, n_heads, seq_length_1, d_k]`,
+    /// key: `[batch_size, n_heads, seq_length_2, d_k]`,
+    /// value: `[batch_size, n_heads, seq_length_2, d_v]`,
+    /// mask: `[batch_size, n_heads, seq_length_1, seq_length_2]`,
+    /// returns: `[batch_size, n_heads, seq_length_1, d_v]`,
+    fn attention(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        mask: Option<BoolTensor<B, 4>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<B, 4> {
+        attention::attention::<B>(query, key, value, mask, options)
+    }
+
+    /// Computes the scaled dot-product attention of each query over the keys in a window around
+    /// its position, as in Longformer and Mistral, optionally with global tokens.
+    ///
+    /// The queries are the last positions of the keys, the query `i` being at the position
+    /// `i + seq_length_2 - seq_length_1`, e.g. the new positions when decoding with a cache.
+    /// The global tokens attend to every key, and every query attends to them. Backends can
+    /// fuse the operation so that neither the attention weights nor a mask are materialized,
+    /// and only the keys of the window are scored without global tokens.
+    ///
+    /// # Shapes
+    ///
+    /// query: `[batch_size, n_heads, seq_length_1, d_k]`,
+    /// key: `[batch_size, n_heads, seq_length_2, d_k]`,
+    /// value: `[batch_size, n_heads, seq_length_2, d_v]`,
+    /// global: `[batch_size, seq_length_2]`,
+    /// returns: `[batch_size, n_heads, seq_length_1, d_v]`,
+    fn window_attention(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        global: Option<BoolTensor<B, 2>>,
+        options: WindowAttentionOptions,
+    ) -> FloatTensor<B, 4> {
+        attention::window_attention::<B>(query, key, value, global, options)
+    }
+
+    /// Computes the selective scan of a state space model whose parameters depend on the input,
+    /// as in the Mamba block.
+    ///
+    /// For each channel, the state `h` is updated at each step `t` with
+    /// `h = exp(delta[t] * a) * h + delta[t] * b[t] * u[t]`, and the output is `c[t] · h`.
+    /// Backends can fuse the recurrence so that the states are never materialized.
+    ///
+    /// # Shapes
+    ///
+    /// u: `[batch_size, channels, length]`,
+    /// delta: `[batch_size, channels, length]`,
+    /// a: `[channels, d_state]`,
+    /// b: `[batch_size, d_state, length]`,
+    /// c: `[batch_size, d_state, length]`,
+    /// returns: `[batch_size, channels, length]`,
+    fn selective_scan(
+        u: FloatTensor<B, 3>,
+        delta: FloatTensor<B, 3>,
+        a: FloatTensor<B, 2>,
+        b: FloatTensor<B, 3>,
+        c: FloatTensor<B, 3>,
+    ) -> FloatTensor<B, 3> {
+        scan::selective_scan::<B>(u, delta, a, b, c)
+    }
+
+    /// Multiplies the input by weights quantized symmetrically per output feature, dequantizing
+    /// them on the fly, `x @ (weight * scale)`.
+    ///
+    /// The weights are the integer values of the quantized weights, e.g. in the range of 8-bit or
+    /// 4-bit integers, and the scale of each output feature maps them back to floating point
+    /// values. Backends can dequantize the weights while computing the product, so that the
+    /// floating point weights are never materialized.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, seq_length, d_input]`,
+    /// weight: `[d_input, d_output]`,
+    /// scale: `[d_output]`,
+    /// returns: `[batch_size, seq_length, d_output]`,
+    fn dequantize_matmul(
+        x: FloatTensor<B, 3>,
+        weight: IntTensor<B, 2>,
+        scale: FloatTensor<B, 1>,
+    ) -> FloatTensor<B, 3> {
+        dequantize::dequantize_matmul::<B>(x, weight, scale)
+    }
+
+    /// Samples the input at the locations given by a grid of normalized coordinates.
+    ///
+    /// The last dimension of the grid holds the `x` and `y` coordinates of each location, between
+    /// `-1` and `1` for the locations inside the input, `x` indexing its width and `y` its height.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// grid: `[batch_size, height_out, width_out, 2]`,
+    /// returns: `[batch_size, channels, height_out, width_out]`,
+    fn grid_sample(
+        x: FloatTensor<B, 4>,
+        grid: FloatTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<B, 4> {
+        grid_sample::grid_sample::<B>(x, grid, options)
+    }
+
+    /// Pools each region of interest to a fixed size, averaging bilinearly sampled values in
+    /// each bin.
+    ///
+    /// Each box is given as `(batch_index, x1, y1, x2, y2)`, the coordinates being scaled by the
+    /// spatial scale of the options.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// boxes: `[num_boxes, 5]`,
+    /// returns: `[num_boxes, channels, height_out, width_out]`,
+    fn roi_align(
+        x: FloatTensor<B, 4>,
+        boxes: FloatTensor<B, 2>,
+        options: RoiAlignOptions,
+    ) -> FloatTensor<B, 4> {
+        roi::roi_align::<B>(x, boxes, options)
+    }
+
+    /// Pools each region of interest to a fixed size, taking the maximum of the pixels in each
+    /// bin.
+    ///
+    /// Each box is given as `(batch_index, x1, y1, x2, y2)`, the coordinates being scaled by the
+    /// spatial scale of the options and rounded to the nearest pixel.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// boxes: `[num_boxes, 5]`,
+    /// returns: `[num_boxes, channels, height_out, width_out]`,
+    fn roi_pool(
+        x: FloatTensor<B, 4>,
+        boxes: FloatTensor<B, 2>,
+        options: RoiPoolOptions,
+    ) -> FloatTensor<B, 4> {
+        roi::roi_pool::<B>(x, boxes, options)
+    }
+
+    /// Performs non-maximum suppression, keeping the boxes which don't overlap a kept box with a
+    /// higher score by more than the intersection over union threshold.
+    ///
+    /// Each box is given as `(x1, y1, x2, y2)`. The number of kept boxes is read from the device.
+    ///
+    /// # Shapes
+    ///
+    /// boxes: `[num_boxes, 4]`,
+    /// scores: `[num_boxes]`,
+    /// returns: `[num_kept]`, the indices of the kept boxes by decreasing score,
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn nms(
+        boxes: FloatTensor<B, 2>,
+        scores: FloatTensor<B, 1>,
+        iou_threshold: f32,
+    ) -> IntTensor<B, 1> {
+        nms::nms::<B>(boxes, scores, iou_threshold)
+    }
+
+    /// Performs [non-maximum suppression](ModuleOps::nms) independently for each category, boxes
+    /// of different categories never suppressing each other.
+    ///
+    /// # Shapes
+    ///
+    /// boxes: `[num_boxes, 4]`,
+    /// scores: `[num_boxes]`,
+    /// idxs: `[num_boxes]`, the category of each box,
+    /// returns: `[num_kept]`, the indices of the kept boxes by decreasing score,
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn batched_nms(
+        boxes: FloatTensor<B, 2>,
+        scores: FloatTensor<B, 1>,
+        idxs: IntTensor<B, 1>,
+        iou_threshold: f32,
+    ) -> IntTensor<B, 1> {
+        nms::batched_nms::<B>(boxes, scores, idxs, iou_threshold)
+    }
 }
@@ -0,0 +1,53 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Int, Numeric, Tensor,
+};
+
+/// Counts the occurrences of each value in `[0, num_bins)` by assigning ones at the values, the
+/// values out of range being ignored.
+///
+/// This is the reference implementation used by backends without an atomic counting kernel: it
+/// only requires tensor operations, so it runs on every backend without reading the values on the
+/// host.
+pub fn select_bincount<B: Backend>(tensor: IntTensor<B, 1>, num_bins: usize) -> IntTensor<B, 1> {
+    let tensor = Tensor::<B, 1, Int>::from_primitive(tensor);
+    let ones = Tensor::<B, 1, Int>::ones([tensor.dims()[0]], &tensor.device());
+
+    select_assign_bins(tensor, ones, num_bins).into_primitive()
+}
+
+/// Sums the weights of the occurrences of each value in `[0, num_bins)` by assigning the weights
+/// at the values, the values out of range being ignored.
+///
+/// This is the reference implementation used by backends without an atomic counting kernel, and
+/// it is differentiable with respect to the weights. See [select_bincount] for more details.
+pub fn select_bincount_weighted<B: Backend>(
+    tensor: IntTensor<B, 1>,
+    weights: FloatTensor<B, 1>,
+    num_bins: usize,
+) -> FloatTensor<B, 1> {
+    select_assign_bins(
+        Tensor::<B, 1, Int>::from_primitive(tensor),
+        Tensor::<B, 1>::from_primitive(weights),
+        num_bins,
+    )
+    .into_primitive()
+}
+
+fn select_assign_bins<B: Backend, K: Numeric<B>>(
+    tensor: Tensor<B, 1, Int>,
+    values: Tensor<B, 1, K>,
+    num_bins: usize,
+) -> Tensor<B, 1, K> {
+    let device = tensor.device();
+    if num_bins == 0 || tensor.dims()[0] == 0 {
+        return Tensor::zeros([num_bins], &device);
+    }
+
+    // The values out of range are assigned at a valid bin, with a value of zero.
+    let bins = tensor.clone().clamp(0, num_bins as i64 - 1);
+    let values = values.mask_fill(bins.clone().not_equal(tensor), 0);
+
+    Tensor::zeros([num_bins], &device).select_assign(0, bins, values)
+}
@@ -0,0 +1,85 @@
+use crate::{
+    backend::Backend,
+    ops::{BoolTensor, FloatElem, FloatTensor, IntTensor},
+    Bool, Int, Tensor, TensorData,
+};
+use alloc::vec::Vec;
+
+/// Number of bits packed in each element of a bitmap.
+pub(crate) const BITS: usize = 8;
+
+/// Packs the last dimension of a bool tensor into bytes, the `i`-th value setting the bit
+/// `i % 8` of the byte `i / 8`.
+pub(crate) fn pack_bits<B: Backend, const D: usize>(tensor: BoolTensor<B, D>) -> IntTensor<B, D> {
+    let tensor = Tensor::<B, D, Bool>::from_primitive(tensor);
+    let dims = tensor.dims();
+    let (rows, size) = split_last(&dims);
+    let packed = size.div_ceil(BITS);
+    let device = tensor.device();
+
+    let mut bits = tensor.int().reshape([rows, size]);
+    if packed * BITS > size {
+        let padding = Tensor::zeros([rows, packed * BITS - size], &device);
+        bits = Tensor::cat(alloc::vec![bits, padding], 1);
+    }
+
+    let mut shape = dims;
+    shape[D - 1] = packed;
+
+    bits.reshape([rows, packed, BITS])
+        .mul(weights::<B>(&device))
+        .sum_dim(2)
+        .reshape(shape)
+        .into_primitive()
+}
+
+/// Unpacks the last dimension of a bitmap into the given number of bool values, inverting
+/// [pack_bits].
+pub(crate) fn unpack_bits<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    size: usize,
+) -> BoolTensor<B, D> {
+    let tensor = Tensor::<B, D, Int>::from_primitive(tensor);
+    let dims = tensor.dims();
+    let (rows, packed) = split_last(&dims);
+    let device = tensor.device();
+
+    let mut shape = dims;
+    shape[D - 1] = size;
+
+    tensor
+        .reshape([rows, packed, 1])
+        .expand([rows, packed, BITS])
+        .div(weights::<B>(&device))
+        .remainder_scalar(2)
+        .reshape([rows, packed * BITS])
+        .slice([0..rows, 0..size])
+        .reshape(shape)
+        .bool()
+        .into_primitive()
+}
+
+/// Fills the values of a float tensor with the given value where the bits of the packed mask
+/// are set.
+pub(crate) fn mask_fill_packed<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    mask: IntTensor<B, D>,
+    value: FloatElem<B>,
+) -> FloatTensor<B, D> {
+    let size = B::float_shape(&tensor).dims[D - 1];
+    let mask = unpack_bits::<B, D>(mask, size);
+
+    B::float_mask_fill(tensor, mask, value)
+}
+
+/// Returns the number of elements before the last dimension, and the size of the last dimension.
+fn split_last<const D: usize>(dims: &[usize; D]) -> (usize, usize) {
+    (dims[..D - 1].iter().product(), dims[D - 1])
+}
+
+/// Returns the weights `[1, 2, 4, ..., 128]` of the bits of a byte.
+fn weights<B: Backend>(device: &B::Device) -> Tensor<B, 3, Int> {
+    let weights: Vec<i64> = (0..BITS).map(|bit| 1 << bit).collect();
+
+    Tensor::from_data(TensorData::new(weights, [1, 1, BITS]), device)
+}
@@ -0,0 +1,134 @@
+use crate::{backend::Backend, ops::BoxIouMode, Tensor};
+
+/// Returns the overlap of every pair of boxes `(x1, y1, x2, y2)`, see
+/// [box_iou](crate::module::box_iou).
+///
+/// # Shapes
+///
+/// lhs: `[n, 4]`,
+/// rhs: `[m, 4]`,
+/// returns: `[n, m]`,
+pub(crate) fn pairwise_box_iou<B: Backend>(
+    lhs: Tensor<B, 2>,
+    rhs: Tensor<B, 2>,
+    mode: BoxIouMode,
+    eps: f64,
+) -> Tensor<B, 2> {
+    let [n, _] = lhs.dims();
+    let [m, _] = rhs.dims();
+    let lhs = coords(&lhs).map(|coord| coord.reshape([n, 1]).expand([n, m]));
+    let rhs = coords(&rhs).map(|coord| coord.reshape([1, m]).expand([n, m]));
+
+    box_iou(lhs, rhs, mode, eps)
+}
+
+/// Returns the overlap of each box `(x1, y1, x2, y2)` with the box at the same index, see
+/// [paired_box_iou](crate::module::paired_box_iou).
+///
+/// # Shapes
+///
+/// lhs: `[n, 4]`,
+/// rhs: `[n, 4]`,
+/// returns: `[n]`,
+pub(crate) fn paired_box_iou<B: Backend>(
+    lhs: Tensor<B, 2>,
+    rhs: Tensor<B, 2>,
+    mode: BoxIouMode,
+    eps: f64,
+) -> Tensor<B, 1> {
+    box_iou(coords(&lhs), coords(&rhs), mode, eps)
+}
+
+/// Splits the boxes into their coordinates `[x1, y1, x2, y2]`.
+fn coords<B: Backend>(boxes: &Tensor<B, 2>) -> [Tensor<B, 1>; 4] {
+    let [num_boxes, _] = boxes.dims();
+
+    [0, 1, 2, 3].map(|index| {
+        boxes
+            .clone()
+            .slice([0..num_boxes, index..index + 1])
+            .reshape([num_boxes])
+    })
+}
+
+/// Returns the overlap of the boxes given by their coordinates, `eps` being added to the
+/// denominators which vanish for degenerate boxes.
+fn box_iou<B: Backend, const D: usize>(
+    lhs: [Tensor<B, D>; 4],
+    rhs: [Tensor<B, D>; 4],
+    mode: BoxIouMode,
+    eps: f64,
+) -> Tensor<B, D> {
+    let [lhs_x1, lhs_y1, lhs_x2, lhs_y2] = lhs;
+    let [rhs_x1, rhs_y1, rhs_x2, rhs_y2] = rhs;
+
+    let lhs_width = lhs_x2.clone().sub(lhs_x1.clone());
+    let lhs_height = lhs_y2.clone().sub(lhs_y1.clone());
+    let rhs_width = rhs_x2.clone().sub(rhs_x1.clone());
+    let rhs_height = rhs_y2.clone().sub(rhs_y1.clone());
+
+    let width = lhs_x2
+        .clone()
+        .min_pair(rhs_x2.clone())
+        .sub(lhs_x1.clone().max_pair(rhs_x1.clone()));
+    let height = lhs_y2
+        .clone()
+        .min_pair(rhs_y2.clone())
+        .sub(lhs_y1.clone().max_pair(rhs_y1.clone()));
+    let intersection = width.clamp_min(0.0).mul(height.clamp_min(0.0));
+    let union = lhs_width
+        .clone()
+        .mul(lhs_height.clone())
+        .add(rhs_width.clone().mul(rhs_height.clone()))
+        .sub(intersection.clone())
+        .add_scalar(eps);
+    let iou = intersection.div(union.clone());
+
+    if let BoxIouMode::Iou = mode {
+        return iou;
+    }
+
+    // The smallest box enclosing both boxes.
+    let enclosing_width = lhs_x2
+        .clone()
+        .max_pair(rhs_x2.clone())
+        .sub(lhs_x1.clone().min_pair(rhs_x1.clone()));
+    let enclosing_height = lhs_y2
+        .clone()
+        .max_pair(rhs_y2.clone())
+        .sub(lhs_y1.clone().min_pair(rhs_y1.clone()));
+
+    if let BoxIouMode::Generalized = mode {
+        let enclosing_area = enclosing_width.mul(enclosing_height).add_scalar(eps);
+
+        return iou.sub(enclosing_area.clone().sub(union).div(enclosing_area));
+    }
+
+    // The squared distance between the centers, relative to the squared diagonal of the
+    // enclosing box.
+    let diagonal = enclosing_width
+        .powf_scalar(2.0)
+        .add(enclosing_height.powf_scalar(2.0))
+        .add_scalar(eps);
+    let center_x = rhs_x1.add(rhs_x2).sub(lhs_x1).sub(lhs_x2).div_scalar(2.0);
+    let center_y = rhs_y1.add(rhs_y2).sub(lhs_y1).sub(lhs_y2).div_scalar(2.0);
+    let distance = center_x.powf_scalar(2.0).add(center_y.powf_scalar(2.0));
+    let diou = iou.clone().sub(distance.div(diagonal));
+
+    if let BoxIouMode::Distance = mode {
+        return diou;
+    }
+
+    // The consistency of the aspect ratios, whose weight is a constant as in the original paper.
+    let aspect_ratio = rhs_width
+        .atan2(rhs_height)
+        .sub(lhs_width.atan2(lhs_height))
+        .powf_scalar(2.0)
+        .mul_scalar(4.0 / (core::f64::consts::PI * core::f64::consts::PI));
+    let alpha = aspect_ratio
+        .clone()
+        .div(iou.neg().add(aspect_ratio.clone()).add_scalar(1.0 + eps))
+        .detach();
+
+    diou.sub(alpha.mul(aspect_ratio))
+}
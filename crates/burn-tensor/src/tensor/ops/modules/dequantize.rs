@@ -0,0 +1,21 @@
+use crate::{backend::Backend, ops::FloatTensor, ops::IntTensor, Int, Tensor};
+
+/// Multiplies the input by the dequantized weights, see
+/// [dequantize_matmul](super::ModuleOps::dequantize_matmul).
+///
+/// The floating point weights are materialized before the product, with tensor operations so
+/// that autodiff backends compute the gradients of the input and of the scale.
+pub fn dequantize_matmul<B: Backend>(
+    x: FloatTensor<B, 3>,
+    weight: IntTensor<B, 2>,
+    scale: FloatTensor<B, 1>,
+) -> FloatTensor<B, 3> {
+    let x = Tensor::<B, 3>::from_primitive(x);
+    let weight = Tensor::<B, 2, Int>::from_primitive(weight);
+    let scale = Tensor::<B, 1>::from_primitive(scale);
+
+    let [d_output] = scale.dims();
+    let weight = weight.float().mul(scale.reshape([1, d_output]));
+
+    x.matmul(weight.unsqueeze()).into_primitive()
+}
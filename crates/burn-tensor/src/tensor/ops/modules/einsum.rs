@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, ops::FloatTensor, Tensor};
+
+/// A contraction of two tensors, where every dimension is identified by a label.
+///
+/// Labels are indices into `sizes`, and each tensor has distinct labels. The labels of the
+/// operands missing from the output are summed over, and dimensions of size 1 are broadcast to
+/// the size of their label. Labels of the output missing from both operands must have a size of 1.
+#[derive(new, Debug, Clone, PartialEq, Eq)]
+pub struct Contraction {
+    /// The labels of the left hand side dimensions.
+    pub lhs: Vec<usize>,
+    /// The labels of the right hand side dimensions.
+    pub rhs: Vec<usize>,
+    /// The labels of the output dimensions.
+    pub output: Vec<usize>,
+    /// The size of each label.
+    pub sizes: Vec<usize>,
+}
+
+impl Contraction {
+    /// The shape of the output.
+    pub fn output_shape<const D: usize>(&self) -> [usize; D] {
+        let mut shape = [0; D];
+        for (size, label) in shape.iter_mut().zip(self.output.iter()) {
+            *size = self.sizes[*label];
+        }
+        shape
+    }
+
+    /// The labels of the operands summed over, in order of appearance.
+    pub fn summed(&self) -> Vec<usize> {
+        let mut summed = Vec::new();
+        for label in self.lhs.iter().chain(self.rhs.iter()) {
+            if !self.output.contains(label) && !summed.contains(label) {
+                summed.push(*label);
+            }
+        }
+        summed
+    }
+}
+
+/// Computes the contraction with a batched matrix multiplication of the permuted and reshaped
+/// tensors.
+///
+/// This is the reference implementation used by backends without a fused contraction: it only
+/// requires matrix multiplications, so it runs on every backend and is differentiable.
+pub fn matmul_contraction<B: Backend, const D: usize>(
+    lhs: FloatTensor<B, D>,
+    rhs: FloatTensor<B, D>,
+    contraction: &Contraction,
+) -> FloatTensor<B, D> {
+    crate::tensor::api::contract_with_matmul(
+        Tensor::<B, D>::from_primitive(lhs),
+        Tensor::<B, D>::from_primitive(rhs),
+        contraction,
+    )
+    .into_primitive()
+}
@@ -0,0 +1,211 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{backend::Backend, Complex64, ComplexPrimitive, Tensor, TensorData};
+
+/// Computes the discrete Fourier transform along the last dimension as products with the
+/// transform matrix.
+///
+/// This is the reference implementation used by backends without a native transform: it only
+/// requires matrix multiplications, so it runs on every backend and is differentiable, but its
+/// cost is quadratic in the size of the transformed dimension.
+pub fn dft<B: Backend, const D: usize>(
+    tensor: ComplexPrimitive<B, D>,
+    inverse: bool,
+) -> ComplexPrimitive<B, D> {
+    let real = Tensor::<B, D>::from_primitive(tensor.real);
+    let imag = Tensor::<B, D>::from_primitive(tensor.imag);
+    let n = real.dims()[D - 1];
+
+    let (scale, sign) = match inverse {
+        true => (1.0 / n as f64, 1.0),
+        false => (1.0, -1.0),
+    };
+    let (cos, sin) = dft_matrices::<B>(n, scale, sign, &real.device());
+
+    // (a + ib)(C + iS) = (aC - bS) + i(bC + aS)
+    let output_real = mul_last(real.clone(), &cos) - mul_last(imag.clone(), &sin);
+    let output_imag = mul_last(imag, &cos) + mul_last(real, &sin);
+
+    ComplexPrimitive {
+        real: output_real.into_primitive(),
+        imag: output_imag.into_primitive(),
+    }
+}
+
+/// Computes the discrete Fourier transform of the values in place, in `O(n log(n))`.
+///
+/// Sizes that are powers of two use the iterative radix-2 algorithm, and the other ones
+/// Bluestein's algorithm, which expresses the transform as a convolution computed with radix-2
+/// transforms of a larger size. The inverse transform is normalized by `1 / n`.
+pub fn fft_in_place(values: &mut [Complex64], inverse: bool) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+
+    match n.is_power_of_two() {
+        true => radix2(values, inverse),
+        false => bluestein(values, inverse),
+    }
+
+    if inverse {
+        let scale = 1.0 / n as f64;
+        values.iter_mut().for_each(|value| *value *= scale);
+    }
+}
+
+/// Returns the power of two size of the radix-2 transforms used by Bluestein's algorithm for a
+/// transform of size `n`, large enough to hold the linear convolution of two signals of size `n`.
+pub fn bluestein_size(n: usize) -> usize {
+    (2 * n - 1).next_power_of_two()
+}
+
+/// Returns the chirp `exp(∓iπk²/n)` of Bluestein's algorithm, the sign being positive for the
+/// inverse transform.
+///
+/// The transform is `X[k] = w[k] Σ x[j] w[j] conj(w[k - j])`, a convolution between the signal
+/// multiplied by the chirp and the conjugate chirp.
+pub fn bluestein_chirp(n: usize, inverse: bool) -> Vec<Complex64> {
+    let sign = match inverse {
+        true => 1.0,
+        false => -1.0,
+    };
+
+    (0..n as u64)
+        .map(|k| {
+            // Reduce k² modulo 2n to keep the angle accurate for large sizes.
+            let angle = sign * PI * ((k * k) % (2 * n as u64)) as f64 / n as f64;
+            Complex64::new(angle.cos(), angle.sin())
+        })
+        .collect()
+}
+
+/// Returns the Fourier transform of the conjugate chirp, wrapped around the size of the
+/// convolution so that negative offsets are stored at the end.
+pub fn bluestein_kernel(chirp: &[Complex64], size: usize) -> Vec<Complex64> {
+    let mut kernel = vec![Complex64::new(0.0, 0.0); size];
+    kernel[0] = chirp[0].conj();
+    for k in 1..chirp.len() {
+        kernel[k] = chirp[k].conj();
+        kernel[size - k] = chirp[k].conj();
+    }
+
+    radix2(&mut kernel, false);
+    kernel
+}
+
+/// Unnormalized radix-2 transform of values whose size is a power of two.
+fn radix2(values: &mut [Complex64], inverse: bool) {
+    let n = values.len();
+    let bits = n.trailing_zeros();
+    let sign = match inverse {
+        true => 1.0,
+        false => -1.0,
+    };
+
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut half = 1;
+    while half < n {
+        // The twiddles are computed directly instead of by repeated products, which accumulate
+        // rounding errors.
+        let twiddles: Vec<Complex64> = (0..half)
+            .map(|k| {
+                let angle = sign * PI * k as f64 / half as f64;
+                Complex64::new(angle.cos(), angle.sin())
+            })
+            .collect();
+
+        for start in (0..n).step_by(2 * half) {
+            for (k, twiddle) in twiddles.iter().enumerate() {
+                let even = values[start + k];
+                let odd = values[start + k + half] * twiddle;
+                values[start + k] = even + odd;
+                values[start + k + half] = even - odd;
+            }
+        }
+
+        half *= 2;
+    }
+}
+
+fn bluestein(values: &mut [Complex64], inverse: bool) {
+    let n = values.len();
+    let size = bluestein_size(n);
+    let chirp = bluestein_chirp(n, inverse);
+    let kernel = bluestein_kernel(&chirp, size);
+
+    let mut signal = vec![Complex64::new(0.0, 0.0); size];
+    for (k, value) in values.iter().enumerate() {
+        signal[k] = value * chirp[k];
+    }
+
+    radix2(&mut signal, false);
+    signal
+        .iter_mut()
+        .zip(kernel.iter())
+        .for_each(|(value, kernel)| *value *= kernel);
+    radix2(&mut signal, true);
+
+    let scale = 1.0 / size as f64;
+    for (k, value) in values.iter_mut().enumerate() {
+        *value = signal[k] * chirp[k] * scale;
+    }
+}
+
+/// Create the `[n, n]` matrices of `scale * cos(2πkj/n)` and `scale * sign * sin(2πkj/n)`.
+fn dft_matrices<B: Backend>(
+    n: usize,
+    scale: f64,
+    sign: f64,
+    device: &B::Device,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let mut cos = Vec::with_capacity(n * n);
+    let mut sin = Vec::with_capacity(n * n);
+
+    for j in 0..n {
+        for k in 0..n {
+            // Reduce the index modulo n to keep the angle accurate for large sizes.
+            let angle = 2.0 * PI * ((k * j) % n) as f64 / n as f64;
+            cos.push((scale * angle.cos()) as f32);
+            sin.push((scale * sign * angle.sin()) as f32);
+        }
+    }
+
+    (
+        matrix::<B>(cos, [n, n], device),
+        matrix::<B>(sin, [n, n], device),
+    )
+}
+
+fn matrix<B: Backend>(values: Vec<f32>, shape: [usize; 2], device: &B::Device) -> Tensor<B, 2> {
+    Tensor::from_data(
+        TensorData::new(values, shape).convert::<B::FloatElem>(),
+        device,
+    )
+}
+
+/// Multiply the last dimension of the tensor by the matrix.
+fn mul_last<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    matrix: &Tensor<B, 2>,
+) -> Tensor<B, D> {
+    let dims = tensor.dims();
+    let [n, _] = matrix.dims();
+    let batch = dims[..D - 1].iter().product::<usize>();
+
+    tensor
+        .reshape([batch, n])
+        .matmul(matrix.clone())
+        .reshape(dims)
+}
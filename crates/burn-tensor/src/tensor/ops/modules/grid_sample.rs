@@ -0,0 +1,193 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, GridSampleMode, GridSampleOptions, GridSamplePaddingMode},
+    Tensor,
+};
+
+/// Samples the input at the locations of the grid, see [grid_sample](super::ModuleOps::grid_sample).
+///
+/// The sampling is done with tensor operations gathering the neighbors of each location, so
+/// autodiff backends compute the gradients of both the input and the grid.
+pub(crate) fn grid_sample<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grid: FloatTensor<B, 4>,
+    options: GridSampleOptions,
+) -> FloatTensor<B, 4> {
+    let x = Tensor::<B, 4>::from_primitive(x);
+    let grid = Tensor::<B, 4>::from_primitive(grid);
+    let [batch_size, channels, height, width] = x.dims();
+    let [_, height_out, width_out, _] = grid.dims();
+    let num_points = height_out * width_out;
+
+    let grid = grid.reshape([batch_size, num_points, 2]);
+    let coord_x = grid
+        .clone()
+        .slice([0..batch_size, 0..num_points, 0..1])
+        .reshape([batch_size, num_points]);
+    let coord_y = grid
+        .slice([0..batch_size, 0..num_points, 1..2])
+        .reshape([batch_size, num_points]);
+    let index_x = source_index(coord_x, width, &options);
+    let index_y = source_index(coord_y, height, &options);
+
+    let values = x.reshape([batch_size, channels, height * width]);
+    let size = [height, width];
+
+    let output = match options.mode {
+        GridSampleMode::Nearest => {
+            let weight = index_x.ones_like();
+            gather(&values, round(index_x), round(index_y), weight, size)
+        }
+        GridSampleMode::Bilinear => {
+            let x0 = floor(index_x.clone());
+            let y0 = floor(index_y.clone());
+            let x1 = x0.clone().add_scalar(1);
+            let y1 = y0.clone().add_scalar(1);
+
+            let weight_x1 = index_x.sub(x0.clone());
+            let weight_y1 = index_y.sub(y0.clone());
+            let weight_x0 = weight_x1.clone().neg().add_scalar(1);
+            let weight_y0 = weight_y1.clone().neg().add_scalar(1);
+
+            gather(
+                &values,
+                x0.clone(),
+                y0.clone(),
+                weight_x0.clone().mul(weight_y0.clone()),
+                size,
+            ) + gather(
+                &values,
+                x1.clone(),
+                y0,
+                weight_x1.clone().mul(weight_y0),
+                size,
+            ) + gather(
+                &values,
+                x0,
+                y1.clone(),
+                weight_x0.mul(weight_y1.clone()),
+                size,
+            ) + gather(&values, x1, y1, weight_x1.mul(weight_y1), size)
+        }
+    };
+
+    output
+        .reshape([batch_size, channels, height_out, width_out])
+        .into_primitive()
+}
+
+/// Maps the normalized coordinates in `[-1, 1]` to indices along a dimension of the given size,
+/// applying the padding mode.
+fn source_index<B: Backend>(
+    coord: Tensor<B, 2>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> Tensor<B, 2> {
+    let size_f = size as f64;
+    let index = match options.align_corners {
+        true => coord.add_scalar(1).mul_scalar((size_f - 1.0) / 2.0),
+        false => coord.add_scalar(1).mul_scalar(size_f / 2.0).sub_scalar(0.5),
+    };
+
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => index,
+        GridSamplePaddingMode::Border => index.clamp(0.0, size_f - 1.0),
+        GridSamplePaddingMode::Reflection => {
+            let size = size as i64;
+            let index = match options.align_corners {
+                true => reflect(index, 0, 2 * (size - 1)),
+                false => reflect(index, -1, 2 * size - 1),
+            };
+
+            index.clamp(0.0, size_f - 1.0)
+        }
+    }
+}
+
+/// Reflects the indices until they are between `twice_low / 2` and `twice_high / 2`, the bounds
+/// being doubled so that they can be half-integers.
+fn reflect<B: Backend>(index: Tensor<B, 2>, twice_low: i64, twice_high: i64) -> Tensor<B, 2> {
+    if twice_low == twice_high {
+        return index.zeros_like();
+    }
+
+    let min = twice_low as f64 / 2.0;
+    let span = (twice_high - twice_low) as f64 / 2.0;
+
+    let index = index.sub_scalar(min).abs();
+    let flips = floor(index.clone().div_scalar(span));
+    let extra = index.sub(flips.clone().mul_scalar(span));
+    let odd = flips.int().remainder_scalar(2).equal_elem(1);
+
+    extra
+        .clone()
+        .add_scalar(min)
+        .mask_where(odd, extra.neg().add_scalar(span + min))
+}
+
+/// Gathers the values at the given integral indices, scaled by the weights, out-of-bounds
+/// locations being zeros.
+///
+/// # Shapes
+///
+/// values: `[batch_size, channels, height * width]`,
+/// index_x, index_y, weight: `[batch_size, num_points]`,
+/// returns: `[batch_size, channels, num_points]`,
+fn gather<B: Backend>(
+    values: &Tensor<B, 3>,
+    index_x: Tensor<B, 2>,
+    index_y: Tensor<B, 2>,
+    weight: Tensor<B, 2>,
+    [height, width]: [usize; 2],
+) -> Tensor<B, 3> {
+    let [batch_size, channels, _] = values.dims();
+    let [_, num_points] = index_x.dims();
+    let index_x = index_x.int();
+    let index_y = index_y.int();
+
+    let in_bounds = index_x
+        .clone()
+        .greater_equal_elem(0)
+        .int()
+        .mul(index_x.clone().lower_elem(width as i32).int())
+        .mul(index_y.clone().greater_equal_elem(0).int())
+        .mul(index_y.clone().lower_elem(height as i32).int());
+    let weight = weight.mul(in_bounds.float());
+
+    let index = index_y
+        .clamp(0, height as i32 - 1)
+        .mul_scalar(width as i32)
+        .add(index_x.clamp(0, width as i32 - 1))
+        .reshape([batch_size, 1, num_points])
+        .expand([batch_size, channels, num_points]);
+
+    values
+        .clone()
+        .gather(2, index)
+        .mul(weight.reshape([batch_size, 1, num_points]))
+}
+
+/// Rounds down to the nearest integer.
+pub(crate) fn floor<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let truncated = tensor.clone().int().float();
+    let adjust = tensor.lower(truncated.clone()).float();
+
+    truncated.sub(adjust)
+}
+
+/// Rounds to the nearest integer, half-way cases being rounded to the nearest even integer.
+fn round<B: Backend>(tensor: Tensor<B, 2>) -> Tensor<B, 2> {
+    let rounded = floor(tensor.clone().add_scalar(0.5));
+    let half_way = rounded.clone().sub(tensor).equal_elem(0.5);
+    let odd = rounded
+        .clone()
+        .int()
+        .remainder_scalar(2)
+        .equal_elem(0)
+        .bool_not();
+    let correction = half_way.int().mul(odd.int()).bool();
+
+    rounded
+        .clone()
+        .mask_where(correction, rounded.sub_scalar(1))
+}
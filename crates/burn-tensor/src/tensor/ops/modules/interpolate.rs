@@ -0,0 +1,134 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, InterpolateMode, InterpolateOptions},
+    Int, Shape, Tensor, TensorData,
+};
+use alloc::vec::Vec;
+use num_traits::Float;
+
+/// Weight of a tap, given the distance of the output position to the previous input index.
+type TapWeight = fn(f64) -> f64;
+
+/// Interpolates the depth, height and width of the input, see
+/// [interpolate3d](super::ModuleOps::interpolate3d).
+///
+/// The interpolation is separable, so it is applied along each spatial dimension in turn with
+/// tensor operations, and autodiff backends compute the gradients of the input.
+pub(crate) fn interpolate3d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> FloatTensor<B, 5> {
+    let mut x = Tensor::<B, 5>::from_primitive(x);
+
+    for (dim, size_out) in output_size.into_iter().enumerate() {
+        x = interpolate_dim(x, dim + 2, size_out, &options.mode);
+    }
+
+    x.into_primitive()
+}
+
+/// Interpolates the input along a single dimension, summing the selected values of each tap.
+fn interpolate_dim<B: Backend>(
+    x: Tensor<B, 5>,
+    dim: usize,
+    size_out: usize,
+    mode: &InterpolateMode,
+) -> Tensor<B, 5> {
+    let size = x.dims()[dim];
+    if size == size_out {
+        return x;
+    }
+
+    let device = x.device();
+    let mut output: Option<Tensor<B, 5>> = None;
+
+    for (indices, weights) in taps(size, size_out, mode) {
+        let indices = Tensor::<B, 1, Int>::from_data(
+            TensorData::new(indices, Shape::new([size_out])).convert::<B::IntElem>(),
+            &device,
+        );
+        let mut shape = [1; 5];
+        shape[dim] = size_out;
+        let weights = Tensor::<B, 1>::from_data(
+            TensorData::new(weights, Shape::new([size_out])).convert::<B::FloatElem>(),
+            &device,
+        )
+        .reshape(shape);
+
+        let values = x.clone().select(dim, indices).mul(weights);
+        output = Some(match output {
+            Some(output) => output.add(values),
+            None => values,
+        });
+    }
+
+    output.unwrap()
+}
+
+/// Returns the indices and weights of the input values combined into each output value, one
+/// pair for each tap of the interpolation.
+///
+/// The corners of the input and the output are aligned, as in the 2D
+/// [interpolate](super::ModuleOps::interpolate).
+fn taps(size: usize, size_out: usize, mode: &InterpolateMode) -> Vec<(Vec<i64>, Vec<f32>)> {
+    let last = size as i64 - 1;
+    let clamp = |index: i64| index.clamp(0, last);
+
+    match mode {
+        InterpolateMode::Nearest => {
+            let ratio = size as f64 / size_out as f64;
+            let indices = (0..size_out)
+                .map(|i| clamp(Float::floor(ratio * i as f64) as i64))
+                .collect();
+
+            alloc::vec![(indices, alloc::vec![1.0; size_out])]
+        }
+        InterpolateMode::Bilinear | InterpolateMode::Bicubic => {
+            let ratio = match size_out {
+                1 => 0.0,
+                _ => last as f64 / (size_out - 1) as f64,
+            };
+            let positions = (0..size_out).map(|i| {
+                let position = ratio * i as f64;
+                let start = Float::floor(position);
+
+                (start as i64, position - start)
+            });
+
+            let offsets_and_weights: Vec<(i64, TapWeight)> = match mode {
+                InterpolateMode::Bilinear => alloc::vec![(0, |t| 1.0 - t), (1, |t| t)],
+                _ => alloc::vec![
+                    (-1, |t| cubic_convolution2(t + 1.0)),
+                    (0, cubic_convolution1),
+                    (1, |t| cubic_convolution1(1.0 - t)),
+                    (2, |t| cubic_convolution2(2.0 - t)),
+                ],
+            };
+
+            offsets_and_weights
+                .into_iter()
+                .map(|(offset, weight)| {
+                    positions
+                        .clone()
+                        .map(|(start, t)| (clamp(start + offset), weight(t) as f32))
+                        .unzip()
+                })
+                .collect()
+        }
+    }
+}
+
+/// Cubic convolution weight of the values at a distance of at most one.
+fn cubic_convolution1(x: f64) -> f64 {
+    const A: f64 = -0.75;
+
+    ((A + 2.0) * x - (A + 3.0)) * x * x + 1.0
+}
+
+/// Cubic convolution weight of the values at a distance between one and two.
+fn cubic_convolution2(x: f64) -> f64 {
+    const A: f64 = -0.75;
+
+    ((A * x - 5.0 * A) * x + 8.0 * A) * x - 4.0 * A
+}
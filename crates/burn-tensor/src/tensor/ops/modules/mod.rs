@@ -1,16 +1,71 @@
 /// Module with convolution operations.
 pub mod conv;
 
+/// Module with bit packing operations
+pub(crate) mod bits;
+/// Module with bounding box operations
+pub(crate) mod boxes;
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with grid sampling operation
+pub(crate) mod grid_sample;
+/// Module with 3D interpolation operation
+pub(crate) mod interpolate;
+/// Module with morphological operations
+pub(crate) mod morphology;
+/// Module with non-maximum suppression operations
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) mod nms;
+/// Module with pixel shuffle operations
+pub(crate) mod pixel_shuffle;
 /// Module with repeat operation
 pub(crate) mod repeat;
+/// Module with region of interest pooling operations
+pub(crate) mod roi;
+/// Module with strided slice operation
+pub(crate) mod slice;
+/// Module with special functions
+pub(crate) mod special;
 /// Module with unfold operations.
 pub(crate) mod unfold;
 
+/// Module with attention operation.
+pub mod attention;
+
+/// Module with bin counting operations.
+pub mod bincount;
+
+/// Module with dequantizing matrix multiplication operation.
+pub mod dequantize;
+
+/// Module with einsum contraction operation.
+pub mod einsum;
+
+/// Module with Fourier transform operations.
+pub mod fft;
+
+/// Module with mode reduction operation.
+pub mod mode;
+
 /// Module with pooling operations.
 pub mod pool;
 
+/// Module with random sampling operations.
+pub mod random;
+
+/// Module with resampling operations.
+pub mod resample;
+
+/// Module with selective scan operation.
+pub mod scan;
+
+/// Module with segment reduction operations.
+pub mod segment;
+
+/// Module with unique elements operation.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub mod unique;
+
 mod base;
 
 pub use base::*;
@@ -0,0 +1,51 @@
+use crate::{backend::Backend, ops::IntTensor, Int, Tensor};
+
+/// Returns the most frequent value along the given dimension with its number of occurrences, the
+/// smallest one among the values occurring the same number of times.
+///
+/// This is the reference implementation used by backends without a native kernel. The occurrences
+/// are counted by comparing each pair of values along the dimension with tensor operations, so the
+/// memory used is quadratic in its size.
+pub fn pairwise_mode<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    dim: usize,
+) -> (IntTensor<B, D>, IntTensor<B, D>) {
+    let tensor = Tensor::<B, D, Int>::from_primitive(tensor);
+    let dims = tensor.dims();
+    let batch = dims[..dim].iter().product::<usize>();
+    let size = dims[dim];
+    let channels = dims[dim + 1..].iter().product::<usize>();
+    let pairs = [batch, size, size, channels];
+
+    // Count the occurrences of each value by comparing it with all the values.
+    let values = tensor.reshape([batch, size, channels]);
+    let counts = values
+        .clone()
+        .reshape([batch, size, 1, channels])
+        .expand(pairs)
+        .equal(
+            values
+                .clone()
+                .reshape([batch, 1, size, channels])
+                .expand(pairs),
+        )
+        .int()
+        .sum_dim(2)
+        .reshape([batch, size, channels]);
+
+    // Keep the smallest value among the most frequent ones.
+    let max_counts = counts.clone().max_dim(1);
+    let most_frequent = counts.equal(max_counts.clone().expand([batch, size, channels]));
+    let max_values = values.clone().max_dim(1).expand([batch, size, channels]);
+    let mode = values
+        .mask_where(most_frequent.bool_not(), max_values)
+        .min_dim(1);
+
+    let mut shape = dims;
+    shape[dim] = 1;
+
+    (
+        mode.reshape(shape).into_primitive(),
+        max_counts.reshape(shape).into_primitive(),
+    )
+}
@@ -0,0 +1,88 @@
+use crate::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor},
+    Bool, Tensor,
+};
+
+/// Replaces each value by the maximum of its neighborhood, see
+/// [dilate](super::ModuleOps::dilate).
+pub(crate) fn dilate<B: Backend>(
+    x: FloatTensor<B, 4>,
+    kernel: BoolTensor<B, 2>,
+) -> FloatTensor<B, 4> {
+    morphology::<B>(x, kernel, false)
+}
+
+/// Replaces each value by the minimum of its neighborhood, see
+/// [erode](super::ModuleOps::erode).
+pub(crate) fn erode<B: Backend>(
+    x: FloatTensor<B, 4>,
+    kernel: BoolTensor<B, 2>,
+) -> FloatTensor<B, 4> {
+    morphology::<B>(x, kernel, true)
+}
+
+/// Reduces the neighborhood of each value selected by the structuring element, with one tensor
+/// operation for each of its positions, so autodiff backends compute the gradients of the input.
+///
+/// The values outside of the input are ignored, being padded with the identity of the reduction.
+fn morphology<B: Backend>(
+    x: FloatTensor<B, 4>,
+    kernel: BoolTensor<B, 2>,
+    erode: bool,
+) -> FloatTensor<B, 4> {
+    let x = Tensor::<B, 4>::from_primitive(x);
+    let kernel = Tensor::<B, 2, Bool>::from_primitive(kernel);
+    let [batch_size, channels, height, width] = x.dims();
+    let [kernel_height, kernel_width] = kernel.dims();
+    let [origin_y, origin_x] = [(kernel_height - 1) / 2, (kernel_width - 1) / 2];
+    let shape = [batch_size, channels, height, width];
+    let device = x.device();
+
+    let border = match erode {
+        true => f32::INFINITY,
+        false => f32::NEG_INFINITY,
+    };
+    let padded = Tensor::<B, 4>::full(
+        [
+            batch_size,
+            channels,
+            height + kernel_height - 1,
+            width + kernel_width - 1,
+        ],
+        border,
+        &device,
+    )
+    .slice_assign(
+        [
+            0..batch_size,
+            0..channels,
+            origin_y..origin_y + height,
+            origin_x..origin_x + width,
+        ],
+        x,
+    );
+
+    let mut output = Tensor::<B, 4>::full(shape, border, &device);
+    for i in 0..kernel_height {
+        for j in 0..kernel_width {
+            let excluded = kernel
+                .clone()
+                .slice([i..i + 1, j..j + 1])
+                .bool_not()
+                .reshape([1, 1, 1, 1])
+                .expand(shape);
+            let window = padded
+                .clone()
+                .slice([0..batch_size, 0..channels, i..i + height, j..j + width])
+                .mask_fill(excluded, border);
+
+            output = match erode {
+                true => output.min_pair(window),
+                false => output.max_pair(window),
+            };
+        }
+    }
+
+    output.into_primitive()
+}
@@ -0,0 +1,83 @@
+use super::boxes::pairwise_box_iou;
+use crate::{
+    backend::Backend,
+    ops::{BoxIouMode, FloatTensor, IntTensor},
+    Int, Shape, Tensor, TensorData,
+};
+use alloc::vec::Vec;
+
+/// Keeps the boxes which don't overlap a box with a higher score, see
+/// [nms](super::ModuleOps::nms).
+///
+/// The overlaps of the boxes are computed on the device, and the greedy selection is done on the
+/// host since it is sequential.
+pub(crate) fn nms<B: Backend>(
+    boxes: FloatTensor<B, 2>,
+    scores: FloatTensor<B, 1>,
+    iou_threshold: f32,
+) -> IntTensor<B, 1> {
+    let boxes = Tensor::<B, 2>::from_primitive(boxes);
+    let scores = Tensor::<B, 1>::from_primitive(scores);
+    let [num_boxes, _] = boxes.dims();
+    let device = boxes.device();
+
+    if num_boxes == 0 {
+        return Tensor::<B, 1, Int>::empty([0], &device).into_primitive();
+    }
+
+    let order = scores.argsort_descending(0);
+    let boxes = boxes.select(0, order.clone());
+    let overlaps = pairwise_box_iou(boxes.clone(), boxes, BoxIouMode::Iou, 0.0)
+        .greater_elem(iou_threshold)
+        .into_data();
+    let overlaps = overlaps.as_slice::<bool>().unwrap();
+
+    let mut suppressed = alloc::vec![false; num_boxes];
+    let mut kept = Vec::new();
+    for i in 0..num_boxes {
+        if suppressed[i] {
+            continue;
+        }
+
+        kept.push(i as i64);
+        for j in (i + 1)..num_boxes {
+            suppressed[j] |= overlaps[i * num_boxes + j];
+        }
+    }
+
+    let num_kept = kept.len();
+    let kept = Tensor::<B, 1, Int>::from_data(
+        TensorData::new(kept, Shape::new([num_kept])).convert::<B::IntElem>(),
+        &device,
+    );
+
+    order.select(0, kept).into_primitive()
+}
+
+/// Applies [nms](super::ModuleOps::nms) independently to the boxes of each category, see
+/// [batched_nms](super::ModuleOps::batched_nms).
+///
+/// The boxes of each category are translated so that they can't overlap the boxes of the other
+/// categories, and are suppressed all at once.
+pub(crate) fn batched_nms<B: Backend>(
+    boxes: FloatTensor<B, 2>,
+    scores: FloatTensor<B, 1>,
+    idxs: IntTensor<B, 1>,
+    iou_threshold: f32,
+) -> IntTensor<B, 1> {
+    let boxes = Tensor::<B, 2>::from_primitive(boxes);
+    let idxs = Tensor::<B, 1, Int>::from_primitive(idxs);
+    let [num_boxes, _] = boxes.dims();
+
+    if num_boxes == 0 {
+        return idxs.into_primitive();
+    }
+
+    let offsets = idxs
+        .float()
+        .reshape([num_boxes, 1])
+        .mul(boxes.clone().max().add_scalar(1.0).reshape([1, 1]));
+    let boxes = boxes.add(offsets);
+
+    B::nms(boxes.into_primitive(), scores, iou_threshold)
+}
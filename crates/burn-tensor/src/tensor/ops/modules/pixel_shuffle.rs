@@ -0,0 +1,41 @@
+use crate::{backend::Backend, ops::FloatTensor, Tensor};
+
+/// Rearranges blocks of channels into spatial blocks, see
+/// [pixel_shuffle](super::ModuleOps::pixel_shuffle).
+pub(crate) fn pixel_shuffle<B: Backend>(
+    x: FloatTensor<B, 4>,
+    upscale_factor: usize,
+) -> FloatTensor<B, 4> {
+    let x = Tensor::<B, 4>::from_primitive(x);
+    let [batch_size, channels, height, width] = x.dims();
+    let factor = upscale_factor;
+    let channels_out = channels / (factor * factor);
+
+    x.reshape([batch_size, channels_out, factor, factor, height, width])
+        .permute([0, 1, 4, 2, 5, 3])
+        .reshape([batch_size, channels_out, height * factor, width * factor])
+        .into_primitive()
+}
+
+/// Rearranges spatial blocks into blocks of channels, see
+/// [pixel_unshuffle](super::ModuleOps::pixel_unshuffle).
+pub(crate) fn pixel_unshuffle<B: Backend>(
+    x: FloatTensor<B, 4>,
+    downscale_factor: usize,
+) -> FloatTensor<B, 4> {
+    let x = Tensor::<B, 4>::from_primitive(x);
+    let [batch_size, channels, height, width] = x.dims();
+    let factor = downscale_factor;
+    let height_out = height / factor;
+    let width_out = width / factor;
+
+    x.reshape([batch_size, channels, height_out, factor, width_out, factor])
+        .permute([0, 1, 3, 5, 2, 4])
+        .reshape([
+            batch_size,
+            channels * factor * factor,
+            height_out,
+            width_out,
+        ])
+        .into_primitive()
+}
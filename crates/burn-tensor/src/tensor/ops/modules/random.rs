@@ -0,0 +1,308 @@
+use alloc::vec::Vec;
+
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Distribution, Int, Shape, Tensor,
+};
+
+/// Number of proposals drawn by the rejection samplers, after which the probability that an
+/// element rejected all of them is negligible.
+const REJECTION_ROUNDS: usize = 10;
+
+/// Poisson rates below this value are sampled with an inverse transform of the cumulative
+/// distribution function, and with a transformed rejection method above it.
+const POISSON_SMALL_RATE: f64 = 10.0;
+
+/// Number of terms of the cumulative distribution function of the inverse transform, whose
+/// tail is negligible for rates below 10.
+const POISSON_TERMS: usize = 40;
+
+/// Samples a tensor from the Gamma, Beta or Poisson distributions with draws of the standard
+/// uniform and normal distributions, so backends without native samplers for these families can
+/// keep the sampling on the device.
+///
+/// Any other distribution is sampled with
+/// [float_random](crate::ops::FloatTensorOps::float_random) directly, so backends must only call
+/// this function for the distributions they don't support.
+pub fn random_from_standard<B: Backend, const D: usize>(
+    shape: Shape<D>,
+    distribution: Distribution,
+    device: &B::Device,
+) -> FloatTensor<B, D> {
+    let full = |value: f64| Tensor::<B, D>::full(shape.clone(), value, device);
+
+    let tensor = match distribution {
+        Distribution::Gamma(concentration, scale) => gamma(full(concentration), full(scale)),
+        Distribution::Beta(alpha, beta) => self::beta(full(alpha), full(beta)),
+        Distribution::Poisson(rate) => poisson(full(rate)),
+        distribution => return B::float_random(shape, distribution, device),
+    };
+
+    tensor.into_primitive()
+}
+
+/// Samples the Gamma distribution with the method of Marsaglia and Tsang, boosting the
+/// concentrations below one with `Gamma(a) = Gamma(a + 1) U^(1 / a)`.
+///
+/// The samples are a differentiable transform of the accepted normal proposals, so gradients
+/// flow to the concentration and the scale as in the rejection sampling reparameterization.
+pub(crate) fn gamma<B: Backend, const D: usize>(
+    concentration: Tensor<B, D>,
+    scale: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let boosted = concentration.clone().lower_elem(1.0).float();
+    let d = concentration
+        .clone()
+        .add(boosted.clone())
+        .sub_scalar(1.0 / 3.0);
+    let c = d.clone().mul_scalar(9.0).sqrt().recip();
+
+    let mut output = d.clone();
+    let mut pending = d.ones_like();
+    for _ in 0..REJECTION_ROUNDS {
+        let x = d.random_like(Distribution::Normal(0.0, 1.0));
+        let v = x.clone().mul(c.clone()).add_scalar(1.0).powf_scalar(3.0);
+
+        // Accept when v > 0 and ln(u) < x^2 / 2 + d - d v + d ln(v).
+        let bound = x
+            .powf_scalar(2.0)
+            .mul_scalar(0.5)
+            .add(d.clone())
+            .sub(d.clone().mul(v.clone()))
+            .add(d.clone().mul(v.clone().clamp_min(1e-30).log()));
+        let accepted = uniform_open(&d)
+            .log()
+            .lower(bound)
+            .float()
+            .mul(v.clone().greater_elem(0.0).float())
+            .mul(pending.clone());
+
+        output = output.mask_where(accepted.clone().bool(), d.clone().mul(v));
+        pending = pending.sub(accepted);
+    }
+
+    // The exponent is zero, and the factor one, for the concentrations that aren't boosted.
+    let exponent = concentration.recip().mul(boosted);
+    let boost = uniform_open(&output).powf(exponent);
+
+    output.mul(boost).mul(scale)
+}
+
+/// Samples the Beta distribution as `X / (X + Y)`, with `X` and `Y` sampled from the Gamma
+/// distributions with a unit scale and the `alpha` and `beta` concentrations.
+pub(crate) fn beta<B: Backend, const D: usize>(
+    alpha: Tensor<B, D>,
+    beta: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let scale = alpha.ones_like();
+    let x = gamma(alpha, scale.clone());
+    let y = gamma(beta, scale);
+
+    x.clone().div(x.add(y))
+}
+
+/// Samples the Poisson distribution, with an inverse transform of the cumulative distribution
+/// function for small rates and the transformed rejection method of Hörmann, "The transformed
+/// rejection method for generating Poisson random variables" (1993), for the others.
+pub(crate) fn poisson<B: Backend, const D: usize>(rate: Tensor<B, D>) -> Tensor<B, D> {
+    let small = rate.clone().lower_elem(POISSON_SMALL_RATE);
+
+    // The number of terms of the cumulative distribution function below a uniform sample.
+    let u = rate.random_like(Distribution::Default);
+    let mut probability = rate.clone().neg().exp();
+    let mut cumulative = probability.clone();
+    let mut inversion = rate.zeros_like();
+    for k in 1..=POISSON_TERMS {
+        inversion = inversion.add(u.clone().greater_equal(cumulative.clone()).float());
+        probability = probability.mul(rate.clone()).div_scalar(k as f64);
+        cumulative = cumulative.add(probability.clone());
+    }
+
+    let rate = rate.clamp_min(POISSON_SMALL_RATE);
+    let log_rate = rate.clone().log();
+    let b = rate.clone().sqrt().mul_scalar(2.53).add_scalar(0.931);
+    let a = b.clone().mul_scalar(0.02483).sub_scalar(0.059);
+    let log_inv_alpha = b
+        .clone()
+        .sub_scalar(3.4)
+        .recip()
+        .mul_scalar(1.1328)
+        .add_scalar(1.1239)
+        .log();
+    let v_r = b
+        .clone()
+        .sub_scalar(2.0)
+        .recip()
+        .mul_scalar(-3.6224)
+        .add_scalar(0.9277);
+
+    let mut rejection = rate.clone().int().float();
+    let mut pending = rate.ones_like();
+    for _ in 0..REJECTION_ROUNDS {
+        let u = rate.random_like(Distribution::Default).sub_scalar(0.5);
+        let v = rate.random_like(Distribution::Default);
+        let u_s = u.clone().abs().neg().add_scalar(0.5);
+
+        let value = a
+            .clone()
+            .mul_scalar(2.0)
+            .div(u_s.clone())
+            .add(b.clone())
+            .mul(u)
+            .add(rate.clone())
+            .add_scalar(0.43);
+        // Truncating is flooring for the non-negative values, the others being rejected.
+        let k = value.clone().int().float();
+
+        let squeezed = u_s
+            .clone()
+            .greater_equal_elem(0.07)
+            .float()
+            .mul(v.clone().lower_equal(v_r.clone()).float());
+        let valid = value.greater_equal_elem(0.0).float().mul(
+            u_s.clone()
+                .lower_elem(0.013)
+                .float()
+                .mul(v.clone().greater(u_s.clone()).float())
+                .neg()
+                .add_scalar(1.0),
+        );
+
+        // Accept when ln(v / alpha) - ln(a / u_s^2 + b) <= k ln(rate) - rate - ln(k!).
+        let bound = k
+            .clone()
+            .mul(log_rate.clone())
+            .sub(rate.clone())
+            .sub(k.clone().add_scalar(1.0).lgamma());
+        let ratio = v
+            .log()
+            .add(log_inv_alpha.clone())
+            .sub(a.clone().div(u_s.powf_scalar(2.0)).add(b.clone()).log());
+        let accepted = squeezed
+            .add(valid.mul(ratio.lower_equal(bound).float()))
+            .greater_elem(0.0)
+            .float()
+            .mul(pending.clone());
+
+        rejection = rejection.mask_where(accepted.clone().bool(), k);
+        pending = pending.sub(accepted);
+    }
+
+    rejection.mask_where(small, inversion)
+}
+
+/// Samples category indices from the unnormalized weights along the last dimension with an
+/// inverse transform of their cumulative sum, so backends without a native sampler can keep the
+/// weights on the device.
+///
+/// Without replacement, the samples are drawn one after the other, the weight of each sampled
+/// category being set to zero.
+pub fn cumsum_multinomial<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    num_samples: usize,
+    replacement: bool,
+) -> IntTensor<B, D> {
+    let weights = Tensor::<B, D>::from_primitive(tensor);
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    check_weights(&weights, num_samples, replacement);
+
+    if replacement {
+        return sample_categories(weights, num_samples).into_primitive();
+    }
+
+    let device = weights.device();
+    let dims = weights.dims();
+    let num_categories = dims[D - 1];
+    let mut shape = [1; D];
+    shape[D - 1] = num_categories;
+    let categories = Tensor::<B, 1, Int>::arange(0..num_categories as i64, &device)
+        .reshape(shape)
+        .expand(dims);
+
+    let mut weights = weights;
+    let mut samples = Vec::with_capacity(num_samples);
+    for _ in 0..num_samples {
+        let sample = sample_categories(weights.clone(), 1);
+        let sampled = categories.clone().equal(sample.clone().expand(dims));
+        weights = weights.mask_fill(sampled, 0.0);
+        samples.push(sample);
+    }
+
+    Tensor::cat(samples, D - 1).into_primitive()
+}
+
+/// Rejects the weights that aren't a distribution, as well as the ones with fewer categories
+/// with a positive weight than the number of samples to draw without replacement, reading back
+/// the reduced checks only.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn check_weights<B: Backend, const D: usize>(
+    weights: &Tensor<B, D>,
+    num_samples: usize,
+    replacement: bool,
+) {
+    use crate::ElementConversion;
+
+    if weights.shape().num_elements() == 0 || num_samples == 0 {
+        return;
+    }
+
+    // NaN weights are neither non-negative nor lower than infinity.
+    let valid = weights
+        .clone()
+        .greater_equal_elem(0.0)
+        .int()
+        .mul(weights.clone().lower_elem(f32::INFINITY).int());
+    assert!(
+        valid.min().into_scalar().elem::<i64>() == 1,
+        "The multinomial weights must be finite and non-negative."
+    );
+
+    let num_positive = weights
+        .clone()
+        .greater_elem(0.0)
+        .int()
+        .sum_dim(D - 1)
+        .min()
+        .into_scalar()
+        .elem::<i64>() as usize;
+    assert!(
+        num_positive > 0,
+        "The multinomial weights of a row must have a positive sum."
+    );
+    assert!(
+        replacement || num_samples <= num_positive,
+        "Can't sample {num_samples} indices without replacement from {num_positive} categories \
+         with a positive weight."
+    );
+}
+
+/// Samples category indices with replacement, finding uniform samples between zero and the
+/// sum of the weights in their cumulative sum.
+fn sample_categories<B: Backend, const D: usize>(
+    weights: Tensor<B, D>,
+    num_samples: usize,
+) -> Tensor<B, D, Int> {
+    let num_categories = weights.dims()[D - 1];
+    let cumulative = weights.cumsum(D - 1);
+    let total = cumulative.clone().narrow(D - 1, num_categories - 1, 1);
+
+    let mut shape = cumulative.dims();
+    shape[D - 1] = num_samples;
+    let values =
+        Tensor::random(shape, Distribution::Default, &cumulative.device()).mul(total.expand(shape));
+
+    // The first category whose cumulative weight is greater than the value, so categories
+    // without weight are never sampled.
+    cumulative
+        .searchsorted(values, true)
+        .clamp_max(num_categories as i64 - 1)
+}
+
+/// Returns uniform samples in `(0, 1]`, whose logarithm is finite.
+fn uniform_open<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+    tensor
+        .random_like(Distribution::Default)
+        .neg()
+        .add_scalar(1.0)
+}
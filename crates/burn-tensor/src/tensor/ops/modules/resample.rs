@@ -0,0 +1,124 @@
+use crate::{
+    backend::Backend,
+    module::conv1d,
+    ops::{ConvOptions, FloatTensor, ResampleOptions},
+    ElementConversion, Tensor, TensorData,
+};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use num_traits::Float;
+
+/// Polyphase windowed sinc filter of a [resampling](super::ModuleOps::resample).
+///
+/// The output value `i * new_freq + phase` is the sum of the input values from
+/// `i * orig_freq - width`, weighted by the row `phase` of the weights, the input being padded
+/// with zeros.
+#[derive(Debug, Clone)]
+pub struct ResampleFilter {
+    /// The original rate, divided by the greatest common divisor of the rates.
+    pub orig_freq: usize,
+    /// The new rate, divided by the greatest common divisor of the rates.
+    pub new_freq: usize,
+    /// The number of input values used before the first value of each group.
+    pub width: usize,
+    /// The weights of shape `[new_freq, num_taps]`.
+    pub weights: Vec<f32>,
+}
+
+impl ResampleFilter {
+    /// Creates the Hann windowed sinc filter converting the original rate to the new one.
+    pub fn new(orig_freq: usize, new_freq: usize, options: &ResampleOptions) -> Self {
+        let divisor = gcd(orig_freq, new_freq);
+        let orig_freq = orig_freq / divisor;
+        let new_freq = new_freq / divisor;
+        let filter_width = options.lowpass_filter_width as f64;
+
+        // The cutoff is below the lowest Nyquist frequency, in units of the reduced rates.
+        let base_freq = usize::min(orig_freq, new_freq) as f64 * options.rolloff;
+        let width = Float::ceil(filter_width * orig_freq as f64 / base_freq) as usize;
+        let num_taps = 2 * width + orig_freq;
+        let scale = base_freq / orig_freq as f64;
+
+        let mut weights = Vec::with_capacity(new_freq * num_taps);
+        for phase in 0..new_freq {
+            for tap in 0..num_taps {
+                let time =
+                    (tap as f64 - width as f64) / orig_freq as f64 - phase as f64 / new_freq as f64;
+                let time = (time * base_freq).clamp(-filter_width, filter_width);
+
+                let window = Float::powi(Float::cos(time * PI / filter_width / 2.0), 2);
+                let sinc = match time == 0.0 {
+                    true => 1.0,
+                    false => Float::sin(time * PI) / (time * PI),
+                };
+
+                weights.push((sinc * window * scale) as f32);
+            }
+        }
+
+        Self {
+            orig_freq,
+            new_freq,
+            width,
+            weights,
+        }
+    }
+
+    /// The number of input values used by each output value.
+    pub fn num_taps(&self) -> usize {
+        2 * self.width + self.orig_freq
+    }
+
+    /// The length of the resampled signal, `ceil(length * new_freq / orig_freq)`.
+    pub fn output_length(&self, length: usize) -> usize {
+        (length * self.new_freq).div_ceil(self.orig_freq)
+    }
+}
+
+/// Resamples the signals with a strided convolution computing every phase of the filter, see
+/// [resample](super::ModuleOps::resample).
+pub(crate) fn resample<B: Backend>(
+    x: FloatTensor<B, 2>,
+    orig_freq: usize,
+    new_freq: usize,
+    options: ResampleOptions,
+) -> FloatTensor<B, 2> {
+    let filter = ResampleFilter::new(orig_freq, new_freq, &options);
+    if filter.orig_freq == filter.new_freq {
+        return x;
+    }
+
+    let x = Tensor::<B, 2>::from_primitive(x);
+    let [batch_size, length] = x.dims();
+    let num_taps = filter.num_taps();
+    let weight = Tensor::<B, 3>::from_data(
+        TensorData::new(filter.weights.clone(), [filter.new_freq, 1, num_taps])
+            .convert::<B::FloatElem>(),
+        &x.device(),
+    );
+
+    let x = x.reshape([batch_size, 1, length]).pad(
+        (filter.width, filter.width + filter.orig_freq, 0, 0),
+        0.elem(),
+    );
+    let output = conv1d(
+        x,
+        weight,
+        None,
+        ConvOptions::new([filter.orig_freq], [0], [1], 1),
+    );
+    let [_, _, num_groups] = output.dims();
+
+    output
+        .swap_dims(1, 2)
+        .reshape([batch_size, num_groups * filter.new_freq])
+        .slice([0..batch_size, 0..filter.output_length(length)])
+        .into_primitive()
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
@@ -0,0 +1,369 @@
+use super::grid_sample::floor;
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, RoiAlignOptions, RoiPoolOptions},
+    Int, Tensor,
+};
+
+/// Pools the regions of interest with bilinear sampling, see
+/// [roi_align](super::ModuleOps::roi_align).
+///
+/// Each bin of a region is the average of a grid of sampled values. The sampled values of all
+/// the regions are gathered at once, so autodiff backends compute the gradients of the input.
+pub(crate) fn roi_align<B: Backend>(
+    x: FloatTensor<B, 4>,
+    boxes: FloatTensor<B, 2>,
+    options: RoiAlignOptions,
+) -> FloatTensor<B, 4> {
+    let x = Tensor::<B, 4>::from_primitive(x);
+    let boxes = Tensor::<B, 2>::from_primitive(boxes);
+    let [batch_size, channels, height, width] = x.dims();
+    let [num_boxes, _] = boxes.dims();
+    let [height_out, width_out] = options.output_size;
+
+    let offset = match options.aligned {
+        true => 0.5,
+        false => 0.0,
+    };
+    let (batch_index, [x1, y1, x2, y2]) = split_boxes(boxes);
+    let start_x = x1.mul_scalar(options.spatial_scale).sub_scalar(offset);
+    let start_y = y1.mul_scalar(options.spatial_scale).sub_scalar(offset);
+    let mut roi_width = x2
+        .mul_scalar(options.spatial_scale)
+        .sub_scalar(offset)
+        .sub(start_x.clone());
+    let mut roi_height = y2
+        .mul_scalar(options.spatial_scale)
+        .sub_scalar(offset)
+        .sub(start_y.clone());
+
+    // Misaligned regions are at least one pixel wide.
+    if !options.aligned {
+        roi_width = roi_width.clamp_min(1.0);
+        roi_height = roi_height.clamp_min(1.0);
+    }
+
+    let bin_width = roi_width.div_scalar(width_out as f64);
+    let bin_height = roi_height.div_scalar(height_out as f64);
+    let (grid_x, grid_y, [grid_width, grid_height]) = match options.sampling_ratio {
+        Some(ratio) => (
+            bin_width.ones_like().mul_scalar(ratio as f64),
+            bin_height.ones_like().mul_scalar(ratio as f64),
+            [ratio, ratio],
+        ),
+        None => adaptive_grid(bin_width.clone(), bin_height.clone()),
+    };
+
+    let (y_low, y_high) = sample_axis(
+        start_y,
+        bin_height,
+        grid_y.clone(),
+        [height_out, grid_height],
+        height,
+    );
+    let (x_low, x_high) = sample_axis(
+        start_x,
+        bin_width,
+        grid_x.clone(),
+        [width_out, grid_width],
+        width,
+    );
+
+    let values = x
+        .permute([0, 2, 3, 1])
+        .reshape([batch_size * height * width, channels]);
+    let first_pixel = batch_index
+        .mul_scalar((height * width) as i32)
+        .reshape([num_boxes, 1, 1, 1, 1]);
+    let samples = [num_boxes, height_out, grid_height, width_out, grid_width];
+    let num_samples = samples.iter().product::<usize>();
+
+    let mut output: Option<Tensor<B, 2>> = None;
+    for (index_y, weight_y) in [y_low, y_high] {
+        for (index_x, weight_x) in [x_low.clone(), x_high.clone()] {
+            let index = first_pixel
+                .clone()
+                .expand(samples)
+                .add(
+                    index_y
+                        .clone()
+                        .mul_scalar(width as i32)
+                        .reshape([num_boxes, height_out, grid_height, 1, 1])
+                        .expand(samples),
+                )
+                .add(
+                    index_x
+                        .reshape([num_boxes, 1, 1, width_out, grid_width])
+                        .expand(samples),
+                )
+                .reshape([num_samples]);
+            let weight = weight_y
+                .clone()
+                .reshape([num_boxes, height_out, grid_height, 1, 1])
+                .expand(samples)
+                .mul(
+                    weight_x
+                        .reshape([num_boxes, 1, 1, width_out, grid_width])
+                        .expand(samples),
+                )
+                .reshape([num_samples, 1]);
+
+            let sampled = values.clone().select(0, index).mul(weight);
+            output = Some(match output {
+                Some(output) => output.add(sampled),
+                None => sampled,
+            });
+        }
+    }
+
+    let count = grid_x
+        .mul(grid_y)
+        .clamp_min(1.0)
+        .reshape([num_boxes, 1, 1, 1]);
+
+    output
+        .unwrap()
+        .reshape([
+            num_boxes * height_out,
+            grid_height,
+            width_out,
+            grid_width,
+            channels,
+        ])
+        .sum_dim(1)
+        .sum_dim(3)
+        .reshape([num_boxes, height_out, width_out, channels])
+        .div(count)
+        .permute([0, 3, 1, 2])
+        .into_primitive()
+}
+
+/// Pools the regions of interest with the maximum of the pixels of each bin, see
+/// [roi_pool](super::ModuleOps::roi_pool).
+///
+/// The maximum is computed separately along the width and the height of the bins, always along
+/// the last dimension, so autodiff backends compute the gradients of the input.
+pub(crate) fn roi_pool<B: Backend>(
+    x: FloatTensor<B, 4>,
+    boxes: FloatTensor<B, 2>,
+    options: RoiPoolOptions,
+) -> FloatTensor<B, 4> {
+    let x = Tensor::<B, 4>::from_primitive(x);
+    let boxes = Tensor::<B, 2>::from_primitive(boxes);
+    let [_, channels, height, width] = x.dims();
+    let [num_boxes, _] = boxes.dims();
+    let [height_out, width_out] = options.output_size;
+
+    let (batch_index, [x1, y1, x2, y2]) = split_boxes(boxes);
+    let scale = options.spatial_scale;
+    let in_bin_x = bin_mask(
+        round(x1.mul_scalar(scale)),
+        round(x2.mul_scalar(scale)),
+        width_out,
+        width,
+    );
+    let in_bin_y = bin_mask(
+        round(y1.mul_scalar(scale)),
+        round(y2.mul_scalar(scale)),
+        height_out,
+        height,
+    );
+
+    let x = x.select(0, batch_index);
+    let max_x = x
+        .reshape([num_boxes, channels, height, 1, width])
+        .expand([num_boxes, channels, height, width_out, width])
+        .mask_fill(
+            in_bin_x
+                .clone()
+                .bool_not()
+                .reshape([num_boxes, 1, 1, width_out, width])
+                .expand([num_boxes, channels, height, width_out, width]),
+            f32::NEG_INFINITY,
+        )
+        .max_dim(4)
+        .reshape([num_boxes, channels, height, width_out])
+        .swap_dims(2, 3)
+        .reshape([num_boxes, channels, 1, width_out, height]);
+    let max = max_x
+        .expand([num_boxes, channels, height_out, width_out, height])
+        .mask_fill(
+            in_bin_y
+                .clone()
+                .bool_not()
+                .reshape([num_boxes, 1, height_out, 1, height])
+                .expand([num_boxes, channels, height_out, width_out, height]),
+            f32::NEG_INFINITY,
+        )
+        .max_dim(4)
+        .reshape([num_boxes, channels, height_out, width_out]);
+
+    // Empty bins are zeros.
+    let non_empty_x = in_bin_x.int().sum_dim(2).reshape([num_boxes, 1, width_out]);
+    let non_empty_y = in_bin_y
+        .int()
+        .sum_dim(2)
+        .reshape([num_boxes, height_out, 1]);
+    let empty = non_empty_y
+        .expand([num_boxes, height_out, width_out])
+        .mul(non_empty_x.expand([num_boxes, height_out, width_out]))
+        .equal_elem(0)
+        .reshape([num_boxes, 1, height_out, width_out])
+        .expand([num_boxes, channels, height_out, width_out]);
+
+    max.mask_fill(empty, 0.0).into_primitive()
+}
+
+/// Splits the boxes `(batch_index, x1, y1, x2, y2)` into the batch indices and the coordinates.
+fn split_boxes<B: Backend>(boxes: Tensor<B, 2>) -> (Tensor<B, 1, Int>, [Tensor<B, 1>; 4]) {
+    let [num_boxes, _] = boxes.dims();
+    let column = |index: usize| {
+        boxes
+            .clone()
+            .slice([0..num_boxes, index..index + 1])
+            .reshape([num_boxes])
+    };
+
+    (
+        column(0).int(),
+        [column(1), column(2), column(3), column(4)],
+    )
+}
+
+/// Returns the number of samples along the width and the height of the bins of each region,
+/// when it depends on their size, with the largest numbers of samples.
+///
+/// The sizes of the grids define the shape of the sampled values, so they are read on the host.
+fn adaptive_grid<B: Backend>(
+    bin_width: Tensor<B, 1>,
+    bin_height: Tensor<B, 1>,
+) -> (Tensor<B, 1>, Tensor<B, 1>, [usize; 2]) {
+    let grid_x = floor(bin_width.neg()).neg();
+    let grid_y = floor(bin_height.neg()).neg();
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    let max = Tensor::cat(alloc::vec![grid_x.clone().max(), grid_y.clone().max()], 0)
+        .into_data()
+        .iter::<f64>()
+        .map(|size| size.max(0.0) as usize)
+        .collect::<alloc::vec::Vec<_>>();
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    let max: [usize; 2] = panic!("The adaptive sampling ratio isn't supported in async contexts.");
+
+    (grid_x, grid_y, [max[0], max[1]])
+}
+
+/// Indices and weights of the samples along an axis.
+type AxisSamples<B> = (Tensor<B, 3, Int>, Tensor<B, 3>);
+
+/// Returns the indices and weights of the bilinear interpolation of the samples along an axis
+/// of the given size, with the shape `[num_boxes, num_bins, grid_size]`.
+///
+/// The samples after the number of samples of their region, or too far outside of the input,
+/// have a weight of zero.
+fn sample_axis<B: Backend>(
+    start: Tensor<B, 1>,
+    bin_size: Tensor<B, 1>,
+    grid: Tensor<B, 1>,
+    [num_bins, grid_size]: [usize; 2],
+    size: usize,
+) -> (AxisSamples<B>, AxisSamples<B>) {
+    let [num_boxes] = start.dims();
+    let shape = [num_boxes, num_bins, grid_size];
+    let device = start.device();
+
+    let bins = Tensor::<B, 1, Int>::arange(0..num_bins as i64, &device)
+        .float()
+        .reshape([1, num_bins, 1])
+        .expand(shape);
+    let samples = Tensor::<B, 1, Int>::arange(0..grid_size as i64, &device)
+        .float()
+        .reshape([1, 1, grid_size])
+        .expand(shape);
+    let grid = grid.reshape([num_boxes, 1, 1]).expand(shape);
+    let bin_size = bin_size.reshape([num_boxes, 1, 1]).expand(shape);
+
+    let position = start.reshape([num_boxes, 1, 1]).expand(shape).add(
+        bins.mul(bin_size.clone()).add(
+            samples
+                .clone()
+                .add_scalar(0.5)
+                .mul(bin_size)
+                .div(grid.clone().clamp_min(1.0)),
+        ),
+    );
+
+    let valid = samples
+        .lower(grid)
+        .int()
+        .mul(position.clone().greater_equal_elem(-1.0).int())
+        .mul(position.clone().lower_equal_elem(size as f64).int())
+        .float();
+
+    let position = position.clamp(0.0, (size - 1) as f64);
+    let low = floor(position.clone());
+    let weight_high = position.sub(low.clone());
+    let weight_low = weight_high.clone().neg().add_scalar(1.0);
+    let low = low.int();
+    let high = low.clone().add_scalar(1).clamp_max(size as i32 - 1);
+
+    (
+        (low, weight_low.mul(valid.clone())),
+        (high, weight_high.mul(valid)),
+    )
+}
+
+/// Returns the mask of the pixels of each bin of the regions along an axis, with the shape
+/// `[num_boxes, num_bins, size]`.
+fn bin_mask<B: Backend>(
+    start: Tensor<B, 1>,
+    end: Tensor<B, 1>,
+    num_bins: usize,
+    size: usize,
+) -> Tensor<B, 3, crate::Bool> {
+    let [num_boxes] = start.dims();
+    let shape = [num_boxes, num_bins, size];
+    let device = start.device();
+
+    let bin_size = end
+        .sub(start.clone())
+        .add_scalar(1.0)
+        .clamp_min(1.0)
+        .div_scalar(num_bins as f64)
+        .reshape([num_boxes, 1]);
+    let bins = Tensor::<B, 1, Int>::arange(0..num_bins as i64, &device)
+        .float()
+        .reshape([1, num_bins]);
+    let start = start.reshape([num_boxes, 1]);
+
+    let bin_start = floor(bins.clone().mul(bin_size.clone()))
+        .add(start.clone())
+        .clamp(0.0, size as f64)
+        .reshape([num_boxes, num_bins, 1])
+        .expand(shape);
+    let bin_end = floor(bins.add_scalar(1.0).mul(bin_size).neg())
+        .neg()
+        .add(start)
+        .clamp(0.0, size as f64)
+        .reshape([num_boxes, num_bins, 1])
+        .expand(shape);
+    let pixels = Tensor::<B, 1, Int>::arange(0..size as i64, &device)
+        .float()
+        .reshape([1, 1, size])
+        .expand(shape);
+
+    pixels
+        .clone()
+        .greater_equal(bin_start)
+        .int()
+        .mul(pixels.lower(bin_end).int())
+        .bool()
+}
+
+/// Rounds to the nearest integer, half-way cases being rounded away from zero.
+fn round<B: Backend>(tensor: Tensor<B, 1>) -> Tensor<B, 1> {
+    let rounded = floor(tensor.clone().abs().add_scalar(0.5));
+
+    rounded.mul(tensor.sign())
+}
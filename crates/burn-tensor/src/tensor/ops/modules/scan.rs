@@ -0,0 +1,56 @@
+use crate::{backend::Backend, ops::FloatTensor, Tensor};
+use alloc::vec::Vec;
+
+/// Computes the selective scan of the input, see
+/// [selective_scan](super::ModuleOps::selective_scan).
+///
+/// The discretized state matrices of every step are computed at once, and the recurrence is
+/// applied one step at a time with tensor operations, so autodiff backends compute the gradients
+/// of every input, and fused implementations can fall back to it for the sizes they don't
+/// support.
+pub fn selective_scan<B: Backend>(
+    u: FloatTensor<B, 3>,
+    delta: FloatTensor<B, 3>,
+    a: FloatTensor<B, 2>,
+    b: FloatTensor<B, 3>,
+    c: FloatTensor<B, 3>,
+) -> FloatTensor<B, 3> {
+    let u = Tensor::<B, 3>::from_primitive(u);
+    let delta = Tensor::<B, 3>::from_primitive(delta);
+    let a = Tensor::<B, 2>::from_primitive(a);
+    let b = Tensor::<B, 3>::from_primitive(b);
+    let c = Tensor::<B, 3>::from_primitive(c);
+
+    let [batch_size, channels, length] = u.dims();
+    let [_, d_state] = a.dims();
+
+    // `[batch_size, channels, length, d_state]`
+    let delta_a = delta
+        .clone()
+        .reshape([batch_size, channels, length, 1])
+        .mul(a.reshape([1, channels, 1, d_state]))
+        .exp();
+    let delta_b_u = delta
+        .mul(u)
+        .reshape([batch_size, channels, length, 1])
+        .mul(b.swap_dims(1, 2).reshape([batch_size, 1, length, d_state]));
+    let c = c.swap_dims(1, 2).reshape([batch_size, 1, length, d_state]);
+
+    let mut state = Tensor::<B, 3>::zeros([batch_size, channels, d_state], &delta_a.device());
+    let mut outputs = Vec::with_capacity(length);
+
+    for t in 0..length {
+        let step = |x: &Tensor<B, 4>, size: usize| {
+            x.clone()
+                .narrow(2, t, 1)
+                .reshape([batch_size, size, d_state])
+        };
+
+        state = step(&delta_a, channels)
+            .mul(state)
+            .add(step(&delta_b_u, channels));
+        outputs.push(state.clone().mul(step(&c, 1)).sum_dim(2));
+    }
+
+    Tensor::cat(outputs, 2).into_primitive()
+}
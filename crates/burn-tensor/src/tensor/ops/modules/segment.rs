@@ -0,0 +1,114 @@
+use crate::{backend::Backend, ops::IntTensor, IndexReduction, Int, Numeric, Tensor};
+
+/// Sums the slices of the first dimension belonging to the same segment by assigning them at
+/// their segment ids, which don't need to be sorted.
+///
+/// This is the reference implementation used by backends without an atomic kernel: it only
+/// requires tensor operations, and it is deterministic and differentiable.
+pub fn select_segment_sum<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: K::Primitive<D>,
+    segment_ids: IntTensor<B, 1>,
+    num_segments: usize,
+) -> K::Primitive<D> {
+    let tensor = Tensor::<B, D, K>::from_primitive(tensor);
+    let mut dims = tensor.dims();
+    dims[0] = num_segments;
+
+    Tensor::<B, D, K>::zeros(dims, &tensor.device())
+        .select_assign(0, Tensor::from_primitive(segment_ids), tensor)
+        .into_primitive()
+}
+
+/// Reduces the slices of the first dimension belonging to the same segment, the segment ids
+/// being sorted, with tensor operations.
+///
+/// This is the reference implementation used by backends without a native kernel. The products
+/// and extrema are computed by an inclusive scan over the slices, where each step combines the
+/// accumulated slices with the previous ones of the same segment, the last slice of each segment
+/// holding the reduction. The bounds of the segments are found with a binary search, so nothing is
+/// read on the host.
+pub fn scan_segment_reduce_sorted<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: K::Primitive<D>,
+    segment_ids: IntTensor<B, 1>,
+    num_segments: usize,
+    reduction: IndexReduction,
+) -> K::Primitive<D> {
+    let tensor = Tensor::<B, D, K>::from_primitive(tensor);
+    let ids = Tensor::<B, 1, Int>::from_primitive(segment_ids);
+    let dims = tensor.dims();
+    let n = dims[0];
+    let device = tensor.device();
+
+    let mut segment_shape = [1; D];
+    segment_shape[0] = num_segments;
+    let mut segment_dims = dims;
+    segment_dims[0] = num_segments;
+
+    if n == 0 {
+        return Tensor::<B, D, K>::zeros(segment_dims, &device).into_primitive();
+    }
+
+    type Combine<B, const D: usize, K> = fn(Tensor<B, D, K>, Tensor<B, D, K>) -> Tensor<B, D, K>;
+    let combine: Combine<B, D, K> = match reduction {
+        IndexReduction::Sum => {
+            return select_segment_sum::<B, D, K>(
+                tensor.into_primitive(),
+                ids.into_primitive(),
+                num_segments,
+            );
+        }
+        IndexReduction::Mean => {
+            let counts = Tensor::<B, 1, K>::zeros([num_segments], &device)
+                .select_assign(0, ids.clone(), Tensor::ones([n], &device))
+                .clamp_min(1)
+                .reshape(segment_shape);
+            let sums = select_segment_sum::<B, D, K>(
+                tensor.into_primitive(),
+                ids.into_primitive(),
+                num_segments,
+            );
+
+            return Tensor::<B, D, K>::from_primitive(sums)
+                .div(counts)
+                .into_primitive();
+        }
+        IndexReduction::Prod => |accumulated, previous| accumulated.mul(previous),
+        IndexReduction::Amax => |accumulated, previous| {
+            let replaced = previous.clone().greater(accumulated.clone());
+            accumulated.mask_where(replaced, previous)
+        },
+        IndexReduction::Amin => |accumulated, previous| {
+            let replaced = previous.clone().lower(accumulated.clone());
+            accumulated.mask_where(replaced, previous)
+        },
+    };
+
+    let mut mask_shape = [1; D];
+    mask_shape[0] = n;
+    let mut output = tensor;
+    let mut offset = 1;
+    while offset < n {
+        let (previous, _) = output.clone().shifted(0, offset);
+        let (previous_ids, valid) = ids.clone().shifted(0, offset);
+        let same = previous_ids.equal(ids.clone()).int() * valid.int();
+        let same = same.reshape(mask_shape).expand(dims);
+
+        let combined = combine(output.clone(), previous);
+        output = output.mask_where(same.bool(), combined);
+        offset *= 2;
+    }
+
+    let segments = Tensor::<B, 1, Int>::arange(0..num_segments as i64, &device);
+    let ends = ids.clone().searchsorted(segments.clone(), true);
+    let starts = ids.searchsorted(segments, false);
+    let empty = ends
+        .clone()
+        .equal(starts)
+        .reshape(segment_shape)
+        .expand(segment_dims);
+
+    output
+        .select(0, ends.sub_scalar(1).clamp_min(0))
+        .mask_fill(empty, 0)
+        .into_primitive()
+}
@@ -0,0 +1,57 @@
+use crate::{backend::Backend, BasicOps, Tensor, TensorKind};
+use alloc::vec;
+use core::ops::Range;
+
+pub(crate) fn slice_with_steps_reshape<
+    B: Backend,
+    const D1: usize,
+    const D2: usize,
+    K: TensorKind<B> + BasicOps<B>,
+>(
+    tensor: Tensor<B, D1, K>,
+    ranges: [Range<usize>; D2],
+    steps: [isize; D2],
+) -> Tensor<B, D1, K> {
+    let mut tensor = tensor.slice(ranges);
+
+    for (dim, step) in steps.into_iter().enumerate() {
+        if step < 0 {
+            tensor = tensor.flip([dim as isize]);
+        }
+        tensor = take_every(tensor, dim, step.unsigned_abs());
+    }
+
+    tensor
+}
+
+/// Keep every `step` element along the dimension, starting from the first one.
+///
+/// The elements before the last selected one are reshaped into rows of `step` elements, whose
+/// first element is kept, and the last selected element is concatenated.
+fn take_every<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+    step: usize,
+) -> Tensor<B, D, K> {
+    let dims = tensor.dims();
+    let count = dims[dim].div_ceil(step);
+    match count {
+        _ if step == 1 => return tensor,
+        1 => return tensor.narrow(dim, 0, 1),
+        _ => (),
+    }
+
+    let prefix = dims[..dim].iter().product::<usize>();
+    let suffix = dims[dim + 1..].iter().product::<usize>();
+    let mut shape = dims;
+    shape[dim] = count - 1;
+
+    let last = tensor.clone().narrow(dim, (count - 1) * step, 1);
+    let strided = tensor
+        .narrow(dim, 0, (count - 1) * step)
+        .reshape([prefix, count - 1, step * suffix])
+        .slice([0..prefix, 0..count - 1, 0..suffix])
+        .reshape(shape);
+
+    Tensor::cat(vec![strided, last], dim)
+}
@@ -0,0 +1,253 @@
+use crate::{backend::Backend, Tensor};
+use core::f64::consts::{FRAC_2_SQRT_PI, PI};
+
+/// `ln(2 pi) / 2`, used by the Stirling term of the Lanczos approximation.
+const HALF_LN_2PI: f64 = 0.918_938_533_204_672_8;
+
+/// Coefficients of the approximation of the inverse error function for `w < 5`, where
+/// `w = -ln((1 - x)(1 + x))`, from Giles, "Approximating the erfinv function" (2010).
+const ERFINV_CENTRAL: [f64; 9] = [
+    2.81022636e-08,
+    3.43273939e-07,
+    -3.5233877e-06,
+    -4.39150654e-06,
+    0.00021858087,
+    -0.00125372503,
+    -0.00417768164,
+    0.246640727,
+    1.50140941,
+];
+
+/// Coefficients of the approximation of the inverse error function for `w >= 5`.
+const ERFINV_TAIL: [f64; 9] = [
+    -0.000200214257,
+    0.000100950558,
+    0.00134934322,
+    -0.00367342844,
+    0.00573950773,
+    -0.0076224613,
+    0.00943887047,
+    1.00167406,
+    2.83297682,
+];
+
+/// Coefficients of the Lanczos approximation of the gamma function with `g = 7`.
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.5203681218851,
+    -1259.1392167224028,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507343278686905,
+    -0.13857109526572012,
+    9.984_369_578_019_572e-6,
+    1.5056327351493116e-7,
+];
+const LANCZOS_G: f64 = 7.0;
+
+/// The Bernoulli numbers `B_2k` for `k = 1..=7`, used by the asymptotic series of the
+/// polygamma functions.
+const BERNOULLI: [f64; 7] = [
+    1.0 / 6.0,
+    -1.0 / 30.0,
+    1.0 / 42.0,
+    -1.0 / 30.0,
+    5.0 / 66.0,
+    -691.0 / 2730.0,
+    7.0 / 6.0,
+];
+
+/// The inputs are shifted with the recurrence relation of the polygamma functions until they
+/// reach this value, where the asymptotic series is accurate.
+const ASYMPTOTIC_MIN: f64 = 10.0;
+
+/// Inverse error function, from the approximation of Giles refined with a step of Newton's
+/// method.
+pub(crate) fn erfinv<B: Backend, const D: usize>(x: Tensor<B, D>) -> Tensor<B, D> {
+    let w = x
+        .clone()
+        .neg()
+        .add_scalar(1.0)
+        .mul(x.clone().add_scalar(1.0))
+        .log()
+        .neg();
+
+    let central = polynomial(w.clone().sub_scalar(2.5), &ERFINV_CENTRAL);
+    let tail = polynomial(w.clone().sqrt().sub_scalar(3.0), &ERFINV_TAIL);
+    let output = central
+        .mask_where(w.greater_equal_elem(5.0), tail)
+        .mul(x.clone());
+
+    // d/dy erf(y) = 2 / sqrt(pi) * exp(-y^2)
+    let derivative = output
+        .clone()
+        .powf_scalar(2.0)
+        .neg()
+        .exp()
+        .mul_scalar(FRAC_2_SQRT_PI);
+    let error = output.clone().erf().sub(x.clone());
+    let output = output.sub(error.div(derivative));
+
+    output.mask_where(x.clone().abs().equal_elem(1.0), x.mul_scalar(f64::INFINITY))
+}
+
+/// Natural logarithm of the absolute value of the gamma function, from the Lanczos
+/// approximation and the reflection formula for inputs below `0.5`.
+pub(crate) fn lgamma<B: Backend, const D: usize>(x: Tensor<B, D>) -> Tensor<B, D> {
+    let reflected = x.clone().lower_elem(0.5);
+    let z = x
+        .clone()
+        .mask_where(reflected.clone(), x.clone().neg().add_scalar(1.0))
+        .sub_scalar(1.0);
+
+    let mut series = z.zeros_like().add_scalar(LANCZOS[0]);
+    for (i, coefficient) in LANCZOS.iter().enumerate().skip(1) {
+        series = series.add(
+            z.clone()
+                .add_scalar(i as f64)
+                .recip()
+                .mul_scalar(*coefficient),
+        );
+    }
+    let t = z.clone().add_scalar(LANCZOS_G + 0.5);
+    let output = z
+        .add_scalar(0.5)
+        .mul(t.clone().log())
+        .sub(t)
+        .add(series.log())
+        .add_scalar(HALF_LN_2PI);
+
+    // ln|gamma(x)| = ln(pi / |sin(pi x)|) - ln|gamma(1 - x)|
+    let reflection = x
+        .mul_scalar(PI)
+        .sin()
+        .abs()
+        .recip()
+        .mul_scalar(PI)
+        .log()
+        .sub(output.clone());
+
+    output.mask_where(reflected, reflection)
+}
+
+/// Digamma function, from its asymptotic series after shifting the inputs with the recurrence
+/// relation, and the reflection formula for inputs below `0.5`.
+pub(crate) fn digamma<B: Backend, const D: usize>(x: Tensor<B, D>) -> Tensor<B, D> {
+    let reflected = x.clone().lower_elem(0.5);
+    let z = x
+        .clone()
+        .mask_where(reflected.clone(), x.clone().neg().add_scalar(1.0));
+
+    // psi(z) = psi(z + 1) - 1 / z, where z >= 0.5 reaches the asymptotic range in 10 steps.
+    let (z, shift) = shift_recurrence(z, |z| z.recip());
+
+    // psi(z) ~ ln(z) - 1 / 2z - sum(B_2k / (2k z^2k))
+    let t = z.clone().powf_scalar(2.0).recip();
+    let coefficients = core::array::from_fn::<_, 7, _>(|k| {
+        let k = 6 - k;
+        -BERNOULLI[k] / (2.0 * (k as f64 + 1.0))
+    });
+    let series = polynomial(t.clone(), &coefficients).mul(t);
+    let output = z
+        .clone()
+        .log()
+        .sub(z.recip().mul_scalar(0.5))
+        .add(series)
+        .sub(shift);
+
+    // psi(x) = psi(1 - x) - pi / tan(pi x)
+    let angle = x.clone().mul_scalar(PI);
+    let reflection = output
+        .clone()
+        .sub(angle.clone().cos().div(angle.sin()).mul_scalar(PI));
+
+    // The poles at the non-positive integers are undefined, like in PyTorch, the positive inputs
+    // being replaced by a fraction so that only the non-positive integers aren't truncated.
+    let non_positive = x.clone().mask_fill(x.greater_elem(0.0), 0.5);
+    let poles = non_positive.clone().int().float().equal(non_positive);
+
+    output
+        .mask_where(reflected, reflection)
+        .mask_fill(poles, f64::NAN)
+}
+
+/// Polygamma function of order `n >= 1`, from its asymptotic series after shifting the inputs
+/// with the recurrence relation. Only positive inputs are supported.
+pub(crate) fn polygamma<B: Backend, const D: usize>(x: Tensor<B, D>, n: u32) -> Tensor<B, D> {
+    let coefficients = polygamma_coefficients(n);
+    let order = n as f64;
+
+    // psi_n(z) = psi_n(z + 1) + (-1)^(n + 1) n! / z^(n + 1)
+    let (z, shift) = shift_recurrence(x, |z| z.powf_scalar(-(order + 1.0)));
+
+    // psi_n(z) ~ (-1)^(n + 1) [(n - 1)! / z^n + n! / 2z^(n + 1)
+    //                          + sum(B_2k (2k + n - 1)! / ((2k)! z^(2k + n)))]
+    let t = z.clone().powf_scalar(2.0).recip();
+    let series = polynomial(t, &coefficients)
+        .add(z.clone().recip().mul_scalar(factorial(n) / 2.0))
+        .mul(z.powf_scalar(-order))
+        .add(shift.mul_scalar(factorial(n)));
+
+    match n % 2 {
+        0 => series.neg(),
+        _ => series,
+    }
+}
+
+/// The coefficients, highest degree first, of the asymptotic series of the polygamma function
+/// of order `n` as a polynomial of `1 / z^2`, including the `(n - 1)!` constant term.
+fn polygamma_coefficients(n: u32) -> [f64; 8] {
+    let mut coefficients = [0.0; 8];
+    // (2k + n - 1)! / (2k)! for k = 1.
+    let mut ratio = factorial(n + 1) / 2.0;
+
+    for (k, bernoulli) in BERNOULLI.iter().enumerate() {
+        coefficients[6 - k] = bernoulli * ratio;
+
+        let k = 2.0 * (k as f64 + 1.0);
+        let order = n as f64;
+        ratio *= (k + order) * (k + order + 1.0) / ((k + 1.0) * (k + 2.0));
+    }
+    coefficients[7] = factorial(n - 1);
+
+    coefficients
+}
+
+/// Shift the inputs below the asymptotic range with a recurrence relation, returning the
+/// shifted inputs and the sum of the terms of the recurrence evaluated before each shift.
+fn shift_recurrence<B: Backend, const D: usize, F>(
+    mut z: Tensor<B, D>,
+    term: F,
+) -> (Tensor<B, D>, Tensor<B, D>)
+where
+    F: Fn(Tensor<B, D>) -> Tensor<B, D>,
+{
+    let mut shift = z.zeros_like();
+
+    for _ in 0..ASYMPTOTIC_MIN as usize {
+        let step = z.clone().lower_elem(ASYMPTOTIC_MIN).float();
+        shift = shift.add(term(z.clone()).mul(step.clone()));
+        z = z.add(step);
+    }
+
+    (z, shift)
+}
+
+/// Evaluate a polynomial with Horner's method, the coefficients starting from the highest
+/// degree.
+fn polynomial<B: Backend, const D: usize>(x: Tensor<B, D>, coefficients: &[f64]) -> Tensor<B, D> {
+    let mut output = x
+        .clone()
+        .mul_scalar(coefficients[0])
+        .add_scalar(coefficients[1]);
+
+    for coefficient in &coefficients[2..] {
+        output = output.mul(x.clone()).add_scalar(*coefficient);
+    }
+
+    output
+}
+
+fn factorial(n: u32) -> f64 {
+    (1..=n).map(|i| i as f64).product()
+}
@@ -0,0 +1,72 @@
+use crate::{backend::Backend, ops::IntTensor, Int, Numeric, Tensor, TensorKind};
+
+/// The unique elements of a tensor, with the inverse indices and the counts.
+pub type UniqueOutput<B, K, const D: usize> = (
+    <K as TensorKind<B>>::Primitive<1>,
+    IntTensor<B, D>,
+    IntTensor<B, 1>,
+);
+
+/// Collapses the runs of equal elements of the flattened tensor with tensor operations, sorting
+/// the elements first unless only the consecutive runs are collapsed.
+///
+/// This is the reference implementation used by backends without a native kernel: the first
+/// element of each run is flagged by comparing each element with the previous one, and the start
+/// of the runs is read on the host since it defines the size of the output.
+pub fn sort_unique<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: K::Primitive<D>,
+    consecutive: bool,
+) -> UniqueOutput<B, K, D> {
+    let tensor = Tensor::<B, D, K>::from_primitive(tensor);
+    let shape = tensor.shape();
+    let device = tensor.device();
+    let n = shape.num_elements();
+
+    let values = tensor.reshape([n]);
+    if n == 0 {
+        return (
+            values.into_primitive(),
+            Tensor::<B, D, Int>::empty(shape, &device).into_primitive(),
+            Tensor::<B, 1, Int>::empty([0], &device).into_primitive(),
+        );
+    }
+
+    let (values, permutation) = match consecutive {
+        true => (values, None),
+        false => {
+            let (values, permutation) = values.sort_with_indices(0);
+            (values, Some(permutation))
+        }
+    };
+
+    let positions = Tensor::<B, 1, Int>::arange(0..n as i64, &device);
+    let previous = values
+        .clone()
+        .select(0, positions.clone().sub_scalar(1).clamp_min(0));
+    let first = values
+        .clone()
+        .not_equal(previous)
+        .int()
+        .mask_fill(positions.clone().equal_elem(0), 1);
+
+    let starts = first.bool().argwhere();
+    let num_runs = starts.dims()[0];
+    let starts = starts.reshape([num_runs]);
+
+    let runs = starts.clone().searchsorted(positions, true).sub_scalar(1);
+    let counts = Tensor::<B, 1, Int>::zeros([num_runs], &device).select_assign(
+        0,
+        runs.clone(),
+        Tensor::ones([n], &device),
+    );
+    let inverse = match permutation {
+        Some(permutation) => Tensor::zeros([n], &device).scatter(0, permutation, runs),
+        None => runs,
+    };
+
+    (
+        values.select(0, starts).into_primitive(),
+        inverse.reshape(shape).into_primitive(),
+        counts.into_primitive(),
+    )
+}
@@ -1,15 +1,32 @@
+use super::bits;
 use super::cat::cat_with_slice_assign;
+use super::einsum::{self, Contraction};
+use super::fft;
+use super::random;
 use super::repeat::repeat_with_slice_assign;
-use super::{BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor};
+use super::segment;
+use super::slice::slice_with_steps_reshape;
+use super::special;
+use super::{
+    BoolTensor, Device, FloatElem, FloatGradHook, FloatGradMap, FloatTensor, FullPrecisionBackend,
+    IntElem, IntTensor,
+};
 use crate::backend::BackendBridge;
+use crate::linalg::{self, QrMode};
 use crate::tensor::cast::ToElement;
-use crate::Tensor;
-use crate::{backend::Backend, tensor::Shape, Distribution, ElementConversion, Float, TensorData};
+use crate::{
+    backend::Backend, tensor::Shape, Distribution, ElementConversion, Float, IndexReduction,
+    TensorData,
+};
 use crate::{tensor::api::chunk, tensor::api::narrow};
+use crate::{ComplexPrimitive, Tensor};
 use alloc::vec::Vec;
+use burn_common::rand::{SeedableRng, StdRng};
 use burn_common::reader::Reader;
 use core::ops::Range;
 
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use super::unique;
 #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
 use crate::{argsort, sort, sort_with_indices};
 
@@ -44,6 +61,59 @@ pub trait FloatTensorOps<B: Backend> {
         device: &Device<B>,
     ) -> FloatTensor<B, D>;
 
+    /// Creates a new tensor with random values sampled with the given seed, instead of the
+    /// global random number generator of the backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the tensor.
+    /// * `distribution` - The distribution to sample from.
+    /// * `seed` - The seed of the random number generator.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the given shape and random values.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation samples the values on the host, so they are the same for all
+    /// the backends.
+    fn float_random_seeded<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        seed: u64,
+        device: &Device<B>,
+    ) -> FloatTensor<B, D> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let data = TensorData::random::<FloatElem<B>, _, _>(shape, distribution, &mut rng);
+
+        B::float_from_data(data, device)
+    }
+
+    /// Samples category indices from the unnormalized weights along the last dimension.
+    ///
+    /// The default implementation [inverts the cumulative sum](random::cumsum_multinomial)
+    /// of the weights with tensor operations, and should be overridden by backends with a native
+    /// sampler.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The non-negative weights.
+    /// * `num_samples` - The number of indices to sample for each sequence of weights.
+    /// * `replacement` - If false, a category is sampled at most once.
+    ///
+    /// # Returns
+    ///
+    /// The `[..., num_samples]` sampled indices.
+    fn float_multinomial<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<B, D> {
+        random::cumsum_multinomial::<B, D>(tensor, num_samples, replacement)
+    }
+
     /// Creates a new tensor with zeros.
     ///
     /// # Arguments
@@ -126,6 +196,24 @@ pub trait FloatTensorOps<B: Backend> {
     /// The data structure with the tensor's data.
     fn float_into_data<const D: usize>(tensor: FloatTensor<B, D>) -> Reader<TensorData>;
 
+    /// Computes a deterministic checksum of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The [checksum](TensorData::checksum) of the tensor's data.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the data of the tensor, backends can compute the same
+    /// checksum on the device to avoid transferring the whole tensor.
+    fn float_checksum<const D: usize>(tensor: FloatTensor<B, D>) -> Reader<u64> {
+        Self::float_into_data(tensor).map(|data| data.checksum())
+    }
+
     /// Gets the device of the tensor.
     ///
     /// # Arguments
@@ -393,6 +481,34 @@ pub trait FloatTensorOps<B: Backend> {
         rhs: FloatTensor<B, D>,
     ) -> FloatTensor<B, D>;
 
+    /// Multiplies a sparse matrix stored in the compressed sparse row (CSR) format by a dense
+    /// matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_offsets` - The `[m + 1]` offsets of the first stored element of each row.
+    /// * `col_indices` - The `[nnz]` column indices of the stored elements.
+    /// * `values` - The `[nnz]` values of the stored elements.
+    /// * `rhs` - The `[k, n]` dense matrix.
+    ///
+    /// # Returns
+    ///
+    /// The `[m, n]` product of the sparse and dense matrices.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation reads the row offsets to expand the row of each stored
+    /// element, backends are expected to reduce each row segment directly.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_spmm_csr(
+        row_offsets: IntTensor<B, 1>,
+        col_indices: IntTensor<B, 1>,
+        values: FloatTensor<B, 1>,
+        rhs: FloatTensor<B, 2>,
+    ) -> FloatTensor<B, 2> {
+        crate::sparse::spmm_csr::<B>(row_offsets, col_indices, values, rhs)
+    }
+
     /// Negates a tensor element-wise.
     fn float_neg<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
         Self::float_mul_scalar(tensor, (-1.0_f32).elem::<FloatElem<B>>())
@@ -558,6 +674,31 @@ pub trait FloatTensorOps<B: Backend> {
         ranges: [Range<usize>; D2],
     ) -> FloatTensor<B, D1>;
 
+    /// Gets the elements of the tensor for the given ranges, with a step between the selected
+    /// indices of each range.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `ranges` - The ranges to get the elements from.
+    /// * `steps` - The non-zero steps of the ranges, negative ones selecting in reverse order.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the selected elements.
+    fn float_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<B, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> FloatTensor<B, D1> {
+        slice_with_steps_reshape::<B, D1, D2, Float>(
+            Tensor::<B, D1, Float>::from_primitive(tensor),
+            ranges,
+            steps,
+        )
+        .into_primitive()
+    }
+
     /// Assign the selected elements corresponding for the given ranges to the given value.
     ///
     /// # Arguments
@@ -609,6 +750,32 @@ pub trait FloatTensorOps<B: Backend> {
         value: FloatElem<B>,
     ) -> FloatTensor<B, D>;
 
+    /// Update the given tensor with the value where the bits of the packed mask are set.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to select from.
+    /// * `mask` - The mask packed with [bool_pack_bits](super::BoolTensorOps::bool_pack_bits),
+    ///   with the shape of the tensor except for its last dimension.
+    /// * `value` - The value to assign to the selected elements.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the selected elements assigned to the given value.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation unpacks the mask before calling
+    /// [float_mask_fill](FloatTensorOps::float_mask_fill), backends should read the bits of the
+    /// mask directly to avoid allocating it.
+    fn float_mask_fill_packed<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        mask: IntTensor<B, D>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<B, D> {
+        bits::mask_fill_packed::<B, D>(tensor, mask, value)
+    }
+
     /// Equal comparison of two tensors.
     ///
     /// # Arguments
@@ -816,6 +983,33 @@ pub trait FloatTensorOps<B: Backend> {
         false
     }
 
+    /// Registers a hook called with the gradient of a tensor during the backward pass.
+    ///
+    /// # Returns
+    ///
+    /// The same tensor, whose gradient is passed to the hook.
+    fn float_register_grad_hook<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        _hook: FloatGradHook<B, D>,
+    ) -> FloatTensor<B, D> {
+        // Should only be overridden by autodiff backends.
+        tensor
+    }
+
+    /// Replaces the gradient of a tensor during the backward pass with the one returned by a
+    /// function called with the gradient.
+    ///
+    /// # Returns
+    ///
+    /// The same tensor, whose gradient is mapped by the function before being propagated.
+    fn float_map_grad<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        _map: FloatGradMap<B, D>,
+    ) -> FloatTensor<B, D> {
+        // Should only be overridden by autodiff backends.
+        tensor
+    }
+
     /// Sum of all elements in a tensor.
     ///
     /// # Arguments
@@ -839,6 +1033,30 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the sum of all elements in `tensor` along `dim`.
     fn float_sum_dim<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D>;
 
+    /// Logarithm of the sum of the exponentials of all elements in a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to reduce.
+    /// * `dim` - The dimension along which to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `log(sum(exp(tensor)))` along `dim`, computed after subtracting the maximum
+    /// of the elements to avoid overflows. An infinite maximum isn't subtracted, so a row that is
+    /// entirely `-inf` reduces to `-inf` instead of NaN.
+    fn float_logsumexp_dim<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> FloatTensor<B, D> {
+        let max = B::float_max_dim(tensor.clone(), dim);
+        let is_infinite = B::float_equal_elem(B::float_abs(max.clone()), f32::INFINITY.elem());
+        let max = B::float_mask_fill(max, is_infinite, 0.elem());
+        let shifted = B::float_exp(B::float_sub(tensor, max.clone()));
+
+        B::float_add(B::float_log(B::float_sum_dim(shifted, dim)), max)
+    }
+
     /// Product of all elements in a tensor.
     ///
     /// # Arguments
@@ -1086,6 +1304,413 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn float_erf<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
 
+    /// Returns a new tensor with the inverse error function values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse error function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse error function values, which are
+    /// infinite at `-1` and `1`.
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        special::erfinv(Tensor::<B, D>::from_primitive(tensor)).into_primitive()
+    }
+
+    /// Returns a new tensor with the natural logarithm of the absolute value of the gamma
+    /// function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the log-gamma function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with log-gamma function values.
+    fn float_lgamma<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        special::lgamma(Tensor::<B, D>::from_primitive(tensor)).into_primitive()
+    }
+
+    /// Returns a new tensor with the digamma function values, the derivative of the log-gamma
+    /// function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the digamma function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with digamma function values.
+    fn float_digamma<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        special::digamma(Tensor::<B, D>::from_primitive(tensor)).into_primitive()
+    }
+
+    /// Returns a new tensor with the polygamma function values of the given order, the
+    /// derivative of that order of the digamma function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the polygamma function of.
+    /// * `order` - The order of the derivative, the order `0` being the digamma function.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with polygamma function values, only defined for
+    /// positive inputs when the order isn't `0`.
+    fn float_polygamma<const D: usize>(tensor: FloatTensor<B, D>, order: u32) -> FloatTensor<B, D> {
+        match order {
+            0 => B::float_digamma(tensor),
+            _ => special::polygamma(Tensor::<B, D>::from_primitive(tensor), order).into_primitive(),
+        }
+    }
+
+    /// Returns a new tensor with the four-quadrant arctangent of `lhs / rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The y coordinates.
+    /// * `rhs` - The x coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the angles, in radians within `[-pi, pi]`, of the points `(rhs, lhs)`.
+    fn float_atan2<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        // Polynomial approximation of the arctangent on [0, 1], with an error below 2e-8, from
+        // Abramowitz and Stegun 4.4.49.
+        const COEFFICIENTS: [f64; 8] = [
+            -0.0040540580,
+            0.0218612288,
+            -0.0559098861,
+            0.0964200441,
+            -0.1390853351,
+            0.1994653599,
+            -0.3332985605,
+            0.9999993329,
+        ];
+
+        let (lhs_shape, rhs_shape) = (B::float_shape(&lhs), B::float_shape(&rhs));
+        let shape = lhs_shape
+            .broadcast(&rhs_shape)
+            .unwrap_or_else(|error| panic!("{error}"));
+        let y = B::float_expand(lhs, shape.clone());
+        let x = B::float_expand(rhs, shape);
+
+        // The angle is computed in the first octant, then mapped to the quadrant of the point.
+        let y_abs = B::float_abs(y.clone());
+        let x_abs = B::float_abs(x.clone());
+        let swapped = B::float_greater(y_abs.clone(), x_abs.clone());
+        let lower = B::float_mask_where(y_abs.clone(), swapped.clone(), x_abs.clone());
+        let upper = B::float_mask_where(x_abs, swapped.clone(), y_abs);
+        let is_origin = B::float_equal_elem(upper.clone(), 0.elem());
+        let ratio = B::float_div(lower, B::float_mask_fill(upper, is_origin, 1.elem()));
+        let ratio_squared = B::float_mul(ratio.clone(), ratio.clone());
+
+        let mut polynomial = B::float_mul_scalar(ratio_squared.clone(), COEFFICIENTS[0].elem());
+        for coefficient in &COEFFICIENTS[1..COEFFICIENTS.len() - 1] {
+            polynomial = B::float_add_scalar(polynomial, coefficient.elem());
+            polynomial = B::float_mul(polynomial, ratio_squared.clone());
+        }
+        let polynomial = B::float_add_scalar(polynomial, COEFFICIENTS[7].elem());
+        let angle = B::float_mul(ratio, polynomial);
+
+        let mirrored = |angle: FloatTensor<B, D>, offset: f64| {
+            B::float_add_scalar(B::float_neg(angle), offset.elem())
+        };
+        let angle = B::float_mask_where(
+            angle.clone(),
+            swapped,
+            mirrored(angle, core::f64::consts::FRAC_PI_2),
+        );
+        let angle = B::float_mask_where(
+            angle.clone(),
+            B::float_lower_elem(x, 0.elem()),
+            mirrored(angle, core::f64::consts::PI),
+        );
+
+        B::float_mask_where(
+            angle.clone(),
+            B::float_lower_elem(y, 0.elem()),
+            B::float_neg(angle),
+        )
+    }
+
+    /// Computes the discrete Fourier transform of a complex tensor along its last dimension.
+    ///
+    /// The default implementation multiplies the tensor by the transform matrix, with a cost
+    /// quadratic in the size of the dimension, and should be overridden by backends with a native
+    /// `O(n log(n))` transform.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The real and imaginary parts of the tensor.
+    /// * `inverse` - Whether to compute the inverse transform, normalized by `1 / n`.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the transform.
+    fn float_fft<const D: usize>(
+        tensor: ComplexPrimitive<B, D>,
+        inverse: bool,
+    ) -> ComplexPrimitive<B, D> {
+        fft::dft::<B, D>(tensor, inverse)
+    }
+
+    /// Computes the reduced singular value decomposition of a batch of matrices.
+    ///
+    /// The default implementation uses [one-sided Jacobi rotations](crate::linalg::jacobi_svd)
+    /// expressed with tensor operations, and should be overridden by backends with a native
+    /// decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., m, n]` matrices.
+    ///
+    /// # Returns
+    ///
+    /// With `k = min(m, n)`, the left singular vectors `U` of shape `[..., m, k]`, the singular
+    /// values `S` of shape `[..., 1, k]` in descending order, and the right singular vectors `Vt`
+    /// of shape `[..., k, n]`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+        let (u, s, vt) = linalg::jacobi_svd(Tensor::<B, D>::from_primitive(tensor));
+
+        (u.into_primitive(), s.into_primitive(), vt.into_primitive())
+    }
+
+    /// Computes the QR decomposition of a batch of matrices, with the diagonal of `R` made
+    /// non-negative.
+    ///
+    /// The default implementation uses [Householder reflections](crate::linalg::householder_qr)
+    /// expressed with tensor operations, and should be overridden by backends with a native
+    /// decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., m, n]` matrices.
+    /// * `mode` - The shape of the decomposition.
+    ///
+    /// # Returns
+    ///
+    /// The matrices `Q` with orthonormal columns and `R` upper triangular, of shapes given by the
+    /// [mode](crate::linalg::QrMode).
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        mode: QrMode,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let (q, r) = linalg::householder_qr(Tensor::<B, D>::from_primitive(tensor), mode);
+
+        (q.into_primitive(), r.into_primitive())
+    }
+
+    /// Computes the Cholesky decomposition of a batch of symmetric positive-definite matrices.
+    ///
+    /// The default implementation computes the decomposition
+    /// [column by column](crate::linalg::column_cholesky) with tensor operations, and should be
+    /// overridden by backends with a native decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., n, n]` matrices, of which only the lower triangular part is read.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular matrices `L` such that `A = L L^T`.
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        linalg::column_cholesky(Tensor::<B, D>::from_primitive(tensor)).into_primitive()
+    }
+
+    /// Solves the linear systems `A X = B` for a batch of square matrices.
+    ///
+    /// The default implementation uses [Gaussian elimination](crate::linalg::elimination_solve)
+    /// expressed with tensor operations, and should be overridden by backends with a native
+    /// solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., n, n]` matrices `A`.
+    /// * `rhs` - The `[..., n, k]` right-hand side `B`, with the same batch dimensions.
+    ///
+    /// # Returns
+    ///
+    /// The `[..., n, k]` solutions `X`, undefined when a matrix is singular.
+    fn float_solve<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        linalg::elimination_solve(
+            Tensor::<B, D>::from_primitive(tensor),
+            Tensor::<B, D>::from_primitive(rhs),
+        )
+        .into_primitive()
+    }
+
+    /// Solves the linear systems `A X = B` for a batch of triangular matrices.
+    ///
+    /// The default implementation uses [substitution](crate::linalg::substitution_solve)
+    /// expressed with tensor operations, and should be overridden by backends with a native
+    /// solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., n, n]` matrices `A`, of which only the triangular part is read.
+    /// * `rhs` - The `[..., n, k]` right-hand side `B`, with the same batch dimensions.
+    /// * `upper` - Whether the matrices are upper or lower triangular.
+    ///
+    /// # Returns
+    ///
+    /// The `[..., n, k]` solutions `X`.
+    fn float_triangular_solve<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+        upper: bool,
+    ) -> FloatTensor<B, D> {
+        linalg::substitution_solve(
+            Tensor::<B, D>::from_primitive(tensor),
+            Tensor::<B, D>::from_primitive(rhs),
+            upper,
+        )
+        .into_primitive()
+    }
+
+    /// Computes the determinant of a batch of square matrices.
+    ///
+    /// The default implementation uses [Gaussian elimination](crate::linalg::elimination_det)
+    /// expressed with tensor operations, and should be overridden by backends with a native
+    /// determinant.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., n, n]` matrices.
+    ///
+    /// # Returns
+    ///
+    /// The determinants with the shape `[..., 1, 1]`.
+    fn float_det<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        linalg::elimination_det(Tensor::<B, D>::from_primitive(tensor)).into_primitive()
+    }
+
+    /// Computes the sign and the natural logarithm of the absolute value of the determinant of a
+    /// batch of square matrices.
+    ///
+    /// The default implementation uses [Gaussian elimination](crate::linalg::elimination_slogdet)
+    /// expressed with tensor operations, and should be overridden by backends with a native
+    /// determinant.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., n, n]` matrices.
+    ///
+    /// # Returns
+    ///
+    /// The signs and the logarithms of the absolute values, both with the shape `[..., 1, 1]`. For
+    /// singular matrices, the sign is zero and the logarithm is negative infinity.
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let (sign, logabsdet) = linalg::elimination_slogdet(Tensor::<B, D>::from_primitive(tensor));
+
+        (sign.into_primitive(), logabsdet.into_primitive())
+    }
+
+    /// Computes the Moore-Penrose pseudo-inverse of a batch of matrices.
+    ///
+    /// The default implementation is [composed](crate::linalg::svd_pinv) from the
+    /// [singular value decomposition](Self::float_svd), and should be overridden by backends with
+    /// a native pseudo-inverse.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., m, n]` matrices.
+    /// * `rtol` - The singular values smaller than `rtol` times the largest one are treated as
+    ///   zero.
+    ///
+    /// # Returns
+    ///
+    /// The `[..., n, m]` pseudo-inverses.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_pinv<const D: usize>(tensor: FloatTensor<B, D>, rtol: f64) -> FloatTensor<B, D> {
+        linalg::svd_pinv(Tensor::<B, D>::from_primitive(tensor), rtol).into_primitive()
+    }
+
+    /// Computes the least squares solutions of the linear systems `A X = B`.
+    ///
+    /// The default implementation is [composed](crate::linalg::qr_lstsq) from the
+    /// [QR decomposition](Self::float_qr) and the
+    /// [triangular solver](Self::float_triangular_solve), and should be overridden by backends
+    /// with a native solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., m, n]` matrices `A`, with full rank.
+    /// * `rhs` - The `[..., m, k]` right-hand side `B`, with the same batch dimensions.
+    ///
+    /// # Returns
+    ///
+    /// The `[..., n, k]` solutions `X`, with the minimum norm for underdetermined systems.
+    fn float_lstsq<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        linalg::qr_lstsq(
+            Tensor::<B, D>::from_primitive(tensor),
+            Tensor::<B, D>::from_primitive(rhs),
+        )
+        .into_primitive()
+    }
+
+    /// Computes the eigendecomposition of a batch of symmetric matrices.
+    ///
+    /// The default implementation uses [two-sided Jacobi rotations](crate::linalg::jacobi_eigh)
+    /// expressed with tensor operations, and should be overridden by backends with a native
+    /// decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The `[..., n, n]` matrices, of which only the lower triangular part is read.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues `W` of shape `[..., 1, n]` in ascending order, and the orthonormal
+    /// eigenvectors `V` of shape `[..., n, n]` as columns.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let (eigenvalues, vectors) = linalg::jacobi_eigh(Tensor::<B, D>::from_primitive(tensor));
+
+        (eigenvalues.into_primitive(), vectors.into_primitive())
+    }
+
+    /// Computes a contraction of two tensors, the building block of [einsum](crate::einsum).
+    ///
+    /// The default implementation uses a [matrix multiplication](einsum::matmul_contraction) of
+    /// the permuted and reshaped tensors, and should be overridden by backends with a fused
+    /// contraction.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    /// * `contraction` - The labels of the dimensions of both tensors and of the output.
+    ///
+    /// # Returns
+    ///
+    /// The products of the elements of both tensors, summed over the labels missing from the
+    /// output.
+    fn float_contract<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+        contraction: Contraction,
+    ) -> FloatTensor<B, D> {
+        einsum::matmul_contraction::<B, D>(lhs, rhs, &contraction)
+    }
+
     /// Concatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1442,4 +2067,86 @@ pub trait FloatTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Float>(tensor, dim, descending)
     }
+
+    /// Collapses the runs of equal elements of the flattened tensor, sorting the elements first
+    /// unless only the consecutive runs are collapsed.
+    ///
+    /// The default implementation [flags the runs](super::unique::sort_unique) with tensor
+    /// operations, and should be overridden by backends with a native kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `consecutive` - Whether only the consecutive runs of the unsorted elements are collapsed.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - The first element of each run.
+    /// - The inverse indices, of the same shape as the tensor, giving the run of each element.
+    /// - The number of elements of each run.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_unique<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        consecutive: bool,
+    ) -> unique::UniqueOutput<B, Float, D> {
+        unique::sort_unique::<B, D, Float>(tensor, consecutive)
+    }
+
+    /// Sums the slices of the first dimension belonging to the same segment.
+    ///
+    /// The order in which the slices are added is unspecified, so backends can add them
+    /// atomically. The default implementation [assigns the slices](super::segment::select_segment_sum)
+    /// with tensor operations, and should be overridden by backends with an atomic kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `segment_ids` - The segment of each slice of the first dimension, which don't need to be
+    ///   sorted.
+    /// * `num_segments` - The number of segments.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `num_segments` slices on the first dimension, where empty segments are
+    /// filled with zeros.
+    fn float_segment_sum<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        segment_ids: IntTensor<B, 1>,
+        num_segments: usize,
+    ) -> FloatTensor<B, D> {
+        segment::select_segment_sum::<B, D, Float>(tensor, segment_ids, num_segments)
+    }
+
+    /// Reduces the slices of the first dimension belonging to the same segment, the segment ids
+    /// being sorted.
+    ///
+    /// Each segment is reduced in the order of its slices, so the result is deterministic. The
+    /// default implementation [scans the slices](super::segment::scan_segment_reduce_sorted) with
+    /// tensor operations, and should be overridden by backends with a native kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `segment_ids` - The sorted segment of each slice of the first dimension.
+    /// * `num_segments` - The number of segments.
+    /// * `reduction` - The reduction of the slices of each segment.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `num_segments` slices on the first dimension, where empty segments are
+    /// filled with zeros.
+    fn float_segment_reduce_sorted<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        segment_ids: IntTensor<B, 1>,
+        num_segments: usize,
+        reduction: IndexReduction,
+    ) -> FloatTensor<B, D> {
+        segment::scan_segment_reduce_sorted::<B, D, Float>(
+            tensor,
+            segment_ids,
+            num_segments,
+            reduction,
+        )
+    }
 }
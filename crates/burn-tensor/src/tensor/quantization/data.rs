@@ -0,0 +1,181 @@
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use super::{QuantizationParameters, QuantizationScheme, QuantizationType};
+use crate::TensorData;
+
+/// Quantized tensor data, storing the packed integer values along with the
+/// [scheme](QuantizationScheme) and [parameters](QuantizationParameters) needed to dequantize them.
+///
+/// 4-bit values are packed two per byte, the first value in the low nibble.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuantizedTensorData {
+    /// The packed quantized values.
+    pub value: Vec<u8>,
+
+    /// The shape of the tensor.
+    pub shape: Vec<usize>,
+
+    /// The quantization scheme.
+    pub scheme: QuantizationScheme,
+
+    /// The quantization parameters.
+    pub params: QuantizationParameters,
+}
+
+impl QuantizedTensorData {
+    /// Quantize the given floating point data with the provided scheme.
+    ///
+    /// The quantization parameters are computed from the range of the values.
+    pub fn quantize(data: &TensorData, scheme: QuantizationScheme) -> Self {
+        let values: Vec<f32> = data.iter::<f32>().collect();
+        let (min, max) = values
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &x| {
+                (min.min(x), max.max(x))
+            });
+        let params = scheme.compute_parameters(min, max);
+
+        Self::quantize_with(&values, data.shape.clone(), scheme, params)
+    }
+
+    /// Quantize the given values with the provided scheme and parameters.
+    pub fn quantize_with(
+        values: &[f32],
+        shape: Vec<usize>,
+        scheme: QuantizationScheme,
+        params: QuantizationParameters,
+    ) -> Self {
+        let quantized: Vec<i8> = values
+            .iter()
+            .map(|&x| scheme.quantize(x, &params))
+            .collect();
+
+        Self::from_values(&quantized, shape, scheme, params)
+    }
+
+    /// Pack the given quantized values, computed with the provided scheme and parameters.
+    pub fn from_values(
+        values: &[i8],
+        shape: Vec<usize>,
+        scheme: QuantizationScheme,
+        params: QuantizationParameters,
+    ) -> Self {
+        let value = match scheme.q_type() {
+            QuantizationType::QInt8 => values.iter().map(|&q| q as u8).collect(),
+            QuantizationType::QInt4 => values
+                .chunks(2)
+                .map(|pair| {
+                    let low = pair[0] as u8 & 0x0F;
+                    let high = pair.get(1).map(|&q| (q as u8 & 0x0F) << 4).unwrap_or(0);
+                    low | high
+                })
+                .collect(),
+        };
+
+        Self {
+            value,
+            shape,
+            scheme,
+            params,
+        }
+    }
+
+    /// The number of elements in the tensor.
+    pub fn num_elements(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Returns the unpacked quantized values.
+    pub fn values(&self) -> Vec<i8> {
+        match self.scheme.q_type() {
+            QuantizationType::QInt8 => self.value.iter().map(|&q| q as i8).collect(),
+            QuantizationType::QInt4 => self
+                .value
+                .iter()
+                // Sign extend each nibble.
+                .flat_map(|&q| [((q << 4) as i8) >> 4, (q as i8) >> 4])
+                .take(self.num_elements())
+                .collect(),
+        }
+    }
+
+    /// Dequantize the values into floating point data.
+    pub fn dequantize(&self) -> TensorData {
+        let values = self
+            .values()
+            .into_iter()
+            .map(|q| self.scheme.dequantize(q, &self.params))
+            .collect::<Vec<f32>>();
+
+        TensorData::new(values, self.shape.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn should_quantize_affine_int8() {
+        let data = TensorData::from([-1.8f32, -1.0, 0.0, 0.5]);
+        let quantized = QuantizedTensorData::quantize(
+            &data,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        );
+
+        assert_eq!(quantized.values(), vec![-128, -39, 72, 127]);
+        quantized.dequantize().assert_approx_eq(&data, 2);
+    }
+
+    #[test]
+    fn should_quantize_symmetric_int8() {
+        let data = TensorData::from([-1.8f32, -1.0, 0.0, 0.5]);
+        let quantized = QuantizedTensorData::quantize(
+            &data,
+            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
+        );
+
+        assert_eq!(quantized.params.offset, None);
+        assert_eq!(quantized.values(), vec![-127, -71, 0, 35]);
+        quantized.dequantize().assert_approx_eq(&data, 1);
+    }
+
+    #[test]
+    fn should_pack_int4_values() {
+        let data = TensorData::from([[-1.4f32, -0.6, 0.0], [0.2, 0.6, 1.4]]);
+        let quantized = QuantizedTensorData::quantize(
+            &data,
+            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4),
+        );
+
+        assert_eq!(quantized.value.len(), 3);
+        assert_eq!(quantized.values(), vec![-7, -3, 0, 1, 3, 7]);
+        assert_eq!(quantized.dequantize().shape, vec![2, 3]);
+    }
+
+    #[test]
+    fn should_pack_odd_number_of_int4_values() {
+        let data = TensorData::from([-0.7f32, 0.0, 0.7]);
+        let quantized = QuantizedTensorData::quantize(
+            &data,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt4),
+        );
+
+        assert_eq!(quantized.value.len(), 2);
+        assert_eq!(quantized.values().len(), 3);
+        quantized.dequantize().assert_approx_eq(&data, 1);
+    }
+
+    #[test]
+    fn should_quantize_zeros() {
+        let data = TensorData::from([0.0f32, 0.0]);
+        let quantized = QuantizedTensorData::quantize(
+            &data,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        );
+
+        quantized.dequantize().assert_eq(&data, true);
+    }
+}
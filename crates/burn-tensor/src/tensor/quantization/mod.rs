@@ -0,0 +1,11 @@
+mod data;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod observer;
+mod scheme;
+mod tensor;
+
+pub use data::*;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub use observer::*;
+pub use scheme::*;
+pub use tensor::*;
@@ -0,0 +1,220 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{QuantizationParameters, QuantizationScheme};
+use crate::{backend::Backend, ElementConversion, Tensor};
+
+/// Observer of the values of tensors, e.g. the activations of a module over calibration data,
+/// computing the range to represent with a [quantization scheme](QuantizationScheme).
+pub trait Observer {
+    /// Updates the statistics of the observer with the values of the tensor.
+    fn observe<B: Backend, const D: usize>(&mut self, tensor: &Tensor<B, D>);
+
+    /// Returns the `(min, max)` range of values to represent, or `None` if no tensor was
+    /// observed.
+    fn range(&self) -> Option<(f32, f32)>;
+
+    /// Computes the quantization parameters of the observed range with the given scheme, or
+    /// `None` if no tensor was observed.
+    fn parameters(&self, scheme: &QuantizationScheme) -> Option<QuantizationParameters> {
+        self.range()
+            .map(|(min, max)| scheme.compute_parameters(min, max))
+    }
+}
+
+/// Observer of the minimum and maximum of all the observed values.
+#[derive(Debug, Clone, Default)]
+pub struct MinMaxObserver {
+    range: Option<(f32, f32)>,
+}
+
+impl MinMaxObserver {
+    /// Creates a new observer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Observer for MinMaxObserver {
+    fn observe<B: Backend, const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        let (min, max) = tensor_range(tensor);
+
+        self.range = Some(match self.range {
+            Some((current_min, current_max)) => (current_min.min(min), current_max.max(max)),
+            None => (min, max),
+        });
+    }
+
+    fn range(&self) -> Option<(f32, f32)> {
+        self.range
+    }
+}
+
+/// Observer of the exponential moving average of the minimum and maximum of each observed
+/// tensor, which is less sensitive to the outliers of a few batches than [MinMaxObserver].
+#[derive(Debug, Clone)]
+pub struct MovingAverageObserver {
+    momentum: f32,
+    range: Option<(f32, f32)>,
+}
+
+impl MovingAverageObserver {
+    /// Creates a new observer, the range being updated with
+    /// `range = momentum * range + (1 - momentum) * observed`.
+    pub fn new(momentum: f32) -> Self {
+        Self {
+            momentum,
+            range: None,
+        }
+    }
+}
+
+impl Observer for MovingAverageObserver {
+    fn observe<B: Backend, const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        let (min, max) = tensor_range(tensor);
+        let average = |current: f32, observed: f32| {
+            self.momentum * current + (1.0 - self.momentum) * observed
+        };
+
+        self.range = Some(match self.range {
+            Some((current_min, current_max)) => {
+                (average(current_min, min), average(current_max, max))
+            }
+            None => (min, max),
+        });
+    }
+
+    fn range(&self) -> Option<(f32, f32)> {
+        self.range
+    }
+}
+
+/// Observer of the histogram of the observed values, whose range excludes the given fraction of
+/// outliers in each tail of the distribution.
+///
+/// The histogram covers the range of all the observed values, its bins being merged when new
+/// values extend it.
+#[derive(Debug, Clone)]
+pub struct HistogramObserver {
+    percentile: f32,
+    counts: Vec<u64>,
+    range: Option<(f32, f32)>,
+}
+
+impl HistogramObserver {
+    /// Creates a new observer with the given number of bins, the range covering the values
+    /// between the `1 - percentile` and `percentile` quantiles, e.g. `0.9999`.
+    pub fn new(num_bins: usize, percentile: f32) -> Self {
+        assert!(num_bins > 0, "A histogram needs at least one bin.");
+        assert!(
+            percentile > 0.5 && percentile <= 1.0,
+            "The percentile {percentile} should be above 0.5 and at most 1."
+        );
+
+        Self {
+            percentile,
+            counts: vec![0; num_bins],
+            range: None,
+        }
+    }
+
+    /// Returns the counts of the bins of the histogram.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Moves the counts of the bins to the bins of the extended range containing their centers.
+    fn extend(&mut self, min: f32, max: f32) {
+        let Some((current_min, current_max)) = self.range else {
+            self.range = Some((min, max));
+            return;
+        };
+        let (new_min, new_max) = (current_min.min(min), current_max.max(max));
+        if (new_min, new_max) == (current_min, current_max) {
+            return;
+        }
+
+        let num_bins = self.counts.len();
+        let width = (current_max - current_min) / num_bins as f32;
+        let mut counts = vec![0; num_bins];
+        for (i, count) in self.counts.iter().enumerate() {
+            let center = current_min + (i as f32 + 0.5) * width;
+            counts[bin(center, new_min, new_max, num_bins)] += count;
+        }
+
+        self.counts = counts;
+        self.range = Some((new_min, new_max));
+    }
+}
+
+impl Observer for HistogramObserver {
+    fn observe<B: Backend, const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        let data = tensor.to_data();
+        let values: Vec<f32> = data.iter::<f32>().collect();
+        let Some((min, max)) = values_range(&values) else {
+            return;
+        };
+
+        self.extend(min, max);
+        let (min, max) = self.range.unwrap();
+        let num_bins = self.counts.len();
+        for value in values.into_iter().filter(|value| value.is_finite()) {
+            self.counts[bin(value, min, max, num_bins)] += 1;
+        }
+    }
+
+    fn range(&self) -> Option<(f32, f32)> {
+        let (min, max) = self.range?;
+        let num_bins = self.counts.len();
+        let width = (max - min) / num_bins as f32;
+        let total = self.counts.iter().sum::<u64>() as f32;
+        let outliers = (1.0 - self.percentile) * total;
+
+        let low = tail_bins(self.counts.iter(), outliers);
+        let high = num_bins - 1 - tail_bins(self.counts.iter().rev(), outliers);
+
+        Some((min + low as f32 * width, min + (high + 1) as f32 * width))
+    }
+}
+
+/// Returns the minimum and maximum of the tensor.
+fn tensor_range<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> (f32, f32) {
+    let min = tensor.clone().min().into_scalar().elem::<f32>();
+    let max = tensor.clone().max().into_scalar().elem::<f32>();
+
+    (min, max)
+}
+
+/// Returns the minimum and maximum of the values, ignoring the ones that aren't finite.
+fn values_range(values: &[f32]) -> Option<(f32, f32)> {
+    values
+        .iter()
+        .filter(|value| value.is_finite())
+        .fold(None, |range, &value| match range {
+            Some((min, max)) => Some((value.min(min), value.max(max))),
+            None => Some((value, value)),
+        })
+}
+
+/// Returns the number of bins in a tail of the histogram before its cumulative count exceeds the
+/// outliers.
+fn tail_bins<'a>(counts: impl Iterator<Item = &'a u64>, outliers: f32) -> usize {
+    let mut cumulative = 0;
+
+    counts
+        .take_while(|&&count| {
+            cumulative += count;
+            cumulative as f32 <= outliers
+        })
+        .count()
+}
+
+/// Returns the index of the bin of the value in the histogram of the range.
+fn bin(value: f32, min: f32, max: f32, num_bins: usize) -> usize {
+    if max <= min {
+        return 0;
+    }
+
+    let index = ((value - min) / (max - min) * num_bins as f32) as usize;
+    index.min(num_bins - 1)
+}
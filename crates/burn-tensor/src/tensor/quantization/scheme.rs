@@ -0,0 +1,113 @@
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use serde::{Deserialize, Serialize};
+
+/// The integer type used to store quantized values.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuantizationType {
+    /// 8-bit signed integer, one value per byte.
+    QInt8,
+    /// 4-bit signed integer, two values packed per byte.
+    QInt4,
+}
+
+impl QuantizationType {
+    /// The number of bits used by each quantized value.
+    pub fn bits(&self) -> usize {
+        match self {
+            QuantizationType::QInt8 => 8,
+            QuantizationType::QInt4 => 4,
+        }
+    }
+
+    /// The range of representable values `(min, max)`.
+    pub fn range(&self) -> (i32, i32) {
+        match self {
+            QuantizationType::QInt8 => (i8::MIN as i32, i8::MAX as i32),
+            QuantizationType::QInt4 => (-8, 7),
+        }
+    }
+}
+
+/// Quantization scheme, mapping floating point values to integers.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuantizationScheme {
+    /// Per-tensor affine quantization, `q = round(x / scale) + offset`.
+    PerTensorAffine(QuantizationType),
+    /// Per-tensor symmetric quantization, `q = round(x / scale)`.
+    PerTensorSymmetric(QuantizationType),
+}
+
+/// Parameters used to quantize and dequantize values with a given [scheme](QuantizationScheme).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QuantizationParameters {
+    /// The scaling factor.
+    pub scale: f32,
+    /// The zero-point offset, only used by affine schemes.
+    pub offset: Option<i32>,
+}
+
+impl QuantizationScheme {
+    /// The integer type used to store quantized values.
+    pub fn q_type(&self) -> QuantizationType {
+        match self {
+            QuantizationScheme::PerTensorAffine(q_type) => *q_type,
+            QuantizationScheme::PerTensorSymmetric(q_type) => *q_type,
+        }
+    }
+
+    /// Compute the quantization parameters for values in the `[min, max]` range.
+    ///
+    /// The range is extended to include zero so that it is always exactly representable.
+    pub fn compute_parameters(&self, min: f32, max: f32) -> QuantizationParameters {
+        let (q_min, q_max) = self.q_type().range();
+        let min = min.min(0.0);
+        let max = max.max(0.0);
+
+        match self {
+            QuantizationScheme::PerTensorAffine(_) => {
+                let scale = non_zero((max - min) / (q_max - q_min) as f32);
+                let offset = (q_min as f32 - min / scale).round() as i32;
+
+                QuantizationParameters {
+                    scale,
+                    offset: Some(offset.clamp(q_min, q_max)),
+                }
+            }
+            QuantizationScheme::PerTensorSymmetric(_) => {
+                let scale = non_zero(min.abs().max(max) / q_max as f32);
+
+                QuantizationParameters {
+                    scale,
+                    offset: None,
+                }
+            }
+        }
+    }
+
+    /// Quantize a single value.
+    pub fn quantize(&self, value: f32, params: &QuantizationParameters) -> i8 {
+        let (q_min, q_max) = self.q_type().range();
+        let offset = params.offset.unwrap_or(0);
+        let q = (value / params.scale).round() as i32 + offset;
+
+        q.clamp(q_min, q_max) as i8
+    }
+
+    /// Dequantize a single value.
+    pub fn dequantize(&self, value: i8, params: &QuantizationParameters) -> f32 {
+        let offset = params.offset.unwrap_or(0);
+
+        (value as i32 - offset) as f32 * params.scale
+    }
+}
+
+/// Avoid dividing by zero when all values are zero.
+fn non_zero(scale: f32) -> f32 {
+    if scale > 0.0 {
+        scale
+    } else {
+        1.0
+    }
+}
@@ -0,0 +1,101 @@
+use super::{QuantizationParameters, QuantizationScheme, QuantizedTensorData};
+use crate::{backend::Backend, Int, Tensor};
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use crate::ElementConversion;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use alloc::vec::Vec;
+
+/// A quantized tensor, storing the integer values on the device along with the
+/// [scheme](QuantizationScheme) and [parameters](QuantizationParameters) needed to dequantize
+/// them.
+#[derive(Debug, Clone)]
+pub struct QuantizedTensor<B: Backend, const D: usize> {
+    /// The quantized values.
+    pub values: Tensor<B, D, Int>,
+    /// The quantization scheme.
+    pub scheme: QuantizationScheme,
+    /// The quantization parameters.
+    pub params: QuantizationParameters,
+}
+
+impl<B: Backend, const D: usize> QuantizedTensor<B, D> {
+    /// Dequantize the values into a float tensor, `x = (q - offset) * scale`.
+    pub fn dequantize(self) -> Tensor<B, D> {
+        let offset = self.params.offset.unwrap_or(0);
+
+        self.values
+            .sub_scalar(offset)
+            .float()
+            .mul_scalar(self.params.scale)
+    }
+
+    /// Returns the packed quantized data of the tensor.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn into_data(self) -> QuantizedTensorData {
+        let data = self.values.into_data();
+        let values: Vec<i8> = data.iter::<i64>().map(|q| q as i8).collect();
+
+        QuantizedTensorData::from_values(&values, data.shape, self.scheme, self.params)
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Quantize the tensor with the given scheme and parameters, e.g. computed by an
+    /// [observer](super::Observer) from calibration data.
+    ///
+    /// `q = clamp(round(x / scale) + offset, q_min, q_max)`, rounding half away from zero.
+    pub fn quantize(
+        self,
+        scheme: &QuantizationScheme,
+        params: &QuantizationParameters,
+    ) -> QuantizedTensor<B, D> {
+        let (q_min, q_max) = scheme.q_type().range();
+        let scaled = self.div_scalar(params.scale);
+
+        // Truncating the magnitude increased by one half rounds half away from zero.
+        let values = scaled
+            .clone()
+            .abs()
+            .add_scalar(0.5)
+            .int()
+            .mul(scaled.sign().int())
+            .add_scalar(params.offset.unwrap_or(0))
+            .clamp(q_min, q_max);
+
+        QuantizedTensor {
+            values,
+            scheme: *scheme,
+            params: *params,
+        }
+    }
+
+    /// Quantize the tensor with the given scheme, computing the parameters from the range of its
+    /// own values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::quantization::{QuantizationScheme, QuantizationType};
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+    ///
+    ///     let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+    ///     let quantized = tensor.quantize_dynamic(&scheme);
+    ///     println!("{}", quantized.values);
+    ///     // [-128, -39, 72, 127]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn quantize_dynamic(self, scheme: &QuantizationScheme) -> QuantizedTensor<B, D> {
+        let min = self.clone().min().into_scalar().elem::<f32>();
+        let max = self.clone().max().into_scalar().elem::<f32>();
+        let params = scheme.compute_parameters(min, max);
+
+        self.quantize(scheme, &params)
+    }
+}
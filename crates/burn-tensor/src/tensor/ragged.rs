@@ -0,0 +1,304 @@
+use alloc::vec::Vec;
+
+use crate::{
+    backend::Backend, BasicOps, Bool, Element, ElementConversion, Float, Int, Numeric, Tensor,
+    TensorData, TensorKind,
+};
+
+/// A batch of sequences of different lengths, stored without padding.
+///
+/// The rows of all the sequences are concatenated on the first dimension of the values, and the
+/// `batch_size + 1` offsets give the range of the rows of each sequence. The offsets are kept on
+/// the host, so the structure of the batch is known without reading the device, while operations
+/// on the rows run on the values directly and never see padding.
+///
+/// The batch can be converted [to](RaggedTensor::to_padded) and [from](RaggedTensor::from_padded)
+/// a padded tensor with one more dimension of size [max_len](RaggedTensor::max_len), along with
+/// its [padding mask](RaggedTensor::padding_mask).
+#[derive(Clone, Debug)]
+pub struct RaggedTensor<B: Backend, const D: usize, K: TensorKind<B> = Float> {
+    values: Tensor<B, D, K>,
+    offsets: Vec<usize>,
+}
+
+impl<B, const D: usize, K> RaggedTensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Create a ragged tensor from the concatenated rows of the sequences and the offsets of
+    /// their first row, followed by the total number of rows.
+    ///
+    /// # Panics
+    ///
+    /// If the offsets don't start at zero, decrease, or don't end at the number of rows.
+    pub fn new(values: Tensor<B, D, K>, offsets: Vec<usize>) -> Self {
+        let num_rows = values.dims()[0];
+        assert!(
+            offsets.first() == Some(&0) && offsets.last() == Some(&num_rows),
+            "The offsets of a ragged tensor should start at 0 and end at the {num_rows} rows."
+        );
+        assert!(
+            offsets.windows(2).all(|range| range[0] <= range[1]),
+            "The offsets of a ragged tensor should be non-decreasing."
+        );
+
+        Self { values, offsets }
+    }
+
+    /// Create a ragged tensor from the concatenated rows of the sequences and their lengths.
+    pub fn from_lengths(values: Tensor<B, D, K>, lengths: &[usize]) -> Self {
+        let mut offsets = Vec::with_capacity(lengths.len() + 1);
+        offsets.push(0);
+        for length in lengths {
+            offsets.push(offsets[offsets.len() - 1] + length);
+        }
+
+        Self::new(values, offsets)
+    }
+
+    /// Create a ragged tensor by concatenating the sequences on the first dimension.
+    pub fn from_tensors(tensors: Vec<Tensor<B, D, K>>) -> Self {
+        let lengths = tensors
+            .iter()
+            .map(|tensor| tensor.dims()[0])
+            .collect::<Vec<_>>();
+
+        Self::from_lengths(Tensor::cat(tensors, 0), &lengths)
+    }
+
+    /// Returns the concatenated rows of the sequences.
+    pub fn values(&self) -> Tensor<B, D, K> {
+        self.values.clone()
+    }
+
+    /// Returns the concatenated rows of the sequences, consuming the ragged tensor.
+    pub fn into_values(self) -> Tensor<B, D, K> {
+        self.values
+    }
+
+    /// Returns the offsets of the first row of each sequence, followed by the total number of
+    /// rows.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the length of each sequence.
+    pub fn lengths(&self) -> Vec<usize> {
+        self.offsets
+            .windows(2)
+            .map(|range| range[1] - range[0])
+            .collect()
+    }
+
+    /// Returns the number of sequences.
+    pub fn batch_size(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns the length of the longest sequence.
+    pub fn max_len(&self) -> usize {
+        self.lengths().into_iter().max().unwrap_or(0)
+    }
+
+    /// Returns the device of the values.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Move the values to the given device.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            values: self.values.to_device(device),
+            offsets: self.offsets,
+        }
+    }
+
+    /// Returns the sequence at the given index.
+    pub fn get(&self, index: usize) -> Tensor<B, D, K> {
+        let (start, end) = (self.offsets[index], self.offsets[index + 1]);
+        self.values.clone().narrow(0, start, end - start)
+    }
+
+    /// Split the ragged tensor into its sequences.
+    pub fn into_tensors(self) -> Vec<Tensor<B, D, K>> {
+        (0..self.batch_size())
+            .map(|index| self.get(index))
+            .collect()
+    }
+
+    /// Apply a function to the concatenated rows, keeping the structure of the batch.
+    ///
+    /// This is how row-wise operations, such as element-wise ops or a linear layer on the last
+    /// dimension, are applied without padding.
+    ///
+    /// # Panics
+    ///
+    /// If the function changes the number of rows.
+    pub fn map<const D2: usize, K2, F>(self, func: F) -> RaggedTensor<B, D2, K2>
+    where
+        K2: BasicOps<B>,
+        F: FnOnce(Tensor<B, D, K>) -> Tensor<B, D2, K2>,
+    {
+        let num_rows = self.values.dims()[0];
+        let values = func(self.values);
+        assert_eq!(
+            values.dims()[0],
+            num_rows,
+            "Mapping a ragged tensor should keep the {num_rows} rows."
+        );
+
+        RaggedTensor {
+            values,
+            offsets: self.offsets,
+        }
+    }
+
+    /// Returns the `[batch_size, max_len]` mask of the padded positions, which is `true` after
+    /// the end of each sequence.
+    pub fn padding_mask(&self) -> Tensor<B, 2, Bool> {
+        let device = self.device();
+        let lengths = self
+            .lengths()
+            .into_iter()
+            .map(|length| length as i64)
+            .collect::<Vec<_>>();
+        let dims = [self.batch_size(), self.max_len()];
+        let lengths = Tensor::<B, 1, Int>::from_ints(lengths.as_slice(), &device)
+            .reshape([dims[0], 1])
+            .expand(dims);
+
+        Tensor::<B, 1, Int>::arange(0..dims[1] as i64, &device)
+            .reshape([1, dims[1]])
+            .expand(dims)
+            .greater_equal(lengths)
+    }
+
+    /// Returns the index of the sequence of each row.
+    pub fn segment_ids(&self) -> Tensor<B, 1, Int> {
+        let ids = self
+            .lengths()
+            .into_iter()
+            .enumerate()
+            .flat_map(|(sequence, length)| (0..length).map(move |_| sequence))
+            .collect::<Vec<_>>();
+
+        index_tensor::<B>(&ids, &self.device())
+    }
+}
+
+impl<B, const D: usize, K> RaggedTensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Create a ragged tensor from the `[batch_size, max_len, ...]` padded tensor, keeping the
+    /// first rows of each sequence up to its length.
+    ///
+    /// # Panics
+    ///
+    /// If `D2` isn't equal to `D + 1`, or if a length is greater than the padded length.
+    pub fn from_padded<const D2: usize>(padded: Tensor<B, D2, K>, lengths: &[usize]) -> Self {
+        assert_eq!(D2, D + 1, "Padded tensors should have one more dimension.");
+
+        let dims = padded.dims();
+        let [batch_size, max_len] = [dims[0], dims[1]];
+        assert_eq!(
+            lengths.len(),
+            batch_size,
+            "Expected a length for each of the {batch_size} padded sequences."
+        );
+        assert!(
+            lengths.iter().all(|length| *length <= max_len),
+            "The lengths of the sequences should not exceed the padded length {max_len}."
+        );
+
+        let mut shape = [0; D];
+        shape[0] = batch_size * max_len;
+        shape[1..].copy_from_slice(&dims[2..]);
+
+        let positions = index_tensor::<B>(&padded_positions(lengths, max_len), &padded.device());
+
+        Self::from_lengths(padded.reshape(shape).select(0, positions), lengths)
+    }
+
+    /// Convert the ragged tensor into a `[batch_size, max_len, ...]` padded tensor, where the
+    /// positions after the end of each sequence are filled with the given value.
+    ///
+    /// # Panics
+    ///
+    /// If `D2` isn't equal to `D + 1`.
+    pub fn to_padded<const D2: usize, E: ElementConversion>(self, value: E) -> Tensor<B, D2, K> {
+        assert_eq!(D2, D + 1, "Padded tensors should have one more dimension.");
+
+        let (batch_size, max_len) = (self.batch_size(), self.max_len());
+        let dims = self.values.dims();
+        let mut flat_dims = dims;
+        flat_dims[0] = batch_size * max_len;
+        let mut padded_dims = [0; D2];
+        padded_dims[0] = batch_size;
+        padded_dims[1] = max_len;
+        padded_dims[2..].copy_from_slice(&dims[1..]);
+        let mut mask_shape = [1; D2];
+        mask_shape[0] = batch_size;
+        mask_shape[1] = max_len;
+
+        let mask = self.padding_mask().reshape(mask_shape).expand(padded_dims);
+
+        let positions = padded_positions(&self.lengths(), max_len);
+        let positions = index_tensor::<B>(&positions, &self.device());
+
+        Tensor::zeros(flat_dims, &self.device())
+            .select_assign(0, positions, self.values)
+            .reshape(padded_dims)
+            .mask_fill(mask, value)
+    }
+
+    /// Sums the rows of each sequence, returning a tensor with `batch_size` rows where empty
+    /// sequences are zeros.
+    pub fn sum(self) -> Tensor<B, D, K> {
+        let batch_size = self.batch_size();
+        let segment_ids = self.segment_ids();
+
+        self.values.segment_sum(segment_ids, batch_size)
+    }
+
+    /// Takes the maximum of the rows of each sequence, returning a tensor with `batch_size` rows
+    /// where empty sequences are zeros.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn max(self) -> Tensor<B, D, K> {
+        let batch_size = self.batch_size();
+        let segment_ids = self.segment_ids();
+
+        self.values.segment_max(segment_ids, batch_size)
+    }
+}
+
+impl<B: Backend, const D: usize> RaggedTensor<B, D> {
+    /// Averages the rows of each sequence, returning a tensor with `batch_size` rows where empty
+    /// sequences are zeros.
+    pub fn mean(self) -> Tensor<B, D> {
+        let batch_size = self.batch_size();
+        let segment_ids = self.segment_ids();
+
+        self.values.segment_mean(segment_ids, batch_size)
+    }
+}
+
+/// Create a one dimensional index tensor.
+fn index_tensor<B: Backend>(indices: &[usize], device: &B::Device) -> Tensor<B, 1, Int> {
+    let values = indices.iter().map(|i| *i as i64).collect::<Vec<_>>();
+    let data = TensorData::new(values, [indices.len()]).convert::<B::IntElem>();
+
+    Tensor::from_data(data, device)
+}
+
+/// Returns the position of each row in the padded tensor flattened on its first two dimensions.
+fn padded_positions(lengths: &[usize], max_len: usize) -> Vec<usize> {
+    lengths
+        .iter()
+        .enumerate()
+        .flat_map(|(sequence, length)| (0..*length).map(move |t| sequence * max_len + t))
+        .collect()
+}
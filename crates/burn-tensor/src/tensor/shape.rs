@@ -1,3 +1,4 @@
+use alloc::vec;
 use alloc::vec::Vec;
 
 /// Shape of a tensor.
@@ -17,6 +18,73 @@ impl<const D: usize> Shape<D> {
 
         num_elements
     }
+
+    /// Returns the shape the two shapes broadcast to, see [broadcast_shapes].
+    pub fn broadcast(&self, other: &Self) -> Result<Self, BroadcastError> {
+        broadcast_shapes(&[&self.dims, &other.dims]).map(Self::from)
+    }
+}
+
+/// The error of shapes that can't be broadcast together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastError {
+    /// The dimension of the broadcast shape, aligned with the trailing dimensions of the
+    /// shapes, where the sizes differ.
+    pub dim: usize,
+    /// The incompatible sizes, none of them being one.
+    pub sizes: (usize, usize),
+}
+
+impl core::fmt::Display for BroadcastError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Incompatible size at dimension '{}' => '{} != {}', which can't be broadcasted.",
+            self.dim, self.sizes.0, self.sizes.1
+        )
+    }
+}
+
+/// Returns the shape the given shapes broadcast to.
+///
+/// The shapes are aligned with their trailing dimensions, the missing leading dimensions having
+/// a size of one, and the sizes of each dimension must either be equal or one, in which case the
+/// dimension is repeated to the size of the others.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::broadcast_shapes;
+///
+/// let shape = broadcast_shapes(&[&[2, 1, 4], &[3, 1]]).unwrap();
+/// assert_eq!(shape, vec![2, 3, 4]);
+///
+/// let error = broadcast_shapes(&[&[2, 3], &[4]]).unwrap_err();
+/// assert_eq!((error.dim, error.sizes), (1, (3, 4)));
+/// ```
+pub fn broadcast_shapes(shapes: &[&[usize]]) -> Result<Vec<usize>, BroadcastError> {
+    let rank = shapes.iter().map(|shape| shape.len()).max().unwrap_or(0);
+    let mut output = vec![1; rank];
+
+    for shape in shapes {
+        let offset = rank - shape.len();
+
+        for (i, &size) in shape.iter().enumerate() {
+            let dim = offset + i;
+            match (output[dim], size) {
+                (current, size) if current == size || size == 1 => {}
+                (1, size) => output[dim] = size,
+                (current, size) => {
+                    return Err(BroadcastError {
+                        dim,
+                        sizes: (current, size),
+                    })
+                }
+            }
+        }
+    }
+
+    Ok(output)
 }
 
 impl<const D: usize> From<[usize; D]> for Shape<D> {
@@ -81,4 +149,25 @@ mod tests {
         let shape = Shape::new(dims);
         assert_eq!(120, shape.num_elements());
     }
+
+    #[test]
+    fn broadcast_shapes_of_different_ranks() {
+        let shape = broadcast_shapes(&[&[5, 1, 3], &[4, 1], &[1]]).unwrap();
+        assert_eq!(shape, vec![5, 4, 3]);
+    }
+
+    #[test]
+    fn broadcast_shapes_should_name_incompatible_dim() {
+        let lhs = Shape::new([2, 3, 4]);
+        let rhs = Shape::new([2, 5, 1]);
+
+        let error = lhs.broadcast(&rhs).unwrap_err();
+        assert_eq!(
+            error,
+            BroadcastError {
+                dim: 1,
+                sizes: (3, 5)
+            }
+        );
+    }
 }
@@ -0,0 +1,324 @@
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    backend::{AutodiffBackend, Backend},
+    ElementConversion, Int, Shape, Tensor, TensorData,
+};
+
+/// A sparse tensor stored in the coordinate (COO) format.
+///
+/// The stored elements are described by a `[D, nnz]` tensor of indices, where each column holds
+/// the coordinates of an element, and a `[nnz]` tensor of values. Coordinates may be duplicated,
+/// in which case their values are summed: accumulating sparse tensors is a concatenation, and
+/// [coalesce](SparseTensor::coalesce) merges the duplicates.
+///
+/// The values are a regular float tensor, so the operations are differentiable with respect to
+/// them, and the [gradient](SparseTensor::grad) only holds an entry per stored element.
+#[derive(Clone, Debug)]
+pub struct SparseTensor<B: Backend, const D: usize> {
+    indices: Tensor<B, 2, Int>,
+    values: Tensor<B, 1>,
+    shape: Shape<D>,
+}
+
+impl<B: Backend, const D: usize> SparseTensor<B, D> {
+    /// Create a sparse tensor from the `[D, nnz]` coordinates of its elements and their `[nnz]`
+    /// values.
+    ///
+    /// # Panics
+    ///
+    /// If the indices and values don't describe the same number of elements.
+    pub fn new<S: Into<Shape<D>>>(
+        indices: Tensor<B, 2, Int>,
+        values: Tensor<B, 1>,
+        shape: S,
+    ) -> Self {
+        let [rank, nnz] = indices.dims();
+        assert_eq!(
+            rank, D,
+            "The indices of a sparse tensor of rank {D} should have {D} rows, got {rank}."
+        );
+        assert_eq!(
+            nnz,
+            values.dims()[0],
+            "The sparse tensor should have as many indices as values."
+        );
+
+        Self {
+            indices,
+            values,
+            shape: shape.into(),
+        }
+    }
+
+    /// Returns the `[D, nnz]` coordinates of the stored elements.
+    pub fn indices(&self) -> Tensor<B, 2, Int> {
+        self.indices.clone()
+    }
+
+    /// Returns the `[nnz]` values of the stored elements.
+    pub fn values(&self) -> Tensor<B, 1> {
+        self.values.clone()
+    }
+
+    /// Returns the coordinates and the values of the stored elements.
+    pub fn into_parts(self) -> (Tensor<B, 2, Int>, Tensor<B, 1>) {
+        (self.indices, self.values)
+    }
+
+    /// Returns the shape of the dense tensor.
+    pub fn shape(&self) -> Shape<D> {
+        self.shape.clone()
+    }
+
+    /// Returns the dimensions of the dense tensor.
+    pub fn dims(&self) -> [usize; D] {
+        self.shape.dims
+    }
+
+    /// Returns the number of stored elements, including duplicated coordinates.
+    pub fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// Returns the device of the tensor.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Move the tensor to the given device.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            indices: self.indices.to_device(device),
+            values: self.values.to_device(device),
+            shape: self.shape,
+        }
+    }
+
+    /// Mark the values to keep gradients during the backward pass.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn require_grad(mut self) -> Self {
+        self.values = self.values.require_grad();
+        self
+    }
+
+    /// Convert the sparse tensor into a dense tensor, summing the values of duplicated
+    /// coordinates.
+    pub fn to_dense(self) -> Tensor<B, D> {
+        let device = self.device();
+        let num_elements = self.shape.num_elements();
+        let offsets = offsets(&self.indices, &self.shape);
+
+        Tensor::zeros([num_elements], &device)
+            .select_assign(0, offsets, self.values)
+            .reshape(self.shape)
+    }
+
+    /// Multiplies the values by a scalar.
+    pub fn mul_scalar<E: ElementConversion>(mut self, other: E) -> Self {
+        self.values = self.values.mul_scalar(other);
+        self
+    }
+
+    /// Adds the sparse tensor to a dense tensor of the same shape.
+    pub fn add_dense(self, dense: Tensor<B, D>) -> Tensor<B, D> {
+        assert_eq!(
+            self.shape,
+            dense.shape(),
+            "The dense tensor should have the same shape as the sparse tensor."
+        );
+
+        let num_elements = self.shape.num_elements();
+        let offsets = offsets(&self.indices, &self.shape);
+
+        dense
+            .reshape([num_elements])
+            .select_assign(0, offsets, self.values)
+            .reshape(self.shape)
+    }
+
+    /// Merge the duplicated coordinates, summing their values, and sort the stored elements in
+    /// row-major order.
+    ///
+    /// The coordinates are read on the host, while the values stay on the device so that the
+    /// operation is differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn coalesce(self) -> Self {
+        let device = self.device();
+        let strides = strides(&self.shape.dims);
+        let offsets = offsets(&self.indices, &self.shape)
+            .into_data()
+            .iter::<i64>()
+            .collect::<Vec<_>>();
+
+        let mut slots = BTreeMap::new();
+        for offset in offsets.iter() {
+            slots.insert(*offset, 0);
+        }
+        for (slot, value) in slots.values_mut().enumerate() {
+            *value = slot as i64;
+        }
+
+        let nnz = slots.len();
+        let mut indices = vec![0; D * nnz];
+        for (slot, offset) in slots.keys().enumerate() {
+            let mut remainder = *offset as usize;
+            for (dim, stride) in strides.iter().enumerate() {
+                indices[dim * nnz + slot] = (remainder / stride) as i64;
+                remainder %= stride;
+            }
+        }
+
+        let targets = offsets
+            .iter()
+            .map(|offset| slots[offset])
+            .collect::<Vec<_>>();
+        let targets = Tensor::<B, 1, Int>::from_data(
+            TensorData::new(targets, [offsets.len()]).convert::<B::IntElem>(),
+            &device,
+        );
+        let values = Tensor::zeros([nnz], &device).select_assign(0, targets, self.values);
+        let indices = Tensor::from_data(
+            TensorData::new(indices, [D, nnz]).convert::<B::IntElem>(),
+            &device,
+        );
+
+        Self {
+            indices,
+            values,
+            shape: self.shape,
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Add<Self> for SparseTensor<B, D> {
+    type Output = Self;
+
+    /// Adds two sparse tensors of the same shape.
+    ///
+    /// The stored elements are concatenated without merging the coordinates present in both
+    /// tensors, which makes it cheap to accumulate many sparse tensors before calling
+    /// [coalesce](SparseTensor::coalesce).
+    fn add(self, other: Self) -> Self {
+        assert_eq!(
+            self.shape, other.shape,
+            "Sparse tensors should have the same shape to be added."
+        );
+
+        Self {
+            indices: Tensor::cat(vec![self.indices, other.indices], 1),
+            values: Tensor::cat(vec![self.values, other.values], 0),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<B: Backend> SparseTensor<B, 2> {
+    /// Multiplies the `[m, k]` sparse matrix by a `[k, n]` dense matrix.
+    ///
+    /// Each stored element gathers a row of the dense matrix, which is scaled and accumulated in
+    /// the output, so the cost is proportional to the number of stored elements and not to the
+    /// size of the sparse matrix. The operation is differentiable with respect to both the values
+    /// and the dense matrix.
+    pub fn spmm(self, rhs: Tensor<B, 2>) -> Tensor<B, 2> {
+        let [m, k] = self.shape.dims;
+        let [k_rhs, n] = rhs.dims();
+        assert_eq!(
+            k, k_rhs,
+            "spmm expects the dense matrix to have {k} rows, got {k_rhs}."
+        );
+
+        let nnz = self.nnz();
+        let rows = self.indices.clone().narrow(0, 0, 1).reshape([nnz]);
+        let cols = self.indices.narrow(0, 1, 1).reshape([nnz]);
+
+        let contributions = rhs.select(0, cols) * self.values.reshape([nnz, 1]);
+
+        Tensor::zeros([m, n], &contributions.device()).select_assign(0, rows, contributions)
+    }
+
+    /// Transpose the sparse matrix.
+    pub fn transpose(self) -> Self {
+        let [m, n] = self.shape.dims;
+        let indices = self.indices.flip([0]);
+
+        Self {
+            indices,
+            values: self.values,
+            shape: Shape::new([n, m]),
+        }
+    }
+}
+
+impl<B: AutodiffBackend, const D: usize> SparseTensor<B, D> {
+    /// Get the gradient of the values if it exists, as a sparse tensor with the same coordinates.
+    pub fn grad(&self, grads: &B::Gradients) -> Option<SparseTensor<B::InnerBackend, D>> {
+        self.values.grad(grads).map(|values| SparseTensor {
+            indices: self.indices.clone().inner(),
+            values,
+            shape: self.shape.clone(),
+        })
+    }
+
+    /// Returns the inner sparse tensor without the autodiff information.
+    pub fn inner(self) -> SparseTensor<B::InnerBackend, D> {
+        SparseTensor {
+            indices: self.indices.inner(),
+            values: self.values.inner(),
+            shape: self.shape,
+        }
+    }
+
+    /// Convert a sparse tensor to the autodiff backend.
+    pub fn from_inner(inner: SparseTensor<B::InnerBackend, D>) -> Self {
+        Self {
+            indices: Tensor::from_inner(inner.indices),
+            values: Tensor::from_inner(inner.values),
+            shape: inner.shape,
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Convert the tensor into a [sparse tensor](SparseTensor) storing its non-zero elements.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn to_sparse(self) -> SparseTensor<B, D> {
+        let shape = self.shape();
+        let indices = self.clone().not_equal_elem(0.0).argwhere().transpose();
+
+        let values = self
+            .reshape([shape.num_elements()])
+            .select(0, offsets(&indices, &shape));
+
+        SparseTensor::new(indices, values, shape)
+    }
+}
+
+/// Returns the row-major offsets of the elements with the given `[D, nnz]` coordinates.
+fn offsets<B: Backend, const D: usize>(
+    indices: &Tensor<B, 2, Int>,
+    shape: &Shape<D>,
+) -> Tensor<B, 1, Int> {
+    let nnz = indices.dims()[1];
+    let strides = strides(&shape.dims)
+        .iter()
+        .map(|stride| *stride as i64)
+        .collect::<Vec<_>>();
+    let strides =
+        Tensor::<B, 1, Int>::from_ints(strides.as_slice(), &indices.device()).reshape([D, 1]);
+
+    (indices.clone() * strides).sum_dim(0).reshape([nnz])
+}
+
+/// Returns the row-major strides of the dimensions.
+fn strides(dims: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; dims.len()];
+    for dim in (0..dims.len().saturating_sub(1)).rev() {
+        strides[dim] = strides[dim + 1] * dims[dim + 1];
+    }
+    strides
+}
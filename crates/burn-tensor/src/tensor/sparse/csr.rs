@@ -0,0 +1,313 @@
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use alloc::vec::Vec;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use core::ops::Range;
+
+use super::SparseTensor;
+use crate::{
+    backend::{AutodiffBackend, Backend},
+    Int, Shape, Tensor,
+};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use crate::{
+    ops::{FloatTensor, IntTensor},
+    TensorData,
+};
+
+/// A sparse matrix stored in the compressed sparse row (CSR) format.
+///
+/// The stored elements are sorted by row and described by their `[nnz]` column indices and
+/// values, while the `[m + 1]` row offsets give the range of the elements of each row. Compared to
+/// the [coordinate format](SparseTensor), rows are contiguous, which makes slicing rows cheap and
+/// lets backends reduce each row segment independently in [spmm](SparseCsrTensor::spmm).
+///
+/// The values are a regular float tensor, so the products are differentiable with respect to
+/// them and the [gradient](SparseCsrTensor::grad) only holds an entry per stored element.
+#[derive(Clone, Debug)]
+pub struct SparseCsrTensor<B: Backend> {
+    row_offsets: Tensor<B, 1, Int>,
+    col_indices: Tensor<B, 1, Int>,
+    values: Tensor<B, 1>,
+    shape: Shape<2>,
+}
+
+impl<B: Backend> SparseCsrTensor<B> {
+    /// Create a sparse matrix from the `[m + 1]` row offsets, and the `[nnz]` column indices and
+    /// values of its elements sorted by row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of row offsets doesn't match the shape, or if the column indices and values
+    /// don't describe the same number of elements.
+    pub fn new<S: Into<Shape<2>>>(
+        row_offsets: Tensor<B, 1, Int>,
+        col_indices: Tensor<B, 1, Int>,
+        values: Tensor<B, 1>,
+        shape: S,
+    ) -> Self {
+        let shape = shape.into();
+        let [num_offsets] = row_offsets.dims();
+        assert_eq!(
+            num_offsets,
+            shape.dims[0] + 1,
+            "A sparse matrix with {} rows should have {} row offsets, got {num_offsets}.",
+            shape.dims[0],
+            shape.dims[0] + 1
+        );
+        assert_eq!(
+            col_indices.dims(),
+            values.dims(),
+            "The sparse matrix should have as many column indices as values."
+        );
+
+        Self {
+            row_offsets,
+            col_indices,
+            values,
+            shape,
+        }
+    }
+
+    /// Returns the `[m + 1]` offsets of the first stored element of each row.
+    pub fn row_offsets(&self) -> Tensor<B, 1, Int> {
+        self.row_offsets.clone()
+    }
+
+    /// Returns the `[nnz]` column indices of the stored elements.
+    pub fn col_indices(&self) -> Tensor<B, 1, Int> {
+        self.col_indices.clone()
+    }
+
+    /// Returns the `[nnz]` values of the stored elements.
+    pub fn values(&self) -> Tensor<B, 1> {
+        self.values.clone()
+    }
+
+    /// Returns the shape of the dense matrix.
+    pub fn shape(&self) -> Shape<2> {
+        self.shape.clone()
+    }
+
+    /// Returns the dimensions of the dense matrix.
+    pub fn dims(&self) -> [usize; 2] {
+        self.shape.dims
+    }
+
+    /// Returns the number of stored elements.
+    pub fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// Returns the device of the matrix.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Move the matrix to the given device.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            row_offsets: self.row_offsets.to_device(device),
+            col_indices: self.col_indices.to_device(device),
+            values: self.values.to_device(device),
+            shape: self.shape,
+        }
+    }
+
+    /// Mark the values to keep gradients during the backward pass.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn require_grad(mut self) -> Self {
+        self.values = self.values.require_grad();
+        self
+    }
+
+    /// Returns the rows in the given range.
+    ///
+    /// Only the two offsets delimiting the range are read on the host, the stored elements of the
+    /// rows being contiguous.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn slice_rows(self, rows: Range<usize>) -> Self {
+        let [_, n] = self.shape.dims;
+        let bounds = self
+            .row_offsets
+            .clone()
+            .select(
+                0,
+                Tensor::from_ints([rows.start as i32, rows.end as i32], &self.device()),
+            )
+            .into_data()
+            .iter::<i64>()
+            .collect::<Vec<_>>();
+        let (start, end) = (bounds[0] as usize, bounds[1] as usize);
+
+        Self {
+            row_offsets: self
+                .row_offsets
+                .narrow(0, rows.start, rows.len() + 1)
+                .sub_scalar(start as i64),
+            col_indices: self.col_indices.narrow(0, start, end - start),
+            values: self.values.narrow(0, start, end - start),
+            shape: Shape::new([rows.len(), n]),
+        }
+    }
+
+    /// Multiplies the `[m, k]` sparse matrix by a `[k, n]` dense matrix.
+    ///
+    /// The operation is differentiable with respect to both the values and the dense matrix.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn spmm(self, rhs: Tensor<B, 2>) -> Tensor<B, 2> {
+        let [_, k] = self.shape.dims;
+        let [k_rhs, _] = rhs.dims();
+        assert_eq!(
+            k, k_rhs,
+            "spmm expects the dense matrix to have {k} rows, got {k_rhs}."
+        );
+
+        Tensor::new(B::float_spmm_csr(
+            self.row_offsets.into_primitive(),
+            self.col_indices.into_primitive(),
+            self.values.into_primitive(),
+            rhs.into_primitive(),
+        ))
+    }
+
+    /// Multiplies the `[m, k]` sparse matrix by a `[k]` dense vector.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn spmv(self, rhs: Tensor<B, 1>) -> Tensor<B, 1> {
+        let [m, _] = self.shape.dims;
+        let [k] = rhs.dims();
+
+        self.spmm(rhs.reshape([k, 1])).reshape([m])
+    }
+
+    /// Convert the sparse matrix into the [coordinate format](SparseTensor).
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn to_coo(self) -> SparseTensor<B, 2> {
+        let nnz = self.nnz();
+        let rows = row_indices(self.row_offsets, nnz).reshape([1, nnz]);
+        let indices = Tensor::cat(alloc::vec![rows, self.col_indices.reshape([1, nnz])], 0);
+
+        SparseTensor::new(indices, self.values, self.shape)
+    }
+
+    /// Convert the sparse matrix into a dense matrix.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn to_dense(self) -> Tensor<B, 2> {
+        self.to_coo().to_dense()
+    }
+}
+
+impl<B: AutodiffBackend> SparseCsrTensor<B> {
+    /// Get the gradient of the values if it exists, as a sparse matrix with the same structure.
+    pub fn grad(&self, grads: &B::Gradients) -> Option<SparseCsrTensor<B::InnerBackend>> {
+        self.values.grad(grads).map(|values| SparseCsrTensor {
+            row_offsets: self.row_offsets.clone().inner(),
+            col_indices: self.col_indices.clone().inner(),
+            values,
+            shape: self.shape.clone(),
+        })
+    }
+
+    /// Returns the inner sparse matrix without the autodiff information.
+    pub fn inner(self) -> SparseCsrTensor<B::InnerBackend> {
+        SparseCsrTensor {
+            row_offsets: self.row_offsets.inner(),
+            col_indices: self.col_indices.inner(),
+            values: self.values.inner(),
+            shape: self.shape,
+        }
+    }
+
+    /// Convert a sparse matrix to the autodiff backend.
+    pub fn from_inner(inner: SparseCsrTensor<B::InnerBackend>) -> Self {
+        Self {
+            row_offsets: Tensor::from_inner(inner.row_offsets),
+            col_indices: Tensor::from_inner(inner.col_indices),
+            values: Tensor::from_inner(inner.values),
+            shape: inner.shape,
+        }
+    }
+}
+
+impl<B: Backend> SparseTensor<B, 2> {
+    /// Convert the sparse matrix into the [compressed sparse row format](SparseCsrTensor),
+    /// merging the duplicated coordinates.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn to_csr(self) -> SparseCsrTensor<B> {
+        let [m, n] = self.dims();
+        let device = self.device();
+        let (indices, values) = self.coalesce().into_parts();
+        let nnz = values.dims()[0];
+
+        // The coalesced elements are sorted by row, so the offsets count the elements of the
+        // previous rows.
+        let mut offsets = alloc::vec![0i64; m + 1];
+        let rows = indices.clone().narrow(0, 0, 1).into_data();
+        for row in rows.iter::<i64>() {
+            offsets[row as usize + 1] += 1;
+        }
+        for row in 0..m {
+            offsets[row + 1] += offsets[row];
+        }
+
+        let row_offsets = Tensor::from_data(
+            TensorData::new(offsets, [m + 1]).convert::<B::IntElem>(),
+            &device,
+        );
+        let col_indices = indices.narrow(0, 1, 1).reshape([nnz]);
+
+        SparseCsrTensor::new(row_offsets, col_indices, values, [m, n])
+    }
+}
+
+impl<B: Backend> Tensor<B, 2> {
+    /// Convert the matrix into a [sparse matrix](SparseCsrTensor) in the compressed sparse row
+    /// format, storing its non-zero elements.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn to_sparse_csr(self) -> SparseCsrTensor<B> {
+        self.to_sparse().to_csr()
+    }
+}
+
+/// Multiplies a sparse matrix in the compressed sparse row format by a dense matrix, by
+/// accumulating the scaled rows of the dense matrix selected by each stored element.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) fn spmm_csr<B: Backend>(
+    row_offsets: IntTensor<B, 1>,
+    col_indices: IntTensor<B, 1>,
+    values: FloatTensor<B, 1>,
+    rhs: FloatTensor<B, 2>,
+) -> FloatTensor<B, 2> {
+    let row_offsets = Tensor::<B, 1, Int>::new(row_offsets);
+    let col_indices = Tensor::<B, 1, Int>::new(col_indices);
+    let values = Tensor::<B, 1>::new(values);
+    let rhs = Tensor::<B, 2>::new(rhs);
+
+    let m = row_offsets.dims()[0] - 1;
+    let [nnz] = values.dims();
+    let [_, n] = rhs.dims();
+
+    let rows = row_indices(row_offsets, nnz);
+    let contributions = rhs.select(0, col_indices) * values.reshape([nnz, 1]);
+
+    Tensor::zeros([m, n], &contributions.device())
+        .select_assign(0, rows, contributions)
+        .into_primitive()
+}
+
+/// Expand the row offsets into the row index of each stored element.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn row_indices<B: Backend>(row_offsets: Tensor<B, 1, Int>, nnz: usize) -> Tensor<B, 1, Int> {
+    let device = row_offsets.device();
+    let offsets = row_offsets.into_data().iter::<i64>().collect::<Vec<_>>();
+
+    let mut rows = Vec::with_capacity(nnz);
+    for (row, range) in offsets.windows(2).enumerate() {
+        rows.extend((range[0]..range[1]).map(|_| row as i64));
+    }
+
+    Tensor::from_data(
+        TensorData::new(rows, [nnz]).convert::<B::IntElem>(),
+        &device,
+    )
+}
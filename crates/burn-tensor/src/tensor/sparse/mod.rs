@@ -0,0 +1,5 @@
+mod coo;
+mod csr;
+
+pub use coo::*;
+pub use csr::*;
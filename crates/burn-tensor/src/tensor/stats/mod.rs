@@ -1,4 +1,4 @@
-use crate::{backend::Backend, Tensor};
+use crate::{backend::Backend, Int, Tensor};
 
 pub fn var<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     let mean = tensor.clone().mean_dim(dim);
@@ -40,3 +40,110 @@ pub fn var_with_mean_n<B: Backend, const D: usize>(
         .sum_dim(dim)
         .div_scalar(n as f32)
 }
+
+pub fn histc<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    bins: usize,
+    min: f64,
+    max: f64,
+) -> Tensor<B, 1> {
+    let n = tensor.shape().num_elements();
+    let values = tensor.reshape([n]);
+    let device = values.device();
+
+    // Without a valid range, the bins span the values, which stays on the device.
+    let (low, high) = match min < max {
+        true => (
+            Tensor::full([n], min, &device),
+            Tensor::full([n], max, &device),
+        ),
+        false => (
+            values.clone().min().expand([n]),
+            values.clone().max().expand([n]),
+        ),
+    };
+    let width = high.clone() - low.clone();
+    let width = width.clone().mask_fill(width.equal_elem(0.0), 1.0);
+
+    let inside = values.clone().greater_equal(low.clone()).float()
+        * values.clone().lower_equal(high).float();
+    let indices = ((values - low) / width * bins as f64)
+        .int()
+        .clamp(0, bins as i64 - 1);
+
+    count_bins(indices, inside, bins)
+}
+
+pub fn histogram<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    edges: Tensor<B, 1>,
+) -> Tensor<B, 1> {
+    let n = tensor.shape().num_elements();
+    let values = tensor.reshape([n]);
+    let bins = edges.dims()[0] - 1;
+
+    let first = edges.clone().narrow(0, 0, 1).expand([n]);
+    let last = edges.clone().narrow(0, bins, 1).expand([n]);
+    let inside =
+        values.clone().greater_equal(first).float() * values.clone().lower_equal(last).float();
+
+    // The last bin also includes its right edge.
+    let indices = edges
+        .searchsorted(values, true)
+        .sub_scalar(1)
+        .clamp(0, bins as i64 - 1);
+
+    count_bins(indices, inside, bins)
+}
+
+pub fn histogramdd<B: Backend, const D: usize>(
+    tensor: Tensor<B, 2>,
+    bins: [usize; D],
+    ranges: [(f64, f64); D],
+) -> Tensor<B, D> {
+    let [n, num_dims] = tensor.dims();
+    assert_eq!(
+        num_dims, D,
+        "histogramdd expects points with {D} dimensions, got {num_dims}."
+    );
+
+    let device = tensor.device();
+    let mut offsets = Tensor::<B, 1, Int>::zeros([n], &device);
+    let mut inside = Tensor::<B, 1>::ones([n], &device);
+    let mut stride = 1;
+
+    for dim in (0..D).rev() {
+        let (min, max) = ranges[dim];
+        let values = tensor.clone().narrow(1, dim, 1).reshape([n]);
+
+        inside = inside
+            * values.clone().greater_equal_elem(min).float()
+            * values.clone().lower_equal_elem(max).float();
+        let indices = values
+            .sub_scalar(min)
+            .div_scalar(max - min)
+            .mul_scalar(bins[dim] as f64)
+            .int()
+            .clamp(0, bins[dim] as i64 - 1);
+
+        offsets = offsets + indices.mul_scalar(stride as i64);
+        stride *= bins[dim];
+    }
+
+    count_bins(offsets, inside, stride).reshape(bins)
+}
+
+/// Counts the elements in each bin with the [bincount](crate::ops::IntTensorOps::int_bincount) of
+/// the backend, which accumulates them atomically on the device, the elements outside of the
+/// range being counted in an extra bin that is then dropped.
+fn count_bins<B: Backend>(
+    indices: Tensor<B, 1, Int>,
+    inside: Tensor<B, 1>,
+    bins: usize,
+) -> Tensor<B, 1> {
+    let indices = indices.mask_fill(inside.equal_elem(0.0), bins as i64);
+
+    Tensor::<B, 1, Int>::new(B::int_bincount(indices.into_primitive(), bins + 1))
+        .narrow(0, 0, bins)
+        .float()
+}
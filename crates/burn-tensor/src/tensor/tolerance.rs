@@ -0,0 +1,212 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use half::{bf16, f16};
+
+use crate::DType;
+
+use num_traits::Float;
+
+/// Number of mismatches with the largest errors listed in the report of a failed comparison.
+const NUM_WORST: usize = 5;
+
+/// The tolerance of the comparison of [tensor data](crate::TensorData) with
+/// [assert_close](crate::TensorData::assert_close_with).
+///
+/// Two values `actual` and `expected` are close when
+/// `|actual - expected| <= absolute + relative * |expected|`, or when they are at most `ulps`
+/// representable values apart in the data type of the compared data. NaNs are close to NaNs, and
+/// infinities to the infinities of the same sign.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// The relative tolerance.
+    pub relative: f64,
+    /// The absolute tolerance.
+    pub absolute: f64,
+    /// The maximum number of units in the last place between the values, if any.
+    pub ulps: Option<u64>,
+}
+
+impl Tolerance {
+    /// Creates a tolerance with the given relative and absolute tolerances.
+    pub fn new(relative: f64, absolute: f64) -> Self {
+        Self {
+            relative,
+            absolute,
+            ulps: None,
+        }
+    }
+
+    /// Creates a tolerance of the given number of units in the last place only.
+    pub fn ulps(ulps: u64) -> Self {
+        Self::new(0.0, 0.0).with_ulps(ulps)
+    }
+
+    /// Returns the default tolerance of the data type, the same as the defaults of
+    /// `torch.testing.assert_close`, integers and booleans being compared exactly.
+    pub fn for_dtype(dtype: DType) -> Self {
+        match dtype {
+            DType::F64 => Self::new(1e-7, 1e-7),
+            DType::F32 => Self::new(1.3e-6, 1e-5),
+            DType::F16 => Self::new(1e-3, 1e-5),
+            DType::BF16 => Self::new(1.6e-2, 1e-5),
+            _ => Self::new(0.0, 0.0),
+        }
+    }
+
+    /// Returns the tolerance with the given relative tolerance.
+    pub fn with_relative(mut self, relative: f64) -> Self {
+        self.relative = relative;
+        self
+    }
+
+    /// Returns the tolerance with the given absolute tolerance.
+    pub fn with_absolute(mut self, absolute: f64) -> Self {
+        self.absolute = absolute;
+        self
+    }
+
+    /// Returns the tolerance also accepting the values at most the given number of units in
+    /// the last place apart.
+    pub fn with_ulps(mut self, ulps: u64) -> Self {
+        self.ulps = Some(ulps);
+        self
+    }
+
+    /// Returns whether the values are close in the given data type.
+    pub fn is_close(&self, actual: f64, expected: f64, dtype: DType) -> bool {
+        if actual.is_nan() || expected.is_nan() {
+            return actual.is_nan() && expected.is_nan();
+        }
+        if actual.is_infinite() || expected.is_infinite() {
+            return actual == expected;
+        }
+
+        let within_tolerance =
+            Float::abs(actual - expected) <= self.absolute + self.relative * Float::abs(expected);
+        let within_ulps = self
+            .ulps
+            .is_some_and(|ulps| ulp_distance(actual, expected, dtype) <= ulps);
+
+        within_tolerance || within_ulps
+    }
+}
+
+/// Compares the actual and expected values of data with the given shape and data type,
+/// returning a report of the mismatches if any.
+pub(crate) fn compare(
+    actual: impl Iterator<Item = f64>,
+    expected: impl Iterator<Item = f64>,
+    shape: &[usize],
+    dtype: DType,
+    tolerance: &Tolerance,
+) -> Option<String> {
+    let mut num_elements = 0;
+    // The index, the values and the absolute error of each mismatch.
+    let mut mismatches = Vec::new();
+    let mut max_relative: Option<(usize, f64)> = None;
+
+    for (i, (a, b)) in actual.zip(expected).enumerate() {
+        num_elements += 1;
+        if tolerance.is_close(a, b, dtype) {
+            continue;
+        }
+
+        let error = Float::abs(a - b);
+        let relative = error / Float::abs(b);
+        if !max_relative.is_some_and(|(_, max)| relative <= max) {
+            max_relative = Some((i, relative));
+        }
+        mismatches.push((i, a, b, error));
+    }
+
+    if mismatches.is_empty() {
+        return None;
+    }
+
+    let mut message = format!(
+        "{} / {num_elements} elements differ ({:.2}%), with relative tolerance {} and absolute \
+         tolerance {}",
+        mismatches.len(),
+        100.0 * mismatches.len() as f64 / num_elements as f64,
+        tolerance.relative,
+        tolerance.absolute,
+    );
+    if let Some(ulps) = tolerance.ulps {
+        message += format!(" or {ulps} ulps").as_str();
+    }
+
+    // NaN errors, e.g. between NaN and a number, are the worst.
+    mismatches.sort_by(|a, b| b.3.total_cmp(&a.3).then(a.0.cmp(&b.0)));
+    mismatches.sort_by_key(|mismatch| !mismatch.3.is_nan());
+
+    let (i, a, b, error) = mismatches[0];
+    message += format!(
+        "\n  => Max absolute error {error} at index {:?}: {a} != {b}",
+        unravel(i, shape)
+    )
+    .as_str();
+    if let Some((i, relative)) = max_relative {
+        message += format!(
+            "\n  => Max relative error {relative} at index {:?}",
+            unravel(i, shape)
+        )
+        .as_str();
+    }
+    message += "\n  => Worst mismatches:";
+    for (i, a, b, error) in mismatches.iter().take(NUM_WORST) {
+        message += format!(
+            "\n     {:?}: {a} != {b} | difference {error}",
+            unravel(*i, shape)
+        )
+        .as_str();
+    }
+
+    Some(message)
+}
+
+/// Returns the number of representable values between the values in the given data type, or
+/// their difference for integers.
+fn ulp_distance(a: f64, b: f64, dtype: DType) -> u64 {
+    // The position of a value among the representable ones, from its sign and magnitude bits.
+    fn ordered(bits: u64, sign: u64) -> i128 {
+        let magnitude = (bits & !sign) as i128;
+        match bits & sign {
+            0 => magnitude,
+            _ => -magnitude,
+        }
+    }
+
+    let (a, b) = match dtype {
+        DType::F64 => (ordered(a.to_bits(), 1 << 63), ordered(b.to_bits(), 1 << 63)),
+        DType::F32 => (
+            ordered((a as f32).to_bits() as u64, 1 << 31),
+            ordered((b as f32).to_bits() as u64, 1 << 31),
+        ),
+        DType::F16 => (
+            ordered(f16::from_f64(a).to_bits() as u64, 1 << 15),
+            ordered(f16::from_f64(b).to_bits() as u64, 1 << 15),
+        ),
+        DType::BF16 => (
+            ordered(bf16::from_f64(a).to_bits() as u64, 1 << 15),
+            ordered(bf16::from_f64(b).to_bits() as u64, 1 << 15),
+        ),
+        _ => (a as i128, b as i128),
+    };
+
+    (a - b).unsigned_abs().min(u64::MAX as u128) as u64
+}
+
+/// Returns the multi-dimensional index of the flat index in the given shape.
+fn unravel(mut index: usize, shape: &[usize]) -> Vec<usize> {
+    let mut indices = alloc::vec![0; shape.len()];
+
+    for (dim, size) in shape.iter().enumerate().rev() {
+        if *size > 0 {
+            indices[dim] = index % size;
+            index /= size;
+        }
+    }
+
+    indices
+}
@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(linalg_cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Distribution, Tensor, TensorData};
+
+    #[test]
+    fn should_compute_cholesky() {
+        let tensor = TestTensor::<2>::from([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let output = linalg::cholesky(tensor);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_batches() {
+        let tensor = TestTensor::<3>::from([[[4.0, 2.0], [2.0, 5.0]], [[9.0, 3.0], [3.0, 5.0]]]);
+
+        let output = tensor.cholesky();
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[2.0, 0.0], [1.0, 2.0]], [[3.0, 0.0], [1.0, 2.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_solve_with_cholesky_factor() {
+        let tensor = TestTensor::<2>::from([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+        let rhs = TestTensor::<2>::from([[-20.0, 4.0], [-43.0, 12.0], [192.0, -16.0]]);
+
+        let output = tensor.cholesky().cholesky_solve(rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 1.0], [2.0, 0.0], [3.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_match_the_reference_decomposition() {
+        let device = Default::default();
+        let x = TestTensor::<3>::random([2, 4, 4], Distribution::Default, &device);
+        let eye = TestTensor::<2>::eye(4, &device).unsqueeze::<3>();
+        let spd = x.clone().matmul(x.transpose()) + eye;
+        // Only the lower triangular part is read.
+        let noise = TestTensor::<3>::random([2, 4, 4], Distribution::Default, &device);
+        let tensor = spd.tril(0) + noise.triu(1);
+
+        let output = linalg::cholesky(tensor.clone());
+        let expected = linalg::column_cholesky(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
@@ -0,0 +1,90 @@
+#[burn_tensor_testgen::testgen(linalg_det)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Distribution, Tensor, TensorData};
+
+    #[test]
+    fn should_compute_det() {
+        let tensor = TestTensor::<2>::from([[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+
+        let output = linalg::det(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-1.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_det_with_row_swap() {
+        let tensor = TestTensor::<3>::from([
+            [[0.0, 1.0], [1.0, 0.0]],
+            [[1.0, 2.0], [2.0, 4.0]],
+            [[4.0, 3.0], [6.0, 3.0]],
+        ]);
+
+        let output = tensor.det();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[-1.0]], [[0.0]], [[-6.0]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_slogdet() {
+        let tensor = TestTensor::<3>::from([[[4.0, 3.0], [6.0, 3.0]], [[2.0, 0.0], [0.0, 50.0]]]);
+
+        let (sign, logabsdet) = linalg::slogdet(tensor);
+
+        sign.into_data()
+            .assert_approx_eq(&TensorData::from([[[-1.0]], [[1.0]]]), 3);
+        logabsdet
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[1.7918]], [[4.6052]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_inverse() {
+        let tensor = TestTensor::<2>::from([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = linalg::inverse(tensor.clone());
+
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.6, -0.7], [-0.2, 0.4]]), 3);
+        tensor
+            .matmul(output)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_match_the_reference_determinants() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::random([3, 4, 4], Distribution::Default, &device);
+        // A singular matrix, with two equal rows.
+        let singular = TestTensor::<3>::from([[
+            [1.0, 2.0, 3.0, 4.0],
+            [0.5, -1.0, 2.0, 0.0],
+            [1.0, 2.0, 3.0, 4.0],
+            [3.0, 0.0, -2.0, 1.0],
+        ]]);
+        let tensor = Tensor::cat(vec![tensor, singular], 0);
+
+        let output = linalg::det(tensor.clone());
+        let expected = linalg::elimination_det(tensor.clone());
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+
+        let (sign, logabsdet) = linalg::slogdet(tensor.clone());
+        let (expected_sign, expected_logabsdet) = linalg::elimination_slogdet(tensor);
+        sign.into_data()
+            .assert_approx_eq(&expected_sign.into_data(), 3);
+        // The logarithm of the singular matrix is negative infinity, which isn't comparable.
+        logabsdet
+            .narrow(0, 0, 3)
+            .into_data()
+            .assert_approx_eq(&expected_logabsdet.narrow(0, 0, 3).into_data(), 3);
+    }
+}
@@ -0,0 +1,112 @@
+#[burn_tensor_testgen::testgen(linalg_eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Distribution, Tensor, TensorData};
+    use core::f32::consts::FRAC_1_SQRT_2;
+
+    fn assert_reconstructs<const D: usize>(tensor: TestTensor<D>) {
+        let (eigenvalues, vectors) = linalg::eigh(tensor.clone());
+
+        let output = (vectors.clone() * eigenvalues).matmul(vectors.transpose());
+        output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_eigh() {
+        let tensor = TestTensor::<2>::from([[2.0, 1.0], [1.0, 2.0]]);
+
+        let (eigenvalues, vectors) = tensor.eigh();
+
+        eigenvalues
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 3.0]]), 3);
+        vectors.abs().into_data().assert_approx_eq(
+            &TensorData::from([
+                [FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+                [FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_only_read_lower_triangle() {
+        let tensor = TestTensor::<2>::from([[2.0, 100.0], [1.0, 2.0]]);
+
+        let (eigenvalues, _) = tensor.eigh();
+
+        eigenvalues
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 3.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_odd_size() {
+        let tensor = TestTensor::<2>::from([[4.0, 1.0, -2.0], [1.0, 2.0, 0.0], [-2.0, 0.0, 3.0]]);
+
+        let (eigenvalues, vectors) = linalg::eigh(tensor.clone());
+
+        vectors
+            .clone()
+            .transpose()
+            .matmul(vectors)
+            .into_data()
+            .assert_approx_eq(
+                &TensorData::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]),
+                3,
+            );
+        eigenvalues
+            .sum()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([9.0]), 3);
+        assert_reconstructs(tensor);
+    }
+
+    #[test]
+    fn should_support_batches() {
+        let tensor = TestTensor::<3>::from([
+            [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, -3.0, 0.0, 0.0],
+                [0.0, 0.0, 2.0, 0.0],
+                [0.0, 0.0, 0.0, 0.5],
+            ],
+            [
+                [2.0, -1.0, 0.0, 0.0],
+                [-1.0, 2.0, -1.0, 0.0],
+                [0.0, -1.0, 2.0, -1.0],
+                [0.0, 0.0, -1.0, 2.0],
+            ],
+        ]);
+
+        let (eigenvalues, _) = linalg::eigh(tensor.clone());
+
+        eigenvalues.into_data().assert_approx_eq(
+            &TensorData::from([[[-3.0, 0.5, 1.0, 2.0]], [[0.382, 1.382, 2.618, 3.618]]]),
+            3,
+        );
+        assert_reconstructs(tensor);
+    }
+
+    #[test]
+    fn should_match_the_reference_decomposition() {
+        let device = Default::default();
+        let x = TestTensor::<3>::random([2, 5, 5], Distribution::Default, &device);
+        let symmetric = x.clone() + x.transpose();
+        // Only the lower triangular part is read.
+        let noise = TestTensor::<3>::random([2, 5, 5], Distribution::Default, &device);
+        let tensor = symmetric.tril(0) + noise.triu(1);
+
+        let (eigenvalues, vectors) = linalg::eigh(tensor.clone());
+        let (expected, expected_vectors) = linalg::jacobi_eigh(tensor);
+
+        eigenvalues
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+        // The signs of the eigenvectors are arbitrary.
+        vectors
+            .abs()
+            .into_data()
+            .assert_approx_eq(&expected_vectors.abs().into_data(), 3);
+    }
+}
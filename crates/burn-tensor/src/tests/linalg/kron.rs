@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(linalg_kron)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn should_compute_kron() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::<2>::from([[0.0, 5.0, 1.0]]);
+
+        let output = linalg::kron(lhs, rhs);
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [0.0, 5.0, 1.0, 0.0, 10.0, 2.0],
+                [0.0, 15.0, 3.0, 0.0, 20.0, 4.0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_compute_batched_kron_with_broadcast() {
+        let lhs = TestTensor::<3>::from([[[1.0], [2.0]], [[-1.0], [0.0]]]);
+        let rhs = TestTensor::<3>::from([[[1.0, 2.0]]]);
+
+        let output = lhs.kron(rhs);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[1.0, 2.0], [2.0, 4.0]], [[-1.0, -2.0], [0.0, 0.0]]]),
+            false,
+        );
+    }
+}
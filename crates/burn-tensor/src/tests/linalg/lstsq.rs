@@ -0,0 +1,102 @@
+#[burn_tensor_testgen::testgen(linalg_lstsq)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Distribution, Tensor, TensorData};
+
+    #[test]
+    fn should_compute_pinv_of_invertible_matrix() {
+        let tensor = TestTensor::<2>::from([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = linalg::pinv(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.6, -0.7], [-0.2, 0.4]]), 3);
+    }
+
+    #[test]
+    fn should_compute_pinv_of_rank_deficient_matrix() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [2.0, 4.0]]);
+
+        let output = tensor.pinv();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.04, 0.08], [0.08, 0.16]]), 3);
+    }
+
+    #[test]
+    fn should_compute_pinv_of_rectangular_matrices() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 0.0], [0.0, 2.0], [0.0, 0.0]],
+            [[1.0, 1.0], [1.0, 2.0], [1.0, 3.0]],
+        ]);
+
+        let output = tensor.clone().pinv();
+
+        assert_eq!(output.dims(), [2, 2, 3]);
+        output
+            .clone()
+            .narrow(0, 0, 1)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[1.0, 0.0, 0.0], [0.0, 0.5, 0.0]]]), 3);
+        output.matmul(tensor).into_data().assert_approx_eq(
+            &TensorData::from([[[1.0, 0.0], [0.0, 1.0]], [[1.0, 0.0], [0.0, 1.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_solve_overdetermined_system() {
+        let tensor = TestTensor::<2>::from([[1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let rhs = TestTensor::<2>::from([[1.0], [2.0], [2.0]]);
+
+        let output = linalg::lstsq(tensor, rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.6667], [0.5]]), 3);
+    }
+
+    #[test]
+    fn should_solve_underdetermined_system_with_minimum_norm() {
+        let tensor = TestTensor::<3>::from([[[1.0, 1.0]]]);
+        let rhs = TestTensor::<3>::from([[[2.0]], [[-4.0]]]);
+
+        let output = tensor.lstsq(rhs);
+
+        assert_eq!(output.dims(), [2, 2, 1]);
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[1.0], [1.0]], [[-2.0], [-2.0]]]), 3);
+    }
+
+    #[test]
+    fn should_match_the_reference_pinv() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::random([2, 5, 3], Distribution::Default, &device);
+
+        let output = linalg::pinv(tensor.clone());
+        let expected = linalg::svd_pinv(tensor, 5.0 * f32::EPSILON as f64);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_match_the_reference_lstsq() {
+        let device = Default::default();
+        for [m, n] in [[5, 3], [3, 5]] {
+            let tensor = TestTensor::<3>::random([2, m, n], Distribution::Default, &device);
+            let rhs = TestTensor::<3>::random([2, m, 2], Distribution::Default, &device);
+
+            let output = linalg::lstsq(tensor.clone(), rhs.clone());
+            let expected = linalg::qr_lstsq(tensor, rhs);
+
+            output
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub(crate) mod cholesky;
+pub(crate) mod det;
+pub(crate) mod eigh;
+pub(crate) mod kron;
+pub(crate) mod lstsq;
+pub(crate) mod qr;
+pub(crate) mod solve;
+pub(crate) mod svd;
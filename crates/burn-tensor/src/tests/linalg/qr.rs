@@ -0,0 +1,104 @@
+#[burn_tensor_testgen::testgen(linalg_qr)]
+mod tests {
+    use super::*;
+    use burn_tensor::linalg::{self, QrMode};
+    use burn_tensor::{Distribution, Tensor, TensorData};
+
+    #[test]
+    fn should_compute_qr() {
+        let tensor =
+            TestTensor::<2>::from([[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone(), QrMode::Reduced);
+
+        q.clone().into_data().assert_approx_eq(
+            &TensorData::from([
+                [0.8571, -0.3943, -0.3314],
+                [0.4286, 0.9029, 0.0343],
+                [-0.2857, 0.1714, -0.9429],
+            ]),
+            3,
+        );
+        r.clone().into_data().assert_approx_eq(
+            &TensorData::from([[14.0, 21.0, -14.0], [0.0, 175.0, -70.0], [0.0, 0.0, 35.0]]),
+            2,
+        );
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 2);
+    }
+
+    #[test]
+    fn should_support_reduced_and_complete_modes() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let (q, r) = tensor.clone().qr(QrMode::Reduced);
+        assert_eq!(q.dims(), [3, 2]);
+        assert_eq!(r.dims(), [2, 2]);
+        q.clone()
+            .transpose()
+            .matmul(q.clone())
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.clone().into_data(), 3);
+
+        let (q, r) = tensor.clone().qr(QrMode::Complete);
+        assert_eq!(q.dims(), [3, 3]);
+        assert_eq!(r.dims(), [3, 2]);
+        q.clone()
+            .transpose()
+            .matmul(q.clone())
+            .into_data()
+            .assert_approx_eq(
+                &TensorData::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]),
+                3,
+            );
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_wide_matrix() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [-1.0, 0.5, 2.0]]);
+
+        let (q, r) = tensor.clone().qr(QrMode::Reduced);
+
+        assert_eq!(q.dims(), [2, 2]);
+        assert_eq!(r.dims(), [2, 3]);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_batches() {
+        let tensor = TestTensor::<3>::from([[[2.0, 1.0], [1.0, 3.0]], [[0.0, 1.0], [1.0, 0.0]]]);
+
+        let (q, r) = tensor.clone().qr(QrMode::Reduced);
+
+        r.clone().into_data().assert_approx_eq(
+            &TensorData::from([[[2.2361, 2.2361], [0.0, 2.2361]], [[1.0, 0.0], [0.0, 1.0]]]),
+            3,
+        );
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_match_the_reference_decomposition() {
+        let device = Default::default();
+        for dims in [[2, 5, 3], [2, 3, 5]] {
+            let tensor = TestTensor::<3>::random(dims, Distribution::Default, &device);
+
+            let (q, r) = linalg::qr(tensor.clone(), QrMode::Reduced);
+            let (expected_q, expected_r) = linalg::householder_qr(tensor, QrMode::Reduced);
+
+            q.into_data().assert_approx_eq(&expected_q.into_data(), 3);
+            r.into_data().assert_approx_eq(&expected_r.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(linalg_solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Distribution, Tensor, TensorData};
+
+    #[test]
+    fn should_solve() {
+        let tensor = TestTensor::<2>::from([[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+        let rhs = TestTensor::<2>::from([[8.0], [-11.0], [-3.0]]);
+
+        let output = linalg::solve(tensor, rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.0], [3.0], [-1.0]]), 3);
+    }
+
+    #[test]
+    fn should_solve_with_zero_leading_pivot() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0], [1.0, 1.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 0.0], [3.0, 1.0]]);
+
+        let output = tensor.solve(rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.0, 1.0], [1.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_broadcast_batch_dimensions() {
+        let tensor = TestTensor::<3>::from([[[2.0, 1.0], [1.0, 3.0]]]);
+        let rhs = TestTensor::<3>::from([[[3.0], [5.0]], [[2.0], [1.0]], [[0.0], [5.0]]]);
+
+        let output = tensor.solve(rhs);
+
+        assert_eq!(output.dims(), [3, 2, 1]);
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[0.8], [1.4]], [[1.0], [0.0]], [[-1.0], [2.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_solve_triangular() {
+        let upper = TestTensor::<2>::from([[2.0, 1.0], [100.0, 4.0]]);
+        let lower = TestTensor::<2>::from([[2.0, 100.0], [1.0, 4.0]]);
+
+        let output = linalg::triangular_solve(upper, TestTensor::from([[4.0], [8.0]]), true);
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0], [2.0]]), 3);
+
+        let output = lower.triangular_solve(TestTensor::from([[4.0], [10.0]]), false);
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.0], [2.0]]), 3);
+    }
+
+    #[test]
+    fn should_match_the_reference_solvers() {
+        let device = Default::default();
+        let x = TestTensor::<3>::random([2, 4, 4], Distribution::Default, &device);
+        let eye = TestTensor::<2>::eye(4, &device).unsqueeze::<3>();
+        // The diagonal is dominant so that the systems are well conditioned.
+        let tensor = x + eye * 4.0;
+        let rhs = TestTensor::<3>::random([2, 4, 3], Distribution::Default, &device);
+
+        let output = linalg::solve(tensor.clone(), rhs.clone());
+        let expected = linalg::elimination_solve(tensor.clone(), rhs.clone());
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+
+        for upper in [true, false] {
+            let output = linalg::triangular_solve(tensor.clone(), rhs.clone(), upper);
+            let expected = linalg::substitution_solve(tensor.clone(), rhs.clone(), upper);
+            output
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+        }
+    }
+}
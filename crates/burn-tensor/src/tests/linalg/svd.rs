@@ -0,0 +1,105 @@
+#[burn_tensor_testgen::testgen(linalg_svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Distribution, Tensor, TensorData};
+
+    fn assert_reconstructs<const D: usize>(tensor: TestTensor<D>) {
+        let (u, s, vt) = linalg::svd(tensor.clone());
+
+        let output = (u * s).matmul(vt);
+        output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_singular_values() {
+        let tensor = TestTensor::<2>::from([[3.0, 0.0], [4.0, 5.0]]);
+
+        let (u, s, vt) = tensor.svd();
+
+        s.into_data()
+            .assert_approx_eq(&TensorData::from([[6.7082, 2.2361]]), 3);
+        u.clone()
+            .transpose()
+            .matmul(u)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+        vt.clone()
+            .matmul(vt.transpose())
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_tall_matrix() {
+        let tensor = TestTensor::<2>::from([
+            [1.0, 2.0, 0.5],
+            [-1.0, 0.0, 3.0],
+            [2.0, 1.0, -2.0],
+            [0.0, 4.0, 1.0],
+        ]);
+
+        let (u, s, vt) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [4, 3]);
+        assert_eq!(s.dims(), [1, 3]);
+        assert_eq!(vt.dims(), [3, 3]);
+        assert_reconstructs(tensor);
+    }
+
+    #[test]
+    fn should_support_wide_matrix() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 0.5, -1.0], [0.0, 3.0, 2.0, 1.0]]);
+
+        let (u, s, vt) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [2, 2]);
+        assert_eq!(s.dims(), [1, 2]);
+        assert_eq!(vt.dims(), [2, 4]);
+        assert_reconstructs(tensor);
+    }
+
+    #[test]
+    fn should_support_batches() {
+        let tensor = TestTensor::<3>::from([
+            [[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]],
+            [[1.0, 1.0, 1.0], [1.0, 2.0, 3.0], [1.0, 3.0, 6.0]],
+        ]);
+
+        let (_, s, _) = linalg::svd(tensor.clone());
+
+        let s = s.into_data().to_vec::<f32>().unwrap();
+        assert!(s[0] >= s[1] && s[1] >= s[2]);
+        assert!(s[3] >= s[4] && s[4] >= s[5]);
+        assert_reconstructs(tensor);
+    }
+
+    #[test]
+    fn should_support_rank_deficient_matrix() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+
+        let (_, s, _) = linalg::svd(tensor.clone());
+
+        s.into_data()
+            .assert_approx_eq(&TensorData::from([[8.3667, 0.0]]), 3);
+        assert_reconstructs(tensor);
+    }
+
+    #[test]
+    fn should_match_the_reference_decomposition() {
+        let device = Default::default();
+        for dims in [[2, 5, 3], [2, 3, 5]] {
+            let tensor = TestTensor::<3>::random(dims, Distribution::Default, &device);
+
+            let (u, s, vt) = linalg::svd(tensor.clone());
+            let (_, expected, _) = linalg::jacobi_svd(tensor.clone());
+
+            s.clone()
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+            (u * s)
+                .matmul(vt)
+                .into_data()
+                .assert_approx_eq(&tensor.into_data(), 3);
+        }
+    }
+}
@@ -1,7 +1,9 @@
 mod activation;
 mod clone_invariance;
+mod linalg;
 mod module;
 mod ops;
+mod sparse;
 mod stats;
 
 #[allow(missing_docs)]
@@ -20,6 +22,20 @@ macro_rules! testgen_all {
         burn_tensor::testgen_silu!();
         burn_tensor::testgen_tanh_activation!();
 
+        // test linalg
+        burn_tensor::testgen_linalg_cholesky!();
+        burn_tensor::testgen_linalg_det!();
+        burn_tensor::testgen_linalg_eigh!();
+        burn_tensor::testgen_linalg_kron!();
+        burn_tensor::testgen_linalg_lstsq!();
+        burn_tensor::testgen_linalg_qr!();
+        burn_tensor::testgen_linalg_solve!();
+        burn_tensor::testgen_linalg_svd!();
+
+        // test sparse
+        burn_tensor::testgen_sparse_coo!();
+        burn_tensor::testgen_sparse_csr!();
+
         // test module
         burn_tensor::testgen_module_forward!();
         burn_tensor::testgen_module_conv1d!();
@@ -34,8 +50,21 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
         burn_tensor::testgen_module_nearest_interpolate!();
+        burn_tensor::testgen_module_grid_sample!();
+        burn_tensor::testgen_module_affine_grid!();
+        burn_tensor::testgen_module_roi_align!();
+        burn_tensor::testgen_module_roi_pool!();
+        burn_tensor::testgen_module_nms!();
+        burn_tensor::testgen_module_pixel_shuffle!();
+        burn_tensor::testgen_module_morphology!();
+        burn_tensor::testgen_module_resample!();
+        burn_tensor::testgen_module_box_iou!();
+        burn_tensor::testgen_module_attention!();
+        burn_tensor::testgen_module_selective_scan!();
+        burn_tensor::testgen_module_dequantize_matmul!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_interpolate3d!();
 
         // test ops
         burn_tensor::testgen_add!();
@@ -45,6 +74,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_arg!();
         burn_tensor::testgen_cast!();
         burn_tensor::testgen_cat!();
+        burn_tensor::testgen_checksum!();
         burn_tensor::testgen_chunk!();
         burn_tensor::testgen_clamp!();
         burn_tensor::testgen_close!();
@@ -52,6 +82,11 @@ macro_rules! testgen_all {
         burn_tensor::testgen_create_like!();
         burn_tensor::testgen_div!();
         burn_tensor::testgen_erf!();
+        burn_tensor::testgen_atan2!();
+        burn_tensor::testgen_special!();
+        burn_tensor::testgen_multinomial!();
+        burn_tensor::testgen_quantize!();
+        burn_tensor::testgen_pack_bits!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
         burn_tensor::testgen_full!();
@@ -88,6 +123,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_any!();
         burn_tensor::testgen_all_op!();
         burn_tensor::testgen_permute!();
+        burn_tensor::testgen_mode!();
         burn_tensor::testgen_movedim!();
         burn_tensor::testgen_flip!();
         burn_tensor::testgen_bool!();
@@ -99,11 +135,29 @@ macro_rules! testgen_all {
         burn_tensor::testgen_topk!();
         burn_tensor::testgen_remainder!();
         burn_tensor::testgen_cartesian_grid!();
+        burn_tensor::testgen_einsum!();
+        burn_tensor::testgen_fft!();
+        burn_tensor::testgen_stft!();
+        burn_tensor::testgen_complex!();
+        burn_tensor::testgen_searchsorted!();
+        burn_tensor::testgen_unique!();
+        burn_tensor::testgen_bincount!();
+        burn_tensor::testgen_cumulative!();
+        burn_tensor::testgen_logsumexp!();
+        burn_tensor::testgen_roll!();
+        burn_tensor::testgen_diff!();
+        burn_tensor::testgen_tensordot!();
+        burn_tensor::testgen_segment!();
+        burn_tensor::testgen_index_reduce!();
+        burn_tensor::testgen_ragged!();
+        burn_tensor::testgen_dim_names!();
+        burn_tensor::testgen_einops!();
 
         // test stats
         burn_tensor::testgen_var!();
         burn_tensor::testgen_cov!();
         burn_tensor::testgen_eye!();
+        burn_tensor::testgen_histogram!();
         burn_tensor::testgen_display!();
 
         // test clone invariance
@@ -0,0 +1,79 @@
+#[burn_tensor_testgen::testgen(module_affine_grid)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid, grid_sample};
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_affine_grid() {
+        let grid = affine_grid(theta(), [2, 1, 2, 3], false);
+
+        grid.into_data().assert_approx_eq(
+            &TensorData::from([
+                [
+                    [[-0.6667, -0.5], [0.0, -0.5], [0.6667, -0.5]],
+                    [[-0.6667, 0.5], [0.0, 0.5], [0.6667, 0.5]],
+                ],
+                [
+                    [[0.1667, -0.6833], [0.5, -0.35], [0.8333, -0.0167]],
+                    [[-0.3333, -0.1833], [0.0, 0.15], [0.3333, 0.4833]],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_affine_grid_align_corners() {
+        let grid = affine_grid(theta(), [2, 1, 2, 3], true);
+
+        grid.into_data().assert_approx_eq(
+            &TensorData::from([
+                [
+                    [[-1.0, -1.0], [0.0, -1.0], [1.0, -1.0]],
+                    [[-1.0, 1.0], [0.0, 1.0], [1.0, 1.0]],
+                ],
+                [
+                    [[0.25, -1.1], [0.75, -0.6], [1.25, -0.1]],
+                    [[-0.75, -0.1], [-0.25, 0.4], [0.25, 0.9]],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_identity_affine_grid_should_sample_the_input() {
+        let x = TestTensor::<4>::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let theta = TestTensor::<3>::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        for align_corners in [false, true] {
+            let grid = affine_grid(theta.clone(), [1, 1, 2, 3], align_corners);
+            let options = GridSampleOptions::new(
+                GridSampleMode::Bilinear,
+                GridSamplePaddingMode::Zeros,
+                align_corners,
+            );
+
+            grid_sample(x.clone(), grid, options)
+                .into_data()
+                .assert_approx_eq(&x.to_data(), 3);
+        }
+    }
+
+    #[test]
+    #[should_panic = "The affine matrices don't have the expected shape."]
+    fn test_affine_grid_should_check_theta_shape() {
+        let theta = TestTensor::<3>::zeros([2, 3, 3], &Default::default());
+
+        affine_grid(theta, [2, 1, 4, 4], false);
+    }
+
+    fn theta() -> TestTensor<3> {
+        TestTensor::from([
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            [[0.5, -0.5, 0.25], [0.5, 0.5, -0.1]],
+        ])
+    }
+}
@@ -0,0 +1,168 @@
+#[burn_tensor_testgen::testgen(module_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{attention, window_attention};
+    use burn_tensor::ops::{AttentionOptions, WindowAttentionOptions};
+    use burn_tensor::{Shape, TensorData};
+
+    #[test]
+    fn test_attention() {
+        let (query, key, value) = inputs();
+
+        let output = attention(
+            query,
+            key,
+            value,
+            None,
+            AttentionOptions::new(0.5, -1.0e4, false),
+        );
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[[1.08174, 0.72643], [1.16268, 0.88071]]]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_attention_mask_quiet_softmax() {
+        let (query, key, value) = inputs();
+        let mask = TestTensorBool::from([[[[false, true, false], [true, true, true]]]]);
+
+        let output = attention(
+            query,
+            key,
+            value,
+            Some(mask),
+            AttentionOptions::new(0.5, -1.0e4, true),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[0.33333, 0.83333], [1.0, 0.375]]]]), 4);
+    }
+
+    #[test]
+    fn test_attention_long_sequence() {
+        let (query, key, value) = long_inputs();
+
+        let output = attention(
+            query,
+            key,
+            value,
+            None,
+            AttentionOptions::new(0.5, -1.0e4, false),
+        );
+
+        assert_eq!(output.shape(), Shape::new([1, 2, 3, 3]));
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[
+                [
+                    [1.00283, 1.00027, 0.99771],
+                    [0.99033, 0.99028, 0.99034],
+                    [1.01358, 1.01081, 1.00794],
+                ],
+                [
+                    [1.06889, 1.06649, 1.06343],
+                    [1.06992, 1.06725, 1.06392],
+                    [1.03221, 1.02895, 1.02541],
+                ],
+            ]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_window_attention_causal() {
+        let query = TestTensor::from([[[[1.0], [1.0], [1.0]]]]);
+        let key = TestTensor::from([[[[0.0], [0.0], [0.0]]]]);
+        let value = TestTensor::from([[[[1.0], [2.0], [3.0]]]]);
+
+        let output = window_attention(
+            query,
+            key,
+            value,
+            None,
+            WindowAttentionOptions::new(1.0, 1, 0, false),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[1.0], [1.5], [2.5]]]]), 4);
+    }
+
+    #[test]
+    fn test_window_attention_global_should_match_masked_attention() {
+        let (query, key, value) = long_inputs();
+        // The queries are the last 3 of the 40 positions.
+        let global = TestTensorBool::from([[
+            false, true, false, false, false, false, false, false, false, false, false, false,
+            false, false, false, false, false, false, false, false, false, false, false, false,
+            false, false, false, false, false, false, false, false, false, false, false, false,
+            false, false, true, false,
+        ]]);
+        // Each query attends to the 5 previous positions, itself, the next one and the global
+        // keys at positions 1 and 38, the query at position 38 attending to every key.
+        let mask = TestTensorInt::arange(0..40, &Default::default())
+            .reshape([1, 1, 1, 40])
+            .expand([1, 2, 3, 40]);
+        let starts = TestTensorInt::<4>::from([[[[32], [0], [34]]]]).expand([1, 2, 3, 40]);
+        let ends = TestTensorInt::<4>::from([[[[39], [40], [40]]]]).expand([1, 2, 3, 40]);
+        let mask = mask
+            .clone()
+            .lower(starts)
+            .int()
+            .add(mask.clone().greater_equal(ends).int())
+            .mul(mask.clone().not_equal_elem(1).int())
+            .mul(mask.not_equal_elem(38).int())
+            .greater_elem(0);
+
+        let output = window_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            Some(global),
+            WindowAttentionOptions::new(0.5, 5, 1, false),
+        );
+        let expected = attention(
+            query,
+            key,
+            value,
+            Some(mask),
+            AttentionOptions::new(0.5, -1.0e9, false),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    fn long_inputs() -> (TestTensor<4>, TestTensor<4>, TestTensor<4>) {
+        let device = Default::default();
+        let query = TestTensorInt::arange(0..24, &device)
+            .float()
+            .mul_scalar(0.3)
+            .sin()
+            .reshape([1, 2, 3, 4]);
+        let key = TestTensorInt::arange(0..320, &device)
+            .float()
+            .mul_scalar(0.2)
+            .cos()
+            .reshape([1, 2, 40, 4]);
+        let value = TestTensorInt::arange(0..240, &device)
+            .float()
+            .mul_scalar(0.1)
+            .sin()
+            .add_scalar(1.0)
+            .reshape([1, 2, 40, 3]);
+
+        (query, key, value)
+    }
+
+    fn inputs() -> (TestTensor<4>, TestTensor<4>, TestTensor<4>) {
+        let query = TestTensor::from([[[[1.0, 0.0], [0.5, -1.0]]]]);
+        let key = TestTensor::from([[[[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]]]);
+        let value = TestTensor::from([[[[1.0, 2.0], [3.0, -1.0], [0.0, 0.5]]]]);
+
+        (query, key, value)
+    }
+}
@@ -0,0 +1,77 @@
+#[burn_tensor_testgen::testgen(module_box_iou)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{box_iou, paired_box_iou};
+    use burn_tensor::ops::BoxIouMode;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_box_iou() {
+        let (lhs, rhs) = boxes();
+
+        let iou = box_iou(lhs, rhs, BoxIouMode::Iou);
+
+        iou.into_data().assert_approx_eq(
+            &TensorData::from([[0.333333, 0.0, 1.0], [0.25, 0.0, 0.111111]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_generalized_box_iou() {
+        let (lhs, rhs) = boxes();
+
+        let iou = box_iou(lhs, rhs, BoxIouMode::Generalized);
+
+        iou.into_data().assert_approx_eq(
+            &TensorData::from([[0.333333, -0.375, 1.0], [0.138889, -0.416667, -0.138889]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_distance_box_iou() {
+        let (lhs, rhs) = boxes();
+
+        let iou = box_iou(lhs, rhs, BoxIouMode::Distance);
+
+        iou.into_data().assert_approx_eq(
+            &TensorData::from([[0.25641, -0.325, 1.0], [0.180556, -0.25, -0.018889]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_complete_box_iou() {
+        let (lhs, rhs) = boxes();
+
+        let iou = box_iou(lhs, rhs, BoxIouMode::Complete);
+
+        iou.into_data().assert_approx_eq(
+            &TensorData::from([[0.25641, -0.325, 1.0], [0.18023, -0.250246, -0.019165]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_paired_box_iou() {
+        let lhs = TestTensor::from([[0.0, 0.0, 2.0, 2.0], [1.0, 1.0, 4.0, 3.0]]);
+        let rhs = TestTensor::from([[1.0, 0.5, 3.0, 2.5], [0.0, 0.0, 2.0, 2.0]]);
+
+        let iou = paired_box_iou(lhs, rhs, BoxIouMode::Generalized);
+
+        iou.into_data()
+            .assert_approx_eq(&TensorData::from([0.097436, -0.138889]), 4);
+    }
+
+    fn boxes() -> (TestTensor<2>, TestTensor<2>) {
+        let lhs = TestTensor::from([[0.0, 0.0, 2.0, 2.0], [1.0, 1.0, 4.0, 3.0]]);
+        let rhs = TestTensor::from([
+            [1.0, 0.0, 3.0, 2.0],
+            [0.0, 3.0, 1.0, 4.0],
+            [0.0, 0.0, 2.0, 2.0],
+        ]);
+
+        (lhs, rhs)
+    }
+}
@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(module_dequantize_matmul)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::dequantize_matmul;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_dequantize_matmul() {
+        let x = TestTensor::<3>::from([[[1.0, 2.0, -1.0], [0.5, -0.5, 1.0]]]);
+        let weight = TestTensorInt::<2>::from([[127, -64], [0, 32], [-8, 7]]);
+        let scale = TestTensor::<1>::from([0.01, 0.1]);
+
+        let output = dequantize_matmul(x, weight, scale);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[1.35, -0.7], [0.555, -4.1]]]), 3);
+    }
+
+    #[test]
+    fn test_dequantize_matmul_transposed_weight() {
+        let x = TestTensor::<3>::from([[[1.0, 2.0, -1.0]], [[0.5, -0.5, 1.0]]]);
+        let weight = TestTensorInt::<2>::from([[127, 0, -8], [-64, 32, 7]]).transpose();
+        let scale = TestTensor::<1>::from([0.01, 0.1]);
+
+        let output = dequantize_matmul(x, weight, scale);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[1.35, -0.7]], [[0.555, -4.1]]]), 3);
+    }
+}
@@ -0,0 +1,140 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample;
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros() {
+        assert_grid_sample(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+            [[0.25, 4.85, 1.47], [0.0, 5.0, 4.1875]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros_align_corners() {
+        assert_grid_sample(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            true,
+            [[1.0, 4.9, 5.52], [4.02, 5.0, 8.25]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_border() {
+        assert_grid_sample(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            false,
+            [[1.0, 4.85, 7.35], [7.0, 5.0, 8.375]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_border_align_corners() {
+        assert_grid_sample(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            true,
+            [[1.0, 4.9, 6.9], [6.7, 5.0, 8.25]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection() {
+        assert_grid_sample(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Reflection,
+            false,
+            [[1.0, 4.85, 7.35], [7.1, 5.0, 8.375]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection_align_corners() {
+        assert_grid_sample(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Reflection,
+            true,
+            [[1.0, 4.9, 6.7], [7.1, 5.0, 8.25]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_zeros() {
+        assert_grid_sample(
+            GridSampleMode::Nearest,
+            GridSamplePaddingMode::Zeros,
+            false,
+            [[1.0, 6.0, 0.0], [0.0, 5.0, 8.0]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_reflection_align_corners() {
+        assert_grid_sample(
+            GridSampleMode::Nearest,
+            GridSamplePaddingMode::Reflection,
+            true,
+            [[1.0, 6.0, 6.0], [7.0, 5.0, 8.0]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_each_sample_and_channel() {
+        let device = Default::default();
+        let x = TestTensor::<4>::from([
+            [[[1.0, 2.0], [3.0, 4.0]], [[-1.0, -2.0], [-3.0, -4.0]]],
+            [[[5.0, 6.0], [7.0, 8.0]], [[0.0, 0.0], [0.0, 1.0]]],
+        ]);
+        // Samples the center and the bottom right corner of each input.
+        let grid = TestTensor::<4>::from_data(
+            [[[[0.0, 0.0], [0.5, 0.5]]], [[[0.0, 0.0], [0.5, 0.5]]]],
+            &device,
+        );
+
+        let output = grid_sample(x, grid, GridSampleOptions::default());
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([
+                [[[2.5, 4.0]], [[-2.5, -4.0]]],
+                [[[6.5, 8.0]], [[0.25, 1.0]]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic = "The grid doesn't have the expected shape."]
+    fn test_grid_sample_should_check_grid_shape() {
+        let x = TestTensor::<4>::zeros([2, 1, 3, 3], &Default::default());
+        let grid = TestTensor::<4>::zeros([2, 4, 4, 3], &Default::default());
+
+        grid_sample(x, grid, GridSampleOptions::default());
+    }
+
+    fn assert_grid_sample<const N: usize>(
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+        expected: [[f32; N]; 2],
+    ) {
+        let x = TestTensor::<4>::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]]);
+        let grid = TestTensor::<4>::from([[
+            [[-1.0, -1.0], [0.5, -0.2], [1.2, 0.3]],
+            [[-1.4, 0.9], [0.0, 0.0], [0.25, 1.0]],
+        ]]);
+        let options = GridSampleOptions::new(mode, padding_mode, align_corners);
+
+        let output = grid_sample(x, grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[expected]]), 3);
+    }
+}
@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(module_interpolate3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{interpolate, interpolate3d};
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_trilinear_interpolation() {
+        let output = interpolate3d(
+            input(),
+            [3, 3, 2],
+            InterpolateOptions::new(InterpolateMode::Bilinear),
+        );
+
+        output.reshape([3, 3, 2]).into_data().assert_approx_eq(
+            &TensorData::from([
+                [[0.0, 0.4], [0.45, 1.45], [0.9, 2.5]],
+                [[1.8, 3.4], [3.15, 5.35], [4.5, 7.3]],
+                [[3.6, 6.4], [5.85, 9.25], [8.1, 12.1]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_nearest_interpolation() {
+        let output = interpolate3d(
+            input(),
+            [3, 1, 4],
+            InterpolateOptions::new(InterpolateMode::Nearest),
+        );
+
+        output.reshape([3, 4]).into_data().assert_approx_eq(
+            &TensorData::from([
+                [0.0, 0.0, 0.1, 0.4],
+                [0.0, 0.0, 0.1, 0.4],
+                [3.6, 3.6, 4.9, 6.4],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolation_of_each_slice_without_resizing_the_depth() {
+        let device = Default::default();
+        let x = TestTensorInt::<1>::arange(0..60, &device)
+            .float()
+            .sin()
+            .reshape([1, 2, 2, 3, 5]);
+
+        for mode in [
+            InterpolateMode::Nearest,
+            InterpolateMode::Bilinear,
+            InterpolateMode::Bicubic,
+        ] {
+            let output = interpolate3d(x.clone(), [2, 4, 7], InterpolateOptions::new(mode.clone()));
+            let expected = interpolate(
+                x.clone().reshape([1, 4, 3, 5]),
+                [4, 7],
+                InterpolateOptions::new(mode),
+            );
+
+            output
+                .reshape([1, 4, 4, 7])
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+        }
+    }
+
+    /// The squares of `0..12` divided by 10, with the shape `[1, 1, 2, 2, 3]`.
+    fn input() -> TestTensor<5> {
+        let device = Default::default();
+
+        TestTensorInt::<1>::arange(0..12, &device)
+            .float()
+            .powf_scalar(2.0)
+            .div_scalar(10.0)
+            .reshape([1, 1, 2, 2, 3])
+    }
+}
@@ -1,15 +1,28 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod affine_grid;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
 mod bicubic_interpolate;
 mod bilinear_interpolate;
+mod box_iou;
 mod conv1d;
 mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod dequantize_matmul;
 mod forward;
+mod grid_sample;
+mod interpolate3d;
 mod maxpool1d;
 mod maxpool2d;
+mod morphology;
 mod nearest_interpolate;
+mod nms;
+mod pixel_shuffle;
+mod resample;
+mod roi_align;
+mod roi_pool;
+mod selective_scan;
 mod unfold4d;
@@ -0,0 +1,143 @@
+#[burn_tensor_testgen::testgen(module_morphology)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{closing, dilate, erode, opening};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_dilate_cross() {
+        let output = dilate(image(), cross());
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[
+                [5.0, 5.0, 5.0, 2.0],
+                [3.0, 6.0, 4.0, 7.0],
+                [6.0, 6.0, 7.0, 7.0],
+            ]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_erode_cross() {
+        let output = erode(image(), cross());
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [2.0, 0.0, 0.0, 0.0],
+            ]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_dilate_asymmetric_element() {
+        let kernel = TestTensorBool::<2>::from([[true, true]]);
+
+        let output = dilate(image(), kernel);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[
+                [5.0, 5.0, 2.0, 0.0],
+                [3.0, 4.0, 4.0, 1.0],
+                [6.0, 6.0, 7.0, 7.0],
+            ]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_opening_should_remove_small_details() {
+        let x = TestTensor::from([[[
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+        ]]]);
+
+        let output = opening(x, square());
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+                [0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+                [0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            ]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_closing_should_fill_small_holes() {
+        let x = TestTensor::from([[[
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        ]]]);
+
+        let output = closing(x, square());
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            ]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_dilate_batch_and_channels() {
+        let x = TestTensor::from([
+            [[[1.0, 0.0, 0.0]], [[0.0, 0.0, 2.0]]],
+            [[[0.0, 3.0, 0.0]], [[4.0, 0.0, 0.0]]],
+        ]);
+        let kernel = TestTensorBool::<2>::from([[true, false, true]]);
+
+        let output = dilate(x, kernel);
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [[[0.0, 1.0, 0.0]], [[0.0, 2.0, 0.0]]],
+                [[[3.0, 0.0, 3.0]], [[0.0, 4.0, 0.0]]],
+            ]),
+            false,
+        );
+    }
+
+    fn image() -> TestTensor<4> {
+        TestTensor::from([[[
+            [1.0, 5.0, 2.0, 0.0],
+            [3.0, 0.0, 4.0, 1.0],
+            [2.0, 6.0, 0.0, 7.0],
+        ]]])
+    }
+
+    fn cross() -> TestTensorBool<2> {
+        TestTensorBool::from([
+            [false, true, false],
+            [true, true, true],
+            [false, true, false],
+        ])
+    }
+
+    fn square() -> TestTensorBool<2> {
+        TestTensorBool::from([[true; 3]; 3])
+    }
+}
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(module_nms)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{batched_nms, nms};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_nms() {
+        let (boxes, scores) = detections();
+
+        let kept = nms(boxes, scores, 0.5);
+
+        kept.into_data()
+            .assert_eq(&TensorData::from([3, 2, 5]), false);
+    }
+
+    #[test]
+    fn test_nms_high_threshold() {
+        let (boxes, scores) = detections();
+
+        let kept = nms(boxes, scores, 0.95);
+
+        kept.into_data()
+            .assert_eq(&TensorData::from([3, 0, 1, 2, 4, 5]), false);
+    }
+
+    #[test]
+    fn test_batched_nms() {
+        let (boxes, scores) = detections();
+        let idxs = TestTensorInt::from([0, 1, 0, 0, 1, 1]);
+
+        let kept = batched_nms(boxes, scores, idxs, 0.5);
+
+        kept.into_data()
+            .assert_eq(&TensorData::from([3, 1, 2, 4, 5]), false);
+    }
+
+    fn detections() -> (TestTensor<2>, TestTensor<1>) {
+        let boxes = TestTensor::from([
+            [0.0, 0.0, 10.0, 10.0],
+            [1.0, 1.0, 11.0, 11.0],
+            [20.0, 20.0, 30.0, 30.0],
+            [0.0, 0.0, 10.0, 9.0],
+            [21.0, 19.0, 31.0, 29.0],
+            [50.0, 50.0, 60.0, 60.0],
+        ]);
+        let scores = TestTensor::from([0.9, 0.8, 0.7, 0.95, 0.6, 0.1]);
+
+        (boxes, scores)
+    }
+}
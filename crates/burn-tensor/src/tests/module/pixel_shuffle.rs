@@ -0,0 +1,104 @@
+#[burn_tensor_testgen::testgen(module_pixel_shuffle)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{pixel_shuffle, pixel_unshuffle};
+    use burn_tensor::{Shape, TensorData};
+
+    #[test]
+    fn test_pixel_shuffle() {
+        let x = TestTensorInt::arange(0..16, &Default::default())
+            .float()
+            .reshape([1, 4, 2, 2]);
+
+        let output = pixel_shuffle(x, 2);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[
+                [0.0, 4.0, 1.0, 5.0],
+                [8.0, 12.0, 9.0, 13.0],
+                [2.0, 6.0, 3.0, 7.0],
+                [10.0, 14.0, 11.0, 15.0],
+            ]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_pixel_shuffle_multiple_channels() {
+        let x = TestTensorInt::arange(0..16, &Default::default())
+            .float()
+            .reshape([2, 8, 1, 1]);
+
+        let output = pixel_shuffle(x, 2);
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]],
+                [[[8.0, 9.0], [10.0, 11.0]], [[12.0, 13.0], [14.0, 15.0]]],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_pixel_unshuffle() {
+        let x = TestTensor::from([[[
+            [0.0, 4.0, 1.0, 5.0],
+            [8.0, 12.0, 9.0, 13.0],
+            [2.0, 6.0, 3.0, 7.0],
+            [10.0, 14.0, 11.0, 15.0],
+        ]]]);
+
+        let output = pixel_unshuffle(x, 2);
+
+        output.into_data().assert_eq(
+            &TestTensorInt::arange(0..16, &Default::default())
+                .float()
+                .reshape([1, 4, 2, 2])
+                .into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_pixel_shuffle_round_trip() {
+        let x = TestTensorInt::arange(0..216, &Default::default())
+            .float()
+            .reshape([2, 12, 3, 3]);
+
+        let output = pixel_unshuffle(pixel_shuffle(x.clone(), 2), 2);
+
+        assert_eq!(output.shape(), Shape::new([2, 12, 3, 3]));
+        output.into_data().assert_eq(&x.into_data(), false);
+    }
+
+    #[test]
+    fn test_pixel_shuffle_non_contiguous() {
+        let device = Default::default();
+        let x = TestTensorInt::arange(0..48, &device)
+            .float()
+            .reshape([1, 4, 3, 4])
+            .swap_dims(2, 3);
+        let x_contiguous = TestTensor::from_data(x.to_data(), &device);
+
+        let output = pixel_shuffle(x, 2);
+        let expected = pixel_shuffle(x_contiguous, 2);
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn test_pixel_unshuffle_non_contiguous() {
+        let device = Default::default();
+        let x = TestTensorInt::arange(0..32, &device)
+            .float()
+            .reshape([1, 2, 4, 4])
+            .swap_dims(2, 3);
+        let x_contiguous = TestTensor::from_data(x.to_data(), &device);
+
+        let output = pixel_unshuffle(x, 2);
+        let expected = pixel_unshuffle(x_contiguous, 2);
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+}
@@ -0,0 +1,75 @@
+#[burn_tensor_testgen::testgen(module_resample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::resample;
+    use burn_tensor::ops::ResampleOptions;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_resample_downsample() {
+        let output = resample(signal(), 2, 1, ResampleOptions::default());
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[-0.136656, 0.954971, 1.620691, -0.255164, 0.543843]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_resample_upsample() {
+        let output = resample(signal(), 16000, 32000, ResampleOptions::default());
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[
+                0.946799, -1.186672, -1.93958, 0.469877, 2.941019, 2.384584, 0.55035, 0.579204,
+                1.961962, 2.233143, 1.025586, -0.310286, -1.016272, -0.940882, 0.011957, 1.225065,
+                1.488131, 0.53201, -0.487177, -0.54247,
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_resample_rational_rate() {
+        let output = resample(signal(), 3, 2, ResampleOptions::new(4, 0.99));
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[
+                -0.133575, 0.430648, 1.728777, 1.383473, -0.690694, 0.781801, 0.075374,
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_resample_batch() {
+        let x = TestTensor::from([
+            [0.5, 1.0, -1.0, 2.0, 0.0, 1.5],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        ]);
+
+        let output = resample(x, 4, 6, ResampleOptions::new(3, 0.9));
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([
+                [
+                    0.564611, 1.068235, 0.106007, -0.67874, 1.019524, 1.464589, 0.307735, 0.928646,
+                    1.14785,
+                ],
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_resample_same_rate() {
+        let output = resample(signal(), 8000, 8000, ResampleOptions::default());
+
+        output.into_data().assert_eq(&signal().into_data(), false);
+    }
+
+    fn signal() -> TestTensor<2> {
+        TestTensor::from([[1.0, -2.0, 3.0, 0.5, 2.0, 1.0, -1.0, 0.0, 1.5, -0.5]])
+    }
+}
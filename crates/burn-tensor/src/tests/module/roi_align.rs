@@ -0,0 +1,84 @@
+#[burn_tensor_testgen::testgen(module_roi_align)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::roi_align;
+    use burn_tensor::ops::RoiAlignOptions;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn test_roi_align_aligned() {
+        let output = roi_align(
+            input(),
+            boxes(),
+            RoiAlignOptions::new([2, 2], 1.0, Some(2), true),
+        );
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([
+                [
+                    [[2.625, 3.875], [6.625, 7.875]],
+                    [[18.625, 19.875], [22.625, 23.875]],
+                ],
+                [
+                    [[0.9092, 1.9688], [2.6768, 1.1289]],
+                    [[0.9092, 1.9688], [2.6768, 1.1289]],
+                ],
+                [
+                    [[10.375, 12.625], [6.1875, 7.3125]],
+                    [[26.375, 28.625], [14.1875, 15.3125]],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_roi_align_adaptive_sampling_ratio() {
+        let output = roi_align(
+            input(),
+            boxes(),
+            RoiAlignOptions::new([2, 2], 0.5, None, false),
+        );
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([
+                [
+                    [[2.5625, 3.1875], [4.5625, 5.1875]],
+                    [[18.5625, 19.1875], [20.5625, 21.1875]],
+                ],
+                [
+                    [[0.7852, 1.0], [1.707, 1.0039]],
+                    [[0.7852, 1.0], [1.707, 1.0039]],
+                ],
+                [
+                    [[6.3125, 7.75], [10.3125, 11.75]],
+                    [[22.3125, 23.75], [26.3125, 27.75]],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    /// Two samples of shape `[2, 4, 4]`.
+    fn input() -> TestTensor<4> {
+        let device = Default::default();
+        let first = TestTensorInt::<1>::arange(0..32, &device)
+            .float()
+            .reshape([1, 2, 4, 4]);
+        let second = TestTensorInt::<1>::arange(0..16, &device)
+            .remainder_scalar(5)
+            .float()
+            .reshape([1, 1, 4, 4])
+            .repeat(1, 2);
+
+        Tensor::cat(vec![first, second], 0)
+    }
+
+    fn boxes() -> TestTensor<2> {
+        TestTensor::from([
+            [0.0, 0.5, 0.5, 3.0, 2.5],
+            [1.0, 1.0, 0.0, 3.5, 3.5],
+            [0.0, -1.0, 2.0, 5.0, 6.0],
+        ])
+    }
+}
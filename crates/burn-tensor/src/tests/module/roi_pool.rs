@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(module_roi_pool)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::roi_pool;
+    use burn_tensor::ops::RoiPoolOptions;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn test_roi_pool() {
+        let boxes = TestTensor::from([
+            [0.0, 0.5, 0.5, 3.0, 2.5],
+            [1.0, 1.0, 0.0, 3.5, 3.5],
+            [0.0, -1.0, 2.0, 5.0, 6.0],
+        ]);
+
+        let output = roi_pool(input(), boxes, RoiPoolOptions::new([2, 2], 1.0));
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [[[10.0, 11.0], [14.0, 15.0]], [[26.0, 27.0], [30.0, 31.0]]],
+                [[[4.0, 3.0], [4.0, 1.0]], [[4.0, 3.0], [4.0, 1.0]]],
+                [[[14.0, 15.0], [0.0, 0.0]], [[30.0, 31.0], [0.0, 0.0]]],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_roi_pool_spatial_scale() {
+        let boxes = TestTensor::from([[0.0, 0.0, 0.0, 6.0, 6.0], [1.0, 2.0, 2.0, 3.0, 7.0]]);
+
+        let output = roi_pool(input(), boxes, RoiPoolOptions::new([3, 2], 0.5));
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [
+                    [[5.0, 7.0], [9.0, 11.0], [13.0, 15.0]],
+                    [[21.0, 23.0], [25.0, 27.0], [29.0, 31.0]],
+                ],
+                [
+                    [[4.0, 1.0], [4.0, 4.0], [3.0, 4.0]],
+                    [[4.0, 1.0], [4.0, 4.0], [3.0, 4.0]],
+                ],
+            ]),
+            false,
+        );
+    }
+
+    /// Two samples of shape `[2, 4, 4]`.
+    fn input() -> TestTensor<4> {
+        let device = Default::default();
+        let first = TestTensorInt::<1>::arange(0..32, &device)
+            .float()
+            .reshape([1, 2, 4, 4]);
+        let second = TestTensorInt::<1>::arange(0..16, &device)
+            .remainder_scalar(5)
+            .float()
+            .reshape([1, 1, 4, 4])
+            .repeat(1, 2);
+
+        Tensor::cat(vec![first, second], 0)
+    }
+}
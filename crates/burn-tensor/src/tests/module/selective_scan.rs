@@ -0,0 +1,73 @@
+#[burn_tensor_testgen::testgen(module_selective_scan)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::selective_scan;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_selective_scan() {
+        let u = TestTensor::<3>::from([[[1.0, 2.0, -1.0], [0.5, -0.5, 1.0]]]);
+        let delta = TestTensor::<3>::from([[[0.1, 0.5, 1.0], [0.2, 0.3, 0.4]]]);
+        let a = TestTensor::<2>::from([[-1.0, -2.0], [-0.5, -1.5]]);
+        let b = TestTensor::<3>::from([[[1.0, 0.5, -1.0], [0.0, 1.0, 2.0]]]);
+        let c = TestTensor::<3>::from([[[1.0, -1.0, 0.5], [0.5, 1.0, 1.0]]]);
+
+        let output = selective_scan(u, delta, a, b, c);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[0.1, 0.43935, -1.26154], [0.1, -0.16107, 0.52221]]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_selective_scan_long_sequence() {
+        let [batch_size, channels, length, d_state] = [2, 3, 20, 4];
+        let device = Default::default();
+        let u = TestTensorInt::arange(0..(batch_size * channels * length) as i64, &device)
+            .float()
+            .mul_scalar(0.3)
+            .sin()
+            .reshape([batch_size, channels, length]);
+        let delta = TestTensorInt::arange(0..(batch_size * channels * length) as i64, &device)
+            .float()
+            .mul_scalar(0.2)
+            .cos()
+            .mul_scalar(0.4)
+            .add_scalar(0.5)
+            .reshape([batch_size, channels, length]);
+        let a = TestTensorInt::arange(0..(channels * d_state) as i64, &device)
+            .float()
+            .mul_scalar(-0.1)
+            .sub_scalar(0.5)
+            .reshape([channels, d_state]);
+        let b = TestTensorInt::arange(0..(batch_size * d_state * length) as i64, &device)
+            .float()
+            .mul_scalar(0.1)
+            .cos()
+            .reshape([batch_size, d_state, length]);
+        let c = TestTensorInt::arange(0..(batch_size * d_state * length) as i64, &device)
+            .float()
+            .mul_scalar(0.25)
+            .sin()
+            .reshape([batch_size, d_state, length]);
+
+        let output = selective_scan(u, delta, a, b, c);
+
+        output
+            .clone()
+            .sum()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([23.8962]), 2);
+        output
+            .narrow(2, length - 1, 1)
+            .into_data()
+            .assert_approx_eq(
+                &TensorData::from([
+                    [[-0.91602], [-1.33341], [-1.32392]],
+                    [[0.54222], [1.55264], [0.98891]],
+                ]),
+                3,
+            );
+    }
+}
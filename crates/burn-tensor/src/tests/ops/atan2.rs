@@ -0,0 +1,42 @@
+#[burn_tensor_testgen::testgen(atan2)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn should_support_atan2_in_every_quadrant() {
+        let device = Default::default();
+        let y = TestTensor::<1>::from_floats([1.0, 1.0, -1.0, -1.0, 0.0, 0.0, 2.0, 0.0], &device);
+        let x = TestTensor::<1>::from_floats([1.0, -1.0, -1.0, 1.0, 1.0, -1.0, 0.0, 0.0], &device);
+
+        let output = y.atan2(x);
+        let expected = TensorData::from([
+            FRAC_PI_4,
+            3.0 * FRAC_PI_4,
+            -3.0 * FRAC_PI_4,
+            -FRAC_PI_4,
+            0.0,
+            PI,
+            FRAC_PI_2,
+            0.0,
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_support_atan2_with_broadcasting() {
+        let device = Default::default();
+        let y = Tensor::<TestBackend, 2>::from_floats([[0.5], [-3.0]], &device);
+        let x = Tensor::<TestBackend, 2>::from_floats([[2.0, -0.25, 10.0]], &device);
+
+        let output = y.atan2(x);
+        let expected = TensorData::from([
+            [0.24497866, 2.03444394, 0.04995840],
+            [-0.98279372, -1.65393755, -0.29145679],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+}
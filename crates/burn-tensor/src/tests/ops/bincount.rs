@@ -0,0 +1,75 @@
+#[burn_tensor_testgen::testgen(bincount)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_bincount() {
+        let tensor = TestTensorInt::<1>::from([1, 3, 1, 0, 3, 3]);
+
+        let output = tensor.bincount(4);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 0, 3]), false);
+    }
+
+    #[test]
+    fn should_support_bincount_with_empty_bins() {
+        let tensor = TestTensorInt::<1>::from([2, 0]);
+
+        let output = tensor.bincount(5);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 0, 1, 0, 0]), false);
+    }
+
+    #[test]
+    fn should_support_bincount_weighted() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([0, 2, 2, 1], &device);
+        let weights = TestTensor::<1>::from_floats([0.5, 1.0, 2.5, -1.0], &device);
+
+        let output = tensor.bincount_weighted(weights, 3);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0.5, -1.0, 3.5]), false);
+    }
+
+    #[test]
+    fn should_grow_the_bins_to_the_largest_value() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1, 4, 0, 3, 1], &device);
+        let weights = TestTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0, 5.0], &device);
+
+        let counts = tensor.clone().bincount(3);
+        let sums = tensor.bincount_weighted(weights, 0);
+
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 0, 1, 1]), false);
+        sums.into_data()
+            .assert_eq(&TensorData::from([3.0, 6.0, 0.0, 4.0, 2.0]), false);
+    }
+
+    #[test]
+    #[should_panic = "non-negative"]
+    fn should_panic_when_counting_negative_values() {
+        let tensor = TestTensorInt::<1>::from([1, -1, 0]);
+
+        let _ = tensor.bincount(3);
+    }
+
+    #[test]
+    fn should_support_bincount_of_empty_tensor() {
+        let tensor = TestTensorInt::<1>::empty([0], &Default::default());
+
+        let output = tensor.bincount(3);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 0]), false);
+    }
+}
@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(checksum)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_match_the_checksum_of_the_data() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        assert_eq!(tensor.checksum(), tensor.to_data().checksum());
+    }
+
+    #[test]
+    fn should_be_deterministic() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let rhs = TestTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device).reshape([2, 2]);
+
+        assert_eq!(lhs.checksum(), rhs.checksum());
+    }
+
+    #[test]
+    fn should_detect_a_single_changed_value() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let changed = tensor.clone().slice_assign(
+            [1..2, 1..2],
+            TestTensor::<2>::from([[4.0 + f32::EPSILON * 4.0]]),
+        );
+
+        assert_ne!(tensor.checksum(), changed.checksum());
+    }
+
+    #[test]
+    fn should_depend_on_the_shape() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        assert_ne!(tensor.checksum(), tensor.clone().reshape([4, 1]).checksum());
+    }
+
+    #[test]
+    fn should_support_int_and_bool_tensors() {
+        let tensor = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+        let mask = tensor.clone().greater_elem(2);
+
+        assert_eq!(tensor.checksum(), tensor.to_data().checksum());
+        assert_eq!(mask.checksum(), mask.to_data().checksum());
+        assert_ne!(mask.checksum(), tensor.greater_elem(1).checksum());
+    }
+}
@@ -0,0 +1,154 @@
+#[burn_tensor_testgen::testgen(complex)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, Complex32, Tensor, TensorData};
+
+    type TestTensorComplex<const D: usize> = Tensor<TestBackend, D, Complex>;
+
+    fn complex<const D: usize>(values: &[(f32, f32)], shape: [usize; D]) -> TestTensorComplex<D> {
+        let values = values
+            .iter()
+            .map(|(re, im)| Complex32::new(*re, *im))
+            .collect::<Vec<_>>();
+
+        TestTensorComplex::from_complex(&values, shape, &Default::default())
+    }
+
+    #[test]
+    fn should_support_complex_mul_and_div() {
+        let lhs = complex(&[(1.0, 2.0), (3.0, -1.0)], [2]);
+        let rhs = complex(&[(2.0, -1.0), (0.0, 1.0)], [2]);
+
+        let product = lhs.clone().complex_mul(rhs.clone());
+        let quotient = product.clone().complex_div(rhs);
+
+        product
+            .clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([4.0, 1.0]), 4);
+        product
+            .imag()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([3.0, 3.0]), 4);
+        quotient.into_data().assert_approx_eq(&lhs.into_data(), 4);
+    }
+
+    #[test]
+    fn should_support_complex_matmul() {
+        let lhs = complex(&[(1.0, 1.0), (0.0, 2.0), (2.0, 0.0), (1.0, -1.0)], [2, 2]);
+        let rhs = complex(&[(1.0, 0.0), (0.0, 1.0)], [2, 1]);
+
+        let output = lhs.matmul(rhs);
+
+        // [(1 + i) + 2i * i, 2 + (1 - i) * i] = [-1 + i, 3 + i]
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-1.0, 1.0], [3.0, 1.0]]), 4);
+    }
+
+    #[test]
+    fn should_support_conj_and_abs() {
+        let tensor = complex(&[(3.0, 4.0), (0.0, -2.0)], [2]);
+
+        let output = tensor.clone().complex_mul(tensor.clone().conj());
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([25.0, 0.0, 4.0, 0.0]), 4);
+        tensor
+            .complex_abs()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([5.0, 2.0]), 4);
+    }
+
+    #[test]
+    fn should_support_real_imag_and_angle() {
+        let tensor = complex(&[(1.0, 1.0), (-2.0, 0.0), (0.0, -3.0)], [3]);
+
+        tensor
+            .clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, -2.0, 0.0]), 4);
+        tensor
+            .clone()
+            .imag()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 0.0, -3.0]), 4);
+        tensor.angle().into_data().assert_approx_eq(
+            &TensorData::from([
+                core::f32::consts::FRAC_PI_4,
+                core::f32::consts::PI,
+                -core::f32::consts::FRAC_PI_2,
+            ]),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_support_complex_reductions() {
+        let tensor = complex(&[(1.0, 2.0), (3.0, -1.0), (-2.0, 0.0), (0.0, 1.0)], [2, 2]);
+
+        tensor
+            .clone()
+            .complex_sum()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.0, 2.0]), 4);
+        tensor
+            .complex_mean_dim(0)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-0.5, 1.0, 1.5, 0.0]]), 4);
+    }
+
+    #[test]
+    fn should_support_data_roundtrip() {
+        let device = Default::default();
+        let data = TensorData::from([[1.0, 2.0, 3.0, -1.0], [0.5, 0.0, -2.0, 4.0]]);
+
+        let tensor = TestTensorComplex::<2>::from_data(data.clone(), &device);
+
+        assert_eq!(tensor.dims(), [2, 2]);
+        tensor
+            .clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 3.0], [0.5, -2.0]]), 4);
+        tensor
+            .clone()
+            .into_interleaved::<3>()
+            .into_data()
+            .assert_approx_eq(
+                &TensorData::from([[[1.0, 2.0], [3.0, -1.0]], [[0.5, 0.0], [-2.0, 4.0]]]),
+                4,
+            );
+        tensor.clone().into_data().assert_approx_eq(&data, 4);
+        assert_eq!(
+            tensor.swap_dims(0, 1).to_complex::<f32>()[1],
+            Complex32::new(0.5, 0.0)
+        );
+    }
+
+    #[test]
+    fn should_support_interleaved_roundtrip() {
+        let device = Default::default();
+        let interleaved = TestTensor::<3>::from_floats([[[1.0, 2.0]], [[-3.0, 0.5]]], &device);
+
+        let tensor = TestTensorComplex::<2>::from_interleaved(interleaved.clone());
+
+        tensor
+            .into_interleaved::<3>()
+            .into_data()
+            .assert_approx_eq(&interleaved.into_data(), 4);
+    }
+
+    #[test]
+    fn should_support_complex_exp() {
+        let tensor = complex(&[(0.0, core::f32::consts::PI), (1.0, 0.0)], [2]);
+
+        tensor
+            .exp()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.0, 0.0, core::f32::consts::E, 0.0]), 4);
+    }
+}
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_cumsum_and_cumprod() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [[1.0, 2.0, 3.0, 4.0, 5.0], [2.0, 0.0, -1.0, 3.0, 0.5]],
+            &device,
+        );
+
+        tensor.clone().cumsum(1).into_data().assert_approx_eq(
+            &TensorData::from([[1.0, 3.0, 6.0, 10.0, 15.0], [2.0, 2.0, 1.0, 4.0, 4.5]]),
+            5,
+        );
+        tensor.clone().cumprod(1).into_data().assert_approx_eq(
+            &TensorData::from([[1.0, 2.0, 6.0, 24.0, 120.0], [2.0, 0.0, 0.0, 0.0, 0.0]]),
+            5,
+        );
+        tensor.cumsum(0).into_data().assert_approx_eq(
+            &TensorData::from([[1.0, 2.0, 3.0, 4.0, 5.0], [3.0, 2.0, 2.0, 7.0, 5.5]]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_support_cumprod_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1, -2, 3, 2, 1, 2], &device);
+
+        tensor
+            .cumprod(0)
+            .into_data()
+            .assert_eq(&TensorData::from([1, -2, -6, -12, -12, -24]), false);
+    }
+
+    #[test]
+    fn should_support_cummax_with_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [
+                [1.0, 3.0, 2.0, 3.0, 5.0, 4.0],
+                [0.0, -1.0, 2.0, 1.0, 2.0, 0.0],
+            ],
+            &device,
+        );
+
+        let (values, indices) = tensor.cummax_with_indices(1);
+
+        values.into_data().assert_approx_eq(
+            &TensorData::from([
+                [1.0, 3.0, 3.0, 3.0, 5.0, 5.0],
+                [0.0, 0.0, 2.0, 2.0, 2.0, 2.0],
+            ]),
+            5,
+        );
+        indices.into_data().assert_eq(
+            &TensorData::from([[0, 1, 1, 3, 4, 4], [0, 0, 2, 2, 4, 4]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_cummin_with_indices() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[3, 1], [2, 4], [1, 1]], &device);
+
+        let (values, indices) = tensor.clone().cummin_with_indices(0);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 1], [2, 1], [1, 1]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0], [1, 0], [2, 2]]), false);
+        tensor
+            .cummin(1)
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 1], [2, 2], [1, 1]]), false);
+    }
+}
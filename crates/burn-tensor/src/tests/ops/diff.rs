@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(diff)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_first_and_second_order_diff() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::<2>::from_floats([[1.0, 2.0, 4.0, 7.0], [0.0, 5.0, 5.0, 2.0]], &device);
+
+        tensor.clone().diff(1, 1, None, None).into_data().assert_eq(
+            &TensorData::from([[1.0, 2.0, 3.0], [5.0, 0.0, -3.0]]),
+            false,
+        );
+        tensor
+            .clone()
+            .diff(2, 1, None, None)
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0], [-5.0, -3.0]]), false);
+        tensor
+            .diff(1, 0, None, None)
+            .into_data()
+            .assert_eq(&TensorData::from([[-1.0, 3.0, 1.0, -5.0]]), false);
+    }
+
+    #[test]
+    fn should_support_diff_with_prepend_and_append() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([3, 5, 9], &device);
+        let prepend = TestTensorInt::<1>::from_ints([0], &device);
+        let append = TestTensorInt::<1>::from_ints([10, 10], &device);
+
+        let output = tensor.diff(1, 0, Some(prepend), Some(append));
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3, 2, 4, 1, 0]), false);
+    }
+
+    #[test]
+    fn should_return_empty_when_order_exceeds_size() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0], [4.0, 7.0]], &device);
+
+        assert_eq!(tensor.clone().diff(2, 1, None, None).dims(), [2, 0]);
+        assert_eq!(tensor.diff(3, 0, None, None).dims(), [0, 2]);
+    }
+}
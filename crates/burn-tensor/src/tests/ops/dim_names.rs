@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(dim_names)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_reduce_and_permute_by_name() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats([[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]], &device)
+            .with_dim_names(["batch", "seq", "dim"]);
+
+        let output = tensor.sum_dim("seq").permute(["dim", "batch", "seq"]);
+
+        assert_eq!(output.names(), [Some("dim"), Some("batch"), Some("seq")]);
+        output
+            .tensor()
+            .into_data()
+            .assert_eq(&TensorData::from([[[9.0]], [[12.0]]]), false);
+    }
+
+    #[test]
+    fn should_carry_names_through_binary_ops() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::ones([2, 3], &device).with_dim_names(["batch", "dim"]);
+        let rhs = TestTensor::<2>::ones([1, 3], &device).with_dim_names([None, Some("dim")]);
+
+        let output = (lhs.clone() + rhs).mul_scalar(2.0) * lhs;
+
+        assert_eq!(output.names(), [Some("batch"), Some("dim")]);
+        output
+            .tensor()
+            .into_data()
+            .assert_eq(&TensorData::from([[4.0, 4.0, 4.0], [4.0, 4.0, 4.0]]), false);
+    }
+
+    #[test]
+    fn should_matmul_with_named_dims() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::ones([2, 3], &device).with_dim_names(["seq", "hidden"]);
+        let rhs = TestTensor::<2>::ones([3, 4], &device).with_dim_names(["hidden", "out"]);
+
+        let output = lhs.matmul(rhs);
+
+        assert_eq!(output.names(), [Some("seq"), Some("out")]);
+        assert_eq!(output.dims(), [2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "don't match")]
+    fn should_panic_when_aligning_different_names() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::ones([3, 3], &device).with_dim_names(["batch", "seq"]);
+        let rhs = TestTensor::<2>::ones([3, 3], &device).with_dim_names(["seq", "batch"]);
+
+        let _ = lhs + rhs;
+    }
+
+    #[test]
+    #[should_panic(expected = "No dimension is named 'time'")]
+    fn should_panic_with_unknown_name() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::ones([2, 3], &device).with_dim_names(["batch", "seq"]);
+
+        let _ = tensor.sum_dim("time");
+    }
+}
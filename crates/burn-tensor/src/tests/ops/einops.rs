@@ -0,0 +1,114 @@
+#[burn_tensor_testgen::testgen(einops)]
+mod tests {
+    use super::*;
+    use burn_tensor::einops::Reduction;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_split_and_permute_axes() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..12, &device)
+            .float()
+            .reshape([1, 6, 2]);
+
+        let output = tensor.rearrange::<4>("b (h w) c -> b c h w", &[("h", 2)]);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[
+                [[0.0, 2.0, 4.0], [6.0, 8.0, 10.0]],
+                [[1.0, 3.0, 5.0], [7.0, 9.0, 11.0]],
+            ]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_merge_axes_and_add_unit_dims() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..6, &device).reshape([2, 3]);
+
+        let output = tensor.rearrange::<3>("h w -> 1 (w h) 1", &[]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[0], [3], [1], [4], [2], [5]]]), false);
+    }
+
+    #[test]
+    fn should_rearrange_with_ellipsis() {
+        let device = Default::default();
+        let tensor = TestTensor::<4>::ones([2, 3, 4, 5], &device);
+
+        let output = tensor.clone().rearrange::<2>("b ... -> b (...)", &[]);
+        assert_eq!(output.dims(), [2, 60]);
+
+        let output = tensor.rearrange::<4>("b ... d -> d ... b", &[]);
+        assert_eq!(output.dims(), [5, 3, 4, 2]);
+    }
+
+    #[test]
+    fn should_reduce_axes() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..8, &device)
+            .float()
+            .reshape([2, 4]);
+
+        let output =
+            tensor
+                .clone()
+                .reduce_pattern::<2>("b (w w2) -> b w", Reduction::Max, &[("w2", 2)]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 3.0], [5.0, 7.0]]), false);
+
+        let output = tensor
+            .clone()
+            .reduce_pattern::<1>("b w -> w", Reduction::Mean, &[]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 3.0, 4.0, 5.0]), false);
+
+        let output = tensor.reduce_pattern::<1>("b w ->", Reduction::Sum, &[]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([28.0]), false);
+    }
+
+    #[test]
+    fn should_repeat_new_axes() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let output = tensor
+            .clone()
+            .repeat_pattern::<2>("h w -> h (w w2)", &[("w2", 2)]);
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, 1.0, 2.0, 2.0], [3.0, 3.0, 4.0, 4.0]]),
+            false,
+        );
+
+        let output = tensor.repeat_pattern::<3>("h w -> n w h", &[("n", 2)]);
+        output.into_data().assert_eq(
+            &TensorData::from([[[1.0, 3.0], [2.0, 4.0]], [[1.0, 3.0], [2.0, 4.0]]]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "can't split the dimension of size 6")]
+    fn should_panic_when_the_size_does_not_divide() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::ones([2, 6], &device);
+
+        let _ = tensor.rearrange::<3>("b (h w) -> b h w", &[("h", 4)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can only be reduced")]
+    fn should_panic_when_rearrange_drops_an_axis() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::ones([2, 6], &device);
+
+        let _ = tensor.rearrange::<1>("b c -> b", &[]);
+    }
+}
@@ -0,0 +1,212 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::ops::einsum::{self, Contraction};
+    use burn_tensor::ops::FloatTensorOps;
+    use burn_tensor::{einsum, Distribution, Tensor, TensorData};
+
+    #[test]
+    fn should_support_matmul() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let rhs = TestTensor::<2>::from_floats([[5.0, 6.0], [7.0, 8.0]], &device);
+
+        let output = TestTensor::<2>::einsum("ij,jk->ik", [lhs.clone().into(), rhs.clone().into()]);
+        let implicit = TestTensor::<2>::einsum("ij,jk", [lhs.into(), rhs.into()]);
+
+        let expected = TensorData::from([[19.0, 22.0], [43.0, 50.0]]);
+        output.into_data().assert_eq(&expected, false);
+        implicit.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_batched_matmul() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::from_floats([[[1.0, 2.0]], [[3.0, 4.0]]], &device);
+        let rhs = TestTensor::<3>::from_floats([[[1.0], [1.0]], [[2.0], [0.0]]], &device);
+
+        let output: Tensor<TestBackend, 3> = einsum("bij,bjk->bik", [lhs.into(), rhs.into()]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[3.0]], [[6.0]]]), false);
+    }
+
+    #[test]
+    fn should_support_transpose() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let output = TestTensor::<2>::einsum("ij->ji", [tensor.clone().into()]);
+        let implicit = TestTensor::<2>::einsum("ji", [tensor.into()]);
+
+        let expected = TensorData::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        output.into_data().assert_eq(&expected, false);
+        implicit.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_trace_and_diagonal() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let trace = TestTensor::<1>::einsum("ii->", [tensor.clone().into()]);
+        let diagonal = TestTensor::<1>::einsum("ii->i", [tensor.into()]);
+
+        trace.into_data().assert_eq(&TensorData::from([5.0]), false);
+        diagonal
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 4.0]), false);
+    }
+
+    #[test]
+    fn should_support_sums() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let total = TestTensor::<1>::einsum("ij->", [tensor.clone().into()]);
+        let columns = TestTensor::<1>::einsum("ij->j", [tensor.into()]);
+
+        total
+            .into_data()
+            .assert_eq(&TensorData::from([10.0]), false);
+        columns
+            .into_data()
+            .assert_eq(&TensorData::from([4.0, 6.0]), false);
+    }
+
+    #[test]
+    fn should_support_outer_and_dot_products() {
+        let device = Default::default();
+        let lhs = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+        let rhs = TestTensor::<1>::from_floats([4.0, 5.0, 6.0], &device);
+
+        let outer = TestTensor::<2>::einsum("i,j->ij", [lhs.clone().into(), rhs.clone().into()]);
+        let dot = TestTensor::<1>::einsum("i,i->", [lhs.into(), rhs.into()]);
+
+        outer.into_data().assert_eq(
+            &TensorData::from([[4.0, 5.0, 6.0], [8.0, 10.0, 12.0], [12.0, 15.0, 18.0]]),
+            false,
+        );
+        dot.into_data().assert_eq(&TensorData::from([32.0]), false);
+    }
+
+    #[test]
+    fn should_support_ellipsis_broadcast() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::from_floats([[[1.0, 2.0]], [[3.0, 4.0]]], &device);
+        let rhs = TestTensor::<2>::from_floats([[1.0, 0.0], [1.0, 2.0]], &device);
+
+        let output =
+            TestTensor::<3>::einsum("...ij,jk->...ik", [lhs.clone().into(), rhs.clone().into()]);
+        let implicit = TestTensor::<3>::einsum("...ij,jk", [lhs.into(), rhs.into()]);
+
+        let expected = TensorData::from([[[3.0, 4.0]], [[7.0, 8.0]]]);
+        output.into_data().assert_eq(&expected, false);
+        implicit.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_broadcast_dimensions_of_size_one() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[1.0, 2.0]], &device);
+        let rhs = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let output = TestTensor::<2>::einsum("ij,ij->ij", [lhs.into(), rhs.into()]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 4.0], [3.0, 8.0]]), false);
+    }
+
+    #[test]
+    fn should_contract_multiple_operands() {
+        let device = Default::default();
+        let a = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let b = TestTensor::<2>::eye(2, &device);
+        let c = TestTensor::<2>::from_floats([[1.0, 0.0], [0.0, 2.0]], &device);
+        let v = TestTensor::<1>::from_floats([1.0, 1.0], &device);
+
+        let output =
+            TestTensor::<2>::einsum("ij,jk,kl->il", [a.clone().into(), b.into(), c.into()]);
+        let vector = TestTensor::<1>::einsum("ij,j->i", [a.into(), v.into()]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 4.0], [3.0, 8.0]]), false);
+        vector
+            .into_data()
+            .assert_eq(&TensorData::from([3.0, 7.0]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_output_rank_mismatch() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let _output = TestTensor::<1>::einsum("ij->ij", [tensor.into()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sizes_mismatch() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::ones([2, 3], &device);
+        let rhs = TestTensor::<2>::ones([2, 3], &device);
+
+        let _output = TestTensor::<2>::einsum("ij,jk->ik", [lhs.into(), rhs.into()]);
+    }
+
+    #[test]
+    fn should_match_the_reference_contraction() {
+        let device = Default::default();
+        // The left hand side is transposed and broadcast along the label 1.
+        let lhs =
+            TestTensor::<3>::random([4, 1, 2], Distribution::Default, &device).swap_dims(0, 2);
+        let rhs = TestTensor::<3>::random([4, 5, 3], Distribution::Default, &device);
+        // The label 4 is only summed over the right hand side.
+        let other = TestTensor::<3>::random([3, 2, 6], Distribution::Default, &device);
+
+        let contractions = [
+            (
+                rhs.clone(),
+                Contraction::new(
+                    vec![0, 1, 2],
+                    vec![2, 3, 1],
+                    vec![0, 3, 1],
+                    vec![2, 3, 4, 5],
+                ),
+            ),
+            (
+                other,
+                Contraction::new(
+                    vec![0, 1, 2],
+                    vec![1, 0, 4],
+                    vec![1, 3, 2],
+                    vec![2, 3, 4, 1, 6],
+                ),
+            ),
+        ];
+
+        for (rhs, contraction) in contractions {
+            let output = TestBackend::float_contract::<3>(
+                lhs.clone().into_primitive(),
+                rhs.clone().into_primitive(),
+                contraction.clone(),
+            );
+            let expected = einsum::matmul_contraction::<TestBackend, 3>(
+                lhs.clone().into_primitive(),
+                rhs.into_primitive(),
+                &contraction,
+            );
+
+            Tensor::<TestBackend, 3>::from_primitive(output)
+                .into_data()
+                .assert_approx_eq(
+                    &Tensor::<TestBackend, 3>::from_primitive(expected).into_data(),
+                    3,
+                );
+        }
+    }
+}
@@ -115,4 +115,33 @@ mod tests {
         let tensor = TestTensorInt::<1>::from([1, 2, 3]);
         let _expanded_tensor = tensor.expand([-1, 3]);
     }
+
+    #[test]
+    fn should_broadcast_to_shape() {
+        let tensor = TestTensor::<2>::from([[1.0], [2.0]]);
+        let output = tensor.broadcast_to([2, 2, 3]);
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]],
+                [[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Incompatible size at dimension '0' => '3 != 1'")]
+    fn should_panic_broadcast_to_smaller_dim() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+        let _output = tensor.broadcast_to([1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Incompatible size at dimension '1' => '3 != 4'")]
+    fn should_panic_binary_op_incompatible_shapes() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0]]);
+        let _output = lhs + rhs;
+    }
 }
@@ -0,0 +1,181 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::ops::{fft::dft, FloatTensorOps};
+    use burn_tensor::{Complex, Distribution, Tensor, TensorData};
+    use core::f32::consts::FRAC_1_SQRT_2;
+
+    type TestTensorComplex<const D: usize> = Tensor<TestBackend, D, Complex>;
+
+    #[test]
+    fn should_support_fft() {
+        let device = Default::default();
+        let real = TestTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+
+        let (real, imag) = TestTensorComplex::from_real(real).fft().into_parts();
+
+        real.into_data()
+            .assert_approx_eq(&TensorData::from([10.0, -2.0, -2.0, -2.0]), 4);
+        imag.into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 2.0, 0.0, -2.0]), 4);
+    }
+
+    #[test]
+    fn should_support_fft_complex_power_of_two() {
+        let device = Default::default();
+        let real =
+            TestTensor::<1>::from_floats([1.0, 0.5, -1.0, 3.0, 0.0, 2.0, -0.5, 1.0], &device);
+        let imag =
+            TestTensor::<1>::from_floats([-1.0, 2.0, 0.0, 0.5, 0.0, -1.0, 1.0, 1.0], &device);
+
+        let (real, imag) = TestTensorComplex::from_parts(real, imag).fft().into_parts();
+
+        real.into_data().assert_approx_eq(
+            &TensorData::from([
+                6.0,
+                -FRAC_1_SQRT_2,
+                2.0,
+                6.2426,
+                -7.0,
+                FRAC_1_SQRT_2,
+                3.0,
+                -2.2426,
+            ]),
+            3,
+        );
+        imag.into_data().assert_approx_eq(
+            &TensorData::from([2.5, 1.6213, -0.5, -4.3284, -2.5, -2.6213, -3.5, 1.3284]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_fft_size_not_power_of_two() {
+        let device = Default::default();
+        let real = TestTensor::<2>::from_floats(
+            [
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+                [1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            ],
+            &device,
+        );
+
+        let (real, imag) = TestTensorComplex::from_real(real).fft().into_parts();
+
+        real.into_data().assert_approx_eq(
+            &TensorData::from([
+                [21.0, -3.0, -3.0, -3.0, -3.0, -3.0],
+                [1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+            ]),
+            3,
+        );
+        imag.into_data().assert_approx_eq(
+            &TensorData::from([
+                [0.0, 5.1962, 1.7321, 0.0, -1.7321, -5.1962],
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_ifft_roundtrip() {
+        let device = Default::default();
+        let real = TestTensor::<2>::from_floats([[1.0, -2.0, 0.5], [3.0, 0.0, 1.0]], &device);
+        let imag = TestTensor::<2>::from_floats([[0.0, 1.0, -1.0], [2.0, 0.5, 0.0]], &device);
+
+        let (output_real, output_imag) = TestTensorComplex::from_parts(real.clone(), imag.clone())
+            .fft()
+            .ifft()
+            .into_parts();
+
+        output_real
+            .into_data()
+            .assert_approx_eq(&real.into_data(), 4);
+        output_imag
+            .into_data()
+            .assert_approx_eq(&imag.into_data(), 4);
+    }
+
+    #[test]
+    fn should_support_rfft_and_irfft() {
+        let device = Default::default();
+        let signal =
+            TestTensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0], [0.0, 1.0, 0.0, -1.0]], &device);
+
+        let spectrum = signal.clone().rfft();
+        let (real, imag) = spectrum.clone().into_parts();
+
+        real.into_data()
+            .assert_approx_eq(&TensorData::from([[10.0, -2.0, -2.0], [0.0, 0.0, 0.0]]), 4);
+        imag.into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 2.0, 0.0], [0.0, -2.0, 0.0]]), 4);
+
+        let output = spectrum.irfft(4);
+        output.into_data().assert_approx_eq(&signal.into_data(), 4);
+    }
+
+    #[test]
+    fn should_support_irfft_odd_size() {
+        let device = Default::default();
+        let signal = TestTensor::<1>::from_floats([1.0, -1.0, 2.0, 0.5, 3.0], &device);
+
+        let output = signal.clone().rfft().irfft(5);
+
+        output.into_data().assert_approx_eq(&signal.into_data(), 4);
+    }
+
+    #[test]
+    fn should_support_fft2() {
+        let device = Default::default();
+        let real = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let tensor = TestTensorComplex::from_real(real.clone());
+
+        let spectrum = tensor.fft2();
+        let (fft_real, fft_imag) = spectrum.clone().into_parts();
+
+        fft_real
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[10.0, -2.0], [-4.0, 0.0]]), 4);
+        fft_imag
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 0.0], [0.0, 0.0]]), 4);
+
+        let (output_real, output_imag) = spectrum.ifft2().into_parts();
+        output_real
+            .into_data()
+            .assert_approx_eq(&real.into_data(), 4);
+        output_imag
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 0.0], [0.0, 0.0]]), 4);
+    }
+
+    #[test]
+    fn should_match_the_reference_transform() {
+        let device = Default::default();
+        for n in [1, 5, 8, 12] {
+            let real = TestTensor::<2>::random([3, n], Distribution::Default, &device);
+            let imag = TestTensor::<2>::random([3, n], Distribution::Default, &device);
+            let tensor = TestTensorComplex::from_parts(real, imag);
+
+            for inverse in [false, true] {
+                let (real, imag) = TestTensorComplex::from_primitive(TestBackend::float_fft(
+                    tensor.clone().into_primitive(),
+                    inverse,
+                ))
+                .into_parts();
+                let (expected_real, expected_imag) =
+                    TestTensorComplex::from_primitive(dft::<TestBackend, 2>(
+                        tensor.clone().into_primitive(),
+                        inverse,
+                    ))
+                    .into_parts();
+
+                real.into_data()
+                    .assert_approx_eq(&expected_real.into_data(), 3);
+                imag.into_data()
+                    .assert_approx_eq(&expected_imag.into_data(), 3);
+            }
+        }
+    }
+}
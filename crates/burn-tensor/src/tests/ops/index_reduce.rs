@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(index_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{IndexReduction, TensorData};
+
+    fn index_reduce(reduction: IndexReduction, include_self: bool) -> TensorData {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+        let indices = TestTensorInt::<1>::from([0, 2, 0]);
+        let values = TestTensor::<2>::from([[2.0, 3.0, 4.0], [-1.0, 2.0, 6.0]]);
+
+        tensor
+            .index_reduce(1, indices, values, reduction, include_self)
+            .into_data()
+    }
+
+    #[test]
+    fn should_index_reduce_sum() {
+        index_reduce(IndexReduction::Sum, true).assert_eq(
+            &TensorData::from([[7.0, 2.0, 6.0, 4.0], [10.0, 6.0, 9.0, 8.0]]),
+            false,
+        );
+        index_reduce(IndexReduction::Sum, false).assert_eq(
+            &TensorData::from([[6.0, 2.0, 3.0, 4.0], [5.0, 6.0, 2.0, 8.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_index_reduce_prod() {
+        index_reduce(IndexReduction::Prod, true).assert_eq(
+            &TensorData::from([[8.0, 2.0, 9.0, 4.0], [-30.0, 6.0, 14.0, 8.0]]),
+            false,
+        );
+        index_reduce(IndexReduction::Prod, false).assert_eq(
+            &TensorData::from([[8.0, 2.0, 3.0, 4.0], [-6.0, 6.0, 2.0, 8.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_index_reduce_mean() {
+        index_reduce(IndexReduction::Mean, true).assert_approx_eq(
+            &TensorData::from([[7.0 / 3.0, 2.0, 3.0, 4.0], [10.0 / 3.0, 6.0, 4.5, 8.0]]),
+            5,
+        );
+        index_reduce(IndexReduction::Mean, false).assert_eq(
+            &TensorData::from([[3.0, 2.0, 3.0, 4.0], [2.5, 6.0, 2.0, 8.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_index_reduce_amax_and_amin() {
+        index_reduce(IndexReduction::Amax, true).assert_eq(
+            &TensorData::from([[4.0, 2.0, 3.0, 4.0], [6.0, 6.0, 7.0, 8.0]]),
+            false,
+        );
+        index_reduce(IndexReduction::Amin, false).assert_eq(
+            &TensorData::from([[2.0, 2.0, 3.0, 4.0], [-1.0, 6.0, 2.0, 8.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_index_reduce_int_mean_truncated() {
+        let tensor = TestTensorInt::<1>::from([10, 20]);
+        let indices = TestTensorInt::<1>::from([1, 1, 1]);
+        let values = TestTensorInt::<1>::from([1, 2, 4]);
+
+        let output = tensor.index_reduce(0, indices, values, IndexReduction::Mean, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([10, 6]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_index_reduce_without_index_for_each_value() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0]);
+        let indices = TestTensorInt::<1>::from([0, 1]);
+        let values = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+
+        let _output = tensor.index_reduce(0, indices, values, IndexReduction::Sum, true);
+    }
+}
@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(logsumexp)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_logsumexp_dim() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [-1.0, -1.0, -1.0]], &device);
+
+        let output = tensor.clone().logsumexp(1);
+        let expected = TensorData::from([[2.407606], [0.098612]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+
+        let output = tensor.logsumexp(0);
+        let expected = TensorData::from([[0.313262, 1.126928, 2.048587]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_not_overflow_with_large_values() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([1000.0, 1000.0, -1000.0], &device);
+
+        let output = tensor.logsumexp(0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1000.693147]), 3);
+    }
+
+    #[test]
+    fn should_support_fully_masked_rows() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [[f32::NEG_INFINITY, f32::NEG_INFINITY], [1.0, 2.0]],
+            &device,
+        );
+
+        let output = tensor.logsumexp(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[f32::NEG_INFINITY], [2.313262]]), 4);
+    }
+}
@@ -37,6 +37,40 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_not_propagate_non_finite_values_of_mask_where_unselected_side() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[f32::INFINITY, 7.0], [2.0, f32::NAN]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false], [false, true]]),
+            &device,
+        );
+        let value = Tensor::<TestBackend, 2>::from_data(
+            TensorData::from([[1.8, f32::NEG_INFINITY], [f32::NAN, 4.8]]),
+            &device,
+        );
+
+        let output = tensor.mask_where(mask, value);
+        let expected = TensorData::from([[1.8, 7.0], [2.0, 4.8]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_not_propagate_non_finite_values_of_mask_fill_unselected_side() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[f32::INFINITY, 7.0], [2.0, f32::NAN]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false], [false, true]]),
+            &device,
+        );
+
+        let output = tensor.mask_fill(mask, 2.0);
+        let expected = TensorData::from([[2.0, 7.0], [2.0, 2.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     fn should_support_int_mask_where_ops() {
         let device = Default::default();
@@ -68,4 +102,36 @@ mod tests {
 
         output.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn should_support_masked_scatter_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0, 4.0], [2.0, 3.0, 5.0]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[false, true, true], [true, false, false]]),
+            &device,
+        );
+        let source = TestTensor::<2>::from_data([[-1.0, -2.0], [-3.0, -4.0]], &device);
+
+        let output = tensor.masked_scatter(mask, source);
+        let expected = TensorData::from([[1.0, -1.0, -2.0], [-3.0, 3.0, 5.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_masked_scatter_broadcast_mask() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::zeros([2, 3], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false, true]]),
+            &device,
+        );
+        let source = Tensor::<TestBackend, 1, Int>::from_data([1, 2, 3, 4], &device);
+
+        let output = tensor.masked_scatter(mask, source);
+        let expected = TensorData::from([[1, 0, 2], [3, 0, 4]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
 }
@@ -122,6 +122,25 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn test_int_max_min_dim_2d_with_0th_dim() {
+        let tensor = TestTensorInt::<2>::from([[0, 4, 2], [3, 1, 5]]);
+
+        let (max, max_index) = tensor.clone().max_dim_with_indices(0);
+        let (min, min_index) = tensor.min_dim_with_indices(0);
+
+        max.into_data()
+            .assert_eq(&TensorData::from([[3, 4, 5]]), false);
+        max_index
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0, 1]]), false);
+        min.into_data()
+            .assert_eq(&TensorData::from([[0, 1, 2]]), false);
+        min_index
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 0]]), false);
+    }
+
     #[test]
     fn test_min_dim_with_indices_2d_with_0th_dim() {
         let tensor =
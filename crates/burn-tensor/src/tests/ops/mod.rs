@@ -7,56 +7,80 @@ mod arange;
 mod arange_step;
 mod arg;
 mod argwhere_nonzero;
+mod atan2;
+mod bincount;
 mod bool;
 mod cartesian_grid;
 mod cast;
 mod cat;
+mod checksum;
 mod chunk;
 mod clamp;
 mod close;
+mod complex;
 mod cos;
 mod create_like;
+mod cumulative;
+mod diff;
+mod dim_names;
 mod div;
+mod einops;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
+mod fft;
 mod flatten;
 mod flip;
 mod full;
 mod gather_scatter;
+mod index_reduce;
 mod init;
 mod iter_dim;
 mod log;
 mod log1p;
+mod logsumexp;
 mod map_comparison;
 mod mask;
 mod matmul;
 mod maxmin;
+mod mode;
 mod movedim;
 mod mul;
+mod multinomial;
 mod narrow;
 mod neg;
 mod one_hot;
+mod pack_bits;
 mod padding;
 mod permute;
 mod powf;
 mod powf_scalar;
+mod quantize;
+mod ragged;
 mod random;
 mod recip;
 mod remainder;
 mod repeat;
 mod reshape;
+mod roll;
+mod searchsorted;
+mod segment;
 mod select;
 mod sign;
 mod sin;
 mod slice;
 mod sort_argsort;
+mod special;
 mod sqrt;
 mod squeeze;
 mod stack;
+mod stft;
 mod sub;
 mod tanh;
+mod tensordot;
 mod topk;
 mod transpose;
 mod tri;
 mod tri_mask;
+mod unique;
@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(mode)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_mode_along_last_dim() {
+        let tensor = TestTensorInt::<2>::from([[2, 1, 2, 0], [3, 1, 1, 3]]);
+
+        let (values, counts) = tensor.mode(1);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[2], [1]]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([[2], [2]]), false);
+    }
+
+    #[test]
+    fn should_support_mode_along_first_dim() {
+        let tensor =
+            TestTensorInt::<3>::from([[[1, 4], [0, 5]], [[1, 3], [2, 5]], [[2, 3], [2, 5]]]);
+
+        let (values, counts) = tensor.mode(0);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[[1, 3], [2, 5]]]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([[[2, 2], [2, 3]]]), false);
+    }
+
+    #[test]
+    fn should_return_the_smallest_of_equally_frequent_values() {
+        let tensor = TestTensorInt::<1>::from([5, -2, 7, 5, 7, -2]);
+
+        let (values, counts) = tensor.mode(0);
+
+        values.into_data().assert_eq(&TensorData::from([-2]), false);
+        counts.into_data().assert_eq(&TensorData::from([2]), false);
+    }
+
+    #[test]
+    fn should_support_bool_mode() {
+        let tensor = TestTensorBool::<2>::from([
+            [true, false, true],
+            [false, false, true],
+            [true, false, false],
+        ]);
+
+        let (values, counts) = tensor.mode(0);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[true, false, true]]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 3, 2]]), false);
+    }
+
+    #[test]
+    fn should_return_false_for_tied_bool_mode() {
+        let tensor = TestTensorBool::<2>::from([[true, false], [true, true]]);
+
+        let (values, counts) = tensor.mode(1);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[false], [true]]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [2]]), false);
+    }
+}
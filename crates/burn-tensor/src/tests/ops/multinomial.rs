@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(multinomial)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ElementConversion, Tensor, TensorData};
+
+    #[test]
+    fn should_only_sample_categories_with_weights() {
+        let device = Default::default();
+        let weights =
+            TestTensor::<2>::from_floats([[0.0, 0.0, 2.0, 0.0], [0.5, 0.0, 0.0, 0.0]], &device);
+
+        let output = weights.multinomial(3, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 2, 2], [0, 0, 0]]), false);
+    }
+
+    #[test]
+    fn should_sample_proportionally_to_the_weights() {
+        let device = Default::default();
+        let weights = TestTensor::<1>::from_floats([1.0, 0.0, 3.0], &device);
+
+        let output = weights.multinomial(4000, true);
+
+        // The expected index is (0 * 1 + 2 * 3) / 4 = 1.5.
+        let mean = output.float().mean().into_scalar().elem::<f32>();
+        assert!((mean - 1.5).abs() < 0.1, "Unexpected mean index {mean}");
+    }
+
+    #[test]
+    fn should_sample_without_replacement() {
+        let device = Default::default();
+        let weights = TestTensor::<2>::from_floats(
+            [[0.0, 1.0, 0.0, 5.0, 4.0], [3.0, 0.0, 1.0, 0.0, 2.0]],
+            &device,
+        );
+
+        let output = weights.multinomial(3, false);
+
+        // All the categories with a weight are sampled once.
+        output
+            .sort(1)
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3, 4], [0, 2, 4]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sampling_more_categories_than_available_without_replacement() {
+        let device = Default::default();
+        let weights = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device);
+
+        let _output = weights.multinomial(3, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sampling_more_categories_than_weighted_without_replacement() {
+        let device = Default::default();
+        let weights = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 0.0, 2.0]], &device);
+
+        let _output = weights.multinomial(4, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_weights_of_a_row_are_zero() {
+        let device = Default::default();
+        let weights = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [0.0, 0.0]], &device);
+
+        let _output = weights.multinomial(1, true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_a_weight_is_negative() {
+        let device = Default::default();
+        let weights = Tensor::<TestBackend, 1>::from_floats([1.0, -0.5, 2.0], &device);
+
+        let _output = weights.multinomial(1, true);
+    }
+}
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(pack_bits)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Tensor, TensorData};
+
+    #[test]
+    fn should_pack_bits_of_last_dim() {
+        let mask = TestTensorBool::<2>::from([
+            [
+                true, false, false, false, false, false, false, false, true, true,
+            ],
+            [false, true, true, true, true, true, true, true, true, false],
+        ]);
+
+        let packed = mask.pack_bits();
+
+        packed
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3], [254, 1]]), false);
+    }
+
+    #[test]
+    fn should_unpack_bits_to_the_packed_mask() {
+        let device = Default::default();
+        let mask = Tensor::<TestBackend, 3, Bool>::tril_mask([2, 5, 19], 1, &device);
+
+        let unpacked = mask.clone().pack_bits().unpack_bits(19);
+
+        unpacked.into_data().assert_eq(&mask.into_data(), true);
+    }
+
+    #[test]
+    fn should_mask_fill_with_packed_mask() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mask = TestTensorBool::<2>::from([[true, false, true], [false, false, true]]);
+
+        let output = tensor.mask_fill_packed(mask.pack_bits(), -1.0);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[-1.0, 2.0, -1.0], [4.0, 5.0, -1.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_unpack_bits_with_too_many_values() {
+        let packed = TestTensorInt::<1>::from([255, 1]);
+
+        let _mask = packed.unpack_bits(17);
+    }
+}
@@ -0,0 +1,97 @@
+#[burn_tensor_testgen::testgen(quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::quantization::{
+        HistogramObserver, MinMaxObserver, MovingAverageObserver, Observer, QuantizationParameters,
+        QuantizationScheme, QuantizationType, QuantizedTensorData,
+    };
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_quantize_dynamic_affine_int8() {
+        let data = TensorData::from([-1.8f32, -1.0, 0.0, 0.5]);
+        let tensor = TestTensor::<1>::from_data(data.clone(), &Default::default());
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+
+        let quantized = tensor.quantize_dynamic(&scheme);
+
+        quantized
+            .values
+            .to_data()
+            .assert_eq(&TensorData::from([-128, -39, 72, 127]), false);
+        assert_eq!(
+            quantized.clone().into_data(),
+            QuantizedTensorData::quantize(&data, scheme)
+        );
+        quantized
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&data, 2);
+    }
+
+    #[test]
+    fn should_quantize_with_parameters() {
+        let tensor =
+            TestTensor::<2>::from_floats([[-2.0, -0.26], [0.24, 3.0]], &Default::default());
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let params = QuantizationParameters {
+            scale: 0.2,
+            offset: None,
+        };
+
+        let quantized = tensor.quantize(&scheme, &params);
+
+        // Rounded half away from zero and clamped to the range of 4-bit integers.
+        quantized
+            .values
+            .to_data()
+            .assert_eq(&TensorData::from([[-8, -1], [1, 7]]), false);
+        let data = quantized.into_data();
+        assert_eq!(data.value.len(), 2);
+        assert_eq!(data.values(), vec![-8, -1, 1, 7]);
+    }
+
+    #[test]
+    fn min_max_observer_should_cover_all_values() {
+        let device = Default::default();
+        let mut observer = MinMaxObserver::new();
+        assert_eq!(observer.range(), None);
+
+        observer.observe(&TestTensor::<1>::from_floats([-1.0, 0.5, 2.0], &device));
+        observer.observe(&TestTensor::<1>::from_floats([-3.0, 1.0], &device));
+
+        assert_eq!(observer.range(), Some((-3.0, 2.0)));
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8);
+        assert_eq!(
+            observer.parameters(&scheme),
+            Some(scheme.compute_parameters(-3.0, 2.0))
+        );
+    }
+
+    #[test]
+    fn moving_average_observer_should_average_ranges() {
+        let device = Default::default();
+        let mut observer = MovingAverageObserver::new(0.75);
+
+        observer.observe(&TestTensor::<1>::from_floats([-1.0, 2.0], &device));
+        observer.observe(&TestTensor::<1>::from_floats([-5.0, 6.0], &device));
+
+        assert_eq!(observer.range(), Some((-2.0, 3.0)));
+    }
+
+    #[test]
+    fn histogram_observer_should_clip_outliers() {
+        let device = Default::default();
+        let mut observer = HistogramObserver::new(100, 0.99);
+
+        let values: Vec<f32> = (0..1000).map(|i| i as f32 / 1000.0).collect();
+        observer.observe(&TestTensor::<1>::from_floats(values.as_slice(), &device));
+        // A single outlier extends the histogram but not the range.
+        observer.observe(&TestTensor::<1>::from_floats([100.0], &device));
+
+        assert_eq!(observer.counts().iter().sum::<u64>(), 1001);
+        let (min, max) = observer.range().unwrap();
+        assert_eq!(min, 0.0);
+        assert!(max > 0.9 && max < 5.0, "Unexpected max {max}");
+    }
+}
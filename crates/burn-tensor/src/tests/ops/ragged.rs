@@ -0,0 +1,87 @@
+#[burn_tensor_testgen::testgen(ragged)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ragged::RaggedTensor, Tensor, TensorData};
+
+    fn ragged() -> RaggedTensor<TestBackend, 2> {
+        let device = Default::default();
+        RaggedTensor::from_tensors(vec![
+            TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device),
+            TestTensor::from_floats([[5.0, 6.0]], &device),
+            TestTensor::from_floats([[7.0, 8.0], [9.0, 0.0], [-1.0, 2.0]], &device),
+        ])
+    }
+
+    #[test]
+    fn should_convert_to_padded_with_mask() {
+        let ragged = ragged();
+
+        assert_eq!(ragged.offsets(), &[0, 2, 3, 6]);
+        assert_eq!(ragged.max_len(), 3);
+
+        ragged.padding_mask().into_data().assert_eq(
+            &TensorData::from([
+                [false, false, true],
+                [false, true, true],
+                [false, false, false],
+            ]),
+            false,
+        );
+
+        let padded: Tensor<TestBackend, 3> = ragged.to_padded(-1.0);
+        padded.into_data().assert_eq(
+            &TensorData::from([
+                [[1.0, 2.0], [3.0, 4.0], [-1.0, -1.0]],
+                [[5.0, 6.0], [-1.0, -1.0], [-1.0, -1.0]],
+                [[7.0, 8.0], [9.0, 0.0], [-1.0, 2.0]],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_round_trip_from_padded() {
+        let ragged = ragged();
+        let values = ragged.values();
+        let padded: Tensor<TestBackend, 3> = ragged.to_padded(0.0);
+
+        let output = RaggedTensor::<TestBackend, 2>::from_padded(padded, &[2, 1, 3]);
+
+        assert_eq!(output.lengths(), vec![2, 1, 3]);
+        output
+            .into_values()
+            .into_data()
+            .assert_eq(&values.into_data(), false);
+    }
+
+    #[test]
+    fn should_reduce_each_sequence() {
+        let ragged = ragged();
+
+        ragged.clone().sum().into_data().assert_eq(
+            &TensorData::from([[4.0, 6.0], [5.0, 6.0], [15.0, 10.0]]),
+            false,
+        );
+        ragged.clone().mean().into_data().assert_approx_eq(
+            &TensorData::from([[2.0, 3.0], [5.0, 6.0], [5.0, 10.0 / 3.0]]),
+            5,
+        );
+        ragged.max().into_data().assert_eq(
+            &TensorData::from([[3.0, 4.0], [5.0, 6.0], [9.0, 8.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_map_rows_and_split() {
+        let ragged = ragged().map(|values| values.sum_dim(1) * 2);
+
+        let sequences = ragged.into_tensors();
+
+        assert_eq!(sequences.len(), 3);
+        sequences[2]
+            .clone()
+            .into_data()
+            .assert_eq(&TensorData::from([[30.0], [18.0], [2.0]]), false);
+    }
+}
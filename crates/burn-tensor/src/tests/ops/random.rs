@@ -1,7 +1,8 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::ops::random::random_from_standard;
+    use burn_tensor::{Distribution, ElementConversion, Generator, Int, Tensor, TensorData};
 
     #[test]
     fn rand_default() {
@@ -33,4 +34,197 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [1f32; 20].into());
     }
+
+    #[test]
+    fn rand_gamma() {
+        let tensor = Tensor::<TestBackend, 1>::random(
+            [4000],
+            Distribution::Gamma(2.0, 3.0),
+            &Default::default(),
+        );
+
+        tensor
+            .clone()
+            .into_data()
+            .assert_within_range(0.0..f32::MAX);
+        assert_mean(tensor, 6.0, 0.4);
+    }
+
+    #[test]
+    fn rand_beta() {
+        let tensor = Tensor::<TestBackend, 1>::random(
+            [4000],
+            Distribution::Beta(2.0, 6.0),
+            &Default::default(),
+        );
+
+        tensor.clone().into_data().assert_within_range(0.0..1.0);
+        assert_mean(tensor, 0.25, 0.02);
+    }
+
+    #[test]
+    fn rand_poisson() {
+        let tensor = Tensor::<TestBackend, 1>::random(
+            [4000],
+            Distribution::Poisson(4.0),
+            &Default::default(),
+        );
+
+        assert_integers(tensor.clone());
+        assert_mean(tensor, 4.0, 0.2);
+    }
+
+    #[test]
+    fn rand_from_standard_gamma() {
+        let device = Default::default();
+
+        let small = random_standard([4000], Distribution::Gamma(0.5, 2.0), &device);
+        let large = random_standard([4000], Distribution::Gamma(5.0, 0.5), &device);
+
+        small.clone().into_data().assert_within_range(0.0..f32::MAX);
+        large.clone().into_data().assert_within_range(0.0..f32::MAX);
+        assert_mean(small, 1.0, 0.1);
+        assert_mean(large, 2.5, 0.1);
+    }
+
+    #[test]
+    fn rand_from_standard_beta() {
+        let tensor = random_standard([4000], Distribution::Beta(2.0, 6.0), &Default::default());
+
+        tensor.clone().into_data().assert_within_range(0.0..1.0);
+        assert_mean(tensor, 0.25, 0.02);
+    }
+
+    #[test]
+    fn rand_from_standard_poisson() {
+        let device = Default::default();
+
+        let small = random_standard([4000], Distribution::Poisson(3.0), &device);
+        let large = random_standard([4000], Distribution::Poisson(50.0), &device);
+
+        assert_integers(small.clone());
+        assert_integers(large.clone());
+        assert_mean(small, 3.0, 0.2);
+        assert_mean(large.clone(), 50.0, 0.6);
+        assert_variance(large, 50.0, 6.0);
+    }
+
+    #[test]
+    fn rand_dirichlet() {
+        let concentration = TestTensor::<2>::from_floats(
+            [[1.0, 1.0, 1.0, 1.0], [0.2, 4.0, 2.0, 0.5]],
+            &Default::default(),
+        );
+
+        let tensor = Tensor::random_dirichlet(concentration);
+
+        tensor.clone().into_data().assert_within_range(0.0..1.0);
+        tensor
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0], [1.0]]), 3);
+    }
+
+    #[test]
+    fn rand_with_generator_should_be_reproducible() {
+        let device = Default::default();
+        let mut generator = Generator::new(7);
+        let saved = generator.clone();
+
+        let float =
+            TestTensor::<2>::random_with([3, 4], Distribution::Default, &mut generator, &device);
+        let int = Tensor::<TestBackend, 1, Int>::random_with(
+            [6],
+            Distribution::Uniform(0.0, 100.0),
+            &mut generator,
+            &device,
+        );
+        assert_eq!(generator.offset(), 2);
+
+        let mut generator = saved;
+        let float_restored =
+            TestTensor::<2>::random_with([3, 4], Distribution::Default, &mut generator, &device);
+        let int_restored = Tensor::<TestBackend, 1, Int>::random_with(
+            [6],
+            Distribution::Uniform(0.0, 100.0),
+            &mut generator,
+            &device,
+        );
+
+        float.clone().into_data().assert_within_range(0.0..1.0);
+        float
+            .into_data()
+            .assert_eq(&float_restored.into_data(), true);
+        int.into_data().assert_eq(&int_restored.into_data(), true);
+    }
+
+    #[test]
+    fn rand_with_forked_generators_should_differ() {
+        let device = Default::default();
+        let mut generator = Generator::new(7);
+        let mut first = generator.fork();
+        let mut second = generator.fork();
+
+        let first = TestTensor::<1>::random_with([16], Distribution::Default, &mut first, &device);
+        let second =
+            TestTensor::<1>::random_with([16], Distribution::Default, &mut second, &device);
+
+        let difference = first.sub(second).abs().sum().into_scalar().elem::<f32>();
+        assert!(difference > 0.0);
+    }
+
+    #[test]
+    fn rand_with_installed_generator_should_draw_its_seeds() {
+        let device = Default::default();
+        let guard = Generator::new(7).install();
+        let tensor = TestTensor::<2>::random([3, 4], Distribution::Default, &device);
+        let tensor_like = tensor.random_like(Distribution::Default);
+        assert_eq!(Generator::installed().map(|g| g.offset()), Some(2));
+        core::mem::drop(guard);
+        assert_eq!(Generator::installed(), None);
+
+        let mut generator = Generator::new(7);
+        let expected =
+            TestTensor::<2>::random_with([3, 4], Distribution::Default, &mut generator, &device);
+        let expected_like =
+            TestTensor::<2>::random_with([3, 4], Distribution::Default, &mut generator, &device);
+
+        tensor.into_data().assert_eq(&expected.into_data(), true);
+        tensor_like
+            .into_data()
+            .assert_eq(&expected_like.into_data(), true);
+    }
+
+    fn random_standard(
+        shape: [usize; 1],
+        distribution: Distribution,
+        device: &<TestBackend as burn_tensor::backend::Backend>::Device,
+    ) -> TestTensor<1> {
+        Tensor::from_primitive(random_from_standard::<TestBackend, 1>(
+            shape.into(),
+            distribution,
+            device,
+        ))
+    }
+
+    fn assert_mean(tensor: TestTensor<1>, expected: f32, tolerance: f32) {
+        let mean = tensor.mean().into_scalar().elem::<f32>();
+        assert!(
+            (mean - expected).abs() < tolerance,
+            "Unexpected mean {mean}, expected {expected}"
+        );
+    }
+
+    fn assert_variance(tensor: TestTensor<1>, expected: f32, tolerance: f32) {
+        let variance = tensor.var(0).into_scalar().elem::<f32>();
+        assert!(
+            (variance - expected).abs() < tolerance,
+            "Unexpected variance {variance}, expected {expected}"
+        );
+    }
+
+    fn assert_integers(tensor: TestTensor<1>) {
+        let difference = tensor.clone().sub(tensor.int().float()).abs().max();
+        assert_eq!(difference.into_scalar().elem::<f32>(), 0.0);
+    }
 }
@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_roll_dim_with_wrap_around() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([0.0, 1.0, 2.0, 3.0, 4.0], &device);
+
+        tensor
+            .clone()
+            .roll_dim(2, 0)
+            .into_data()
+            .assert_eq(&TensorData::from([3.0, 4.0, 0.0, 1.0, 2.0]), false);
+        tensor
+            .clone()
+            .roll_dim(-1, 0)
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, 3.0, 4.0, 0.0]), false);
+        tensor
+            .roll_dim(10, 0)
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 1.0, 2.0, 3.0, 4.0]), false);
+    }
+
+    #[test]
+    fn should_roll_multiple_dims() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1, 2], [3, 4, 5], [6, 7, 8]], &device);
+
+        let output = tensor.roll(&[1, -1], &[0, 1]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[7, 8, 6], [1, 2, 0], [4, 5, 3]]), false);
+    }
+
+    #[test]
+    fn should_roll_bool() {
+        let device = Default::default();
+        let tensor = TestTensorBool::<2>::from_bool(
+            TensorData::from([[true, false, false], [false, false, true]]),
+            &device,
+        );
+
+        let output = tensor.roll(&[4], &[1]);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[false, true, false], [true, false, false]]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shifts_and_dims_differ() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+
+        let _ = tensor.roll(&[1, 2], &[0]);
+    }
+}
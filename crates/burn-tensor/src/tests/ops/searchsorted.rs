@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(searchsorted)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_searchsorted_left_and_right() {
+        let device = Default::default();
+        let sequence = TestTensor::<1>::from_floats([1.0, 3.0, 3.0, 5.0, 7.0], &device);
+        let values = TestTensor::<1>::from_floats([0.0, 3.0, 4.0, 7.0, 9.0], &device);
+
+        let left = sequence.clone().searchsorted(values.clone(), false);
+        let right = sequence.searchsorted(values, true);
+
+        left.into_data()
+            .assert_eq(&TensorData::from([0, 1, 3, 4, 5]), false);
+        right
+            .into_data()
+            .assert_eq(&TensorData::from([0, 3, 3, 5, 5]), false);
+    }
+
+    #[test]
+    fn should_support_batched_searchsorted() {
+        let device = Default::default();
+        let sequences =
+            TestTensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0], [-2.0, 0.0, 2.0, 4.0]], &device);
+        let values = TestTensor::<2>::from_floats([[2.5, 0.0, 4.0], [2.5, 0.0, 4.0]], &device);
+
+        let output = sequences.searchsorted(values, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 0, 3], [3, 1, 3]]), false);
+    }
+
+    #[test]
+    fn should_broadcast_sequence_batch_dims() {
+        let device = Default::default();
+        let sequence = TestTensorInt::<2>::from_ints([[0, 10, 20]], &device);
+        let values = TestTensorInt::<2>::from_ints([[5, 25], [10, -1]], &device);
+
+        let output = sequence.searchsorted(values, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3], [2, 0]]), false);
+    }
+}
@@ -0,0 +1,161 @@
+#[burn_tensor_testgen::testgen(segment)]
+mod tests {
+    use super::*;
+    use burn_tensor::{IndexReduction, TensorData};
+
+    #[test]
+    fn should_support_segment_sum_and_mean() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]], &device);
+        let segment_ids = TestTensorInt::<1>::from_ints([2, 0, 2, 2], &device);
+
+        tensor
+            .clone()
+            .segment_sum(segment_ids.clone(), 4)
+            .into_data()
+            .assert_eq(
+                &TensorData::from([[3.0, 4.0], [0.0, 0.0], [13.0, 16.0], [0.0, 0.0]]),
+                false,
+            );
+        tensor
+            .segment_mean(segment_ids, 4)
+            .into_data()
+            .assert_approx_eq(
+                &TensorData::from([[3.0, 4.0], [0.0, 0.0], [13.0 / 3.0, 16.0 / 3.0], [0.0, 0.0]]),
+                5,
+            );
+    }
+
+    #[test]
+    fn should_support_segment_max_and_min() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [
+                [1.0, -2.0],
+                [3.0, 4.0],
+                [-5.0, 6.0],
+                [7.0, -8.0],
+                [0.5, 0.0],
+            ],
+            &device,
+        );
+        let segment_ids = TestTensorInt::<1>::from_ints([1, 0, 1, 1, 3], &device);
+
+        tensor
+            .clone()
+            .segment_max(segment_ids.clone(), 4)
+            .into_data()
+            .assert_eq(
+                &TensorData::from([[3.0, 4.0], [7.0, 6.0], [0.0, 0.0], [0.5, 0.0]]),
+                false,
+            );
+        tensor.segment_min(segment_ids, 4).into_data().assert_eq(
+            &TensorData::from([[3.0, 4.0], [-5.0, -8.0], [0.0, 0.0], [0.5, 0.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_segment_max_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([4, 9, 2, 7, 7, 1, 3, 8, 6], &device);
+        let segment_ids = TestTensorInt::<1>::from_ints([0, 0, 0, 1, 1, 2, 2, 2, 2], &device);
+
+        tensor
+            .segment_max(segment_ids, 3)
+            .into_data()
+            .assert_eq(&TensorData::from([9, 7, 8]), false);
+    }
+
+    #[test]
+    fn should_support_segment_prod() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([2.0, -3.0, 4.0, 0.5, 5.0], &device);
+        let segment_ids = TestTensorInt::<1>::from_ints([1, 0, 1, 1, 0], &device);
+
+        tensor
+            .segment_prod(segment_ids, 3)
+            .into_data()
+            .assert_eq(&TensorData::from([-15.0, 4.0, 0.0]), false);
+    }
+
+    #[test]
+    fn should_support_segment_reduce_sorted() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [
+                [1.0, -2.0],
+                [3.0, 4.0],
+                [-5.0, 6.0],
+                [7.0, -8.0],
+                [0.5, 0.0],
+            ],
+            &device,
+        );
+        let segment_ids = TestTensorInt::<1>::from_ints([0, 0, 2, 2, 3], &device);
+        let reduce = |reduction| {
+            tensor
+                .clone()
+                .segment_reduce_sorted(segment_ids.clone(), 5, reduction)
+                .into_data()
+        };
+
+        reduce(IndexReduction::Sum).assert_eq(
+            &TensorData::from([[4.0, 2.0], [0.0, 0.0], [2.0, -2.0], [0.5, 0.0], [0.0, 0.0]]),
+            false,
+        );
+        reduce(IndexReduction::Mean).assert_eq(
+            &TensorData::from([[2.0, 1.0], [0.0, 0.0], [1.0, -1.0], [0.5, 0.0], [0.0, 0.0]]),
+            false,
+        );
+        reduce(IndexReduction::Prod).assert_eq(
+            &TensorData::from([
+                [3.0, -8.0],
+                [0.0, 0.0],
+                [-35.0, -48.0],
+                [0.5, 0.0],
+                [0.0, 0.0],
+            ]),
+            false,
+        );
+        reduce(IndexReduction::Amax).assert_eq(
+            &TensorData::from([[3.0, 4.0], [0.0, 0.0], [7.0, 6.0], [0.5, 0.0], [0.0, 0.0]]),
+            false,
+        );
+        reduce(IndexReduction::Amin).assert_eq(
+            &TensorData::from([
+                [1.0, -2.0],
+                [0.0, 0.0],
+                [-5.0, -8.0],
+                [0.5, 0.0],
+                [0.0, 0.0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_segment_sum_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([4, 9, 2, 7, 7, 1], &device);
+        let segment_ids = TestTensorInt::<1>::from_ints([2, 0, 2, 1, 0, 2], &device);
+
+        tensor
+            .segment_sum(segment_ids, 3)
+            .into_data()
+            .assert_eq(&TensorData::from([16, 7, 7]), false);
+    }
+
+    #[test]
+    fn should_support_segment_reduce_sorted_of_empty_tensor() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::empty([0, 2], &device);
+        let segment_ids = TestTensorInt::<1>::empty([0], &device);
+
+        tensor
+            .segment_reduce_sorted(segment_ids, 2, IndexReduction::Amax)
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 0.0], [0.0, 0.0]]), false);
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(slice)]
 mod tests {
     use super::*;
-    use burn_tensor::{Int, Tensor, TensorData};
+    use burn_tensor::{Int, Slice, Tensor, TensorData};
 
     #[test]
     fn should_support_full_sliceing_1d() {
@@ -79,6 +79,92 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_support_slicing_with_steps() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..12, &device).reshape([3, 4]);
+
+        let output = tensor
+            .clone()
+            .slice([Slice::from(..), Slice::from(1..).with_step(2)]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3], [5, 7], [9, 11]]), false);
+
+        let output = tensor.slice([Slice::from(0..3).with_step(2), Slice::from(..3)]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 2], [8, 9, 10]]), false);
+    }
+
+    #[test]
+    fn should_support_slicing_with_negative_steps() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]],
+            &device,
+        );
+
+        let output = tensor.clone().slice([Slice::from(..).with_step(-1)]);
+        output.into_data().assert_eq(
+            &TensorData::from([[5.0, 6.0, 7.0, 8.0, 9.0], [0.0, 1.0, 2.0, 3.0, 4.0]]),
+            false,
+        );
+
+        let output = tensor.slice([Slice::from(..), Slice::from(0..4).with_step(-2)]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 1.0], [8.0, 6.0]]), false);
+    }
+
+    #[test]
+    fn should_support_slicing_with_negative_bounds() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([0.0, 1.0, 2.0, 3.0, 4.0], &device);
+
+        let output = tensor.clone().slice([Slice::new(-3, None, 1)]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 3.0, 4.0]), false);
+
+        let output = tensor.slice([Slice::new(-2, Some(0), -2)]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3.0, 1.0]), false);
+    }
+
+    #[test]
+    fn should_slice_with_negative_steps_from_the_start_to_the_end_like_numpy() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..6, &device);
+
+        // [-1::-2]
+        let output = tensor.clone().slice([Slice::new(-1, None, -2)]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([5, 3, 1]), false);
+
+        // [4:1:-1]
+        let output = tensor.slice([Slice::new(4, Some(1), -1)]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([4, 3, 2]), false);
+    }
+
+    #[test]
+    fn should_support_slicing_bool_with_steps() {
+        let device = Default::default();
+        let tensor = TestTensorBool::<1>::from_bool(
+            TensorData::from([true, false, false, true, true]),
+            &device,
+        );
+
+        let output = tensor.slice([Slice::from(..).with_step(-3)]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([true, false]), false);
+    }
+
     #[test]
     fn should_support_slice_assign_1d() {
         let data = TensorData::from([0.0, 1.0, 2.0]);
@@ -180,4 +266,16 @@ mod tests {
 
         output.into_data().assert_eq(&data, false);
     }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_slice_step_is_zero() {
+        let tensor = TestTensor::<1>::from_floats([0.0, 1.0, 2.0], &Default::default());
+
+        let _ = tensor.slice([Slice {
+            start: 0,
+            end: None,
+            step: 0,
+        }]);
+    }
 }
@@ -0,0 +1,99 @@
+#[burn_tensor_testgen::testgen(special)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_erfinv() {
+        let tensor = TestTensor::<1>::from_floats([0.0, 0.5, -0.9, 0.999], &Default::default());
+
+        let output = tensor.erfinv();
+        let expected = TensorData::from([0.0, 0.47693628, -1.16308715, 2.32675377]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_support_erfinv_of_erf() {
+        let tensor = TestTensor::<2>::from_floats([[-2.0, -0.3], [0.1, 1.5]], &Default::default());
+
+        let output = tensor.clone().erf().erfinv();
+
+        output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_erfinv_at_the_bounds() {
+        let tensor = TestTensor::<1>::from_floats([-1.0, 1.0], &Default::default());
+
+        let output = tensor.erfinv();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([f32::NEG_INFINITY, f32::INFINITY]), false);
+    }
+
+    #[test]
+    fn should_support_lgamma() {
+        let tensor =
+            TestTensor::<1>::from_floats([0.5, 1.0, 2.5, 10.0, -0.5, -2.5], &Default::default());
+
+        let output = tensor.lgamma();
+        let expected = TensorData::from([
+            0.57236494,
+            0.0,
+            0.28468287,
+            12.80182748,
+            1.26551212,
+            -0.05624372,
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_support_digamma() {
+        let tensor =
+            TestTensor::<1>::from_floats([1.0, 0.5, 2.5, 10.0, -0.5, 0.1], &Default::default());
+
+        let output = tensor.digamma();
+        let expected = TensorData::from([
+            -0.57721566,
+            -1.96351003,
+            0.70315664,
+            2.25175259,
+            0.03648997,
+            -10.42375494,
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_return_nan_at_the_poles_of_digamma() {
+        let tensor = TestTensor::<1>::from_floats([0.0, -1.0, -2.0, -2.5], &Default::default());
+
+        let output = tensor.digamma();
+        let expected = TensorData::from([f32::NAN, f32::NAN, f32::NAN, 1.10315664]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_support_polygamma() {
+        let tensor = TestTensor::<1>::from_floats([1.0, 0.5, 2.5, 10.0], &Default::default());
+
+        let output = tensor.clone().polygamma(1);
+        let expected = TensorData::from([1.64493407, 4.93480220, 0.49035776, 0.10516634]);
+        output.into_data().assert_approx_eq(&expected, 4);
+
+        let output = tensor.clone().polygamma(2);
+        let expected = TensorData::from([-2.40411381, -16.82879664, -0.23620405, -0.01104983]);
+        output.into_data().assert_approx_eq(&expected, 3);
+
+        let output = tensor.clone().polygamma(0);
+        output
+            .into_data()
+            .assert_approx_eq(&tensor.digamma().into_data(), 5);
+    }
+}
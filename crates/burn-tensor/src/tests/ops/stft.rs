@@ -0,0 +1,79 @@
+#[burn_tensor_testgen::testgen(stft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{StftOptions, TensorData, WindowFunction};
+
+    #[test]
+    fn should_support_stft() {
+        let device = Default::default();
+        let signal = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]], &device);
+        let options = StftOptions::new(4, 2, WindowFunction::Rectangular, false);
+
+        let (real, imag) = signal.stft(&options);
+
+        real.into_data().assert_approx_eq(
+            &TensorData::from([[[10.0, 18.0], [-2.0, -2.0], [-2.0, -2.0]]]),
+            4,
+        );
+        imag.into_data()
+            .assert_approx_eq(&TensorData::from([[[0.0, 0.0], [2.0, 2.0], [0.0, 0.0]]]), 4);
+    }
+
+    #[test]
+    fn should_support_stft_centered_with_window() {
+        let device = Default::default();
+        let signal = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0, 5.0]], &device);
+        let options = StftOptions::new(4, 2, WindowFunction::Hann, true);
+
+        let (real, imag) = signal.stft(&options);
+
+        real.into_data().assert_approx_eq(
+            &TensorData::from([[[3.0, 6.0, 9.0], [-1.0, -3.0, -5.0], [-1.0, 0.0, 1.0]]]),
+            4,
+        );
+        imag.into_data().assert_approx_eq(
+            &TensorData::from([[[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_support_istft_roundtrip() {
+        let device = Default::default();
+        let signal = TestTensor::<2>::from_floats(
+            [
+                [0.5, -1.0, 2.0, 0.0, 1.5, -0.5, 3.0, 1.0, -2.0, 0.25, 1.0],
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0],
+            ],
+            &device,
+        );
+        let options = StftOptions::new(8, 2, WindowFunction::Hann, true);
+
+        let (real, imag) = signal.clone().stft(&options);
+        let output = real.istft(imag, &options, Some(11));
+
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_istft_without_length() {
+        let device = Default::default();
+        let signal = TestTensor::<2>::from_floats([[1.0, -2.0, 3.0, 0.5, 2.0, 1.0]], &device);
+        let options = StftOptions::new(4, 2, WindowFunction::Rectangular, false);
+
+        let (real, imag) = signal.clone().stft(&options);
+        let output = real.istft(imag, &options, None);
+
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_window_functions() {
+        let hann = WindowFunction::Hann.values(4);
+        let hamming = WindowFunction::Hamming.values(4);
+
+        TensorData::new(hann, [4]).assert_approx_eq(&TensorData::from([0.0, 0.5, 1.0, 0.5]), 5);
+        TensorData::new(hamming, [4])
+            .assert_approx_eq(&TensorData::from([0.08, 0.54, 1.0, 0.54]), 5);
+    }
+}
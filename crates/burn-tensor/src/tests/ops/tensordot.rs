@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(tensordot)]
+mod tests {
+    use super::*;
+    use burn_tensor::{einsum, tensordot, Tensor, TensorData};
+
+    #[test]
+    fn should_support_matmul() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let rhs = TestTensor::<2>::from_floats([[5.0, 6.0], [7.0, 8.0]], &device);
+
+        let output: Tensor<TestBackend, 2> = lhs.tensordot(rhs, ([1], [0]));
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[19.0, 22.0], [43.0, 50.0]]), false);
+    }
+
+    #[test]
+    fn should_contract_multiple_dims_like_einsum() {
+        let device = Default::default();
+        let lhs = TestTensorInt::<1>::arange(0..24, &device)
+            .float()
+            .reshape([2, 3, 4]);
+        let rhs = TestTensorInt::<1>::arange(0..60, &device)
+            .float()
+            .reshape([4, 5, 3]);
+
+        let output: Tensor<TestBackend, 2> = tensordot(lhs.clone(), rhs.clone(), ([1, 2], [2, 0]));
+        let expected: Tensor<TestBackend, 2> = einsum("ijk,klj->il", [lhs.into(), rhs.into()]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_outer_and_full_contractions() {
+        let device = Default::default();
+        let lhs = TestTensor::<1>::from_floats([1.0, 2.0], &device);
+        let rhs = TestTensor::<1>::from_floats([3.0, 4.0], &device);
+
+        let outer: Tensor<TestBackend, 2> = tensordot(lhs.clone(), rhs.clone(), ([], []));
+        let inner: Tensor<TestBackend, 1> = tensordot(lhs, rhs, ([0], [0]));
+
+        outer
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 4.0], [6.0, 8.0]]), false);
+        inner
+            .into_data()
+            .assert_eq(&TensorData::from([11.0]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_different_contracted_sizes() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::zeros([2, 3], &device);
+        let rhs = TestTensor::<2>::zeros([2, 3], &device);
+
+        let _: Tensor<TestBackend, 2> = tensordot(lhs, rhs, ([1], [0]));
+    }
+}
@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_unique() {
+        let tensor = TestTensorInt::<2>::from([[3, 1, 2], [1, 3, 3]]);
+
+        let output = tensor.unique();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3]), false);
+    }
+
+    #[test]
+    fn should_support_unique_with_inverse_and_counts() {
+        let tensor = TestTensor::<2>::from([[3.0, 1.0, 2.0], [1.0, 3.0, 3.0]]);
+
+        let (values, inverse, counts) = tensor.unique_with_inverse_and_counts();
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, 3.0]), false);
+        inverse
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 0, 1], [0, 2, 2]]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 1, 3]), false);
+    }
+
+    #[test]
+    fn should_support_unique_consecutive() {
+        let tensor = TestTensorInt::<1>::from([1, 1, 2, 2, 3, 1, 1, 2]);
+
+        let (values, inverse, counts) = tensor.unique_consecutive_with_inverse_and_counts();
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3, 1, 2]), false);
+        inverse
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 1, 1, 2, 3, 3, 4]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 2, 1, 2, 1]), false);
+    }
+
+    #[test]
+    fn should_support_unique_of_single_element() {
+        let tensor = TestTensorInt::<1>::from([7]);
+
+        let (values, inverse, counts) = tensor.unique_with_inverse_and_counts();
+
+        values.into_data().assert_eq(&TensorData::from([7]), false);
+        inverse.into_data().assert_eq(&TensorData::from([0]), false);
+        counts.into_data().assert_eq(&TensorData::from([1]), false);
+    }
+
+    #[test]
+    fn should_support_unique_of_empty_tensor() {
+        let tensor = TestTensor::<2>::empty([0, 3], &Default::default());
+
+        let (values, inverse, counts) = tensor.clone().unique_with_inverse_and_counts();
+        let consecutive = tensor.unique_consecutive();
+
+        assert_eq!(values.dims(), [0]);
+        assert_eq!(inverse.dims(), [0, 3]);
+        assert_eq!(counts.dims(), [0]);
+        assert_eq!(consecutive.dims(), [0]);
+    }
+}
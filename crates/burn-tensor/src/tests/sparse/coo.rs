@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(sparse_coo)]
+mod tests {
+    use super::*;
+    use burn_tensor::{sparse::SparseTensor, TensorData};
+
+    fn sparse() -> SparseTensor<TestBackend, 2> {
+        let device = Default::default();
+        let indices = TestTensorInt::<2>::from_ints([[0, 1, 2, 0], [1, 0, 2, 1]], &device);
+        let values = TestTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+
+        SparseTensor::new(indices, values, [3, 3])
+    }
+
+    #[test]
+    fn should_convert_to_dense_summing_duplicates() {
+        let output = sparse().to_dense();
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0.0, 5.0, 0.0], [2.0, 0.0, 0.0], [0.0, 0.0, 3.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_roundtrip_to_sparse() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [[[0.0, 1.5], [0.0, 0.0]], [[-2.0, 0.0], [0.0, 3.0]]],
+            &device,
+        );
+
+        let sparse = tensor.clone().to_sparse();
+
+        assert_eq!(sparse.nnz(), 3);
+        sparse
+            .indices()
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 1], [0, 0, 1], [1, 0, 1]]), false);
+        sparse
+            .to_dense()
+            .into_data()
+            .assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    fn should_coalesce_duplicates() {
+        let output = sparse().coalesce();
+
+        assert_eq!(output.nnz(), 3);
+        output
+            .indices()
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 2], [1, 0, 2]]), false);
+        output
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([5.0, 2.0, 3.0]), false);
+    }
+
+    #[test]
+    fn should_support_spmm() {
+        let device = Default::default();
+        let rhs = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+        let expected = sparse().to_dense().matmul(rhs.clone());
+
+        let output = sparse().spmm(rhs);
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn should_support_add_and_transpose() {
+        let device = Default::default();
+        let dense = TestTensor::<2>::from_floats(
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            &device,
+        );
+
+        let output = (sparse() + sparse().transpose()).add_dense(dense);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, 7.0, 0.0], [7.0, 1.0, 0.0], [0.0, 0.0, 7.0]]),
+            false,
+        );
+    }
+}
@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(sparse_csr)]
+mod tests {
+    use super::*;
+    use burn_tensor::{sparse::SparseCsrTensor, TensorData};
+
+    fn dense() -> TestTensor<2> {
+        TestTensor::from([
+            [0.0, 2.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [3.0, 0.0, -1.0, 0.0],
+            [0.0, 0.0, 0.0, 4.0],
+        ])
+    }
+
+    #[test]
+    fn should_convert_from_dense() {
+        let sparse = dense().to_sparse_csr();
+
+        sparse
+            .row_offsets()
+            .into_data()
+            .assert_eq(&TensorData::from([0, 2, 2, 4, 5]), false);
+        sparse
+            .col_indices()
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3, 0, 2, 3]), false);
+        sparse
+            .to_dense()
+            .into_data()
+            .assert_eq(&dense().into_data(), false);
+    }
+
+    #[test]
+    fn should_slice_rows() {
+        let output = dense().to_sparse_csr().slice_rows(1..3);
+
+        assert_eq!(output.nnz(), 2);
+        output
+            .row_offsets()
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 2]), false);
+        output.to_dense().into_data().assert_eq(
+            &TensorData::from([[0.0, 0.0, 0.0, 0.0], [3.0, 0.0, -1.0, 0.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_spmm() {
+        let device = Default::default();
+        let rhs =
+            TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]], &device);
+
+        let output = dense().to_sparse_csr().spmm(rhs.clone());
+
+        output
+            .into_data()
+            .assert_eq(&dense().matmul(rhs).into_data(), false);
+    }
+
+    #[test]
+    fn should_support_spmv() {
+        let device = Default::default();
+        let row_offsets = TestTensorInt::<1>::from_ints([0, 1, 3], &device);
+        let col_indices = TestTensorInt::<1>::from_ints([2, 0, 1], &device);
+        let values = TestTensor::<1>::from_floats([2.0, -1.0, 0.5], &device);
+        let sparse = SparseCsrTensor::new(row_offsets, col_indices, values, [2, 3]);
+        let vector = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+
+        let output = sparse.spmv(vector);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([6.0, 0.0]), false);
+    }
+}
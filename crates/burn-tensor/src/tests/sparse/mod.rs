@@ -0,0 +1,2 @@
+pub(crate) mod coo;
+pub(crate) mod csr;
@@ -2,7 +2,7 @@
 mod tests {
     use super::*;
     use burn_tensor::backend::Backend;
-    use burn_tensor::{Shape, Tensor, TensorData};
+    use burn_tensor::{PrintOptions, Shape, Tensor, TensorData};
 
     type FloatElem = <TestBackend as Backend>::FloatElem;
     type IntElem = <TestBackend as Backend>::IntElem;
@@ -281,4 +281,58 @@ mod tests {
         );
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_display_with_precision() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.26], [-3.126, 1.0 / 3.0]]);
+        let options = PrintOptions {
+            precision: Some(2),
+            compact: true,
+            ..Default::default()
+        };
+
+        let output = format!("{}", tensor.display_with(options));
+        let expected = format!(
+            "tensor([[1.00, 2.26],\n        [-3.13, 0.33]], shape=[2, 2], device={:?}, dtype=f32)",
+            tensor.device(),
+        );
+        assert_eq!(output, expected);
+
+        // The precision of the formatter takes precedence.
+        let output = format!("{:.1}", tensor.display_with(options));
+        assert!(output.starts_with("tensor([[1.0, 2.3],\n        [-3.1, 0.3]]"));
+    }
+
+    #[test]
+    fn test_display_with_sci_mode() {
+        let tensor = TestTensor::<1>::from([1500.0, 0.00025, 0.0]);
+        let options = PrintOptions {
+            precision: Some(1),
+            sci_mode: true,
+            compact: true,
+            ..Default::default()
+        };
+
+        let output = format!("{}", tensor.display_with(options));
+        assert!(output.starts_with("tensor([1.5e3, 2.5e-4, 0.0e0], shape=[3]"));
+    }
+
+    #[test]
+    fn test_display_with_edge_items() {
+        let tensor = TestTensorInt::<1>::arange(0..10, &Default::default());
+        let options = PrintOptions {
+            threshold: 5,
+            edge_items: 2,
+            compact: true,
+            ..Default::default()
+        };
+
+        let output = format!("{}", tensor.display_with(options));
+        let expected = format!(
+            "tensor([0, 1, ..., 8, 9], shape=[10], device={:?}, dtype={dtype})",
+            tensor.device(),
+            dtype = core::any::type_name::<IntElem>(),
+        );
+        assert_eq!(output, expected);
+    }
 }
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(histogram)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_histc() {
+        let tensor = TestTensor::<2>::from([[0.0, 0.5, 1.0], [2.9, 3.0, 4.0]]);
+
+        let output = tensor.histc(3, 0.0, 3.0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 1.0, 2.0]), false);
+    }
+
+    #[test]
+    fn should_support_histc_with_data_range() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 1.0, 5.0]);
+
+        let output = tensor.histc(4, 0.0, 0.0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 1.0, 0.0, 1.0]), false);
+    }
+
+    #[test]
+    fn should_support_histogram_with_edges() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([-1.0, 0.0, 0.5, 1.0, 3.0, 10.0, 11.0], &device);
+        let edges = TestTensor::<1>::from_floats([0.0, 1.0, 10.0], &device);
+
+        let output = tensor.histogram(edges);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 3.0]), false);
+    }
+
+    #[test]
+    fn should_support_histogramdd() {
+        let points =
+            TestTensor::<2>::from([[0.1, 0.1], [0.9, 0.2], [0.8, 0.9], [0.7, 0.6], [2.0, 0.5]]);
+
+        let output = points.histogramdd([2, 2], [(0.0, 1.0), (0.0, 1.0)]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 0.0], [1.0, 2.0]]), false);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{TraceBridge, TraceTensor};
+use burn_common::sync_type::SyncType;
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    ops::{BoolTensor, FloatTensor, IntTensor},
+};
+use core::marker::PhantomData;
+
+/// Record the graph of the tensor operations.
+///
+/// This works as a backend decorator: each tensor of the inner backend is given an
+/// [identifier](crate::TensorId), and the operations forwarded to the inner backend are recorded
+/// while [tracing](crate::trace), with the tensors they read and create. Outside of a trace, the
+/// operations are only forwarded.
+///
+/// The operations are recorded as they are called on the decorated backend, so the operations
+/// implemented by the inner backend with other operations, or fused by it, appear as a single
+/// node, while the ones implemented by default with other operations appear decomposed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Trace<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for Trace<B> {
+    type Device = B::Device;
+
+    type FullPrecisionBridge = TraceBridge<B::FullPrecisionBridge>;
+
+    type FloatTensorPrimitive<const D: usize> = TraceTensor<B::FloatTensorPrimitive<D>>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = TraceTensor<B::IntTensorPrimitive<D>>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = TraceTensor<B::BoolTensorPrimitive<D>>;
+
+    fn ad_enabled() -> bool {
+        B::ad_enabled()
+    }
+
+    fn name() -> String {
+        format!("trace<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn sync(device: &B::Device, sync_type: SyncType) {
+        B::sync(device, sync_type)
+    }
+}
+
+impl<B: AutodiffBackend> AutodiffBackend for Trace<B> {
+    type InnerBackend = Trace<B::InnerBackend>;
+    type Gradients = B::Gradients;
+
+    fn backward<const D: usize>(tensor: FloatTensor<Self, D>) -> Self::Gradients {
+        B::backward(tensor.primitive)
+    }
+
+    fn backward_with_hook<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        hook: &mut dyn FnMut(&Self::Gradients),
+    ) -> Self::Gradients {
+        B::backward_with_hook(tensor.primitive, hook)
+    }
+
+    fn grad_complete<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+        grads: &Self::Gradients,
+    ) -> bool {
+        B::grad_complete(&tensor.primitive, grads)
+    }
+
+    fn grad<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+        grads: &Self::Gradients,
+    ) -> Option<FloatTensor<Self::InnerBackend, D>> {
+        B::grad(&tensor.primitive, grads).map(TraceTensor::new)
+    }
+
+    fn grad_remove<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+        grads: &mut Self::Gradients,
+    ) -> Option<FloatTensor<Self::InnerBackend, D>> {
+        B::grad_remove(&tensor.primitive, grads).map(TraceTensor::new)
+    }
+
+    fn grad_replace<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+        grads: &mut Self::Gradients,
+        grad: FloatTensor<Self::InnerBackend, D>,
+    ) {
+        B::grad_replace(&tensor.primitive, grads, grad.primitive)
+    }
+
+    fn inner<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self::InnerBackend, D> {
+        tensor.map(B::inner)
+    }
+
+    fn int_inner<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self::InnerBackend, D> {
+        tensor.map(B::int_inner)
+    }
+
+    fn bool_inner<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self::InnerBackend, D> {
+        tensor.map(B::bool_inner)
+    }
+
+    fn from_inner<const D: usize>(
+        tensor: FloatTensor<Self::InnerBackend, D>,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(B::from_inner)
+    }
+
+    fn int_from_inner<const D: usize>(
+        tensor: IntTensor<Self::InnerBackend, D>,
+    ) -> IntTensor<Self, D> {
+        tensor.map(B::int_from_inner)
+    }
+
+    fn bool_from_inner<const D: usize>(
+        tensor: BoolTensor<Self::InnerBackend, D>,
+    ) -> BoolTensor<Self, D> {
+        tensor.map(B::bool_from_inner)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{Trace, TraceTensor};
+use burn_tensor::{
+    backend::{Backend, BackendBridge},
+    ops::FloatTensor,
+    Device,
+};
+use core::marker::PhantomData;
+
+/// Trace the tensors converted by a [backend bridge](BackendBridge).
+#[derive(Debug)]
+pub struct TraceBridge<Bridge> {
+    _p: PhantomData<Bridge>,
+}
+
+impl<B, Bridge> BackendBridge<Trace<B>> for TraceBridge<Bridge>
+where
+    B: Backend,
+    Bridge: BackendBridge<B> + 'static,
+{
+    type Target = Trace<Bridge::Target>;
+
+    fn into_target<const D: usize>(
+        tensor: FloatTensor<Trace<B>, D>,
+        device: Option<Device<Self::Target>>,
+    ) -> FloatTensor<Self::Target, D> {
+        TraceTensor::new(Bridge::into_target(tensor.primitive, device))
+    }
+
+    fn from_target<const D: usize>(
+        tensor: FloatTensor<Self::Target, D>,
+        device: Option<Device<Trace<B>>>,
+    ) -> FloatTensor<Trace<B>, D> {
+        TraceTensor::new(Bridge::from_target(tensor.primitive, device))
+    }
+}
@@ -0,0 +1,137 @@
+use crate::TensorId;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// The kind of the elements of a traced tensor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TensorKind {
+    /// Floating point elements.
+    Float,
+    /// Integer elements.
+    Int,
+    /// Boolean elements.
+    Bool,
+}
+
+/// A tensor read or created by a traced operation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TracedTensor {
+    /// The identifier of the tensor.
+    pub id: TensorId,
+    /// The kind of the elements of the tensor.
+    pub kind: TensorKind,
+    /// The shape of the tensor.
+    pub shape: Vec<usize>,
+}
+
+/// An operation of a [traced graph](TraceGraph).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceNode {
+    /// The name of the operation, the one of the backend method, e.g. `float_matmul`.
+    pub op: String,
+    /// The tensors read by the operation.
+    pub inputs: Vec<TracedTensor>,
+    /// The tensors created by the operation, none when the operation reads the data of a
+    /// tensor, e.g. `float_into_data`, which synchronizes the inner backend.
+    pub outputs: Vec<TracedTensor>,
+}
+
+/// The graph of the tensor operations recorded by [trace](crate::trace), in the order they were
+/// executed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceGraph {
+    /// The operations of the graph, in the order they were executed.
+    pub nodes: Vec<TraceNode>,
+}
+
+impl TraceGraph {
+    /// The tensors read by the operations of the graph without being created by one of them,
+    /// e.g. the inputs and the parameters of a model.
+    pub fn inputs(&self) -> Vec<&TracedTensor> {
+        let created = self.created();
+        let mut visited = HashSet::new();
+
+        self.nodes
+            .iter()
+            .flat_map(|node| node.inputs.iter())
+            .filter(|tensor| !created.contains_key(&tensor.id) && visited.insert(tensor.id))
+            .collect()
+    }
+
+    /// The tensors created by the operations of the graph without being read by one of them,
+    /// e.g. the outputs of a model.
+    pub fn outputs(&self) -> Vec<&TracedTensor> {
+        let read: HashSet<_> = self
+            .nodes
+            .iter()
+            .flat_map(|node| node.inputs.iter().map(|tensor| tensor.id))
+            .collect();
+
+        self.nodes
+            .iter()
+            .flat_map(|node| node.outputs.iter())
+            .filter(|tensor| !read.contains(&tensor.id))
+            .collect()
+    }
+
+    /// Export the graph to the [DOT](https://graphviz.org/doc/info/lang.html) language, e.g. to
+    /// render it with Graphviz.
+    ///
+    /// The operations are boxes, linked by the tensors they read, labeled by their shape, while
+    /// the [inputs](Self::inputs) and [outputs](Self::outputs) of the graph are ellipses.
+    pub fn to_dot(&self) -> String {
+        let created = self.created();
+        let mut dot = String::from("digraph {\n    node [shape=box];\n");
+
+        for tensor in self.inputs().into_iter().chain(self.outputs()) {
+            writeln!(
+                dot,
+                "    t{} [label=\"{:?} {:?}\", shape=ellipse];",
+                tensor.id.value(),
+                tensor.kind,
+                tensor.shape
+            )
+            .unwrap();
+        }
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            writeln!(dot, "    op{index} [label=\"{}\"];", node.op).unwrap();
+
+            for tensor in node.inputs.iter() {
+                let source = match created.get(&tensor.id) {
+                    Some(producer) => format!("op{producer}"),
+                    None => format!("t{}", tensor.id.value()),
+                };
+                writeln!(
+                    dot,
+                    "    {source} -> op{index} [label=\"{:?}\"];",
+                    tensor.shape
+                )
+                .unwrap();
+            }
+        }
+
+        for tensor in self.outputs() {
+            let producer = created[&tensor.id];
+            writeln!(dot, "    op{producer} -> t{};", tensor.id.value()).unwrap();
+        }
+
+        dot.push('}');
+        dot
+    }
+
+    /// Export the graph to JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("The graph should be serializable to JSON.")
+    }
+
+    /// The index of the operation creating each tensor of the graph.
+    fn created(&self) -> HashMap<TensorId, usize> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .flat_map(|(index, node)| node.outputs.iter().map(move |tensor| (tensor.id, index)))
+            .collect()
+    }
+}
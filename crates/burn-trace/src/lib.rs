@@ -0,0 +1,30 @@
+#![warn(missing_docs)]
+
+//! # Burn Trace
+//!
+//! This library records the graph of the tensor operations executed by the Burn project, e.g.
+//! during the forward pass of a model, to visualize its architecture.
+//!
+//! The recording is done by the [Trace] backend decorator: every tensor of the decorated backend
+//! is identified by a node of the graph, and each operation forwarded to the inner backend while
+//! [tracing](trace) is recorded with the tensors it reads and creates. The recorded
+//! [graph](TraceGraph) can be exported to [DOT](TraceGraph::to_dot), e.g. for Graphviz, or to
+//! [JSON](TraceGraph::to_json).
+
+/// Operation module.
+pub mod ops;
+
+mod backend;
+mod bridge;
+mod graph;
+mod recorder;
+mod tensor;
+
+pub use backend::*;
+pub use bridge::*;
+pub use graph::*;
+pub use recorder::*;
+pub use tensor::*;
+
+#[cfg(test)]
+mod tests;
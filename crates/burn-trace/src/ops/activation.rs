@@ -0,0 +1,105 @@
+use crate::{recorder::Op, Trace};
+use burn_tensor::{
+    backend::Backend,
+    ops::{ActivationOps, FloatElem, FloatTensor},
+};
+
+impl<B: Backend> ActivationOps<Self> for Trace<B> {
+    fn leaky_relu<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        negative_slope: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("leaky_relu").float::<B, D>(&tensor);
+        let output = B::leaky_relu(tensor.primitive, negative_slope);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("relu").float::<B, D>(&tensor);
+        let output = B::relu(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn relu_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("relu_backward")
+            .float::<B, D>(&output)
+            .float::<B, D>(&grad);
+        let output = B::relu_backward(output.primitive, grad.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn gelu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("gelu").float::<B, D>(&tensor);
+        let output = B::gelu(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn prelu<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        alpha: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("prelu")
+            .float::<B, D>(&tensor)
+            .float::<B, D>(&alpha);
+        let output = B::prelu(tensor.primitive, alpha.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn gelu_backward<const D: usize>(
+        x: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("gelu_backward")
+            .float::<B, D>(&x)
+            .float::<B, D>(&grad);
+        let output = B::gelu_backward(x.primitive, grad.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("sigmoid").float::<B, D>(&tensor);
+        let output = B::sigmoid(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn sigmoid_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("sigmoid_backward")
+            .float::<B, D>(&output)
+            .float::<B, D>(&grad);
+        let output = B::sigmoid_backward(output.primitive, grad.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn log_sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("log_sigmoid").float::<B, D>(&tensor);
+        let output = B::log_sigmoid(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn log_sigmoid_backward<const D: usize>(
+        x: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("log_sigmoid_backward")
+            .float::<B, D>(&x)
+            .float::<B, D>(&grad);
+        let output = B::log_sigmoid_backward(x.primitive, grad.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{recorder::Op, Trace};
+use burn_common::reader::Reader;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, BoolTensorOps, FloatTensor, IntTensor},
+    Device, Shape, TensorData,
+};
+use core::ops::Range;
+
+impl<B: Backend> BoolTensorOps<Self> for Trace<B> {
+    fn bool_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_empty");
+        let output = B::bool_empty(shape, device);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<Self, D>) -> Shape<D> {
+        B::bool_shape(&tensor.primitive)
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<Self, D>) -> Reader<TensorData> {
+        let _op = Op::new("bool_into_data").bool::<B, D>(&tensor);
+
+        B::bool_into_data(tensor.primitive)
+    }
+
+    fn bool_checksum<const D: usize>(tensor: BoolTensor<Self, D>) -> Reader<u64> {
+        let _op = Op::new("bool_checksum").bool::<B, D>(&tensor);
+
+        B::bool_checksum(tensor.primitive)
+    }
+
+    fn bool_to_data<const D: usize>(tensor: &BoolTensor<Self, D>) -> Reader<TensorData> {
+        let _op = Op::new("bool_to_data").bool::<B, D>(tensor);
+
+        B::bool_to_data(&tensor.primitive)
+    }
+
+    fn bool_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_from_data");
+        let output = B::bool_from_data(data, device);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_into_int<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        let mut op = Op::new("bool_into_int").bool::<B, D>(&tensor);
+        let output = B::bool_into_int(tensor.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("bool_into_float").bool::<B, D>(&tensor);
+        let output = B::bool_into_float(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<Self, D>) -> Device<Self> {
+        B::bool_device(&tensor.primitive)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_to_device").bool::<B, D>(&tensor);
+        let output = B::bool_to_device(tensor.primitive, device);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        let mut op = Op::new("bool_reshape").bool::<B, D1>(&tensor);
+        let output = B::bool_reshape(tensor.primitive, shape);
+
+        op.bool_output::<B, D2>(output)
+    }
+
+    fn bool_slice<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> BoolTensor<Self, D1> {
+        let mut op = Op::new("bool_slice").bool::<B, D1>(&tensor);
+        let output = B::bool_slice(tensor.primitive, ranges);
+
+        op.bool_output::<B, D1>(output)
+    }
+
+    fn bool_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> BoolTensor<Self, D1> {
+        let mut op = Op::new("bool_slice_with_steps").bool::<B, D1>(&tensor);
+        let output = B::bool_slice_with_steps(tensor.primitive, ranges, steps);
+
+        op.bool_output::<B, D1>(output)
+    }
+
+    fn bool_slice_assign<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: BoolTensor<Self, D1>,
+    ) -> BoolTensor<Self, D1> {
+        let mut op = Op::new("bool_slice_assign")
+            .bool::<B, D1>(&tensor)
+            .bool::<B, D1>(&value);
+        let output = B::bool_slice_assign(tensor.primitive, ranges, value.primitive);
+
+        op.bool_output::<B, D1>(output)
+    }
+
+    fn bool_repeat<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_repeat").bool::<B, D>(&tensor);
+        let output = B::bool_repeat(tensor.primitive, dim, times);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_cat<const D: usize>(
+        tensors: Vec<BoolTensor<Self, D>>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_cat").bool_all::<B, D>(&tensors);
+        let output = B::bool_cat(
+            tensors.into_iter().map(|tensor| tensor.primitive).collect(),
+            dim,
+        );
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_equal").bool::<B, D>(&lhs).bool::<B, D>(&rhs);
+        let output = B::bool_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_not_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_not_equal")
+            .bool::<B, D>(&lhs)
+            .bool::<B, D>(&rhs);
+        let output = B::bool_not_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_not<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_not").bool::<B, D>(&tensor);
+        let output = B::bool_not(tensor.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_transpose<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_transpose").bool::<B, D>(&tensor);
+        let output = B::bool_transpose(tensor.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_swap_dims<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_swap_dims").bool::<B, D>(&tensor);
+        let output = B::bool_swap_dims(tensor.primitive, dim1, dim2);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_permute<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: [usize; D],
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_permute").bool::<B, D>(&tensor);
+        let output = B::bool_permute(tensor.primitive, axes);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_flip<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: &[usize],
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_flip").bool::<B, D>(&tensor);
+        let output = B::bool_flip(tensor.primitive, axes);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_narrow<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_narrow").bool::<B, D>(&tensor);
+        let output = B::bool_narrow(tensor.primitive, dim, start, length);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_chunk<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<BoolTensor<Self, D>> {
+        let mut op = Op::new("bool_chunk").bool::<B, D>(&tensor);
+        let outputs = B::bool_chunk(tensor.primitive, chunks, dim);
+
+        outputs
+            .into_iter()
+            .map(|output| op.bool_output::<B, D>(output))
+            .collect()
+    }
+
+    fn bool_any<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let mut op = Op::new("bool_any").bool::<B, D>(&tensor);
+        let output = B::bool_any(tensor.primitive);
+
+        op.bool_output::<B, 1>(output)
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_any_dim").bool::<B, D>(&tensor);
+        let output = B::bool_any_dim(tensor.primitive, dim);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_all<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let mut op = Op::new("bool_all").bool::<B, D>(&tensor);
+        let output = B::bool_all(tensor.primitive);
+
+        op.bool_output::<B, 1>(output)
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("bool_all_dim").bool::<B, D>(&tensor);
+        let output = B::bool_all_dim(tensor.primitive, dim);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn bool_argwhere<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, 2> {
+        let mut op = Op::new("bool_argwhere").bool::<B, D>(&tensor);
+        let output = B::bool_argwhere(tensor.primitive);
+
+        op.int_output::<B, 2>(output)
+    }
+
+    fn bool_nonzero<const D: usize>(tensor: BoolTensor<Self, D>) -> Vec<IntTensor<Self, 1>> {
+        let mut op = Op::new("bool_nonzero").bool::<B, D>(&tensor);
+        let outputs = B::bool_nonzero(tensor.primitive);
+
+        outputs
+            .into_iter()
+            .map(|output| op.int_output::<B, 1>(output))
+            .collect()
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        let mut op = Op::new("bool_expand").bool::<B, D1>(&tensor);
+        let output = B::bool_expand(tensor.primitive, shape);
+
+        op.bool_output::<B, D2>(output)
+    }
+
+    fn bool_pack_bits<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        let mut op = Op::new("bool_pack_bits").bool::<B, D>(&tensor);
+        let output = B::bool_pack_bits(tensor.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+}
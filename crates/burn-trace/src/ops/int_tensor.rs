@@ -0,0 +1,843 @@
+use crate::{recorder::Op, Trace};
+use burn_common::reader::Reader;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
+    Device, Distribution, Shape, TensorData,
+};
+use core::ops::Range;
+
+impl<B: Backend> IntTensorOps<Self> for Trace<B> {
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_empty");
+        let output = B::int_empty(shape, device);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_shape<const D: usize>(tensor: &IntTensor<Self, D>) -> Shape<D> {
+        B::int_shape(&tensor.primitive)
+    }
+
+    fn int_into_data<const D: usize>(tensor: IntTensor<Self, D>) -> Reader<TensorData> {
+        let _op = Op::new("int_into_data").int::<B, D>(&tensor);
+
+        B::int_into_data(tensor.primitive)
+    }
+
+    fn int_checksum<const D: usize>(tensor: IntTensor<Self, D>) -> Reader<u64> {
+        let _op = Op::new("int_checksum").int::<B, D>(&tensor);
+
+        B::int_checksum(tensor.primitive)
+    }
+
+    fn int_to_data<const D: usize>(tensor: &IntTensor<Self, D>) -> Reader<TensorData> {
+        let _op = Op::new("int_to_data").int::<B, D>(tensor);
+
+        B::int_to_data(&tensor.primitive)
+    }
+
+    fn int_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_from_data");
+        let output = B::int_from_data(data, device);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<Self, D>) -> Device<Self> {
+        B::int_device(&tensor.primitive)
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_to_device").int::<B, D>(&tensor);
+        let output = B::int_to_device(tensor.primitive, device);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        let mut op = Op::new("int_reshape").int::<B, D1>(&tensor);
+        let output = B::int_reshape(tensor.primitive, shape);
+
+        op.int_output::<B, D2>(output)
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+    ) -> IntTensor<Self, D1> {
+        let mut op = Op::new("int_slice").int::<B, D1>(&tensor);
+        let output = B::int_slice(tensor.primitive, indices);
+
+        op.int_output::<B, D1>(output)
+    }
+
+    fn int_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> IntTensor<Self, D1> {
+        let mut op = Op::new("int_slice_with_steps").int::<B, D1>(&tensor);
+        let output = B::int_slice_with_steps(tensor.primitive, ranges, steps);
+
+        op.int_output::<B, D1>(output)
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+        value: IntTensor<Self, D1>,
+    ) -> IntTensor<Self, D1> {
+        let mut op = Op::new("int_slice_assign")
+            .int::<B, D1>(&tensor)
+            .int::<B, D1>(&value);
+        let output = B::int_slice_assign(tensor.primitive, indices, value.primitive);
+
+        op.int_output::<B, D1>(output)
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("int_into_float").int::<B, D>(&tensor);
+        let output = B::int_into_float(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        source: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_mask_where")
+            .int::<B, D>(&tensor)
+            .bool::<B, D>(&mask)
+            .int::<B, D>(&source);
+        let output = B::int_mask_where(tensor.primitive, mask.primitive, source.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_mask_fill")
+            .int::<B, D>(&tensor)
+            .bool::<B, D>(&mask);
+        let output = B::int_mask_fill(tensor.primitive, mask.primitive, value);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_gather")
+            .int::<B, D>(&tensor)
+            .int::<B, D>(&indices);
+        let output = B::int_gather(dim, tensor.primitive, indices.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_scatter")
+            .int::<B, D>(&tensor)
+            .int::<B, D>(&indices)
+            .int::<B, D>(&value);
+        let output = B::int_scatter(dim, tensor.primitive, indices.primitive, value.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_select")
+            .int::<B, D>(&tensor)
+            .int::<B, 1>(&indices);
+        let output = B::int_select(tensor.primitive, dim, indices.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_select_assign")
+            .int::<B, D>(&tensor)
+            .int::<B, 1>(&indices)
+            .int::<B, D>(&value);
+        let output =
+            B::int_select_assign(tensor.primitive, dim, indices.primitive, value.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_repeat<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_repeat").int::<B, D>(&tensor);
+        let output = B::int_repeat(tensor.primitive, dim, times);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<Self, D>>, dim: usize) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_cat").int_all::<B, D>(&tensors);
+        let output = B::int_cat(
+            tensors.into_iter().map(|tensor| tensor.primitive).collect(),
+            dim,
+        );
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_equal").int::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::int_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_not_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_not_equal").int::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::int_not_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_equal_elem").int::<B, D>(&lhs);
+        let output = B::int_equal_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_not_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_not_equal_elem").int::<B, D>(&lhs);
+        let output = B::int_not_equal_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_greater<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_greater").int::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::int_greater(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_greater_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_greater_elem").int::<B, D>(&lhs);
+        let output = B::int_greater_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_greater_equal")
+            .int::<B, D>(&lhs)
+            .int::<B, D>(&rhs);
+        let output = B::int_greater_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_greater_equal_elem").int::<B, D>(&lhs);
+        let output = B::int_greater_equal_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_lower<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_lower").int::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::int_lower(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_lower_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_lower_elem").int::<B, D>(&lhs);
+        let output = B::int_lower_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_lower_equal")
+            .int::<B, D>(&lhs)
+            .int::<B, D>(&rhs);
+        let output = B::int_lower_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_lower_equal_elem").int::<B, D>(&lhs);
+        let output = B::int_lower_equal_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_add").int::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::int_add(lhs.primitive, rhs.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_add_scalar").int::<B, D>(&lhs);
+        let output = B::int_add_scalar(lhs.primitive, rhs);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_powi<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_powi").int::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::int_powi(lhs.primitive, rhs.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_powf<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_powf").int::<B, D>(&lhs).float::<B, D>(&rhs);
+        let output = B::int_powf(lhs.primitive, rhs.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_powi_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_powi_scalar").int::<B, D>(&lhs);
+        let output = B::int_powi_scalar(lhs.primitive, rhs);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_powf_scalar<const D: usize>(lhs: IntTensor<Self, D>, rhs: f32) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_powf_scalar").int::<B, D>(&lhs);
+        let output = B::int_powf_scalar(lhs.primitive, rhs);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_clamp_min<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_clamp_min").int::<B, D>(&tensor);
+        let output = B::int_clamp_min(tensor.primitive, min);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_clamp_max<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_clamp_max").int::<B, D>(&tensor);
+        let output = B::int_clamp_max(tensor.primitive, max);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_clamp<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_clamp").int::<B, D>(&tensor);
+        let output = B::int_clamp(tensor.primitive, min, max);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_sub").int::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::int_sub(lhs.primitive, rhs.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_sub_scalar").int::<B, D>(&lhs);
+        let output = B::int_sub_scalar(lhs.primitive, rhs);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_mul").int::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::int_mul(lhs.primitive, rhs.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_mul_scalar").int::<B, D>(&lhs);
+        let output = B::int_mul_scalar(lhs.primitive, rhs);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_div<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_div").int::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::int_div(lhs.primitive, rhs.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_div_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_div_scalar").int::<B, D>(&lhs);
+        let output = B::int_div_scalar(lhs.primitive, rhs);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_remainder_scalar").int::<B, D>(&lhs);
+        let output = B::int_remainder_scalar(lhs.primitive, rhs);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_neg<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_neg").int::<B, D>(&tensor);
+        let output = B::int_neg(tensor.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_zeros");
+        let output = B::int_zeros(shape, device);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_ones");
+        let output = B::int_ones(shape, device);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: IntElem<Self>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_full");
+        let output = B::int_full(shape, fill_value, device);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let mut op = Op::new("int_sum").int::<B, D>(&tensor);
+        let output = B::int_sum(tensor.primitive);
+
+        op.int_output::<B, 1>(output)
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_sum_dim").int::<B, D>(&tensor);
+        let output = B::int_sum_dim(tensor.primitive, dim);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_prod<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let mut op = Op::new("int_prod").int::<B, D>(&tensor);
+        let output = B::int_prod(tensor.primitive);
+
+        op.int_output::<B, 1>(output)
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_prod_dim").int::<B, D>(&tensor);
+        let output = B::int_prod_dim(tensor.primitive, dim);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_mean<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let mut op = Op::new("int_mean").int::<B, D>(&tensor);
+        let output = B::int_mean(tensor.primitive);
+
+        op.int_output::<B, 1>(output)
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_mean_dim").int::<B, D>(&tensor);
+        let output = B::int_mean_dim(tensor.primitive, dim);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_argmax").int::<B, D>(&tensor);
+        let output = B::int_argmax(tensor.primitive, dim);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_argmin").int::<B, D>(&tensor);
+        let output = B::int_argmin(tensor.primitive, dim);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_max<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let mut op = Op::new("int_max").int::<B, D>(&tensor);
+        let output = B::int_max(tensor.primitive);
+
+        op.int_output::<B, 1>(output)
+    }
+
+    fn int_max_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_max_dim").int::<B, D>(&tensor);
+        let output = B::int_max_dim(tensor.primitive, dim);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_max_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        let mut op = Op::new("int_max_dim_with_indices").int::<B, D>(&tensor);
+        let (output_0, output_1) = B::int_max_dim_with_indices(tensor.primitive, dim);
+
+        (
+            op.int_output::<B, D>(output_0),
+            op.int_output::<B, D>(output_1),
+        )
+    }
+
+    fn int_min<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let mut op = Op::new("int_min").int::<B, D>(&tensor);
+        let output = B::int_min(tensor.primitive);
+
+        op.int_output::<B, 1>(output)
+    }
+
+    fn int_min_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_min_dim").int::<B, D>(&tensor);
+        let output = B::int_min_dim(tensor.primitive, dim);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_min_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        let mut op = Op::new("int_min_dim_with_indices").int::<B, D>(&tensor);
+        let (output_0, output_1) = B::int_min_dim_with_indices(tensor.primitive, dim);
+
+        (
+            op.int_output::<B, D>(output_0),
+            op.int_output::<B, D>(output_1),
+        )
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_abs").int::<B, D>(&tensor);
+        let output = B::int_abs(tensor.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_transpose<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_transpose").int::<B, D>(&tensor);
+        let output = B::int_transpose(tensor.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_swap_dims").int::<B, D>(&tensor);
+        let output = B::int_swap_dims(tensor.primitive, dim1, dim2);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_permute<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        axes: [usize; D],
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_permute").int::<B, D>(&tensor);
+        let output = B::int_permute(tensor.primitive, axes);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_flip").int::<B, D>(&tensor);
+        let output = B::int_flip(tensor.primitive, axes);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_narrow<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_narrow").int::<B, D>(&tensor);
+        let output = B::int_narrow(tensor.primitive, dim, start, length);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_chunk<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<IntTensor<Self, D>> {
+        let mut op = Op::new("int_chunk").int::<B, D>(&tensor);
+        let outputs = B::int_chunk(tensor.primitive, chunks, dim);
+
+        outputs
+            .into_iter()
+            .map(|output| op.int_output::<B, D>(output))
+            .collect()
+    }
+
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_random");
+        let output = B::int_random(shape, distribution, device);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_random_seeded<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        seed: u64,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_random_seeded");
+        let output = B::int_random_seeded(shape, distribution, seed, device);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_arange_step(
+        range: Range<i64>,
+        step: usize,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, 1> {
+        let mut op = Op::new("int_arange_step");
+        let output = B::int_arange_step(range, step, device);
+
+        op.int_output::<B, 1>(output)
+    }
+
+    fn int_arange(range: Range<i64>, device: &Device<Self>) -> IntTensor<Self, 1> {
+        let mut op = Op::new("int_arange");
+        let output = B::int_arange(range, device);
+
+        op.int_output::<B, 1>(output)
+    }
+
+    fn int_any<const D: usize>(tensor: IntTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let mut op = Op::new("int_any").int::<B, D>(&tensor);
+        let output = B::int_any(tensor.primitive);
+
+        op.bool_output::<B, 1>(output)
+    }
+
+    fn int_any_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_any_dim").int::<B, D>(&tensor);
+        let output = B::int_any_dim(tensor.primitive, dim);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_all<const D: usize>(tensor: IntTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let mut op = Op::new("int_all").int::<B, D>(&tensor);
+        let output = B::int_all(tensor.primitive);
+
+        op.bool_output::<B, 1>(output)
+    }
+
+    fn int_all_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_all_dim").int::<B, D>(&tensor);
+        let output = B::int_all_dim(tensor.primitive, dim);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn int_sign<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_sign").int::<B, D>(&tensor);
+        let output = B::int_sign(tensor.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        let mut op = Op::new("int_expand").int::<B, D1>(&tensor);
+        let output = B::int_expand(tensor.primitive, shape);
+
+        op.int_output::<B, D2>(output)
+    }
+
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_sort").int::<B, D>(&tensor);
+        let output = B::int_sort(tensor.primitive, dim, descending);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        let mut op = Op::new("int_sort_with_indices").int::<B, D>(&tensor);
+        let (output_0, output_1) = B::int_sort_with_indices(tensor.primitive, dim, descending);
+
+        (
+            op.int_output::<B, D>(output_0),
+            op.int_output::<B, D>(output_1),
+        )
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("int_argsort").int::<B, D>(&tensor);
+        let output = B::int_argsort(tensor.primitive, dim, descending);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn int_unpack_bits<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        size: usize,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("int_unpack_bits").int::<B, D>(&tensor);
+        let output = B::int_unpack_bits(tensor.primitive, size);
+
+        op.bool_output::<B, D>(output)
+    }
+}
@@ -0,0 +1,538 @@
+use crate::{recorder::Op, Trace};
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        AttentionOptions, BoolTensor, ConvOptions, ConvTransposeOptions, FloatTensor,
+        GridSampleOptions, IntTensor, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices,
+        ModuleOps, ResampleOptions, RoiAlignOptions, RoiPoolOptions, UnfoldOptions,
+        WindowAttentionOptions,
+    },
+};
+
+impl<B: Backend> ModuleOps<Self> for Trace<B> {
+    fn embedding(
+        weights: FloatTensor<Self, 2>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("embedding")
+            .float::<B, 2>(&weights)
+            .int::<B, 2>(&indices);
+        let output = B::embedding(weights.primitive, indices.primitive);
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn embedding_backward(
+        weights: FloatTensor<Self, 2>,
+        output_grad: FloatTensor<Self, 3>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 2> {
+        let mut op = Op::new("embedding_backward")
+            .float::<B, 2>(&weights)
+            .float::<B, 3>(&output_grad)
+            .int::<B, 2>(&indices);
+        let output =
+            B::embedding_backward(weights.primitive, output_grad.primitive, indices.primitive);
+
+        op.float_output::<B, 2>(output)
+    }
+
+    fn conv1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("conv1d")
+            .float::<B, 3>(&x)
+            .float::<B, 3>(&weight)
+            .float_opt::<B, 1>(bias.as_ref());
+        let output = B::conv1d(
+            x.primitive,
+            weight.primitive,
+            bias.map(|tensor| tensor.primitive),
+            options,
+        );
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("conv2d")
+            .float::<B, 4>(&x)
+            .float::<B, 4>(&weight)
+            .float_opt::<B, 1>(bias.as_ref());
+        let output = B::conv2d(
+            x.primitive,
+            weight.primitive,
+            bias.map(|tensor| tensor.primitive),
+            options,
+        );
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn conv_transpose1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("conv_transpose1d")
+            .float::<B, 3>(&x)
+            .float::<B, 3>(&weight)
+            .float_opt::<B, 1>(bias.as_ref());
+        let output = B::conv_transpose1d(
+            x.primitive,
+            weight.primitive,
+            bias.map(|tensor| tensor.primitive),
+            options,
+        );
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("conv_transpose2d")
+            .float::<B, 4>(&x)
+            .float::<B, 4>(&weight)
+            .float_opt::<B, 1>(bias.as_ref());
+        let output = B::conv_transpose2d(
+            x.primitive,
+            weight.primitive,
+            bias.map(|tensor| tensor.primitive),
+            options,
+        );
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn unfold4d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("unfold4d").float::<B, 4>(&x);
+        let output = B::unfold4d(x.primitive, kernel_size, options);
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn avg_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("avg_pool1d").float::<B, 3>(&x);
+        let output = B::avg_pool1d(x.primitive, kernel_size, stride, padding, count_include_pad);
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("avg_pool1d_backward")
+            .float::<B, 3>(&x)
+            .float::<B, 3>(&grad);
+        let output = B::avg_pool1d_backward(
+            x.primitive,
+            grad.primitive,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        );
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("avg_pool2d").float::<B, 4>(&x);
+        let output = B::avg_pool2d(x.primitive, kernel_size, stride, padding, count_include_pad);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("avg_pool2d_backward")
+            .float::<B, 4>(&x)
+            .float::<B, 4>(&grad);
+        let output = B::avg_pool2d_backward(
+            x.primitive,
+            grad.primitive,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        );
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("adaptive_avg_pool2d").float::<B, 4>(&x);
+        let output = B::adaptive_avg_pool2d(x.primitive, output_size);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("adaptive_avg_pool2d_backward")
+            .float::<B, 4>(&x)
+            .float::<B, 4>(&grad);
+        let output = B::adaptive_avg_pool2d_backward(x.primitive, grad.primitive);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn adaptive_avg_pool1d(x: FloatTensor<Self, 3>, output_size: usize) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("adaptive_avg_pool1d").float::<B, 3>(&x);
+        let output = B::adaptive_avg_pool1d(x.primitive, output_size);
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn adaptive_avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("adaptive_avg_pool1d_backward")
+            .float::<B, 3>(&x)
+            .float::<B, 3>(&grad);
+        let output = B::adaptive_avg_pool1d_backward(x.primitive, grad.primitive);
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn max_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("max_pool1d").float::<B, 3>(&x);
+        let output = B::max_pool1d(x.primitive, kernel_size, stride, padding, dilation);
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("max_pool2d").float::<B, 4>(&x);
+        let output = B::max_pool2d(x.primitive, kernel_size, stride, padding, dilation);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn interpolate(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("interpolate").float::<B, 4>(&x);
+        let output = B::interpolate(x.primitive, output_size, options);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("interpolate_backward")
+            .float::<B, 4>(&x)
+            .float::<B, 4>(&grad);
+        let output = B::interpolate_backward(x.primitive, grad.primitive, output_size, options);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn interpolate3d(
+        x: FloatTensor<Self, 5>,
+        output_size: [usize; 3],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 5> {
+        let mut op = Op::new("interpolate3d").float::<B, 5>(&x);
+        let output = B::interpolate3d(x.primitive, output_size, options);
+
+        op.float_output::<B, 5>(output)
+    }
+
+    fn dilate(x: FloatTensor<Self, 4>, kernel: BoolTensor<Self, 2>) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("dilate").float::<B, 4>(&x).bool::<B, 2>(&kernel);
+        let output = B::dilate(x.primitive, kernel.primitive);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn erode(x: FloatTensor<Self, 4>, kernel: BoolTensor<Self, 2>) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("erode").float::<B, 4>(&x).bool::<B, 2>(&kernel);
+        let output = B::erode(x.primitive, kernel.primitive);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn pixel_shuffle(x: FloatTensor<Self, 4>, upscale_factor: usize) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("pixel_shuffle").float::<B, 4>(&x);
+        let output = B::pixel_shuffle(x.primitive, upscale_factor);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn pixel_unshuffle(x: FloatTensor<Self, 4>, downscale_factor: usize) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("pixel_unshuffle").float::<B, 4>(&x);
+        let output = B::pixel_unshuffle(x.primitive, downscale_factor);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn resample(
+        x: FloatTensor<Self, 2>,
+        orig_freq: usize,
+        new_freq: usize,
+        options: ResampleOptions,
+    ) -> FloatTensor<Self, 2> {
+        let mut op = Op::new("resample").float::<B, 2>(&x);
+        let output = B::resample(x.primitive, orig_freq, new_freq, options);
+
+        op.float_output::<B, 2>(output)
+    }
+
+    fn attention(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        mask: Option<BoolTensor<Self, 4>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("attention")
+            .float::<B, 4>(&query)
+            .float::<B, 4>(&key)
+            .float::<B, 4>(&value)
+            .bool_opt::<B, 4>(mask.as_ref());
+        let output = B::attention(
+            query.primitive,
+            key.primitive,
+            value.primitive,
+            mask.map(|tensor| tensor.primitive),
+            options,
+        );
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn window_attention(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        global: Option<BoolTensor<Self, 2>>,
+        options: WindowAttentionOptions,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("window_attention")
+            .float::<B, 4>(&query)
+            .float::<B, 4>(&key)
+            .float::<B, 4>(&value)
+            .bool_opt::<B, 2>(global.as_ref());
+        let output = B::window_attention(
+            query.primitive,
+            key.primitive,
+            value.primitive,
+            global.map(|tensor| tensor.primitive),
+            options,
+        );
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn selective_scan(
+        u: FloatTensor<Self, 3>,
+        delta: FloatTensor<Self, 3>,
+        a: FloatTensor<Self, 2>,
+        b: FloatTensor<Self, 3>,
+        c: FloatTensor<Self, 3>,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("selective_scan")
+            .float::<B, 3>(&u)
+            .float::<B, 3>(&delta)
+            .float::<B, 2>(&a)
+            .float::<B, 3>(&b)
+            .float::<B, 3>(&c);
+        let output = B::selective_scan(
+            u.primitive,
+            delta.primitive,
+            a.primitive,
+            b.primitive,
+            c.primitive,
+        );
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn dequantize_matmul(
+        x: FloatTensor<Self, 3>,
+        weight: IntTensor<Self, 2>,
+        scale: FloatTensor<Self, 1>,
+    ) -> FloatTensor<Self, 3> {
+        let mut op = Op::new("dequantize_matmul")
+            .float::<B, 3>(&x)
+            .int::<B, 2>(&weight)
+            .float::<B, 1>(&scale);
+        let output = B::dequantize_matmul(x.primitive, weight.primitive, scale.primitive);
+
+        op.float_output::<B, 3>(output)
+    }
+
+    fn grid_sample(
+        x: FloatTensor<Self, 4>,
+        grid: FloatTensor<Self, 4>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("grid_sample")
+            .float::<B, 4>(&x)
+            .float::<B, 4>(&grid);
+        let output = B::grid_sample(x.primitive, grid.primitive, options);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn roi_align(
+        x: FloatTensor<Self, 4>,
+        boxes: FloatTensor<Self, 2>,
+        options: RoiAlignOptions,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("roi_align").float::<B, 4>(&x).float::<B, 2>(&boxes);
+        let output = B::roi_align(x.primitive, boxes.primitive, options);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn roi_pool(
+        x: FloatTensor<Self, 4>,
+        boxes: FloatTensor<Self, 2>,
+        options: RoiPoolOptions,
+    ) -> FloatTensor<Self, 4> {
+        let mut op = Op::new("roi_pool").float::<B, 4>(&x).float::<B, 2>(&boxes);
+        let output = B::roi_pool(x.primitive, boxes.primitive, options);
+
+        op.float_output::<B, 4>(output)
+    }
+
+    fn nms(
+        boxes: FloatTensor<Self, 2>,
+        scores: FloatTensor<Self, 1>,
+        iou_threshold: f32,
+    ) -> IntTensor<Self, 1> {
+        let mut op = Op::new("nms").float::<B, 2>(&boxes).float::<B, 1>(&scores);
+        let output = B::nms(boxes.primitive, scores.primitive, iou_threshold);
+
+        op.int_output::<B, 1>(output)
+    }
+
+    fn batched_nms(
+        boxes: FloatTensor<Self, 2>,
+        scores: FloatTensor<Self, 1>,
+        idxs: IntTensor<Self, 1>,
+        iou_threshold: f32,
+    ) -> IntTensor<Self, 1> {
+        let mut op = Op::new("batched_nms")
+            .float::<B, 2>(&boxes)
+            .float::<B, 1>(&scores)
+            .int::<B, 1>(&idxs);
+        let output = B::batched_nms(
+            boxes.primitive,
+            scores.primitive,
+            idxs.primitive,
+            iou_threshold,
+        );
+
+        op.int_output::<B, 1>(output)
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        let mut op = Op::new("max_pool2d_with_indices").float::<B, 4>(&x);
+        let output =
+            B::max_pool2d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+        MaxPool2dWithIndices::new(
+            op.float_output::<B, 4>(output.output),
+            op.int_output::<B, 4>(output.indices),
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        let mut op = Op::new("max_pool2d_with_indices_backward")
+            .float::<B, 4>(&x)
+            .float::<B, 4>(&output_grad)
+            .int::<B, 4>(&indices);
+        let output = B::max_pool2d_with_indices_backward(
+            x.primitive,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad.primitive,
+            indices.primitive,
+        );
+
+        MaxPool2dBackward::new(op.float_output::<B, 4>(output.x_grad))
+    }
+}
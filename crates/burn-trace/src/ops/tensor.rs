@@ -0,0 +1,1061 @@
+use crate::{recorder::Op, Trace, TraceTensor};
+use burn_common::reader::Reader;
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        BoolTensor, FloatElem, FloatGradHook, FloatGradMap, FloatTensor, FloatTensorOps, IntElem,
+        IntTensor,
+    },
+    Device, Distribution, Shape, TensorData,
+};
+use core::ops::Range;
+use std::sync::Arc;
+
+impl<B: Backend> FloatTensorOps<Self> for Trace<B> {
+    fn float_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_from_data");
+        let output = B::float_from_data(data, device);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_random");
+        let output = B::float_random(shape, distribution, device);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_random_seeded<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        seed: u64,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_random_seeded");
+        let output = B::float_random_seeded(shape, distribution, seed, device);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_zeros");
+        let output = B::float_zeros(shape, device);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_ones");
+        let output = B::float_ones(shape, device);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: FloatElem<Self>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_full");
+        let output = B::float_full(shape, fill_value, device);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        B::float_shape(&tensor.primitive)
+    }
+
+    fn float_to_data<const D: usize>(tensor: &FloatTensor<Self, D>) -> Reader<TensorData> {
+        let _op = Op::new("float_to_data").float::<B, D>(tensor);
+
+        B::float_to_data(&tensor.primitive)
+    }
+
+    fn float_into_data<const D: usize>(tensor: FloatTensor<Self, D>) -> Reader<TensorData> {
+        let _op = Op::new("float_into_data").float::<B, D>(&tensor);
+
+        B::float_into_data(tensor.primitive)
+    }
+
+    fn float_checksum<const D: usize>(tensor: FloatTensor<Self, D>) -> Reader<u64> {
+        let _op = Op::new("float_checksum").float::<B, D>(&tensor);
+
+        B::float_checksum(tensor.primitive)
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        B::float_device(&tensor.primitive)
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_to_device").float::<B, D>(&tensor);
+        let output = B::float_to_device(tensor.primitive, device);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        let mut op = Op::new("float_into_int").float::<B, D>(&tensor);
+        let output = B::float_into_int(tensor.primitive);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_empty");
+        let output = B::float_empty(shape, device);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_repeat<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_repeat").float::<B, D>(&tensor);
+        let output = B::float_repeat(tensor.primitive, dim, times);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_add").float::<B, D>(&lhs).float::<B, D>(&rhs);
+        let output = B::float_add(lhs.primitive, rhs.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_add_scalar").float::<B, D>(&lhs);
+        let output = B::float_add_scalar(lhs.primitive, rhs);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_clamp_min<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_clamp_min").float::<B, D>(&tensor);
+        let output = B::float_clamp_min(tensor.primitive, min);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_clamp_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_clamp_max").float::<B, D>(&tensor);
+        let output = B::float_clamp_max(tensor.primitive, max);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_clamp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_clamp").float::<B, D>(&tensor);
+        let output = B::float_clamp(tensor.primitive, min, max);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_sub").float::<B, D>(&lhs).float::<B, D>(&rhs);
+        let output = B::float_sub(lhs.primitive, rhs.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_sub_scalar").float::<B, D>(&lhs);
+        let output = B::float_sub_scalar(lhs.primitive, rhs);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_mul").float::<B, D>(&lhs).float::<B, D>(&rhs);
+        let output = B::float_mul(lhs.primitive, rhs.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_mul_scalar").float::<B, D>(&lhs);
+        let output = B::float_mul_scalar(lhs.primitive, rhs);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_div").float::<B, D>(&lhs).float::<B, D>(&rhs);
+        let output = B::float_div(lhs.primitive, rhs.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_div_scalar").float::<B, D>(&lhs);
+        let output = B::float_div_scalar(lhs.primitive, rhs);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_remainder_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_remainder_scalar").float::<B, D>(&lhs);
+        let output = B::float_remainder_scalar(lhs.primitive, rhs);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_matmul")
+            .float::<B, D>(&lhs)
+            .float::<B, D>(&rhs);
+        let output = B::float_matmul(lhs.primitive, rhs.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_spmm_csr(
+        row_offsets: IntTensor<Self, 1>,
+        col_indices: IntTensor<Self, 1>,
+        values: FloatTensor<Self, 1>,
+        rhs: FloatTensor<Self, 2>,
+    ) -> FloatTensor<Self, 2> {
+        let mut op = Op::new("float_spmm_csr")
+            .int::<B, 1>(&row_offsets)
+            .int::<B, 1>(&col_indices)
+            .float::<B, 1>(&values)
+            .float::<B, 2>(&rhs);
+        let output = B::float_spmm_csr(
+            row_offsets.primitive,
+            col_indices.primitive,
+            values.primitive,
+            rhs.primitive,
+        );
+
+        op.float_output::<B, 2>(output)
+    }
+
+    fn float_neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_neg").float::<B, D>(&tensor);
+        let output = B::float_neg(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_recip").float::<B, D>(&tensor);
+        let output = B::float_recip(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_transpose<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_transpose").float::<B, D>(&tensor);
+        let output = B::float_transpose(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_swap_dims").float::<B, D>(&tensor);
+        let output = B::float_swap_dims(tensor.primitive, dim1, dim2);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_permute<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: [usize; D],
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_permute").float::<B, D>(&tensor);
+        let output = B::float_permute(tensor.primitive, axes);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_flip<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: &[usize],
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_flip").float::<B, D>(&tensor);
+        let output = B::float_flip(tensor.primitive, axes);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        let mut op = Op::new("float_reshape").float::<B, D1>(&tensor);
+        let output = B::float_reshape(tensor.primitive, shape);
+
+        op.float_output::<B, D2>(output)
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_gather")
+            .float::<B, D>(&tensor)
+            .int::<B, D>(&indices);
+        let output = B::float_gather(dim, tensor.primitive, indices.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_scatter")
+            .float::<B, D>(&tensor)
+            .int::<B, D>(&indices)
+            .float::<B, D>(&value);
+        let output = B::float_scatter(dim, tensor.primitive, indices.primitive, value.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_select")
+            .float::<B, D>(&tensor)
+            .int::<B, 1>(&indices);
+        let output = B::float_select(tensor.primitive, dim, indices.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_select_assign")
+            .float::<B, D>(&tensor)
+            .int::<B, 1>(&indices)
+            .float::<B, D>(&value);
+        let output =
+            B::float_select_assign(tensor.primitive, dim, indices.primitive, value.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        let mut op = Op::new("float_slice").float::<B, D1>(&tensor);
+        let output = B::float_slice(tensor.primitive, ranges);
+
+        op.float_output::<B, D1>(output)
+    }
+
+    fn float_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> FloatTensor<Self, D1> {
+        let mut op = Op::new("float_slice_with_steps").float::<B, D1>(&tensor);
+        let output = B::float_slice_with_steps(tensor.primitive, ranges, steps);
+
+        op.float_output::<B, D1>(output)
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        let mut op = Op::new("float_slice_assign")
+            .float::<B, D1>(&tensor)
+            .float::<B, D1>(&value);
+        let output = B::float_slice_assign(tensor.primitive, ranges, value.primitive);
+
+        op.float_output::<B, D1>(output)
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_mask_where")
+            .float::<B, D>(&tensor)
+            .bool::<B, D>(&mask)
+            .float::<B, D>(&value);
+        let output = B::float_mask_where(tensor.primitive, mask.primitive, value.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_mask_fill")
+            .float::<B, D>(&tensor)
+            .bool::<B, D>(&mask);
+        let output = B::float_mask_fill(tensor.primitive, mask.primitive, value);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_mask_fill_packed<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: IntTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_mask_fill_packed")
+            .float::<B, D>(&tensor)
+            .int::<B, D>(&mask);
+        let output = B::float_mask_fill_packed(tensor.primitive, mask.primitive, value);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_equal")
+            .float::<B, D>(&lhs)
+            .float::<B, D>(&rhs);
+        let output = B::float_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_not_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_not_equal")
+            .float::<B, D>(&lhs)
+            .float::<B, D>(&rhs);
+        let output = B::float_not_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_equal_elem").float::<B, D>(&lhs);
+        let output = B::float_equal_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_not_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_not_equal_elem").float::<B, D>(&lhs);
+        let output = B::float_not_equal_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_greater")
+            .float::<B, D>(&lhs)
+            .float::<B, D>(&rhs);
+        let output = B::float_greater(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_greater_elem").float::<B, D>(&lhs);
+        let output = B::float_greater_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_greater_equal")
+            .float::<B, D>(&lhs)
+            .float::<B, D>(&rhs);
+        let output = B::float_greater_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_greater_equal_elem").float::<B, D>(&lhs);
+        let output = B::float_greater_equal_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_lower")
+            .float::<B, D>(&lhs)
+            .float::<B, D>(&rhs);
+        let output = B::float_lower(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_lower_elem").float::<B, D>(&lhs);
+        let output = B::float_lower_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_lower_equal")
+            .float::<B, D>(&lhs)
+            .float::<B, D>(&rhs);
+        let output = B::float_lower_equal(lhs.primitive, rhs.primitive);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_lower_equal_elem").float::<B, D>(&lhs);
+        let output = B::float_lower_equal_elem(lhs.primitive, rhs);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_detach<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // The detached tensor is the same node of the graph.
+        tensor.map(B::float_detach)
+    }
+
+    fn float_set_require_grad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        require_grad: bool,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_set_require_grad(tensor, require_grad))
+    }
+
+    fn float_is_require_grad<const D: usize>(tensor: &FloatTensor<Self, D>) -> bool {
+        B::float_is_require_grad(&tensor.primitive)
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let mut op = Op::new("float_sum").float::<B, D>(&tensor);
+        let output = B::float_sum(tensor.primitive);
+
+        op.float_output::<B, 1>(output)
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_sum_dim").float::<B, D>(&tensor);
+        let output = B::float_sum_dim(tensor.primitive, dim);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_logsumexp_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_logsumexp_dim").float::<B, D>(&tensor);
+        let output = B::float_logsumexp_dim(tensor.primitive, dim);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_prod<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let mut op = Op::new("float_prod").float::<B, D>(&tensor);
+        let output = B::float_prod(tensor.primitive);
+
+        op.float_output::<B, 1>(output)
+    }
+
+    fn float_prod_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_prod_dim").float::<B, D>(&tensor);
+        let output = B::float_prod_dim(tensor.primitive, dim);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let mut op = Op::new("float_mean").float::<B, D>(&tensor);
+        let output = B::float_mean(tensor.primitive);
+
+        op.float_output::<B, 1>(output)
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_mean_dim").float::<B, D>(&tensor);
+        let output = B::float_mean_dim(tensor.primitive, dim);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_exp").float::<B, D>(&tensor);
+        let output = B::float_exp(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_log").float::<B, D>(&tensor);
+        let output = B::float_log(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_log1p").float::<B, D>(&tensor);
+        let output = B::float_log1p(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_powf")
+            .float::<B, D>(&lhs)
+            .float::<B, D>(&rhs);
+        let output = B::float_powf(lhs.primitive, rhs.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_powi<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_powi").float::<B, D>(&lhs).int::<B, D>(&rhs);
+        let output = B::float_powi(lhs.primitive, rhs.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_powi_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_powi_scalar").float::<B, D>(&lhs);
+        let output = B::float_powi_scalar(lhs.primitive, rhs);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_powf_scalar").float::<B, D>(&tensor);
+        let output = B::float_powf_scalar(tensor.primitive, value);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_sqrt").float::<B, D>(&tensor);
+        let output = B::float_sqrt(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_abs").float::<B, D>(&tensor);
+        let output = B::float_abs(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_cos").float::<B, D>(&tensor);
+        let output = B::float_cos(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_sin").float::<B, D>(&tensor);
+        let output = B::float_sin(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_tanh").float::<B, D>(&tensor);
+        let output = B::float_tanh(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_erf").float::<B, D>(&tensor);
+        let output = B::float_erf(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_erfinv").float::<B, D>(&tensor);
+        let output = B::float_erfinv(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_lgamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_lgamma").float::<B, D>(&tensor);
+        let output = B::float_lgamma(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_digamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_digamma").float::<B, D>(&tensor);
+        let output = B::float_digamma(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_polygamma<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        order: u32,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_polygamma").float::<B, D>(&tensor);
+        let output = B::float_polygamma(tensor.primitive, order);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_atan2<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_atan2")
+            .float::<B, D>(&lhs)
+            .float::<B, D>(&rhs);
+        let output = B::float_atan2(lhs.primitive, rhs.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_cat").float_all::<B, D>(&tensors);
+        let output = B::float_cat(
+            tensors.into_iter().map(|tensor| tensor.primitive).collect(),
+            dim,
+        );
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_argmax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("float_argmax").float::<B, D>(&tensor);
+        let output = B::float_argmax(tensor.primitive, dim);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn float_argmin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("float_argmin").float::<B, D>(&tensor);
+        let output = B::float_argmin(tensor.primitive, dim);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn float_max<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let mut op = Op::new("float_max").float::<B, D>(&tensor);
+        let output = B::float_max(tensor.primitive);
+
+        op.float_output::<B, 1>(output)
+    }
+
+    fn float_max_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_max_dim").float::<B, D>(&tensor);
+        let output = B::float_max_dim(tensor.primitive, dim);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        let mut op = Op::new("float_max_dim_with_indices").float::<B, D>(&tensor);
+        let (output_0, output_1) = B::float_max_dim_with_indices(tensor.primitive, dim);
+
+        (
+            op.float_output::<B, D>(output_0),
+            op.int_output::<B, D>(output_1),
+        )
+    }
+
+    fn float_min<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let mut op = Op::new("float_min").float::<B, D>(&tensor);
+        let output = B::float_min(tensor.primitive);
+
+        op.float_output::<B, 1>(output)
+    }
+
+    fn float_min_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_min_dim").float::<B, D>(&tensor);
+        let output = B::float_min_dim(tensor.primitive, dim);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        let mut op = Op::new("float_min_dim_with_indices").float::<B, D>(&tensor);
+        let (output_0, output_1) = B::float_min_dim_with_indices(tensor.primitive, dim);
+
+        (
+            op.float_output::<B, D>(output_0),
+            op.int_output::<B, D>(output_1),
+        )
+    }
+
+    fn float_narrow<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_narrow").float::<B, D>(&tensor);
+        let output = B::float_narrow(tensor.primitive, dim, start, length);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_chunk<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<FloatTensor<Self, D>> {
+        let mut op = Op::new("float_chunk").float::<B, D>(&tensor);
+        let outputs = B::float_chunk(tensor.primitive, chunks, dim);
+
+        outputs
+            .into_iter()
+            .map(|output| op.float_output::<B, D>(output))
+            .collect()
+    }
+
+    fn float_any<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let mut op = Op::new("float_any").float::<B, D>(&tensor);
+        let output = B::float_any(tensor.primitive);
+
+        op.bool_output::<B, 1>(output)
+    }
+
+    fn float_any_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_any_dim").float::<B, D>(&tensor);
+        let output = B::float_any_dim(tensor.primitive, dim);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_all<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let mut op = Op::new("float_all").float::<B, D>(&tensor);
+        let output = B::float_all(tensor.primitive);
+
+        op.bool_output::<B, 1>(output)
+    }
+
+    fn float_all_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let mut op = Op::new("float_all_dim").float::<B, D>(&tensor);
+        let output = B::float_all_dim(tensor.primitive, dim);
+
+        op.bool_output::<B, D>(output)
+    }
+
+    fn float_sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_sign").float::<B, D>(&tensor);
+        let output = B::float_sign(tensor.primitive);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_expand<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        let mut op = Op::new("float_expand").float::<B, D1>(&tensor);
+        let output = B::float_expand(tensor.primitive, shape);
+
+        op.float_output::<B, D2>(output)
+    }
+
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        let mut op = Op::new("float_sort").float::<B, D>(&tensor);
+        let output = B::float_sort(tensor.primitive, dim, descending);
+
+        op.float_output::<B, D>(output)
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        let mut op = Op::new("float_sort_with_indices").float::<B, D>(&tensor);
+        let (output_0, output_1) = B::float_sort_with_indices(tensor.primitive, dim, descending);
+
+        (
+            op.float_output::<B, D>(output_0),
+            op.int_output::<B, D>(output_1),
+        )
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        let mut op = Op::new("float_argsort").float::<B, D>(&tensor);
+        let output = B::float_argsort(tensor.primitive, dim, descending);
+
+        op.int_output::<B, D>(output)
+    }
+
+    fn float_register_grad_hook<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        hook: FloatGradHook<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let hook: FloatGradHook<B, D> = Arc::new(move |grad| hook(TraceTensor::new(grad)));
+
+        tensor.map(|tensor| B::float_register_grad_hook(tensor, hook))
+    }
+
+    fn float_map_grad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        map: FloatGradMap<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let map: FloatGradMap<B, D> = Arc::new(move |grad| map(TraceTensor::new(grad)).primitive);
+
+        tensor.map(|tensor| B::float_map_grad(tensor, map))
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{TensorId, TensorKind, TraceGraph, TraceNode, TraceTensor, TracedTensor};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+    Shape,
+};
+use std::cell::RefCell;
+
+thread_local! {
+    static NODES: RefCell<Option<Vec<TraceNode>>> = const { RefCell::new(None) };
+}
+
+/// Record the graph of the tensor operations executed by the given function on the
+/// [Trace](crate::Trace) backend, e.g. the forward pass of a model.
+///
+/// The operations are recorded on the current thread only, so the operations executed by other
+/// threads, e.g. by other tests or by a data loader, are not part of the graph.
+///
+/// # Panics
+///
+/// If a trace is already being recorded on the current thread.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let (output, graph) = burn_trace::trace(|| model.forward(input));
+///
+/// std::fs::write("model.dot", graph.to_dot()).unwrap();
+/// ```
+pub fn trace<O>(func: impl FnOnce() -> O) -> (O, TraceGraph) {
+    NODES.with(|nodes| {
+        let mut nodes = nodes.borrow_mut();
+        assert!(
+            nodes.is_none(),
+            "A trace is already being recorded on the current thread."
+        );
+        *nodes = Some(Vec::new());
+    });
+
+    // Stop the recording even when the function panics.
+    let guard = TraceGuard;
+    let output = func();
+    let nodes = guard.finish();
+
+    (output, TraceGraph { nodes })
+}
+
+/// Whether a trace is being recorded on the current thread.
+pub fn is_tracing() -> bool {
+    NODES.with(|nodes| nodes.borrow().is_some())
+}
+
+struct TraceGuard;
+
+impl TraceGuard {
+    fn finish(self) -> Vec<TraceNode> {
+        NODES.with(|nodes| nodes.borrow_mut().take().unwrap_or_default())
+    }
+}
+
+impl Drop for TraceGuard {
+    fn drop(&mut self) {
+        NODES.with(|nodes| nodes.borrow_mut().take());
+    }
+}
+
+/// An operation being recorded, added to the graph when dropped.
+///
+/// Nothing is recorded when no trace is being recorded, so that the shapes of the tensors are
+/// only read while tracing.
+pub(crate) struct Op {
+    node: Option<TraceNode>,
+}
+
+impl Op {
+    /// Record a new operation, with the name of the backend method.
+    pub(crate) fn new(name: &'static str) -> Self {
+        let node = is_tracing().then(|| TraceNode {
+            op: name.to_string(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        });
+
+        Self { node }
+    }
+
+    /// Add a float tensor read by the operation.
+    pub(crate) fn float<B: Backend, const D: usize>(
+        self,
+        tensor: &TraceTensor<FloatTensor<B, D>>,
+    ) -> Self {
+        self.input(tensor.id, TensorKind::Float, || {
+            B::float_shape(&tensor.primitive)
+        })
+    }
+
+    /// Add an int tensor read by the operation.
+    pub(crate) fn int<B: Backend, const D: usize>(
+        self,
+        tensor: &TraceTensor<IntTensor<B, D>>,
+    ) -> Self {
+        self.input(tensor.id, TensorKind::Int, || {
+            B::int_shape(&tensor.primitive)
+        })
+    }
+
+    /// Add a bool tensor read by the operation.
+    pub(crate) fn bool<B: Backend, const D: usize>(
+        self,
+        tensor: &TraceTensor<BoolTensor<B, D>>,
+    ) -> Self {
+        self.input(tensor.id, TensorKind::Bool, || {
+            B::bool_shape(&tensor.primitive)
+        })
+    }
+
+    /// Add a float tensor read by the operation, if any.
+    pub(crate) fn float_opt<B: Backend, const D: usize>(
+        self,
+        tensor: Option<&TraceTensor<FloatTensor<B, D>>>,
+    ) -> Self {
+        match tensor {
+            Some(tensor) => self.float::<B, D>(tensor),
+            None => self,
+        }
+    }
+
+    /// Add a bool tensor read by the operation, if any.
+    pub(crate) fn bool_opt<B: Backend, const D: usize>(
+        self,
+        tensor: Option<&TraceTensor<BoolTensor<B, D>>>,
+    ) -> Self {
+        match tensor {
+            Some(tensor) => self.bool::<B, D>(tensor),
+            None => self,
+        }
+    }
+
+    /// Add float tensors read by the operation.
+    pub(crate) fn float_all<B: Backend, const D: usize>(
+        self,
+        tensors: &[TraceTensor<FloatTensor<B, D>>],
+    ) -> Self {
+        tensors
+            .iter()
+            .fold(self, |op, tensor| op.float::<B, D>(tensor))
+    }
+
+    /// Add int tensors read by the operation.
+    pub(crate) fn int_all<B: Backend, const D: usize>(
+        self,
+        tensors: &[TraceTensor<IntTensor<B, D>>],
+    ) -> Self {
+        tensors
+            .iter()
+            .fold(self, |op, tensor| op.int::<B, D>(tensor))
+    }
+
+    /// Add bool tensors read by the operation.
+    pub(crate) fn bool_all<B: Backend, const D: usize>(
+        self,
+        tensors: &[TraceTensor<BoolTensor<B, D>>],
+    ) -> Self {
+        tensors
+            .iter()
+            .fold(self, |op, tensor| op.bool::<B, D>(tensor))
+    }
+
+    /// Identify a float tensor created by the operation.
+    pub(crate) fn float_output<B: Backend, const D: usize>(
+        &mut self,
+        primitive: FloatTensor<B, D>,
+    ) -> TraceTensor<FloatTensor<B, D>> {
+        let tensor = TraceTensor::new(primitive);
+        self.output(&tensor, TensorKind::Float, || {
+            B::float_shape(&tensor.primitive)
+        });
+        tensor
+    }
+
+    /// Identify an int tensor created by the operation.
+    pub(crate) fn int_output<B: Backend, const D: usize>(
+        &mut self,
+        primitive: IntTensor<B, D>,
+    ) -> TraceTensor<IntTensor<B, D>> {
+        let tensor = TraceTensor::new(primitive);
+        self.output(&tensor, TensorKind::Int, || B::int_shape(&tensor.primitive));
+        tensor
+    }
+
+    /// Identify a bool tensor created by the operation.
+    pub(crate) fn bool_output<B: Backend, const D: usize>(
+        &mut self,
+        primitive: BoolTensor<B, D>,
+    ) -> TraceTensor<BoolTensor<B, D>> {
+        let tensor = TraceTensor::new(primitive);
+        self.output(&tensor, TensorKind::Bool, || {
+            B::bool_shape(&tensor.primitive)
+        });
+        tensor
+    }
+
+    fn input<const D: usize>(
+        mut self,
+        id: TensorId,
+        kind: TensorKind,
+        shape: impl FnOnce() -> Shape<D>,
+    ) -> Self {
+        if let Some(node) = self.node.as_mut() {
+            node.inputs.push(TracedTensor {
+                id,
+                kind,
+                shape: shape().dims.to_vec(),
+            });
+        }
+        self
+    }
+
+    fn output<P, const D: usize>(
+        &mut self,
+        tensor: &TraceTensor<P>,
+        kind: TensorKind,
+        shape: impl FnOnce() -> Shape<D>,
+    ) {
+        if let Some(node) = self.node.as_mut() {
+            node.outputs.push(TracedTensor {
+                id: tensor.id,
+                kind,
+                shape: shape().dims.to_vec(),
+            });
+        }
+    }
+}
+
+impl Drop for Op {
+    fn drop(&mut self) {
+        if let Some(node) = self.node.take() {
+            NODES.with(|nodes| {
+                if let Some(nodes) = nodes.borrow_mut().as_mut() {
+                    nodes.push(node);
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+use serde::{Deserialize, Serialize};
+
+/// The identifier of a tensor in a [traced graph](crate::TraceGraph).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TensorId(u64);
+
+impl TensorId {
+    /// Create a new unique tensor id.
+    pub(crate) fn new() -> Self {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        Self(COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// The value of the id.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A tensor of the inner backend of the [Trace](crate::Trace) backend, identified in the traced
+/// graphs.
+#[derive(Clone, Debug)]
+pub struct TraceTensor<P> {
+    /// The tensor of the inner backend.
+    pub primitive: P,
+    /// The identifier of the tensor.
+    pub id: TensorId,
+}
+
+impl<P> TraceTensor<P> {
+    /// Identify a new tensor of the inner backend.
+    pub fn new(primitive: P) -> Self {
+        Self {
+            primitive,
+            id: TensorId::new(),
+        }
+    }
+
+    /// Map the tensor of the inner backend, keeping the identifier, e.g. when a tensor is
+    /// detached or marked as requiring gradients.
+    pub(crate) fn map<T>(self, func: impl FnOnce(P) -> T) -> TraceTensor<T> {
+        TraceTensor {
+            primitive: func(self.primitive),
+            id: self.id,
+        }
+    }
+}
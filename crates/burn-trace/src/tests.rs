@@ -0,0 +1,110 @@
+use crate::{trace, TensorKind, Trace, TraceGraph};
+use burn_autodiff::Autodiff;
+use burn_ndarray::NdArray;
+use burn_tensor::{activation::relu, Tensor, TensorData};
+
+type TestBackend = Trace<NdArray<f32>>;
+type TestAutodiffBackend = Trace<Autodiff<NdArray<f32>>>;
+
+fn ops(graph: &TraceGraph) -> Vec<&str> {
+    graph.nodes.iter().map(|node| node.op.as_str()).collect()
+}
+
+#[test]
+fn should_record_the_operations_with_their_tensors() {
+    let device = Default::default();
+    let x = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 3.0]], &device);
+    let weight = Tensor::<TestBackend, 2>::from_floats([[1.0], [1.0], [1.0]], &device);
+    let bias = Tensor::<TestBackend, 2>::from_floats([[-1.0]], &device);
+
+    let (output, graph) = trace(|| relu(x.clone().matmul(weight.clone()).add(bias.clone())));
+
+    assert_eq!(ops(&graph), ["float_matmul", "float_add", "relu"]);
+    let inputs: Vec<_> = graph
+        .inputs()
+        .iter()
+        .map(|tensor| (tensor.id, tensor.shape.clone()))
+        .collect();
+    assert_eq!(
+        inputs,
+        [
+            (x.into_primitive().id, vec![1, 3]),
+            (weight.into_primitive().id, vec![3, 1]),
+            (bias.into_primitive().id, vec![1, 1]),
+        ]
+    );
+    let outputs = graph.outputs();
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].kind, TensorKind::Float);
+    assert_eq!(outputs[0].shape, [1, 1]);
+    // The output of each operation is the input of the next one.
+    assert_eq!(graph.nodes[0].outputs[0].id, graph.nodes[1].inputs[0].id);
+    assert_eq!(graph.nodes[1].outputs[0].id, graph.nodes[2].inputs[0].id);
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[1.0f32]]), false);
+}
+
+#[test]
+fn should_only_record_while_tracing() {
+    let device = Default::default();
+    let x = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device);
+
+    let y = x.clone().mul_scalar(3.0);
+    let (_, graph) = trace(|| x.sum());
+    let z = y.neg();
+
+    assert_eq!(ops(&graph), ["float_sum"]);
+    z.into_data()
+        .assert_eq(&TensorData::from([-3.0f32, -6.0]), false);
+}
+
+#[test]
+fn should_export_the_graph_to_dot_and_json() {
+    let device = Default::default();
+    let x = Tensor::<TestBackend, 2>::ones([2, 3], &device);
+    let x_id = x.clone().into_primitive().id.value();
+
+    let (_, graph) = trace(|| x.mul_scalar(2.0).sum_dim(1).greater_elem(1.0));
+
+    let output_id = graph.outputs()[0].id.value();
+    assert_eq!(
+        graph.to_dot(),
+        format!(
+            "digraph {{
+    node [shape=box];
+    t{x_id} [label=\"Float [2, 3]\", shape=ellipse];
+    t{output_id} [label=\"Bool [2, 1]\", shape=ellipse];
+    op0 [label=\"float_mul_scalar\"];
+    t{x_id} -> op0 [label=\"[2, 3]\"];
+    op1 [label=\"float_sum_dim\"];
+    op0 -> op1 [label=\"[2, 3]\"];
+    op2 [label=\"float_greater_elem\"];
+    op1 -> op2 [label=\"[2, 1]\"];
+    op2 -> t{output_id};
+}}"
+        )
+    );
+    let json: TraceGraph = serde_json::from_str(&graph.to_json()).unwrap();
+    assert_eq!(json, graph);
+}
+
+#[test]
+fn should_trace_autodiff_backends() {
+    let device = Default::default();
+    let x = Tensor::<TestAutodiffBackend, 1>::from_floats([1.0, 2.0], &device).require_grad();
+
+    let (grads, graph) = trace(|| x.clone().powf_scalar(2.0).sum().backward());
+
+    assert_eq!(ops(&graph), ["float_powf_scalar", "float_sum"]);
+    x.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([2.0f32, 4.0]), false);
+}
+
+#[test]
+#[should_panic = "A trace is already being recorded on the current thread."]
+fn should_not_nest_traces() {
+    trace(|| trace(|| ()));
+}
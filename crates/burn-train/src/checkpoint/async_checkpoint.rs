@@ -1,6 +1,6 @@
 use super::{Checkpointer, CheckpointerError};
 use burn_core::{record::Record, tensor::backend::Backend};
-use std::sync::mpsc;
+use std::sync::{mpsc, Arc, Mutex};
 
 enum Message<R, B: Backend> {
     Restore(
@@ -10,13 +10,18 @@ enum Message<R, B: Backend> {
     ),
     Save(usize, R),
     Delete(usize),
+    Flush(mpsc::SyncSender<()>),
     End,
 }
 
+/// Error of the last failed background operation, reported on the next call.
+type PendingError = Arc<Mutex<Option<CheckpointerError>>>;
+
 #[derive(new)]
 struct CheckpointerThread<C, R, B: Backend> {
     checkpointer: C,
     receiver: mpsc::Receiver<Message<R, B>>,
+    error: PendingError,
 }
 
 impl<C, R, B> CheckpointerThread<C, R, B>
@@ -34,26 +39,48 @@ where
                         .send(record)
                         .expect("Can send response through callback channel.");
                 }
-                Message::Save(epoch, state) => self
-                    .checkpointer
-                    .save(epoch, state)
-                    .expect("Can save the state."),
-                Message::Delete(epoch) => self
-                    .checkpointer
-                    .delete(epoch)
-                    .expect("Can delete the state."),
+                Message::Save(epoch, state) => {
+                    let result = self.checkpointer.save(epoch, state);
+                    self.report(result);
+                }
+                Message::Delete(epoch) => {
+                    let result = self.checkpointer.delete(epoch);
+                    self.report(result);
+                }
+                Message::Flush(callback) => {
+                    callback
+                        .send(())
+                        .expect("Can send response through callback channel.");
+                }
                 Message::End => {
                     return;
                 }
             };
         }
     }
+
+    fn report(&self, result: Result<(), CheckpointerError>) {
+        if let Err(err) = result {
+            log::error!("Background checkpoint operation failed: {err:?}");
+            *self.error.lock().unwrap() = Some(err);
+        }
+    }
 }
 
 /// Async checkpointer.
+///
+/// Saving and deleting checkpoints is done on a background thread, so the training loop isn't
+/// blocked while the record is serialized and written. The tensors of a record are immutable, so
+/// the model can keep being updated while its weights are read back to the host in the
+/// background.
+///
+/// When the maximum number of pending saves is reached, saving blocks until the previous ones are
+/// done, which bounds the memory held by in-flight checkpoints. Errors of background operations
+/// are returned by the next call to the checkpointer.
 pub struct AsyncCheckpointer<Record, B: Backend> {
     sender: mpsc::SyncSender<Message<Record, B>>,
     handler: Option<std::thread::JoinHandle<()>>,
+    error: PendingError,
 }
 
 impl<R, B> AsyncCheckpointer<R, B>
@@ -63,6 +90,8 @@ where
 {
     /// Create a new async checkpointer.
     ///
+    /// Saving a checkpoint blocks while a previous one is still being saved.
+    ///
     /// # Arguments
     ///
     /// * `checkpointer` - The checkpointer.
@@ -74,12 +103,63 @@ where
     where
         C: Checkpointer<R, B> + Send + 'static,
     {
-        // Only on checkpoint can be done in advance.
-        let (sender, receiver) = mpsc::sync_channel(0);
-        let thread = CheckpointerThread::new(checkpointer, receiver);
+        // Nothing is queued, saving waits until the previous checkpoint is written.
+        Self::with_max_pending(checkpointer, 0)
+    }
+
+    /// Create a new async checkpointer queuing up to `max_pending` operations while a save is
+    /// in flight.
+    ///
+    /// # Arguments
+    ///
+    /// * `checkpointer` - The checkpointer.
+    /// * `max_pending` - The number of operations that can be queued before blocking.
+    ///
+    /// # Returns
+    ///
+    /// The async checkpointer.
+    pub fn with_max_pending<C>(checkpointer: C, max_pending: usize) -> Self
+    where
+        C: Checkpointer<R, B> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(max_pending);
+        let error = PendingError::default();
+        let thread = CheckpointerThread::new(checkpointer, receiver, error.clone());
         let handler = Some(std::thread::spawn(move || thread.run()));
 
-        Self { sender, handler }
+        Self {
+            sender,
+            handler,
+            error,
+        }
+    }
+
+    /// Wait for all pending operations to complete.
+    ///
+    /// # Returns
+    ///
+    /// The error of the last failed operation, if any.
+    pub fn flush(&self) -> Result<(), CheckpointerError> {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.send(Message::Flush(sender))?;
+        receiver
+            .recv()
+            .map_err(|e| CheckpointerError::Unknown(e.to_string()))?;
+
+        self.take_error()
+    }
+
+    fn send(&self, message: Message<R, B>) -> Result<(), CheckpointerError> {
+        self.sender
+            .send(message)
+            .map_err(|e| CheckpointerError::Unknown(e.to_string()))
+    }
+
+    fn take_error(&self) -> Result<(), CheckpointerError> {
+        match self.error.lock().unwrap().take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
     }
 }
 
@@ -89,18 +169,13 @@ where
     B: Backend,
 {
     fn save(&self, epoch: usize, record: R) -> Result<(), CheckpointerError> {
-        self.sender
-            .send(Message::Save(epoch, record))
-            .expect("Can send message to checkpointer thread.");
-
-        Ok(())
+        self.take_error()?;
+        self.send(Message::Save(epoch, record))
     }
 
     fn restore(&self, epoch: usize, device: &B::Device) -> Result<R, CheckpointerError> {
         let (sender, receiver) = mpsc::sync_channel(1);
-        self.sender
-            .send(Message::Restore(epoch, device.clone(), sender))
-            .map_err(|e| CheckpointerError::Unknown(e.to_string()))?;
+        self.send(Message::Restore(epoch, device.clone(), sender))?;
 
         if let Ok(record) = receiver.recv() {
             return record;
@@ -110,11 +185,8 @@ where
     }
 
     fn delete(&self, epoch: usize) -> Result<(), CheckpointerError> {
-        self.sender
-            .send(Message::Delete(epoch))
-            .map_err(|e| CheckpointerError::Unknown(e.to_string()))?;
-
-        Ok(())
+        self.take_error()?;
+        self.send(Message::Delete(epoch))
     }
 }
 
@@ -135,3 +207,85 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[derive(Default, Clone)]
+    struct SlowCheckpointer {
+        saved: Arc<Mutex<Vec<(usize, usize)>>>,
+        in_flight: Arc<AtomicUsize>,
+        max_in_flight: Arc<AtomicUsize>,
+    }
+
+    impl Checkpointer<usize, TestBackend> for SlowCheckpointer {
+        fn save(&self, epoch: usize, record: usize) -> Result<(), CheckpointerError> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(10));
+            self.saved.lock().unwrap().push((epoch, record));
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            if record == usize::MAX {
+                return Err(CheckpointerError::Unknown("Can't save".to_string()));
+            }
+
+            Ok(())
+        }
+
+        fn delete(&self, epoch: usize) -> Result<(), CheckpointerError> {
+            self.saved.lock().unwrap().retain(|(e, _)| *e != epoch);
+            Ok(())
+        }
+
+        fn restore(
+            &self,
+            epoch: usize,
+            _device: &<TestBackend as Backend>::Device,
+        ) -> Result<usize, CheckpointerError> {
+            self.saved
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|(e, _)| *e == epoch)
+                .map(|(_, record)| *record)
+                .ok_or_else(|| CheckpointerError::Unknown("Not found".to_string()))
+        }
+    }
+
+    #[test]
+    fn should_save_in_order_one_at_a_time() {
+        let checkpointer = SlowCheckpointer::default();
+        let async_checkpointer = AsyncCheckpointer::with_max_pending(checkpointer.clone(), 2);
+
+        for epoch in 1..=5 {
+            async_checkpointer.save(epoch, epoch * 10).unwrap();
+        }
+        async_checkpointer.delete(3).unwrap();
+        async_checkpointer.flush().unwrap();
+
+        assert_eq!(
+            *checkpointer.saved.lock().unwrap(),
+            vec![(1, 10), (2, 20), (4, 40), (5, 50)]
+        );
+        assert_eq!(checkpointer.max_in_flight.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            async_checkpointer.restore(4, &Default::default()).unwrap(),
+            40
+        );
+    }
+
+    #[test]
+    fn should_report_background_errors() {
+        let async_checkpointer = AsyncCheckpointer::new(SlowCheckpointer::default());
+
+        async_checkpointer.save(1, usize::MAX).unwrap();
+
+        assert!(async_checkpointer.flush().is_err());
+        assert!(async_checkpointer.flush().is_ok());
+    }
+}
@@ -0,0 +1,87 @@
+use super::CheckpointerError;
+use serde::{Deserialize, Serialize};
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// The manifest of the checkpoints saved by the [learner](crate::Learner), listing the epochs
+/// whose checkpoints exist with the names of their records, e.g. `model` and `optim`.
+///
+/// It is saved as `manifest.json` in the checkpoint directory after each epoch.
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+pub struct CheckpointManifest {
+    /// The checkpoints, sorted by epoch.
+    pub checkpoints: Vec<CheckpointEntry>,
+}
+
+/// The checkpoint of an epoch listed in a [manifest](CheckpointManifest).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CheckpointEntry {
+    /// The epoch of the checkpoint.
+    pub epoch: usize,
+    /// The names of the saved records.
+    pub records: Vec<String>,
+}
+
+impl CheckpointManifest {
+    /// Load the manifest of the checkpoint directory.
+    pub fn load(directory: &str) -> Result<Self, CheckpointerError> {
+        let content = std::fs::read_to_string(format!("{directory}/{MANIFEST_FILE}"))
+            .map_err(CheckpointerError::IOError)?;
+
+        serde_json::from_str(&content).map_err(|err| CheckpointerError::Unknown(err.to_string()))
+    }
+
+    /// Save the manifest in the checkpoint directory.
+    pub fn save(&self, directory: &str) -> Result<(), CheckpointerError> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|err| CheckpointerError::Unknown(err.to_string()))?;
+
+        std::fs::create_dir_all(directory).map_err(CheckpointerError::IOError)?;
+        std::fs::write(format!("{directory}/{MANIFEST_FILE}"), content)
+            .map_err(CheckpointerError::IOError)
+    }
+
+    /// The epochs of the checkpoints.
+    pub fn epochs(&self) -> impl Iterator<Item = usize> + '_ {
+        self.checkpoints.iter().map(|checkpoint| checkpoint.epoch)
+    }
+
+    pub(crate) fn insert(&mut self, epoch: usize, records: Vec<String>) {
+        self.remove(epoch);
+
+        let index = self
+            .checkpoints
+            .partition_point(|checkpoint| checkpoint.epoch < epoch);
+        self.checkpoints
+            .insert(index, CheckpointEntry { epoch, records });
+    }
+
+    pub(crate) fn remove(&mut self, epoch: usize) {
+        self.checkpoints
+            .retain(|checkpoint| checkpoint.epoch != epoch);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn manifest_should_be_saved_and_loaded() {
+        let temp_dir = TempDir::new().unwrap();
+        let directory = temp_dir.path().to_str().unwrap();
+        let mut manifest = CheckpointManifest::default();
+
+        manifest.insert(3, vec!["model".to_string()]);
+        manifest.insert(1, vec!["model".to_string(), "ema".to_string()]);
+        manifest.insert(2, vec!["model".to_string()]);
+        manifest.remove(2);
+        manifest.save(directory).unwrap();
+
+        let loaded = CheckpointManifest::load(directory).unwrap();
+        assert_eq!(loaded, manifest);
+        assert_eq!(loaded.epochs().collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(loaded.checkpoints[0].records, vec!["model", "ema"]);
+    }
+}
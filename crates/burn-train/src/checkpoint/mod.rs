@@ -1,9 +1,13 @@
 mod async_checkpoint;
 mod base;
 mod file;
+mod manifest;
+mod state;
 mod strategy;
 
 pub use async_checkpoint::*;
 pub use base::*;
 pub use file::*;
+pub use manifest::*;
+pub use state::*;
 pub use strategy::*;
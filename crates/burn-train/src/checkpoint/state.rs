@@ -0,0 +1,119 @@
+use super::CheckpointerError;
+use burn_core::tensor::Generator;
+use burn_core::LearningRate;
+use serde::{Deserialize, Serialize};
+
+/// The state of the training saved with the checkpoint of an epoch, besides the model, the
+/// optimizer and the learning rate scheduler, so that a resumed training continues with the same
+/// random numbers and the same shuffling of the data loaders.
+///
+/// The state is saved at the end of each epoch, and when the training is interrupted during an
+/// epoch with the [cursor](EpochCursor) of the interrupted iteration, so that the training
+/// resumes in the middle of the epoch. The gradients accumulated since the last optimizer step
+/// are lost, and the cursor isn't saved when a single learner trains on multiple devices.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TrainingState {
+    /// The epoch of the checkpoint, the last completed one or the interrupted one.
+    pub epoch: usize,
+    /// The number of iterations started over the training data loader before the epoch of the
+    /// cursor, if any.
+    pub dataloader_train_iterations: usize,
+    /// The number of iterations started over the validation data loader.
+    pub dataloader_valid_iterations: usize,
+    /// The state of each rank of a distributed training, a single one otherwise.
+    #[serde(default)]
+    pub ranks: Vec<RankState>,
+    /// Where the interrupted epoch resumes, if the training was interrupted during the epoch.
+    #[serde(default)]
+    pub cursor: Option<EpochCursor>,
+}
+
+/// The state of a rank of the training, which draws its own random numbers and iterates over its
+/// own data loader.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct RankState {
+    /// The generator forking the generator of each epoch, installed on the rank for each training
+    /// iteration, if the training is seeded.
+    pub generator: Option<Generator>,
+    /// The [positions](burn_core::data::dataloader::DataLoaderIterator::positions) of the
+    /// training data loader in the interrupted epoch, if any.
+    pub positions: Vec<usize>,
+}
+
+/// The position of the training within an interrupted epoch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct EpochCursor {
+    /// The number of training iterations done in the epoch.
+    pub iteration: usize,
+    /// The learning rate of the epoch, when the scheduler is only stepped once per
+    /// [epoch](crate::LrSchedulerGranularity::Epoch).
+    pub lr: Option<LearningRate>,
+}
+
+impl TrainingState {
+    /// Load the state of the given epoch from the checkpoint directory.
+    pub fn load(directory: &str, epoch: usize) -> Result<Self, CheckpointerError> {
+        let content = std::fs::read_to_string(Self::path(directory, epoch))
+            .map_err(CheckpointerError::IOError)?;
+
+        serde_json::from_str(&content).map_err(|err| CheckpointerError::Unknown(err.to_string()))
+    }
+
+    /// Save the state in the checkpoint directory.
+    pub fn save(&self, directory: &str) -> Result<(), CheckpointerError> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|err| CheckpointerError::Unknown(err.to_string()))?;
+
+        std::fs::create_dir_all(directory).map_err(CheckpointerError::IOError)?;
+        std::fs::write(Self::path(directory, self.epoch), content)
+            .map_err(CheckpointerError::IOError)
+    }
+
+    /// Delete the state of the given epoch from the checkpoint directory, if present.
+    pub fn delete(directory: &str, epoch: usize) -> Result<(), CheckpointerError> {
+        let path = Self::path(directory, epoch);
+
+        if std::path::Path::new(&path).exists() {
+            std::fs::remove_file(path).map_err(CheckpointerError::IOError)?;
+        }
+
+        Ok(())
+    }
+
+    fn path(directory: &str, epoch: usize) -> String {
+        format!("{directory}/state-{epoch}.json")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn state_should_be_saved_loaded_and_deleted() {
+        let temp_dir = TempDir::new().unwrap();
+        let directory = temp_dir.path().to_str().unwrap();
+        let mut generator = Generator::new(42);
+        generator.next_seed();
+        let state = TrainingState {
+            epoch: 3,
+            dataloader_train_iterations: 2,
+            dataloader_valid_iterations: 2,
+            ranks: vec![RankState {
+                generator: Some(generator),
+                positions: vec![4, 6],
+            }],
+            cursor: Some(EpochCursor {
+                iteration: 5,
+                lr: Some(0.1),
+            }),
+        };
+
+        state.save(directory).unwrap();
+        assert_eq!(TrainingState::load(directory, 3).unwrap(), state);
+
+        TrainingState::delete(directory, 3).unwrap();
+        assert!(TrainingState::load(directory, 3).is_err());
+    }
+}
@@ -2,8 +2,10 @@ mod base;
 mod composed;
 mod lastn;
 mod metric;
+mod topk;
 
 pub use base::*;
 pub use composed::*;
 pub use lastn::*;
 pub use metric::*;
+pub use topk::*;
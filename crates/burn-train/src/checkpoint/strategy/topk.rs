@@ -0,0 +1,206 @@
+use super::CheckpointingStrategy;
+use crate::{
+    checkpoint::CheckpointingAction,
+    metric::{
+        store::{Aggregate, Direction, EventStoreClient, Split},
+        Metric,
+    },
+};
+
+/// Keep the best K checkpoints based on a metric.
+///
+/// The best epochs are computed from the metric values of all the epochs collected by the
+/// [event store](EventStoreClient), so the stale checkpoints of a resumed training are deleted as
+/// well. It can be combined with [KeepLastNCheckpoints](super::KeepLastNCheckpoints) using a
+/// [composed strategy](super::ComposedCheckpointingStrategy) to also be able to resume the
+/// training.
+pub struct KeepBestKCheckpoints {
+    num_keep: usize,
+    aggregate: Aggregate,
+    direction: Direction,
+    split: Split,
+    name: String,
+}
+
+impl KeepBestKCheckpoints {
+    /// Create a new strategy keeping the checkpoints of the `num_keep` best epochs.
+    pub fn new<M>(num_keep: usize, aggregate: Aggregate, direction: Direction, split: Split) -> Self
+    where
+        M: Metric,
+    {
+        Self {
+            num_keep,
+            name: M::NAME.to_string(),
+            aggregate,
+            direction,
+            split,
+        }
+    }
+
+    /// The best epochs until the given one, the earliest first among equal values.
+    fn best_epochs(&self, epoch: usize, store: &EventStoreClient) -> Vec<usize> {
+        let mut values: Vec<(usize, f64)> = (1..=epoch)
+            .filter_map(|epoch| {
+                store
+                    .find_metric(&self.name, epoch, self.aggregate, self.split)
+                    .map(|value| (epoch, value))
+            })
+            .collect();
+
+        values.sort_by(|(epoch_a, a), (epoch_b, b)| {
+            let ordering = match self.direction {
+                Direction::Lowest => a.total_cmp(b),
+                Direction::Highest => b.total_cmp(a),
+            };
+            ordering.then(epoch_a.cmp(epoch_b))
+        });
+
+        values
+            .into_iter()
+            .take(self.num_keep)
+            .map(|(epoch, _)| epoch)
+            .collect()
+    }
+}
+
+impl CheckpointingStrategy for KeepBestKCheckpoints {
+    fn checkpointing(
+        &mut self,
+        epoch: usize,
+        store: &EventStoreClient,
+    ) -> Vec<CheckpointingAction> {
+        let previous = self.best_epochs(epoch - 1, store);
+        let current = self.best_epochs(epoch, store);
+        let mut actions = Vec::new();
+
+        if current.contains(&epoch) {
+            actions.push(CheckpointingAction::Save);
+        }
+
+        for epoch in previous {
+            if !current.contains(&epoch) {
+                actions.push(CheckpointingAction::Delete(epoch));
+            }
+        }
+
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        checkpoint::{ComposedCheckpointingStrategy, KeepLastNCheckpoints},
+        logger::InMemoryMetricLogger,
+        metric::{
+            processor::{
+                test_utils::{end_epoch, process_train},
+                Metrics, MinimalEventProcessor,
+            },
+            store::LogEventStore,
+            LossMetric,
+        },
+        TestBackend,
+    };
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn store_with_losses(losses: &[f64]) -> Rc<EventStoreClient> {
+        let mut store = LogEventStore::default();
+        let mut metrics = Metrics::<f64, f64>::default();
+        store.register_logger_train(InMemoryMetricLogger::default());
+        metrics.register_train_metric_numeric(LossMetric::<TestBackend>::new());
+        let store = Rc::new(EventStoreClient::new(store));
+        let mut processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        for (i, loss) in losses.iter().enumerate() {
+            process_train(&mut processor, *loss, i + 1);
+            end_epoch(&mut processor, i + 1);
+        }
+
+        store
+    }
+
+    fn strategy() -> KeepBestKCheckpoints {
+        KeepBestKCheckpoints::new::<LossMetric<TestBackend>>(
+            2,
+            Aggregate::Mean,
+            Direction::Lowest,
+            Split::Train,
+        )
+    }
+
+    #[test]
+    fn should_keep_the_best_k_epochs() {
+        let store = store_with_losses(&[3.0, 2.0, 4.0, 1.0, 2.5]);
+        let mut strategy = strategy();
+
+        assert_eq!(
+            vec![CheckpointingAction::Save],
+            strategy.checkpointing(1, &store)
+        );
+        assert_eq!(
+            vec![CheckpointingAction::Save],
+            strategy.checkpointing(2, &store)
+        );
+        // Worse than the two best epochs.
+        assert!(strategy.checkpointing(3, &store).is_empty());
+        assert_eq!(
+            vec![CheckpointingAction::Save, CheckpointingAction::Delete(1)],
+            strategy.checkpointing(4, &store)
+        );
+        assert!(strategy.checkpointing(5, &store).is_empty());
+    }
+
+    #[test]
+    fn should_delete_stale_checkpoints_when_resumed() {
+        let store = store_with_losses(&[3.0, 2.0, 4.0, 1.0]);
+        // A new strategy, as when the training is resumed after the third epoch.
+        let mut strategy = strategy();
+
+        assert_eq!(
+            vec![CheckpointingAction::Save, CheckpointingAction::Delete(1)],
+            strategy.checkpointing(4, &store)
+        );
+    }
+
+    #[test]
+    fn should_only_delete_when_neither_best_k_nor_last_n() {
+        let store = store_with_losses(&[1.0, 2.0, 3.0, 4.0, 0.5, 0.7]);
+        let mut strategy = ComposedCheckpointingStrategy::builder()
+            .add(strategy())
+            .add(KeepLastNCheckpoints::new(1))
+            .build();
+
+        assert_eq!(
+            vec![CheckpointingAction::Save],
+            strategy.checkpointing(1, &store)
+        );
+        assert_eq!(
+            vec![CheckpointingAction::Save],
+            strategy.checkpointing(2, &store)
+        );
+        // The third epoch is only kept as the last one.
+        assert_eq!(
+            vec![CheckpointingAction::Save],
+            strategy.checkpointing(3, &store)
+        );
+        assert_eq!(
+            vec![CheckpointingAction::Save, CheckpointingAction::Delete(3)],
+            strategy.checkpointing(4, &store)
+        );
+        assert_eq!(
+            vec![
+                CheckpointingAction::Save,
+                CheckpointingAction::Delete(2),
+                CheckpointingAction::Delete(4)
+            ],
+            strategy.checkpointing(5, &store)
+        );
+        assert_eq!(
+            vec![CheckpointingAction::Save, CheckpointingAction::Delete(1)],
+            strategy.checkpointing(6, &store)
+        );
+    }
+}
@@ -1,13 +1,17 @@
-use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
+use crate::checkpoint::{
+    CheckpointManifest, Checkpointer, CheckpointingAction, CheckpointingStrategy, TrainingState,
+};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::ddp::DistributedDataParallel;
+use crate::learner::{EarlyStoppingStrategy, ModelEma, ModelSwa};
+use crate::logger::TensorBoardHistograms;
 use crate::metric::store::EventStoreClient;
 use crate::LearnerSummaryConfig;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
 use burn_core::optim::Optimizer;
 use burn_core::tensor::backend::Backend;
-use burn_core::tensor::Device;
+use burn_core::tensor::{Device, Generator};
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -22,8 +26,16 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) lr_granularity: LrSchedulerGranularity,
+    pub(crate) ema_decay: Option<f64>,
+    pub(crate) ema_validation: bool,
+    pub(crate) swa_start: Option<usize>,
+    pub(crate) swa_update_bn: bool,
+    pub(crate) histograms: Option<TensorBoardHistograms>,
+    pub(crate) generator: Option<Generator>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
+    pub(crate) ddp: Option<DistributedDataParallel>,
     pub(crate) interrupter: TrainingInterrupter,
     pub(crate) early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
     pub(crate) event_processor: LC::EventProcessor,
@@ -36,44 +48,158 @@ pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     model: LC::CheckpointerModel,
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
+    ema: LC::CheckpointerModel,
+    swa: LC::CheckpointerModel,
     strategy: LC::CheckpointerStrategy,
+    #[new(default)]
+    manifest: CheckpointManifest,
+    /// The directory where the manifest and the training states are saved, if any.
+    #[new(default)]
+    directory: Option<String>,
 }
 
 impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
+    /// Track the saved checkpoints in the manifest of the directory, loading the existing one,
+    /// and save the training states in the directory.
+    pub(crate) fn with_directory(mut self, directory: String) -> Self {
+        self.manifest = CheckpointManifest::load(&directory).unwrap_or_default();
+        self.directory = Some(directory);
+        self
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn checkpoint(
         &mut self,
         model: &LC::Model,
         optim: &LC::Optimizer,
         scheduler: &LC::LrScheduler,
-        epoch: usize,
+        ema: Option<&ModelEma<LC::Backend, LC::Model>>,
+        swa: Option<&ModelSwa<LC::Backend, LC::Model>>,
+        state: &TrainingState,
         store: &EventStoreClient,
     ) {
-        let actions = self.strategy.checkpointing(epoch, store);
+        let actions = self.strategy.checkpointing(state.epoch, store);
 
         for action in actions {
             match action {
-                CheckpointingAction::Delete(epoch) => {
-                    self.model
-                        .delete(epoch)
-                        .expect("Can delete model checkpoint.");
-                    self.optim
-                        .delete(epoch)
-                        .expect("Can delete optimizer checkpoint.");
-                    self.lr_scheduler
-                        .delete(epoch)
-                        .expect("Can delete learning rate scheduler checkpoint.");
-                }
-                CheckpointingAction::Save => {
-                    self.model
-                        .save(epoch, model.clone().into_record())
-                        .expect("Can save model checkpoint.");
-                    self.optim
-                        .save(epoch, optim.to_record())
-                        .expect("Can save optimizer checkpoint.");
-                    self.lr_scheduler
-                        .save(epoch, scheduler.to_record())
-                        .expect("Can save learning rate scheduler checkpoint.");
-                }
+                CheckpointingAction::Delete(epoch) => self.delete(epoch),
+                CheckpointingAction::Save => self.save(model, optim, scheduler, ema, swa, state),
+            }
+        }
+
+        self.save_manifest();
+    }
+
+    /// Save the checkpoint of an interrupted epoch, whatever the checkpointing strategy, so that
+    /// the training can resume at the [cursor](crate::checkpoint::EpochCursor) of its state.
+    pub(crate) fn checkpoint_interrupted(
+        &mut self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        ema: Option<&ModelEma<LC::Backend, LC::Model>>,
+        swa: Option<&ModelSwa<LC::Backend, LC::Model>>,
+        state: &TrainingState,
+    ) {
+        // Without its state, the checkpoint would be resumed as the one of a completed epoch.
+        if self.directory.is_none() {
+            return;
+        }
+
+        log::info!(
+            "Saving the checkpoint of the interrupted epoch {}",
+            state.epoch
+        );
+        self.save(model, optim, scheduler, ema, swa, state);
+        self.save_manifest();
+    }
+
+    fn save(
+        &mut self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        ema: Option<&ModelEma<LC::Backend, LC::Model>>,
+        swa: Option<&ModelSwa<LC::Backend, LC::Model>>,
+        state: &TrainingState,
+    ) {
+        let epoch = state.epoch;
+        let mut records = vec!["model", "optim", "scheduler"];
+
+        self.model
+            .save(epoch, model.clone().into_record())
+            .expect("Can save model checkpoint.");
+        self.optim
+            .save(epoch, optim.to_record())
+            .expect("Can save optimizer checkpoint.");
+        self.lr_scheduler
+            .save(epoch, scheduler.to_record())
+            .expect("Can save learning rate scheduler checkpoint.");
+        if let Some(ema) = ema {
+            self.ema
+                .save(epoch, ema.model().clone().into_record())
+                .expect("Can save moving average checkpoint.");
+            records.push("ema");
+        }
+        if let Some(swa) = swa {
+            self.swa
+                .save(epoch, swa.model().clone().into_record())
+                .expect("Can save weight average checkpoint.");
+            records.push("swa");
+        }
+        if let Some(directory) = &self.directory {
+            state
+                .save(directory)
+                .expect("Can save training state checkpoint.");
+            records.push("state");
+        }
+
+        self.manifest
+            .insert(epoch, records.into_iter().map(String::from).collect());
+    }
+
+    /// Delete the checkpoints listed in the manifest of the epochs after the given one, which are
+    /// stale when the training is started or resumed from that epoch.
+    pub(crate) fn delete_stale(&mut self, epoch: usize) {
+        let stale: Vec<usize> = self.manifest.epochs().filter(|e| *e > epoch).collect();
+
+        if stale.is_empty() {
+            return;
+        }
+
+        log::info!("Deleting the stale checkpoints of the epochs {stale:?}");
+        for epoch in stale {
+            self.delete(epoch);
+        }
+        self.save_manifest();
+    }
+
+    fn delete(&mut self, epoch: usize) {
+        self.model
+            .delete(epoch)
+            .expect("Can delete model checkpoint.");
+        self.optim
+            .delete(epoch)
+            .expect("Can delete optimizer checkpoint.");
+        self.lr_scheduler
+            .delete(epoch)
+            .expect("Can delete learning rate scheduler checkpoint.");
+        self.ema
+            .delete(epoch)
+            .expect("Can delete moving average checkpoint.");
+        self.swa
+            .delete(epoch)
+            .expect("Can delete weight average checkpoint.");
+        if let Some(directory) = &self.directory {
+            TrainingState::delete(directory, epoch).expect("Can delete training state checkpoint.");
+        }
+        self.manifest.remove(epoch);
+    }
+
+    fn save_manifest(&self) {
+        if let Some(directory) = &self.directory {
+            if let Err(err) = self.manifest.save(directory) {
+                log::warn!("Could not save the checkpoint manifest: {err:?}");
             }
         }
     }
@@ -106,6 +232,65 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
 
         (model, optim, scheduler)
     }
+
+    /// Load the training state of the epoch, if saved.
+    pub(crate) fn load_state(&self, epoch: usize) -> Option<TrainingState> {
+        let directory = self.directory.as_ref()?;
+
+        match TrainingState::load(directory, epoch) {
+            Ok(state) => Some(state),
+            Err(err) => {
+                log::warn!("Could not load the training state checkpoint: {err:?}");
+                None
+            }
+        }
+    }
+
+    /// Load the moving average of the model, which is kept as is when the checkpoint doesn't
+    /// include it, e.g. when the moving average was enabled after the checkpoint was saved.
+    pub(crate) fn load_ema(
+        &self,
+        ema: ModelEma<LC::Backend, LC::Model>,
+        device: &Device<LC::Backend>,
+        epoch: usize,
+    ) -> ModelEma<LC::Backend, LC::Model> {
+        match self.ema.restore(epoch, device) {
+            Ok(record) => ema.load_record(record),
+            Err(err) => {
+                log::warn!("Could not load the moving average checkpoint, starting from the model: {err:?}");
+                ema
+            }
+        }
+    }
+
+    /// Load the weight average of the model, which is kept as is when the checkpoint doesn't
+    /// include it.
+    pub(crate) fn load_swa(
+        &self,
+        swa: ModelSwa<LC::Backend, LC::Model>,
+        num_averaged: usize,
+        device: &Device<LC::Backend>,
+        epoch: usize,
+    ) -> ModelSwa<LC::Backend, LC::Model> {
+        match self.swa.restore(epoch, device) {
+            Ok(record) => swa.load_record(record, num_averaged),
+            Err(err) => {
+                log::warn!("Could not load the weight average checkpoint, starting from the model: {err:?}");
+                swa
+            }
+        }
+    }
+}
+
+/// How often the [learning rate scheduler](LrScheduler) is stepped during the training.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LrSchedulerGranularity {
+    /// Step the scheduler at each training iteration.
+    #[default]
+    Iteration,
+    /// Step the scheduler at the first iteration of each training epoch, keeping the same learning
+    /// rate for the whole epoch.
+    Epoch,
 }
 
 #[derive(Clone, Default)]
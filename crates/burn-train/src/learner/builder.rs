@@ -7,9 +7,10 @@ use crate::checkpoint::{
     KeepLastNCheckpoints, MetricCheckpointingStrategy,
 };
 use crate::components::LearnerComponentsMarker;
-use crate::learner::base::TrainingInterrupter;
+use crate::learner::base::{LrSchedulerGranularity, TrainingInterrupter};
+use crate::learner::ddp::{DistributedDataParallel, DEFAULT_BUCKET_SIZE};
 use crate::learner::EarlyStoppingStrategy;
-use crate::logger::{FileMetricLogger, MetricLogger};
+use crate::logger::{FileMetricLogger, MetricLogger, TensorBoardHistograms, TensorBoardWriter};
 use crate::metric::processor::{FullEventProcessor, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
 use crate::metric::{Adaptor, LossMetric, Metric};
@@ -18,11 +19,13 @@ use crate::{
     ApplicationLoggerInstaller, FileApplicationLoggerInstaller, LearnerCheckpointer,
     LearnerSummaryConfig,
 };
+use burn_core::collective::Collective;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
 use burn_core::optim::Optimizer;
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::Generator;
 
 /// Struct to configure and create a [learner](Learner).
 pub struct LearnerBuilder<B, T, V, M, O, S>
@@ -42,12 +45,24 @@ where
         AsyncCheckpointer<M::Record, B>,
         AsyncCheckpointer<O::Record, B>,
         AsyncCheckpointer<S::Record, B>,
+        AsyncCheckpointer<M::Record, B>,
+        AsyncCheckpointer<M::Record, B>,
     )>,
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: String,
     grad_accumulation: Option<usize>,
+    lr_granularity: LrSchedulerGranularity,
+    ema_decay: Option<f64>,
+    ema_validation: bool,
+    swa_start: Option<usize>,
+    swa_update_bn: bool,
+    histograms: Option<TensorBoardHistograms>,
+    generator: Option<Generator>,
     devices: Vec<B::Device>,
+    collective: Option<Collective>,
+    bucket_size: usize,
+    sharded: bool,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
     event_store: LogEventStore,
@@ -81,7 +96,17 @@ where
             checkpointers: None,
             directory: directory.to_string(),
             grad_accumulation: None,
+            lr_granularity: LrSchedulerGranularity::Iteration,
+            ema_decay: None,
+            ema_validation: false,
+            swa_start: None,
+            swa_update_bn: false,
+            histograms: None,
+            generator: None,
             devices: vec![B::Device::default()],
+            collective: None,
+            bucket_size: DEFAULT_BUCKET_SIZE,
+            sharded: false,
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
             renderer: None,
@@ -123,6 +148,51 @@ where
         self
     }
 
+    /// Log the metrics to a [Weights & Biases run](crate::logger::WandbRun), in addition to the
+    /// other metric loggers, and upload the checkpoints to it.
+    ///
+    /// The run should be [finished](crate::logger::WandbRun::finish) after the training.
+    #[cfg(feature = "wandb")]
+    pub fn wandb(mut self, run: &crate::logger::WandbRun) -> Self {
+        run.upload_checkpoints(format!("{}/checkpoint", self.directory).as_str());
+        self.event_store
+            .register_logger_train(run.metric_logger(Split::Train));
+        self.event_store
+            .register_logger_valid(run.metric_logger(Split::Valid));
+        self
+    }
+
+    /// Log the metrics to an [MLflow run](crate::logger::MlflowRun), in addition to the other
+    /// metric loggers, and upload the checkpoints to its artifacts.
+    ///
+    /// The run should be [finished](crate::logger::MlflowRun::finish) after the training.
+    #[cfg(feature = "mlflow")]
+    pub fn mlflow(mut self, run: &crate::logger::MlflowRun) -> Self {
+        run.upload_checkpoints(format!("{}/checkpoint", self.directory).as_str());
+        self.event_store
+            .register_logger_train(run.metric_logger(Split::Train));
+        self.event_store
+            .register_logger_valid(run.metric_logger(Split::Valid));
+        self
+    }
+
+    /// Log the metrics as scalars to [TensorBoard](TensorBoardWriter), in addition to the other
+    /// metric loggers.
+    pub fn tensorboard(mut self, writer: &TensorBoardWriter) -> Self {
+        self.event_store
+            .register_logger_train(writer.metric_logger(Split::Train));
+        self.event_store
+            .register_logger_valid(writer.metric_logger(Split::Valid));
+        self
+    }
+
+    /// Log the histograms of the parameters and gradients of the model to TensorBoard, sampled
+    /// every few optimizer steps.
+    pub fn tensorboard_histograms(mut self, histograms: TensorBoardHistograms) -> Self {
+        self.histograms = Some(histograms);
+        self
+    }
+
     /// Update the checkpointing_strategy.
     pub fn with_checkpointing_strategy<CS>(mut self, strategy: CS) -> Self
     where
@@ -178,6 +248,71 @@ where
         self
     }
 
+    /// Set how often the [learning rate scheduler](LrScheduler) is stepped, at each iteration by
+    /// default.
+    ///
+    /// With [epoch](LrSchedulerGranularity::Epoch) granularity, the number of steps of the
+    /// scheduler is the number of epochs, e.g. for a step decay of the learning rate every few
+    /// epochs.
+    pub fn lr_scheduler_granularity(mut self, granularity: LrSchedulerGranularity) -> Self {
+        self.lr_granularity = granularity;
+        self
+    }
+
+    /// Maintain an [exponential moving average](crate::ModelEma) of the parameters of the model, updated
+    /// after each optimizer step with the given decay, e.g. `0.999`.
+    ///
+    /// The moving average is saved by the [file checkpointer](Self::with_file_checkpointer) next to
+    /// the model, as `ema-{epoch}`, so the averaged model of the training can be loaded from the
+    /// checkpoint of its last epoch.
+    pub fn ema(mut self, decay: f64) -> Self {
+        self.ema_decay = Some(decay);
+        self
+    }
+
+    /// Validate the [moving average](Self::ema) of the model instead of the model itself.
+    pub fn ema_validation(mut self) -> Self {
+        self.ema_validation = true;
+        self
+    }
+
+    /// Enable stochastic weight averaging (SWA), averaging the parameters of the model at the end
+    /// of each epoch from the given one, e.g. with a constant or cyclic learning rate.
+    ///
+    /// The [fitted](Learner::fit) model is then the [averaged model](crate::ModelSwa) instead of
+    /// the one of the last epoch, and the average is saved by the
+    /// [file checkpointer](Self::with_file_checkpointer) as `swa-{epoch}`.
+    ///
+    /// Reference: [Averaging Weights Leads to Wider Optima and Better Generalization](https://arxiv.org/abs/1803.05407)
+    pub fn swa(mut self, start_epoch: usize) -> Self {
+        self.swa_start = Some(start_epoch);
+        self
+    }
+
+    /// Recompute the running statistics of the batch norm layers of the
+    /// [averaged model](Self::swa) at the end of the training with the training dataloader, since
+    /// the statistics of the trained models don't match the averaged parameters.
+    ///
+    /// The batches are passed to the [forward step](crate::TrainStep::forward_step) of the
+    /// model. See [update_bn](crate::update_bn) to use another dataloader.
+    pub fn swa_update_bn(mut self) -> Self {
+        self.swa_update_bn = true;
+        self
+    }
+
+    /// Seed the random tensors of the training, e.g. for dropout, with a [generator](Generator)
+    /// created with the given seed.
+    ///
+    /// Each rank of a distributed training forks its own generator, which forks a generator for
+    /// each epoch, [installed](Generator::install) on the thread of the rank after forking it
+    /// again for each training iteration. The generators of the ranks are saved with the
+    /// [training state](crate::checkpoint::TrainingState) of the checkpoints, so a resumed
+    /// training samples the same random numbers as an uninterrupted one.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.generator = Some(Generator::new(seed));
+        self
+    }
+
     /// Register a [numeric](crate::metric::Numeric) training [metric](Metric).
     pub fn metric_train_numeric<Me>(mut self, metric: Me) -> Self
     where
@@ -214,6 +349,95 @@ where
         self
     }
 
+    /// Train with distributed data parallelism (DDP), the learner being the rank of the collective.
+    ///
+    /// Each rank builds its own learner, on its own thread or [process](Collective::connect) and
+    /// device, with a replica of the same model, the same optimizer and a data loader over its
+    /// [shard](burn_core::data::dataloader::DataLoaderBuilder::shard) of the dataset. The
+    /// replicas start from the parameters of the first rank, and the gradients are averaged
+    /// across the ranks before each optimizer step, so that every replica is updated with the same
+    /// gradients. The loss of each rank should be averaged over its own batch.
+    ///
+    /// Without [gradient accumulation](Self::grads_accumulation), the gradients are averaged
+    /// during the backward pass of each training step, which must then run a single backward
+    /// pass, as soon as the [buckets](Self::ddp_bucket_size) of gradients are complete.
+    ///
+    /// The training and validation metrics are the ones of the batches of each rank, and only
+    /// the first rank saves the checkpoints, while every rank resumes from them. The other ranks
+    /// should use a [renderer](Self::renderer) that doesn't draw in the terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// let handles = Collective::group(devices.len())
+    ///     .into_iter()
+    ///     .zip(devices)
+    ///     .map(|(collective, device)| {
+    ///         std::thread::spawn(move || {
+    ///             let (rank, world_size) = (collective.rank(), collective.world_size());
+    ///             let dataloader_train = DataLoaderBuilder::new(batcher)
+    ///                 .batch_size(64)
+    ///                 .shard(rank, world_size)
+    ///                 .build(dataset);
+    ///             let learner = LearnerBuilder::new(ARTIFACT_DIR)
+    ///                 .devices(vec![device.clone()])
+    ///                 .ddp(collective)
+    ///                 .build(model.init(&device), optimizer.init(), 1e-3);
+    ///
+    ///             learner.fit(dataloader_train, dataloader_valid)
+    ///         })
+    ///     });
+    /// ```
+    pub fn ddp(mut self, collective: Collective) -> Self {
+        self.collective = Some(collective);
+        self.sharded = false;
+        self
+    }
+
+    /// Train with fully sharded data parallelism (FSDP), the learner being the rank of the
+    /// collective.
+    ///
+    /// As with [distributed data parallelism](Self::ddp), each rank builds its own learner with a
+    /// data loader over its shard of the dataset, but the parameters of the model are
+    /// [sharded](burn_core::module::Sharded) across the ranks instead of replicated, so the
+    /// gradients and the optimizer states are sharded as well. Every parameter of the model
+    /// must be in a [sharded module](burn_core::module::Sharded) of the same collective, whose
+    /// gradients are reduced during the backward pass.
+    ///
+    /// Each rank saves the checkpoints of its shards, so the ranks should use different
+    /// directories.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// let model = Model {
+    ///     blocks: config
+    ///         .init_blocks(&device)
+    ///         .into_iter()
+    ///         .map(|block| Sharded::new(block, &collective))
+    ///         .collect(),
+    /// };
+    /// let learner = LearnerBuilder::new(&format!("{ARTIFACT_DIR}/rank-{}", collective.rank()))
+    ///     .devices(vec![device])
+    ///     .fsdp(collective)
+    ///     .build(model, optimizer.init(), 1e-3);
+    /// ```
+    pub fn fsdp(mut self, collective: Collective) -> Self {
+        self.collective = Some(collective);
+        self.sharded = true;
+        self
+    }
+
+    /// The minimum number of elements of the buckets of gradients reduced together with
+    /// [distributed data parallelism](Self::ddp). Default: 4194304
+    ///
+    /// Larger buckets need fewer collective operations, while smaller ones overlap more of the
+    /// communication with the backward pass.
+    pub fn ddp_bucket_size(mut self, bucket_size: usize) -> Self {
+        self.bucket_size = bucket_size;
+        self
+    }
+
     /// The epoch from which the training must resume.
     pub fn checkpoint(mut self, checkpoint: usize) -> Self {
         self.checkpoint = Some(checkpoint);
@@ -267,15 +491,27 @@ where
             "optim",
         );
         let checkpointer_scheduler: FileCheckpointer<FR> = FileCheckpointer::new(
-            recorder,
+            recorder.clone(),
             format!("{}/checkpoint", self.directory).as_str(),
             "scheduler",
         );
+        let checkpointer_ema = FileCheckpointer::new(
+            recorder.clone(),
+            format!("{}/checkpoint", self.directory).as_str(),
+            "ema",
+        );
+        let checkpointer_swa = FileCheckpointer::new(
+            recorder,
+            format!("{}/checkpoint", self.directory).as_str(),
+            "swa",
+        );
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
             AsyncCheckpointer::new(checkpointer_optimizer),
             AsyncCheckpointer::new(checkpointer_scheduler),
+            AsyncCheckpointer::new(checkpointer_ema),
+            AsyncCheckpointer::new(checkpointer_swa),
         ));
 
         self
@@ -341,9 +577,19 @@ where
         let event_store = Rc::new(EventStoreClient::new(self.event_store));
         let event_processor = FullEventProcessor::new(self.metrics, renderer, event_store.clone());
 
-        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
-        });
+        let checkpointer = self
+            .checkpointers
+            .map(|(model, optim, scheduler, ema, swa)| {
+                LearnerCheckpointer::new(
+                    model,
+                    optim,
+                    scheduler,
+                    ema,
+                    swa,
+                    self.checkpointer_strategy,
+                )
+                .with_directory(format!("{}/checkpoint", self.directory))
+            });
 
         let summary = if self.summary {
             Some(LearnerSummaryConfig {
@@ -364,7 +610,19 @@ where
             event_store,
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
+            lr_granularity: self.lr_granularity,
+            ema_decay: self.ema_decay,
+            ema_validation: self.ema_validation,
+            swa_start: self.swa_start,
+            swa_update_bn: self.swa_update_bn,
+            histograms: self.histograms,
+            generator: self.generator,
             devices: self.devices,
+            ddp: self.collective.map(|collective| DistributedDataParallel {
+                collective,
+                bucket_size: self.bucket_size,
+                sharded: self.sharded,
+            }),
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
             summary,
@@ -0,0 +1,150 @@
+use burn_core::collective::{Collective, ReduceOp};
+use burn_core::module::{AutodiffModule, ModuleMapper, ParamId};
+use burn_core::optim::{GradientsParams, GradientsReducer};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{Tensor, TensorData};
+
+use crate::checkpoint::RankState;
+use crate::TrainingInterrupter;
+
+/// The default minimum number of elements of the buckets of gradients reduced together,
+/// 16 MiB of `f32` gradients.
+pub(crate) const DEFAULT_BUCKET_SIZE: usize = 1 << 22;
+
+/// The rank of a [learner](crate::Learner) training a replica of the model with distributed data
+/// parallelism.
+#[derive(Clone, Debug)]
+pub(crate) struct DistributedDataParallel {
+    pub(crate) collective: Collective,
+    pub(crate) bucket_size: usize,
+    /// If the parameters of the model are [sharded](burn_core::module::Sharded) across the
+    /// ranks instead of replicated.
+    pub(crate) sharded: bool,
+}
+
+impl DistributedDataParallel {
+    /// If the learner saves its checkpoints: each rank saves the checkpoints of its shards, while
+    /// only the first rank saves the ones of the replicas.
+    pub(crate) fn saves_checkpoints(&self) -> bool {
+        self.sharded || self.collective.rank() == 0
+    }
+
+    /// Replace the parameters of the replica with the ones of the first rank, so that all the
+    /// replicas start from the same model.
+    ///
+    /// The shards are already initialized from the module of the first rank.
+    pub(crate) fn sync_model<B: AutodiffBackend, M: AutodiffModule<B>>(&self, model: M) -> M {
+        if self.sharded {
+            return model;
+        }
+
+        model.map(&mut Broadcast {
+            collective: &self.collective,
+        })
+    }
+
+    /// Start averaging the gradients of the replica with the ones of every rank during the next
+    /// backward pass, so that the communication overlaps with it.
+    ///
+    /// The gradients of the shards are already reduced during the backward pass.
+    pub(crate) fn reducer<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        model: &M,
+    ) -> Option<GradientsReducer<B>> {
+        if self.sharded {
+            return None;
+        }
+
+        Some(GradientsReducer::new(
+            model,
+            &self.collective,
+            self.bucket_size,
+        ))
+    }
+
+    /// Average the gradients of the replica with the ones of every rank, with the
+    /// [reducer](Self::reducer) of the backward pass computing them if any.
+    ///
+    /// The gradients of the shards are already reduced during the backward pass.
+    pub(crate) fn sync_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        grads: GradientsParams,
+        model: &M,
+        reducer: Option<GradientsReducer<B>>,
+    ) -> GradientsParams {
+        if self.sharded {
+            return grads;
+        }
+
+        match reducer {
+            Some(reducer) => reducer.finish(grads, model),
+            None => grads.all_reduce(model, &self.collective, self.bucket_size),
+        }
+    }
+
+    /// If every rank continues the training epoch: the epoch ends for all the ranks as soon as
+    /// one of them has no more items, and the training is interrupted for all the ranks as soon
+    /// as one of them is interrupted.
+    pub(crate) fn sync_continue<B: Backend>(
+        &self,
+        has_item: bool,
+        interrupter: &TrainingInterrupter,
+        device: &B::Device,
+    ) -> bool {
+        let flags = Tensor::<B, 1>::from_floats(
+            [
+                !has_item as u8 as f32,
+                interrupter.should_stop() as u8 as f32,
+            ],
+            device,
+        );
+        let flags = self.collective.all_reduce(flags, ReduceOp::Sum).into_data();
+        let flags: Vec<f32> = flags.iter::<f32>().collect();
+
+        if flags[1] > 0.0 {
+            interrupter.stop();
+        }
+
+        flags[0] == 0.0 && flags[1] == 0.0
+    }
+
+    /// The states of all the ranks, ordered by rank, so that the checkpoint saved by one rank
+    /// restores the state of every rank.
+    pub(crate) fn gather_states(&self, state: RankState) -> Vec<RankState> {
+        let bytes = serde_json::to_vec(&state).expect("Can serialize the rank state.");
+        let num_bytes = bytes.len();
+        let states = self
+            .collective
+            .all_gather_data(TensorData::new(bytes, [num_bytes]));
+
+        states
+            .iter()
+            .map(|state| {
+                let bytes = state.as_slice::<u8>().unwrap();
+                serde_json::from_slice(bytes).expect("Can deserialize the rank state.")
+            })
+            .collect()
+    }
+
+    /// The decision of the first rank, so that all the ranks stop at the same epoch.
+    pub(crate) fn sync_decision<B: Backend>(&self, decision: bool, device: &B::Device) -> bool {
+        let decision = Tensor::<B, 1>::from_floats([decision as u8 as f32], device);
+        let decision = self.collective.broadcast(decision, 0).into_data();
+        let decision = decision.iter::<f32>().next().unwrap();
+
+        decision > 0.0
+    }
+}
+
+struct Broadcast<'a> {
+    collective: &'a Collective,
+}
+
+impl<'a, B: AutodiffBackend> ModuleMapper<B> for Broadcast<'a> {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let is_require_grad = tensor.is_require_grad();
+        let tensor = self.collective.broadcast(tensor.inner(), 0);
+
+        Tensor::from_inner(tensor).set_require_grad(is_require_grad)
+    }
+}
@@ -0,0 +1,158 @@
+use burn_core::module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId};
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::container::TensorContainer;
+use burn_core::tensor::Tensor;
+use core::marker::PhantomData;
+
+/// An exponential moving average (EMA) of the parameters of a model, updated after each optimizer
+/// step with `ema = decay * ema + (1 - decay) * param`.
+///
+/// The averaged model is often better than the raw model at the end of the training, so it can be
+/// used for the validation instead, see [ema](crate::LearnerBuilder::ema).
+#[derive(Clone, Debug)]
+pub struct ModelEma<B: AutodiffBackend, M: AutodiffModule<B>> {
+    model: M,
+    decay: f64,
+    _backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModelEma<B, M> {
+    /// Create the moving average of the model, starting from its current parameters.
+    ///
+    /// # Panics
+    ///
+    /// If the decay isn't between 0 and 1.
+    pub fn new(model: &M, decay: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&decay),
+            "The decay of the moving average must be between 0 and 1, got {decay}"
+        );
+
+        Self {
+            model: model.clone().no_grad(),
+            decay,
+            _backend: PhantomData,
+        }
+    }
+
+    /// Update the moving average with the current parameters of the model.
+    pub fn update(&mut self, model: &M) {
+        self.model = update_average(self.model.clone(), model, 1.0 - self.decay);
+    }
+
+    /// The averaged model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Consume the moving average, returning the averaged model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+
+    /// Load the parameters of the averaged model from a record.
+    pub fn load_record(mut self, record: M::Record) -> Self {
+        self.model = self.model.load_record(record);
+        self
+    }
+}
+
+/// Move the parameters of the average towards the ones of the model, with
+/// `average = (1 - weight) * average + weight * param`.
+pub(super) fn update_average<B: AutodiffBackend, M: AutodiffModule<B>>(
+    average: M,
+    model: &M,
+    weight: f64,
+) -> M {
+    let mut collector = ParamsCollector::<B> {
+        params: TensorContainer::new(),
+        _backend: PhantomData,
+    };
+    model.visit(&mut collector);
+
+    average.map(&mut AverageUpdate::<B> {
+        params: collector.params,
+        weight,
+        _backend: PhantomData,
+    })
+}
+
+struct ParamsCollector<B: AutodiffBackend> {
+    params: TensorContainer<ParamId>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for ParamsCollector<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params.register(id.clone(), tensor.clone().inner());
+    }
+}
+
+struct AverageUpdate<B: AutodiffBackend> {
+    params: TensorContainer<ParamId>,
+    weight: f64,
+    _backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleMapper<B> for AverageUpdate<B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let Some(param) = self.params.remove::<B::InnerBackend, D>(id) else {
+            return tensor;
+        };
+        let average = tensor.inner().mul_scalar(1.0 - self.weight) + param.mul_scalar(self.weight);
+
+        Tensor::from_inner(average)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::module::Module;
+    use burn_core::nn::{Linear, LinearConfig};
+
+    #[test]
+    fn ema_should_average_the_parameters() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(2, 2).init(&device);
+        let mut ema = ModelEma::new(&linear, 0.75);
+
+        let updated = linear.clone().map(&mut Shift);
+        ema.update(&updated);
+        ema.update(&updated);
+
+        // 0.75^2 * w + (1 - 0.75^2) * (w + 1)
+        let expected = linear
+            .weight
+            .val()
+            .add_scalar(1.0 - 0.75 * 0.75)
+            .into_data();
+        ema.model()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected, 5);
+        assert!(!ema.model().weight.is_require_grad());
+        assert_eq!(ema.model().weight.id, linear.weight.id);
+    }
+
+    struct Shift;
+
+    impl ModuleMapper<TestAutodiffBackend> for Shift {
+        fn map_float<const D: usize>(
+            &mut self,
+            _id: &ParamId,
+            tensor: Tensor<TestAutodiffBackend, D>,
+        ) -> Tensor<TestAutodiffBackend, D> {
+            tensor.add_scalar(1.0)
+        }
+    }
+
+    #[test]
+    #[should_panic = "The decay of the moving average must be between 0 and 1"]
+    fn ema_should_panic_with_invalid_decay() {
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(2, 2).init(&Default::default());
+        ModelEma::new(&linear, 1.5);
+    }
+}
@@ -1,12 +1,22 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::DataLoader,
+    lr_scheduler::LrScheduler,
+    module::AutodiffModule,
+    optim::{GradientsAccumulator, GradientsParams},
+    tensor::backend::{AutodiffBackend, Backend},
+    tensor::{Generator, GeneratorGuard},
+    LearningRate,
 };
-use std::sync::Arc;
-
+use std::sync::{Arc, Mutex};
+
+use crate::checkpoint::EpochCursor;
+use crate::components::LearnerComponents;
+use crate::learner::base::{LrSchedulerGranularity, TrainingInterrupter};
+use crate::learner::ddp::DistributedDataParallel;
+use crate::learner::ModelEma;
+use crate::logger::TensorBoardHistograms;
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
-use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
-use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{ManualTrainStep, MultiDevicesTrainStep, TrainStep, ValidStep};
 
 /// A validation epoch.
 #[derive(new)]
@@ -23,6 +33,17 @@ pub struct TrainEpoch<TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    lr_granularity: LrSchedulerGranularity,
+    histograms: Option<TensorBoardHistograms>,
+    /// Where the epoch resumes, if it was interrupted.
+    #[new(default)]
+    cursor: Option<EpochCursor>,
+    /// The generator forking the one installed for each training iteration.
+    #[new(default)]
+    generator: Mutex<Option<Generator>>,
+    /// Where the epoch was interrupted, with the positions of the data loader.
+    #[new(default)]
+    interrupted: Mutex<Option<(EpochCursor, Vec<usize>)>>,
 }
 
 impl<VI> ValidEpoch<VI> {
@@ -73,6 +94,74 @@ impl<VI> ValidEpoch<VI> {
 }
 
 impl<TI> TrainEpoch<TI> {
+    /// Resume the epoch at the cursor of an interrupted one, the data loader being already set
+    /// at its positions.
+    pub(crate) fn with_cursor(mut self, cursor: Option<EpochCursor>) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Install a generator forked from the given one during each training iteration, so that the
+    /// random tensors of an iteration only depend on the iterations before it.
+    pub(crate) fn with_generator(self, generator: Option<Generator>) -> Self {
+        *self.generator.lock().unwrap() = generator;
+        self
+    }
+
+    /// Where the epoch was interrupted, with the positions of the data loader, if it was.
+    pub(crate) fn interrupted(&self) -> Option<(EpochCursor, Vec<usize>)> {
+        self.interrupted.lock().unwrap().clone()
+    }
+
+    /// Returns the number of iterations already done and the learning rate of the epoch, when
+    /// resuming an interrupted epoch.
+    fn start(&self) -> (usize, Option<LearningRate>) {
+        match &self.cursor {
+            Some(cursor) => (cursor.iteration, cursor.lr),
+            None => (0, None),
+        }
+    }
+
+    /// Installs the generator of the iteration, if the training is seeded.
+    fn install_generator(&self) -> Option<GeneratorGuard> {
+        let mut generator = self.generator.lock().unwrap();
+
+        generator
+            .as_mut()
+            .map(|generator| generator.fork().install())
+    }
+
+    /// Records where the epoch was interrupted.
+    fn interrupt(&self, iteration: usize, lr: Option<LearningRate>, positions: Vec<usize>) {
+        let cursor = EpochCursor { iteration, lr };
+        *self.interrupted.lock().unwrap() = Some((cursor, positions));
+    }
+
+    /// Returns the learning rate of the iteration, only stepping the scheduler once per epoch with
+    /// the [epoch](LrSchedulerGranularity::Epoch) granularity.
+    fn step_lr<B: Backend, S: LrScheduler<B>>(
+        &self,
+        scheduler: &mut S,
+        lr_epoch: &mut Option<LearningRate>,
+    ) -> LearningRate {
+        match self.lr_granularity {
+            LrSchedulerGranularity::Iteration => scheduler.step(),
+            LrSchedulerGranularity::Epoch => *lr_epoch.get_or_insert_with(|| scheduler.step()),
+        }
+    }
+
+    /// Logs the histograms of the parameters and gradients before an optimizer step, when they
+    /// are sampled.
+    fn log_histograms<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        model: &M,
+        grads: &GradientsParams,
+    ) {
+        if let Some(histograms) = &self.histograms {
+            histograms.step(model, grads);
+        }
+    }
+
     /// Runs the training epoch.
     ///
     /// # Arguments
@@ -81,6 +170,7 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `ema` - The moving average of the model to update after each optimizer step.
     ///
     /// # Returns
     ///
@@ -92,6 +182,7 @@ impl<TI> TrainEpoch<TI> {
         scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
         interrupter: &TrainingInterrupter,
+        ema: &mut Option<ModelEma<LC::Backend, LC::Model>>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -100,13 +191,14 @@ impl<TI> TrainEpoch<TI> {
         log::info!("Executing training step for epoch {}", self.epoch,);
 
         let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
+        let (mut iteration, mut lr_epoch) = self.start();
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
         while let Some(item) = iterator.next() {
             iteration += 1;
-            let lr = scheduler.step();
+            let lr = self.step_lr(scheduler, &mut lr_epoch);
+            let _generator = self.install_generator();
             log::info!("Iteration {}", iteration);
 
             let progress = iterator.progress();
@@ -119,11 +211,23 @@ impl<TI> TrainEpoch<TI> {
 
                     if accumulation <= accumulation_current {
                         let grads = accumulator.grads();
+                        self.log_histograms(&model, &grads);
                         model = model.optimize(&mut optim, lr, grads);
                         accumulation_current = 0;
+
+                        if let Some(ema) = ema.as_mut() {
+                            ema.update(&model);
+                        }
+                    }
+                }
+                None => {
+                    self.log_histograms(&model, &item.grads);
+                    model = model.optimize(&mut optim, lr, item.grads);
+
+                    if let Some(ema) = ema.as_mut() {
+                        ema.update(&model);
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
             }
 
             let item = LearnerItem::new(
@@ -139,6 +243,75 @@ impl<TI> TrainEpoch<TI> {
 
             if interrupter.should_stop() {
                 log::info!("Training interrupted.");
+                self.interrupt(iteration, lr_epoch, iterator.positions());
+                break;
+            }
+        }
+        processor.process_train(Event::EndEpoch(self.epoch));
+
+        (model, optim)
+    }
+
+    /// Runs the training epoch with a [manual training step](ManualTrainStep), which executes the
+    /// backward passes and the optimizer steps itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model to train.
+    /// * `optim` - The optimizer given to the training step.
+    /// * `scheduler` - The learning rate scheduler to use.
+    /// * `processor` - The event processor to use.
+    /// * `ema` - The moving average of the model to update after each training step.
+    ///
+    /// # Returns
+    ///
+    /// The trained model and the optimizer.
+    pub fn run_manual<LC: LearnerComponents, TO>(
+        &self,
+        mut model: LC::Model,
+        mut optim: LC::Optimizer,
+        scheduler: &mut LC::LrScheduler,
+        processor: &mut LC::EventProcessor,
+        interrupter: &TrainingInterrupter,
+        ema: &mut Option<ModelEma<LC::Backend, LC::Model>>,
+    ) -> (LC::Model, LC::Optimizer)
+    where
+        LC::EventProcessor: EventProcessor<ItemTrain = TO>,
+        LC::Model: ManualTrainStep<LC::Optimizer, TI, TO>,
+    {
+        log::info!("Executing manual training step for epoch {}", self.epoch);
+
+        let mut iterator = self.dataloader.iter();
+        let (mut iteration, mut lr_epoch) = self.start();
+
+        while let Some(item) = iterator.next() {
+            iteration += 1;
+            let lr = self.step_lr(scheduler, &mut lr_epoch);
+            let _generator = self.install_generator();
+            log::info!("Iteration {}", iteration);
+
+            let progress = iterator.progress();
+            let output;
+            (model, output) = model.step(item, &mut optim, lr);
+
+            if let Some(ema) = ema.as_mut() {
+                ema.update(&model);
+            }
+
+            let item = LearnerItem::new(
+                output,
+                progress,
+                self.epoch,
+                self.epoch_total,
+                iteration,
+                Some(lr),
+            );
+
+            processor.process_train(Event::ProcessedItem(item));
+
+            if interrupter.should_stop() {
+                log::info!("Training interrupted.");
+                self.interrupt(iteration, lr_epoch, iterator.positions());
                 break;
             }
         }
@@ -151,6 +324,10 @@ impl<TI> TrainEpoch<TI> {
 impl<TI> TrainEpoch<TI> {
     /// Runs the training epoch on multiple devices.
     ///
+    /// The training steps run on a thread per device, so they are seeded with the global seed of
+    /// the backend instead of the generator of the epoch, and the position of an interrupted
+    /// epoch isn't recorded.
+    ///
     /// # Arguments
     ///
     /// * `model` - The model to train.
@@ -158,10 +335,12 @@ impl<TI> TrainEpoch<TI> {
     /// * `lr_scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
     /// * `devices` - The devices to use.
+    /// * `ema` - The moving average of the model to update after each optimizer step.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_multi_device<LC: LearnerComponents, TO>(
         &self,
         mut model: LC::Model,
@@ -170,6 +349,7 @@ impl<TI> TrainEpoch<TI> {
         processor: &mut LC::EventProcessor,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
+        ema: &mut Option<ModelEma<LC::Backend, LC::Model>>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -184,7 +364,7 @@ impl<TI> TrainEpoch<TI> {
         );
 
         let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
+        let (mut iteration, mut lr_epoch) = self.start();
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
@@ -203,7 +383,7 @@ impl<TI> TrainEpoch<TI> {
 
             for item in items {
                 iteration += 1;
-                let lr = lr_scheduler.step();
+                let lr = self.step_lr(lr_scheduler, &mut lr_epoch);
                 let progress = iterator.progress();
 
                 let grads = item.grads.to_device(&device_main, &model);
@@ -213,8 +393,13 @@ impl<TI> TrainEpoch<TI> {
 
                 if accumulation <= accumulation_current {
                     let grads = accumulator.grads();
+                    self.log_histograms(&model, &grads);
                     model = model.optimize(&mut optim, lr, grads);
                     accumulation_current = 0;
+
+                    if let Some(ema) = ema.as_mut() {
+                        ema.update(&model);
+                    }
                 }
 
                 let item = LearnerItem::new(
@@ -245,3 +430,99 @@ impl<TI> TrainEpoch<TI> {
         (model, optim)
     }
 }
+
+impl<TI> TrainEpoch<TI> {
+    /// Runs the training epoch of a rank of a distributed data parallel training, averaging the
+    /// gradients of all the ranks before each optimizer step, during the backward pass of the
+    /// training step when the gradients aren't accumulated.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn run_ddp<LC: LearnerComponents, TO>(
+        &self,
+        mut model: LC::Model,
+        mut optim: LC::Optimizer,
+        scheduler: &mut LC::LrScheduler,
+        processor: &mut LC::EventProcessor,
+        interrupter: &TrainingInterrupter,
+        ddp: &DistributedDataParallel,
+        device: &<LC::Backend as Backend>::Device,
+        ema: &mut Option<ModelEma<LC::Backend, LC::Model>>,
+    ) -> (LC::Model, LC::Optimizer)
+    where
+        LC::EventProcessor: EventProcessor<ItemTrain = TO>,
+        LC::Model: TrainStep<TI, TO>,
+    {
+        log::info!(
+            "Executing training step for epoch {} on rank {}",
+            self.epoch,
+            ddp.collective.rank()
+        );
+
+        let mut iterator = self.dataloader.iter();
+        let (mut iteration, mut lr_epoch) = self.start();
+        let mut accumulator = GradientsAccumulator::new();
+        let mut accumulation_current = 0;
+        let accumulation = self.grad_accumulation.unwrap_or(1);
+        let mut positions;
+
+        loop {
+            // The item of an interrupted iteration isn't trained on, so the epoch resumes before
+            // it.
+            positions = iterator.positions();
+            let item = iterator.next();
+
+            // Every rank must execute the same collective operations.
+            if !ddp.sync_continue::<LC::Backend>(item.is_some(), interrupter, device) {
+                break;
+            }
+            let item = item.unwrap();
+
+            iteration += 1;
+            let lr = self.step_lr(scheduler, &mut lr_epoch);
+            let _generator = self.install_generator();
+            log::info!("Iteration {}", iteration);
+
+            let progress = iterator.progress();
+
+            // The gradients are only reduced before the optimizer steps, during the backward
+            // pass of the step without accumulation.
+            let reducer = match accumulation {
+                1 => ddp.reducer(&model),
+                _ => None,
+            };
+            let item = model.step(item);
+
+            accumulator.accumulate(&model, item.grads);
+            accumulation_current += 1;
+
+            if accumulation <= accumulation_current {
+                let grads = ddp.sync_grads(accumulator.grads(), &model, reducer);
+                self.log_histograms(&model, &grads);
+                model = model.optimize(&mut optim, lr, grads);
+                accumulation_current = 0;
+
+                if let Some(ema) = ema.as_mut() {
+                    ema.update(&model);
+                }
+            }
+
+            let item = LearnerItem::new(
+                item.item,
+                progress,
+                self.epoch,
+                self.epoch_total,
+                iteration,
+                Some(lr),
+            );
+
+            processor.process_train(Event::ProcessedItem(item));
+        }
+
+        if interrupter.should_stop() {
+            log::info!("Training interrupted.");
+            self.interrupt(iteration, lr_epoch, positions);
+        }
+        processor.process_train(Event::EndEpoch(self.epoch));
+
+        (model, optim)
+    }
+}
@@ -2,11 +2,14 @@ mod application_logger;
 mod base;
 mod builder;
 mod classification;
+mod ddp;
 mod early_stopping;
+mod ema;
 mod epoch;
 mod regression;
 mod step;
 mod summary;
+mod swa;
 mod train_val;
 
 pub use application_logger::*;
@@ -14,9 +17,11 @@ pub use base::*;
 pub use builder::*;
 pub use classification::*;
 pub use early_stopping::*;
+pub use ema::*;
 pub use epoch::*;
 pub use regression::*;
 pub use step::*;
 pub use summary::*;
+pub use swa::*;
 pub use train::*;
 pub use train_val::*;
@@ -0,0 +1,178 @@
+use burn_core::data::dataloader::DataLoader;
+use burn_core::module::AutodiffModule;
+use burn_core::nn::cumulative_statistics;
+use burn_core::tensor::backend::AutodiffBackend;
+use core::marker::PhantomData;
+use std::sync::Arc;
+
+use super::ema::update_average;
+use crate::TrainStep;
+
+/// The stochastic weight average (SWA) of the parameters of a model, the running mean of the
+/// models it is [updated](ModelSwa::update) with, usually at the end of each epoch.
+///
+/// The running statistics of the batch norm layers aren't averaged, but should be recomputed for
+/// the averaged parameters with [update_bn].
+///
+/// Reference: [Averaging Weights Leads to Wider Optima and Better Generalization](https://arxiv.org/abs/1803.05407)
+#[derive(Clone, Debug)]
+pub struct ModelSwa<B: AutodiffBackend, M: AutodiffModule<B>> {
+    model: M,
+    num_averaged: usize,
+    _backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModelSwa<B, M> {
+    /// Create the weight average, starting from the current parameters of the model.
+    pub fn new(model: &M) -> Self {
+        Self {
+            model: model.clone().no_grad(),
+            num_averaged: 1,
+            _backend: PhantomData,
+        }
+    }
+
+    /// Add the current parameters of the model to the average.
+    pub fn update(&mut self, model: &M) {
+        self.num_averaged += 1;
+        self.model = update_average(self.model.clone(), model, 1.0 / self.num_averaged as f64);
+    }
+
+    /// The averaged model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Consume the weight average, returning the averaged model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+
+    /// The number of models in the average.
+    pub fn num_averaged(&self) -> usize {
+        self.num_averaged
+    }
+
+    /// Load the parameters of the averaged model from a record, with the number of models it
+    /// averages.
+    pub fn load_record(mut self, record: M::Record, num_averaged: usize) -> Self {
+        self.model = self.model.load_record(record);
+        self.num_averaged = num_averaged;
+        self
+    }
+}
+
+/// Recompute the running statistics of the batch norm layers of the model, e.g. of a
+/// [weight average](ModelSwa), as the cumulative average of the statistics of the batches of the
+/// dataloader, running the forward pass of the model on each of them.
+///
+/// The previous running statistics are discarded and the parameters of the model aren't updated.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// update_bn(swa.model(), dataloader, |model, batch| {
+///     model.forward(batch.images);
+/// });
+/// ```
+pub fn update_bn<M, TI, F>(model: &M, dataloader: Arc<dyn DataLoader<TI>>, mut forward: F)
+where
+    F: FnMut(&M, TI),
+{
+    log::info!("Updating the batch norm statistics");
+
+    cumulative_statistics(|| {
+        for item in dataloader.iter() {
+            forward(model, item);
+        }
+    });
+}
+
+/// Recompute the running statistics of the batch norm layers of the model with its
+/// [forward step](TrainStep::forward_step).
+pub(crate) fn update_bn_step<M, TI, TO>(model: &M, dataloader: Arc<dyn DataLoader<TI>>)
+where
+    M: TrainStep<TI, TO>,
+{
+    update_bn(model, dataloader, |model, item| model.forward_step(item));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::data::dataloader::batcher::Batcher;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::nn::{BatchNorm, BatchNormConfig, Linear, LinearConfig};
+    use burn_core::tensor::{Tensor, TensorData};
+
+    #[test]
+    fn swa_should_average_the_parameters() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(2, 2).init(&device);
+        let mut swa = ModelSwa::new(&linear);
+
+        let weight = linear.weight.val();
+        for shift in [1.0, 2.0, 6.0] {
+            let mut shifted = linear.clone();
+            shifted.weight = shifted.weight.map(|weight| weight.add_scalar(shift));
+            swa.update(&shifted);
+        }
+
+        // The mean of the weights shifted by 0, 1, 2 and 6.
+        let expected = weight.add_scalar(9.0 / 4.0).into_data();
+        swa.model()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected, 5);
+        assert_eq!(swa.num_averaged(), 4);
+    }
+
+    #[derive(Clone)]
+    struct RowBatcher;
+
+    impl Batcher<[f32; 2], Tensor<TestAutodiffBackend, 2>> for RowBatcher {
+        fn batch(&self, items: Vec<[f32; 2]>) -> Tensor<TestAutodiffBackend, 2> {
+            let shape = [items.len(), 2];
+            let data = TensorData::new(items.concat(), shape);
+            Tensor::from_data(data, &Default::default())
+        }
+    }
+
+    #[test]
+    fn update_bn_should_recompute_the_statistics_of_the_dataset() {
+        let device = Default::default();
+        let batch_norm: BatchNorm<TestAutodiffBackend, 0> = BatchNormConfig::new(2).init(&device);
+        batch_norm.forward(Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[10.0, -5.0], [20.0, 5.0]],
+            &device,
+        ));
+
+        // Both batches have the same means, so the mean of their variances is the variance of
+        // the dataset.
+        let dataloader = DataLoaderBuilder::new(RowBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![
+                [1.0, 2.0],
+                [3.0, 2.0],
+                [0.0, 0.0],
+                [4.0, 4.0],
+            ]));
+        update_bn(&batch_norm, dataloader, |batch_norm, batch| {
+            batch_norm.forward(batch);
+        });
+
+        batch_norm
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.0, 2.0]), 5);
+        batch_norm
+            .running_var
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.5, 2.0]), 5);
+    }
+}
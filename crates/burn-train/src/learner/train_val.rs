@@ -1,10 +1,13 @@
+use super::ddp::DistributedDataParallel;
+use crate::checkpoint::{RankState, TrainingState};
 use crate::components::LearnerComponents;
 use crate::metric::processor::EventProcessor;
-use crate::{Learner, TrainEpoch, ValidEpoch};
+use crate::{update_bn_step, Learner, ModelEma, ModelSwa, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
 use burn_core::module::{AutodiffModule, Module};
 use burn_core::optim::{GradientsParams, Optimizer};
 use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::Generator;
 use std::sync::Arc;
 
 /// A training output.
@@ -62,6 +65,18 @@ pub trait TrainStep<TI, TO> {
     ///
     /// The training output containing the model output and the gradients.
     fn step(&self, item: TI) -> TrainOutput<TO>;
+    /// Runs the forward pass of the training step without the backward pass, e.g. to
+    /// [recompute the batch norm statistics](crate::update_bn) of a weight average.
+    ///
+    /// The default implementation runs the whole [step](TrainStep::step), discarding its
+    /// gradients, and should be overridden to skip the backward pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The training input for the model.
+    fn forward_step(&self, item: TI) {
+        let _ = self.step(item);
+    }
     /// Optimize the current module with the provided gradients and learning rate.
     ///
     /// # Arguments
@@ -83,6 +98,33 @@ pub trait TrainStep<TI, TO> {
     }
 }
 
+/// Trait to be implemented for training models which execute the backward passes and the
+/// optimizer steps themselves, e.g. to alternate the updates of a generator and a discriminator.
+///
+/// The optimizer is the one of the [learner](Learner), which can hold several optimizers by
+/// implementing [Optimizer] with a [record](burn_core::record::Record) grouping their records, so
+/// that they are saved with the checkpoints.
+///
+/// # Notes
+///
+/// To be used with [fit_manual](Learner::fit_manual), which only trains on a single device,
+/// doesn't accumulate the gradients and doesn't log the histograms of the parameters.
+pub trait ManualTrainStep<O, TI, TO>: Sized {
+    /// Runs the training step, which executes the forward and backward passes and the optimizer
+    /// steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The training input for the model.
+    /// * `optim` - The optimizer of the learner.
+    /// * `lr` - The learning rate of the iteration.
+    ///
+    /// # Returns
+    ///
+    /// The updated model and the training output.
+    fn step(self, item: TI, optim: &mut O, lr: f64) -> (Self, TO);
+}
+
 /// Trait to be implemented for validating models.
 pub trait ValidStep<VI, VO> {
     /// Runs a validation step.
@@ -97,6 +139,9 @@ pub trait ValidStep<VI, VO> {
     fn step(&self, item: VI) -> VO;
 }
 
+/// Updates the batch norm statistics of a model over a dataloader.
+type UpdateBn<M, TI> = fn(&M, Arc<dyn DataLoader<TI>>);
+
 impl<LC: LearnerComponents> Learner<LC> {
     /// Fits the model.
     ///
@@ -109,7 +154,7 @@ impl<LC: LearnerComponents> Learner<LC> {
     ///
     /// The fitted model.
     pub fn fit<InputTrain, InputValid, OutputTrain, OutputValid>(
-        mut self,
+        self,
         dataloader_train: Arc<dyn DataLoader<InputTrain>>,
         dataloader_valid: Arc<dyn DataLoader<InputValid>>,
     ) -> LC::Model
@@ -121,6 +166,122 @@ impl<LC: LearnerComponents> Learner<LC> {
         LC::Model: TrainStep<InputTrain, OutputTrain>,
         <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<InputValid, OutputValid>,
         LC::EventProcessor: EventProcessor<ItemTrain = OutputTrain, ItemValid = OutputValid>,
+    {
+        let run_epoch = |mut learner: Self, epoch_train: &TrainEpoch<InputTrain>, ema: &mut _| {
+            if let Some(ddp) = &learner.ddp {
+                let device = learner.devices.first().cloned().unwrap_or_default();
+                (learner.model, learner.optim) = epoch_train.run_ddp::<LC, OutputTrain>(
+                    learner.model,
+                    learner.optim,
+                    &mut learner.lr_scheduler,
+                    &mut learner.event_processor,
+                    &learner.interrupter,
+                    ddp,
+                    &device,
+                    ema,
+                )
+            } else if learner.devices.len() > 1 {
+                (learner.model, learner.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
+                    learner.model,
+                    learner.optim,
+                    &mut learner.lr_scheduler,
+                    &mut learner.event_processor,
+                    learner.devices.clone(),
+                    &learner.interrupter,
+                    ema,
+                )
+            } else {
+                (learner.model, learner.optim) = epoch_train.run::<LC, OutputTrain>(
+                    learner.model,
+                    learner.optim,
+                    &mut learner.lr_scheduler,
+                    &mut learner.event_processor,
+                    &learner.interrupter,
+                    ema,
+                );
+            }
+
+            learner
+        };
+
+        self.fit_with(
+            dataloader_train,
+            dataloader_valid,
+            run_epoch,
+            Some(update_bn_step::<LC::Model, InputTrain, OutputTrain>),
+        )
+    }
+
+    /// Fits the model with a [manual training step](ManualTrainStep), which executes the backward
+    /// passes and the optimizer steps itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader_train` - The training dataloader.
+    /// * `dataloader_valid` - The validation dataloader.
+    ///
+    /// # Returns
+    ///
+    /// The fitted model.
+    ///
+    /// # Panics
+    ///
+    /// If the learner trains on multiple devices.
+    pub fn fit_manual<InputTrain, InputValid, OutputTrain, OutputValid>(
+        self,
+        dataloader_train: Arc<dyn DataLoader<InputTrain>>,
+        dataloader_valid: Arc<dyn DataLoader<InputValid>>,
+    ) -> LC::Model
+    where
+        InputValid: Send,
+        OutputValid: Send,
+        LC::Model: ManualTrainStep<LC::Optimizer, InputTrain, OutputTrain>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<InputValid, OutputValid>,
+        LC::EventProcessor: EventProcessor<ItemTrain = OutputTrain, ItemValid = OutputValid>,
+    {
+        assert!(
+            self.ddp.is_none() && self.devices.len() <= 1,
+            "The manual training step only trains on a single device"
+        );
+        if self.grad_accumulation.is_some() {
+            log::warn!("The gradients aren't accumulated with a manual training step");
+        }
+
+        let run_epoch = |mut learner: Self, epoch_train: &TrainEpoch<InputTrain>, ema: &mut _| {
+            (learner.model, learner.optim) = epoch_train.run_manual::<LC, OutputTrain>(
+                learner.model,
+                learner.optim,
+                &mut learner.lr_scheduler,
+                &mut learner.event_processor,
+                &learner.interrupter,
+                ema,
+            );
+
+            learner
+        };
+
+        self.fit_with(dataloader_train, dataloader_valid, run_epoch, None)
+    }
+
+    /// Fits the model, running each training epoch with the given function and updating the batch
+    /// norm statistics of the averaged model with the given one, if any.
+    fn fit_with<InputTrain, InputValid, OutputTrain, OutputValid, F>(
+        mut self,
+        dataloader_train: Arc<dyn DataLoader<InputTrain>>,
+        dataloader_valid: Arc<dyn DataLoader<InputValid>>,
+        mut run_epoch: F,
+        update_bn: Option<UpdateBn<LC::Model, InputTrain>>,
+    ) -> LC::Model
+    where
+        InputValid: Send,
+        OutputValid: Send,
+        F: FnMut(
+            Self,
+            &TrainEpoch<InputTrain>,
+            &mut Option<ModelEma<LC::Backend, LC::Model>>,
+        ) -> Self,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<InputValid, OutputValid>,
+        LC::EventProcessor: EventProcessor<ItemTrain = OutputTrain, ItemValid = OutputValid>,
     {
         log::info!("Fitting {}", self.model.to_string());
         // The reference model is always on the first device provided.
@@ -128,77 +289,216 @@ impl<LC: LearnerComponents> Learner<LC> {
             self.model = self.model.fork(device);
         }
 
-        let starting_epoch = match self.checkpoint {
-            Some(checkpoint) => {
-                if let Some(checkpointer) = &mut self.checkpointer {
-                    (self.model, self.optim, self.lr_scheduler) = checkpointer.load_checkpoint(
-                        self.model,
-                        self.optim,
-                        self.lr_scheduler,
-                        &Default::default(), // Load the checkpoint on the default device.
-                        checkpoint,
-                    );
+        if let (Some(checkpoint), Some(checkpointer)) = (self.checkpoint, &mut self.checkpointer) {
+            (self.model, self.optim, self.lr_scheduler) = checkpointer.load_checkpoint(
+                self.model,
+                self.optim,
+                self.lr_scheduler,
+                &Default::default(), // Load the checkpoint on the default device.
+                checkpoint,
+            );
+        }
+
+        // Each completed epoch starts one iteration over each data loader, which is the state
+        // restored when the checkpoint has no saved state.
+        let checkpoint = self.checkpoint.unwrap_or(0);
+        let mut state = TrainingState {
+            epoch: checkpoint,
+            dataloader_train_iterations: checkpoint,
+            dataloader_valid_iterations: checkpoint,
+            ranks: Vec::new(),
+            cursor: None,
+        };
+        if let Some(saved) = self.checkpoint.and_then(|checkpoint| {
+            let checkpointer = self.checkpointer.as_ref()?;
+            checkpointer.load_state(checkpoint)
+        }) {
+            state = saved;
+        }
+
+        let rank = self.ddp.as_ref().map_or(0, |ddp| ddp.collective.rank());
+        let rank_state = state.ranks.get(rank).cloned().unwrap_or_default();
+
+        // Each rank draws its own random numbers from a generator forked from the configured one,
+        // which is fast-forwarded past the epochs already started when the checkpoint has no
+        // saved generator.
+        let mut generator = rank_state.generator.or_else(|| {
+            let mut generator = self.generator.clone()?;
+            (0..rank).for_each(|_| {
+                generator.next_seed();
+            });
+            let mut generator = generator.fork();
+            (0..state.dataloader_train_iterations).for_each(|_| {
+                generator.next_seed();
+            });
+
+            Some(generator)
+        });
+
+        if self.checkpoint.is_some() {
+            dataloader_train.set_iterations(state.dataloader_train_iterations);
+            dataloader_valid.set_iterations(state.dataloader_valid_iterations);
+        }
+        // An interrupted epoch is trained again from its cursor.
+        let starting_epoch = match state.cursor {
+            Some(_) => {
+                dataloader_train.set_positions(&rank_state.positions);
+                state.epoch
+            }
+            None => state.epoch + 1,
+        };
+
+        let saves_checkpoints = match &self.ddp {
+            Some(ddp) => ddp.saves_checkpoints(),
+            None => true,
+        };
+        if let (Some(checkpointer), true) = (&mut self.checkpointer, saves_checkpoints) {
+            checkpointer.delete_stale(state.epoch);
+        }
+
+        if let Some(ddp) = &self.ddp {
+            self.model = ddp.sync_model(self.model);
+        }
+
+        let mut ema = self.ema_decay.map(|decay| {
+            let ema = ModelEma::new(&self.model, decay);
+
+            match (self.checkpoint, &self.checkpointer) {
+                (Some(checkpoint), Some(checkpointer)) => {
+                    checkpointer.load_ema(ema, &Default::default(), checkpoint)
                 }
-                checkpoint + 1
+                _ => ema,
             }
-            None => 1,
+        });
+        // The weights are averaged at the end of each completed epoch.
+        let mut swa = match (self.swa_start, self.checkpoint, &self.checkpointer) {
+            (Some(start), Some(checkpoint), Some(checkpointer)) if starting_epoch > start => {
+                let swa = ModelSwa::new(&self.model);
+                Some(checkpointer.load_swa(
+                    swa,
+                    starting_epoch - start,
+                    &Default::default(),
+                    checkpoint,
+                ))
+            }
+            _ => None,
         };
+        let device = self.devices.first().cloned().unwrap_or_default();
 
         for epoch in starting_epoch..self.num_epochs + 1 {
+            // The generator of the epoch is forked again when resuming it, skipping the generators
+            // of the iterations already done.
+            let cursor = state.cursor.take();
+            let generator_start = generator.clone();
+            let mut generator_epoch = generator.as_mut().map(Generator::fork);
+            if let (Some(generator), Some(cursor)) = (&mut generator_epoch, &cursor) {
+                (0..cursor.iteration).for_each(|_| {
+                    generator.next_seed();
+                });
+            }
+
             let epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
-            );
+                self.lr_granularity,
+                self.histograms.clone(),
+            )
+            .with_cursor(cursor)
+            .with_generator(generator_epoch);
 
-            if self.devices.len() > 1 {
-                (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
-                    self.model,
-                    self.optim,
-                    &mut self.lr_scheduler,
-                    &mut self.event_processor,
-                    self.devices.clone(),
-                    &self.interrupter,
-                )
-            } else {
-                (self.model, self.optim) = epoch_train.run::<LC, OutputTrain>(
-                    self.model,
-                    self.optim,
-                    &mut self.lr_scheduler,
-                    &mut self.event_processor,
-                    &self.interrupter,
-                );
-            }
+            self = run_epoch(self, &epoch_train, &mut ema);
 
             if self.interrupter.should_stop() {
+                if let Some((cursor, positions)) = epoch_train.interrupted() {
+                    let rank_state = RankState {
+                        generator: generator_start,
+                        positions,
+                    };
+                    state.epoch = epoch;
+                    state.ranks = gather_states(self.ddp.as_ref(), rank_state);
+                    state.cursor = Some(cursor);
+
+                    if let (Some(checkpointer), true) = (&mut self.checkpointer, saves_checkpoints)
+                    {
+                        checkpointer.checkpoint_interrupted(
+                            &self.model,
+                            &self.optim,
+                            &self.lr_scheduler,
+                            ema.as_ref(),
+                            swa.as_ref(),
+                            &state,
+                        );
+                    }
+                }
                 break;
             }
+            state.dataloader_train_iterations += 1;
+
+            if self.swa_start.is_some_and(|start| epoch >= start) {
+                match &mut swa {
+                    Some(swa) => swa.update(&self.model),
+                    None => swa = Some(ModelSwa::new(&self.model)),
+                }
+            }
 
             let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
+            let model_valid = match (&ema, self.ema_validation) {
+                (Some(ema), true) => ema.model(),
+                _ => &self.model,
+            };
             epoch_valid.run::<LC, OutputValid>(
-                &self.model,
+                model_valid,
                 &mut self.event_processor,
                 &self.interrupter,
             );
+            state.dataloader_valid_iterations += 1;
+            state.epoch = epoch;
+            let rank_state = RankState {
+                generator: generator.clone(),
+                positions: Vec::new(),
+            };
+            state.ranks = gather_states(self.ddp.as_ref(), rank_state);
 
-            if let Some(checkpointer) = &mut self.checkpointer {
+            if let (Some(checkpointer), true) = (&mut self.checkpointer, saves_checkpoints) {
                 checkpointer.checkpoint(
                     &self.model,
                     &self.optim,
                     &self.lr_scheduler,
-                    epoch,
+                    ema.as_ref(),
+                    swa.as_ref(),
+                    &state,
                     &self.event_store,
                 );
             }
 
             if let Some(early_stopping) = &mut self.early_stopping {
-                if early_stopping.should_stop(epoch, &self.event_store) {
+                let mut should_stop = early_stopping.should_stop(epoch, &self.event_store);
+                if let Some(ddp) = &self.ddp {
+                    should_stop = ddp.sync_decision::<LC::Backend>(should_stop, &device);
+                }
+
+                if should_stop {
                     break;
                 }
             }
         }
 
+        if let Some(swa) = swa {
+            self.model = swa.into_model();
+
+            match (self.swa_update_bn, update_bn) {
+                (true, Some(update_bn)) => update_bn(&self.model, dataloader_train),
+                (true, None) => {
+                    log::warn!(
+                        "The batch norm statistics aren't updated with a manual training step"
+                    )
+                }
+                _ => (),
+            }
+        }
+
         // Display learner summary
         if let Some(summary) = self.summary {
             match summary.init() {
@@ -215,3 +515,211 @@ impl<LC: LearnerComponents> Learner<LC> {
         self.model
     }
 }
+
+/// The states of all the ranks of the training, gathered from every rank with distributed data
+/// parallelism.
+fn gather_states(ddp: Option<&DistributedDataParallel>, state: RankState) -> Vec<RankState> {
+    match ddp {
+        Some(ddp) => ddp.gather_states(state),
+        None => vec![state],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+    use crate::{LearnerBuilder, TestAutodiffBackend, TestBackend, TrainingInterrupter};
+    use burn_core::collective::Collective;
+    use burn_core::data::dataloader::batcher::Batcher;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::lr_scheduler::exponential::ExponentialLrSchedulerConfig;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::record::DefaultRecorder;
+    use burn_core::tensor::backend::Backend;
+    use burn_core::tensor::{Distribution, ElementConversion, Tensor};
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    type TestModel = Linear<TestAutodiffBackend>;
+
+    /// An optimizer counting its steps, recording the step, the learning rate and a random value
+    /// sampled by each one, and interrupting the training after the given step.
+    #[derive(Clone, Default)]
+    struct CountingOptimizer {
+        num_steps: usize,
+        steps: Arc<Mutex<Vec<(usize, f64, f32)>>>,
+        interrupt: Option<(usize, TrainingInterrupter)>,
+    }
+
+    impl Optimizer<TestModel, TestAutodiffBackend> for CountingOptimizer {
+        type Record = usize;
+
+        fn step(&mut self, lr: f64, module: TestModel, _grads: GradientsParams) -> TestModel {
+            let sample = Tensor::<TestAutodiffBackend, 1>::random(
+                [1],
+                Distribution::Default,
+                &Default::default(),
+            );
+            let sample = sample.into_scalar().elem::<f32>();
+
+            self.num_steps += 1;
+            self.steps
+                .lock()
+                .unwrap()
+                .push((self.num_steps, lr, sample));
+            if let Some((step, interrupter)) = &self.interrupt {
+                if *step == self.num_steps {
+                    interrupter.stop();
+                }
+            }
+            module
+        }
+
+        fn to_record(&self) -> Self::Record {
+            self.num_steps
+        }
+
+        fn load_record(mut self, record: Self::Record) -> Self {
+            self.num_steps = record;
+            self
+        }
+    }
+
+    impl ManualTrainStep<CountingOptimizer, usize, ()> for TestModel {
+        fn step(self, _item: usize, optim: &mut CountingOptimizer, lr: f64) -> (Self, ()) {
+            (optim.step(lr, self, GradientsParams::new()), ())
+        }
+    }
+
+    impl ValidStep<usize, ()> for Linear<TestBackend> {
+        fn step(&self, _item: usize) {}
+    }
+
+    #[derive(Clone)]
+    struct CountBatcher;
+
+    impl Batcher<usize, usize> for CountBatcher {
+        fn batch(&self, items: Vec<usize>) -> usize {
+            items.len()
+        }
+    }
+
+    struct NoopRenderer;
+
+    impl MetricsRenderer for NoopRenderer {
+        fn update_train(&mut self, _state: MetricState) {}
+        fn update_valid(&mut self, _state: MetricState) {}
+        fn render_train(&mut self, _item: TrainingProgress) {}
+        fn render_valid(&mut self, _item: TrainingProgress) {}
+    }
+
+    /// Fits a seeded model with a manual training step over 3 shuffled batches per epoch, with a
+    /// learning rate halved at each iteration, returning the steps of the optimizer.
+    fn fit_manual(
+        directory: &str,
+        num_epochs: usize,
+        checkpoint: Option<usize>,
+        devices: Vec<<TestAutodiffBackend as Backend>::Device>,
+        collective: Option<Collective>,
+    ) -> Vec<(usize, f64, f32)> {
+        fit_manual_interrupted(directory, num_epochs, checkpoint, devices, collective, None)
+    }
+
+    /// Fits the model like [fit_manual], interrupting the training after the given step.
+    fn fit_manual_interrupted(
+        directory: &str,
+        num_epochs: usize,
+        checkpoint: Option<usize>,
+        devices: Vec<<TestAutodiffBackend as Backend>::Device>,
+        collective: Option<Collective>,
+        interrupt: Option<usize>,
+    ) -> Vec<(usize, f64, f32)> {
+        let dataloader = DataLoaderBuilder::new(CountBatcher)
+            .batch_size(2)
+            .shuffle(42)
+            .build(InMemDataset::new((0..5).collect()));
+        let model: TestModel = LinearConfig::new(2, 2).init(&Default::default());
+
+        let mut builder = LearnerBuilder::new(directory)
+            .with_file_checkpointer(DefaultRecorder::new())
+            .renderer(NoopRenderer)
+            .devices(devices)
+            .num_epochs(num_epochs)
+            .seed(7);
+        if let Some(checkpoint) = checkpoint {
+            builder = builder.checkpoint(checkpoint);
+        }
+        if let Some(collective) = collective {
+            builder = builder.ddp(collective);
+        }
+        let optim = CountingOptimizer {
+            interrupt: interrupt.map(|step| (step, builder.interrupter())),
+            ..Default::default()
+        };
+        let steps = optim.steps.clone();
+        let scheduler = ExponentialLrSchedulerConfig::new(1.0, 0.5).init();
+        let learner = builder.build(model, optim, scheduler);
+
+        learner.fit_manual(dataloader.clone(), dataloader);
+
+        let steps = steps.lock().unwrap().clone();
+        steps
+    }
+
+    #[test]
+    fn fit_manual_should_step_once_per_batch_with_the_scheduled_lr() {
+        let directory = TempDir::new().unwrap();
+        let steps = fit_manual(directory.path().to_str().unwrap(), 2, None, vec![], None);
+
+        let steps = steps.into_iter().map(|(step, lr, _)| (step, lr));
+        let expected = (1..=6).map(|step| (step, 0.5f64.powi(step as i32)));
+        assert_eq!(steps.collect::<Vec<_>>(), expected.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn fit_manual_should_resume_the_optimizer_state() {
+        let directory = TempDir::new().unwrap();
+        let directory = directory.path().to_str().unwrap();
+        let expected = fit_manual(directory, 3, None, vec![], None);
+        fit_manual(directory, 2, None, vec![], None);
+        let steps = fit_manual(directory, 3, Some(2), vec![], None);
+
+        assert_eq!(steps, expected[6..]);
+    }
+
+    #[test]
+    fn fit_manual_should_resume_an_interrupted_epoch() {
+        let directory = TempDir::new().unwrap();
+        let directory = directory.path().to_str().unwrap();
+        let expected = fit_manual(directory, 2, None, vec![], None);
+        let interrupted = fit_manual_interrupted(directory, 2, None, vec![], None, Some(4));
+        let steps = fit_manual(directory, 2, Some(2), vec![], None);
+
+        assert_eq!(interrupted, expected[..4]);
+        assert_eq!(steps, expected[4..]);
+    }
+
+    #[test]
+    #[should_panic = "The manual training step only trains on a single device"]
+    fn fit_manual_should_panic_with_multiple_devices() {
+        let directory = TempDir::new().unwrap();
+        let devices = vec![Default::default(), Default::default()];
+        fit_manual(directory.path().to_str().unwrap(), 1, None, devices, None);
+    }
+
+    #[test]
+    #[should_panic = "The manual training step only trains on a single device"]
+    fn fit_manual_should_panic_with_ddp() {
+        let directory = TempDir::new().unwrap();
+        let collective = Collective::group(1).remove(0);
+        fit_manual(
+            directory.path().to_str().unwrap(),
+            1,
+            None,
+            vec![],
+            Some(collective),
+        );
+    }
+}
@@ -0,0 +1,602 @@
+use super::tracking::new_checkpoints;
+use super::MetricLogger;
+use crate::metric::store::Split;
+use crate::metric::{MetricEntry, NumericEntry};
+use reqwest::blocking::{Client, RequestBuilder};
+use reqwest::{Method, StatusCode};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const DEFAULT_TRACKING_URI: &str = "http://localhost:5000";
+
+/// The maximum number of metrics and params of a batch logging request.
+const MAX_BATCH_METRICS: usize = 1000;
+const MAX_BATCH_PARAMS: usize = 100;
+
+/// The minimum duration between two requests logging the metrics.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The error type of the [MLflow](MlflowRun) client.
+#[derive(Debug)]
+pub enum MlflowError {
+    /// HTTP error.
+    HttpError(reqwest::Error),
+
+    /// Error returned by the tracking server.
+    ApiError(String),
+
+    /// IO error.
+    IOError(std::io::Error),
+}
+
+impl core::fmt::Display for MlflowError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::HttpError(err) => write!(f, "HTTP error: {err}"),
+            Self::ApiError(err) => write!(f, "MLflow API error: {err}"),
+            Self::IOError(err) => write!(f, "IO error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for MlflowError {}
+
+impl From<reqwest::Error> for MlflowError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::HttpError(err)
+    }
+}
+
+/// Configuration to create an [MLflow run](MlflowRun) using the [init function](MlflowRunConfig::init).
+#[derive(Clone, Debug)]
+pub struct MlflowRunConfig {
+    experiment: String,
+    name: Option<String>,
+    tracking_uri: Option<String>,
+    token: Option<String>,
+}
+
+impl MlflowRunConfig {
+    /// Create the configuration of a run of the given experiment, which is created if it doesn't
+    /// exist.
+    pub fn new(experiment: &str) -> Self {
+        Self {
+            experiment: experiment.to_string(),
+            name: None,
+            tracking_uri: None,
+            token: None,
+        }
+    }
+
+    /// The name of the run, generated by the tracking server by default.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// The URI of the tracking server, read from the `MLFLOW_TRACKING_URI` environment variable by
+    /// default, else `http://localhost:5000`.
+    pub fn with_tracking_uri(mut self, tracking_uri: &str) -> Self {
+        self.tracking_uri = Some(tracking_uri.to_string());
+        self
+    }
+
+    /// The bearer token of the tracking server, read from the `MLFLOW_TRACKING_TOKEN` environment
+    /// variable by default.
+    ///
+    /// Without token, the `MLFLOW_TRACKING_USERNAME` and `MLFLOW_TRACKING_PASSWORD` environment
+    /// variables are used for basic authentication when set.
+    pub fn with_token(mut self, token: &str) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+
+    /// Create the run.
+    pub fn init(&self) -> Result<MlflowRun, MlflowError> {
+        let tracking_uri = self
+            .tracking_uri
+            .clone()
+            .or_else(|| std::env::var("MLFLOW_TRACKING_URI").ok())
+            .unwrap_or_else(|| DEFAULT_TRACKING_URI.to_string());
+        let auth = match self
+            .token
+            .clone()
+            .or_else(|| std::env::var("MLFLOW_TRACKING_TOKEN").ok())
+        {
+            Some(token) => MlflowAuth::Bearer(token),
+            None => match std::env::var("MLFLOW_TRACKING_USERNAME") {
+                Ok(username) => {
+                    MlflowAuth::Basic(username, std::env::var("MLFLOW_TRACKING_PASSWORD").ok())
+                }
+                Err(_) => MlflowAuth::None,
+            },
+        };
+
+        let mut state = MlflowRunState::new(
+            Some(Client::builder().timeout(Duration::from_secs(60)).build()?),
+            tracking_uri.trim_end_matches('/').to_string(),
+            auth,
+        );
+
+        state.experiment_id = state.experiment_id(&self.experiment)?;
+        let response = state.request(
+            Method::POST,
+            "runs/create",
+            json!({
+                "experiment_id": state.experiment_id,
+                "run_name": self.name,
+                "start_time": timestamp_millis(),
+            }),
+        )?;
+        let info = &response["run"]["info"];
+        state.run_id = info["run_id"]
+            .as_str()
+            .ok_or_else(|| MlflowError::ApiError("No run id".to_string()))?
+            .to_string();
+        state.artifact_uri = info["artifact_uri"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+
+        Ok(MlflowRun {
+            state: Arc::new(Mutex::new(state)),
+        })
+    }
+}
+
+/// An [MLflow](https://mlflow.org) run of an experiment, to which the metrics of the training are
+/// logged with [metric loggers](MlflowRun::metric_logger), e.g. with
+/// [LearnerBuilder::mlflow](crate::LearnerBuilder::mlflow).
+///
+/// The metrics are sent to the tracking server at most every few seconds, the run should be
+/// [finished](MlflowRun::finish) at the end of the training to send the remaining ones.
+#[derive(Clone)]
+pub struct MlflowRun {
+    state: Arc<Mutex<MlflowRunState>>,
+}
+
+impl MlflowRun {
+    /// The id of the run.
+    pub fn id(&self) -> String {
+        self.state.lock().unwrap().run_id.clone()
+    }
+
+    /// The id of the experiment of the run.
+    pub fn experiment_id(&self) -> String {
+        self.state.lock().unwrap().experiment_id.clone()
+    }
+
+    /// Log a param of the run.
+    pub fn log_param(&self, key: &str, value: impl core::fmt::Display) -> Result<(), MlflowError> {
+        self.state
+            .lock()
+            .unwrap()
+            .log_params(vec![(key.to_string(), value.to_string())])
+    }
+
+    /// Log the fields of a configuration as params of the run, e.g. the
+    /// [config](burn_core::config::Config) of the model or of the training, with keys prefixed by
+    /// the given name, e.g. `optimizer.weight_decay`.
+    pub fn log_config<C: Serialize>(&self, name: &str, config: &C) -> Result<(), MlflowError> {
+        let value = serde_json::to_value(config)
+            .map_err(|err| MlflowError::ApiError(format!("Can't serialize config: {err}")))?;
+        let mut params = Vec::new();
+        flatten_params(name.to_string(), value, &mut params);
+
+        self.state.lock().unwrap().log_params(params)
+    }
+
+    /// Create a metric logger of the given split, logging the numeric metrics of each iteration
+    /// prefixed by the split, e.g. `train/Loss`.
+    pub fn metric_logger(&self, split: Split) -> MlflowMetricLogger {
+        MlflowMetricLogger {
+            run: self.clone(),
+            split,
+            names: HashSet::new(),
+            step: 0,
+        }
+    }
+
+    /// Upload a file to the artifacts of the run, under the given path.
+    ///
+    /// Only the artifacts proxied by the tracking server, i.e. with a `mlflow-artifacts:/` URI,
+    /// are supported.
+    pub fn log_artifact(&self, path: &str, artifact_path: &str) -> Result<(), MlflowError> {
+        self.state.lock().unwrap().log_artifact(path, artifact_path)
+    }
+
+    /// Upload the files saved in the checkpoint directory to the artifacts of the run, under
+    /// `checkpoint/`.
+    ///
+    /// The checkpoints of an epoch are uploaded once the training of the next epoch ends, and
+    /// the remaining ones when the run is [finished](MlflowRun::finish).
+    pub fn upload_checkpoints(&self, directory: &str) {
+        self.state.lock().unwrap().checkpoint_directory = Some(directory.to_string());
+    }
+
+    /// Finish the run, sending the remaining metrics and checkpoints.
+    pub fn finish(self) -> Result<(), MlflowError> {
+        let mut state = self.state.lock().unwrap();
+
+        state.upload_checkpoints(None);
+        state.flush()?;
+        state.request(
+            Method::POST,
+            "runs/update",
+            json!({
+                "run_id": state.run_id,
+                "status": "FINISHED",
+                "end_time": timestamp_millis(),
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn log_metric(&self, key: String, value: f64, step: usize) {
+        self.state.lock().unwrap().log_metric(key, value, step);
+    }
+
+    fn end_epoch(&self, split: Split, epoch: usize) {
+        let mut state = self.state.lock().unwrap();
+
+        // The checkpoints of the previous epochs are saved by now.
+        if let Split::Train = split {
+            state.upload_checkpoints(Some(epoch));
+        }
+        if let Err(err) = state.flush() {
+            log::warn!("Could not send the metrics to MLflow: {err}");
+        }
+    }
+}
+
+#[derive(Debug)]
+enum MlflowAuth {
+    None,
+    Bearer(String),
+    Basic(String, Option<String>),
+}
+
+struct MlflowRunState {
+    client: Option<Client>,
+    tracking_uri: String,
+    auth: MlflowAuth,
+    experiment_id: String,
+    run_id: String,
+    artifact_uri: String,
+    metrics: Vec<Value>,
+    last_flush: Instant,
+    checkpoint_directory: Option<String>,
+    uploaded: HashSet<String>,
+}
+
+impl MlflowRunState {
+    /// Create the state of a run, without client when testing.
+    fn new(client: Option<Client>, tracking_uri: String, auth: MlflowAuth) -> Self {
+        Self {
+            client,
+            tracking_uri,
+            auth,
+            experiment_id: String::new(),
+            run_id: String::new(),
+            artifact_uri: String::new(),
+            metrics: Vec::new(),
+            last_flush: Instant::now(),
+            checkpoint_directory: None,
+            uploaded: HashSet::new(),
+        }
+    }
+
+    fn builder(&self, method: Method, url: String) -> Result<RequestBuilder, MlflowError> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| MlflowError::ApiError("No client".to_string()))?;
+        let builder = client.request(method, url);
+
+        Ok(match &self.auth {
+            MlflowAuth::None => builder,
+            MlflowAuth::Bearer(token) => builder.bearer_auth(token),
+            MlflowAuth::Basic(username, password) => {
+                builder.basic_auth(username, password.as_ref())
+            }
+        })
+    }
+
+    fn request(&self, method: Method, endpoint: &str, body: Value) -> Result<Value, MlflowError> {
+        let url = format!("{}/api/2.0/mlflow/{endpoint}", self.tracking_uri);
+        let builder = self.builder(method.clone(), url)?;
+        let builder = match method {
+            Method::GET => builder.query(&body),
+            _ => builder.json(&body),
+        };
+
+        let response = builder.send()?;
+        let status = response.status();
+        let response: Value = response.json().unwrap_or_default();
+
+        if !status.is_success() {
+            return Err(MlflowError::ApiError(format!(
+                "{status} {}: {}",
+                response["error_code"].as_str().unwrap_or_default(),
+                response["message"].as_str().unwrap_or_default()
+            )));
+        }
+
+        Ok(response)
+    }
+
+    /// The id of the experiment of the given name, which is created if it doesn't exist.
+    fn experiment_id(&self, name: &str) -> Result<String, MlflowError> {
+        let response = match self.request(
+            Method::GET,
+            "experiments/get-by-name",
+            json!({ "experiment_name": name }),
+        ) {
+            Ok(response) => response["experiment"]["experiment_id"].clone(),
+            Err(MlflowError::ApiError(err)) if err.starts_with(StatusCode::NOT_FOUND.as_str()) => {
+                self.request(Method::POST, "experiments/create", json!({ "name": name }))?
+                    ["experiment_id"]
+                    .clone()
+            }
+            Err(err) => return Err(err),
+        };
+
+        response
+            .as_str()
+            .map(|id| id.to_string())
+            .ok_or_else(|| MlflowError::ApiError(format!("No id for the experiment {name}")))
+    }
+
+    fn log_params(&self, params: Vec<(String, String)>) -> Result<(), MlflowError> {
+        for params in params.chunks(MAX_BATCH_PARAMS) {
+            let params: Vec<Value> = params
+                .iter()
+                .map(|(key, value)| json!({ "key": key, "value": value }))
+                .collect();
+
+            self.request(
+                Method::POST,
+                "runs/log-batch",
+                json!({ "run_id": self.run_id, "params": params }),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn log_metric(&mut self, key: String, value: f64, step: usize) {
+        self.metrics.push(json!({
+            "key": key,
+            "value": value,
+            "timestamp": timestamp_millis(),
+            "step": step,
+        }));
+
+        if self.last_flush.elapsed() >= FLUSH_INTERVAL {
+            if let Err(err) = self.flush() {
+                log::warn!("Could not send the metrics to MLflow: {err}");
+            }
+        }
+    }
+
+    /// Send the metrics logged since the last flush, which are kept to be sent again on failure.
+    fn flush(&mut self) -> Result<(), MlflowError> {
+        self.last_flush = Instant::now();
+
+        while !self.metrics.is_empty() {
+            let num_metrics = usize::min(self.metrics.len(), MAX_BATCH_METRICS);
+
+            self.request(
+                Method::POST,
+                "runs/log-batch",
+                json!({ "run_id": self.run_id, "metrics": self.metrics[..num_metrics] }),
+            )?;
+            self.metrics.drain(..num_metrics);
+        }
+
+        Ok(())
+    }
+
+    fn log_artifact(&self, path: &str, artifact_path: &str) -> Result<(), MlflowError> {
+        let location = self
+            .artifact_uri
+            .strip_prefix("mlflow-artifacts:")
+            .ok_or_else(|| {
+                MlflowError::ApiError(format!(
+                    "Unsupported artifact location {}",
+                    self.artifact_uri
+                ))
+            })?
+            .trim_start_matches('/');
+        let url = format!(
+            "{}/api/2.0/mlflow-artifacts/artifacts/{location}/{artifact_path}",
+            self.tracking_uri
+        );
+
+        self.builder(Method::PUT, url)?
+            .body(std::fs::read(path).map_err(MlflowError::IOError)?)
+            .send()?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Upload the checkpoints not uploaded yet, of the epochs before the given one if any.
+    fn upload_checkpoints(&mut self, before_epoch: Option<usize>) {
+        let Some(directory) = self.checkpoint_directory.clone() else {
+            return;
+        };
+
+        for file in new_checkpoints(&directory, &self.uploaded, before_epoch) {
+            let path = format!("{directory}/{file}");
+
+            match self.log_artifact(&path, &format!("checkpoint/{file}")) {
+                Ok(()) => {
+                    self.uploaded.insert(file);
+                }
+                Err(err) => log::warn!("Could not upload the checkpoint {path}: {err}"),
+            }
+        }
+    }
+}
+
+/// Flatten the fields of a configuration into params, with the keys of nested objects joined by
+/// dots.
+fn flatten_params(key: String, value: Value, params: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(fields) => {
+            for (name, value) in fields {
+                flatten_params(format!("{key}.{name}"), value, params);
+            }
+        }
+        Value::String(value) => params.push((key, value)),
+        value => params.push((key, value.to_string())),
+    }
+}
+
+fn timestamp_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Metric logger sending the numeric metrics of a split to an [MLflow run](MlflowRun).
+///
+/// The step of the metrics is the number of iterations of the split since the start of the
+/// training.
+///
+/// The metrics can't be read back, so another logger, e.g. the default
+/// [file logger](crate::logger::FileMetricLogger), must be registered for the metrics to be
+/// aggregated, as done by [LearnerBuilder::mlflow](crate::LearnerBuilder::mlflow).
+pub struct MlflowMetricLogger {
+    run: MlflowRun,
+    split: Split,
+    // The metrics logged during the current iteration.
+    names: HashSet<String>,
+    step: usize,
+}
+
+impl MetricLogger for MlflowMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        let value = match NumericEntry::deserialize(&item.serialize) {
+            Ok(NumericEntry::Value(value)) => value,
+            Ok(NumericEntry::Aggregated(value, _)) => value,
+            Err(_) => return,
+        };
+        // The tracking server rejects the values which aren't numbers in JSON.
+        if !value.is_finite() {
+            return;
+        }
+        let prefix = match self.split {
+            Split::Train => "train",
+            Split::Valid => "valid",
+        };
+        let key = format!("{prefix}/{}", item.name);
+
+        // Each metric is logged once per iteration.
+        if !self.names.insert(key.clone()) {
+            self.names.clear();
+            self.names.insert(key.clone());
+            self.step += 1;
+        }
+        self.run.log_metric(key, value, self.step);
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        if !self.names.is_empty() {
+            self.names.clear();
+            self.step += 1;
+        }
+        self.run.end_epoch(self.split, epoch);
+    }
+
+    fn read_numeric(&mut self, _name: &str, _epoch: usize) -> Result<Vec<NumericEntry>, String> {
+        Err("The MLflow metric logger can't read the metrics.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offline_run() -> MlflowRun {
+        MlflowRun {
+            state: Arc::new(Mutex::new(MlflowRunState::new(
+                None,
+                DEFAULT_TRACKING_URI.to_string(),
+                MlflowAuth::None,
+            ))),
+        }
+    }
+
+    fn entry(name: &str, value: f64) -> MetricEntry {
+        MetricEntry::new(
+            name.to_string(),
+            value.to_string(),
+            NumericEntry::Value(value).serialize(),
+        )
+    }
+
+    #[test]
+    fn metrics_should_be_logged_with_the_iteration_as_step() {
+        let run = offline_run();
+        let mut logger = run.metric_logger(Split::Train);
+
+        for value in [1.0, 2.0] {
+            logger.log(&entry("Loss", value));
+            logger.log(&entry("Accuracy", value * 10.0));
+            logger.log(&entry("Ratio", f64::NAN));
+        }
+        logger.end_epoch(1);
+        logger.log(&entry("Loss", 3.0));
+
+        let state = run.state.lock().unwrap();
+        let metrics: Vec<(&str, f64, u64)> = state
+            .metrics
+            .iter()
+            .map(|metric| {
+                (
+                    metric["key"].as_str().unwrap(),
+                    metric["value"].as_f64().unwrap(),
+                    metric["step"].as_u64().unwrap(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            metrics,
+            vec![
+                ("train/Loss", 1.0, 0),
+                ("train/Accuracy", 10.0, 0),
+                ("train/Loss", 2.0, 1),
+                ("train/Accuracy", 20.0, 1),
+                ("train/Loss", 3.0, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn config_should_be_flattened_into_params() {
+        let mut params = Vec::new();
+        flatten_params(
+            "optimizer".to_string(),
+            json!({ "lr": 0.1, "name": "adam", "betas": { "beta_1": 0.9 } }),
+            &mut params,
+        );
+        params.sort();
+
+        assert_eq!(
+            params,
+            vec![
+                ("optimizer.betas.beta_1".to_string(), "0.9".to_string()),
+                ("optimizer.lr".to_string(), "0.1".to_string()),
+                ("optimizer.name".to_string(), "adam".to_string()),
+            ]
+        );
+    }
+}
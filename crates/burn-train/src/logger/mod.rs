@@ -3,9 +3,21 @@ mod base;
 mod file;
 mod in_memory;
 mod metric;
+#[cfg(feature = "mlflow")]
+mod mlflow;
+mod tensorboard;
+#[cfg(any(feature = "wandb", feature = "mlflow"))]
+mod tracking;
+#[cfg(feature = "wandb")]
+mod wandb;
 
 pub use async_logger::*;
 pub use base::*;
 pub use file::*;
 pub use in_memory::*;
 pub use metric::*;
+#[cfg(feature = "mlflow")]
+pub use mlflow::*;
+pub use tensorboard::*;
+#[cfg(feature = "wandb")]
+pub use wandb::*;
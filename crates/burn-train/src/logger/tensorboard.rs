@@ -0,0 +1,593 @@
+use super::MetricLogger;
+use crate::metric::store::Split;
+use crate::metric::{MetricEntry, NumericEntry};
+use burn_core::module::{AutodiffModule, ModuleVisitor, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::Tensor;
+use core::marker::PhantomData;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The number of buckets of the histograms.
+const NUM_BUCKETS: usize = 30;
+
+/// The minimum duration between two flushes of the event file.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Writer of [TensorBoard](https://www.tensorflow.org/tensorboard) event files, logging scalars,
+/// histograms and embedding projections in a log directory.
+///
+/// The metrics of the training are logged as scalars with [metric loggers](TensorBoardWriter::metric_logger),
+/// e.g. with [LearnerBuilder::tensorboard](crate::LearnerBuilder::tensorboard), and the
+/// histograms of the parameters and gradients with [TensorBoardHistograms].
+#[derive(Clone)]
+pub struct TensorBoardWriter {
+    state: Arc<Mutex<TensorBoardWriterState>>,
+}
+
+struct TensorBoardWriterState {
+    directory: String,
+    file: BufWriter<File>,
+    // The embeddings of the projector config.
+    embeddings: Vec<ProjectorEmbedding>,
+    last_flush: Instant,
+}
+
+impl TensorBoardWriter {
+    /// Create a writer of a new event file in the log directory.
+    pub fn new(directory: &str) -> Result<Self, std::io::Error> {
+        std::fs::create_dir_all(directory)?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let path = format!(
+            "{directory}/events.out.tfevents.{}.{}.burn",
+            timestamp.as_secs(),
+            std::process::id()
+        );
+        let mut file = BufWriter::new(File::create(path)?);
+        write_record(
+            &mut file,
+            &encode_event(timestamp.as_secs_f64(), 0, EventContent::FileVersion),
+        )?;
+        file.flush()?;
+
+        Ok(Self {
+            state: Arc::new(Mutex::new(TensorBoardWriterState {
+                directory: directory.to_string(),
+                file,
+                embeddings: Vec::new(),
+                last_flush: Instant::now(),
+            })),
+        })
+    }
+
+    /// Log the value of a scalar at the given step.
+    pub fn add_scalar(&self, tag: &str, value: f64, step: usize) {
+        let mut summary = Vec::new();
+        put_bytes(&mut summary, 1, &encode_scalar(tag, value as f32));
+
+        self.write_summary(&summary, step);
+    }
+
+    /// Log the histogram of the values at the given step, ignoring the values which aren't
+    /// finite.
+    pub fn add_histogram(&self, tag: &str, values: &[f64], step: usize) {
+        let mut summary = Vec::new();
+        put_bytes(&mut summary, 1, &encode_histogram(tag, values));
+
+        self.write_summary(&summary, step);
+    }
+
+    /// Log the embeddings, with one embedding per row, to be visualized with the projector of
+    /// TensorBoard, with an optional label per embedding.
+    ///
+    /// The embeddings are saved in tab separated files in `{directory}/{tag}/{step}`.
+    pub fn add_embedding<B: Backend>(
+        &self,
+        tag: &str,
+        embeddings: Tensor<B, 2>,
+        labels: Option<&[String]>,
+        step: usize,
+    ) -> Result<(), std::io::Error> {
+        let [num_embeddings, dim] = embeddings.dims();
+        if let Some(labels) = labels {
+            assert_eq!(
+                labels.len(),
+                num_embeddings,
+                "There must be one label per embedding"
+            );
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let relative = format!("{tag}/{step:05}");
+        let directory = format!("{}/{relative}", state.directory);
+        std::fs::create_dir_all(&directory)?;
+
+        let values: Vec<f64> = embeddings.into_data().iter::<f64>().collect();
+        let mut tensors = BufWriter::new(File::create(format!("{directory}/tensors.tsv"))?);
+        for row in values.chunks(dim.max(1)) {
+            let row: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+            writeln!(tensors, "{}", row.join("\t"))?;
+        }
+        tensors.flush()?;
+
+        if let Some(labels) = labels {
+            let mut metadata = BufWriter::new(File::create(format!("{directory}/metadata.tsv"))?);
+            for label in labels {
+                // The labels can't contain the separators of the file.
+                writeln!(metadata, "{}", label.replace(['\t', '\n'], " "))?;
+            }
+            metadata.flush()?;
+        }
+
+        let name = format!("{tag}:{step:05}");
+        state.embeddings.retain(|embedding| embedding.name != name);
+        state.embeddings.push(ProjectorEmbedding {
+            name,
+            directory: relative,
+            labels: labels.is_some(),
+        });
+        state.write_projector_config()
+    }
+
+    /// Create a metric logger of the given split, logging the numeric metrics of each iteration
+    /// as scalars prefixed by the split, e.g. `train/Loss`.
+    pub fn metric_logger(&self, split: Split) -> TensorBoardMetricLogger {
+        TensorBoardMetricLogger {
+            writer: self.clone(),
+            split,
+            names: HashSet::new(),
+            step: 0,
+        }
+    }
+
+    /// Flush the events to the event file.
+    pub fn flush(&self) -> Result<(), std::io::Error> {
+        let mut state = self.state.lock().unwrap();
+
+        state.last_flush = Instant::now();
+        state.file.flush()
+    }
+
+    fn write_summary(&self, summary: &[u8], step: usize) {
+        let wall_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs_f64();
+        let event = encode_event(wall_time, step as i64, EventContent::Summary(summary));
+        let mut state = self.state.lock().unwrap();
+
+        if let Err(err) = write_record(&mut state.file, &event) {
+            log::warn!("Could not write the TensorBoard event: {err}");
+        }
+        if state.last_flush.elapsed() >= FLUSH_INTERVAL {
+            state.last_flush = Instant::now();
+
+            if let Err(err) = state.file.flush() {
+                log::warn!("Could not flush the TensorBoard events: {err}");
+            }
+        }
+    }
+}
+
+/// An embedding of the projector config, with its directory relative to the log directory.
+struct ProjectorEmbedding {
+    name: String,
+    directory: String,
+    labels: bool,
+}
+
+impl TensorBoardWriterState {
+    fn write_projector_config(&self) -> Result<(), std::io::Error> {
+        let mut config = String::new();
+
+        for embedding in self.embeddings.iter() {
+            let directory = &embedding.directory;
+
+            config += "embeddings {\n";
+            config += &format!("  tensor_name: \"{}\"\n", embedding.name);
+            config += &format!("  tensor_path: \"{directory}/tensors.tsv\"\n");
+            if embedding.labels {
+                config += &format!("  metadata_path: \"{directory}/metadata.tsv\"\n");
+            }
+            config += "}\n";
+        }
+
+        std::fs::write(format!("{}/projector_config.pbtxt", self.directory), config)
+    }
+}
+
+/// Logging of the histograms of the parameters and gradients of the model to
+/// [TensorBoard](TensorBoardWriter), sampled every few optimizer steps.
+///
+/// The histograms are tagged with the path of the parameters in the module, e.g.
+/// `params/linear.weight` and `grads/linear.weight`, see
+/// [LearnerBuilder::tensorboard_histograms](crate::LearnerBuilder::tensorboard_histograms).
+#[derive(Clone)]
+pub struct TensorBoardHistograms {
+    writer: TensorBoardWriter,
+    every: usize,
+    params: bool,
+    grads: bool,
+    // The number of optimizer steps, shared between the epochs.
+    steps: Arc<AtomicUsize>,
+}
+
+impl TensorBoardHistograms {
+    /// Log the histograms of the parameters and gradients every `every` optimizer steps.
+    ///
+    /// # Panics
+    ///
+    /// If `every` is 0.
+    pub fn new(writer: &TensorBoardWriter, every: usize) -> Self {
+        assert!(
+            every > 0,
+            "The histograms must be logged every step or more"
+        );
+
+        Self {
+            writer: writer.clone(),
+            every,
+            params: true,
+            grads: true,
+            steps: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Whether the histograms of the parameters are logged, true by default.
+    pub fn with_params(mut self, params: bool) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Whether the histograms of the gradients are logged, true by default.
+    pub fn with_grads(mut self, grads: bool) -> Self {
+        self.grads = grads;
+        self
+    }
+
+    /// Count an optimizer step, logging the histograms of the parameters of the model and of the
+    /// gradients of the step when it is sampled.
+    pub fn step<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        model: &M,
+        grads: &GradientsParams,
+    ) {
+        let step = self.steps.fetch_add(1, Ordering::Relaxed);
+
+        if step % self.every != 0 {
+            return;
+        }
+
+        model.visit(&mut HistogramsVisitor::<B> {
+            writer: &self.writer,
+            grads: self.grads.then_some(grads),
+            params: self.params,
+            path: Vec::new(),
+            step,
+            _backend: PhantomData,
+        });
+
+        if let Err(err) = self.writer.flush() {
+            log::warn!("Could not flush the TensorBoard events: {err}");
+        }
+    }
+}
+
+struct HistogramsVisitor<'a, B: AutodiffBackend> {
+    writer: &'a TensorBoardWriter,
+    grads: Option<&'a GradientsParams>,
+    params: bool,
+    path: Vec<String>,
+    step: usize,
+    _backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for HistogramsVisitor<'_, B> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let path = self.path.join(".");
+
+        if self.params {
+            let values: Vec<f64> = tensor.to_data().iter::<f64>().collect();
+            self.writer
+                .add_histogram(&format!("params/{path}"), &values, self.step);
+        }
+        if let Some(grad) = self
+            .grads
+            .and_then(|grads| grads.get::<B::InnerBackend, D>(id))
+        {
+            let values: Vec<f64> = grad.into_data().iter::<f64>().collect();
+            self.writer
+                .add_histogram(&format!("grads/{path}"), &values, self.step);
+        }
+    }
+}
+
+/// Metric logger writing the numeric metrics of a split as scalars to
+/// [TensorBoard](TensorBoardWriter).
+///
+/// The step of the scalars is the number of iterations of the split since the start of the
+/// training.
+///
+/// The metrics can't be read back, so another logger, e.g. the default
+/// [file logger](crate::logger::FileMetricLogger), must be registered for the metrics to be
+/// aggregated, as done by [LearnerBuilder::tensorboard](crate::LearnerBuilder::tensorboard).
+pub struct TensorBoardMetricLogger {
+    writer: TensorBoardWriter,
+    split: Split,
+    // The metrics logged during the current iteration.
+    names: HashSet<String>,
+    step: usize,
+}
+
+impl MetricLogger for TensorBoardMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        let value = match NumericEntry::deserialize(&item.serialize) {
+            Ok(NumericEntry::Value(value)) => value,
+            Ok(NumericEntry::Aggregated(value, _)) => value,
+            Err(_) => return,
+        };
+        let prefix = match self.split {
+            Split::Train => "train",
+            Split::Valid => "valid",
+        };
+        let tag = format!("{prefix}/{}", item.name);
+
+        // Each metric is logged once per iteration.
+        if !self.names.insert(tag.clone()) {
+            self.names.clear();
+            self.names.insert(tag.clone());
+            self.step += 1;
+        }
+        self.writer.add_scalar(&tag, value, self.step);
+    }
+
+    fn end_epoch(&mut self, _epoch: usize) {
+        if !self.names.is_empty() {
+            self.names.clear();
+            self.step += 1;
+        }
+        if let Err(err) = self.writer.flush() {
+            log::warn!("Could not flush the TensorBoard events: {err}");
+        }
+    }
+
+    fn read_numeric(&mut self, _name: &str, _epoch: usize) -> Result<Vec<NumericEntry>, String> {
+        Err("The TensorBoard metric logger can't read the metrics.".to_string())
+    }
+}
+
+enum EventContent<'a> {
+    FileVersion,
+    Summary(&'a [u8]),
+}
+
+/// Encode an `Event` protobuf message.
+fn encode_event(wall_time: f64, step: i64, content: EventContent) -> Vec<u8> {
+    let mut event = Vec::new();
+
+    put_double(&mut event, 1, wall_time);
+    put_varint_field(&mut event, 2, step as u64);
+    match content {
+        EventContent::FileVersion => put_bytes(&mut event, 3, b"brain.Event:2"),
+        EventContent::Summary(summary) => put_bytes(&mut event, 5, summary),
+    }
+
+    event
+}
+
+/// Encode a `Summary.Value` protobuf message of a scalar.
+fn encode_scalar(tag: &str, value: f32) -> Vec<u8> {
+    let mut summary_value = Vec::new();
+
+    put_bytes(&mut summary_value, 1, tag.as_bytes());
+    put_key(&mut summary_value, 2, 5);
+    summary_value.extend_from_slice(&value.to_le_bytes());
+
+    summary_value
+}
+
+/// Encode a `Summary.Value` protobuf message of the histogram of the values.
+fn encode_histogram(tag: &str, values: &[f64]) -> Vec<u8> {
+    let values: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let (limits, counts) = histogram_buckets(&values, min, max);
+
+    let mut histogram = Vec::new();
+    if !values.is_empty() {
+        put_double(&mut histogram, 1, min);
+        put_double(&mut histogram, 2, max);
+    }
+    put_double(&mut histogram, 3, values.len() as f64);
+    put_double(&mut histogram, 4, values.iter().sum());
+    put_double(&mut histogram, 5, values.iter().map(|v| v * v).sum());
+    put_packed_doubles(&mut histogram, 6, &limits);
+    put_packed_doubles(&mut histogram, 7, &counts);
+
+    let mut summary_value = Vec::new();
+    put_bytes(&mut summary_value, 1, tag.as_bytes());
+    put_bytes(&mut summary_value, 5, &histogram);
+
+    summary_value
+}
+
+/// The right edges and the counts of the buckets of equal width between the min and the max.
+fn histogram_buckets(values: &[f64], min: f64, max: f64) -> (Vec<f64>, Vec<f64>) {
+    if values.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+    if min == max {
+        return (vec![max], vec![values.len() as f64]);
+    }
+
+    let width = (max - min) / NUM_BUCKETS as f64;
+    let limits = (1..=NUM_BUCKETS)
+        .map(|i| match i {
+            NUM_BUCKETS => max,
+            i => min + width * i as f64,
+        })
+        .collect();
+    let mut counts = vec![0.0; NUM_BUCKETS];
+    for value in values {
+        let index = ((value - min) / width) as usize;
+        counts[index.min(NUM_BUCKETS - 1)] += 1.0;
+    }
+
+    (limits, counts)
+}
+
+fn put_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn put_key(buffer: &mut Vec<u8>, field: u32, wire_type: u8) {
+    put_varint(buffer, ((field as u64) << 3) | wire_type as u64);
+}
+
+fn put_varint_field(buffer: &mut Vec<u8>, field: u32, value: u64) {
+    put_key(buffer, field, 0);
+    put_varint(buffer, value);
+}
+
+fn put_double(buffer: &mut Vec<u8>, field: u32, value: f64) {
+    put_key(buffer, field, 1);
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_bytes(buffer: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    put_key(buffer, field, 2);
+    put_varint(buffer, bytes.len() as u64);
+    buffer.extend_from_slice(bytes);
+}
+
+fn put_packed_doubles(buffer: &mut Vec<u8>, field: u32, values: &[f64]) {
+    put_key(buffer, field, 2);
+    put_varint(buffer, (values.len() * 8) as u64);
+    for value in values {
+        buffer.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Write a record of the TFRecord format: the length of the data, its checksum, the data and its
+/// checksum.
+fn write_record<W: Write>(writer: &mut W, data: &[u8]) -> Result<(), std::io::Error> {
+    let length = (data.len() as u64).to_le_bytes();
+
+    writer.write_all(&length)?;
+    writer.write_all(&masked_crc32c(&length).to_le_bytes())?;
+    writer.write_all(data)?;
+    writer.write_all(&masked_crc32c(data).to_le_bytes())
+}
+
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let crc = crc32c(data);
+
+    crc.rotate_right(15).wrapping_add(0xa282_ead8)
+}
+
+/// The CRC-32C (Castagnoli) checksum of the data.
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0x82f6_3b78,
+                _ => crc >> 1,
+            };
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use tempfile::TempDir;
+
+    #[test]
+    fn crc32c_should_match_the_check_value() {
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+    }
+
+    #[test]
+    fn histogram_buckets_should_count_the_values() {
+        let values: Vec<f64> = (0..60).map(|i| i as f64 / 2.0).collect();
+        let (limits, counts) = histogram_buckets(&values, 0.0, 29.5);
+
+        assert_eq!(limits.len(), NUM_BUCKETS);
+        assert_eq!(limits[NUM_BUCKETS - 1], 29.5);
+        assert_eq!(counts.iter().sum::<f64>(), 60.0);
+        assert_eq!(counts[0], 2.0);
+        assert_eq!(
+            histogram_buckets(&[1.0, 1.0], 1.0, 1.0),
+            (vec![1.0], vec![2.0])
+        );
+    }
+
+    #[test]
+    fn writer_should_write_records_and_projector_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let directory = temp_dir.path().to_str().unwrap();
+        let writer = TensorBoardWriter::new(directory).unwrap();
+
+        writer.add_scalar("train/Loss", 0.5, 1);
+        writer.add_histogram("params/weight", &[0.0, 1.0, f64::NAN], 1);
+        let embeddings =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &Default::default());
+        let labels = ["a".to_string(), "b".to_string()];
+        writer
+            .add_embedding("embedding", embeddings, Some(&labels), 3)
+            .unwrap();
+        writer.flush().unwrap();
+
+        let file = std::fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.to_str().unwrap().contains("tfevents"))
+            .unwrap();
+        let bytes = std::fs::read(file).unwrap();
+        let mut num_records = 0;
+        let mut rest = bytes.as_slice();
+        while !rest.is_empty() {
+            let length = u64::from_le_bytes(rest[..8].try_into().unwrap()) as usize;
+            let data = &rest[12..12 + length];
+            let crc = u32::from_le_bytes(rest[12 + length..16 + length].try_into().unwrap());
+            assert_eq!(crc, masked_crc32c(data));
+            rest = &rest[16 + length..];
+            num_records += 1;
+        }
+        assert_eq!(num_records, 3);
+
+        let tensors =
+            std::fs::read_to_string(format!("{directory}/embedding/00003/tensors.tsv")).unwrap();
+        assert_eq!(tensors, "1\t2\n3\t4\n");
+        let config =
+            std::fs::read_to_string(format!("{directory}/projector_config.pbtxt")).unwrap();
+        assert!(config.contains("tensor_name: \"embedding:00003\""));
+        assert!(config.contains("metadata_path: \"embedding/00003/metadata.tsv\""));
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::HashSet;
+
+/// The files of the checkpoint directory which aren't uploaded yet, of the epochs before the given
+/// one if any, sorted by name.
+pub(crate) fn new_checkpoints(
+    directory: &str,
+    uploaded: &HashSet<String>,
+    before_epoch: Option<usize>,
+) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|file| !uploaded.contains(file))
+        .filter(|file| match before_epoch {
+            Some(before_epoch) => checkpoint_epoch(file).is_some_and(|e| e < before_epoch),
+            None => true,
+        })
+        .collect();
+    files.sort();
+
+    files
+}
+
+/// The epoch of a checkpoint file, named `{name}-{epoch}.{extension}`.
+fn checkpoint_epoch(file: &str) -> Option<usize> {
+    let stem = file.split('.').next()?;
+
+    stem.rsplit('-').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkpoint_epoch_should_be_parsed_from_the_file_name() {
+        assert_eq!(checkpoint_epoch("model-12.mpk"), Some(12));
+        assert_eq!(checkpoint_epoch("optim-3.bin.gz"), Some(3));
+        assert_eq!(checkpoint_epoch("experiment.log"), None);
+    }
+}
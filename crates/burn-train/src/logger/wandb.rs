@@ -0,0 +1,554 @@
+use super::tracking::new_checkpoints;
+use super::MetricLogger;
+use crate::metric::store::Split;
+use crate::metric::{MetricEntry, NumericEntry};
+use reqwest::blocking::Client;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const DEFAULT_BASE_URL: &str = "https://api.wandb.ai";
+const HISTORY_FILE: &str = "wandb-history.jsonl";
+const SUMMARY_FILE: &str = "wandb-summary.json";
+
+/// The minimum duration between two requests streaming the logged metrics.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The error type of the [Weights & Biases](WandbRun) client.
+#[derive(Debug)]
+pub enum WandbError {
+    /// HTTP error.
+    HttpError(reqwest::Error),
+
+    /// Error returned by the API.
+    ApiError(String),
+
+    /// IO error.
+    IOError(std::io::Error),
+}
+
+impl core::fmt::Display for WandbError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::HttpError(err) => write!(f, "HTTP error: {err}"),
+            Self::ApiError(err) => write!(f, "Weights & Biases API error: {err}"),
+            Self::IOError(err) => write!(f, "IO error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for WandbError {}
+
+impl From<reqwest::Error> for WandbError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::HttpError(err)
+    }
+}
+
+/// Configuration to create a [Weights & Biases run](WandbRun) using the
+/// [init function](WandbRunConfig::init).
+#[derive(Clone, Debug)]
+pub struct WandbRunConfig {
+    project: String,
+    entity: Option<String>,
+    name: Option<String>,
+    api_key: Option<String>,
+    base_url: Option<String>,
+}
+
+impl WandbRunConfig {
+    /// Create the configuration of a run of the given project.
+    pub fn new(project: &str) -> Self {
+        Self {
+            project: project.to_string(),
+            entity: None,
+            name: None,
+            api_key: None,
+            base_url: None,
+        }
+    }
+
+    /// The user or team owning the project, the default entity of the user by default.
+    pub fn with_entity(mut self, entity: &str) -> Self {
+        self.entity = Some(entity.to_string());
+        self
+    }
+
+    /// The display name of the run, generated by Weights & Biases by default.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// The API key, read from the `WANDB_API_KEY` environment variable by default.
+    pub fn with_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+
+    /// The URL of the server, read from the `WANDB_BASE_URL` environment variable by default,
+    /// e.g. for a self-hosted server.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    /// Create the run.
+    pub fn init(&self) -> Result<WandbRun, WandbError> {
+        let api_key = match &self.api_key {
+            Some(api_key) => api_key.clone(),
+            None => std::env::var("WANDB_API_KEY").map_err(|_| {
+                WandbError::ApiError("No API key, set the WANDB_API_KEY variable".to_string())
+            })?,
+        };
+        let base_url = self
+            .base_url
+            .clone()
+            .or_else(|| std::env::var("WANDB_BASE_URL").ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+
+        let mut state = WandbRunState::new(
+            Some(Client::builder().timeout(Duration::from_secs(60)).build()?),
+            base_url.trim_end_matches('/').to_string(),
+            api_key,
+            self.entity.clone().unwrap_or_default(),
+            self.project.clone(),
+        );
+
+        if state.entity.is_empty() {
+            let response = state.graphql("query Viewer { viewer { entity } }", json!({}))?;
+            state.entity = response["viewer"]["entity"]
+                .as_str()
+                .ok_or_else(|| WandbError::ApiError("No default entity".to_string()))?
+                .to_string();
+        }
+        state.upsert_run(self.name.as_deref())?;
+
+        Ok(WandbRun {
+            state: Arc::new(Mutex::new(state)),
+        })
+    }
+}
+
+/// A [Weights & Biases](https://wandb.ai) run, to which the metrics of the training are logged
+/// with [metric loggers](WandbRun::metric_logger), e.g. with
+/// [LearnerBuilder::wandb](crate::LearnerBuilder::wandb).
+///
+/// The metrics are streamed in the history of the run at most every few seconds, the run should
+/// be [finished](WandbRun::finish) at the end of the training to send the remaining ones.
+#[derive(Clone)]
+pub struct WandbRun {
+    state: Arc<Mutex<WandbRunState>>,
+}
+
+impl WandbRun {
+    /// The id of the run.
+    pub fn id(&self) -> String {
+        self.state.lock().unwrap().run_id.clone()
+    }
+
+    /// Add a configuration to the config of the run, e.g. the [config](burn_core::config::Config)
+    /// of the model or of the training.
+    pub fn log_config<C: Serialize>(&self, name: &str, config: &C) -> Result<(), WandbError> {
+        let value = serde_json::to_value(config)
+            .map_err(|err| WandbError::ApiError(format!("Can't serialize config: {err}")))?;
+
+        let mut state = self.state.lock().unwrap();
+        state
+            .config
+            .insert(name.to_string(), json!({ "value": value }));
+        state.upsert_run(None)
+    }
+
+    /// Create a metric logger of the given split, logging the numeric metrics of each iteration
+    /// in a row of the history of the run, prefixed by the split, e.g. `train/Loss`.
+    pub fn metric_logger(&self, split: Split) -> WandbMetricLogger {
+        WandbMetricLogger {
+            run: self.clone(),
+            prefix: match split {
+                Split::Train => "train",
+                Split::Valid => "valid",
+            },
+            row: Map::new(),
+            epoch: 1,
+        }
+    }
+
+    /// Upload a file to the files of the run, under the given name.
+    pub fn upload_file(&self, path: &str, name: &str) -> Result<(), WandbError> {
+        self.state.lock().unwrap().upload_file(path, name)
+    }
+
+    /// Upload the files saved in the checkpoint directory to the files of the run, under
+    /// `checkpoint/`.
+    ///
+    /// The checkpoints of an epoch are uploaded once the training of the next epoch ends, and
+    /// the remaining ones when the run is [finished](WandbRun::finish).
+    pub fn upload_checkpoints(&self, directory: &str) {
+        self.state.lock().unwrap().checkpoint_directory = Some(directory.to_string());
+    }
+
+    /// Finish the run, sending the remaining metrics and checkpoints.
+    pub fn finish(self) -> Result<(), WandbError> {
+        let mut state = self.state.lock().unwrap();
+
+        state.upload_checkpoints(None);
+        state.flush()?;
+        state.file_stream(json!({ "complete": true, "exitcode": 0 }))
+    }
+
+    fn log_row(&self, row: Map<String, Value>, epoch: usize) {
+        self.state.lock().unwrap().log_row(row, epoch);
+    }
+
+    fn end_epoch(&self, split: Split, epoch: usize) {
+        let mut state = self.state.lock().unwrap();
+
+        // The checkpoints of the previous epochs are saved by now.
+        if let Split::Train = split {
+            state.upload_checkpoints(Some(epoch));
+        }
+        if let Err(err) = state.flush() {
+            log::warn!("Could not send the metrics to Weights & Biases: {err}");
+        }
+    }
+}
+
+struct WandbRunState {
+    client: Option<Client>,
+    base_url: String,
+    api_key: String,
+    entity: String,
+    project: String,
+    run_id: String,
+    config: Map<String, Value>,
+    step: usize,
+    rows: Vec<String>,
+    history_offset: usize,
+    summary: Map<String, Value>,
+    start: Instant,
+    last_flush: Instant,
+    checkpoint_directory: Option<String>,
+    uploaded: HashSet<String>,
+}
+
+impl WandbRunState {
+    /// Create the state of a run, without client when testing.
+    fn new(
+        client: Option<Client>,
+        base_url: String,
+        api_key: String,
+        entity: String,
+        project: String,
+    ) -> Self {
+        Self {
+            client,
+            base_url,
+            api_key,
+            entity,
+            project,
+            run_id: generate_run_id(),
+            config: Map::new(),
+            step: 0,
+            rows: Vec::new(),
+            history_offset: 0,
+            summary: Map::new(),
+            start: Instant::now(),
+            last_flush: Instant::now(),
+            checkpoint_directory: None,
+            uploaded: HashSet::new(),
+        }
+    }
+
+    fn client(&self) -> Result<&Client, WandbError> {
+        self.client
+            .as_ref()
+            .ok_or_else(|| WandbError::ApiError("No client".to_string()))
+    }
+
+    fn graphql(&self, query: &str, variables: Value) -> Result<Value, WandbError> {
+        let mut response: Value = self
+            .client()?
+            .post(format!("{}/graphql", self.base_url))
+            .basic_auth("api", Some(&self.api_key))
+            .json(&json!({ "query": query, "variables": variables }))
+            .send()?
+            .error_for_status()?
+            .json()?;
+
+        if let Some(errors) = response.get("errors") {
+            return Err(WandbError::ApiError(errors.to_string()));
+        }
+
+        Ok(response["data"].take())
+    }
+
+    fn upsert_run(&self, display_name: Option<&str>) -> Result<(), WandbError> {
+        self.graphql(
+            "mutation UpsertBucket($name: String, $project: String, $entity: String, \
+             $config: JSONString, $displayName: String) { \
+             upsertBucket(input: {name: $name, modelName: $project, entityName: $entity, \
+             config: $config, displayName: $displayName}) { bucket { id } } }",
+            json!({
+                "name": self.run_id,
+                "project": self.project,
+                "entity": self.entity,
+                "config": Value::Object(self.config.clone()).to_string(),
+                "displayName": display_name,
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn file_stream(&self, body: Value) -> Result<(), WandbError> {
+        self.client()?
+            .post(format!(
+                "{}/files/{}/{}/{}/file_stream",
+                self.base_url, self.entity, self.project, self.run_id
+            ))
+            .basic_auth("api", Some(&self.api_key))
+            .json(&body)
+            .send()?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    fn log_row(&mut self, mut row: Map<String, Value>, epoch: usize) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        for (name, value) in row.iter() {
+            self.summary.insert(name.clone(), value.clone());
+        }
+        row.insert("epoch".to_string(), json!(epoch));
+        row.insert("_step".to_string(), json!(self.step));
+        row.insert(
+            "_runtime".to_string(),
+            json!(self.start.elapsed().as_secs_f64()),
+        );
+        row.insert("_timestamp".to_string(), json!(timestamp.as_secs_f64()));
+        self.step += 1;
+        self.rows.push(Value::Object(row).to_string());
+
+        if self.last_flush.elapsed() >= FLUSH_INTERVAL {
+            if let Err(err) = self.flush() {
+                log::warn!("Could not send the metrics to Weights & Biases: {err}");
+            }
+        }
+    }
+
+    /// Send the rows logged since the last flush, which are kept to be sent again on failure.
+    fn flush(&mut self) -> Result<(), WandbError> {
+        self.last_flush = Instant::now();
+
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+
+        self.file_stream(json!({
+            "files": {
+                HISTORY_FILE: { "offset": self.history_offset, "content": self.rows },
+                SUMMARY_FILE: {
+                    "offset": 0,
+                    "content": [Value::Object(self.summary.clone()).to_string()],
+                },
+            }
+        }))?;
+        self.history_offset += self.rows.len();
+        self.rows.clear();
+
+        Ok(())
+    }
+
+    fn upload_file(&mut self, path: &str, name: &str) -> Result<(), WandbError> {
+        let response = self.graphql(
+            "mutation CreateRunFiles($entity: String!, $project: String!, $run: String!, \
+             $files: [String!]!) { createRunFiles(input: {entityName: $entity, \
+             projectName: $project, runName: $run, files: $files}) { \
+             uploadHeaders files { name uploadUrl } } }",
+            json!({
+                "entity": self.entity,
+                "project": self.project,
+                "run": self.run_id,
+                "files": [name],
+            }),
+        )?;
+        let upload_url = response["createRunFiles"]["files"][0]["uploadUrl"]
+            .as_str()
+            .ok_or_else(|| WandbError::ApiError(format!("No upload URL for {name}")))?;
+
+        let mut request = self
+            .client()?
+            .put(upload_url)
+            .body(std::fs::read(path).map_err(WandbError::IOError)?);
+        for header in response["createRunFiles"]["uploadHeaders"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|header| header.as_str()?.split_once(':'))
+        {
+            request = request.header(header.0, header.1);
+        }
+        request.send()?.error_for_status()?;
+
+        self.file_stream(json!({ "uploaded": [name] }))
+    }
+
+    /// Upload the checkpoints not uploaded yet, of the epochs before the given one if any.
+    fn upload_checkpoints(&mut self, before_epoch: Option<usize>) {
+        let Some(directory) = self.checkpoint_directory.clone() else {
+            return;
+        };
+        let files = new_checkpoints(&directory, &self.uploaded, before_epoch);
+
+        for file in files {
+            let path = format!("{directory}/{file}");
+
+            match self.upload_file(&path, &format!("checkpoint/{file}")) {
+                Ok(()) => {
+                    self.uploaded.insert(file);
+                }
+                Err(err) => log::warn!("Could not upload the checkpoint {path}: {err}"),
+            }
+        }
+    }
+}
+
+/// A random run id of 8 lowercase alphanumeric characters.
+fn generate_run_id() -> String {
+    const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let mut seed = nanos ^ (std::process::id() as u128) << 64;
+
+    (0..8)
+        .map(|_| {
+            let char = CHARS[(seed % CHARS.len() as u128) as usize] as char;
+            seed /= CHARS.len() as u128;
+            char
+        })
+        .collect()
+}
+
+/// Metric logger sending the numeric metrics of a split to a [Weights & Biases run](WandbRun).
+///
+/// The metrics can't be read back, so another logger, e.g. the default
+/// [file logger](crate::logger::FileMetricLogger), must be registered for the metrics to be
+/// aggregated, as done by [LearnerBuilder::wandb](crate::LearnerBuilder::wandb).
+pub struct WandbMetricLogger {
+    run: WandbRun,
+    prefix: &'static str,
+    // The metrics of the current iteration.
+    row: Map<String, Value>,
+    epoch: usize,
+}
+
+impl WandbMetricLogger {
+    fn log_row(&mut self) {
+        if !self.row.is_empty() {
+            self.run.log_row(core::mem::take(&mut self.row), self.epoch);
+        }
+    }
+}
+
+impl MetricLogger for WandbMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        let value = match NumericEntry::deserialize(&item.serialize) {
+            Ok(NumericEntry::Value(value)) => value,
+            Ok(NumericEntry::Aggregated(value, _)) => value,
+            Err(_) => return,
+        };
+        let name = format!("{}/{}", self.prefix, item.name);
+
+        // Each metric is logged once per iteration.
+        if self.row.contains_key(&name) {
+            self.log_row();
+        }
+        self.row.insert(name, json!(value));
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        self.log_row();
+        self.run.end_epoch(
+            match self.prefix {
+                "train" => Split::Train,
+                _ => Split::Valid,
+            },
+            epoch,
+        );
+        self.epoch = epoch + 1;
+    }
+
+    fn read_numeric(&mut self, _name: &str, _epoch: usize) -> Result<Vec<NumericEntry>, String> {
+        Err("The Weights & Biases metric logger can't read the metrics.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offline_run() -> WandbRun {
+        WandbRun {
+            state: Arc::new(Mutex::new(WandbRunState::new(
+                None,
+                DEFAULT_BASE_URL.to_string(),
+                String::new(),
+                "entity".to_string(),
+                "project".to_string(),
+            ))),
+        }
+    }
+
+    fn entry(name: &str, value: f64) -> MetricEntry {
+        MetricEntry::new(
+            name.to_string(),
+            value.to_string(),
+            NumericEntry::Value(value).serialize(),
+        )
+    }
+
+    #[test]
+    fn metrics_of_an_iteration_should_be_logged_in_the_same_row() {
+        let run = offline_run();
+        let mut logger_train = run.metric_logger(Split::Train);
+        let mut logger_valid = run.metric_logger(Split::Valid);
+
+        for value in [1.0, 2.0] {
+            logger_train.log(&entry("Loss", value));
+            logger_train.log(&entry("Accuracy", value * 10.0));
+            logger_train.log(&MetricEntry::new(
+                "Name".to_string(),
+                "Not numeric".to_string(),
+                "Not numeric".to_string(),
+            ));
+        }
+        logger_train.log_row();
+        logger_valid.log(&entry("Loss", 3.0));
+        logger_valid.log_row();
+
+        let state = run.state.lock().unwrap();
+        let rows: Vec<Value> = state
+            .rows
+            .iter()
+            .map(|row| serde_json::from_str(row).unwrap())
+            .collect();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0]["train/Loss"], json!(1.0));
+        assert_eq!(rows[0]["train/Accuracy"], json!(10.0));
+        assert_eq!(rows[1]["train/Loss"], json!(2.0));
+        assert_eq!(rows[2]["valid/Loss"], json!(3.0));
+        assert_eq!(rows[2]["_step"], json!(2));
+        assert_eq!(rows[2]["epoch"], json!(1));
+        assert_eq!(state.summary["train/Accuracy"], json!(20.0));
+    }
+}
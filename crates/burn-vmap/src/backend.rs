@@ -0,0 +1,127 @@
+use crate::{ops::RANK, VmapBridge};
+use alloc::{format, string::String};
+use burn_common::sync_type::SyncType;
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    ops::{BoolTensor, FloatTensor, IntTensor},
+};
+use core::marker::PhantomData;
+
+/// Map operations over a leading batch dimension.
+///
+/// This works as a backend decorator: each tensor of rank `D` is stored as a tensor of the
+/// inner backend of rank [RANK](crate::ops::RANK), whose first dimension is the batch and whose
+/// last `D` dimensions are the logical shape. Tensors created inside a mapped function, as well
+/// as [shared](crate::shared) ones, have a batch of size one and are broadcasted to the other
+/// samples.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Vmap<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for Vmap<B> {
+    type Device = B::Device;
+
+    type FullPrecisionBridge = VmapBridge<B::FullPrecisionBridge>;
+
+    type FloatTensorPrimitive<const D: usize> = B::FloatTensorPrimitive<RANK>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = B::IntTensorPrimitive<RANK>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<RANK>;
+
+    fn ad_enabled() -> bool {
+        B::ad_enabled()
+    }
+
+    fn name() -> String {
+        format!("vmap<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn sync(device: &B::Device, sync_type: SyncType) {
+        B::sync(device, sync_type)
+    }
+}
+
+impl<B: AutodiffBackend> AutodiffBackend for Vmap<B> {
+    type InnerBackend = Vmap<B::InnerBackend>;
+    type Gradients = B::Gradients;
+
+    fn backward<const D: usize>(tensor: FloatTensor<Self, D>) -> Self::Gradients {
+        B::backward(tensor)
+    }
+
+    fn backward_with_hook<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        hook: &mut dyn FnMut(&Self::Gradients),
+    ) -> Self::Gradients {
+        B::backward_with_hook(tensor, hook)
+    }
+
+    fn grad_complete<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+        grads: &Self::Gradients,
+    ) -> bool {
+        B::grad_complete(tensor, grads)
+    }
+
+    fn grad<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+        grads: &Self::Gradients,
+    ) -> Option<FloatTensor<Self::InnerBackend, D>> {
+        B::grad(tensor, grads)
+    }
+
+    fn grad_remove<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+        grads: &mut Self::Gradients,
+    ) -> Option<FloatTensor<Self::InnerBackend, D>> {
+        B::grad_remove(tensor, grads)
+    }
+
+    fn grad_replace<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+        grads: &mut Self::Gradients,
+        grad: FloatTensor<Self::InnerBackend, D>,
+    ) {
+        B::grad_replace(tensor, grads, grad)
+    }
+
+    fn inner<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self::InnerBackend, D> {
+        B::inner(tensor)
+    }
+
+    fn int_inner<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self::InnerBackend, D> {
+        B::int_inner(tensor)
+    }
+
+    fn bool_inner<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self::InnerBackend, D> {
+        B::bool_inner(tensor)
+    }
+
+    fn from_inner<const D: usize>(
+        tensor: FloatTensor<Self::InnerBackend, D>,
+    ) -> FloatTensor<Self, D> {
+        B::from_inner(tensor)
+    }
+
+    fn int_from_inner<const D: usize>(
+        tensor: IntTensor<Self::InnerBackend, D>,
+    ) -> IntTensor<Self, D> {
+        B::int_from_inner(tensor)
+    }
+
+    fn bool_from_inner<const D: usize>(
+        tensor: BoolTensor<Self::InnerBackend, D>,
+    ) -> BoolTensor<Self, D> {
+        B::bool_from_inner(tensor)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{ops::RANK, Vmap};
+use burn_tensor::{
+    backend::{Backend, BackendBridge},
+    ops::FloatTensor,
+    Device,
+};
+use core::marker::PhantomData;
+
+/// Map operations over a batch dimension on a [backend bridge](BackendBridge).
+#[derive(Debug)]
+pub struct VmapBridge<Bridge> {
+    _p: PhantomData<Bridge>,
+}
+
+impl<B, Bridge> BackendBridge<Vmap<B>> for VmapBridge<Bridge>
+where
+    B: Backend,
+    Bridge: BackendBridge<B> + 'static,
+{
+    type Target = Vmap<Bridge::Target>;
+
+    fn into_target<const D: usize>(
+        tensor: FloatTensor<Vmap<B>, D>,
+        device: Option<Device<Self::Target>>,
+    ) -> FloatTensor<Self::Target, D> {
+        Bridge::into_target::<RANK>(tensor, device)
+    }
+
+    fn from_target<const D: usize>(
+        tensor: FloatTensor<Self::Target, D>,
+        device: Option<Device<Vmap<B>>>,
+    ) -> FloatTensor<Vmap<B>, D> {
+        Bridge::from_target::<RANK>(tensor, device)
+    }
+}
@@ -0,0 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+//! # Burn Vmap
+//!
+//! This library provides a vectorizing map for the Burn project. It lifts functions written
+//! for unbatched tensors into functions mapping over a leading batch dimension.
+//!
+//! The batching is done by the [Vmap] backend decorator: every tensor of the decorated backend
+//! carries a hidden batch dimension, and each operation rewrites its shapes and dimensions so
+//! that it is applied to all the samples at once. Since the operations are forwarded to the
+//! inner backend, it works with any backend, including [autodiff](burn_tensor::backend::AutodiffBackend)
+//! ones.
+
+extern crate alloc;
+
+/// Operation module.
+pub mod ops;
+
+mod backend;
+mod bridge;
+mod transform;
+
+pub use backend::*;
+pub use bridge::*;
+pub use transform::*;
+
+#[cfg(test)]
+mod tests;
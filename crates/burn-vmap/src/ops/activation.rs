@@ -0,0 +1,66 @@
+use super::base::RANK;
+use crate::Vmap;
+use burn_tensor::{
+    backend::Backend,
+    ops::{ActivationOps, FloatElem, FloatTensor},
+};
+
+impl<B: Backend> ActivationOps<Self> for Vmap<B> {
+    fn leaky_relu<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        negative_slope: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::leaky_relu(tensor, negative_slope)
+    }
+
+    fn relu<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::relu(tensor)
+    }
+
+    fn relu_backward<const D: usize>(
+        output: FloatTensor<B, RANK>,
+        grad: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::relu_backward(output, grad)
+    }
+
+    fn gelu<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::gelu(tensor)
+    }
+
+    fn prelu<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        alpha: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::prelu(tensor, alpha)
+    }
+
+    fn gelu_backward<const D: usize>(
+        x: FloatTensor<B, RANK>,
+        grad: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::gelu_backward(x, grad)
+    }
+
+    fn sigmoid<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::sigmoid(tensor)
+    }
+
+    fn sigmoid_backward<const D: usize>(
+        output: FloatTensor<B, RANK>,
+        grad: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::sigmoid_backward(output, grad)
+    }
+
+    fn log_sigmoid<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::log_sigmoid(tensor)
+    }
+
+    fn log_sigmoid_backward<const D: usize>(
+        x: FloatTensor<B, RANK>,
+        grad: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::log_sigmoid_backward(x, grad)
+    }
+}
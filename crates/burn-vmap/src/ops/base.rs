@@ -0,0 +1,198 @@
+use alloc::{vec, vec::Vec};
+use burn_tensor::{backend::Backend, ops::IntTensor, BasicOps, Shape, TensorData};
+use core::ops::Range;
+
+/// Rank of the tensors of the inner backend.
+///
+/// The first dimension holds the batch, and a tensor of rank `D` is stored in the last `D`
+/// dimensions, the ones in between having a size of one. Tensors of rank up to `RANK - 1` can
+/// therefore be mapped.
+pub const RANK: usize = 6;
+
+/// Returns the number of dimensions preceding the logical dimensions of a tensor of rank `D`.
+pub(crate) fn offset<const D: usize>() -> usize {
+    assert!(
+        D < RANK,
+        "Can't map over tensors of rank {D}, the maximum supported rank is {}.",
+        RANK - 1
+    );
+
+    RANK - D
+}
+
+/// Returns the dimension of the inner tensor holding the given dimension of a tensor of rank `D`.
+pub(crate) fn dim<const D: usize>(dim: usize) -> usize {
+    offset::<D>() + dim
+}
+
+/// Returns the dimensions of the inner tensor holding the given dimensions of a tensor of rank `D`.
+pub(crate) fn dims<const D: usize>(dims: &[usize]) -> Vec<usize> {
+    dims.iter().map(|value| dim::<D>(*value)).collect()
+}
+
+/// Returns the inner shape of a batch of tensors with the given shape.
+pub(crate) fn batch_shape<const D: usize>(batch: usize, shape: Shape<D>) -> Shape<RANK> {
+    let mut dims = [1; RANK];
+    dims[0] = batch;
+    dims[offset::<D>()..].copy_from_slice(&shape.dims);
+
+    Shape::new(dims)
+}
+
+/// Returns the shape of the tensors of a batch from its inner shape.
+pub(crate) fn sample_shape<const D: usize>(shape: Shape<RANK>) -> Shape<D> {
+    let mut dims = [0; D];
+    dims.copy_from_slice(&shape.dims[offset::<D>()..]);
+
+    Shape::new(dims)
+}
+
+/// Returns the number of samples of an inner tensor.
+pub(crate) fn batch_size<B: Backend, K: BasicOps<B>>(tensor: &K::Primitive<RANK>) -> usize {
+    K::shape(tensor).dims[0]
+}
+
+/// Broadcasts an inner tensor with a single sample to the given batch size.
+pub(crate) fn expand_batch<B: Backend, K: BasicOps<B>>(
+    tensor: K::Primitive<RANK>,
+    batch: usize,
+) -> K::Primitive<RANK> {
+    let mut shape = K::shape(&tensor);
+
+    if shape.dims[0] == batch {
+        return tensor;
+    }
+
+    assert_eq!(
+        shape.dims[0], 1,
+        "Can't map over batches of different sizes: {} != {batch}.",
+        shape.dims[0]
+    );
+    shape.dims[0] = batch;
+
+    K::expand(tensor, shape)
+}
+
+/// Reshapes an inner tensor holding a tensor of rank `D` so that all its values are in the
+/// last dimension, which is how full reductions are mapped.
+pub(crate) fn flatten<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: K::Primitive<RANK>,
+) -> K::Primitive<RANK> {
+    let shape = K::shape(&tensor);
+    let batch = shape.dims[0];
+    let num_elements = shape.num_elements() / batch;
+
+    K::reshape(tensor, batch_shape(batch, Shape::new([num_elements])))
+}
+
+/// Merges the batch into the first dimension of a tensor of rank `D`, which is how operations
+/// already working on batches are mapped.
+pub(crate) fn fold<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: K::Primitive<RANK>,
+) -> K::Primitive<D> {
+    let shape = K::shape(&tensor);
+    let mut sample = sample_shape::<D>(shape.clone());
+    sample.dims[0] *= shape.dims[0];
+
+    K::reshape(tensor, sample)
+}
+
+/// Splits the first dimension of a tensor of rank `D` into the batch and the first dimension of
+/// the samples, inverting [fold].
+pub(crate) fn unfold<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: K::Primitive<D>,
+    batch: usize,
+) -> K::Primitive<RANK> {
+    let mut shape = K::shape(&tensor);
+    shape.dims[0] /= batch;
+
+    K::reshape(tensor, batch_shape(batch, shape))
+}
+
+/// Returns the ranges of an inner tensor covering the given ranges of a tensor of rank `D`.
+pub(crate) fn ranges<const D: usize, const D2: usize>(
+    shape: &Shape<RANK>,
+    ranges: [Range<usize>; D2],
+) -> [Range<usize>; RANK] {
+    let offset = offset::<D>();
+
+    core::array::from_fn(|i| match i.checked_sub(offset) {
+        Some(index) if index < D2 => ranges[index].clone(),
+        _ => 0..shape.dims[i],
+    })
+}
+
+/// Returns the permutation of an inner tensor applying the given permutation to a tensor of
+/// rank `D`, leaving the batch and padding dimensions in place.
+pub(crate) fn permutation<const D: usize>(axes: [usize; D]) -> [usize; RANK] {
+    let offset = offset::<D>();
+
+    core::array::from_fn(|i| match i.checked_sub(offset) {
+        Some(index) => axes[index] + offset,
+        None => i,
+    })
+}
+
+/// Returns the steps of an inner tensor slice applying the given steps to a tensor of rank `D1`.
+pub(crate) fn steps<const D1: usize, const D2: usize>(steps: [isize; D2]) -> [isize; RANK] {
+    let offset = offset::<D1>();
+
+    core::array::from_fn(|i| match i.checked_sub(offset) {
+        Some(index) if index < D2 => steps[index],
+        _ => 1,
+    })
+}
+
+/// Indices of a selection along a dimension.
+pub(crate) enum SelectIndices<B: Backend> {
+    /// The same indices are used for all the samples, with a select operation.
+    Shared(IntTensor<B, 1>),
+    /// Each sample has its own indices, broadcasted to the shape of a gather or scatter operation.
+    Batched(IntTensor<B, RANK>),
+}
+
+/// Returns the inner indices of a selection along the given inner dimension, for an output of
+/// the given inner shape.
+pub(crate) fn select_indices<B: Backend>(
+    indices: IntTensor<B, RANK>,
+    dim: usize,
+    shape: &Shape<RANK>,
+) -> SelectIndices<B> {
+    let indices_shape = B::int_shape(&indices);
+    let num_indices = indices_shape.dims[RANK - 1];
+
+    if indices_shape.dims[0] == 1 {
+        return SelectIndices::Shared(B::int_reshape(indices, Shape::new([num_indices])));
+    }
+
+    let mut dims = [1; RANK];
+    dims[0] = indices_shape.dims[0];
+    dims[dim] = num_indices;
+
+    let mut target = shape.clone();
+    target.dims[dim] = num_indices;
+
+    let indices = B::int_reshape(indices, Shape::new(dims));
+    SelectIndices::Batched(B::int_expand(indices, target))
+}
+
+/// Moves data created for a tensor of rank `D` into a batch with a single sample.
+pub(crate) fn batch_data<const D: usize>(mut data: TensorData) -> TensorData {
+    let mut dims = vec![1; RANK];
+    dims[offset::<D>()..].copy_from_slice(&data.shape);
+    data.shape = dims;
+
+    data
+}
+
+/// Extracts the data of the single sample of a batch holding tensors of rank `D`.
+pub(crate) fn sample_data<const D: usize>(mut data: TensorData) -> TensorData {
+    assert_eq!(
+        data.shape[0], 1,
+        "Can't read the data of a batch of {} tensors inside a mapped function.",
+        data.shape[0]
+    );
+    data.shape = data.shape[offset::<D>()..].to_vec();
+
+    data
+}
@@ -0,0 +1,200 @@
+use super::base::{
+    batch_data, batch_shape, batch_size, dim, dims, expand_batch, flatten, permutation, ranges,
+    sample_data, sample_shape, steps, RANK,
+};
+use crate::Vmap;
+use alloc::vec::Vec;
+use burn_common::reader::Reader;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, BoolTensorOps, FloatTensor, IntTensor},
+    Bool, Device, Shape, TensorData,
+};
+use core::ops::Range;
+
+impl<B: Backend> BoolTensorOps<Self> for Vmap<B> {
+    fn bool_empty<const D: usize>(shape: Shape<D>, device: &Device<B>) -> BoolTensor<B, RANK> {
+        B::bool_empty(batch_shape(1, shape), device)
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<B, RANK>) -> Shape<D> {
+        sample_shape(B::bool_shape(tensor))
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<B, RANK>) -> Reader<TensorData> {
+        B::bool_into_data(tensor).map(sample_data::<D>)
+    }
+
+    fn bool_from_data<const D: usize>(data: TensorData, device: &Device<B>) -> BoolTensor<B, RANK> {
+        B::bool_from_data(batch_data::<D>(data), device)
+    }
+
+    fn bool_into_int<const D: usize>(tensor: BoolTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::bool_into_int(tensor)
+    }
+
+    fn bool_into_float<const D: usize>(tensor: BoolTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::bool_into_float(tensor)
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<B, RANK>) -> Device<B> {
+        B::bool_device(tensor)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: BoolTensor<B, RANK>,
+        device: &Device<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::bool_to_device(tensor, device)
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<B, RANK>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<B, RANK> {
+        let batch = batch_size::<B, Bool>(&tensor);
+
+        B::bool_reshape(tensor, batch_shape(batch, shape))
+    }
+
+    fn bool_slice<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<B, RANK>,
+        ranges: [Range<usize>; D2],
+    ) -> BoolTensor<B, RANK> {
+        let ranges = self::ranges::<D1, D2>(&B::bool_shape(&tensor), ranges);
+
+        B::bool_slice(tensor, ranges)
+    }
+
+    fn bool_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<B, RANK>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> BoolTensor<B, RANK> {
+        let ranges = self::ranges::<D1, D2>(&B::bool_shape(&tensor), ranges);
+
+        B::bool_slice_with_steps(tensor, ranges, self::steps::<D1, D2>(steps))
+    }
+
+    fn bool_slice_assign<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<B, RANK>,
+        ranges: [Range<usize>; D2],
+        value: BoolTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        let batch = batch_size::<B, Bool>(&tensor).max(batch_size::<B, Bool>(&value));
+        let tensor = expand_batch::<B, Bool>(tensor, batch);
+        let value = expand_batch::<B, Bool>(value, batch);
+        let ranges = self::ranges::<D1, D2>(&B::bool_shape(&tensor), ranges);
+
+        B::bool_slice_assign(tensor, ranges, value)
+    }
+
+    fn bool_repeat<const D: usize>(
+        tensor: BoolTensor<B, RANK>,
+        dim: usize,
+        times: usize,
+    ) -> BoolTensor<B, RANK> {
+        B::bool_repeat(tensor, self::dim::<D>(dim), times)
+    }
+
+    fn bool_cat<const D: usize>(
+        tensors: Vec<BoolTensor<B, RANK>>,
+        dim: usize,
+    ) -> BoolTensor<B, RANK> {
+        let batch = tensors.iter().map(batch_size::<B, Bool>).max().unwrap_or(1);
+        let tensors = tensors
+            .into_iter()
+            .map(|tensor| expand_batch::<B, Bool>(tensor, batch))
+            .collect();
+
+        B::bool_cat(tensors, self::dim::<D>(dim))
+    }
+
+    fn bool_equal<const D: usize>(
+        lhs: BoolTensor<B, RANK>,
+        rhs: BoolTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::bool_equal(lhs, rhs)
+    }
+
+    fn bool_not_equal<const D: usize>(
+        lhs: BoolTensor<B, RANK>,
+        rhs: BoolTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::bool_not_equal(lhs, rhs)
+    }
+
+    fn bool_not<const D: usize>(tensor: BoolTensor<B, RANK>) -> BoolTensor<B, RANK> {
+        B::bool_not(tensor)
+    }
+
+    fn bool_swap_dims<const D: usize>(
+        tensor: BoolTensor<B, RANK>,
+        dim1: usize,
+        dim2: usize,
+    ) -> BoolTensor<B, RANK> {
+        B::bool_swap_dims(tensor, dim::<D>(dim1), dim::<D>(dim2))
+    }
+
+    fn bool_permute<const D: usize>(
+        tensor: BoolTensor<B, RANK>,
+        axes: [usize; D],
+    ) -> BoolTensor<B, RANK> {
+        B::bool_permute(tensor, permutation::<D>(axes))
+    }
+
+    fn bool_flip<const D: usize>(
+        tensor: BoolTensor<B, RANK>,
+        axes: &[usize],
+    ) -> BoolTensor<B, RANK> {
+        B::bool_flip(tensor, &dims::<D>(axes))
+    }
+
+    fn bool_narrow<const D: usize>(
+        tensor: BoolTensor<B, RANK>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> BoolTensor<B, RANK> {
+        B::bool_narrow(tensor, self::dim::<D>(dim), start, length)
+    }
+
+    fn bool_chunk<const D: usize>(
+        tensor: BoolTensor<B, RANK>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<BoolTensor<B, RANK>> {
+        B::bool_chunk(tensor, chunks, self::dim::<D>(dim))
+    }
+
+    fn bool_any<const D: usize>(tensor: BoolTensor<B, RANK>) -> BoolTensor<B, RANK> {
+        B::bool_any_dim(flatten::<B, Bool, D>(tensor), RANK - 1)
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: BoolTensor<B, RANK>,
+        dim: usize,
+    ) -> BoolTensor<B, RANK> {
+        B::bool_any_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn bool_all<const D: usize>(tensor: BoolTensor<B, RANK>) -> BoolTensor<B, RANK> {
+        B::bool_all_dim(flatten::<B, Bool, D>(tensor), RANK - 1)
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: BoolTensor<B, RANK>,
+        dim: usize,
+    ) -> BoolTensor<B, RANK> {
+        B::bool_all_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<B, RANK>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<B, RANK> {
+        let batch = batch_size::<B, Bool>(&tensor);
+
+        B::bool_expand(tensor, batch_shape(batch, shape))
+    }
+}
@@ -0,0 +1,582 @@
+use super::base::{
+    batch_data, batch_shape, batch_size, dim, dims, expand_batch, flatten, permutation, ranges,
+    sample_data, sample_shape, select_indices, steps, SelectIndices, RANK,
+};
+use crate::Vmap;
+use alloc::vec::Vec;
+use burn_common::reader::Reader;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
+    Bool, Device, Distribution, Int, Shape, TensorData,
+};
+use core::ops::Range;
+
+impl<B: Backend> IntTensorOps<Self> for Vmap<B> {
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, RANK> {
+        B::int_empty(batch_shape(1, shape), device)
+    }
+
+    fn int_shape<const D: usize>(tensor: &IntTensor<B, RANK>) -> Shape<D> {
+        sample_shape(B::int_shape(tensor))
+    }
+
+    fn int_into_data<const D: usize>(tensor: IntTensor<B, RANK>) -> Reader<TensorData> {
+        B::int_into_data(tensor).map(sample_data::<D>)
+    }
+
+    fn int_from_data<const D: usize>(data: TensorData, device: &Device<B>) -> IntTensor<B, RANK> {
+        B::int_from_data(batch_data::<D>(data), device)
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<B, RANK>) -> Device<B> {
+        B::int_device(tensor)
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        device: &Device<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_to_device(tensor, device)
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, RANK>,
+        shape: Shape<D2>,
+    ) -> IntTensor<B, RANK> {
+        let batch = batch_size::<B, Int>(&tensor);
+
+        B::int_reshape(tensor, batch_shape(batch, shape))
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, RANK>,
+        indices: [Range<usize>; D2],
+    ) -> IntTensor<B, RANK> {
+        let ranges = ranges::<D1, D2>(&B::int_shape(&tensor), indices);
+
+        B::int_slice(tensor, ranges)
+    }
+
+    fn int_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, RANK>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> IntTensor<B, RANK> {
+        let ranges = self::ranges::<D1, D2>(&B::int_shape(&tensor), ranges);
+
+        B::int_slice_with_steps(tensor, ranges, self::steps::<D1, D2>(steps))
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, RANK>,
+        indices: [Range<usize>; D2],
+        value: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        let batch = batch_size::<B, Int>(&tensor).max(batch_size::<B, Int>(&value));
+        let tensor = expand_batch::<B, Int>(tensor, batch);
+        let value = expand_batch::<B, Int>(value, batch);
+        let ranges = ranges::<D1, D2>(&B::int_shape(&tensor), indices);
+
+        B::int_slice_assign(tensor, ranges, value)
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::int_into_float(tensor)
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        mask: BoolTensor<B, RANK>,
+        source: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        let batch = batch_size::<B, Int>(&tensor)
+            .max(batch_size::<B, Bool>(&mask))
+            .max(batch_size::<B, Int>(&source));
+        let tensor = expand_batch::<B, Int>(tensor, batch);
+        let mask = expand_batch::<B, Bool>(mask, batch);
+        let source = expand_batch::<B, Int>(source, batch);
+
+        B::int_mask_where(tensor, mask, source)
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        mask: BoolTensor<B, RANK>,
+        value: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        let batch = batch_size::<B, Int>(&tensor).max(batch_size::<B, Bool>(&mask));
+        let tensor = expand_batch::<B, Int>(tensor, batch);
+        let mask = expand_batch::<B, Bool>(mask, batch);
+
+        B::int_mask_fill(tensor, mask, value)
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, RANK>,
+        indices: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        let batch = batch_size::<B, Int>(&tensor).max(batch_size::<B, Int>(&indices));
+        let tensor = expand_batch::<B, Int>(tensor, batch);
+        let indices = expand_batch::<B, Int>(indices, batch);
+
+        B::int_gather(self::dim::<D>(dim), tensor, indices)
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, RANK>,
+        indices: IntTensor<B, RANK>,
+        value: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        let batch = batch_size::<B, Int>(&tensor)
+            .max(batch_size::<B, Int>(&indices))
+            .max(batch_size::<B, Int>(&value));
+        let tensor = expand_batch::<B, Int>(tensor, batch);
+        let indices = expand_batch::<B, Int>(indices, batch);
+        let value = expand_batch::<B, Int>(value, batch);
+
+        B::int_scatter(self::dim::<D>(dim), tensor, indices, value)
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim: usize,
+        indices: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        let dim = self::dim::<D>(dim);
+        let batch = batch_size::<B, Int>(&tensor).max(batch_size::<B, Int>(&indices));
+        let tensor = expand_batch::<B, Int>(tensor, batch);
+        let shape = B::int_shape(&tensor);
+
+        match select_indices::<B>(indices, dim, &shape) {
+            SelectIndices::Shared(indices) => B::int_select(tensor, dim, indices),
+            SelectIndices::Batched(indices) => B::int_gather(dim, tensor, indices),
+        }
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim: usize,
+        indices: IntTensor<B, RANK>,
+        value: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        let dim = self::dim::<D>(dim);
+        let batch = batch_size::<B, Int>(&tensor)
+            .max(batch_size::<B, Int>(&indices))
+            .max(batch_size::<B, Int>(&value));
+        let tensor = expand_batch::<B, Int>(tensor, batch);
+        let value = expand_batch::<B, Int>(value, batch);
+        let shape = B::int_shape(&value);
+
+        match select_indices::<B>(indices, dim, &shape) {
+            SelectIndices::Shared(indices) => B::int_select_assign(tensor, dim, indices, value),
+            SelectIndices::Batched(indices) => B::int_scatter(dim, tensor, indices, value),
+        }
+    }
+
+    fn int_repeat<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim: usize,
+        times: usize,
+    ) -> IntTensor<B, RANK> {
+        B::int_repeat(tensor, self::dim::<D>(dim), times)
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<B, RANK>>, dim: usize) -> IntTensor<B, RANK> {
+        let batch = tensors.iter().map(batch_size::<B, Int>).max().unwrap_or(1);
+        let tensors = tensors
+            .into_iter()
+            .map(|tensor| expand_batch::<B, Int>(tensor, batch))
+            .collect();
+
+        B::int_cat(tensors, self::dim::<D>(dim))
+    }
+
+    fn int_equal<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_equal(lhs, rhs)
+    }
+
+    fn int_not_equal<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_not_equal(lhs, rhs)
+    }
+
+    fn int_equal_elem<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_equal_elem(lhs, rhs)
+    }
+
+    fn int_not_equal_elem<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_not_equal_elem(lhs, rhs)
+    }
+
+    fn int_greater<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_greater(lhs, rhs)
+    }
+
+    fn int_greater_elem<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_greater_elem(lhs, rhs)
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_greater_equal(lhs, rhs)
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_greater_equal_elem(lhs, rhs)
+    }
+
+    fn int_lower<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_lower(lhs, rhs)
+    }
+
+    fn int_lower_elem<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_lower_elem(lhs, rhs)
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_lower_equal(lhs, rhs)
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::int_lower_equal_elem(lhs, rhs)
+    }
+
+    fn int_add<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        B::int_add(lhs, rhs)
+    }
+
+    fn int_add_scalar<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_add_scalar(lhs, rhs)
+    }
+
+    fn int_powi<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        B::int_powi(lhs, rhs)
+    }
+
+    fn int_powf<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        B::int_powf(lhs, rhs)
+    }
+
+    fn int_powi_scalar<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_powi_scalar(lhs, rhs)
+    }
+
+    fn int_powf_scalar<const D: usize>(lhs: IntTensor<B, RANK>, rhs: f32) -> IntTensor<B, RANK> {
+        B::int_powf_scalar(lhs, rhs)
+    }
+
+    fn int_clamp_min<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        min: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_clamp_min(tensor, min)
+    }
+
+    fn int_clamp_max<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        max: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_clamp_max(tensor, max)
+    }
+
+    fn int_clamp<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        min: IntElem<B>,
+        max: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_clamp(tensor, min, max)
+    }
+
+    fn int_sub<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        B::int_sub(lhs, rhs)
+    }
+
+    fn int_sub_scalar<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_sub_scalar(lhs, rhs)
+    }
+
+    fn int_mul<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        B::int_mul(lhs, rhs)
+    }
+
+    fn int_mul_scalar<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_mul_scalar(lhs, rhs)
+    }
+
+    fn int_div<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> IntTensor<B, RANK> {
+        B::int_div(lhs, rhs)
+    }
+
+    fn int_div_scalar<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_div_scalar(lhs, rhs)
+    }
+
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_remainder_scalar(lhs, rhs)
+    }
+
+    fn int_neg<const D: usize>(tensor: IntTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::int_neg(tensor)
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, RANK> {
+        B::int_zeros(batch_shape(1, shape), device)
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, RANK> {
+        B::int_ones(batch_shape(1, shape), device)
+    }
+
+    fn int_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: IntElem<B>,
+        device: &Device<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_full(batch_shape(1, shape), fill_value, device)
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::int_sum_dim(flatten::<B, Int, D>(tensor), RANK - 1)
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<B, RANK>, dim: usize) -> IntTensor<B, RANK> {
+        B::int_sum_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_prod<const D: usize>(tensor: IntTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::int_prod_dim(flatten::<B, Int, D>(tensor), RANK - 1)
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<B, RANK>, dim: usize) -> IntTensor<B, RANK> {
+        B::int_prod_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_mean<const D: usize>(tensor: IntTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::int_mean_dim(flatten::<B, Int, D>(tensor), RANK - 1)
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<B, RANK>, dim: usize) -> IntTensor<B, RANK> {
+        B::int_mean_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<B, RANK>, dim: usize) -> IntTensor<B, RANK> {
+        B::int_argmax(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<B, RANK>, dim: usize) -> IntTensor<B, RANK> {
+        B::int_argmin(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_max<const D: usize>(tensor: IntTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::int_max_dim(flatten::<B, Int, D>(tensor), RANK - 1)
+    }
+
+    fn int_max_dim<const D: usize>(tensor: IntTensor<B, RANK>, dim: usize) -> IntTensor<B, RANK> {
+        B::int_max_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_max_dim_with_indices<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim: usize,
+    ) -> (IntTensor<B, RANK>, IntTensor<B, RANK>) {
+        B::int_max_dim_with_indices(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_min<const D: usize>(tensor: IntTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::int_min_dim(flatten::<B, Int, D>(tensor), RANK - 1)
+    }
+
+    fn int_min_dim<const D: usize>(tensor: IntTensor<B, RANK>, dim: usize) -> IntTensor<B, RANK> {
+        B::int_min_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_min_dim_with_indices<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim: usize,
+    ) -> (IntTensor<B, RANK>, IntTensor<B, RANK>) {
+        B::int_min_dim_with_indices(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::int_abs(tensor)
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<B, RANK> {
+        B::int_swap_dims(tensor, dim::<D>(dim1), dim::<D>(dim2))
+    }
+
+    fn int_permute<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        axes: [usize; D],
+    ) -> IntTensor<B, RANK> {
+        B::int_permute(tensor, permutation::<D>(axes))
+    }
+
+    fn int_flip<const D: usize>(tensor: IntTensor<B, RANK>, axes: &[usize]) -> IntTensor<B, RANK> {
+        B::int_flip(tensor, &dims::<D>(axes))
+    }
+
+    fn int_narrow<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> IntTensor<B, RANK> {
+        B::int_narrow(tensor, self::dim::<D>(dim), start, length)
+    }
+
+    fn int_chunk<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<IntTensor<B, RANK>> {
+        B::int_chunk(tensor, chunks, self::dim::<D>(dim))
+    }
+
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_random(batch_shape(1, shape), distribution, device)
+    }
+
+    fn int_random_seeded<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        seed: u64,
+        device: &Device<B>,
+    ) -> IntTensor<B, RANK> {
+        B::int_random_seeded(batch_shape(1, shape), distribution, seed, device)
+    }
+
+    fn int_any<const D: usize>(tensor: IntTensor<B, RANK>) -> BoolTensor<B, RANK> {
+        B::int_any_dim(flatten::<B, Int, D>(tensor), RANK - 1)
+    }
+
+    fn int_any_dim<const D: usize>(tensor: IntTensor<B, RANK>, dim: usize) -> BoolTensor<B, RANK> {
+        B::int_any_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_all<const D: usize>(tensor: IntTensor<B, RANK>) -> BoolTensor<B, RANK> {
+        B::int_all_dim(flatten::<B, Int, D>(tensor), RANK - 1)
+    }
+
+    fn int_all_dim<const D: usize>(tensor: IntTensor<B, RANK>, dim: usize) -> BoolTensor<B, RANK> {
+        B::int_all_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn int_sign<const D: usize>(tensor: IntTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::int_sign(tensor)
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, RANK>,
+        shape: Shape<D2>,
+    ) -> IntTensor<B, RANK> {
+        let batch = batch_size::<B, Int>(&tensor);
+
+        B::int_expand(tensor, batch_shape(batch, shape))
+    }
+
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, RANK> {
+        B::int_sort(tensor, self::dim::<D>(dim), descending)
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<B, RANK>, IntTensor<B, RANK>) {
+        B::int_sort_with_indices(tensor, self::dim::<D>(dim), descending)
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<B, RANK>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, RANK> {
+        B::int_argsort(tensor, self::dim::<D>(dim), descending)
+    }
+}
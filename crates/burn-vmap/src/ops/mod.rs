@@ -0,0 +1,9 @@
+mod activation;
+mod base;
+mod bool_tensor;
+mod int_tensor;
+mod module;
+mod tensor;
+
+pub use base::RANK;
+pub(crate) use base::{batch_shape, expand_batch, sample_shape};
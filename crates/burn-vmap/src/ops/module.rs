@@ -0,0 +1,296 @@
+use super::base::{batch_shape, batch_size, dim, expand_batch, fold, unfold, RANK};
+use crate::Vmap;
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, InterpolateOptions,
+        MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps, UnfoldOptions,
+    },
+    Float, Int, Shape,
+};
+
+impl<B: Backend> ModuleOps<Self> for Vmap<B> {
+    fn conv2d(
+        x: FloatTensor<B, RANK>,
+        weight: FloatTensor<B, RANK>,
+        bias: Option<FloatTensor<B, RANK>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<B, RANK> {
+        let weight_batch = weight_batch_size::<B>(&weight, &bias);
+
+        if weight_batch == 1 {
+            let batch = batch_size::<B, Float>(&x);
+            let output = B::conv2d(
+                fold::<B, Float, 4>(x),
+                fold::<B, Float, 4>(weight),
+                bias.map(fold::<B, Float, 1>),
+                options,
+            );
+
+            return unfold::<B, Float, 4>(output, batch);
+        }
+
+        // Each sample has its own weights: the samples are mapped to groups of channels.
+        let batch = weight_batch.max(batch_size::<B, Float>(&x));
+        let options = ConvOptions::new(
+            options.stride,
+            options.padding,
+            options.dilation,
+            options.groups * batch,
+        );
+        let output = B::conv2d(
+            into_channels::<B, 4>(expand_batch::<B, Float>(x, batch)),
+            fold::<B, Float, 4>(expand_batch::<B, Float>(weight, batch)),
+            bias.map(|bias| fold::<B, Float, 1>(expand_batch::<B, Float>(bias, batch))),
+            options,
+        );
+
+        from_channels::<B, 4>(output, batch)
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<B, RANK>,
+        weight: FloatTensor<B, RANK>,
+        bias: Option<FloatTensor<B, RANK>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<B, RANK> {
+        let weight_batch = weight_batch_size::<B>(&weight, &bias);
+
+        if weight_batch == 1 {
+            let batch = batch_size::<B, Float>(&x);
+            let output = B::conv_transpose2d(
+                fold::<B, Float, 4>(x),
+                fold::<B, Float, 4>(weight),
+                bias.map(fold::<B, Float, 1>),
+                options,
+            );
+
+            return unfold::<B, Float, 4>(output, batch);
+        }
+
+        // Each sample has its own weights: the samples are mapped to groups of channels.
+        let batch = weight_batch.max(batch_size::<B, Float>(&x));
+        let options = ConvTransposeOptions::new(
+            options.stride,
+            options.padding,
+            options.padding_out,
+            options.dilation,
+            options.groups * batch,
+        );
+        let output = B::conv_transpose2d(
+            into_channels::<B, 4>(expand_batch::<B, Float>(x, batch)),
+            fold::<B, Float, 4>(expand_batch::<B, Float>(weight, batch)),
+            bias.map(|bias| fold::<B, Float, 1>(expand_batch::<B, Float>(bias, batch))),
+            options,
+        );
+
+        from_channels::<B, 4>(output, batch)
+    }
+
+    fn unfold4d(
+        x: FloatTensor<B, RANK>,
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&x);
+        let output = B::unfold4d(fold::<B, Float, 4>(x), kernel_size, options);
+
+        unfold::<B, Float, 3>(output, batch)
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<B, RANK>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&x);
+        let output = B::avg_pool2d(
+            fold::<B, Float, 4>(x),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        );
+
+        unfold::<B, Float, 4>(output, batch)
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<B, RANK>,
+        grad: FloatTensor<B, RANK>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&x).max(batch_size::<B, Float>(&grad));
+        let x_grad = B::avg_pool2d_backward(
+            fold::<B, Float, 4>(expand_batch::<B, Float>(x, batch)),
+            fold::<B, Float, 4>(expand_batch::<B, Float>(grad, batch)),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        );
+
+        unfold::<B, Float, 4>(x_grad, batch)
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<B, RANK>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&x);
+        let output = B::adaptive_avg_pool2d(fold::<B, Float, 4>(x), output_size);
+
+        unfold::<B, Float, 4>(output, batch)
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<B, RANK>,
+        grad: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&x).max(batch_size::<B, Float>(&grad));
+        let x_grad = B::adaptive_avg_pool2d_backward(
+            fold::<B, Float, 4>(expand_batch::<B, Float>(x, batch)),
+            fold::<B, Float, 4>(expand_batch::<B, Float>(grad, batch)),
+        );
+
+        unfold::<B, Float, 4>(x_grad, batch)
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<B, RANK>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&x);
+        let output = B::max_pool2d(
+            fold::<B, Float, 4>(x),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        );
+
+        unfold::<B, Float, 4>(output, batch)
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<B, RANK>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        let batch = batch_size::<B, Float>(&x);
+        let output = B::max_pool2d_with_indices(
+            fold::<B, Float, 4>(x),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        );
+
+        MaxPool2dWithIndices::new(
+            unfold::<B, Float, 4>(output.output, batch),
+            unfold::<B, Int, 4>(output.indices, batch),
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<B, RANK>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<B, RANK>,
+        indices: IntTensor<B, RANK>,
+    ) -> MaxPool2dBackward<Self> {
+        let batch = batch_size::<B, Float>(&x)
+            .max(batch_size::<B, Float>(&output_grad))
+            .max(batch_size::<B, Int>(&indices));
+        let backward = B::max_pool2d_with_indices_backward(
+            fold::<B, Float, 4>(expand_batch::<B, Float>(x, batch)),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            fold::<B, Float, 4>(expand_batch::<B, Float>(output_grad, batch)),
+            fold::<B, Int, 4>(expand_batch::<B, Int>(indices, batch)),
+        );
+
+        MaxPool2dBackward::new(unfold::<B, Float, 4>(backward.x_grad, batch))
+    }
+
+    fn interpolate(
+        x: FloatTensor<B, RANK>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&x);
+        let output = B::interpolate(fold::<B, Float, 4>(x), output_size, options);
+
+        unfold::<B, Float, 4>(output, batch)
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<B, RANK>,
+        grad: FloatTensor<B, RANK>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&x).max(batch_size::<B, Float>(&grad));
+        let x_grad = B::interpolate_backward(
+            fold::<B, Float, 4>(expand_batch::<B, Float>(x, batch)),
+            fold::<B, Float, 4>(expand_batch::<B, Float>(grad, batch)),
+            output_size,
+            options,
+        );
+
+        unfold::<B, Float, 4>(x_grad, batch)
+    }
+}
+
+/// Returns the number of samples of the weights of a convolution.
+fn weight_batch_size<B: Backend>(
+    weight: &FloatTensor<B, RANK>,
+    bias: &Option<FloatTensor<B, RANK>>,
+) -> usize {
+    let bias_batch = bias.as_ref().map(batch_size::<B, Float>).unwrap_or(1);
+
+    batch_size::<B, Float>(weight).max(bias_batch)
+}
+
+/// Merges the batch into the channels of a tensor of rank `D` with the layout
+/// `[batch_size, channels, ...]`, the channels of each sample being contiguous.
+fn into_channels<B: Backend, const D: usize>(x: FloatTensor<B, RANK>) -> FloatTensor<B, D> {
+    let shape = B::float_shape(&x);
+    let batch = shape.dims[0];
+    let x = B::float_swap_dims(x, 0, dim::<D>(0));
+
+    let mut dims = [0; D];
+    dims.copy_from_slice(&shape.dims[RANK - D..]);
+    dims[1] *= batch;
+
+    B::float_reshape(x, Shape::new(dims))
+}
+
+/// Splits the channels of a tensor of rank `D` into the batch and the channels of each sample,
+/// inverting [into_channels].
+fn from_channels<B: Backend, const D: usize>(
+    output: FloatTensor<B, D>,
+    batch: usize,
+) -> FloatTensor<B, RANK> {
+    let mut shape = B::float_shape(&output);
+    let batch_size = shape.dims[0];
+    shape.dims[0] = batch;
+    shape.dims[1] /= batch;
+
+    let output = B::float_reshape(output, batch_shape(batch_size, shape));
+    B::float_swap_dims(output, 0, dim::<D>(0))
+}
@@ -0,0 +1,726 @@
+use super::base::{
+    batch_data, batch_shape, batch_size, dim, dims, expand_batch, flatten, permutation, ranges,
+    sample_data, sample_shape, select_indices, steps, SelectIndices, RANK,
+};
+use crate::Vmap;
+use alloc::vec::Vec;
+use burn_common::reader::Reader;
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        BoolTensor, FloatElem, FloatGradHook, FloatGradMap, FloatTensor, FloatTensorOps, IntElem,
+        IntTensor,
+    },
+    Bool, Device, Distribution, Float, Int, Shape, TensorData,
+};
+use core::ops::Range;
+
+impl<B: Backend> FloatTensorOps<Self> for Vmap<B> {
+    fn float_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_from_data(batch_data::<D>(data), device)
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_random(batch_shape(1, shape), distribution, device)
+    }
+
+    fn float_random_seeded<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        seed: u64,
+        device: &Device<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_random_seeded(batch_shape(1, shape), distribution, seed, device)
+    }
+
+    fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<B>) -> FloatTensor<B, RANK> {
+        B::float_zeros(batch_shape(1, shape), device)
+    }
+
+    fn float_ones<const D: usize>(shape: Shape<D>, device: &Device<B>) -> FloatTensor<B, RANK> {
+        B::float_ones(batch_shape(1, shape), device)
+    }
+
+    fn float_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: FloatElem<B>,
+        device: &Device<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_full(batch_shape(1, shape), fill_value, device)
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<B, RANK>) -> Shape<D> {
+        sample_shape(B::float_shape(tensor))
+    }
+
+    fn float_into_data<const D: usize>(tensor: FloatTensor<B, RANK>) -> Reader<TensorData> {
+        B::float_into_data(tensor).map(sample_data::<D>)
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<B, RANK>) -> Device<B> {
+        B::float_device(tensor)
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        device: &Device<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_to_device(tensor, device)
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<B, RANK>) -> IntTensor<B, RANK> {
+        B::float_into_int(tensor)
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<B>) -> FloatTensor<B, RANK> {
+        B::float_empty(batch_shape(1, shape), device)
+    }
+
+    fn float_repeat<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<B, RANK> {
+        B::float_repeat(tensor, self::dim::<D>(dim), times)
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_add(lhs, rhs)
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_add_scalar(lhs, rhs)
+    }
+
+    fn float_clamp_min<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        min: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_clamp_min(tensor, min)
+    }
+
+    fn float_clamp_max<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        max: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_clamp_max(tensor, max)
+    }
+
+    fn float_clamp<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        min: FloatElem<B>,
+        max: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_clamp(tensor, min, max)
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_sub(lhs, rhs)
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_sub_scalar(lhs, rhs)
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_mul(lhs, rhs)
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_mul_scalar(lhs, rhs)
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_div(lhs, rhs)
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_div_scalar(lhs, rhs)
+    }
+
+    fn float_remainder_scalar<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_remainder_scalar(lhs, rhs)
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_matmul(lhs, rhs)
+    }
+
+    fn float_neg<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_neg(tensor)
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_recip(tensor)
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<B, RANK> {
+        B::float_swap_dims(tensor, dim::<D>(dim1), dim::<D>(dim2))
+    }
+
+    fn float_permute<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        axes: [usize; D],
+    ) -> FloatTensor<B, RANK> {
+        B::float_permute(tensor, permutation::<D>(axes))
+    }
+
+    fn float_flip<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        axes: &[usize],
+    ) -> FloatTensor<B, RANK> {
+        B::float_flip(tensor, &dims::<D>(axes))
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<B, RANK>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&tensor);
+
+        B::float_reshape(tensor, batch_shape(batch, shape))
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<B, RANK>,
+        indices: IntTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&tensor).max(batch_size::<B, Int>(&indices));
+        let tensor = expand_batch::<B, Float>(tensor, batch);
+        let indices = expand_batch::<B, Int>(indices, batch);
+
+        B::float_gather(self::dim::<D>(dim), tensor, indices)
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<B, RANK>,
+        indices: IntTensor<B, RANK>,
+        value: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&tensor)
+            .max(batch_size::<B, Int>(&indices))
+            .max(batch_size::<B, Float>(&value));
+        let tensor = expand_batch::<B, Float>(tensor, batch);
+        let indices = expand_batch::<B, Int>(indices, batch);
+        let value = expand_batch::<B, Float>(value, batch);
+
+        B::float_scatter(self::dim::<D>(dim), tensor, indices, value)
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+        indices: IntTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        let dim = self::dim::<D>(dim);
+        let batch = batch_size::<B, Float>(&tensor).max(batch_size::<B, Int>(&indices));
+        let tensor = expand_batch::<B, Float>(tensor, batch);
+        let shape = B::float_shape(&tensor);
+
+        match select_indices::<B>(indices, dim, &shape) {
+            SelectIndices::Shared(indices) => B::float_select(tensor, dim, indices),
+            SelectIndices::Batched(indices) => B::float_gather(dim, tensor, indices),
+        }
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+        indices: IntTensor<B, RANK>,
+        value: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        let dim = self::dim::<D>(dim);
+        let batch = batch_size::<B, Float>(&tensor)
+            .max(batch_size::<B, Int>(&indices))
+            .max(batch_size::<B, Float>(&value));
+        let tensor = expand_batch::<B, Float>(tensor, batch);
+        let value = expand_batch::<B, Float>(value, batch);
+        let shape = B::float_shape(&value);
+
+        match select_indices::<B>(indices, dim, &shape) {
+            SelectIndices::Shared(indices) => B::float_select_assign(tensor, dim, indices, value),
+            SelectIndices::Batched(indices) => B::float_scatter(dim, tensor, indices, value),
+        }
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<B, RANK>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<B, RANK> {
+        let ranges = self::ranges::<D1, D2>(&B::float_shape(&tensor), ranges);
+
+        B::float_slice(tensor, ranges)
+    }
+
+    fn float_slice_with_steps<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<B, RANK>,
+        ranges: [Range<usize>; D2],
+        steps: [isize; D2],
+    ) -> FloatTensor<B, RANK> {
+        let ranges = self::ranges::<D1, D2>(&B::float_shape(&tensor), ranges);
+
+        B::float_slice_with_steps(tensor, ranges, self::steps::<D1, D2>(steps))
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<B, RANK>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&tensor).max(batch_size::<B, Float>(&value));
+        let tensor = expand_batch::<B, Float>(tensor, batch);
+        let value = expand_batch::<B, Float>(value, batch);
+        let ranges = self::ranges::<D1, D2>(&B::float_shape(&tensor), ranges);
+
+        B::float_slice_assign(tensor, ranges, value)
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        mask: BoolTensor<B, RANK>,
+        value: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&tensor)
+            .max(batch_size::<B, Bool>(&mask))
+            .max(batch_size::<B, Float>(&value));
+        let tensor = expand_batch::<B, Float>(tensor, batch);
+        let mask = expand_batch::<B, Bool>(mask, batch);
+        let value = expand_batch::<B, Float>(value, batch);
+
+        B::float_mask_where(tensor, mask, value)
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        mask: BoolTensor<B, RANK>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&tensor).max(batch_size::<B, Bool>(&mask));
+        let tensor = expand_batch::<B, Float>(tensor, batch);
+        let mask = expand_batch::<B, Bool>(mask, batch);
+
+        B::float_mask_fill(tensor, mask, value)
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_equal(lhs, rhs)
+    }
+
+    fn float_not_equal<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_not_equal(lhs, rhs)
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_equal_elem(lhs, rhs)
+    }
+
+    fn float_not_equal_elem<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_not_equal_elem(lhs, rhs)
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_greater(lhs, rhs)
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_greater_elem(lhs, rhs)
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_greater_equal(lhs, rhs)
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_greater_equal_elem(lhs, rhs)
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_lower(lhs, rhs)
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_lower_elem(lhs, rhs)
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_lower_equal(lhs, rhs)
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, RANK> {
+        B::float_lower_equal_elem(lhs, rhs)
+    }
+
+    fn float_detach<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_detach(tensor)
+    }
+
+    fn float_set_require_grad<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        require_grad: bool,
+    ) -> FloatTensor<B, RANK> {
+        B::float_set_require_grad(tensor, require_grad)
+    }
+
+    fn float_is_require_grad<const D: usize>(tensor: &FloatTensor<B, RANK>) -> bool {
+        B::float_is_require_grad(tensor)
+    }
+
+    fn float_register_grad_hook<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        hook: FloatGradHook<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_register_grad_hook(tensor, hook)
+    }
+
+    fn float_map_grad<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        map: FloatGradMap<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_map_grad(tensor, map)
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_sum_dim(flatten::<B, Float, D>(tensor), RANK - 1)
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> FloatTensor<B, RANK> {
+        B::float_sum_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_prod<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_prod_dim(flatten::<B, Float, D>(tensor), RANK - 1)
+    }
+
+    fn float_prod_dim<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> FloatTensor<B, RANK> {
+        B::float_prod_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_mean<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_mean_dim(flatten::<B, Float, D>(tensor), RANK - 1)
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> FloatTensor<B, RANK> {
+        B::float_mean_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_exp(tensor)
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_log(tensor)
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_log1p(tensor)
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_powf(lhs, rhs)
+    }
+
+    fn float_powi<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: IntTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_powi(lhs, rhs)
+    }
+
+    fn float_powi_scalar<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: IntElem<B>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_powi_scalar(lhs, rhs)
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        value: f32,
+    ) -> FloatTensor<B, RANK> {
+        B::float_powf_scalar(tensor, value)
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_sqrt(tensor)
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_abs(tensor)
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_cos(tensor)
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_sin(tensor)
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_tanh(tensor)
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_erf(tensor)
+    }
+
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_erfinv(tensor)
+    }
+
+    fn float_lgamma<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_lgamma(tensor)
+    }
+
+    fn float_digamma<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_digamma(tensor)
+    }
+
+    fn float_polygamma<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        order: u32,
+    ) -> FloatTensor<B, RANK> {
+        B::float_polygamma(tensor, order)
+    }
+
+    fn float_atan2<const D: usize>(
+        lhs: FloatTensor<B, RANK>,
+        rhs: FloatTensor<B, RANK>,
+    ) -> FloatTensor<B, RANK> {
+        B::float_atan2(lhs, rhs)
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<B, RANK>>,
+        dim: usize,
+    ) -> FloatTensor<B, RANK> {
+        let batch = tensors
+            .iter()
+            .map(batch_size::<B, Float>)
+            .max()
+            .unwrap_or(1);
+        let tensors = tensors
+            .into_iter()
+            .map(|tensor| expand_batch::<B, Float>(tensor, batch))
+            .collect();
+
+        B::float_cat(tensors, self::dim::<D>(dim))
+    }
+
+    fn float_argmax<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> IntTensor<B, RANK> {
+        B::float_argmax(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_argmin<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> IntTensor<B, RANK> {
+        B::float_argmin(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_max<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_max_dim(flatten::<B, Float, D>(tensor), RANK - 1)
+    }
+
+    fn float_max_dim<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> FloatTensor<B, RANK> {
+        B::float_max_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> (FloatTensor<B, RANK>, IntTensor<B, RANK>) {
+        B::float_max_dim_with_indices(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_min<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_min_dim(flatten::<B, Float, D>(tensor), RANK - 1)
+    }
+
+    fn float_min_dim<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> FloatTensor<B, RANK> {
+        B::float_min_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> (FloatTensor<B, RANK>, IntTensor<B, RANK>) {
+        B::float_min_dim_with_indices(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_narrow<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> FloatTensor<B, RANK> {
+        B::float_narrow(tensor, self::dim::<D>(dim), start, length)
+    }
+
+    fn float_chunk<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<FloatTensor<B, RANK>> {
+        B::float_chunk(tensor, chunks, self::dim::<D>(dim))
+    }
+
+    fn float_any<const D: usize>(tensor: FloatTensor<B, RANK>) -> BoolTensor<B, RANK> {
+        B::float_any_dim(flatten::<B, Float, D>(tensor), RANK - 1)
+    }
+
+    fn float_any_dim<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> BoolTensor<B, RANK> {
+        B::float_any_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_all<const D: usize>(tensor: FloatTensor<B, RANK>) -> BoolTensor<B, RANK> {
+        B::float_all_dim(flatten::<B, Float, D>(tensor), RANK - 1)
+    }
+
+    fn float_all_dim<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+    ) -> BoolTensor<B, RANK> {
+        B::float_all_dim(tensor, self::dim::<D>(dim))
+    }
+
+    fn float_sign<const D: usize>(tensor: FloatTensor<B, RANK>) -> FloatTensor<B, RANK> {
+        B::float_sign(tensor)
+    }
+
+    fn float_expand<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<B, RANK>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<B, RANK> {
+        let batch = batch_size::<B, Float>(&tensor);
+
+        B::float_expand(tensor, batch_shape(batch, shape))
+    }
+
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<B, RANK> {
+        B::float_sort(tensor, self::dim::<D>(dim), descending)
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<B, RANK>, IntTensor<B, RANK>) {
+        B::float_sort_with_indices(tensor, self::dim::<D>(dim), descending)
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<B, RANK>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, RANK> {
+        B::float_argsort(tensor, self::dim::<D>(dim), descending)
+    }
+}
@@ -0,0 +1,232 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use crate::{shared, vmap, vmap2, Vmap};
+use burn_autodiff::Autodiff;
+use burn_ndarray::NdArray;
+use burn_tensor::{backend::Backend, module::conv2d, ops::ConvOptions, Int, Tensor, TensorData};
+
+type TestBackend = NdArray<f32>;
+type TestAutodiffBackend = Autodiff<TestBackend>;
+
+fn normalize<B: Backend>(tensor: Tensor<B, 1>) -> Tensor<B, 1> {
+    let norm = tensor.clone().powf_scalar(2.0).sum().sqrt();
+
+    tensor.div(norm)
+}
+
+fn dot<B: Backend>(lhs: Tensor<B, 1>, rhs: Tensor<B, 1>) -> Tensor<B, 1> {
+    lhs.mul(rhs).sum()
+}
+
+#[test]
+fn should_map_elementwise_ops_and_reductions() {
+    let device = Default::default();
+    let input =
+        Tensor::<TestBackend, 2>::from_floats([[3.0, 4.0], [0.0, 2.0], [6.0, 8.0]], &device);
+
+    let output: Tensor<TestBackend, 2> = vmap(normalize::<Vmap<TestBackend>>)(input);
+
+    output
+        .into_data()
+        .assert_approx_eq(&TensorData::from([[0.6, 0.8], [0.0, 1.0], [0.6, 0.8]]), 3);
+}
+
+#[test]
+fn should_map_functions_of_two_tensors() {
+    let device = Default::default();
+    let lhs = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+    let rhs = Tensor::<TestBackend, 2>::from_floats([[5.0, 6.0], [7.0, 8.0]], &device);
+
+    let output: Tensor<TestBackend, 2> = vmap2(dot::<Vmap<TestBackend>>)(lhs, rhs);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[17.0], [53.0]]), false);
+}
+
+#[test]
+fn should_broadcast_shared_and_created_tensors() {
+    let device = Default::default();
+    let weight = Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0, 1.0], [0.0, 2.0, 0.0]], &device);
+    let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+    let output: Tensor<TestBackend, 2> = vmap(|x: Tensor<Vmap<TestBackend>, 1>| {
+        let y = shared(weight.clone())
+            .matmul(x.reshape([3, 1]))
+            .reshape([2]);
+        let bias = Tensor::from_floats([0.5], &y.device());
+
+        Tensor::cat(vec![y, bias], 0)
+    })(input);
+
+    output.into_data().assert_eq(
+        &TensorData::from([[4.0, 4.0, 0.5], [10.0, 10.0, 0.5]]),
+        false,
+    );
+}
+
+#[test]
+fn should_map_selections_with_batched_indices() {
+    let device = Default::default();
+    let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 7.0, 3.0], [9.0, 2.0, 4.0]], &device);
+
+    let output: Tensor<TestBackend, 2> = vmap(|x: Tensor<Vmap<TestBackend>, 1>| {
+        let indices: Tensor<Vmap<TestBackend>, 1, Int> = x.clone().argmax(0);
+        let shifted = indices.clone().add_scalar(1).remainder_scalar(3);
+
+        x.clone().select(0, indices).sub(x.select(0, shifted))
+    })(input);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[4.0], [7.0]]), false);
+}
+
+#[test]
+fn should_map_dims_of_the_samples() {
+    let device = Default::default();
+    let input = Tensor::<TestBackend, 3>::from_floats(
+        [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+        &device,
+    );
+
+    let output: Tensor<TestBackend, 3> =
+        vmap(|x: Tensor<Vmap<TestBackend>, 2>| x.clone().transpose().sub(x.sum_dim(0)))(input);
+
+    output.into_data().assert_eq(
+        &TensorData::from([[[-3.0, -3.0], [-2.0, -2.0]], [[-7.0, -7.0], [-6.0, -6.0]]]),
+        false,
+    );
+}
+
+#[test]
+fn should_map_convolutions_with_batched_weights() {
+    let device = Default::default();
+    let input = Tensor::<TestBackend, 1, Int>::arange(0..32, &device)
+        .float()
+        .reshape([2, 1, 4, 4]);
+    let weight = Tensor::<TestBackend, 2>::from_floats(
+        [[1.0, 0.0, 0.0, 1.0], [0.0, 2.0, 1.0, 0.0]],
+        &device,
+    )
+    .reshape([2, 1, 1, 2, 2]);
+
+    let output: Tensor<TestBackend, 4> = vmap2(
+        |x: Tensor<Vmap<TestBackend>, 3>, w: Tensor<Vmap<TestBackend>, 4>| {
+            conv2d(
+                x.unsqueeze(),
+                w,
+                None,
+                ConvOptions::new([1, 1], [0, 0], [1, 1], 1),
+            )
+            .squeeze::<3>(0)
+        },
+    )(input.clone(), weight.clone());
+
+    for sample in 0..2 {
+        let expected = conv2d(
+            input.clone().slice([sample..sample + 1]),
+            weight.clone().slice([sample..sample + 1]).squeeze(0),
+            None,
+            ConvOptions::new([1, 1], [0, 0], [1, 1], 1),
+        );
+
+        output
+            .clone()
+            .slice([sample..sample + 1])
+            .into_data()
+            .assert_eq(&expected.into_data(), false);
+    }
+}
+
+#[test]
+fn should_backward_through_mapped_functions() {
+    let device = Default::default();
+    let lhs = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device)
+        .require_grad();
+    let rhs = Tensor::<TestAutodiffBackend, 2>::from_floats([[5.0, 6.0], [7.0, 8.0]], &device)
+        .require_grad();
+
+    let output: Tensor<TestAutodiffBackend, 2> =
+        vmap2(dot::<Vmap<TestAutodiffBackend>>)(lhs.clone(), rhs.clone());
+    let grads = output.sum().backward();
+
+    lhs.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&rhs.clone().inner().into_data(), false);
+    rhs.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&lhs.inner().into_data(), false);
+}
+
+#[test]
+fn should_compute_per_sample_gradients() {
+    let device = Default::default();
+    let weight = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, -1.0]], &device);
+    let input = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0], [3.0, 5.0]], &device);
+
+    let output: Tensor<TestAutodiffBackend, 2> = vmap2(
+        |x: Tensor<Vmap<TestAutodiffBackend>, 1>, weight: Tensor<Vmap<TestAutodiffBackend>, 1>| {
+            let weight = weight.detach().require_grad();
+            let loss = dot(x, weight.clone()).powf_scalar(2.0);
+            let grads = loss.backward();
+
+            Tensor::from_inner(weight.grad(&grads).unwrap())
+        },
+    )(input, weight.expand([2, 2]));
+
+    // The gradient of (x . w)^2 with respect to w is 2 (x . w) x.
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[-2.0, -4.0], [-12.0, -20.0]]), false);
+}
+
+#[test]
+fn should_sum_gradients_of_shared_tensors() {
+    let device = Default::default();
+    let weight = Tensor::<TestAutodiffBackend, 1>::from_floats([1.0, -1.0], &device).require_grad();
+    let input = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0], [3.0, 5.0]], &device);
+
+    let output: Tensor<TestAutodiffBackend, 2> = vmap(|x: Tensor<Vmap<TestAutodiffBackend>, 1>| {
+        dot(x, shared(weight.clone())).powf_scalar(2.0)
+    })(input);
+    let grads = output.sum().backward();
+
+    weight
+        .grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([-14.0, -24.0]), false);
+}
+
+#[test]
+fn should_read_data_of_shared_tensors() {
+    let device = Default::default();
+    let input = Tensor::<TestBackend, 2>::from_floats([[1.0], [2.0]], &device);
+
+    let output: Tensor<TestBackend, 2> = vmap(|x: Tensor<Vmap<TestBackend>, 1>| {
+        let scale = Tensor::<Vmap<TestBackend>, 1>::from_floats([3.0], &x.device());
+        let value = scale.into_scalar();
+
+        x.mul_scalar(value)
+    })(input);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[3.0], [6.0]]), false);
+}
+
+#[test]
+#[should_panic = "Can't read the data of a batch of 2 tensors inside a mapped function."]
+fn should_not_read_data_of_batched_tensors() {
+    let device = Default::default();
+    let input = Tensor::<TestBackend, 2>::from_floats([[1.0], [2.0]], &device);
+
+    let _: Tensor<TestBackend, 2> = vmap(|x: Tensor<Vmap<TestBackend>, 1>| {
+        let value = x.clone().into_scalar();
+
+        x.mul_scalar(value)
+    })(input);
+}
@@ -0,0 +1,121 @@
+use crate::{
+    ops::{batch_shape, expand_batch, sample_shape, RANK},
+    Vmap,
+};
+use burn_tensor::{backend::Backend, Float, Shape, Tensor};
+
+/// Lifts a function written for tensors of rank `D` into a function mapping it over the first
+/// dimension of tensors of rank `DB`, which must be `D + 1`.
+///
+/// The function is called once, with a tensor of the [Vmap] backend holding the whole batch, so
+/// it must be generic over the backend. Tensors it creates or [shares](shared) are the same for
+/// all samples.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// fn normalize<B: Backend>(tensor: Tensor<B, 1>) -> Tensor<B, 1> {
+///     let norm = tensor.clone().powf_scalar(2.0).sum().sqrt();
+///     tensor.div(norm)
+/// }
+///
+/// // Normalizes each row of a `[batch_size, size]` tensor.
+/// let output: Tensor<B, 2> = vmap(normalize::<Vmap<B>>)(input);
+/// ```
+pub fn vmap<B, F, const D: usize, const DB: usize, const O: usize, const OB: usize>(
+    func: F,
+) -> impl Fn(Tensor<B, DB>) -> Tensor<B, OB>
+where
+    B: Backend,
+    F: Fn(Tensor<Vmap<B>, D>) -> Tensor<Vmap<B>, O>,
+{
+    move |input| {
+        let batch_size = input.dims()[0];
+
+        unbatched(func(batched(input)), batch_size)
+    }
+}
+
+/// Lifts a function written for two tensors of ranks `D1` and `D2` into a function mapping it
+/// over the first dimension of tensors of ranks `D1 + 1` and `D2 + 1`.
+///
+/// See [vmap] for more details.
+pub fn vmap2<
+    B,
+    F,
+    const D1: usize,
+    const DB1: usize,
+    const D2: usize,
+    const DB2: usize,
+    const O: usize,
+    const OB: usize,
+>(
+    func: F,
+) -> impl Fn(Tensor<B, DB1>, Tensor<B, DB2>) -> Tensor<B, OB>
+where
+    B: Backend,
+    F: Fn(Tensor<Vmap<B>, D1>, Tensor<Vmap<B>, D2>) -> Tensor<Vmap<B>, O>,
+{
+    move |lhs, rhs| {
+        let batch_size = lhs.dims()[0];
+        assert_eq!(
+            batch_size,
+            rhs.dims()[0],
+            "Can't map over batches of different sizes."
+        );
+
+        unbatched(func(batched(lhs), batched(rhs)), batch_size)
+    }
+}
+
+/// Maps each sample of the first dimension of a tensor of rank `DB` to a tensor of rank `D`,
+/// which must be `DB - 1`.
+pub fn batched<B: Backend, const D: usize, const DB: usize>(
+    tensor: Tensor<B, DB>,
+) -> Tensor<Vmap<B>, D> {
+    assert_eq!(
+        DB,
+        D + 1,
+        "A batch of tensors of rank {D} must have a rank of {}, got {DB}.",
+        D + 1
+    );
+    let dims = tensor.dims();
+    let mut sample = [0; D];
+    sample.copy_from_slice(&dims[1..]);
+    let shape = batch_shape(dims[0], Shape::new(sample));
+
+    Tensor::from_primitive(B::float_reshape(tensor.into_primitive(), shape))
+}
+
+/// Shares a tensor with all the samples of a batch, without copying it.
+///
+/// This is how tensors captured by a mapped function, such as the parameters of a model, should
+/// be used inside it.
+pub fn shared<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<Vmap<B>, D> {
+    let shape = batch_shape(1, tensor.shape());
+
+    Tensor::from_primitive(B::float_reshape(tensor.into_primitive(), shape))
+}
+
+/// Stacks the samples of a tensor of rank `D` into a tensor of rank `DB`, which must be `D + 1`,
+/// inverting [batched].
+///
+/// A tensor [shared](shared) by all samples is repeated to the given batch size.
+pub fn unbatched<B: Backend, const D: usize, const DB: usize>(
+    tensor: Tensor<Vmap<B>, D>,
+    batch_size: usize,
+) -> Tensor<B, DB> {
+    assert_eq!(
+        DB,
+        D + 1,
+        "A batch of tensors of rank {D} must have a rank of {}, got {DB}.",
+        D + 1
+    );
+    let tensor = expand_batch::<B, Float>(tensor.into_primitive(), batch_size);
+    let shape = sample_shape::<D>(B::float_shape(&tensor));
+
+    let mut dims = [batch_size; DB];
+    dims[1..].copy_from_slice(&shape.dims);
+
+    Tensor::from_primitive(B::float_reshape::<RANK, DB>(tensor, Shape::new(dims)))
+}
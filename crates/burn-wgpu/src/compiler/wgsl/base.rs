@@ -46,8 +46,11 @@ pub enum Variable {
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub enum Elem {
     F32,
+    AtomicF32,
     I32,
+    AtomicI32,
     U32,
+    AtomicU32,
     Bool,
 }
 
@@ -176,9 +179,9 @@ impl Item {
 impl Elem {
     pub fn size(&self) -> usize {
         match self {
-            Self::F32 => core::mem::size_of::<f32>(),
-            Self::I32 => core::mem::size_of::<i32>(),
-            Self::U32 => core::mem::size_of::<u32>(),
+            Self::F32 | Self::AtomicF32 => core::mem::size_of::<f32>(),
+            Self::I32 | Self::AtomicI32 => core::mem::size_of::<i32>(),
+            Self::U32 | Self::AtomicU32 => core::mem::size_of::<u32>(),
             Self::Bool => core::mem::size_of::<bool>(),
         }
     }
@@ -188,8 +191,12 @@ impl Display for Elem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::F32 => f.write_str("f32"),
+            // Atomic floats are stored as their bits, and updated with compare-exchange loops.
+            Self::AtomicF32 => f.write_str("atomic<u32>"),
             Self::I32 => f.write_str("i32"),
+            Self::AtomicI32 => f.write_str("atomic<i32>"),
             Self::U32 => f.write_str("u32"),
+            Self::AtomicU32 => f.write_str("atomic<u32>"),
             Self::Bool => f.write_str("bool"),
         }
     }
@@ -229,9 +236,9 @@ impl Display for Variable {
                 f.write_fmt(format_args!("scalars_{elem}[{number}]"))
             }
             Variable::ConstantScalar(number, elem) => match elem {
-                Elem::F32 => f.write_fmt(format_args!("{number}f")),
-                Elem::I32 => f.write_fmt(format_args!("{number}i")),
-                Elem::U32 => f.write_fmt(format_args!("{number}u")),
+                Elem::F32 | Elem::AtomicF32 => f.write_fmt(format_args!("{number}f")),
+                Elem::I32 | Elem::AtomicI32 => f.write_fmt(format_args!("{number}i")),
+                Elem::U32 | Elem::AtomicU32 => f.write_fmt(format_args!("{number}u")),
                 Elem::Bool => f.write_fmt(format_args!("bool({number})")),
             },
             Variable::SharedMemory(number, _, _) => {
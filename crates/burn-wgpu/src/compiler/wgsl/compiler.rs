@@ -116,11 +116,20 @@ impl WgslCompiler {
                 cube::FloatKind::F32 => wgsl::Elem::F32,
                 cube::FloatKind::F64 => panic!("f64 is not a valid WgpuElement"),
             },
+            cube::Elem::AtomicFloat(f) => match f {
+                cube::FloatKind::F32 => wgsl::Elem::AtomicF32,
+                _ => panic!("atomic<{f:?}> is not a valid WgpuElement"),
+            },
             cube::Elem::Int(i) => match i {
                 cube::IntKind::I32 => wgsl::Elem::I32,
                 cube::IntKind::I64 => panic!("i64 is not a valid WgpuElement"),
             },
+            cube::Elem::AtomicInt(i) => match i {
+                cube::IntKind::I32 => wgsl::Elem::AtomicI32,
+                cube::IntKind::I64 => panic!("atomic<i64> is not a valid WgpuElement"),
+            },
             cube::Elem::UInt => wgsl::Elem::U32,
+            cube::Elem::AtomicUInt => wgsl::Elem::AtomicU32,
             cube::Elem::Bool => wgsl::Elem::Bool,
         }
     }
@@ -640,6 +649,12 @@ impl WgslCompiler {
                 rhs: self.compile_variable(op.rhs),
                 out: self.compile_variable(op.out),
             },
+            cube::Operator::AtomicAdd(op) => wgsl::Instruction::AtomicAdd {
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                value: self.compile_variable(op.value),
+                out: self.compile_variable(op.out),
+            },
         }
     }
 
@@ -1,5 +1,5 @@
 use super::{
-    base::{Item, Variable},
+    base::{Elem, Item, Variable},
     Subgroup,
 };
 use std::fmt::Display;
@@ -226,6 +226,12 @@ pub enum Instruction {
         rhs: Variable,
         out: Variable,
     },
+    AtomicAdd {
+        array: Variable,
+        index: Variable,
+        value: Variable,
+        out: Variable,
+    },
     Subgroup(Subgroup),
 }
 
@@ -262,6 +268,33 @@ impl Display for Instruction {
             Instruction::Remainder { lhs, rhs, out } => {
                 f.write_fmt(format_args!("{out} = (({lhs} % {rhs}) + {rhs}) % {rhs};\n"))
             }
+            Instruction::AtomicAdd {
+                array,
+                index,
+                value,
+                out,
+            } => match array.elem() {
+                // WGSL only has integer atomics, floats are added with a compare-exchange loop on
+                // their bits.
+                Elem::AtomicF32 => f.write_fmt(format_args!(
+                    "
+{{
+    var old_bits = atomicLoad(&{array}[{index}]);
+    loop {{
+        let exchange = atomicCompareExchangeWeak(&{array}[{index}], old_bits, bitcast<u32>(bitcast<f32>(old_bits) + {value}));
+        if exchange.exchanged {{
+            break;
+        }}
+        old_bits = exchange.old_value;
+    }}
+    {out} = bitcast<f32>(old_bits);
+}}
+"
+                )),
+                _ => f.write_fmt(format_args!(
+                    "{out} = atomicAdd(&{array}[{index}], {value});\n"
+                )),
+            },
             Instruction::Sub { lhs, rhs, out } => {
                 f.write_fmt(format_args!("{out} = {lhs} - {rhs};\n"))
             }